@@ -11,6 +11,7 @@ const BLOCKED_MCP_DESCRIPTION_TERMS: &[&str] = &[
     "`who sync`",
     "`update [--check]`",
     "`uninstall`",
+    "--vcf <path>",
 ];
 const STUDY_PATTERN_LINE: &str = "- `study list|download|query|co-occurrence|cohort|survival|compare` - local cBioPortal study analytics";
 const MCP_SAFE_STUDY_PATTERN_LINE: &str = "- `study list|download --list|query|filter|co-occurrence|cohort|survival|compare` - local cBioPortal study analytics";
@@ -34,6 +35,24 @@ fn command_output(command: &str, args: &[&str]) -> Option<String> {
 fn is_blocked_mcp_description_line(line: &str) -> bool {
     // Cache-family commands stay CLI-only because they reveal workstation-local paths.
     line.trim_start().starts_with("- `cache ")
+        // Bookmarks read and write the local config directory, so they stay CLI-only too.
+        || line.trim_start().starts_with("- `bookmark ")
+        // Query history lives in the local cache directory as well.
+        || line.trim_start().starts_with("- `history ")
+        // Gene snapshots for `diff` are written there too.
+        || line.trim_start().starts_with("- `diff ")
+        // Scheduled jobs live beside bookmarks and run local commands.
+        || line.trim_start().starts_with("- `schedule ")
+        // Exec streams read the server's own stdin.
+        || line.trim_start().starts_with("- `exec ")
+        // Offline snapshot bundles are written to the local cache directory.
+        || line.trim_start().starts_with("- `snapshot ")
+        // Annotation commands read local result files, so they stay CLI-only.
+        || line.trim_start().starts_with("- `annotate ")
+        // Repurposing reads local gene list files as well.
+        || line.trim_start().starts_with("- `repurpose ")
+        // Trial matching reads a local patient profile.
+        || line.trim_start().starts_with("- `match ")
         || BLOCKED_MCP_DESCRIPTION_TERMS
             .iter()
             .any(|term| line.contains(term))
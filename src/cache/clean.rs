@@ -1,11 +1,12 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::time::Duration;
 
 use ssri::Integrity;
 
 use super::{
-    CachePlannerError, CacheSnapshot, ConfigOrigin, ResolvedCacheConfig, plan_composite_cleanup,
+    CacheCleanupPlan, CachePlannerError, CacheSnapshot, ConfigOrigin, ResolvedCacheConfig,
+    forget_cache_tags, load_cache_tags, plan_composite_cleanup, plan_entry_removals,
     snapshot_cache,
 };
 use crate::error::BioMcpError;
@@ -49,8 +50,8 @@ fn execute_cache_clean_with<S, RK, RB>(
     config: &ResolvedCacheConfig,
     now_ms: u128,
     snapshotter: S,
-    mut remove_key: RK,
-    mut remove_blob: RB,
+    remove_key: RK,
+    remove_blob: RB,
 ) -> Result<CleanReport, BioMcpError>
 where
     S: FnOnce(&Path) -> Result<CacheSnapshot, CachePlannerError>,
@@ -64,9 +65,107 @@ where
     let snapshot =
         snapshotter(cache_path).map_err(|err| BioMcpError::Io(std::io::Error::other(err)))?;
     let plan = plan_composite_cleanup(&snapshot, now_ms, effective_max_age, effective_max_size);
+    Ok(apply_cleanup_plan(
+        cache_path,
+        &plan,
+        options.dry_run,
+        remove_key,
+        remove_blob,
+    ))
+}
+
+/// Removes entries older than `older_than` and every orphan blob, deleting the
+/// index buckets outright instead of appending tombstones so purged request
+/// keys do not linger in the cache index.
+pub(crate) fn execute_cache_purge(
+    cache_path: &Path,
+    older_than: Duration,
+    dry_run: bool,
+    now_ms: u128,
+) -> Result<CleanReport, BioMcpError> {
+    execute_cache_purge_with(
+        cache_path,
+        older_than,
+        dry_run,
+        now_ms,
+        snapshot_cache,
+        |path, key| {
+            cacache::RemoveOpts::new()
+                .remove_fully(true)
+                .remove_sync(path, key)
+        },
+        |path, integrity| cacache::remove_hash_sync(path, integrity),
+    )
+}
+
+/// Removes every entry attributed to `source` in the tag index, along with the
+/// blobs only they referenced, deleting index buckets outright like purge.
+pub(crate) fn execute_cache_clear_source(
+    cache_path: &Path,
+    source: &str,
+) -> Result<CleanReport, BioMcpError> {
+    let snapshot =
+        snapshot_cache(cache_path).map_err(|err| BioMcpError::Io(std::io::Error::other(err)))?;
+    let tags = load_cache_tags(cache_path)?;
+    let entries = snapshot
+        .entries
+        .iter()
+        .filter(|entry| tags.tag_for(&entry.key).source == source)
+        .cloned()
+        .collect();
+    let plan = plan_entry_removals(&snapshot, entries);
+    Ok(apply_cleanup_plan(
+        cache_path,
+        &plan,
+        false,
+        |path, key| {
+            cacache::RemoveOpts::new()
+                .remove_fully(true)
+                .remove_sync(path, key)
+        },
+        |path, integrity| cacache::remove_hash_sync(path, integrity),
+    ))
+}
 
-    if options.dry_run {
-        return Ok(CleanReport {
+fn execute_cache_purge_with<S, RK, RB>(
+    cache_path: &Path,
+    older_than: Duration,
+    dry_run: bool,
+    now_ms: u128,
+    snapshotter: S,
+    remove_key: RK,
+    remove_blob: RB,
+) -> Result<CleanReport, BioMcpError>
+where
+    S: FnOnce(&Path) -> Result<CacheSnapshot, CachePlannerError>,
+    RK: for<'a, 'b> FnMut(&'a Path, &'b str) -> Result<(), cacache::Error>,
+    RB: for<'a, 'b> FnMut(&'a Path, &'b Integrity) -> Result<(), cacache::Error>,
+{
+    let snapshot =
+        snapshotter(cache_path).map_err(|err| BioMcpError::Io(std::io::Error::other(err)))?;
+    let plan = plan_composite_cleanup(&snapshot, now_ms, Some(older_than), None);
+    Ok(apply_cleanup_plan(
+        cache_path,
+        &plan,
+        dry_run,
+        remove_key,
+        remove_blob,
+    ))
+}
+
+fn apply_cleanup_plan<RK, RB>(
+    cache_path: &Path,
+    plan: &CacheCleanupPlan,
+    dry_run: bool,
+    mut remove_key: RK,
+    mut remove_blob: RB,
+) -> CleanReport
+where
+    RK: for<'a, 'b> FnMut(&'a Path, &'b str) -> Result<(), cacache::Error>,
+    RB: for<'a, 'b> FnMut(&'a Path, &'b Integrity) -> Result<(), cacache::Error>,
+{
+    if dry_run {
+        return CleanReport {
             dry_run: true,
             orphans_removed: plan
                 .blob_removals
@@ -76,7 +175,7 @@ where
             entries_removed: plan.entry_removals.len(),
             bytes_freed: plan.reclaimed_blob_bytes,
             errors: Vec::new(),
-        });
+        };
     }
 
     let mut planned_key_count_by_integrity = HashMap::new();
@@ -87,6 +186,7 @@ where
     }
 
     let mut successful_key_count_by_integrity = HashMap::new();
+    let mut removed_keys = HashSet::new();
     let mut entries_removed = 0usize;
     let mut orphans_removed = 0usize;
     let mut bytes_freed = 0u64;
@@ -96,6 +196,7 @@ where
         match remove_key(cache_path, &entry.key) {
             Ok(()) => {
                 entries_removed += 1;
+                removed_keys.insert(entry.key.as_str());
                 *successful_key_count_by_integrity
                     .entry(entry.integrity.clone())
                     .or_insert(0usize) += 1;
@@ -141,13 +242,19 @@ where
         }
     }
 
-    Ok(CleanReport {
+    if !removed_keys.is_empty()
+        && let Err(err) = forget_cache_tags(cache_path, &removed_keys)
+    {
+        errors.push(format!("failed to update cache tag index: {err}"));
+    }
+
+    CleanReport {
         dry_run: false,
         orphans_removed,
         entries_removed,
         bytes_freed,
         errors,
-    })
+    }
 }
 
 fn resolve_effective_limit<T: Copy>(
@@ -169,7 +276,10 @@ mod tests {
 
     use ssri::Integrity;
 
-    use super::{CleanOptions, CleanReport, execute_cache_clean, execute_cache_clean_with};
+    use super::{
+        CleanOptions, CleanReport, execute_cache_clean, execute_cache_clean_with,
+        execute_cache_clear_source, execute_cache_purge,
+    };
     use crate::cache::{
         CacheConfigOrigins, CachePlannerError, ConfigOrigin, DiskFreeThreshold,
         ResolvedCacheConfig, snapshot_cache,
@@ -247,6 +357,7 @@ mod tests {
             max_size,
             min_disk_free: DiskFreeThreshold::Percent(10),
             max_age,
+            encrypt: false,
             origins: CacheConfigOrigins {
                 cache_root: ConfigOrigin::Default,
                 max_size: max_size_origin,
@@ -378,6 +489,92 @@ mod tests {
         assert!(!blob_path_for_integrity(&cache_path, &old).exists());
     }
 
+    fn index_mentions(cache_path: &Path, needle: &str) -> bool {
+        fn visit(path: &Path, needle: &str) -> bool {
+            let Ok(entries) = fs::read_dir(path) else {
+                return false;
+            };
+            entries.flatten().any(|entry| {
+                let path = entry.path();
+                if path.is_dir() {
+                    visit(&path, needle)
+                } else {
+                    fs::read_to_string(&path).is_ok_and(|text| text.contains(needle))
+                }
+            })
+        }
+        visit(&cache_path.join("index-v5"), needle)
+    }
+
+    #[test]
+    fn cache_purge_removes_old_entries_without_leaving_index_tombstones() {
+        let root = TempDirGuard::new("purge");
+        let cache_path = root.http_dir();
+        let old = write_entry(&cache_path, "GET:https://example.test/old", b"old", 100);
+        let _ = write_entry(&cache_path, "GET:https://example.test/new", b"new", 900);
+        let orphan = make_orphan(&cache_path, "orphan", b"orphan", 950);
+
+        let preview = execute_cache_purge(&cache_path, Duration::from_millis(500), true, 1_000)
+            .expect("purge dry run should succeed");
+        assert!(preview.dry_run);
+        assert_eq!(preview.entries_removed, 1);
+        assert!(blob_path_for_integrity(&cache_path, &old).exists());
+
+        let report = execute_cache_purge(&cache_path, Duration::from_millis(500), false, 1_000)
+            .expect("purge should succeed");
+        assert_eq!(report.entries_removed, 1);
+        assert_eq!(report.orphans_removed, 1);
+        assert_eq!(
+            snapshot_keys(&cache_path),
+            vec!["GET:https://example.test/new"]
+        );
+        assert!(!blob_path_for_integrity(&cache_path, &old).exists());
+        assert!(!blob_path_for_integrity(&cache_path, &orphan).exists());
+        assert!(!index_mentions(&cache_path, "example.test/old"));
+        assert!(index_mentions(&cache_path, "example.test/new"));
+    }
+
+    #[tokio::test]
+    async fn cache_clear_source_removes_only_that_sources_entries_and_tags() {
+        let root = TempDirGuard::new("clear-source");
+        let cache_path = root.http_dir();
+        let gene = write_entry(
+            &cache_path,
+            "GET:https://mygene.info/v3/gene/673",
+            b"gene",
+            100,
+        );
+        let _ = write_entry(
+            &cache_path,
+            "GET:https://rest.uniprot.org/uniprotkb/P15056",
+            b"protein",
+            100,
+        );
+        let sealed = write_entry(&cache_path, "enc:0123abcd", b"sealed", 100);
+        crate::cache::record_cache_tag(
+            &cache_path,
+            "enc:0123abcd",
+            "GET:https://mygene.info/v3/query?q=BRAF",
+        )
+        .await;
+
+        let report =
+            execute_cache_clear_source(&cache_path, "mygene").expect("clear source should succeed");
+
+        assert_eq!(report.entries_removed, 2);
+        assert_eq!(report.bytes_freed, (b"gene".len() + b"sealed".len()) as u64);
+        assert!(report.errors.is_empty());
+        assert_eq!(
+            snapshot_keys(&cache_path),
+            vec!["GET:https://rest.uniprot.org/uniprotkb/P15056"]
+        );
+        assert!(!blob_path_for_integrity(&cache_path, &gene).exists());
+        assert!(!blob_path_for_integrity(&cache_path, &sealed).exists());
+        assert!(!index_mentions(&cache_path, "mygene.info"));
+        let tags = fs::read_to_string(cache_path.join("tags-v1.jsonl")).expect("tag index");
+        assert!(!tags.contains("enc:0123abcd"));
+    }
+
     #[test]
     fn cache_clean_size_cleanup_uses_explicit_config_without_flag() {
         let root = TempDirGuard::new("size-origin");
@@ -1,10 +1,12 @@
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::RwLock;
 use std::time::Duration;
 
 use bytesize::ByteSize;
 use serde::Deserialize;
 
+use super::paths::{long_path, normalize_cache_dir};
 use crate::error::BioMcpError;
 
 const DEFAULT_MAX_SIZE: u64 = 10_000_000_000;
@@ -39,6 +41,7 @@ impl DiskFreeThreshold {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ConfigOrigin {
+    Cli,
     Env,
     File,
     Default,
@@ -58,6 +61,8 @@ pub(crate) struct ResolvedCacheConfig {
     pub(crate) max_size: u64,
     pub(crate) min_disk_free: DiskFreeThreshold,
     pub(crate) max_age: Duration,
+    /// Seal cached responses with AES-256-GCM (see `cache::crypto`).
+    pub(crate) encrypt: bool,
     pub(crate) origins: CacheConfigOrigins,
 }
 
@@ -77,12 +82,39 @@ struct CacheTomlSection {
     max_size: Option<u64>,
     min_disk_free: Option<String>,
     max_age_secs: Option<u64>,
+    encrypt: Option<bool>,
+}
+
+static CACHE_DIR_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Sets the process-wide `--cache-dir` override, which wins over
+/// `BIOMCP_CACHE_DIR` and `cache.toml`. Call before the first HTTP request.
+pub(crate) fn set_cache_dir_override(dir: Option<&str>) -> Result<(), BioMcpError> {
+    let dir = dir
+        .map(|dir| {
+            normalize_cache_dir(dir).map_err(|message| {
+                BioMcpError::InvalidArgument(format!("--cache-dir {message}: got '{dir}'"))
+            })
+        })
+        .transpose()?;
+    *CACHE_DIR_OVERRIDE
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = dir;
+    Ok(())
+}
+
+fn cache_dir_override() -> Option<PathBuf> {
+    CACHE_DIR_OVERRIDE
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
 }
 
 pub(crate) fn resolve_cache_config() -> Result<CacheConfig, BioMcpError> {
     let env_dir = std::env::var("BIOMCP_CACHE_DIR").ok();
     let env_max_size = std::env::var("BIOMCP_CACHE_MAX_SIZE").ok();
     let env_min_disk_free = std::env::var("BIOMCP_CACHE_MIN_DISK_FREE").ok();
+    let env_encrypt = std::env::var("BIOMCP_CACHE_ENCRYPT").ok();
     let default_cache_root = default_cache_root();
     let config_path = config_file_path();
     let toml_content = match config_path.as_deref() {
@@ -90,14 +122,22 @@ pub(crate) fn resolve_cache_config() -> Result<CacheConfig, BioMcpError> {
         None => None,
     };
 
-    resolve_cache_config_with_source(
+    let mut config = resolve_cache_config_with_source(
         env_dir.as_deref(),
         env_max_size.as_deref(),
         env_min_disk_free.as_deref(),
         toml_content.as_deref(),
         default_cache_root,
         config_path.as_deref(),
-    )
+    )?;
+    if let Some(encrypt) = parse_env_encrypt(env_encrypt.as_deref())? {
+        config.encrypt = encrypt;
+    }
+    if let Some(dir) = cache_dir_override() {
+        config.cache_root = dir;
+        config.origins.cache_root = ConfigOrigin::Cli;
+    }
+    Ok(config)
 }
 
 fn resolve_cache_config_from_parts(
@@ -117,9 +157,11 @@ fn resolve_cache_config_from_parts(
 }
 
 fn default_cache_root() -> PathBuf {
-    dirs::cache_dir()
-        .unwrap_or_else(std::env::temp_dir)
-        .join("biomcp")
+    long_path(
+        dirs::cache_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join("biomcp"),
+    )
 }
 
 fn config_file_path() -> Option<PathBuf> {
@@ -141,11 +183,12 @@ fn resolve_cache_config_with_source(
                 max_size: toml_max_size,
                 min_disk_free: toml_min_disk_free,
                 max_age_secs: toml_max_age_secs,
+                encrypt: toml_encrypt,
             },
     } = parse_cache_toml(toml_content, config_path)?;
 
-    let (cache_root, cache_root_origin) = if let Some(dir) = normalize_env_value(env_dir) {
-        (PathBuf::from(dir), ConfigOrigin::Env)
+    let (cache_root, cache_root_origin) = if let Some(dir) = parse_env_dir(env_dir)? {
+        (dir, ConfigOrigin::Env)
     } else if let Some(dir) = parse_toml_dir(toml_dir.as_deref(), config_path)? {
         (dir, ConfigOrigin::File)
     } else {
@@ -186,6 +229,7 @@ fn resolve_cache_config_with_source(
         max_size,
         min_disk_free,
         max_age: Duration::from_secs(max_age_secs),
+        encrypt: toml_encrypt.unwrap_or(false),
         origins: CacheConfigOrigins {
             cache_root: cache_root_origin,
             max_size: max_size_origin,
@@ -226,16 +270,29 @@ fn normalize_env_value(value: Option<&str>) -> Option<&str> {
     value.map(str::trim).filter(|value| !value.is_empty())
 }
 
+fn parse_env_dir(value: Option<&str>) -> Result<Option<PathBuf>, BioMcpError> {
+    let Some(value) = normalize_env_value(value) else {
+        return Ok(None);
+    };
+
+    normalize_cache_dir(value).map(Some).map_err(|message| {
+        BioMcpError::InvalidArgument(format!("BIOMCP_CACHE_DIR {message}: got '{value}'"))
+    })
+}
+
 fn parse_env_max_size(value: Option<&str>) -> Result<Option<u64>, BioMcpError> {
     let Some(value) = normalize_env_value(value) else {
         return Ok(None);
     };
 
-    let parsed = value.parse::<u64>().map_err(|_| {
-        BioMcpError::InvalidArgument(format!(
-            "BIOMCP_CACHE_MAX_SIZE must be a positive integer number of bytes: got '{value}'"
-        ))
-    })?;
+    let parsed = value
+        .parse::<bytesize::ByteSize>()
+        .map(|size| size.as_u64())
+        .map_err(|_| {
+            BioMcpError::InvalidArgument(format!(
+                "BIOMCP_CACHE_MAX_SIZE must be a number of bytes or a size like 5G or 500M: got '{value}'"
+            ))
+        })?;
 
     if parsed == 0 {
         return Err(BioMcpError::InvalidArgument(
@@ -246,6 +303,20 @@ fn parse_env_max_size(value: Option<&str>) -> Result<Option<u64>, BioMcpError> {
     Ok(Some(parsed))
 }
 
+fn parse_env_encrypt(value: Option<&str>) -> Result<Option<bool>, BioMcpError> {
+    let Some(value) = normalize_env_value(value) else {
+        return Ok(None);
+    };
+
+    match value.to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Ok(Some(true)),
+        "0" | "false" | "no" | "off" => Ok(Some(false)),
+        _ => Err(BioMcpError::InvalidArgument(format!(
+            "BIOMCP_CACHE_ENCRYPT must be true or false: got '{value}'"
+        ))),
+    }
+}
+
 fn parse_env_min_disk_free(value: Option<&str>) -> Result<Option<DiskFreeThreshold>, BioMcpError> {
     let Some(value) = normalize_env_value(value) else {
         return Ok(None);
@@ -268,15 +339,9 @@ fn parse_toml_dir(
         return Ok(None);
     };
 
-    let trimmed = value.trim();
-    if trimmed.is_empty() {
-        return Err(invalid_config(
-            config_path,
-            "[cache].dir must not be blank".into(),
-        ));
-    }
-
-    Ok(Some(PathBuf::from(trimmed)))
+    normalize_cache_dir(value)
+        .map(Some)
+        .map_err(|message| invalid_config(config_path, format!("[cache].dir {message}")))
 }
 
 fn parse_toml_positive_u64(
@@ -352,8 +417,8 @@ fn config_source_label(config_path: Option<&std::path::Path>) -> String {
 mod tests {
     use super::{
         CacheConfig, CacheConfigOrigins, ConfigOrigin, DEFAULT_MAX_AGE_SECS, DEFAULT_MAX_SIZE,
-        DEFAULT_MIN_DISK_FREE, DiskFreeThreshold, default_cache_root, resolve_cache_config,
-        resolve_cache_config_from_parts,
+        DEFAULT_MIN_DISK_FREE, DiskFreeThreshold, default_cache_root, parse_env_encrypt,
+        resolve_cache_config, resolve_cache_config_from_parts, set_cache_dir_override,
     };
     use crate::error::BioMcpError;
     use std::path::{Path, PathBuf};
@@ -427,6 +492,7 @@ mod tests {
             max_size: DEFAULT_MAX_SIZE,
             min_disk_free: DEFAULT_MIN_DISK_FREE,
             max_age: Duration::from_secs(DEFAULT_MAX_AGE_SECS),
+            encrypt: false,
             origins: CacheConfigOrigins {
                 cache_root: ConfigOrigin::Default,
                 max_size: ConfigOrigin::Default,
@@ -482,6 +548,27 @@ mod tests {
         assert_eq!(config.origins.max_size, ConfigOrigin::Env);
     }
 
+    #[test]
+    fn env_max_size_accepts_unit_suffixes() {
+        let config = resolve_cache_config_from_parts(
+            None,
+            Some("5G"),
+            None,
+            PathBuf::from("/tmp/default-cache"),
+        )
+        .expect("ok");
+        assert_eq!(config.max_size, 5_000_000_000);
+
+        let config = resolve_cache_config_from_parts(
+            None,
+            Some("500 MiB"),
+            None,
+            PathBuf::from("/tmp/default-cache"),
+        )
+        .expect("ok");
+        assert_eq!(config.max_size, 500 * 1024 * 1024);
+    }
+
     #[test]
     fn toml_dir_overrides_default() {
         let config = resolve_cache_config_from_parts(
@@ -521,6 +608,27 @@ mod tests {
         assert_eq!(config.origins.max_age, ConfigOrigin::File);
     }
 
+    #[test]
+    fn toml_encrypt_enables_cache_encryption() {
+        let config = resolve_cache_config_from_parts(
+            None,
+            None,
+            Some("[cache]\nencrypt = true\n"),
+            PathBuf::from("/tmp/default-cache"),
+        )
+        .expect("ok");
+        assert!(config.encrypt);
+    }
+
+    #[test]
+    fn env_encrypt_accepts_boolean_words_and_rejects_others() {
+        assert_eq!(parse_env_encrypt(Some(" on ")).expect("ok"), Some(true));
+        assert_eq!(parse_env_encrypt(Some("0")).expect("ok"), Some(false));
+        assert_eq!(parse_env_encrypt(Some("  ")).expect("ok"), None);
+        let err = parse_env_encrypt(Some("maybe")).expect_err("invalid value");
+        assert!(err.to_string().contains("BIOMCP_CACHE_ENCRYPT"));
+    }
+
     #[test]
     fn origins_track_mixed_precedence_without_max_age_env_override() {
         let config = resolve_cache_config_from_parts(
@@ -676,6 +784,7 @@ mod tests {
         let _cache_dir = set_env_var("BIOMCP_CACHE_DIR", None);
         let _cache_size = set_env_var("BIOMCP_CACHE_MAX_SIZE", None);
         let _min_disk_free = set_env_var("BIOMCP_CACHE_MIN_DISK_FREE", None);
+        let _encrypt = set_env_var("BIOMCP_CACHE_ENCRYPT", None);
 
         let config = resolve_cache_config().expect("defaults should resolve");
         assert_eq!(config, default_config_with_root(default_cache_root()));
@@ -720,6 +829,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cache_dir_override_wins_over_env_and_rejects_blank_values() {
+        let _lock = env_lock();
+        let root = TempDirGuard::new("cli-override");
+        let _config_home = set_env_var(
+            "XDG_CONFIG_HOME",
+            Some(&root.path().join("config-home").to_string_lossy()),
+        );
+        let _cache_dir = set_env_var(
+            "BIOMCP_CACHE_DIR",
+            Some(&root.path().join("env-cache").to_string_lossy()),
+        );
+        let cli_dir = root.path().join("cli cache");
+
+        set_cache_dir_override(Some(&format!("\"{}\"", cli_dir.display())))
+            .expect("override should apply");
+        let config = resolve_cache_config();
+        set_cache_dir_override(None).expect("override should reset");
+        let config = config.expect("override should resolve");
+        assert_eq!(config.cache_root, cli_dir);
+        assert_eq!(config.origins.cache_root, ConfigOrigin::Cli);
+
+        let err = set_cache_dir_override(Some("  ")).expect_err("blank override");
+        assert!(err.to_string().contains("--cache-dir must not be blank"));
+        assert_eq!(
+            resolve_cache_config().expect("env dir").origins.cache_root,
+            ConfigOrigin::Env
+        );
+    }
+
     #[test]
     fn resolve_cache_config_env_overrides_file() {
         let _lock = env_lock();
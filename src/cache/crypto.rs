@@ -0,0 +1,226 @@
+//! Optional AES-256-GCM encryption for the on-disk HTTP cache.
+//!
+//! When `[cache].encrypt` (or `BIOMCP_CACHE_ENCRYPT`) is on, every cached
+//! response is sealed before it reaches `cacache`, and index keys are stored
+//! as HMAC-SHA256 tags under a key derived from the cache key, so query URLs
+//! never land on disk in plaintext and cannot be confirmed by hashing a
+//! guessed URL. The key comes from `BIOMCP_CACHE_KEY` (base64, 32 bytes) or
+//! the OS keychain, where BioMCP creates one on first use.
+
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use ring::aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::error::BioMcpError;
+
+const KEY_ENV: &str = "BIOMCP_CACHE_KEY";
+const KEYCHAIN_SERVICE: &str = "biomcp";
+const KEYCHAIN_ACCOUNT: &str = "cache-encryption-key";
+const KEY_LEN: usize = 32;
+const MAGIC: &[u8; 4] = b"BMC1";
+const STORAGE_KEY_PREFIX: &str = "enc:";
+/// Derives the index key from the cache key, so sealing and indexing never
+/// use the same key material directly.
+const INDEX_KEY_LABEL: &[u8] = b"biomcp-cache-index";
+
+static SHARED_CIPHER: OnceLock<Arc<CacheCipher>> = OnceLock::new();
+
+/// Sealing key for encrypted cache entries.
+pub(crate) struct CacheCipher {
+    key: LessSafeKey,
+    index_key: hmac::Key,
+    rng: SystemRandom,
+}
+
+impl fmt::Debug for CacheCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CacheCipher(..)")
+    }
+}
+
+impl CacheCipher {
+    pub(crate) fn from_key_bytes(bytes: &[u8]) -> Result<Self, BioMcpError> {
+        if bytes.len() != KEY_LEN {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "{KEY_ENV} must decode to {KEY_LEN} bytes; got {}",
+                bytes.len()
+            )));
+        }
+        let key = UnboundKey::new(&AES_256_GCM, bytes).map_err(|_| {
+            BioMcpError::InvalidArgument("cache encryption key was rejected".into())
+        })?;
+        let index_key = hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, bytes), INDEX_KEY_LABEL);
+        Ok(Self {
+            key: LessSafeKey::new(key),
+            index_key: hmac::Key::new(hmac::HMAC_SHA256, index_key.as_ref()),
+            rng: SystemRandom::new(),
+        })
+    }
+
+    /// Loads the key from `BIOMCP_CACHE_KEY`, falling back to the OS keychain.
+    pub(crate) fn load() -> Result<Self, BioMcpError> {
+        let env_key = std::env::var(KEY_ENV).ok();
+        match env_key.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+            Some(encoded) => Self::from_key_bytes(&decode_key(encoded)?),
+            None => Self::from_key_bytes(&keychain_key()?),
+        }
+    }
+
+    /// The process-wide cipher when encryption is on. The key is loaded once;
+    /// startup calls this first so a missing key fails there, not per client.
+    pub(crate) fn shared(encrypt: bool) -> Result<Option<Arc<Self>>, BioMcpError> {
+        if !encrypt {
+            return Ok(None);
+        }
+        if let Some(cipher) = SHARED_CIPHER.get() {
+            return Ok(Some(Arc::clone(cipher)));
+        }
+        let cipher = Arc::new(Self::load()?);
+        Ok(Some(Arc::clone(SHARED_CIPHER.get_or_init(|| cipher))))
+    }
+
+    /// Index key for a cache entry: a keyed digest, so the URL is neither
+    /// readable on disk nor recoverable without the cache key.
+    pub(crate) fn storage_key(&self, cache_key: &str) -> String {
+        let tag = hmac::sign(&self.index_key, cache_key.as_bytes());
+        let hex = tag
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+        format!("{STORAGE_KEY_PREFIX}{hex}")
+    }
+
+    /// Encrypts `plaintext`, binding it to `cache_key` as associated data.
+    pub(crate) fn seal(&self, cache_key: &str, plaintext: &[u8]) -> Result<Vec<u8>, BioMcpError> {
+        let mut nonce = [0u8; NONCE_LEN];
+        self.rng.fill(&mut nonce).map_err(|_| {
+            BioMcpError::Io(std::io::Error::other("failed to generate cache nonce"))
+        })?;
+        let mut sealed = plaintext.to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(cache_key.as_bytes()),
+                &mut sealed,
+            )
+            .map_err(|_| BioMcpError::Io(std::io::Error::other("failed to encrypt cache entry")))?;
+
+        let mut out = Vec::with_capacity(MAGIC.len() + NONCE_LEN + sealed.len());
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&sealed);
+        Ok(out)
+    }
+
+    /// Decrypts a sealed entry; `None` when the data is not ours or was tampered with.
+    pub(crate) fn open(&self, cache_key: &str, sealed: &[u8]) -> Option<Vec<u8>> {
+        let rest = sealed.strip_prefix(MAGIC.as_slice())?;
+        if rest.len() < NONCE_LEN {
+            return None;
+        }
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).ok()?;
+        let mut buf = ciphertext.to_vec();
+        let plaintext = self
+            .key
+            .open_in_place(nonce, Aad::from(cache_key.as_bytes()), &mut buf)
+            .ok()?;
+        Some(plaintext.to_vec())
+    }
+}
+
+fn decode_key(encoded: &str) -> Result<Vec<u8>, BioMcpError> {
+    STANDARD.decode(encoded).map_err(|_| {
+        BioMcpError::InvalidArgument(format!(
+            "{KEY_ENV} must be base64 for a {KEY_LEN}-byte key (e.g. `openssl rand -base64 32`)"
+        ))
+    })
+}
+
+fn keychain_key() -> Result<Vec<u8>, BioMcpError> {
+    let keychain_error = |err: keyring::Error| {
+        BioMcpError::InvalidArgument(format!(
+            "cache encryption is enabled but the OS keychain is unavailable ({err}). \
+             Set {KEY_ENV} to a base64 32-byte key instead."
+        ))
+    };
+    let entry = keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT).map_err(keychain_error)?;
+    match entry.get_password() {
+        Ok(encoded) => decode_key(encoded.trim()),
+        Err(keyring::Error::NoEntry) => {
+            let mut key = vec![0u8; KEY_LEN];
+            SystemRandom::new().fill(&mut key).map_err(|_| {
+                BioMcpError::Io(std::io::Error::other("failed to generate cache key"))
+            })?;
+            entry
+                .set_password(&STANDARD.encode(&key))
+                .map_err(keychain_error)?;
+            Ok(key)
+        }
+        Err(err) => Err(keychain_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cipher() -> CacheCipher {
+        CacheCipher::from_key_bytes(&[7u8; KEY_LEN]).expect("cipher")
+    }
+
+    #[test]
+    fn seal_and_open_round_trip_with_matching_key() {
+        let cipher = cipher();
+        let sealed = cipher
+            .seal("GET:https://example.org/q?gene=BRAF", b"payload")
+            .expect("seal");
+        assert!(sealed.starts_with(MAGIC));
+        assert!(!sealed.windows(7).any(|window| window == b"payload"));
+        assert_eq!(
+            cipher
+                .open("GET:https://example.org/q?gene=BRAF", &sealed)
+                .as_deref(),
+            Some(b"payload".as_slice())
+        );
+    }
+
+    #[test]
+    fn open_rejects_wrong_cache_key_wrong_cipher_and_plaintext() {
+        let cipher = cipher();
+        let sealed = cipher.seal("GET:a", b"payload").expect("seal");
+        assert!(cipher.open("GET:b", &sealed).is_none());
+        let other = CacheCipher::from_key_bytes(&[8u8; KEY_LEN]).expect("cipher");
+        assert!(other.open("GET:a", &sealed).is_none());
+        assert!(cipher.open("GET:a", b"unencrypted").is_none());
+    }
+
+    #[test]
+    fn storage_key_hides_the_request_url() {
+        let key = cipher().storage_key("GET:https://example.org/q?patient=1");
+        assert!(key.starts_with("enc:"));
+        assert_eq!(key.len(), "enc:".len() + 64);
+        assert!(!key.contains("patient"));
+    }
+
+    #[test]
+    fn storage_key_depends_on_the_cache_key() {
+        let request = "GET:https://example.org/q?patient=1";
+        let other = CacheCipher::from_key_bytes(&[8u8; KEY_LEN]).expect("cipher");
+        assert_eq!(cipher().storage_key(request), cipher().storage_key(request));
+        assert_ne!(cipher().storage_key(request), other.storage_key(request));
+    }
+
+    #[test]
+    fn from_key_bytes_rejects_wrong_length() {
+        let err = CacheCipher::from_key_bytes(&[1u8; 16]).expect_err("short key");
+        assert!(err.to_string().contains("32 bytes"));
+        assert!(decode_key("not base64!").is_err());
+    }
+}
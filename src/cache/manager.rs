@@ -11,6 +11,7 @@ use http_cache_reqwest::CACacheManager;
 use http_cache_semantics::CachePolicy;
 use tracing::warn;
 
+use super::crypto::CacheCipher;
 use super::{
     CleanOptions, FilesystemSpace, ResolvedCacheConfig, evaluate_cache_limits, execute_cache_clean,
     inspect_filesystem_space, snapshot_cache, summarize_cache_usage,
@@ -35,6 +36,7 @@ pub(crate) struct SizeAwareCacheManager {
     approx_bytes: Arc<AtomicU64>,
     eviction_running: Arc<AtomicBool>,
     services: ManagerServices,
+    cipher: Option<Arc<CacheCipher>>,
 }
 
 impl SizeAwareCacheManager {
@@ -91,8 +93,39 @@ impl SizeAwareCacheManager {
             approx_bytes: Arc::new(AtomicU64::new(approx_bytes)),
             eviction_running: Arc::new(AtomicBool::new(false)),
             services,
+            cipher: None,
         }
     }
+
+    /// Seals entries at rest; index keys become keyed digests of the request key.
+    pub(crate) fn with_cipher(mut self, cipher: Option<Arc<CacheCipher>>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    async fn get_sealed(
+        &self,
+        cipher: &CacheCipher,
+        cache_key: &str,
+    ) -> http_cache::Result<Option<(HttpResponse, CachePolicy)>> {
+        let storage_key = cipher.storage_key(cache_key);
+        let sealed = match cacache::read(&self.inner.path, &storage_key).await {
+            Ok(sealed) => sealed,
+            Err(cacache::Error::EntryNotFound(..)) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let entry = cipher
+            .open(cache_key, &sealed)
+            .and_then(|plain| bincode::deserialize::<(HttpResponse, CachePolicy)>(&plain).ok());
+        if entry.is_none() {
+            warn!(
+                cache_path = %self.inner.path.display(),
+                "encrypted cache entry could not be opened with the current key; dropping it"
+            );
+            let _ = cacache::remove(&self.inner.path, &storage_key).await;
+        }
+        Ok(entry)
+    }
 }
 
 #[async_trait]
@@ -101,7 +134,10 @@ impl CacheManager for SizeAwareCacheManager {
         &self,
         cache_key: &str,
     ) -> http_cache::Result<Option<(HttpResponse, CachePolicy)>> {
-        self.inner.get(cache_key).await
+        match &self.cipher {
+            Some(cipher) => self.get_sealed(cipher, cache_key).await,
+            None => self.inner.get(cache_key).await,
+        }
     }
 
     async fn put(
@@ -110,7 +146,22 @@ impl CacheManager for SizeAwareCacheManager {
         res: HttpResponse,
         policy: CachePolicy,
     ) -> http_cache::Result<HttpResponse> {
-        let response = self.inner.put(cache_key.clone(), res, policy).await?;
+        let request_key = cache_key.clone();
+        let (response, cache_key) = match &self.cipher {
+            Some(cipher) => {
+                let plain = bincode::serialize(&(&res, &policy))?;
+                let sealed = cipher.seal(&cache_key, &plain)?;
+                let storage_key = cipher.storage_key(&cache_key);
+                cacache::write(&self.inner.path, &storage_key, sealed).await?;
+                (res, storage_key)
+            }
+            None => (
+                self.inner.put(cache_key.clone(), res, policy).await?,
+                cache_key,
+            ),
+        };
+
+        super::record_cache_tag(&self.inner.path, &cache_key, &request_key).await;
 
         match cacache::metadata(&self.inner.path, &cache_key).await {
             Ok(Some(metadata)) => {
@@ -162,6 +213,11 @@ impl CacheManager for SizeAwareCacheManager {
     }
 
     async fn delete(&self, cache_key: &str) -> http_cache::Result<()> {
+        if let Some(cipher) = &self.cipher {
+            let storage_key = cipher.storage_key(cache_key);
+            cacache::remove(&self.inner.path, &storage_key).await?;
+            return Ok(());
+        }
         self.inner.delete(cache_key).await
     }
 }
@@ -352,7 +408,8 @@ mod tests {
     use http_cache_semantics::CachePolicy;
 
     use super::{
-        FilesystemSpace, SizeAwareCacheManager, estimate_cache_bytes_fast, run_eviction_cycle_with,
+        CacheCipher, FilesystemSpace, SizeAwareCacheManager, estimate_cache_bytes_fast,
+        run_eviction_cycle_with,
     };
     use crate::cache::{
         CacheConfigOrigins, ConfigOrigin, DiskFreeThreshold, ResolvedCacheConfig, snapshot_cache,
@@ -417,6 +474,7 @@ mod tests {
             max_size,
             min_disk_free,
             max_age: Duration::from_secs(86_400),
+            encrypt: false,
             origins: CacheConfigOrigins {
                 cache_root: ConfigOrigin::Default,
                 max_size: ConfigOrigin::Default,
@@ -689,6 +747,38 @@ mod tests {
         assert_eq!(manager.approx_bytes.load(Ordering::Relaxed), 8);
     }
 
+    #[tokio::test(flavor = "current_thread")]
+    async fn encrypted_manager_round_trips_and_hides_keys_and_bodies() {
+        let root = TempDirGuard::new("encrypted");
+        let config = test_config(
+            root.cache_root(),
+            u64::MAX / 2,
+            DiskFreeThreshold::Percent(1),
+        );
+        let cipher = |byte| Arc::new(CacheCipher::from_key_bytes(&[byte; 32]).expect("cipher"));
+        let manager = SizeAwareCacheManager::new(root.http_dir(), config.clone())
+            .with_cipher(Some(cipher(3)));
+        let key = "GET:https://example.test/cache-key?patient=1";
+
+        manager
+            .put(key.into(), test_http_response(b"secret"), test_policy())
+            .await
+            .expect("put");
+        let (response, _) = manager.get(key).await.expect("get").expect("hit");
+        assert_eq!(response.body, b"secret");
+
+        let snapshot = snapshot_cache(&root.http_dir()).expect("snapshot");
+        assert_eq!(snapshot.entries.len(), 1);
+        assert!(snapshot.entries[0].key.starts_with("enc:"));
+        let plain = SizeAwareCacheManager::new(root.http_dir(), config.clone());
+        assert!(plain.get(key).await.expect("get").is_none());
+
+        let rotated =
+            SizeAwareCacheManager::new(root.http_dir(), config).with_cipher(Some(cipher(4)));
+        assert!(rotated.get(key).await.expect("get").is_none());
+        assert!(manager.get(key).await.expect("get").is_none());
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn put_schedules_eviction_for_preexisting_oversized_cache_with_ample_disk() {
         let root = TempDirGuard::new("schedule-oversized");
@@ -1,20 +1,26 @@
 mod clean;
 mod clear;
 mod config;
+mod crypto;
 mod limits;
 mod manager;
 pub(crate) mod migration;
+mod paths;
 mod planner;
+mod tags;
 
 #[allow(unused_imports)]
-pub(crate) use clean::{CleanOptions, CleanReport, execute_cache_clean};
+pub(crate) use clean::{
+    CleanOptions, CleanReport, execute_cache_clean, execute_cache_clear_source, execute_cache_purge,
+};
 #[allow(unused_imports)]
 pub(crate) use clear::{ClearReport, execute_cache_clear};
 #[allow(unused_imports)]
 pub(crate) use config::{
     CacheConfig, CacheConfigOrigins, ConfigOrigin, DiskFreeThreshold, ResolvedCacheConfig,
-    resolve_cache_config,
+    resolve_cache_config, set_cache_dir_override,
 };
+pub(crate) use crypto::CacheCipher;
 #[allow(unused_imports)]
 pub(crate) use limits::{
     CacheLimitEvaluation, CacheUsage, FilesystemSpace, evaluate_cache_limits,
@@ -25,5 +31,10 @@ pub(crate) use migration::{MigrationOutcome, migrate_http_cache};
 #[allow(unused_imports)]
 pub(crate) use planner::{
     CacheBlob, CacheCleanupPlan, CacheEntry, CachePlannerError, CacheSnapshot, plan_age_cleanup,
-    plan_composite_cleanup, plan_orphan_gc, plan_size_lru, snapshot_cache,
+    plan_composite_cleanup, plan_entry_removals, plan_orphan_gc, plan_size_lru, snapshot_cache,
+};
+#[allow(unused_imports)]
+pub(crate) use tags::{
+    CacheTag, CacheTags, UNTAGGED, forget_cache_tags, load_cache_tags, record_cache_tag,
+    source_for_request_key,
 };
@@ -0,0 +1,219 @@
+//! Normalization for user-supplied cache directories.
+//!
+//! Cache roots arrive from `--cache-dir`, `BIOMCP_CACHE_DIR`, and
+//! `cache.toml`. On Windows those values are often pasted with surrounding
+//! quotes, use `~`, or point at deep profile directories where the cacache
+//! `content-v2/<algo>/xx/yy/<digest>` layout pushes paths past `MAX_PATH`.
+//! Roots are therefore unquoted, home-expanded, checked for path components
+//! Windows cannot create, and converted to extended-length (`\\?\`) form so
+//! long cache paths keep working.
+
+use std::path::PathBuf;
+
+/// Characters Windows rejects inside a file or directory name.
+const WINDOWS_INVALID_CHARS: &[char] = &['<', '>', ':', '"', '|', '?', '*'];
+
+/// Device names Windows reserves regardless of extension (`NUL.txt` included).
+const WINDOWS_RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Normalizes a configured cache directory; the error is a message fragment
+/// that callers prefix with the setting name.
+pub(crate) fn normalize_cache_dir(raw: &str) -> Result<PathBuf, String> {
+    let value = strip_matching_quotes(raw.trim()).trim();
+    if value.is_empty() {
+        return Err("must not be blank".into());
+    }
+
+    let value = expand_home(value)?;
+    if cfg!(windows)
+        && let Some(problem) = windows_path_problem(&value)
+    {
+        return Err(problem);
+    }
+
+    Ok(PathBuf::from(long_path_form(value)))
+}
+
+fn strip_matching_quotes(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if value.len() >= 2
+            && let Some(inner) = value
+                .strip_prefix(quote)
+                .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    value
+}
+
+fn expand_home(value: &str) -> Result<String, String> {
+    let rest = match value.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(['/', '\\']) => rest,
+        _ => return Ok(value.to_string()),
+    };
+    let home = dirs::home_dir().ok_or("uses `~` but the home directory is unknown")?;
+    Ok(format!("{}{rest}", home.display()))
+}
+
+/// Describes the first component Windows cannot create, if any.
+fn windows_path_problem(value: &str) -> Option<String> {
+    let body = value
+        .strip_prefix(r"\\?\UNC\")
+        .or_else(|| value.strip_prefix(r"\\?\"))
+        .or_else(|| value.strip_prefix(r"\\"))
+        .unwrap_or(value);
+    let body = match body.as_bytes() {
+        [drive, b':', ..] if drive.is_ascii_alphabetic() => &body[2..],
+        _ => body,
+    };
+
+    for component in body.split(['/', '\\']) {
+        if component.is_empty() || component == "." || component == ".." {
+            continue;
+        }
+        if let Some(ch) = component
+            .chars()
+            .find(|ch| WINDOWS_INVALID_CHARS.contains(ch) || ch.is_control())
+        {
+            return Some(format!(
+                "contains `{}`, which Windows does not allow in `{component}`",
+                ch.escape_default()
+            ));
+        }
+        if component.ends_with(['.', ' ']) {
+            return Some(format!(
+                "has `{component}`, which ends with a dot or space that Windows strips"
+            ));
+        }
+        let stem = component.split('.').next().unwrap_or(component).trim_end();
+        if WINDOWS_RESERVED_NAMES
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(stem))
+        {
+            return Some(format!("has `{component}`, a reserved Windows device name"));
+        }
+    }
+    None
+}
+
+/// Extended-length form of `path` on Windows; unchanged elsewhere.
+pub(crate) fn long_path(path: PathBuf) -> PathBuf {
+    match path.to_str() {
+        Some(value) if cfg!(windows) => extended_length_form(value).map_or(path, PathBuf::from),
+        _ => path,
+    }
+}
+
+fn long_path_form(value: String) -> String {
+    if cfg!(windows) {
+        extended_length_form(&value).unwrap_or(value)
+    } else {
+        value
+    }
+}
+
+/// Extended-length form of an absolute Windows path (`C:\x` -> `\\?\C:\x`,
+/// `\\srv\share` -> `\\?\UNC\srv\share`). Relative paths, paths already in
+/// that form, and paths with `.`/`..` segments (which `\\?\` does not resolve)
+/// are left alone.
+fn extended_length_form(value: &str) -> Option<String> {
+    if value.starts_with(r"\\?\") || value.starts_with(r"\\.\") {
+        return None;
+    }
+    let normalized = value.replace('/', "\\");
+    if normalized
+        .split('\\')
+        .any(|component| component == "." || component == "..")
+    {
+        return None;
+    }
+    if let Some(unc) = normalized.strip_prefix(r"\\") {
+        return Some(format!(r"\\?\UNC\{unc}"));
+    }
+    match normalized.as_bytes() {
+        [drive, b':', b'\\', ..] if drive.is_ascii_alphabetic() => {
+            Some(format!(r"\\?\{normalized}"))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_cache_dir_strips_quotes_and_rejects_blank_values() {
+        assert_eq!(
+            normalize_cache_dir("  \"/tmp/biomcp cache\"  ").expect("quoted path"),
+            PathBuf::from("/tmp/biomcp cache")
+        );
+        assert_eq!(
+            normalize_cache_dir("'/tmp/cache'").expect("single quotes"),
+            PathBuf::from("/tmp/cache")
+        );
+        assert_eq!(
+            normalize_cache_dir("\"\"").expect_err("blank"),
+            "must not be blank"
+        );
+    }
+
+    #[test]
+    fn normalize_cache_dir_expands_home_prefix_only() {
+        let Some(home) = dirs::home_dir() else {
+            return;
+        };
+        assert_eq!(
+            normalize_cache_dir("~/biomcp").expect("home path"),
+            PathBuf::from(format!("{}/biomcp", home.display()))
+        );
+        assert_eq!(
+            normalize_cache_dir("~other/biomcp").expect("literal tilde"),
+            PathBuf::from("~other/biomcp")
+        );
+    }
+
+    #[test]
+    fn windows_path_problem_flags_invalid_characters_and_reserved_names() {
+        assert_eq!(
+            windows_path_problem(r"C:\Users\me\AppData\Local\biomcp"),
+            None
+        );
+        assert_eq!(windows_path_problem(r"\\?\C:\cache"), None);
+        assert_eq!(windows_path_problem(r"\\server\share\biomcp"), None);
+        assert!(
+            windows_path_problem(r"C:\cache\run:1")
+                .expect("colon")
+                .contains("`:`")
+        );
+        assert!(
+            windows_path_problem(r"D:\data\nul.txt")
+                .expect("reserved")
+                .contains("reserved Windows device name")
+        );
+        assert!(
+            windows_path_problem(r"C:\cache\trailing.")
+                .expect("trailing dot")
+                .contains("dot or space")
+        );
+    }
+
+    #[test]
+    fn extended_length_form_handles_drive_unc_and_relative_paths() {
+        assert_eq!(
+            extended_length_form("C:/Users/me/biomcp").as_deref(),
+            Some(r"\\?\C:\Users\me\biomcp")
+        );
+        assert_eq!(
+            extended_length_form(r"\\server\share\biomcp").as_deref(),
+            Some(r"\\?\UNC\server\share\biomcp")
+        );
+        assert_eq!(extended_length_form(r"\\?\C:\cache"), None);
+        assert_eq!(extended_length_form(r"C:\cache\..\other"), None);
+        assert_eq!(extended_length_form("relative\\cache"), None);
+    }
+}
@@ -222,6 +222,19 @@ pub(crate) fn plan_size_lru(
     }
 }
 
+/// Removes the given entries and every blob only they referenced.
+pub(crate) fn plan_entry_removals(
+    snapshot: &CacheSnapshot,
+    entry_removals: Vec<CacheEntry>,
+) -> CacheCleanupPlan {
+    let blob_removals = projected_blob_removals(snapshot, &entry_removals);
+    CacheCleanupPlan {
+        reclaimed_blob_bytes: sum_blob_bytes(&blob_removals),
+        entry_removals,
+        blob_removals,
+    }
+}
+
 pub(crate) fn plan_composite_cleanup(
     snapshot: &CacheSnapshot,
     now_ms: u128,
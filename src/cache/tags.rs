@@ -0,0 +1,396 @@
+//! Source and entity tags for HTTP cache entries.
+//!
+//! cacache keys are opaque (and digests when encryption is on), so every write
+//! appends a `{key, source, entity}` record to `tags-v1.jsonl` beside the
+//! cacache index. The last record for a key wins. The file lives inside the
+//! managed `http/` directory, so `cache clear` removes it with everything else.
+//! Clean and purge rewrite it without the keys they delete, and appends compact
+//! it once it holds more records than the cache has live entries.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+const TAG_INDEX_FILE: &str = "tags-v1.jsonl";
+/// Appends check for stale records each time the index grows past another
+/// multiple of this size, so the check runs every few thousand writes.
+const COMPACTION_CHECK_BYTES: u64 = 256 * 1024;
+
+/// Serializes appends and compaction within one process.
+static TAG_INDEX_LOCK: Mutex<()> = Mutex::new(());
+/// Label for entries whose source cannot be recovered: encrypted keys written
+/// before tagging existed.
+pub(crate) const UNTAGGED: &str = "untagged";
+
+/// Known upstream hosts, most specific path prefix first; unknown hosts are
+/// reported by host name.
+const SOURCE_HOSTS: &[(&str, &str, &str)] = &[
+    ("mygene.info", "", "mygene"),
+    ("myvariant.info", "", "myvariant"),
+    ("mychem.info", "", "mychem"),
+    ("mydisease.info", "", "mydisease"),
+    ("clinicaltrials.gov", "", "ctgov"),
+    ("clinicaltrialsapi.cancer.gov", "", "nci-cts"),
+    ("euclinicaltrials.eu", "", "ctis"),
+    ("eutils.ncbi.nlm.nih.gov", "", "pubmed"),
+    (
+        "www.ncbi.nlm.nih.gov",
+        "/research/pubtator3-api",
+        "pubtator",
+    ),
+    (
+        "www.ncbi.nlm.nih.gov",
+        "/research/litsense2-api",
+        "litsense",
+    ),
+    ("www.ncbi.nlm.nih.gov", "/pmc/", "pmc-oa"),
+    ("pmc.ncbi.nlm.nih.gov", "", "ncbi-idconv"),
+    ("www.ebi.ac.uk", "/europepmc", "europepmc"),
+    ("www.ebi.ac.uk", "/QuickGO", "quickgo"),
+    ("www.ebi.ac.uk", "/chembl", "chembl"),
+    ("www.ebi.ac.uk", "/gwas", "gwas"),
+    ("www.ebi.ac.uk", "/intact", "complexportal"),
+    ("www.ebi.ac.uk", "/interpro", "interpro"),
+    ("www.ebi.ac.uk", "/cgi-bin/ipd", "ipd-imgt"),
+    ("www.ebi.ac.uk", "/ols4", "ols"),
+    ("rest.uniprot.org", "", "uniprot"),
+    ("api.unpaywall.org", "", "unpaywall"),
+    ("data.rcsb.org", "", "rcsb"),
+    ("search.rcsb.org", "", "rcsb"),
+    ("reactome.org", "", "reactome"),
+    ("rest.kegg.jp", "", "kegg"),
+    ("www.wikipathways.org", "", "wikipathways"),
+    ("string-db.org", "", "string"),
+    ("api.platform.opentargets.org", "", "opentargets"),
+    ("api.fda.gov", "", "openfda"),
+    ("civicdb.org", "", "civic"),
+    ("www.oncokb.org", "", "oncokb"),
+    ("api.disgenet.com", "", "disgenet"),
+    ("api.cpicpgx.org", "", "cpic"),
+    ("api.pharmgkb.org", "", "pharmgkb"),
+    ("api-v3.monarchinitiative.org", "", "monarch"),
+    ("ontology.jax.org", "", "hpo"),
+    ("gtexportal.org", "", "gtex"),
+    ("depmap.org", "", "depmap"),
+    ("dgidb.org", "", "dgidb"),
+    ("search.clinicalgenome.org", "", "clingen"),
+    ("gnomad.broadinstitute.org", "", "gnomad"),
+    ("www.cbioportal.org", "", "cbioportal"),
+    ("maayanlab.cloud", "", "enrichr"),
+    ("biit.cs.ut.ee", "", "gprofiler"),
+    ("api.semanticscholar.org", "", "semantic-scholar"),
+    ("api.reporter.nih.gov", "", "nih-reporter"),
+    ("ghoapi.azureedge.net", "", "who-gho"),
+    ("search.patentsview.org", "", "patentsview"),
+    ("api.clue.io", "", "clue"),
+    ("rest.variantvalidator.org", "", "variantvalidator"),
+    ("grch37.rest.ensembl.org", "", "ensembl"),
+    ("rest.ensembl.org", "", "ensembl"),
+    ("www.proteinatlas.org", "", "hpa"),
+    ("medlineplus.gov", "", "medlineplus"),
+    ("api-evsrest.nci.nih.gov", "", "nci-evs"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CacheTag {
+    pub(crate) source: String,
+    pub(crate) entity: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TagRecord {
+    key: String,
+    source: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    entity: Option<String>,
+}
+
+/// Tags loaded from the index, keyed by cacache storage key.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CacheTags {
+    by_key: HashMap<String, CacheTag>,
+}
+
+impl CacheTags {
+    /// Tag for one cacache key, falling back to the request URL in plaintext keys.
+    pub(crate) fn tag_for(&self, storage_key: &str) -> CacheTag {
+        if let Some(tag) = self.by_key.get(storage_key) {
+            return tag.clone();
+        }
+        CacheTag {
+            source: source_for_request_key(storage_key).unwrap_or_else(|| UNTAGGED.to_string()),
+            entity: None,
+        }
+    }
+}
+
+fn tag_index_path(cache_path: &Path) -> PathBuf {
+    cache_path.join(TAG_INDEX_FILE)
+}
+
+/// Source id for a `[tenant:<name>:]METHOD:URI` request key.
+pub(crate) fn source_for_request_key(request_key: &str) -> Option<String> {
+    let start = request_key
+        .find("https://")
+        .or_else(|| request_key.find("http://"))?;
+    let url = reqwest::Url::parse(&request_key[start..]).ok()?;
+    let host = url.host_str()?;
+    let source = SOURCE_HOSTS
+        .iter()
+        .find(|(known, prefix, _)| *known == host && url.path().starts_with(prefix))
+        .map_or_else(|| host.to_string(), |(_, _, source)| (*source).to_string());
+    Some(source)
+}
+
+/// Appends the tag for a freshly written entry on the blocking pool. Failures
+/// only cost attribution in `cache stats`, so they are logged rather than
+/// failing the request.
+pub(crate) async fn record_cache_tag(cache_path: &Path, storage_key: &str, request_key: &str) {
+    let record = TagRecord {
+        key: storage_key.to_string(),
+        source: source_for_request_key(request_key).unwrap_or_else(|| UNTAGGED.to_string()),
+        entity: crate::sources::current_cache_entity().map(str::to_string),
+    };
+    let path = cache_path.to_path_buf();
+    let result = match tokio::task::spawn_blocking(move || append_tag_record(&path, &record)).await
+    {
+        Ok(result) => result,
+        Err(err) => Err(io::Error::other(err)),
+    };
+    if let Err(err) = result {
+        warn!(
+            cache_path = %cache_path.display(),
+            "failed to record cache entry tag: {err}"
+        );
+    }
+}
+
+fn append_tag_record(cache_path: &Path, record: &TagRecord) -> io::Result<()> {
+    let mut line = serde_json::to_string(record).map_err(io::Error::other)?;
+    line.push('\n');
+    let _guard = TAG_INDEX_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(tag_index_path(cache_path))?;
+    let before = file.metadata()?.len();
+    file.write_all(line.as_bytes())?;
+    let after = before + line.len() as u64;
+    if before / COMPACTION_CHECK_BYTES != after / COMPACTION_CHECK_BYTES {
+        compact_cache_tags(cache_path)?;
+    }
+    Ok(())
+}
+
+/// Keys of the entries currently in the cacache index.
+fn live_cache_keys(cache_path: &Path) -> io::Result<HashSet<String>> {
+    if !cache_path.join("index-v5").try_exists()? {
+        return Ok(HashSet::new());
+    }
+    cacache::list_sync(cache_path)
+        .map(|result| {
+            result
+                .map(|metadata| metadata.key)
+                .map_err(io::Error::other)
+        })
+        .collect()
+}
+
+/// Rewrites the tag index down to one record per live cache key when it holds
+/// more records than the cache has entries. Returns whether it rewrote.
+fn compact_cache_tags(cache_path: &Path) -> io::Result<bool> {
+    let raw = match fs::read_to_string(tag_index_path(cache_path)) {
+        Ok(raw) => raw,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err),
+    };
+    let records = raw.lines().filter(|line| !line.trim().is_empty()).count();
+    let live = live_cache_keys(cache_path)?;
+    if records <= live.len() {
+        return Ok(false);
+    }
+    rewrite_cache_tags(cache_path, |key| live.contains(key))?;
+    Ok(true)
+}
+
+pub(crate) fn load_cache_tags(cache_path: &Path) -> io::Result<CacheTags> {
+    let raw = match fs::read_to_string(tag_index_path(cache_path)) {
+        Ok(raw) => raw,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(CacheTags::default()),
+        Err(err) => return Err(err),
+    };
+    let mut by_key = HashMap::new();
+    for line in raw.lines() {
+        // A torn line from an interrupted append only loses that one tag.
+        let Ok(record) = serde_json::from_str::<TagRecord>(line) else {
+            continue;
+        };
+        by_key.insert(
+            record.key,
+            CacheTag {
+                source: record.source,
+                entity: record.entity,
+            },
+        );
+    }
+    Ok(CacheTags { by_key })
+}
+
+/// Rewrites the tag index with one record per key, dropping `removed` keys.
+/// Tags appended by another process during the rewrite can be lost; those
+/// entries fall back to URL attribution.
+pub(crate) fn forget_cache_tags(cache_path: &Path, removed: &HashSet<&str>) -> io::Result<()> {
+    let _guard = TAG_INDEX_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    rewrite_cache_tags(cache_path, |key| !removed.contains(key))
+}
+
+fn rewrite_cache_tags(cache_path: &Path, keep: impl Fn(&str) -> bool) -> io::Result<()> {
+    let path = tag_index_path(cache_path);
+    if !path.try_exists()? {
+        return Ok(());
+    }
+    let tags = load_cache_tags(cache_path)?;
+    let mut records = tags
+        .by_key
+        .into_iter()
+        .filter(|(key, _)| keep(key))
+        .map(|(key, tag)| TagRecord {
+            key,
+            source: tag.source,
+            entity: tag.entity,
+        })
+        .collect::<Vec<_>>();
+    records.sort_by(|left, right| left.key.cmp(&right.key));
+
+    let mut out = String::new();
+    for record in &records {
+        out.push_str(&serde_json::to_string(record).map_err(io::Error::other)?);
+        out.push('\n');
+    }
+    let tmp = path.with_extension("jsonl.tmp");
+    fs::write(&tmp, out)?;
+    fs::rename(&tmp, &path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::*;
+
+    struct TempDirGuard {
+        path: PathBuf,
+    }
+
+    impl TempDirGuard {
+        fn new(label: &str) -> Self {
+            let suffix = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos();
+            let path = std::env::temp_dir().join(format!(
+                "biomcp-cache-tags-{label}-{}-{suffix}",
+                std::process::id()
+            ));
+            fs::create_dir_all(&path).expect("create temp dir");
+            Self { path }
+        }
+    }
+
+    impl Drop for TempDirGuard {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    #[test]
+    fn source_for_request_key_maps_hosts_paths_and_tenants() {
+        let source = source_for_request_key;
+        assert_eq!(
+            source("GET:https://mygene.info/v3/query?q=BRAF").as_deref(),
+            Some("mygene")
+        );
+        assert_eq!(
+            source("tenant:oncology:POST:https://www.ebi.ac.uk/europepmc/webservices/rest/search")
+                .as_deref(),
+            Some("europepmc")
+        );
+        assert_eq!(
+            source("GET:https://www.ncbi.nlm.nih.gov/research/pubtator3-api/search/").as_deref(),
+            Some("pubtator")
+        );
+        assert_eq!(
+            source("GET:https://api.example.org/v1/items").as_deref(),
+            Some("api.example.org")
+        );
+        assert_eq!(source("enc:abc123"), None);
+    }
+
+    #[tokio::test]
+    async fn tags_round_trip_last_record_wins_and_forget_compacts() {
+        let root = TempDirGuard::new("round-trip");
+        record_cache_tag(&root.path, "k1", "GET:https://mygene.info/v3/gene/673").await;
+        record_cache_tag(
+            &root.path,
+            "k2",
+            "GET:https://rest.uniprot.org/uniprotkb/P15056",
+        )
+        .await;
+        record_cache_tag(&root.path, "k1", "GET:https://myvariant.info/v1/query").await;
+        fs::OpenOptions::new()
+            .append(true)
+            .open(root.path.join(TAG_INDEX_FILE))
+            .and_then(|mut file| file.write_all(b"{\"key\":\"torn"))
+            .expect("append torn line");
+
+        let tags = load_cache_tags(&root.path).expect("load");
+        assert_eq!(tags.tag_for("k1").source, "myvariant");
+        assert_eq!(tags.tag_for("k2").source, "uniprot");
+        assert_eq!(tags.tag_for("enc:missing").source, UNTAGGED);
+        assert_eq!(
+            tags.tag_for("GET:https://civicdb.org/api/graphql").source,
+            "civic"
+        );
+
+        forget_cache_tags(&root.path, &HashSet::from(["k2"])).expect("forget");
+        let raw = fs::read_to_string(root.path.join(TAG_INDEX_FILE)).expect("read");
+        assert_eq!(raw.lines().count(), 1);
+        assert!(raw.contains("\"k1\""));
+        assert!(!raw.contains("uniprot"));
+    }
+
+    #[tokio::test]
+    async fn record_cache_tag_captures_scoped_entity() {
+        let root = TempDirGuard::new("entity");
+        crate::sources::with_cache_entity(Some("gene"), async {
+            record_cache_tag(&root.path, "k1", "GET:https://mygene.info/v3/gene/673").await;
+        })
+        .await;
+        record_cache_tag(&root.path, "k2", "GET:https://mygene.info/v3/gene/7157").await;
+
+        let tags = load_cache_tags(&root.path).expect("load");
+        assert_eq!(tags.tag_for("k1").entity.as_deref(), Some("gene"));
+        assert_eq!(tags.tag_for("k2").entity, None);
+    }
+
+    #[tokio::test]
+    async fn compaction_keeps_one_record_per_live_key() {
+        let root = TempDirGuard::new("compact");
+        cacache::write_sync(&root.path, "k1", b"body").expect("write entry");
+        for _ in 0..3 {
+            record_cache_tag(&root.path, "k1", "GET:https://mygene.info/v3/gene/673").await;
+        }
+        record_cache_tag(&root.path, "gone", "GET:https://mygene.info/v3/gene/7157").await;
+
+        assert!(compact_cache_tags(&root.path).expect("compact"));
+        let raw = fs::read_to_string(root.path.join(TAG_INDEX_FILE)).expect("read");
+        assert_eq!(raw.lines().count(), 1);
+        assert!(raw.contains("\"k1\""));
+        assert!(!compact_cache_tags(&root.path).expect("already compact"));
+    }
+}
@@ -1,4 +1,6 @@
-use super::{AdverseEventGetArgs, AdverseEventSearchArgs};
+use super::{
+    AdverseEventGetArgs, AdverseEventSearchArgs, DeviceClearanceGetArgs, DeviceClearanceSearchArgs,
+};
 use crate::cli::CommandOutcome;
 
 pub(crate) async fn handle_get(
@@ -16,6 +18,7 @@ pub(crate) async fn handle_get(
                     crate::render::markdown::adverse_event_evidence_urls(report),
                     crate::render::markdown::related_adverse_event(report),
                     crate::render::provenance::adverse_event_report_section_sources(&event),
+                    Vec::new(),
                 )?
             }
             crate::entities::adverse_event::AdverseEventReport::Device(report) => {
@@ -24,6 +27,7 @@ pub(crate) async fn handle_get(
                     crate::render::markdown::device_event_evidence_urls(report),
                     crate::render::markdown::related_device_event(report),
                     crate::render::provenance::adverse_event_report_section_sources(&event),
+                    Vec::new(),
                 )?
             }
         }
@@ -48,6 +52,88 @@ pub(crate) async fn handle_search(
     let query_type =
         crate::entities::adverse_event::AdverseEventQueryType::from_flag(&args.r#type)?;
 
+    if crate::entities::adverse_event::AdverseEventSpecies::from(args.species)
+        == crate::entities::adverse_event::AdverseEventSpecies::Veterinary
+    {
+        if !matches!(
+            query_type,
+            crate::entities::adverse_event::AdverseEventQueryType::Faers
+        ) {
+            return Err(crate::error::BioMcpError::InvalidArgument(
+                "--species veterinary is only valid for drug adverse events (--type faers)".into(),
+            )
+            .into());
+        }
+        if args.device.is_some() || args.manufacturer.is_some() || args.product_code.is_some() {
+            return Err(crate::error::BioMcpError::InvalidArgument(
+                "--device/--manufacturer/--product-code can only be used with --type device".into(),
+            )
+            .into());
+        }
+        if args.outcome.is_some()
+            || args.suspect_only
+            || args.sex.is_some()
+            || args.age_min.is_some()
+            || args.age_max.is_some()
+            || args.reporter.is_some()
+            || args.count.is_some()
+            || args.classification.is_some()
+        {
+            return Err(crate::error::BioMcpError::InvalidArgument(
+                "--outcome/--suspect-only/--sex/--age-min/--age-max/--reporter/--count/--classification are not supported with --species veterinary".into(),
+            )
+            .into());
+        }
+
+        let filters = crate::entities::adverse_event::VeterinaryEventSearchFilters {
+            drug,
+            animal: args.animal,
+            reaction: args.reaction,
+            serious: args.serious.is_some(),
+            since: args.date_from,
+            date_to: args.date_to,
+        };
+        let mut query_summary = crate::entities::adverse_event::veterinary_query_summary(&filters);
+        if args.offset > 0 {
+            query_summary = format!("{query_summary}, offset={}", args.offset);
+        }
+        let page = crate::entities::adverse_event::search_veterinary_page(
+            &filters,
+            args.limit,
+            args.offset,
+        )
+        .await?;
+        let results = page.results;
+        let pagination = super::super::PaginationMeta::offset(
+            args.offset,
+            args.limit,
+            results.len(),
+            page.total,
+        );
+        if json {
+            return super::super::search_json_with_meta(
+                results,
+                pagination,
+                vec!["biomcp list adverse-event".to_string()],
+            )
+            .map(CommandOutcome::stdout);
+        }
+        let footer = super::super::pagination_footer_offset(&pagination);
+        let text = crate::render::markdown::veterinary_event_search_markdown_with_footer(
+            &query_summary,
+            &results,
+            page.total,
+            &footer,
+        )?;
+        return Ok(CommandOutcome::stdout(text));
+    }
+    if args.animal.is_some() {
+        return Err(crate::error::BioMcpError::InvalidArgument(
+            "--animal can only be used with --species veterinary".into(),
+        )
+        .into());
+    }
+
     let text = match query_type {
         crate::entities::adverse_event::AdverseEventQueryType::Faers => {
             if args.device.is_some() {
@@ -326,3 +412,66 @@ pub(crate) async fn handle_search(
 
     Ok(CommandOutcome::stdout(text))
 }
+
+pub(crate) async fn handle_device_clearance_get(
+    args: DeviceClearanceGetArgs,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let (clearance, section_errors) = crate::entities::section_errors::collect(
+        crate::entities::adverse_event::get_device_clearance(&args.k_number),
+    )
+    .await;
+    let clearance = clearance?;
+    let text = if json {
+        crate::render::json::to_entity_json(
+            &clearance,
+            crate::render::markdown::device_clearance_evidence_urls(&clearance),
+            crate::render::markdown::related_device_clearance(&clearance),
+            crate::render::provenance::device_clearance_section_sources(&clearance),
+            section_errors,
+        )?
+    } else {
+        crate::render::markdown::device_clearance_markdown(&clearance)?
+    };
+    Ok(CommandOutcome::stdout(text))
+}
+
+pub(crate) async fn handle_device_clearance_search(
+    args: DeviceClearanceSearchArgs,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let filters = crate::entities::adverse_event::DeviceClearanceSearchFilters {
+        product_code: args.product_code,
+        device: args.device,
+        applicant: args.applicant,
+        since: args.date_from,
+        date_to: args.date_to,
+    };
+    let mut query_summary =
+        crate::entities::adverse_event::device_clearance_query_summary(&filters);
+    if args.offset > 0 {
+        query_summary = format!("{query_summary}, offset={}", args.offset);
+    }
+    let page = crate::entities::adverse_event::search_device_clearance_page(
+        &filters,
+        args.limit,
+        args.offset,
+    )
+    .await?;
+    let results = page.results;
+    let pagination =
+        super::super::PaginationMeta::offset(args.offset, args.limit, results.len(), page.total);
+    if json {
+        let next_commands =
+            crate::render::markdown::search_next_commands_device_clearances(&results);
+        return super::super::search_json_with_meta(results, pagination, next_commands)
+            .map(CommandOutcome::stdout);
+    }
+    let footer = super::super::pagination_footer_offset(&pagination);
+    let text = crate::render::markdown::device_clearance_search_markdown_with_footer(
+        &query_summary,
+        &results,
+        &footer,
+    )?;
+    Ok(CommandOutcome::stdout(text))
+}
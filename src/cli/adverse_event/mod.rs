@@ -1,7 +1,9 @@
-//! Adverse-event CLI payloads.
+//! Adverse-event and device-clearance CLI payloads.
 
 use clap::Args;
 
+use crate::cli::SpeciesArg;
+
 #[derive(Args, Debug)]
 pub struct AdverseEventSearchArgs {
     /// Drug name (required for FAERS queries)
@@ -55,6 +57,12 @@ pub struct AdverseEventSearchArgs {
     /// Query type: faers (default), recall, or device
     #[arg(long, default_value = "faers")]
     pub r#type: String,
+    /// Patient population: human (FAERS, default) or veterinary (openFDA animal & veterinary reports)
+    #[arg(long, value_enum, default_value_t = SpeciesArg::Human)]
+    pub species: SpeciesArg,
+    /// Animal species filter for --species veterinary (e.g., Dog, Cat, Horse)
+    #[arg(long)]
+    pub animal: Option<String>,
     /// Filter by recall classification (Class I, Class II, Class III)
     #[arg(long)]
     pub classification: Option<String>,
@@ -75,8 +83,41 @@ pub struct AdverseEventGetArgs {
     pub sections: Vec<String>,
 }
 
+#[derive(Args, Debug)]
+pub struct DeviceClearanceSearchArgs {
+    /// FDA device product code (e.g., QBJ)
+    #[arg(long = "product-code")]
+    pub product_code: Option<String>,
+    /// Device name (substring match)
+    #[arg(long)]
+    pub device: Option<String>,
+    /// 510(k) applicant (substring match)
+    #[arg(long)]
+    pub applicant: Option<String>,
+    /// Decided on or after year/date (YYYY or YYYY-MM-DD)
+    #[arg(long = "date-from", alias = "since")]
+    pub date_from: Option<String>,
+    /// Decided on or before year/date (YYYY or YYYY-MM-DD)
+    #[arg(long = "date-to", alias = "until")]
+    pub date_to: Option<String>,
+    /// Maximum results (default: 10)
+    #[arg(short, long, default_value = "10")]
+    pub limit: usize,
+    /// Skip the first N results
+    #[arg(long, default_value = "0")]
+    pub offset: usize,
+}
+
+#[derive(Args, Debug)]
+pub struct DeviceClearanceGetArgs {
+    /// 510(k) number (e.g., K213456)
+    pub k_number: String,
+}
+
 mod dispatch;
-pub(crate) use self::dispatch::{handle_get, handle_search};
+pub(crate) use self::dispatch::{
+    handle_device_clearance_get, handle_device_clearance_search, handle_get, handle_search,
+};
 
 #[cfg(test)]
 mod tests;
@@ -1,6 +1,6 @@
 use clap::Parser;
 
-use crate::cli::{Cli, Commands, GetEntity, SearchEntity, execute};
+use crate::cli::{Cli, Commands, GetEntity, SearchEntity, SpeciesArg, execute};
 
 #[test]
 fn search_adverse_event_parses_serious_default_and_limit() {
@@ -26,6 +26,7 @@ fn search_adverse_event_parses_serious_default_and_limit() {
                         r#type,
                         limit,
                         offset,
+                        species,
                         ..
                     }),
             },
@@ -36,6 +37,7 @@ fn search_adverse_event_parses_serious_default_and_limit() {
     };
 
     assert_eq!(drug.as_deref(), Some("ibuprofen"));
+    assert_eq!(species, SpeciesArg::Human);
     assert_eq!(serious.as_deref(), Some("any"));
     assert_eq!(r#type, "faers");
     assert_eq!(limit, 2);
@@ -115,3 +117,154 @@ async fn search_adverse_event_device_rejects_positional_drug_alias() {
             .contains("--drug cannot be used with --type device")
     );
 }
+
+#[test]
+fn search_adverse_event_parses_veterinary_species_alias() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "adverse-event",
+        "-d",
+        "carprofen",
+        "--species",
+        "vet",
+        "--animal",
+        "dog",
+    ])
+    .expect("veterinary adverse-event search should parse");
+
+    let Cli {
+        command:
+            Commands::Search {
+                entity:
+                    SearchEntity::AdverseEvent(crate::cli::adverse_event::AdverseEventSearchArgs {
+                        species,
+                        animal,
+                        ..
+                    }),
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected adverse-event search command");
+    };
+
+    assert_eq!(species, SpeciesArg::Veterinary);
+    assert_eq!(animal.as_deref(), Some("dog"));
+}
+
+#[tokio::test]
+async fn search_adverse_event_veterinary_rejects_device_and_human_only_filters() {
+    let err = execute(vec![
+        "biomcp".to_string(),
+        "search".to_string(),
+        "adverse-event".to_string(),
+        "--type".to_string(),
+        "device".to_string(),
+        "--species".to_string(),
+        "veterinary".to_string(),
+    ])
+    .await
+    .expect_err("veterinary mode only covers drug events");
+    assert!(err.to_string().contains("--type faers"));
+
+    let err = execute(vec![
+        "biomcp".to_string(),
+        "search".to_string(),
+        "adverse-event".to_string(),
+        "-d".to_string(),
+        "carprofen".to_string(),
+        "--species".to_string(),
+        "veterinary".to_string(),
+        "--sex".to_string(),
+        "f".to_string(),
+    ])
+    .await
+    .expect_err("sex filter is FAERS-only");
+    assert!(
+        err.to_string()
+            .contains("not supported with --species veterinary")
+    );
+}
+
+#[tokio::test]
+async fn search_adverse_event_rejects_animal_without_veterinary_species() {
+    let err = execute(vec![
+        "biomcp".to_string(),
+        "search".to_string(),
+        "adverse-event".to_string(),
+        "-d".to_string(),
+        "carprofen".to_string(),
+        "--animal".to_string(),
+        "dog".to_string(),
+    ])
+    .await
+    .expect_err("animal requires veterinary species");
+    assert!(
+        err.to_string()
+            .contains("--animal can only be used with --species veterinary")
+    );
+}
+
+#[test]
+fn search_device_clearance_parses_filters_and_since_alias() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "device-clearance",
+        "--product-code",
+        "QBJ",
+        "--since",
+        "2020",
+        "-l",
+        "5",
+    ])
+    .expect("device-clearance search should parse");
+
+    let Cli {
+        command:
+            Commands::Search {
+                entity:
+                    SearchEntity::DeviceClearance(
+                        crate::cli::adverse_event::DeviceClearanceSearchArgs {
+                            product_code,
+                            date_from,
+                            limit,
+                            offset,
+                            ..
+                        },
+                    ),
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected device-clearance search command");
+    };
+
+    assert_eq!(product_code.as_deref(), Some("QBJ"));
+    assert_eq!(date_from.as_deref(), Some("2020"));
+    assert_eq!(limit, 5);
+    assert_eq!(offset, 0);
+}
+
+#[tokio::test]
+async fn device_clearance_commands_validate_before_fetching() {
+    let err = execute(vec![
+        "biomcp".to_string(),
+        "search".to_string(),
+        "device-clearance".to_string(),
+    ])
+    .await
+    .expect_err("search needs a filter");
+    assert!(err.to_string().contains("At least one filter is required"));
+
+    let err = execute(vec![
+        "biomcp".to_string(),
+        "get".to_string(),
+        "device-clearance".to_string(),
+        "P200001".to_string(),
+    ])
+    .await
+    .expect_err("PMA numbers are not 510(k) numbers");
+    assert!(err.to_string().contains("K followed by six digits"));
+}
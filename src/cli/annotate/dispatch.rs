@@ -0,0 +1,100 @@
+use super::{AnnotateCommand, AnnotateFormat, AnnotateVcfFormat};
+use crate::cli::CommandOutcome;
+use crate::error::BioMcpError;
+
+pub(in crate::cli) async fn handle_command(
+    cmd: AnnotateCommand,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let text = match cmd {
+        AnnotateCommand::Deg {
+            file,
+            gene_column,
+            druggable,
+            format,
+            limit,
+        } => {
+            if json && format == AnnotateFormat::Tsv {
+                return Err(BioMcpError::InvalidArgument(
+                    "--format tsv prints TSV; drop --json or use --format markdown".into(),
+                )
+                .into());
+            }
+            if limit == 0 {
+                return Err(
+                    BioMcpError::InvalidArgument("--limit must be at least 1".into()).into(),
+                );
+            }
+            let content = std::fs::read_to_string(&file).map_err(|err| {
+                BioMcpError::Io(std::io::Error::new(
+                    err.kind(),
+                    format!("failed to read {}: {}", file.display(), err),
+                ))
+            })?;
+            let table = crate::entities::gene::annotate_deg(
+                &content,
+                &file.display().to_string(),
+                gene_column.as_deref(),
+                druggable,
+            )
+            .await?;
+            if json {
+                crate::render::json::to_pretty(&table)?
+            } else {
+                match format {
+                    AnnotateFormat::Tsv => table.to_tsv(),
+                    AnnotateFormat::Markdown => {
+                        crate::render::markdown::gene_deg_annotation_markdown(&table, limit)?
+                    }
+                }
+            }
+        }
+        AnnotateCommand::Vcf {
+            path,
+            assembly,
+            format,
+            limit,
+        } => {
+            if json && format != AnnotateVcfFormat::Markdown {
+                return Err(BioMcpError::InvalidArgument(
+                    "--format tsv and --format vcf print files; drop --json or use --format markdown"
+                        .into(),
+                )
+                .into());
+            }
+            if limit == 0 {
+                return Err(
+                    BioMcpError::InvalidArgument("--limit must be at least 1".into()).into(),
+                );
+            }
+            let assembly = crate::entities::variant::GenomeAssembly::from_flag(&assembly)?;
+            let content = std::fs::read_to_string(&path).map_err(|err| {
+                BioMcpError::Io(std::io::Error::new(
+                    err.kind(),
+                    format!("failed to read {}: {}", path.display(), err),
+                ))
+            })?;
+            let annotation = crate::entities::variant::annotate_vcf(
+                &content,
+                &path.display().to_string(),
+                assembly,
+            )
+            .await?;
+            if json {
+                crate::render::json::to_pretty(&annotation)?
+            } else {
+                match format {
+                    AnnotateVcfFormat::Tsv => annotation.to_tsv(),
+                    AnnotateVcfFormat::Vcf => annotation.to_vcf(),
+                    AnnotateVcfFormat::Markdown => {
+                        crate::render::markdown::variant_vcf_annotation_markdown(
+                            &annotation,
+                            limit,
+                        )?
+                    }
+                }
+            }
+        }
+    };
+    Ok(CommandOutcome::stdout(text))
+}
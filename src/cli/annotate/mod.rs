@@ -0,0 +1,88 @@
+//! Annotation CLI payloads and subcommands for local result files.
+
+use std::path::PathBuf;
+
+use clap::{Subcommand, ValueEnum};
+
+/// Output format for `annotate deg`; `--json` stays the global switch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AnnotateFormat {
+    Markdown,
+    Tsv,
+}
+
+/// Output format for `annotate vcf`; `--json` stays the global switch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AnnotateVcfFormat {
+    Markdown,
+    Tsv,
+    Vcf,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AnnotateCommand {
+    /// Annotate a differential-expression results table with gene symbols and names
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp annotate deg --file deseq2_results.csv
+  biomcp annotate deg --file results.tsv --gene-column gene_id --druggable
+  biomcp annotate deg --file results.csv --format tsv > annotated.tsv
+
+Reads a DESeq2/edgeR/limma CSV or TSV export (tab in the header selects TSV).
+Ensembl gene IDs (version suffixes stripped) and symbols are detected per row
+and resolved through MyGene.info batch queries. The default gene column is the
+first column, which holds row names in R write.csv output. --druggable adds
+DGIdb interacting/approved drug counts per gene.
+See also: biomcp list gene")]
+    Deg {
+        /// Results table to annotate (CSV or TSV)
+        #[arg(long, value_name = "PATH")]
+        file: PathBuf,
+        /// Column holding gene IDs or symbols (default: first column)
+        #[arg(long, value_name = "NAME")]
+        gene_column: Option<String>,
+        /// Add DGIdb drug-interaction counts per gene
+        #[arg(long)]
+        druggable: bool,
+        /// Output format: markdown (default) or tsv
+        #[arg(long, value_enum, default_value = "markdown")]
+        format: AnnotateFormat,
+        /// Rows shown in markdown output (default: 25; TSV and JSON include every row)
+        #[arg(short, long, default_value = "25")]
+        limit: usize,
+    },
+    /// Annotate VCF alleles with ClinVar, gnomAD, CADD, and REVEL from MyVariant.info
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp annotate vcf calls.vcf
+  biomcp annotate vcf calls.vcf --format vcf > calls.annotated.vcf
+  biomcp annotate vcf calls.vcf --assembly grch38 --format tsv > calls.tsv
+
+Each ALT allele is converted to genomic HGVS (chr7:g.140453136A>T) and looked
+up in MyVariant.info batches of up to 1000. --format vcf writes the input with
+BIOMCP_GENE, BIOMCP_CLNSIG, BIOMCP_GNOMAD_AF, BIOMCP_CADD, and BIOMCP_REVEL
+INFO fields (Number=A); --format tsv writes one row per allele. Symbolic
+alleles (<DEL>, *) and contigs other than 1-22/X/Y/MT are kept but not queried.
+Coordinates default to GRCh37, the MyVariant.info default assembly.
+See also: biomcp get variant <id>")]
+    Vcf {
+        /// VCF file to annotate (uncompressed)
+        #[arg(value_name = "PATH")]
+        path: PathBuf,
+        /// Genome assembly of the VCF coordinates: grch37 (default) or grch38
+        #[arg(long, default_value = "grch37")]
+        assembly: String,
+        /// Output format: markdown (default), tsv, or vcf
+        #[arg(long, value_enum, default_value = "markdown")]
+        format: AnnotateVcfFormat,
+        /// Alleles shown in markdown output (default: 25; TSV, VCF, and JSON include every allele)
+        #[arg(short, long, default_value = "25")]
+        limit: usize,
+    },
+}
+
+mod dispatch;
+pub(super) use self::dispatch::handle_command;
+
+#[cfg(test)]
+mod tests;
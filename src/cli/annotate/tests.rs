@@ -0,0 +1,122 @@
+use clap::Parser;
+
+use super::{AnnotateCommand, AnnotateFormat, AnnotateVcfFormat};
+use crate::cli::{Cli, Commands};
+
+#[test]
+fn annotate_deg_parses_file_column_and_flags() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "annotate",
+        "deg",
+        "--file",
+        "results.csv",
+        "--gene-column",
+        "gene_id",
+        "--druggable",
+        "--format",
+        "tsv",
+    ])
+    .expect("annotate deg should parse");
+
+    match cli.command {
+        Commands::Annotate {
+            cmd:
+                AnnotateCommand::Deg {
+                    file,
+                    gene_column,
+                    druggable,
+                    format,
+                    limit,
+                },
+        } => {
+            assert_eq!(file, std::path::PathBuf::from("results.csv"));
+            assert_eq!(gene_column.as_deref(), Some("gene_id"));
+            assert!(druggable);
+            assert_eq!(format, AnnotateFormat::Tsv);
+            assert_eq!(limit, 25);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn annotate_deg_rejects_json_with_tsv_before_reading_file() {
+    let err = super::handle_command(
+        AnnotateCommand::Deg {
+            file: "missing.csv".into(),
+            gene_column: None,
+            druggable: false,
+            format: AnnotateFormat::Tsv,
+            limit: 25,
+        },
+        true,
+    )
+    .await
+    .expect_err("json + tsv should fail");
+    assert!(err.to_string().contains("--format tsv prints TSV"));
+}
+
+#[tokio::test]
+async fn annotate_deg_rejects_missing_gene_column_before_lookups() {
+    let dir = crate::cli::test_support::TempDirGuard::new("annotate-deg");
+    let path = dir.path().join("results.csv");
+    std::fs::write(&path, "gene_id,log2FoldChange\nENSG00000141510,1.2\n").expect("write csv");
+
+    let err = super::handle_command(
+        AnnotateCommand::Deg {
+            file: path,
+            gene_column: Some("symbol".into()),
+            druggable: false,
+            format: AnnotateFormat::Markdown,
+            limit: 25,
+        },
+        false,
+    )
+    .await
+    .expect_err("unknown column should fail before MyGene lookups");
+    assert!(
+        err.to_string()
+            .contains("Available columns: gene_id, log2FoldChange")
+    );
+}
+
+#[test]
+fn annotate_vcf_parses_positional_path_and_defaults() {
+    let cli = Cli::try_parse_from(["biomcp", "annotate", "vcf", "calls.vcf", "--format", "vcf"])
+        .expect("annotate vcf should parse");
+
+    match cli.command {
+        Commands::Annotate {
+            cmd:
+                AnnotateCommand::Vcf {
+                    path,
+                    assembly,
+                    format,
+                    limit,
+                },
+        } => {
+            assert_eq!(path, std::path::PathBuf::from("calls.vcf"));
+            assert_eq!(assembly, "grch37");
+            assert_eq!(format, AnnotateVcfFormat::Vcf);
+            assert_eq!(limit, 25);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn annotate_vcf_rejects_unknown_assembly_before_reading_file() {
+    let err = super::handle_command(
+        AnnotateCommand::Vcf {
+            path: "missing.vcf".into(),
+            assembly: "t2t".into(),
+            format: AnnotateVcfFormat::Markdown,
+            limit: 25,
+        },
+        false,
+    )
+    .await
+    .expect_err("unknown assembly should fail");
+    assert!(err.to_string().contains("Unknown --assembly 't2t'"));
+}
@@ -7,13 +7,18 @@ pub(in crate::cli) async fn handle_get(
 ) -> anyhow::Result<CommandOutcome> {
     let (sections, json_override) = super::super::extract_json_from_sections(&args.sections);
     let json_output = json || json_override;
-    let article = crate::entities::article::get(&args.id, &sections).await?;
+    let (article, section_errors) = crate::entities::section_errors::collect(
+        crate::entities::article::get(&args.id, &sections),
+    )
+    .await;
+    let article = article?;
     let text = if json_output {
         crate::render::json::to_entity_json(
             &article,
             crate::render::markdown::article_evidence_urls(&article),
             crate::render::markdown::related_article(&article),
             crate::render::provenance::article_section_sources(&article),
+            section_errors,
         )?
     } else {
         crate::render::markdown::article_markdown(&article, &sections)?
@@ -67,6 +72,7 @@ pub(in crate::cli) async fn handle_search(
         open_access: args.open_access,
         no_preprints: args.no_preprints,
         exclude_retracted,
+        min_quartile: args.min_quartile,
         max_per_source: args.max_per_source,
         sort,
         ranking,
@@ -226,6 +232,10 @@ pub(super) fn article_query_summary(
             .map(|v| format!("date_from={v}")),
         filters.date_to.as_deref().map(|v| format!("date_to={v}")),
         filters.journal.as_deref().map(|v| format!("journal={v}")),
+        filters
+            .min_quartile
+            .as_deref()
+            .map(|v| format!("min_quartile={v}")),
         filters.open_access.then(|| "open_access=true".to_string()),
         filters
             .no_preprints
@@ -283,6 +293,10 @@ pub(super) fn article_debug_filters(
         filters.date_to.as_deref().map(|v| format!("date_to={v}")),
         filters.article_type.as_deref().map(|v| format!("type={v}")),
         filters.journal.as_deref().map(|v| format!("journal={v}")),
+        filters
+            .min_quartile
+            .as_deref()
+            .map(|v| format!("min_quartile={v}")),
         filters.open_access.then(|| "open_access=true".to_string()),
         filters
             .no_preprints
@@ -29,10 +29,10 @@ pub struct ArticleSearchArgs {
     #[arg(value_name = "QUERY")]
     pub positional_query: Option<String>,
     /// Published after date (YYYY, YYYY-MM, or YYYY-MM-DD)
-    #[arg(long = "date-from", visible_alias = "since")]
+    #[arg(long = "date-from", visible_aliases = ["since", "published-after"])]
     pub date_from: Option<String>,
     /// Published before date (YYYY, YYYY-MM, or YYYY-MM-DD)
-    #[arg(long = "date-to", visible_alias = "until")]
+    #[arg(long = "date-to", visible_aliases = ["until", "published-before"])]
     pub date_to: Option<String>,
     /// Filter by publication type [values: research-article, review, case-reports, meta-analysis]
     #[arg(long = "type")]
@@ -40,6 +40,9 @@ pub struct ArticleSearchArgs {
     /// Filter by journal title
     #[arg(long, num_args = 1..)]
     pub journal: Vec<String>,
+    /// Keep only journals ranked in this SCImago quartile or better [values: Q1, Q2, Q3, Q4]
+    #[arg(long = "min-quartile")]
+    pub min_quartile: Option<String>,
     /// Restrict to open-access articles (default: off, includes all access models)
     #[arg(long = "open-access")]
     pub open_access: bool,
@@ -52,8 +55,12 @@ pub struct ArticleSearchArgs {
     /// Include retracted publications in search results (default excludes them)
     #[arg(long, conflicts_with = "exclude_retracted")]
     pub include_retracted: bool,
-    /// Sort order [values: date, citations, relevance] (default: relevance)
-    #[arg(long, default_value = "relevance", value_parser = ["date", "citations", "relevance"])]
+    /// Sort order [values: date, citations, relevance; `cited` is an alias for citations] (default: relevance)
+    #[arg(
+        long,
+        default_value = "relevance",
+        value_parser = ["date", "citations", "cited", "relevance"]
+    )]
     pub sort: String,
     /// Relevance ranking mode [values: lexical, semantic, hybrid] (default: hybrid with keyword, lexical otherwise)
     #[arg(long = "ranking-mode", value_parser = ["lexical", "semantic", "hybrid"])]
@@ -95,7 +102,7 @@ pub struct ArticleSearchArgs {
 pub struct ArticleGetArgs {
     /// PMID (e.g., 22663011), PMCID (e.g., PMC9984800), or DOI (e.g., 10.1056/NEJMoa1203421)
     pub id: String,
-    /// Sections to include (annotations, fulltext, tldr, all)
+    /// Sections to include (annotations, fulltext, tldr, citations, all)
     #[arg(trailing_var_arg = true)]
     pub sections: Vec<String>,
 }
@@ -69,8 +69,8 @@ fn article_date_help_advertises_shared_accepted_formats() {
 
     assert!(help.contains("Published after date (YYYY, YYYY-MM, or YYYY-MM-DD)"));
     assert!(help.contains("Published before date (YYYY, YYYY-MM, or YYYY-MM-DD)"));
-    assert!(help.contains("[aliases: --since]"));
-    assert!(help.contains("[aliases: --until]"));
+    assert!(help.contains("[aliases: --since, --published-after]"));
+    assert!(help.contains("[aliases: --until, --published-before]"));
     assert!(help.contains("--max-per-source <N>"));
     assert!(help.contains(
         "Cap each federated source's contribution after deduplication and before ranking."
@@ -82,6 +82,41 @@ fn article_date_help_advertises_shared_accepted_formats() {
     assert!(help.contains("Setting it equal to `--limit` disables capping."));
 }
 
+#[test]
+fn search_article_accepts_published_window_aliases_and_cited_sort() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "article",
+        "-g",
+        "BRAF",
+        "--published-after",
+        "2020",
+        "--published-before",
+        "2022-06",
+        "--sort",
+        "cited",
+    ])
+    .expect("article search should parse");
+
+    let Cli {
+        command:
+            Commands::Search {
+                entity: crate::cli::SearchEntity::Article(args),
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected article search command");
+    };
+    assert_eq!(args.date_from.as_deref(), Some("2020"));
+    assert_eq!(args.date_to.as_deref(), Some("2022-06"));
+    assert_eq!(
+        crate::entities::article::ArticleSort::from_flag(&args.sort).expect("sort"),
+        crate::entities::article::ArticleSort::Citations
+    );
+}
+
 #[tokio::test]
 async fn handle_command_rejects_zero_limit_before_backend_lookup() {
     let cli = Cli::try_parse_from(["biomcp", "article", "citations", "22663011", "--limit", "0"])
@@ -133,6 +168,8 @@ fn article_search_json_includes_query_and_ranking_context() {
         ],
         score: None,
         is_retracted: Some(false),
+        journal_rank: None,
+        preprint: None,
         abstract_snippet: Some("Abstract".into()),
         ranking: Some(crate::entities::article::ArticleRankingMetadata {
             directness_tier: 3,
@@ -223,6 +260,7 @@ fn build_article_debug_plan_includes_article_type_limitation_note() {
         open_access: false,
         no_preprints: false,
         exclude_retracted: false,
+        min_quartile: None,
         max_per_source: None,
         sort: crate::entities::article::ArticleSort::Relevance,
         ranking: crate::entities::article::ArticleRankingOptions::default(),
@@ -393,6 +431,7 @@ fn truncate_article_annotations_applies_limit_per_bucket() {
                 count: 1,
             },
         ],
+        trials: Vec::new(),
     };
     let truncated = truncate_article_annotations(annotations, 1);
     assert_eq!(truncated.genes.len(), 1);
@@ -0,0 +1,582 @@
+//! Retrieve-only question answering: resolve a free-text question into typed
+//! anchors, fan out through `search all`, and assemble a cited evidence bundle.
+//!
+//! No text is generated here; the bundle is grounding input for an agent LLM.
+
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cli::search_all::{SearchAllInput, SearchAllSection};
+use crate::entities::discover::{
+    DiscoverConcept, DiscoverIntent, DiscoverMode, DiscoverResult, DiscoverType, MatchTier,
+};
+use crate::error::BioMcpError;
+
+const MAX_ASK_LIMIT: usize = 10;
+
+#[derive(Debug, Clone)]
+pub struct AskInput {
+    pub question: String,
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AskAnchors {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gene: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disease: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AskEvidenceItem {
+    pub id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// 1-based index into `citations`, when the item has a citable source record.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub citation: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AskEvidenceSection {
+    pub entity: String,
+    pub label: String,
+    pub items: Vec<AskEvidenceItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AskCitation {
+    pub index: usize,
+    pub source: String,
+    pub id: String,
+    pub title: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AskBundle {
+    pub question: String,
+    pub intent: String,
+    pub anchors: AskAnchors,
+    pub evidence: Vec<AskEvidenceSection>,
+    pub citations: Vec<AskCitation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+    pub next_commands: Vec<String>,
+    pub wall_time_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct VariantMention {
+    gene: String,
+    change: String,
+    /// The matched text, removed from the question before concept resolution.
+    text: String,
+}
+
+/// Finds gene + protein change mentions such as `BRAF V600E` or `KRAS p.G12C`.
+fn extract_variant_mention(question: &str) -> Option<VariantMention> {
+    static MENTION_RE: OnceLock<Regex> = OnceLock::new();
+    let re = MENTION_RE.get_or_init(|| {
+        Regex::new(r"\b([A-Z][A-Z0-9-]{1,9})\s+(?:p\.)?([A-Z]\d{1,5}(?:[A-Z]\b|\*|fs\b))")
+            .expect("valid regex")
+    });
+    let captures = re.captures(question)?;
+    let gene = captures.get(1)?.as_str();
+    if !crate::sources::is_valid_gene_symbol(gene) {
+        return None;
+    }
+    Some(VariantMention {
+        gene: gene.to_string(),
+        change: captures.get(2)?.as_str().to_string(),
+        text: captures.get(0)?.as_str().to_string(),
+    })
+}
+
+fn extract_rsid(question: &str) -> Option<String> {
+    static RSID_RE: OnceLock<Regex> = OnceLock::new();
+    RSID_RE
+        .get_or_init(|| Regex::new(r"\b(rs\d{3,})\b").expect("valid regex"))
+        .captures(question)
+        .and_then(|captures| captures.get(1))
+        .map(|m| m.as_str().to_string())
+}
+
+/// Removes the variant mention and trailing punctuation so the resolver sees
+/// only the remaining disease/drug/gene phrasing.
+fn concept_query(question: &str, variant_mention: Option<&str>) -> String {
+    let mut text = question.to_string();
+    if let Some(mention) = variant_mention {
+        text = text.replace(mention, " ");
+    }
+    text.trim()
+        .trim_end_matches(['?', '.', '!'])
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn top_concept(concepts: &[DiscoverConcept], kind: DiscoverType) -> Option<String> {
+    concepts
+        .iter()
+        .find(|concept| concept.primary_type == kind && concept.match_tier != MatchTier::Weak)
+        .map(|concept| concept.label.clone())
+}
+
+fn resolve_anchors(
+    question: &str,
+    discover: Option<&DiscoverResult>,
+    variant_mention: Option<VariantMention>,
+    rsid: Option<String>,
+) -> AskAnchors {
+    let concepts = discover.map_or(&[][..], |result| result.concepts.as_slice());
+    let mut anchors = AskAnchors {
+        gene: top_concept(concepts, DiscoverType::Gene),
+        variant: None,
+        disease: top_concept(concepts, DiscoverType::Disease),
+        drug: top_concept(concepts, DiscoverType::Drug),
+        keyword: None,
+    };
+
+    if let Some(mention) = variant_mention {
+        anchors.variant = Some(format!("{} {}", mention.gene, mention.change));
+        anchors.gene = Some(mention.gene);
+    } else if let Some(rsid) = rsid {
+        anchors.variant = Some(rsid);
+    }
+
+    if anchors.gene.is_none()
+        && anchors.variant.is_none()
+        && anchors.disease.is_none()
+        && anchors.drug.is_none()
+    {
+        let keyword = concept_query(question, None);
+        anchors.keyword = (!keyword.is_empty()).then_some(keyword);
+    }
+    anchors
+}
+
+fn intent_label(intent: DiscoverIntent) -> &'static str {
+    match intent {
+        DiscoverIntent::TrialSearch => "trial_search",
+        DiscoverIntent::DrugSafety => "drug_safety",
+        DiscoverIntent::TreatmentSearch => "treatment_search",
+        DiscoverIntent::SymptomSearch => "symptom_search",
+        DiscoverIntent::GeneDiseaseOrientation => "gene_disease_orientation",
+        DiscoverIntent::GeneFunction => "gene_function",
+        DiscoverIntent::General => "general",
+    }
+}
+
+/// Orders evidence so the entities most relevant to the question's intent come first.
+fn section_priority(intent: DiscoverIntent) -> &'static [&'static str] {
+    match intent {
+        DiscoverIntent::TreatmentSearch => &["drug", "trial", "article", "variant", "pgx"],
+        DiscoverIntent::TrialSearch => &["trial", "drug", "article"],
+        DiscoverIntent::DrugSafety => &["adverse-event", "drug", "article", "pgx"],
+        DiscoverIntent::SymptomSearch => &["disease", "article", "gene"],
+        DiscoverIntent::GeneDiseaseOrientation | DiscoverIntent::GeneFunction => {
+            &["gene", "variant", "disease", "article", "pathway"]
+        }
+        DiscoverIntent::General => &[],
+    }
+}
+
+fn sort_sections(sections: &mut [SearchAllSection], intent: DiscoverIntent) {
+    let priority = section_priority(intent);
+    sections.sort_by_key(|section| {
+        priority
+            .iter()
+            .position(|entity| *entity == section.entity)
+            .unwrap_or(priority.len())
+    });
+}
+
+fn field<'a>(row: &'a Value, key: &str) -> Option<&'a str> {
+    row.get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn join_details(parts: &[Option<&str>]) -> Option<String> {
+    let parts = parts.iter().flatten().copied().collect::<Vec<_>>();
+    (!parts.is_empty()).then(|| parts.join(" · "))
+}
+
+struct RowEvidence {
+    id: String,
+    title: String,
+    detail: Option<String>,
+    /// Citable source name and URL.
+    citation: Option<(&'static str, String)>,
+}
+
+impl RowEvidence {
+    fn new(id: &str, title: &str, detail: Option<String>) -> Self {
+        Self {
+            id: id.to_string(),
+            title: title.to_string(),
+            detail,
+            citation: None,
+        }
+    }
+
+    fn cited(mut self, source: &'static str, url: String) -> Self {
+        self.citation = Some((source, url));
+        self
+    }
+}
+
+/// Maps a `search all` row to an evidence item for its entity.
+fn evidence_fields(entity: &str, row: &Value) -> Option<RowEvidence> {
+    match entity {
+        "article" => {
+            let pmid = field(row, "pmid")?;
+            Some(
+                RowEvidence::new(
+                    pmid,
+                    field(row, "title").unwrap_or(pmid),
+                    join_details(&[field(row, "journal"), field(row, "date")]),
+                )
+                .cited("PubMed", format!("https://pubmed.ncbi.nlm.nih.gov/{pmid}/")),
+            )
+        }
+        "trial" => {
+            let nct = field(row, "nct_id")?;
+            Some(
+                RowEvidence::new(
+                    nct,
+                    field(row, "title").unwrap_or(nct),
+                    join_details(&[field(row, "status"), field(row, "phase")]),
+                )
+                .cited(
+                    "ClinicalTrials.gov",
+                    format!("https://clinicaltrials.gov/study/{nct}"),
+                ),
+            )
+        }
+        "variant" => {
+            let id = field(row, "id")?;
+            let title = join_details(&[field(row, "gene"), field(row, "hgvs_p")]);
+            Some(RowEvidence::new(
+                id,
+                title.as_deref().unwrap_or(id),
+                field(row, "significance").map(str::to_string),
+            ))
+        }
+        "drug" => {
+            let name = field(row, "name")?;
+            Some(RowEvidence::new(
+                name,
+                name,
+                join_details(&[
+                    field(row, "target"),
+                    field(row, "mechanism").or_else(|| field(row, "drug_type")),
+                ]),
+            ))
+        }
+        "gene" => {
+            let symbol = field(row, "symbol")?;
+            Some(RowEvidence::new(
+                symbol,
+                field(row, "name").unwrap_or(symbol),
+                None,
+            ))
+        }
+        "disease" | "pathway" => {
+            let id = field(row, "id")?;
+            Some(RowEvidence::new(id, field(row, "name").unwrap_or(id), None))
+        }
+        "pgx" => {
+            let gene = field(row, "genesymbol")?;
+            let drug = field(row, "drugname").unwrap_or("-");
+            Some(RowEvidence::new(
+                &format!("{gene}/{drug}"),
+                &format!("{gene} / {drug}"),
+                field(row, "cpiclevel").map(|level| format!("CPIC {level}")),
+            ))
+        }
+        "gwas" => {
+            let rsid = field(row, "rsid")?;
+            Some(RowEvidence::new(
+                rsid,
+                field(row, "trait_name").unwrap_or(rsid),
+                None,
+            ))
+        }
+        "adverse-event" => {
+            let reaction = field(row, "reaction")?;
+            Some(RowEvidence::new(
+                reaction,
+                reaction,
+                row.get("count").map(|count| format!("{count} reports")),
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn build_evidence(sections: &[SearchAllSection]) -> (Vec<AskEvidenceSection>, Vec<AskCitation>) {
+    let mut citations: Vec<AskCitation> = Vec::new();
+    let evidence = sections
+        .iter()
+        .filter(|section| section.error.is_some() || !section.results.is_empty())
+        .map(|section| {
+            let items = section
+                .results
+                .iter()
+                .filter_map(|row| evidence_fields(&section.entity, row))
+                .map(|row| {
+                    let RowEvidence {
+                        id,
+                        title,
+                        detail,
+                        citation,
+                    } = row;
+                    let citation = citation.map(|(source, url)| {
+                        if let Some(existing) = citations.iter().find(|c| c.url == url) {
+                            return existing.index;
+                        }
+                        let index = citations.len() + 1;
+                        citations.push(AskCitation {
+                            index,
+                            source: source.to_string(),
+                            id: id.clone(),
+                            title: title.clone(),
+                            url,
+                        });
+                        index
+                    });
+                    AskEvidenceItem {
+                        id,
+                        title,
+                        detail,
+                        citation,
+                    }
+                })
+                .collect::<Vec<_>>();
+            AskEvidenceSection {
+                entity: section.entity.clone(),
+                label: section.label.clone(),
+                items,
+                error: section.error.clone(),
+                command: section
+                    .links
+                    .iter()
+                    .find(|link| link.rel == "get.top")
+                    .or_else(|| section.links.first())
+                    .map(|link| link.command.clone()),
+            }
+        })
+        .collect();
+    (evidence, citations)
+}
+
+pub async fn run(input: &AskInput) -> Result<AskBundle, BioMcpError> {
+    let question = input.question.trim();
+    if question.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "A question is required. Example: biomcp ask \"what first-line therapies exist for BRAF V600E melanoma?\"".into(),
+        ));
+    }
+    if input.limit == 0 || input.limit > MAX_ASK_LIMIT {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--limit must be between 1 and {MAX_ASK_LIMIT}"
+        )));
+    }
+
+    let started = Instant::now();
+    let mut notes = Vec::new();
+    let variant_mention = extract_variant_mention(question);
+    let rsid = extract_rsid(question);
+    let concept_text = concept_query(
+        question,
+        variant_mention
+            .as_ref()
+            .map(|mention| mention.text.as_str()),
+    );
+
+    let discover = if concept_text.is_empty() {
+        None
+    } else {
+        match crate::entities::discover::resolve_query(&concept_text, DiscoverMode::Command).await {
+            Ok(result) => {
+                notes.extend(result.notes.iter().cloned());
+                Some(result)
+            }
+            Err(err) => {
+                notes.push(format!(
+                    "Concept resolution unavailable ({err}); falling back to keyword search."
+                ));
+                None
+            }
+        }
+    };
+    let intent = discover
+        .as_ref()
+        .map_or(DiscoverIntent::General, |result| result.intent);
+    let anchors = resolve_anchors(question, discover.as_ref(), variant_mention, rsid);
+
+    let search_input = SearchAllInput {
+        gene: anchors.gene.clone(),
+        variant: anchors.variant.clone(),
+        disease: anchors.disease.clone(),
+        drug: anchors.drug.clone(),
+        keyword: anchors.keyword.clone(),
+        since: None,
+        limit: input.limit,
+        counts_only: false,
+        debug_plan: false,
+    };
+    let mut results = crate::cli::search_all::dispatch(&search_input).await?;
+    sort_sections(&mut results.sections, intent);
+    let (evidence, citations) = build_evidence(&results.sections);
+
+    let mut next_commands = discover
+        .as_ref()
+        .map(|result| result.next_commands.clone())
+        .unwrap_or_default();
+    next_commands.truncate(3);
+    for section in &evidence {
+        if let Some(command) = section.command.as_ref()
+            && !next_commands.contains(command)
+        {
+            next_commands.push(command.clone());
+        }
+    }
+
+    Ok(AskBundle {
+        question: question.to_string(),
+        intent: intent_label(intent).to_string(),
+        anchors,
+        evidence,
+        citations,
+        notes,
+        next_commands,
+        wall_time_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn section(entity: &str, results: Vec<Value>) -> SearchAllSection {
+        SearchAllSection {
+            entity: entity.to_string(),
+            label: entity.to_string(),
+            count: results.len(),
+            total: None,
+            error: None,
+            note: None,
+            results,
+            links: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn extract_variant_mention_finds_gene_and_protein_change() {
+        let mention =
+            extract_variant_mention("what first-line therapies exist for BRAF V600E melanoma?")
+                .expect("BRAF V600E mention");
+        assert_eq!(
+            (
+                mention.gene.as_str(),
+                mention.change.as_str(),
+                mention.text.as_str()
+            ),
+            ("BRAF", "V600E", "BRAF V600E")
+        );
+        let mention = extract_variant_mention("is KRAS p.G12C targetable").expect("KRAS mention");
+        assert_eq!(mention.text, "KRAS p.G12C");
+        assert_eq!(extract_variant_mention("what treats melanoma?"), None);
+        assert_eq!(
+            extract_rsid("risk from rs113488022?").as_deref(),
+            Some("rs113488022")
+        );
+    }
+
+    #[test]
+    fn concept_query_drops_variant_mention_and_punctuation() {
+        assert_eq!(
+            concept_query(
+                "what first-line therapies exist for BRAF V600E melanoma?",
+                Some("BRAF V600E")
+            ),
+            "what first-line therapies exist for melanoma"
+        );
+    }
+
+    #[test]
+    fn resolve_anchors_falls_back_to_keyword_without_concepts() {
+        let anchors = resolve_anchors("how does autophagy work?", None, None, None);
+        assert_eq!(anchors.keyword.as_deref(), Some("how does autophagy work"));
+
+        let anchors = resolve_anchors(
+            "BRAF V600E melanoma",
+            None,
+            Some(VariantMention {
+                gene: "BRAF".into(),
+                change: "V600E".into(),
+                text: "BRAF V600E".into(),
+            }),
+            None,
+        );
+        assert_eq!(anchors.gene.as_deref(), Some("BRAF"));
+        assert_eq!(anchors.variant.as_deref(), Some("BRAF V600E"));
+        assert!(anchors.keyword.is_none());
+    }
+
+    #[test]
+    fn build_evidence_numbers_citations_and_orders_by_intent() {
+        let mut sections = vec![
+            section(
+                "article",
+                vec![json!({"pmid": "123", "title": "BRAF review", "date": "2024"})],
+            ),
+            section("gene", vec![json!({"symbol": "BRAF", "name": "B-Raf"})]),
+            section(
+                "trial",
+                vec![
+                    json!({"nct_id": "NCT00000001", "title": "Dabrafenib trial", "status": "RECRUITING"}),
+                ],
+            ),
+            section("drug", Vec::new()),
+        ];
+        sort_sections(&mut sections, DiscoverIntent::TreatmentSearch);
+        let (evidence, citations) = build_evidence(&sections);
+
+        let order = evidence
+            .iter()
+            .map(|s| s.entity.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["trial", "article", "gene"]);
+        assert_eq!(citations.len(), 2);
+        assert_eq!(
+            citations[0].url,
+            "https://clinicaltrials.gov/study/NCT00000001"
+        );
+        assert_eq!(evidence[0].items[0].citation, Some(1));
+        assert_eq!(evidence[1].items[0].citation, Some(2));
+        assert_eq!(evidence[2].items[0].citation, None);
+    }
+}
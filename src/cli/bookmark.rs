@@ -0,0 +1,690 @@
+//! Saved query bookmarks stored in `<config_dir>/biomcp/bookmarks.toml`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use clap::{CommandFactory, Parser, Subcommand};
+use serde::{Deserialize, Serialize};
+
+use super::diff::DiffCommand;
+use super::{Cli, CommandOutcome, Commands, StudyCommand, display_command};
+use crate::error::BioMcpError;
+
+const BOOKMARKS_FILE: &str = "bookmarks.toml";
+const MAX_BOOKMARK_NAME_LEN: usize = 64;
+
+#[derive(Subcommand, Debug)]
+pub enum BookmarkCommand {
+    /// Save a BioMCP command under a short name
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp bookmark save melanoma-trials -- search trial -c melanoma --phase 3
+  biomcp bookmark save braf-variants --force -- search variant -g BRAF --limit 20")]
+    Save {
+        /// Bookmark name (letters, digits, '-' and '_')
+        name: String,
+        /// Replace an existing bookmark with the same name
+        #[arg(long)]
+        force: bool,
+        /// Command to save, after `--` (without the leading `biomcp`)
+        #[arg(last = true, required = true, value_name = "COMMAND")]
+        command: Vec<String>,
+    },
+    /// Run a saved bookmark; extra flags or query tokens override the saved ones
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp bookmark run melanoma-trials
+  biomcp bookmark run melanoma-trials --phase 2 --limit 5
+  biomcp --json bookmark run melanoma-trials")]
+    Run {
+        /// Bookmark name
+        name: String,
+        /// Flags or positional tokens that replace the saved values
+        #[arg(
+            value_name = "OVERRIDES",
+            trailing_var_arg = true,
+            allow_hyphen_values = true
+        )]
+        overrides: Vec<String>,
+    },
+    /// List saved bookmarks
+    List,
+    /// Delete a saved bookmark
+    Delete {
+        /// Bookmark name
+        name: String,
+    },
+    /// Print all bookmarks as TOML (or JSON with --json) for sharing
+    Export,
+    /// Import bookmarks from a TOML file produced by `bookmark export`
+    Import {
+        /// Path to the bookmarks file
+        path: PathBuf,
+        /// Replace existing bookmarks with the same names
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Bookmark {
+    pub(crate) args: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) saved_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct BookmarkFile {
+    #[serde(default)]
+    pub(crate) bookmarks: BTreeMap<String, Bookmark>,
+}
+
+#[derive(Debug, Serialize)]
+struct BookmarkListEntry<'a> {
+    name: &'a str,
+    command: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    saved_at: Option<&'a str>,
+}
+
+fn bookmarks_path() -> Result<PathBuf, BioMcpError> {
+    crate::config::config_dir()
+        .map(|dir| dir.join(BOOKMARKS_FILE))
+        .ok_or_else(|| {
+            BioMcpError::InvalidArgument(
+                "Unable to resolve a config directory for bookmarks".into(),
+            )
+        })
+}
+
+pub(crate) fn parse_bookmark_file(
+    content: &str,
+    path: Option<&Path>,
+) -> Result<BookmarkFile, BioMcpError> {
+    if content.trim().is_empty() {
+        return Ok(BookmarkFile::default());
+    }
+    let file = toml::from_str::<BookmarkFile>(content).map_err(|err| {
+        crate::config::invalid_config(path, format!("failed to parse bookmarks: {err}"))
+    })?;
+    for (name, bookmark) in &file.bookmarks {
+        validate_bookmark_name(name)?;
+        validate_bookmark_args(&bookmark.args)?;
+    }
+    Ok(file)
+}
+
+fn load_bookmarks(path: &Path) -> Result<BookmarkFile, BioMcpError> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => parse_bookmark_file(&content, Some(path)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(BookmarkFile::default()),
+        Err(err) => Err(BioMcpError::Io(std::io::Error::new(
+            err.kind(),
+            format!("failed to read {}: {}", path.display(), err),
+        ))),
+    }
+}
+
+fn store_bookmarks(path: &Path, file: &BookmarkFile) -> Result<(), BioMcpError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let content = toml::to_string_pretty(file).map_err(|err| {
+        BioMcpError::InvalidArgument(format!("failed to encode bookmarks: {err}"))
+    })?;
+    let staging = path.with_extension("toml.tmp");
+    std::fs::write(&staging, content)?;
+    std::fs::rename(&staging, path)?;
+    Ok(())
+}
+
+pub(crate) fn validate_bookmark_name(name: &str) -> Result<(), BioMcpError> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_BOOKMARK_NAME_LEN
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(BioMcpError::InvalidArgument(format!(
+            "Invalid bookmark name '{name}'. Use 1-{MAX_BOOKMARK_NAME_LEN} letters, digits, '-' or '_'."
+        )))
+    }
+}
+
+/// Read-only query families. Anything else (including subcommands added later)
+/// manages local state, runs servers, or schedules work, and is refused.
+pub(super) fn is_bookmarkable(command: &Commands) -> bool {
+    match command {
+        Commands::Search { .. }
+        | Commands::Get { .. }
+        | Commands::Variant { .. }
+        | Commands::Drug { .. }
+        | Commands::Disease { .. }
+        | Commands::Article { .. }
+        | Commands::Gene { .. }
+        | Commands::Pathway { .. }
+        | Commands::Protein { .. }
+        | Commands::Pgx { .. }
+        | Commands::Report { .. }
+        | Commands::Compare { .. }
+        | Commands::Network { .. }
+        | Commands::Ontology { .. }
+        | Commands::Annotate { .. }
+        | Commands::Match { .. }
+        | Commands::Validate { .. }
+        | Commands::Health(_)
+        | Commands::Selftest(_)
+        | Commands::List(_)
+        | Commands::Sections(_)
+        | Commands::Schema(_)
+        | Commands::Batch(_)
+        | Commands::Enrich(_)
+        | Commands::Dossier(_)
+        | Commands::Raw(_)
+        | Commands::Repurpose(_)
+        | Commands::Discover(_)
+        | Commands::Ask(_)
+        | Commands::Version(_) => true,
+        Commands::Study { cmd } => !matches!(cmd, StudyCommand::Download { .. }),
+        // A plain diff stores a new snapshot; only the compare-only form is a query.
+        Commands::Diff {
+            cmd: DiffCommand::Gene { no_save, .. },
+        } => *no_save,
+        _ => false,
+    }
+}
+
+/// Rejects commands that cannot be replayed as a read-only query.
+pub(crate) fn validate_bookmark_args(args: &[String]) -> Result<(), BioMcpError> {
+    let Some(first) = args.first() else {
+        return Err(BioMcpError::InvalidArgument(
+            "Bookmark command is empty. Example: biomcp bookmark save melanoma-trials -- search trial -c melanoma".into(),
+        ));
+    };
+    if first.eq_ignore_ascii_case("biomcp") {
+        return Err(BioMcpError::InvalidArgument(
+            "Save the command without the leading `biomcp`.".into(),
+        ));
+    }
+    let argv = std::iter::once("biomcp".to_string()).chain(args.iter().cloned());
+    let cli = Cli::try_parse_from(argv).map_err(|err| {
+        BioMcpError::InvalidArgument(format!(
+            "Bookmark command does not parse: {}",
+            err.to_string()
+                .lines()
+                .next()
+                .unwrap_or("invalid arguments")
+        ))
+    })?;
+    if !is_bookmarkable(&cli.command) {
+        let family = args
+            .iter()
+            .find(|arg| !arg.starts_with('-'))
+            .unwrap_or(first);
+        return Err(BioMcpError::InvalidArgument(format!(
+            "`{family}` commands cannot be bookmarked; only read-only queries can be saved"
+        )));
+    }
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+struct ArgSegment {
+    /// Clap argument id for flags; `None` for positional tokens.
+    id: Option<String>,
+    tokens: Vec<String>,
+}
+
+fn find_flag<'a>(command: &'a clap::Command, token: &str) -> Option<&'a clap::Arg> {
+    if let Some(long) = token.strip_prefix("--") {
+        let long = long.split_once('=').map_or(long, |(name, _)| name);
+        return command.get_arguments().find(|arg| {
+            arg.get_long() == Some(long)
+                || arg
+                    .get_all_aliases()
+                    .is_some_and(|aliases| aliases.contains(&long))
+        });
+    }
+    let mut chars = token.strip_prefix('-')?.chars();
+    let short = chars.next()?;
+    command
+        .get_arguments()
+        .find(|arg| arg.get_short() == Some(short))
+}
+
+fn split_segments(command: &clap::Command, args: &[String]) -> Vec<ArgSegment> {
+    let mut segments = Vec::new();
+    let mut i = 0usize;
+    while i < args.len() {
+        let token = &args[i];
+        let is_flag = token.len() > 1
+            && token.starts_with('-')
+            && token.parse::<f64>().is_err()
+            && token != "--";
+        if !is_flag {
+            segments.push(ArgSegment {
+                id: None,
+                tokens: vec![token.clone()],
+            });
+            i += 1;
+            continue;
+        }
+
+        let arg = find_flag(command, token);
+        let id = arg.map_or_else(
+            || {
+                token
+                    .split_once('=')
+                    .map_or(token.as_str(), |(name, _)| name)
+                    .to_string()
+            },
+            |arg| arg.get_id().to_string(),
+        );
+        let inline_value = token.contains('=') || (!token.starts_with("--") && token.len() > 2);
+        let takes_value = arg.is_some_and(|arg| arg.get_action().takes_values());
+        let mut tokens = vec![token.clone()];
+        if takes_value && !inline_value && i + 1 < args.len() {
+            tokens.push(args[i + 1].clone());
+            i += 1;
+        }
+        segments.push(ArgSegment {
+            id: Some(id),
+            tokens,
+        });
+        i += 1;
+    }
+    segments
+}
+
+/// Applies run-time overrides to saved bookmark args.
+///
+/// Flags given as overrides replace every saved occurrence of the same flag
+/// (matched by clap id, so `-c` and `--condition` are the same flag).
+/// Positional override tokens replace the saved positional query.
+pub(crate) fn merge_override_args(saved: &[String], overrides: &[String]) -> Vec<String> {
+    if overrides.is_empty() {
+        return saved.to_vec();
+    }
+
+    let mut command = Cli::command();
+    command.build();
+    let mut leaf = &command;
+    let mut prefix_len = 0usize;
+    for token in saved {
+        match leaf.find_subcommand(token) {
+            Some(sub) => {
+                leaf = sub;
+                prefix_len += 1;
+            }
+            None => break,
+        }
+    }
+
+    let saved_segments = split_segments(leaf, &saved[prefix_len..]);
+    let override_segments = split_segments(leaf, overrides);
+    let override_ids = override_segments
+        .iter()
+        .filter_map(|segment| segment.id.as_deref())
+        .collect::<BTreeSet<_>>();
+    let replaces_positionals = override_segments.iter().any(|segment| segment.id.is_none());
+
+    let mut positionals = Vec::new();
+    let mut flags = Vec::new();
+    let tagged = saved_segments
+        .iter()
+        .map(|segment| (segment, false))
+        .chain(override_segments.iter().map(|segment| (segment, true)));
+    for (segment, from_override) in tagged {
+        match segment.id.as_deref() {
+            None if from_override || !replaces_positionals => {
+                positionals.extend(segment.tokens.iter().cloned())
+            }
+            None => {}
+            Some(id) if from_override || !override_ids.contains(id) => {
+                flags.extend(segment.tokens.iter().cloned())
+            }
+            Some(_) => {}
+        }
+    }
+
+    let mut merged = saved[..prefix_len].to_vec();
+    merged.extend(positionals);
+    merged.extend(flags);
+    merged
+}
+
+/// Strips global output flags from run-time overrides so they apply to the replayed command.
+fn take_global_flags(overrides: Vec<String>) -> (Vec<String>, bool, bool) {
+    let mut json = false;
+    let mut no_cache = false;
+    let rest = overrides
+        .into_iter()
+        .filter(|token| match token.as_str() {
+            "--json" | "-j" => {
+                json = true;
+                false
+            }
+            "--no-cache" => {
+                no_cache = true;
+                false
+            }
+            _ => true,
+        })
+        .collect();
+    (rest, json, no_cache)
+}
+
+fn unknown_bookmark(name: &str) -> BioMcpError {
+    BioMcpError::NotFound {
+        entity: "bookmark".into(),
+        id: name.to_string(),
+        suggestion: "List saved bookmarks with `biomcp bookmark list`.".into(),
+    }
+}
+
+fn now_rfc3339() -> String {
+    humantime::format_rfc3339_seconds(SystemTime::now()).to_string()
+}
+
+pub(crate) async fn handle_command(
+    cmd: BookmarkCommand,
+    json: bool,
+    no_cache: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let path = bookmarks_path()?;
+    match cmd {
+        BookmarkCommand::Save {
+            name,
+            force,
+            command,
+        } => {
+            validate_bookmark_name(&name)?;
+            validate_bookmark_args(&command)?;
+            let mut file = load_bookmarks(&path)?;
+            if !force && file.bookmarks.contains_key(&name) {
+                return Err(BioMcpError::InvalidArgument(format!(
+                    "Bookmark '{name}' already exists. Pass --force to replace it."
+                ))
+                .into());
+            }
+            let bookmark = Bookmark {
+                args: command,
+                saved_at: Some(now_rfc3339()),
+            };
+            let text = if json {
+                crate::render::json::to_pretty(&BookmarkListEntry {
+                    name: &name,
+                    command: display_command(&bookmark.args),
+                    saved_at: bookmark.saved_at.as_deref(),
+                })?
+            } else {
+                format!(
+                    "Saved bookmark `{name}`: `{}`\nRun it with: `biomcp bookmark run {name}`",
+                    display_command(&bookmark.args)
+                )
+            };
+            file.bookmarks.insert(name, bookmark);
+            store_bookmarks(&path, &file)?;
+            Ok(CommandOutcome::stdout(text))
+        }
+        BookmarkCommand::Run { name, overrides } => {
+            let file = load_bookmarks(&path)?;
+            let bookmark = file
+                .bookmarks
+                .get(&name)
+                .ok_or_else(|| unknown_bookmark(&name))?;
+            let (overrides, override_json, override_no_cache) = take_global_flags(overrides);
+            let args = merge_override_args(&bookmark.args, &overrides);
+            validate_bookmark_args(&args)?;
+            let mut cli = Cli::try_parse_from(std::iter::once("biomcp".to_string()).chain(args))?;
+            cli.json |= json || override_json;
+            cli.no_cache |= no_cache || override_no_cache;
+            Box::pin(super::run_outcome(cli)).await
+        }
+        BookmarkCommand::List => {
+            let file = load_bookmarks(&path)?;
+            let entries = file
+                .bookmarks
+                .iter()
+                .map(|(name, bookmark)| BookmarkListEntry {
+                    name,
+                    command: display_command(&bookmark.args),
+                    saved_at: bookmark.saved_at.as_deref(),
+                })
+                .collect::<Vec<_>>();
+            if json {
+                return Ok(CommandOutcome::stdout(crate::render::json::to_pretty(
+                    &entries,
+                )?));
+            }
+            if entries.is_empty() {
+                return Ok(CommandOutcome::stdout(
+                    "No bookmarks saved. Example: biomcp bookmark save melanoma-trials -- search trial -c melanoma".into(),
+                ));
+            }
+            let mut out = format!(
+                "# Bookmarks ({})\n\n| Name | Command |\n|---|---|\n",
+                entries.len()
+            );
+            for entry in &entries {
+                out.push_str(&format!("| {} | `{}` |\n", entry.name, entry.command));
+            }
+            Ok(CommandOutcome::stdout(out))
+        }
+        BookmarkCommand::Delete { name } => {
+            let mut file = load_bookmarks(&path)?;
+            if file.bookmarks.remove(&name).is_none() {
+                return Err(unknown_bookmark(&name).into());
+            }
+            store_bookmarks(&path, &file)?;
+            Ok(CommandOutcome::stdout(format!("Deleted bookmark `{name}`")))
+        }
+        BookmarkCommand::Export => {
+            let file = load_bookmarks(&path)?;
+            let text = if json {
+                crate::render::json::to_pretty(&file)?
+            } else {
+                toml::to_string_pretty(&file).map_err(|err| {
+                    BioMcpError::InvalidArgument(format!("failed to encode bookmarks: {err}"))
+                })?
+            };
+            Ok(CommandOutcome::stdout(text))
+        }
+        BookmarkCommand::Import {
+            path: source,
+            force,
+        } => {
+            let content = std::fs::read_to_string(&source).map_err(|err| {
+                BioMcpError::Io(std::io::Error::new(
+                    err.kind(),
+                    format!("failed to read {}: {}", source.display(), err),
+                ))
+            })?;
+            let incoming = parse_bookmark_file(&content, Some(&source))?;
+            let mut file = load_bookmarks(&path)?;
+            let (imported, conflicts) = merge_imported(&mut file, incoming, force);
+            if !conflicts.is_empty() {
+                return Err(BioMcpError::InvalidArgument(format!(
+                    "Bookmarks already exist: {}. Pass --force to replace them.",
+                    conflicts.join(", ")
+                ))
+                .into());
+            }
+            store_bookmarks(&path, &file)?;
+            Ok(CommandOutcome::stdout(format!(
+                "Imported {imported} bookmark(s) from {}",
+                source.display()
+            )))
+        }
+    }
+}
+
+/// Merges imported bookmarks into `file`, returning the count and any name conflicts.
+///
+/// Nothing is merged when conflicts exist and `force` is false.
+fn merge_imported(
+    file: &mut BookmarkFile,
+    incoming: BookmarkFile,
+    force: bool,
+) -> (usize, Vec<String>) {
+    if !force {
+        let conflicts = incoming
+            .bookmarks
+            .keys()
+            .filter(|name| file.bookmarks.contains_key(*name))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !conflicts.is_empty() {
+            return (0, conflicts);
+        }
+    }
+    let imported = incoming.bookmarks.len();
+    file.bookmarks.extend(incoming.bookmarks);
+    (imported, Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Bookmark, BookmarkFile, merge_imported, merge_override_args, parse_bookmark_file,
+        take_global_flags, validate_bookmark_args, validate_bookmark_name,
+    };
+
+    fn argv(tokens: &[&str]) -> Vec<String> {
+        tokens.iter().map(|token| token.to_string()).collect()
+    }
+
+    #[test]
+    fn merge_override_args_replaces_matching_flags_by_id() {
+        let saved = argv(&["search", "trial", "-c", "melanoma", "--phase", "3"]);
+        let merged = merge_override_args(&saved, &argv(&["--condition", "glioma", "--limit", "5"]));
+        assert_eq!(
+            merged,
+            argv(&[
+                "search",
+                "trial",
+                "--phase",
+                "3",
+                "--condition",
+                "glioma",
+                "--limit",
+                "5"
+            ])
+        );
+    }
+
+    #[test]
+    fn merge_override_args_replaces_positional_query() {
+        let saved = argv(&["search", "variant", "BRAF", "--limit", "5"]);
+        let merged = merge_override_args(&saved, &argv(&["KRAS"]));
+        assert_eq!(merged, argv(&["search", "variant", "KRAS", "--limit", "5"]));
+
+        let untouched = merge_override_args(&saved, &[]);
+        assert_eq!(untouched, saved);
+    }
+
+    #[test]
+    fn take_global_flags_lifts_json_and_no_cache() {
+        let (rest, json, no_cache) = take_global_flags(argv(&["--json", "--limit", "3"]));
+        assert_eq!(rest, argv(&["--limit", "3"]));
+        assert!(json);
+        assert!(!no_cache);
+    }
+
+    #[test]
+    fn validate_bookmark_rejects_bad_names_and_commands() {
+        assert!(validate_bookmark_name("melanoma-trials_2").is_ok());
+        assert!(validate_bookmark_name("bad name").is_err());
+        assert!(validate_bookmark_name("").is_err());
+
+        assert!(validate_bookmark_args(&argv(&["search", "trial", "-c", "melanoma"])).is_ok());
+        assert!(validate_bookmark_args(&argv(&["biomcp", "search", "trial"])).is_err());
+        assert!(validate_bookmark_args(&argv(&["bookmark", "list"])).is_err());
+        assert!(validate_bookmark_args(&argv(&["search", "nope"])).is_err());
+    }
+
+    #[test]
+    fn validate_bookmark_args_allows_only_read_only_queries() {
+        for allowed in [
+            &["--json", "get", "gene", "BRAF"][..],
+            &[
+                "study",
+                "query",
+                "--study",
+                "msk_impact_2017",
+                "--gene",
+                "TP53",
+                "--type",
+                "mutations",
+            ],
+            &["diff", "gene", "BRAF", "--no-save"],
+        ] {
+            assert!(
+                validate_bookmark_args(&argv(allowed)).is_ok(),
+                "{allowed:?}"
+            );
+        }
+        for denied in [
+            &["cache", "clear", "--yes"][..],
+            &["cache", "purge", "--older-than", "30d"],
+            &["history", "list"],
+            &["jobs", "submit", "--", "get", "gene", "BRAF"],
+            &["snapshot", "create", "--entities", "genes.txt"],
+            &["exec", "--jsonl"],
+            &["study", "download", "msk_impact_2017"],
+            &["diff", "gene", "BRAF"],
+            &["--json", "serve-http"],
+        ] {
+            let err = validate_bookmark_args(&argv(denied)).expect_err("denied");
+            assert!(
+                err.to_string().contains("cannot be bookmarked"),
+                "{denied:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn bookmark_file_round_trips_through_toml() {
+        let mut file = BookmarkFile::default();
+        file.bookmarks.insert(
+            "melanoma-trials".into(),
+            Bookmark {
+                args: argv(&["search", "trial", "-c", "melanoma", "--phase", "3"]),
+                saved_at: Some("2026-01-01T00:00:00Z".into()),
+            },
+        );
+        let encoded = toml::to_string_pretty(&file).expect("encode");
+        let decoded = parse_bookmark_file(&encoded, None).expect("decode");
+        assert_eq!(decoded, file);
+
+        let err = parse_bookmark_file("[bookmarks.x]\nargs = [\"mcp\"]\n", None)
+            .expect_err("mcp should be rejected");
+        assert!(err.to_string().contains("cannot be bookmarked"));
+    }
+
+    #[test]
+    fn merge_imported_reports_conflicts_without_force() {
+        let bookmark = Bookmark {
+            args: argv(&["search", "gene", "BRAF"]),
+            saved_at: None,
+        };
+        let mut file = BookmarkFile::default();
+        file.bookmarks.insert("braf".into(), bookmark.clone());
+        let mut incoming = BookmarkFile::default();
+        incoming.bookmarks.insert("braf".into(), bookmark.clone());
+        incoming.bookmarks.insert("kras".into(), bookmark);
+
+        let (imported, conflicts) = merge_imported(&mut file, incoming.clone(), false);
+        assert_eq!((imported, conflicts), (0, vec!["braf".to_string()]));
+        assert_eq!(file.bookmarks.len(), 1);
+
+        let (imported, conflicts) = merge_imported(&mut file, incoming, true);
+        assert_eq!((imported, conflicts.len()), (2, 0));
+        assert_eq!(file.bookmarks.len(), 2);
+    }
+}
@@ -1,11 +1,12 @@
 use std::io::{self, Write};
+use std::path::PathBuf;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use clap::Subcommand;
 
 use crate::error::BioMcpError;
 
-#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
 pub enum CacheCommand {
     /// Print the managed HTTP cache path as plain text (`--json` is ignored)
     #[command(long_about = "\
@@ -19,8 +20,9 @@ This command family is CLI-only because it reveals workstation-local filesystem
     #[command(long_about = "\
 Show HTTP cache statistics.
 
-Print an on-demand snapshot of blob counts, bytes, age range, and configured cache limits.
-Use the global `--json` flag for machine-readable output.
+Print an on-demand snapshot of blob counts, bytes, age range, and configured cache limits,
+followed by entry counts and bytes per upstream source (mygene, uniprot, ...) and per
+entity command (gene, variant, ...). Use the global `--json` flag for machine-readable output.
 This command is CLI-only because cache commands reveal workstation-local filesystem paths.")]
     Stats,
     /// Remove orphan blobs and optionally evict cache entries by age or size
@@ -46,20 +48,66 @@ This command is CLI-only because cache commands reveal workstation-local filesys
         #[arg(long)]
         dry_run: bool,
     },
-    /// Wipe the entire managed HTTP cache directory
+    /// Delete cache entries older than a duration, leaving no index trace
     #[command(long_about = "\
-Wipe the entire managed HTTP cache directory.
+Delete cache entries older than a duration, leaving no index trace.
+
+Removes every entry older than --older-than (e.g. 30d, 12h) together with its
+response blob and all orphaned blobs. Unlike `biomcp cache clean`, purged keys
+are deleted from the cache index outright instead of being tombstoned, so the
+request URLs of purged queries no longer appear on disk. Schedule it to enforce
+data-retention policies for cached clinical queries. Use --dry-run to preview
+the plan; the global `--json` flag returns the structured report.
+This command is CLI-only because cache commands reveal workstation-local filesystem paths.")]
+    Purge {
+        /// Delete entries older than this duration (e.g. 30d, 12h)
+        #[arg(long, value_parser = parse_cache_older_than)]
+        older_than: Duration,
+
+        /// Show the purge plan without deleting anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Wipe the managed HTTP cache directory, or one source's entries
+    #[command(long_about = "\
+Wipe the managed HTTP cache directory, or one source's entries.
 
 Deletes all contents of <resolved cache_root>/http. This is a destructive full wipe;
-use `biomcp cache clean` for targeted cleanup instead. The managed downloads/ sibling
-directory is never touched. Interactive confirmation is required unless you pass
---yes. Without a TTY and without --yes, this command refuses even under `--json`.
+use `biomcp cache clean` for targeted cleanup instead. With --source (e.g. mygene,
+uniprot), only entries fetched from that source are deleted, together with their
+response blobs; `biomcp cache stats` lists the source names present. The managed
+downloads/ sibling directory is never touched. Interactive confirmation is required
+unless you pass --yes. Without a TTY and without --yes, this command refuses even
+under `--json`.
 
 This command is CLI-only because cache commands reveal workstation-local filesystem paths.")]
     Clear {
         /// Skip the confirmation prompt for non-interactive or scripted use
         #[arg(long)]
         yes: bool,
+
+        /// Only delete entries fetched from this source (e.g. mygene, uniprot)
+        #[arg(long, value_parser = parse_cache_source)]
+        source: Option<String>,
+    },
+    /// Pre-fetch gene cards into the HTTP cache
+    #[command(long_about = "\
+Pre-fetch gene cards into the HTTP cache.
+
+Reads gene symbols from --genes-file (separated by commas, whitespace, or lines; `#`
+starts a comment) and fetches each gene card with the requested sections, so later
+`biomcp get gene` calls and agent sessions are served from the local cache. Genes
+that fail to resolve are listed instead of failing the run; the command exits 1 if
+any gene failed. Use the global `--json` flag for the structured report.
+This command is CLI-only because cache commands reveal workstation-local filesystem paths.")]
+    Warm {
+        /// Panel file with one or more gene symbols per line
+        #[arg(long, value_name = "PATH")]
+        genes_file: PathBuf,
+
+        /// Gene sections to fetch as well (e.g. civic,clinvar-summary)
+        #[arg(long, value_delimiter = ',')]
+        sections: Vec<String>,
     },
 }
 
@@ -68,6 +116,19 @@ fn parse_cache_max_age(value: &str) -> Result<Duration, String> {
         .map_err(|err| format!("--max-age must be a duration like 30d or 12h: {err}"))
 }
 
+fn parse_cache_older_than(value: &str) -> Result<Duration, String> {
+    humantime::parse_duration(value)
+        .map_err(|err| format!("--older-than must be a duration like 30d or 12h: {err}"))
+}
+
+fn parse_cache_source(value: &str) -> Result<String, String> {
+    let source = value.trim().to_ascii_lowercase();
+    if source.is_empty() {
+        return Err("--source must name a cache source such as mygene or uniprot".into());
+    }
+    Ok(source)
+}
+
 fn parse_cache_max_size(value: &str) -> Result<u64, String> {
     value
         .parse::<bytesize::ByteSize>()
@@ -75,6 +136,27 @@ fn parse_cache_max_size(value: &str) -> Result<u64, String> {
         .map_err(|err| format!("--max-size must be a size like 5G or 500M: {err}"))
 }
 
+/// Apply the global `--cache-dir` flag before any cache or HTTP client is created.
+///
+/// # Errors
+///
+/// Returns an error if the directory is blank or cannot exist on this platform.
+pub fn apply_cache_dir_override(dir: Option<&str>) -> Result<(), BioMcpError> {
+    crate::cache::set_cache_dir_override(dir)
+}
+
+/// Load the cache encryption key once at startup when `[cache].encrypt` is on,
+/// so a missing key or keychain is reported before any command runs.
+///
+/// # Errors
+///
+/// Returns an error if cache configuration resolution fails or encryption is
+/// enabled and no usable key is available.
+pub fn init_cache_encryption() -> Result<(), BioMcpError> {
+    let config = crate::cache::resolve_cache_config()?;
+    crate::cache::CacheCipher::shared(config.encrypt).map(drop)
+}
+
 /// Render the managed HTTP cache path without creating or migrating cache directories.
 ///
 /// # Errors
@@ -85,6 +167,15 @@ pub fn render_path() -> Result<String, BioMcpError> {
     Ok(config.cache_root.join("http").display().to_string())
 }
 
+fn current_time_ms() -> Result<u128, BioMcpError> {
+    Ok(SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|err| {
+            BioMcpError::InvalidArgument(format!("system clock is before the Unix epoch: {err}"))
+        })?
+        .as_millis())
+}
+
 pub(crate) fn execute_clean(
     max_age: Option<Duration>,
     max_size: Option<u64>,
@@ -92,12 +183,7 @@ pub(crate) fn execute_clean(
 ) -> Result<crate::cache::CleanReport, BioMcpError> {
     let config = crate::cache::resolve_cache_config()?;
     let cache_path = config.cache_root.join("http");
-    let now_ms = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|err| {
-            BioMcpError::InvalidArgument(format!("system clock is before the Unix epoch: {err}"))
-        })?
-        .as_millis();
+    let now_ms = current_time_ms()?;
     crate::cache::execute_cache_clean(
         &cache_path,
         crate::cache::CleanOptions {
@@ -110,6 +196,26 @@ pub(crate) fn execute_clean(
     )
 }
 
+pub(crate) fn execute_purge(
+    older_than: Duration,
+    dry_run: bool,
+) -> Result<crate::cache::CleanReport, BioMcpError> {
+    let config = crate::cache::resolve_cache_config()?;
+    let cache_path = config.cache_root.join("http");
+    crate::cache::execute_cache_purge(&cache_path, older_than, dry_run, current_time_ms()?)
+}
+
+pub(crate) fn render_purge_text(report: &crate::cache::CleanReport) -> String {
+    format!(
+        "Cache purge: dry_run={} entries_removed={} orphans_removed={} bytes_freed={} errors={}",
+        report.dry_run,
+        report.entries_removed,
+        report.orphans_removed,
+        report.bytes_freed,
+        report.errors.len()
+    )
+}
+
 pub(crate) fn render_clean_text(report: &crate::cache::CleanReport) -> String {
     format!(
         "Cache clean: dry_run={} orphans_removed={} entries_removed={} bytes_freed={} errors={}",
@@ -136,13 +242,23 @@ pub(crate) fn render_clear_text(report: &crate::cache::ClearReport) -> String {
     )
 }
 
-pub(crate) fn prompt_clear_confirmation(cache_path: &std::path::Path) -> Result<bool, BioMcpError> {
+pub(crate) fn prompt_clear_confirmation(
+    cache_path: &std::path::Path,
+    source: Option<&str>,
+) -> Result<bool, BioMcpError> {
     let mut stderr = io::stderr();
-    write!(
-        &mut stderr,
-        "This will permanently delete the managed HTTP cache at {}. Continue? [y/N]: ",
-        cache_path.display()
-    )?;
+    match source {
+        Some(source) => write!(
+            &mut stderr,
+            "This will permanently delete cached {source} responses from {}. Continue? [y/N]: ",
+            cache_path.display()
+        )?,
+        None => write!(
+            &mut stderr,
+            "This will permanently delete the managed HTTP cache at {}. Continue? [y/N]: ",
+            cache_path.display()
+        )?,
+    }
     stderr.flush()?;
 
     let mut answer = String::new();
@@ -151,6 +267,84 @@ pub(crate) fn prompt_clear_confirmation(cache_path: &std::path::Path) -> Result<
     Ok(answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes"))
 }
 
+/// Gene cards fetched at once by `cache warm`; keeps bursts polite to MyGene
+/// and the section sources behind it.
+const WARM_CONCURRENCY: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub(crate) struct CacheWarmFailure {
+    pub(crate) gene: String,
+    pub(crate) error: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub(crate) struct CacheWarmReport {
+    pub(crate) requested: usize,
+    pub(crate) warmed: usize,
+    pub(crate) failed: Vec<CacheWarmFailure>,
+}
+
+pub(crate) async fn execute_warm(
+    genes_file: &std::path::Path,
+    sections: &[String],
+) -> Result<CacheWarmReport, BioMcpError> {
+    use futures::StreamExt;
+
+    let raw = std::fs::read_to_string(genes_file).map_err(|err| {
+        BioMcpError::Io(io::Error::new(
+            err.kind(),
+            format!("failed to read {}: {}", genes_file.display(), err),
+        ))
+    })?;
+    let genes = crate::entities::panel::parse_panel_genes(&raw)?;
+    if genes.is_empty() {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "No gene symbols found in {}",
+            genes_file.display()
+        )));
+    }
+
+    let requested = genes.len();
+    let results = crate::sources::with_cache_entity(Some("gene"), async {
+        futures::stream::iter(genes.into_iter().map(|gene| async move {
+            let result = crate::entities::gene::get(&gene, sections).await;
+            (gene, result)
+        }))
+        .buffered(WARM_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await
+    })
+    .await;
+
+    let failed = results
+        .into_iter()
+        .filter_map(|(gene, result)| {
+            result.err().map(|err| CacheWarmFailure {
+                gene,
+                error: err.to_string(),
+            })
+        })
+        .collect::<Vec<_>>();
+    Ok(CacheWarmReport {
+        requested,
+        warmed: requested - failed.len(),
+        failed,
+    })
+}
+
+pub(crate) fn render_warm_text(report: &CacheWarmReport) -> String {
+    let mut out = format!(
+        "Cache warm: requested={} warmed={} failed={}",
+        report.requested,
+        report.warmed,
+        report.failed.len()
+    );
+    for failure in &report.failed {
+        out.push_str(&format!("\n- {}: {}", failure.gene, failure.error));
+    }
+    out
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
 pub(crate) struct CacheStatsAgeRange {
     pub(crate) oldest_ms: u64,
@@ -160,6 +354,7 @@ pub(crate) struct CacheStatsAgeRange {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
 #[serde(rename_all = "lowercase")]
 pub(crate) enum CacheStatsOrigin {
+    Cli,
     Env,
     File,
     Default,
@@ -168,6 +363,7 @@ pub(crate) enum CacheStatsOrigin {
 impl CacheStatsOrigin {
     fn as_str(self) -> &'static str {
         match self {
+            Self::Cli => "cli",
             Self::Env => "env",
             Self::File => "file",
             Self::Default => "default",
@@ -178,6 +374,7 @@ impl CacheStatsOrigin {
 impl From<crate::cache::ConfigOrigin> for CacheStatsOrigin {
     fn from(value: crate::cache::ConfigOrigin) -> Self {
         match value {
+            crate::cache::ConfigOrigin::Cli => Self::Cli,
             crate::cache::ConfigOrigin::Env => Self::Env,
             crate::cache::ConfigOrigin::File => Self::File,
             crate::cache::ConfigOrigin::Default => Self::Default,
@@ -195,10 +392,26 @@ pub(crate) struct CacheStatsReport {
     pub(crate) age_range: Option<CacheStatsAgeRange>,
     pub(crate) max_size_bytes: u64,
     pub(crate) max_size_origin: CacheStatsOrigin,
+    /// Referenced blob bytes as a whole percentage of `max_size_bytes`.
+    pub(crate) max_size_used_percent: u64,
+    /// Set when referenced bytes exceed the limit; the next cache write
+    /// schedules LRU eviction back under it.
+    pub(crate) over_max_size: bool,
     pub(crate) min_disk_free: String,
     pub(crate) min_disk_free_origin: CacheStatsOrigin,
     pub(crate) max_age_secs: u64,
     pub(crate) max_age_origin: CacheStatsOrigin,
+    /// Index entries and their recorded bytes per upstream source, largest first.
+    pub(crate) sources: Vec<CacheStatsGroup>,
+    /// The same entries grouped by the entity command that fetched them.
+    pub(crate) entities: Vec<CacheStatsGroup>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub(crate) struct CacheStatsGroup {
+    pub(crate) name: String,
+    pub(crate) entries: usize,
+    pub(crate) bytes: u64,
 }
 
 impl CacheStatsReport {
@@ -207,7 +420,7 @@ impl CacheStatsReport {
             Some(range) => format!("{} .. {}", range.oldest_ms, range.newest_ms),
             None => "none".to_string(),
         };
-        [
+        let mut rows = vec![
             format!("| Path | {} |", self.path),
             format!("| Blob bytes | {} |", self.blob_bytes),
             format!("| Referenced blob bytes | {} |", self.referenced_blob_bytes),
@@ -219,6 +432,15 @@ impl CacheStatsReport {
                 self.max_size_bytes,
                 self.max_size_origin.as_str()
             ),
+            format!(
+                "| Max size used | {}%{} |",
+                self.max_size_used_percent,
+                if self.over_max_size {
+                    " (over limit; LRU eviction runs on the next cache write)"
+                } else {
+                    ""
+                }
+            ),
             format!(
                 "| Min disk free | {} ({}) |",
                 self.min_disk_free,
@@ -229,9 +451,17 @@ impl CacheStatsReport {
                 self.max_age_secs,
                 self.max_age_origin.as_str()
             ),
-            String::new(), // trailing newline
-        ]
-        .join("\n")
+        ];
+        for (label, groups) in [("Source", &self.sources), ("Entity", &self.entities)] {
+            rows.extend(groups.iter().map(|group| {
+                format!(
+                    "| {label} {} | {} entries, {} bytes |",
+                    group.name, group.entries, group.bytes
+                )
+            }));
+        }
+        rows.push(String::new()); // trailing newline
+        rows.join("\n")
     }
 }
 
@@ -243,8 +473,39 @@ fn checked_timestamp_ms(timestamp: u128) -> Result<u64, BioMcpError> {
     })
 }
 
+fn group_usage(
+    snapshot: &crate::cache::CacheSnapshot,
+    mut name: impl FnMut(&crate::cache::CacheEntry) -> String,
+) -> Vec<CacheStatsGroup> {
+    let mut groups = std::collections::BTreeMap::<String, CacheStatsGroup>::new();
+    for entry in &snapshot.entries {
+        let name = name(entry);
+        let group = groups
+            .entry(name.clone())
+            .or_insert_with(|| CacheStatsGroup {
+                name,
+                entries: 0,
+                bytes: 0,
+            });
+        group.entries += 1;
+        group.bytes = group.bytes.saturating_add(entry.size_bytes);
+    }
+    let mut groups = groups.into_values().collect::<Vec<_>>();
+    groups.sort_by(|left, right| right.bytes.cmp(&left.bytes));
+    groups
+}
+
+fn used_percent(used_bytes: u64, max_bytes: u64) -> u64 {
+    if max_bytes == 0 {
+        return 0;
+    }
+    let percent = u128::from(used_bytes) * 100 / u128::from(max_bytes);
+    u64::try_from(percent).unwrap_or(u64::MAX)
+}
+
 pub(crate) fn build_cache_stats_report(
     snapshot: &crate::cache::CacheSnapshot,
+    tags: &crate::cache::CacheTags,
     config: &crate::cache::ResolvedCacheConfig,
 ) -> Result<CacheStatsReport, BioMcpError> {
     let age_range = match (
@@ -273,10 +534,18 @@ pub(crate) fn build_cache_stats_report(
         age_range,
         max_size_bytes: config.max_size,
         max_size_origin: CacheStatsOrigin::from(config.origins.max_size),
+        max_size_used_percent: used_percent(usage.referenced_blob_bytes, config.max_size),
+        over_max_size: usage.referenced_blob_bytes > config.max_size,
         min_disk_free: config.min_disk_free.display(),
         min_disk_free_origin: CacheStatsOrigin::from(config.origins.min_disk_free),
         max_age_secs: config.max_age.as_secs(),
         max_age_origin: CacheStatsOrigin::from(config.origins.max_age),
+        sources: group_usage(snapshot, |entry| tags.tag_for(&entry.key).source),
+        entities: group_usage(snapshot, |entry| {
+            tags.tag_for(&entry.key)
+                .entity
+                .unwrap_or_else(|| crate::cache::UNTAGGED.to_string())
+        }),
     })
 }
 
@@ -301,7 +570,8 @@ where
     let http_path = config.cache_root.join("http");
     let snapshot =
         snapshotter(&http_path).map_err(|err| BioMcpError::Io(std::io::Error::other(err)))?;
-    build_cache_stats_report(&snapshot, &config)
+    let tags = crate::cache::load_cache_tags(&http_path)?;
+    build_cache_stats_report(&snapshot, &tags, &config)
 }
 
 #[cfg(test)]
@@ -315,12 +585,13 @@ mod tests {
     use tokio::sync::MutexGuard;
 
     use super::{
-        CacheStatsAgeRange, CacheStatsOrigin, CacheStatsReport, build_cache_stats_report,
-        collect_cache_stats_report_with, render_path,
+        CacheStatsAgeRange, CacheStatsOrigin, CacheStatsReport, CacheWarmFailure, CacheWarmReport,
+        build_cache_stats_report, collect_cache_stats_report_with, execute_warm, render_path,
+        render_warm_text,
     };
     use crate::cache::{
-        CacheBlob, CacheConfigOrigins, CacheEntry, CacheSnapshot, ConfigOrigin, DiskFreeThreshold,
-        ResolvedCacheConfig,
+        CacheBlob, CacheConfigOrigins, CacheEntry, CacheSnapshot, CacheTags, ConfigOrigin,
+        DiskFreeThreshold, ResolvedCacheConfig,
     };
     use crate::error::BioMcpError;
 
@@ -589,6 +860,7 @@ mod tests {
             max_size,
             min_disk_free: DiskFreeThreshold::Percent(10),
             max_age: Duration::from_secs(max_age_secs),
+            encrypt: false,
             origins,
         }
     }
@@ -608,7 +880,8 @@ mod tests {
             },
         );
 
-        let report = build_cache_stats_report(&snapshot, &config).expect("empty snapshot report");
+        let report = build_cache_stats_report(&snapshot, &CacheTags::default(), &config)
+            .expect("empty snapshot report");
 
         assert_eq!(
             report,
@@ -621,10 +894,14 @@ mod tests {
                 age_range: None,
                 max_size_bytes: 10_000_000_000,
                 max_size_origin: CacheStatsOrigin::Default,
+                max_size_used_percent: 0,
+                over_max_size: false,
                 min_disk_free: "10%".into(),
                 min_disk_free_origin: CacheStatsOrigin::Default,
                 max_age_secs: 86_400,
                 max_age_origin: CacheStatsOrigin::Default,
+                sources: Vec::new(),
+                entities: Vec::new(),
             }
         );
 
@@ -666,7 +943,8 @@ mod tests {
             },
         );
 
-        let report = build_cache_stats_report(&snapshot, &config).expect("report");
+        let report =
+            build_cache_stats_report(&snapshot, &CacheTags::default(), &config).expect("report");
         assert_eq!(
             report.blob_bytes,
             b"live-bytes".len() as u64 + b"orphan-bytes".len() as u64
@@ -674,6 +952,44 @@ mod tests {
         assert_eq!(report.referenced_blob_bytes, b"live-bytes".len() as u64);
         assert_eq!(report.blob_count, 2);
         assert_eq!(report.orphan_count, 1);
+        assert_eq!(report.max_size_used_percent, 0);
+        assert!(!report.over_max_size);
+    }
+
+    #[test]
+    fn build_cache_stats_report_flags_referenced_bytes_over_max_size() {
+        let snapshot = test_snapshot(
+            "/tmp/cache/http",
+            vec![test_entry("retained", b"live-bytes", 100)],
+            vec![
+                test_blob("retained", b"live-bytes", 1),
+                test_blob("orphan", b"orphan-bytes", 0),
+            ],
+        );
+        let config = test_config(
+            "/tmp/cache",
+            8,
+            3_600,
+            CacheConfigOrigins {
+                cache_root: ConfigOrigin::Default,
+                max_size: ConfigOrigin::Env,
+                min_disk_free: ConfigOrigin::Default,
+                max_age: ConfigOrigin::Default,
+            },
+        );
+
+        let report =
+            build_cache_stats_report(&snapshot, &CacheTags::default(), &config).expect("report");
+        assert_eq!(report.max_size_used_percent, 125);
+        assert!(report.over_max_size);
+        assert!(report.to_markdown().lines().any(|line| {
+            line == "| Max size used | 125% (over limit; LRU eviction runs on the next cache write) |"
+        }));
+
+        let json = crate::render::json::to_pretty(&report).expect("json");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+        assert_eq!(value["max_size_used_percent"], 125);
+        assert_eq!(value["over_max_size"], true);
     }
 
     #[test]
@@ -702,7 +1018,8 @@ mod tests {
             },
         );
 
-        let report = build_cache_stats_report(&snapshot, &config).expect("report");
+        let report =
+            build_cache_stats_report(&snapshot, &CacheTags::default(), &config).expect("report");
         assert_eq!(
             report.age_range,
             Some(CacheStatsAgeRange {
@@ -718,6 +1035,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_cache_stats_report_groups_entries_by_source_and_entity() {
+        let snapshot = test_snapshot(
+            "/tmp/cache/http",
+            vec![
+                test_entry("GET:https://mygene.info/v3/gene/673", b"braf-gene", 100),
+                test_entry("GET:https://mygene.info/v3/gene/7157", b"tp53", 200),
+                test_entry(
+                    "GET:https://rest.uniprot.org/uniprotkb/P15056",
+                    b"p15056",
+                    300,
+                ),
+                test_entry("enc:0123abcd", b"sealed", 400),
+            ],
+            Vec::new(),
+        );
+        let config = test_config(
+            "/tmp/cache",
+            2_048,
+            7_200,
+            CacheConfigOrigins {
+                cache_root: ConfigOrigin::Default,
+                max_size: ConfigOrigin::Default,
+                min_disk_free: ConfigOrigin::Default,
+                max_age: ConfigOrigin::Default,
+            },
+        );
+
+        let report =
+            build_cache_stats_report(&snapshot, &CacheTags::default(), &config).expect("report");
+        let sources = report
+            .sources
+            .iter()
+            .map(|group| (group.name.as_str(), group.entries, group.bytes))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sources,
+            vec![("mygene", 2, 13), ("uniprot", 1, 6), ("untagged", 1, 6)]
+        );
+        assert_eq!(report.entities.len(), 1);
+        assert_eq!(report.entities[0].name, "untagged");
+        assert_eq!(report.entities[0].entries, 4);
+        assert!(
+            report
+                .to_markdown()
+                .lines()
+                .any(|line| line == "| Source mygene | 2 entries, 13 bytes |")
+        );
+    }
+
     #[test]
     fn cache_stats_report_json_serializes_env_and_file_origins_lowercase() {
         let snapshot = test_snapshot("/tmp/cache/http", Vec::new(), Vec::new());
@@ -733,7 +1100,8 @@ mod tests {
             },
         );
 
-        let report = build_cache_stats_report(&snapshot, &config).expect("report");
+        let report =
+            build_cache_stats_report(&snapshot, &CacheTags::default(), &config).expect("report");
         let json = crate::render::json::to_pretty(&report).expect("json");
         let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
         assert_eq!(value["max_size_origin"], "env");
@@ -755,10 +1123,14 @@ mod tests {
             }),
             max_size_bytes: 5_000,
             max_size_origin: CacheStatsOrigin::Env,
+            max_size_used_percent: 0,
+            over_max_size: false,
             min_disk_free: "10%".into(),
             min_disk_free_origin: CacheStatsOrigin::Default,
             max_age_secs: 7_200,
             max_age_origin: CacheStatsOrigin::File,
+            sources: Vec::new(),
+            entities: Vec::new(),
         };
 
         assert_eq!(
@@ -771,12 +1143,47 @@ mod tests {
 | Orphan blobs | 1 |
 | Age range | 100 .. 500 |
 | Max size | 5000 bytes (env) |
+| Max size used | 0% |
 | Min disk free | 10% (default) |
 | Max age | 7200 s (file) |
 "
         );
     }
 
+    #[tokio::test]
+    async fn execute_warm_rejects_missing_and_empty_gene_files() {
+        let root = TempDirGuard::new("warm-empty");
+        let err = execute_warm(&root.path().join("missing.txt"), &[])
+            .await
+            .expect_err("missing file");
+        assert!(err.to_string().contains("missing.txt"));
+
+        let panel = root.path().join("panel.txt");
+        std::fs::write(&panel, "# HBOC panel, not filled in yet\n\n").expect("write panel");
+        let err = execute_warm(&panel, &[]).await.expect_err("empty panel");
+        assert!(err.to_string().contains("No gene symbols found"));
+
+        std::fs::write(&panel, "BRCA1, BRAF V600E?\n").expect("write panel");
+        let err = execute_warm(&panel, &[]).await.expect_err("invalid symbol");
+        assert!(err.to_string().contains("Invalid gene symbol"));
+    }
+
+    #[test]
+    fn render_warm_text_lists_failed_genes() {
+        let text = render_warm_text(&CacheWarmReport {
+            requested: 3,
+            warmed: 2,
+            failed: vec![CacheWarmFailure {
+                gene: "NOTAGENE1".into(),
+                error: "gene not found".into(),
+            }],
+        });
+        assert_eq!(
+            text,
+            "Cache warm: requested=3 warmed=2 failed=1\n- NOTAGENE1: gene not found"
+        );
+    }
+
     #[test]
     fn collect_cache_stats_report_calls_snapshot_once_for_resolved_http_path() {
         let config = test_config(
@@ -3,8 +3,10 @@
 use clap::Subcommand;
 
 use super::{
-    adverse_event, article, cache, chart, disease, drug, gene, gwas, pathway, pgx, phenotype,
-    protein, search_all_command, skill, study, system, trial, variant,
+    adverse_event, annotate, article, bookmark, cache, chart, compare, diff, disease, drug, exec,
+    gene, guideline, gwas, history, hla, jobs, matching, network, ontology, pathway, pgx,
+    phenotype, protein, report, schedule, search_all_command, selftest, skill, snapshot, structure,
+    study, system, transcript, trial, validate, variant,
 };
 
 #[derive(Subcommand, Debug)]
@@ -55,18 +57,136 @@ pub enum Commands {
         #[command(subcommand)]
         cmd: protein::ProteinCommand,
     },
+    /// Pharmacogenomics panel helpers
+    Pgx {
+        #[command(subcommand)]
+        cmd: pgx::PgxCommand,
+    },
+    /// Consolidated multi-gene clinical reports
+    Report {
+        #[command(subcommand)]
+        cmd: report::ReportCommand,
+    },
+    /// Side-by-side comparisons of two entities
+    Compare {
+        #[command(subcommand)]
+        cmd: compare::CompareCommand,
+    },
+    /// Interaction network export (STRING)
+    Network {
+        #[command(subcommand)]
+        cmd: network::NetworkCommand,
+    },
+    /// Ontology hierarchy traversal (MONDO via OLS)
+    Ontology {
+        #[command(subcommand)]
+        cmd: ontology::OntologyCommand,
+    },
+    /// Compare current reports with stored snapshots (CLI-only; snapshots live in the local cache directory)
+    Diff {
+        #[command(subcommand)]
+        cmd: diff::DiffCommand,
+    },
+    /// Annotate local analysis result files (CLI-only; reads workstation-local files)
+    Annotate {
+        #[command(subcommand)]
+        cmd: annotate::AnnotateCommand,
+    },
+    /// Rank trials against a patient profile (CLI-only; reads a workstation-local profile file)
+    Match {
+        #[command(subcommand)]
+        cmd: matching::MatchCommand,
+    },
     /// Local cBioPortal study analytics
     Study {
         #[command(subcommand)]
         cmd: study::StudyCommand,
     },
+    /// Validate and normalize identifiers before querying
+    Validate {
+        #[command(subcommand)]
+        cmd: validate::ValidateCommand,
+    },
     /// Check external API connectivity
     Health(system::HealthArgs),
+    /// Run canonical queries against live APIs and report response-shape drift
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp selftest
+  biomcp selftest --sources mygene,myvariant,ctgov
+  biomcp --json selftest --sources uniprot
+
+Each source gets one or two canonical requests whose JSON responses are checked
+for the fields BioMCP parses. `drift` rows name the missing or retyped fields;
+`error` rows mean the request failed. Exits 1 unless every case is ok.
+Requests bypass the HTTP cache and honor BIOMCP_*_BASE overrides.
+See also: biomcp health --apis-only")]
+    Selftest(selftest::SelftestArgs),
     /// Inspect the managed HTTP cache (CLI-only; cache commands reveal workstation-local filesystem paths)
     Cache {
         #[command(subcommand)]
         cmd: cache::CacheCommand,
     },
+    /// Save and replay named queries (CLI-only; bookmarks live in the local config directory)
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp bookmark save melanoma-trials -- search trial -c melanoma --phase 3
+  biomcp bookmark run melanoma-trials --limit 5
+  biomcp bookmark list")]
+    Bookmark {
+        #[command(subcommand)]
+        cmd: bookmark::BookmarkCommand,
+    },
+    /// List, replay, and export past queries (CLI-only; history lives in the local cache directory)
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp history list
+  biomcp history replay 42
+  biomcp history export > history.jsonl")]
+    History {
+        #[command(subcommand)]
+        cmd: history::HistoryCommand,
+    },
+    /// Run BioMCP commands on a cron schedule (CLI-only; jobs live in the local config directory)
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp schedule add --cron \"0 6 * * MON\" --job \"diff gene BRAF\"
+  biomcp schedule run --daemon
+  biomcp schedule list")]
+    Schedule {
+        #[command(subcommand)]
+        cmd: schedule::ScheduleCommand,
+    },
+    /// Run long BioMCP commands in the background and poll for their results (jobs live in the local cache directory)
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp jobs submit -- snapshot create --entities genes.txt variants.txt
+  biomcp jobs status 3
+  biomcp jobs result 3")]
+    Jobs {
+        #[command(subcommand)]
+        cmd: jobs::JobsCommand,
+    },
+    /// Run newline-delimited JSON commands from stdin in one process, one JSON result per line (CLI-only)
+    #[command(after_help = "\
+EXAMPLES:
+  echo '{\"cmd\":\"get\",\"entity\":\"gene\",\"id\":\"BRAF\",\"sections\":[\"civic\"]}' | biomcp exec --jsonl
+  biomcp exec --jsonl < queries.jsonl > results.jsonl
+
+Each line is an object with `cmd` and optional `entity`, `id`, `sections`,
+`args` (extra flags), `json` (default true), and `tag` (echoed back). Each
+result line carries `line`, `ok`, `exit_code`, and `output` or `error`.")]
+    Exec(exec::ExecArgs),
+    /// Store gene and variant cards for `--offline` use (CLI-only; bundles live in the local cache directory)
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp snapshot create --entities genes.txt variants.txt
+  biomcp --offline get variant \"BRAF V600E\"
+  biomcp --offline search gene -q BRAF")]
+    Snapshot {
+        #[command(subcommand)]
+        cmd: snapshot::SnapshotCommand,
+    },
     /// EMA (European Medicines Agency) local data management
     #[command(after_help = "\
 EXAMPLES:
@@ -123,10 +243,87 @@ EXAMPLES:
     Uninstall,
     /// Command reference for entities and flags
     List(system::ListArgs),
+    /// JSON manifest of get sections with sources, latency class, and API keys
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp sections gene
+  biomcp sections adverse-event
+  biomcp sections
+
+Each section lists its upstream sources, a latency class (fast: read from the
+base record; moderate: one extra request; slow: several requests or a slow
+API), and auth (none, or an optional or required environment variable).
+See also: biomcp list <entity>")]
+    Sections(system::SectionsArgs),
+    /// JSON Schema for an entity's `get --json` output
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp schema gene
+  biomcp schema adverse-event
+
+The schema is derived from the same types that `get <entity> <id> --json`
+serializes, including the trailing `_meta` object. `_meta.schema_version`
+changes only when a field is renamed, removed, or changes type.
+See also: biomcp sections <entity>")]
+    Schema(system::SchemaArgs),
     /// Parallel get operations (comma-separated IDs, max 10)
     Batch(system::BatchArgs),
     /// Gene set enrichment against g:Profiler
     Enrich(system::EnrichArgs),
+    /// Score the evidence linking a gene to a disease across OpenTargets, CIViC, trials, and articles
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp dossier --gene BRAF --disease melanoma
+  biomcp dossier --gene EGFR --disease \"non-small cell lung cancer\"
+  biomcp --json dossier --gene KRAS --disease \"pancreatic cancer\"
+
+Scores the pair out of 100: OpenTargets association (40), CIViC evidence
+levels (25), drugs targeting the gene by furthest stage in the disease (20),
+recruiting or active trials with the gene as a biomarker (10), and article
+volume (5). The score is a triage heuristic, not a clinical validity
+classification. A source that fails is listed under Notes and scores zero.
+See also: biomcp search all --gene <symbol> --disease <name>")]
+    Dossier(system::DossierArgs),
+    /// GET a whitelisted upstream endpoint and print its JSON (fields BioMCP does not map)
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp raw mygene query --param q=symbol:BRAF --param fields=symbol,name,genomic_pos
+  biomcp raw ensembl lookup/id/ENSG00000157764 --param expand=1
+  biomcp raw clinicaltrials studies --param query.term=BRAF --param pageSize=5
+
+Sources and allowed path prefixes:
+  mygene          query, gene, metadata
+  myvariant       query, variant, metadata
+  mychem          query, chem, metadata
+  uniprot         uniprotkb
+  ensembl         lookup, xrefs, homology, overlap
+  clinicaltrials  studies
+  europepmc       search
+  pubtator        search, entity/autocomplete, publications/export/biocjson
+  reactome        data, search
+
+Only GET requests are sent, through the same retrying, cached client as the
+mapped commands. Query strings go in --param, never in the path, and
+credential-like parameters are refused. Responses must be JSON and at most
+1 MiB; page large queries with the source's size/limit parameter. The output
+is the upstream JSON as-is, so its shape follows the upstream API, not BioMCP.
+See also: biomcp schema <entity>")]
+    Raw(system::RawArgs),
+    /// Rank compounds whose LINCS/CMap signatures reverse an up/down gene signature (CLI-only)
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp repurpose --up up.txt --down down.txt
+  biomcp repurpose --up up.txt --down down.txt --limit 50 --wait 1800
+  biomcp --json repurpose --up up.txt --down down.txt
+
+Submits the signature to the CLUE (clue.io) query API against the LINCS L1000
+Touchstone reference and waits for the job. Requires CLUE_API_KEY. Gene files
+hold symbols, Ensembl, or Entrez IDs ranked by effect size; each direction
+needs at least 10 resolvable genes and the first 150 are sent. Candidates are
+compounds with the most negative connectivity scores; named compounds map to
+`biomcp get drug <name>`.
+See also: biomcp annotate deg, biomcp list drug")]
+    Repurpose(system::RepurposeArgs),
     /// Resolve free-text biomedical text into typed concepts and suggested commands
     #[command(after_help = "\
 When to use: use discover when you only have free text and need BioMCP to pick the next typed command.
@@ -139,6 +336,18 @@ EXAMPLES:
 
 See also: biomcp list discover")]
     Discover(system::DiscoverArgs),
+    /// Retrieve a cited evidence bundle for a free-text question (no generated answer)
+    #[command(after_help = "\
+When to use: use ask when an agent needs grounding evidence for a question that spans variants, drugs, trials, and articles.
+ask retrieves and cites; it never writes an answer.
+
+EXAMPLES:
+  biomcp ask \"what first-line therapies exist for BRAF V600E melanoma?\"
+  biomcp ask \"recruiting trials for KRAS G12C lung cancer\" --limit 5
+  biomcp --json ask \"is rs113488022 pathogenic?\"
+
+See also: biomcp discover, biomcp search all")]
+    Ask(system::AskArgs),
     /// Show version
     Version(system::VersionArgs),
 }
@@ -161,6 +370,7 @@ See also: biomcp list search-all")]
 EXAMPLES:
   biomcp search gene BRAF
   biomcp search gene -q kinase --type protein-coding --region chr7:140424943-140624564 --limit 5
+  biomcp search gene Trp53 --species mouse
 
 See also: biomcp list gene")]
     Gene(gene::GeneSearchArgs),
@@ -169,6 +379,9 @@ See also: biomcp list gene")]
 EXAMPLES:
   biomcp search disease \"lung cancer\"
   biomcp search disease -q melanoma --inheritance \"autosomal dominant\" --phenotype HP:0001250 --onset adult --limit 5
+  biomcp search disease NSCLC
+
+Note: Abbreviations such as NSCLC are expanded through the NCI Thesaurus; pass --no-normalize to search as typed.
 
 See also: biomcp list disease")]
     Disease(disease::DiseaseSearchArgs),
@@ -197,6 +410,20 @@ EXAMPLES:
 
 See also: biomcp list gwas")]
     Gwas(gwas::GwasSearchArgs),
+    /// Search clinical practice guidelines by condition and issuing organization (USPSTF + Europe PMC)
+    #[command(
+        alias = "guideline",
+        after_help = "\
+EXAMPLES:
+  biomcp search guidelines --condition \"atrial fibrillation\" --org AHA,NICE
+  biomcp search guidelines \"colorectal cancer\" --org USPSTF --limit 5
+
+USPSTF rows carry the task force grade (A/B/C/D/I) and need USPSTF_API_KEY.
+Society guidelines are found through Europe PMC practice-guideline records and report publication year only.
+
+See also: biomcp list guidelines"
+    )]
+    Guidelines(guideline::GuidelineSearchArgs),
     /// Search articles by gene, disease, drug, keyword, or author (PubTator3 + Europe PMC + PubMed + keyword-gated LitSense2, optional Semantic Scholar)
     #[command(after_help = "\
 When to use: use keyword search to scan a topic before you know the entities. Add -g/--gene when you already know the molecular anchor. Prefer --type review for synthesis questions.
@@ -247,6 +474,7 @@ EXAMPLES:
   biomcp search trial -p 3 -i pembrolizumab
   biomcp search trial -c melanoma --facility \"MD Anderson\" --age 67 --limit 5
   biomcp search trial --age 0.5 --count-only          # infants eligible (6 months)
+  biomcp search trial --sponsor Genentech -p 3 --summarize-by condition
   biomcp search trial --mutation \"BRAF V600E\" --status recruiting --study-type interventional --has-results --limit 5
   biomcp search trial -c \"endometrial cancer\" --criteria \"mismatch repair deficient\" -s recruiting
   biomcp search trial -c melanoma --source nci --status recruiting --limit 5
@@ -272,6 +500,8 @@ EXAMPLES:
   biomcp search variant -g BRAF --significance pathogenic
   biomcp search variant -g BRCA1 --review-status 2 --revel-min 0.7 --consequence missense_variant --limit 5
   biomcp search variant --hgvsp p.Val600Glu -g BRAF --limit 5
+  biomcp search variant -g BRCA1 --significance pathogenic --group-by consequence --limit 20
+  biomcp search variant -g BRCA1 --rank-profile research --limit 10
 
 For variant mentions in trials: biomcp variant trials \"BRAF V600E\"
 See also: biomcp list variant")]
@@ -287,8 +517,10 @@ EXAMPLES:
   biomcp search drug Keytruda --region eu --limit 5
   biomcp search drug --indication malaria --region who --limit 5
   biomcp search drug -q \"kinase inhibitor\" --target EGFR --atc L01 --pharm-class kinase --limit 5
+  biomcp search drug 5-FU --limit 5
 
 Note: --interactions is currently unavailable from the public data sources BioMCP uses.
+Abbreviations such as 5-FU are searched under their NCI Thesaurus preferred name; pass --no-normalize to search as typed.
 Omitting --region on a plain name/alias search checks U.S., EU, and WHO data.
 If you omit --region while using structured filters such as --target or --indication, BioMCP stays on the U.S. MyChem path.
 Explicit --region who filters structured U.S. hits through WHO Prequalification.
@@ -309,14 +541,34 @@ EXAMPLES:
 See also: biomcp list pathway"
     )]
     Pathway(pathway::PathwaySearchArgs),
-    /// Search proteins by name or accession (UniProt)
+    /// Search proteins by name, accession, or domain (UniProt; InterPro for --domain)
     #[command(after_help = "\
 EXAMPLES:
   biomcp search protein kinase
   biomcp search protein -q \"BRAF\" --reviewed --disease melanoma --existence 1 --limit 5
+  biomcp search protein --organism 9606 --reviewed --all --columns accession,gene_primary,length
+  biomcp search protein --domain PF07714 --organism human --limit 20
 
 See also: biomcp list protein")]
     Protein(protein::ProteinSearchArgs),
+    /// List PDB entries for a UniProt accession, best resolution first (RCSB PDB)
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp search structure --uniprot P15056
+  biomcp search structure --uniprot P0DTC2 --limit 20 --offset 20
+
+See also: biomcp list structure")]
+    Structure(structure::StructureSearchArgs),
+    /// Search public cBioPortal cancer studies by keyword or cancer type
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp search study \"lung adenocarcinoma\"
+  biomcp search study tcga --cancer-type brca --limit 5
+
+Results come from the public cBioPortal API, largest cohorts first. Use `biomcp study download <id>` for local cohort analytics.
+
+See also: biomcp list study")]
+    Study(study::StudySearchArgs),
     /// Search adverse event reports (OpenFDA FAERS)
     #[command(after_help = "\
 EXAMPLES:
@@ -326,6 +578,15 @@ EXAMPLES:
 
 See also: biomcp list adverse-event")]
     AdverseEvent(adverse_event::AdverseEventSearchArgs),
+    /// Search FDA 510(k) device clearances (OpenFDA)
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp search device-clearance --product-code QBJ
+  biomcp search device-clearance --device \"insulin pump\" --date-from 2020
+  biomcp search device-clearance --applicant dexcom --limit 5
+
+See also: biomcp list device-clearance")]
+    DeviceClearance(adverse_event::DeviceClearanceSearchArgs),
 }
 
 #[derive(Subcommand, Debug)]
@@ -339,7 +600,9 @@ EXAMPLES:
   biomcp get gene BRAF pathways
   biomcp get gene BRAF hpa
   biomcp get gene ERBB2 funding
+  biomcp get gene Braf protein --species mouse
 
+Non-human genes (--species mouse|rat|zebrafish|<taxid>) support the protein and go sections only.
 See also: biomcp list gene")]
     Gene(gene::GeneGetArgs),
     /// Get article by PMID, PMCID, or DOI
@@ -371,6 +634,20 @@ EXAMPLES:
 
 See also: biomcp list pgx")]
     Pgx(pgx::PgxGetArgs),
+    /// Get HLA allele card by WHO allele name (e.g., HLA-B*57:01)
+    #[command(after_help = "\
+When to use: use this when a pharmacogenomic or immunogenetic question hinges on one HLA allele, such as abacavir hypersensitivity with HLA-B*57:01.
+
+EXAMPLES:
+  biomcp get hla HLA-B*57:01
+  biomcp get hla HLA-B*57:01 drugs
+  biomcp get hla B*58:01 frequencies
+  biomcp get hla DRB1*15:01 diseases
+
+Quote allele names in shells that expand `*`.
+
+See also: biomcp list hla")]
+    Hla(hla::HlaGetArgs),
     /// Get trial by NCT ID (e.g., NCT02576665)
     #[command(after_help = "\
 EXAMPLES:
@@ -386,6 +663,9 @@ EXAMPLES:
   biomcp get variant rs113488022
   biomcp get variant \"BRAF V600E\" clinvar
   biomcp get variant \"BRAF p.Val600Glu\"
+  biomcp get variant rs80359550 --source vep
+  biomcp get variant chr7:g.140753336A>T --assembly grch38 predict
+  biomcp get variant rs113488022 population --gnomad-version v3
 
 Shorthand like \"PTPN22 620W\" or \"R620W\" should go through `biomcp search variant`.
 
@@ -401,6 +681,7 @@ EXAMPLES:
   biomcp get drug Ozempic safety --region eu
   biomcp get drug pembrolizumab targets
   biomcp get drug pembrolizumab approvals
+  biomcp get drug imatinib patents
 
 See also: biomcp list drug")]
     Drug(drug::DrugGetArgs),
@@ -423,6 +704,34 @@ EXAMPLES:
 
 See also: biomcp list protein")]
     Protein(protein::ProteinGetArgs),
+    /// Get experimental structure by PDB ID (e.g., 6VSB)
+    #[command(after_help = "\
+When to use: use this after `get protein <accession> structures` or `search structure --uniprot` to check method, resolution, chain composition, and bound ligands before choosing a model.
+
+EXAMPLES:
+  biomcp get structure 6VSB
+  biomcp get structure 4MNE
+
+See also: biomcp list structure")]
+    Structure(structure::StructureGetArgs),
+    /// Get Ensembl transcript by ID (e.g., ENST00000288602)
+    #[command(after_help = "\
+When to use: use this after `get variant <id> --source vep` or when a paper names a transcript, to check exon structure, canonical and MANE status, and the protein product before comparing isoforms.
+
+EXAMPLES:
+  biomcp get transcript ENST00000288602
+  biomcp get transcript ENST00000269305.9
+
+See also: biomcp list transcript")]
+    Transcript(transcript::TranscriptGetArgs),
+    /// Get cBioPortal cancer study by ID (e.g., luad_tcga_pan_can_atlas_2018)
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp get study luad_tcga_pan_can_atlas_2018
+  biomcp get study msk_impact_2017 genes
+
+See also: biomcp list study")]
+    Study(study::StudyGetArgs),
     /// Get adverse event report by FAERS safetyreportid or MAUDE mdr_report_key
     #[command(after_help = "\
 EXAMPLES:
@@ -431,4 +740,12 @@ EXAMPLES:
 
 See also: biomcp list adverse-event")]
     AdverseEvent(adverse_event::AdverseEventGetArgs),
+    /// Get an FDA 510(k) clearance with its product-code classification
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp get device-clearance K213456
+  biomcp get device-clearance K213456 --json
+
+See also: biomcp list device-clearance")]
+    DeviceClearance(adverse_event::DeviceClearanceGetArgs),
 }
@@ -0,0 +1,19 @@
+use super::CompareCommand;
+use crate::cli::CommandOutcome;
+
+pub(in crate::cli) async fn handle_command(
+    cmd: CompareCommand,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let text = match cmd {
+        CompareCommand::Variants { first, second } => {
+            let comparison = crate::entities::variant::compare_variants(&first, &second).await?;
+            if json {
+                crate::render::json::to_pretty(&comparison)?
+            } else {
+                crate::render::markdown::variant_compare_markdown(&comparison)?
+            }
+        }
+    };
+    Ok(CommandOutcome::stdout(text))
+}
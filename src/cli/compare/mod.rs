@@ -0,0 +1,32 @@
+//! Compare CLI payloads and subcommands.
+
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+pub enum CompareCommand {
+    /// Compare two variants side by side and highlight the fields that differ
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp compare variants \"BRAF V600E\" \"BRAF V600K\"
+  biomcp compare variants rs80357906 rs80357713
+  biomcp --json compare variants \"KRAS G12C\" \"KRAS G12D\"
+
+Both variants are fetched concurrently with their ClinVar, population,
+predictions, and CIViC sections. The table lists ClinVar significance and
+review status, gnomAD AF, CADD, REVEL, the OncoKB level (requires
+ONCOKB_TOKEN), and CIViC evidence and assertion counts; values that differ
+are shown in bold.
+See also: biomcp get variant <id> all")]
+    Variants {
+        /// First variant identifier (rsID, HGVS, or "GENE CHANGE")
+        first: String,
+        /// Second variant identifier
+        second: String,
+    },
+}
+
+mod dispatch;
+pub(super) use self::dispatch::handle_command;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,27 @@
+use clap::Parser;
+
+use super::CompareCommand;
+use crate::cli::{Cli, Commands};
+
+#[test]
+fn compare_variants_parses_two_ids() {
+    let cli = Cli::try_parse_from(["biomcp", "compare", "variants", "BRAF V600E", "rs121913227"])
+        .expect("compare variants should parse");
+
+    match cli.command {
+        Commands::Compare {
+            cmd: CompareCommand::Variants { first, second },
+        } => {
+            assert_eq!(first, "BRAF V600E");
+            assert_eq!(second, "rs121913227");
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[test]
+fn compare_variants_requires_a_second_id() {
+    let err = Cli::try_parse_from(["biomcp", "compare", "variants", "BRAF V600E"])
+        .expect_err("second id is required");
+    assert!(err.to_string().contains("<SECOND>"));
+}
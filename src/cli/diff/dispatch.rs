@@ -0,0 +1,23 @@
+use super::DiffCommand;
+use crate::cli::CommandOutcome;
+
+pub(in crate::cli) async fn handle_command(
+    cmd: DiffCommand,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let text = match cmd {
+        DiffCommand::Gene {
+            symbol,
+            since,
+            no_save,
+        } => {
+            let diff = crate::entities::gene::diff(&symbol, since.as_deref(), !no_save).await?;
+            if json {
+                crate::render::json::to_pretty(&diff)?
+            } else {
+                crate::render::markdown::gene_diff_markdown(&diff)?
+            }
+        }
+    };
+    Ok(CommandOutcome::stdout(text))
+}
@@ -0,0 +1,38 @@
+//! Diff CLI payloads and subcommands.
+
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+pub enum DiffCommand {
+    /// Compare a gene report with a stored snapshot and list changed fields
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp diff gene BRAF
+  biomcp diff gene BRAF --since 2026-03-01
+  biomcp diff gene BRAF --since 20260310T120000Z --no-save
+  biomcp --json diff gene BRCA2
+
+Each run fetches the gene with its druggability, ClinVar summary, ClinGen,
+CIViC, and pathway sections, compares it with a stored snapshot, and stores
+the fresh report as a new snapshot. The first run records a baseline.
+`--since` takes a snapshot id or a date (latest snapshot on or before it);
+without it the most recent snapshot is used. Snapshots live under
+<cache_root>/snapshots/gene/.
+See also: biomcp get gene <symbol> all")]
+    Gene {
+        /// Gene symbol (e.g., BRAF)
+        symbol: String,
+        /// Snapshot id or date (YYYY, YYYY-MM, or YYYY-MM-DD) to compare against
+        #[arg(long)]
+        since: Option<String>,
+        /// Compare without storing the current report as a snapshot
+        #[arg(long)]
+        no_save: bool,
+    },
+}
+
+mod dispatch;
+pub(super) use self::dispatch::handle_command;
+
+#[cfg(test)]
+mod tests;
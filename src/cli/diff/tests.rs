@@ -0,0 +1,49 @@
+use clap::Parser;
+
+use super::DiffCommand;
+use crate::cli::{Cli, Commands};
+
+#[test]
+fn diff_gene_parses_since_and_no_save() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "diff",
+        "gene",
+        "BRAF",
+        "--since",
+        "2026-03-01",
+        "--no-save",
+    ])
+    .expect("diff gene should parse");
+
+    match cli.command {
+        Commands::Diff {
+            cmd:
+                DiffCommand::Gene {
+                    symbol,
+                    since,
+                    no_save,
+                },
+        } => {
+            assert_eq!(symbol, "BRAF");
+            assert_eq!(since.as_deref(), Some("2026-03-01"));
+            assert!(no_save);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[test]
+fn diff_gene_defaults_to_latest_snapshot_and_saving() {
+    let cli = Cli::try_parse_from(["biomcp", "diff", "gene", "BRCA2"]).expect("diff gene");
+
+    match cli.command {
+        Commands::Diff {
+            cmd: DiffCommand::Gene { since, no_save, .. },
+        } => {
+            assert_eq!(since, None);
+            assert!(!no_save);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
@@ -7,13 +7,19 @@ pub(in crate::cli) async fn handle_get(
 ) -> anyhow::Result<CommandOutcome> {
     let (sections, json_override) = super::super::extract_json_from_sections(&args.sections);
     let json_output = json || json_override;
-    let disease = crate::entities::disease::get(&args.name_or_id, &sections).await?;
+    let fetch = crate::entities::normalize::with_normalization(
+        !args.no_normalize,
+        crate::entities::disease::get(&args.name_or_id, &sections),
+    );
+    let (disease, section_errors) = crate::entities::section_errors::collect(fetch).await;
+    let disease = disease?;
     let text = if json_output {
         crate::render::json::to_entity_json(
             &disease,
             crate::render::markdown::disease_evidence_urls(&disease),
             crate::render::markdown::related_disease(&disease),
             crate::render::provenance::disease_section_sources(&disease),
+            section_errors,
         )?
     } else {
         crate::render::markdown::disease_markdown(&disease, &sections)?
@@ -37,7 +43,11 @@ pub(in crate::cli) async fn handle_search(
     if args.offset > 0 {
         query_summary = format!("{query_summary}, offset={}", args.offset);
     }
-    let mut page = crate::entities::disease::search_page(&filters, args.limit, args.offset).await?;
+    let mut page = crate::entities::normalize::with_normalization(
+        !args.no_normalize,
+        crate::entities::disease::search_page(&filters, args.limit, args.offset),
+    )
+    .await?;
     let mut fallback_used = false;
     if page.results.is_empty()
         && !args.no_fallback
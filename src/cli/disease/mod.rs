@@ -25,6 +25,9 @@ pub struct DiseaseSearchArgs {
     /// Disable automatic discover fallback when zero direct disease rows are found
     #[arg(long)]
     pub no_fallback: bool,
+    /// Search the query as typed, without NCI Thesaurus abbreviation expansion (e.g., NSCLC)
+    #[arg(long)]
+    pub no_normalize: bool,
     /// Maximum results (default: 10)
     #[arg(short, long, default_value = "10")]
     pub limit: usize,
@@ -37,9 +40,12 @@ pub struct DiseaseSearchArgs {
 pub struct DiseaseGetArgs {
     /// Disease name (e.g., melanoma) or ID (e.g., MONDO:0005105)
     pub name_or_id: String,
-    /// Sections to include (genes, pathways, phenotypes, variants, models, prevalence, survival, civic, disgenet, funding, all)
+    /// Sections to include (genes, pathways, phenotypes, variants, models, prevalence, survival, civic, disgenet, funding, epidemiology, therapies, all)
     #[arg(trailing_var_arg = true)]
     pub sections: Vec<String>,
+    /// Resolve the name as typed, without NCI Thesaurus abbreviation expansion (e.g., NSCLC)
+    #[arg(long)]
+    pub no_normalize: bool,
 }
 
 #[derive(Subcommand, Debug)]
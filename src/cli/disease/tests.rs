@@ -2,7 +2,7 @@ use clap::{CommandFactory, Parser};
 
 use super::DiseaseCommand;
 use super::dispatch::disease_search_json;
-use crate::cli::{Cli, Commands, PaginationMeta};
+use crate::cli::{Cli, Commands, GetEntity, PaginationMeta, SearchEntity};
 
 fn render_disease_get_long_help() -> String {
     let mut command = Cli::command();
@@ -55,6 +55,32 @@ fn disease_trials_parses_source_and_limit() {
     }
 }
 
+#[test]
+fn disease_search_and_get_parse_no_normalize() {
+    let cli = Cli::try_parse_from(["biomcp", "search", "disease", "NSCLC", "--no-normalize"])
+        .expect("search disease should parse");
+    let Commands::Search {
+        entity: SearchEntity::Disease(args),
+    } = cli.command
+    else {
+        panic!("expected search disease command");
+    };
+    assert_eq!(args.positional_query.as_deref(), Some("NSCLC"));
+    assert!(args.no_normalize);
+
+    let cli = Cli::try_parse_from(["biomcp", "get", "disease", "NSCLC", "--no-normalize"])
+        .expect("get disease should parse");
+    let Commands::Get {
+        entity: GetEntity::Disease(args),
+    } = cli.command
+    else {
+        panic!("expected get disease command");
+    };
+    assert_eq!(args.name_or_id, "NSCLC");
+    assert!(args.sections.is_empty());
+    assert!(args.no_normalize);
+}
+
 #[tokio::test]
 async fn handle_command_rejects_zero_limit_before_related_lookup() {
     let cli = Cli::try_parse_from(["biomcp", "disease", "articles", "melanoma", "--limit", "0"])
@@ -1,5 +1,6 @@
 use super::{DrugCommand, DrugGetArgs, DrugSearchArgs};
 use crate::cli::CommandOutcome;
+use crate::cli::SpeciesArg;
 use crate::entities::drug::DrugRegion;
 
 pub(crate) async fn handle_get(
@@ -7,16 +8,26 @@ pub(crate) async fn handle_get(
     json: bool,
     alias_suggestions_as_json: bool,
 ) -> anyhow::Result<CommandOutcome> {
+    if args.species == SpeciesArg::Veterinary {
+        return Err(crate::error::BioMcpError::InvalidArgument(format!(
+            "openFDA publishes human drug labels only, so `get drug --species veterinary` has no label source. Use `biomcp search adverse-event --species veterinary -d {}` for animal adverse-event reports.",
+            crate::render::markdown::quote_arg(&args.name)
+        ))
+        .into());
+    }
     let (sections, json_override) = super::super::extract_json_from_sections(&args.sections);
     let region = args.region.map(DrugRegion::from);
     let json_output = json || json_override;
-    render_drug_card_outcome(
-        &args.name,
-        &sections,
-        region,
-        args.raw,
-        json_output,
-        alias_suggestions_as_json,
+    crate::entities::normalize::with_normalization(
+        !args.no_normalize,
+        render_drug_card_outcome(
+            &args.name,
+            &sections,
+            region,
+            args.raw,
+            json_output,
+            alias_suggestions_as_json,
+        ),
     )
     .await
 }
@@ -41,11 +52,9 @@ pub(crate) async fn handle_search(
     if args.offset > 0 {
         query_summary = format!("{query_summary}, offset={}", args.offset);
     }
-    let text = match crate::entities::drug::search_page_with_region(
-        &filters,
-        args.limit,
-        args.offset,
-        region,
+    let text = match crate::entities::normalize::with_normalization(
+        !args.no_normalize,
+        crate::entities::drug::search_page_with_region(&filters, args.limit, args.offset, region),
     )
     .await?
     {
@@ -318,15 +327,16 @@ pub(super) async fn render_drug_card_outcome(
     alias_suggestions_as_json: bool,
 ) -> anyhow::Result<CommandOutcome> {
     let effective_region = region.unwrap_or(DrugRegion::Us);
-    match crate::entities::drug::get_with_region(
-        name,
-        sections,
-        effective_region,
-        region.is_some(),
-        raw_label,
-    )
-    .await
-    {
+    let (drug, section_errors) =
+        crate::entities::section_errors::collect(crate::entities::drug::get_with_region(
+            name,
+            sections,
+            effective_region,
+            region.is_some(),
+            raw_label,
+        ))
+        .await;
+    match drug {
         Ok(drug) => {
             let text = if json_output {
                 crate::render::json::to_entity_json(
@@ -334,6 +344,7 @@ pub(super) async fn render_drug_card_outcome(
                     crate::render::markdown::drug_evidence_urls(&drug),
                     crate::render::markdown::related_drug(&drug),
                     crate::render::provenance::drug_section_sources(&drug),
+                    section_errors,
                 )?
             } else {
                 crate::render::markdown::drug_markdown_with_region(
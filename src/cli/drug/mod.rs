@@ -2,7 +2,7 @@
 
 use clap::{Args, Subcommand};
 
-use crate::cli::DrugRegionArg;
+use crate::cli::{DrugRegionArg, SpeciesArg};
 
 #[derive(Args, Debug)]
 pub struct DrugSearchArgs {
@@ -42,13 +42,16 @@ pub struct DrugSearchArgs {
     /// Data region for drug regulatory context [default: all]
     #[arg(long, value_enum)]
     pub region: Option<DrugRegionArg>,
+    /// Search the query as typed, without NCI Thesaurus name normalization (e.g., 5-FU)
+    #[arg(long)]
+    pub no_normalize: bool,
 }
 
 #[derive(Args, Debug)]
 pub struct DrugGetArgs {
     /// Drug name (e.g., pembrolizumab, carboplatin)
     pub name: String,
-    /// Sections to include (label, regulatory, safety, shortage, targets, indications, interactions, civic, approvals, all)
+    /// Sections to include (label, regulatory, safety, shortage, targets, indications, interactions, civic, approvals, patents, all)
     pub sections: Vec<String>,
     /// Data region for regional sections (regulatory, safety, shortage, or all)
     #[arg(long, value_enum)]
@@ -56,6 +59,12 @@ pub struct DrugGetArgs {
     /// Preserve raw FDA label subsections when used with `label` or `all`
     #[arg(long)]
     pub raw: bool,
+    /// Patient population; openFDA publishes human labels only, so `veterinary` is rejected with a pointer to veterinary adverse events
+    #[arg(long, value_enum, default_value_t = SpeciesArg::Human)]
+    pub species: SpeciesArg,
+    /// Look up the name as typed, without NCI Thesaurus name normalization (e.g., 5-FU)
+    #[arg(long)]
+    pub no_normalize: bool,
 }
 
 #[derive(Subcommand, Debug)]
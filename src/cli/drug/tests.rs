@@ -1,7 +1,7 @@
 use clap::{CommandFactory, Parser};
 
 use super::dispatch::{drug_all_region_search_json, resolve_drug_search_region};
-use crate::cli::{Cli, Commands, DrugCommand, DrugRegionArg, GetEntity, SearchEntity};
+use crate::cli::{Cli, Commands, DrugCommand, DrugRegionArg, GetEntity, SearchEntity, SpeciesArg};
 use crate::entities::drug::{DrugRegion, DrugSearchFilters};
 
 #[test]
@@ -94,11 +94,14 @@ fn get_drug_parses_region_split_form() {
                         name,
                         sections,
                         region,
+                        species,
                         raw,
+                        no_normalize,
                     }),
             },
         json,
         no_cache,
+        ..
     } = cli
     else {
         panic!("expected get drug command");
@@ -107,11 +110,27 @@ fn get_drug_parses_region_split_form() {
     assert_eq!(name, "trastuzumab");
     assert_eq!(sections, vec!["regulatory".to_string()]);
     assert_eq!(region, Some(DrugRegionArg::Who));
+    assert_eq!(species, SpeciesArg::Human);
     assert!(!raw);
+    assert!(!no_normalize);
     assert!(!json);
     assert!(!no_cache);
 }
 
+#[test]
+fn search_drug_parses_no_normalize() {
+    let cli = Cli::try_parse_from(["biomcp", "search", "drug", "5-FU", "--no-normalize"])
+        .expect("search drug should parse");
+    let Commands::Search {
+        entity: SearchEntity::Drug(args),
+    } = cli.command
+    else {
+        panic!("expected search drug command");
+    };
+    assert_eq!(args.positional_query.as_deref(), Some("5-FU"));
+    assert!(args.no_normalize);
+}
+
 #[test]
 fn drug_bare_name_parses_as_external_subcommand() {
     let cli =
@@ -316,3 +335,20 @@ fn drug_all_region_search_json_includes_who_bucket() {
         serde_json::Value::String("biomcp list drug".into())
     );
 }
+
+#[tokio::test]
+async fn get_drug_veterinary_species_points_to_adverse_event_search() {
+    let err = crate::cli::execute(vec![
+        "biomcp".to_string(),
+        "get".to_string(),
+        "drug".to_string(),
+        "carprofen".to_string(),
+        "--species".to_string(),
+        "veterinary".to_string(),
+    ])
+    .await
+    .expect_err("veterinary drug labels are not available");
+    let message = err.to_string();
+    assert!(message.contains("human drug labels only"));
+    assert!(message.contains("biomcp search adverse-event --species veterinary -d carprofen"));
+}
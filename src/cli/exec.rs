@@ -0,0 +1,372 @@
+//! `exec --jsonl`: many BioMCP commands through one process.
+//!
+//! Each stdin line is a JSON command object such as
+//! `{"cmd":"get","entity":"gene","id":"BRAF","sections":["civic"]}`. Commands
+//! run one after another in this process, so the shared HTTP clients, the
+//! response cache, and the compiled templates are set up once for the whole
+//! stream instead of once per spawned `biomcp`. Every command produces exactly
+//! one JSON line on stdout, written as soon as it finishes, and failures are
+//! reported on their own line rather than ending the run.
+
+use std::io::Write;
+
+use clap::{Args, Parser};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+
+use super::jobs::JobsCommand;
+use super::{Cli, CommandOutcome, Commands, OutputStream};
+use crate::error::BioMcpError;
+
+#[derive(Args, Debug)]
+pub struct ExecArgs {
+    /// Read one JSON command object per stdin line and write one JSON result per line
+    #[arg(long, required = true)]
+    pub jsonl: bool,
+}
+
+/// One stdin line.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExecRequest {
+    /// Caller-chosen value echoed on the result line, for matching results to requests.
+    #[serde(default)]
+    tag: Option<Value>,
+    /// Top-level command: `get`, `search`, `variant`, `sections`, ...
+    cmd: String,
+    /// Entity or subcommand that follows `cmd`.
+    #[serde(default)]
+    entity: Option<String>,
+    /// Identifier passed after the entity.
+    #[serde(default)]
+    id: Option<String>,
+    /// Sections appended after the identifier.
+    #[serde(default)]
+    sections: Vec<String>,
+    /// Remaining arguments, exactly as typed on the command line.
+    #[serde(default)]
+    args: Vec<String>,
+    /// Ask for `--json` output (default); `false` returns the markdown text.
+    #[serde(default = "default_json")]
+    json: bool,
+}
+
+fn default_json() -> bool {
+    true
+}
+
+impl ExecRequest {
+    fn argv(&self, no_cache: bool) -> Vec<String> {
+        let mut argv = vec!["biomcp".to_string()];
+        if self.json {
+            argv.push("--json".to_string());
+        }
+        if no_cache {
+            argv.push("--no-cache".to_string());
+        }
+        argv.push(self.cmd.clone());
+        argv.extend(self.entity.iter().cloned());
+        argv.extend(self.id.iter().cloned());
+        argv.extend(self.sections.iter().cloned());
+        argv.extend(self.args.iter().cloned());
+        argv
+    }
+}
+
+/// One stdout line.
+#[derive(Debug, Serialize)]
+struct ExecResult {
+    /// 1-based stdin line number.
+    line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag: Option<Value>,
+    ok: bool,
+    exit_code: u8,
+    /// Parsed JSON when the command printed JSON, otherwise its text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl ExecResult {
+    fn failed(line: usize, tag: Option<Value>, error: String) -> Self {
+        Self {
+            line,
+            tag,
+            ok: false,
+            exit_code: 1,
+            output: None,
+            error: Some(error),
+        }
+    }
+
+    fn from_outcome(line: usize, tag: Option<Value>, outcome: CommandOutcome) -> Self {
+        let ok = outcome.exit_code == 0;
+        let (output, error) = match outcome.stream {
+            OutputStream::Stdout => {
+                let output = serde_json::from_str(&outcome.text)
+                    .unwrap_or_else(|_| Value::String(outcome.text));
+                (Some(output), None)
+            }
+            OutputStream::Stderr => (None, Some(strip_error_prefix(&outcome.text))),
+        };
+        Self {
+            line,
+            tag,
+            ok,
+            exit_code: outcome.exit_code,
+            output,
+            error,
+        }
+    }
+}
+
+fn strip_error_prefix(text: &str) -> String {
+    let text = text.trim();
+    text.strip_prefix("Error: ").unwrap_or(text).to_string()
+}
+
+/// Commands that cannot run inside an exec stream: servers, the scheduler and
+/// job worker, which block until stopped, self-management, and `exec` itself,
+/// which would compete for the same stdin.
+fn rejected_command(command: &Commands) -> Option<&'static str> {
+    match command {
+        Commands::Exec(_) => Some("exec"),
+        Commands::Mcp | Commands::Serve | Commands::ServeHttp(_) | Commands::ServeSse => {
+            Some("MCP servers")
+        }
+        Commands::Schedule { .. } => Some("schedule"),
+        Commands::Jobs {
+            cmd: JobsCommand::Worker { .. },
+        } => Some("jobs worker"),
+        Commands::Update(_) | Commands::Uninstall => Some("update/uninstall"),
+        _ => None,
+    }
+}
+
+async fn run_request(line: usize, raw: &str, no_cache: bool) -> ExecResult {
+    let request = match serde_json::from_str::<ExecRequest>(raw) {
+        Ok(request) => request,
+        Err(err) => {
+            return ExecResult::failed(line, None, format!("Invalid command object: {err}"));
+        }
+    };
+    let tag = request.tag.clone();
+    let cli = match Cli::try_parse_from(request.argv(no_cache)) {
+        Ok(cli) => cli,
+        Err(err) => {
+            let message = err.to_string();
+            let first = message.lines().next().unwrap_or("invalid arguments");
+            return ExecResult::failed(line, tag, first.trim().to_string());
+        }
+    };
+    if let Some(name) = rejected_command(&cli.command) {
+        return ExecResult::failed(line, tag, format!("{name} cannot run inside exec --jsonl"));
+    }
+    // The cache root is fixed for the whole stream, so a per-line override
+    // would be silently ignored.
+    if cli.cache_dir.is_some() {
+        return ExecResult::failed(
+            line,
+            tag,
+            "--cache-dir applies to the whole stream; pass it before `exec` instead".into(),
+        );
+    }
+    match Box::pin(super::run_outcome(cli)).await {
+        Ok(outcome) => ExecResult::from_outcome(line, tag, outcome),
+        Err(err) => {
+            let message = match err.downcast_ref::<BioMcpError>() {
+                Some(bio_err) => bio_err.to_string(),
+                None => err.to_string(),
+            };
+            ExecResult::failed(line, tag, message)
+        }
+    }
+}
+
+/// Commands run and how many failed.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ExecSummary {
+    commands: usize,
+    failed: usize,
+}
+
+/// Runs each non-blank line of `input`, writing and flushing one result line
+/// to `output` per command.
+async fn run_jsonl<R, W>(
+    input: R,
+    output: &mut W,
+    no_cache: bool,
+) -> Result<ExecSummary, BioMcpError>
+where
+    R: AsyncBufRead + Unpin,
+    W: Write,
+{
+    let mut lines = input.lines();
+    let mut summary = ExecSummary::default();
+    let mut line = 0usize;
+    while let Some(raw) = lines.next_line().await? {
+        line += 1;
+        if raw.trim().is_empty() {
+            continue;
+        }
+        let result = run_request(line, &raw, no_cache).await;
+        summary.commands += 1;
+        if !result.ok {
+            summary.failed += 1;
+        }
+        writeln!(output, "{}", serde_json::to_string(&result)?)?;
+        output.flush()?;
+    }
+    Ok(summary)
+}
+
+pub(crate) async fn handle_command(
+    _args: ExecArgs,
+    no_cache: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let stdin = tokio::io::BufReader::new(tokio::io::stdin());
+    let mut stdout = std::io::stdout();
+    let summary = run_jsonl(stdin, &mut stdout, no_cache).await?;
+    // Results already went to stdout line by line; the summary stays on stderr
+    // so stdout remains pure JSON lines.
+    Ok(CommandOutcome::stderr_with_exit(
+        format!(
+            "exec: {} command(s), {} failed",
+            summary.commands, summary.failed
+        ),
+        u8::from(summary.failed > 0),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results(output: &[u8]) -> Vec<Value> {
+        std::str::from_utf8(output)
+            .expect("utf8")
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("result line is JSON"))
+            .collect()
+    }
+
+    #[test]
+    fn exec_requires_jsonl_flag() {
+        assert!(Cli::try_parse_from(["biomcp", "exec"]).is_err());
+        let cli = Cli::try_parse_from(["biomcp", "exec", "--jsonl"]).expect("exec should parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Exec(ExecArgs { jsonl: true })
+        ));
+    }
+
+    #[test]
+    fn request_argv_places_entity_id_sections_then_args() {
+        let request: ExecRequest = serde_json::from_str(
+            r#"{"cmd":"get","entity":"gene","id":"BRAF","sections":["civic"],"args":["--max-output-tokens","500"]}"#,
+        )
+        .expect("request");
+        assert_eq!(
+            request.argv(true),
+            [
+                "biomcp",
+                "--json",
+                "--no-cache",
+                "get",
+                "gene",
+                "BRAF",
+                "civic",
+                "--max-output-tokens",
+                "500"
+            ]
+        );
+
+        let request: ExecRequest =
+            serde_json::from_str(r#"{"cmd":"list","entity":"gene","json":false}"#)
+                .expect("request");
+        assert_eq!(request.argv(false), ["biomcp", "list", "gene"]);
+    }
+
+    #[tokio::test]
+    async fn run_request_rejects_blocking_commands_and_per_line_cache_dir() {
+        for (raw, error) in [
+            (
+                r#"{"cmd":"schedule","entity":"run","args":["--daemon"]}"#,
+                "schedule cannot run inside exec --jsonl",
+            ),
+            (
+                r#"{"cmd":"jobs","entity":"worker","args":["--dir","/tmp"]}"#,
+                "jobs worker cannot run inside exec --jsonl",
+            ),
+            (
+                r#"{"cmd":"schema","entity":"gene","args":["--cache-dir","/tmp/elsewhere"]}"#,
+                "--cache-dir applies to the whole stream; pass it before `exec` instead",
+            ),
+        ] {
+            let result = run_request(1, raw, false).await;
+            assert!(!result.ok, "{raw}");
+            assert_eq!(result.error.as_deref(), Some(error));
+        }
+    }
+
+    #[tokio::test]
+    async fn run_jsonl_writes_one_line_per_command_and_keeps_going_after_failures() {
+        let input = concat!(
+            r#"{"cmd":"schema","entity":"gene","tag":"first"}"#,
+            "\n\n",
+            "not json\n",
+            r#"{"cmd":"list","entity":"gene","json":false}"#,
+            "\n",
+            r#"{"cmd":"exec","args":["--jsonl"],"tag":7}"#,
+            "\n",
+            r#"{"cmd":"get","entity":"device-clearance","id":"P200001"}"#,
+            "\n",
+        );
+        let mut output = Vec::new();
+        let summary = run_jsonl(input.as_bytes(), &mut output, false)
+            .await
+            .expect("exec run");
+        assert_eq!(
+            summary,
+            ExecSummary {
+                commands: 5,
+                failed: 3
+            }
+        );
+
+        let lines = results(&output);
+        assert_eq!(lines.len(), 5);
+
+        assert_eq!(lines[0]["line"], 1);
+        assert_eq!(lines[0]["tag"], "first");
+        assert_eq!(lines[0]["ok"], true);
+        assert_eq!(lines[0]["output"]["x-biomcp-entity"], "gene");
+
+        assert_eq!(lines[1]["line"], 3);
+        assert_eq!(lines[1]["ok"], false);
+        assert!(
+            lines[1]["error"]
+                .as_str()
+                .is_some_and(|error| error.starts_with("Invalid command object"))
+        );
+
+        assert!(
+            lines[2]["output"]
+                .as_str()
+                .is_some_and(|text| text.contains("# gene"))
+        );
+
+        assert_eq!(lines[3]["tag"], 7);
+        assert_eq!(lines[3]["error"], "exec cannot run inside exec --jsonl");
+
+        assert_eq!(lines[4]["exit_code"], 1);
+        assert!(
+            lines[4]["error"]
+                .as_str()
+                .is_some_and(|error| error.contains("K followed by six digits"))
+        );
+    }
+}
@@ -1,6 +1,8 @@
-use super::{GeneCommand, GeneGetArgs, GeneSearchArgs};
+use super::{GeneCommand, GeneGetArgs, GeneSearchArgs, GeneSpecies, GeneVariantsFormat};
 use crate::cli::CommandOutcome;
 
+const GENE_VARIANTS_MAX_LIMIT: usize = 1000;
+
 pub(crate) async fn handle_get(
     args: GeneGetArgs,
     json: bool,
@@ -8,15 +10,42 @@ pub(crate) async fn handle_get(
 ) -> anyhow::Result<CommandOutcome> {
     let (sections, json_override) = super::super::extract_json_from_sections(&args.sections);
     let json_output = json || json_override;
+    let (sections, trailing_species) = extract_species_from_sections(&sections)?;
     render_gene_card_outcome(
         &args.symbol,
         &sections,
+        trailing_species.unwrap_or(args.species),
         json_output,
         alias_suggestions_as_json,
     )
     .await
 }
 
+/// `--species` typed after a section name lands in the trailing section list.
+pub(super) fn extract_species_from_sections(
+    sections: &[String],
+) -> Result<(Vec<String>, Option<GeneSpecies>), crate::error::BioMcpError> {
+    let mut cleaned = Vec::new();
+    let mut species = None;
+    let mut iter = sections.iter();
+    while let Some(raw) = iter.next() {
+        let value = if raw == "--species" {
+            iter.next().map(String::as_str).ok_or_else(|| {
+                crate::error::BioMcpError::InvalidArgument(
+                    "--species requires a value (human, mouse, rat, zebrafish, or a taxid)".into(),
+                )
+            })?
+        } else if let Some(value) = raw.strip_prefix("--species=") {
+            value
+        } else {
+            cleaned.push(raw.clone());
+            continue;
+        };
+        species = Some(value.parse::<GeneSpecies>()?);
+    }
+    Ok((cleaned, species))
+}
+
 pub(crate) async fn handle_search(
     args: GeneSearchArgs,
     json: bool,
@@ -29,6 +58,7 @@ pub(crate) async fn handle_search(
         region: args.region,
         pathway: args.pathway,
         go_term: args.go_term,
+        species: args.species,
     };
     let mut query_summary = crate::entities::gene::search_query_summary(&filters);
     if args.offset > 0 {
@@ -63,6 +93,7 @@ pub(crate) async fn handle_command(
             render_gene_card_outcome(
                 &symbol,
                 super::super::empty_sections(),
+                GeneSpecies::Human,
                 json,
                 alias_suggestions_as_json,
             )
@@ -73,6 +104,7 @@ pub(crate) async fn handle_command(
             render_gene_card_outcome(
                 &symbol,
                 super::super::empty_sections(),
+                GeneSpecies::Human,
                 json,
                 alias_suggestions_as_json,
             )
@@ -229,6 +261,60 @@ pub(crate) async fn handle_command(
                         crate::render::markdown::gene_markdown(&gene, &sections)?
                     }
                 }
+                GeneCommand::VariantMap { symbol } => {
+                    let map = crate::entities::gene::variant_map(&symbol).await?;
+                    if json {
+                        crate::render::json::to_pretty(&map)?
+                    } else {
+                        crate::render::markdown::gene_variant_map_markdown(&map)?
+                    }
+                }
+                GeneCommand::Variants {
+                    symbol,
+                    significance,
+                    format,
+                    all,
+                    limit,
+                    offset,
+                } => {
+                    if json && format == GeneVariantsFormat::Tsv {
+                        return Err(crate::error::BioMcpError::InvalidArgument(
+                            "--format tsv prints TSV; drop --json or use --format markdown".into(),
+                        )
+                        .into());
+                    }
+                    let table = if all {
+                        crate::entities::gene::clinvar_variants_all(
+                            &symbol,
+                            significance.as_deref(),
+                        )
+                        .await?
+                    } else {
+                        if limit == 0 || limit > GENE_VARIANTS_MAX_LIMIT {
+                            return Err(crate::error::BioMcpError::InvalidArgument(format!(
+                                "--limit must be between 1 and {GENE_VARIANTS_MAX_LIMIT}; use --all for the full table"
+                            ))
+                            .into());
+                        }
+                        crate::entities::gene::clinvar_variants(
+                            &symbol,
+                            significance.as_deref(),
+                            limit,
+                            offset,
+                        )
+                        .await?
+                    };
+                    if json {
+                        crate::render::json::to_pretty(&table)?
+                    } else {
+                        match format {
+                            GeneVariantsFormat::Tsv => table.to_tsv(),
+                            GeneVariantsFormat::Markdown => {
+                                crate::render::markdown::gene_clinvar_variants_markdown(&table)?
+                            }
+                        }
+                    }
+                }
                 GeneCommand::Definition { .. } | GeneCommand::External(_) => {
                     unreachable!("handled above")
                 }
@@ -242,10 +328,15 @@ pub(crate) async fn handle_command(
 pub(super) async fn render_gene_card_outcome(
     symbol: &str,
     sections: &[String],
+    species: GeneSpecies,
     json_output: bool,
     alias_suggestions_as_json: bool,
 ) -> anyhow::Result<CommandOutcome> {
-    match crate::entities::gene::get(symbol, sections).await {
+    let (gene, section_errors) = crate::entities::section_errors::collect(
+        crate::entities::gene::get_for_species(symbol, sections, species),
+    )
+    .await;
+    match gene {
         Ok(gene) => {
             let text = if json_output {
                 crate::render::json::to_entity_json(
@@ -253,13 +344,14 @@ pub(super) async fn render_gene_card_outcome(
                     crate::render::markdown::gene_evidence_urls(&gene),
                     crate::render::markdown::related_gene(&gene),
                     crate::render::provenance::gene_section_sources(&gene),
+                    section_errors,
                 )?
             } else {
                 crate::render::markdown::gene_markdown(&gene, sections)?
             };
             Ok(CommandOutcome::stdout(text))
         }
-        Err(err @ crate::error::BioMcpError::NotFound { .. }) => {
+        Err(err @ crate::error::BioMcpError::NotFound { .. }) if species.is_human() => {
             if let Some(outcome) = super::super::try_alias_fallback_outcome(
                 symbol,
                 crate::entities::discover::DiscoverType::Gene,
@@ -1,6 +1,9 @@
 //! Gene CLI payloads and subcommands.
 
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
+
+use crate::entities::gene::GeneSpecies;
+use crate::error::BioMcpError;
 
 #[derive(Args, Debug)]
 pub struct GeneSearchArgs {
@@ -25,6 +28,9 @@ pub struct GeneSearchArgs {
     /// Filter by GO term ID/text (e.g., GO:0004672)
     #[arg(long = "go")]
     pub go_term: Option<String>,
+    /// Species to search: human, mouse, rat, zebrafish, or an NCBI taxonomy ID (e.g., 7227)
+    #[arg(long, default_value = "human", value_parser = parse_gene_species)]
+    pub species: GeneSpecies,
     /// Maximum results (default: 10)
     #[arg(short, long, default_value = "10")]
     pub limit: usize,
@@ -37,9 +43,26 @@ pub struct GeneSearchArgs {
 pub struct GeneGetArgs {
     /// Gene symbol (e.g., BRAF, TP53, EGFR)
     pub symbol: String,
-    /// Sections to include (pathways, ontology, diseases, protein, go, interactions, civic, expression, hpa, druggability, clingen, constraint, disgenet, funding, all)
+    /// Sections to include (pathways, ontology, diseases, protein, go, interactions, civic, expression, hpa, druggability, tractability, clingen, constraint, disgenet, clinvar-summary, funding, key-papers, dependency, orthologs, all)
     #[arg(trailing_var_arg = true)]
     pub sections: Vec<String>,
+    /// Species: human, mouse, rat, zebrafish, or an NCBI taxonomy ID; non-human genes support only protein and go
+    #[arg(long, default_value = "human", value_parser = parse_gene_species)]
+    pub species: GeneSpecies,
+}
+
+fn parse_gene_species(value: &str) -> Result<GeneSpecies, String> {
+    value.parse().map_err(|err| match err {
+        BioMcpError::InvalidArgument(message) => message,
+        other => other.to_string(),
+    })
+}
+
+/// Output format for `gene variants`; `--json` stays the global switch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GeneVariantsFormat {
+    Markdown,
+    Tsv,
 }
 
 #[derive(Subcommand, Debug)]
@@ -132,6 +155,50 @@ See also: biomcp list gene")]
         #[arg(long, default_value = "0")]
         offset: usize,
     },
+    /// Map pathogenic and somatic variant density along the protein
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp gene variant-map EGFR
+  biomcp --json gene variant-map TP53
+
+Counts ClinVar pathogenic/likely pathogenic variants (MyVariant.info) and somatic
+mutations from the configured cBioPortal study by protein position. JSON output
+lists per-position counts and fixed-width bins for lollipop plots.
+See also: biomcp list gene")]
+    VariantMap {
+        /// HGNC gene symbol (e.g., EGFR)
+        symbol: String,
+    },
+    /// List ClinVar variants in this gene as a curation table
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp gene variants BRCA1 --significance pathogenic
+  biomcp gene variants BRCA1 --significance pathogenic --format tsv --all > brca1_plp.tsv
+  biomcp --json gene variants TP53 --significance likely_pathogenic --limit 50
+
+Rows carry HGVS c./p. (RefSeq preferred), the strongest ClinVar significance,
+review status with star rating, and conditions. --all scrolls through every
+matching MyVariant.info record, past the 10,000-hit paging window.
+See also: biomcp list gene")]
+    Variants {
+        /// HGNC gene symbol (e.g., BRCA1)
+        symbol: String,
+        /// ClinVar significance filter (pathogenic, likely_pathogenic, uncertain_significance, benign, ...)
+        #[arg(long)]
+        significance: Option<String>,
+        /// Output format: markdown (default) or tsv
+        #[arg(long, value_enum, default_value = "markdown")]
+        format: GeneVariantsFormat,
+        /// Fetch every matching variant via scrolling (ignores --limit)
+        #[arg(long, conflicts_with = "offset")]
+        all: bool,
+        /// Maximum results (default: 25, max 1000)
+        #[arg(short, long, default_value = "25")]
+        limit: usize,
+        /// Skip the first N results
+        #[arg(long, default_value = "0")]
+        offset: usize,
+    },
     #[command(external_subcommand)]
     External(Vec<String>),
 }
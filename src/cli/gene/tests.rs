@@ -1,10 +1,11 @@
 use clap::{CommandFactory, Parser};
 
-use super::GeneCommand;
+use super::{GeneCommand, GeneVariantsFormat};
 use crate::cli::test_support::{
     MockServer, lock_env, mount_gene_lookup_miss, mount_ols_alias, set_env_var,
 };
-use crate::cli::{Cli, Commands, GetEntity, OutputStream};
+use crate::cli::{Cli, Commands, GetEntity, OutputStream, SearchEntity};
+use crate::entities::gene::GeneSpecies;
 
 fn render_gene_get_long_help() -> String {
     let mut command = Cli::command();
@@ -80,6 +81,119 @@ fn gene_pathways_parses_limit_and_offset() {
     }
 }
 
+#[test]
+fn gene_variant_map_parses_symbol() {
+    let cli = Cli::try_parse_from(["biomcp", "gene", "variant-map", "EGFR"])
+        .expect("gene variant-map should parse");
+
+    match cli.command {
+        Commands::Gene {
+            cmd: GeneCommand::VariantMap { symbol },
+        } => assert_eq!(symbol, "EGFR"),
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[test]
+fn gene_variants_parses_tsv_export_flags() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "gene",
+        "variants",
+        "BRCA1",
+        "--significance",
+        "pathogenic",
+        "--format",
+        "tsv",
+        "--all",
+    ])
+    .expect("gene variants export flags should parse");
+
+    match cli.command {
+        Commands::Gene {
+            cmd:
+                GeneCommand::Variants {
+                    symbol,
+                    significance,
+                    format,
+                    all,
+                    ..
+                },
+        } => {
+            assert_eq!(symbol, "BRCA1");
+            assert_eq!(significance.as_deref(), Some("pathogenic"));
+            assert_eq!(format, GeneVariantsFormat::Tsv);
+            assert!(all);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[test]
+fn gene_variants_rejects_all_with_offset() {
+    let err = Cli::try_parse_from([
+        "biomcp", "gene", "variants", "BRCA1", "--all", "--offset", "25",
+    ])
+    .expect_err("--all should conflict with --offset");
+    assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+}
+
+#[test]
+fn get_and_search_gene_parse_species() {
+    let cli = Cli::try_parse_from(["biomcp", "get", "gene", "Braf", "--species", "mouse", "go"])
+        .expect("get gene --species should parse");
+    let Cli {
+        command: Commands::Get {
+            entity: GetEntity::Gene(args),
+        },
+        ..
+    } = cli
+    else {
+        panic!("expected get gene command");
+    };
+    assert_eq!(args.species, GeneSpecies::Mouse);
+    assert_eq!(args.sections, vec!["go"]);
+
+    let cli = Cli::try_parse_from(["biomcp", "search", "gene", "tp53", "--species", "7955"])
+        .expect("search gene --species should parse");
+    let Cli {
+        command: Commands::Search {
+            entity: SearchEntity::Gene(args),
+        },
+        ..
+    } = cli
+    else {
+        panic!("expected search gene command");
+    };
+    assert_eq!(args.species, GeneSpecies::Zebrafish);
+
+    let err = Cli::try_parse_from(["biomcp", "search", "gene", "tp53", "--species", "yeast"])
+        .expect_err("unknown species should fail");
+    assert!(err.to_string().contains("Unknown species \"yeast\""));
+}
+
+#[test]
+fn trailing_species_flag_is_pulled_out_of_sections() {
+    let sections = vec![
+        "protein".to_string(),
+        "--species".to_string(),
+        "rat".to_string(),
+    ];
+    let (sections, species) =
+        super::dispatch::extract_species_from_sections(&sections).expect("species");
+    assert_eq!(sections, vec!["protein"]);
+    assert_eq!(species, Some(GeneSpecies::Rat));
+
+    let (_, species) =
+        super::dispatch::extract_species_from_sections(&["--species=10090".to_string()])
+            .expect("species");
+    assert_eq!(species, Some(GeneSpecies::Mouse));
+
+    let err = super::dispatch::extract_species_from_sections(&["--species".to_string()])
+        .expect_err("missing value");
+    assert!(err.to_string().contains("--species requires a value"));
+}
+
 #[tokio::test]
 async fn handle_get_gene_alias_fallback_returns_markdown_suggestion() {
     let _guard = lock_env().await;
@@ -0,0 +1,38 @@
+use super::GuidelineSearchArgs;
+use crate::cli::CommandOutcome;
+
+pub(in crate::cli) async fn handle_search(
+    args: GuidelineSearchArgs,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let condition =
+        super::super::resolve_query_input(args.condition, args.positional_query, "--condition")?;
+    let orgs = match args.org.as_deref() {
+        Some(value) => crate::entities::guideline::parse_org_list(value)?,
+        None => Vec::new(),
+    };
+    let filters = crate::entities::guideline::GuidelineSearchFilters {
+        condition: condition.unwrap_or_default(),
+        orgs,
+    };
+    let mut query_summary = crate::entities::guideline::search_query_summary(&filters);
+    if args.offset > 0 {
+        query_summary = format!("{query_summary}, offset={}", args.offset);
+    }
+    let page = crate::entities::guideline::search_page(&filters, args.limit, args.offset).await?;
+    let results = page.results;
+    let pagination =
+        super::super::PaginationMeta::offset(args.offset, args.limit, results.len(), page.total);
+    let text = if json {
+        let next_commands = crate::render::markdown::search_next_commands_guideline(&results);
+        super::super::search_json_with_meta(results, pagination, next_commands)?
+    } else {
+        let footer = super::super::pagination_footer_offset(&pagination);
+        crate::render::markdown::guideline_search_markdown_with_footer(
+            &query_summary,
+            &results,
+            &footer,
+        )?
+    };
+    Ok(CommandOutcome::stdout(text))
+}
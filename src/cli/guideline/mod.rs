@@ -0,0 +1,28 @@
+//! Clinical guideline CLI payloads.
+
+use clap::Args;
+
+#[derive(Args, Debug)]
+pub struct GuidelineSearchArgs {
+    /// Condition or disease name (e.g., "atrial fibrillation")
+    #[arg(short = 'c', long)]
+    pub condition: Option<String>,
+    /// Optional positional query alias for -c/--condition
+    #[arg(value_name = "QUERY")]
+    pub positional_query: Option<String>,
+    /// Comma-separated issuing organizations (USPSTF, NICE, AHA, ACC, ESC, IDSA, ASCO, ADA, WHO)
+    #[arg(long)]
+    pub org: Option<String>,
+    /// Maximum results (default: 10)
+    #[arg(short, long, default_value = "10")]
+    pub limit: usize,
+    /// Skip the first N results
+    #[arg(long, default_value = "0")]
+    pub offset: usize,
+}
+
+mod dispatch;
+pub(super) use self::dispatch::handle_search;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,94 @@
+use clap::Parser;
+
+use crate::cli::{Cli, Commands, SearchEntity};
+
+#[test]
+fn search_guidelines_parses_condition_and_orgs() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "guidelines",
+        "--condition",
+        "atrial fibrillation",
+        "--org",
+        "AHA,NICE",
+        "--limit",
+        "5",
+    ])
+    .expect("search guidelines should parse");
+
+    let Cli {
+        command:
+            Commands::Search {
+                entity:
+                    SearchEntity::Guidelines(crate::cli::guideline::GuidelineSearchArgs {
+                        condition,
+                        positional_query,
+                        org,
+                        limit,
+                        offset,
+                    }),
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected search guidelines command");
+    };
+
+    assert_eq!(condition.as_deref(), Some("atrial fibrillation"));
+    assert_eq!(positional_query, None);
+    assert_eq!(org.as_deref(), Some("AHA,NICE"));
+    assert_eq!(limit, 5);
+    assert_eq!(offset, 0);
+}
+
+#[test]
+fn search_guideline_singular_alias_accepts_positional_condition() {
+    let cli = Cli::try_parse_from(["biomcp", "search", "guideline", "asthma"])
+        .expect("singular alias should parse");
+
+    let Cli {
+        command: Commands::Search {
+            entity: SearchEntity::Guidelines(args),
+        },
+        ..
+    } = cli
+    else {
+        panic!("expected search guidelines command");
+    };
+
+    assert_eq!(args.positional_query.as_deref(), Some("asthma"));
+}
+
+#[tokio::test]
+async fn handle_search_rejects_unknown_org_before_backend_lookup() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "guidelines",
+        "-c",
+        "asthma",
+        "--org",
+        "GINA",
+    ])
+    .expect("search guidelines should parse");
+
+    let Cli {
+        command: Commands::Search {
+            entity: SearchEntity::Guidelines(args),
+        },
+        json,
+        ..
+    } = cli
+    else {
+        panic!("expected search guidelines command");
+    };
+
+    let err = super::handle_search(args, json)
+        .await
+        .expect_err("unknown org should fail fast");
+    assert!(
+        err.to_string()
+            .contains("Unknown guideline organization 'GINA'")
+    );
+}
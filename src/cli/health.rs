@@ -166,6 +166,13 @@ const HEALTH_SOURCES: &[SourceDescriptor] = &[
             url: "https://mychem.info/v1/query?q=aspirin&size=1",
         },
     },
+    SourceDescriptor {
+        api: "NCI EVS",
+        affects: Some("drug code-name and trial condition synonym expansion"),
+        probe: ProbeKind::Get {
+            url: "https://api-evsrest.nci.nih.gov/api/v1/concept/ncit/search?terms=ABT-199&type=match&pageSize=1",
+        },
+    },
     SourceDescriptor {
         api: "PubTator3",
         affects: Some("article annotations and entity extraction"),
@@ -215,6 +222,15 @@ const HEALTH_SOURCES: &[SourceDescriptor] = &[
             url: "https://pmc.ncbi.nlm.nih.gov/tools/idconv/api/v1/articles/?format=json&idtype=pmid&ids=22663011",
         },
     },
+    SourceDescriptor {
+        api: "Unpaywall",
+        affects: Some("article fulltext fallback outside PMC"),
+        probe: ProbeKind::AuthQueryParam {
+            url: "https://api.unpaywall.org/v2/10.1038/nature12373",
+            env_var: "UNPAYWALL_EMAIL",
+            param_name: "email",
+        },
+    },
     SourceDescriptor {
         api: "ClinicalTrials.gov",
         affects: Some("search/get trial and trial helper commands"),
@@ -232,6 +248,14 @@ const HEALTH_SOURCES: &[SourceDescriptor] = &[
             header_value_prefix: "",
         },
     },
+    SourceDescriptor {
+        api: "CTIS",
+        affects: Some("trial --source ctis"),
+        probe: ProbeKind::PostJson {
+            url: "https://euclinicaltrials.eu/ctis-public-api/search",
+            payload: r#"{"pagination":{"page":1,"size":1},"sort":{"property":"decisionDate","direction":"DESC"},"searchCriteria":{"medicalCondition":"melanoma"}}"#,
+        },
+    },
     SourceDescriptor {
         api: "Enrichr",
         affects: Some("gene/pathway enrichment sections"),
@@ -273,6 +297,26 @@ const HEALTH_SOURCES: &[SourceDescriptor] = &[
             env_var: "ALPHAGENOME_API_KEY",
         },
     },
+    SourceDescriptor {
+        api: "CLUE",
+        affects: Some("repurpose command"),
+        probe: ProbeKind::AuthGet {
+            url: "https://api.clue.io/api/perts?filter=%7B%22limit%22%3A1%7D",
+            env_var: "CLUE_API_KEY",
+            header_name: "user_key",
+            header_value_prefix: "",
+        },
+    },
+    SourceDescriptor {
+        api: "PatentsView",
+        affects: Some("drug patents section"),
+        probe: ProbeKind::AuthGet {
+            url: "https://search.patentsview.org/api/v1/patent/?q=%7B%22patent_id%22%3A%227544799%22%7D&f=%5B%22patent_id%22%5D",
+            env_var: "PATENTSVIEW_API_KEY",
+            header_name: "X-Api-Key",
+            header_value_prefix: "",
+        },
+    },
     SourceDescriptor {
         api: "Semantic Scholar",
         affects: Some("Semantic Scholar features"),
@@ -302,6 +346,13 @@ const HEALTH_SOURCES: &[SourceDescriptor] = &[
             url: "https://api.pharmgkb.org/v1/data/labelAnnotation?relatedChemicals.name=warfarin&view=min",
         },
     },
+    SourceDescriptor {
+        api: "IPD-IMGT/HLA",
+        affects: Some("hla allele registry"),
+        probe: ProbeKind::Get {
+            url: "https://www.ebi.ac.uk/cgi-bin/ipd/api/allele?project=HLA&query=startsWith(name,%22B*57:01%22)&fields=name&limit=1",
+        },
+    },
     SourceDescriptor {
         api: "Monarch",
         affects: Some("disease genes, phenotypes, and models"),
@@ -330,6 +381,13 @@ const HEALTH_SOURCES: &[SourceDescriptor] = &[
             url: "https://seer.cancer.gov/statistics-network/explorer/source/content_writers/get_var_formats.php",
         },
     },
+    SourceDescriptor {
+        api: "WHO GHO",
+        affects: Some("disease epidemiology section"),
+        probe: ProbeKind::Get {
+            url: "https://ghoapi.azureedge.net/api/MDG_0000000020?$filter=SpatialDimType%20eq%20'GLOBAL'&$top=1",
+        },
+    },
     SourceDescriptor {
         api: "NIH Reporter",
         affects: Some("gene and disease funding sections"),
@@ -360,6 +418,13 @@ const HEALTH_SOURCES: &[SourceDescriptor] = &[
             url: "https://gtexportal.org/api/v2/",
         },
     },
+    SourceDescriptor {
+        api: "DepMap",
+        affects: Some("gene dependency section"),
+        probe: ProbeKind::Get {
+            url: "https://depmap.org/portal/api/health_check/ok",
+        },
+    },
     SourceDescriptor {
         api: "DGIdb",
         affects: Some("gene druggability section"),
@@ -383,6 +448,48 @@ const HEALTH_SOURCES: &[SourceDescriptor] = &[
             payload: r#"{"query":"query { __typename }"}"#,
         },
     },
+    SourceDescriptor {
+        api: "VariantValidator",
+        affects: Some("validate variant and reference-sequence HGVS lookups"),
+        probe: ProbeKind::Get {
+            url: "https://rest.variantvalidator.org/hello/?content-type=application%2Fjson",
+        },
+    },
+    SourceDescriptor {
+        api: "Ensembl VEP",
+        affects: Some("get variant --source vep consequences"),
+        probe: ProbeKind::Get {
+            url: "https://grch37.rest.ensembl.org/info/ping?content-type=application/json",
+        },
+    },
+    SourceDescriptor {
+        api: "Ensembl REST",
+        affects: Some("get transcript"),
+        probe: ProbeKind::Get {
+            url: "https://rest.ensembl.org/info/ping?content-type=application/json",
+        },
+    },
+    SourceDescriptor {
+        api: "ClinGen Allele Registry",
+        affects: Some("get variant CAid lookup and ClinGen allele IDs"),
+        probe: ProbeKind::Get {
+            url: "https://reg.genome.network/allele/CA7200",
+        },
+    },
+    SourceDescriptor {
+        api: "ClinVar",
+        affects: Some("get variant clinvar-submissions section"),
+        probe: ProbeKind::Get {
+            url: "https://eutils.ncbi.nlm.nih.gov/entrez/eutils/efetch.fcgi?db=clinvar&rettype=vcv&is_variationid=true&id=13961",
+        },
+    },
+    SourceDescriptor {
+        api: "LitVar",
+        affects: Some("get variant litvar section"),
+        probe: ProbeKind::Get {
+            url: "https://www.ncbi.nlm.nih.gov/research/litvar2-api/variant/autocomplete/?query=rs113488022",
+        },
+    },
     SourceDescriptor {
         api: "UniProt",
         affects: Some("gene protein summary and protein detail sections"),
@@ -390,6 +497,13 @@ const HEALTH_SOURCES: &[SourceDescriptor] = &[
             url: "https://rest.uniprot.org/uniprotkb/P15056.json",
         },
     },
+    SourceDescriptor {
+        api: "RCSB PDB",
+        affects: Some("get structure and search structure"),
+        probe: ProbeKind::Get {
+            url: "https://data.rcsb.org/rest/v1/core/entry/4HHB",
+        },
+    },
     SourceDescriptor {
         api: "QuickGO",
         affects: Some("gene go terms and protein annotation sections"),
@@ -435,7 +549,9 @@ const HEALTH_SOURCES: &[SourceDescriptor] = &[
     },
     SourceDescriptor {
         api: "OpenTargets",
-        affects: Some("gene druggability, drug target, and disease association sections"),
+        affects: Some(
+            "gene druggability, drug target, disease association, and disease therapies sections",
+        ),
         probe: ProbeKind::PostJson {
             url: "https://api.platform.opentargets.org/api/v4/graphql",
             payload: r#"{"query":"query { drug(chemblId: \"CHEMBL25\") { id name } }"}"#,
@@ -492,6 +608,22 @@ const HEALTH_SOURCES: &[SourceDescriptor] = &[
             url: "https://wsearch.nlm.nih.gov/ws/query?db=healthTopics&term=chest+pain&retmax=1",
         },
     },
+    SourceDescriptor {
+        api: "MedlinePlus Supplements",
+        affects: Some("supplement interactions in drug interactions section"),
+        probe: ProbeKind::Get {
+            url: "https://medlineplus.gov/druginfo/natural/329.html",
+        },
+    },
+    SourceDescriptor {
+        api: "USPSTF",
+        affects: Some("USPSTF graded rows in guideline search"),
+        probe: ProbeKind::AuthQueryParam {
+            url: "https://data.uspreventiveservicestaskforce.org/api/json",
+            env_var: "USPSTF_API_KEY",
+            param_name: "key",
+        },
+    },
     SourceDescriptor {
         api: "cBioPortal",
         affects: Some("cohort frequency section"),
@@ -503,6 +635,8 @@ const HEALTH_SOURCES: &[SourceDescriptor] = &[
 
 const EMA_LOCAL_DATA_AFFECTS: &str = "default plain-name drug search plus search/get drug --region eu|all and EU regulatory/safety/shortage sections";
 const WHO_LOCAL_DATA_AFFECTS: &str = "default plain-name drug search plus search/get drug --region who|all and WHO regulatory sections";
+const SCIMAGO_LOCAL_DATA_AFFECTS: &str =
+    "journal quartiles in search article results and --min-quartile";
 
 fn health_sources() -> &'static [SourceDescriptor] {
     HEALTH_SOURCES
@@ -537,10 +671,7 @@ fn outcome(row: HealthRow, class: ProbeClass) -> ProbeOutcome {
 }
 
 fn configured_key(env_var: &str) -> Option<String> {
-    std::env::var(env_var)
-        .ok()
-        .map(|value| value.trim().to_string())
-        .filter(|value| !value.is_empty())
+    crate::sources::tenant::api_key(env_var)
 }
 
 fn excluded_outcome(api: &str, env_var: &str, affects: Option<&'static str>) -> ProbeOutcome {
@@ -962,6 +1093,24 @@ fn check_who_local_data() -> ProbeOutcome {
     who_local_data_outcome(&root, env_configured)
 }
 
+fn scimago_local_data_outcome(root: &Path, env_configured: bool) -> ProbeOutcome {
+    local_data_outcome(
+        "SCImago local data",
+        root,
+        env_configured,
+        crate::sources::scimago::SCIMAGO_REQUIRED_FILES,
+        crate::sources::scimago::SCIMAGO_STALE_AFTER,
+        SCIMAGO_LOCAL_DATA_AFFECTS,
+        crate::sources::scimago::scimago_missing_files,
+    )
+}
+
+fn check_scimago_local_data() -> ProbeOutcome {
+    let env_configured = configured_key("BIOMCP_SCIMAGO_DIR").is_some();
+    let root = crate::sources::scimago::resolve_scimago_root();
+    scimago_local_data_outcome(&root, env_configured)
+}
+
 async fn probe_source(client: reqwest::Client, source: &SourceDescriptor) -> ProbeOutcome {
     match source.probe {
         ProbeKind::Get { url } => check_get(client, source.api, url, source.affects).await,
@@ -1195,6 +1344,7 @@ pub async fn check(apis_only: bool) -> Result<HealthReport, BioMcpError> {
     if !apis_only {
         outcomes.push(check_ema_local_data());
         outcomes.push(check_who_local_data());
+        outcomes.push(check_scimago_local_data());
         outcomes.push(check_cache_dir().await);
         outcomes.push(check_cache_limits().await);
     }
@@ -1301,9 +1451,10 @@ mod tests {
 
     use super::{
         EMA_LOCAL_DATA_AFFECTS, HealthReport, HealthRow, ProbeClass, ProbeKind, ProbeOutcome,
-        SourceDescriptor, WHO_LOCAL_DATA_AFFECTS, affects_for_api, check_cache_dir,
-        check_cache_limits_with, ema_local_data_outcome, health_sources, probe_cache_dir,
-        probe_source, report_from_outcomes, who_local_data_outcome,
+        SCIMAGO_LOCAL_DATA_AFFECTS, SourceDescriptor, WHO_LOCAL_DATA_AFFECTS, affects_for_api,
+        check_cache_dir, check_cache_limits_with, ema_local_data_outcome, health_sources,
+        probe_cache_dir, probe_source, report_from_outcomes, scimago_local_data_outcome,
+        who_local_data_outcome,
     };
     use crate::cache::{
         CacheBlob, CacheConfigOrigins, CacheEntry, CachePlannerError, CacheSnapshot, ConfigOrigin,
@@ -1505,6 +1656,7 @@ mod tests {
             max_size,
             min_disk_free,
             max_age: Duration::from_secs(86_400),
+            encrypt: false,
             origins: CacheConfigOrigins {
                 cache_root: ConfigOrigin::Default,
                 max_size: ConfigOrigin::Default,
@@ -1626,6 +1778,7 @@ mod tests {
                 "MyGene",
                 "MyVariant",
                 "MyChem",
+                "NCI EVS",
                 "PubTator3",
                 "PubMed",
                 "Europe PMC",
@@ -1633,28 +1786,42 @@ mod tests {
                 "LitSense2",
                 "PMC OA",
                 "NCBI ID Converter",
+                "Unpaywall",
                 "ClinicalTrials.gov",
                 "NCI CTS",
+                "CTIS",
                 "Enrichr",
                 "OpenFDA",
                 "OncoKB",
                 "DisGeNET",
                 "AlphaGenome",
+                "CLUE",
+                "PatentsView",
                 "Semantic Scholar",
                 "CPIC",
                 "PharmGKB",
+                "IPD-IMGT/HLA",
                 "Monarch",
                 "HPO",
                 "MyDisease",
                 "SEER Explorer",
+                "WHO GHO",
                 "NIH Reporter",
                 "CIViC",
                 "GWAS Catalog",
                 "GTEx",
+                "DepMap",
                 "DGIdb",
                 "ClinGen",
                 "gnomAD",
+                "VariantValidator",
+                "Ensembl VEP",
+                "Ensembl REST",
+                "ClinGen Allele Registry",
+                "ClinVar",
+                "LitVar",
                 "UniProt",
+                "RCSB PDB",
                 "QuickGO",
                 "STRING",
                 "Reactome",
@@ -1669,6 +1836,8 @@ mod tests {
                 "OLS4",
                 "UMLS",
                 "MedlinePlus",
+                "MedlinePlus Supplements",
+                "USPSTF",
                 "cBioPortal",
             ]
         );
@@ -1884,6 +2053,28 @@ mod tests {
         assert_eq!(outcome.row.affects.as_deref(), Some(WHO_LOCAL_DATA_AFFECTS));
     }
 
+    #[test]
+    fn scimago_local_data_reports_missing_then_configured_export() {
+        let root = TempDirGuard::new();
+
+        let outcome = scimago_local_data_outcome(root.path(), false);
+        assert_eq!(outcome.class, ProbeClass::Excluded);
+        assert_eq!(outcome.row.status, "not configured");
+        assert_eq!(
+            outcome.row.affects.as_deref(),
+            Some(SCIMAGO_LOCAL_DATA_AFFECTS)
+        );
+
+        std::fs::write(
+            root.path().join(crate::sources::scimago::SCIMAGO_CSV_FILE),
+            "Rank;Title;SJR;SJR Best Quartile\n1;Nature Medicine;19,045;Q1\n",
+        )
+        .expect("SCImago fixture should write");
+        let outcome = scimago_local_data_outcome(root.path(), true);
+        assert_eq!(outcome.class, ProbeClass::Healthy);
+        assert_eq!(outcome.row.status, "configured");
+    }
+
     #[test]
     fn key_gated_source_is_excluded_when_env_missing() {
         let _lock = env_lock();
@@ -2482,10 +2673,13 @@ mod tests {
     #[test]
     fn markdown_shows_new_affects_mappings() {
         assert_eq!(affects_for_api("GTEx"), Some("gene expression section"));
+        assert_eq!(affects_for_api("DepMap"), Some("gene dependency section"));
         assert_eq!(affects_for_api("DGIdb"), Some("gene druggability section"));
         assert_eq!(
             affects_for_api("OpenTargets"),
-            Some("gene druggability, drug target, and disease association sections")
+            Some(
+                "gene druggability, drug target, disease association, and disease therapies sections"
+            )
         );
         assert_eq!(affects_for_api("ClinGen"), Some("gene clingen section"));
         assert_eq!(affects_for_api("gnomAD"), Some("gene constraint section"));
@@ -0,0 +1,253 @@
+//! Query history commands backed by `<cache_root>/history.jsonl`.
+
+use clap::{CommandFactory, Parser, Subcommand};
+use serde::Serialize;
+
+use super::{Cli, CommandOutcome, display_command};
+use crate::error::BioMcpError;
+use crate::utils::history::{self, HistoryEntry, HistoryOrigin};
+
+const DEFAULT_LIST_LIMIT: usize = 20;
+
+/// Families that manage local state or servers rather than query sources.
+const UNRECORDED_FAMILIES: &[&str] = &[
+    "bookmark",
+    "cache",
+    "chart",
+    "ema",
+    "exec",
+    "history",
+    "jobs",
+    "list",
+    "mcp",
+    "schedule",
+    "serve",
+    "serve-http",
+    "serve-sse",
+    "skill",
+    "snapshot",
+    "uninstall",
+    "update",
+    "version",
+    "who",
+];
+
+#[derive(Subcommand, Debug)]
+pub enum HistoryCommand {
+    /// List recorded queries, newest first
+    List {
+        /// Maximum entries to show
+        #[arg(short, long, default_value_t = DEFAULT_LIST_LIMIT)]
+        limit: usize,
+    },
+    /// Re-run a recorded query by id
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp history replay 42
+  biomcp --json history replay 42
+  biomcp --no-cache history replay 42    # refetch instead of reading the HTTP cache")]
+    Replay {
+        /// History entry id from `history list`
+        id: u64,
+    },
+    /// Print the full history as JSON lines (or a JSON array with --json)
+    Export,
+}
+
+#[derive(Debug, Serialize)]
+struct HistoryListEntry<'a> {
+    #[serde(flatten)]
+    entry: &'a HistoryEntry,
+    display: String,
+}
+
+/// Subcommand path of `args` (without the leading `biomcp`), or `None` for
+/// commands that are not recorded.
+fn command_path(args: &[String]) -> Option<String> {
+    let mut command = Cli::command();
+    command.build();
+    let mut leaf = &command;
+    let mut path = Vec::new();
+    for token in args.iter().filter(|token| !token.starts_with('-')) {
+        match leaf.find_subcommand(token) {
+            Some(sub) => {
+                path.push(sub.get_name().to_string());
+                leaf = sub;
+            }
+            None => break,
+        }
+    }
+    let family = path.first()?;
+    if UNRECORDED_FAMILIES.contains(&family.as_str()) {
+        return None;
+    }
+    Some(path.join(" "))
+}
+
+/// Records a finished CLI or MCP query; `args` includes the leading `biomcp`.
+pub(crate) fn record_query(origin: HistoryOrigin, args: &[String], output: Option<&str>) {
+    let args = args.get(1..).unwrap_or_default();
+    if let Some(command) = command_path(args) {
+        history::record(origin, command, args.to_vec(), output);
+    }
+}
+
+fn unknown_entry(id: u64) -> BioMcpError {
+    BioMcpError::NotFound {
+        entity: "history entry".into(),
+        id: id.to_string(),
+        suggestion: "List recorded queries with `biomcp history list`.".into(),
+    }
+}
+
+fn render_list(entries: &[HistoryEntry]) -> String {
+    if entries.is_empty() {
+        return "No queries recorded yet. Set BIOMCP_HISTORY=on to record queries as you run them."
+            .into();
+    }
+    let mut out = format!(
+        "# Query History ({})\n\n| ID | When | Origin | Command | Results |\n|---|---|---|---|---|\n",
+        entries.len()
+    );
+    for entry in entries {
+        let results = match (entry.ok, entry.result_count) {
+            (false, _) => "error".to_string(),
+            (true, Some(count)) => count.to_string(),
+            (true, None) => "-".to_string(),
+        };
+        out.push_str(&format!(
+            "| {} | {} | {} | `{}` | {} |\n",
+            entry.id,
+            entry.timestamp,
+            entry.origin.as_str(),
+            display_command(&entry.args),
+            results
+        ));
+    }
+    out.push_str("\nReplay one with: `biomcp history replay <id>`\n");
+    out
+}
+
+pub(crate) async fn handle_command(
+    cmd: HistoryCommand,
+    json: bool,
+    no_cache: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let store = history::HistoryStore::open()?;
+    match cmd {
+        HistoryCommand::List { limit } => {
+            let entries = store
+                .load()?
+                .into_iter()
+                .rev()
+                .take(limit)
+                .collect::<Vec<_>>();
+            if json {
+                let listed = entries
+                    .iter()
+                    .map(|entry| HistoryListEntry {
+                        entry,
+                        display: display_command(&entry.args),
+                    })
+                    .collect::<Vec<_>>();
+                return Ok(CommandOutcome::stdout(crate::render::json::to_pretty(
+                    &listed,
+                )?));
+            }
+            Ok(CommandOutcome::stdout(render_list(&entries)))
+        }
+        HistoryCommand::Replay { id } => {
+            let entry = store
+                .load()?
+                .into_iter()
+                .find(|entry| entry.id == id)
+                .ok_or_else(|| unknown_entry(id))?;
+            let mut cli = Cli::try_parse_from(
+                std::iter::once("biomcp".to_string()).chain(entry.args.iter().cloned()),
+            )
+            .map_err(|err| {
+                BioMcpError::InvalidArgument(format!(
+                    "History entry {id} no longer parses: {}",
+                    err.to_string()
+                        .lines()
+                        .next()
+                        .unwrap_or("invalid arguments")
+                ))
+            })?;
+            cli.json |= json;
+            cli.no_cache |= no_cache;
+            Box::pin(super::run_outcome(cli)).await
+        }
+        HistoryCommand::Export => {
+            let entries = store.load()?;
+            let text = if json {
+                crate::render::json::to_pretty(&entries)?
+            } else {
+                let mut lines = Vec::with_capacity(entries.len());
+                for entry in &entries {
+                    lines.push(serde_json::to_string(entry)?);
+                }
+                lines.join("\n")
+            };
+            Ok(CommandOutcome::stdout(text))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HistoryCommand, command_path, render_list};
+    use crate::cli::{Cli, Commands};
+    use crate::utils::history::{HistoryEntry, HistoryOrigin};
+    use clap::Parser;
+
+    fn argv(tokens: &[&str]) -> Vec<String> {
+        tokens.iter().map(|token| token.to_string()).collect()
+    }
+
+    #[test]
+    fn command_path_names_query_subcommands_and_skips_local_families() {
+        assert_eq!(
+            command_path(&argv(&["--json", "search", "trial", "-c", "melanoma"])).as_deref(),
+            Some("search trial")
+        );
+        assert_eq!(
+            command_path(&argv(&["get", "gene", "BRAF", "civic"])).as_deref(),
+            Some("get gene")
+        );
+        assert_eq!(command_path(&argv(&["history", "list"])), None);
+        assert_eq!(command_path(&argv(&["bookmark", "run", "x"])), None);
+        assert_eq!(command_path(&argv(&["cache", "stats"])), None);
+    }
+
+    #[test]
+    fn render_list_marks_failures_and_uncounted_results() {
+        let entry = |id, ok, result_count| HistoryEntry {
+            id,
+            timestamp: "2026-10-16T09:00:00Z".into(),
+            origin: HistoryOrigin::Cli,
+            command: "search trial".into(),
+            args: argv(&["search", "trial", "-c", "lung cancer"]),
+            ok,
+            result_count,
+        };
+        let markdown = render_list(&[entry(2, true, Some(10)), entry(1, false, None)]);
+        assert!(markdown.contains("| ID | When | Origin | Command | Results |"));
+        assert!(markdown.contains(
+            "| 2 | 2026-10-16T09:00:00Z | cli | `biomcp search trial -c 'lung cancer'` | 10 |"
+        ));
+        assert!(markdown.contains("| 1 | 2026-10-16T09:00:00Z | cli |"));
+        assert!(markdown.contains("| error |"));
+    }
+
+    #[test]
+    fn history_replay_parses_numeric_id() {
+        let cli = Cli::try_parse_from(["biomcp", "history", "replay", "42"]).expect("parse");
+        assert!(matches!(
+            cli.command,
+            Commands::History {
+                cmd: HistoryCommand::Replay { id: 42 }
+            }
+        ));
+    }
+}
@@ -0,0 +1,27 @@
+use super::HlaGetArgs;
+use crate::cli::CommandOutcome;
+
+pub(in crate::cli) async fn handle_get(
+    args: HlaGetArgs,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let (sections, json_override) = super::super::extract_json_from_sections(&args.sections);
+    let json_output = json || json_override;
+    let (hla, section_errors) = crate::entities::section_errors::collect(
+        crate::entities::hla::get(&args.allele, &sections),
+    )
+    .await;
+    let hla = hla?;
+    let text = if json_output {
+        crate::render::json::to_entity_json(
+            &hla,
+            crate::render::markdown::hla_evidence_urls(&hla),
+            crate::render::markdown::related_hla(&hla),
+            crate::render::provenance::hla_section_sources(&hla),
+            section_errors,
+        )?
+    } else {
+        crate::render::markdown::hla_markdown(&hla, &sections)?
+    };
+    Ok(CommandOutcome::stdout(text))
+}
@@ -0,0 +1,18 @@
+//! HLA allele CLI payloads.
+
+use clap::Args;
+
+#[derive(Args, Debug)]
+pub struct HlaGetArgs {
+    /// HLA allele in WHO nomenclature (e.g., HLA-B*57:01, B*57:01, DRB1*15:01)
+    pub allele: String,
+    /// Sections to include (frequencies, drugs, diseases, all)
+    #[arg(trailing_var_arg = true)]
+    pub sections: Vec<String>,
+}
+
+mod dispatch;
+pub(super) use self::dispatch::handle_get;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,45 @@
+use clap::Parser;
+
+use crate::cli::{Cli, Commands, GetEntity};
+
+#[test]
+fn get_hla_parses_allele_and_sections() {
+    let cli = Cli::try_parse_from(["biomcp", "get", "hla", "HLA-B*57:01", "drugs", "diseases"])
+        .expect("get hla should parse");
+
+    let Cli {
+        command:
+            Commands::Get {
+                entity: GetEntity::Hla(crate::cli::hla::HlaGetArgs { allele, sections }),
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected get hla command");
+    };
+
+    assert_eq!(allele, "HLA-B*57:01");
+    assert_eq!(sections, vec!["drugs", "diseases"]);
+}
+
+#[tokio::test]
+async fn handle_get_rejects_invalid_allele_before_backend_lookup() {
+    let cli =
+        Cli::try_parse_from(["biomcp", "get", "hla", "CYP2D6"]).expect("get hla should parse");
+
+    let Cli {
+        command: Commands::Get {
+            entity: GetEntity::Hla(args),
+        },
+        json,
+        ..
+    } = cli
+    else {
+        panic!("expected get hla command");
+    };
+
+    let err = super::handle_get(args, json)
+        .await
+        .expect_err("non-HLA input should fail fast");
+    assert!(err.to_string().contains("Invalid HLA allele"));
+}
@@ -0,0 +1,771 @@
+//! Background jobs stored under `<cache_root>/jobs/`.
+//!
+//! `jobs submit` queues a BioMCP command and starts a detached
+//! `biomcp jobs worker` process, so a trial snapshot or a large `annotate`
+//! batch keeps running after the submitting call returns. Workers take queued
+//! jobs oldest first, at most [`MAX_RUNNING_JOBS`] at a time. Each job is a
+//! JSON state file plus its captured output; `jobs status` and `jobs result`
+//! read them back, which lets MCP clients poll instead of holding a tool call
+//! open.
+
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use clap::{Parser, Subcommand};
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::{Cli, CommandOutcome, Commands, display_command};
+use crate::error::BioMcpError;
+
+const JOBS_DIR: &str = "jobs";
+const QUEUE_LOCK_FILE: &str = "queue.lock";
+/// Jobs that may run at once; later submissions wait in the queue.
+const MAX_RUNNING_JOBS: usize = 2;
+const WORKER_EXITED: &str = "worker exited before the job finished";
+
+#[derive(Subcommand, Debug)]
+pub enum JobsCommand {
+    /// Queue a BioMCP command to run in the background
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp jobs submit -- snapshot create --entities genes.txt variants.txt
+  biomcp jobs submit -- annotate vcf sample.vcf --format tsv
+  biomcp jobs submit -- search trial -c melanoma --status recruiting --json
+
+Prints the job id. At most 2 jobs run at once; the rest wait in the queue.")]
+    Submit {
+        /// Command to run, after `--` (without the leading `biomcp`)
+        #[arg(last = true, required = true, value_name = "COMMAND")]
+        command: Vec<String>,
+    },
+    /// Show one job, or every job newest first
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp jobs status 3
+  biomcp jobs status
+  biomcp --json jobs status 3")]
+    Status {
+        /// Job id (omit to list all jobs)
+        id: Option<u64>,
+    },
+    /// Print the output of a finished job
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp jobs result 3
+  biomcp jobs result 3 > trials.json
+
+Exits with the job's own exit code, or 1 while the job is still queued or running.")]
+    Result {
+        /// Job id
+        id: u64,
+    },
+    /// Run queued jobs until the queue is empty (started by `jobs submit`)
+    #[command(hide = true)]
+    Worker {
+        /// Jobs directory to drain
+        #[arg(long, value_name = "DIR")]
+        dir: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum JobStatus {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+}
+
+impl JobStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+        }
+    }
+
+    fn is_finished(self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct JobRecord {
+    pub(crate) id: u64,
+    pub(crate) args: Vec<String>,
+    /// Working directory at submission, so relative paths inside the job resolve as typed.
+    pub(crate) dir: PathBuf,
+    pub(crate) status: JobStatus,
+    pub(crate) submitted_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) started_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) finished_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) exit_code: Option<u8>,
+    /// Worker process running the job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) pid: Option<u32>,
+    /// `serve-http` tenant that submitted the job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) tenant: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) error: Option<String>,
+}
+
+fn now() -> String {
+    humantime::format_rfc3339_seconds(SystemTime::now()).to_string()
+}
+
+/// Jobs directory for the caller; `serve-http` tenants each get their own.
+fn jobs_dir() -> Result<PathBuf, BioMcpError> {
+    let root = crate::cache::resolve_cache_config()?
+        .cache_root
+        .join(JOBS_DIR);
+    Ok(match crate::sources::tenant::current_scope() {
+        Some((name, _)) => root.join("tenants").join(name),
+        None => root,
+    })
+}
+
+fn record_path(dir: &Path, id: u64) -> PathBuf {
+    dir.join(format!("{id}.json"))
+}
+
+fn output_path(dir: &Path, id: u64) -> PathBuf {
+    dir.join(format!("{id}.out"))
+}
+
+/// Held by the worker for as long as the job runs, so a stale `running`
+/// record can be told apart from a live one.
+fn run_lock_path(dir: &Path, id: u64) -> PathBuf {
+    dir.join(format!("{id}.lock"))
+}
+
+fn open_lock(path: &Path) -> Result<File, BioMcpError> {
+    Ok(OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)?)
+}
+
+/// Serializes id assignment and state changes across processes; released on drop.
+fn lock_queue(dir: &Path) -> Result<File, BioMcpError> {
+    std::fs::create_dir_all(dir)?;
+    let file = open_lock(&dir.join(QUEUE_LOCK_FILE))?;
+    file.lock_exclusive()?;
+    Ok(file)
+}
+
+fn unknown_job(id: u64) -> BioMcpError {
+    BioMcpError::NotFound {
+        entity: "job".into(),
+        id: id.to_string(),
+        suggestion: "List submitted jobs with `biomcp jobs status`.".into(),
+    }
+}
+
+fn parse_record(content: &str, path: &Path) -> Result<JobRecord, BioMcpError> {
+    serde_json::from_str(content).map_err(|err| {
+        BioMcpError::InvalidArgument(format!("failed to parse {}: {err}", path.display()))
+    })
+}
+
+fn load_record(dir: &Path, id: u64) -> Result<JobRecord, BioMcpError> {
+    let path = record_path(dir, id);
+    match std::fs::read_to_string(&path) {
+        Ok(content) => parse_record(&content, &path),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Err(unknown_job(id)),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn store_record(dir: &Path, record: &JobRecord) -> Result<(), BioMcpError> {
+    std::fs::create_dir_all(dir)?;
+    let content = serde_json::to_string_pretty(record)
+        .map_err(|err| BioMcpError::InvalidArgument(format!("failed to encode job: {err}")))?;
+    let path = record_path(dir, record.id);
+    let staging = path.with_extension("json.tmp");
+    std::fs::write(&staging, content)?;
+    std::fs::rename(&staging, path)?;
+    Ok(())
+}
+
+/// Reads every job, oldest first. Unreadable files are skipped.
+fn load_records(dir: &Path) -> Result<Vec<JobRecord>, BioMcpError> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut records = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+        match std::fs::read_to_string(&path)
+            .map_err(BioMcpError::from)
+            .and_then(|content| parse_record(&content, &path))
+        {
+            Ok(record) => records.push(record),
+            Err(err) => warn!(path = %path.display(), "skipping unreadable job: {err}"),
+        }
+    }
+    records.sort_by_key(|record| record.id);
+    Ok(records)
+}
+
+/// Marks `running` jobs whose worker no longer holds the run lock as failed.
+/// Call with the queue lock held.
+fn reap_stale(dir: &Path, records: &mut [JobRecord]) -> Result<(), BioMcpError> {
+    for record in records
+        .iter_mut()
+        .filter(|record| record.status == JobStatus::Running)
+    {
+        let lock = open_lock(&run_lock_path(dir, record.id))?;
+        if lock.try_lock_exclusive().is_err() {
+            continue;
+        }
+        drop(lock);
+        record.status = JobStatus::Failed;
+        record.finished_at = Some(now());
+        record.error = Some(WORKER_EXITED.into());
+        store_record(dir, record)?;
+    }
+    Ok(())
+}
+
+fn running_count(records: &[JobRecord]) -> usize {
+    records
+        .iter()
+        .filter(|record| record.status == JobStatus::Running)
+        .count()
+}
+
+/// Checks that `command` parses as a BioMCP command that can run unattended.
+pub(super) fn parse_job(command: &[String]) -> Result<Vec<String>, BioMcpError> {
+    let mut args = command.to_vec();
+    if args
+        .first()
+        .is_some_and(|first| first.eq_ignore_ascii_case("biomcp"))
+    {
+        args.remove(0);
+    }
+    if args.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "Job is empty. Example: biomcp jobs submit -- snapshot create --entities genes.txt"
+                .into(),
+        ));
+    }
+    let argv = std::iter::once("biomcp".to_string()).chain(args.iter().cloned());
+    let cli = Cli::try_parse_from(argv).map_err(|err| {
+        BioMcpError::InvalidArgument(format!(
+            "Job does not parse: {}",
+            err.to_string()
+                .lines()
+                .next()
+                .unwrap_or("invalid arguments")
+        ))
+    })?;
+    if matches!(
+        cli.command,
+        Commands::Jobs { .. }
+            | Commands::Schedule { .. }
+            | Commands::Exec(_)
+            | Commands::Mcp
+            | Commands::Serve
+            | Commands::ServeHttp(_)
+            | Commands::ServeSse
+            | Commands::Update(_)
+            | Commands::Uninstall
+    ) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "`{}` commands cannot run as background jobs",
+            args[0]
+        )));
+    }
+    Ok(args)
+}
+
+/// Starts a detached worker for `dir`. It inherits the caller's tenant keys
+/// and cache root, and outlives the submitting process.
+fn spawn_worker(dir: &Path) -> Result<(), BioMcpError> {
+    let exe = std::env::current_exe()?;
+    let cache_root = crate::cache::resolve_cache_config()?.cache_root;
+    let mut command = Command::new(exe);
+    command
+        .arg("--cache-dir")
+        .arg(&cache_root)
+        .args(["jobs", "worker", "--dir"])
+        .arg(dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    if let Some((_, keys)) = crate::sources::tenant::current_scope() {
+        // Only the tenant's own keys reach the worker, never the server's.
+        for name in crate::sources::tenant::TENANT_KEY_ENVS {
+            command.env_remove(name);
+        }
+        command.envs(keys);
+    }
+    // A new process group keeps Ctrl-C in the submitting terminal from reaching the worker.
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+    let mut child = command.spawn()?;
+    // Reap the worker when it exits so a long-lived MCP server collects no zombies.
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
+
+fn submit(command: &[String]) -> Result<JobRecord, BioMcpError> {
+    let args = parse_job(command)?;
+    let dir = jobs_dir()?;
+    let (mut record, running) = {
+        let _queue = lock_queue(&dir)?;
+        let mut records = load_records(&dir)?;
+        reap_stale(&dir, &mut records)?;
+        let record = JobRecord {
+            id: records.last().map_or(1, |last| last.id + 1),
+            args,
+            dir: std::env::current_dir()?,
+            status: JobStatus::Queued,
+            submitted_at: now(),
+            started_at: None,
+            finished_at: None,
+            exit_code: None,
+            pid: None,
+            tenant: crate::sources::tenant::current_scope().map(|(name, _)| name),
+            error: None,
+        };
+        store_record(&dir, &record)?;
+        (record, running_count(&records))
+    };
+    if running < MAX_RUNNING_JOBS
+        && let Err(err) = spawn_worker(&dir)
+    {
+        let _queue = lock_queue(&dir)?;
+        record.status = JobStatus::Failed;
+        record.finished_at = Some(now());
+        record.error = Some(format!("failed to start worker: {err}"));
+        store_record(&dir, &record)?;
+        return Err(err);
+    }
+    Ok(record)
+}
+
+/// Claims the oldest queued job unless the running limit is reached. The
+/// returned file holds the job's run lock.
+fn claim_next(dir: &Path) -> Result<Option<(JobRecord, File)>, BioMcpError> {
+    let _queue = lock_queue(dir)?;
+    let mut records = load_records(dir)?;
+    reap_stale(dir, &mut records)?;
+    if running_count(&records) >= MAX_RUNNING_JOBS {
+        return Ok(None);
+    }
+    let Some(mut record) = records
+        .into_iter()
+        .find(|record| record.status == JobStatus::Queued)
+    else {
+        return Ok(None);
+    };
+    let run_lock = open_lock(&run_lock_path(dir, record.id))?;
+    run_lock.lock_exclusive()?;
+    record.status = JobStatus::Running;
+    record.started_at = Some(now());
+    record.pid = Some(std::process::id());
+    store_record(dir, &record)?;
+    Ok(Some((record, run_lock)))
+}
+
+async fn run_job(dir: &Path, mut record: JobRecord) -> Result<JobRecord, BioMcpError> {
+    if record.dir.is_dir() {
+        std::env::set_current_dir(&record.dir)?;
+    }
+    let argv = std::iter::once("biomcp".to_string()).chain(record.args.clone());
+    let outcome = match Cli::try_parse_from(argv) {
+        Ok(cli) => match record.tenant.clone() {
+            // Keys arrive through the worker's environment, which holds only
+            // the tenant's own; the scope keeps its HTTP cache entries separate.
+            Some(name) => {
+                let keys = crate::sources::tenant::TENANT_KEY_ENVS
+                    .iter()
+                    .filter_map(|key| Some((key.to_string(), std::env::var(key).ok()?)))
+                    .collect::<BTreeMap<_, _>>();
+                let scope = Arc::new(crate::sources::tenant::TenantScope::new(name, keys));
+                crate::sources::tenant::with_tenant(scope, Box::pin(super::run_outcome(cli))).await
+            }
+            None => Box::pin(super::run_outcome(cli)).await,
+        },
+        Err(err) => Err(BioMcpError::InvalidArgument(format!(
+            "Job {} no longer parses: {err}",
+            record.id
+        ))
+        .into()),
+    };
+    let (exit_code, text) = match outcome {
+        Ok(outcome) => (outcome.exit_code, outcome.text),
+        Err(err) => (1, format!("Error: {err}")),
+    };
+    std::fs::write(output_path(dir, record.id), format!("{text}\n"))?;
+    record.status = if exit_code == 0 {
+        JobStatus::Succeeded
+    } else {
+        JobStatus::Failed
+    };
+    record.exit_code = Some(exit_code);
+    record.finished_at = Some(now());
+    if exit_code != 0 {
+        record.error = text
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(str::to_string);
+    }
+    Ok(record)
+}
+
+/// Runs queued jobs one after another until none can be claimed.
+async fn drain_queue(dir: &Path) -> Result<usize, BioMcpError> {
+    let mut ran = 0;
+    while let Some((record, run_lock)) = claim_next(dir)? {
+        let id = record.id;
+        let finished = match run_job(dir, record.clone()).await {
+            Ok(finished) => finished,
+            Err(err) => JobRecord {
+                status: JobStatus::Failed,
+                finished_at: Some(now()),
+                error: Some(err.to_string()),
+                ..record
+            },
+        };
+        if finished.status == JobStatus::Succeeded {
+            info!(job = id, "background job finished");
+        } else {
+            warn!(
+                job = id,
+                "background job failed: {}",
+                finished.error.as_deref().unwrap_or("-")
+            );
+        }
+        {
+            let _queue = lock_queue(dir)?;
+            store_record(dir, &finished)?;
+        }
+        drop(run_lock);
+        let _ = std::fs::remove_file(run_lock_path(dir, id));
+        ran += 1;
+    }
+    Ok(ran)
+}
+
+/// Loads every job after clearing stale `running` states.
+fn current_records(dir: &Path) -> Result<Vec<JobRecord>, BioMcpError> {
+    let _queue = lock_queue(dir)?;
+    let mut records = load_records(dir)?;
+    reap_stale(dir, &mut records)?;
+    Ok(records)
+}
+
+fn current_record(dir: &Path, id: u64) -> Result<JobRecord, BioMcpError> {
+    current_records(dir)?
+        .into_iter()
+        .find(|record| record.id == id)
+        .map_or_else(|| load_record(dir, id), Ok)
+}
+
+/// JSON view of a job. The submitting directory is left out: `jobs status`
+/// also answers MCP clients, which should not learn the server's paths.
+fn record_json(record: &JobRecord) -> Result<serde_json::Value, BioMcpError> {
+    let mut value = serde_json::to_value(record)
+        .map_err(|err| BioMcpError::InvalidArgument(format!("failed to encode job: {err}")))?;
+    if let Some(fields) = value.as_object_mut() {
+        fields.remove("dir");
+    }
+    Ok(value)
+}
+
+fn render_submitted(record: &JobRecord) -> String {
+    let id = record.id;
+    format!(
+        "Submitted job {id} ({}): `{}`\nPoll with `biomcp jobs status {id}`; read the output with `biomcp jobs result {id}`.",
+        record.status.as_str(),
+        display_command(&record.args)
+    )
+}
+
+fn render_record(record: &JobRecord) -> String {
+    let id = record.id;
+    let mut out = format!(
+        "# Job {id}: {}\n\nCommand: `{}`\nSubmitted: {}\n",
+        record.status.as_str(),
+        display_command(&record.args),
+        record.submitted_at
+    );
+    if let Some(started) = &record.started_at {
+        out.push_str(&format!("Started: {started}\n"));
+    }
+    if let Some(finished) = &record.finished_at {
+        out.push_str(&format!("Finished: {finished}\n"));
+    }
+    if let Some(exit_code) = record.exit_code {
+        out.push_str(&format!("Exit code: {exit_code}\n"));
+    }
+    if let Some(error) = &record.error {
+        out.push_str(&format!("Error: {error}\n"));
+    }
+    out.push('\n');
+    out.push_str(&if record.status.is_finished() {
+        format!("Read the output with `biomcp jobs result {id}`.")
+    } else {
+        format!("Poll again with `biomcp jobs status {id}`.")
+    });
+    out
+}
+
+fn render_list(records: &[JobRecord]) -> String {
+    if records.is_empty() {
+        return "No jobs submitted. Example: biomcp jobs submit -- snapshot create --entities genes.txt".into();
+    }
+    let mut out = format!(
+        "# Jobs ({})\n\n| ID | Status | Command | Submitted | Finished |\n|---|---|---|---|---|\n",
+        records.len()
+    );
+    for record in records.iter().rev() {
+        out.push_str(&format!(
+            "| {} | {} | `{}` | {} | {} |\n",
+            record.id,
+            record.status.as_str(),
+            display_command(&record.args),
+            record.submitted_at,
+            record.finished_at.as_deref().unwrap_or("-")
+        ));
+    }
+    out
+}
+
+pub(crate) async fn handle_command(cmd: JobsCommand, json: bool) -> anyhow::Result<CommandOutcome> {
+    match cmd {
+        JobsCommand::Submit { command } => {
+            let record = submit(&command)?;
+            let text = if json {
+                crate::render::json::to_pretty(&record_json(&record)?)?
+            } else {
+                render_submitted(&record)
+            };
+            Ok(CommandOutcome::stdout(text))
+        }
+        JobsCommand::Status { id: Some(id) } => {
+            let record = current_record(&jobs_dir()?, id)?;
+            let text = if json {
+                crate::render::json::to_pretty(&record_json(&record)?)?
+            } else {
+                render_record(&record)
+            };
+            Ok(CommandOutcome::stdout(text))
+        }
+        JobsCommand::Status { id: None } => {
+            let records = current_records(&jobs_dir()?)?;
+            if json {
+                let records = records
+                    .iter()
+                    .rev()
+                    .map(record_json)
+                    .collect::<Result<Vec<_>, _>>()?;
+                return Ok(CommandOutcome::stdout(crate::render::json::to_pretty(
+                    &records,
+                )?));
+            }
+            Ok(CommandOutcome::stdout(render_list(&records)))
+        }
+        JobsCommand::Result { id } => {
+            let dir = jobs_dir()?;
+            let record = current_record(&dir, id)?;
+            if !record.status.is_finished() {
+                let text = if json {
+                    crate::render::json::to_pretty(&record_json(&record)?)?
+                } else {
+                    format!(
+                        "Job {id} is {}; poll with `biomcp jobs status {id}`.",
+                        record.status.as_str()
+                    )
+                };
+                return Ok(CommandOutcome::stdout_with_exit(text, 1));
+            }
+            let text = match std::fs::read_to_string(output_path(&dir, id)) {
+                Ok(text) => text.trim_end_matches('\n').to_string(),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => format!(
+                    "Error: job {id} left no output ({})",
+                    record.error.as_deref().unwrap_or("unknown error")
+                ),
+                Err(err) => return Err(BioMcpError::from(err).into()),
+            };
+            Ok(CommandOutcome::stdout_with_exit(
+                text,
+                record.exit_code.unwrap_or(1),
+            ))
+        }
+        JobsCommand::Worker { dir } => {
+            let ran = Box::pin(drain_queue(&dir)).await?;
+            Ok(CommandOutcome::stdout(format!(
+                "Worker finished after {ran} job(s)."
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        JobRecord, JobStatus, JobsCommand, claim_next, current_records, load_records, parse_job,
+        record_json, render_list, store_record,
+    };
+    use crate::cli::test_support::TempDirGuard;
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+
+    fn argv(tokens: &[&str]) -> Vec<String> {
+        tokens.iter().map(|token| token.to_string()).collect()
+    }
+
+    fn record(id: u64, status: JobStatus) -> JobRecord {
+        JobRecord {
+            id,
+            args: argv(&["search", "trial", "-c", "lung cancer"]),
+            dir: "/tmp".into(),
+            status,
+            submitted_at: "2026-10-16T09:00:00Z".into(),
+            started_at: None,
+            finished_at: None,
+            exit_code: None,
+            pid: None,
+            tenant: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn jobs_submit_and_status_parse() {
+        let cli = Cli::try_parse_from([
+            "biomcp",
+            "jobs",
+            "submit",
+            "--",
+            "snapshot",
+            "create",
+            "--entities",
+            "genes.txt",
+        ])
+        .expect("jobs submit should parse");
+        let Commands::Jobs {
+            cmd: JobsCommand::Submit { command },
+        } = cli.command
+        else {
+            panic!("expected jobs submit");
+        };
+        assert_eq!(
+            command,
+            argv(&["snapshot", "create", "--entities", "genes.txt"])
+        );
+
+        let cli =
+            Cli::try_parse_from(["biomcp", "jobs", "status"]).expect("jobs status should parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Jobs {
+                cmd: JobsCommand::Status { id: None }
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_job_strips_prefix_and_rejects_unqueueable_commands() {
+        assert_eq!(
+            parse_job(&argv(&["biomcp", "search", "trial", "-c", "melanoma"])).expect("valid job"),
+            argv(&["search", "trial", "-c", "melanoma"])
+        );
+        for bad in [
+            argv(&[]),
+            argv(&["jobs", "status"]),
+            argv(&["schedule", "list"]),
+            argv(&["serve-http"]),
+            argv(&["search", "nope"]),
+        ] {
+            assert!(parse_job(&bad).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn claim_next_takes_oldest_queued_job_and_reaps_dead_workers() {
+        let dir = TempDirGuard::new("jobs-queue");
+        // No worker holds job 1's run lock, so it is reaped as failed.
+        store_record(dir.path(), &record(1, JobStatus::Running)).expect("store");
+        store_record(dir.path(), &record(2, JobStatus::Succeeded)).expect("store");
+        store_record(dir.path(), &record(4, JobStatus::Queued)).expect("store");
+        store_record(dir.path(), &record(3, JobStatus::Queued)).expect("store");
+
+        let (claimed, _run_lock) = claim_next(dir.path())
+            .expect("claim")
+            .expect("a queued job");
+        assert_eq!(claimed.id, 3);
+        assert_eq!(claimed.status, JobStatus::Running);
+
+        // Job 3's run lock is still held here, so it stays running.
+        let records = current_records(dir.path()).expect("records");
+        let statuses = records
+            .iter()
+            .map(|record| (record.id, record.status))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                (1, JobStatus::Failed),
+                (2, JobStatus::Succeeded),
+                (3, JobStatus::Running),
+                (4, JobStatus::Queued),
+            ]
+        );
+        assert_eq!(
+            load_records(dir.path()).expect("records")[0]
+                .error
+                .as_deref(),
+            Some(super::WORKER_EXITED)
+        );
+    }
+
+    #[test]
+    fn record_json_leaves_out_the_submitting_directory() {
+        let value = record_json(&record(1, JobStatus::Queued)).expect("json");
+        assert_eq!(value["id"], 1);
+        assert_eq!(value["status"], "queued");
+        assert!(value.get("dir").is_none());
+    }
+
+    #[test]
+    fn render_list_shows_newest_first() {
+        let markdown = render_list(&[
+            record(1, JobStatus::Succeeded),
+            record(2, JobStatus::Queued),
+        ]);
+        assert!(markdown.starts_with("# Jobs (2)"));
+        let second = markdown.find("| 2 | queued |").expect("job 2 row");
+        let first = markdown.find("| 1 | succeeded |").expect("job 1 row");
+        assert!(second < first);
+        assert!(markdown.contains("`biomcp search trial -c 'lung cancer'`"));
+    }
+}
@@ -14,28 +14,30 @@ pub fn render(entity: Option<&str>) -> Result<String, BioMcpError> {
             "disease" => Ok(list_disease()),
             "phenotype" => Ok(list_phenotype()),
             "pgx" => Ok(list_pgx()),
+            "hla" => Ok(list_hla()),
             "gwas" => Ok(list_gwas()),
+            "guidelines" | "guideline" => Ok(list_guidelines()),
             "pathway" => Ok(list_pathway()),
             "protein" => Ok(list_protein()),
+            "structure" => Ok(list_structure()),
+            "transcript" => Ok(list_transcript()),
             "study" => Ok(list_study()),
             "adverse-event" | "adverse_event" | "adverseevent" => Ok(list_adverse_event()),
+            "device-clearance" | "device_clearance" => Ok(list_device_clearance()),
             "search-all" | "search_all" | "searchall" => Ok(list_search_all()),
             "discover" => Ok(list_discover()),
             "batch" => Ok(list_batch()),
             "enrich" => Ok(list_enrich()),
             "skill" | "skills" => Ok(crate::cli::skill::list_use_cases()?),
             other => Err(BioMcpError::InvalidArgument(format!(
-                "Unknown entity: {other}\n\nValid entities:\n- gene\n- variant\n- article\n- trial\n- drug\n- disease\n- phenotype\n- pgx\n- gwas\n- pathway\n- protein\n- study\n- adverse-event\n- search-all\n- discover\n- batch\n- enrich\n- skill"
+                "Unknown entity: {other}\n\nValid entities:\n- gene\n- variant\n- article\n- trial\n- drug\n- disease\n- phenotype\n- pgx\n- hla\n- gwas\n- guidelines\n- pathway\n- protein\n- structure\n- transcript\n- study\n- adverse-event\n- device-clearance\n- search-all\n- discover\n- batch\n- enrich\n- skill"
             ))),
         },
     }
 }
 
 fn list_all() -> String {
-    let has_oncokb = std::env::var("ONCOKB_TOKEN")
-        .ok()
-        .map(|v| !v.trim().is_empty())
-        .unwrap_or(false);
+    let has_oncokb = crate::sources::tenant::api_key("ONCOKB_TOKEN").is_some();
 
     let mut out = LIST_REFERENCE.to_string();
 
@@ -86,11 +88,17 @@ fn list_gene() -> String {
 - `get gene <symbol> expression` - GTEx tissue expression summary
 - `get gene <symbol> hpa` - Human Protein Atlas protein tissue expression + localization
 - `get gene <symbol> druggability` - DGIdb interactions plus OpenTargets tractability/safety
+- `get gene <symbol> tractability` - full OpenTargets tractability buckets per modality plus every known safety event (opt-in)
 - `get gene <symbol> clingen` - ClinGen validity + dosage sensitivity
 - `get gene <symbol> constraint` - gnomAD gene constraint (pLI, LOEUF, mis_z, syn_z)
 - `get gene <symbol> disgenet` - DisGeNET scored gene-disease associations (requires `DISGENET_API_KEY`)
+- `get gene <symbol> clinvar-summary` - ClinVar P/LP/VUS/LB/B counts, review-status mix, and most recently evaluated variants
 - `get gene <symbol> funding` - NIH Reporter grants mentioning the gene in the most recent 5 NIH fiscal years
-- `get gene <symbol> all` - include every standard section (`funding` stays opt-in)
+- `get gene <symbol> key-papers` - up to 5 most-cited papers and recent reviews with the gene in the title (Europe PMC)
+- `get gene <symbol> dependency` - DepMap CRISPR mean gene effect, dependent cell-line count, and most dependent lineages
+- `get gene <symbol> orthologs` - mouse, rat, and zebrafish orthologs from Ensembl Compara with percent identity and Ensembl links
+- `get gene <symbol> all` - include every standard section (`funding` stays opt-in; so do `disgenet`, `clinvar-summary`, `tractability`, `key-papers`, `dependency`, and `orthologs`)
+- `get gene <symbol> --species <mouse|rat|zebrafish|taxid>` - non-human gene card from MyGene.info; only `protein` and `go` sections apply, and `all` expands to those two
 - `gene definition <symbol>` - same card as `get gene <symbol>`
 - `gene get <symbol>` - alias for `gene definition <symbol>`
 
@@ -103,6 +111,7 @@ fn list_gene() -> String {
 - `search gene -q <query> --region <chr:start-end>`
 - `search gene -q <query> --pathway <id>`
 - `search gene -q <query> --go <GO:0000000>`
+- `search gene -q <query> --species <mouse|rat|zebrafish|taxid>` (default: human)
 - `search gene -q <query> --limit <N> --offset <N>`
 
 ## Search output
@@ -112,7 +121,7 @@ fn list_gene() -> String {
 ## JSON Output
 
 - Non-empty `search gene --json` responses include `_meta.next_commands`.
-- The first follow-up drills the top result with `biomcp get gene <symbol>`.
+- The first follow-up drills the top result with `biomcp get gene <symbol>`, adding `--species` for non-human searches.
 - `biomcp list gene` is always included so agents can inspect the full filter surface.
 
 ## Helpers
@@ -121,32 +130,39 @@ fn list_gene() -> String {
 - `gene drugs <symbol>`
 - `gene articles <symbol>`
 - `gene pathways <symbol> --limit <N> --offset <N>`
+- `gene variant-map <symbol>` - ClinVar P/LP and cBioPortal somatic counts by protein position, with a coarse histogram (`--json` for lollipop-plot data)
+- `gene variants <symbol> [--significance <value>] [--format markdown|tsv] [--all]` - ClinVar variant table (HGVS c./p., significance, review stars, conditions); `--all` scrolls every record for curation spreadsheets
+- `report panel --genes <g1,g2,...>` - one markdown report across a gene panel: summaries, ClinGen/OpenTargets disease associations, CIViC actionable variants, ClinVar P/LP counts
+- `dossier --gene <symbol> --disease <name>` - evidence dossier for one gene-disease pair with a 0-100 triage score from OpenTargets, CIViC, targeting drugs, active trials, and article volume
 "#
     .to_string()
 }
 
 fn list_variant() -> String {
-    let has_oncokb = std::env::var("ONCOKB_TOKEN")
-        .ok()
-        .map(|v| !v.trim().is_empty())
-        .unwrap_or(false);
+    let has_oncokb = crate::sources::tenant::api_key("ONCOKB_TOKEN").is_some();
 
     let mut out = r#"# variant
 
 ## Commands
 
 - `get variant <id>` - core annotation (MyVariant.info)
-- `get variant <id> predict` - AlphaGenome prediction (requires `ALPHAGENOME_API_KEY`)
+- `get variant <id> predict` - AlphaGenome prediction (requires `ALPHAGENOME_API_KEY`; GRCh38-only, so pair it with `--assembly grch38`)
 - `get variant <id> predictions` - expanded dbNSFP model scores (REVEL, AlphaMissense, etc.)
 - `get variant <id> clinvar` - ClinVar section details
-- `get variant <id> population` - gnomAD population frequencies
+- `get variant <id> clinvar-submissions` - per-submitter ClinVar classifications (NCBI E-utilities)
+- `get variant <id> litvar` - publications that mention this exact variant, with PMIDs and mention sentences (NCBI LitVar2)
+- `get variant <id> population` - gnomAD population frequencies, plus per-group AC/AN/homozygotes, filters, coverage, and popmax from the gnomAD API
 - `get variant <id> conservation` - phyloP/phastCons/GERP conservation scores
 - `get variant <id> cosmic` - COSMIC context from cached MyVariant payload
 - `get variant <id> cgi` - CGI drug-association evidence table
 - `get variant <id> civic` - CIViC cached + GraphQL clinical evidence
 - `get variant <id> cbioportal` - cBioPortal frequency enrichment (on-demand)
 - `get variant <id> gwas` - GWAS trait associations
+- `get variant <id> transcripts` - per-transcript dbNSFP consequences, MANE/canonical flags, SIFT/PolyPhen
 - `get variant <id> all` - include all sections
+- `get variant <id> --source vep` - add Ensembl VEP transcript consequences, impact ratings, and canonical flags; open one with `get transcript <ENST id>`
+- `get variant <id> --assembly grch38` - read genomic HGVS input and coordinates on GRCh38 (default `grch37`); the card marks sections that are GRCh38-only or lifted over
+- `get variant <id> population --gnomad-version v2|v3|v4` - gnomAD release for the population counts (default `v4`; `v2` is GRCh37)
 
 ## Search filters
 
@@ -167,10 +183,14 @@ fn list_variant() -> String {
 - `--has <field>`
 - `--missing <field>`
 - `--therapy <name>`
+- `--rank-profile <default|clinical|research|name>`
+- `--group-by <gene|consequence|significance>`
 
 ## Search output
 
 - Includes ClinVar Stars, REVEL, and GERP in default result rows.
+- `--rank-profile` reorders results: `clinical` favors ClinVar significance and review stars, `research` favors REVEL and CADD scores. Custom profiles live in `config.toml` under `[variant.rank_profiles.<name>]`.
+- `--group-by` nests the returned page under group headers with subtotals; JSON swaps `results` for `groups[].{key,count,results}`.
 
 ## JSON Output
 
@@ -182,13 +202,21 @@ fn list_variant() -> String {
 
 Supported formats:
 - rsID: `rs113488022`
-- HGVS genomic: `chr7:g.140453136A>T`
+- HGVS genomic: `chr7:g.140453136A>T` (also `chr7:140453136A>T` and VCF-style `7-140453136-A-T`)
+- HGVS reference: `NM_000546.6:c.524G>A`, `ENST00000269305.9:c.524G>A`, `NC_000007.14:g.140753336A>T` (RefSeq `c.` descriptions match ClinVar's transcript HGVS first; the rest are projected onto the requested `--assembly` through VariantValidator)
 - Gene + protein: `BRAF V600E`, `BRAF p.Val600Glu`
+- ClinGen allele ID: `CA7200` (resolved through the ClinGen Allele Registry; `get variant` output reports the CAid for every variant it can match)
+
+Unambiguous notation slips in HGVS (lowercase bases, `->`, a missing `c.`/`g.` prefix) are corrected before lookup. Run `validate hgvs "<hgvs>"` to see why a description is rejected, with suggested corrections, or to check the reference allele before querying.
 
 ## Helpers
 
 - `variant trials <id> --source <ctgov|nci> --limit <N> --offset <N>`
 - `variant articles <id>`
+- `variant export-clinvar-template <id> --classification <term> [--condition <name>]` - ClinVar submission spreadsheet row (TSV) with suggested ACMG/AMP criteria
+- `variant compare-af <id> --cases <alt/total> --controls <alt/total>` - case/control odds ratio, Fisher exact p-value, and gnomAD frequency comparison
+- `compare variants <id1> <id2>` - side-by-side ClinVar significance, gnomAD AF, CADD, REVEL, OncoKB level, and CIViC evidence counts with differences in bold
+- `validate hgvs "<hgvs>"` (alias of `validate variant`) - HGVS syntax, reference-allele, and normalization report with suggested corrections (VariantValidator)
 "#
     .to_string();
 
@@ -215,8 +243,9 @@ fn list_article() -> String {
 
 - `get article <id>` - get by PMID/PMCID/DOI
 - `get article <id> tldr` - Semantic Scholar TLDR/influence section (optional auth; shared pool without `S2_API_KEY`)
-- `get article <id> annotations` - PubTator entity mentions
-- `get article <id> fulltext` - download/cache full text
+- `get article <id> annotations` - PubTator entity mentions plus NCT IDs cited in the abstract
+- `get article <id> fulltext` - download/cache full text (PMC first, then an Unpaywall open-access copy when `UNPAYWALL_EMAIL` is set)
+- `get article <id> citations` - most-cited citing and referenced articles with PubMed IDs (Europe PMC)
 - `get article <id> all` - include all article sections
 - `article entities <pmid> --limit <N>` - annotated entities with next commands
 - `article batch <id> [<id>...]` - compact multi-article summary cards
@@ -233,12 +262,14 @@ fn list_article() -> String {
 - `search article -k <keyword>` (or `-q <keyword>`) - free text keyword
 - `search article --type <review|research|case-reports|meta-analysis>`
 - `search article --date-from <YYYY|YYYY-MM|YYYY-MM-DD> --date-to <YYYY|YYYY-MM|YYYY-MM-DD>`
-- `search article --since <YYYY|YYYY-MM|YYYY-MM-DD>` - alias for `--date-from`
+- `search article --since <YYYY|YYYY-MM|YYYY-MM-DD>` - alias for `--date-from` (also `--published-after`)
+- `search article --published-before <YYYY|YYYY-MM|YYYY-MM-DD>` - alias for `--date-to` (also `--until`)
 - `search article --journal <name>`
+- `search article --min-quartile <Q1|Q2|Q3|Q4>` - SCImago journal quartile floor
 - `search article --open-access`
 - `search article --exclude-retracted`
 - `search article --include-retracted`
-- `search article --sort <date|citations|relevance>`
+- `search article --sort <date|citations|relevance>` - `cited` is accepted for `citations`
 - `search article --ranking-mode <lexical|semantic|hybrid>`
 - `search article --weight-semantic <float>`
 - `search article --weight-lexical <float>`
@@ -311,23 +342,29 @@ fn list_trial() -> String {
 
 - `get trial <nct_id>` - protocol card by NCT ID
 - `get trial <nct_id> eligibility` - show eligibility criteria inline
+- `get trial <nct_id> eligibility --offset <N>` - next part of long eligibility text (0-based)
 - `get trial <nct_id> locations` - site locations section
 - `get trial <nct_id> locations --offset <N> --limit <N>` - paged location slice
 - `get trial <nct_id> outcomes` - primary/secondary outcomes
 - `get trial <nct_id> arms` - arm/intervention details
 - `get trial <nct_id> references` - trial publication references
+- `get trial <nct_id> publications` - results vs protocol papers from registry links plus Europe PMC NCT-ID search
+- `get trial <nct_id> results` - posted baseline characteristics, outcome measures with effect estimates, and adverse events
 - `get trial <nct_id> all` - include every section
-- `search trial [filters]` - search ClinicalTrials.gov (default) or NCI CTS (`--source nci`)
+- `search trial [filters]` - search ClinicalTrials.gov (default), NCI CTS (`--source nci`), or EU CTIS (`--source ctis`)
+- `match trials --profile <path>` - rank recruiting ClinicalTrials.gov trials against a YAML patient profile with per-criterion explanations (CLI-only)
 
 ## Useful filters (ctgov)
 
-- `--condition <name>` (or `-c`)
+- `--condition <name>` (or `-c`; expanded with MONDO/NCIt synonyms, shown as `expanded=` in the query summary)
+- `--no-expand` (search the condition exactly as given)
 - `--intervention <name>` (or `-i`)
 - `--status <status>` (or `-s`)
 - `--phase <NA|1|1/2|2|3|4>` (or `-p`)
 - `--facility <name>`
 - `--age <years>` (decimals accepted, e.g. `0.5`)
 - `--sex <female|male|all>`
+- `--ecog <0-4>` (reads inclusion criteria; unreadable limits are kept and flagged)
 - `--mutation <text>`
 - `--criteria <text>`
 - `--biomarker <text>`
@@ -341,6 +378,7 @@ fn list_trial() -> String {
 - `--study-type <interventional|observational|...>`
 - `--date-from <YYYY-MM-DD> --date-to <YYYY-MM-DD>`
 - `--count-only`
+- `--summarize-by <condition|phase|status|sponsor>` (portfolio counts per group, with phase breakdown)
 - `--limit <N> --offset <N>`
 
 ## NCI source notes
@@ -350,6 +388,11 @@ fn list_trial() -> String {
 - `--source nci --phase 1/2` maps to CTS `I_II`; `--phase early_phase1` is not supported.
 - `--source nci --lat/--lon/--distance` uses direct `sites.org_coordinates_*` CTS filters and serializes distance with the required `mi` suffix.
 
+## EU CTIS source notes
+
+- `--source ctis` searches the EU Clinical Trials Information System by `--condition`, `--intervention`, and `--sponsor`; other filters are rejected.
+- CTIS rows are keyed by EU CT number; trials cross-registered on ClinicalTrials.gov show their NCT ID so `get trial <NCT>` works.
+
 ## JSON Output
 
 - Non-empty `search trial --json` responses include `_meta.next_commands`.
@@ -374,12 +417,13 @@ fn list_drug() -> String {
 - `get drug <name> label [--raw]` - compact FDA approved-indications summary by default; add `--raw` for the truncated FDA label text
 - `get drug <name> regulatory [--region <us|eu|who|all>]` - regional regulatory summary (Drugs@FDA, EMA, and/or WHO Prequalification)
 - `get drug <name> safety [--region <us|eu|all>]` - regional safety context (OpenFDA and/or EMA)
-- `get drug <name> shortage [--region <us|eu|all>]` - query current shortage status
+- `get drug <name> shortage [--region <us|eu|all>]` - query current shortage status (active US shortages flag open trials using the drug)
 - `get drug <name> targets` - generic targets from ChEMBL/OpenTargets plus additive CIViC variant-target annotations when available
 - `get drug <name> indications` - enrich with OpenTargets indications
-- `get drug <name> interactions` - OpenFDA label interaction text when available; otherwise a truthful public-data fallback
+- `get drug <name> interactions` - OpenFDA label interaction text when available; otherwise a truthful public-data fallback; plus lower-confidence supplement interactions from MedlinePlus monographs
 - `get drug <name> civic` - CIViC therapy evidence/assertion summary
 - `get drug <name> approvals` - Drugs@FDA approval/application details (US-only legacy section)
+- `get drug <name> patents` - informational US patent landscape from PatentsView: approximate families with estimated expiry (requires `PATENTSVIEW_API_KEY`; not part of `all`)
 - `get drug <name> all [--region <us|eu|who|all>]` - include all sections
 
 ## Search
@@ -387,6 +431,7 @@ fn list_drug() -> String {
 - `search drug <query>`
 - `search drug -q <query>`
 - `search drug <query> --region <us|eu|who|all>`
+- `search drug <query> --no-normalize` - search abbreviations such as `5-FU` as typed instead of under the NCI Thesaurus preferred name (also accepted by `get drug`)
 - `search drug --target <gene>`
 - `search drug --indication <disease>`
 - `search drug --mechanism <text>`
@@ -443,7 +488,9 @@ fn list_disease() -> String {
 - `get disease <name_or_id> civic` - CIViC disease-context evidence
 - `get disease <name_or_id> disgenet` - DisGeNET scored disease-gene associations (requires `DISGENET_API_KEY`)
 - `get disease <name_or_id> funding` - NIH Reporter grants for the requested disease phrase, or the resolved canonical name for identifier lookups, over the most recent 5 NIH fiscal years
-- `get disease <name_or_id> all` - include all standard disease sections (`funding` stays opt-in)
+- `get disease <name_or_id> epidemiology` - WHO GHO incidence/prevalence by WHO region and year for curated conditions
+- `get disease <name_or_id> therapies` - approved (first-line) and Phase 3 (second-line) drugs for the indication from OpenTargets and Drugs@FDA; derived from approvals, not curated guidelines
+- `get disease <name_or_id> all` - include all standard disease sections (`funding`, `epidemiology`, and `therapies` stay opt-in)
 - `search disease <query>` - positional search by name
 - `search disease -q <query>` - search by name
 - `search phenotype "<HP terms or symptom phrases>"` - HPO IDs or resolved symptom text to ranked diseases
@@ -452,6 +499,7 @@ fn list_disease() -> String {
 - `search disease -q <query> --phenotype <HP:...>`
 - `search disease -q <query> --onset <period>`
 - `search disease -q <query> --no-fallback` - skip discover recovery and keep the direct zero-result response
+- `search disease <query> --no-normalize` - search abbreviations such as `NSCLC` as typed instead of expanding them through the NCI Thesaurus (also accepted by `get disease`)
 - `search disease ... --limit <N> --offset <N>`
 
 ## Helpers
@@ -459,6 +507,7 @@ fn list_disease() -> String {
 - `disease trials <name>`
 - `disease articles <name>`
 - `disease drugs <name>`
+- `ontology disease <mondo_id> --children --depth <N>` - indented MONDO subtype tree from OLS (`--ancestors` walks up instead; depth 1-10)
 
 ## JSON Output
 
@@ -517,6 +566,10 @@ fn list_pgx() -> String {
 - `search pgx --cpic-level <A|B|C|D>`
 - `search pgx --pgx-testing <value>`
 - `search pgx --evidence <level>`
+- `pgx panel --genes <g1,g2,...>` - combined CPIC level A/B drug table across a gene panel
+- `pgx panel --genes <g1,g2,...> --vcf <path>` - add star-allele calls (INFO `GENE=`, `DIPLOTYPE=`, `PHENOTYPE=`) and matching CPIC recommendations (CLI-only)
+- `pgx diplotype <gene> <diplotype>` - CPIC phenotype, activity score, and drug recommendations for a star-allele diplotype
+- `pgx dose-check --drug <name> --gene <symbol> --phenotype <text>` - CPIC dosing for one phenotype cross-checked against the FDA label's gene-specific dosing text, with disagreements flagged
 - `search gwas -g <gene>` - GWAS-linked variants by gene
 - `search gwas --trait <text>` - GWAS-linked variants by disease trait
 
@@ -525,6 +578,9 @@ fn list_pgx() -> String {
 - `get pgx CYP2D6`
 - `get pgx codeine recommendations`
 - `search pgx -g CYP2D6 --limit 5`
+- `pgx panel --genes CYP2D6,CYP2C19,TPMT,DPYD`
+- `pgx diplotype CYP2D6 '*1/*4'`
+- `pgx dose-check --drug azathioprine --gene TPMT --phenotype "intermediate metabolizer"`
 - `search gwas --trait "type 2 diabetes" --limit 5`
 
 ## JSON Output
@@ -536,6 +592,38 @@ fn list_pgx() -> String {
     .to_string()
 }
 
+fn list_hla() -> String {
+    r#"# hla
+
+## Commands
+
+- `get hla <allele>` - HLA allele card with IPD-IMGT/HLA registry names and accession
+- `get hla <allele> frequencies` - population frequencies (CPIC tables compiled from Allele Frequency Net)
+- `get hla <allele> drugs` - CPIC drug hypersensitivity recommendations for carriers
+- `get hla <allele> diseases` - curated disease risk and protective associations
+- `get hla <allele> all` - include all HLA sections
+
+## Examples
+
+- `get hla "HLA-B*57:01" drugs`
+- `get hla "HLA-B*58:01" frequencies`
+- `get hla "HLA-B*27" diseases`
+- `get hla DRB1*15:01 all`
+
+## Allele names
+
+- WHO nomenclature with or without the `HLA-` prefix: `HLA-B*57:01`, `B*57:01`.
+- Allele groups (`B*27`) match every allele in the group; 3- and 4-field names match their 2-field parent.
+- Legacy colon-free names such as `B5701` are normalized to `B*57:01`.
+- Quote names in shells that expand `*`.
+
+## Related
+
+- `list pgx` - gene-level CPIC interactions and recommendations (`get pgx HLA-B`)
+"#
+    .to_string()
+}
+
 fn list_gwas() -> String {
     r#"# gwas
 
@@ -575,6 +663,37 @@ fn list_gwas() -> String {
     .to_string()
 }
 
+fn list_guidelines() -> String {
+    r#"# guidelines
+
+## Commands
+
+- `search guidelines --condition <name>` - practice guidelines for a condition
+- `search guidelines --condition <name> --org <ORG1,ORG2>` - restrict to issuing organizations
+- `search guidelines ... --limit <N> --offset <N>`
+
+Supported organizations: `USPSTF`, `NICE`, `AHA`, `ACC`, `ESC`, `IDSA`, `ASCO`, `ADA`, `WHO`.
+
+## Examples
+
+- `search guidelines --condition "atrial fibrillation" --org AHA,NICE`
+- `search guidelines --condition "colorectal cancer" --org USPSTF --limit 5`
+
+## Sources
+
+- `USPSTF` rows come from the Prevention TaskForce API and carry the task force grade (A/B/C/D/I); set `USPSTF_API_KEY`.
+- Other organizations are matched through Europe PMC practice-guideline records and report publication year without a grade.
+- Without `--org`, BioMCP searches all Europe PMC practice guidelines and adds USPSTF only when `USPSTF_API_KEY` is set.
+
+## JSON Output
+
+- Non-empty `search guidelines --json` responses include `_meta.next_commands`.
+- The first follow-up drills a literature-indexed guideline with `biomcp get article <pmid>`.
+- `biomcp list guidelines` is always included so agents can inspect the full filter surface.
+"#
+    .to_string()
+}
+
 fn list_batch() -> String {
     r#"# batch
 
@@ -589,7 +708,7 @@ fn list_batch() -> String {
 
 ## Options
 
-- `--sections <s1,s2,...>` - request specific sections on each entity
+- `--sections <s1,s2,...>` - request specific sections on each entity; `sections <entity>` lists them with sources and cost
 - `--source <ctgov|nci>` - trial source when `entity=trial` (default: `ctgov`)
 
 ## Supported entities
@@ -721,6 +840,8 @@ fn list_study() -> String {
 
 ## Commands
 
+- `search study [<query>] [--cancer-type <type>] [--limit <N>] [--offset <N>]` - search public cBioPortal studies by name or cancer type (no download needed)
+- `get study <study_id> [genes|all]` - study card from the cBioPortal API; `genes` adds the top mutated genes
 - `study list` - list locally available cBioPortal studies from `BIOMCP_STUDY_DIR`
 - `study download [--list] [<study_id>]` - list downloadable study IDs or install a study into `BIOMCP_STUDY_DIR`
 - `study top-mutated --study <id> [--limit <N>]` - rank the most frequently mutated genes in a study
@@ -741,6 +862,9 @@ fn list_study() -> String {
 
 ## Examples
 
+- `search study "lung adenocarcinoma"`
+- `search study --cancer-type brca --limit 5`
+- `get study msk_impact_2017 genes`
 - `study list`
 - `study download --list`
 - `study download msk_impact_2017`
@@ -769,12 +893,15 @@ fn list_protein() -> String {
 - `search protein -q <query> --reviewed`
 - `search protein -q <query> --disease <name>`
 - `search protein -q <query> --existence <1-5>`
+- `search protein -q <query> --organism <taxid|name>`
+- `search protein --organism <taxid> --reviewed --all [--columns <fields>]` - bulk TSV export
+- `search protein --domain <PF/IPR/SM/cd/PTHR accession> [--organism <taxid|name>]` - proteins sharing a domain (InterPro)
 - `search protein ... --limit <N> --offset <N>`
 - `get protein <accession_or_symbol>` - base protein card
 - `get protein <accession> domains` - InterPro domains
 - `get protein <accession> interactions` - STRING interactions
 - `get protein <accession> complexes` - ComplexPortal protein complexes
-- `get protein <accession> structures` - structure IDs (PDB/AlphaFold)
+- `get protein <accession> structures` - structure IDs (PDB/AlphaFold); open one with `get structure <pdb_id>`
 - `get protein <accession> all` - include all sections
 
 ## Search filters
@@ -785,12 +912,16 @@ fn list_protein() -> String {
 - `--reviewed` (default behavior uses reviewed=true for safer results)
 - `--disease <name>`
 - `--existence <1-5>`
+- `--organism <taxid|name>` (replaces the human-only default; `--reviewed` is then opt-in)
 - `--limit <N> --offset <N>`
 - `--next-page <token>` (cursor compatibility alias; `--offset` is preferred UX)
+- `--all` walks every cursor page and prints TSV; `--columns <f1,f2,...>` picks UniProt return fields
+- `--domain <accession>` (InterPro; combine with `--organism`, `--reviewed`, `--all-species`, `--limit`, `--next-page`)
 
 ## Helpers
 
 - `protein structures <accession> --limit <N> --offset <N>`
+- `network protein <accession> --depth <1-3> --min-score <0-1> --format <markdown|graphml|dot|json>` - STRING interaction graph for Cytoscape or Graphviz
 
 ## Workflow examples
 
@@ -802,6 +933,59 @@ fn list_protein() -> String {
     .to_string()
 }
 
+fn list_structure() -> String {
+    r#"# structure
+
+## Commands
+
+- `get structure <pdb_id>` - PDB entry card: method, resolution, release dates, primary citation, polymer chains with UniProt mappings, and bound ligands (RCSB PDB)
+- `search structure --uniprot <accession>` - PDB entries containing a chain mapped to the UniProt accession, best resolution first
+- `search structure --uniprot <accession> --limit <N> --offset <N>`
+
+## Examples
+
+- `get structure 6VSB`
+- `search structure --uniprot P15056 --limit 5`
+
+## Identifiers
+
+- Four-character PDB IDs (`6VSB`, case-insensitive) and extended `PDB_0000xxxx` IDs.
+- `--uniprot` takes an accession (`P15056`); resolve gene symbols first with `get protein <symbol>`.
+- NMR and other non-diffraction entries report no resolution (`-`).
+
+## Related
+
+- `list protein` - UniProt cards; `get protein <accession> structures` lists PDB and AlphaFold IDs
+"#
+    .to_string()
+}
+
+fn list_transcript() -> String {
+    r#"# transcript
+
+## Commands
+
+- `get transcript <ENST id>` - Ensembl transcript card: location, exons in transcript order, Ensembl canonical and MANE status, protein product, and RefSeq/CCDS/UniProt cross-references (Ensembl REST, GRCh38)
+
+## Examples
+
+- `get transcript ENST00000288602`
+- `get transcript ENST00000288602.11 --json`
+
+## Identifiers
+
+- Human Ensembl transcript IDs (`ENST` plus 11 digits); a version suffix such as `.11` is accepted and ignored.
+- Find transcript IDs for a variant with `get variant <id> --source vep`.
+- Markdown lists the first 50 exons; `--json` returns all of them.
+
+## Related
+
+- `list gene` - the parent gene card
+- `list protein` - UniProt cards for the translated product
+"#
+    .to_string()
+}
+
 fn list_adverse_event() -> String {
     r#"# adverse-event
 
@@ -823,17 +1007,57 @@ fn list_adverse_event() -> String {
 - `search adverse-event --type device --device <name>` - MAUDE device events
 - `search adverse-event --type device --manufacturer <name>` - MAUDE by manufacturer
 - `search adverse-event --type device --product-code <code>` - MAUDE by product code
+- `search device-clearance --product-code <code>` - 510(k) clearances for the same product code (see `list device-clearance`)
+- `search adverse-event --species veterinary --drug <name>` - animal drug adverse events (OpenFDA animal & veterinary)
+- `search adverse-event --species veterinary --drug <name> --animal <species> --reaction <term>` - filter by animal species and VeDDRA reaction term
 
 ## JSON Output
 
 - Non-empty `search adverse-event --json` responses include `_meta.next_commands`.
 - FAERS and device searches drill the top result with `biomcp get adverse-event <report_id>`.
+- Veterinary searches return `biomcp list adverse-event` only; openFDA exposes no per-report `get` for animal events.
 - Recall searches currently return `biomcp list adverse-event` without a recall-specific `get` command.
 - `biomcp list adverse-event` is always included so agents can inspect the full filter surface.
 "#
     .to_string()
 }
 
+fn list_device_clearance() -> String {
+    r#"# device-clearance
+
+## Commands
+
+- `search device-clearance --product-code <code>` - FDA 510(k) premarket notifications, newest decision first (OpenFDA)
+- `search device-clearance --device <name>` / `--applicant <name>` - substring match on device or applicant name
+- `search device-clearance ... --date-from <YYYY|YYYY-MM-DD> --date-to <YYYY|YYYY-MM-DD>` - decision date window
+- `search device-clearance ... --limit <N> --offset <N>`
+- `get device-clearance <K number>` - clearance card with the product code's classification: device class, 21 CFR regulation, implant and life-sustaining flags
+
+## Examples
+
+- `search device-clearance --product-code QBJ`
+- `search device-clearance --device "insulin pump" --date-from 2020`
+- `get device-clearance K213456`
+
+## Identifiers
+
+- 510(k) numbers are `K` plus six digits (`K213456`, case-insensitive). PMA (`P...`) and De Novo (`DEN...`) numbers are not covered.
+- Product codes are the three-letter FDA codes shared with MAUDE events and recalls.
+
+## JSON Output
+
+- Non-empty `search device-clearance --json` responses include `_meta.next_commands`.
+- The top result is drilled with `biomcp get device-clearance <k_number>`, and `biomcp list device-clearance` is always included.
+- `get device-clearance --json` lists a failed classification lookup in `_meta.section_errors` instead of failing the command.
+
+## Related
+
+- `search adverse-event --type device --product-code <code>` - MAUDE events for the same product code
+- `list adverse-event` - FAERS, MAUDE, and recall searches
+"#
+    .to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::{list_drug, list_gene, render};
@@ -868,6 +1092,43 @@ mod tests {
         assert!(out.contains("--json discover <query>"));
     }
 
+    #[test]
+    fn list_hla_page_documents_allele_forms() {
+        let out = render(Some("hla")).expect("list hla should render");
+        assert!(out.contains("# hla"));
+        assert!(out.contains("`get hla <allele> drugs`"));
+        assert!(out.contains("Legacy colon-free names"));
+        assert!(out.contains("`list pgx`"));
+    }
+
+    #[test]
+    fn list_structure_page_documents_pdb_and_uniprot_lookups() {
+        let out = render(Some("structure")).expect("list structure should render");
+        assert!(out.contains("# structure"));
+        assert!(out.contains("`get structure <pdb_id>`"));
+        assert!(out.contains("`search structure --uniprot <accession>`"));
+        assert!(out.contains("`list protein`"));
+    }
+
+    #[test]
+    fn list_device_clearance_page_documents_510k_lookups() {
+        let out = render(Some("device-clearance")).expect("list device-clearance should render");
+        assert!(out.contains("# device-clearance"));
+        assert!(out.contains("`get device-clearance <K number>`"));
+        assert!(out.contains("`search device-clearance --product-code <code>`"));
+        assert!(out.contains("`_meta.section_errors`"));
+        assert!(out.contains("`list adverse-event`"));
+    }
+
+    #[test]
+    fn list_transcript_page_documents_ensembl_lookup() {
+        let out = render(Some("transcript")).expect("list transcript should render");
+        assert!(out.contains("# transcript"));
+        assert!(out.contains("`get transcript <ENST id>`"));
+        assert!(out.contains("`get variant <id> --source vep`"));
+        assert!(out.contains("`list gene`"));
+    }
+
     #[test]
     fn list_search_all_page_mentions_counts_only_json_contract() {
         let out = render(Some("search-all")).expect("list search-all should render");
@@ -925,6 +1186,8 @@ mod tests {
         let out = render(Some("study")).expect("list study should render");
         assert!(out.contains("# study"));
         assert!(out.contains("study download [--list] [<study_id>]"));
+        assert!(out.contains("search study [<query>] [--cancer-type <type>]"));
+        assert!(out.contains("get study <study_id> [genes|all]"));
         assert!(out.contains("study top-mutated --study <id> [--limit <N>]"));
         assert!(out.contains(
             "study filter --study <id> [--mutated <symbol>] [--amplified <symbol>] [--deleted <symbol>]"
@@ -952,10 +1215,15 @@ mod tests {
         assert!(out.contains("get gene <symbol> expression"));
         assert!(out.contains("get gene <symbol> hpa"));
         assert!(out.contains("get gene <symbol> druggability"));
+        assert!(out.contains("get gene <symbol> tractability"));
         assert!(out.contains("get gene <symbol> clingen"));
         assert!(out.contains("get gene <symbol> constraint"));
         assert!(out.contains("get gene <symbol> disgenet"));
+        assert!(out.contains("get gene <symbol> clinvar-summary"));
         assert!(out.contains("get gene <symbol> funding"));
+        assert!(out.contains("get gene <symbol> key-papers"));
+        assert!(out.contains("get gene <symbol> dependency"));
+        assert!(out.contains("get gene <symbol> orthologs"));
         assert!(out.contains("`funding` stays opt-in"));
     }
 
@@ -992,6 +1260,13 @@ mod tests {
         assert!(out.contains("get drug <name> label [--raw]"));
     }
 
+    #[test]
+    fn list_drug_documents_patents_section() {
+        let out = list_drug();
+        assert!(out.contains("get drug <name> patents"));
+        assert!(out.contains("PATENTSVIEW_API_KEY"));
+    }
+
     #[test]
     fn list_disease_mentions_disgenet_section() {
         let out = render(Some("disease")).expect("list disease should render");
@@ -1006,7 +1281,9 @@ mod tests {
         assert!(out.contains("get disease <name_or_id> survival"));
         assert!(out.contains("Use `search article -d <disease>` when you need broader review"));
         assert!(out.contains("get disease <name_or_id> disgenet"));
-        assert!(out.contains("`funding` stays opt-in"));
+        assert!(out.contains("get disease <name_or_id> epidemiology"));
+        assert!(out.contains("get disease <name_or_id> therapies"));
+        assert!(out.contains("`funding`, `epidemiology`, and `therapies` stay opt-in"));
     }
 
     #[test]
@@ -1020,6 +1297,7 @@ mod tests {
     fn list_trial_and_article_include_missing_flags() {
         let trial = render(Some("trial")).expect("list trial should render");
         assert!(trial.contains("--biomarker <text>"));
+        assert!(trial.contains("match trials --profile <path>"));
         assert!(trial.contains("## NCI source notes"));
         assert!(trial.contains("## JSON Output"));
         assert!(trial.contains("`_meta.next_commands`"));
@@ -1140,6 +1418,8 @@ mod tests {
             ("pgx", "biomcp list pgx"),
             ("pathway", "biomcp list pathway"),
             ("adverse-event", "biomcp list adverse-event"),
+            ("device-clearance", "biomcp list device-clearance"),
+            ("guidelines", "biomcp list guidelines"),
         ] {
             let out = render(Some(entity)).expect("list page should render");
             assert!(
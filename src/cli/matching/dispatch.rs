@@ -0,0 +1,27 @@
+use super::MatchCommand;
+use crate::cli::CommandOutcome;
+use crate::error::BioMcpError;
+
+pub(in crate::cli) async fn handle_command(
+    cmd: MatchCommand,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let text = match cmd {
+        MatchCommand::Trials { profile, limit } => {
+            let content = std::fs::read_to_string(&profile).map_err(|err| {
+                BioMcpError::Io(std::io::Error::new(
+                    err.kind(),
+                    format!("failed to read {}: {}", profile.display(), err),
+                ))
+            })?;
+            let profile = crate::entities::trial::PatientProfile::parse(&content)?;
+            let matches = crate::entities::trial::match_trials(&profile, limit).await?;
+            if json {
+                crate::render::json::to_pretty(&matches)?
+            } else {
+                crate::render::markdown::trial_match_markdown(&matches)?
+            }
+        }
+    };
+    Ok(CommandOutcome::stdout(text))
+}
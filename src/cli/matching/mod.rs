@@ -0,0 +1,49 @@
+//! Patient-profile matching CLI subcommands.
+
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+pub enum MatchCommand {
+    /// Rank recruiting ClinicalTrials.gov trials against a patient profile
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp match trials --profile profile.yaml
+  biomcp match trials --profile profile.yaml --limit 5
+  biomcp --json match trials --profile profile.yaml
+
+PROFILE (YAML):
+  age: 62
+  sex: female
+  diagnosis: non-small cell lung cancer
+  biomarkers:
+    - EGFR L858R
+  ecog: 1
+  location:
+    city: Boston
+    country: United States
+
+Only diagnosis is required. location also accepts lat/lon, plus distance in
+miles to restrict the search to nearby sites (default scoring radius 100 mi).
+Recruiting trials for the diagnosis are searched, preferring ones that mention
+a profile biomarker, and each trial's eligibility record is checked for age,
+sex, ECOG, biomarkers (inclusion vs exclusion criteria), and site location.
+Every check is reported as met, not met, or unknown with the reason. Trials
+with an unmet criterion other than location rank last.
+See also: biomcp search trial, biomcp get trial <nct_id> eligibility")]
+    Trials {
+        /// Patient profile YAML file
+        #[arg(long, value_name = "PATH")]
+        profile: PathBuf,
+        /// Maximum trials to show (default: 10, max: 25)
+        #[arg(short, long, default_value = "10")]
+        limit: usize,
+    },
+}
+
+mod dispatch;
+pub(super) use self::dispatch::handle_command;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use super::MatchCommand;
+use crate::cli::{Cli, Commands};
+
+#[test]
+fn match_trials_parses_profile_path_and_default_limit() {
+    let cli = Cli::try_parse_from(["biomcp", "match", "trials", "--profile", "patient.yaml"])
+        .expect("match trials should parse");
+
+    let Cli {
+        command:
+            Commands::Match {
+                cmd: MatchCommand::Trials { profile, limit },
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected match trials command");
+    };
+    assert_eq!(profile, PathBuf::from("patient.yaml"));
+    assert_eq!(limit, 10);
+}
+
+#[test]
+fn match_trials_requires_profile() {
+    let err = Cli::try_parse_from(["biomcp", "match", "trials"]).expect_err("missing --profile");
+    assert!(err.to_string().contains("--profile <PATH>"));
+}
+
+#[tokio::test]
+async fn handle_command_reports_unreadable_and_invalid_profiles() {
+    let err = super::handle_command(
+        MatchCommand::Trials {
+            profile: PathBuf::from("/nonexistent/biomcp-profile.yaml"),
+            limit: 10,
+        },
+        false,
+    )
+    .await
+    .expect_err("missing file");
+    assert!(
+        err.to_string()
+            .contains("failed to read /nonexistent/biomcp-profile.yaml")
+    );
+
+    let dir = crate::cli::test_support::TempDirGuard::new("match-trials");
+    let path = dir.path().join("profile.yaml");
+    std::fs::write(&path, "age: 40\nsex: female\n").expect("write profile");
+    let err = super::handle_command(
+        MatchCommand::Trials {
+            profile: path,
+            limit: 10,
+        },
+        false,
+    )
+    .await
+    .expect_err("diagnosis missing");
+    assert!(err.to_string().contains("`diagnosis` is required"));
+}
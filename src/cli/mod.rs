@@ -1,34 +1,54 @@
 //! Top-level CLI parsing and command execution.
 
 mod adverse_event;
+mod annotate;
 mod article;
+pub mod ask;
+mod bookmark;
 pub mod cache;
 pub mod chart;
 mod commands;
+mod compare;
 pub mod debug_plan;
+mod diff;
 pub mod discover;
 mod disease;
 mod drug;
+mod exec;
 mod gene;
+mod guideline;
 mod gwas;
+pub(crate) mod history;
 pub mod health;
+mod hla;
+mod jobs;
 pub mod list;
+mod matching;
+mod network;
+mod ontology;
 mod outcome;
 mod pathway;
 mod pgx;
 mod phenotype;
 mod protein;
+mod report;
+mod schedule;
+mod selftest;
 pub mod search_all;
 mod search_all_command;
 mod shared;
 pub mod skill;
+mod snapshot;
+mod structure;
 mod study;
 mod system;
 #[cfg(test)]
 mod test_support;
+mod transcript;
 mod trial;
 mod types;
 pub mod update;
+mod validate;
 mod variant;
 
 pub use self::article::ArticleCommand;
@@ -38,21 +58,23 @@ pub use self::drug::DrugCommand;
 pub use self::gene::GeneCommand;
 #[cfg(test)]
 use self::outcome::{McpChartPass, rewrite_mcp_chart_args};
-pub use self::outcome::{execute, execute_mcp, run, run_outcome};
+pub use self::outcome::{execute, execute_mcp, run, run_outcome, run_recorded};
 pub use self::pathway::PathwayCommand;
+pub use self::pgx::PgxCommand;
 pub use self::protein::ProteinCommand;
 pub use self::shared::{build_cli, parse_cli_from_env};
 pub use self::study::StudyCommand;
 pub use self::system::{EmaCommand, WhoCommand};
 pub use self::types::{
-    ChartArgs, ChartType, Cli, CliOutput, CommandOutcome, DrugRegionArg, OutputStream,
+    ChartArgs, ChartType, Cli, CliOutput, CommandOutcome, DrugRegionArg, MarkdownThemeArg,
+    OutputStream, SpeciesArg,
 };
 pub use self::variant::VariantCommand;
 
 #[cfg(test)]
 use self::shared::RUNTIME_HELP_SUBCOMMANDS;
 use self::shared::{
-    PaginationMeta, SearchJsonMeta, empty_sections, extract_json_from_sections,
+    PaginationMeta, SearchJsonMeta, display_command, empty_sections, extract_json_from_sections,
     log_pagination_truncation, normalize_cli_query, normalize_cli_tokens, paged_fetch_limit,
     paginate_results, pagination_footer_cursor, pagination_footer_offset, related_article_filters,
     render_batch_json, resolve_query_input, search_json, search_json_with_meta, search_meta,
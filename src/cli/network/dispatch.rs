@@ -0,0 +1,40 @@
+use super::{NetworkCommand, NetworkFormat};
+use crate::cli::CommandOutcome;
+use crate::error::BioMcpError;
+
+pub(in crate::cli) async fn handle_command(
+    cmd: NetworkCommand,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let text = match cmd {
+        NetworkCommand::Protein {
+            accession,
+            depth,
+            min_score,
+            limit,
+            format,
+        } => {
+            if json && format != NetworkFormat::Markdown {
+                return Err(BioMcpError::InvalidArgument(
+                    "--format graphml, dot, and json print graph files; drop --json or use --format markdown"
+                        .into(),
+                )
+                .into());
+            }
+            let network =
+                crate::entities::network::protein_network(&accession, depth, min_score, limit)
+                    .await?;
+            if json {
+                crate::render::json::to_pretty(&network)?
+            } else {
+                match format {
+                    NetworkFormat::Markdown => crate::render::markdown::network_markdown(&network)?,
+                    NetworkFormat::Graphml => network.to_graphml(),
+                    NetworkFormat::Dot => network.to_dot(),
+                    NetworkFormat::Json => crate::render::json::to_pretty(&network.to_cytoscape())?,
+                }
+            }
+        }
+    };
+    Ok(CommandOutcome::stdout(text))
+}
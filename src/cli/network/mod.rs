@@ -0,0 +1,51 @@
+//! Interaction network CLI payloads and subcommands.
+
+use clap::{Subcommand, ValueEnum};
+
+/// Output format for `network protein`; `--json` stays the global switch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum NetworkFormat {
+    Markdown,
+    Graphml,
+    Dot,
+    Json,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum NetworkCommand {
+    /// Build a STRING protein-protein interaction graph and export it
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp network protein P15056
+  biomcp network protein P15056 --depth 2 --format graphml > braf.graphml
+  biomcp network protein TP53 --min-score 0.9 --format dot | dot -Tsvg > tp53.svg
+
+Each level asks STRING (human, taxon 9606) for the top --limit partners of
+every protein added at the previous level and keeps edges whose combined
+score is at least --min-score. Edges between partners are kept too. --format
+graphml and json (Cytoscape.js elements) import into Cytoscape; dot feeds
+Graphviz. --depth accepts 1-3; graphs stop at 300 proteins.
+See also: biomcp get protein <accession> interactions")]
+    Protein {
+        /// UniProt accession or gene symbol (e.g., P15056, BRAF)
+        accession: String,
+        /// Interaction levels to walk out from the protein (1-3)
+        #[arg(long, default_value_t = 1)]
+        depth: usize,
+        /// Minimum STRING combined score, 0-1 (0.7 = high confidence)
+        #[arg(long, default_value_t = 0.7)]
+        min_score: f64,
+        /// Partners requested per protein (default: 10, max 25)
+        #[arg(short, long, default_value_t = 10)]
+        limit: usize,
+        /// Output format: markdown (default), graphml, dot, or json (Cytoscape)
+        #[arg(long, value_enum, default_value = "markdown")]
+        format: NetworkFormat,
+    },
+}
+
+mod dispatch;
+pub(super) use self::dispatch::handle_command;
+
+#[cfg(test)]
+mod tests;
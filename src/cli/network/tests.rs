@@ -0,0 +1,48 @@
+use clap::Parser;
+
+use super::{NetworkCommand, NetworkFormat};
+use crate::cli::{Cli, Commands};
+
+#[test]
+fn network_protein_parses_export_options() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "network",
+        "protein",
+        "P15056",
+        "--depth",
+        "2",
+        "--min-score",
+        "0.9",
+        "--format",
+        "graphml",
+    ])
+    .expect("network protein should parse");
+
+    match cli.command {
+        Commands::Network {
+            cmd:
+                NetworkCommand::Protein {
+                    accession,
+                    depth,
+                    min_score,
+                    limit,
+                    format,
+                },
+        } => {
+            assert_eq!(accession, "P15056");
+            assert_eq!(depth, 2);
+            assert_eq!(min_score, 0.9);
+            assert_eq!(limit, 10);
+            assert_eq!(format, NetworkFormat::Graphml);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[test]
+fn network_protein_rejects_unknown_formats() {
+    let err = Cli::try_parse_from(["biomcp", "network", "protein", "P15056", "--format", "gml"])
+        .expect_err("gml is not a supported format");
+    assert!(err.to_string().contains("graphml"));
+}
@@ -0,0 +1,30 @@
+use super::OntologyCommand;
+use crate::cli::CommandOutcome;
+use crate::entities::ontology::OntologyDirection;
+
+pub(in crate::cli) async fn handle_command(
+    cmd: OntologyCommand,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let text = match cmd {
+        OntologyCommand::Disease {
+            id,
+            children: _,
+            ancestors,
+            depth,
+        } => {
+            let direction = if ancestors {
+                OntologyDirection::Ancestors
+            } else {
+                OntologyDirection::Children
+            };
+            let tree = crate::entities::ontology::disease_hierarchy(&id, direction, depth).await?;
+            if json {
+                crate::render::json::to_pretty(&tree)?
+            } else {
+                crate::render::markdown::ontology_tree_markdown(&tree)?
+            }
+        }
+    };
+    Ok(CommandOutcome::stdout(text))
+}
@@ -0,0 +1,39 @@
+//! Ontology CLI payloads and subcommands.
+
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+pub enum OntologyCommand {
+    /// Walk the MONDO disease hierarchy below or above a term
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp ontology disease MONDO:0005105 --children --depth 2
+  biomcp ontology disease MONDO:0005105 --ancestors --depth 3
+  biomcp --json ontology disease MONDO:0007254
+
+Terms come from the MONDO ontology served by EMBL-EBI OLS. --children (the
+default) lists subtypes; --ancestors lists parent terms up toward the root.
+MONDO terms can have several parents, so a term reached a second time is
+marked as listed above instead of being expanded again. --depth accepts 1-10;
+trees stop after 250 terms.
+See also: biomcp get disease <id>, biomcp search disease <name>")]
+    Disease {
+        /// MONDO ID (e.g., MONDO:0005105)
+        id: String,
+        /// List subtypes below the term (default)
+        #[arg(long, conflicts_with = "ancestors")]
+        children: bool,
+        /// List ancestor terms above the term
+        #[arg(long)]
+        ancestors: bool,
+        /// Number of levels to walk (1-10)
+        #[arg(long, default_value_t = 1)]
+        depth: usize,
+    },
+}
+
+mod dispatch;
+pub(super) use self::dispatch::handle_command;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,50 @@
+use clap::Parser;
+
+use super::OntologyCommand;
+use crate::cli::{Cli, Commands};
+
+#[test]
+fn ontology_disease_parses_direction_and_depth() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "ontology",
+        "disease",
+        "MONDO:0005105",
+        "--ancestors",
+        "--depth",
+        "3",
+    ])
+    .expect("ontology disease should parse");
+
+    match cli.command {
+        Commands::Ontology {
+            cmd:
+                OntologyCommand::Disease {
+                    id,
+                    children,
+                    ancestors,
+                    depth,
+                },
+        } => {
+            assert_eq!(id, "MONDO:0005105");
+            assert!(!children);
+            assert!(ancestors);
+            assert_eq!(depth, 3);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[test]
+fn ontology_disease_rejects_both_directions() {
+    let err = Cli::try_parse_from([
+        "biomcp",
+        "ontology",
+        "disease",
+        "MONDO:0005105",
+        "--children",
+        "--ancestors",
+    ])
+    .expect_err("--children and --ancestors conflict");
+    assert!(err.to_string().contains("--ancestors"));
+}
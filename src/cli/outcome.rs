@@ -4,7 +4,10 @@ use std::io::IsTerminal;
 
 use clap::Parser;
 
-use super::{Cli, CliOutput, CommandOutcome, Commands, GetEntity, SearchEntity, StudyCommand};
+use super::{
+    Cli, CliOutput, CommandOutcome, Commands, GetEntity, OutputStream, SearchEntity, StudyCommand,
+};
+use crate::render::markdown::MarkdownTheme;
 
 fn outcome_to_string(outcome: CommandOutcome) -> anyhow::Result<String> {
     if outcome.exit_code == 0 {
@@ -213,6 +216,7 @@ pub async fn run(cli: Cli) -> anyhow::Result<String> {
         command,
         json,
         no_cache,
+        ..
     } = cli;
 
     crate::sources::with_no_cache(no_cache, async move {
@@ -229,6 +233,9 @@ pub async fn run(cli: Cli) -> anyhow::Result<String> {
             Commands::Get {
                 entity: GetEntity::Pgx(args),
             } => outcome_to_string(super::pgx::handle_get(args, json).await?),
+            Commands::Get {
+                entity: GetEntity::Hla(args),
+            } => outcome_to_string(super::hla::handle_get(args, json).await?),
             Commands::Get {
                 entity: GetEntity::Trial(args),
             } => outcome_to_string(super::trial::handle_get(args, json).await?),
@@ -241,12 +248,26 @@ pub async fn run(cli: Cli) -> anyhow::Result<String> {
             Commands::Get {
                 entity: GetEntity::Pathway(args),
             } => outcome_to_string(super::pathway::handle_get(args, json).await?),
+            Commands::Get {
+                entity: GetEntity::Study(args),
+            } => outcome_to_string(super::study::handle_get(args, json).await?),
             Commands::Get {
                 entity: GetEntity::Protein(args),
             } => outcome_to_string(super::protein::handle_get(args, json).await?),
+            Commands::Get {
+                entity: GetEntity::Structure(args),
+            } => outcome_to_string(super::structure::handle_get(args, json).await?),
+            Commands::Get {
+                entity: GetEntity::Transcript(args),
+            } => outcome_to_string(super::transcript::handle_get(args, json).await?),
             Commands::Get {
                 entity: GetEntity::AdverseEvent(args),
             } => outcome_to_string(super::adverse_event::handle_get(args, json).await?),
+            Commands::Get {
+                entity: GetEntity::DeviceClearance(args),
+            } => outcome_to_string(
+                super::adverse_event::handle_device_clearance_get(args, json).await?,
+            ),
             Commands::Variant { cmd } => {
                 outcome_to_string(super::variant::handle_command(cmd, json).await?)
             }
@@ -268,9 +289,36 @@ pub async fn run(cli: Cli) -> anyhow::Result<String> {
             Commands::Protein { cmd } => {
                 outcome_to_string(super::protein::handle_command(cmd, json).await?)
             }
+            Commands::Pgx { cmd } => {
+                outcome_to_string(super::pgx::handle_command(cmd, json).await?)
+            }
+            Commands::Report { cmd } => {
+                outcome_to_string(super::report::handle_command(cmd, json).await?)
+            }
+            Commands::Compare { cmd } => {
+                outcome_to_string(super::compare::handle_command(cmd, json).await?)
+            }
+            Commands::Network { cmd } => {
+                outcome_to_string(super::network::handle_command(cmd, json).await?)
+            }
+            Commands::Ontology { cmd } => {
+                outcome_to_string(super::ontology::handle_command(cmd, json).await?)
+            }
+            Commands::Diff { cmd } => {
+                outcome_to_string(super::diff::handle_command(cmd, json).await?)
+            }
+            Commands::Annotate { cmd } => {
+                outcome_to_string(super::annotate::handle_command(cmd, json).await?)
+            }
+            Commands::Match { cmd } => {
+                outcome_to_string(super::matching::handle_command(cmd, json).await?)
+            }
             Commands::Study { cmd } => {
                 outcome_to_string(super::study::handle_command(cmd, json).await?)
             }
+            Commands::Validate { cmd } => {
+                outcome_to_string(super::validate::handle_command(cmd, json).await?)
+            }
             Commands::Batch(args) => {
                 outcome_to_string(super::system::handle_batch(args, json).await?)
             }
@@ -323,6 +371,9 @@ pub async fn run(cli: Cli) -> anyhow::Result<String> {
                 SearchEntity::Gwas(args) => {
                     outcome_to_string(super::gwas::handle_search(args, json).await?)
                 }
+                SearchEntity::Guidelines(args) => {
+                    outcome_to_string(super::guideline::handle_search(args, json).await?)
+                }
                 SearchEntity::Article(args) => {
                     outcome_to_string(super::article::handle_search(args, json).await?)
                 }
@@ -338,12 +389,21 @@ pub async fn run(cli: Cli) -> anyhow::Result<String> {
                 SearchEntity::Pathway(args) => {
                     outcome_to_string(super::pathway::handle_search(args, json).await?)
                 }
+                SearchEntity::Study(args) => {
+                    outcome_to_string(super::study::handle_search(args, json).await?)
+                }
                 SearchEntity::Protein(args) => {
                     outcome_to_string(super::protein::handle_search(args, json).await?)
                 }
+                SearchEntity::Structure(args) => {
+                    outcome_to_string(super::structure::handle_search(args, json).await?)
+                }
                 SearchEntity::AdverseEvent(args) => {
                     outcome_to_string(super::adverse_event::handle_search(args, json).await?)
                 }
+                SearchEntity::DeviceClearance(args) => outcome_to_string(
+                    super::adverse_event::handle_device_clearance_search(args, json).await?,
+                ),
             },
             Commands::Health(super::system::HealthArgs { apis_only }) => {
                 let report = crate::cli::health::check(apis_only).await?;
@@ -353,6 +413,14 @@ pub async fn run(cli: Cli) -> anyhow::Result<String> {
                     Ok(report.to_markdown())
                 }
             }
+            Commands::Selftest(args) => {
+                let report = super::selftest::run(&args).await?;
+                if json {
+                    Ok(crate::render::json::to_pretty(&report)?)
+                } else {
+                    Ok(report.to_markdown())
+                }
+            }
             Commands::Cache { cmd } => match cmd {
                 super::cache::CacheCommand::Path => Ok(crate::cli::cache::render_path()?),
                 super::cache::CacheCommand::Stats => {
@@ -375,13 +443,49 @@ pub async fn run(cli: Cli) -> anyhow::Result<String> {
                         Ok(crate::cli::cache::render_clean_text(&report))
                     }
                 }
+                super::cache::CacheCommand::Purge {
+                    older_than,
+                    dry_run,
+                } => {
+                    let report = crate::cli::cache::execute_purge(older_than, dry_run)?;
+                    if json {
+                        Ok(crate::render::json::to_pretty(&report)?)
+                    } else {
+                        Ok(crate::cli::cache::render_purge_text(&report))
+                    }
+                }
                 super::cache::CacheCommand::Clear { .. } => {
                     Err(crate::error::BioMcpError::InvalidArgument(
                         "cache clear must be executed through run_outcome()".into(),
                     )
                     .into())
                 }
+                super::cache::CacheCommand::Warm { .. } => {
+                    Err(crate::error::BioMcpError::InvalidArgument(
+                        "cache warm must be executed through run_outcome()".into(),
+                    )
+                    .into())
+                }
             },
+            Commands::Bookmark { cmd } => outcome_to_string(
+                Box::pin(super::bookmark::handle_command(cmd, json, no_cache)).await?,
+            ),
+            Commands::History { cmd } => outcome_to_string(
+                Box::pin(super::history::handle_command(cmd, json, no_cache)).await?,
+            ),
+            Commands::Schedule { cmd } => {
+                outcome_to_string(Box::pin(super::schedule::handle_command(cmd, json)).await?)
+            }
+            Commands::Jobs { cmd } => {
+                outcome_to_string(Box::pin(super::jobs::handle_command(cmd, json)).await?)
+            }
+            Commands::Exec(_) => Err(crate::error::BioMcpError::InvalidArgument(
+                "exec must be executed through run_outcome()".into(),
+            )
+            .into()),
+            Commands::Snapshot { cmd } => {
+                outcome_to_string(Box::pin(super::snapshot::handle_command(cmd, json)).await?)
+            }
             Commands::Ema { cmd } => outcome_to_string(super::system::handle_ema(cmd).await?),
             Commands::Who { cmd } => outcome_to_string(super::system::handle_who(cmd).await?),
             Commands::Skill { command } => match command {
@@ -411,12 +515,32 @@ pub async fn run(cli: Cli) -> anyhow::Result<String> {
             Commands::Enrich(args) => {
                 outcome_to_string(super::system::handle_enrich(args, json).await?)
             }
+            Commands::Dossier(args) => {
+                outcome_to_string(super::system::handle_dossier(args, json).await?)
+            }
+            Commands::Raw(args) => outcome_to_string(super::system::handle_raw(args).await?),
+            Commands::Repurpose(args) => {
+                outcome_to_string(super::system::handle_repurpose(args, json).await?)
+            }
             Commands::Discover(super::system::DiscoverArgs { query }) => {
                 crate::cli::discover::run(crate::cli::discover::DiscoverArgs { query }, json).await
             }
+            Commands::Ask(super::system::AskArgs { question, limit }) => {
+                let bundle =
+                    crate::cli::ask::run(&crate::cli::ask::AskInput { question, limit }).await?;
+                if json {
+                    Ok(crate::render::json::to_pretty(&bundle)?)
+                } else {
+                    Ok(crate::render::markdown::ask_markdown(&bundle)?)
+                }
+            }
             Commands::List(super::system::ListArgs { entity }) => {
                 crate::cli::list::render(entity.as_deref()).map_err(Into::into)
             }
+            Commands::Sections(args) => {
+                outcome_to_string(super::system::handle_sections(args).await?)
+            }
+            Commands::Schema(args) => outcome_to_string(super::system::handle_schema(args)?),
             Commands::Mcp | Commands::Serve | Commands::ServeHttp(_) | Commands::ServeSse => {
                 anyhow::bail!("MCP/serve commands should not go through CLI run()")
             }
@@ -428,19 +552,199 @@ pub async fn run(cli: Cli) -> anyhow::Result<String> {
     .await
 }
 
+/// Entity label recorded on HTTP cache entries written while serving `command`.
+fn cache_entity_label(command: &Commands) -> Option<&'static str> {
+    let label = match command {
+        Commands::Search { entity } => match entity {
+            SearchEntity::All(_) => "all",
+            SearchEntity::Gene(_) => "gene",
+            SearchEntity::Disease(_) => "disease",
+            SearchEntity::Pgx(_) => "pgx",
+            SearchEntity::Phenotype(_) => "phenotype",
+            SearchEntity::Gwas(_) => "gwas",
+            SearchEntity::Guidelines(_) => "guidelines",
+            SearchEntity::Article(_) => "article",
+            SearchEntity::Trial(_) => "trial",
+            SearchEntity::Variant(_) => "variant",
+            SearchEntity::Drug(_) => "drug",
+            SearchEntity::Pathway(_) => "pathway",
+            SearchEntity::Protein(_) => "protein",
+            SearchEntity::Structure(_) => "structure",
+            SearchEntity::Study(_) => "study",
+            SearchEntity::AdverseEvent(_) => "adverse-event",
+            SearchEntity::DeviceClearance(_) => "device-clearance",
+        },
+        Commands::Get { entity } => match entity {
+            GetEntity::Gene(_) => "gene",
+            GetEntity::Article(_) => "article",
+            GetEntity::Disease(_) => "disease",
+            GetEntity::Pgx(_) => "pgx",
+            GetEntity::Hla(_) => "hla",
+            GetEntity::Trial(_) => "trial",
+            GetEntity::Variant(_) => "variant",
+            GetEntity::Drug(_) => "drug",
+            GetEntity::Pathway(_) => "pathway",
+            GetEntity::Protein(_) => "protein",
+            GetEntity::Structure(_) => "structure",
+            GetEntity::Transcript(_) => "transcript",
+            GetEntity::Study(_) => "study",
+            GetEntity::AdverseEvent(_) => "adverse-event",
+            GetEntity::DeviceClearance(_) => "device-clearance",
+        },
+        Commands::Variant { .. } => "variant",
+        Commands::Drug { .. } => "drug",
+        Commands::Disease { .. } => "disease",
+        Commands::Article { .. } => "article",
+        Commands::Gene { .. } => "gene",
+        Commands::Pathway { .. } => "pathway",
+        Commands::Protein { .. } => "protein",
+        Commands::Pgx { .. } => "pgx",
+        Commands::Study { .. } => "study",
+        Commands::Report { .. } => "report",
+        Commands::Compare { .. } => "variant",
+        Commands::Network { .. } => "protein",
+        Commands::Ontology { .. } => "disease",
+        Commands::Dossier(_) => "gene",
+        _ => return None,
+    };
+    Some(label)
+}
+
+/// Whether `--offline` can serve `command` from the snapshot bundle.
+fn offline_supported(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Get {
+            entity: GetEntity::Gene(_) | GetEntity::Variant(_),
+        } | Commands::Search {
+            entity: SearchEntity::Gene(_) | SearchEntity::Variant(_),
+        } | Commands::List(_)
+            | Commands::Sections(_)
+            | Commands::Schema(_)
+    )
+}
+
 async fn run_outcome_inner(
     cli: Cli,
     alias_suggestions_as_json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    if cli.raw.is_some() && !matches!(cli.command, Commands::Get { .. }) {
+        return Ok(CommandOutcome::stderr_with_exit(
+            "Error: --raw applies only to get commands, e.g. biomcp get variant rs113488022 --raw myvariant"
+                .to_string(),
+            1,
+        ));
+    }
+    let offline_store = if cli.offline {
+        if !offline_supported(&cli.command) {
+            return Ok(CommandOutcome::stderr_with_exit(
+                "Error: --offline serves only get/search gene and get/search variant from the snapshot bundle; drop --offline for other commands."
+                    .to_string(),
+                1,
+            ));
+        }
+        let dir = crate::utils::snapshot::default_dir()?;
+        Some(std::sync::Arc::new(
+            crate::utils::snapshot::SnapshotStore::open(&dir)?,
+        ))
+    } else {
+        None
+    };
+    let cache_entity = cache_entity_label(&cli.command);
+    crate::utils::snapshot::with_offline(
+        offline_store,
+        crate::sources::with_cache_entity(
+            cache_entity,
+            run_outcome_scoped(cli, alias_suggestions_as_json),
+        ),
+    )
+    .await
+}
+
+async fn run_outcome_scoped(
+    mut cli: Cli,
+    alias_suggestions_as_json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let theme = cli.md_theme.map(MarkdownTheme::from);
+    let max_output_tokens = cli.max_output_tokens;
+    let raw_source = cli.raw.take();
+    let provenance = cli.provenance;
+    cli.json |= raw_source.is_some() || provenance;
+    let json = cli.json;
+    let mut outcome = if cli.verbose || provenance {
+        let verbose = cli.verbose;
+        let (outcome, stats) = crate::sources::request_stats::collect(run_outcome_captured(
+            cli,
+            raw_source,
+            alias_suggestions_as_json,
+        ))
+        .await;
+        let mut outcome = outcome?;
+        if verbose {
+            outcome.diagnostics = Some(stats.render());
+        }
+        if provenance && outcome.stream == OutputStream::Stdout && outcome.exit_code == 0 {
+            outcome.text = stats.attach_provenance(&outcome.text)?;
+        }
+        outcome
+    } else {
+        run_outcome_captured(cli, raw_source, alias_suggestions_as_json).await?
+    };
+    if let Some(theme) = theme
+        && !json
+        && outcome.stream == OutputStream::Stdout
+    {
+        outcome.text = crate::render::markdown::apply_theme(&outcome.text, theme);
+    }
+    if let Some(max_tokens) = max_output_tokens
+        && !json
+        && outcome.stream == OutputStream::Stdout
+    {
+        outcome.text =
+            crate::render::markdown::apply_output_budget(&outcome.text, max_tokens as usize);
+    }
+    Ok(outcome)
+}
+
+/// Runs the command, attaching the `--raw` source's upstream bodies to its JSON output.
+async fn run_outcome_captured(
+    cli: Cli,
+    raw_source: Option<String>,
+    alias_suggestions_as_json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let Some(source) = raw_source else {
+        return run_outcome_unthemed(cli, alias_suggestions_as_json).await;
+    };
+    let (outcome, capture) = crate::sources::raw_capture::collect(
+        &source,
+        run_outcome_unthemed(cli, alias_suggestions_as_json),
+    )
+    .await;
+    let mut outcome = outcome?;
+    if outcome.stream == OutputStream::Stdout && outcome.exit_code == 0 {
+        outcome.text = capture.attach(&outcome.text)?;
+    }
+    Ok(outcome)
+}
+
+async fn run_outcome_unthemed(
+    cli: Cli,
+    alias_suggestions_as_json: bool,
 ) -> anyhow::Result<CommandOutcome> {
     let Cli {
         command,
         json,
         no_cache,
+        verbose,
+        md_theme,
+        max_output_tokens,
+        offline,
+        ..
     } = cli;
 
     match command {
         Commands::Cache {
-            cmd: super::cache::CacheCommand::Clear { yes },
+            cmd: super::cache::CacheCommand::Clear { yes, source },
         } => {
             if !yes && !std::io::stdin().is_terminal() {
                 return Ok(CommandOutcome::stderr_with_exit(
@@ -453,13 +757,31 @@ async fn run_outcome_inner(
             let config = crate::cache::resolve_cache_config()?;
             let cache_path = config.cache_root.join("http");
 
-            let report = if yes || crate::cli::cache::prompt_clear_confirmation(&cache_path)? {
-                crate::cache::execute_cache_clear(&cache_path)?
-            } else {
+            let confirmed = yes
+                || crate::cli::cache::prompt_clear_confirmation(&cache_path, source.as_deref())?;
+            let report = if !confirmed {
                 crate::cache::ClearReport {
                     bytes_freed: None,
                     entries_removed: 0,
                 }
+            } else if let Some(source) = source {
+                let report = crate::cache::execute_cache_clear_source(&cache_path, &source)?;
+                if !report.errors.is_empty() {
+                    return Ok(CommandOutcome::stderr_with_exit(
+                        format!(
+                            "Error: biomcp cache clear --source {source} removed {} entries but failed on others: {}",
+                            report.entries_removed,
+                            report.errors.join("; ")
+                        ),
+                        1,
+                    ));
+                }
+                crate::cache::ClearReport {
+                    bytes_freed: Some(report.bytes_freed),
+                    entries_removed: report.entries_removed,
+                }
+            } else {
+                crate::cache::execute_cache_clear(&cache_path)?
             };
 
             let text = if json {
@@ -469,6 +791,39 @@ async fn run_outcome_inner(
             };
             Ok(CommandOutcome::stdout(text))
         }
+        Commands::Cache {
+            cmd:
+                super::cache::CacheCommand::Warm {
+                    genes_file,
+                    sections,
+                },
+        } => {
+            if no_cache {
+                return Ok(CommandOutcome::stderr_with_exit(
+                    "Error: biomcp cache warm cannot run with --no-cache.".to_string(),
+                    1,
+                ));
+            }
+            let report = crate::cli::cache::execute_warm(&genes_file, &sections).await?;
+            let text = if json {
+                crate::render::json::to_pretty(&report)?
+            } else {
+                crate::cli::cache::render_warm_text(&report)
+            };
+            let exit_code = if report.failed.is_empty() { 0 } else { 1 };
+            Ok(CommandOutcome::stdout_with_exit(text, exit_code))
+        }
+        Commands::Bookmark { cmd } => {
+            Box::pin(super::bookmark::handle_command(cmd, json, no_cache)).await
+        }
+        Commands::History { cmd } => {
+            Box::pin(super::history::handle_command(cmd, json, no_cache)).await
+        }
+        Commands::Schedule { cmd } => Box::pin(super::schedule::handle_command(cmd, json)).await,
+        Commands::Jobs { cmd } => Box::pin(super::jobs::handle_command(cmd, json)).await,
+        Commands::Exec(args) => Box::pin(super::exec::handle_command(args, no_cache)).await,
+        Commands::Snapshot { cmd } => Box::pin(super::snapshot::handle_command(cmd, json)).await,
+        Commands::Selftest(args) => Ok(super::selftest::handle_command(args, json).await?),
         Commands::Get {
             entity: GetEntity::Gene(args),
         } => {
@@ -545,6 +900,13 @@ async fn run_outcome_inner(
                 command,
                 json,
                 no_cache,
+                verbose,
+                md_theme,
+                max_output_tokens,
+                cache_dir: None,
+                offline,
+                raw: None,
+                provenance: false,
             })
             .await?,
         )),
@@ -555,6 +917,18 @@ pub async fn run_outcome(cli: Cli) -> anyhow::Result<CommandOutcome> {
     run_outcome_inner(cli, false).await
 }
 
+/// [`run_outcome`] for the CLI entrypoint: also records the query in the local
+/// history under `args` (the process arguments, including `biomcp`).
+pub async fn run_recorded(cli: Cli, args: Vec<String>) -> anyhow::Result<CommandOutcome> {
+    let outcome = run_outcome(cli).await;
+    let output = match &outcome {
+        Ok(outcome) if outcome.exit_code == 0 => Some(outcome.text.as_str()),
+        _ => None,
+    };
+    super::history::record_query(crate::utils::history::HistoryOrigin::Cli, &args, output);
+    outcome
+}
+
 async fn run_outcome_with_worker_stack(cli: Cli) -> anyhow::Result<CommandOutcome> {
     const EXECUTE_STACK_BYTES: usize = 8 * 1024 * 1024;
 
@@ -591,12 +965,42 @@ pub async fn execute(mut args: Vec<String>) -> anyhow::Result<String> {
     outcome_to_string(outcome)
 }
 
+/// Rejects global flags that point at workstation-local state.
+fn reject_cli_only_flags(cli: &Cli) -> anyhow::Result<()> {
+    if cli.cache_dir.is_some() {
+        return Err(crate::error::BioMcpError::InvalidArgument(
+            "--cache-dir is CLI-only; set BIOMCP_CACHE_DIR for the MCP server process instead"
+                .into(),
+        )
+        .into());
+    }
+    if cli.offline {
+        return Err(crate::error::BioMcpError::InvalidArgument(
+            "--offline is CLI-only; snapshot bundles are workstation-local".into(),
+        )
+        .into());
+    }
+    Ok(())
+}
+
 pub async fn execute_mcp(mut args: Vec<String>) -> anyhow::Result<CliOutput> {
     if args.is_empty() {
         args.push("biomcp".to_string());
     }
 
     let cli = Cli::try_parse_from(args.clone())?;
+    reject_cli_only_flags(&cli)?;
+    // The job worker runs the submitted command through `run_outcome`, so
+    // check it against the same MCP rules before it is queued.
+    if let Commands::Jobs {
+        cmd: super::jobs::JobsCommand::Submit { command },
+    } = &cli.command
+    {
+        let job = super::jobs::parse_job(command)?;
+        reject_cli_only_flags(&Cli::try_parse_from(
+            std::iter::once("biomcp".to_string()).chain(job),
+        )?)?;
+    }
     if !is_charted_mcp_study_command(&cli)? {
         let outcome = Box::pin(run_outcome_inner(cli, true)).await?;
         return Ok(CliOutput {
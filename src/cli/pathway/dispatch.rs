@@ -9,13 +9,18 @@ pub(in crate::cli) async fn handle_get(
 ) -> anyhow::Result<CommandOutcome> {
     let (sections, json_override) = super::super::extract_json_from_sections(&args.sections);
     let json_output = json || json_override;
-    let pathway = crate::entities::pathway::get(&args.id, &sections).await?;
+    let (pathway, section_errors) = crate::entities::section_errors::collect(
+        crate::entities::pathway::get(&args.id, &sections),
+    )
+    .await;
+    let pathway = pathway?;
     let text = if json_output {
         crate::render::json::to_entity_json(
             &pathway,
             crate::render::markdown::pathway_evidence_urls(&pathway),
             crate::render::markdown::related_pathway(&pathway),
             crate::render::provenance::pathway_section_sources(&pathway),
+            section_errors,
         )?
     } else {
         crate::render::markdown::pathway_markdown(&pathway, &sections)?
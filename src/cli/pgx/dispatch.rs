@@ -1,5 +1,6 @@
-use super::{PgxGetArgs, PgxSearchArgs};
+use super::{PgxCommand, PgxGetArgs, PgxSearchArgs};
 use crate::cli::CommandOutcome;
+use crate::error::BioMcpError;
 
 pub(in crate::cli) async fn handle_get(
     args: PgxGetArgs,
@@ -7,13 +8,17 @@ pub(in crate::cli) async fn handle_get(
 ) -> anyhow::Result<CommandOutcome> {
     let (sections, json_override) = super::super::extract_json_from_sections(&args.sections);
     let json_output = json || json_override;
-    let pgx = crate::entities::pgx::get(&args.query, &sections).await?;
+    let (pgx, section_errors) =
+        crate::entities::section_errors::collect(crate::entities::pgx::get(&args.query, &sections))
+            .await;
+    let pgx = pgx?;
     let text = if json_output {
         crate::render::json::to_entity_json(
             &pgx,
             crate::render::markdown::pgx_evidence_urls(&pgx),
             crate::render::markdown::related_pgx(&pgx),
             crate::render::provenance::pgx_section_sources(&pgx),
+            section_errors,
         )?
     } else {
         crate::render::markdown::pgx_markdown(&pgx, &sections)?
@@ -54,3 +59,61 @@ pub(in crate::cli) async fn handle_search(
     };
     Ok(CommandOutcome::stdout(text))
 }
+
+pub(in crate::cli) async fn handle_command(
+    cmd: PgxCommand,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let text = match cmd {
+        PgxCommand::Panel { genes, vcf } => {
+            let (calls, genotype_source) = match vcf {
+                Some(path) => {
+                    let content = std::fs::read_to_string(&path).map_err(|err| {
+                        BioMcpError::Io(std::io::Error::new(
+                            err.kind(),
+                            format!("failed to read {}: {}", path.display(), err),
+                        ))
+                    })?;
+                    let calls = crate::entities::pgx::parse_vcf_star_alleles(&content)?;
+                    if calls.is_empty() {
+                        return Err(BioMcpError::InvalidArgument(format!(
+                            "No star-allele calls found in {}. Expected INFO fields GENE= and DIPLOTYPE= (or STAR=/PHENOTYPE=).",
+                            path.display()
+                        ))
+                        .into());
+                    }
+                    (calls, Some(path.display().to_string()))
+                }
+                None => (Vec::new(), None),
+            };
+            let panel =
+                crate::entities::pgx::panel(genes.as_deref(), calls, genotype_source).await?;
+            if json {
+                crate::render::json::to_pretty(&panel)?
+            } else {
+                crate::render::markdown::pgx_panel_markdown(&panel)?
+            }
+        }
+        PgxCommand::Diplotype { gene, diplotype } => {
+            let result = crate::entities::pgx::diplotype(&gene, &diplotype).await?;
+            if json {
+                crate::render::json::to_pretty(&result)?
+            } else {
+                crate::render::markdown::pgx_diplotype_markdown(&result)?
+            }
+        }
+        PgxCommand::DoseCheck {
+            drug,
+            gene,
+            phenotype,
+        } => {
+            let check = crate::entities::pgx::dose_check(&drug, &gene, &phenotype).await?;
+            if json {
+                crate::render::json::to_pretty(&check)?
+            } else {
+                crate::render::markdown::pgx_dose_check_markdown(&check)?
+            }
+        }
+    };
+    Ok(CommandOutcome::stdout(text))
+}
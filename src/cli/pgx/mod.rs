@@ -1,6 +1,8 @@
-//! Pharmacogenomics CLI payloads.
+//! Pharmacogenomics CLI payloads and subcommands.
 
-use clap::Args;
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
 
 #[derive(Args, Debug)]
 pub struct PgxSearchArgs {
@@ -39,8 +41,72 @@ pub struct PgxGetArgs {
     pub sections: Vec<String>,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum PgxCommand {
+    /// Combined actionable-drug table for a pre-emptive PGx gene panel
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp pgx panel --genes CYP2D6,CYP2C19,TPMT,DPYD
+  biomcp pgx panel --genes CYP2D6,CYP2C19 --vcf sample.pgx.vcf
+  biomcp --json pgx panel --vcf sample.pgx.vcf
+
+Lists CPIC level A/B gene-drug pairs across the panel. With --vcf, star-allele
+calls are read from INFO fields (GENE=, DIPLOTYPE= or STAR=, optional PHENOTYPE=)
+and CPIC recommendations matching each called phenotype are attached.
+See also: biomcp list pgx")]
+    Panel {
+        /// Comma-separated gene symbols (e.g., CYP2D6,CYP2C19,TPMT,DPYD)
+        #[arg(long)]
+        genes: Option<String>,
+        /// VCF with star-allele calls in INFO (GENE=, DIPLOTYPE=, PHENOTYPE=)
+        #[arg(long, value_name = "PATH")]
+        vcf: Option<PathBuf>,
+    },
+    /// Translate a star-allele diplotype to its CPIC phenotype and drug recommendations
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp pgx diplotype CYP2D6 '*1/*4'
+  biomcp pgx diplotype CYP2C19 '*2/*17'
+  biomcp --json pgx diplotype TPMT '*1/*3A'
+
+Looks the diplotype up in CPIC's allele tables (either allele order) to get the
+phenotype and, for activity-scored genes, the total activity score, then lists
+every CPIC drug recommendation keyed on that result. Quote the diplotype so the
+shell does not expand the asterisks.
+See also: biomcp pgx dose-check --drug <name> --gene <symbol> --phenotype <text>")]
+    Diplotype {
+        /// Gene symbol (e.g., CYP2D6)
+        gene: String,
+        /// Diplotype as two star alleles (e.g., *1/*4)
+        diplotype: String,
+    },
+    /// Cross-check CPIC dosing for a genotype against the FDA label
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp pgx dose-check --drug azathioprine --gene TPMT --phenotype \"intermediate metabolizer\"
+  biomcp pgx dose-check --drug clopidogrel --gene CYP2C19 --phenotype \"poor metabolizer\"
+  biomcp --json pgx dose-check --drug codeine --gene CYP2D6 --phenotype \"ultrarapid metabolizer\"
+
+CPIC recommendations matching the phenotype are set beside sentences from the
+current FDA label that name the gene. Each side is read as avoid, reduce,
+increase, or standard dosing, and differing directions are flagged. The
+reading is keyword-based; confirm against the guideline and label text shown.
+See also: biomcp get pgx <gene> recommendations")]
+    DoseCheck {
+        /// Drug name (e.g., azathioprine)
+        #[arg(short, long)]
+        drug: String,
+        /// Gene symbol (e.g., TPMT)
+        #[arg(short, long)]
+        gene: String,
+        /// Called phenotype as CPIC words it (e.g., "intermediate metabolizer")
+        #[arg(short, long)]
+        phenotype: String,
+    },
+}
+
 mod dispatch;
-pub(super) use self::dispatch::{handle_get, handle_search};
+pub(super) use self::dispatch::{handle_command, handle_get, handle_search};
 
 #[cfg(test)]
 mod tests;
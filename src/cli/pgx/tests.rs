@@ -59,3 +59,126 @@ async fn handle_search_rejects_zero_limit_before_backend_lookup() {
         .expect_err("zero pgx limit should fail fast");
     assert!(err.to_string().contains("--limit must be between 1 and 50"));
 }
+
+#[test]
+fn pgx_panel_parses_genes_and_vcf() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "pgx",
+        "panel",
+        "--genes",
+        "CYP2D6,CYP2C19,TPMT,DPYD",
+        "--vcf",
+        "sample.pgx.vcf",
+    ])
+    .expect("pgx panel should parse");
+
+    match cli.command {
+        Commands::Pgx {
+            cmd: crate::cli::pgx::PgxCommand::Panel { genes, vcf },
+        } => {
+            assert_eq!(genes.as_deref(), Some("CYP2D6,CYP2C19,TPMT,DPYD"));
+            assert_eq!(vcf, Some(std::path::PathBuf::from("sample.pgx.vcf")));
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn pgx_panel_rejects_vcf_without_star_allele_calls() {
+    let dir = crate::cli::test_support::TempDirGuard::new("pgx-panel");
+    let path = dir.path().join("plain.vcf");
+    std::fs::write(
+        &path,
+        "##fileformat=VCFv4.2\n1\t100\t.\tA\tG\t.\tPASS\tDP=12\n",
+    )
+    .expect("write vcf");
+
+    let err = super::handle_command(
+        crate::cli::pgx::PgxCommand::Panel {
+            genes: Some("CYP2D6".into()),
+            vcf: Some(path),
+        },
+        false,
+    )
+    .await
+    .expect_err("VCF without calls should fail before CPIC lookups");
+    assert!(err.to_string().contains("No star-allele calls found"));
+}
+
+#[test]
+fn pgx_dose_check_parses_drug_gene_and_phenotype() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "pgx",
+        "dose-check",
+        "-d",
+        "azathioprine",
+        "--gene",
+        "TPMT",
+        "--phenotype",
+        "intermediate metabolizer",
+    ])
+    .expect("pgx dose-check should parse");
+
+    match cli.command {
+        Commands::Pgx {
+            cmd:
+                crate::cli::pgx::PgxCommand::DoseCheck {
+                    drug,
+                    gene,
+                    phenotype,
+                },
+        } => {
+            assert_eq!(drug, "azathioprine");
+            assert_eq!(gene, "TPMT");
+            assert_eq!(phenotype, "intermediate metabolizer");
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn pgx_dose_check_rejects_invalid_gene_before_lookups() {
+    let err = super::handle_command(
+        crate::cli::pgx::PgxCommand::DoseCheck {
+            drug: "azathioprine".into(),
+            gene: "not a gene".into(),
+            phenotype: "intermediate metabolizer".into(),
+        },
+        false,
+    )
+    .await
+    .expect_err("invalid gene should fail before CPIC lookups");
+    assert!(err.to_string().contains("Invalid gene symbol"));
+}
+
+#[test]
+fn pgx_diplotype_parses_gene_and_diplotype() {
+    let cli = Cli::try_parse_from(["biomcp", "pgx", "diplotype", "CYP2D6", "*1/*4"])
+        .expect("pgx diplotype should parse");
+
+    match cli.command {
+        Commands::Pgx {
+            cmd: crate::cli::pgx::PgxCommand::Diplotype { gene, diplotype },
+        } => {
+            assert_eq!(gene, "CYP2D6");
+            assert_eq!(diplotype, "*1/*4");
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn pgx_diplotype_rejects_malformed_diplotype_before_lookups() {
+    let err = super::handle_command(
+        crate::cli::pgx::PgxCommand::Diplotype {
+            gene: "CYP2D6".into(),
+            diplotype: "*4".into(),
+        },
+        false,
+    )
+    .await
+    .expect_err("single allele should fail before CPIC lookups");
+    assert!(err.to_string().contains("Invalid diplotype"));
+}
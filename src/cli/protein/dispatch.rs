@@ -7,13 +7,18 @@ pub(in crate::cli) async fn handle_get(
 ) -> anyhow::Result<CommandOutcome> {
     let (sections, json_override) = super::super::extract_json_from_sections(&args.sections);
     let json_output = json || json_override;
-    let protein = crate::entities::protein::get(&args.accession, &sections).await?;
+    let (protein, section_errors) = crate::entities::section_errors::collect(
+        crate::entities::protein::get(&args.accession, &sections),
+    )
+    .await;
+    let protein = protein?;
     let text = if json_output {
         crate::render::json::to_entity_json(
             &protein,
             crate::render::markdown::protein_evidence_urls(&protein),
             crate::render::markdown::related_protein(&protein, &sections),
             crate::render::provenance::protein_section_sources(&protein),
+            section_errors,
         )?
     } else {
         crate::render::markdown::protein_markdown(&protein, &sections)?
@@ -39,13 +44,54 @@ pub(in crate::cli) async fn handle_search(
         )
         .into());
     }
-    let mut query_summary = crate::entities::protein::search_query_summary(
-        &query,
-        args.reviewed,
-        args.disease.as_deref(),
-        args.existence,
-        args.all_species,
-    );
+    let filters = crate::entities::protein::ProteinSearchFilters {
+        query,
+        all_species: args.all_species,
+        reviewed: args.reviewed,
+        disease: args.disease,
+        existence: args.existence,
+        organism: args
+            .organism
+            .as_deref()
+            .map(crate::entities::protein::parse_organism)
+            .transpose()?,
+        domain: args.domain,
+    };
+    if filters.domain.is_some() {
+        let query_summary = crate::entities::protein::search_query_summary(&filters);
+        let page = crate::entities::protein::search_by_domain(&filters, args.limit, args.next_page)
+            .await?;
+        let results = page.results;
+        let pagination = super::super::PaginationMeta::cursor(
+            0,
+            args.limit,
+            results.len(),
+            page.total,
+            page.next_page_token,
+        );
+        let text = if json {
+            super::super::search_json(results, pagination)?
+        } else {
+            let footer = super::super::pagination_footer_cursor(&pagination);
+            crate::render::markdown::protein_domain_search_markdown(
+                &query_summary,
+                &results,
+                &footer,
+            )?
+        };
+        return Ok(CommandOutcome::stdout(text));
+    }
+    if args.all {
+        if json {
+            return Err(crate::error::BioMcpError::InvalidArgument(
+                "--all prints TSV; drop --json or page with --limit/--next-page instead".into(),
+            )
+            .into());
+        }
+        let tsv = crate::entities::protein::export_tsv(&filters, &args.columns).await?;
+        return Ok(CommandOutcome::stdout(tsv));
+    }
+    let mut query_summary = crate::entities::protein::search_query_summary(&filters);
     if args.offset > 0 {
         query_summary = if query_summary.is_empty() {
             format!("offset={}", args.offset)
@@ -53,17 +99,9 @@ pub(in crate::cli) async fn handle_search(
             format!("{query_summary}, offset={}", args.offset)
         };
     }
-    let page = crate::entities::protein::search_page(
-        &query,
-        args.limit,
-        args.offset,
-        args.next_page,
-        args.all_species,
-        args.reviewed,
-        args.disease.as_deref(),
-        args.existence,
-    )
-    .await?;
+    let page =
+        crate::entities::protein::search_page(&filters, args.limit, args.offset, args.next_page)
+            .await?;
     let results = page.results;
     let pagination = super::super::PaginationMeta::cursor(
         args.offset,
@@ -11,8 +11,14 @@ pub struct ProteinSearchArgs {
     #[arg(value_name = "QUERY")]
     pub positional_query: Option<String>,
     /// Include all species (default: off, human-only)
-    #[arg(long)]
+    #[arg(long, conflicts_with = "organism")]
     pub all_species: bool,
+    /// Restrict to one organism by NCBI taxonomy ID or common name (e.g., 9606, mouse)
+    #[arg(long)]
+    pub organism: Option<String>,
+    /// List proteins carrying a domain (Pfam, InterPro, SMART, CDD, or PANTHER accession; InterPro)
+    #[arg(long, conflicts_with_all = ["disease", "existence", "all", "offset"])]
+    pub domain: Option<String>,
     /// Restrict to reviewed entries
     #[arg(long)]
     pub reviewed: bool,
@@ -31,6 +37,12 @@ pub struct ProteinSearchArgs {
     /// Cursor token from a previous response
     #[arg(long = "next-page")]
     pub next_page: Option<String>,
+    /// Fetch every matching entry via cursor pagination and print TSV (ignores --limit)
+    #[arg(long, conflicts_with_all = ["offset", "next_page"])]
+    pub all: bool,
+    /// UniProt return fields for --all TSV output (comma-separated)
+    #[arg(long, value_delimiter = ',', requires = "all")]
+    pub columns: Vec<String>,
 }
 
 #[derive(Args, Debug)]
@@ -67,3 +67,63 @@ async fn handle_search_rejects_next_page_with_offset() {
             .contains("--next-page cannot be used together with --offset")
     );
 }
+
+#[test]
+fn search_protein_parses_bulk_export_flags() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "protein",
+        "--organism",
+        "9606",
+        "--reviewed",
+        "--all",
+        "--columns",
+        "accession,gene_primary",
+    ])
+    .expect("bulk protein export flags should parse");
+
+    let Commands::Search {
+        entity: SearchEntity::Protein(args),
+    } = cli.command
+    else {
+        panic!("expected protein search command");
+    };
+    assert_eq!(args.organism.as_deref(), Some("9606"));
+    assert!(args.reviewed);
+    assert!(args.all);
+    assert_eq!(args.columns, vec!["accession", "gene_primary"]);
+
+    Cli::try_parse_from(["biomcp", "search", "protein", "--columns", "accession"])
+        .expect_err("--columns should require --all");
+    Cli::try_parse_from(["biomcp", "search", "protein", "--all", "--offset", "5"])
+        .expect_err("--all should conflict with --offset");
+}
+
+#[test]
+fn search_protein_parses_domain_flag() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "protein",
+        "--domain",
+        "PF07714",
+        "--organism",
+        "human",
+    ])
+    .expect("domain search should parse");
+
+    let Commands::Search {
+        entity: SearchEntity::Protein(args),
+    } = cli.command
+    else {
+        panic!("expected protein search command");
+    };
+    assert_eq!(args.domain.as_deref(), Some("PF07714"));
+    assert_eq!(args.organism.as_deref(), Some("human"));
+
+    Cli::try_parse_from([
+        "biomcp", "search", "protein", "--domain", "PF07714", "--all",
+    ])
+    .expect_err("--domain should conflict with --all");
+}
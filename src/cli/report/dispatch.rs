@@ -0,0 +1,32 @@
+use super::ReportCommand;
+use crate::cli::CommandOutcome;
+use crate::error::BioMcpError;
+
+pub(in crate::cli) async fn handle_command(
+    cmd: ReportCommand,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let text = match cmd {
+        ReportCommand::Panel { genes, file } => {
+            let mut raw = genes.unwrap_or_default();
+            if let Some(path) = file {
+                let content = std::fs::read_to_string(&path).map_err(|err| {
+                    BioMcpError::Io(std::io::Error::new(
+                        err.kind(),
+                        format!("failed to read {}: {}", path.display(), err),
+                    ))
+                })?;
+                raw.push('\n');
+                raw.push_str(&content);
+            }
+            let genes = crate::entities::panel::parse_panel_genes(&raw)?;
+            let report = crate::entities::panel::report(&genes).await?;
+            if json {
+                crate::render::json::to_pretty(&report)?
+            } else {
+                crate::render::markdown::gene_panel_markdown(&report)?
+            }
+        }
+    };
+    Ok(CommandOutcome::stdout(text))
+}
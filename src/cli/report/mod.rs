@@ -0,0 +1,37 @@
+//! Report CLI payloads and subcommands.
+
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+pub enum ReportCommand {
+    /// Consolidated clinical report for a gene panel
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp report panel --genes BRCA1,BRCA2,TP53
+  biomcp report panel --file hboc_panel.txt > hboc_report.md
+  biomcp --json report panel --genes BRCA1,BRCA2,PALB2,ATM,CHEK2
+
+Fetches every gene concurrently with its ClinGen, CIViC, and ClinVar summary
+sections and renders one markdown report: a panel overview table, then per-gene
+summaries, disease associations (ClinGen validity, OpenTargets), and actionable
+variants (CIViC assertions and level A/B predictive evidence). Panel files list
+symbols separated by commas, whitespace, or lines; `#` starts a comment.
+Genes that fail to resolve are noted instead of failing the report.
+See also: biomcp list gene, biomcp pgx panel")]
+    Panel {
+        /// Comma-separated gene symbols (e.g., BRCA1,BRCA2,TP53)
+        #[arg(long, required_unless_present = "file")]
+        genes: Option<String>,
+        /// Panel file with one or more gene symbols per line
+        #[arg(long, value_name = "PATH")]
+        file: Option<PathBuf>,
+    },
+}
+
+mod dispatch;
+pub(super) use self::dispatch::handle_command;
+
+#[cfg(test)]
+mod tests;
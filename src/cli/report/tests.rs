@@ -0,0 +1,56 @@
+use clap::Parser;
+
+use super::ReportCommand;
+use crate::cli::{Cli, Commands};
+
+#[test]
+fn report_panel_parses_genes_and_file() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "report",
+        "panel",
+        "--genes",
+        "BRCA1,BRCA2,TP53",
+        "--file",
+        "panel.txt",
+    ])
+    .expect("report panel should parse");
+
+    match cli.command {
+        Commands::Report {
+            cmd: ReportCommand::Panel { genes, file },
+        } => {
+            assert_eq!(genes.as_deref(), Some("BRCA1,BRCA2,TP53"));
+            assert_eq!(file, Some(std::path::PathBuf::from("panel.txt")));
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[test]
+fn report_panel_requires_genes_or_file() {
+    let err = Cli::try_parse_from(["biomcp", "report", "panel"])
+        .expect_err("report panel without genes should fail");
+    assert!(err.to_string().contains("--genes"));
+}
+
+#[tokio::test]
+async fn report_panel_rejects_invalid_symbols_in_file_before_fetching() {
+    let dir = crate::cli::test_support::TempDirGuard::new("report-panel");
+    let path = dir.path().join("panel.txt");
+    std::fs::write(&path, "# HBOC\nBRCA1\nBRCA2 c.68_69delAG\n").expect("write panel");
+
+    let err = super::handle_command(
+        ReportCommand::Panel {
+            genes: None,
+            file: Some(path),
+        },
+        false,
+    )
+    .await
+    .expect_err("invalid symbol should fail before gene lookups");
+    assert!(
+        err.to_string()
+            .contains("Invalid gene symbol in panel: c.68_69delAG")
+    );
+}
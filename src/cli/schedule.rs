@@ -0,0 +1,689 @@
+//! Recurring BioMCP jobs stored in `<config_dir>/biomcp/schedules.toml`.
+//!
+//! `schedule run` executes jobs whose cron expression has fired since their
+//! last run, each in a child `biomcp` process started in the directory the job
+//! was added from; `--daemon` keeps checking until interrupted, so recurring
+//! reports need no system cron or Task Scheduler entry.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+
+use clap::{Parser, Subcommand};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tracing::{info, warn};
+
+use super::{Cli, CommandOutcome, Commands, display_command};
+use crate::error::BioMcpError;
+use crate::utils::cron::CronSchedule;
+
+const SCHEDULES_FILE: &str = "schedules.toml";
+const MAX_JOB_NAME_LEN: usize = 64;
+/// The daemon re-reads the schedule file at least this often to pick up edits.
+const MAX_DAEMON_SLEEP: Duration = Duration::from_secs(60);
+
+#[derive(Subcommand, Debug)]
+pub enum ScheduleCommand {
+    /// Schedule a BioMCP command on a cron expression (UTC)
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp schedule add --cron \"0 6 * * MON\" --job \"diff gene BRAF\"
+  biomcp schedule add --name melanoma-weekly --cron \"0 6 * * MON\" --job \"search trial -c melanoma --status recruiting\" --output weekly.md
+  biomcp schedule add --cron @daily --job \"report panel --genes BRCA1,BRCA2\" --output panel.md
+
+Cron fields are `minute hour day-of-month month day-of-week`, evaluated in UTC.")]
+    Add {
+        /// Five-field cron expression, or @hourly, @daily, @weekly, @monthly
+        #[arg(long)]
+        cron: String,
+        /// BioMCP command to run, quoted (the leading `biomcp` is optional)
+        #[arg(long)]
+        job: String,
+        /// Job name (letters, digits, '-' and '_'; defaults to job-N)
+        #[arg(long)]
+        name: Option<String>,
+        /// File that receives the job output on each run
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Replace an existing job with the same name
+        #[arg(long)]
+        force: bool,
+    },
+    /// Run jobs that are due, once, or keep running them with --daemon
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp schedule run
+  biomcp schedule run --daemon
+  RUST_LOG=info biomcp schedule run --daemon    # log each run to stderr")]
+    Run {
+        /// Keep running in the foreground and run jobs as they come due (Ctrl-C to stop)
+        #[arg(long)]
+        daemon: bool,
+    },
+    /// List scheduled jobs with their last and next runs
+    List,
+    /// Remove a scheduled job
+    Remove {
+        /// Job name
+        name: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ScheduledJob {
+    pub(crate) cron: String,
+    pub(crate) args: Vec<String>,
+    /// Working directory at `schedule add`, so relative paths inside the job resolve as typed.
+    pub(crate) dir: PathBuf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) output: Option<PathBuf>,
+    pub(crate) added_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) last_run: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) last_status: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ScheduleFile {
+    #[serde(default)]
+    pub(crate) jobs: BTreeMap<String, ScheduledJob>,
+}
+
+#[derive(Debug, Serialize)]
+struct ScheduleListEntry<'a> {
+    name: &'a str,
+    cron: &'a str,
+    command: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output: Option<&'a Path>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_run: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_status: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_run: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct JobRun {
+    name: String,
+    ok: bool,
+    status: String,
+    output: PathBuf,
+}
+
+fn schedules_path() -> Result<PathBuf, BioMcpError> {
+    crate::config::config_dir()
+        .map(|dir| dir.join(SCHEDULES_FILE))
+        .ok_or_else(|| {
+            BioMcpError::InvalidArgument(
+                "Unable to resolve a config directory for schedules".into(),
+            )
+        })
+}
+
+fn parse_schedule_file(content: &str, path: Option<&Path>) -> Result<ScheduleFile, BioMcpError> {
+    if content.trim().is_empty() {
+        return Ok(ScheduleFile::default());
+    }
+    toml::from_str::<ScheduleFile>(content).map_err(|err| {
+        crate::config::invalid_config(path, format!("failed to parse schedules: {err}"))
+    })
+}
+
+fn load_schedules(path: &Path) -> Result<ScheduleFile, BioMcpError> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => parse_schedule_file(&content, Some(path)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(ScheduleFile::default()),
+        Err(err) => Err(BioMcpError::Io(std::io::Error::new(
+            err.kind(),
+            format!("failed to read {}: {}", path.display(), err),
+        ))),
+    }
+}
+
+fn store_schedules(path: &Path, file: &ScheduleFile) -> Result<(), BioMcpError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let content = toml::to_string_pretty(file).map_err(|err| {
+        BioMcpError::InvalidArgument(format!("failed to encode schedules: {err}"))
+    })?;
+    let staging = path.with_extension("toml.tmp");
+    std::fs::write(&staging, content)?;
+    std::fs::rename(&staging, path)?;
+    Ok(())
+}
+
+fn validate_job_name(name: &str) -> Result<(), BioMcpError> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_JOB_NAME_LEN
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(BioMcpError::InvalidArgument(format!(
+            "Invalid job name '{name}'. Use 1-{MAX_JOB_NAME_LEN} letters, digits, '-' or '_'."
+        )))
+    }
+}
+
+/// Splits a quoted job into arguments and checks that it parses as a BioMCP command.
+fn parse_job(job: &str) -> Result<Vec<String>, BioMcpError> {
+    let mut args = shlex::split(job)
+        .ok_or_else(|| BioMcpError::InvalidArgument(format!("Job has unbalanced quotes: {job}")))?;
+    if args
+        .first()
+        .is_some_and(|first| first.eq_ignore_ascii_case("biomcp"))
+    {
+        args.remove(0);
+    }
+    if args.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "Job is empty. Example: biomcp schedule add --cron \"0 6 * * MON\" --job \"diff gene BRAF\"".into(),
+        ));
+    }
+    let argv = std::iter::once("biomcp".to_string()).chain(args.iter().cloned());
+    let cli = Cli::try_parse_from(argv).map_err(|err| {
+        BioMcpError::InvalidArgument(format!(
+            "Job does not parse: {}",
+            err.to_string()
+                .lines()
+                .next()
+                .unwrap_or("invalid arguments")
+        ))
+    })?;
+    if !is_schedulable(&cli.command) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "`{}` commands cannot be scheduled",
+            args[0]
+        )));
+    }
+    Ok(args)
+}
+
+/// Read-only query families, plus `diff`, whose stored snapshots are what
+/// recurring re-review compares against. Anything else is refused.
+fn is_schedulable(command: &Commands) -> bool {
+    super::bookmark::is_bookmarkable(command) || matches!(command, Commands::Diff { .. })
+}
+
+fn next_free_name(file: &ScheduleFile) -> String {
+    (1..)
+        .map(|index| format!("job-{index}"))
+        .find(|name| !file.jobs.contains_key(name))
+        .unwrap_or_else(|| "job".to_string())
+}
+
+fn unknown_job(name: &str) -> BioMcpError {
+    BioMcpError::NotFound {
+        entity: "scheduled job".into(),
+        id: name.to_string(),
+        suggestion: "List scheduled jobs with `biomcp schedule list`.".into(),
+    }
+}
+
+fn format_time(at: OffsetDateTime) -> String {
+    humantime::format_rfc3339_seconds(SystemTime::from(at)).to_string()
+}
+
+fn parse_time(value: &str) -> Option<OffsetDateTime> {
+    humantime::parse_rfc3339(value)
+        .ok()
+        .map(OffsetDateTime::from)
+}
+
+/// Next time `job` should run: the first cron match after its last run, or
+/// after it was added when it has never run.
+fn next_run(job: &ScheduledJob) -> Option<OffsetDateTime> {
+    let schedule = CronSchedule::parse(&job.cron).ok()?;
+    let since = job.last_run.as_deref().unwrap_or(&job.added_at);
+    schedule.next_after(parse_time(since)?)
+}
+
+/// Names of jobs due at `now`, in name order.
+fn due_jobs(file: &ScheduleFile, now: OffsetDateTime) -> Vec<String> {
+    file.jobs
+        .iter()
+        .filter(|(_, job)| next_run(job).is_some_and(|at| at <= now))
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+fn default_output(name: &str) -> Result<PathBuf, BioMcpError> {
+    Ok(crate::cache::resolve_cache_config()?
+        .cache_root
+        .join("schedule")
+        .join(format!("{name}.md")))
+}
+
+/// The `biomcp` process for one run of `job`. It starts in the job's directory
+/// so relative paths resolve as typed, and shares the scheduler's cache root.
+fn job_command(exe: &Path, job: &ScheduledJob, cache_root: &Path) -> Command {
+    let mut command = Command::new(exe);
+    command
+        .args(&job.args)
+        .env("BIOMCP_CACHE_DIR", cache_root)
+        // Scheduled runs stay out of the query history, as they did in-process.
+        .env_remove("BIOMCP_HISTORY")
+        .stdin(Stdio::null());
+    if job.dir.is_dir() {
+        command.current_dir(&job.dir);
+    }
+    command
+}
+
+async fn run_job(name: &str, job: &ScheduledJob) -> Result<JobRun, BioMcpError> {
+    let output = match &job.output {
+        Some(path) => path.clone(),
+        None => default_output(name)?,
+    };
+    Cli::try_parse_from(std::iter::once("biomcp".to_string()).chain(job.args.clone())).map_err(
+        |err| BioMcpError::InvalidArgument(format!("Job {name} no longer parses: {err}")),
+    )?;
+    let mut command = job_command(
+        &std::env::current_exe()?,
+        job,
+        &crate::cache::resolve_cache_config()?.cache_root,
+    );
+    let finished = tokio::task::spawn_blocking(move || command.output())
+        .await
+        .map_err(|err| BioMcpError::Io(std::io::Error::other(err)))??;
+    let stdout = String::from_utf8_lossy(&finished.stdout);
+    let stderr = String::from_utf8_lossy(&finished.stderr);
+    let ok = finished.status.success();
+    let text = if ok || stderr.trim().is_empty() {
+        stdout.trim_end().to_string()
+    } else {
+        stderr.trim_end().to_string()
+    };
+    let status = if ok {
+        "ok".to_string()
+    } else {
+        let line = text.lines().next().unwrap_or("failed").trim();
+        format!("error: {line}")
+    };
+    if let Some(parent) = output.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&output, format!("{text}\n"))?;
+    Ok(JobRun {
+        name: name.to_string(),
+        ok,
+        status,
+        output,
+    })
+}
+
+/// Runs every due job once, recording each run in the schedule file as it finishes.
+async fn run_due(path: &Path) -> Result<Vec<JobRun>, BioMcpError> {
+    let mut runs = Vec::new();
+    for name in due_jobs(&load_schedules(path)?, OffsetDateTime::now_utc()) {
+        // Re-read per job so edits made while earlier jobs ran are kept.
+        let Some(job) = load_schedules(path)?.jobs.get(&name).cloned() else {
+            continue;
+        };
+        let started = format_time(OffsetDateTime::now_utc());
+        let run = match run_job(&name, &job).await {
+            Ok(run) => run,
+            Err(err) => JobRun {
+                name: name.clone(),
+                ok: false,
+                status: format!("error: {err}"),
+                output: job.output.clone().unwrap_or_default(),
+            },
+        };
+        if run.ok {
+            info!(job = %name, output = %run.output.display(), "scheduled job finished");
+        } else {
+            warn!(job = %name, "scheduled job failed: {}", run.status);
+        }
+        let mut file = load_schedules(path)?;
+        if let Some(stored) = file.jobs.get_mut(&name) {
+            stored.last_run = Some(started);
+            stored.last_status = Some(run.status.clone());
+            store_schedules(path, &file)?;
+        }
+        runs.push(run);
+    }
+    Ok(runs)
+}
+
+fn daemon_sleep(path: &Path) -> Duration {
+    let now = OffsetDateTime::now_utc();
+    load_schedules(path)
+        .ok()
+        .and_then(|file| file.jobs.values().filter_map(next_run).min())
+        .map(|at| (at - now).try_into().unwrap_or(Duration::ZERO))
+        .unwrap_or(MAX_DAEMON_SLEEP)
+        .clamp(Duration::from_secs(1), MAX_DAEMON_SLEEP)
+}
+
+async fn run_daemon(path: &Path) -> Result<usize, BioMcpError> {
+    info!(file = %path.display(), "scheduler started");
+    let mut total = 0;
+    loop {
+        total += run_due(path).await?.len();
+        tokio::select! {
+            _ = tokio::time::sleep(daemon_sleep(path)) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(total),
+        }
+    }
+}
+
+fn render_runs(runs: &[JobRun]) -> String {
+    if runs.is_empty() {
+        return "No scheduled jobs are due. See `biomcp schedule list` for next runs.".into();
+    }
+    let mut out = format!(
+        "# Scheduled Runs ({})\n\n| Job | Status | Output |\n|---|---|---|\n",
+        runs.len()
+    );
+    for run in runs {
+        out.push_str(&format!(
+            "| {} | {} | {} |\n",
+            run.name,
+            run.status,
+            run.output.display()
+        ));
+    }
+    out
+}
+
+fn render_list(entries: &[ScheduleListEntry<'_>]) -> String {
+    if entries.is_empty() {
+        return "No jobs scheduled. Example: biomcp schedule add --cron \"0 6 * * MON\" --job \"diff gene BRAF\"".into();
+    }
+    let mut out = format!(
+        "# Scheduled Jobs ({})\n\n| Name | Cron (UTC) | Command | Last run | Next run |\n|---|---|---|---|---|\n",
+        entries.len()
+    );
+    for entry in entries {
+        let last = match (entry.last_run, entry.last_status) {
+            (Some(at), Some(status)) => format!("{at} ({status})"),
+            (Some(at), None) => at.to_string(),
+            _ => "-".to_string(),
+        };
+        out.push_str(&format!(
+            "| {} | `{}` | `{}` | {} | {} |\n",
+            entry.name,
+            entry.cron,
+            entry.command,
+            last,
+            entry.next_run.as_deref().unwrap_or("-")
+        ));
+    }
+    out
+}
+
+pub(crate) async fn handle_command(
+    cmd: ScheduleCommand,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let path = schedules_path()?;
+    match cmd {
+        ScheduleCommand::Add {
+            cron,
+            job,
+            name,
+            output,
+            force,
+        } => {
+            CronSchedule::parse(&cron)?;
+            let args = parse_job(&job)?;
+            let mut file = load_schedules(&path)?;
+            let name = match name {
+                Some(name) => name,
+                None => next_free_name(&file),
+            };
+            validate_job_name(&name)?;
+            if !force && file.jobs.contains_key(&name) {
+                return Err(BioMcpError::InvalidArgument(format!(
+                    "Job '{name}' already exists. Pass --force to replace it."
+                ))
+                .into());
+            }
+            let dir = std::env::current_dir()?;
+            let job = ScheduledJob {
+                cron,
+                args,
+                output: output.map(|output| dir.join(output)),
+                dir,
+                added_at: format_time(OffsetDateTime::now_utc()),
+                last_run: None,
+                last_status: None,
+            };
+            let next = next_run(&job).map(format_time);
+            let text = if json {
+                crate::render::json::to_pretty(&ScheduleListEntry {
+                    name: &name,
+                    cron: &job.cron,
+                    command: display_command(&job.args),
+                    output: job.output.as_deref(),
+                    last_run: None,
+                    last_status: None,
+                    next_run: next,
+                })?
+            } else {
+                format!(
+                    "Scheduled `{name}`: `{}` on `{}` (UTC)\nNext run: {}\nKeep `biomcp schedule run --daemon` running to execute it.",
+                    display_command(&job.args),
+                    job.cron,
+                    next.as_deref()
+                        .unwrap_or("never (the expression matches no date)")
+                )
+            };
+            file.jobs.insert(name, job);
+            store_schedules(&path, &file)?;
+            Ok(CommandOutcome::stdout(text))
+        }
+        ScheduleCommand::Run { daemon } => {
+            if daemon {
+                let total = run_daemon(&path).await?;
+                return Ok(CommandOutcome::stdout(format!(
+                    "Scheduler stopped after {total} job run(s)."
+                )));
+            }
+            let runs = run_due(&path).await?;
+            let text = if json {
+                crate::render::json::to_pretty(&runs)?
+            } else {
+                render_runs(&runs)
+            };
+            let exit_code = if runs.iter().all(|run| run.ok) { 0 } else { 1 };
+            Ok(CommandOutcome::stdout_with_exit(text, exit_code))
+        }
+        ScheduleCommand::List => {
+            let file = load_schedules(&path)?;
+            let entries = file
+                .jobs
+                .iter()
+                .map(|(name, job)| ScheduleListEntry {
+                    name,
+                    cron: &job.cron,
+                    command: display_command(&job.args),
+                    output: job.output.as_deref(),
+                    last_run: job.last_run.as_deref(),
+                    last_status: job.last_status.as_deref(),
+                    next_run: next_run(job).map(format_time),
+                })
+                .collect::<Vec<_>>();
+            if json {
+                return Ok(CommandOutcome::stdout(crate::render::json::to_pretty(
+                    &entries,
+                )?));
+            }
+            Ok(CommandOutcome::stdout(render_list(&entries)))
+        }
+        ScheduleCommand::Remove { name } => {
+            let mut file = load_schedules(&path)?;
+            if file.jobs.remove(&name).is_none() {
+                return Err(unknown_job(&name).into());
+            }
+            store_schedules(&path, &file)?;
+            Ok(CommandOutcome::stdout(format!(
+                "Removed scheduled job `{name}`"
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ScheduleCommand, ScheduleFile, ScheduledJob, due_jobs, job_command, next_free_name,
+        parse_job, parse_schedule_file, parse_time,
+    };
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+
+    fn argv(tokens: &[&str]) -> Vec<String> {
+        tokens.iter().map(|token| token.to_string()).collect()
+    }
+
+    fn job(cron: &str, added_at: &str, last_run: Option<&str>) -> ScheduledJob {
+        ScheduledJob {
+            cron: cron.into(),
+            args: argv(&["diff", "gene", "BRAF"]),
+            dir: "/tmp".into(),
+            output: None,
+            added_at: added_at.into(),
+            last_run: last_run.map(str::to_string),
+            last_status: None,
+        }
+    }
+
+    #[test]
+    fn schedule_add_and_run_daemon_parse() {
+        let cli = Cli::try_parse_from([
+            "biomcp",
+            "schedule",
+            "add",
+            "--cron",
+            "0 6 * * MON",
+            "--job",
+            "report panel --genes BRCA1,BRCA2",
+            "-o",
+            "weekly.md",
+        ])
+        .expect("schedule add should parse");
+        let Commands::Schedule {
+            cmd: ScheduleCommand::Add {
+                cron, job, output, ..
+            },
+        } = cli.command
+        else {
+            panic!("expected schedule add");
+        };
+        assert_eq!(cron, "0 6 * * MON");
+        assert_eq!(job, "report panel --genes BRCA1,BRCA2");
+        assert_eq!(output.as_deref(), Some(std::path::Path::new("weekly.md")));
+
+        let cli = Cli::try_parse_from(["biomcp", "schedule", "run", "--daemon"])
+            .expect("schedule run should parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Schedule {
+                cmd: ScheduleCommand::Run { daemon: true }
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_job_strips_prefix_and_rejects_unschedulable_commands() {
+        assert_eq!(
+            parse_job("biomcp search trial -c 'lung cancer'").expect("valid job"),
+            argv(&["search", "trial", "-c", "lung cancer"])
+        );
+        assert_eq!(
+            parse_job("diff gene BRAF").expect("diff stores snapshots on purpose"),
+            argv(&["diff", "gene", "BRAF"])
+        );
+        for bad in [
+            "",
+            "schedule list",
+            "serve-http",
+            "cache clear --yes",
+            "bookmark delete melanoma",
+            "jobs submit -- search trial -c melanoma",
+            "exec",
+            "study download msk_impact_2017",
+            "search nope",
+            "get gene 'BRAF",
+        ] {
+            assert!(parse_job(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn job_command_runs_in_the_job_directory_without_moving_the_scheduler() {
+        let before = std::env::current_dir().expect("cwd");
+        let dir = std::env::temp_dir();
+        let job = ScheduledJob {
+            dir: dir.clone(),
+            ..job("@daily", "2026-10-16T09:30:00Z", None)
+        };
+        let command = job_command(
+            std::path::Path::new("biomcp"),
+            &job,
+            std::path::Path::new("/var/cache/biomcp"),
+        );
+
+        assert_eq!(command.get_current_dir(), Some(dir.as_path()));
+        assert_eq!(
+            command.get_args().collect::<Vec<_>>(),
+            vec!["diff", "gene", "BRAF"]
+        );
+        assert!(
+            command
+                .get_envs()
+                .any(|(name, value)| name == "BIOMCP_CACHE_DIR"
+                    && value == Some(std::ffi::OsStr::new("/var/cache/biomcp")))
+        );
+        assert_eq!(std::env::current_dir().expect("cwd"), before);
+    }
+
+    #[test]
+    fn due_jobs_uses_last_run_or_added_time() {
+        let mut file = ScheduleFile::default();
+        // Added Friday; the Monday 06:00 run has passed by Tuesday.
+        file.jobs.insert(
+            "weekly".into(),
+            job("0 6 * * MON", "2026-10-16T09:30:00Z", None),
+        );
+        // Already ran Monday, so it is not due again until next week.
+        file.jobs.insert(
+            "done".into(),
+            job(
+                "0 6 * * MON",
+                "2026-10-01T00:00:00Z",
+                Some("2026-10-19T06:00:05Z"),
+            ),
+        );
+        file.jobs
+            .insert("daily".into(), job("@daily", "2026-10-20T00:30:00Z", None));
+
+        let now = parse_time("2026-10-20T08:00:00Z").expect("time");
+        assert_eq!(due_jobs(&file, now), vec!["weekly".to_string()]);
+        assert_eq!(next_free_name(&file), "job-1");
+    }
+
+    #[test]
+    fn schedule_file_round_trips_through_toml() {
+        let mut file = ScheduleFile::default();
+        file.jobs.insert(
+            "weekly".into(),
+            job("0 6 * * MON", "2026-10-16T09:30:00Z", None),
+        );
+        let encoded = toml::to_string_pretty(&file).expect("encode");
+        assert_eq!(parse_schedule_file(&encoded, None).expect("decode"), file);
+        assert!(parse_schedule_file("[jobs.x]\nunknown = 1\n", None).is_err());
+    }
+}
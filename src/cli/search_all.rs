@@ -663,6 +663,7 @@ fn article_filters(input: &PreparedInput) -> crate::entities::article::ArticleSe
         open_access: false,
         no_preprints: false,
         exclude_retracted: true,
+        min_quartile: None,
         max_per_source: None,
         sort: crate::entities::article::ArticleSort::Relevance,
         ranking: crate::entities::article::ArticleRankingOptions::default(),
@@ -2244,6 +2245,8 @@ mod tests {
             matched_sources: vec![ArticleSource::EuropePmc, ArticleSource::SemanticScholar],
             score: None,
             is_retracted: Some(false),
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: Some("Abstract".into()),
             ranking: Some(ArticleRankingMetadata {
                 directness_tier: 3,
@@ -2739,6 +2742,9 @@ mod tests {
             phase: None,
             conditions: Vec::new(),
             sponsor: None,
+            registry: None,
+            cross_registered_nct: None,
+            eligibility_unchecked: Vec::new(),
         }
     }
 
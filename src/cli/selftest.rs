@@ -0,0 +1,640 @@
+//! Live contract checks against upstream APIs.
+//!
+//! `selftest` sends a few canonical requests per source and checks that the
+//! fields BioMCP deserializes are still present with the expected JSON types.
+//! Unlike `health`, which only checks reachability, a source whose response
+//! shape has drifted is reported with the offending JSON paths. Requests skip
+//! the HTTP cache and honor the `BIOMCP_*_BASE` overrides.
+
+use std::time::{Duration, Instant};
+
+use clap::Args;
+use futures::future::join_all;
+use serde_json::Value;
+
+use super::CommandOutcome;
+use crate::error::BioMcpError;
+use crate::sources::clinicaltrials::{CTGOV_BASE, CTGOV_BASE_ENV};
+use crate::sources::europepmc::{EUROPE_PMC_BASE, EUROPE_PMC_BASE_ENV};
+use crate::sources::mychem::{MYCHEM_BASE, MYCHEM_BASE_ENV};
+use crate::sources::mygene::{MYGENE_BASE, MYGENE_BASE_ENV};
+use crate::sources::myvariant::{MYVARIANT_BASE, MYVARIANT_BASE_ENV};
+use crate::sources::pubmed::{PUBMED_EUTILS_BASE, PUBMED_EUTILS_BASE_ENV};
+use crate::sources::rcsb::{RCSB_DATA_BASE, RCSB_DATA_BASE_ENV};
+use crate::sources::uniprot::{UNIPROT_BASE, UNIPROT_BASE_ENV};
+
+#[derive(Args, Debug)]
+pub struct SelftestArgs {
+    /// Comma-separated sources to check (default: all)
+    #[arg(long, value_delimiter = ',')]
+    pub sources: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    String,
+    Number,
+    /// MyGene-style identifiers that arrive as either a string or a number.
+    StringOrNumber,
+    Array,
+    Object,
+}
+
+impl FieldKind {
+    fn label(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Number => "number",
+            Self::StringOrNumber => "string or number",
+            Self::Array => "array",
+            Self::Object => "object",
+        }
+    }
+
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Number => value.is_number(),
+            Self::StringOrNumber => value.is_string() || value.is_number(),
+            Self::Array => value.is_array(),
+            Self::Object => value.is_object(),
+        }
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// One expected field, addressed by JSON pointer.
+#[derive(Debug, Clone, Copy)]
+struct FieldCheck {
+    pointer: &'static str,
+    kind: FieldKind,
+}
+
+const fn field(pointer: &'static str, kind: FieldKind) -> FieldCheck {
+    FieldCheck { pointer, kind }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ContractRequest {
+    Get {
+        path: &'static str,
+    },
+    PostJson {
+        path: &'static str,
+        body: &'static str,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ContractCase {
+    name: &'static str,
+    request: ContractRequest,
+    fields: &'static [FieldCheck],
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SourceContract {
+    id: &'static str,
+    api: &'static str,
+    base: &'static str,
+    base_env: &'static str,
+    cases: &'static [ContractCase],
+}
+
+use FieldKind::{Array, Number, Object, String as Str, StringOrNumber};
+
+const SOURCE_CONTRACTS: &[SourceContract] = &[
+    SourceContract {
+        id: "mygene",
+        api: "MyGene",
+        base: MYGENE_BASE,
+        base_env: MYGENE_BASE_ENV,
+        cases: &[ContractCase {
+            name: "gene query BRAF",
+            request: ContractRequest::Get {
+                path: "/query?q=symbol:BRAF&species=human&fields=symbol,name,entrezgene,summary&size=1",
+            },
+            fields: &[
+                field("/total", Number),
+                field("/hits", Array),
+                field("/hits/0/symbol", Str),
+                field("/hits/0/name", Str),
+                field("/hits/0/entrezgene", StringOrNumber),
+                field("/hits/0/summary", Str),
+            ],
+        }],
+    },
+    SourceContract {
+        id: "myvariant",
+        api: "MyVariant",
+        base: MYVARIANT_BASE,
+        base_env: MYVARIANT_BASE_ENV,
+        cases: &[ContractCase {
+            name: "variant query rs113488022",
+            request: ContractRequest::Get {
+                path: "/query?q=dbsnp.rsid:rs113488022&fields=_id,dbsnp.rsid,cadd.phred&size=1",
+            },
+            fields: &[
+                field("/total", Number),
+                field("/hits", Array),
+                field("/hits/0/_id", Str),
+                field("/hits/0/dbsnp/rsid", Str),
+                field("/hits/0/cadd/phred", Number),
+            ],
+        }],
+    },
+    SourceContract {
+        id: "mychem",
+        api: "MyChem",
+        base: MYCHEM_BASE,
+        base_env: MYCHEM_BASE_ENV,
+        cases: &[ContractCase {
+            name: "drug query imatinib",
+            request: ContractRequest::Get {
+                path: "/query?q=imatinib&size=1",
+            },
+            fields: &[
+                field("/total", Number),
+                field("/hits", Array),
+                field("/hits/0/_id", Str),
+            ],
+        }],
+    },
+    SourceContract {
+        id: "ctgov",
+        api: "ClinicalTrials.gov",
+        base: CTGOV_BASE,
+        base_env: CTGOV_BASE_ENV,
+        cases: &[
+            ContractCase {
+                name: "trial search melanoma",
+                request: ContractRequest::Get {
+                    path: "/studies?query.cond=melanoma&countTotal=true&pageSize=1",
+                },
+                fields: &[
+                    field("/totalCount", Number),
+                    field("/studies", Array),
+                    field("/studies/0/protocolSection/identificationModule/nctId", Str),
+                ],
+            },
+            ContractCase {
+                name: "trial NCT02576665",
+                request: ContractRequest::Get {
+                    path: "/studies/NCT02576665",
+                },
+                fields: &[
+                    field("/protocolSection/identificationModule/nctId", Str),
+                    field("/protocolSection/identificationModule/briefTitle", Str),
+                    field("/protocolSection/statusModule/overallStatus", Str),
+                    field("/protocolSection/conditionsModule/conditions", Array),
+                ],
+            },
+        ],
+    },
+    SourceContract {
+        id: "pubmed",
+        api: "PubMed",
+        base: PUBMED_EUTILS_BASE,
+        base_env: PUBMED_EUTILS_BASE_ENV,
+        cases: &[ContractCase {
+            name: "esearch BRAF",
+            request: ContractRequest::Get {
+                path: "/esearch.fcgi?db=pubmed&retmode=json&retmax=1&term=BRAF",
+            },
+            fields: &[
+                field("/esearchresult/count", Str),
+                field("/esearchresult/idlist", Array),
+                field("/esearchresult/idlist/0", Str),
+            ],
+        }],
+    },
+    SourceContract {
+        id: "europepmc",
+        api: "Europe PMC",
+        base: EUROPE_PMC_BASE,
+        base_env: EUROPE_PMC_BASE_ENV,
+        cases: &[ContractCase {
+            name: "article search BRAF",
+            request: ContractRequest::Get {
+                path: "/search?query=BRAF&format=json&pageSize=1",
+            },
+            fields: &[
+                field("/hitCount", Number),
+                field("/resultList/result", Array),
+                field("/resultList/result/0/id", Str),
+                field("/resultList/result/0/source", Str),
+                field("/resultList/result/0/title", Str),
+            ],
+        }],
+    },
+    SourceContract {
+        id: "uniprot",
+        api: "UniProt",
+        base: UNIPROT_BASE,
+        base_env: UNIPROT_BASE_ENV,
+        cases: &[ContractCase {
+            name: "protein P15056",
+            request: ContractRequest::Get {
+                path: "/uniprotkb/P15056.json",
+            },
+            fields: &[
+                field("/primaryAccession", Str),
+                field("/uniProtkbId", Str),
+                field("/proteinDescription", Object),
+                field("/genes/0/geneName/value", Str),
+                field("/sequence/length", Number),
+            ],
+        }],
+    },
+    SourceContract {
+        id: "rcsb",
+        api: "RCSB PDB",
+        base: RCSB_DATA_BASE,
+        base_env: RCSB_DATA_BASE_ENV,
+        cases: &[ContractCase {
+            name: "structure 4HHB",
+            request: ContractRequest::PostJson {
+                path: "/graphql",
+                body: r#"{"query":"{ entry(entry_id: \"4HHB\") { rcsb_id struct { title } exptl { method } rcsb_accession_info { initial_release_date } polymer_entities { rcsb_polymer_entity_container_identifiers { entity_id auth_asym_ids } } } }"}"#,
+            },
+            fields: &[
+                field("/data/entry/rcsb_id", Str),
+                field("/data/entry/struct/title", Str),
+                field("/data/entry/exptl/0/method", Str),
+                field("/data/entry/rcsb_accession_info/initial_release_date", Str),
+                field(
+                    "/data/entry/polymer_entities/0/rcsb_polymer_entity_container_identifiers/auth_asym_ids",
+                    Array,
+                ),
+            ],
+        }],
+    },
+];
+
+/// Lists every expected field that is missing or has the wrong JSON type.
+fn contract_drift(body: &Value, fields: &[FieldCheck]) -> Vec<String> {
+    fields
+        .iter()
+        .filter_map(|check| match body.pointer(check.pointer) {
+            None => Some(format!("{}: missing", check.pointer)),
+            Some(value) if !check.kind.matches(value) => Some(format!(
+                "{}: expected {}, got {}",
+                check.pointer,
+                check.kind.label(),
+                json_type(value)
+            )),
+            Some(_) => None,
+        })
+        .collect()
+}
+
+fn select_contracts(requested: &[String]) -> Result<Vec<&'static SourceContract>, BioMcpError> {
+    let requested = requested
+        .iter()
+        .map(|id| id.trim().to_ascii_lowercase())
+        .filter(|id| !id.is_empty())
+        .collect::<Vec<_>>();
+    if requested.is_empty() {
+        return Ok(SOURCE_CONTRACTS.iter().collect());
+    }
+
+    let mut selected = Vec::new();
+    for id in &requested {
+        let Some(contract) = SOURCE_CONTRACTS.iter().find(|contract| contract.id == id) else {
+            let valid = SOURCE_CONTRACTS
+                .iter()
+                .map(|contract| contract.id)
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(BioMcpError::InvalidArgument(format!(
+                "Unknown selftest source \"{id}\". Valid sources: {valid}"
+            )));
+        };
+        if !selected
+            .iter()
+            .any(|seen: &&SourceContract| seen.id == contract.id)
+        {
+            selected.push(contract);
+        }
+    }
+    Ok(selected)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum SelftestStatus {
+    Ok,
+    Drift,
+    Error,
+}
+
+impl SelftestStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Drift => "drift",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub(crate) struct SelftestRow {
+    pub(crate) source: String,
+    pub(crate) api: String,
+    pub(crate) case: String,
+    pub(crate) status: SelftestStatus,
+    pub(crate) latency: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) drift: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) error: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub(crate) struct SelftestReport {
+    pub(crate) ok: usize,
+    pub(crate) drift: usize,
+    pub(crate) error: usize,
+    pub(crate) total: usize,
+    pub(crate) rows: Vec<SelftestRow>,
+}
+
+impl SelftestReport {
+    fn from_rows(rows: Vec<SelftestRow>) -> Self {
+        let count = |status: SelftestStatus| rows.iter().filter(|row| row.status == status).count();
+        Self {
+            ok: count(SelftestStatus::Ok),
+            drift: count(SelftestStatus::Drift),
+            error: count(SelftestStatus::Error),
+            total: rows.len(),
+            rows,
+        }
+    }
+
+    pub(crate) fn all_ok(&self) -> bool {
+        self.ok == self.total
+    }
+
+    pub(crate) fn to_markdown(&self) -> String {
+        let mut out = String::from("# BioMCP Self-Test\n\n");
+        out.push_str("| Source | Case | Status | Latency | Details |\n");
+        out.push_str("|--------|------|--------|---------|---------|\n");
+        for row in &self.rows {
+            let details = match (&row.error, row.drift.is_empty()) {
+                (Some(error), _) => error.replace('|', "\\|"),
+                (None, false) => row.drift.join("; "),
+                (None, true) => "-".to_string(),
+            };
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                row.api,
+                row.case,
+                row.status.as_str(),
+                row.latency,
+                details
+            ));
+        }
+        out.push_str(&format!(
+            "\nStatus: {} ok, {} drift, {} error\n",
+            self.ok, self.drift, self.error
+        ));
+        out
+    }
+}
+
+fn selftest_http_client() -> Result<reqwest::Client, BioMcpError> {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .connect_timeout(Duration::from_secs(10))
+        .user_agent(concat!("biomcp-cli/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(BioMcpError::HttpClientInit)
+}
+
+async fn run_case(
+    client: reqwest::Client,
+    base: &str,
+    contract: &SourceContract,
+    case: &ContractCase,
+) -> SelftestRow {
+    let base = base.trim_end_matches('/');
+    let request = match case.request {
+        ContractRequest::Get { path } => client.get(format!("{base}{path}")),
+        ContractRequest::PostJson { path, body } => client
+            .post(format!("{base}{path}"))
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body),
+    };
+
+    let start = Instant::now();
+    let result = async {
+        let response = request.send().await.map_err(|err| err.to_string())?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(format!("HTTP {}", status.as_u16()));
+        }
+        let bytes = response.bytes().await.map_err(|err| err.to_string())?;
+        serde_json::from_slice::<Value>(&bytes)
+            .map_err(|err| format!("response is not JSON: {err}"))
+    }
+    .await;
+    let latency = format!("{}ms", start.elapsed().as_millis());
+
+    let (status, drift, error) = match result {
+        Ok(body) => {
+            let drift = contract_drift(&body, case.fields);
+            let status = if drift.is_empty() {
+                SelftestStatus::Ok
+            } else {
+                SelftestStatus::Drift
+            };
+            (status, drift, None)
+        }
+        Err(error) => (SelftestStatus::Error, Vec::new(), Some(error)),
+    };
+    SelftestRow {
+        source: contract.id.to_string(),
+        api: contract.api.to_string(),
+        case: case.name.to_string(),
+        status,
+        latency,
+        drift,
+        error,
+    }
+}
+
+pub(crate) async fn run(args: &SelftestArgs) -> Result<SelftestReport, BioMcpError> {
+    let contracts = select_contracts(&args.sources)?;
+    let client = selftest_http_client()?;
+    let rows = join_all(contracts.into_iter().flat_map(|contract| {
+        let client = client.clone();
+        let base = crate::sources::env_base(contract.base, contract.base_env);
+        contract.cases.iter().map(move |case| {
+            let client = client.clone();
+            let base = base.clone();
+            async move { run_case(client, &base, contract, case).await }
+        })
+    }))
+    .await;
+    Ok(SelftestReport::from_rows(rows))
+}
+
+/// Runs the selected contracts; any drift or error exits with status 1.
+pub(crate) async fn handle_command(
+    args: SelftestArgs,
+    json: bool,
+) -> Result<CommandOutcome, BioMcpError> {
+    let report = run(&args).await?;
+    let text = if json {
+        crate::render::json::to_pretty(&report)?
+    } else {
+        report.to_markdown()
+    };
+    let exit_code = if report.all_ok() { 0 } else { 1 };
+    Ok(CommandOutcome::stdout_with_exit(text, exit_code))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[test]
+    fn contract_ids_are_unique_and_lowercase() {
+        let mut ids = SOURCE_CONTRACTS
+            .iter()
+            .map(|contract| contract.id)
+            .collect::<Vec<_>>();
+        assert!(ids.iter().all(|id| *id == id.to_ascii_lowercase()));
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), SOURCE_CONTRACTS.len());
+        assert!(SOURCE_CONTRACTS.iter().all(|contract| {
+            !contract.cases.is_empty()
+                && contract
+                    .cases
+                    .iter()
+                    .all(|case| case.fields.iter().all(|f| f.pointer.starts_with('/')))
+        }));
+    }
+
+    #[test]
+    fn contract_drift_reports_missing_fields_and_type_changes() {
+        let body = json!({
+            "total": "12",
+            "hits": [{"symbol": "BRAF", "entrezgene": 673}]
+        });
+        let drift = contract_drift(
+            &body,
+            &[
+                field("/total", Number),
+                field("/hits/0/symbol", Str),
+                field("/hits/0/entrezgene", StringOrNumber),
+                field("/hits/0/summary", Str),
+            ],
+        );
+        assert_eq!(
+            drift,
+            vec![
+                "/total: expected number, got string".to_string(),
+                "/hits/0/summary: missing".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn select_contracts_defaults_to_all_and_rejects_unknown_ids() {
+        assert_eq!(
+            select_contracts(&[]).expect("all").len(),
+            SOURCE_CONTRACTS.len()
+        );
+
+        let selected = select_contracts(&[
+            "MyGene".to_string(),
+            " ctgov ".to_string(),
+            "mygene".to_string(),
+        ])
+        .expect("known ids");
+        let ids = selected
+            .iter()
+            .map(|contract| contract.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["mygene", "ctgov"]);
+
+        let err = select_contracts(&["clinvar".to_string()]).expect_err("unknown id");
+        let message = err.to_string();
+        assert!(message.contains("Unknown selftest source \"clinvar\""));
+        assert!(message.contains("mygene, myvariant"));
+    }
+
+    #[tokio::test]
+    async fn run_case_classifies_ok_drift_and_http_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ok"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "x", "n": 1})))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/drift"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": 7})))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/down"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        const FIELDS: &[FieldCheck] = &[field("/id", Str), field("/n", Number)];
+        let base = server.uri();
+        let contract = SourceContract {
+            id: "mock",
+            api: "Mock",
+            base: "http://127.0.0.1",
+            base_env: "BIOMCP_SELFTEST_MOCK_BASE",
+            cases: &[],
+        };
+        let case = |name, path| ContractCase {
+            name,
+            request: ContractRequest::Get { path },
+            fields: FIELDS,
+        };
+        let client = selftest_http_client().expect("client");
+
+        let ok = run_case(client.clone(), &base, &contract, &case("ok", "/ok")).await;
+        assert_eq!(ok.status, SelftestStatus::Ok);
+
+        let drift = run_case(client.clone(), &base, &contract, &case("drift", "/drift")).await;
+        assert_eq!(drift.status, SelftestStatus::Drift);
+        assert_eq!(
+            drift.drift,
+            vec!["/id: expected string, got number", "/n: missing"]
+        );
+
+        let down = run_case(client, &base, &contract, &case("down", "/down")).await;
+        assert_eq!(down.status, SelftestStatus::Error);
+        assert_eq!(down.error.as_deref(), Some("HTTP 503"));
+
+        let report = SelftestReport::from_rows(vec![ok, drift, down]);
+        assert!(!report.all_ok());
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("| Mock | drift | drift |"));
+        assert!(markdown.contains("/id: expected string, got number; /n: missing"));
+        assert!(markdown.ends_with("Status: 1 ok, 1 drift, 1 error\n"));
+    }
+}
@@ -61,6 +61,7 @@ pub(super) fn related_article_filters() -> crate::entities::article::ArticleSear
         open_access: false,
         no_preprints: true,
         exclude_retracted: true,
+        min_quartile: None,
         max_per_source: None,
         sort: crate::entities::article::ArticleSort::Relevance,
         ranking: crate::entities::article::ArticleRankingOptions::default(),
@@ -108,6 +109,13 @@ pub(super) fn normalize_cli_tokens(values: Vec<String>) -> Option<String> {
     normalize_cli_query(Some(joined))
 }
 
+/// Renders stored arguments (without `biomcp`) as a command line a user can paste.
+pub(super) fn display_command(args: &[String]) -> String {
+    let quoted =
+        shlex::try_join(args.iter().map(String::as_str)).unwrap_or_else(|_| args.join(" "));
+    format!("biomcp {quoted}")
+}
+
 pub(super) fn resolve_query_input(
     flag_query: Option<String>,
     positional_query: Option<String>,
@@ -0,0 +1,344 @@
+//! Offline snapshot bundle creation for `--offline` runs.
+
+use std::path::{Path, PathBuf};
+
+use clap::Subcommand;
+use futures::StreamExt;
+use serde::Serialize;
+
+use super::CommandOutcome;
+use crate::entities::variant::Variant;
+use crate::error::BioMcpError;
+use crate::utils::snapshot::{SnapshotEntity, SnapshotWriter};
+
+const SNAPSHOT_FETCH_CONCURRENCY: usize = 4;
+
+#[derive(Subcommand, Debug)]
+pub enum SnapshotCommand {
+    /// Fetch genes and variants listed in files and store them as an offline bundle
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp snapshot create --entities genes.txt variants.txt
+  biomcp snapshot create --entities panel.txt --gene-sections pathways,civic
+  biomcp --offline get gene BRAF
+
+Each non-blank line names one gene symbol (BRAF) or one variant (rs113488022,
+chr7:g.140453136A>T, BRAF V600E); `#` starts a comment. Lines that fail to fetch
+are listed in the report and the command exits 1; the bundle keeps the rest.
+The bundle replaces any earlier one in the same directory.")]
+    Create {
+        /// Files listing one gene symbol or variant per line
+        #[arg(long, value_name = "PATH", num_args = 1.., required = true)]
+        entities: Vec<PathBuf>,
+
+        /// Bundle directory (default: BIOMCP_SNAPSHOT_DIR, else <cache_root>/offline-snapshot)
+        #[arg(long, value_name = "DIR")]
+        dir: Option<PathBuf>,
+
+        /// Gene sections to store as well (e.g. pathways,civic or all)
+        #[arg(long, value_delimiter = ',')]
+        gene_sections: Vec<String>,
+
+        /// Variant sections to store as well (e.g. clinvar,population or all)
+        #[arg(long, value_delimiter = ',')]
+        variant_sections: Vec<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SnapshotRequest {
+    entity: SnapshotEntity,
+    query: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct SnapshotFailure {
+    pub(crate) entity: SnapshotEntity,
+    pub(crate) query: String,
+    pub(crate) error: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct SnapshotCreateReport {
+    pub(crate) dir: String,
+    pub(crate) created_at: String,
+    pub(crate) genes: usize,
+    pub(crate) variants: usize,
+    pub(crate) failed: Vec<SnapshotFailure>,
+}
+
+/// Classifies each listed line as a variant (anything `get variant` accepts)
+/// or a gene symbol.
+fn parse_entity_file(path: &Path, raw: &str) -> Result<Vec<SnapshotRequest>, BioMcpError> {
+    let mut out = Vec::new();
+    for (index, line) in raw.lines().enumerate() {
+        let entry = line.split('#').next().unwrap_or_default().trim();
+        if entry.is_empty() {
+            continue;
+        }
+        let entity = if crate::entities::variant::parse_variant_id(entry).is_ok() {
+            SnapshotEntity::Variant
+        } else if crate::sources::is_valid_gene_symbol(entry) {
+            SnapshotEntity::Gene
+        } else {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "{}:{}: '{entry}' is neither a gene symbol nor a variant ID (rs113488022, chr7:g.140453136A>T, BRAF V600E)",
+                path.display(),
+                index + 1
+            )));
+        };
+        out.push(SnapshotRequest {
+            entity,
+            query: entry.to_string(),
+        });
+    }
+    Ok(out)
+}
+
+fn read_entity_files(paths: &[PathBuf]) -> Result<Vec<SnapshotRequest>, BioMcpError> {
+    let mut out: Vec<SnapshotRequest> = Vec::new();
+    for path in paths {
+        let raw = std::fs::read_to_string(path).map_err(|err| {
+            BioMcpError::Io(std::io::Error::new(
+                err.kind(),
+                format!("failed to read {}: {}", path.display(), err),
+            ))
+        })?;
+        for request in parse_entity_file(path, &raw)? {
+            if !out.iter().any(|seen| {
+                seen.entity == request.entity && seen.query.eq_ignore_ascii_case(&request.query)
+            }) {
+                out.push(request);
+            }
+        }
+    }
+    if out.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "No gene symbols or variants found in the --entities files".into(),
+        ));
+    }
+    Ok(out)
+}
+
+/// Lookup keys a variant answers to besides the listed name.
+fn variant_keys(query: &str, variant: &Variant) -> Vec<String> {
+    let mut keys = vec![query.to_string(), variant.id.clone()];
+    keys.extend(variant.rsid.clone());
+    keys.extend(variant.legacy_name.clone());
+    if let Some(hgvs_p) = variant.hgvs_p.as_deref() {
+        keys.push(format!("{} {hgvs_p}", variant.gene));
+        keys.push(format!(
+            "{} {}",
+            variant.gene,
+            hgvs_p.trim_start_matches("p.")
+        ));
+    }
+    keys
+}
+
+enum Fetched {
+    Gene(Box<crate::entities::gene::Gene>),
+    Variant(Box<Variant>),
+}
+
+async fn fetch(
+    request: &SnapshotRequest,
+    gene_sections: &[String],
+    variant_sections: &[String],
+) -> Result<Fetched, BioMcpError> {
+    match request.entity {
+        SnapshotEntity::Gene => crate::sources::with_cache_entity(
+            Some("gene"),
+            crate::entities::gene::get(&request.query, gene_sections),
+        )
+        .await
+        .map(|gene| Fetched::Gene(Box::new(gene))),
+        SnapshotEntity::Variant => crate::sources::with_cache_entity(
+            Some("variant"),
+            crate::entities::variant::get(&request.query, variant_sections),
+        )
+        .await
+        .map(|variant| Fetched::Variant(Box::new(variant))),
+    }
+}
+
+pub(crate) async fn execute_create(
+    entities: &[PathBuf],
+    dir: Option<&Path>,
+    gene_sections: &[String],
+    variant_sections: &[String],
+) -> Result<SnapshotCreateReport, BioMcpError> {
+    let requests = read_entity_files(entities)?;
+    let dir = match dir {
+        Some(dir) => dir.to_path_buf(),
+        None => crate::utils::snapshot::default_dir()?,
+    };
+
+    let results = futures::stream::iter(requests.iter().map(|request| async move {
+        (
+            request,
+            fetch(request, gene_sections, variant_sections).await,
+        )
+    }))
+    .buffered(SNAPSHOT_FETCH_CONCURRENCY)
+    .collect::<Vec<_>>()
+    .await;
+
+    let mut writer: Option<SnapshotWriter> = None;
+    let mut failed = Vec::new();
+    for (request, result) in results {
+        let fetched = match result {
+            Ok(fetched) => fetched,
+            Err(err) => {
+                failed.push(SnapshotFailure {
+                    entity: request.entity,
+                    query: request.query.clone(),
+                    error: err.to_string(),
+                });
+                continue;
+            }
+        };
+        if writer.is_none() {
+            writer = Some(SnapshotWriter::create(&dir)?);
+        }
+        let Some(writer) = writer.as_mut() else {
+            continue;
+        };
+        match fetched {
+            Fetched::Gene(gene) => writer.add(
+                SnapshotEntity::Gene,
+                &[request.query.as_str(), gene.symbol.as_str()],
+                gene_sections,
+                &gene,
+            )?,
+            Fetched::Variant(variant) => {
+                let keys = variant_keys(&request.query, &variant);
+                let keys = keys.iter().map(String::as_str).collect::<Vec<_>>();
+                writer.add(SnapshotEntity::Variant, &keys, variant_sections, &variant)?
+            }
+        }
+    }
+
+    // Leave any earlier bundle in place when nothing could be fetched.
+    let Some(writer) = writer else {
+        let first = failed
+            .first()
+            .map(|failure| format!(" First error: {}: {}", failure.query, failure.error))
+            .unwrap_or_default();
+        return Err(BioMcpError::SourceUnavailable {
+            source_name: "snapshot create".into(),
+            reason: format!(
+                "None of the {} listed entities could be fetched.{first}",
+                failed.len()
+            ),
+            suggestion: "biomcp health --apis-only".into(),
+        });
+    };
+    let manifest = writer
+        .finish(humantime::format_rfc3339_seconds(std::time::SystemTime::now()).to_string())?;
+    Ok(SnapshotCreateReport {
+        dir: dir.display().to_string(),
+        created_at: manifest.created_at.clone(),
+        genes: manifest.count(SnapshotEntity::Gene),
+        variants: manifest.count(SnapshotEntity::Variant),
+        failed,
+    })
+}
+
+pub(crate) fn render_create_text(report: &SnapshotCreateReport) -> String {
+    let mut out = format!(
+        "Snapshot created: {}\ngenes={} variants={} failed={}",
+        report.dir,
+        report.genes,
+        report.variants,
+        report.failed.len()
+    );
+    for failure in &report.failed {
+        out.push_str(&format!(
+            "\n- {} {}: {}",
+            failure.entity.as_str(),
+            failure.query,
+            failure.error
+        ));
+    }
+    out
+}
+
+/// Runs a snapshot command; any failed entity exits with status 1.
+pub(crate) async fn handle_command(
+    cmd: SnapshotCommand,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    match cmd {
+        SnapshotCommand::Create {
+            entities,
+            dir,
+            gene_sections,
+            variant_sections,
+        } => {
+            let report =
+                execute_create(&entities, dir.as_deref(), &gene_sections, &variant_sections)
+                    .await?;
+            let text = if json {
+                crate::render::json::to_pretty(&report)?
+            } else {
+                render_create_text(&report)
+            };
+            let exit_code = if report.failed.is_empty() { 0 } else { 1 };
+            Ok(CommandOutcome::stdout_with_exit(text, exit_code))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn parse_entity_file_classifies_genes_and_variants() {
+        let raw =
+            "# melanoma panel\nBRAF\n\nBRAF V600E  # hotspot\nrs113488022\nchr7:g.140453136A>T\n";
+        let requests = parse_entity_file(Path::new("panel.txt"), raw).expect("parse");
+        let summary = requests
+            .iter()
+            .map(|request| (request.entity, request.query.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (SnapshotEntity::Gene, "BRAF"),
+                (SnapshotEntity::Variant, "BRAF V600E"),
+                (SnapshotEntity::Variant, "rs113488022"),
+                (SnapshotEntity::Variant, "chr7:g.140453136A>T"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_entity_file_reports_line_of_unrecognized_entry() {
+        let err = parse_entity_file(Path::new("genes.txt"), "BRAF\nnot a gene?\n")
+            .expect_err("invalid line");
+        assert!(err.to_string().contains("genes.txt:2: 'not a gene?'"));
+    }
+
+    #[test]
+    fn render_create_text_lists_failures() {
+        let text = render_create_text(&SnapshotCreateReport {
+            dir: "/tmp/bundle".into(),
+            created_at: "2026-10-16T00:00:00Z".into(),
+            genes: 1,
+            variants: 2,
+            failed: vec![SnapshotFailure {
+                entity: SnapshotEntity::Gene,
+                query: "NOTAGENE1".into(),
+                error: "gene not found".into(),
+            }],
+        });
+        assert_eq!(
+            text,
+            "Snapshot created: /tmp/bundle\ngenes=1 variants=2 failed=1\n- gene NOTAGENE1: gene not found"
+        );
+    }
+}
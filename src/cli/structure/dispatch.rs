@@ -0,0 +1,49 @@
+use super::{StructureGetArgs, StructureSearchArgs};
+use crate::cli::CommandOutcome;
+
+pub(in crate::cli) async fn handle_get(
+    args: StructureGetArgs,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let structure = crate::entities::structure::get(&args.pdb_id).await?;
+    let text = if json {
+        crate::render::json::to_entity_json(
+            &structure,
+            crate::render::markdown::structure_evidence_urls(&structure),
+            crate::render::markdown::related_structure(&structure),
+            crate::render::provenance::structure_section_sources(&structure),
+            Vec::new(),
+        )?
+    } else {
+        crate::render::markdown::structure_markdown(&structure)?
+    };
+    Ok(CommandOutcome::stdout(text))
+}
+
+pub(in crate::cli) async fn handle_search(
+    args: StructureSearchArgs,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let mut query_summary = format!("uniprot={}", args.uniprot.trim().to_ascii_uppercase());
+    if args.offset > 0 {
+        query_summary = format!("{query_summary}, offset={}", args.offset);
+    }
+    let page =
+        crate::entities::structure::search_by_uniprot(&args.uniprot, args.limit, args.offset)
+            .await?;
+    let results = page.results;
+    let pagination =
+        super::super::PaginationMeta::offset(args.offset, args.limit, results.len(), page.total);
+    let text = if json {
+        super::super::search_json(results, pagination)?
+    } else {
+        let footer = super::super::pagination_footer_offset(&pagination);
+        crate::render::markdown::structure_search_markdown_with_footer(
+            &query_summary,
+            &results,
+            page.total,
+            &footer,
+        )?
+    };
+    Ok(CommandOutcome::stdout(text))
+}
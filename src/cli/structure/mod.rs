@@ -0,0 +1,28 @@
+//! PDB structure CLI payloads.
+
+use clap::Args;
+
+#[derive(Args, Debug)]
+pub struct StructureSearchArgs {
+    /// UniProt accession whose PDB entries to list (e.g., P15056)
+    #[arg(long)]
+    pub uniprot: String,
+    /// Maximum results (default: 10)
+    #[arg(short, long, default_value = "10")]
+    pub limit: usize,
+    /// Skip the first N results
+    #[arg(long, default_value = "0")]
+    pub offset: usize,
+}
+
+#[derive(Args, Debug)]
+pub struct StructureGetArgs {
+    /// PDB ID (e.g., 6VSB)
+    pub pdb_id: String,
+}
+
+mod dispatch;
+pub(super) use self::dispatch::{handle_get, handle_search};
+
+#[cfg(test)]
+mod tests;
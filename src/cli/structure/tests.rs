@@ -0,0 +1,58 @@
+use clap::Parser;
+
+use crate::cli::{Cli, Commands, GetEntity, SearchEntity};
+
+#[test]
+fn get_and_search_structure_parse() {
+    let cli =
+        Cli::try_parse_from(["biomcp", "get", "structure", "6VSB"]).expect("get should parse");
+    let Cli {
+        command:
+            Commands::Get {
+                entity: GetEntity::Structure(crate::cli::structure::StructureGetArgs { pdb_id }),
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected get structure command");
+    };
+    assert_eq!(pdb_id, "6VSB");
+
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "structure",
+        "--uniprot",
+        "P15056",
+        "--limit",
+        "5",
+    ])
+    .expect("search should parse");
+    let Cli {
+        command: Commands::Search {
+            entity: SearchEntity::Structure(args),
+        },
+        ..
+    } = cli
+    else {
+        panic!("expected search structure command");
+    };
+    assert_eq!(args.uniprot, "P15056");
+    assert_eq!(args.limit, 5);
+    assert_eq!(args.offset, 0);
+
+    assert!(Cli::try_parse_from(["biomcp", "search", "structure"]).is_err());
+}
+
+#[tokio::test]
+async fn handle_get_rejects_invalid_pdb_id_before_backend_lookup() {
+    let err = super::handle_get(
+        crate::cli::structure::StructureGetArgs {
+            pdb_id: "BRAF".to_string(),
+        },
+        false,
+    )
+    .await
+    .expect_err("gene symbol should fail fast");
+    assert!(err.to_string().contains("Invalid PDB ID"));
+}
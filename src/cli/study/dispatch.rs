@@ -1,6 +1,60 @@
-use super::StudyCommand;
+use super::{StudyCommand, StudyGetArgs, StudySearchArgs};
 use crate::cli::{ChartArgs, ChartType, CommandOutcome};
 
+pub(crate) async fn handle_search(
+    args: StudySearchArgs,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let query = super::super::resolve_query_input(args.query, args.positional_query, "--query")?;
+    let filters = crate::entities::study::StudySearchFilters {
+        query,
+        cancer_type: args.cancer_type,
+    };
+    let mut query_summary = crate::entities::study::search_query_summary(&filters);
+    if args.offset > 0 {
+        query_summary = format!("{query_summary}, offset={}", args.offset);
+    }
+    let page = crate::entities::study::search_page(&filters, args.limit, args.offset).await?;
+    let results = page.results;
+    let pagination =
+        super::super::PaginationMeta::offset(args.offset, args.limit, results.len(), page.total);
+    let text = if json {
+        let next_commands = crate::render::markdown::search_next_commands_study(&results);
+        super::super::search_json_with_meta(results, pagination, next_commands)?
+    } else {
+        let footer = super::super::pagination_footer_offset(&pagination);
+        crate::render::markdown::study_search_markdown_with_footer(
+            &query_summary,
+            &results,
+            page.total,
+            &footer,
+        )?
+    };
+    Ok(CommandOutcome::stdout(text))
+}
+
+pub(crate) async fn handle_get(args: StudyGetArgs, json: bool) -> anyhow::Result<CommandOutcome> {
+    let (sections, json_override) = super::super::extract_json_from_sections(&args.sections);
+    let json_output = json || json_override;
+    let (study, section_errors) = crate::entities::section_errors::collect(
+        crate::entities::study::get(&args.study_id, &sections),
+    )
+    .await;
+    let study = study?;
+    let text = if json_output {
+        crate::render::json::to_entity_json(
+            &study,
+            crate::render::markdown::study_evidence_urls(&study),
+            crate::render::markdown::related_study(&study),
+            crate::render::provenance::study_section_sources(&study),
+            section_errors,
+        )?
+    } else {
+        crate::render::markdown::study_markdown(&study, &sections)?
+    };
+    Ok(CommandOutcome::stdout(text))
+}
+
 pub(crate) async fn handle_command(
     cmd: StudyCommand,
     json: bool,
@@ -1,9 +1,37 @@
 //! Study CLI subcommands.
 
-use clap::Subcommand;
+use clap::{Args, Subcommand};
 
 use crate::cli::ChartArgs;
 
+#[derive(Args, Debug)]
+pub struct StudySearchArgs {
+    /// Free text matched against study names, descriptions, and IDs (cBioPortal keyword search)
+    #[arg(short, long)]
+    pub query: Option<String>,
+    /// Positional alias for -q/--query
+    #[arg(value_name = "QUERY")]
+    pub positional_query: Option<String>,
+    /// OncoTree code (e.g., luad) or part of the cancer type name (e.g., breast)
+    #[arg(long = "cancer-type")]
+    pub cancer_type: Option<String>,
+    /// Maximum results (default: 10)
+    #[arg(short, long, default_value = "10")]
+    pub limit: usize,
+    /// Skip the first N results
+    #[arg(long, default_value = "0")]
+    pub offset: usize,
+}
+
+#[derive(Args, Debug)]
+pub struct StudyGetArgs {
+    /// cBioPortal study ID (e.g., luad_tcga_pan_can_atlas_2018)
+    pub study_id: String,
+    /// Sections to include (genes, all)
+    #[arg(trailing_var_arg = true)]
+    pub sections: Vec<String>,
+}
+
 #[derive(Subcommand, Debug)]
 pub enum StudyCommand {
     #[command(after_help = "\
@@ -139,7 +167,7 @@ See also: biomcp list study")]
 }
 
 mod dispatch;
-pub(crate) use self::dispatch::handle_command;
+pub(crate) use self::dispatch::{handle_command, handle_get, handle_search};
 
 #[cfg(test)]
 mod tests;
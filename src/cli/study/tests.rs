@@ -2,7 +2,7 @@ use clap::{CommandFactory, Parser};
 
 use super::handle_command;
 use crate::cli::study::StudyCommand;
-use crate::cli::{ChartType, Cli, Commands, execute};
+use crate::cli::{ChartType, Cli, Commands, GetEntity, SearchEntity, execute};
 
 mod charts;
 mod parsing;
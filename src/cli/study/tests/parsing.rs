@@ -268,3 +268,45 @@ fn study_co_occurrence_parses_gene_list() {
         other => panic!("unexpected command: {other:?}"),
     }
 }
+
+#[test]
+fn search_study_parses_positional_query_and_cancer_type() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "study",
+        "tcga",
+        "--cancer-type",
+        "brca",
+        "--limit",
+        "5",
+    ])
+    .expect("search study should parse");
+
+    match cli.command {
+        Commands::Search {
+            entity: SearchEntity::Study(args),
+        } => {
+            assert_eq!(args.positional_query.as_deref(), Some("tcga"));
+            assert_eq!(args.cancer_type.as_deref(), Some("brca"));
+            assert_eq!(args.limit, 5);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[test]
+fn get_study_parses_study_id_and_sections() {
+    let cli = Cli::try_parse_from(["biomcp", "get", "study", "msk_impact_2017", "genes"])
+        .expect("get study should parse");
+
+    match cli.command {
+        Commands::Get {
+            entity: GetEntity::Study(args),
+        } => {
+            assert_eq!(args.study_id, "msk_impact_2017");
+            assert_eq!(args.sections, vec!["genes"]);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
@@ -87,3 +87,15 @@ async fn study_compare_rejects_unknown_type() {
     .expect_err("study compare should validate type");
     assert!(err.to_string().contains("Unknown comparison type"));
 }
+
+#[tokio::test]
+async fn search_study_requires_query_or_cancer_type() {
+    let err = execute(vec![
+        "biomcp".to_string(),
+        "search".to_string(),
+        "study".to_string(),
+    ])
+    .await
+    .expect_err("search study should require a filter");
+    assert!(err.to_string().contains("Provide a query or --cancer-type"));
+}
@@ -1,7 +1,10 @@
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
-use super::{BatchArgs, EmaCommand, EnrichArgs, VersionArgs, WhoCommand};
+use super::{
+    BatchArgs, DossierArgs, EmaCommand, EnrichArgs, RawArgs, RepurposeArgs, SchemaArgs,
+    SectionsArgs, VersionArgs, WhoCommand,
+};
 use crate::cli::CommandOutcome;
 use futures::future::try_join_all;
 
@@ -30,23 +33,27 @@ pub(crate) async fn handle_batch(args: BatchArgs, json: bool) -> anyhow::Result<
 
     let text = match entity.as_str() {
         "gene" => {
-            let futs = parsed_ids
-                .iter()
-                .map(|id| crate::entities::gene::get(id, &batch_sections));
+            let futs = parsed_ids.iter().map(|id| {
+                crate::entities::section_errors::collect_ok(crate::entities::gene::get(
+                    id,
+                    &batch_sections,
+                ))
+            });
             let results = try_join_all(futs).await?;
             if json {
-                super::super::render_batch_json(&results, |item| {
+                super::super::render_batch_json(&results, |(item, section_errors)| {
                     crate::render::json::to_entity_json_value(
                         item,
                         crate::render::markdown::gene_evidence_urls(item),
                         crate::render::markdown::related_gene(item),
                         crate::render::provenance::gene_section_sources(item),
+                        section_errors.clone(),
                     )
                 })?
             } else {
                 let mut out = String::new();
                 out.push_str(&format!("# Batch: gene ({})\n\n", results.len()));
-                for (idx, item) in results.iter().enumerate() {
+                for (idx, (item, _)) in results.iter().enumerate() {
                     if idx > 0 {
                         out.push_str("\n\n---\n\n");
                     }
@@ -59,23 +66,27 @@ pub(crate) async fn handle_batch(args: BatchArgs, json: bool) -> anyhow::Result<
             }
         }
         "variant" => {
-            let futs = parsed_ids
-                .iter()
-                .map(|id| crate::entities::variant::get(id, &batch_sections));
+            let futs = parsed_ids.iter().map(|id| {
+                crate::entities::section_errors::collect_ok(crate::entities::variant::get(
+                    id,
+                    &batch_sections,
+                ))
+            });
             let results = try_join_all(futs).await?;
             if json {
-                super::super::render_batch_json(&results, |item| {
+                super::super::render_batch_json(&results, |(item, section_errors)| {
                     crate::render::json::to_entity_json_value(
                         item,
                         crate::render::markdown::variant_evidence_urls(item),
                         crate::render::markdown::related_variant(item),
                         crate::render::provenance::variant_section_sources(item),
+                        section_errors.clone(),
                     )
                 })?
             } else {
                 let mut out = String::new();
                 out.push_str(&format!("# Batch: variant ({})\n\n", results.len()));
-                for (idx, item) in results.iter().enumerate() {
+                for (idx, (item, _)) in results.iter().enumerate() {
                     if idx > 0 {
                         out.push_str("\n\n---\n\n");
                     }
@@ -88,23 +99,27 @@ pub(crate) async fn handle_batch(args: BatchArgs, json: bool) -> anyhow::Result<
             }
         }
         "article" => {
-            let futs = parsed_ids
-                .iter()
-                .map(|id| crate::entities::article::get(id, &batch_sections));
+            let futs = parsed_ids.iter().map(|id| {
+                crate::entities::section_errors::collect_ok(crate::entities::article::get(
+                    id,
+                    &batch_sections,
+                ))
+            });
             let results = try_join_all(futs).await?;
             if json {
-                super::super::render_batch_json(&results, |item| {
+                super::super::render_batch_json(&results, |(item, section_errors)| {
                     crate::render::json::to_entity_json_value(
                         item,
                         crate::render::markdown::article_evidence_urls(item),
                         crate::render::markdown::related_article(item),
                         crate::render::provenance::article_section_sources(item),
+                        section_errors.clone(),
                     )
                 })?
             } else {
                 let mut out = String::new();
                 out.push_str(&format!("# Batch: article ({})\n\n", results.len()));
-                for (idx, item) in results.iter().enumerate() {
+                for (idx, (item, _)) in results.iter().enumerate() {
                     if idx > 0 {
                         out.push_str("\n\n---\n\n");
                     }
@@ -118,23 +133,28 @@ pub(crate) async fn handle_batch(args: BatchArgs, json: bool) -> anyhow::Result<
         }
         "trial" => {
             let trial_source = crate::entities::trial::TrialSource::from_flag(&args.source)?;
-            let futs = parsed_ids
-                .iter()
-                .map(|id| crate::entities::trial::get(id, &batch_sections, trial_source));
+            let futs = parsed_ids.iter().map(|id| {
+                crate::entities::section_errors::collect_ok(crate::entities::trial::get(
+                    id,
+                    &batch_sections,
+                    trial_source,
+                ))
+            });
             let results = try_join_all(futs).await?;
             if json {
-                super::super::render_batch_json(&results, |item| {
+                super::super::render_batch_json(&results, |(item, section_errors)| {
                     crate::render::json::to_entity_json_value(
                         item,
                         crate::render::markdown::trial_evidence_urls(item),
                         crate::render::markdown::related_trial(item),
                         crate::render::provenance::trial_section_sources(item),
+                        section_errors.clone(),
                     )
                 })?
             } else {
                 let mut out = String::new();
                 out.push_str(&format!("# Batch: trial ({})\n\n", results.len()));
-                for (idx, item) in results.iter().enumerate() {
+                for (idx, (item, _)) in results.iter().enumerate() {
                     if idx > 0 {
                         out.push_str("\n\n---\n\n");
                     }
@@ -147,23 +167,27 @@ pub(crate) async fn handle_batch(args: BatchArgs, json: bool) -> anyhow::Result<
             }
         }
         "drug" => {
-            let futs = parsed_ids
-                .iter()
-                .map(|id| crate::entities::drug::get(id, &batch_sections));
+            let futs = parsed_ids.iter().map(|id| {
+                crate::entities::section_errors::collect_ok(crate::entities::drug::get(
+                    id,
+                    &batch_sections,
+                ))
+            });
             let results = try_join_all(futs).await?;
             if json {
-                super::super::render_batch_json(&results, |item| {
+                super::super::render_batch_json(&results, |(item, section_errors)| {
                     crate::render::json::to_entity_json_value(
                         item,
                         crate::render::markdown::drug_evidence_urls(item),
                         crate::render::markdown::related_drug(item),
                         crate::render::provenance::drug_section_sources(item),
+                        section_errors.clone(),
                     )
                 })?
             } else {
                 let mut out = String::new();
                 out.push_str(&format!("# Batch: drug ({})\n\n", results.len()));
-                for (idx, item) in results.iter().enumerate() {
+                for (idx, (item, _)) in results.iter().enumerate() {
                     if idx > 0 {
                         out.push_str("\n\n---\n\n");
                     }
@@ -176,23 +200,27 @@ pub(crate) async fn handle_batch(args: BatchArgs, json: bool) -> anyhow::Result<
             }
         }
         "disease" => {
-            let futs = parsed_ids
-                .iter()
-                .map(|id| crate::entities::disease::get(id, &batch_sections));
+            let futs = parsed_ids.iter().map(|id| {
+                crate::entities::section_errors::collect_ok(crate::entities::disease::get(
+                    id,
+                    &batch_sections,
+                ))
+            });
             let results = try_join_all(futs).await?;
             if json {
-                super::super::render_batch_json(&results, |item| {
+                super::super::render_batch_json(&results, |(item, section_errors)| {
                     crate::render::json::to_entity_json_value(
                         item,
                         crate::render::markdown::disease_evidence_urls(item),
                         crate::render::markdown::related_disease(item),
                         crate::render::provenance::disease_section_sources(item),
+                        section_errors.clone(),
                     )
                 })?
             } else {
                 let mut out = String::new();
                 out.push_str(&format!("# Batch: disease ({})\n\n", results.len()));
-                for (idx, item) in results.iter().enumerate() {
+                for (idx, (item, _)) in results.iter().enumerate() {
                     if idx > 0 {
                         out.push_str("\n\n---\n\n");
                     }
@@ -205,23 +233,27 @@ pub(crate) async fn handle_batch(args: BatchArgs, json: bool) -> anyhow::Result<
             }
         }
         "pgx" => {
-            let futs = parsed_ids
-                .iter()
-                .map(|id| crate::entities::pgx::get(id, &batch_sections));
+            let futs = parsed_ids.iter().map(|id| {
+                crate::entities::section_errors::collect_ok(crate::entities::pgx::get(
+                    id,
+                    &batch_sections,
+                ))
+            });
             let results = try_join_all(futs).await?;
             if json {
-                super::super::render_batch_json(&results, |item| {
+                super::super::render_batch_json(&results, |(item, section_errors)| {
                     crate::render::json::to_entity_json_value(
                         item,
                         crate::render::markdown::pgx_evidence_urls(item),
                         crate::render::markdown::related_pgx(item),
                         crate::render::provenance::pgx_section_sources(item),
+                        section_errors.clone(),
                     )
                 })?
             } else {
                 let mut out = String::new();
                 out.push_str(&format!("# Batch: pgx ({})\n\n", results.len()));
-                for (idx, item) in results.iter().enumerate() {
+                for (idx, (item, _)) in results.iter().enumerate() {
                     if idx > 0 {
                         out.push_str("\n\n---\n\n");
                     }
@@ -234,23 +266,27 @@ pub(crate) async fn handle_batch(args: BatchArgs, json: bool) -> anyhow::Result<
             }
         }
         "pathway" => {
-            let futs = parsed_ids
-                .iter()
-                .map(|id| crate::entities::pathway::get(id, &batch_sections));
+            let futs = parsed_ids.iter().map(|id| {
+                crate::entities::section_errors::collect_ok(crate::entities::pathway::get(
+                    id,
+                    &batch_sections,
+                ))
+            });
             let results = try_join_all(futs).await?;
             if json {
-                super::super::render_batch_json(&results, |item| {
+                super::super::render_batch_json(&results, |(item, section_errors)| {
                     crate::render::json::to_entity_json_value(
                         item,
                         crate::render::markdown::pathway_evidence_urls(item),
                         crate::render::markdown::related_pathway(item),
                         crate::render::provenance::pathway_section_sources(item),
+                        section_errors.clone(),
                     )
                 })?
             } else {
                 let mut out = String::new();
                 out.push_str(&format!("# Batch: pathway ({})\n\n", results.len()));
-                for (idx, item) in results.iter().enumerate() {
+                for (idx, (item, _)) in results.iter().enumerate() {
                     if idx > 0 {
                         out.push_str("\n\n---\n\n");
                     }
@@ -263,23 +299,27 @@ pub(crate) async fn handle_batch(args: BatchArgs, json: bool) -> anyhow::Result<
             }
         }
         "protein" => {
-            let futs = parsed_ids
-                .iter()
-                .map(|id| crate::entities::protein::get(id, &batch_sections));
+            let futs = parsed_ids.iter().map(|id| {
+                crate::entities::section_errors::collect_ok(crate::entities::protein::get(
+                    id,
+                    &batch_sections,
+                ))
+            });
             let results = try_join_all(futs).await?;
             if json {
-                super::super::render_batch_json(&results, |item| {
+                super::super::render_batch_json(&results, |(item, section_errors)| {
                     crate::render::json::to_entity_json_value(
                         item,
                         crate::render::markdown::protein_evidence_urls(item),
                         crate::render::markdown::related_protein(item, &batch_sections),
                         crate::render::provenance::protein_section_sources(item),
+                        section_errors.clone(),
                     )
                 })?
             } else {
                 let mut out = String::new();
                 out.push_str(&format!("# Batch: protein ({})\n\n", results.len()));
-                for (idx, item) in results.iter().enumerate() {
+                for (idx, (item, _)) in results.iter().enumerate() {
                     if idx > 0 {
                         out.push_str("\n\n---\n\n");
                     }
@@ -310,6 +350,7 @@ pub(crate) async fn handle_batch(args: BatchArgs, json: bool) -> anyhow::Result<
                             crate::render::markdown::adverse_event_evidence_urls(report),
                             crate::render::markdown::related_adverse_event(report),
                             crate::render::provenance::adverse_event_report_section_sources(item),
+                            Vec::new(),
                         )
                     }
                     crate::entities::adverse_event::AdverseEventReport::Device(report) => {
@@ -318,6 +359,7 @@ pub(crate) async fn handle_batch(args: BatchArgs, json: bool) -> anyhow::Result<
                             crate::render::markdown::device_event_evidence_urls(report),
                             crate::render::markdown::related_device_event(report),
                             crate::render::provenance::adverse_event_report_section_sources(item),
+                            Vec::new(),
                         )
                     }
                 })?
@@ -418,6 +460,93 @@ pub(crate) async fn handle_enrich(args: EnrichArgs, json: bool) -> anyhow::Resul
     Ok(CommandOutcome::stdout(text))
 }
 
+pub(crate) async fn handle_dossier(
+    args: DossierArgs,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let dossier = crate::entities::dossier::gene_disease_dossier(&args.gene, &args.disease).await?;
+    let text = if json {
+        crate::render::json::to_pretty(&dossier)?
+    } else {
+        crate::render::markdown::dossier_markdown(&dossier)?
+    };
+    Ok(CommandOutcome::stdout(text))
+}
+
+/// Always prints JSON: the passthrough has no markdown view of upstream payloads.
+pub(crate) async fn handle_raw(args: RawArgs) -> anyhow::Result<CommandOutcome> {
+    let value = crate::sources::passthrough::fetch(&args.source, &args.path, &args.params).await?;
+    Ok(CommandOutcome::stdout(crate::render::json::to_pretty(
+        &value,
+    )?))
+}
+
+pub(crate) async fn handle_repurpose(
+    args: RepurposeArgs,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    use crate::entities::repurpose::{MAX_REPURPOSE_LIMIT, MAX_WAIT_SECS};
+
+    if args.limit == 0 || args.limit > MAX_REPURPOSE_LIMIT {
+        return Err(crate::error::BioMcpError::InvalidArgument(format!(
+            "--limit must be between 1 and {MAX_REPURPOSE_LIMIT}"
+        ))
+        .into());
+    }
+    if args.wait == 0 || args.wait > MAX_WAIT_SECS {
+        return Err(crate::error::BioMcpError::InvalidArgument(format!(
+            "--wait must be between 1 and {MAX_WAIT_SECS} seconds"
+        ))
+        .into());
+    }
+    let read_genes = |path: &Path| {
+        std::fs::read_to_string(path)
+            .map(|content| crate::entities::repurpose::parse_gene_list(&content))
+            .map_err(|err| {
+                crate::error::BioMcpError::Io(std::io::Error::new(
+                    err.kind(),
+                    format!("failed to read {}: {}", path.display(), err),
+                ))
+            })
+    };
+    let up = read_genes(&args.up)?;
+    let down = read_genes(&args.down)?;
+
+    let result = crate::entities::repurpose::repurpose(
+        &up,
+        &down,
+        args.limit,
+        std::time::Duration::from_secs(args.wait),
+    )
+    .await?;
+    let text = if json {
+        crate::render::json::to_pretty(&result)?
+    } else {
+        crate::render::markdown::repurpose_markdown(&result)?
+    };
+    Ok(CommandOutcome::stdout(text))
+}
+
+/// Prints the section manifest as JSON; there is no markdown form because the
+/// manifest is meant for agents planning `get` calls.
+pub(crate) async fn handle_sections(args: SectionsArgs) -> anyhow::Result<CommandOutcome> {
+    use crate::entities::section_manifest;
+
+    let text = match args.entity.as_deref() {
+        Some(entity) => crate::render::json::to_pretty(&section_manifest::manifest(entity)?)?,
+        None => crate::render::json::to_pretty(&section_manifest::all_manifests())?,
+    };
+    Ok(CommandOutcome::stdout(text))
+}
+
+/// Prints the JSON Schema that `get <entity> <id> --json` output conforms to.
+pub(crate) fn handle_schema(args: SchemaArgs) -> anyhow::Result<CommandOutcome> {
+    let schema = crate::entities::entity_schema::entity_schema(&args.entity)?;
+    Ok(CommandOutcome::stdout(crate::render::json::to_pretty(
+        &schema,
+    )?))
+}
+
 pub(crate) async fn handle_uninstall() -> anyhow::Result<CommandOutcome> {
     Ok(CommandOutcome::stdout(uninstall_self()?))
 }
@@ -1,5 +1,8 @@
 //! Top-level CLI payloads and subcommands that stay outside the per-entity families.
 
+use std::path::PathBuf;
+use std::time::Duration;
+
 use clap::{Args, Subcommand};
 
 #[derive(Args, Debug)]
@@ -29,6 +32,21 @@ pub struct ServeHttpArgs {
     /// Port to listen on
     #[arg(long, default_value = "8080")]
     pub port: u16,
+    /// Re-fetch recently requested entities in the background this often (e.g. 15m, 1h)
+    #[arg(long, value_name = "DURATION", value_parser = parse_refresh_interval)]
+    pub refresh_interval: Option<Duration>,
+}
+
+/// Shorter intervals would mostly re-hit upstreams for data that has not changed.
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+fn parse_refresh_interval(value: &str) -> Result<Duration, String> {
+    let interval = humantime::parse_duration(value)
+        .map_err(|err| format!("--refresh-interval must be a duration like 15m or 1h: {err}"))?;
+    if interval < MIN_REFRESH_INTERVAL {
+        return Err("--refresh-interval must be at least 1m".into());
+    }
+    Ok(interval)
 }
 
 #[derive(Args, Debug)]
@@ -44,6 +62,18 @@ pub struct ListArgs {
     pub entity: Option<String>,
 }
 
+#[derive(Args, Debug)]
+pub struct SectionsArgs {
+    /// Entity name (gene, variant, article, trial, drug, disease, pgx, hla, pathway, protein, study, adverse-event); omit for all
+    pub entity: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct SchemaArgs {
+    /// Entity name (gene, variant, article, trial, drug, disease, pgx, pathway, protein, adverse-event)
+    pub entity: String,
+}
+
 #[derive(Args, Debug)]
 pub struct BatchArgs {
     /// Entity type (gene, variant, article, trial, drug, disease, pgx, pathway, protein, adverse-event)
@@ -67,12 +97,58 @@ pub struct EnrichArgs {
     pub limit: usize,
 }
 
+#[derive(Args, Debug)]
+pub struct DossierArgs {
+    /// HGNC gene symbol (e.g., BRAF)
+    #[arg(long)]
+    pub gene: String,
+    /// Disease name (e.g., melanoma)
+    #[arg(long)]
+    pub disease: String,
+}
+
+#[derive(Args, Debug)]
+pub struct RawArgs {
+    /// Upstream source (mygene, myvariant, mychem, uniprot, ensembl, clinicaltrials, europepmc, pubtator, reactome)
+    pub source: String,
+    /// Endpoint path under the source's base URL (e.g., query or lookup/id/ENSG00000157764)
+    pub path: String,
+    /// Query parameter as key=value; repeat for several
+    #[arg(long = "param", value_name = "KEY=VALUE")]
+    pub params: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct RepurposeArgs {
+    /// Up-regulated genes, one per line or comma-separated (symbols, Ensembl, or Entrez IDs)
+    #[arg(long, value_name = "PATH")]
+    pub up: PathBuf,
+    /// Down-regulated genes, same format as --up
+    #[arg(long, value_name = "PATH")]
+    pub down: PathBuf,
+    /// Maximum candidate compounds (default: 20, max: 100)
+    #[arg(short, long, default_value = "20")]
+    pub limit: usize,
+    /// Seconds to wait for the CLUE query job (default: 900, max: 3600)
+    #[arg(long, value_name = "SECS", default_value = "900")]
+    pub wait: u64,
+}
+
 #[derive(Args, Debug)]
 pub struct DiscoverArgs {
     /// Free-text biomedical query
     pub query: String,
 }
 
+#[derive(Args, Debug)]
+pub struct AskArgs {
+    /// Free-text biomedical question
+    pub question: String,
+    /// Evidence rows per entity (1-10)
+    #[arg(short, long, default_value = "3")]
+    pub limit: usize,
+}
+
 #[derive(Args, Debug)]
 pub struct VersionArgs {
     /// Include executable provenance and PATH diagnostics
@@ -82,7 +158,8 @@ pub struct VersionArgs {
 
 mod dispatch;
 pub(crate) use self::dispatch::{
-    handle_batch, handle_ema, handle_enrich, handle_uninstall, handle_version, handle_who,
+    handle_batch, handle_dossier, handle_ema, handle_enrich, handle_raw, handle_repurpose,
+    handle_schema, handle_sections, handle_uninstall, handle_version, handle_who,
 };
 
 #[cfg(test)]
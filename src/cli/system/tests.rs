@@ -122,6 +122,70 @@ fn list_command_parses_entity_name() {
     assert_eq!(entity.as_deref(), Some("drug"));
 }
 
+#[test]
+fn sections_command_entity_is_optional() {
+    let cli = Cli::try_parse_from(["biomcp", "sections"]).expect("sections should parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Sections(crate::cli::system::SectionsArgs { entity: None })
+    ));
+}
+
+#[tokio::test]
+async fn sections_command_prints_json_manifest_and_rejects_unknown_entities() {
+    let out = execute(vec![
+        "biomcp".to_string(),
+        "sections".to_string(),
+        "variant".to_string(),
+    ])
+    .await
+    .expect("sections variant");
+    let manifest: serde_json::Value = serde_json::from_str(&out).expect("json manifest");
+    assert_eq!(manifest["entity"], "variant");
+    let predict = manifest["sections"]
+        .as_array()
+        .and_then(|rows| rows.iter().find(|row| row["name"] == "predict"))
+        .expect("predict section");
+    assert_eq!(predict["latency"], "slow");
+    assert_eq!(predict["auth"]["env"], "ALPHAGENOME_API_KEY");
+
+    let err = execute(vec![
+        "biomcp".to_string(),
+        "sections".to_string(),
+        "structure".to_string(),
+    ])
+    .await
+    .expect_err("structure has no sections");
+    assert!(err.to_string().contains("Unknown entity \"structure\""));
+}
+
+#[tokio::test]
+async fn schema_command_prints_entity_json_schema() {
+    let out = execute(vec![
+        "biomcp".to_string(),
+        "schema".to_string(),
+        "trial".to_string(),
+    ])
+    .await
+    .expect("schema trial");
+    let schema: serde_json::Value = serde_json::from_str(&out).expect("json schema");
+    assert_eq!(schema["x-biomcp-entity"], "trial");
+    assert!(schema["properties"]["nct_id"].is_object());
+    assert!(schema["properties"]["_meta"].is_object());
+
+    let err = execute(vec![
+        "biomcp".to_string(),
+        "schema".to_string(),
+        "hla".to_string(),
+    ])
+    .await
+    .expect_err("hla has no published schema");
+    assert!(
+        err.to_string()
+            .contains("Unknown entity \"hla\" for schema")
+    );
+}
+
 #[test]
 fn batch_command_parses_sections_and_source() {
     let cli = Cli::try_parse_from([
@@ -173,6 +237,64 @@ fn enrich_command_parses_limit() {
     assert_eq!(limit, 5);
 }
 
+#[test]
+fn dossier_command_requires_gene_and_disease() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "dossier",
+        "--gene",
+        "BRAF",
+        "--disease",
+        "melanoma",
+    ])
+    .expect("dossier should parse");
+
+    let Cli {
+        command: Commands::Dossier(crate::cli::system::DossierArgs { gene, disease }),
+        ..
+    } = cli
+    else {
+        panic!("expected dossier command");
+    };
+
+    assert_eq!(gene, "BRAF");
+    assert_eq!(disease, "melanoma");
+    assert!(Cli::try_parse_from(["biomcp", "dossier", "--gene", "BRAF"]).is_err());
+}
+
+#[test]
+fn raw_command_collects_repeated_params() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "raw",
+        "mygene",
+        "query",
+        "--param",
+        "q=symbol:BRAF",
+        "--param",
+        "fields=symbol,name",
+    ])
+    .expect("raw should parse");
+
+    let Cli {
+        command:
+            Commands::Raw(crate::cli::system::RawArgs {
+                source,
+                path,
+                params,
+            }),
+        ..
+    } = cli
+    else {
+        panic!("expected raw command");
+    };
+
+    assert_eq!(source, "mygene");
+    assert_eq!(path, "query");
+    assert_eq!(params, vec!["q=symbol:BRAF", "fields=symbol,name"]);
+    assert!(Cli::try_parse_from(["biomcp", "raw", "mygene"]).is_err());
+}
+
 #[test]
 fn version_command_parses_verbose_flag() {
     let cli =
@@ -251,3 +373,55 @@ async fn enrich_rejects_limit_above_max_before_api_call() {
     .expect_err("enrich should reject --limit > 50");
     assert!(err.to_string().contains("--limit must be between 1 and 50"));
 }
+
+#[test]
+fn repurpose_command_parses_signature_files_and_defaults() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "repurpose",
+        "--up",
+        "up.txt",
+        "--down",
+        "down.txt",
+    ])
+    .expect("repurpose should parse");
+
+    let Cli {
+        command:
+            Commands::Repurpose(crate::cli::system::RepurposeArgs {
+                up,
+                down,
+                limit,
+                wait,
+            }),
+        ..
+    } = cli
+    else {
+        panic!("expected repurpose command");
+    };
+
+    assert_eq!(up, std::path::PathBuf::from("up.txt"));
+    assert_eq!(down, std::path::PathBuf::from("down.txt"));
+    assert_eq!(limit, 20);
+    assert_eq!(wait, 900);
+}
+
+#[tokio::test]
+async fn repurpose_rejects_wait_above_max_before_reading_files() {
+    let err = execute(vec![
+        "biomcp".to_string(),
+        "repurpose".to_string(),
+        "--up".to_string(),
+        "missing-up.txt".to_string(),
+        "--down".to_string(),
+        "missing-down.txt".to_string(),
+        "--wait".to_string(),
+        "3601".to_string(),
+    ])
+    .await
+    .expect_err("repurpose should reject --wait > 3600");
+    assert!(
+        err.to_string()
+            .contains("--wait must be between 1 and 3600 seconds")
+    );
+}
@@ -138,6 +138,18 @@ pub(crate) async fn mount_drug_lookup_miss(server: &MockServer, query: &str) {
         .await;
 }
 
+pub(crate) async fn mount_ncit_miss(server: &MockServer, query: &str) {
+    Mock::given(method("GET"))
+        .and(path("/concept/ncit/search"))
+        .and(query_param("terms", query))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(r#"{"total":0,"concepts":[]}"#, "application/json"),
+        )
+        .mount(server)
+        .await;
+}
+
 pub(crate) async fn mount_ols_alias(
     server: &MockServer,
     query: &str,
@@ -48,6 +48,34 @@ fn cache_clean_command_parses_with_flags() {
     assert!(dry_run);
 }
 
+#[test]
+fn cache_purge_command_requires_older_than() {
+    let cli = Cli::try_parse_from(["biomcp", "cache", "purge", "--older-than", "30d"])
+        .expect("cache purge should parse");
+
+    let Cli {
+        command:
+            Commands::Cache {
+                cmd:
+                    crate::cli::cache::CacheCommand::Purge {
+                        older_than,
+                        dry_run,
+                    },
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected cache purge command");
+    };
+
+    assert_eq!(
+        older_than,
+        std::time::Duration::from_secs(30 * 24 * 60 * 60)
+    );
+    assert!(!dry_run);
+    assert!(Cli::try_parse_from(["biomcp", "cache", "purge"]).is_err());
+}
+
 #[test]
 fn cache_clear_command_parses() {
     Cli::try_parse_from(["biomcp", "cache", "clear"]).expect("cache clear should parse");
@@ -59,6 +87,60 @@ fn cache_clear_command_parses_with_yes_flag() {
         .expect("cache clear --yes should parse");
 }
 
+#[test]
+fn cache_clear_command_parses_source_filter() {
+    let cli = Cli::try_parse_from(["biomcp", "cache", "clear", "--source", " MyGene ", "--yes"])
+        .expect("cache clear --source should parse");
+
+    let Cli {
+        command:
+            Commands::Cache {
+                cmd: crate::cli::cache::CacheCommand::Clear { yes, source },
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected cache clear command");
+    };
+
+    assert!(yes);
+    assert_eq!(source.as_deref(), Some("mygene"));
+    assert!(Cli::try_parse_from(["biomcp", "cache", "clear", "--source", " "]).is_err());
+}
+
+#[test]
+fn cache_warm_command_requires_genes_file() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "cache",
+        "warm",
+        "--genes-file",
+        "panel.txt",
+        "--sections",
+        "civic,clinvar-summary",
+    ])
+    .expect("cache warm should parse");
+
+    let Cli {
+        command:
+            Commands::Cache {
+                cmd:
+                    crate::cli::cache::CacheCommand::Warm {
+                        genes_file,
+                        sections,
+                    },
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected cache warm command");
+    };
+
+    assert_eq!(genes_file, std::path::PathBuf::from("panel.txt"));
+    assert_eq!(sections, vec!["civic", "clinvar-summary"]);
+    assert!(Cli::try_parse_from(["biomcp", "cache", "warm"]).is_err());
+}
+
 #[test]
 fn cache_path_help_mentions_plain_text_and_ignored_json() {
     let help = render_cache_path_long_help();
@@ -204,6 +286,8 @@ async fn cache_stats_execute_json_returns_structured_report() {
         "age_range",
         "max_size_bytes",
         "max_size_origin",
+        "max_size_used_percent",
+        "over_max_size",
         "max_age_secs",
         "max_age_origin",
     ] {
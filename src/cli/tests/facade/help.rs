@@ -59,10 +59,18 @@ fn runtime_commands_still_parse_hidden_global_flags() {
     assert!(cli.no_cache);
     assert!(matches!(
         cli.command,
-        Commands::ServeHttp(crate::cli::system::ServeHttpArgs { host, port })
-            if host == "127.0.0.1" && port == 8080
+        Commands::ServeHttp(crate::cli::system::ServeHttpArgs { host, port, refresh_interval })
+            if host == "127.0.0.1" && port == 8080 && refresh_interval.is_none()
     ));
 
+    let cli = parse_built_cli(["biomcp", "serve-http", "--refresh-interval", "15m"]);
+    assert!(matches!(
+        cli.command,
+        Commands::ServeHttp(crate::cli::system::ServeHttpArgs { refresh_interval, .. })
+            if refresh_interval == Some(std::time::Duration::from_secs(900))
+    ));
+    assert!(Cli::try_parse_from(["biomcp", "serve-http", "--refresh-interval", "10s"]).is_err());
+
     for args in [
         ["biomcp", "mcp", "--json", "--no-cache"].as_slice(),
         ["biomcp", "serve", "--json", "--no-cache"].as_slice(),
@@ -74,6 +82,19 @@ fn runtime_commands_still_parse_hidden_global_flags() {
     }
 }
 
+#[test]
+fn top_level_verbose_flag_coexists_with_version_verbose() {
+    let cli = parse_built_cli(["biomcp", "--verbose", "get", "gene", "BRAF"]);
+    assert!(cli.verbose);
+
+    let cli = parse_built_cli(["biomcp", "version", "--verbose"]);
+    assert!(!cli.verbose);
+    assert!(matches!(
+        cli.command,
+        Commands::Version(crate::cli::system::VersionArgs { verbose: true })
+    ));
+}
+
 #[test]
 fn serve_sse_help_stays_callable_and_deprecated() {
     let mut command = crate::cli::build_cli();
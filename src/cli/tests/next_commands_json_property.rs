@@ -45,8 +45,32 @@ fn assert_entity_json_next_commands<T: Serialize>(
     next_commands: Vec<String>,
     section_sources: Vec<crate::render::provenance::SectionSource>,
 ) {
-    let json =
-        crate::render::json::to_entity_json(entity, evidence_urls, next_commands, section_sources)
-            .unwrap_or_else(|e| panic!("{label}: failed to render entity json: {e}"));
+    let json = crate::render::json::to_entity_json(
+        entity,
+        evidence_urls,
+        next_commands,
+        section_sources,
+        Vec::new(),
+    )
+    .unwrap_or_else(|e| panic!("{label}: failed to render entity json: {e}"));
     assert_json_next_commands_parse(label, &json);
+    assert_json_matches_entity_schema(label, &json);
+}
+
+/// `label` starts with the entity name, e.g. `adverse-event-faers`.
+fn assert_json_matches_entity_schema(label: &str, json: &str) {
+    use crate::entities::entity_schema::{SCHEMA_ENTITIES, entity_schema, schema_violations};
+
+    let entity = SCHEMA_ENTITIES
+        .iter()
+        .filter(|entity| label.starts_with(**entity))
+        .max_by_key(|entity| entity.len())
+        .unwrap_or_else(|| panic!("{label}: no published schema"));
+    let schema = entity_schema(entity).unwrap_or_else(|e| panic!("{label}: schema: {e}"));
+    let value: serde_json::Value = serde_json::from_str(json).expect("valid json");
+    let violations = schema_violations(&schema, &value);
+    assert!(
+        violations.is_empty(),
+        "{label}: --json output does not match `biomcp schema {entity}`: {violations:?}"
+    );
 }
@@ -27,6 +27,10 @@ fn disease_json_next_commands_parse() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -75,6 +79,10 @@ fn disease_json_next_commands_include_top_gene_context() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -88,6 +96,7 @@ fn disease_json_next_commands_include_top_gene_context() {
         crate::render::markdown::disease_evidence_urls(&disease),
         next_commands,
         crate::render::provenance::disease_section_sources(&disease),
+        Vec::new(),
     )
     .expect("disease json");
     assert_json_next_commands_parse("disease-top-gene", &json);
@@ -142,6 +151,8 @@ fn trial_json_next_commands_parse() {
         outcomes: None,
         arms: None,
         references: None,
+        publications: None,
+        results: None,
     };
     let next_commands = crate::render::markdown::related_trial(&trial);
     assert!(next_commands.iter().any(|cmd| {
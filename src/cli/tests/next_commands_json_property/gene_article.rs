@@ -15,6 +15,7 @@ fn gene_json_next_commands_parse() {
         uniprot_id: Some("P15056".to_string()),
         summary: None,
         gene_type: None,
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -31,8 +32,14 @@ fn gene_json_next_commands_parse() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     assert_entity_json_next_commands(
@@ -57,6 +64,7 @@ fn gene_json_next_commands_include_clingen_trial_search() {
         uniprot_id: Some("P35498".to_string()),
         summary: None,
         gene_type: None,
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -82,8 +90,14 @@ fn gene_json_next_commands_include_clingen_trial_search() {
         }),
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let next_commands = crate::render::markdown::related_gene(&gene);
@@ -92,6 +106,7 @@ fn gene_json_next_commands_include_clingen_trial_search() {
         crate::render::markdown::gene_evidence_urls(&gene),
         next_commands,
         crate::render::provenance::gene_section_sources(&gene),
+        Vec::new(),
     )
     .expect("gene json");
     assert_json_next_commands_parse("gene-clingen", &json);
@@ -117,6 +132,7 @@ fn article_json_next_commands_parse() {
         abstract_text: None,
         full_text_path: None,
         full_text_note: None,
+        full_text_source: None,
         annotations: Some(ArticleAnnotations {
             genes: vec![AnnotationCount {
                 text: "serine-threonine protein kinase".to_string(),
@@ -131,8 +147,10 @@ fn article_json_next_commands_parse() {
                 count: 1,
             }],
             mutations: Vec::new(),
+            trials: Vec::new(),
         }),
         semantic_scholar: None,
+        citation_graph: None,
         pubtator_fallback: false,
     };
     let next_commands = crate::render::markdown::related_article(&article);
@@ -26,6 +26,7 @@ fn batch_protein_json_omits_requested_section_from_next_commands() {
             crate::render::markdown::protein_evidence_urls(item),
             crate::render::markdown::related_protein(item, &requested_sections),
             crate::render::provenance::protein_section_sources(item),
+            Vec::new(),
         )
     })
     .expect("batch json");
@@ -163,12 +164,14 @@ fn batch_adverse_event_json_uses_variant_specific_meta() {
             crate::render::markdown::adverse_event_evidence_urls(report),
             crate::render::markdown::related_adverse_event(report),
             crate::render::provenance::adverse_event_report_section_sources(item),
+            Vec::new(),
         ),
         AdverseEventReport::Device(report) => crate::render::json::to_entity_json_value(
             item,
             crate::render::markdown::device_event_evidence_urls(report),
             crate::render::markdown::related_device_event(report),
             crate::render::provenance::adverse_event_report_section_sources(item),
+            Vec::new(),
         ),
     })
     .expect("batch json");
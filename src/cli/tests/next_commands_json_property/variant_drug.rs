@@ -39,6 +39,7 @@ fn variant_json_next_commands_include_vus_literature_route() {
         crate::render::markdown::variant_evidence_urls(&variant),
         next_commands,
         crate::render::provenance::variant_section_sources(&variant),
+        Vec::new(),
     )
     .expect("variant json");
     assert_json_next_commands_parse("variant-vus", &json);
@@ -73,12 +74,14 @@ fn drug_json_next_commands_parse() {
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: Vec::new(),
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: None,
+        shortage_trials: None,
         approvals: None,
         us_safety_warnings: None,
         ema_regulatory: None,
@@ -86,6 +89,8 @@ fn drug_json_next_commands_parse() {
         ema_shortage: None,
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     assert_entity_json_next_commands(
@@ -17,6 +17,7 @@ fn gene_next_commands_parse() {
     assert_parses("biomcp search article -g BRAF");
     assert_parses("biomcp search drug --target BRAF");
     assert_parses("biomcp gene trials BRAF");
+    assert_parses("biomcp gene variants BRCA1 --significance pathogenic --format tsv --all");
 }
 
 #[test]
@@ -102,6 +103,30 @@ fn pgx_next_commands_parse() {
     assert_parses("biomcp search pgx -d warfarin");
 }
 
+#[test]
+fn hla_next_commands_parse() {
+    assert_parses(r#"biomcp get hla "HLA-B*57:01" drugs"#);
+    assert_parses("biomcp get pgx HLA-B");
+    assert_parses("biomcp get drug abacavir");
+    assert_parses(r#"biomcp search article -k "HLA-B*57:01""#);
+}
+
+#[test]
+fn structure_next_commands_parse() {
+    assert_parses("biomcp get structure 6VSB");
+    assert_parses("biomcp get protein P0DTC2");
+    assert_parses("biomcp search structure --uniprot P0DTC2");
+    assert_parses("biomcp get article 32075877");
+}
+
+#[test]
+fn transcript_next_commands_parse() {
+    assert_parses("biomcp get transcript ENST00000288602");
+    assert_parses("biomcp get gene BRAF");
+    assert_parses("biomcp search variant -g BRAF");
+    assert_parses("biomcp get protein P15056");
+}
+
 #[test]
 fn pgx_search_json_next_commands_parse() {
     assert_parses("biomcp get pgx CYP2D6");
@@ -164,6 +189,29 @@ fn gwas_search_json_next_commands_parse() {
     assert_parses("biomcp list gwas");
 }
 
+#[test]
+fn variant_validation_lookup_command_parses() {
+    assert_parses(r#"biomcp get variant "chr17:g.7676154G>C""#);
+    assert_parses(r#"biomcp validate variant "NM_000546.6:c.215C>G""#);
+    assert_parses(r#"biomcp validate hgvs "NM_004333.4:c.1799T>A""#);
+    assert_parses(r#"biomcp get variant "NM_007294.4:c.68_69del""#);
+}
+
+#[test]
+fn guideline_search_json_next_commands_parse() {
+    assert_parses("biomcp get article 38033089");
+    assert_parses("biomcp list guidelines");
+}
+
+#[test]
+fn study_next_commands_parse() {
+    assert_parses("biomcp get study msk_impact_2017 genes");
+    assert_parses("biomcp get gene TP53");
+    assert_parses("biomcp get article 28481359");
+    assert_parses("biomcp study download msk_impact_2017");
+    assert_parses(r#"biomcp search study "lung adenocarcinoma" --limit 5"#);
+}
+
 #[test]
 fn discover_next_commands_parse() {
     // gene — unambiguous and ambiguous
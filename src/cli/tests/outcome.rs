@@ -6,8 +6,9 @@ use std::sync::{
 use clap::Parser;
 
 use super::super::test_support::{
-    Mock, MockServer, ResponseTemplate, lock_env, method, mount_drug_lookup_miss,
-    mount_gene_lookup_hit, mount_gene_lookup_miss, mount_ols_alias, path, query_param, set_env_var,
+    Mock, MockServer, ResponseTemplate, TempDirGuard, lock_env, method, mount_drug_lookup_miss,
+    mount_gene_lookup_hit, mount_gene_lookup_miss, mount_ncit_miss, mount_ols_alias, path,
+    query_param, set_env_var,
 };
 use super::super::{
     Cli, OutputStream, PaginationMeta, execute, execute_mcp, extract_json_from_sections,
@@ -112,6 +113,7 @@ fn search_json_with_meta_includes_next_commands() {
             genomic_coordinates: None,
             uniprot_id: None,
             omim_id: None,
+            species: None,
         }],
         pagination,
         vec![
@@ -144,6 +146,7 @@ fn search_json_with_meta_omits_meta_when_empty() {
             genomic_coordinates: None,
             uniprot_id: None,
             omim_id: None,
+            species: None,
         }],
         pagination,
         vec![String::new(), "   ".to_string()],
@@ -227,6 +230,78 @@ async fn canonical_gene_lookup_skips_discovery() {
     assert!(outcome.text.contains("# TP53"));
 }
 
+#[tokio::test]
+async fn raw_attaches_upstream_json_to_get_output_and_refuses_other_commands() {
+    let _guard = lock_env().await;
+    let mygene = MockServer::start().await;
+    let ols = MockServer::start().await;
+    let _mygene_base = set_env_var("BIOMCP_MYGENE_BASE", Some(&format!("{}/v3", mygene.uri())));
+    let _ols_base = set_env_var("BIOMCP_OLS4_BASE", Some(&ols.uri()));
+    let _umls_base = set_env_var("BIOMCP_UMLS_BASE", None);
+    let _umls_key = set_env_var("UMLS_API_KEY", None);
+
+    mount_gene_lookup_hit(&mygene, "TP53", "tumor protein p53", "7157").await;
+
+    let cli =
+        Cli::try_parse_from(["biomcp", "get", "gene", "TP53", "--raw", "mygene"]).expect("parse");
+    let outcome = run_outcome(cli).await.expect("success outcome");
+    assert_eq!(outcome.exit_code, 0);
+    let value: serde_json::Value = serde_json::from_str(&outcome.text).expect("json output");
+    assert_eq!(value["symbol"], "TP53");
+    let raw = &value["_raw"];
+    assert_eq!(raw["source"], "mygene");
+    assert_eq!(raw["responses"][0]["api"], "mygene.info");
+    assert_eq!(raw["responses"][0]["status"], 200);
+    assert_eq!(raw["responses"][0]["body"]["hits"][0]["entrezgene"], "7157");
+
+    let cli = Cli::try_parse_from(["biomcp", "search", "gene", "-q", "TP53", "--raw", "mygene"])
+        .expect("parse");
+    let outcome = run_outcome(cli).await.expect("refusal outcome");
+    assert_eq!(outcome.stream, OutputStream::Stderr);
+    assert_eq!(outcome.exit_code, 1);
+    assert!(outcome.text.contains("--raw applies only to get commands"));
+}
+
+#[tokio::test]
+async fn provenance_lists_upstream_calls_in_json_output() {
+    let _guard = lock_env().await;
+    let mygene = MockServer::start().await;
+    let ols = MockServer::start().await;
+    let _mygene_base = set_env_var("BIOMCP_MYGENE_BASE", Some(&format!("{}/v3", mygene.uri())));
+    let _ols_base = set_env_var("BIOMCP_OLS4_BASE", Some(&ols.uri()));
+    let _umls_base = set_env_var("BIOMCP_UMLS_BASE", None);
+    let _umls_key = set_env_var("UMLS_API_KEY", None);
+
+    mount_gene_lookup_hit(&mygene, "TP53", "tumor protein p53", "7157").await;
+
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "--no-cache",
+        "get",
+        "gene",
+        "TP53",
+        "--provenance",
+    ])
+    .expect("parse");
+    let outcome = run_outcome(cli).await.expect("success outcome");
+    assert_eq!(outcome.exit_code, 0);
+    assert!(outcome.diagnostics.is_none());
+    let value: serde_json::Value = serde_json::from_str(&outcome.text).expect("json output");
+    assert_eq!(value["symbol"], "TP53");
+    let calls = value["_provenance"].as_array().expect("provenance array");
+    let mygene_call = calls
+        .iter()
+        .find(|call| {
+            call["url"]
+                .as_str()
+                .is_some_and(|url| url.starts_with(&mygene.uri()))
+        })
+        .expect("mygene call");
+    assert_eq!(mygene_call["status"], 200);
+    assert_eq!(mygene_call["cache"], "miss");
+    assert!(mygene_call["elapsed_ms"].is_u64());
+}
+
 #[test]
 fn batch_gene_json_includes_meta_per_item() {
     std::thread::Builder::new()
@@ -383,12 +458,15 @@ async fn drug_alias_fallback_returns_exit_1_markdown_suggestion() {
     let _guard = lock_env().await;
     let mychem = MockServer::start().await;
     let ols = MockServer::start().await;
+    let nci_evs = MockServer::start().await;
     let _mychem_base = set_env_var("BIOMCP_MYCHEM_BASE", Some(&format!("{}/v1", mychem.uri())));
     let _ols_base = set_env_var("BIOMCP_OLS4_BASE", Some(&ols.uri()));
+    let _nci_evs_base = set_env_var("BIOMCP_NCI_EVS_BASE", Some(&nci_evs.uri()));
     let _umls_base = set_env_var("BIOMCP_UMLS_BASE", None);
     let _umls_key = set_env_var("UMLS_API_KEY", None);
 
     mount_drug_lookup_miss(&mychem, "Keytruda").await;
+    mount_ncit_miss(&nci_evs, "Keytruda").await;
     mount_ols_alias(
         &ols,
         "Keytruda",
@@ -418,12 +496,15 @@ async fn drug_alias_fallback_json_writes_stdout_and_exit_1() {
     let _guard = lock_env().await;
     let mychem = MockServer::start().await;
     let ols = MockServer::start().await;
+    let nci_evs = MockServer::start().await;
     let _mychem_base = set_env_var("BIOMCP_MYCHEM_BASE", Some(&format!("{}/v1", mychem.uri())));
     let _ols_base = set_env_var("BIOMCP_OLS4_BASE", Some(&ols.uri()));
+    let _nci_evs_base = set_env_var("BIOMCP_NCI_EVS_BASE", Some(&nci_evs.uri()));
     let _umls_base = set_env_var("BIOMCP_UMLS_BASE", None);
     let _umls_key = set_env_var("UMLS_API_KEY", None);
 
     mount_drug_lookup_miss(&mychem, "Keytruda").await;
+    mount_ncit_miss(&nci_evs, "Keytruda").await;
     mount_ols_alias(
         &ols,
         "Keytruda",
@@ -478,3 +559,120 @@ async fn execute_mcp_alias_suggestion_returns_structured_json_text() {
     assert_eq!(value["_meta"]["alias_resolution"]["kind"], "canonical");
     assert_eq!(value["_meta"]["alias_resolution"]["canonical"], "EGFR");
 }
+
+#[tokio::test]
+async fn execute_mcp_rejects_cache_dir_override() {
+    let err = execute_mcp(vec![
+        "biomcp".to_string(),
+        "--cache-dir".to_string(),
+        "/tmp/elsewhere".to_string(),
+        "get".to_string(),
+        "gene".to_string(),
+        "BRAF".to_string(),
+    ])
+    .await
+    .expect_err("--cache-dir should be rejected in MCP mode");
+
+    assert!(err.to_string().contains("--cache-dir is CLI-only"));
+}
+
+#[tokio::test]
+async fn execute_mcp_checks_submitted_jobs_for_cli_only_flags() {
+    for flag in ["--offline", "--cache-dir=/tmp/elsewhere"] {
+        let err = execute_mcp(
+            [
+                "biomcp", "jobs", "submit", "--", "get", "gene", "BRAF", flag,
+            ]
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+        )
+        .await
+        .expect_err("CLI-only flags should be rejected inside MCP jobs");
+
+        assert!(err.to_string().contains("is CLI-only"), "{flag}: {err}");
+    }
+}
+
+#[tokio::test]
+async fn max_output_tokens_truncates_markdown_and_rejects_tiny_budgets() {
+    assert!(Cli::try_parse_from(["biomcp", "--max-output-tokens", "50", "list"]).is_err());
+
+    let full = run_outcome(Cli::try_parse_from(["biomcp", "list"]).expect("list should parse"))
+        .await
+        .expect("list should render");
+    let cli = Cli::try_parse_from(["biomcp", "list", "--max-output-tokens", "300"])
+        .expect("global budget flag should parse after the subcommand");
+    let budgeted = run_outcome(cli).await.expect("list should render");
+
+    assert!(budgeted.text.len() < full.text.len());
+    assert!(
+        budgeted
+            .text
+            .contains("**Truncated** to fit `--max-output-tokens 300`")
+    );
+}
+
+#[tokio::test]
+async fn offline_serves_snapshot_genes_and_refuses_everything_else() {
+    let _guard = lock_env().await;
+    let root = TempDirGuard::new("offline-snapshot");
+    let dir = root.path().join("bundle");
+    let mut writer = crate::utils::snapshot::SnapshotWriter::create(&dir).expect("snapshot writer");
+    writer
+        .add(
+            crate::utils::snapshot::SnapshotEntity::Gene,
+            &["BRAF"],
+            &[],
+            &serde_json::json!({
+                "symbol": "BRAF",
+                "name": "B-Raf proto-oncogene, serine/threonine kinase",
+                "entrez_id": "673",
+                "aliases": ["BRAF1", "RAFB1"]
+            }),
+        )
+        .expect("add gene");
+    writer
+        .finish("2026-10-16T00:00:00Z".into())
+        .expect("finish bundle");
+    let _snapshot_dir = set_env_var("BIOMCP_SNAPSHOT_DIR", dir.to_str());
+    let _mygene = set_env_var("BIOMCP_MYGENE_BASE", Some("http://127.0.0.1:9"));
+
+    let cli = Cli::try_parse_from(["biomcp", "--offline", "get", "gene", "braf"])
+        .expect("offline get should parse");
+    let outcome = run_outcome(cli).await.expect("offline gene card");
+    assert!(outcome.text.contains("B-Raf proto-oncogene"));
+
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "--offline",
+        "--json",
+        "search",
+        "gene",
+        "-q",
+        "RAFB1",
+    ])
+    .expect("offline search should parse");
+    let outcome = run_outcome(cli).await.expect("offline gene search");
+    let value: serde_json::Value = serde_json::from_str(&outcome.text).expect("search json");
+    assert_eq!(value["results"][0]["symbol"], "BRAF");
+
+    let cli = Cli::try_parse_from(["biomcp", "--offline", "get", "gene", "TP53"])
+        .expect("offline get should parse");
+    let err = run_outcome(cli).await.expect_err("uncovered gene");
+    assert!(
+        err.to_string()
+            .contains("gene 'TP53' is not in the snapshot")
+    );
+
+    let cli = Cli::try_parse_from(["biomcp", "--offline", "search", "trial", "-c", "melanoma"])
+        .expect("offline trial search should parse");
+    let outcome = run_outcome(cli).await.expect("refusal outcome");
+    assert_eq!(outcome.stream, OutputStream::Stderr);
+    assert_eq!(outcome.exit_code, 1);
+    assert!(
+        outcome
+            .text
+            .contains("--offline serves only get/search gene")
+    );
+}
@@ -0,0 +1,21 @@
+use super::TranscriptGetArgs;
+use crate::cli::CommandOutcome;
+
+pub(in crate::cli) async fn handle_get(
+    args: TranscriptGetArgs,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let transcript = crate::entities::transcript::get(&args.id).await?;
+    let text = if json {
+        crate::render::json::to_entity_json(
+            &transcript,
+            crate::render::markdown::transcript_evidence_urls(&transcript),
+            crate::render::markdown::related_transcript(&transcript),
+            crate::render::provenance::transcript_section_sources(&transcript),
+            Vec::new(),
+        )?
+    } else {
+        crate::render::markdown::transcript_markdown(&transcript)?
+    };
+    Ok(CommandOutcome::stdout(text))
+}
@@ -0,0 +1,15 @@
+//! Ensembl transcript CLI payloads.
+
+use clap::Args;
+
+#[derive(Args, Debug)]
+pub struct TranscriptGetArgs {
+    /// Ensembl transcript ID, with or without version (e.g., ENST00000288602)
+    pub id: String,
+}
+
+mod dispatch;
+pub(super) use self::dispatch::handle_get;
+
+#[cfg(test)]
+mod tests;
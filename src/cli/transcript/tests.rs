@@ -0,0 +1,35 @@
+use clap::Parser;
+
+use crate::cli::{Cli, Commands, GetEntity};
+
+#[test]
+fn get_transcript_parses_versioned_ids() {
+    let cli = Cli::try_parse_from(["biomcp", "get", "transcript", "ENST00000288602.11"])
+        .expect("get should parse");
+    let Cli {
+        command:
+            Commands::Get {
+                entity: GetEntity::Transcript(crate::cli::transcript::TranscriptGetArgs { id }),
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected get transcript command");
+    };
+    assert_eq!(id, "ENST00000288602.11");
+
+    assert!(Cli::try_parse_from(["biomcp", "get", "transcript"]).is_err());
+}
+
+#[tokio::test]
+async fn handle_get_rejects_gene_ids_before_backend_lookup() {
+    let err = super::handle_get(
+        crate::cli::transcript::TranscriptGetArgs {
+            id: "ENSG00000157764".to_string(),
+        },
+        false,
+    )
+    .await
+    .expect_err("gene ID should fail fast");
+    assert!(err.to_string().contains("Invalid Ensembl transcript ID"));
+}
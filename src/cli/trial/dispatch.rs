@@ -5,21 +5,41 @@ pub(in crate::cli) async fn handle_get(
     args: TrialGetArgs,
     json: bool,
 ) -> anyhow::Result<CommandOutcome> {
-    let (sections, location_offset, location_limit) = parse_trial_location_paging(&args.sections)?;
+    let (sections, mut location_offset, location_limit) =
+        parse_trial_location_paging(&args.sections)?;
     let (sections, json_override) = super::super::extract_json_from_sections(&sections);
     let json_output = json || json_override;
     let trial_source = crate::entities::trial::TrialSource::from_flag(&args.source)?;
     let includes_locations = sections
         .iter()
         .any(|section| section.trim().eq_ignore_ascii_case("locations"));
-    if !includes_locations && (location_offset.is_some() || location_limit.is_some()) {
+    let includes_eligibility = sections
+        .iter()
+        .any(|section| section.trim().eq_ignore_ascii_case("eligibility"));
+    // `--offset` pages locations when requested, otherwise long eligibility text.
+    let eligibility_offset = if includes_locations {
+        0
+    } else if includes_eligibility && location_limit.is_none() {
+        location_offset.take().unwrap_or(0)
+    } else if location_offset.is_some() || location_limit.is_some() {
         return Err(crate::error::BioMcpError::InvalidArgument(
-            "--offset and --limit are only valid with the 'locations' section".into(),
+            "--offset and --limit are only valid with the 'locations' section (--offset also pages the 'eligibility' section)".into(),
         )
         .into());
-    }
+    } else {
+        0
+    };
 
-    let mut trial = crate::entities::trial::get(&args.nct_id, &sections, trial_source).await?;
+    let (trial, section_errors) = crate::entities::section_errors::collect(
+        crate::entities::trial::get_with_eligibility_offset(
+            &args.nct_id,
+            &sections,
+            trial_source,
+            eligibility_offset,
+        ),
+    )
+    .await;
+    let mut trial = trial?;
     let mut location_pagination = None;
     if includes_locations {
         let offset = location_offset.unwrap_or(0);
@@ -29,13 +49,14 @@ pub(in crate::cli) async fn handle_get(
 
     let text = if json_output {
         if let Some(loc_page) = location_pagination {
-            trial_locations_json(&trial, loc_page)?
+            trial_locations_json(&trial, loc_page, section_errors)?
         } else {
             crate::render::json::to_entity_json(
                 &trial,
                 crate::render::markdown::trial_evidence_urls(&trial),
                 crate::render::markdown::related_trial(&trial),
                 crate::render::provenance::trial_section_sources(&trial),
+                section_errors,
             )?
         }
     } else {
@@ -84,8 +105,9 @@ pub(in crate::cli) async fn handle_search(
     let progression_on = super::super::normalize_cli_tokens(args.progression_on);
     let sponsor = super::super::normalize_cli_tokens(args.sponsor);
     let trial_source = crate::entities::trial::TrialSource::from_flag(&args.source)?;
-    let filters = crate::entities::trial::TrialSearchFilters {
+    let mut filters = crate::entities::trial::TrialSearchFilters {
         condition,
+        condition_synonyms: Vec::new(),
         intervention,
         facility,
         status: args.status,
@@ -93,6 +115,7 @@ pub(in crate::cli) async fn handle_search(
         study_type: args.study_type,
         age: args.age,
         sex: args.sex,
+        ecog: args.ecog,
         sponsor,
         sponsor_type: args.sponsor_type,
         date_from: args.date_from,
@@ -123,7 +146,34 @@ pub(in crate::cli) async fn handle_search(
         .into());
     }
 
+    if !args.no_expand
+        && matches!(
+            trial_source,
+            crate::entities::trial::TrialSource::ClinicalTrialsGov
+        )
+        && let Some(condition) = filters.condition.as_deref()
+    {
+        filters.condition_synonyms = crate::entities::trial::condition_synonyms(condition).await?;
+    }
+
     let query = trial_search_query_summary(&filters, args.offset, args.next_page.as_deref());
+    if let Some(summarize_by) = args.summarize_by.as_deref() {
+        let by = crate::entities::trial::TrialSummaryBy::from_flag(summarize_by)?;
+        if args.count_only || args.offset > 0 || args.next_page.is_some() {
+            return Err(crate::error::BioMcpError::InvalidArgument(
+                "--summarize-by cannot be combined with --count-only, --offset, or --next-page"
+                    .into(),
+            )
+            .into());
+        }
+        let summary = crate::entities::trial::summarize(&filters, by, args.limit).await?;
+        let text = if json {
+            crate::render::json::to_pretty(&summary)?
+        } else {
+            crate::render::markdown::trial_summary_markdown(&query, &summary)?
+        };
+        return Ok(CommandOutcome::stdout(text));
+    }
     let text = if args.count_only {
         let count = crate::entities::trial::count_all(&filters).await?;
         if json {
@@ -274,6 +324,7 @@ pub(super) struct LocationPaginationMeta {
 pub(super) fn trial_locations_json(
     trial: &crate::entities::trial::Trial,
     location_pagination: LocationPaginationMeta,
+    section_errors: Vec<crate::entities::section_errors::SectionError>,
 ) -> anyhow::Result<String> {
     #[derive(serde::Serialize)]
     struct TrialWithLocationPagination<'a> {
@@ -290,6 +341,7 @@ pub(super) fn trial_locations_json(
         crate::render::markdown::trial_evidence_urls(trial),
         crate::render::markdown::related_trial(trial),
         crate::render::provenance::trial_section_sources(trial),
+        section_errors,
     )
     .map_err(Into::into)
 }
@@ -322,6 +374,8 @@ pub(super) fn trial_search_query_summary(
             .condition
             .as_deref()
             .map(|v| format!("condition={v}")),
+        (!filters.condition_synonyms.is_empty())
+            .then(|| format!("expanded={}", filters.condition_synonyms.join("; "))),
         filters
             .intervention
             .as_deref()
@@ -329,6 +383,7 @@ pub(super) fn trial_search_query_summary(
         filters.facility.as_deref().map(|v| format!("facility={v}")),
         filters.age.map(|v| format!("age={v}")),
         filters.sex.as_deref().map(|v| format!("sex={v}")),
+        filters.ecog.map(|v| format!("ecog={v}")),
         filters.status.as_deref().map(|v| format!("status={v}")),
         filters.phase.as_deref().map(|v| format!("phase={v}")),
         filters
@@ -368,6 +423,8 @@ pub(super) fn trial_search_query_summary(
         filters.distance.map(|v| format!("distance={v}")),
         matches!(filters.source, crate::entities::trial::TrialSource::NciCts)
             .then(|| "source=nci".to_string()),
+        matches!(filters.source, crate::entities::trial::TrialSource::Ctis)
+            .then(|| "source=ctis".to_string()),
         filters
             .results_available
             .then(|| "has_results=true".to_string()),
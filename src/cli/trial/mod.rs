@@ -10,6 +10,14 @@ pub struct TrialSearchArgs {
     /// Optional positional query alias for -c/--condition
     #[arg(value_name = "QUERY")]
     pub positional_query: Option<String>,
+    /// Search the condition exactly as given (ctgov only).
+    ///
+    /// By default BioMCP grounds `--condition` to a MONDO disease and ORs its
+    /// exact MONDO and NCI Thesaurus synonyms into the ClinicalTrials.gov
+    /// query, so "stomach cancer" also matches "gastric carcinoma". The
+    /// synonyms used are listed in the query summary.
+    #[arg(long = "no-expand")]
+    pub no_expand: bool,
     /// Filter by intervention/drug
     #[arg(short = 'i', long, num_args = 1..)]
     pub intervention: Vec<String>,
@@ -46,6 +54,14 @@ pub struct TrialSearchArgs {
     /// apply an actual restriction.
     #[arg(long)]
     pub sex: Option<String>,
+    /// Patient ECOG performance status (0-4) for eligibility matching.
+    ///
+    /// ClinicalTrials.gov has no structured ECOG field, so BioMCP reads each
+    /// trial's inclusion criteria and drops trials whose stated ECOG limit is
+    /// below this value. Trials with a performance-status requirement it
+    /// cannot read are kept and flagged as not machine-checked.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(0..=4))]
+    pub ecog: Option<u8>,
     /// Filter by trial status [values: recruiting, not_yet_recruiting, enrolling_by_invitation, active_not_recruiting, completed, suspended, terminated, withdrawn]
     #[arg(short = 's', long)]
     pub status: Option<String>,
@@ -94,7 +110,10 @@ pub struct TrialSearchArgs {
     /// Return only total count (no result table)
     #[arg(long = "count-only")]
     pub count_only: bool,
-    /// Trial data source (ctgov or nci)
+    /// Count matching trials per group instead of listing them; `--limit` caps the groups shown [values: condition, phase, status, sponsor]
+    #[arg(long = "summarize-by", value_parser = ["condition", "phase", "status", "sponsor"])]
+    pub summarize_by: Option<String>,
+    /// Trial data source (ctgov, nci, or ctis for the EU Clinical Trials Information System)
     #[arg(long, default_value = "ctgov")]
     pub source: String,
     /// Skip the first N results (pagination)
@@ -112,7 +131,7 @@ pub struct TrialSearchArgs {
 pub struct TrialGetArgs {
     /// ClinicalTrials.gov identifier (e.g., NCT02693535)
     pub nct_id: String,
-    /// Sections to include (eligibility, locations, outcomes, arms, references, all)
+    /// Sections to include (eligibility, locations, outcomes, arms, references, publications, results, all)
     #[arg(trailing_var_arg = true)]
     pub sections: Vec<String>,
     /// Trial data source (ctgov or nci)
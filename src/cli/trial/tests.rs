@@ -94,6 +94,7 @@ fn search_trial_parses_positional_query() {
                         study_type,
                         age,
                         sex,
+                        ecog,
                         status,
                         mutation,
                         criteria,
@@ -110,6 +111,7 @@ fn search_trial_parses_positional_query() {
                         distance,
                         results_available,
                         count_only,
+                        summarize_by,
                         source,
                         offset,
                         next_page,
@@ -130,6 +132,7 @@ fn search_trial_parses_positional_query() {
     assert_eq!(study_type, None);
     assert_eq!(age, None);
     assert_eq!(sex, None);
+    assert_eq!(ecog, None);
     assert_eq!(status, None);
     assert!(mutation.is_empty());
     assert!(criteria.is_empty());
@@ -146,6 +149,7 @@ fn search_trial_parses_positional_query() {
     assert_eq!(distance, None);
     assert!(!results_available);
     assert!(!count_only);
+    assert_eq!(summarize_by, None);
     assert_eq!(source, "ctgov");
     assert_eq!(offset, 0);
     assert_eq!(next_page, None);
@@ -266,6 +270,30 @@ fn search_trial_rejects_non_numeric_age() {
     assert!(rendered.contains("invalid float literal"));
 }
 
+#[test]
+fn search_trial_parses_ecog_and_rejects_out_of_range_scores() {
+    let cli = Cli::try_parse_from(["biomcp", "search", "trial", "-c", "nsclc", "--ecog", "1"])
+        .expect("search trial should parse");
+    let Cli {
+        command:
+            Commands::Search {
+                entity: SearchEntity::Trial(crate::cli::trial::TrialSearchArgs { ecog, .. }),
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected search trial command");
+    };
+    assert_eq!(ecog, Some(1));
+
+    let err = Cli::try_parse_from(["biomcp", "search", "trial", "--ecog", "5"])
+        .expect_err("ECOG above 4 should fail");
+    assert!(
+        err.to_string()
+            .contains("invalid value '5' for '--ecog <ECOG>'")
+    );
+}
+
 #[test]
 fn search_trial_parses_unquoted_multi_token_mutation() {
     let cli = Cli::try_parse_from([
@@ -366,6 +394,41 @@ async fn handle_search_rejects_next_page_with_offset() {
     );
 }
 
+#[tokio::test]
+async fn handle_search_rejects_summarize_by_with_count_only() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "trial",
+        "--sponsor",
+        "Genentech",
+        "--summarize-by",
+        "condition",
+        "--count-only",
+    ])
+    .expect("search trial should parse");
+
+    let Cli {
+        command: Commands::Search {
+            entity: SearchEntity::Trial(args),
+        },
+        json,
+        ..
+    } = cli
+    else {
+        panic!("expected trial search command");
+    };
+    assert_eq!(args.summarize_by.as_deref(), Some("condition"));
+
+    let err = super::handle_search(args, json)
+        .await
+        .expect_err("summarize-by plus count-only should fail fast");
+    assert!(
+        err.to_string()
+            .contains("--summarize-by cannot be combined with --count-only")
+    );
+}
+
 #[test]
 fn parse_trial_location_paging_extracts_offset_limit_flags() {
     let sections = vec![
@@ -381,6 +444,29 @@ fn parse_trial_location_paging_extracts_offset_limit_flags() {
     assert_eq!(limit, Some(10));
 }
 
+#[tokio::test]
+async fn handle_get_rejects_limit_without_locations_section() {
+    let args = crate::cli::trial::TrialGetArgs {
+        nct_id: "NCT02576665".into(),
+        sections: vec![
+            "eligibility".into(),
+            "--offset".into(),
+            "1".into(),
+            "--limit".into(),
+            "5".into(),
+        ],
+        source: "ctgov".into(),
+    };
+
+    let err = super::handle_get(args, false)
+        .await
+        .expect_err("--limit should require the locations section");
+    assert!(
+        err.to_string()
+            .contains("--offset also pages the 'eligibility' section")
+    );
+}
+
 #[test]
 fn trial_locations_json_preserves_location_pagination_and_section_sources() {
     let trial = crate::entities::trial::Trial {
@@ -411,6 +497,8 @@ fn trial_locations_json_preserves_location_pagination_and_section_sources() {
         outcomes: None,
         arms: None,
         references: None,
+        publications: None,
+        results: None,
     };
 
     let json = trial_locations_json(
@@ -421,6 +509,7 @@ fn trial_locations_json_preserves_location_pagination_and_section_sources() {
             limit: 10,
             has_more: true,
         },
+        Vec::new(),
     )
     .expect("trial locations json");
 
@@ -467,6 +556,8 @@ fn paginate_trial_locations_handles_missing_locations() {
         outcomes: None,
         arms: None,
         references: None,
+        publications: None,
+        results: None,
     };
 
     let meta = paginate_trial_locations(&mut trial, 20, 10);
@@ -523,6 +614,43 @@ fn trial_search_query_summary_includes_nci_source_marker() {
     assert!(summary.contains("source=nci"));
 }
 
+#[test]
+fn trial_search_query_summary_lists_condition_synonyms() {
+    let summary = trial_search_query_summary(
+        &crate::entities::trial::TrialSearchFilters {
+            condition: Some("stomach cancer".into()),
+            condition_synonyms: vec!["gastric cancer".into(), "Gastric Carcinoma".into()],
+            ..Default::default()
+        },
+        0,
+        None,
+    );
+    assert_eq!(
+        summary,
+        "condition=stomach cancer, expanded=gastric cancer; Gastric Carcinoma"
+    );
+
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "trial",
+        "-c",
+        "stomach cancer",
+        "--no-expand",
+    ])
+    .expect("--no-expand should parse");
+    let Cli {
+        command: Commands::Search {
+            entity: SearchEntity::Trial(args),
+        },
+        ..
+    } = cli
+    else {
+        panic!("expected trial search command");
+    };
+    assert!(args.no_expand);
+}
+
 #[test]
 fn trial_zero_result_nickname_hint_requires_positional_ctgov_query_with_zero_results() {
     use crate::entities::trial::TrialSource;
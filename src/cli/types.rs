@@ -4,7 +4,9 @@ use std::path::PathBuf;
 
 use clap::{Args, Parser, ValueEnum};
 
+use crate::entities::adverse_event::AdverseEventSpecies;
 use crate::entities::drug::DrugRegion;
+use crate::render::markdown::MarkdownTheme;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -24,6 +26,56 @@ pub struct Cli {
     /// Disable HTTP caching (always fetch fresh data)
     #[arg(long, global = true)]
     pub no_cache: bool,
+
+    /// Cache root directory for this run (overrides BIOMCP_CACHE_DIR and cache.toml)
+    #[arg(long, global = true, value_name = "DIR")]
+    pub cache_dir: Option<String>,
+
+    /// Report cache hits, ETag revalidations (304), full fetches, and bytes transferred on stderr
+    #[arg(long)]
+    pub verbose: bool,
+
+    /// Markdown theme: plain (default), rich (TOC + anchors), or agent-compact (fewest tokens)
+    #[arg(long, global = true, value_enum, value_name = "THEME")]
+    pub md_theme: Option<MarkdownThemeArg>,
+
+    /// Approximate token budget for markdown output; long text, then trailing sections, are cut to fit
+    #[arg(
+        long,
+        global = true,
+        value_name = "N",
+        value_parser = clap::value_parser!(u32).range(200..=1_000_000)
+    )]
+    pub max_output_tokens: Option<u32>,
+
+    /// Serve get/search gene and variant from the `biomcp snapshot create` bundle without network access
+    #[arg(long, global = true)]
+    pub offline: bool,
+
+    /// Attach the unprocessed upstream JSON from one source (e.g. myvariant) to get output as `_raw`; implies --json
+    #[arg(long, global = true, value_name = "SOURCE")]
+    pub raw: Option<String>,
+
+    /// Attach a `_provenance` list of upstream calls (masked URL, status, cache hit/miss, response time) to JSON output; implies --json
+    #[arg(long, global = true)]
+    pub provenance: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MarkdownThemeArg {
+    Plain,
+    Rich,
+    AgentCompact,
+}
+
+impl From<MarkdownThemeArg> for MarkdownTheme {
+    fn from(value: MarkdownThemeArg) -> Self {
+        match value {
+            MarkdownThemeArg::Plain => MarkdownTheme::Plain,
+            MarkdownThemeArg::Rich => MarkdownTheme::Rich,
+            MarkdownThemeArg::AgentCompact => MarkdownTheme::AgentCompact,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
@@ -138,6 +190,23 @@ impl From<DrugRegionArg> for DrugRegion {
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum SpeciesArg {
+    #[default]
+    Human,
+    #[value(alias = "vet", alias = "animal")]
+    Veterinary,
+}
+
+impl From<SpeciesArg> for AdverseEventSpecies {
+    fn from(value: SpeciesArg) -> Self {
+        match value {
+            SpeciesArg::Human => AdverseEventSpecies::Human,
+            SpeciesArg::Veterinary => AdverseEventSpecies::Veterinary,
+        }
+    }
+}
+
 #[derive(Args, Debug, Clone, PartialEq, Default)]
 pub struct ChartArgs {
     #[arg(
@@ -272,6 +341,8 @@ pub struct CommandOutcome {
     pub text: String,
     pub stream: OutputStream,
     pub exit_code: u8,
+    /// Extra stderr text printed after `text` (e.g. `--verbose` request stats).
+    pub diagnostics: Option<String>,
 }
 
 impl CommandOutcome {
@@ -280,6 +351,7 @@ impl CommandOutcome {
             text,
             stream: OutputStream::Stdout,
             exit_code: 0,
+            diagnostics: None,
         }
     }
 
@@ -288,6 +360,7 @@ impl CommandOutcome {
             text,
             stream: OutputStream::Stdout,
             exit_code,
+            diagnostics: None,
         }
     }
 
@@ -296,6 +369,7 @@ impl CommandOutcome {
             text,
             stream: OutputStream::Stderr,
             exit_code,
+            diagnostics: None,
         }
     }
 }
@@ -0,0 +1,19 @@
+use super::ValidateCommand;
+use crate::cli::CommandOutcome;
+
+pub(in crate::cli) async fn handle_command(
+    cmd: ValidateCommand,
+    json: bool,
+) -> anyhow::Result<CommandOutcome> {
+    let text = match cmd {
+        ValidateCommand::Variant { description } => {
+            let report = crate::entities::variant::validate_variant(&description).await?;
+            if json {
+                crate::render::json::to_pretty(&report)?
+            } else {
+                crate::render::markdown::variant_validation_markdown(&report)?
+            }
+        }
+    };
+    Ok(CommandOutcome::stdout(text))
+}
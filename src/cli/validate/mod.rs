@@ -0,0 +1,35 @@
+//! Nomenclature validation CLI subcommands.
+
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+pub enum ValidateCommand {
+    /// Validate and normalize an HGVS variant description (VariantValidator)
+    #[command(
+        visible_alias = "hgvs",
+        after_help = "\
+EXAMPLES:
+  biomcp validate hgvs \"NM_004333.4:c.1799T>A\"
+  biomcp validate variant \"NM_000546.6:c.215C>G\"
+  biomcp validate variant \"NM_007294.4(BRCA1):c.68_69del\"
+  biomcp --json validate variant \"NC_000017.11:g.7676154G>C\"
+
+Checks HGVS syntax locally, then asks VariantValidator to confirm the stated
+reference allele, apply HGVS 3' shifting, and project the variant onto GRCh38,
+GRCh37, left-aligned VCF, and the predicted protein change.
+Malformed input lists suggested corrections (letter case, arrows, a missing
+':' or c./g. prefix) instead of calling the service.
+Protein (p.) descriptions are syntax-checked only.
+See also: biomcp get variant"
+    )]
+    Variant {
+        /// HGVS description with a versioned RefSeq, Ensembl, or LRG reference
+        description: String,
+    },
+}
+
+mod dispatch;
+pub(super) use self::dispatch::handle_command;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,50 @@
+use clap::Parser;
+
+use super::ValidateCommand;
+use crate::cli::{Cli, Commands};
+
+#[test]
+fn validate_variant_parses_hgvs_description() {
+    let cli = Cli::try_parse_from(["biomcp", "validate", "variant", "NM_000546.6:c.215C>G"])
+        .expect("validate variant should parse");
+
+    let Cli {
+        command:
+            Commands::Validate {
+                cmd: ValidateCommand::Variant { description },
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected validate variant command");
+    };
+    assert_eq!(description, "NM_000546.6:c.215C>G");
+}
+
+#[test]
+fn validate_hgvs_is_an_alias_for_validate_variant() {
+    let cli = Cli::try_parse_from(["biomcp", "validate", "hgvs", "NM_004333.4:c.1799T>A"])
+        .expect("validate hgvs should parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Validate {
+            cmd: ValidateCommand::Variant { description },
+        } if description == "NM_004333.4:c.1799T>A"
+    ));
+}
+
+#[tokio::test]
+async fn handle_command_renders_syntax_errors_as_invalid_reports() {
+    let outcome = super::handle_command(
+        ValidateCommand::Variant {
+            description: "BRAF V600E".into(),
+        },
+        true,
+    )
+    .await
+    .expect("syntax errors are reported, not raised");
+    let value: serde_json::Value = serde_json::from_str(&outcome.text).expect("json");
+    assert_eq!(value["status"], "invalid");
+    assert_eq!(value["syntax_valid"], false);
+    assert_eq!(value["reference_check"], "not_checked");
+}
@@ -1,8 +1,8 @@
 use super::{VariantCommand, VariantGetArgs, VariantSearchArgs};
 use crate::cli::CommandOutcome;
 use crate::cli::{
-    PaginationMeta, empty_sections, normalize_cli_query, pagination_footer_offset,
-    search_json_with_meta,
+    PaginationMeta, SearchJsonMeta, empty_sections, normalize_cli_query, pagination_footer_offset,
+    search_json_with_meta, search_meta,
 };
 
 pub(crate) async fn handle_get(
@@ -12,7 +12,19 @@ pub(crate) async fn handle_get(
 ) -> anyhow::Result<CommandOutcome> {
     let (sections, json_override) = super::super::extract_json_from_sections(&args.sections);
     let json_output = json || json_override;
-    render_variant_card_outcome(&args.id, &sections, json_output, alias_suggestions_as_json).await
+    let source = crate::entities::variant::VariantAnnotationSource::from_flag(&args.source)?;
+    let assembly = crate::entities::variant::GenomeAssembly::from_flag(&args.assembly)?;
+    let gnomad_version = crate::entities::variant::GnomadVersion::from_flag(&args.gnomad_version)?;
+    render_variant_card_outcome(
+        &args.id,
+        &sections,
+        source,
+        assembly,
+        gnomad_version,
+        json_output,
+        alias_suggestions_as_json,
+    )
+    .await
 }
 
 pub(crate) async fn handle_search(
@@ -42,6 +54,8 @@ pub(crate) async fn handle_search(
             has: args.has,
             missing: args.missing,
             therapy: args.therapy,
+            rank_profile: args.rank_profile,
+            group_by: args.group_by,
             limit: args.limit,
             offset: args.offset,
         },
@@ -105,6 +119,11 @@ pub(crate) async fn handle_command(
                 crate::entities::variant::VariantIdFormat::GeneProteinChange { gene, change } => {
                     (Some(gene), Some(change))
                 }
+                crate::entities::variant::VariantIdFormat::ClinGenAllele(id)
+                | crate::entities::variant::VariantIdFormat::HgvsReference(id) => {
+                    let variant = crate::entities::variant::get(&id, empty_sections()).await?;
+                    (None, Some(variant.rsid.unwrap_or(variant.id)))
+                }
             };
 
             let filters = crate::entities::article::ArticleSearchFilters {
@@ -167,6 +186,44 @@ pub(crate) async fn handle_command(
                 crate::render::markdown::variant_oncokb_markdown(&result)
             }
         }
+        VariantCommand::ExportClinvarTemplate {
+            id,
+            classification,
+            condition,
+        } => {
+            crate::entities::variant::parse_clinvar_classification(&classification)?;
+            let sections =
+                ["clinvar", "population", "predictions", "transcripts"].map(str::to_string);
+            let variant = crate::entities::variant::get(&id, &sections).await?;
+            let citations = variant_citation_pmids(&variant).await;
+            let draft = crate::entities::variant::clinvar_submission_draft(
+                &variant,
+                &classification,
+                condition.as_deref(),
+                &citations,
+            )?;
+            if json {
+                crate::render::json::to_pretty(&draft)?
+            } else {
+                draft.to_tsv()
+            }
+        }
+        VariantCommand::CompareAf {
+            id,
+            cases,
+            controls,
+        } => {
+            let cases = crate::entities::variant::AlleleCount::parse("--cases", &cases)?;
+            let controls = crate::entities::variant::AlleleCount::parse("--controls", &controls)?;
+            let sections = ["population".to_string()];
+            let variant = crate::entities::variant::get(&id, &sections).await?;
+            let comparison = crate::entities::variant::compare_cohort_af(&variant, cases, controls);
+            if json {
+                crate::render::json::to_pretty(&comparison)?
+            } else {
+                crate::render::markdown::variant_compare_af_markdown(&comparison)?
+            }
+        }
         VariantCommand::External(args) => {
             let id = args.join(" ");
             let variant =
@@ -177,6 +234,7 @@ pub(crate) async fn handle_command(
                     crate::render::markdown::variant_evidence_urls(&variant),
                     crate::render::markdown::related_variant(&variant),
                     crate::render::provenance::variant_section_sources(&variant),
+                    Vec::new(),
                 )?
             } else {
                 crate::render::markdown::variant_markdown(&variant, super::super::empty_sections())?
@@ -277,6 +335,8 @@ struct VariantSearchRequest {
     has: Option<String>,
     missing: Option<String>,
     therapy: Option<String>,
+    rank_profile: Option<String>,
+    group_by: Option<String>,
     limit: usize,
     offset: usize,
 }
@@ -516,6 +576,9 @@ fn variant_guidance_outcome(
 async fn render_variant_card_outcome(
     id: &str,
     sections: &[String],
+    source: crate::entities::variant::VariantAnnotationSource,
+    assembly: crate::entities::variant::GenomeAssembly,
+    gnomad_version: crate::entities::variant::GnomadVersion,
     json_output: bool,
     guidance_as_json: bool,
 ) -> anyhow::Result<CommandOutcome> {
@@ -523,7 +586,11 @@ async fn render_variant_card_outcome(
         return variant_guidance_outcome(&guidance, json_output || guidance_as_json);
     }
 
-    match crate::entities::variant::get(id, sections).await {
+    let (variant, section_errors) = crate::entities::section_errors::collect(
+        crate::entities::variant::get_with_source(id, sections, source, assembly, gnomad_version),
+    )
+    .await;
+    match variant {
         Ok(variant) => {
             let text = if json_output {
                 crate::render::json::to_entity_json(
@@ -531,6 +598,7 @@ async fn render_variant_card_outcome(
                     crate::render::markdown::variant_evidence_urls(&variant),
                     crate::render::markdown::related_variant(&variant),
                     crate::render::provenance::variant_section_sources(&variant),
+                    section_errors,
                 )?
             } else {
                 crate::render::markdown::variant_markdown(&variant, sections)?
@@ -565,10 +633,16 @@ async fn render_variant_search_outcome(
         has,
         missing,
         therapy,
+        rank_profile,
+        group_by,
         limit,
         offset,
     } = request;
 
+    let group_by = group_by
+        .as_deref()
+        .map(crate::entities::variant::VariantSearchGroupBy::from_flag)
+        .transpose()?;
     let resolved =
         match resolve_variant_query(gene, hgvsp, consequence, condition, positional_query)? {
             VariantSearchPlan::Standard(resolved) => resolved,
@@ -598,6 +672,7 @@ async fn render_variant_search_outcome(
         has,
         missing,
         therapy,
+        rank_profile,
     };
 
     let mut query = crate::entities::variant::search_query_summary(&filters);
@@ -618,6 +693,14 @@ async fn render_variant_search_outcome(
             filters.gene.as_deref(),
             filters.condition.as_deref(),
         );
+        if let Some(group_by) = group_by {
+            return Ok(CommandOutcome::stdout(grouped_search_json(
+                &results,
+                group_by,
+                pagination,
+                next_commands,
+            )?));
+        }
         return Ok(CommandOutcome::stdout(search_json_with_meta(
             results,
             pagination,
@@ -626,15 +709,50 @@ async fn render_variant_search_outcome(
     }
 
     let footer = pagination_footer_offset(&pagination);
-    Ok(CommandOutcome::stdout(
-        crate::render::markdown::variant_search_markdown_with_context(
+    let markdown = match group_by {
+        Some(group_by) => crate::render::markdown::variant_search_grouped_markdown_with_context(
             &query,
             &results,
+            group_by,
             &footer,
             filters.gene.as_deref(),
             filters.condition.as_deref(),
         )?,
-    ))
+        None => crate::render::markdown::variant_search_markdown_with_context(
+            &query,
+            &results,
+            &footer,
+            filters.gene.as_deref(),
+            filters.condition.as_deref(),
+        )?,
+    };
+    Ok(CommandOutcome::stdout(markdown))
+}
+
+#[derive(serde::Serialize)]
+struct GroupedVariantSearchResponse {
+    pagination: PaginationMeta,
+    count: usize,
+    group_by: crate::entities::variant::VariantSearchGroupBy,
+    groups: Vec<crate::entities::variant::VariantSearchGroup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _meta: Option<SearchJsonMeta>,
+}
+
+fn grouped_search_json(
+    results: &[crate::entities::variant::VariantSearchResult],
+    group_by: crate::entities::variant::VariantSearchGroupBy,
+    pagination: PaginationMeta,
+    next_commands: Vec<String>,
+) -> anyhow::Result<String> {
+    crate::render::json::to_pretty(&GroupedVariantSearchResponse {
+        pagination,
+        count: results.len(),
+        group_by,
+        groups: crate::entities::variant::group_search_results(results, group_by),
+        _meta: search_meta(next_commands),
+    })
+    .map_err(Into::into)
 }
 
 pub(super) fn trim_protein_change_prefix(value: &str) -> &str {
@@ -686,3 +804,31 @@ async fn variant_trial_mutation_query(id: &str) -> String {
 
     id.to_string()
 }
+
+const CLINVAR_CITATION_SEARCH_LIMIT: usize = 5;
+
+/// Best-effort PMIDs for the ClinVar citations column; search failures only warn.
+async fn variant_citation_pmids(variant: &crate::entities::variant::Variant) -> Vec<String> {
+    let gene = variant.gene.trim();
+    let protein = variant
+        .hgvs_p
+        .as_deref()
+        .map(normalize_search_hgvsp)
+        .filter(|value| !value.is_empty());
+    let keyword = protein
+        .or_else(|| variant.rsid.clone())
+        .unwrap_or_else(|| variant.id.clone());
+    let filters = crate::entities::article::ArticleSearchFilters {
+        gene: (!gene.is_empty()).then(|| gene.to_string()),
+        gene_anchored: true,
+        keyword: Some(keyword),
+        ..super::super::related_article_filters()
+    };
+    match crate::entities::article::search(&filters, CLINVAR_CITATION_SEARCH_LIMIT).await {
+        Ok(rows) => rows.into_iter().map(|row| row.pmid).collect(),
+        Err(err) => {
+            tracing::warn!(?err, variant = variant.id, "ClinVar citation lookup failed");
+            Vec::new()
+        }
+    }
+}
@@ -58,6 +58,12 @@ pub struct VariantSearchArgs {
     /// Filter CIViC therapy name
     #[arg(long)]
     pub therapy: Option<String>,
+    /// Ranking profile: default, clinical, research, or a profile defined in config.toml
+    #[arg(long = "rank-profile")]
+    pub rank_profile: Option<String>,
+    /// Nest the returned page under group headers with subtotals [values: gene, consequence, significance]
+    #[arg(long = "group-by", value_parser = ["gene", "consequence", "significance"])]
+    pub group_by: Option<String>,
     /// Maximum results (default: 10)
     #[arg(short, long, default_value = "10")]
     pub limit: usize,
@@ -70,9 +76,18 @@ pub struct VariantSearchArgs {
 pub struct VariantGetArgs {
     /// Exact rsID, HGVS, or "GENE CHANGE" (e.g., rs113488022, "BRAF V600E", "BRAF p.Val600Glu")
     pub id: String,
-    /// Sections to include (predict, predictions, clinvar, population, conservation, cosmic, cgi, civic, cbioportal, gwas, all)
+    /// Sections to include (predict, predictions, clinvar, clinvar-submissions, population, conservation, cosmic, cgi, civic, cbioportal, gwas, transcripts, litvar, all)
     #[arg(trailing_var_arg = true)]
     pub sections: Vec<String>,
+    /// Consequence annotation source (myvariant or vep); vep adds Ensembl VEP transcript consequences
+    #[arg(long, default_value = "myvariant")]
+    pub source: String,
+    /// Genome assembly of genomic HGVS input and card coordinates: grch37 (default) or grch38
+    #[arg(long, default_value = "grch37")]
+    pub assembly: String,
+    /// gnomAD release for the population section's allele counts: v2 (GRCh37), v3, or v4 (default)
+    #[arg(long = "gnomad-version", default_value = "v4")]
+    pub gnomad_version: String,
 }
 
 #[derive(Subcommand, Debug)]
@@ -128,6 +143,42 @@ See also: biomcp list variant")]
         /// Variant identifier (rsID, HGVS, or "GENE CHANGE")
         id: String,
     },
+    /// Pre-fill a ClinVar submission spreadsheet row (TSV) from collected evidence
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp variant export-clinvar-template rs80359550 --classification \"Likely pathogenic\" > brca2.tsv
+  biomcp variant export-clinvar-template \"BRCA1 C61G\" --classification VUS --condition \"Hereditary breast ovarian cancer syndrome\"
+
+Note: Suggested ACMG/AMP criteria are written to the comment column for curator review; condition IDs and the assertion method are left blank.
+See also: biomcp list variant")]
+    ExportClinvarTemplate {
+        /// Variant identifier (rsID, HGVS, or "GENE CHANGE")
+        id: String,
+        /// Germline classification (Pathogenic, Likely pathogenic, VUS, Likely benign, Benign)
+        #[arg(long)]
+        classification: String,
+        /// Condition name (default: top ClinVar condition)
+        #[arg(long)]
+        condition: Option<String>,
+    },
+    /// Compare case/control allele counts with an odds ratio, Fisher exact test, and gnomAD frequencies
+    #[command(after_help = "\
+EXAMPLES:
+  biomcp variant compare-af rs80357906 --cases 12/3400 --controls 1/5600
+  biomcp --json variant compare-af \"BRCA1 C61G\" --cases 3/1200 --controls 0/2400
+
+Note: Counts are ALT/TOTAL alleles (AC/AN, as in gnomAD), not carriers/individuals. The odds ratio is allelic with a Woolf 95% CI; zero counts use the +0.5 Haldane-Anscombe correction.
+See also: biomcp list variant")]
+    CompareAf {
+        /// Variant identifier (rsID, HGVS, or "GENE CHANGE")
+        id: String,
+        /// Case cohort allele counts as ALT/TOTAL (e.g. 12/3400)
+        #[arg(long)]
+        cases: String,
+        /// Control cohort allele counts as ALT/TOTAL (e.g. 1/5600)
+        #[arg(long)]
+        controls: String,
+    },
     #[command(external_subcommand)]
     External(Vec<String>),
 }
@@ -35,6 +35,75 @@ fn search_variant_parses_single_token_positional_query() {
     assert_eq!(offset, 0);
 }
 
+#[test]
+fn search_variant_parses_group_by_flag() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "variant",
+        "-g",
+        "BRCA1",
+        "--group-by",
+        "consequence",
+    ])
+    .expect("search variant should parse");
+
+    let Cli {
+        command:
+            Commands::Search {
+                entity:
+                    SearchEntity::Variant(crate::cli::variant::VariantSearchArgs { group_by, .. }),
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected search variant command");
+    };
+
+    assert_eq!(group_by.as_deref(), Some("consequence"));
+    assert!(
+        Cli::try_parse_from([
+            "biomcp",
+            "search",
+            "variant",
+            "BRAF",
+            "--group-by",
+            "tissue"
+        ])
+        .is_err()
+    );
+}
+
+#[test]
+fn search_variant_parses_rank_profile_flag() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "search",
+        "variant",
+        "-g",
+        "BRCA1",
+        "--rank-profile",
+        "research",
+    ])
+    .expect("search variant should parse");
+
+    let Cli {
+        command:
+            Commands::Search {
+                entity:
+                    SearchEntity::Variant(crate::cli::variant::VariantSearchArgs {
+                        rank_profile, ..
+                    }),
+            },
+        ..
+    } = cli
+    else {
+        panic!("expected search variant command");
+    };
+
+    assert_eq!(rank_profile.as_deref(), Some("research"));
+}
+
 #[test]
 fn search_variant_parses_multi_token_positional_query_and_flag() {
     let cli = Cli::try_parse_from([
@@ -134,6 +203,171 @@ fn variant_trials_parses_source_flag() {
     }
 }
 
+#[test]
+fn get_variant_parses_vep_source_before_sections() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "get",
+        "variant",
+        "BRCA2 c.5946delT",
+        "--source",
+        "vep",
+        "clinvar",
+    ])
+    .expect("get variant with --source should parse");
+
+    match cli.command {
+        Commands::Get {
+            entity: GetEntity::Variant(args),
+        } => {
+            assert_eq!(args.id, "BRCA2 c.5946delT");
+            assert_eq!(args.source, "vep");
+            assert_eq!(args.assembly, "grch37");
+            assert_eq!(args.gnomad_version, "v4");
+            assert_eq!(args.sections, vec!["clinvar".to_string()]);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[test]
+fn get_variant_parses_assembly_flag() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "get",
+        "variant",
+        "chr7:g.140753336A>T",
+        "--assembly",
+        "grch38",
+        "predict",
+    ])
+    .expect("get variant with --assembly should parse");
+
+    match cli.command {
+        Commands::Get {
+            entity: GetEntity::Variant(args),
+        } => {
+            assert_eq!(args.assembly, "grch38");
+            assert_eq!(args.sections, vec!["predict".to_string()]);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[test]
+fn get_variant_parses_gnomad_version_flag() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "get",
+        "variant",
+        "rs113488022",
+        "--gnomad-version",
+        "v3",
+        "population",
+    ])
+    .expect("get variant with --gnomad-version should parse");
+
+    match cli.command {
+        Commands::Get {
+            entity: GetEntity::Variant(args),
+        } => {
+            assert_eq!(args.gnomad_version, "v3");
+            assert_eq!(args.sections, vec!["population".to_string()]);
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[test]
+fn variant_export_clinvar_template_parses_classification_and_condition() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "variant",
+        "export-clinvar-template",
+        "rs80359550",
+        "--classification",
+        "Likely pathogenic",
+        "--condition",
+        "Hereditary breast ovarian cancer syndrome",
+    ])
+    .expect("variant export-clinvar-template should parse");
+
+    match cli.command {
+        Commands::Variant {
+            cmd:
+                VariantCommand::ExportClinvarTemplate {
+                    id,
+                    classification,
+                    condition,
+                },
+        } => {
+            assert_eq!(id, "rs80359550");
+            assert_eq!(classification, "Likely pathogenic");
+            assert_eq!(
+                condition.as_deref(),
+                Some("Hereditary breast ovarian cancer syndrome")
+            );
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[test]
+fn variant_export_clinvar_template_requires_classification() {
+    let err = Cli::try_parse_from(["biomcp", "variant", "export-clinvar-template", "rs80359550"])
+        .expect_err("--classification is required");
+    assert!(err.to_string().contains("--classification"));
+}
+
+#[test]
+fn variant_compare_af_parses_case_and_control_counts() {
+    let cli = Cli::try_parse_from([
+        "biomcp",
+        "variant",
+        "compare-af",
+        "rs80357906",
+        "--cases",
+        "12/3400",
+        "--controls",
+        "1/5600",
+    ])
+    .expect("variant compare-af should parse");
+
+    match cli.command {
+        Commands::Variant {
+            cmd:
+                VariantCommand::CompareAf {
+                    id,
+                    cases,
+                    controls,
+                },
+        } => {
+            assert_eq!(id, "rs80357906");
+            assert_eq!(cases, "12/3400");
+            assert_eq!(controls, "1/5600");
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn variant_compare_af_rejects_malformed_counts_before_lookup() {
+    let err = super::handle_command(
+        VariantCommand::CompareAf {
+            id: "rs80357906".into(),
+            cases: "12/3400".into(),
+            controls: "5600".into(),
+        },
+        false,
+    )
+    .await
+    .expect_err("malformed --controls should fail");
+    assert!(
+        err.to_string()
+            .contains("--controls must be ALT/TOTAL allele counts")
+    );
+}
+
 #[tokio::test]
 async fn handle_get_returns_guidance_json_for_shorthand_variant() {
     let cli = Cli::try_parse_from(["biomcp", "--json", "get", "variant", "R620W"]).expect("parse");
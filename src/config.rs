@@ -0,0 +1,214 @@
+//! User configuration loaded from `<config_dir>/biomcp/config.toml`.
+//!
+//! The file is optional. `BIOMCP_CONFIG` points at an alternate file, which is
+//! useful for containers and tests. Unknown keys are rejected so typos surface
+//! as errors instead of silently falling back to defaults.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error::BioMcpError;
+
+const CONFIG_ENV: &str = "BIOMCP_CONFIG";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct UserConfig {
+    #[serde(default)]
+    pub(crate) variant: VariantConfigSection,
+    /// Per-entity output field filters keyed by entity name (`[fields.adverse-event]`).
+    #[serde(default)]
+    pub(crate) fields: BTreeMap<String, FieldFilterToml>,
+    /// `serve-http` tenants keyed by tenant name (`[tenants.oncology]`).
+    #[serde(default)]
+    pub(crate) tenants: BTreeMap<String, TenantToml>,
+}
+
+/// One `serve-http` tenant. Secrets stay in the environment: the config only
+/// names the variables that hold them.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct TenantToml {
+    /// Variable holding the bearer token this tenant's clients send.
+    pub(crate) token_env: String,
+    /// Upstream API key overrides: key variable (`ONCOKB_TOKEN`) -> variable
+    /// holding this tenant's value.
+    #[serde(default)]
+    pub(crate) keys_env: BTreeMap<String, String>,
+}
+
+/// Deployment field filter for one entity; paths use the `--json` key names.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct FieldFilterToml {
+    /// When set, only these fields (and their nested values) are kept.
+    pub(crate) allow: Option<Vec<String>>,
+    /// Fields removed after the allowlist is applied.
+    #[serde(default)]
+    pub(crate) deny: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct VariantConfigSection {
+    /// Ranking profile used when `--rank-profile` is omitted.
+    pub(crate) rank_profile: Option<String>,
+    #[serde(default)]
+    pub(crate) rank_profiles: BTreeMap<String, VariantRankProfileToml>,
+}
+
+/// User-defined variant ranking weights; omitted weights inherit from `base`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct VariantRankProfileToml {
+    pub(crate) base: Option<String>,
+    pub(crate) significance: Option<f64>,
+    pub(crate) gnomad_af: Option<f64>,
+    pub(crate) clinvar_present: Option<f64>,
+    pub(crate) clinvar_per_star: Option<f64>,
+    pub(crate) revel_present: Option<f64>,
+    pub(crate) revel_per_unit: Option<f64>,
+    pub(crate) cadd_present: Option<f64>,
+    pub(crate) cadd_per_10_phred: Option<f64>,
+    pub(crate) gerp: Option<f64>,
+    pub(crate) hgvs_p: Option<f64>,
+    pub(crate) gene: Option<f64>,
+}
+
+pub(crate) fn config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("biomcp"))
+}
+
+pub(crate) fn config_file_path() -> Option<PathBuf> {
+    match std::env::var(CONFIG_ENV)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+    {
+        Some(path) => Some(PathBuf::from(path)),
+        None => config_dir().map(|dir| dir.join("config.toml")),
+    }
+}
+
+pub(crate) fn load_user_config() -> Result<UserConfig, BioMcpError> {
+    let Some(path) = config_file_path() else {
+        return Ok(UserConfig::default());
+    };
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(UserConfig::default());
+        }
+        Err(err) => {
+            return Err(BioMcpError::Io(std::io::Error::new(
+                err.kind(),
+                format!("failed to read {}: {}", path.display(), err),
+            )));
+        }
+    };
+    parse_user_config(&content, Some(&path))
+}
+
+pub(crate) fn parse_user_config(
+    content: &str,
+    path: Option<&Path>,
+) -> Result<UserConfig, BioMcpError> {
+    if content.trim().is_empty() {
+        return Ok(UserConfig::default());
+    }
+    toml::from_str::<UserConfig>(content)
+        .map_err(|err| invalid_config(path, format!("failed to parse config: {err}")))
+}
+
+pub(crate) fn invalid_config(path: Option<&Path>, message: String) -> BioMcpError {
+    let label = path
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|| "config.toml".to_string());
+    BioMcpError::InvalidArgument(format!("{label}: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_user_config;
+
+    #[test]
+    fn parse_user_config_accepts_empty_content() {
+        let config = parse_user_config("  \n", None).expect("empty config should parse");
+        assert!(config.variant.rank_profile.is_none());
+        assert!(config.variant.rank_profiles.is_empty());
+    }
+
+    #[test]
+    fn parse_user_config_reads_variant_rank_profiles() {
+        let config = parse_user_config(
+            r#"
+[variant]
+rank_profile = "lab"
+
+[variant.rank_profiles.lab]
+base = "research"
+revel_per_unit = 8
+"#,
+            None,
+        )
+        .expect("config should parse");
+
+        assert_eq!(config.variant.rank_profile.as_deref(), Some("lab"));
+        let lab = &config.variant.rank_profiles["lab"];
+        assert_eq!(lab.base.as_deref(), Some("research"));
+        assert_eq!(lab.revel_per_unit, Some(8.0));
+    }
+
+    #[test]
+    fn parse_user_config_reads_field_filters() {
+        let config = parse_user_config(
+            r#"
+[fields.adverse-event]
+deny = ["patient", "indication"]
+
+[fields.gene]
+allow = ["symbol", "name", "summary"]
+"#,
+            None,
+        )
+        .expect("config should parse");
+
+        let adverse_event = &config.fields["adverse-event"];
+        assert!(adverse_event.allow.is_none());
+        assert_eq!(adverse_event.deny, vec!["patient", "indication"]);
+        assert_eq!(
+            config.fields["gene"].allow.as_deref(),
+            Some(&["symbol".to_string(), "name".into(), "summary".into()][..])
+        );
+    }
+
+    #[test]
+    fn parse_user_config_reads_tenants() {
+        let config = parse_user_config(
+            r#"
+[tenants.oncology]
+token_env = "ONCOLOGY_BIOMCP_TOKEN"
+keys_env = { ONCOKB_TOKEN = "ONCOLOGY_ONCOKB_TOKEN" }
+
+[tenants.genomics]
+token_env = "GENOMICS_BIOMCP_TOKEN"
+"#,
+            None,
+        )
+        .expect("config should parse");
+
+        let oncology = &config.tenants["oncology"];
+        assert_eq!(oncology.token_env, "ONCOLOGY_BIOMCP_TOKEN");
+        assert_eq!(oncology.keys_env["ONCOKB_TOKEN"], "ONCOLOGY_ONCOKB_TOKEN");
+        assert!(config.tenants["genomics"].keys_env.is_empty());
+    }
+
+    #[test]
+    fn parse_user_config_rejects_unknown_keys() {
+        let err = parse_user_config("[variant]\nrank_profle = \"x\"\n", None)
+            .expect_err("typo should be rejected");
+        assert!(err.to_string().contains("config.toml"));
+    }
+}
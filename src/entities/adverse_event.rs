@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::entities::SearchPage;
@@ -8,7 +9,18 @@ use crate::sources::openfda::OpenFdaClient;
 use crate::transform;
 use crate::utils::date::validate_since;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+mod device_clearance;
+
+pub use self::device_clearance::{
+    DeviceClassification, DeviceClearance, DeviceClearanceSearchFilters,
+    DeviceClearanceSearchResult,
+};
+pub(crate) use self::device_clearance::{
+    get as get_device_clearance, query_summary as device_clearance_query_summary,
+    search_page as search_device_clearance_page,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AdverseEvent {
     pub report_id: String,
     pub drug: String,
@@ -62,7 +74,7 @@ pub struct AdverseEventSearchResponse {
     pub results: Vec<AdverseEventSearchResult>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DeviceEvent {
     pub report_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -90,7 +102,21 @@ pub struct DeviceEventSearchResult {
     pub description: Option<String>,
 }
 
+/// One openFDA animal & veterinary adverse-event report.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VeterinaryEventSearchResult {
+    pub report_id: String,
+    pub drug: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub species: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<String>,
+    pub serious: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", content = "data", rename_all = "kebab-case")]
 pub enum AdverseEventReport {
     Faers(AdverseEvent),
@@ -117,6 +143,16 @@ impl AdverseEventQueryType {
     }
 }
 
+/// Patient population for drug adverse-event queries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AdverseEventSpecies {
+    /// FAERS human reports (`drug/event`).
+    #[default]
+    Human,
+    /// Center for Veterinary Medicine reports (`animalandveterinary/event`).
+    Veterinary,
+}
+
 const ADVERSE_EVENT_SECTION_REACTIONS: &str = "reactions";
 const ADVERSE_EVENT_SECTION_OUTCOMES: &str = "outcomes";
 const ADVERSE_EVENT_SECTION_CONCOMITANT: &str = "concomitant";
@@ -203,6 +239,16 @@ pub struct DeviceEventSearchFilters {
     pub since: Option<String>,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct VeterinaryEventSearchFilters {
+    pub drug: Option<String>,
+    pub animal: Option<String>,
+    pub reaction: Option<String>,
+    pub serious: bool,
+    pub since: Option<String>,
+    pub date_to: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AdverseEventCountBucket {
     pub value: String,
@@ -712,6 +758,116 @@ pub async fn search_device_page(
     ))
 }
 
+fn build_veterinary_query(filters: &VeterinaryEventSearchFilters) -> Result<String, BioMcpError> {
+    let drug = filters
+        .drug
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| {
+            BioMcpError::InvalidArgument(
+                "drug name is required. Example: biomcp search adverse-event --species veterinary -d carprofen"
+                    .into(),
+            )
+        })?;
+
+    let mut terms: Vec<String> = Vec::new();
+    let escaped_drug = OpenFdaClient::escape_query_value(drug);
+    terms.push(format!(
+        "(drug.active_ingredients.name:\"{escaped_drug}\" OR drug.brand_name:\"{escaped_drug}\")"
+    ));
+
+    if let Some(animal) = filters
+        .animal
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        terms.push(format!(
+            "animal.species:\"{}\"",
+            OpenFdaClient::escape_query_value(animal)
+        ));
+    }
+
+    if let Some(reaction) = filters
+        .reaction
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        terms.push(format!(
+            "reaction.veddra_term_name:\"{}\"",
+            OpenFdaClient::escape_query_value(reaction)
+        ));
+    }
+
+    if filters.serious {
+        terms.push("serious_ae:true".to_string());
+    }
+
+    let since = filters
+        .since
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| yyyymmdd_from_date(v, false))
+        .transpose()?;
+    let date_to = filters
+        .date_to
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| yyyymmdd_from_date(v, true))
+        .transpose()?;
+    if let (Some(from), Some(to)) = (since.as_deref(), date_to.as_deref())
+        && from > to
+    {
+        return Err(BioMcpError::InvalidArgument(
+            "--date-from must be <= --date-to".into(),
+        ));
+    }
+    if let Some(from) = since {
+        terms.push(format!("original_receive_date:[{from} TO *]"));
+    }
+    if let Some(to) = date_to {
+        terms.push(format!("original_receive_date:[* TO {to}]"));
+    }
+
+    Ok(terms.join(" AND "))
+}
+
+pub async fn search_veterinary_page(
+    filters: &VeterinaryEventSearchFilters,
+    limit: usize,
+    offset: usize,
+) -> Result<SearchPage<VeterinaryEventSearchResult>, BioMcpError> {
+    const MAX_SEARCH_LIMIT: usize = 50;
+    if limit == 0 || limit > MAX_SEARCH_LIMIT {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--limit must be between 1 and {MAX_SEARCH_LIMIT}"
+        )));
+    }
+
+    let q = build_veterinary_query(filters)?;
+    let requested_drug = filters.drug.as_deref().map(str::trim);
+
+    let client = OpenFdaClient::new()?;
+    let resp = client.veterinary_event_search(&q, limit, offset).await?;
+    let Some(resp) = resp else {
+        return Ok(SearchPage::offset(Vec::new(), Some(0)));
+    };
+
+    Ok(SearchPage::offset(
+        resp.results
+            .iter()
+            .map(|r| {
+                transform::adverse_event::from_openfda_veterinary_search_result(r, requested_drug)
+            })
+            .collect(),
+        Some(resp.meta.results.total),
+    ))
+}
+
 fn normalize_classification(value: &str) -> Result<String, BioMcpError> {
     let v = value.trim();
     if v.is_empty() {
@@ -828,9 +984,8 @@ async fn get_faers(report_id: &str) -> Result<Option<AdverseEvent>, BioMcpError>
         return Ok(None);
     };
 
-    Ok(Some(
-        transform::adverse_event::from_openfda_faers_get_result(&first),
-    ))
+    let event = transform::adverse_event::from_openfda_faers_get_result(&first);
+    transform::fields::apply("adverse-event", event).map(Some)
 }
 
 async fn get_device(report_id: &str) -> Result<Option<DeviceEvent>, BioMcpError> {
@@ -857,9 +1012,8 @@ async fn get_device(report_id: &str) -> Result<Option<DeviceEvent>, BioMcpError>
         return Ok(None);
     };
 
-    Ok(Some(
-        transform::adverse_event::from_openfda_device_get_result(&first),
-    ))
+    let event = transform::adverse_event::from_openfda_device_get_result(&first);
+    transform::fields::apply("adverse-event", event).map(Some)
 }
 
 pub async fn get(report_id: &str) -> Result<AdverseEventReport, BioMcpError> {
@@ -961,6 +1115,55 @@ pub fn search_query_summary(filters: &AdverseEventSearchFilters) -> String {
     parts.join(", ")
 }
 
+pub fn veterinary_query_summary(filters: &VeterinaryEventSearchFilters) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    if let Some(d) = filters
+        .drug
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        parts.push(format!("drug={d}"));
+    }
+    parts.push("species=veterinary".into());
+    if let Some(a) = filters
+        .animal
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        parts.push(format!("animal={a}"));
+    }
+    if let Some(r) = filters
+        .reaction
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        parts.push(format!("reaction={r}"));
+    }
+    if filters.serious {
+        parts.push("serious=true".into());
+    }
+    if let Some(s) = filters
+        .since
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        parts.push(format!("date_from={s}"));
+    }
+    if let Some(s) = filters
+        .date_to
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        parts.push(format!("date_to={s}"));
+    }
+    parts.join(", ")
+}
+
 pub fn device_query_summary(filters: &DeviceEventSearchFilters) -> String {
     let mut parts: Vec<String> = Vec::new();
     if let Some(d) = filters
@@ -1089,6 +1292,49 @@ mod tests {
         assert_eq!(summary, "manufacturer=Medtronic, product_code=PQP");
     }
 
+    #[test]
+    fn build_veterinary_query_maps_filters_to_cvm_fields() {
+        let q = build_veterinary_query(&VeterinaryEventSearchFilters {
+            drug: Some("carprofen".into()),
+            animal: Some("Dog".into()),
+            reaction: Some("Vomiting".into()),
+            serious: true,
+            since: Some("2020".into()),
+            date_to: Some("2023-06-30".into()),
+        })
+        .unwrap();
+        assert_eq!(
+            q,
+            "(drug.active_ingredients.name:\"carprofen\" OR drug.brand_name:\"carprofen\") AND animal.species:\"Dog\" AND reaction.veddra_term_name:\"Vomiting\" AND serious_ae:true AND original_receive_date:[20200101 TO *] AND original_receive_date:[* TO 20230630]"
+        );
+
+        let err = build_veterinary_query(&VeterinaryEventSearchFilters::default()).unwrap_err();
+        assert!(err.to_string().contains("--species veterinary"));
+
+        let err = build_veterinary_query(&VeterinaryEventSearchFilters {
+            drug: Some("carprofen".into()),
+            since: Some("2024".into()),
+            date_to: Some("2023".into()),
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("--date-from must be <= --date-to"));
+    }
+
+    #[test]
+    fn veterinary_query_summary_marks_species() {
+        let summary = veterinary_query_summary(&VeterinaryEventSearchFilters {
+            drug: Some("carprofen".into()),
+            animal: Some("Cat".into()),
+            serious: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            summary,
+            "drug=carprofen, species=veterinary, animal=Cat, serious=true"
+        );
+    }
+
     #[test]
     fn normalize_classification_accepts_common_forms() {
         assert_eq!(normalize_classification("Class II").unwrap(), "Class II");
@@ -0,0 +1,312 @@
+//! FDA 510(k) clearances and device classifications.
+//!
+//! `search device-clearance` lists 510(k) premarket notifications from the
+//! openFDA `device/510k` endpoint, newest decision first. `get
+//! device-clearance <K number>` adds the product code's classification record
+//! (device class, regulation number, implant and life-sustaining flags), so
+//! MAUDE events and recalls for the same product code can be read against the
+//! regulatory pathway the device was cleared through.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::{normalize_product_code, yyyymmdd_from_date};
+use crate::entities::{SearchPage, section_errors};
+use crate::error::BioMcpError;
+use crate::sources::openfda::OpenFdaClient;
+use crate::transform;
+use crate::utils::text::non_empty;
+
+const MAX_DEVICE_CLEARANCE_SEARCH_LIMIT: usize = 50;
+/// Section name reported in `_meta.section_errors` when the classification
+/// lookup fails.
+const DEVICE_CLEARANCE_SECTION_CLASSIFICATION: &str = "classification";
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DeviceClearance {
+    /// 510(k) number, e.g. `K213456`.
+    pub k_number: String,
+    pub device_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applicant: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_code: Option<String>,
+    /// FDA decision, e.g. "Substantially Equivalent".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_received: Option<String>,
+    /// Traditional, Special, or Abbreviated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clearance_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_panel: Option<String>,
+    /// Whether the applicant filed a 510(k) summary or a statement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub third_party_review: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expedited_review: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<DeviceClassification>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DeviceClassification {
+    pub product_code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_name: Option<String>,
+    /// `Class I`, `Class II`, `Class III`, or `Unclassified`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_class: Option<String>,
+    /// 21 CFR regulation number, e.g. `862.1355`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regulation_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub medical_specialty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub definition: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implant: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub life_sustaining: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gmp_exempt: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub third_party_eligible: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceClearanceSearchResult {
+    pub k_number: String,
+    pub device_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applicant: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_class: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DeviceClearanceSearchFilters {
+    pub product_code: Option<String>,
+    pub device: Option<String>,
+    pub applicant: Option<String>,
+    pub since: Option<String>,
+    pub date_to: Option<String>,
+}
+
+/// Upper-cases a 510(k) number and checks the `K` + six digits shape.
+fn normalize_k_number(value: &str) -> Result<String, BioMcpError> {
+    let normalized = value.trim().to_ascii_uppercase();
+    let digits = normalized.strip_prefix('K').unwrap_or_default();
+    if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(BioMcpError::InvalidArgument(
+            "510(k) number must be K followed by six digits. Example: biomcp get device-clearance K213456"
+                .into(),
+        ));
+    }
+    Ok(normalized)
+}
+
+fn build_clearance_query(filters: &DeviceClearanceSearchFilters) -> Result<String, BioMcpError> {
+    let product_code = filters
+        .product_code
+        .as_deref()
+        .and_then(normalize_product_code);
+    let device = non_empty(filters.device.as_deref());
+    let applicant = non_empty(filters.applicant.as_deref());
+
+    if product_code.is_none() && device.is_none() && applicant.is_none() {
+        return Err(BioMcpError::InvalidArgument(
+            "At least one filter is required (--product-code, --device, or --applicant). Example: biomcp search device-clearance --product-code QBJ"
+                .into(),
+        ));
+    }
+
+    let mut terms: Vec<String> = Vec::new();
+    if let Some(product_code) = product_code {
+        terms.push(format!(
+            "product_code:\"{}\"",
+            OpenFdaClient::escape_query_value(&product_code)
+        ));
+    }
+    if let Some(device) = device {
+        let escaped = OpenFdaClient::escape_query_value(&device);
+        if device.chars().any(char::is_whitespace) {
+            terms.push(format!("device_name:\"{escaped}\""));
+        } else {
+            terms.push(format!("device_name:*{escaped}*"));
+        }
+    }
+    if let Some(applicant) = applicant {
+        let escaped = OpenFdaClient::escape_query_value(&applicant);
+        if applicant.chars().any(char::is_whitespace) {
+            terms.push(format!("applicant:\"{escaped}\""));
+        } else {
+            terms.push(format!("applicant:*{escaped}*"));
+        }
+    }
+
+    let since = non_empty(filters.since.as_deref());
+    let date_to = non_empty(filters.date_to.as_deref());
+    if since.is_some() || date_to.is_some() {
+        let from = since
+            .map(|value| yyyymmdd_from_date(&value, false))
+            .transpose()?
+            .unwrap_or_else(|| "*".to_string());
+        let to = date_to
+            .map(|value| yyyymmdd_from_date(&value, true))
+            .transpose()?
+            .unwrap_or_else(|| "*".to_string());
+        terms.push(format!("decision_date:[{from} TO {to}]"));
+    }
+
+    Ok(terms.join(" AND "))
+}
+
+pub async fn search_page(
+    filters: &DeviceClearanceSearchFilters,
+    limit: usize,
+    offset: usize,
+) -> Result<SearchPage<DeviceClearanceSearchResult>, BioMcpError> {
+    if limit == 0 || limit > MAX_DEVICE_CLEARANCE_SEARCH_LIMIT {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--limit must be between 1 and {MAX_DEVICE_CLEARANCE_SEARCH_LIMIT}"
+        )));
+    }
+
+    let q = build_clearance_query(filters)?;
+    let client = OpenFdaClient::new()?;
+    let Some(resp) = client.device_510k_search(&q, limit, offset).await? else {
+        return Ok(SearchPage::offset(Vec::new(), Some(0)));
+    };
+
+    Ok(SearchPage::offset(
+        resp.results
+            .iter()
+            .map(transform::adverse_event::from_openfda_510k_search_result)
+            .collect(),
+        Some(resp.meta.results.total),
+    ))
+}
+
+/// Fetches one 510(k) clearance and, when it names a product code, that code's
+/// classification. A failed classification lookup leaves the card without it
+/// and records a section error instead of failing the command.
+pub async fn get(k_number: &str) -> Result<DeviceClearance, BioMcpError> {
+    let k_number = normalize_k_number(k_number)?;
+    let client = OpenFdaClient::new()?;
+    let query = format!("k_number:\"{k_number}\"");
+    let first = client
+        .device_510k_search(&query, 1, 0)
+        .await?
+        .and_then(|resp| resp.results.into_iter().next());
+    let Some(first) = first else {
+        return Err(BioMcpError::NotFound {
+            entity: "device-clearance".into(),
+            id: k_number.clone(),
+            suggestion: format!(
+                "510(k) {k_number} was not found. Try searching by product code: biomcp search device-clearance --product-code <code>"
+            ),
+        });
+    };
+
+    let mut clearance = transform::adverse_event::from_openfda_510k_result(&first);
+    if let Some(product_code) = clearance.product_code.clone() {
+        match client.device_classification(&product_code).await {
+            Ok(row) => {
+                clearance.classification = row
+                    .as_ref()
+                    .map(transform::adverse_event::from_openfda_device_classification);
+            }
+            Err(err) => {
+                warn!("OpenFDA classification unavailable for {product_code}: {err}");
+                section_errors::record(DEVICE_CLEARANCE_SECTION_CLASSIFICATION, "OpenFDA", &err);
+            }
+        }
+    }
+
+    transform::fields::apply("device-clearance", clearance)
+}
+
+pub fn query_summary(filters: &DeviceClearanceSearchFilters) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    if let Some(code) = filters
+        .product_code
+        .as_deref()
+        .and_then(normalize_product_code)
+    {
+        parts.push(format!("product_code={code}"));
+    }
+    if let Some(device) = non_empty(filters.device.as_deref()) {
+        parts.push(format!("device={device}"));
+    }
+    if let Some(applicant) = non_empty(filters.applicant.as_deref()) {
+        parts.push(format!("applicant={applicant}"));
+    }
+    if let Some(since) = non_empty(filters.since.as_deref()) {
+        parts.push(format!("since={since}"));
+    }
+    if let Some(date_to) = non_empty(filters.date_to.as_deref()) {
+        parts.push(format!("date_to={date_to}"));
+    }
+    parts.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_k_number_accepts_case_and_rejects_other_shapes() {
+        assert_eq!(normalize_k_number(" k213456 ").unwrap(), "K213456");
+        for bad in ["", "K21345", "K2134567", "P200001", "DEN190040", "K21345X"] {
+            assert!(
+                matches!(
+                    normalize_k_number(bad),
+                    Err(BioMcpError::InvalidArgument(_))
+                ),
+                "{bad} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn build_clearance_query_requires_a_filter_and_combines_terms() {
+        let err = build_clearance_query(&DeviceClearanceSearchFilters::default()).unwrap_err();
+        assert!(err.to_string().contains("--product-code"));
+
+        let query = build_clearance_query(&DeviceClearanceSearchFilters {
+            product_code: Some(" qbj ".into()),
+            device: Some("glucose monitor".into()),
+            applicant: Some("Dexcom".into()),
+            since: Some("2020".into()),
+            date_to: None,
+        })
+        .unwrap();
+        assert_eq!(
+            query,
+            "product_code:\"QBJ\" AND device_name:\"glucose monitor\" AND applicant:*Dexcom* AND decision_date:[20200101 TO *]"
+        );
+    }
+
+    #[test]
+    fn query_summary_lists_active_filters() {
+        let summary = query_summary(&DeviceClearanceSearchFilters {
+            product_code: Some("qbj".into()),
+            date_to: Some("2024".into()),
+            ..Default::default()
+        });
+        assert_eq!(summary, "product_code=QBJ, date_to=2024");
+    }
+}
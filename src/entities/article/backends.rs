@@ -364,6 +364,8 @@ pub(super) async fn search_semantic_scholar_candidates(
             matched_sources: vec![ArticleSource::SemanticScholar],
             score: None,
             is_retracted: None,
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: abstract_text
                 .as_deref()
                 .and_then(transform::article::article_search_abstract_snippet),
@@ -467,6 +469,8 @@ pub(super) async fn search_litsense2_candidates(
                 matched_sources: vec![ArticleSource::LitSense2],
                 score: Some(hit.score),
                 is_retracted: None,
+                journal_rank: None,
+                preprint: None,
                 abstract_snippet: transform::article::article_search_abstract_snippet(
                     &cleaned_text,
                 ),
@@ -22,6 +22,7 @@ fn article_batch_item_projection_keeps_requested_id_year_and_top_entities() {
         abstract_text: None,
         full_text_path: None,
         full_text_note: None,
+        full_text_source: None,
         annotations: Some(ArticleAnnotations {
             genes: vec![
                 AnnotationCount {
@@ -53,6 +54,7 @@ fn article_batch_item_projection_keeps_requested_id_year_and_top_entities() {
                 text: "V600E".to_string(),
                 count: 3,
             }],
+            trials: Vec::new(),
         }),
         semantic_scholar: Some(ArticleSemanticScholar {
             paper_id: Some("paper-1".to_string()),
@@ -63,6 +65,7 @@ fn article_batch_item_projection_keeps_requested_id_year_and_top_entities() {
             is_open_access: None,
             open_access_pdf: None,
         }),
+        citation_graph: None,
         pubtator_fallback: false,
     };
 
@@ -3,6 +3,7 @@
 use crate::entities::SearchPage;
 use crate::error::BioMcpError;
 
+use super::preprints::collapse_preprint_candidates;
 use super::ranking::sort_article_rows;
 use super::{ArticleSearchFilters, ArticleSearchResult, ArticleSource};
 
@@ -301,7 +302,7 @@ pub(super) fn finalize_article_candidates(
         ensure_matched_sources(row);
     }
 
-    let mut rows = merge_article_candidates(rows);
+    let mut rows = collapse_preprint_candidates(merge_article_candidates(rows));
     rows.retain(|candidate| !candidate.row.pmid.trim().is_empty());
     rows = cap_article_candidates_by_source(rows, resolve_article_source_cap(filters, limit));
     sort_article_rows(&mut rows, filters.sort, filters);
@@ -39,6 +39,8 @@ fn pubmed_unique_row_survives_first_page_in_mixed_federation() {
         matched_sources: vec![ArticleSource::PubMed],
         score: None,
         is_retracted: Some(false),
+        journal_rank: None,
+        preprint: None,
         abstract_snippet: None,
         ranking: None,
         normalized_title: "braf v600e mutations in melanoma".into(),
@@ -62,6 +64,8 @@ fn pubmed_unique_row_survives_first_page_in_mixed_federation() {
             matched_sources: vec![ArticleSource::EuropePmc],
             score: None,
             is_retracted: Some(false),
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: None,
             ranking: None,
             normalized_title: format!("unrelated oncology study {i}"),
@@ -424,6 +428,8 @@ fn merge_article_candidates_dedups_transitively_across_identifiers() {
             source: ArticleSource::PubTator,
             score: Some(42.0),
             is_retracted: None,
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: None,
             ranking: None,
             matched_sources: vec![ArticleSource::PubTator],
@@ -444,6 +450,8 @@ fn merge_article_candidates_dedups_transitively_across_identifiers() {
             source: ArticleSource::EuropePmc,
             score: None,
             is_retracted: Some(false),
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: Some("Europe abstract".into()),
             ranking: None,
             matched_sources: vec![ArticleSource::EuropePmc],
@@ -464,6 +472,8 @@ fn merge_article_candidates_dedups_transitively_across_identifiers() {
             source: ArticleSource::SemanticScholar,
             score: None,
             is_retracted: None,
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: Some("Semantic Scholar abstract".into()),
             ranking: None,
             matched_sources: vec![ArticleSource::SemanticScholar],
@@ -1,5 +1,6 @@
 //! Article detail lookup, identifier parsing, and full-text retrieval.
 
+use crate::entities::section_errors;
 use crate::error::BioMcpError;
 use crate::sources::europepmc::{EuropePmcClient, EuropePmcResult, EuropePmcSearchResponse};
 use crate::sources::ncbi_efetch::NcbiEfetchClient;
@@ -7,14 +8,16 @@ use crate::sources::ncbi_idconv::NcbiIdConverterClient;
 use crate::sources::pmc_oa::PmcOaClient;
 use crate::sources::pubtator::PubTatorClient;
 use crate::sources::semantic_scholar::{SemanticScholarClient, SemanticScholarPaper};
+use crate::sources::unpaywall::UnpaywallClient;
 use crate::transform;
 use crate::utils::download;
 use tracing::warn;
 
 use super::{
-    ARTICLE_SECTION_ALL, ARTICLE_SECTION_ANNOTATIONS, ARTICLE_SECTION_FULLTEXT,
-    ARTICLE_SECTION_NAMES, ARTICLE_SECTION_TLDR, Article, ArticleSemanticScholar,
-    ArticleSemanticScholarPdf, FULLTEXT_CACHE_VERSION, INVALID_ARTICLE_ID_MSG,
+    ARTICLE_SECTION_ALL, ARTICLE_SECTION_ANNOTATIONS, ARTICLE_SECTION_CITATIONS,
+    ARTICLE_SECTION_FULLTEXT, ARTICLE_SECTION_NAMES, ARTICLE_SECTION_TLDR, Article,
+    ArticleFullTextSource, ArticleSemanticScholar, ArticleSemanticScholarPdf,
+    FULLTEXT_CACHE_VERSION, INVALID_ARTICLE_ID_MSG,
 };
 
 pub(super) fn is_doi(id: &str) -> bool {
@@ -110,11 +113,67 @@ async fn render_fulltext_xml(xml: String) -> Result<String, BioMcpError> {
         })
 }
 
+/// Open-access copy saved from an Unpaywall location.
+struct UnpaywallFullText {
+    path: std::path::PathBuf,
+    source: ArticleFullTextSource,
+}
+
+/// Looks up the DOI's best open-access location and saves it under `key`:
+/// PDFs as-is, HTML pages converted to plain text.
+async fn fetch_unpaywall_full_text(
+    doi: &str,
+    key: &str,
+) -> Result<Option<UnpaywallFullText>, BioMcpError> {
+    let client = UnpaywallClient::new()?;
+    let Some(location) = client.best_oa_location(doi).await? else {
+        return Ok(None);
+    };
+    let Some(url) = location.document_url().map(str::to_string) else {
+        return Ok(None);
+    };
+    let document = client.download(&url).await?;
+    let (path, format) = if document.is_pdf {
+        let path =
+            download::save_bytes_atomic(&fulltext_cache_key(key), "pdf", &document.bytes).await?;
+        (path, "pdf")
+    } else {
+        let html = String::from_utf8_lossy(&document.bytes).into_owned();
+        let text =
+            tokio::task::spawn_blocking(move || transform::article::extract_text_from_html(&html))
+                .await
+                .map_err(|err| BioMcpError::Api {
+                    api: "article".to_string(),
+                    message: format!("Full text render worker failed: {err}"),
+                })?;
+        if text.trim().is_empty() {
+            return Ok(None);
+        }
+        (
+            download::save_atomic(&fulltext_cache_key(key), &text).await?,
+            "text",
+        )
+    };
+
+    Ok(Some(UnpaywallFullText {
+        path,
+        source: ArticleFullTextSource {
+            provider: "Unpaywall".to_string(),
+            url,
+            format: format.to_string(),
+            host_type: location.host_type,
+            version: location.version,
+            license: location.license,
+        },
+    }))
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub(super) struct ArticleSections {
     pub(super) include_annotations: bool,
     pub(super) include_fulltext: bool,
     pub(super) include_tldr: bool,
+    pub(super) include_citations: bool,
     pub(super) include_all: bool,
 }
 
@@ -134,6 +193,7 @@ pub(super) fn parse_sections(sections: &[String]) -> Result<ArticleSections, Bio
             ARTICLE_SECTION_ANNOTATIONS => out.include_annotations = true,
             ARTICLE_SECTION_FULLTEXT => out.include_fulltext = true,
             ARTICLE_SECTION_TLDR => out.include_tldr = true,
+            ARTICLE_SECTION_CITATIONS => out.include_citations = true,
             ARTICLE_SECTION_ALL => out.include_all = true,
             _ => {
                 return Err(BioMcpError::InvalidArgument(format!(
@@ -148,6 +208,7 @@ pub(super) fn parse_sections(sections: &[String]) -> Result<ArticleSections, Bio
         out.include_annotations = true;
         out.include_fulltext = true;
         out.include_tldr = true;
+        out.include_citations = true;
     }
 
     Ok(out)
@@ -367,6 +428,7 @@ pub async fn get(id: &str, sections: &[String]) -> Result<Article, BioMcpError>
     let section_only = is_section_only_request(sections, section_flags.include_all);
     let europe = EuropePmcClient::new()?;
     let mut article = get_article_base(id).await?;
+    transform::article::attach_trial_ids(&mut article);
 
     enrich_article_with_semantic_scholar(&mut article).await?;
 
@@ -377,6 +439,16 @@ pub async fn get(id: &str, sections: &[String]) -> Result<Article, BioMcpError>
         article.semantic_scholar = None;
     }
 
+    if section_flags.include_citations {
+        match super::graph::citation_graph(&article, &europe).await {
+            Ok(graph) => article.citation_graph = graph,
+            Err(err) => {
+                warn!(?err, id, "Europe PMC citation graph failed");
+                section_errors::record(ARTICLE_SECTION_CITATIONS, "Europe PMC", &err);
+            }
+        }
+    }
+
     if full_text {
         let mut full_text_err: Option<BioMcpError> = None;
         let mut resolved_pmcid = article.pmcid.clone();
@@ -442,32 +514,59 @@ pub async fn get(id: &str, sections: &[String]) -> Result<Article, BioMcpError>
             }
         }
 
+        let key = article
+            .pmid
+            .as_deref()
+            .or(article.doi.as_deref())
+            .or(article.pmcid.as_deref())
+            .unwrap_or(id)
+            .to_string();
+        let mut unpaywall_unconfigured = false;
         if let Some(xml) = xml {
             let text = render_fulltext_xml(xml).await?;
-            let key = article
-                .pmid
-                .as_deref()
-                .or(article.doi.as_deref())
-                .or(article.pmcid.as_deref())
-                .unwrap_or(id);
-            let path = download::save_atomic(&fulltext_cache_key(key), &text).await?;
+            let path = download::save_atomic(&fulltext_cache_key(&key), &text).await?;
+            super::fulltext_index::record(&article, &path);
             article.full_text_path = Some(path);
             article.full_text_note = None;
-        } else if let Some(err) = full_text_err {
-            warn!(?err, id, "Full text retrieval failed");
-            article.full_text_note = Some("Full text not available: API error".into());
-        } else if article.pmcid.is_none() {
-            article.full_text_note =
-                Some("Full text not available: Article not in PubMed Central".into());
-        } else {
-            article.full_text_note = Some(
+        } else if let Some(doi) = article.doi.clone() {
+            // Not in PMC: fall back to a legal open-access copy by DOI.
+            match fetch_unpaywall_full_text(&doi, &key).await {
+                Ok(Some(found)) => {
+                    // The index backs text-only MCP resources; PDFs stay
+                    // reachable through the returned path alone.
+                    if found.source.format != "pdf" {
+                        super::fulltext_index::record(&article, &found.path);
+                    }
+                    article.full_text_path = Some(found.path);
+                    article.full_text_source = Some(found.source);
+                    article.full_text_note = None;
+                }
+                Ok(None) => {}
+                Err(BioMcpError::ApiKeyRequired { .. }) => unpaywall_unconfigured = true,
+                Err(err) => full_text_err = Some(err),
+            }
+        }
+
+        if article.full_text_path.is_none() {
+            let mut note = if let Some(err) = full_text_err {
+                warn!(?err, id, "Full text retrieval failed");
+                "Full text not available: API error".to_string()
+            } else if article.pmcid.is_none() {
+                "Full text not available: Article not in PubMed Central".to_string()
+            } else {
                 "Full text not available: Full text not available from PMC full-text sources"
-                    .into(),
-            );
+                    .to_string()
+            };
+            if unpaywall_unconfigured {
+                note.push_str(
+                    ". Set UNPAYWALL_EMAIL to look for an open-access copy through Unpaywall.",
+                );
+            }
+            article.full_text_note = Some(note);
         }
     }
 
-    Ok(article)
+    transform::fields::apply("article", article)
 }
 
 #[cfg(test)]
@@ -228,17 +228,144 @@ async fn get_fulltext_falls_back_to_ncbi_efetch_before_pmc_oa() {
     assert!(metadata.len() > 0);
 }
 
+#[tokio::test]
+async fn get_fulltext_falls_back_to_unpaywall_outside_pmc() {
+    let _guard = lock_env().await;
+    let pubtator = MockServer::start().await;
+    let europepmc = MockServer::start().await;
+    let idconv = MockServer::start().await;
+    let unpaywall = MockServer::start().await;
+    let s2 = MockServer::start().await;
+    let _pubtator_base = set_env_var("BIOMCP_PUBTATOR_BASE", Some(&pubtator.uri()));
+    let _europepmc_base = set_env_var("BIOMCP_EUROPEPMC_BASE", Some(&europepmc.uri()));
+    let _idconv_base = set_env_var("BIOMCP_NCBI_IDCONV_BASE", Some(&idconv.uri()));
+    let _unpaywall_base = set_env_var("BIOMCP_UNPAYWALL_BASE", Some(&unpaywall.uri()));
+    let _unpaywall_email = set_env_var("UNPAYWALL_EMAIL", Some("curator@example.org"));
+    let _s2_base = set_env_var("BIOMCP_S2_BASE", Some(&s2.uri()));
+    let _s2_key = set_env_var("S2_API_KEY", None);
+
+    Mock::given(method("GET"))
+        .and(path("/publications/export/biocjson"))
+        .and(query_param("pmids", "22663013"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "PubTator3": [{
+                "pmid": 22663013,
+                "passages": [
+                    {"infons": {"type": "title"}, "text": "Unpaywall fallback winner"},
+                    {"infons": {"type": "abstract"}, "text": "Abstract text."}
+                ]
+            }]
+        })))
+        .expect(1)
+        .mount(&pubtator)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("query", "EXT_ID:22663013 AND SRC:MED"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "hitCount": 1,
+            "resultList": {
+                "result": [{
+                    "id": "22663013",
+                    "pmid": "22663013",
+                    "doi": "10.1000/oa.13",
+                    "title": "Unpaywall fallback winner",
+                    "journalTitle": "Journal One",
+                    "firstPublicationDate": "2025-01-01"
+                }]
+            }
+        })))
+        .expect(1)
+        .mount(&europepmc)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/22663013/fullTextXML"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&europepmc)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(query_param("ids", "22663013"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": "ok",
+            "records": [{"pmid": "22663013"}]
+        })))
+        .expect(1)
+        .mount(&idconv)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/10.1000/oa.13"))
+        .and(query_param("email", "curator@example.org"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "doi": "10.1000/oa.13",
+            "is_oa": true,
+            "best_oa_location": {
+                "url_for_pdf": format!("{}/files/oa.13.pdf", unpaywall.uri()),
+                "host_type": "repository",
+                "version": "acceptedVersion",
+                "license": "cc-by"
+            }
+        })))
+        .expect(1)
+        .mount(&unpaywall)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/files/oa.13.pdf"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/pdf")
+                .set_body_bytes(b"%PDF-1.7\nUnpaywall body\n".to_vec()),
+        )
+        .expect(1)
+        .mount(&unpaywall)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/graph/v1/paper/PMID:22663013"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "paperId": "paper-1",
+            "title": "Unpaywall fallback winner"
+        })))
+        .mount(&s2)
+        .await;
+
+    let article = get("22663013", &["fulltext".to_string()])
+        .await
+        .expect("fulltext request should succeed");
+
+    assert!(article.full_text_note.is_none());
+    let source = article.full_text_source.expect("full text source");
+    assert_eq!(source.provider, "Unpaywall");
+    assert_eq!(source.format, "pdf");
+    assert_eq!(source.host_type.as_deref(), Some("repository"));
+    assert_eq!(source.license.as_deref(), Some("cc-by"));
+    let path = article.full_text_path.expect("full text path");
+    assert_eq!(path.extension().and_then(|ext| ext.to_str()), Some("pdf"));
+    assert!(std::fs::read(path).expect("saved PDF").starts_with(b"%PDF"));
+}
+
 #[test]
 fn parse_sections_supports_tldr_and_all() {
     let tldr_only = parse_sections(&["tldr".to_string()]).expect("tldr should parse");
     assert!(tldr_only.include_tldr);
     assert!(!tldr_only.include_annotations);
     assert!(!tldr_only.include_fulltext);
+    assert!(!tldr_only.include_citations);
+
+    let citations = parse_sections(&["citations".to_string()]).expect("citations should parse");
+    assert!(citations.include_citations);
+    assert!(!citations.include_tldr);
 
     let all = parse_sections(&["all".to_string()]).expect("all should parse");
     assert!(all.include_tldr);
     assert!(all.include_annotations);
     assert!(all.include_fulltext);
+    assert!(all.include_citations);
 }
 
 #[test]
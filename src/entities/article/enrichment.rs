@@ -11,6 +11,7 @@ use crate::sources::semantic_scholar::{SemanticScholarClient, SemanticScholarPap
 
 use super::candidates::finalize_article_candidates;
 use super::detail::{parse_pmid, resolve_article_from_pmid};
+use super::preprints::link_visible_preprints_to_published;
 use super::{
     Article, ArticleSearchFilters, ArticleSearchResult, ArticleSource,
     SEMANTIC_SCHOLAR_BATCH_LOOKUP_MAX_IDS,
@@ -212,6 +213,18 @@ async fn enrich_visible_article_search_rows_with_article_base(rows: &mut [Articl
     }
 }
 
+/// Adds SCImago journal context to rows when local SCImago data is present.
+fn annotate_article_search_rows_with_journal_rank(rows: &mut [ArticleSearchResult]) {
+    for row in rows {
+        if row.journal_rank.is_none() {
+            row.journal_rank = row
+                .journal
+                .as_deref()
+                .and_then(crate::sources::scimago::lookup);
+        }
+    }
+}
+
 pub(super) async fn enrich_and_finalize_article_candidates(
     mut rows: Vec<ArticleSearchResult>,
     limit: usize,
@@ -222,6 +235,8 @@ pub(super) async fn enrich_and_finalize_article_candidates(
     enrich_article_search_rows_with_semantic_scholar(&mut rows).await;
     let mut page = finalize_article_candidates(rows, limit, offset, total, filters);
     enrich_visible_article_search_rows_with_article_base(&mut page.results).await;
+    link_visible_preprints_to_published(&mut page.results).await;
+    annotate_article_search_rows_with_journal_rank(&mut page.results);
     page
 }
 
@@ -230,6 +245,8 @@ pub(super) async fn enrich_visible_article_search_page(
 ) -> SearchPage<ArticleSearchResult> {
     enrich_article_search_rows_with_semantic_scholar(&mut page.results).await;
     enrich_visible_article_search_rows_with_article_base(&mut page.results).await;
+    link_visible_preprints_to_published(&mut page.results).await;
+    annotate_article_search_rows_with_journal_rank(&mut page.results);
     page
 }
 
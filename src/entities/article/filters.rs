@@ -23,6 +23,33 @@ pub(super) fn normalize_article_type(value: &str) -> Result<&'static str, BioMcp
     }
 }
 
+/// Parses a quartile label (`Q2`, `q2`, or `2`) into 1-4.
+pub(super) fn quartile_number(value: &str) -> Option<u8> {
+    let value = value.trim();
+    let digits = value
+        .strip_prefix('Q')
+        .or_else(|| value.strip_prefix('q'))
+        .unwrap_or(value);
+    digits
+        .parse::<u8>()
+        .ok()
+        .filter(|number| (1..=4).contains(number))
+}
+
+pub(super) fn min_quartile(filters: &ArticleSearchFilters) -> Result<Option<u8>, BioMcpError> {
+    let Some(value) = filters
+        .min_quartile
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    else {
+        return Ok(None);
+    };
+    quartile_number(value).map(Some).ok_or_else(|| {
+        BioMcpError::InvalidArgument("--min-quartile must be one of: Q1, Q2, Q3, Q4".into())
+    })
+}
+
 fn relabel_date_argument_error(err: BioMcpError, flag_name: &str) -> BioMcpError {
     if let BioMcpError::InvalidArgument(message) = err {
         BioMcpError::InvalidArgument(message.replace("--since", flag_name))
@@ -53,6 +80,7 @@ pub(super) fn validate_search_filter_values(
     {
         normalize_article_type(article_type)?;
     }
+    min_quartile(filters)?;
     Ok(())
 }
 
@@ -135,6 +163,20 @@ fn matches_optional_journal_filter(
         .contains(&expected.to_ascii_lowercase())
 }
 
+/// Rows from unranked or unmatched journals fail any quartile threshold.
+fn matches_min_quartile_filter(row: &ArticleSearchResult, filters: &ArticleSearchFilters) -> bool {
+    let Ok(Some(threshold)) = min_quartile(filters) else {
+        return true;
+    };
+    let rank = row.journal_rank.clone().or_else(|| {
+        row.journal
+            .as_deref()
+            .and_then(crate::sources::scimago::lookup)
+    });
+    rank.and_then(|rank| quartile_number(&rank.quartile))
+        .is_some_and(|quartile| quartile <= threshold)
+}
+
 pub(super) fn matches_optional_date_filter(
     row_date: Option<&str>,
     date_from: Option<&str>,
@@ -174,6 +216,9 @@ pub(super) fn matches_result_filters(
     if !matches_optional_journal_filter(row.journal.as_deref(), filters.journal.as_deref()) {
         return false;
     }
+    if !matches_min_quartile_filter(row, filters) {
+        return false;
+    }
     if !matches_optional_date_filter(row.date.as_deref(), date_from, date_to) {
         return false;
     }
@@ -171,3 +171,52 @@ fn exclude_retracted_keeps_unknown_retraction_status() {
     assert!(matches_result_filters(&row, &exclude_filters, None, None));
     assert!(matches_result_filters(&row, &include_filters, None, None));
 }
+
+#[test]
+fn min_quartile_accepts_labels_and_rejects_out_of_range_values() {
+    assert_eq!(quartile_number("Q2"), Some(2));
+    assert_eq!(quartile_number(" q1 "), Some(1));
+    assert_eq!(quartile_number("4"), Some(4));
+    assert_eq!(quartile_number("Q5"), None);
+
+    let filters = ArticleSearchFilters {
+        min_quartile: Some("Q0".into()),
+        ..empty_filters()
+    };
+    let err = validate_search_filter_values(&filters).expect_err("Q0 should fail");
+    assert!(
+        err.to_string()
+            .contains("--min-quartile must be one of: Q1, Q2, Q3, Q4")
+    );
+}
+
+#[test]
+fn min_quartile_drops_lower_ranked_and_unranked_journals() {
+    let ranked = |quartile: &str| ArticleSearchResult {
+        journal_rank: Some(ArticleJournalRank {
+            quartile: quartile.into(),
+            sjr: None,
+            year: None,
+        }),
+        ..row("100", ArticleSource::EuropePmc)
+    };
+    let unranked = ArticleSearchResult {
+        journal: None,
+        ..row("101", ArticleSource::EuropePmc)
+    };
+    let filters = ArticleSearchFilters {
+        min_quartile: Some("Q2".into()),
+        ..empty_filters()
+    };
+
+    assert!(matches_result_filters(&ranked("Q1"), &filters, None, None));
+    assert!(matches_result_filters(&ranked("Q2"), &filters, None, None));
+    assert!(!matches_result_filters(&ranked("Q3"), &filters, None, None));
+    assert!(!matches_result_filters(&unranked, &filters, None, None));
+    assert!(matches_result_filters(
+        &unranked,
+        &empty_filters(),
+        None,
+        None
+    ));
+}
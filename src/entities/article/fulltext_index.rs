@@ -0,0 +1,156 @@
+//! Index of downloaded article full texts.
+//!
+//! Full texts are saved under `<cache_root>/downloads/` with hashed file
+//! names, so this JSON index maps each file back to its PMID, PMCID, and DOI.
+//! MCP resources list and read full texts through it.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::error::BioMcpError;
+
+use super::Article;
+
+const INDEX_FILE: &str = "fulltext-index.json";
+/// Oldest entries are dropped once the index grows past this many.
+const MAX_INDEX_ENTRIES: usize = 500;
+
+/// Serializes index rewrites from concurrent MCP tool calls within one process.
+static INDEX_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedFullText {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pmid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pmcid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+    pub title: String,
+    /// File name inside the downloads directory.
+    pub file: String,
+    /// RFC 3339 timestamp, UTC, second precision.
+    pub saved_at: String,
+}
+
+impl CachedFullText {
+    /// Identifier used in resource URIs: PMCID, then PMID, then DOI.
+    pub fn resource_id(&self) -> &str {
+        self.pmcid
+            .as_deref()
+            .or(self.pmid.as_deref())
+            .or(self.doi.as_deref())
+            .unwrap_or(self.file.as_str())
+    }
+
+    fn matches(&self, id: &str) -> bool {
+        let id = id.trim();
+        [&self.pmcid, &self.pmid, &self.doi]
+            .into_iter()
+            .flatten()
+            .any(|value| value.eq_ignore_ascii_case(id))
+    }
+}
+
+fn downloads_dir() -> Result<PathBuf, BioMcpError> {
+    Ok(crate::cache::resolve_cache_config()?
+        .cache_root
+        .join("downloads"))
+}
+
+fn load(path: &Path) -> Result<Vec<CachedFullText>, BioMcpError> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    Ok(serde_json::from_str(&content).unwrap_or_else(|err| {
+        warn!(path = %path.display(), "ignoring unreadable full-text index: {err}");
+        Vec::new()
+    }))
+}
+
+fn upsert(dir: &Path, entry: CachedFullText) -> Result<(), BioMcpError> {
+    let _guard = INDEX_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let path = dir.join(INDEX_FILE);
+    let mut entries = load(&path)?;
+    entries.retain(|row| row.file != entry.file);
+    entries.push(entry);
+    let excess = entries.len().saturating_sub(MAX_INDEX_ENTRIES);
+    entries.drain(..excess);
+
+    let content = serde_json::to_string_pretty(&entries).map_err(|err| {
+        BioMcpError::InvalidArgument(format!("failed to encode full-text index: {err}"))
+    })?;
+    std::fs::create_dir_all(dir)?;
+    let staging = path.with_extension("json.tmp");
+    std::fs::write(&staging, content)?;
+    std::fs::rename(&staging, &path)?;
+    Ok(())
+}
+
+fn entry_for(article: &Article, file: &Path) -> Option<CachedFullText> {
+    let non_empty = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    Some(CachedFullText {
+        pmid: non_empty(&article.pmid),
+        pmcid: non_empty(&article.pmcid),
+        doi: non_empty(&article.doi),
+        title: article.title.trim().to_string(),
+        file: file.file_name()?.to_str()?.to_string(),
+        saved_at: humantime::format_rfc3339_seconds(SystemTime::now()).to_string(),
+    })
+}
+
+/// Records a saved full text; failures only log so indexing never breaks `get article`.
+pub(super) fn record(article: &Article, file: &Path) {
+    let Some(entry) = entry_for(article, file) else {
+        return;
+    };
+    let dir = match file.parent() {
+        Some(dir) => dir.to_path_buf(),
+        None => return,
+    };
+    if let Err(err) = upsert(&dir, entry) {
+        warn!("failed to index downloaded full text: {err}");
+    }
+}
+
+fn list_in(dir: &Path) -> Result<Vec<CachedFullText>, BioMcpError> {
+    let mut entries = load(&dir.join(INDEX_FILE))?;
+    entries.retain(|row| {
+        Path::new(&row.file).file_name() == Some(row.file.as_ref()) && dir.join(&row.file).is_file()
+    });
+    entries.reverse();
+    Ok(entries)
+}
+
+/// Indexed full texts whose files still exist, newest first.
+pub fn cached_full_texts() -> Result<Vec<CachedFullText>, BioMcpError> {
+    list_in(&downloads_dir()?)
+}
+
+fn read_in(dir: &Path, id: &str) -> Result<Option<(CachedFullText, String)>, BioMcpError> {
+    let Some(entry) = list_in(dir)?.into_iter().find(|row| row.matches(id)) else {
+        return Ok(None);
+    };
+    let content = std::fs::read_to_string(dir.join(&entry.file))?;
+    Ok(Some((entry, content)))
+}
+
+/// Reads a downloaded full text by PMCID, PMID, or DOI.
+pub fn read_cached_full_text(id: &str) -> Result<Option<(CachedFullText, String)>, BioMcpError> {
+    read_in(&downloads_dir()?, id)
+}
+
+#[cfg(test)]
+mod tests;
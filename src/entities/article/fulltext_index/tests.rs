@@ -0,0 +1,94 @@
+//! Tests for the downloaded full-text index.
+
+use std::time::UNIX_EPOCH;
+
+use super::*;
+
+struct TempDirGuard {
+    path: PathBuf,
+}
+
+impl TempDirGuard {
+    fn new(label: &str) -> Self {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "biomcp-fulltext-index-test-{label}-{}-{stamp}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&path).expect("temp dir should be created");
+        Self { path }
+    }
+}
+
+impl Drop for TempDirGuard {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+fn entry(pmid: &str, pmcid: Option<&str>, file: &str) -> CachedFullText {
+    CachedFullText {
+        pmid: Some(pmid.to_string()),
+        pmcid: pmcid.map(str::to_string),
+        doi: None,
+        title: format!("Article {pmid}"),
+        file: file.to_string(),
+        saved_at: "2026-10-16T09:30:00Z".to_string(),
+    }
+}
+
+#[test]
+fn upsert_lists_newest_first_and_replaces_same_file() {
+    let dir = TempDirGuard::new("upsert");
+    for file in ["a.txt", "b.txt"] {
+        std::fs::write(dir.path.join(file), "# Body").expect("write full text");
+    }
+
+    upsert(&dir.path, entry("111", Some("PMC111"), "a.txt")).expect("first");
+    upsert(&dir.path, entry("222", None, "b.txt")).expect("second");
+    upsert(&dir.path, entry("111", Some("PMC999"), "a.txt")).expect("replace");
+
+    let rows = list_in(&dir.path).expect("list");
+    let ids = rows.iter().map(|row| row.resource_id()).collect::<Vec<_>>();
+    assert_eq!(ids, vec!["PMC999", "222"]);
+}
+
+#[test]
+fn list_in_skips_missing_files_and_path_like_names() {
+    let dir = TempDirGuard::new("missing");
+    std::fs::write(dir.path.join("kept.txt"), "# Body").expect("write full text");
+
+    upsert(&dir.path, entry("111", None, "kept.txt")).expect("kept");
+    upsert(&dir.path, entry("222", None, "gone.txt")).expect("gone");
+    upsert(&dir.path, entry("333", None, "../kept.txt")).expect("escape");
+
+    let rows = list_in(&dir.path).expect("list");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].pmid.as_deref(), Some("111"));
+}
+
+#[test]
+fn read_in_matches_any_identifier_case_insensitively() {
+    let dir = TempDirGuard::new("read");
+    std::fs::write(dir.path.join("a.txt"), "# Full text body").expect("write full text");
+    let mut row = entry("22663011", Some("PMC3382431"), "a.txt");
+    row.doi = Some("10.1056/NEJMoa1203421".to_string());
+    upsert(&dir.path, row).expect("upsert");
+
+    for id in [
+        "PMC3382431",
+        "pmc3382431",
+        "22663011",
+        "10.1056/nejmoa1203421",
+    ] {
+        let (found, content) = read_in(&dir.path, id)
+            .expect("read")
+            .unwrap_or_else(|| panic!("{id} should resolve"));
+        assert_eq!(found.file, "a.txt");
+        assert_eq!(content, "# Full text body");
+    }
+    assert!(read_in(&dir.path, "PMC1").expect("read").is_none());
+}
@@ -3,7 +3,7 @@
 use std::collections::HashSet;
 
 use crate::error::BioMcpError;
-use crate::sources::europepmc::EuropePmcClient;
+use crate::sources::europepmc::{EuropePmcClient, EuropePmcLinkedArticle};
 use crate::sources::semantic_scholar::{
     SemanticScholarCitationEdge, SemanticScholarClient, SemanticScholarPaper,
     SemanticScholarReferenceEdge,
@@ -13,9 +13,15 @@ use super::detail::{
     article_not_found, first_europepmc_hit, is_doi, parse_arxiv_id, parse_pmcid, parse_pmid,
 };
 use super::{
-    ArticleGraphEdge, ArticleGraphResult, ArticleRecommendationsResult, ArticleRelatedPaper,
+    Article, ArticleCitationGraph, ArticleCitationLink, ArticleGraphEdge, ArticleGraphResult,
+    ArticleRecommendationsResult, ArticleRelatedPaper,
 };
 
+/// Linked articles fetched per direction before ranking by citation count.
+const CITATION_GRAPH_FETCH_SIZE: usize = 100;
+/// Rows kept per direction in the `citations` section.
+const CITATION_GRAPH_TOP_N: usize = 10;
+
 fn is_semantic_scholar_paper_id(id: &str) -> bool {
     id.len() == 40 && id.chars().all(|ch| ch.is_ascii_hexdigit())
 }
@@ -252,5 +258,87 @@ pub async fn recommendations(
     })
 }
 
+fn json_number<T: std::str::FromStr>(value: Option<&serde_json::Value>) -> Option<T> {
+    match value? {
+        serde_json::Value::Number(number) => number.to_string().parse().ok(),
+        serde_json::Value::String(text) => text.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn citation_link_from_europepmc(row: EuropePmcLinkedArticle) -> Option<ArticleCitationLink> {
+    let id = row.id.map(|value| value.trim().to_string())?;
+    if id.is_empty() {
+        return None;
+    }
+    let source = row
+        .source
+        .map(|value| value.trim().to_ascii_uppercase())
+        .unwrap_or_default();
+    let clean = |value: Option<String>| {
+        value
+            .map(|value| value.trim().trim_end_matches('.').trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    Some(ArticleCitationLink {
+        pmid: (source == "MED").then(|| id.clone()),
+        title: clean(row.title).unwrap_or_default(),
+        authors: clean(row.author_string),
+        journal: clean(row.journal_abbreviation),
+        year: json_number(row.pub_year.as_ref()),
+        cited_by_count: json_number(row.cited_by_count.as_ref()),
+        source,
+        id,
+    })
+}
+
+/// Most-cited first; ties keep Europe PMC's order.
+fn top_citation_links(rows: Vec<EuropePmcLinkedArticle>) -> Vec<ArticleCitationLink> {
+    let mut links: Vec<ArticleCitationLink> = rows
+        .into_iter()
+        .filter_map(citation_link_from_europepmc)
+        .collect();
+    links.sort_by_key(|link| std::cmp::Reverse(link.cited_by_count.unwrap_or(0)));
+    links.truncate(CITATION_GRAPH_TOP_N);
+    links
+}
+
+/// Builds the `citations` section from Europe PMC's citations and references
+/// endpoints. Returns `None` when the article has neither a PMID nor a PMCID.
+pub(super) async fn citation_graph(
+    article: &Article,
+    europe: &EuropePmcClient,
+) -> Result<Option<ArticleCitationGraph>, BioMcpError> {
+    let (source, id) = if let Some(pmid) = article.pmid.as_deref() {
+        ("MED", pmid.trim().to_string())
+    } else if let Some(pmcid) = article.pmcid.as_deref().and_then(parse_pmcid) {
+        ("PMC", pmcid)
+    } else {
+        return Ok(None);
+    };
+
+    let (citations, references) = tokio::join!(
+        europe.citations(source, &id, CITATION_GRAPH_FETCH_SIZE),
+        europe.references(source, &id, CITATION_GRAPH_FETCH_SIZE),
+    );
+    let citations = citations?;
+    let references = references?;
+
+    let citing = citations
+        .citation_list
+        .map(|list| list.citation)
+        .unwrap_or_default();
+    let cited = references
+        .reference_list
+        .map(|list| list.reference)
+        .unwrap_or_default();
+    Ok(Some(ArticleCitationGraph {
+        cited_by_count: citations.hit_count.unwrap_or(citing.len() as u64),
+        reference_count: references.hit_count.unwrap_or(cited.len() as u64),
+        citing: top_citation_links(citing),
+        references: top_citation_links(cited),
+    }))
+}
+
 #[cfg(test)]
 mod tests;
@@ -201,3 +201,69 @@ async fn recommendations_work_without_api_key() {
     assert_eq!(result.recommendations.len(), 1);
     assert_eq!(result.recommendations[0].pmid.as_deref(), Some("28052061"));
 }
+
+#[tokio::test]
+async fn citation_graph_ranks_europepmc_links_by_citation_count() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/MED/22663011/citations"))
+        .and(query_param("format", "json"))
+        .and(query_param("pageSize", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "hitCount": 1532,
+            "citationList": {"citation": [
+                {"id": "30000001", "source": "MED", "title": "Low impact follow-up.", "journalAbbreviation": "J Low", "pubYear": 2019, "citedByCount": 3},
+                {"id": "PPR123456", "source": "PPR", "title": "Preprint follow-up", "pubYear": 2023, "citedByCount": 0},
+                {"id": "29000001", "source": "MED", "title": "High | impact follow-up", "authorString": "Doe J, Roe R.", "journalAbbreviation": "Nature", "pubYear": 2018, "citedByCount": 410}
+            ]}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/MED/22663011/references"))
+        .and(query_param("pageSize", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "hitCount": 2,
+            "referenceList": {"reference": [
+                {"id": "20000001", "source": "MED", "title": "Older reference", "pubYear": "2010", "citedByCount": "55"},
+                {"title": "Unindexed book chapter"}
+            ]}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let europe = EuropePmcClient::new_for_test(server.uri()).expect("client");
+    let article: Article = serde_json::from_value(serde_json::json!({
+        "pmid": "22663011",
+        "title": "Seed paper"
+    }))
+    .expect("article");
+    let graph = citation_graph(&article, &europe)
+        .await
+        .expect("citation graph")
+        .expect("graph for PMID article");
+
+    assert_eq!(graph.cited_by_count, 1532);
+    assert_eq!(graph.reference_count, 2);
+    let citing: Vec<&str> = graph.citing.iter().map(|link| link.id.as_str()).collect();
+    assert_eq!(citing, ["29000001", "30000001", "PPR123456"]);
+    assert_eq!(graph.citing[0].pmid.as_deref(), Some("29000001"));
+    assert_eq!(graph.citing[0].authors.as_deref(), Some("Doe J, Roe R"));
+    assert_eq!(graph.citing[1].title, "Low impact follow-up");
+    assert_eq!(graph.citing[2].pmid, None);
+    assert_eq!(graph.references.len(), 1);
+    assert_eq!(graph.references[0].year, Some(2010));
+    assert_eq!(graph.references[0].cited_by_count, Some(55));
+
+    let no_ids: Article =
+        serde_json::from_value(serde_json::json!({"doi": "10.1000/x", "title": "DOI only"}))
+            .expect("article");
+    assert!(
+        citation_graph(&no_ids, &europe)
+            .await
+            .expect("no-op")
+            .is_none()
+    );
+}
@@ -6,8 +6,10 @@ mod candidates;
 mod detail;
 mod enrichment;
 mod filters;
+mod fulltext_index;
 mod graph;
 mod planner;
+mod preprints;
 mod query;
 mod ranking;
 mod search;
@@ -16,6 +18,7 @@ mod test_support;
 
 pub use self::batch::get_batch_compact;
 pub use self::detail::get;
+pub use self::fulltext_index::{CachedFullText, cached_full_texts, read_cached_full_text};
 pub use self::graph::{citations, recommendations, references};
 #[allow(unused_imports)]
 pub(crate) use self::planner::{
@@ -28,12 +31,13 @@ pub use self::search::{search, search_page};
 
 use std::path::PathBuf;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::error::BioMcpError;
 use crate::sources::europepmc::EuropePmcSort;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Article {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pmid: Option<String>,
@@ -60,15 +64,20 @@ pub struct Article {
     pub full_text_path: Option<PathBuf>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub full_text_note: Option<String>,
+    /// Set when the saved full text is an open-access copy found outside PMC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_text_source: Option<ArticleFullTextSource>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub annotations: Option<ArticleAnnotations>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub semantic_scholar: Option<ArticleSemanticScholar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub citation_graph: Option<ArticleCitationGraph>,
     #[serde(default)]
     pub pubtator_fallback: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct ArticleSemanticScholar {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub paper_id: Option<String>,
@@ -86,7 +95,7 @@ pub struct ArticleSemanticScholar {
     pub open_access_pdf: Option<ArticleSemanticScholarPdf>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct ArticleSemanticScholarPdf {
     pub url: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -95,7 +104,26 @@ pub struct ArticleSemanticScholarPdf {
     pub license: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ArticleFullTextSource {
+    /// Service that located the copy, e.g. `Unpaywall`.
+    pub provider: String,
+    /// Document that was downloaded.
+    pub url: String,
+    /// `pdf` when the original PDF was saved, `text` when an HTML page was
+    /// converted to plain text.
+    pub format: String,
+    /// `publisher` or `repository`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_type: Option<String>,
+    /// Manuscript version, e.g. `publishedVersion` or `acceptedVersion`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct ArticleAnnotations {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub genes: Vec<AnnotationCount>,
@@ -105,14 +133,61 @@ pub struct ArticleAnnotations {
     pub chemicals: Vec<AnnotationCount>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub mutations: Vec<AnnotationCount>,
+    /// ClinicalTrials.gov NCT IDs cited in the title or abstract.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trials: Vec<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl ArticleAnnotations {
+    /// True when PubTator contributed at least one entity mention.
+    pub fn has_entities(&self) -> bool {
+        !self.genes.is_empty()
+            || !self.diseases.is_empty()
+            || !self.chemicals.is_empty()
+            || !self.mutations.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct AnnotationCount {
     pub text: String,
     pub count: u32,
 }
 
+/// Europe PMC citation network around one article.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ArticleCitationGraph {
+    /// Articles Europe PMC lists as citing this one.
+    pub cited_by_count: u64,
+    /// Entries in this article's reference list.
+    pub reference_count: u64,
+    /// Most-cited citing articles.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub citing: Vec<ArticleCitationLink>,
+    /// Most-cited referenced articles.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<ArticleCitationLink>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ArticleCitationLink {
+    /// Europe PMC record source, e.g. `MED` or `PPR` for preprints.
+    pub source: String,
+    /// Europe PMC record id; the PMID when `source` is `MED`.
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pmid: Option<String>,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authors: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub journal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cited_by_count: Option<u64>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ArticleBatchItem {
     pub requested_id: String,
@@ -172,6 +247,11 @@ pub struct ArticleSearchResult {
     pub score: Option<f64>,
     #[serde(default)]
     pub is_retracted: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub journal_rank: Option<ArticleJournalRank>,
+    /// Preprint collapsed into this published row.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preprint: Option<ArticlePreprintVersion>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub abstract_snippet: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -186,6 +266,32 @@ pub struct ArticleSearchResult {
     pub source_local_position: usize,
 }
 
+/// Journal-level context from the SCImago Journal Rank dataset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArticleJournalRank {
+    /// Best SJR quartile across the journal's subject categories (`Q1`-`Q4`).
+    pub quartile: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sjr: Option<f64>,
+    /// SCImago dataset year.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year: Option<u16>,
+}
+
+/// Identifiers of a preprint whose published version replaced it in search results.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArticlePreprintVersion {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pmid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+    /// Preprint server, e.g. `bioRxiv`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ArticlePubMedRescueKind {
@@ -341,7 +447,7 @@ impl ArticleSort {
         let value = value.trim();
         match value.to_ascii_lowercase().as_str() {
             "date" => Ok(Self::Date),
-            "citations" => Ok(Self::Citations),
+            "citations" | "cited" => Ok(Self::Citations),
             "relevance" => Ok(Self::Relevance),
             _ => Err(BioMcpError::InvalidArgument(
                 "Invalid article sort. Expected one of: date, citations, relevance".into(),
@@ -463,6 +569,7 @@ pub struct ArticleSearchFilters {
     pub open_access: bool,
     pub no_preprints: bool,
     pub exclude_retracted: bool,
+    pub min_quartile: Option<String>,
     pub max_per_source: Option<usize>,
     pub sort: ArticleSort,
     pub ranking: ArticleRankingOptions,
@@ -471,12 +578,14 @@ pub struct ArticleSearchFilters {
 const ARTICLE_SECTION_ANNOTATIONS: &str = "annotations";
 const ARTICLE_SECTION_FULLTEXT: &str = "fulltext";
 const ARTICLE_SECTION_TLDR: &str = "tldr";
+const ARTICLE_SECTION_CITATIONS: &str = "citations";
 const ARTICLE_SECTION_ALL: &str = "all";
 
 pub const ARTICLE_SECTION_NAMES: &[&str] = &[
     ARTICLE_SECTION_ANNOTATIONS,
     ARTICLE_SECTION_FULLTEXT,
     ARTICLE_SECTION_TLDR,
+    ARTICLE_SECTION_CITATIONS,
     ARTICLE_SECTION_ALL,
 ];
 
@@ -556,6 +665,10 @@ mod tests {
             ArticleSort::from_flag("citations").expect("citations should parse"),
             ArticleSort::Citations
         );
+        assert_eq!(
+            ArticleSort::from_flag("cited").expect("cited should parse"),
+            ArticleSort::Citations
+        );
         assert_eq!(
             ArticleSort::from_flag("relevance").expect("relevance should parse"),
             ArticleSort::Relevance
@@ -202,6 +202,8 @@ fn summarize_debug_plan_reports_federated_sources_and_matches() {
         ],
         score: None,
         is_retracted: Some(false),
+        journal_rank: None,
+        preprint: None,
         abstract_snippet: None,
         ranking: None,
         normalized_title: "braf melanoma".into(),
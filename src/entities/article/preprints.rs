@@ -0,0 +1,194 @@
+//! Preprint/published duplicate detection for article search.
+//!
+//! bioRxiv, medRxiv, and arXiv preprints often reappear as journal articles with
+//! a new PMID and DOI, so the identifier-based merge keeps both. Rows are
+//! matched by title instead: a preprint whose published version is already in
+//! the candidate pool is dropped, and visible preprints without one are looked
+//! up in Europe PMC and replaced by their published record when it exists.
+
+use std::collections::{HashMap, HashSet};
+
+use tracing::warn;
+
+use crate::sources::europepmc::EuropePmcClient;
+
+use super::candidates::ArticleCandidate;
+use super::filters::is_preprint_journal;
+use super::{ArticlePreprintVersion, ArticleSearchResult};
+
+/// Shorter titles ("Editorial", "Correction") are too generic to match on.
+const PREPRINT_TITLE_MIN_WORDS: usize = 4;
+const PREPRINT_LOOKUP_MAX_ROWS: usize = 5;
+const PREPRINT_LOOKUP_PAGE_SIZE: usize = 5;
+
+pub(super) fn is_preprint_row(row: &ArticleSearchResult) -> bool {
+    row.journal.as_deref().is_some_and(is_preprint_journal)
+        || row
+            .publication_type
+            .as_deref()
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("preprint"))
+}
+
+/// Lowercase alphanumeric words, so punctuation and casing edits between
+/// versions do not break the match.
+pub(super) fn preprint_title_key(title: &str) -> Option<String> {
+    let words = title
+        .split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>();
+    (words.len() >= PREPRINT_TITLE_MIN_WORDS).then(|| words.join(" "))
+}
+
+fn preprint_version(row: &ArticleSearchResult) -> ArticlePreprintVersion {
+    let non_empty = |value: &str| {
+        let value = value.trim();
+        (!value.is_empty()).then(|| value.to_string())
+    };
+    ArticlePreprintVersion {
+        pmid: non_empty(&row.pmid),
+        doi: row.doi.as_deref().and_then(non_empty),
+        server: row.journal.as_deref().and_then(non_empty),
+        date: row.date.clone(),
+    }
+}
+
+/// Drops preprint candidates whose published version is also in the pool.
+pub(super) fn collapse_preprint_candidates(
+    mut candidates: Vec<ArticleCandidate>,
+) -> Vec<ArticleCandidate> {
+    let published = candidates
+        .iter()
+        .enumerate()
+        .filter(|(_, candidate)| !is_preprint_row(&candidate.row))
+        .filter_map(|(idx, candidate)| {
+            preprint_title_key(&candidate.row.title).map(|key| (key, idx))
+        })
+        .collect::<HashMap<_, _>>();
+    if published.is_empty() {
+        return candidates;
+    }
+
+    let mut collapsed = HashSet::new();
+    for idx in 0..candidates.len() {
+        if !is_preprint_row(&candidates[idx].row) {
+            continue;
+        }
+        let Some(target) = preprint_title_key(&candidates[idx].row.title)
+            .and_then(|key| published.get(&key).copied())
+        else {
+            continue;
+        };
+        let version = preprint_version(&candidates[idx].row);
+        candidates[target].row.preprint.get_or_insert(version);
+        collapsed.insert(idx);
+    }
+
+    candidates
+        .into_iter()
+        .enumerate()
+        .filter(|(idx, _)| !collapsed.contains(idx))
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+fn promote_published_version(row: &mut ArticleSearchResult, published: ArticleSearchResult) {
+    let version = preprint_version(row);
+    row.pmid = published.pmid;
+    row.pmcid = published.pmcid;
+    row.doi = published.doi;
+    row.title = published.title;
+    row.journal = published.journal;
+    row.date = published.date;
+    row.is_retracted = published.is_retracted;
+    row.citation_count = row.citation_count.max(published.citation_count);
+    row.publication_type = published.publication_type;
+    row.journal_rank = None;
+    row.preprint = Some(version);
+}
+
+async fn find_published_version(
+    client: &EuropePmcClient,
+    row: &ArticleSearchResult,
+) -> Option<ArticleSearchResult> {
+    let key = preprint_title_key(&row.title)?;
+    let title = row.title.replace('"', " ");
+    let query = format!("TITLE:\"{}\" NOT SRC:PPR", title.trim());
+    let response = match client
+        .search_query(&query, 1, PREPRINT_LOOKUP_PAGE_SIZE)
+        .await
+    {
+        Ok(response) => response,
+        Err(err) => {
+            warn!(
+                ?err,
+                pmid = row.pmid,
+                "Europe PMC published-version lookup failed"
+            );
+            return None;
+        }
+    };
+    response
+        .result_list
+        .map(|list| list.result)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(crate::transform::article::from_europepmc_search_result)
+        .find(|hit| {
+            !is_preprint_row(hit)
+                && hit.pmid != row.pmid
+                && preprint_title_key(&hit.title).as_deref() == Some(key.as_str())
+        })
+}
+
+async fn link_preprints_with_client(client: &EuropePmcClient, rows: &mut Vec<ArticleSearchResult>) {
+    let lookups = rows
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| row.preprint.is_none() && is_preprint_row(row))
+        .map(|(idx, _)| idx)
+        .take(PREPRINT_LOOKUP_MAX_ROWS)
+        .collect::<Vec<_>>();
+
+    let mut collapsed = HashSet::new();
+    for idx in lookups {
+        let Some(published) = find_published_version(client, &rows[idx]).await else {
+            continue;
+        };
+        match rows.iter().position(|row| row.pmid == published.pmid) {
+            Some(existing) => {
+                let version = preprint_version(&rows[idx]);
+                rows[existing].preprint.get_or_insert(version);
+                collapsed.insert(idx);
+            }
+            None => promote_published_version(&mut rows[idx], published),
+        }
+    }
+    if collapsed.is_empty() {
+        return;
+    }
+
+    let mut idx = 0;
+    rows.retain(|_| {
+        idx += 1;
+        !collapsed.contains(&(idx - 1))
+    });
+}
+
+/// Replaces visible preprint rows with their published versions when Europe PMC has one.
+pub(super) async fn link_visible_preprints_to_published(rows: &mut Vec<ArticleSearchResult>) {
+    if !rows.iter().any(is_preprint_row) {
+        return;
+    }
+    let client = match EuropePmcClient::new() {
+        Ok(client) => client,
+        Err(err) => {
+            warn!(?err, "Europe PMC published-version lookup unavailable");
+            return;
+        }
+    };
+    link_preprints_with_client(&client, rows).await;
+}
+
+#[cfg(test)]
+mod tests;
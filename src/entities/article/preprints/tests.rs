@@ -0,0 +1,133 @@
+#[allow(unused_imports)]
+use super::super::test_support::*;
+use super::*;
+use crate::entities::article::candidates::merge_article_candidates;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn preprint_row(pmid: &str, title: &str) -> ArticleSearchResult {
+    ArticleSearchResult {
+        title: title.into(),
+        journal: Some("bioRxiv".into()),
+        doi: Some("10.1101/2023.05.01.538900".into()),
+        date: Some("2023-05-02".into()),
+        ..row(pmid, ArticleSource::PubTator)
+    }
+}
+
+#[test]
+fn preprint_title_key_ignores_case_punctuation_and_short_titles() {
+    assert_eq!(
+        preprint_title_key("KRAS G12C inhibition: resistance in NSCLC."),
+        preprint_title_key("KRAS G12C Inhibition - Resistance in NSCLC")
+    );
+    assert_eq!(preprint_title_key("Correction."), None);
+}
+
+#[test]
+fn collapse_preprint_candidates_keeps_published_version_with_link() {
+    let title = "Acquired resistance to KRAS G12C inhibition in cancer";
+    let published = ArticleSearchResult {
+        title: format!("{title}."),
+        journal: Some("New England Journal of Medicine".into()),
+        ..row("34161704", ArticleSource::PubMed)
+    };
+    let rows = vec![
+        preprint_row("33000001", title),
+        published,
+        row("222", ArticleSource::EuropePmc),
+    ];
+
+    let collapsed = collapse_preprint_candidates(merge_article_candidates(rows));
+
+    let pmids = collapsed
+        .iter()
+        .map(|candidate| candidate.row.pmid.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(pmids, vec!["34161704", "222"]);
+    assert_eq!(
+        collapsed[0].row.preprint,
+        Some(ArticlePreprintVersion {
+            pmid: Some("33000001".into()),
+            doi: Some("10.1101/2023.05.01.538900".into()),
+            server: Some("bioRxiv".into()),
+            date: Some("2023-05-02".into()),
+        })
+    );
+}
+
+#[tokio::test]
+async fn visible_preprints_are_replaced_by_europepmc_published_versions() {
+    let server = MockServer::start().await;
+    let title = "Single-cell atlas of glioblastoma invasion niches";
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param(
+            "query",
+            format!("TITLE:\"{title}\" NOT SRC:PPR").as_str(),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "hitCount": 1,
+            "resultList": {"result": [{
+                "id": "38000002",
+                "source": "MED",
+                "pmid": "38000002",
+                "doi": "10.1038/s41586-024-00001-x",
+                "title": "Single-cell atlas of glioblastoma invasion niches.",
+                "journalTitle": "Nature",
+                "firstPublicationDate": "2024-02-14"
+            }]}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = EuropePmcClient::new_for_test(server.uri()).expect("client");
+    let mut rows = vec![
+        preprint_row("37000001", title),
+        row("222", ArticleSource::PubMed),
+    ];
+    link_preprints_with_client(&client, &mut rows).await;
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].pmid, "38000002");
+    assert_eq!(rows[0].journal.as_deref(), Some("Nature"));
+    assert_eq!(rows[0].doi.as_deref(), Some("10.1038/s41586-024-00001-x"));
+    let preprint = rows[0].preprint.as_ref().expect("preprint link");
+    assert_eq!(preprint.pmid.as_deref(), Some("37000001"));
+    assert_eq!(preprint.server.as_deref(), Some("bioRxiv"));
+}
+
+#[tokio::test]
+async fn visible_preprint_collapses_into_published_row_already_on_page() {
+    let server = MockServer::start().await;
+    let title = "Single-cell atlas of glioblastoma invasion niches";
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "resultList": {"result": [{
+                "pmid": "38000002",
+                "title": title,
+                "journalTitle": "Nature"
+            }]}
+        })))
+        .mount(&server)
+        .await;
+
+    let client = EuropePmcClient::new_for_test(server.uri()).expect("client");
+    let mut rows = vec![
+        row("38000002", ArticleSource::PubMed),
+        preprint_row("37000001", title),
+    ];
+    link_preprints_with_client(&client, &mut rows).await;
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].pmid, "38000002");
+    assert_eq!(
+        rows[0]
+            .preprint
+            .as_ref()
+            .and_then(|preprint| preprint.pmid.as_deref()),
+        Some("37000001")
+    );
+}
@@ -21,6 +21,8 @@ fn directness_ranking_uses_full_title_and_token_boundaries() {
             source: ArticleSource::EuropePmc,
             score: None,
             is_retracted: Some(false),
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: Some("Direct abstract".into()),
             ranking: None,
             matched_sources: vec![ArticleSource::EuropePmc],
@@ -44,6 +46,8 @@ fn directness_ranking_uses_full_title_and_token_boundaries() {
             source: ArticleSource::EuropePmc,
             score: None,
             is_retracted: Some(false),
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: None,
             ranking: None,
             matched_sources: vec![ArticleSource::EuropePmc],
@@ -64,6 +68,8 @@ fn directness_ranking_uses_full_title_and_token_boundaries() {
             source: ArticleSource::EuropePmc,
             score: None,
             is_retracted: Some(false),
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: Some("MET is discussed in the abstract".into()),
             ranking: None,
             matched_sources: vec![ArticleSource::EuropePmc],
@@ -126,6 +132,8 @@ fn directness_ranking_prefers_cue_then_citation_then_source_local_position() {
             source: ArticleSource::EuropePmc,
             score: None,
             is_retracted: Some(false),
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: None,
             ranking: None,
             matched_sources: vec![ArticleSource::EuropePmc],
@@ -146,6 +154,8 @@ fn directness_ranking_prefers_cue_then_citation_then_source_local_position() {
             source: ArticleSource::EuropePmc,
             score: None,
             is_retracted: Some(false),
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: None,
             ranking: None,
             matched_sources: vec![ArticleSource::EuropePmc],
@@ -166,6 +176,8 @@ fn directness_ranking_prefers_cue_then_citation_then_source_local_position() {
             source: ArticleSource::EuropePmc,
             score: None,
             is_retracted: Some(false),
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: None,
             ranking: None,
             matched_sources: vec![ArticleSource::EuropePmc],
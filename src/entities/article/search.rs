@@ -14,7 +14,8 @@ use super::enrichment::{
     enrich_and_finalize_article_candidates, enrich_visible_article_search_page,
 };
 use super::filters::{
-    normalized_date_bounds, validate_required_search_filters, validate_search_filter_values,
+    min_quartile, normalized_date_bounds, validate_required_search_filters,
+    validate_search_filter_values,
 };
 use super::planner::{
     BackendPlan, litsense2_search_enabled, plan_backends, pubmed_filter_compatible,
@@ -301,6 +302,11 @@ pub async fn search_page(
     validate_search_filter_values(filters)?;
     validate_article_ranking_options(filters)?;
     let plan = plan_backends(filters, source)?;
+    if min_quartile(filters)?.is_some() {
+        crate::sources::scimago::ScimagoClient::ready()
+            .await?
+            .index()?;
+    }
     if filters.sort == ArticleSort::Relevance {
         return search_relevance_page(filters, limit, offset, plan).await;
     }
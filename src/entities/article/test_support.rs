@@ -3,9 +3,10 @@
 #[allow(unused_imports)]
 pub(super) use super::{
     ARTICLE_BATCH_MAX_IDS, AnnotationCount, Article, ArticleAnnotations, ArticleBatchEntitySummary,
-    ArticleBatchItem, ArticlePubMedRescueKind, ArticleRankingMode, ArticleRankingOptions,
-    ArticleSearchFilters, ArticleSearchResult, ArticleSemanticScholar, ArticleSemanticScholarPdf,
-    ArticleSort, ArticleSource, ArticleSourceFilter,
+    ArticleBatchItem, ArticleJournalRank, ArticlePreprintVersion, ArticlePubMedRescueKind,
+    ArticleRankingMode, ArticleRankingOptions, ArticleSearchFilters, ArticleSearchResult,
+    ArticleSemanticScholar, ArticleSemanticScholarPdf, ArticleSort, ArticleSource,
+    ArticleSourceFilter,
 };
 #[allow(unused_imports)]
 pub(super) use crate::entities::SearchPage;
@@ -75,6 +76,7 @@ pub(super) fn empty_filters() -> ArticleSearchFilters {
         open_access: false,
         no_preprints: false,
         exclude_retracted: false,
+        min_quartile: None,
         max_per_source: None,
         sort: ArticleSort::Relevance,
         ranking: ArticleRankingOptions::default(),
@@ -105,6 +107,8 @@ pub(super) fn row_with(
         matched_sources: vec![source],
         score: (source == ArticleSource::PubTator).then_some(42.0),
         is_retracted,
+        journal_rank: None,
+        preprint: None,
         abstract_snippet: None,
         ranking: None,
         normalized_title: format!("title-{pmid}"),
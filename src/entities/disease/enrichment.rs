@@ -1,5 +1,7 @@
 //! Disease enrichment orchestration and non-association section handlers.
 
+use std::future::Future;
+
 use super::*;
 
 use super::associations::{
@@ -12,10 +14,26 @@ use super::get::DiseaseSections;
 use super::resolution::{DiseaseLookupInput, normalize_disease_id, parse_disease_lookup_input};
 
 const OPTIONAL_ENRICHMENT_TIMEOUT: Duration = Duration::from_secs(8);
+/// Per upstream step of a requested section; sections run concurrently, so
+/// this bounds the whole fan-out rather than adding up across sections.
+const SECTION_STEP_TIMEOUT: Duration = Duration::from_secs(20);
 const SURVIVAL_NO_DATA_NOTE: &str = "SEER survival data not available for this condition.";
 const SURVIVAL_UNAVAILABLE_NOTE: &str = "SEER survival data is temporarily unavailable.";
 const FUNDING_NO_DATA_NOTE: &str = "No NIH funding data found for this query.";
 const FUNDING_UNAVAILABLE_NOTE: &str = "NIH Reporter funding data is temporarily unavailable.";
+const EPIDEMIOLOGY_SOURCE: &str = "WHO Global Health Observatory";
+const EPIDEMIOLOGY_NO_DATA_NOTE: &str =
+    "No WHO GHO incidence or prevalence indicator is curated for this condition.";
+const EPIDEMIOLOGY_UNAVAILABLE_NOTE: &str = "WHO GHO epidemiology data is temporarily unavailable.";
+const EPIDEMIOLOGY_YEAR_WINDOW: usize = 5;
+const THERAPIES_BASIS: &str = "Derived from regulatory approvals and clinical stage (OpenTargets indications, Drugs@FDA), not from curated treatment guidelines.";
+const THERAPIES_NO_DATA_NOTE: &str =
+    "No approved or late-stage drugs with an OpenTargets indication for this condition.";
+const THERAPIES_UNAVAILABLE_NOTE: &str =
+    "OpenTargets drug-indication data is temporarily unavailable.";
+const THERAPIES_CANDIDATE_LIMIT: usize = 50;
+const THERAPIES_MAX_ROWS: usize = 15;
+const THERAPIES_FDA_LOOKUPS: usize = 8;
 
 fn normalize_ols_disease_id(value: &str) -> Option<String> {
     normalize_disease_id(value).or_else(|| normalize_disease_id(&value.replace('_', ":")))
@@ -253,6 +271,7 @@ async fn add_survival_section(disease: &mut Disease) -> Result<(), BioMcpError>
         Ok(client) => client,
         Err(err) => {
             warn!("SEER Explorer unavailable for disease survival section: {err}");
+            section_errors::record(DISEASE_SECTION_SURVIVAL, "SEER Explorer", &err);
             disease.survival = None;
             disease.survival_note = Some(SURVIVAL_UNAVAILABLE_NOTE.into());
             return Ok(());
@@ -263,6 +282,7 @@ async fn add_survival_section(disease: &mut Disease) -> Result<(), BioMcpError>
         Ok(catalog) => catalog,
         Err(err) => {
             warn!("SEER Explorer catalog unavailable for disease survival section: {err}");
+            section_errors::record(DISEASE_SECTION_SURVIVAL, "SEER Explorer", &err);
             disease.survival = None;
             disease.survival_note = Some(SURVIVAL_UNAVAILABLE_NOTE.into());
             return Ok(());
@@ -285,6 +305,7 @@ async fn add_survival_section(disease: &mut Disease) -> Result<(), BioMcpError>
                 "SEER Explorer survival unavailable for disease {} at site {}: {err}",
                 disease.id, site.site_code
             );
+            section_errors::record(DISEASE_SECTION_SURVIVAL, "SEER Explorer", &err);
             disease.survival = None;
             disease.survival_note = Some(SURVIVAL_UNAVAILABLE_NOTE.into());
         }
@@ -304,10 +325,16 @@ async fn add_civic_section(disease: &mut Disease) {
         client.by_disease(&query, 10).await
     };
 
-    match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, civic_fut).await {
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        civic_fut,
+    )
+    .await
+    {
         Ok(Ok(context)) => disease.civic = Some(context),
         Ok(Err(err)) => {
             warn!(query = %query, "CIViC unavailable for disease section: {err}");
+            section_errors::record(DISEASE_SECTION_CIVIC, "CIViC", &err);
             disease.civic = Some(CivicContext::default());
         }
         Err(_) => {
@@ -316,6 +343,11 @@ async fn add_civic_section(disease: &mut Disease) {
                 timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
                 "CIViC disease section timed out"
             );
+            section_errors::record_timeout(
+                DISEASE_SECTION_CIVIC,
+                "CIViC",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
             disease.civic = Some(CivicContext::default());
         }
     }
@@ -342,7 +374,12 @@ async fn add_funding_section(disease: &mut Disease, requested_lookup: Option<&st
         client.funding(&query).await
     };
 
-    match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, funding_fut).await {
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        funding_fut,
+    )
+    .await
+    {
         Ok(Ok(section)) => {
             let no_hits = section.matching_project_years == 0 && section.grants.is_empty();
             disease.funding = Some(section);
@@ -354,6 +391,7 @@ async fn add_funding_section(disease: &mut Disease, requested_lookup: Option<&st
         }
         Ok(Err(err)) => {
             warn!(query = %query, "NIH Reporter unavailable for disease funding section: {err}");
+            section_errors::record(DISEASE_SECTION_FUNDING, "NIH Reporter", &err);
             disease.funding = None;
             disease.funding_note = Some(FUNDING_UNAVAILABLE_NOTE.into());
         }
@@ -363,12 +401,330 @@ async fn add_funding_section(disease: &mut Disease, requested_lookup: Option<&st
                 timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
                 "NIH Reporter disease funding section timed out"
             );
+            section_errors::record_timeout(
+                DISEASE_SECTION_FUNDING,
+                "NIH Reporter",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
             disease.funding = None;
             disease.funding_note = Some(FUNDING_UNAVAILABLE_NOTE.into());
         }
     }
 }
 
+/// Maps regional GHO rows to estimates, keeping both-sexes rows from the most recent years.
+fn map_epidemiology_rows(
+    indicator: &WhoGhoIndicator,
+    rows: Vec<WhoGhoRow>,
+) -> Vec<DiseaseEpidemiologyEstimate> {
+    let mut years = rows
+        .iter()
+        .filter_map(|row| row.time_dim)
+        .collect::<Vec<_>>();
+    years.sort_unstable_by(|a, b| b.cmp(a));
+    years.dedup();
+    let oldest_kept = years
+        .get(EPIDEMIOLOGY_YEAR_WINDOW - 1)
+        .or(years.last())
+        .copied();
+
+    let mut estimates = rows
+        .iter()
+        .filter(|row| row.dimension("SEX").is_none_or(|sex| sex == "SEX_BTSX"))
+        .filter_map(|row| {
+            let region_code = row.spatial_dim.as_deref()?.trim();
+            let year = row.time_dim?;
+            if region_code.is_empty() || oldest_kept.is_some_and(|oldest| year < oldest) {
+                return None;
+            }
+            Some(DiseaseEpidemiologyEstimate {
+                indicator_code: indicator.code.to_string(),
+                indicator: indicator.label.to_string(),
+                measure: indicator.measure.to_string(),
+                unit: indicator.unit.to_string(),
+                region_code: region_code.to_string(),
+                region: region_label(region_code).to_string(),
+                year,
+                age_group: row.dimension("AGEGROUP").map(str::to_string),
+                value: row.numeric_value?,
+                lower: row.low,
+                upper: row.high,
+            })
+        })
+        .collect::<Vec<_>>();
+    estimates.sort_by(|a, b| {
+        (a.region_code != "GLOBAL")
+            .cmp(&(b.region_code != "GLOBAL"))
+            .then_with(|| a.region.cmp(&b.region))
+            .then_with(|| b.year.cmp(&a.year))
+            .then_with(|| a.age_group.cmp(&b.age_group))
+    });
+    estimates
+}
+
+async fn add_epidemiology_section(disease: &mut Disease) {
+    let indicators = resolve_indicators(disease);
+    if indicators.is_empty() {
+        disease.epidemiology = None;
+        disease.epidemiology_note = Some(EPIDEMIOLOGY_NO_DATA_NOTE.into());
+        return;
+    }
+
+    let epidemiology_fut = async {
+        let client = WhoGhoClient::new()?;
+        futures::future::try_join_all(
+            indicators
+                .iter()
+                .map(|indicator| client.regional_rows(indicator.code)),
+        )
+        .await
+    };
+
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        epidemiology_fut,
+    )
+    .await
+    {
+        Ok(Ok(responses)) => {
+            let estimates = indicators
+                .iter()
+                .zip(responses)
+                .flat_map(|(indicator, rows)| map_epidemiology_rows(indicator, rows))
+                .collect::<Vec<_>>();
+            disease.epidemiology_note = if estimates.is_empty() {
+                Some(EPIDEMIOLOGY_UNAVAILABLE_NOTE.into())
+            } else {
+                None
+            };
+            disease.epidemiology = Some(DiseaseEpidemiology {
+                source: EPIDEMIOLOGY_SOURCE.into(),
+                estimates,
+            });
+        }
+        Ok(Err(err)) => {
+            warn!(disease = %disease.id, "WHO GHO unavailable for disease epidemiology section: {err}");
+            section_errors::record(DISEASE_SECTION_EPIDEMIOLOGY, "WHO GHO", &err);
+            disease.epidemiology = None;
+            disease.epidemiology_note = Some(EPIDEMIOLOGY_UNAVAILABLE_NOTE.into());
+        }
+        Err(_) => {
+            warn!(
+                disease = %disease.id,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "WHO GHO disease epidemiology section timed out"
+            );
+            section_errors::record_timeout(
+                DISEASE_SECTION_EPIDEMIOLOGY,
+                "WHO GHO",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+            disease.epidemiology = None;
+            disease.epidemiology_note = Some(EPIDEMIOLOGY_UNAVAILABLE_NOTE.into());
+        }
+    }
+}
+
+/// Approved and post-marketing indications count as first-line; Phase 3 and Phase 2/3 as second-line.
+fn therapy_line(stage: &str) -> Option<(&'static str, &'static str)> {
+    match stage.trim().to_ascii_uppercase().as_str() {
+        "APPROVAL" => Some(("first-line", "Approved")),
+        "PHASE_4" => Some(("first-line", "Phase 4")),
+        "PHASE_3" => Some(("second-line", "Phase 3")),
+        "PHASE_2_3" => Some(("second-line", "Phase 2/3")),
+        _ => None,
+    }
+}
+
+/// Expects candidates ordered by clinical stage, as returned by OpenTargets.
+fn map_therapy_candidates(candidates: Vec<OpenTargetsDiseaseDrug>) -> Vec<DiseaseTherapy> {
+    candidates
+        .into_iter()
+        .filter_map(|row| {
+            let (line, stage) = therapy_line(row.max_clinical_stage.as_deref()?)?;
+            Some(DiseaseTherapy {
+                line: line.to_string(),
+                drug: row.name,
+                chembl_id: row.chembl_id,
+                drug_type: row.drug_type,
+                max_clinical_stage: stage.to_string(),
+                fda_application: None,
+                fda_approval_date: None,
+            })
+        })
+        .take(THERAPIES_MAX_ROWS)
+        .collect()
+}
+
+fn format_fda_date(value: &str) -> String {
+    let value = value.trim();
+    if value.len() == 8 && value.chars().all(|c| c.is_ascii_digit()) {
+        format!("{}-{}-{}", &value[0..4], &value[4..6], &value[6..8])
+    } else {
+        value.to_string()
+    }
+}
+
+/// Application with the earliest original (`ORIG`) approval across Drugs@FDA results.
+fn earliest_fda_approval(
+    resp: OpenFdaResponse<DrugsFdaResult>,
+) -> Option<(String, Option<String>)> {
+    resp.results
+        .into_iter()
+        .filter_map(|row| {
+            let application = row
+                .application_number
+                .as_deref()
+                .map(str::trim)
+                .filter(|v| !v.is_empty())?
+                .to_string();
+            let approved = row
+                .submissions
+                .iter()
+                .filter(|sub| {
+                    sub.submission_type
+                        .as_deref()
+                        .is_some_and(|v| v.trim().eq_ignore_ascii_case("ORIG"))
+                        && sub
+                            .submission_status
+                            .as_deref()
+                            .is_some_and(|v| v.trim().eq_ignore_ascii_case("AP"))
+                })
+                .filter_map(|sub| sub.submission_status_date.as_deref())
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .min()
+                .map(str::to_string);
+            Some((application, approved))
+        })
+        .min_by(|a, b| match (&a.1, &b.1) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        })
+        .map(|(application, approved)| (application, approved.as_deref().map(format_fda_date)))
+}
+
+async fn attach_fda_approvals(therapies: &mut [DiseaseTherapy]) {
+    let client = match OpenFdaClient::new() {
+        Ok(client) => client,
+        Err(err) => {
+            warn!("OpenFDA client init failed for disease therapies section: {err}");
+            return;
+        }
+    };
+
+    let lookups = therapies
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| row.line == "first-line")
+        .take(THERAPIES_FDA_LOOKUPS)
+        .map(|(idx, row)| {
+            let escaped = OpenFdaClient::escape_query_value(&row.drug);
+            let query = format!("openfda.generic_name:\"{escaped}\"");
+            let client = &client;
+            async move { (idx, client.drugsfda_search(&query, 5, 0).await) }
+        })
+        .collect::<Vec<_>>();
+
+    for (idx, result) in join_all(lookups).await {
+        match result {
+            Ok(Some(resp)) => {
+                if let Some((application, approved)) = earliest_fda_approval(resp) {
+                    therapies[idx].fda_application = Some(application);
+                    therapies[idx].fda_approval_date = approved;
+                }
+            }
+            Ok(None) => {}
+            Err(err) => {
+                warn!(drug = %therapies[idx].drug, "OpenFDA Drugs@FDA unavailable for disease therapies section: {err}");
+            }
+        }
+    }
+}
+
+async fn add_therapies_section(disease: &mut Disease) {
+    let mut queries: Vec<String> = Vec::new();
+    for query in [disease.id.trim(), disease.name.trim()] {
+        if query.is_empty() || queries.iter().any(|q| q.eq_ignore_ascii_case(query)) {
+            continue;
+        }
+        queries.push(query.to_string());
+    }
+
+    let therapies_fut = async {
+        let client = OpenTargetsClient::new()?;
+        for query in &queries {
+            let rows = client
+                .disease_drug_candidates(query, THERAPIES_CANDIDATE_LIMIT)
+                .await?;
+            if !rows.is_empty() {
+                return Ok(rows);
+            }
+        }
+        Ok::<_, BioMcpError>(Vec::new())
+    };
+
+    let candidates = match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        therapies_fut,
+    )
+    .await
+    {
+        Ok(Ok(candidates)) => candidates,
+        Ok(Err(err)) => {
+            warn!(disease = %disease.id, "OpenTargets unavailable for disease therapies section: {err}");
+            section_errors::record(DISEASE_SECTION_THERAPIES, "Open Targets", &err);
+            disease.therapies = None;
+            disease.therapies_note = Some(THERAPIES_UNAVAILABLE_NOTE.into());
+            return;
+        }
+        Err(_) => {
+            warn!(
+                disease = %disease.id,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "OpenTargets disease therapies section timed out"
+            );
+            section_errors::record_timeout(
+                DISEASE_SECTION_THERAPIES,
+                "Open Targets",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+            disease.therapies = None;
+            disease.therapies_note = Some(THERAPIES_UNAVAILABLE_NOTE.into());
+            return;
+        }
+    };
+
+    let mut therapies = map_therapy_candidates(candidates);
+    if therapies.is_empty() {
+        disease.therapies = None;
+        disease.therapies_note = Some(THERAPIES_NO_DATA_NOTE.into());
+        return;
+    }
+
+    if tokio::time::timeout(
+        OPTIONAL_ENRICHMENT_TIMEOUT,
+        attach_fda_approvals(&mut therapies),
+    )
+    .await
+    .is_err()
+    {
+        warn!(
+            disease = %disease.id,
+            timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+            "OpenFDA Drugs@FDA lookups for disease therapies section timed out"
+        );
+    }
+
+    disease.therapies_note = None;
+    disease.therapies = Some(DiseaseTherapies {
+        basis: THERAPIES_BASIS.into(),
+        therapies,
+    });
+}
+
 fn map_disgenet_disease_association(row: DisgenetAssociationRecord) -> DiseaseDisgenetAssociation {
     DiseaseDisgenetAssociation {
         symbol: row.gene_symbol,
@@ -418,64 +774,279 @@ pub(super) async fn enrich_base_context(disease: &mut Disease) {
     }
 }
 
+/// Runs one upstream step of a requested section under
+/// [`SECTION_STEP_TIMEOUT`], logging and recording a failure or timeout against
+/// `section`. Returns whether the step completed.
+async fn section_step<F>(section: &str, source: &str, step: F) -> bool
+where
+    F: Future<Output = Result<(), BioMcpError>>,
+{
+    let timeout = section_errors::budget(SECTION_STEP_TIMEOUT);
+    match tokio::time::timeout(timeout, step).await {
+        Ok(Ok(())) => true,
+        Ok(Err(err)) => {
+            warn!(section, source, "disease section source unavailable: {err}");
+            section_errors::record(section, source, &err);
+            false
+        }
+        Err(_) => {
+            warn!(
+                section,
+                source,
+                timeout_secs = timeout.as_secs(),
+                "disease section source timed out"
+            );
+            section_errors::record_timeout(section, source, timeout);
+            false
+        }
+    }
+}
+
+/// Fills the requested sections. Each section works on its own copy of the
+/// card and they run concurrently; pathways reuse the gene list, so genes and
+/// pathways share one branch. Only the fields a section owns are copied back.
 pub(super) async fn apply_requested_sections(
     disease: &mut Disease,
     sections: DiseaseSections,
     requested_lookup: Option<&str>,
 ) -> Result<(), BioMcpError> {
-    if sections.include_genes {
-        if let Err(err) = add_monarch_gene_section(disease).await {
-            warn!("Monarch unavailable for disease genes section: {err}");
+    let base: &Disease = disease;
+
+    let genes = async {
+        if !sections.include_genes && !sections.include_pathways {
+            return None;
         }
-        if let Err(err) = augment_genes_with_civic(disease).await {
-            warn!("CIViC unavailable for disease gene augmentation: {err}");
+        let mut part = base.clone();
+        if sections.include_genes {
+            section_step(
+                DISEASE_SECTION_GENES,
+                "Monarch",
+                add_monarch_gene_section(&mut part),
+            )
+            .await;
+            section_step(
+                DISEASE_SECTION_GENES,
+                "CIViC",
+                augment_genes_with_civic(&mut part),
+            )
+            .await;
+            section_step(
+                DISEASE_SECTION_GENES,
+                "Open Targets",
+                augment_genes_with_opentargets(&mut part),
+            )
+            .await;
+            attach_opentargets_scores(&mut part);
         }
-        if let Err(err) = augment_genes_with_opentargets(disease).await {
-            warn!("OpenTargets unavailable for disease gene augmentation: {err}");
+        if sections.include_pathways {
+            section_step(
+                DISEASE_SECTION_PATHWAYS,
+                "Reactome",
+                add_pathways_section(&mut part),
+            )
+            .await;
         }
-        attach_opentargets_scores(disease);
-    }
-    if sections.include_pathways
-        && let Err(err) = add_pathways_section(disease).await
-    {
-        warn!("Reactome unavailable for disease pathways section: {err}");
-    }
-    if sections.include_phenotypes {
-        if let Err(err) = add_monarch_phenotypes(disease).await {
-            warn!("Monarch unavailable for disease phenotypes section: {err}");
+        Some(part)
+    };
+    let phenotypes = async {
+        if !sections.include_phenotypes {
+            return None;
+        }
+        let mut part = base.clone();
+        section_step(
+            DISEASE_SECTION_PHENOTYPES,
+            "Monarch",
+            add_monarch_phenotypes(&mut part),
+        )
+        .await;
+        section_step(
+            DISEASE_SECTION_PHENOTYPES,
+            "HPO",
+            add_phenotypes_section(&mut part),
+        )
+        .await;
+        Some(part)
+    };
+    let variants = async {
+        if !sections.include_variants {
+            return None;
+        }
+        let mut part = base.clone();
+        section_step(
+            DISEASE_SECTION_VARIANTS,
+            "CIViC",
+            add_civic_variants(&mut part),
+        )
+        .await;
+        Some(part)
+    };
+    let models = async {
+        if !sections.include_models {
+            return None;
+        }
+        let mut part = base.clone();
+        section_step(
+            DISEASE_SECTION_MODELS,
+            "Monarch",
+            add_monarch_models(&mut part),
+        )
+        .await;
+        Some(part)
+    };
+    let prevalence = async {
+        if !sections.include_prevalence {
+            return None;
+        }
+        let mut part = base.clone();
+        if !section_step(
+            DISEASE_SECTION_PREVALENCE,
+            "Open Targets",
+            add_prevalence_section(&mut part),
+        )
+        .await
+        {
+            part.prevalence.clear();
+            part.prevalence_note = Some("No prevalence data available from OpenTargets.".into());
         }
-        if let Err(err) = add_phenotypes_section(disease).await {
-            warn!("HPO unavailable for disease phenotypes section: {err}");
+        Some(part)
+    };
+    let survival = async {
+        if !sections.include_survival {
+            return None;
+        }
+        let mut part = base.clone();
+        if !section_step(
+            DISEASE_SECTION_SURVIVAL,
+            "SEER Explorer",
+            add_survival_section(&mut part),
+        )
+        .await
+        {
+            part.survival = None;
+            part.survival_note = Some(SURVIVAL_UNAVAILABLE_NOTE.into());
+        }
+        Some(part)
+    };
+    let funding = async {
+        if !sections.include_funding {
+            return None;
+        }
+        let mut part = base.clone();
+        add_funding_section(&mut part, requested_lookup).await;
+        Some(part)
+    };
+    let civic = async {
+        if !sections.include_civic {
+            return None;
+        }
+        let mut part = base.clone();
+        add_civic_section(&mut part).await;
+        Some(part)
+    };
+    // A DisGeNET failure (usually a missing API key) fails the whole request.
+    let disgenet = async {
+        if !sections.include_disgenet {
+            return Ok(None);
         }
+        let mut part = base.clone();
+        let timeout = section_errors::budget(SECTION_STEP_TIMEOUT);
+        match tokio::time::timeout(timeout, add_disgenet_section(&mut part)).await {
+            Ok(result) => result?,
+            Err(_) => {
+                warn!(
+                    timeout_secs = timeout.as_secs(),
+                    "DisGeNET timed out for disease disgenet section"
+                );
+                section_errors::record_timeout(DISEASE_SECTION_DISGENET, "DisGeNET", timeout);
+            }
+        }
+        Ok::<_, BioMcpError>(Some(part))
+    };
+    let epidemiology = async {
+        if !sections.include_epidemiology {
+            return None;
+        }
+        let mut part = base.clone();
+        add_epidemiology_section(&mut part).await;
+        Some(part)
+    };
+    let therapies = async {
+        if !sections.include_therapies {
+            return None;
+        }
+        let mut part = base.clone();
+        add_therapies_section(&mut part).await;
+        Some(part)
+    };
+
+    let (
+        genes,
+        phenotypes,
+        variants,
+        models,
+        prevalence,
+        survival,
+        funding,
+        civic,
+        disgenet,
+        epidemiology,
+        therapies,
+    ) = tokio::join!(
+        genes,
+        phenotypes,
+        variants,
+        models,
+        prevalence,
+        survival,
+        funding,
+        civic,
+        disgenet,
+        epidemiology,
+        therapies
+    );
+    let disgenet = disgenet?;
+
+    if let Some(part) = genes {
+        disease.associated_genes = part.associated_genes;
+        disease.gene_associations = part.gene_associations;
+        disease.top_gene_scores = part.top_gene_scores;
+        disease.pathways = part.pathways;
     }
-    if sections.include_variants
-        && let Err(err) = add_civic_variants(disease).await
-    {
-        warn!("CIViC unavailable for disease variants section: {err}");
+    if let Some(part) = phenotypes {
+        disease.phenotypes = part.phenotypes;
     }
-    if sections.include_models
-        && let Err(err) = add_monarch_models(disease).await
-    {
-        warn!("Monarch unavailable for disease models section: {err}");
+    if let Some(part) = variants {
+        disease.variants = part.variants;
+        disease.top_variant = part.top_variant;
     }
-    if sections.include_prevalence
-        && let Err(err) = add_prevalence_section(disease).await
-    {
-        warn!("OpenTargets unavailable for disease prevalence section: {err}");
-        disease.prevalence.clear();
-        disease.prevalence_note = Some("No prevalence data available from OpenTargets.".into());
+    if let Some(part) = models {
+        disease.models = part.models;
+    }
+    if let Some(part) = prevalence {
+        disease.prevalence = part.prevalence;
+        disease.prevalence_note = part.prevalence_note;
     }
-    if sections.include_survival {
-        add_survival_section(disease).await?;
+    if let Some(part) = survival {
+        disease.survival = part.survival;
+        disease.survival_note = part.survival_note;
     }
-    if sections.include_funding {
-        add_funding_section(disease, requested_lookup).await;
+    if let Some(part) = funding {
+        disease.funding = part.funding;
+        disease.funding_note = part.funding_note;
     }
-    if sections.include_civic {
-        add_civic_section(disease).await;
+    if let Some(part) = civic {
+        disease.civic = part.civic;
     }
-    if sections.include_disgenet {
-        add_disgenet_section(disease).await?;
+    if let Some(part) = disgenet {
+        disease.disgenet = part.disgenet;
+    }
+    if let Some(part) = epidemiology {
+        disease.epidemiology = part.epidemiology;
+        disease.epidemiology_note = part.epidemiology_note;
+    }
+    if let Some(part) = therapies {
+        disease.therapies = part.therapies;
+        disease.therapies_note = part.therapies_note;
     }
 
     if !sections.include_genes && !sections.include_pathways {
@@ -510,6 +1081,14 @@ pub(super) async fn apply_requested_sections(
     if !sections.include_disgenet {
         disease.disgenet = None;
     }
+    if !sections.include_epidemiology {
+        disease.epidemiology = None;
+        disease.epidemiology_note = None;
+    }
+    if !sections.include_therapies {
+        disease.therapies = None;
+        disease.therapies_note = None;
+    }
 
     disease.key_features = transform::disease::derive_key_features(disease);
 
@@ -41,6 +41,353 @@ async fn apply_requested_sections_clears_funding_when_not_requested() {
     assert!(disease.funding_note.is_none());
 }
 
+#[tokio::test]
+async fn apply_requested_sections_fetches_independent_sections_concurrently() {
+    let _lock = lock_env().await;
+    with_no_http_cache(async {
+        let delay = std::time::Duration::from_millis(500);
+        let monarch = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v3/api/association"))
+            .and(query_param("object", "MONDO:0005136"))
+            .and(query_param("subject_category", "biolink:Genotype"))
+            .respond_with(ResponseTemplate::new(200).set_delay(delay).set_body_json(
+                serde_json::json!({
+                    "items": [{
+                        "subject": "MGI:0000001",
+                        "subject_label": "Pb ANKA infected",
+                        "subject_taxon_label": "Mus musculus",
+                        "predicate": "biolink:model_of"
+                    }]
+                }),
+            ))
+            .expect(1)
+            .mount(&monarch)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v3/api/association"))
+            .and(query_param("subject", "MONDO:0005136"))
+            .and(query_param("object_category", "biolink:PhenotypicFeature"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_delay(delay)
+                    .set_body_json(serde_json::json!({"items": []})),
+            )
+            .expect(1)
+            .mount(&monarch)
+            .await;
+        let gho = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/MALARIA_EST_INCIDENCE"))
+            .respond_with(ResponseTemplate::new(200).set_delay(delay).set_body_json(
+                serde_json::json!({
+                    "value": [
+                        {"SpatialDimType": "GLOBAL", "SpatialDim": "GLOBAL", "TimeDim": 2022,
+                         "NumericValue": 58.4}
+                    ]
+                }),
+            ))
+            .expect(1)
+            .mount(&gho)
+            .await;
+        let _monarch_base = set_env_var("BIOMCP_MONARCH_BASE", Some(&monarch.uri()));
+        let _gho_base = set_env_var("BIOMCP_WHO_GHO_BASE", Some(&gho.uri()));
+
+        let mut disease = test_disease("MONDO:0005136", "malaria");
+        let sections = DiseaseSections {
+            include_phenotypes: true,
+            include_models: true,
+            include_epidemiology: true,
+            ..DiseaseSections::default()
+        };
+        let started = std::time::Instant::now();
+        let ((), errors) = section_errors::collect(async {
+            apply_requested_sections(&mut disease, sections, None)
+                .await
+                .expect("sections should apply");
+        })
+        .await;
+        let elapsed = started.elapsed();
+
+        // Run one after another the three delayed calls take at least 1.5s.
+        assert!(
+            elapsed < std::time::Duration::from_millis(1200),
+            "{elapsed:?}"
+        );
+        assert!(errors.is_empty(), "{errors:?}");
+        assert_eq!(disease.models.len(), 1);
+        assert_eq!(disease.models[0].model, "Pb ANKA infected");
+        assert!(disease.phenotypes.is_empty());
+        assert_eq!(
+            disease
+                .epidemiology
+                .as_ref()
+                .map(|section| section.estimates.len()),
+            Some(1)
+        );
+        assert!(disease.funding.is_none());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn section_step_records_a_deadline_when_the_budget_runs_out() {
+    let started = std::time::Instant::now();
+    let ((completed, errors), skipped) =
+        section_errors::with_soft_deadline(std::time::Duration::from_millis(100), async {
+            section_errors::collect(section_step(DISEASE_SECTION_MODELS, "Monarch", async {
+                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+                Ok(())
+            }))
+            .await
+        })
+        .await;
+
+    assert!(!completed);
+    assert!(started.elapsed() < std::time::Duration::from_millis(900));
+    assert_eq!(skipped, vec![DISEASE_SECTION_MODELS.to_string()]);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].source, "Monarch");
+    assert_eq!(errors[0].error_code, "deadline");
+}
+
+fn gho_row(region: &str, year: i32, sex: &str, value: f64) -> WhoGhoRow {
+    WhoGhoRow {
+        spatial_dim_type: Some("REGION".into()),
+        spatial_dim: Some(region.into()),
+        time_dim: Some(year),
+        dim1_type: Some("SEX".into()),
+        dim1: Some(sex.into()),
+        dim2_type: None,
+        dim2: None,
+        numeric_value: Some(value),
+        low: None,
+        high: None,
+    }
+}
+
+#[test]
+fn map_epidemiology_rows_keeps_recent_both_sexes_rows_global_first() {
+    let indicator = resolve_indicators(&test_disease("MONDO:0018076", "tuberculosis"))[0];
+    let mut rows = (2015..=2022)
+        .map(|year| gho_row("AFR", year, "SEX_BTSX", f64::from(year - 1800)))
+        .collect::<Vec<_>>();
+    rows.push(gho_row("AFR", 2022, "SEX_MLE", 300.0));
+    rows.push(gho_row("GLOBAL", 2022, "SEX_BTSX", 133.0));
+
+    let estimates = map_epidemiology_rows(&indicator, rows);
+    let summary = estimates
+        .iter()
+        .map(|row| (row.region.as_str(), row.year))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            ("Global", 2022),
+            ("Africa", 2022),
+            ("Africa", 2021),
+            ("Africa", 2020),
+            ("Africa", 2019),
+            ("Africa", 2018),
+        ]
+    );
+    assert_eq!(estimates[1].value, 222.0);
+    assert_eq!(estimates[0].measure, "incidence");
+}
+
+#[tokio::test]
+async fn add_epidemiology_section_sets_note_for_uncurated_disease() {
+    let mut disease = test_disease("MONDO:0007947", "Marfan syndrome");
+    add_epidemiology_section(&mut disease).await;
+
+    assert!(disease.epidemiology.is_none());
+    assert_eq!(
+        disease.epidemiology_note.as_deref(),
+        Some(EPIDEMIOLOGY_NO_DATA_NOTE)
+    );
+}
+
+#[tokio::test]
+async fn add_epidemiology_section_maps_gho_rows() {
+    let _lock = lock_env().await;
+    with_no_http_cache(async {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/MALARIA_EST_INCIDENCE"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "value": [
+                    {"SpatialDimType": "GLOBAL", "SpatialDim": "GLOBAL", "TimeDim": 2022,
+                     "NumericValue": 58.4, "Low": 51.0, "High": 69.0}
+                ]
+            })))
+            .mount(&server)
+            .await;
+        let _gho_base = set_env_var("BIOMCP_WHO_GHO_BASE", Some(&server.uri()));
+
+        let mut disease = test_disease("MONDO:0005136", "malaria");
+        add_epidemiology_section(&mut disease).await;
+
+        let epidemiology = disease.epidemiology.expect("epidemiology");
+        assert_eq!(epidemiology.source, EPIDEMIOLOGY_SOURCE);
+        assert_eq!(epidemiology.estimates.len(), 1);
+        assert_eq!(epidemiology.estimates[0].region, "Global");
+        assert_eq!(epidemiology.estimates[0].upper, Some(69.0));
+        assert!(disease.epidemiology_note.is_none());
+    })
+    .await;
+}
+
+fn ot_candidate(name: &str, stage: &str) -> OpenTargetsDiseaseDrug {
+    OpenTargetsDiseaseDrug {
+        chembl_id: None,
+        name: name.into(),
+        drug_type: Some("Small molecule".into()),
+        max_clinical_stage: Some(stage.into()),
+    }
+}
+
+#[test]
+fn map_therapy_candidates_splits_approved_and_late_stage_lines() {
+    let therapies = map_therapy_candidates(vec![
+        ot_candidate("Methotrexate", "APPROVAL"),
+        ot_candidate("Tofacitinib", "PHASE_4"),
+        ot_candidate("Olokizumab", "PHASE_3"),
+        ot_candidate("Otilimab", "PHASE_2"),
+    ]);
+    let summary = therapies
+        .iter()
+        .map(|row| {
+            (
+                row.line.as_str(),
+                row.drug.as_str(),
+                row.max_clinical_stage.as_str(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            ("first-line", "Methotrexate", "Approved"),
+            ("first-line", "Tofacitinib", "Phase 4"),
+            ("second-line", "Olokizumab", "Phase 3"),
+        ]
+    );
+}
+
+#[test]
+fn earliest_fda_approval_prefers_oldest_original_approval() {
+    let resp: OpenFdaResponse<DrugsFdaResult> = serde_json::from_value(serde_json::json!({
+        "meta": {"results": {"skip": 0, "limit": 5, "total": 2}},
+        "results": [
+            {
+                "application_number": "ANDA040054",
+                "submissions": [
+                    {"submission_type": "ORIG", "submission_status": "AP", "submission_status_date": "19950601"}
+                ]
+            },
+            {
+                "application_number": "NDA008085",
+                "submissions": [
+                    {"submission_type": "SUPPL", "submission_status": "AP", "submission_status_date": "19880101"},
+                    {"submission_type": "ORIG", "submission_status": "AP", "submission_status_date": "19531207"}
+                ]
+            }
+        ]
+    }))
+    .expect("drugsfda response");
+
+    assert_eq!(
+        earliest_fda_approval(resp),
+        Some(("NDA008085".to_string(), Some("1953-12-07".to_string())))
+    );
+}
+
+#[tokio::test]
+async fn add_therapies_section_merges_opentargets_stages_with_drugsfda() {
+    let _lock = lock_env().await;
+    with_no_http_cache(async {
+        let opentargets = MockServer::start().await;
+        let openfda = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("DiseaseDrugCandidates"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"disease": {"drugAndClinicalCandidates": {"rows": [
+                    {"maxClinicalStage": "APPROVAL", "drug": {"id": "CHEMBL34259", "name": "METHOTREXATE", "drugType": "Small molecule"}},
+                    {"maxClinicalStage": "PHASE_3", "drug": {"id": "CHEMBL4297610", "name": "OLOKIZUMAB", "drugType": "Antibody"}}
+                ]}}}
+            })))
+            .mount(&opentargets)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/drug/drugsfda.json"))
+            .and(query_param("search", "openfda.generic_name:\"METHOTREXATE\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "meta": {"results": {"skip": 0, "limit": 5, "total": 1}},
+                "results": [{
+                    "application_number": "NDA008085",
+                    "submissions": [
+                        {"submission_type": "ORIG", "submission_status": "AP", "submission_status_date": "19531207"}
+                    ]
+                }]
+            })))
+            .expect(1)
+            .mount(&openfda)
+            .await;
+        let _ot_base = set_env_var("BIOMCP_OPENTARGETS_BASE", Some(&opentargets.uri()));
+        let _fda_base = set_env_var("BIOMCP_OPENFDA_BASE", Some(&openfda.uri()));
+
+        let mut disease = test_disease("EFO_0000685", "rheumatoid arthritis");
+        add_therapies_section(&mut disease).await;
+
+        let therapies = disease.therapies.expect("therapies");
+        assert_eq!(therapies.basis, THERAPIES_BASIS);
+        assert_eq!(therapies.therapies.len(), 2);
+        assert_eq!(therapies.therapies[0].line, "first-line");
+        assert_eq!(
+            therapies.therapies[0].fda_application.as_deref(),
+            Some("NDA008085")
+        );
+        assert_eq!(
+            therapies.therapies[0].fda_approval_date.as_deref(),
+            Some("1953-12-07")
+        );
+        assert_eq!(therapies.therapies[1].line, "second-line");
+        assert!(therapies.therapies[1].fda_application.is_none());
+        assert!(disease.therapies_note.is_none());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn add_therapies_section_sets_note_when_no_late_stage_drugs() {
+    let _lock = lock_env().await;
+    with_no_http_cache(async {
+        let opentargets = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("DiseaseDrugCandidates"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"disease": {"drugAndClinicalCandidates": {"rows": [
+                    {"maxClinicalStage": "PHASE_1", "drug": {"id": "CHEMBL1", "name": "EXAMPLIMAB"}}
+                ]}}}
+            })))
+            .mount(&opentargets)
+            .await;
+        let _ot_base = set_env_var("BIOMCP_OPENTARGETS_BASE", Some(&opentargets.uri()));
+
+        let mut disease = test_disease("EFO_0000685", "rheumatoid arthritis");
+        add_therapies_section(&mut disease).await;
+
+        assert!(disease.therapies.is_none());
+        assert_eq!(
+            disease.therapies_note.as_deref(),
+            Some(THERAPIES_NO_DATA_NOTE)
+        );
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn add_survival_section_sets_truthful_note_for_unmapped_disease() {
     let _lock = lock_env().await;
@@ -21,6 +21,8 @@ pub(super) struct DiseaseSections {
     pub(super) include_funding: bool,
     pub(super) include_civic: bool,
     pub(super) include_disgenet: bool,
+    pub(super) include_epidemiology: bool,
+    pub(super) include_therapies: bool,
 }
 
 fn parse_sections(sections: &[String]) -> Result<DiseaseSections, BioMcpError> {
@@ -47,6 +49,8 @@ fn parse_sections(sections: &[String]) -> Result<DiseaseSections, BioMcpError> {
             DISEASE_SECTION_FUNDING => out.include_funding = true,
             DISEASE_SECTION_CIVIC => out.include_civic = true,
             DISEASE_SECTION_DISGENET => out.include_disgenet = true,
+            DISEASE_SECTION_EPIDEMIOLOGY => out.include_epidemiology = true,
+            DISEASE_SECTION_THERAPIES => out.include_therapies = true,
             DISEASE_SECTION_ALL => include_all = true,
             _ => {
                 return Err(BioMcpError::InvalidArgument(format!(
@@ -97,7 +101,7 @@ pub async fn get(name_or_id: &str, sections: &[String]) -> Result<Disease, BioMc
             disease.parents = resolve_parent_names(&client, &disease.parents).await;
             enrich_base_context(&mut disease).await;
             apply_requested_sections(&mut disease, parsed_sections, Some(name_or_id)).await?;
-            return Ok(disease);
+            return transform::fields::apply("disease", disease);
         }
         DiseaseLookupInput::CrosswalkId(kind, value) => {
             let resp = client
@@ -116,7 +120,7 @@ pub async fn get(name_or_id: &str, sections: &[String]) -> Result<Disease, BioMc
             disease.parents = resolve_parent_names(&client, &disease.parents).await;
             enrich_base_context(&mut disease).await;
             apply_requested_sections(&mut disease, parsed_sections, Some(name_or_id)).await?;
-            return Ok(disease);
+            return transform::fields::apply("disease", disease);
         }
         DiseaseLookupInput::FreeText => {}
     }
@@ -131,7 +135,7 @@ pub async fn get(name_or_id: &str, sections: &[String]) -> Result<Disease, BioMc
     disease.parents = resolve_parent_names(&client, &disease.parents).await;
     enrich_base_context(&mut disease).await;
     apply_requested_sections(&mut disease, parsed_sections, Some(name_or_id)).await?;
-    Ok(disease)
+    transform::fields::apply("disease", disease)
 }
 
 async fn resolve_parent_label(client: &MyDiseaseClient, parent_id: &str) -> String {
@@ -11,6 +11,8 @@ fn parse_sections_supports_new_disease_sections() {
         "survival".to_string(),
         "funding".to_string(),
         "disgenet".to_string(),
+        "epidemiology".to_string(),
+        "therapies".to_string(),
         "all".to_string(),
     ])
     .expect("sections should parse");
@@ -24,6 +26,8 @@ fn parse_sections_supports_new_disease_sections() {
     assert!(flags.include_funding);
     assert!(flags.include_civic);
     assert!(flags.include_disgenet);
+    assert!(flags.include_epidemiology);
+    assert!(flags.include_therapies);
 }
 
 #[test]
@@ -32,6 +36,8 @@ fn parse_sections_all_keeps_disgenet_opt_in() {
     assert!(flags.include_survival);
     assert!(!flags.include_funding);
     assert!(!flags.include_disgenet);
+    assert!(!flags.include_epidemiology);
+    assert!(!flags.include_therapies);
 }
 
 #[tokio::test]
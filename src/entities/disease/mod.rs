@@ -4,11 +4,13 @@ use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 
 use futures::future::join_all;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
 
 use crate::entities::SearchPage;
 use crate::entities::drug::{self, DrugSearchFilters};
+use crate::entities::section_errors;
 use crate::entities::trial::{self, TrialSearchFilters, TrialSource};
 use crate::error::BioMcpError;
 use crate::sources::civic::{CivicClient, CivicContext};
@@ -20,12 +22,16 @@ use crate::sources::monarch::{
 use crate::sources::mydisease::{MyDiseaseClient, MyDiseaseHit};
 use crate::sources::nih_reporter::{NihReporterClient, NihReporterFundingSection};
 use crate::sources::ols4::OlsClient;
-use crate::sources::opentargets::OpenTargetsClient;
+use crate::sources::openfda::{DrugsFdaResult, OpenFdaClient, OpenFdaResponse};
+use crate::sources::opentargets::{OpenTargetsClient, OpenTargetsDiseaseDrug};
 use crate::sources::reactome::ReactomeClient;
 use crate::sources::seer::{SeerClient, SeerSurvivalPayload, resolve_site};
+use crate::sources::who_gho::{
+    WhoGhoClient, WhoGhoIndicator, WhoGhoRow, region_label, resolve_indicators,
+};
 use crate::transform;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Disease {
     pub id: String, // e.g., MONDO:0005105
     pub name: String,
@@ -72,6 +78,14 @@ pub struct Disease {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub funding_note: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub epidemiology: Option<DiseaseEpidemiology>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epidemiology_note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub therapies: Option<DiseaseTherapies>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub therapies_note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub civic: Option<CivicContext>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disgenet: Option<DiseaseDisgenet>,
@@ -79,13 +93,13 @@ pub struct Disease {
     pub xrefs: HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiseasePathway {
     pub id: String,
     pub name: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiseasePhenotype {
     pub hpo_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -108,7 +122,7 @@ pub struct DiseasePhenotype {
     pub source: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiseaseGeneAssociation {
     pub gene: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -119,7 +133,7 @@ pub struct DiseaseGeneAssociation {
     pub opentargets_score: Option<DiseaseAssociationScoreSummary>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiseaseAssociationScoreSummary {
     pub overall_score: f64,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -130,14 +144,14 @@ pub struct DiseaseAssociationScoreSummary {
     pub somatic_mutation_score: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiseaseTargetScore {
     pub symbol: String,
     #[serde(flatten)]
     pub summary: DiseaseAssociationScoreSummary,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiseaseVariantAssociation {
     pub variant: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -148,7 +162,7 @@ pub struct DiseaseVariantAssociation {
     pub evidence_count: Option<u32>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiseaseModelAssociation {
     pub model: String,
     #[serde(skip)]
@@ -163,7 +177,7 @@ pub struct DiseaseModelAssociation {
     pub evidence_count: Option<u32>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiseasePrevalenceEvidence {
     pub estimate: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -172,7 +186,7 @@ pub struct DiseasePrevalenceEvidence {
     pub source: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiseaseSurvival {
     pub site_code: u16,
     pub site_label: String,
@@ -180,7 +194,7 @@ pub struct DiseaseSurvival {
     pub series: Vec<DiseaseSurvivalSeries>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiseaseSurvivalSeries {
     pub sex: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -191,7 +205,7 @@ pub struct DiseaseSurvivalSeries {
     pub points: Vec<DiseaseSurvivalPoint>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiseaseSurvivalPoint {
     pub year: u16,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -208,7 +222,56 @@ pub struct DiseaseSurvivalPoint {
     pub case_count: Option<u32>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiseaseEpidemiology {
+    pub source: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub estimates: Vec<DiseaseEpidemiologyEstimate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiseaseEpidemiologyEstimate {
+    pub indicator_code: String,
+    pub indicator: String,
+    pub measure: String,
+    pub unit: String,
+    pub region_code: String,
+    pub region: String,
+    pub year: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age_group: Option<String>,
+    pub value: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lower: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upper: Option<f64>,
+}
+
+/// Approval-derived therapy summary; not a curated guideline line of therapy.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiseaseTherapies {
+    pub basis: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub therapies: Vec<DiseaseTherapy>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiseaseTherapy {
+    /// `first-line` for approved indications, `second-line` for late-stage candidates.
+    pub line: String,
+    pub drug: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chembl_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drug_type: Option<String>,
+    pub max_clinical_stage: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fda_application: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fda_approval_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiseaseDisgenetAssociation {
     pub symbol: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -224,7 +287,7 @@ pub struct DiseaseDisgenetAssociation {
     pub evidence_level: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct DiseaseDisgenet {
     pub associations: Vec<DiseaseDisgenetAssociation>,
 }
@@ -267,6 +330,8 @@ const DISEASE_SECTION_SURVIVAL: &str = "survival";
 const DISEASE_SECTION_FUNDING: &str = "funding";
 const DISEASE_SECTION_CIVIC: &str = "civic";
 const DISEASE_SECTION_DISGENET: &str = "disgenet";
+const DISEASE_SECTION_EPIDEMIOLOGY: &str = "epidemiology";
+const DISEASE_SECTION_THERAPIES: &str = "therapies";
 const DISEASE_SECTION_ALL: &str = "all";
 
 pub const DISEASE_SECTION_NAMES: &[&str] = &[
@@ -280,6 +345,8 @@ pub const DISEASE_SECTION_NAMES: &[&str] = &[
     DISEASE_SECTION_FUNDING,
     DISEASE_SECTION_CIVIC,
     DISEASE_SECTION_DISGENET,
+    DISEASE_SECTION_EPIDEMIOLOGY,
+    DISEASE_SECTION_THERAPIES,
     DISEASE_SECTION_ALL,
 ];
 
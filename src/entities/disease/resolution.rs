@@ -361,6 +361,21 @@ pub(super) fn resolver_queries(name_or_id: &str) -> Vec<String> {
     queries
 }
 
+/// Appends [`resolver_queries`] for each NCIt-expanded name of the query.
+pub(super) fn with_expanded_queries(mut queries: Vec<String>, expanded: &[String]) -> Vec<String> {
+    for name in expanded {
+        for candidate in resolver_queries(name) {
+            if !queries
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(&candidate))
+            {
+                queries.push(candidate);
+            }
+        }
+    }
+    queries
+}
+
 struct DiseaseSearchCandidate {
     hit: MyDiseaseHit,
     first_seen_query_idx: usize,
@@ -430,7 +445,8 @@ pub(super) async fn resolve_disease_hit_by_name_direct(
     client: &MyDiseaseClient,
     name_or_id: &str,
 ) -> Result<Option<MyDiseaseHit>, BioMcpError> {
-    let queries = resolver_queries(name_or_id);
+    let expanded = crate::entities::normalize::expand_disease_name(name_or_id).await;
+    let queries = with_expanded_queries(resolver_queries(name_or_id), &expanded);
     if queries.is_empty() {
         return Ok(None);
     }
@@ -196,3 +196,24 @@ fn resolver_queries_adds_carcinoma_fallback_for_cancer_terms() {
     assert!(queries.iter().any(|q| q == "breast cancer"));
     assert!(queries.iter().any(|q| q == "breast carcinoma"));
 }
+
+#[test]
+fn with_expanded_queries_appends_expanded_name_variants_without_duplicates() {
+    let queries = with_expanded_queries(
+        resolver_queries("NSCLC"),
+        &[
+            "Lung Non-Small Cell Carcinoma".to_string(),
+            "Non-Small Cell Lung Cancer".to_string(),
+            "nsclc".to_string(),
+        ],
+    );
+    assert_eq!(
+        queries,
+        vec![
+            "NSCLC",
+            "Lung Non-Small Cell Carcinoma",
+            "Non-Small Cell Lung Cancer",
+            "non-small cell lung carcinoma",
+        ]
+    );
+}
@@ -3,7 +3,7 @@
 use super::*;
 
 use super::associations::normalize_hpo_id;
-use super::resolution::{rerank_disease_search_hits, resolver_queries};
+use super::resolution::{rerank_disease_search_hits, resolver_queries, with_expanded_queries};
 
 pub(super) const MAX_DISEASE_SEARCH_LIMIT: usize = 50;
 
@@ -125,9 +125,15 @@ pub async fn search_page(
         .map(str::trim)
         .is_some_and(|s| s.eq_ignore_ascii_case("doid"));
 
+    let expanded = crate::entities::normalize::expand_disease_name(query).await;
+    let queries = with_expanded_queries(resolver_queries(query), &expanded);
+    // An abbreviation scores poorly against full labels, so rank by the NCIt
+    // preferred name when the query was expanded.
+    let rank_query = expanded.first().map(String::as_str).unwrap_or(query);
+
     let mut merged_total = 0usize;
     let mut query_hits = Vec::new();
-    for (query_idx, resolved_query) in resolver_queries(query).into_iter().enumerate() {
+    for (query_idx, resolved_query) in queries.into_iter().enumerate() {
         let resp = client
             .query(
                 &resolved_query,
@@ -152,7 +158,7 @@ pub async fn search_page(
         query_hits.push((query_idx, hits));
     }
 
-    let ranked_hits = rerank_disease_search_hits(query, query_hits);
+    let ranked_hits = rerank_disease_search_hits(rank_query, query_hits);
     let total = Some(merged_total.max(ranked_hits.len()));
     let results = ranked_hits
         .into_iter()
@@ -84,6 +84,10 @@ pub(super) fn test_disease(id: &str, name: &str) -> Disease {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         funding: None,
         funding_note: None,
         civic: None,
@@ -0,0 +1,404 @@
+//! Gene-disease evidence dossier.
+//!
+//! One report per gene-disease pair, assembled from the OpenTargets
+//! association, CIViC clinical evidence, drugs that target the gene, recruiting
+//! trials, and the literature. Each part also contributes points to a 0-100
+//! score. The score is a triage heuristic for deciding where to look first; it
+//! is not a clinical validity classification such as ClinGen's.
+
+use serde::Serialize;
+
+use crate::entities::article::{
+    ArticleRankingOptions, ArticleSearchFilters, ArticleSearchResult, ArticleSort,
+    ArticleSourceFilter,
+};
+use crate::entities::trial::{TrialSearchFilters, TrialSearchResult, TrialSource};
+use crate::error::BioMcpError;
+use crate::sources::civic::{CivicClient, CivicContext};
+use crate::sources::opentargets::{OpenTargetsClient, OpenTargetsGeneDiseaseEvidence};
+
+const DOSSIER_CIVIC_LIMIT: usize = 25;
+const DOSSIER_TRIAL_LIMIT: usize = 5;
+const DOSSIER_ARTICLE_LIMIT: usize = 5;
+const ACTIVE_TRIAL_STATUSES: &str =
+    "RECRUITING,ACTIVE_NOT_RECRUITING,ENROLLING_BY_INVITATION,NOT_YET_RECRUITING";
+
+const ASSOCIATION_MAX_POINTS: u32 = 40;
+const CIVIC_MAX_POINTS: u32 = 25;
+const DRUG_MAX_POINTS: u32 = 20;
+const TRIAL_MAX_POINTS: u32 = 10;
+const ARTICLE_MAX_POINTS: u32 = 5;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvidenceDossier {
+    pub gene: String,
+    pub disease: String,
+    pub score: DossierScore,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub association: Option<DossierAssociation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub civic: Option<CivicContext>,
+    pub drugs: Vec<DossierDrug>,
+    pub trials: Vec<TrialSearchResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_total: Option<usize>,
+    pub articles: Vec<ArticleSearchResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub article_total: Option<usize>,
+    /// Sources that failed; their score components count as zero.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DossierScore {
+    /// Sum of the component points, out of 100.
+    pub total: u32,
+    /// `strong`, `moderate`, `limited`, or `none`.
+    pub label: String,
+    pub components: Vec<DossierScoreComponent>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DossierScoreComponent {
+    pub name: String,
+    pub points: u32,
+    pub max: u32,
+    /// What the points were awarded for.
+    pub basis: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DossierAssociation {
+    pub disease_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disease_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overall_score: Option<f64>,
+    pub datatype_scores: Vec<DossierDatatypeScore>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DossierDatatypeScore {
+    pub datatype: String,
+    pub score: f64,
+}
+
+/// A drug whose mechanism of action targets the gene, with its furthest stage
+/// in the disease.
+#[derive(Debug, Clone, Serialize)]
+pub struct DossierDrug {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chembl_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drug_type: Option<String>,
+    /// `Approved`, `Phase 3`, and so on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<String>,
+}
+
+fn stage_label(stage: &str) -> String {
+    match stage.trim().to_ascii_uppercase().as_str() {
+        "APPROVAL" | "APPROVED" => "Approved".to_string(),
+        "PHASE_4" => "Phase 4".to_string(),
+        "PHASE_3" => "Phase 3".to_string(),
+        "PHASE_2_3" => "Phase 2/3".to_string(),
+        "PHASE_2" => "Phase 2".to_string(),
+        "PHASE_1_2" => "Phase 1/2".to_string(),
+        "PHASE_1" => "Phase 1".to_string(),
+        "EARLY_PHASE_1" => "Early phase 1".to_string(),
+        other => other.replace('_', " ").to_lowercase(),
+    }
+}
+
+fn split_evidence(
+    evidence: OpenTargetsGeneDiseaseEvidence,
+) -> (DossierAssociation, Vec<DossierDrug>) {
+    let drugs = evidence
+        .drugs
+        .into_iter()
+        .map(|drug| DossierDrug {
+            name: drug.name,
+            chembl_id: drug.chembl_id,
+            drug_type: drug.drug_type,
+            stage: drug.max_clinical_stage.as_deref().map(stage_label),
+        })
+        .collect();
+    let association = DossierAssociation {
+        disease_id: evidence.disease_id,
+        disease_name: evidence.disease_name,
+        overall_score: evidence.overall_score,
+        datatype_scores: evidence
+            .datatype_scores
+            .into_iter()
+            .map(|(datatype, score)| DossierDatatypeScore { datatype, score })
+            .collect(),
+    };
+    (association, drugs)
+}
+
+fn component(name: &str, points: u32, max: u32, basis: String) -> DossierScoreComponent {
+    DossierScoreComponent {
+        name: name.to_string(),
+        points: points.min(max),
+        max,
+        basis,
+    }
+}
+
+fn association_component(association: Option<&DossierAssociation>) -> DossierScoreComponent {
+    let score = association.and_then(|association| association.overall_score);
+    match score {
+        Some(score) => component(
+            "OpenTargets association",
+            (score.clamp(0.0, 1.0) * f64::from(ASSOCIATION_MAX_POINTS)).round() as u32,
+            ASSOCIATION_MAX_POINTS,
+            format!("overall score {score:.3}"),
+        ),
+        None => component(
+            "OpenTargets association",
+            0,
+            ASSOCIATION_MAX_POINTS,
+            "no association".to_string(),
+        ),
+    }
+}
+
+/// Level A items count 5 points, level B 3, and the rest 1.
+fn civic_component(civic: Option<&CivicContext>) -> DossierScoreComponent {
+    let items = civic
+        .map(|civic| civic.evidence_items.as_slice())
+        .unwrap_or_default();
+    let count = |level: &str| {
+        items
+            .iter()
+            .filter(|item| item.evidence_level.trim().eq_ignore_ascii_case(level))
+            .count() as u32
+    };
+    let (a, b) = (count("A"), count("B"));
+    let other = items.len() as u32 - a - b;
+    let basis = if items.is_empty() {
+        "no evidence items".to_string()
+    } else {
+        format!("{a} level A, {b} level B, {other} other evidence items")
+    };
+    component(
+        "CIViC evidence",
+        a * 5 + b * 3 + other,
+        CIVIC_MAX_POINTS,
+        basis,
+    )
+}
+
+/// Scored on the furthest-developed drug only.
+fn drug_component(drugs: &[DossierDrug]) -> DossierScoreComponent {
+    let stage_points = |drug: &DossierDrug| match drug.stage.as_deref() {
+        Some("Approved" | "Phase 4") => 20,
+        Some("Phase 3" | "Phase 2/3") => 10,
+        _ => 5,
+    };
+    let best = drugs.iter().max_by_key(|drug| stage_points(drug));
+    match best {
+        Some(drug) => component(
+            "Targeted drugs",
+            stage_points(drug),
+            DRUG_MAX_POINTS,
+            format!(
+                "{} ({})",
+                drug.name,
+                drug.stage.as_deref().unwrap_or("stage unknown")
+            ),
+        ),
+        None => component(
+            "Targeted drugs",
+            0,
+            DRUG_MAX_POINTS,
+            "no drugs targeting the gene".to_string(),
+        ),
+    }
+}
+
+fn trial_component(trials: usize) -> DossierScoreComponent {
+    component(
+        "Active trials",
+        (trials as u32).saturating_mul(2),
+        TRIAL_MAX_POINTS,
+        format!("{trials} recruiting or active trials"),
+    )
+}
+
+fn article_component(articles: usize) -> DossierScoreComponent {
+    let points = match articles {
+        100.. => 5,
+        20.. => 3,
+        1.. => 1,
+        0 => 0,
+    };
+    component(
+        "Literature",
+        points,
+        ARTICLE_MAX_POINTS,
+        format!("{articles} articles"),
+    )
+}
+
+fn score_label(total: u32) -> &'static str {
+    match total {
+        70.. => "strong",
+        40.. => "moderate",
+        1.. => "limited",
+        0 => "none",
+    }
+}
+
+fn score_dossier(
+    association: Option<&DossierAssociation>,
+    civic: Option<&CivicContext>,
+    drugs: &[DossierDrug],
+    trials: usize,
+    articles: usize,
+) -> DossierScore {
+    let components = vec![
+        association_component(association),
+        civic_component(civic),
+        drug_component(drugs),
+        trial_component(trials),
+        article_component(articles),
+    ];
+    let total = components.iter().map(|component| component.points).sum();
+    DossierScore {
+        total,
+        label: score_label(total).to_string(),
+        components,
+    }
+}
+
+/// Builds the evidence dossier for `gene` in `disease`.
+pub async fn gene_disease_dossier(
+    gene: &str,
+    disease: &str,
+) -> Result<EvidenceDossier, BioMcpError> {
+    let gene = gene.trim().to_ascii_uppercase();
+    let disease = disease.trim().to_string();
+    if disease.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "--disease is required. Example: biomcp dossier --gene BRAF --disease melanoma".into(),
+        ));
+    }
+    if !crate::sources::is_valid_gene_symbol(&gene) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Invalid gene symbol: {gene}. Example: biomcp dossier --gene BRAF --disease melanoma"
+        )));
+    }
+
+    let trial_filters = TrialSearchFilters {
+        condition: Some(disease.clone()),
+        biomarker: Some(gene.clone()),
+        status: Some(ACTIVE_TRIAL_STATUSES.to_string()),
+        source: TrialSource::ClinicalTrialsGov,
+        ..Default::default()
+    };
+    let article_filters = ArticleSearchFilters {
+        gene: Some(gene.clone()),
+        gene_anchored: true,
+        disease: Some(disease.clone()),
+        drug: None,
+        author: None,
+        keyword: None,
+        date_from: None,
+        date_to: None,
+        article_type: None,
+        journal: None,
+        open_access: false,
+        no_preprints: true,
+        exclude_retracted: true,
+        min_quartile: None,
+        max_per_source: None,
+        sort: ArticleSort::Relevance,
+        ranking: ArticleRankingOptions::default(),
+    };
+
+    let (evidence, civic, trials, articles) = tokio::join!(
+        async {
+            OpenTargetsClient::new()?
+                .gene_disease_evidence(&gene, &disease)
+                .await
+        },
+        async {
+            CivicClient::new()?
+                .by_molecular_profile_and_disease(&gene, &disease, DOSSIER_CIVIC_LIMIT)
+                .await
+        },
+        crate::entities::trial::search_page(&trial_filters, DOSSIER_TRIAL_LIMIT, 0, None),
+        crate::entities::article::search_page(
+            &article_filters,
+            DOSSIER_ARTICLE_LIMIT,
+            0,
+            ArticleSourceFilter::All,
+        ),
+    );
+
+    let mut notes = Vec::new();
+    let (association, drugs) = match evidence {
+        Ok(Some(evidence)) => {
+            let (association, drugs) = split_evidence(evidence);
+            (Some(association), drugs)
+        }
+        Ok(None) => {
+            notes.push(format!(
+                "OpenTargets could not resolve {gene} or '{disease}'; association and drugs are empty."
+            ));
+            (None, Vec::new())
+        }
+        Err(err) => {
+            notes.push(format!("OpenTargets unavailable: {err}"));
+            (None, Vec::new())
+        }
+    };
+    let civic = match civic {
+        Ok(civic) => Some(civic),
+        Err(err) => {
+            notes.push(format!("CIViC unavailable: {err}"));
+            None
+        }
+    };
+    let (trials, trial_total) = match trials {
+        Ok(page) => (page.results, page.total),
+        Err(err) => {
+            notes.push(format!("ClinicalTrials.gov unavailable: {err}"));
+            (Vec::new(), None)
+        }
+    };
+    let (articles, article_total) = match articles {
+        Ok(page) => (page.results, page.total),
+        Err(err) => {
+            notes.push(format!("Article search unavailable: {err}"));
+            (Vec::new(), None)
+        }
+    };
+
+    let score = score_dossier(
+        association.as_ref(),
+        civic.as_ref(),
+        &drugs,
+        trial_total.unwrap_or(trials.len()),
+        article_total.unwrap_or(articles.len()),
+    );
+    Ok(EvidenceDossier {
+        gene,
+        disease,
+        score,
+        association,
+        civic,
+        drugs,
+        trials,
+        trial_total,
+        articles,
+        article_total,
+        notes,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,130 @@
+use super::*;
+use crate::sources::civic::CivicEvidenceItem;
+use crate::sources::opentargets::OpenTargetsDiseaseDrug;
+
+fn evidence_item(id: i64, level: &str) -> CivicEvidenceItem {
+    CivicEvidenceItem {
+        id,
+        name: format!("EID{id}"),
+        molecular_profile: "BRAF V600E".into(),
+        evidence_type: "Predictive".into(),
+        evidence_level: level.into(),
+        significance: "Sensitivity/Response".into(),
+        disease: Some("Melanoma".into()),
+        therapies: vec!["Vemurafenib".into()],
+        status: "accepted".into(),
+        citation: None,
+        source_type: None,
+        publication_year: None,
+    }
+}
+
+fn civic(levels: &[&str]) -> CivicContext {
+    CivicContext {
+        evidence_total_count: levels.len(),
+        assertion_total_count: 0,
+        evidence_items: levels
+            .iter()
+            .enumerate()
+            .map(|(idx, level)| evidence_item(idx as i64 + 1, level))
+            .collect(),
+        assertions: Vec::new(),
+    }
+}
+
+fn drug(name: &str, stage: &str) -> DossierDrug {
+    DossierDrug {
+        name: name.into(),
+        chembl_id: None,
+        drug_type: None,
+        stage: Some(stage.into()),
+    }
+}
+
+#[test]
+fn split_evidence_labels_stages_and_keeps_datatype_order() {
+    let (association, drugs) = split_evidence(OpenTargetsGeneDiseaseEvidence {
+        disease_id: "EFO_0000756".into(),
+        disease_name: Some("melanoma".into()),
+        overall_score: Some(0.81),
+        datatype_scores: vec![("somatic_mutation".into(), 0.9), ("literature".into(), 0.7)],
+        drugs: vec![
+            OpenTargetsDiseaseDrug {
+                chembl_id: Some("CHEMBL1229517".into()),
+                name: "VEMURAFENIB".into(),
+                drug_type: Some("Small molecule".into()),
+                max_clinical_stage: Some("APPROVAL".into()),
+            },
+            OpenTargetsDiseaseDrug {
+                chembl_id: None,
+                name: "TOVORAFENIB".into(),
+                drug_type: None,
+                max_clinical_stage: Some("PHASE_2".into()),
+            },
+        ],
+    });
+    assert_eq!(association.disease_id, "EFO_0000756");
+    assert_eq!(association.datatype_scores[0].datatype, "somatic_mutation");
+    assert_eq!(drugs[0].stage.as_deref(), Some("Approved"));
+    assert_eq!(drugs[1].stage.as_deref(), Some("Phase 2"));
+}
+
+#[test]
+fn score_dossier_caps_components_and_labels_total() {
+    let association = DossierAssociation {
+        disease_id: "EFO_0000756".into(),
+        disease_name: None,
+        overall_score: Some(0.81),
+        datatype_scores: Vec::new(),
+    };
+    let civic = civic(&["A", "A", "A", "B", "B", "C", "D", "a"]);
+    let drugs = vec![
+        drug("TOVORAFENIB", "Phase 2"),
+        drug("VEMURAFENIB", "Approved"),
+    ];
+
+    let score = score_dossier(Some(&association), Some(&civic), &drugs, 40, 2500);
+    let points = score
+        .components
+        .iter()
+        .map(|component| component.points)
+        .collect::<Vec<_>>();
+    assert_eq!(points, vec![32, 25, 20, 10, 5]);
+    assert_eq!(
+        score.components[1].basis,
+        "4 level A, 2 level B, 2 other evidence items"
+    );
+    assert_eq!(score.components[2].basis, "VEMURAFENIB (Approved)");
+    assert_eq!(score.total, 92);
+    assert_eq!(score.label, "strong");
+}
+
+#[test]
+fn score_dossier_counts_missing_sources_as_zero() {
+    let score = score_dossier(None, None, &[], 0, 0);
+    assert_eq!(score.total, 0);
+    assert_eq!(score.label, "none");
+    assert!(
+        score
+            .components
+            .iter()
+            .all(|component| component.points == 0)
+    );
+
+    let score = score_dossier(None, Some(&civic(&["C"])), &[drug("X", "Phase 3")], 1, 20);
+    assert_eq!(score.total, 1 + 10 + 2 + 3);
+    assert_eq!(score.label, "limited");
+}
+
+#[tokio::test]
+async fn gene_disease_dossier_rejects_bad_input() {
+    let err = gene_disease_dossier("BRAF", "  ")
+        .await
+        .expect_err("empty disease should fail");
+    assert!(err.to_string().contains("--disease is required"));
+
+    let err = gene_disease_dossier("BRAF;rm", "melanoma")
+        .await
+        .expect_err("invalid gene should fail");
+    assert!(err.to_string().contains("Invalid gene symbol"));
+}
@@ -2,9 +2,12 @@
 
 use tracing::warn;
 
+use crate::entities::section_errors;
+use crate::entities::trial::{TrialSearchFilters, TrialSearchResult};
 use crate::error::BioMcpError;
 use crate::sources::civic::{CivicClient, CivicContext};
 use crate::sources::ema::{EmaClient, EmaSyncMode};
+use crate::sources::medlineplus_herbs::MedlinePlusHerbsClient;
 use crate::sources::openfda::OpenFdaClient;
 use crate::sources::who_pq::{WhoPqClient, WhoPqSyncMode};
 use crate::transform;
@@ -14,16 +17,19 @@ use super::label::{
     extract_label_warnings_text,
 };
 use super::metadata::{
-    apply_openfda_metadata, fetch_shortage_entries, fetch_top_adverse_events,
+    apply_openfda_metadata, fetch_shortage_entries, fetch_top_adverse_events, is_active_shortage,
     map_drugsfda_approvals,
 };
+use super::ncit::resolve_via_ncit;
+use super::patents::fetch_patents;
 use super::search::search_page;
 use super::targets::{enrich_indications, enrich_targets};
 use super::{
     DRUG_SECTION_ALL, DRUG_SECTION_APPROVALS, DRUG_SECTION_CIVIC, DRUG_SECTION_INDICATIONS,
-    DRUG_SECTION_INTERACTIONS, DRUG_SECTION_LABEL, DRUG_SECTION_NAMES, DRUG_SECTION_REGULATORY,
-    DRUG_SECTION_SAFETY, DRUG_SECTION_SHORTAGE, DRUG_SECTION_TARGETS, Drug, DrugRegion,
-    DrugSearchFilters, OPTIONAL_SAFETY_TIMEOUT, build_ema_identity, build_who_identity,
+    DRUG_SECTION_INTERACTIONS, DRUG_SECTION_LABEL, DRUG_SECTION_NAMES, DRUG_SECTION_PATENTS,
+    DRUG_SECTION_REGULATORY, DRUG_SECTION_SAFETY, DRUG_SECTION_SHORTAGE, DRUG_SECTION_TARGETS,
+    Drug, DrugRegion, DrugSearchFilters, OPTIONAL_SAFETY_TIMEOUT, SHORTAGE_TRIAL_LIMIT,
+    SHORTAGE_TRIAL_STATUSES, SupplementInteraction, build_ema_identity, build_who_identity,
     direct_drug_lookup,
 };
 
@@ -38,6 +44,7 @@ struct DrugSections {
     include_interactions: bool,
     include_civic: bool,
     include_approvals: bool,
+    include_patents: bool,
     requested_all: bool,
     requested_safety: bool,
     requested_shortage: bool,
@@ -75,6 +82,7 @@ fn parse_sections(sections: &[String]) -> Result<DrugSections, BioMcpError> {
             DRUG_SECTION_INTERACTIONS => out.include_interactions = true,
             DRUG_SECTION_CIVIC => out.include_civic = true,
             DRUG_SECTION_APPROVALS => out.include_approvals = true,
+            DRUG_SECTION_PATENTS => out.include_patents = true,
             DRUG_SECTION_ALL => {
                 include_all = true;
                 out.requested_all = true;
@@ -122,10 +130,11 @@ async fn fetch_civic_therapy_context(name: &str) -> Option<CivicContext> {
         client.by_therapy(name, 10).await
     };
 
-    match tokio::time::timeout(OPTIONAL_SAFETY_TIMEOUT, civic_fut).await {
+    match tokio::time::timeout(section_errors::budget(OPTIONAL_SAFETY_TIMEOUT), civic_fut).await {
         Ok(Ok(context)) => Some(context),
         Ok(Err(err)) => {
             warn!(drug = %name, "CIViC unavailable for drug section: {err}");
+            section_errors::record(DRUG_SECTION_CIVIC, "CIViC", &err);
             None
         }
         Err(_) => {
@@ -134,6 +143,43 @@ async fn fetch_civic_therapy_context(name: &str) -> Option<CivicContext> {
                 timeout_secs = OPTIONAL_SAFETY_TIMEOUT.as_secs(),
                 "CIViC drug section timed out"
             );
+            section_errors::record_timeout(DRUG_SECTION_CIVIC, "CIViC", OPTIONAL_SAFETY_TIMEOUT);
+            None
+        }
+    }
+}
+
+async fn fetch_shortage_trials(name: &str) -> Option<Vec<TrialSearchResult>> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let filters = TrialSearchFilters {
+        intervention: Some(name.to_string()),
+        status: Some(SHORTAGE_TRIAL_STATUSES.to_string()),
+        ..Default::default()
+    };
+    let trials_fut = crate::entities::trial::search(&filters, SHORTAGE_TRIAL_LIMIT, 0);
+
+    match tokio::time::timeout(section_errors::budget(OPTIONAL_SAFETY_TIMEOUT), trials_fut).await {
+        Ok(Ok((results, _))) => Some(results),
+        Ok(Err(err)) => {
+            warn!(drug = %name, "ClinicalTrials.gov unavailable for shortage trials: {err}");
+            section_errors::record(DRUG_SECTION_SHORTAGE, "ClinicalTrials.gov", &err);
+            None
+        }
+        Err(_) => {
+            warn!(
+                drug = %name,
+                timeout_secs = OPTIONAL_SAFETY_TIMEOUT.as_secs(),
+                "Shortage trial lookup timed out"
+            );
+            section_errors::record_timeout(
+                DRUG_SECTION_SHORTAGE,
+                "ClinicalTrials.gov",
+                OPTIONAL_SAFETY_TIMEOUT,
+            );
             None
         }
     }
@@ -162,13 +208,19 @@ async fn add_approvals_section(drug: &mut Drug) {
         client.drugsfda_search(&query, 8, 0).await
     };
 
-    match tokio::time::timeout(OPTIONAL_SAFETY_TIMEOUT, approvals_fut).await {
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_SAFETY_TIMEOUT),
+        approvals_fut,
+    )
+    .await
+    {
         Ok(Ok(resp)) => {
             let approvals = resp.map(map_drugsfda_approvals).unwrap_or_default();
             drug.approvals = Some(approvals);
         }
         Ok(Err(err)) => {
             warn!(drug = %drug.name, "OpenFDA Drugs@FDA unavailable: {err}");
+            section_errors::record(DRUG_SECTION_APPROVALS, "Drugs@FDA", &err);
             drug.approvals = Some(Vec::new());
         }
         Err(_) => {
@@ -177,6 +229,11 @@ async fn add_approvals_section(drug: &mut Drug) {
                 timeout_secs = OPTIONAL_SAFETY_TIMEOUT.as_secs(),
                 "OpenFDA Drugs@FDA section timed out"
             );
+            section_errors::record_timeout(
+                DRUG_SECTION_APPROVALS,
+                "Drugs@FDA",
+                OPTIONAL_SAFETY_TIMEOUT,
+            );
             drug.approvals = Some(Vec::new());
         }
     }
@@ -210,8 +267,31 @@ async fn resolve_drug_base(
         suggestion: format!("Try searching: biomcp search drug -q \"{name}\""),
     };
 
-    let mut lookup_name = name.to_string();
-    let mut resp = direct_drug_lookup(name).await?;
+    // Abbreviations such as 5-FU are looked up under their NCIt names first;
+    // other names only fall back to NCIt when MyChem.info misses.
+    let abbreviated = crate::entities::normalize::applies(name);
+    let early = if abbreviated {
+        resolve_via_ncit(name).await
+    } else {
+        None
+    };
+    let (mut lookup_name, mut resp, mut ncit) = match early {
+        Some(resolution) => (
+            resolution.lookup_name,
+            resolution.response,
+            Some(resolution.mapping),
+        ),
+        None => (name.to_string(), direct_drug_lookup(name).await?, None),
+    };
+
+    if resp.hits.is_empty()
+        && !abbreviated
+        && let Some(resolution) = resolve_via_ncit(name).await
+    {
+        lookup_name = resolution.lookup_name;
+        resp = resolution.response;
+        ncit = Some(resolution.mapping);
+    }
 
     if resp.hits.is_empty() {
         let fallback_filters = DrugSearchFilters {
@@ -249,6 +329,7 @@ async fn resolve_drug_base(
 
     let selected = transform::drug::select_hits_for_name(&resp.hits, &lookup_name);
     let mut drug = transform::drug::merge_mychem_hits(&selected, &lookup_name);
+    drug.ncit = ncit;
 
     let mut label_response_opt: Option<serde_json::Value> = None;
     if fetch_label_response {
@@ -280,6 +361,47 @@ async fn resolve_drug_base(
     })
 }
 
+/// Names the supplement monographs may use for this drug: generic name plus brands.
+fn supplement_match_names(drug: &Drug) -> Vec<String> {
+    let mut names = vec![drug.name.clone()];
+    for brand in &drug.brand_names {
+        if !names.iter().any(|name| name.eq_ignore_ascii_case(brand)) {
+            names.push(brand.clone());
+        }
+    }
+    names
+}
+
+async fn fetch_supplement_interactions(drug: &Drug) -> Vec<SupplementInteraction> {
+    let names = supplement_match_names(drug);
+    let fetch = async {
+        let client = MedlinePlusHerbsClient::new()?;
+        Ok::<_, BioMcpError>(client.interactions_for(&names).await)
+    };
+
+    match tokio::time::timeout(section_errors::budget(OPTIONAL_SAFETY_TIMEOUT), fetch).await {
+        Ok(Ok(rows)) => rows,
+        Ok(Err(err)) => {
+            warn!(drug = %drug.name, "MedlinePlus supplement interactions unavailable: {err}");
+            section_errors::record(DRUG_SECTION_INTERACTIONS, "MedlinePlus", &err);
+            Vec::new()
+        }
+        Err(_) => {
+            warn!(
+                drug = %drug.name,
+                timeout_secs = OPTIONAL_SAFETY_TIMEOUT.as_secs(),
+                "MedlinePlus supplement interactions timed out"
+            );
+            section_errors::record_timeout(
+                DRUG_SECTION_INTERACTIONS,
+                "MedlinePlus",
+                OPTIONAL_SAFETY_TIMEOUT,
+            );
+            Vec::new()
+        }
+    }
+}
+
 async fn populate_common_sections(
     drug: &mut Drug,
     label_response: Option<&serde_json::Value>,
@@ -300,9 +422,11 @@ async fn populate_common_sections(
 
     if section_flags.include_interactions {
         drug.interaction_text = label_response.and_then(extract_interaction_text_from_label);
+        drug.supplement_interactions = fetch_supplement_interactions(drug).await;
     } else {
         drug.interactions.clear();
         drug.interaction_text = None;
+        drug.supplement_interactions.clear();
     }
 
     if section_flags.include_targets {
@@ -355,9 +479,16 @@ async fn populate_us_regional_sections(
     section_flags: &DrugSections,
 ) -> Result<(), BioMcpError> {
     if section_flags.include_shortage {
-        drug.shortage = Some(fetch_shortage_entries(&drug.name).await?);
+        let shortage = fetch_shortage_entries(&drug.name).await?;
+        drug.shortage_trials = if shortage.iter().any(is_active_shortage) {
+            fetch_shortage_trials(&drug.name).await
+        } else {
+            None
+        };
+        drug.shortage = Some(shortage);
     } else {
         drug.shortage = None;
+        drug.shortage_trials = None;
     }
 
     if section_flags.include_regulatory || section_flags.include_approvals {
@@ -501,6 +632,13 @@ pub async fn get_with_region(
     )
     .await;
 
+    // Requires PATENTSVIEW_API_KEY, so it stays opt-in rather than part of `all`.
+    resolved.drug.patents = if section_flags.include_patents {
+        Some(fetch_patents(&resolved.drug).await?)
+    } else {
+        None
+    };
+
     if region.includes_us() && (!section_only || section_flags.include_safety) {
         populate_top_adverse_event_preview(&mut resolved.drug).await;
     } else {
@@ -517,6 +655,7 @@ pub async fn get_with_region(
         .await?;
     } else {
         resolved.drug.shortage = None;
+        resolved.drug.shortage_trials = None;
         resolved.drug.approvals = None;
         resolved.drug.us_safety_warnings = None;
     }
@@ -535,7 +674,13 @@ pub async fn get_with_region(
         resolved.drug.who_prequalification = None;
     }
 
-    Ok(resolved.drug)
+    // Field filters round-trip through JSON, which drops the serde-skipped link hints.
+    let faers_query = resolved.drug.faers_query.take();
+    let label_set_id = resolved.drug.label_set_id.take();
+    let mut drug = transform::fields::apply("drug", resolved.drug)?;
+    drug.faers_query = faers_query;
+    drug.label_set_id = label_set_id;
+    Ok(drug)
 }
 
 pub async fn get(name: &str, sections: &[String]) -> Result<Drug, BioMcpError> {
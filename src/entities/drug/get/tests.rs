@@ -14,6 +14,7 @@ fn parse_sections_supports_all_and_rejects_unknown() {
     assert!(flags.include_interactions);
     assert!(flags.include_civic);
     assert!(!flags.include_approvals);
+    assert!(!flags.include_patents);
 
     let err = parse_sections(&["bad".to_string()]).unwrap_err();
     assert!(matches!(err, BioMcpError::InvalidArgument(_)));
@@ -25,6 +26,13 @@ fn parse_sections_all_with_explicit_label_keeps_label() {
     assert!(flags.include_label);
 }
 
+#[test]
+fn parse_sections_accepts_patents() {
+    let flags = parse_sections(&["patents".to_string()]).unwrap();
+    assert!(flags.include_patents);
+    assert!(!flags.include_targets);
+}
+
 #[test]
 fn parse_sections_default_card_includes_targets_enrichment() {
     let flags = parse_sections(&[]).unwrap();
@@ -88,3 +96,18 @@ fn validate_raw_usage_allows_raw_with_label_section() {
     let flags = parse_sections(&["label".to_string()]).unwrap();
     validate_raw_usage(&flags, true).expect("raw label should be valid");
 }
+
+#[test]
+fn supplement_match_names_adds_unique_brand_names() {
+    let mut drug: Drug =
+        serde_json::from_value(serde_json::json!({"name": "tacrolimus"})).expect("drug");
+    drug.brand_names = vec![
+        "Prograf".to_string(),
+        "TACROLIMUS".to_string(),
+        "Envarsus XR".to_string(),
+    ];
+    assert_eq!(
+        supplement_match_names(&drug),
+        vec!["tacrolimus", "Prograf", "Envarsus XR"]
+    );
+}
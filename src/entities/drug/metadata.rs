@@ -263,6 +263,15 @@ pub(super) fn map_drugsfda_approvals(resp: OpenFdaResponse<DrugsFdaResult>) -> V
     out
 }
 
+/// OpenFDA marks ongoing shortages `Current`; `Resolved` and discontinuation notices are not active.
+pub(super) fn is_active_shortage(entry: &DrugShortageEntry) -> bool {
+    entry
+        .status
+        .as_deref()
+        .map(str::trim)
+        .is_some_and(|status| status.to_ascii_lowercase().starts_with("current"))
+}
+
 pub(super) async fn fetch_shortage_entries(
     drug_name: &str,
 ) -> Result<Vec<DrugShortageEntry>, BioMcpError> {
@@ -59,3 +59,22 @@ fn extract_top_adverse_events_ranks_by_frequency() {
     let out = extract_top_adverse_events(&resp);
     assert_eq!(out, vec!["Fatigue", "Rash", "Nausea"]);
 }
+
+#[test]
+fn is_active_shortage_matches_current_status_only() {
+    let entry = |status: Option<&str>| DrugShortageEntry {
+        status: status.map(str::to_string),
+        availability: None,
+        company_name: None,
+        generic_name: None,
+        related_info: None,
+        update_date: None,
+        initial_posting_date: None,
+    };
+
+    assert!(is_active_shortage(&entry(Some("Current"))));
+    assert!(is_active_shortage(&entry(Some(" Currently in Shortage "))));
+    assert!(!is_active_shortage(&entry(Some("Resolved"))));
+    assert!(!is_active_shortage(&entry(Some("To Be Discontinued"))));
+    assert!(!is_active_shortage(&entry(None)));
+}
@@ -3,6 +3,8 @@
 mod get;
 mod label;
 mod metadata;
+mod ncit;
+mod patents;
 mod query;
 mod search;
 mod targets;
@@ -18,16 +20,19 @@ pub use self::search::{
 
 use std::time::Duration;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::entities::SearchPage;
+use crate::entities::trial::TrialSearchResult;
 use crate::error::BioMcpError;
 use crate::sources::civic::CivicContext;
 use crate::sources::ema::EmaDrugIdentity;
+use crate::sources::medlineplus_herbs::SupplementInteraction;
 use crate::sources::mychem::{MYCHEM_FIELDS_GET, MyChemClient, MyChemQueryResponse};
 use crate::sources::who_pq::WhoPqIdentity;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Drug {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -68,6 +73,9 @@ pub struct Drug {
     pub interactions: Vec<DrugInteraction>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interaction_text: Option<String>,
+    /// Supplement-drug interactions from MedlinePlus monographs; lower evidence confidence.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub supplement_interactions: Vec<SupplementInteraction>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub pharm_classes: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -84,6 +92,9 @@ pub struct Drug {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shortage: Option<Vec<DrugShortageEntry>>,
+    /// Open trials listing this drug as an intervention, loaded only during an active US shortage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shortage_trials: Option<Vec<TrialSearchResult>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub approvals: Option<Vec<DrugApproval>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -98,16 +109,35 @@ pub struct Drug {
     pub who_prequalification: Option<Vec<WhoPrequalificationEntry>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub civic: Option<CivicContext>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patents: Option<DrugPatents>,
+    /// Set when the requested name only resolved through an NCI Thesaurus synonym.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ncit: Option<DrugNcitMapping>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// NCI Thesaurus concept that mapped a code name or other synonym to the
+/// preferred drug name used for MyChem.info and openFDA lookups.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DrugNcitMapping {
+    /// NCIt concept code, e.g. `C95746`.
+    pub code: String,
+    pub preferred_name: String,
+    /// Name as the user typed it, e.g. `ABT-199`.
+    pub queried_name: String,
+    /// Investigational code names NCIt lists for the concept.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub code_names: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DrugInteraction {
     pub drug: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DrugLabelIndication {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -116,7 +146,7 @@ pub struct DrugLabelIndication {
     pub pivotal_trial: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DrugLabel {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub indication_summary: Vec<DrugLabelIndication>,
@@ -128,7 +158,7 @@ pub struct DrugLabel {
     pub dosage: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DrugShortageEntry {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
@@ -146,7 +176,7 @@ pub struct DrugShortageEntry {
     pub initial_posting_date: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DrugApproval {
     pub application_number: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -161,7 +191,7 @@ pub struct DrugApproval {
     pub submissions: Vec<DrugApprovalSubmission>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DrugApprovalProduct {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub brand_name: Option<String>,
@@ -175,7 +205,7 @@ pub struct DrugApprovalProduct {
     pub active_ingredients: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DrugApprovalSubmission {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub submission_type: Option<String>,
@@ -187,6 +217,44 @@ pub struct DrugApprovalSubmission {
     pub status_date: Option<String>,
 }
 
+/// Granted US patents that name the drug, grouped into approximate families.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DrugPatents {
+    /// Drug and brand names searched as phrases in patent titles and abstracts.
+    pub query_terms: Vec<String>,
+    pub total_hits: usize,
+    pub families: Vec<DrugPatentFamily>,
+    pub basis: String,
+}
+
+/// Patents sharing an assignee and earliest application date, as continuations
+/// and divisionals of one filing do.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DrugPatentFamily {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliest_application_date: Option<String>,
+    /// Earliest application date plus the 20-year utility term, before any
+    /// term adjustment or extension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_expiry: Option<String>,
+    pub expired: bool,
+    pub patents: Vec<DrugPatent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DrugPatent {
+    pub patent_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grant_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patent_type: Option<String>,
+    pub url: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DrugSearchResult {
     pub name: String,
@@ -200,7 +268,7 @@ pub struct DrugSearchResult {
     pub target: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct WhoPrequalificationEntry {
     pub who_reference_number: String,
     pub inn: String,
@@ -269,7 +337,7 @@ pub struct EmaDrugSearchResult {
     pub status: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EmaRegulatoryRow {
     pub medicine_name: String,
     pub active_substance: String,
@@ -281,14 +349,14 @@ pub struct EmaRegulatoryRow {
     pub recent_activity: Vec<EmaRegulatoryActivity>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EmaRegulatoryActivity {
     pub first_published_date: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_updated_date: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct EmaSafetyInfo {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub dhpcs: Vec<EmaDhpcEntry>,
@@ -298,7 +366,7 @@ pub struct EmaSafetyInfo {
     pub psusas: Vec<EmaPsusaEntry>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EmaDhpcEntry {
     pub medicine_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -311,7 +379,7 @@ pub struct EmaDhpcEntry {
     pub last_updated_date: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EmaReferralEntry {
     pub referral_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -330,7 +398,7 @@ pub struct EmaReferralEntry {
     pub prac_recommendation: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EmaPsusaEntry {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub related_medicines: Option<String>,
@@ -346,7 +414,7 @@ pub struct EmaPsusaEntry {
     pub last_updated_date: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EmaShortageEntry {
     pub medicine_affected: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -404,6 +472,7 @@ const DRUG_SECTION_INDICATIONS: &str = "indications";
 const DRUG_SECTION_INTERACTIONS: &str = "interactions";
 const DRUG_SECTION_CIVIC: &str = "civic";
 const DRUG_SECTION_APPROVALS: &str = "approvals";
+const DRUG_SECTION_PATENTS: &str = "patents";
 const DRUG_SECTION_ALL: &str = "all";
 
 pub const DRUG_SECTION_NAMES: &[&str] = &[
@@ -416,11 +485,18 @@ pub const DRUG_SECTION_NAMES: &[&str] = &[
     DRUG_SECTION_INTERACTIONS,
     DRUG_SECTION_CIVIC,
     DRUG_SECTION_APPROVALS,
+    DRUG_SECTION_PATENTS,
     DRUG_SECTION_ALL,
 ];
 
 const OPTIONAL_SAFETY_TIMEOUT: Duration = Duration::from_secs(8);
 
+/// Maximum open trials cross-linked from an active shortage.
+const SHORTAGE_TRIAL_LIMIT: usize = 5;
+
+/// Trial statuses that mean the drug is in use right now.
+const SHORTAGE_TRIAL_STATUSES: &str = "RECRUITING,ACTIVE_NOT_RECRUITING,ENROLLING_BY_INVITATION";
+
 fn build_ema_identity(requested_name: &str, drug: &Drug) -> EmaDrugIdentity {
     EmaDrugIdentity::with_aliases(requested_name, Some(&drug.name), &drug.brand_names)
 }
@@ -0,0 +1,105 @@
+//! NCI Thesaurus synonym expansion for drug names MyChem.info does not index.
+//!
+//! Oncology code names such as `ABT-199` are NCIt (and NCI Drug Dictionary)
+//! synonyms of the preferred agent name. When a lookup misses, the NCIt
+//! concept supplies the names worth retrying.
+
+use tracing::warn;
+
+use crate::sources::mychem::MyChemQueryResponse;
+use crate::sources::nci_evs::{NciEvsClient, NcitConcept};
+
+use super::{DrugNcitMapping, direct_drug_lookup};
+
+/// MyChem.info retries per NCIt concept; the preferred name almost always hits.
+const NCIT_RETRY_NAME_LIMIT: usize = 3;
+
+/// A drug lookup that succeeded under an NCIt-provided name.
+pub(super) struct NcitResolution {
+    pub(super) lookup_name: String,
+    pub(super) response: MyChemQueryResponse,
+    pub(super) mapping: DrugNcitMapping,
+}
+
+/// Preferred name first, then FDA/NCI preferred terms and brand names. Code
+/// names are skipped: they are what MyChem.info already failed to match.
+fn retry_names(concept: &NcitConcept, queried: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    let candidates = std::iter::once(concept.name.trim())
+        .chain(concept.synonyms_of_type("PT"))
+        .chain(concept.synonyms_of_type("BR"));
+    for name in candidates {
+        if name.is_empty()
+            || name.eq_ignore_ascii_case(queried)
+            || out.iter().any(|seen| seen.eq_ignore_ascii_case(name))
+        {
+            continue;
+        }
+        out.push(name.to_string());
+        if out.len() == NCIT_RETRY_NAME_LIMIT {
+            break;
+        }
+    }
+    out
+}
+
+fn mapping(concept: &NcitConcept, queried: &str) -> DrugNcitMapping {
+    DrugNcitMapping {
+        code: concept.code.clone(),
+        preferred_name: concept.name.trim().to_string(),
+        queried_name: queried.to_string(),
+        code_names: concept
+            .synonyms_of_type("CN")
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+    }
+}
+
+/// First NCIt drug concept matching `name`. NCI EVS failures are logged and
+/// treated as no match so the caller reports its own not-found error; under
+/// `--no-normalize` NCI EVS is not consulted at all.
+async fn drug_concept(name: &str) -> Option<NcitConcept> {
+    if !crate::entities::normalize::enabled() {
+        return None;
+    }
+    let result = match NciEvsClient::new() {
+        Ok(client) => client.drug_concepts(name).await,
+        Err(err) => Err(err),
+    };
+    match result {
+        Ok(concepts) => concepts.into_iter().next(),
+        Err(err) => {
+            warn!(drug = %name, "NCI EVS unavailable for synonym expansion: {err}");
+            None
+        }
+    }
+}
+
+/// Retries MyChem.info under the NCIt preferred name and synonyms of `name`.
+pub(super) async fn resolve_via_ncit(name: &str) -> Option<NcitResolution> {
+    let concept = drug_concept(name).await?;
+    for candidate in retry_names(&concept, name) {
+        if let Ok(response) = direct_drug_lookup(&candidate).await
+            && !response.hits.is_empty()
+        {
+            return Some(NcitResolution {
+                lookup_name: candidate,
+                response,
+                mapping: mapping(&concept, name),
+            });
+        }
+    }
+    None
+}
+
+/// NCIt preferred name for a free-text drug query, when it differs from the query.
+pub(super) async fn preferred_name(query: &str) -> Option<String> {
+    let concept = drug_concept(query).await?;
+    let preferred = concept.name.trim();
+    (!preferred.is_empty() && !preferred.eq_ignore_ascii_case(query.trim()))
+        .then(|| preferred.to_string())
+}
+
+#[cfg(test)]
+mod tests;
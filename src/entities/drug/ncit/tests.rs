@@ -0,0 +1,47 @@
+//! Tests for NCI Thesaurus drug synonym expansion.
+
+use super::*;
+
+fn venetoclax() -> NcitConcept {
+    serde_json::from_value(serde_json::json!({
+        "code": "C95746",
+        "name": "Venetoclax",
+        "synonyms": [
+            {"name": "VENETOCLAX", "termType": "PT", "source": "FDA"},
+            {"name": "ABT-199", "termType": "CN", "source": "NCI"},
+            {"name": "GDC-0199", "termType": "CN", "source": "NCI"},
+            {"name": "RG7601", "termType": "CN", "source": "NCI"},
+            {"name": "Venclexta", "termType": "BR", "source": "NCI"},
+            {"name": "Venclyxto", "termType": "BR", "source": "NCI"}
+        ]
+    }))
+    .expect("ncit concept json")
+}
+
+#[test]
+fn retry_names_prefer_the_preferred_name_and_skip_code_names() {
+    assert_eq!(
+        retry_names(&venetoclax(), "ABT-199"),
+        vec!["Venetoclax", "Venclexta", "Venclyxto"]
+    );
+    assert_eq!(
+        retry_names(&venetoclax(), "venetoclax"),
+        vec!["Venclexta", "Venclyxto"]
+    );
+}
+
+#[test]
+fn mapping_records_code_preferred_name_and_code_names() {
+    let mapping = mapping(&venetoclax(), "ABT-199");
+    assert_eq!(mapping.code, "C95746");
+    assert_eq!(mapping.preferred_name, "Venetoclax");
+    assert_eq!(mapping.queried_name, "ABT-199");
+    assert_eq!(mapping.code_names, vec!["ABT-199", "GDC-0199", "RG7601"]);
+}
+
+#[tokio::test]
+async fn no_normalize_skips_nci_evs_lookups() {
+    let preferred =
+        crate::entities::normalize::with_normalization(false, preferred_name("5-FU")).await;
+    assert!(preferred.is_none());
+}
@@ -0,0 +1,137 @@
+//! Drug patent landscape from PatentsView, grouped into approximate families.
+
+use std::collections::HashMap;
+
+use crate::error::BioMcpError;
+use crate::sources::patentsview::{PatentsViewClient, PatentsViewPatent};
+
+use super::{Drug, DrugPatent, DrugPatentFamily, DrugPatents};
+
+/// Patents fetched per query; grouping happens client-side.
+const PATENT_FETCH_LIMIT: usize = 200;
+const PATENT_FAMILY_LIMIT: usize = 15;
+const PATENT_QUERY_TERM_LIMIT: usize = 4;
+const UTILITY_TERM_YEARS: i32 = 20;
+
+const PATENTS_BASIS: &str = "Informational only, not legal or freedom-to-operate advice. Granted US patents whose title or abstract names the drug (PatentsView); families are approximated by shared assignee and earliest application date, and expiry is estimated as earliest application date + 20 years without term adjustments, extensions, or regulatory exclusivities.";
+
+/// Drug name plus brand names, deduplicated case-insensitively.
+fn query_terms(drug: &Drug) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for term in std::iter::once(&drug.name).chain(drug.brand_names.iter()) {
+        let term = term.trim();
+        if term.is_empty() || terms.iter().any(|seen| seen.eq_ignore_ascii_case(term)) {
+            continue;
+        }
+        terms.push(term.to_string());
+        if terms.len() == PATENT_QUERY_TERM_LIMIT {
+            break;
+        }
+    }
+    terms
+}
+
+fn is_iso_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    bytes.len() == 10
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && bytes
+            .iter()
+            .enumerate()
+            .all(|(i, b)| i == 4 || i == 7 || b.is_ascii_digit())
+}
+
+fn estimated_expiry(
+    patent_type: Option<&str>,
+    earliest_application: Option<&str>,
+) -> Option<String> {
+    if !patent_type.is_none_or(|kind| kind.eq_ignore_ascii_case("utility")) {
+        return None;
+    }
+    let date = earliest_application?.trim();
+    if !is_iso_date(date) {
+        return None;
+    }
+    let year: i32 = date[..4].parse().ok()?;
+    Some(format!("{}{}", year + UTILITY_TERM_YEARS, &date[4..]))
+}
+
+fn clean(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn map_patent(row: &PatentsViewPatent) -> DrugPatent {
+    let patent_id = row.patent_id.trim().to_string();
+    DrugPatent {
+        url: format!("https://patents.google.com/patent/US{patent_id}"),
+        patent_id,
+        title: clean(row.patent_title.clone()),
+        grant_date: clean(row.patent_date.clone()),
+        patent_type: clean(row.patent_type.clone()),
+    }
+}
+
+/// Groups patents by assignee and earliest application date, latest-expiring families first.
+pub(super) fn group_families(rows: &[PatentsViewPatent], today: &str) -> Vec<DrugPatentFamily> {
+    let mut families: Vec<DrugPatentFamily> = Vec::new();
+    let mut index: HashMap<(String, String), usize> = HashMap::new();
+
+    for row in rows {
+        if row.patent_id.trim().is_empty() {
+            continue;
+        }
+        let assignee = row
+            .assignees
+            .iter()
+            .find_map(|assignee| clean(assignee.assignee_organization.clone()));
+        let earliest = clean(row.patent_earliest_application_date.clone());
+        let key = (
+            assignee.as_deref().unwrap_or_default().to_ascii_lowercase(),
+            earliest.clone().unwrap_or_else(|| row.patent_id.clone()),
+        );
+        let patent = map_patent(row);
+        if let Some(&slot) = index.get(&key) {
+            families[slot].patents.push(patent);
+            continue;
+        }
+        let estimated_expiry = estimated_expiry(row.patent_type.as_deref(), earliest.as_deref());
+        index.insert(key, families.len());
+        families.push(DrugPatentFamily {
+            assignee,
+            expired: estimated_expiry
+                .as_deref()
+                .is_some_and(|expiry| expiry < today),
+            earliest_application_date: earliest,
+            estimated_expiry,
+            patents: vec![patent],
+        });
+    }
+
+    families.sort_by(|a, b| {
+        b.estimated_expiry
+            .cmp(&a.estimated_expiry)
+            .then_with(|| b.patents.len().cmp(&a.patents.len()))
+    });
+    families.truncate(PATENT_FAMILY_LIMIT);
+    families
+}
+
+pub(super) async fn fetch_patents(drug: &Drug) -> Result<DrugPatents, BioMcpError> {
+    let query_terms = query_terms(drug);
+    let resp = PatentsViewClient::new()?
+        .search_by_terms(&query_terms, PATENT_FETCH_LIMIT)
+        .await?;
+    let today = time::OffsetDateTime::now_utc().date().to_string();
+    Ok(DrugPatents {
+        families: group_families(&resp.patents, &today),
+        total_hits: resp.total_hits,
+        query_terms,
+        basis: PATENTS_BASIS.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests;
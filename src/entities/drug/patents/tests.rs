@@ -0,0 +1,61 @@
+//! Tests for drug patent family grouping.
+
+use super::*;
+use crate::sources::patentsview::PatentsViewAssignee;
+
+fn patent(id: &str, kind: &str, earliest: &str, assignee: &str) -> PatentsViewPatent {
+    PatentsViewPatent {
+        patent_id: id.to_string(),
+        patent_title: Some(format!("Title {id}")),
+        patent_date: Some("2012-01-01".to_string()),
+        patent_type: Some(kind.to_string()),
+        patent_earliest_application_date: Some(earliest.to_string()),
+        assignees: vec![PatentsViewAssignee {
+            assignee_organization: Some(assignee.to_string()),
+        }],
+    }
+}
+
+#[test]
+fn estimated_expiry_adds_utility_term_only() {
+    assert_eq!(
+        estimated_expiry(Some("utility"), Some("2005-03-14")),
+        Some("2025-03-14".to_string())
+    );
+    assert_eq!(
+        estimated_expiry(None, Some("2010-12-01")),
+        Some("2030-12-01".to_string())
+    );
+    assert_eq!(estimated_expiry(Some("design"), Some("2010-12-01")), None);
+    assert_eq!(estimated_expiry(Some("utility"), Some("2010")), None);
+}
+
+#[test]
+fn group_families_merges_continuations_and_orders_by_expiry() {
+    let rows = vec![
+        patent("7544799", "utility", "1997-07-18", "Novartis AG"),
+        patent("8067421", "utility", "2004-04-01", "Novartis AG"),
+        patent("9000001", "utility", "2004-04-01", "NOVARTIS AG"),
+        patent("D700000", "design", "2012-05-05", "Acme Packaging"),
+    ];
+    let families = group_families(&rows, "2026-10-16");
+
+    assert_eq!(families.len(), 3);
+    assert_eq!(families[0].estimated_expiry.as_deref(), Some("2024-04-01"));
+    assert_eq!(families[0].patents.len(), 2);
+    assert!(families[0].expired);
+    assert_eq!(families[1].estimated_expiry.as_deref(), Some("2017-07-18"));
+    assert_eq!(families[2].estimated_expiry, None);
+    assert!(!families[2].expired);
+    assert_eq!(
+        families[2].patents[0].url,
+        "https://patents.google.com/patent/USD700000"
+    );
+}
+
+#[test]
+fn query_terms_dedupes_brand_names() {
+    let mut drug = crate::transform::drug::merge_mychem_hits(&[], "imatinib");
+    drug.brand_names = vec!["Gleevec".into(), "IMATINIB".into(), "Glivec".into()];
+    assert_eq!(query_terms(&drug), vec!["imatinib", "Gleevec", "Glivec"]);
+}
@@ -32,6 +32,53 @@ pub async fn search_page(
     filters: &DrugSearchFilters,
     limit: usize,
     offset: usize,
+) -> Result<SearchPage<DrugSearchResult>, BioMcpError> {
+    let query = filters
+        .query
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty());
+    // Abbreviations such as 5-FU seldom match MyChem.info labels; search under
+    // the NCI Thesaurus preferred name first and fall back to the name as typed.
+    if let Some(query) = query
+        && crate::entities::normalize::applies(query)
+    {
+        if let Some(preferred) = super::ncit::preferred_name(query).await {
+            let expanded = DrugSearchFilters {
+                query: Some(preferred),
+                ..filters.clone()
+            };
+            let page = search_mychem_page(&expanded, limit, offset).await?;
+            if !page.results.is_empty() {
+                return Ok(page);
+            }
+        }
+        return search_mychem_page(filters, limit, offset).await;
+    }
+
+    let page = search_mychem_page(filters, limit, offset).await?;
+    if !should_attempt_openfda_fallback(&page.results, offset, filters) {
+        return Ok(page);
+    }
+    // Code names such as ABT-199 miss MyChem.info and openFDA; retry once
+    // under the NCI Thesaurus preferred name.
+    let Some(query) = query else {
+        return Ok(page);
+    };
+    let Some(preferred) = super::ncit::preferred_name(query).await else {
+        return Ok(page);
+    };
+    let expanded = DrugSearchFilters {
+        query: Some(preferred),
+        ..filters.clone()
+    };
+    search_mychem_page(&expanded, limit, offset).await
+}
+
+async fn search_mychem_page(
+    filters: &DrugSearchFilters,
+    limit: usize,
+    offset: usize,
 ) -> Result<SearchPage<DrugSearchResult>, BioMcpError> {
     const MAX_SEARCH_LIMIT: usize = 50;
     if limit == 0 || limit > MAX_SEARCH_LIMIT {
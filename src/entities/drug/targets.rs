@@ -4,12 +4,13 @@ use std::collections::{HashMap, HashSet};
 
 use tracing::warn;
 
+use crate::entities::section_errors;
 use crate::sources::chembl::{ChemblClient, ChemblTarget};
 use crate::sources::civic::CivicContext;
 use crate::sources::opentargets::{OpenTargetsClient, OpenTargetsTarget};
 
-use super::Drug;
 use super::metadata::merge_unique_casefold;
+use super::{DRUG_SECTION_INDICATIONS, DRUG_SECTION_TARGETS, Drug};
 
 pub(super) async fn enrich_targets(drug: &mut Drug, civic_context: Option<&CivicContext>) {
     let mut chembl_rows = Vec::new();
@@ -37,9 +38,15 @@ pub(super) async fn enrich_targets(drug: &mut Drug, civic_context: Option<&Civic
                     merge_unique_casefold(&mut drug.mechanisms, mechanisms);
                     chembl_rows = rows;
                 }
-                Err(err) => warn!("ChEMBL unavailable for drug targets section: {err}"),
+                Err(err) => {
+                    warn!("ChEMBL unavailable for drug targets section: {err}");
+                    section_errors::record(DRUG_SECTION_TARGETS, "ChEMBL", &err);
+                }
             },
-            Err(err) => warn!("ChEMBL client init failed: {err}"),
+            Err(err) => {
+                warn!("ChEMBL client init failed: {err}");
+                section_errors::record(DRUG_SECTION_TARGETS, "ChEMBL", &err);
+            }
         }
 
         match OpenTargetsClient::new() {
@@ -53,9 +60,15 @@ pub(super) async fn enrich_targets(drug: &mut Drug, civic_context: Option<&Civic
                     merge_unique_casefold(&mut drug.targets, targets);
                     opentargets_targets = sections.targets;
                 }
-                Err(err) => warn!("OpenTargets unavailable for drug targets section: {err}"),
+                Err(err) => {
+                    warn!("OpenTargets unavailable for drug targets section: {err}");
+                    section_errors::record(DRUG_SECTION_TARGETS, "Open Targets", &err);
+                }
             },
-            Err(err) => warn!("OpenTargets client init failed: {err}"),
+            Err(err) => {
+                warn!("OpenTargets client init failed: {err}");
+                section_errors::record(DRUG_SECTION_TARGETS, "Open Targets", &err);
+            }
         }
     }
 
@@ -84,12 +97,14 @@ pub(super) async fn enrich_targets(drug: &mut Drug, civic_context: Option<&Civic
                 Ok(_) => {}
                 Err(err) => {
                     warn!("ChEMBL unavailable for drug target family summary: {err}");
+                    section_errors::record(DRUG_SECTION_TARGETS, "ChEMBL", &err);
                     drug.target_family = inferred_target_family.clone();
                     drug.target_family_name = inferred_target_family_name.clone();
                 }
             },
             Err(err) => {
                 warn!("ChEMBL client init failed: {err}");
+                section_errors::record(DRUG_SECTION_TARGETS, "ChEMBL", &err);
                 drug.target_family = inferred_target_family.clone();
                 drug.target_family_name = inferred_target_family_name.clone();
             }
@@ -318,9 +333,15 @@ pub(super) async fn enrich_indications(drug: &mut Drug) {
                     .collect::<Vec<_>>();
                 merge_unique_casefold(&mut drug.indications, indications);
             }
-            Err(err) => warn!("OpenTargets unavailable for drug indications section: {err}"),
+            Err(err) => {
+                warn!("OpenTargets unavailable for drug indications section: {err}");
+                section_errors::record(DRUG_SECTION_INDICATIONS, "Open Targets", &err);
+            }
         },
-        Err(err) => warn!("OpenTargets client init failed: {err}"),
+        Err(err) => {
+            warn!("OpenTargets client init failed: {err}");
+            section_errors::record(DRUG_SECTION_INDICATIONS, "Open Targets", &err);
+        }
     }
 
     drug.indications.truncate(12);
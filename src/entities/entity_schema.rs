@@ -0,0 +1,323 @@
+//! JSON Schema for the entity payloads `get <entity> <id> --json` prints.
+//!
+//! Schemas are derived from the entity structs with `schemars` using the
+//! serialize contract, so fields that are skipped when empty are optional and
+//! the schema cannot drift from what serde actually writes. Each schema also
+//! describes the trailing `_meta` object, pinning `_meta.schema_version` to
+//! [`ENTITY_SCHEMA_VERSION`].
+
+use schemars::JsonSchema;
+use schemars::generate::SchemaSettings;
+use serde_json::{Map, Value};
+
+use crate::error::BioMcpError;
+use crate::render::json::{ENTITY_SCHEMA_VERSION, EntityMeta};
+
+/// Entities with a published schema, in `biomcp list` order.
+pub(crate) const SCHEMA_ENTITIES: &[&str] = &[
+    "gene",
+    "variant",
+    "article",
+    "trial",
+    "drug",
+    "disease",
+    "pgx",
+    "pathway",
+    "protein",
+    "adverse-event",
+    "device-clearance",
+];
+
+fn serialize_schema<T: JsonSchema>() -> Result<Value, BioMcpError> {
+    let schema = SchemaSettings::draft2020_12()
+        .for_serialize()
+        .into_generator()
+        .into_root_schema_for::<T>();
+    Ok(serde_json::to_value(schema)?)
+}
+
+fn payload_schema(entity: &str) -> Option<Result<Value, BioMcpError>> {
+    use crate::entities::{
+        adverse_event, article, disease, drug, gene, pathway, pgx, protein, trial, variant,
+    };
+
+    Some(match entity {
+        "gene" => serialize_schema::<gene::Gene>(),
+        "variant" => serialize_schema::<variant::Variant>(),
+        "article" => serialize_schema::<article::Article>(),
+        "trial" => serialize_schema::<trial::Trial>(),
+        "drug" => serialize_schema::<drug::Drug>(),
+        "disease" => serialize_schema::<disease::Disease>(),
+        "pgx" => serialize_schema::<pgx::Pgx>(),
+        "pathway" => serialize_schema::<pathway::Pathway>(),
+        "protein" => serialize_schema::<protein::Protein>(),
+        // FAERS and MAUDE reports share one command, tagged as `type`/`data`.
+        "adverse-event" => serialize_schema::<adverse_event::AdverseEventReport>(),
+        "device-clearance" => serialize_schema::<adverse_event::DeviceClearance>(),
+        _ => return None,
+    })
+}
+
+/// Adds `_meta` as a required property of an object schema.
+fn attach_meta(schema: &mut Map<String, Value>, meta: &Value) {
+    if let Some(properties) = schema
+        .entry("properties")
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+    {
+        properties.insert("_meta".to_string(), meta.clone());
+    }
+    if let Some(required) = schema
+        .entry("required")
+        .or_insert_with(|| Value::Array(Vec::new()))
+        .as_array_mut()
+    {
+        required.push(Value::String("_meta".to_string()));
+    }
+}
+
+fn with_meta(entity: &str, mut schema: Value) -> Result<Value, BioMcpError> {
+    let mut meta = serialize_schema::<EntityMeta>()?;
+    let meta_defs = meta.as_object_mut().and_then(|meta| {
+        meta.remove("$schema");
+        meta.remove("$defs")
+    });
+    if let Some(version) = meta
+        .pointer_mut("/properties/schema_version")
+        .and_then(Value::as_object_mut)
+    {
+        version.insert("const".to_string(), Value::from(ENTITY_SCHEMA_VERSION));
+    }
+
+    let Some(root) = schema.as_object_mut() else {
+        return Ok(schema);
+    };
+    root.insert("x-biomcp-entity".to_string(), Value::from(entity));
+    root.insert(
+        "x-biomcp-schema-version".to_string(),
+        Value::from(ENTITY_SCHEMA_VERSION),
+    );
+    if let Some(Value::Object(meta_defs)) = meta_defs
+        && let Some(defs) = root
+            .entry("$defs")
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+    {
+        for (name, definition) in meta_defs {
+            defs.entry(name).or_insert(definition);
+        }
+    }
+
+    // Tagged enums have no top-level properties; each variant object carries
+    // the flattened `_meta` instead.
+    let has_properties = root.get("properties").is_some_and(Value::is_object);
+    let branch_keyword = ["oneOf", "anyOf"]
+        .into_iter()
+        .find(|keyword| root.get(*keyword).is_some_and(Value::is_array));
+    match branch_keyword {
+        Some(keyword) if !has_properties => {
+            if let Some(branches) = root.get_mut(keyword).and_then(Value::as_array_mut) {
+                for branch in branches.iter_mut().filter_map(Value::as_object_mut) {
+                    attach_meta(branch, &meta);
+                }
+            }
+        }
+        _ => attach_meta(root, &meta),
+    }
+    Ok(schema)
+}
+
+/// JSON Schema for `biomcp get <entity> <id> --json`; accepts `adverse_event`
+/// for `adverse-event`.
+pub fn entity_schema(entity: &str) -> Result<Value, BioMcpError> {
+    let key = entity.trim().to_ascii_lowercase().replace('_', "-");
+    let schema = payload_schema(&key).ok_or_else(|| {
+        BioMcpError::InvalidArgument(format!(
+            "Unknown entity \"{}\" for schema. Use one of: {}",
+            entity.trim(),
+            SCHEMA_ENTITIES.join(", ")
+        ))
+    })??;
+    with_meta(&key, schema)
+}
+
+/// Reasons `value` fails `schema`, covering the keywords `schemars` emits
+/// (`$ref`, `type`, `const`, `enum`, `oneOf`/`anyOf`/`allOf`, `properties`,
+/// `required`, `additionalProperties`, `items`). Empty means it validates.
+#[cfg(test)]
+pub(crate) fn schema_violations(schema: &Value, value: &Value) -> Vec<String> {
+    let mut violations = Vec::new();
+    check(schema, schema, value, "$", &mut violations);
+    violations
+}
+
+#[cfg(test)]
+fn check(root: &Value, schema: &Value, value: &Value, path: &str, out: &mut Vec<String>) {
+    let schema = match schema {
+        Value::Bool(true) => return,
+        Value::Bool(false) => {
+            out.push(format!("{path}: no value is allowed here"));
+            return;
+        }
+        Value::Object(schema) => schema,
+        _ => return,
+    };
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        match reference
+            .strip_prefix("#/$defs/")
+            .and_then(|name| root["$defs"].get(name))
+        {
+            Some(target) => check(root, target, value, path, out),
+            None => out.push(format!("{path}: unresolved {reference}")),
+        }
+    }
+    if let Some(types) = schema.get("type") {
+        let allowed = match types {
+            Value::String(kind) => vec![kind.as_str()],
+            Value::Array(kinds) => kinds.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.iter().any(|kind| has_type(value, kind)) {
+            out.push(format!("{path}: expected {}", allowed.join(" or ")));
+            return;
+        }
+    }
+    if let Some(expected) = schema.get("const")
+        && value != expected
+    {
+        out.push(format!("{path}: expected {expected}"));
+    }
+    if let Some(options) = schema.get("enum").and_then(Value::as_array)
+        && !options.contains(value)
+    {
+        out.push(format!("{path}: {value} is not one of {options:?}"));
+    }
+    for keyword in ["oneOf", "anyOf"] {
+        if let Some(branches) = schema.get(keyword).and_then(Value::as_array)
+            && !branches.iter().any(|branch| {
+                let mut nested = Vec::new();
+                check(root, branch, value, path, &mut nested);
+                nested.is_empty()
+            })
+        {
+            out.push(format!("{path}: matches no {keyword} branch"));
+        }
+    }
+    if let Some(branches) = schema.get("allOf").and_then(Value::as_array) {
+        for branch in branches {
+            check(root, branch, value, path, out);
+        }
+    }
+    match value {
+        Value::Object(object) => {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for key in schema
+                .get("required")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+            {
+                if !object.contains_key(key) {
+                    out.push(format!("{path}: missing required {key}"));
+                }
+            }
+            for (key, item) in object {
+                let child = format!("{path}.{key}");
+                match properties.and_then(|properties| properties.get(key)) {
+                    Some(property) => check(root, property, item, &child, out),
+                    None => {
+                        if let Some(extra) = schema.get("additionalProperties") {
+                            check(root, extra, item, &child, out);
+                        }
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    check(root, item_schema, item, &format!("{path}[{index}]"), out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+fn has_type(value: &Value, kind: &str) -> bool {
+    match kind {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_schema_entity_has_a_payload_schema_with_meta() {
+        for entity in SCHEMA_ENTITIES {
+            let schema = entity_schema(entity).unwrap_or_else(|err| panic!("{entity}: {err}"));
+            assert_eq!(schema["x-biomcp-entity"], *entity);
+            assert_eq!(schema["x-biomcp-schema-version"], ENTITY_SCHEMA_VERSION);
+            assert!(
+                schema.to_string().contains("\"_meta\""),
+                "{entity} schema should describe _meta"
+            );
+        }
+    }
+
+    #[test]
+    fn gene_schema_requires_identity_fields_and_pins_meta_version() {
+        let schema = entity_schema(" Gene ").expect("gene schema");
+        let required = schema["required"].as_array().expect("required");
+        for key in ["symbol", "name", "entrez_id", "_meta"] {
+            assert!(required.iter().any(|value| value == key), "{key} required");
+        }
+        assert!(
+            !required.iter().any(|value| value == "genomic_coordinates"),
+            "fields skipped when empty must stay optional"
+        );
+        assert_eq!(
+            schema["properties"]["_meta"]["properties"]["schema_version"]["const"],
+            ENTITY_SCHEMA_VERSION
+        );
+    }
+
+    #[test]
+    fn schema_violations_reports_type_and_required_mismatches() {
+        let schema = entity_schema("adverse_event").expect("adverse-event schema");
+        let valid = serde_json::json!({
+            "type": "device",
+            "data": {"report_id": "MDR-1", "device": "pump"},
+            "_meta": {
+                "schema_version": ENTITY_SCHEMA_VERSION,
+                "evidence_urls": [],
+                "next_commands": [],
+                "section_sources": [],
+                "section_errors": []
+            }
+        });
+        assert_eq!(schema_violations(&schema, &valid), Vec::<String>::new());
+
+        let mut invalid = valid.clone();
+        invalid["data"]["device"] = serde_json::json!(7);
+        invalid["_meta"]["schema_version"] = serde_json::json!(0);
+        assert!(!schema_violations(&schema, &invalid).is_empty());
+
+        let err = entity_schema("structure").expect_err("no schema");
+        assert!(
+            err.to_string()
+                .contains("Unknown entity \"structure\" for schema")
+        );
+    }
+}
@@ -2,13 +2,16 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use futures::future::try_join_all;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
 
 use crate::entities::SearchPage;
+use crate::entities::section_errors;
 use crate::error::BioMcpError;
 use crate::sources::civic::{CivicClient, CivicContext};
 use crate::sources::clingen::{ClinGenClient, GeneClinGen};
+use crate::sources::depmap::{DepMapClient, GeneDependency};
 use crate::sources::dgidb::{
     DgidbClient, GeneDruggability, GeneSafetyLiability, GeneTractabilityModality,
 };
@@ -20,16 +23,43 @@ use crate::sources::gnomad::{
 use crate::sources::gtex::{GeneExpression, GtexClient};
 use crate::sources::hpa::{GeneHpa, HpaClient};
 use crate::sources::mygene::MyGeneClient;
+use crate::sources::myvariant::{
+    MYVARIANT_CLINVAR_REVIEW_STATUS_FACET, MYVARIANT_CLINVAR_SIGNIFICANCE_FACET, MyVariantClient,
+    MyVariantClinVarRecentHit, MyVariantFacetTerm,
+};
 use crate::sources::nih_reporter::{NihReporterClient, NihReporterFundingSection};
-use crate::sources::opentargets::{OpenTargetsClient, OpenTargetsTargetDruggabilityContext};
+use crate::sources::opentargets::{
+    GeneTractability, OpenTargetsClient, OpenTargetsTargetDruggabilityContext,
+};
+use crate::sources::plugin::{self, PluginEntity, PluginLookup, PluginSection};
 use crate::sources::quickgo::QuickGoClient;
 use crate::sources::reactome::ReactomeClient;
 use crate::sources::string::StringClient;
 use crate::sources::uniprot::UniProtClient;
 use crate::transform;
 
+mod clinvar_variants;
+mod deg;
+mod key_papers;
+mod offline;
+mod orthologs;
+mod snapshot;
+mod species;
+mod variant_map;
+
+pub use self::clinvar_variants::{
+    GeneClinVarVariant, GeneClinVarVariants, clinvar_variants, clinvar_variants_all,
+};
+pub(crate) use self::deg::entrez_ids;
+pub use self::deg::{DegAnnotation, DegRow, annotate_deg};
+pub use self::key_papers::{GeneKeyPaper, GeneKeyPaperKind};
+pub use self::orthologs::GeneOrtholog;
+pub use self::snapshot::{GeneDiff, diff, latest_snapshot_json, stored_snapshots};
+pub use self::species::GeneSpecies;
+pub use self::variant_map::{GeneVariantMap, variant_map};
+
 /// Gene entity from MyGene.info plus optional enrichment sections.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Gene {
     pub symbol: String,
     pub name: String,
@@ -44,6 +74,9 @@ pub struct Gene {
     pub uniprot_id: Option<String>,
     pub summary: Option<String>,
     pub gene_type: Option<String>,
+    /// `--species` value for non-human genes; absent for human.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub species: Option<String>,
     pub aliases: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub clinical_diseases: Vec<String>,
@@ -70,25 +103,38 @@ pub struct Gene {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub druggability: Option<GeneDruggability>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub tractability: Option<GeneTractability>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub clingen: Option<GeneClinGen>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constraint: Option<GeneConstraint>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disgenet: Option<GeneDisgenet>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub clinvar_summary: Option<GeneClinVarSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub funding: Option<NihReporterFundingSection>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub funding_note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_papers: Option<Vec<GeneKeyPaper>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependency: Option<GeneDependency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orthologs: Option<Vec<GeneOrtholog>>,
+    /// Sections contributed by source plugins, in request order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plugin_sections: Vec<PluginSection>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GenePathway {
     pub source: String,
     pub id: String,
     pub name: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GeneProtein {
     pub accession: String,
     pub name: String,
@@ -102,14 +148,14 @@ pub struct GeneProtein {
     pub alternative_names: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GeneProteinIsoform {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub length: Option<u32>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GeneGoTerm {
     pub id: String,
     pub name: String,
@@ -119,14 +165,14 @@ pub struct GeneGoTerm {
     pub evidence: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GeneInteraction {
     pub partner: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub score: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GeneConstraint {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pli: Option<f64>,
@@ -143,7 +189,7 @@ pub struct GeneConstraint {
     pub reference_genome: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GeneDisgenetAssociation {
     pub disease_name: String,
     pub disease_cui: String,
@@ -158,11 +204,48 @@ pub struct GeneDisgenetAssociation {
     pub evidence_level: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct GeneDisgenet {
     pub associations: Vec<GeneDisgenetAssociation>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GeneClinVarCount {
+    pub label: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GeneClinVarSubmission {
+    pub variant_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clinvar_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hgvs_coding: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub significance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_evaluated: Option<String>,
+}
+
+/// Gene-level ClinVar landscape aggregated from MyVariant.info facets.
+///
+/// Counts are per variant, so a variant with several ClinVar records may
+/// appear in more than one significance or review-status row.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+pub struct GeneClinVarSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_variants: Option<u64>,
+    #[serde(default)]
+    pub significance: Vec<GeneClinVarCount>,
+    #[serde(default)]
+    pub review_status: Vec<GeneClinVarCount>,
+    #[serde(default)]
+    pub recent: Vec<GeneClinVarSubmission>,
+}
+
 /// Search result (lighter than full Gene)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GeneSearchResult {
@@ -172,6 +255,8 @@ pub struct GeneSearchResult {
     pub genomic_coordinates: Option<String>,
     pub uniprot_id: Option<String>,
     pub omim_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub species: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -182,6 +267,7 @@ pub struct GeneSearchFilters {
     pub region: Option<String>,
     pub pathway: Option<String>,
     pub go_term: Option<String>,
+    pub species: GeneSpecies,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -196,10 +282,15 @@ enum GeneIncludeType {
     Expression,
     Hpa,
     Druggability,
+    Tractability,
     ClinGen,
     Constraint,
     Disgenet,
+    ClinVarSummary,
     Funding,
+    KeyPapers,
+    Dependency,
+    Orthologs,
 }
 
 const GENE_SECTION_PATHWAYS: &str = "pathways";
@@ -212,10 +303,15 @@ const GENE_SECTION_CIVIC: &str = "civic";
 const GENE_SECTION_EXPRESSION: &str = "expression";
 const GENE_SECTION_HPA: &str = "hpa";
 const GENE_SECTION_DRUGGABILITY: &str = "druggability";
+const GENE_SECTION_TRACTABILITY: &str = "tractability";
 const GENE_SECTION_CLINGEN: &str = "clingen";
 const GENE_SECTION_CONSTRAINT: &str = "constraint";
 const GENE_SECTION_DISGENET: &str = "disgenet";
+const GENE_SECTION_CLINVAR_SUMMARY: &str = "clinvar-summary";
 const GENE_SECTION_FUNDING: &str = "funding";
+const GENE_SECTION_KEY_PAPERS: &str = "key-papers";
+const GENE_SECTION_DEPENDENCY: &str = "dependency";
+const GENE_SECTION_ORTHOLOGS: &str = "orthologs";
 const GENE_SECTION_ALL: &str = "all";
 
 pub const GENE_SECTION_NAMES: &[&str] = &[
@@ -229,10 +325,15 @@ pub const GENE_SECTION_NAMES: &[&str] = &[
     GENE_SECTION_EXPRESSION,
     GENE_SECTION_HPA,
     GENE_SECTION_DRUGGABILITY,
+    GENE_SECTION_TRACTABILITY,
     GENE_SECTION_CLINGEN,
     GENE_SECTION_CONSTRAINT,
     GENE_SECTION_DISGENET,
+    GENE_SECTION_CLINVAR_SUMMARY,
     GENE_SECTION_FUNDING,
+    GENE_SECTION_KEY_PAPERS,
+    GENE_SECTION_DEPENDENCY,
+    GENE_SECTION_ORTHOLOGS,
     GENE_SECTION_ALL,
 ];
 
@@ -249,14 +350,27 @@ impl GeneIncludeType {
             GENE_SECTION_EXPRESSION => Some(Self::Expression),
             GENE_SECTION_HPA => Some(Self::Hpa),
             GENE_SECTION_DRUGGABILITY | "drugs" => Some(Self::Druggability),
+            GENE_SECTION_TRACTABILITY | "safety" => Some(Self::Tractability),
             GENE_SECTION_CLINGEN => Some(Self::ClinGen),
             GENE_SECTION_CONSTRAINT => Some(Self::Constraint),
             GENE_SECTION_DISGENET => Some(Self::Disgenet),
+            GENE_SECTION_CLINVAR_SUMMARY | "clinvar_summary" | "clinvar" => {
+                Some(Self::ClinVarSummary)
+            }
             GENE_SECTION_FUNDING => Some(Self::Funding),
+            GENE_SECTION_KEY_PAPERS | "key_papers" => Some(Self::KeyPapers),
+            GENE_SECTION_DEPENDENCY | "depmap" => Some(Self::Dependency),
+            GENE_SECTION_ORTHOLOGS | "orthologues" | "orthology" => Some(Self::Orthologs),
             _ => None,
         }
     }
 
+    /// Sections keyed on the UniProt accession, which MyGene.info resolves for
+    /// any species. The rest read human-only sources.
+    fn cross_species(self) -> bool {
+        matches!(self, Self::Protein | Self::Go)
+    }
+
     pub fn libraries(&self) -> &'static [&'static str] {
         match self {
             // Pathways come from Reactome directly, not Enrichr.
@@ -270,10 +384,15 @@ impl GeneIncludeType {
             | Self::Expression
             | Self::Hpa
             | Self::Druggability
+            | Self::Tractability
             | Self::ClinGen
             | Self::Constraint
             | Self::Disgenet
-            | Self::Funding => &[],
+            | Self::ClinVarSummary
+            | Self::Funding
+            | Self::KeyPapers
+            | Self::Dependency
+            | Self::Orthologs => &[],
         }
     }
 }
@@ -282,13 +401,13 @@ const OPTIONAL_ENRICHMENT_TIMEOUT: Duration = Duration::from_secs(8);
 const FUNDING_NO_DATA_NOTE: &str = "No NIH funding data found for this query.";
 const FUNDING_UNAVAILABLE_NOTE: &str = "NIH Reporter funding data is temporarily unavailable.";
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EnrichmentResult {
     pub library: String,
     pub terms: Vec<EnrichmentTerm>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EnrichmentTerm {
     pub name: String,
     pub p_value: f64,
@@ -480,10 +599,15 @@ async fn enrich_gene(
             | GeneIncludeType::Expression
             | GeneIncludeType::Hpa
             | GeneIncludeType::Druggability
+            | GeneIncludeType::Tractability
             | GeneIncludeType::ClinGen
             | GeneIncludeType::Constraint
             | GeneIncludeType::Disgenet
-            | GeneIncludeType::Funding => {}
+            | GeneIncludeType::ClinVarSummary
+            | GeneIncludeType::Funding
+            | GeneIncludeType::KeyPapers
+            | GeneIncludeType::Dependency
+            | GeneIncludeType::Orthologs => {}
             GeneIncludeType::Ontology => {
                 if let Some(v) = ontology.as_mut() {
                     v.push(result);
@@ -556,9 +680,38 @@ fn parse_sections(symbol: &str, sections: &[String]) -> Result<Vec<GeneIncludeTy
     Ok(include)
 }
 
+fn parse_species_sections(
+    symbol: &str,
+    sections: &[String],
+    species: GeneSpecies,
+) -> Result<Vec<GeneIncludeType>, BioMcpError> {
+    if species.is_human() {
+        return parse_sections(symbol, sections);
+    }
+    if sections
+        .iter()
+        .any(|raw| raw.trim().eq_ignore_ascii_case(GENE_SECTION_ALL))
+    {
+        return Ok(vec![GeneIncludeType::Protein, GeneIncludeType::Go]);
+    }
+
+    let include = parse_sections(symbol, sections)?;
+    if let Some(section) = sections
+        .iter()
+        .map(|raw| raw.trim())
+        .find(|raw| GeneIncludeType::from_section(raw).is_some_and(|kind| !kind.cross_species()))
+    {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Section \"{section}\" is only available for human genes. With --species {species}, use: {GENE_SECTION_PROTEIN}, {GENE_SECTION_GO}"
+        )));
+    }
+    Ok(include)
+}
+
 async fn resolve_uniprot_accession(
     explicit: Option<&str>,
     symbol: &str,
+    species: GeneSpecies,
 ) -> Result<Option<String>, BioMcpError> {
     if let Some(value) = explicit
         .map(str::trim)
@@ -568,7 +721,12 @@ async fn resolve_uniprot_accession(
         return Ok(Some(value));
     }
 
-    let page = UniProtClient::new()?.search(symbol, 1, 0, None).await?;
+    let query = if species.is_human() {
+        symbol.to_string()
+    } else {
+        format!("gene_exact:{symbol} AND organism_id:{}", species.taxid())
+    };
+    let page = UniProtClient::new()?.search(&query, 1, 0, None).await?;
     Ok(page
         .results
         .into_iter()
@@ -580,8 +738,9 @@ async fn resolve_uniprot_accession(
 async fn fetch_protein_section(
     uniprot_id: Option<&str>,
     symbol: &str,
+    species: GeneSpecies,
 ) -> Result<Option<GeneProtein>, BioMcpError> {
-    let accession = resolve_uniprot_accession(uniprot_id, symbol).await?;
+    let accession = resolve_uniprot_accession(uniprot_id, symbol, species).await?;
     let Some(accession) = accession else {
         return Ok(None);
     };
@@ -614,8 +773,9 @@ async fn fetch_protein_section(
 async fn fetch_go_section(
     uniprot_id: Option<&str>,
     symbol: &str,
+    species: GeneSpecies,
 ) -> Result<Vec<GeneGoTerm>, BioMcpError> {
-    let accession = resolve_uniprot_accession(uniprot_id, symbol).await?;
+    let accession = resolve_uniprot_accession(uniprot_id, symbol, species).await?;
     let Some(accession) = accession else {
         return Ok(Vec::new());
     };
@@ -835,10 +995,16 @@ async fn add_civic_section(gene: &mut Gene) {
         client.by_molecular_profile(symbol, 10).await
     };
 
-    match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, civic_fut).await {
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        civic_fut,
+    )
+    .await
+    {
         Ok(Ok(context)) => gene.civic = Some(context),
         Ok(Err(err)) => {
             warn!(symbol = %gene.symbol, "CIViC unavailable for gene section: {err}");
+            section_errors::record(GENE_SECTION_CIVIC, "CIViC", &err);
             gene.civic = Some(CivicContext::default());
         }
         Err(_) => {
@@ -847,6 +1013,11 @@ async fn add_civic_section(gene: &mut Gene) {
                 timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
                 "CIViC gene section timed out"
             );
+            section_errors::record_timeout(
+                GENE_SECTION_CIVIC,
+                "CIViC",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
             gene.civic = Some(CivicContext::default());
         }
     }
@@ -869,7 +1040,12 @@ async fn add_expression_section(gene: &mut Gene) {
         Ok::<_, BioMcpError>(GeneExpression { tissues })
     };
 
-    match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, expression_fut).await {
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        expression_fut,
+    )
+    .await
+    {
         Ok(Ok(expression)) => gene.expression = Some(expression),
         Ok(Err(err)) => {
             warn!(
@@ -877,6 +1053,7 @@ async fn add_expression_section(gene: &mut Gene) {
                 ensembl_id = %ensembl_id,
                 "GTEx unavailable for gene expression section: {err}"
             );
+            section_errors::record(GENE_SECTION_EXPRESSION, "GTEx", &err);
             gene.expression = Some(GeneExpression::default());
         }
         Err(_) => {
@@ -886,6 +1063,11 @@ async fn add_expression_section(gene: &mut Gene) {
                 timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
                 "GTEx expression section timed out"
             );
+            section_errors::record_timeout(
+                GENE_SECTION_EXPRESSION,
+                "GTEx",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
             gene.expression = Some(GeneExpression::default());
         }
     }
@@ -907,7 +1089,7 @@ async fn add_hpa_section(gene: &mut Gene) {
         client.protein_data(ensembl_id).await
     };
 
-    match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, hpa_fut).await {
+    match tokio::time::timeout(section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT), hpa_fut).await {
         Ok(Ok(hpa)) => gene.hpa = Some(hpa),
         Ok(Err(err)) => {
             warn!(
@@ -915,6 +1097,7 @@ async fn add_hpa_section(gene: &mut Gene) {
                 ensembl_id = %ensembl_id,
                 "HPA unavailable for gene section: {err}"
             );
+            section_errors::record(GENE_SECTION_HPA, "Human Protein Atlas", &err);
             gene.hpa = Some(GeneHpa::default());
         }
         Err(_) => {
@@ -924,6 +1107,11 @@ async fn add_hpa_section(gene: &mut Gene) {
                 timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
                 "HPA gene section timed out"
             );
+            section_errors::record_timeout(
+                GENE_SECTION_HPA,
+                "Human Protein Atlas",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
             gene.hpa = Some(GeneHpa::default());
         }
     }
@@ -936,14 +1124,16 @@ async fn add_druggability_section(gene: &mut Gene) {
         return;
     }
 
-    let dgidb_fut = tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, async {
-        let client = DgidbClient::new()?;
-        client.gene_interactions(symbol).await
-    });
-    let opentargets_fut = tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, async {
-        let client = OpenTargetsClient::new()?;
-        client.target_druggability_context(symbol).await
-    });
+    let dgidb_fut =
+        tokio::time::timeout(section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT), async {
+            let client = DgidbClient::new()?;
+            client.gene_interactions(symbol).await
+        });
+    let opentargets_fut =
+        tokio::time::timeout(section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT), async {
+            let client = OpenTargetsClient::new()?;
+            client.target_druggability_context(symbol).await
+        });
 
     let (dgidb_result, opentargets_result) = tokio::join!(dgidb_fut, opentargets_fut);
 
@@ -954,6 +1144,7 @@ async fn add_druggability_section(gene: &mut Gene) {
                 symbol = %gene.symbol,
                 "DGIdb unavailable for gene druggability section: {err}"
             );
+            section_errors::record(GENE_SECTION_DRUGGABILITY, "DGIdb", &err);
             Err(err)
         }
         Err(_) => {
@@ -962,6 +1153,11 @@ async fn add_druggability_section(gene: &mut Gene) {
                 timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
                 "DGIdb gene section timed out"
             );
+            section_errors::record_timeout(
+                GENE_SECTION_DRUGGABILITY,
+                "DGIdb",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
             Err(BioMcpError::Api {
                 api: "dgidb".to_string(),
                 message: "timed out".to_string(),
@@ -976,6 +1172,7 @@ async fn add_druggability_section(gene: &mut Gene) {
                 symbol = %gene.symbol,
                 "OpenTargets unavailable for gene druggability section: {err}"
             );
+            section_errors::record(GENE_SECTION_DRUGGABILITY, "Open Targets", &err);
             Err(err)
         }
         Err(_) => {
@@ -984,6 +1181,11 @@ async fn add_druggability_section(gene: &mut Gene) {
                 timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
                 "OpenTargets gene druggability section timed out"
             );
+            section_errors::record_timeout(
+                GENE_SECTION_DRUGGABILITY,
+                "Open Targets",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
             Err(BioMcpError::Api {
                 api: "opentargets".to_string(),
                 message: "timed out".to_string(),
@@ -1030,6 +1232,43 @@ fn merge_druggability_results(
     merged
 }
 
+async fn add_tractability_section(gene: &mut Gene) {
+    let symbol = gene.symbol.trim();
+    if symbol.is_empty() {
+        gene.tractability = Some(GeneTractability::default());
+        return;
+    }
+
+    let fut = async {
+        let client = OpenTargetsClient::new()?;
+        client.target_tractability(symbol).await
+    };
+    match tokio::time::timeout(section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT), fut).await {
+        Ok(Ok(tractability)) => gene.tractability = Some(tractability),
+        Ok(Err(err)) => {
+            warn!(
+                symbol = %gene.symbol,
+                "OpenTargets unavailable for gene tractability section: {err}"
+            );
+            section_errors::record(GENE_SECTION_TRACTABILITY, "Open Targets", &err);
+            gene.tractability = Some(GeneTractability::default());
+        }
+        Err(_) => {
+            warn!(
+                symbol = %gene.symbol,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "OpenTargets gene tractability section timed out"
+            );
+            section_errors::record_timeout(
+                GENE_SECTION_TRACTABILITY,
+                "Open Targets",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+            gene.tractability = Some(GeneTractability::default());
+        }
+    }
+}
+
 async fn add_clingen_section(gene: &mut Gene) {
     let symbol = gene.symbol.trim();
     if symbol.is_empty() {
@@ -1048,13 +1287,19 @@ async fn add_clingen_section(gene: &mut Gene) {
         })
     };
 
-    match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, clingen_fut).await {
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        clingen_fut,
+    )
+    .await
+    {
         Ok(Ok(clingen)) => gene.clingen = Some(clingen),
         Ok(Err(err)) => {
             warn!(
                 symbol = %gene.symbol,
                 "ClinGen unavailable for gene clingen section: {err}"
             );
+            section_errors::record(GENE_SECTION_CLINGEN, "ClinGen", &err);
             gene.clingen = Some(GeneClinGen::default());
         }
         Err(_) => {
@@ -1063,6 +1308,11 @@ async fn add_clingen_section(gene: &mut Gene) {
                 timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
                 "ClinGen gene section timed out"
             );
+            section_errors::record_timeout(
+                GENE_SECTION_CLINGEN,
+                "ClinGen",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
             gene.clingen = Some(GeneClinGen::default());
         }
     }
@@ -1099,7 +1349,12 @@ async fn add_constraint_section(gene: &mut Gene) {
         client.gene_constraint(symbol).await
     };
 
-    match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, constraint_fut).await {
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        constraint_fut,
+    )
+    .await
+    {
         Ok(Ok(Some(constraint))) => {
             gene.constraint = Some(gnomad_constraint_section(
                 constraint.transcript,
@@ -1117,6 +1372,7 @@ async fn add_constraint_section(gene: &mut Gene) {
                 symbol = %gene.symbol,
                 "gnomAD unavailable for gene constraint section: {err}"
             );
+            section_errors::record(GENE_SECTION_CONSTRAINT, "gnomAD", &err);
             gene.constraint = Some(gnomad_constraint_section(None, None, None, None, None));
         }
         Err(_) => {
@@ -1125,6 +1381,11 @@ async fn add_constraint_section(gene: &mut Gene) {
                 timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
                 "gnomAD gene constraint section timed out"
             );
+            section_errors::record_timeout(
+                GENE_SECTION_CONSTRAINT,
+                "gnomAD",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
             gene.constraint = Some(gnomad_constraint_section(None, None, None, None, None));
         }
     }
@@ -1154,6 +1415,151 @@ async fn add_disgenet_section(gene: &mut Gene) -> Result<(), BioMcpError> {
     Ok(())
 }
 
+const CLINVAR_SUMMARY_FACET_SIZE: usize = 20;
+const CLINVAR_SUMMARY_REVIEW_STATUS_LIMIT: usize = 8;
+const CLINVAR_SUMMARY_RECENT_LIMIT: usize = 5;
+
+// Core classes are always shown so a zero count is visible; the rest only when present.
+const CLINVAR_SIGNIFICANCE_BUCKETS: &[(&str, bool)] = &[
+    ("Pathogenic", true),
+    ("Likely pathogenic", true),
+    ("Pathogenic/Likely pathogenic", false),
+    ("Uncertain significance", true),
+    ("Conflicting", false),
+    ("Likely benign", true),
+    ("Benign", true),
+    ("Benign/Likely benign", false),
+    ("Other", false),
+];
+
+fn clinvar_significance_bucket(term: &str) -> &'static str {
+    let normalized = term.trim().to_ascii_lowercase().replace('_', " ");
+    match normalized.as_str() {
+        "pathogenic" => "Pathogenic",
+        "likely pathogenic" => "Likely pathogenic",
+        "pathogenic/likely pathogenic" => "Pathogenic/Likely pathogenic",
+        "uncertain significance" => "Uncertain significance",
+        "likely benign" => "Likely benign",
+        "benign" => "Benign",
+        "benign/likely benign" => "Benign/Likely benign",
+        v if v.starts_with("conflicting") => "Conflicting",
+        _ => "Other",
+    }
+}
+
+fn clinvar_significance_counts(terms: &[MyVariantFacetTerm]) -> Vec<GeneClinVarCount> {
+    let mut counts: HashMap<&'static str, u64> = HashMap::new();
+    for row in terms {
+        *counts
+            .entry(clinvar_significance_bucket(&row.term))
+            .or_default() += row.count;
+    }
+    CLINVAR_SIGNIFICANCE_BUCKETS
+        .iter()
+        .filter_map(|(label, always)| {
+            let count = counts.get(label).copied().unwrap_or(0);
+            (*always || count > 0).then(|| GeneClinVarCount {
+                label: (*label).to_string(),
+                count,
+            })
+        })
+        .collect()
+}
+
+fn clinvar_recent_submission(hit: MyVariantClinVarRecentHit) -> GeneClinVarSubmission {
+    let clinvar = hit.clinvar;
+    // The newest RCV drives the row, matching the sort order of the query.
+    let latest = clinvar.as_ref().and_then(|c| {
+        c.rcv
+            .iter()
+            .max_by(|a, b| a.last_evaluated.cmp(&b.last_evaluated))
+    });
+    GeneClinVarSubmission {
+        variant_id: hit.id,
+        clinvar_id: clinvar.as_ref().and_then(|c| c.variant_id),
+        hgvs_coding: clinvar
+            .as_ref()
+            .and_then(|c| c.hgvs.as_ref())
+            .and_then(|h| h.coding.first())
+            .map(str::to_string),
+        significance: latest.and_then(|r| r.clinical_significance.clone()),
+        review_status: latest.and_then(|r| r.review_status.clone()),
+        last_evaluated: latest.and_then(|r| r.last_evaluated.clone()),
+    }
+}
+
+async fn fetch_clinvar_summary(symbol: &str) -> Result<GeneClinVarSummary, BioMcpError> {
+    let client = MyVariantClient::new()?;
+    let (mut facets, recent) = tokio::try_join!(
+        client.clinvar_gene_facets(symbol, CLINVAR_SUMMARY_FACET_SIZE),
+        client.clinvar_gene_recent(symbol, CLINVAR_SUMMARY_RECENT_LIMIT),
+    )?;
+    let significance = facets
+        .facets
+        .remove(MYVARIANT_CLINVAR_SIGNIFICANCE_FACET)
+        .unwrap_or_default();
+    let review_status = facets
+        .facets
+        .remove(MYVARIANT_CLINVAR_REVIEW_STATUS_FACET)
+        .unwrap_or_default();
+    Ok(GeneClinVarSummary {
+        total_variants: facets.total,
+        significance: clinvar_significance_counts(&significance.terms),
+        review_status: review_status
+            .terms
+            .into_iter()
+            .take(CLINVAR_SUMMARY_REVIEW_STATUS_LIMIT)
+            .map(|row| GeneClinVarCount {
+                label: row.term,
+                count: row.count,
+            })
+            .collect(),
+        recent: recent
+            .hits
+            .into_iter()
+            .map(clinvar_recent_submission)
+            .collect(),
+    })
+}
+
+async fn add_clinvar_summary_section(gene: &mut Gene) {
+    let symbol = gene.symbol.trim();
+    if symbol.is_empty() {
+        gene.clinvar_summary = Some(GeneClinVarSummary::default());
+        return;
+    }
+
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        fetch_clinvar_summary(symbol),
+    )
+    .await
+    {
+        Ok(Ok(summary)) => gene.clinvar_summary = Some(summary),
+        Ok(Err(err)) => {
+            warn!(
+                symbol = %gene.symbol,
+                "MyVariant.info unavailable for gene ClinVar summary section: {err}"
+            );
+            section_errors::record(GENE_SECTION_CLINVAR_SUMMARY, "MyVariant.info", &err);
+            gene.clinvar_summary = Some(GeneClinVarSummary::default());
+        }
+        Err(_) => {
+            warn!(
+                symbol = %gene.symbol,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "MyVariant.info gene ClinVar summary section timed out"
+            );
+            section_errors::record_timeout(
+                GENE_SECTION_CLINVAR_SUMMARY,
+                "MyVariant.info",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+            gene.clinvar_summary = Some(GeneClinVarSummary::default());
+        }
+    }
+}
+
 async fn add_funding_section(gene: &mut Gene) {
     let symbol = gene.symbol.trim();
     if symbol.is_empty() {
@@ -1172,7 +1578,12 @@ async fn add_funding_section(gene: &mut Gene) {
         client.funding(symbol).await
     };
 
-    match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, funding_fut).await {
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        funding_fut,
+    )
+    .await
+    {
         Ok(Ok(section)) => {
             let no_hits = section.matching_project_years == 0 && section.grants.is_empty();
             gene.funding = Some(section);
@@ -1184,6 +1595,7 @@ async fn add_funding_section(gene: &mut Gene) {
         }
         Ok(Err(err)) => {
             warn!(symbol = %gene.symbol, "NIH Reporter unavailable for gene funding section: {err}");
+            section_errors::record(GENE_SECTION_FUNDING, "NIH Reporter", &err);
             gene.funding = None;
             gene.funding_note = Some(FUNDING_UNAVAILABLE_NOTE.into());
         }
@@ -1193,27 +1605,169 @@ async fn add_funding_section(gene: &mut Gene) {
                 timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
                 "NIH Reporter gene funding section timed out"
             );
+            section_errors::record_timeout(
+                GENE_SECTION_FUNDING,
+                "NIH Reporter",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
             gene.funding = None;
             gene.funding_note = Some(FUNDING_UNAVAILABLE_NOTE.into());
         }
     }
 }
 
+async fn add_key_papers_section(gene: &mut Gene) {
+    let symbol = gene.symbol.trim();
+    if symbol.is_empty() {
+        gene.key_papers = Some(Vec::new());
+        return;
+    }
+
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        key_papers::fetch_key_papers(symbol),
+    )
+    .await
+    {
+        Ok(Ok(papers)) => gene.key_papers = Some(papers),
+        Ok(Err(err)) => {
+            warn!(symbol = %gene.symbol, "Europe PMC unavailable for gene key-papers section: {err}");
+            section_errors::record(GENE_SECTION_KEY_PAPERS, "Europe PMC", &err);
+            gene.key_papers = Some(Vec::new());
+        }
+        Err(_) => {
+            warn!(
+                symbol = %gene.symbol,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "Europe PMC gene key-papers section timed out"
+            );
+            section_errors::record_timeout(
+                GENE_SECTION_KEY_PAPERS,
+                "Europe PMC",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+            gene.key_papers = Some(Vec::new());
+        }
+    }
+}
+
+async fn add_dependency_section(gene: &mut Gene) {
+    let symbol = gene.symbol.trim();
+    if symbol.is_empty() {
+        gene.dependency = Some(GeneDependency::default());
+        return;
+    }
+
+    let fut = async {
+        let client = DepMapClient::new()?;
+        client.gene_dependency(symbol).await
+    };
+    match tokio::time::timeout(section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT), fut).await {
+        Ok(Ok(dependency)) => gene.dependency = Some(dependency),
+        Ok(Err(err)) => {
+            warn!(symbol = %gene.symbol, "DepMap unavailable for gene dependency section: {err}");
+            section_errors::record(GENE_SECTION_DEPENDENCY, "DepMap", &err);
+            gene.dependency = Some(GeneDependency::default());
+        }
+        Err(_) => {
+            warn!(
+                symbol = %gene.symbol,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "DepMap gene dependency section timed out"
+            );
+            section_errors::record_timeout(
+                GENE_SECTION_DEPENDENCY,
+                "DepMap",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+            gene.dependency = Some(GeneDependency::default());
+        }
+    }
+}
+
+async fn add_orthologs_section(gene: &mut Gene) {
+    let query = gene
+        .ensembl_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .unwrap_or(gene.symbol.trim())
+        .to_string();
+    if query.is_empty() {
+        gene.orthologs = Some(Vec::new());
+        return;
+    }
+
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        orthologs::fetch_orthologs(&query),
+    )
+    .await
+    {
+        Ok(Ok(rows)) => gene.orthologs = Some(rows),
+        Ok(Err(err)) => {
+            warn!(symbol = %gene.symbol, "Ensembl Compara unavailable for gene orthologs section: {err}");
+            section_errors::record(GENE_SECTION_ORTHOLOGS, "Ensembl Compara", &err);
+            gene.orthologs = Some(Vec::new());
+        }
+        Err(_) => {
+            warn!(
+                symbol = %gene.symbol,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "Ensembl Compara gene orthologs section timed out"
+            );
+            section_errors::record_timeout(
+                GENE_SECTION_ORTHOLOGS,
+                "Ensembl Compara",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+            gene.orthologs = Some(Vec::new());
+        }
+    }
+}
+
 pub async fn get(symbol: &str, sections: &[String]) -> Result<Gene, BioMcpError> {
+    get_for_species(symbol, sections, GeneSpecies::Human).await
+}
+
+/// Non-human genes support only the UniProt-backed `protein` and `go`
+/// sections; `all` expands to those two.
+pub async fn get_for_species(
+    symbol: &str,
+    sections: &[String],
+    species: GeneSpecies,
+) -> Result<Gene, BioMcpError> {
     if symbol.trim().is_empty() {
         return Err(BioMcpError::InvalidArgument(
             "Gene symbol is required. Example: biomcp get gene BRAF".into(),
         ));
     }
 
-    let include = parse_sections(symbol, sections)?;
+    let (plugins, sections) = plugin::split_sections(
+        plugin::registered(),
+        PluginEntity::Gene,
+        GENE_SECTION_NAMES,
+        sections,
+    );
+    let include = parse_species_sections(symbol, &sections, species)?;
+
+    if let Some(store) = crate::utils::snapshot::offline_store() {
+        if !species.is_human() {
+            return Err(offline::non_human_error(species));
+        }
+        return offline::get(&store, symbol, &sections);
+    }
 
     let client = MyGeneClient::new()?;
-    let resp = client.get(symbol, false).await?;
+    let resp = client
+        .get_for_species(symbol, false, &species.mygene_value())
+        .await?;
 
-    let mut gene = transform::gene::from_mygene_get(resp);
+    let mut gene = transform::gene::from_mygene_get(resp, species);
 
-    if let Err(err) = add_clinical_context(&mut gene).await {
+    if species.is_human()
+        && let Err(err) = add_clinical_context(&mut gene).await
+    {
         warn!("OpenTargets unavailable for gene clinical context: {err}");
     }
 
@@ -1222,6 +1776,7 @@ pub async fn get(symbol: &str, sections: &[String]) -> Result<Gene, BioMcpError>
             Ok(v) => merge_pathways(gene.pathways.take(), v),
             Err(err) => {
                 warn!("Reactome unavailable for gene pathways section: {err}");
+                section_errors::record(GENE_SECTION_PATHWAYS, "Reactome", &err);
                 gene.pathways
             }
         };
@@ -1242,20 +1797,23 @@ pub async fn get(symbol: &str, sections: &[String]) -> Result<Gene, BioMcpError>
     }
 
     if include.contains(&GeneIncludeType::Protein) {
-        gene.protein = match fetch_protein_section(gene.uniprot_id.as_deref(), &gene.symbol).await {
-            Ok(v) => v,
-            Err(err) => {
-                warn!("UniProt unavailable for gene protein section: {err}");
-                None
-            }
-        };
+        gene.protein =
+            match fetch_protein_section(gene.uniprot_id.as_deref(), &gene.symbol, species).await {
+                Ok(v) => v,
+                Err(err) => {
+                    warn!("UniProt unavailable for gene protein section: {err}");
+                    section_errors::record(GENE_SECTION_PROTEIN, "UniProt", &err);
+                    None
+                }
+            };
     }
 
     if include.contains(&GeneIncludeType::Go) {
-        gene.go = match fetch_go_section(gene.uniprot_id.as_deref(), &gene.symbol).await {
+        gene.go = match fetch_go_section(gene.uniprot_id.as_deref(), &gene.symbol, species).await {
             Ok(v) => Some(v),
             Err(err) => {
                 warn!("QuickGO unavailable for gene GO section: {err}");
+                section_errors::record(GENE_SECTION_GO, "QuickGO", &err);
                 Some(Vec::new())
             }
         };
@@ -1266,6 +1824,7 @@ pub async fn get(symbol: &str, sections: &[String]) -> Result<Gene, BioMcpError>
             Ok(v) => Some(v),
             Err(err) => {
                 warn!("STRING unavailable for gene interactions section: {err}");
+                section_errors::record(GENE_SECTION_INTERACTIONS, "STRING", &err);
                 Some(Vec::new())
             }
         };
@@ -1287,6 +1846,10 @@ pub async fn get(symbol: &str, sections: &[String]) -> Result<Gene, BioMcpError>
         add_druggability_section(&mut gene).await;
     }
 
+    if include.contains(&GeneIncludeType::Tractability) {
+        add_tractability_section(&mut gene).await;
+    }
+
     if include.contains(&GeneIncludeType::ClinGen) {
         add_clingen_section(&mut gene).await;
     }
@@ -1299,11 +1862,36 @@ pub async fn get(symbol: &str, sections: &[String]) -> Result<Gene, BioMcpError>
         add_disgenet_section(&mut gene).await?;
     }
 
+    if include.contains(&GeneIncludeType::ClinVarSummary) {
+        add_clinvar_summary_section(&mut gene).await;
+    }
+
     if include.contains(&GeneIncludeType::Funding) {
         add_funding_section(&mut gene).await;
     }
 
-    Ok(gene)
+    if include.contains(&GeneIncludeType::KeyPapers) {
+        add_key_papers_section(&mut gene).await;
+    }
+
+    if include.contains(&GeneIncludeType::Dependency) {
+        add_dependency_section(&mut gene).await;
+    }
+
+    if include.contains(&GeneIncludeType::Orthologs) {
+        add_orthologs_section(&mut gene).await;
+    }
+
+    if !plugins.is_empty() {
+        let lookup = PluginLookup::default()
+            .with("symbol", Some(&gene.symbol))
+            .with("entrez_id", Some(&gene.entrez_id))
+            .with("ensembl_id", gene.ensembl_id.as_deref())
+            .with("uniprot_id", gene.uniprot_id.as_deref());
+        gene.plugin_sections = plugin::fetch_sections(&plugins, PluginEntity::Gene, &lookup).await;
+    }
+
+    transform::fields::apply("gene", gene)
 }
 
 #[allow(dead_code)]
@@ -1321,6 +1909,18 @@ pub async fn search_page(
 ) -> Result<SearchPage<GeneSearchResult>, BioMcpError> {
     const MAX_SEARCH_LIMIT: usize = 50;
 
+    if let Some(store) = crate::utils::snapshot::offline_store() {
+        if limit == 0 || limit > MAX_SEARCH_LIMIT {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "--limit must be between 1 and {MAX_SEARCH_LIMIT}"
+            )));
+        }
+        if !filters.species.is_human() {
+            return Err(offline::non_human_error(filters.species));
+        }
+        return offline::search_page(&store, filters, limit, offset);
+    }
+
     let query = filters
         .query
         .as_deref()
@@ -1437,7 +2037,13 @@ pub async fn search_page(
         limit
     };
     let resp = client
-        .search(&q, fetch_limit, offset, normalized_chromosome.as_deref())
+        .search(
+            &q,
+            fetch_limit,
+            offset,
+            normalized_chromosome.as_deref(),
+            &filters.species.mygene_value(),
+        )
         .await?;
     let expected_gene_type = normalized_gene_type.map(str::to_ascii_lowercase);
     let expected_chr = normalized_chromosome.map(|v| v.to_ascii_uppercase());
@@ -1492,7 +2098,7 @@ pub async fn search_page(
 
             true
         })
-        .map(transform::gene::from_mygene_hit)
+        .map(|hit| transform::gene::from_mygene_hit(hit, filters.species))
         .collect::<Vec<_>>();
     out.truncate(limit);
     Ok(SearchPage::offset(out, Some(resp.total)))
@@ -1551,6 +2157,9 @@ pub fn search_query_summary(filters: &GeneSearchFilters) -> String {
     {
         parts.push(format!("go={v}"));
     }
+    if let Some(v) = filters.species.flag_value() {
+        parts.push(format!("species={v}"));
+    }
 
     parts.join(", ")
 }
@@ -1568,6 +2177,7 @@ mod tests {
             region: None,
             pathway: None,
             go_term: None,
+            species: GeneSpecies::Human,
         });
         assert_eq!(summary, "kinase, type=protein-coding, chromosome=7");
     }
@@ -1593,6 +2203,7 @@ mod tests {
             region: None,
             pathway: None,
             go_term: None,
+            species: GeneSpecies::Human,
         });
         assert_eq!(summary, "BRCA1, chromosome=17");
     }
@@ -1665,10 +2276,15 @@ mod tests {
         assert!(GENE_SECTION_NAMES.contains(&"expression"));
         assert!(GENE_SECTION_NAMES.contains(&"hpa"));
         assert!(GENE_SECTION_NAMES.contains(&"druggability"));
+        assert!(GENE_SECTION_NAMES.contains(&"tractability"));
         assert!(GENE_SECTION_NAMES.contains(&"clingen"));
         assert!(GENE_SECTION_NAMES.contains(&"constraint"));
         assert!(GENE_SECTION_NAMES.contains(&"disgenet"));
+        assert!(GENE_SECTION_NAMES.contains(&"clinvar-summary"));
         assert!(GENE_SECTION_NAMES.contains(&"funding"));
+        assert!(GENE_SECTION_NAMES.contains(&"key-papers"));
+        assert!(GENE_SECTION_NAMES.contains(&"dependency"));
+        assert!(GENE_SECTION_NAMES.contains(&"orthologs"));
     }
 
     #[test]
@@ -1695,6 +2311,144 @@ mod tests {
         assert_eq!(parsed.len(), 12);
         assert!(!parsed.contains(&GeneIncludeType::Disgenet));
         assert!(!parsed.contains(&GeneIncludeType::Funding));
+        assert!(!parsed.contains(&GeneIncludeType::ClinVarSummary));
+        assert!(!parsed.contains(&GeneIncludeType::Tractability));
+        assert!(!parsed.contains(&GeneIncludeType::KeyPapers));
+        assert!(!parsed.contains(&GeneIncludeType::Dependency));
+        assert!(!parsed.contains(&GeneIncludeType::Orthologs));
+    }
+
+    #[test]
+    fn parse_sections_accepts_tractability_and_safety_alias() {
+        let parsed = parse_sections("EGFR", &["tractability".to_string(), "safety".to_string()])
+            .expect("tractability should parse");
+        assert_eq!(parsed, vec![GeneIncludeType::Tractability]);
+    }
+
+    #[test]
+    fn parse_sections_accepts_clinvar_summary_aliases() {
+        let parsed = parse_sections(
+            "BRCA1",
+            &["clinvar-summary".to_string(), "clinvar_summary".to_string()],
+        )
+        .expect("clinvar summary should parse");
+        assert_eq!(parsed, vec![GeneIncludeType::ClinVarSummary]);
+    }
+
+    #[test]
+    fn parse_sections_accepts_key_papers_aliases() {
+        let parsed = parse_sections(
+            "TP53",
+            &["key-papers".to_string(), "key_papers".to_string()],
+        )
+        .expect("key papers should parse");
+        assert_eq!(parsed, vec![GeneIncludeType::KeyPapers]);
+    }
+
+    #[test]
+    fn parse_sections_accepts_dependency_and_depmap_alias() {
+        let parsed = parse_sections("KRAS", &["dependency".to_string(), "depmap".to_string()])
+            .expect("dependency should parse");
+        assert_eq!(parsed, vec![GeneIncludeType::Dependency]);
+    }
+
+    #[test]
+    fn parse_sections_accepts_orthologs_aliases() {
+        let parsed = parse_sections(
+            "BRAF",
+            &[
+                "orthologs".to_string(),
+                "orthologues".to_string(),
+                "orthology".to_string(),
+            ],
+        )
+        .expect("orthologs should parse");
+        assert_eq!(parsed, vec![GeneIncludeType::Orthologs]);
+
+        let err = parse_species_sections("Braf", &["orthologs".to_string()], GeneSpecies::Mouse)
+            .expect_err("orthologs are human-only");
+        assert!(err.to_string().contains("only available for human genes"));
+    }
+
+    #[test]
+    fn parse_species_sections_limits_non_human_genes_to_uniprot_sections() {
+        let all = parse_species_sections("Braf", &["all".to_string()], GeneSpecies::Mouse)
+            .expect("all should parse for mouse");
+        assert_eq!(all, vec![GeneIncludeType::Protein, GeneIncludeType::Go]);
+
+        let go = parse_species_sections("Braf", &["go".to_string()], GeneSpecies::Rat)
+            .expect("go should parse for rat");
+        assert_eq!(go, vec![GeneIncludeType::Go]);
+
+        let err = parse_species_sections(
+            "Braf",
+            &["protein".to_string(), "civic".to_string()],
+            GeneSpecies::Mouse,
+        )
+        .expect_err("civic is human-only");
+        let msg = err.to_string();
+        assert!(msg.contains("Section \"civic\" is only available for human genes"));
+        assert!(msg.contains("--species mouse"));
+
+        let human = parse_species_sections("BRAF", &["all".to_string()], GeneSpecies::Human)
+            .expect("human all should parse");
+        assert_eq!(human.len(), 12);
+    }
+
+    #[test]
+    fn search_query_summary_includes_non_human_species() {
+        let summary = search_query_summary(&GeneSearchFilters {
+            query: Some("Braf".into()),
+            species: GeneSpecies::Mouse,
+            ..Default::default()
+        });
+        assert_eq!(summary, "Braf, species=mouse");
+    }
+
+    #[test]
+    fn clinvar_significance_counts_buckets_terms_and_keeps_core_classes() {
+        let terms = vec![
+            MyVariantFacetTerm {
+                term: "pathogenic".into(),
+                count: 40,
+            },
+            MyVariantFacetTerm {
+                term: "Uncertain_significance".into(),
+                count: 120,
+            },
+            MyVariantFacetTerm {
+                term: "conflicting interpretations of pathogenicity".into(),
+                count: 9,
+            },
+            MyVariantFacetTerm {
+                term: "conflicting data from submitters".into(),
+                count: 1,
+            },
+            MyVariantFacetTerm {
+                term: "not provided".into(),
+                count: 3,
+            },
+            MyVariantFacetTerm {
+                term: "benign".into(),
+                count: 12,
+            },
+        ];
+        let counts = clinvar_significance_counts(&terms)
+            .into_iter()
+            .map(|row| (row.label, row.count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            counts,
+            vec![
+                ("Pathogenic".to_string(), 40),
+                ("Likely pathogenic".to_string(), 0),
+                ("Uncertain significance".to_string(), 120),
+                ("Conflicting".to_string(), 10),
+                ("Likely benign".to_string(), 0),
+                ("Benign".to_string(), 12),
+                ("Other".to_string(), 3),
+            ]
+        );
     }
 
     #[test]
@@ -0,0 +1,269 @@
+//! Per-gene ClinVar variant tables for curation spreadsheets.
+//!
+//! Rows come from MyVariant.info with a narrow field selection. Paged listings
+//! stay inside the BioThings 10,000-hit window; `--all` walks the full result
+//! set with `fetch_all` scrolling so large genes (BRCA1, BRCA2) export complete.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+use crate::sources::myvariant::{MyVariantClient, MyVariantClinVarTableHit};
+use crate::transform;
+use crate::utils::text::tsv_field;
+
+const MAX_SCROLL_BATCHES: usize = 500;
+const MAX_CONDITIONS_PER_ROW: usize = 8;
+const TSV_COLUMNS: &[&str] = &[
+    "variant",
+    "clinvar_id",
+    "rsid",
+    "hgvs_c",
+    "hgvs_p",
+    "significance",
+    "review_status",
+    "stars",
+    "conditions",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneClinVarVariants {
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub significance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    pub offset: usize,
+    /// True when every matching ClinVar record was fetched (`--all`).
+    pub complete: bool,
+    pub variants: Vec<GeneClinVarVariant>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneClinVarVariant {
+    /// MyVariant.info HGVS genomic ID (hg19), e.g. `chr17:g.41197732G>A`.
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clinvar_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rsid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hgvs_c: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hgvs_p: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub significance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_stars: Option<u8>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<String>,
+}
+
+/// Prefers RefSeq accessions (`NM_`/`NP_`) over LRG and Ensembl aliases.
+fn pick_refseq(values: Vec<String>, prefix: &str) -> Option<String> {
+    let values = values
+        .into_iter()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .collect::<Vec<_>>();
+    values
+        .iter()
+        .find(|value| value.starts_with(prefix))
+        .or_else(|| values.first())
+        .cloned()
+}
+
+fn variant_row(hit: MyVariantClinVarTableHit) -> GeneClinVarVariant {
+    let rsid = hit
+        .dbsnp
+        .and_then(|dbsnp| dbsnp.rsid)
+        .map(|rsid| rsid.trim().to_string())
+        .filter(|rsid| !rsid.is_empty());
+    let Some(clinvar) = hit.clinvar else {
+        return GeneClinVarVariant {
+            id: hit.id,
+            clinvar_id: None,
+            rsid,
+            hgvs_c: None,
+            hgvs_p: None,
+            significance: None,
+            review_status: None,
+            review_stars: None,
+            conditions: Vec::new(),
+        };
+    };
+
+    let (hgvs_c, hgvs_p) = match clinvar.hgvs {
+        Some(hgvs) => (
+            pick_refseq(hgvs.coding.into_vec(), "NM_"),
+            pick_refseq(hgvs.protein.into_vec(), "NP_"),
+        ),
+        None => (None, None),
+    };
+    let (review_status, review_stars) = transform::variant::pick_review_status(&clinvar.rcv);
+    let mut conditions: Vec<String> = Vec::new();
+    for name in clinvar
+        .rcv
+        .iter()
+        .flat_map(transform::variant::clinvar_condition_names)
+    {
+        let name = name.trim();
+        if name.is_empty()
+            || conditions
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(name))
+        {
+            continue;
+        }
+        conditions.push(name.to_string());
+    }
+    conditions.truncate(MAX_CONDITIONS_PER_ROW);
+
+    GeneClinVarVariant {
+        id: hit.id,
+        clinvar_id: clinvar.variant_id,
+        rsid,
+        hgvs_c,
+        hgvs_p,
+        significance: transform::variant::pick_significance(&clinvar.rcv),
+        review_status,
+        review_stars,
+        conditions,
+    }
+}
+
+fn normalize_symbol(symbol: &str) -> Result<String, BioMcpError> {
+    let symbol = symbol.trim();
+    if symbol.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "Gene symbol is required. Example: biomcp gene variants BRCA1".into(),
+        ));
+    }
+    Ok(symbol.to_string())
+}
+
+fn normalize_significance(significance: Option<&str>) -> Option<String> {
+    significance
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Lists one page of a gene's ClinVar variants.
+pub async fn clinvar_variants(
+    symbol: &str,
+    significance: Option<&str>,
+    limit: usize,
+    offset: usize,
+) -> Result<GeneClinVarVariants, BioMcpError> {
+    let symbol = normalize_symbol(symbol)?;
+    let significance = normalize_significance(significance);
+    let resp = MyVariantClient::new()?
+        .clinvar_gene_variants(&symbol, significance.as_deref(), limit, offset)
+        .await?;
+    let variants = resp.hits.into_iter().map(variant_row).collect::<Vec<_>>();
+    let complete = offset == 0
+        && resp
+            .total
+            .is_some_and(|total| total as usize <= variants.len());
+    Ok(GeneClinVarVariants {
+        symbol,
+        significance,
+        total: resp.total,
+        offset,
+        complete,
+        variants,
+    })
+}
+
+/// Scrolls through every ClinVar variant in the gene.
+pub async fn clinvar_variants_all(
+    symbol: &str,
+    significance: Option<&str>,
+) -> Result<GeneClinVarVariants, BioMcpError> {
+    let symbol = normalize_symbol(symbol)?;
+    let significance = normalize_significance(significance);
+    let client = MyVariantClient::new()?;
+
+    let mut variants = Vec::new();
+    let mut total = None;
+    let mut scroll_id: Option<String> = None;
+    for batch in 0..MAX_SCROLL_BATCHES {
+        let resp = client
+            .clinvar_gene_variants_scroll(&symbol, significance.as_deref(), scroll_id.as_deref())
+            .await?;
+        if total.is_none() {
+            total = resp.total;
+        }
+        let fetched = resp.hits.len();
+        variants.extend(resp.hits.into_iter().map(variant_row));
+        tracing::debug!(
+            rows = variants.len(),
+            total = ?total,
+            "ClinVar gene variant scroll batch {}",
+            batch + 1
+        );
+
+        let reached_total = total.is_some_and(|total| variants.len() as u64 >= total);
+        if fetched == 0 || reached_total || resp.scroll_id.is_none() {
+            if let Some(total) = total
+                && (variants.len() as u64) < total
+            {
+                return Err(BioMcpError::Api {
+                    api: "myvariant.info".into(),
+                    message: format!(
+                        "ClinVar scroll ended after {} of {total} variants; retry the export",
+                        variants.len()
+                    ),
+                });
+            }
+            return Ok(GeneClinVarVariants {
+                symbol,
+                significance,
+                total,
+                offset: 0,
+                complete: true,
+                variants,
+            });
+        }
+        scroll_id = resp.scroll_id;
+    }
+
+    Err(BioMcpError::InvalidArgument(format!(
+        "--all stopped after {MAX_SCROLL_BATCHES} batches ({} rows). Narrow the export with --significance.",
+        variants.len()
+    )))
+}
+
+impl GeneClinVarVariants {
+    /// Renders the table as tab-separated values with a header row.
+    pub fn to_tsv(&self) -> String {
+        let mut out = TSV_COLUMNS.join("\t");
+        out.push('\n');
+        for row in &self.variants {
+            let fields = [
+                row.id.clone(),
+                row.clinvar_id.map(|id| id.to_string()).unwrap_or_default(),
+                row.rsid.clone().unwrap_or_default(),
+                row.hgvs_c.clone().unwrap_or_default(),
+                row.hgvs_p.clone().unwrap_or_default(),
+                row.significance.clone().unwrap_or_default(),
+                row.review_status.clone().unwrap_or_default(),
+                row.review_stars.map(|s| s.to_string()).unwrap_or_default(),
+                row.conditions.join("; "),
+            ];
+            let line = fields
+                .iter()
+                .map(|field| tsv_field(field))
+                .collect::<Vec<_>>()
+                .join("\t");
+            out.push_str(&line);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,117 @@
+//! Tests for per-gene ClinVar variant tables.
+
+use super::*;
+
+fn hit(value: serde_json::Value) -> MyVariantClinVarTableHit {
+    serde_json::from_value(value).expect("table hit")
+}
+
+#[test]
+fn pick_refseq_prefers_refseq_accessions() {
+    assert_eq!(
+        pick_refseq(
+            vec!["LRG_292t1:c.5503C>T".into(), "NM_007294.4:c.5503C>T".into()],
+            "NM_"
+        ),
+        Some("NM_007294.4:c.5503C>T".to_string())
+    );
+    assert_eq!(
+        pick_refseq(vec!["LRG_292p1:p.Arg1835Ter".into()], "NP_"),
+        Some("LRG_292p1:p.Arg1835Ter".to_string())
+    );
+    assert_eq!(pick_refseq(vec![" ".into()], "NM_"), None);
+}
+
+#[test]
+fn variant_row_summarizes_rcv_records() {
+    let row = variant_row(hit(serde_json::json!({
+        "_id": "chr17:g.41197732G>A",
+        "clinvar": {
+            "variant_id": 55555,
+            "hgvs": {
+                "coding": ["LRG_292t1:c.5503C>T", "NM_007294.4:c.5503C>T"],
+                "protein": ["LRG_292p1:p.Arg1835Ter", "NP_009225.1:p.Arg1835Ter"]
+            },
+            "rcv": [
+                {
+                    "clinical_significance": "Likely pathogenic",
+                    "review_status": "criteria provided, single submitter",
+                    "conditions": {"name": "Breast-ovarian cancer, familial 1"}
+                },
+                {
+                    "clinical_significance": "Pathogenic",
+                    "review_status": "reviewed by expert panel",
+                    "conditions": [
+                        {"name": "breast-ovarian cancer, familial 1"},
+                        {"name": "Hereditary cancer-predisposing syndrome"}
+                    ]
+                }
+            ]
+        },
+        "dbsnp": {"rsid": "rs80357260"}
+    })));
+
+    assert_eq!(row.clinvar_id, Some(55555));
+    assert_eq!(row.rsid.as_deref(), Some("rs80357260"));
+    assert_eq!(row.hgvs_c.as_deref(), Some("NM_007294.4:c.5503C>T"));
+    assert_eq!(row.hgvs_p.as_deref(), Some("NP_009225.1:p.Arg1835Ter"));
+    assert_eq!(row.significance.as_deref(), Some("Pathogenic"));
+    assert_eq!(
+        row.review_status.as_deref(),
+        Some("reviewed by expert panel")
+    );
+    assert_eq!(row.review_stars, Some(3));
+    assert_eq!(
+        row.conditions,
+        vec![
+            "Breast-ovarian cancer, familial 1".to_string(),
+            "Hereditary cancer-predisposing syndrome".to_string()
+        ]
+    );
+}
+
+#[test]
+fn variant_row_tolerates_missing_clinvar_block() {
+    let row = variant_row(hit(serde_json::json!({"_id": "chr17:g.41197732G>A"})));
+    assert_eq!(row.id, "chr17:g.41197732G>A");
+    assert!(row.significance.is_none());
+    assert!(row.conditions.is_empty());
+}
+
+#[test]
+fn to_tsv_writes_header_and_sanitizes_fields() {
+    let table = GeneClinVarVariants {
+        symbol: "BRCA1".into(),
+        significance: Some("pathogenic".into()),
+        total: Some(1),
+        offset: 0,
+        complete: true,
+        variants: vec![GeneClinVarVariant {
+            id: "chr17:g.41197732G>A".into(),
+            clinvar_id: Some(55555),
+            rsid: None,
+            hgvs_c: Some("NM_007294.4:c.5503C>T".into()),
+            hgvs_p: None,
+            significance: Some("Pathogenic".into()),
+            review_status: Some("reviewed by expert panel".into()),
+            review_stars: Some(3),
+            conditions: vec!["Breast\tcancer".into(), "Ovarian cancer".into()],
+        }],
+    };
+
+    let tsv = table.to_tsv();
+    let mut lines = tsv.lines();
+    assert_eq!(
+        lines.next(),
+        Some(
+            "variant\tclinvar_id\trsid\thgvs_c\thgvs_p\tsignificance\treview_status\tstars\tconditions"
+        )
+    );
+    assert_eq!(
+        lines.next(),
+        Some(
+            "chr17:g.41197732G>A\t55555\t\tNM_007294.4:c.5503C>T\t\tPathogenic\treviewed by expert panel\t3\tBreast cancer; Ovarian cancer"
+        )
+    );
+    assert_eq!(lines.next(), None);
+}
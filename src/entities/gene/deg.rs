@@ -0,0 +1,477 @@
+//! Gene annotation for differential-expression result tables.
+//!
+//! DESeq2, edgeR, and limma exports identify genes by Ensembl ID or by symbol
+//! in one column of a CSV/TSV file. Each row keeps its original fields and
+//! gains the MyGene.info symbol, Entrez/Ensembl IDs, name, and summary; with
+//! `druggable` set, DGIdb drug counts are appended too. [`entrez_ids`] reuses
+//! the same detection to turn plain gene lists into Entrez IDs.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+
+use crate::error::BioMcpError;
+use crate::sources::dgidb::DgidbClient;
+use crate::sources::mygene::{MyGeneClient, MyGeneQueryManyHit, StringOrU64};
+use crate::utils::text::tsv_field;
+
+/// Upper bound on table rows; whole-transcriptome tables are ~60,000 genes.
+const MAX_DEG_ROWS: usize = 100_000;
+const MYGENE_QUERY_CHUNK: usize = 1000;
+const DGIDB_QUERY_CHUNK: usize = 100;
+const ANNOTATION_COLUMNS: &[&str] = &["symbol", "entrez_id", "ensembl_id", "name", "summary"];
+const DRUGGABLE_COLUMNS: &[&str] = &["druggable", "drug_count", "approved_drug_count"];
+const FOLD_CHANGE_COLUMNS: &[&str] = &["log2foldchange", "logfc", "log2fc", "log2_fold_change"];
+const ADJUSTED_P_COLUMNS: &[&str] = &["padj", "adj.p.val", "fdr", "qvalue", "q_value", "p_adj"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DegAnnotation {
+    pub source: String,
+    pub gene_column: String,
+    /// `ensembl`, `symbol`, or `mixed`, from the IDs seen in the gene column.
+    pub id_type: String,
+    pub columns: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fold_change_column: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adjusted_p_column: Option<String>,
+    pub resolved: usize,
+    pub druggable_checked: bool,
+    pub rows: Vec<DegRow>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DegRow {
+    /// Value from the gene column as written in the file.
+    pub input: String,
+    /// Original fields, aligned with `DegAnnotation::columns`.
+    pub values: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entrez_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ensembl_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub druggable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drug_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approved_drug_count: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GeneIdKind {
+    Ensembl,
+    Symbol,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ParsedTable {
+    columns: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+/// Parses a CSV or TSV table; a tab in the header line selects TSV.
+fn parse_table(content: &str) -> Result<ParsedTable, BioMcpError> {
+    let content = content.trim_start_matches('\u{feff}');
+    let header_line = content
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .ok_or_else(|| BioMcpError::InvalidArgument("Results file is empty".into()))?;
+    let delimiter = if header_line.contains('\t') {
+        b'\t'
+    } else {
+        b','
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+    let columns = reader
+        .headers()
+        .map_err(|err| BioMcpError::InvalidArgument(format!("Invalid results header: {err}")))?
+        .iter()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+
+    let mut rows = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let record = record.map_err(|err| {
+            BioMcpError::InvalidArgument(format!("Invalid results row {}: {err}", index + 2))
+        })?;
+        if record.iter().all(str::is_empty) {
+            continue;
+        }
+        if rows.len() == MAX_DEG_ROWS {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "Results file has more than {MAX_DEG_ROWS} rows; filter it (e.g. by padj) first"
+            )));
+        }
+        rows.push(record.iter().map(str::to_string).collect::<Vec<_>>());
+    }
+
+    // R's write.table omits the row-name header, leaving one fewer column
+    // name than fields; name that leading column "" like write.csv does.
+    let mut columns = columns;
+    if let Some(width) = rows.iter().map(Vec::len).max()
+        && width == columns.len() + 1
+    {
+        columns.insert(0, String::new());
+    }
+    for row in &mut rows {
+        row.resize(columns.len(), String::new());
+    }
+    Ok(ParsedTable { columns, rows })
+}
+
+/// Picks the gene column by case-insensitive name, else the first column.
+fn gene_column_index(columns: &[String], requested: Option<&str>) -> Result<usize, BioMcpError> {
+    let Some(requested) = requested.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Ok(0);
+    };
+    columns
+        .iter()
+        .position(|column| column.eq_ignore_ascii_case(requested))
+        .ok_or_else(|| {
+            let available = columns
+                .iter()
+                .map(|column| {
+                    if column.is_empty() {
+                        "(unnamed)".to_string()
+                    } else {
+                        column.clone()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            BioMcpError::InvalidArgument(format!(
+                "Column '{requested}' not found. Available columns: {available}"
+            ))
+        })
+}
+
+fn find_column(columns: &[String], candidates: &[&str]) -> Option<String> {
+    columns
+        .iter()
+        .find(|column| {
+            candidates
+                .iter()
+                .any(|candidate| column.eq_ignore_ascii_case(candidate))
+        })
+        .cloned()
+}
+
+/// Classifies a gene identifier, stripping Ensembl version suffixes
+/// (`ENSG00000141510.17` -> `ENSG00000141510`).
+fn classify_gene_id(value: &str) -> Option<(GeneIdKind, String)> {
+    let value = value.trim().trim_matches('"');
+    if value.is_empty() || value.eq_ignore_ascii_case("NA") {
+        return None;
+    }
+    let upper = value.to_ascii_uppercase();
+    let base = upper
+        .split_once('.')
+        .map_or(upper.as_str(), |(base, _)| base);
+    if let Some(digits) = base.strip_prefix("ENSG")
+        && !digits.is_empty()
+        && digits.chars().all(|ch| ch.is_ascii_digit())
+    {
+        return Some((GeneIdKind::Ensembl, base.to_string()));
+    }
+    Some((GeneIdKind::Symbol, upper))
+}
+
+fn id_type_label(kinds: &HashSet<GeneIdKind>) -> &'static str {
+    match (
+        kinds.contains(&GeneIdKind::Ensembl),
+        kinds.contains(&GeneIdKind::Symbol),
+    ) {
+        (true, true) => "mixed",
+        (true, false) => "ensembl",
+        _ => "symbol",
+    }
+}
+
+/// Keeps the first hit per query term; later hits are lower-scoring matches.
+fn index_hits(hits: Vec<MyGeneQueryManyHit>) -> HashMap<String, MyGeneQueryManyHit> {
+    let mut out = HashMap::new();
+    for hit in hits {
+        if hit.notfound {
+            continue;
+        }
+        let Some(query) = hit
+            .query
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+        else {
+            continue;
+        };
+        out.entry(query.to_ascii_uppercase()).or_insert(hit);
+    }
+    out
+}
+
+async fn lookup(
+    client: &MyGeneClient,
+    terms: Vec<String>,
+    scopes: &str,
+) -> Result<HashMap<String, MyGeneQueryManyHit>, BioMcpError> {
+    let mut hits = Vec::new();
+    for chunk in terms.chunks(MYGENE_QUERY_CHUNK) {
+        hits.extend(client.query_many(chunk, scopes).await?);
+    }
+    Ok(index_hits(hits))
+}
+
+fn clean(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn annotate_row(row: &mut DegRow, hit: &MyGeneQueryManyHit) {
+    row.symbol = clean(hit.symbol.clone());
+    row.entrez_id = hit.entrezgene.as_ref().map(StringOrU64::as_string);
+    if row.ensembl_id.is_none() {
+        row.ensembl_id = hit.ensembl.as_ref().and_then(|field| field.gene()).cloned();
+    }
+    row.name = clean(hit.name.clone());
+    row.summary = clean(hit.summary.clone());
+}
+
+/// Resolves gene symbols, Ensembl IDs, or Entrez IDs to distinct Entrez IDs
+/// in input order; the second list holds inputs MyGene.info could not map.
+pub(crate) async fn entrez_ids(
+    terms: &[String],
+) -> Result<(Vec<String>, Vec<String>), BioMcpError> {
+    let mut classified = Vec::with_capacity(terms.len());
+    let mut ensembl_terms = Vec::new();
+    let mut symbol_terms = Vec::new();
+    for input in terms {
+        let input = input.trim();
+        if !input.is_empty() && input.chars().all(|ch| ch.is_ascii_digit()) {
+            classified.push((input.to_string(), None));
+            continue;
+        }
+        let Some((kind, term)) = classify_gene_id(input) else {
+            continue;
+        };
+        match kind {
+            GeneIdKind::Ensembl => ensembl_terms.push(term.clone()),
+            GeneIdKind::Symbol => symbol_terms.push(term.clone()),
+        }
+        classified.push((input.to_string(), Some((kind, term))));
+    }
+    ensembl_terms.sort();
+    ensembl_terms.dedup();
+    symbol_terms.sort();
+    symbol_terms.dedup();
+
+    let client = MyGeneClient::new()?;
+    let ensembl_hits = lookup(&client, ensembl_terms, "ensembl.gene").await?;
+    let symbol_hits = lookup(&client, symbol_terms, "symbol,alias").await?;
+
+    let mut seen = HashSet::new();
+    let mut ids = Vec::new();
+    let mut unresolved = Vec::new();
+    for (input, classified) in classified {
+        let id = match classified {
+            None => Some(input.clone()),
+            Some((kind, term)) => match kind {
+                GeneIdKind::Ensembl => ensembl_hits.get(&term),
+                GeneIdKind::Symbol => symbol_hits.get(&term),
+            }
+            .and_then(|hit| hit.entrezgene.as_ref())
+            .map(StringOrU64::as_string),
+        };
+        match id {
+            Some(id) => {
+                if seen.insert(id.clone()) {
+                    ids.push(id);
+                }
+            }
+            None => unresolved.push(input),
+        }
+    }
+    Ok((ids, unresolved))
+}
+
+/// Annotates a differential-expression results table read from `source`.
+pub async fn annotate_deg(
+    content: &str,
+    source: &str,
+    gene_column: Option<&str>,
+    druggable: bool,
+) -> Result<DegAnnotation, BioMcpError> {
+    let table = parse_table(content)?;
+    if table.rows.is_empty() {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "No data rows found in {source}"
+        )));
+    }
+    let column = gene_column_index(&table.columns, gene_column)?;
+
+    let mut kinds = HashSet::new();
+    let mut ensembl_terms = Vec::new();
+    let mut symbol_terms = Vec::new();
+    let mut seen = HashSet::new();
+    let mut rows = Vec::with_capacity(table.rows.len());
+    for values in table.rows {
+        let input = values.get(column).cloned().unwrap_or_default();
+        let classified = classify_gene_id(&input);
+        if let Some((kind, term)) = &classified {
+            kinds.insert(*kind);
+            if seen.insert(term.clone()) {
+                match kind {
+                    GeneIdKind::Ensembl => ensembl_terms.push(term.clone()),
+                    GeneIdKind::Symbol => symbol_terms.push(term.clone()),
+                }
+            }
+        }
+        rows.push((
+            classified,
+            DegRow {
+                input,
+                values,
+                symbol: None,
+                entrez_id: None,
+                ensembl_id: None,
+                name: None,
+                summary: None,
+                druggable: None,
+                drug_count: None,
+                approved_drug_count: None,
+            },
+        ));
+    }
+    if kinds.is_empty() {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Column '{}' has no gene identifiers; pass --gene-column",
+            table.columns[column]
+        )));
+    }
+
+    let client = MyGeneClient::new()?;
+    let ensembl_hits = lookup(&client, ensembl_terms, "ensembl.gene").await?;
+    let symbol_hits = lookup(&client, symbol_terms, "symbol,alias").await?;
+
+    let mut resolved = 0;
+    for (classified, row) in &mut rows {
+        let Some((kind, term)) = classified else {
+            continue;
+        };
+        let hit = match kind {
+            GeneIdKind::Ensembl => {
+                row.ensembl_id = Some(term.clone());
+                ensembl_hits.get(term.as_str())
+            }
+            GeneIdKind::Symbol => symbol_hits.get(term.as_str()),
+        };
+        if let Some(hit) = hit {
+            annotate_row(row, hit);
+            resolved += 1;
+        }
+    }
+    let mut rows = rows.into_iter().map(|(_, row)| row).collect::<Vec<_>>();
+
+    if druggable {
+        let mut symbols = rows
+            .iter()
+            .filter_map(|row| row.symbol.as_deref())
+            .filter(|symbol| crate::sources::is_valid_gene_symbol(symbol))
+            .map(str::to_ascii_uppercase)
+            .collect::<Vec<_>>();
+        symbols.sort();
+        symbols.dedup();
+
+        let dgidb = DgidbClient::new()?;
+        let mut counts = HashMap::new();
+        for chunk in symbols.chunks(DGIDB_QUERY_CHUNK) {
+            counts.extend(dgidb.gene_drug_counts(chunk).await?);
+        }
+        for row in &mut rows {
+            let Some(symbol) = row.symbol.as_deref() else {
+                continue;
+            };
+            let count = counts
+                .get(&symbol.to_ascii_uppercase())
+                .copied()
+                .unwrap_or_default();
+            row.druggable = Some(count.drugs > 0);
+            row.drug_count = Some(count.drugs);
+            row.approved_drug_count = Some(count.approved_drugs);
+        }
+    }
+
+    Ok(DegAnnotation {
+        source: source.to_string(),
+        gene_column: table.columns[column].clone(),
+        id_type: id_type_label(&kinds).to_string(),
+        fold_change_column: find_column(&table.columns, FOLD_CHANGE_COLUMNS),
+        adjusted_p_column: find_column(&table.columns, ADJUSTED_P_COLUMNS),
+        columns: table.columns,
+        resolved,
+        druggable_checked: druggable,
+        rows,
+    })
+}
+
+impl DegAnnotation {
+    /// Renders the original table plus annotation columns as TSV.
+    pub fn to_tsv(&self) -> String {
+        let mut header = self.columns.clone();
+        header.extend(ANNOTATION_COLUMNS.iter().map(|column| column.to_string()));
+        if self.druggable_checked {
+            header.extend(DRUGGABLE_COLUMNS.iter().map(|column| column.to_string()));
+        }
+        let mut out = header
+            .iter()
+            .map(|field| tsv_field(field))
+            .collect::<Vec<_>>()
+            .join("\t");
+        out.push('\n');
+
+        for row in &self.rows {
+            let mut fields = row.values.clone();
+            fields.extend([
+                row.symbol.clone().unwrap_or_default(),
+                row.entrez_id.clone().unwrap_or_default(),
+                row.ensembl_id.clone().unwrap_or_default(),
+                row.name.clone().unwrap_or_default(),
+                row.summary.clone().unwrap_or_default(),
+            ]);
+            if self.druggable_checked {
+                fields.extend([
+                    row.druggable
+                        .map(|value| value.to_string())
+                        .unwrap_or_default(),
+                    row.drug_count
+                        .map(|value| value.to_string())
+                        .unwrap_or_default(),
+                    row.approved_drug_count
+                        .map(|value| value.to_string())
+                        .unwrap_or_default(),
+                ]);
+            }
+            let line = fields
+                .iter()
+                .map(|field| tsv_field(field))
+                .collect::<Vec<_>>()
+                .join("\t");
+            out.push_str(&line);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests;
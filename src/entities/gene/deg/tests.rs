@@ -0,0 +1,123 @@
+//! Tests for differential-expression table annotation.
+
+use super::*;
+
+fn hit(value: serde_json::Value) -> MyGeneQueryManyHit {
+    serde_json::from_value(value).expect("query hit")
+}
+
+#[test]
+fn parse_table_reads_r_row_names_and_pads_short_rows() {
+    let table = parse_table(
+        "\u{feff}baseMean\tlog2FoldChange\tpadj\nENSG00000141510.17\t812.4\t-1.9\t1e-8\nENSG00000157764\t90.1\t0.4\n",
+    )
+    .expect("tsv");
+    assert_eq!(
+        table.columns,
+        vec!["", "baseMean", "log2FoldChange", "padj"]
+    );
+    assert_eq!(
+        table.rows[0],
+        vec!["ENSG00000141510.17", "812.4", "-1.9", "1e-8"]
+    );
+    assert_eq!(table.rows[1], vec!["ENSG00000157764", "90.1", "0.4", ""]);
+
+    let csv = parse_table("\"\",\"logFC\",\"adj.P.Val\"\n\"TP53\",2.1,0.001\n\n").expect("csv");
+    assert_eq!(csv.columns, vec!["", "logFC", "adj.P.Val"]);
+    assert_eq!(csv.rows, vec![vec!["TP53", "2.1", "0.001"]]);
+
+    assert!(parse_table("  \n").is_err());
+}
+
+#[test]
+fn gene_column_index_matches_case_insensitively_and_lists_columns() {
+    let columns = vec![String::new(), "gene_id".to_string(), "padj".to_string()];
+    assert_eq!(gene_column_index(&columns, None).unwrap(), 0);
+    assert_eq!(gene_column_index(&columns, Some("GENE_ID")).unwrap(), 1);
+
+    let err = gene_column_index(&columns, Some("symbol")).expect_err("missing");
+    assert!(
+        err.to_string()
+            .contains("Available columns: (unnamed), gene_id, padj")
+    );
+}
+
+#[test]
+fn classify_gene_id_strips_ensembl_versions_and_skips_missing_values() {
+    assert_eq!(
+        classify_gene_id("ENSG00000141510.17"),
+        Some((GeneIdKind::Ensembl, "ENSG00000141510".to_string()))
+    );
+    assert_eq!(
+        classify_gene_id("tp53"),
+        Some((GeneIdKind::Symbol, "TP53".to_string()))
+    );
+    assert_eq!(
+        classify_gene_id("ENSGR0000001"),
+        Some((GeneIdKind::Symbol, "ENSGR0000001".to_string()))
+    );
+    assert_eq!(classify_gene_id("NA"), None);
+    assert_eq!(classify_gene_id(" "), None);
+
+    let kinds = HashSet::from([GeneIdKind::Ensembl, GeneIdKind::Symbol]);
+    assert_eq!(id_type_label(&kinds), "mixed");
+}
+
+#[test]
+fn index_hits_keeps_first_match_and_drops_misses() {
+    let hits = index_hits(vec![
+        hit(serde_json::json!({"query": "MLL", "symbol": "KMT2A", "entrezgene": 4297})),
+        hit(serde_json::json!({"query": "MLL", "symbol": "KMT2A-AS1"})),
+        hit(serde_json::json!({"query": "FAKE1", "notfound": true})),
+    ]);
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits["MLL"].symbol.as_deref(), Some("KMT2A"));
+}
+
+#[test]
+fn to_tsv_appends_annotation_and_druggability_columns() {
+    let mut row = DegRow {
+        input: "ENSG00000146648".into(),
+        values: vec!["ENSG00000146648".into(), "3.2".into()],
+        symbol: None,
+        entrez_id: None,
+        ensembl_id: Some("ENSG00000146648".into()),
+        name: None,
+        summary: None,
+        druggable: Some(true),
+        drug_count: Some(12),
+        approved_drug_count: Some(9),
+    };
+    annotate_row(
+        &mut row,
+        &hit(serde_json::json!({
+            "query": "ENSG00000146648",
+            "symbol": "EGFR",
+            "name": "epidermal growth factor receptor",
+            "summary": "Receptor\ttyrosine kinase.\n",
+            "entrezgene": 1956
+        })),
+    );
+    let table = DegAnnotation {
+        source: "results.csv".into(),
+        gene_column: String::new(),
+        id_type: "ensembl".into(),
+        columns: vec![String::new(), "log2FoldChange".into()],
+        fold_change_column: Some("log2FoldChange".into()),
+        adjusted_p_column: None,
+        resolved: 1,
+        druggable_checked: true,
+        rows: vec![row],
+    };
+
+    let tsv = table.to_tsv();
+    let lines = tsv.lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines[0],
+        "\tlog2FoldChange\tsymbol\tentrez_id\tensembl_id\tname\tsummary\tdruggable\tdrug_count\tapproved_drug_count"
+    );
+    assert_eq!(
+        lines[1],
+        "ENSG00000146648\t3.2\tEGFR\t1956\tENSG00000146648\tepidermal growth factor receptor\tReceptor tyrosine kinase.\ttrue\t12\t9"
+    );
+}
@@ -0,0 +1,153 @@
+//! Starter reading list for the gene `key-papers` section.
+//!
+//! Europe PMC supplies two pools of MEDLINE articles with the symbol in the
+//! title, both ranked by citation count: all-time papers (the seminal work)
+//! and reviews from the last few years. The pools are merged and deduplicated
+//! into a short list rather than a search page.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+use crate::sources::europepmc::{
+    EuropePmcClient, EuropePmcResult, EuropePmcSearchResponse, EuropePmcSort,
+};
+
+const KEY_PAPERS_LIMIT: usize = 5;
+const SEMINAL_SLOTS: usize = 3;
+const RECENT_REVIEW_YEARS: i32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GeneKeyPaperKind {
+    /// Among the most-cited papers with the symbol in the title.
+    Seminal,
+    /// A highly cited review published in the last few years.
+    RecentReview,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GeneKeyPaper {
+    pub kind: GeneKeyPaperKind,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pmid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub journal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub citations: Option<u64>,
+}
+
+fn seminal_query(symbol: &str) -> String {
+    format!("TITLE:\"{symbol}\" AND SRC:MED")
+}
+
+fn recent_review_query(symbol: &str, current_year: i32) -> String {
+    format!(
+        "TITLE:\"{symbol}\" AND SRC:MED AND PUB_TYPE:\"review\" AND PUB_YEAR:[{} TO {current_year}]",
+        current_year - RECENT_REVIEW_YEARS
+    )
+}
+
+fn citation_count(value: Option<&serde_json::Value>) -> Option<u64> {
+    match value? {
+        serde_json::Value::Number(n) => n.as_u64(),
+        serde_json::Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn clean(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn key_paper(hit: EuropePmcResult, kind: GeneKeyPaperKind) -> Option<GeneKeyPaper> {
+    let title = clean(hit.title)?;
+    Some(GeneKeyPaper {
+        kind,
+        title: title.trim_end_matches('.').to_string(),
+        pmid: clean(hit.pmid),
+        doi: clean(hit.doi),
+        journal: clean(hit.journal_title),
+        year: clean(hit.pub_year),
+        citations: citation_count(hit.cited_by_count.as_ref()),
+    })
+}
+
+fn key_paper_rows(response: EuropePmcSearchResponse, kind: GeneKeyPaperKind) -> Vec<GeneKeyPaper> {
+    response
+        .result_list
+        .map(|list| list.result)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|hit| key_paper(hit, kind))
+        .collect()
+}
+
+fn same_paper(a: &GeneKeyPaper, b: &GeneKeyPaper) -> bool {
+    match (&a.pmid, &b.pmid) {
+        (Some(left), Some(right)) => left == right,
+        _ => a.title.eq_ignore_ascii_case(&b.title),
+    }
+}
+
+fn push_unique(out: &mut Vec<GeneKeyPaper>, paper: GeneKeyPaper, limit: usize) {
+    if out.len() < limit && !out.iter().any(|seen| same_paper(seen, &paper)) {
+        out.push(paper);
+    }
+}
+
+/// Fills up to [`SEMINAL_SLOTS`] with seminal papers, then recent reviews,
+/// then tops up from whichever pool still has unused rows.
+fn select_key_papers(
+    seminal: Vec<GeneKeyPaper>,
+    reviews: Vec<GeneKeyPaper>,
+    limit: usize,
+) -> Vec<GeneKeyPaper> {
+    let mut out = Vec::new();
+    let mut seminal = seminal.into_iter();
+    for paper in seminal.by_ref().take(SEMINAL_SLOTS) {
+        push_unique(&mut out, paper, limit);
+    }
+    for paper in reviews {
+        push_unique(&mut out, paper, limit);
+    }
+    for paper in seminal {
+        push_unique(&mut out, paper, limit);
+    }
+    out
+}
+
+pub(super) async fn fetch_key_papers(symbol: &str) -> Result<Vec<GeneKeyPaper>, BioMcpError> {
+    let client = EuropePmcClient::new()?;
+    let current_year = time::OffsetDateTime::now_utc().year();
+    let (seminal, reviews) = tokio::try_join!(
+        client.search_query_with_sort(
+            &seminal_query(symbol),
+            1,
+            KEY_PAPERS_LIMIT,
+            EuropePmcSort::Citations,
+        ),
+        client.search_query_with_sort(
+            &recent_review_query(symbol, current_year),
+            1,
+            KEY_PAPERS_LIMIT,
+            EuropePmcSort::Citations,
+        ),
+    )?;
+
+    Ok(select_key_papers(
+        key_paper_rows(seminal, GeneKeyPaperKind::Seminal),
+        key_paper_rows(reviews, GeneKeyPaperKind::RecentReview),
+        KEY_PAPERS_LIMIT,
+    ))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,67 @@
+//! Tests for gene key-paper selection.
+
+use super::*;
+
+fn paper(kind: GeneKeyPaperKind, pmid: &str, title: &str) -> GeneKeyPaper {
+    GeneKeyPaper {
+        kind,
+        title: title.to_string(),
+        pmid: Some(pmid.to_string()),
+        doi: None,
+        journal: None,
+        year: None,
+        citations: None,
+    }
+}
+
+#[test]
+fn queries_restrict_to_title_matches_and_recent_reviews() {
+    assert_eq!(seminal_query("BRAF"), "TITLE:\"BRAF\" AND SRC:MED");
+    assert_eq!(
+        recent_review_query("BRAF", 2026),
+        "TITLE:\"BRAF\" AND SRC:MED AND PUB_TYPE:\"review\" AND PUB_YEAR:[2023 TO 2026]"
+    );
+}
+
+#[test]
+fn key_paper_reads_europe_pmc_hit_fields() {
+    let hit: EuropePmcResult = serde_json::from_value(serde_json::json!({
+        "title": "Mutations of the BRAF gene in human cancer.",
+        "pmid": "12068308",
+        "doi": "10.1038/nature00766",
+        "journalTitle": "Nature",
+        "pubYear": "2002",
+        "citedByCount": 9876
+    }))
+    .expect("hit should deserialize");
+
+    let row = key_paper(hit, GeneKeyPaperKind::Seminal).expect("row");
+    assert_eq!(row.title, "Mutations of the BRAF gene in human cancer");
+    assert_eq!(row.pmid.as_deref(), Some("12068308"));
+    assert_eq!(row.journal.as_deref(), Some("Nature"));
+    assert_eq!(row.year.as_deref(), Some("2002"));
+    assert_eq!(row.citations, Some(9876));
+}
+
+#[test]
+fn select_key_papers_mixes_pools_and_drops_duplicates() {
+    let seminal = (1..=5)
+        .map(|n| {
+            paper(
+                GeneKeyPaperKind::Seminal,
+                &n.to_string(),
+                &format!("Seminal {n}"),
+            )
+        })
+        .collect();
+    let reviews = vec![
+        paper(GeneKeyPaperKind::RecentReview, "2", "Seminal 2"),
+        paper(GeneKeyPaperKind::RecentReview, "20", "Review 20"),
+    ];
+
+    let pmids = select_key_papers(seminal, reviews, KEY_PAPERS_LIMIT)
+        .into_iter()
+        .map(|row| row.pmid.unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(pmids, vec!["1", "2", "3", "20", "4"]);
+}
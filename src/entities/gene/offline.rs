@@ -0,0 +1,94 @@
+//! Gene cards served from an offline snapshot bundle under `--offline`.
+
+use crate::entities::SearchPage;
+use crate::error::BioMcpError;
+use crate::transform;
+use crate::utils::snapshot::{SnapshotEntity, SnapshotStore, lookup_key, offline_unavailable};
+
+use super::{Gene, GeneSearchFilters, GeneSearchResult, GeneSpecies};
+
+pub(super) fn get(
+    store: &SnapshotStore,
+    symbol: &str,
+    sections: &[String],
+) -> Result<Gene, BioMcpError> {
+    let gene: Gene = store.get(SnapshotEntity::Gene, symbol, sections)?;
+    transform::fields::apply("gene", gene)
+}
+
+/// Snapshot bundles are built from human gene cards only.
+pub(super) fn non_human_error(species: GeneSpecies) -> BioMcpError {
+    offline_unavailable(format!(
+        "Offline gene snapshots hold human genes only; --species {species} needs MyGene.info."
+    ))
+}
+
+fn matches_query(gene: &Gene, query: &str) -> bool {
+    let query = lookup_key(query);
+    lookup_key(&gene.symbol) == query
+        || lookup_key(&gene.name).contains(&query)
+        || gene.aliases.iter().any(|alias| lookup_key(alias) == query)
+}
+
+fn search_result(gene: Gene) -> GeneSearchResult {
+    GeneSearchResult {
+        symbol: gene.symbol,
+        name: gene.name,
+        entrez_id: gene.entrez_id,
+        genomic_coordinates: gene.genomic_coordinates,
+        uniprot_id: gene.uniprot_id,
+        omim_id: gene.omim_id,
+        species: None,
+    }
+}
+
+/// Matches the query against stored symbols, aliases, and names. The
+/// structured filters need MyGene.info and are refused rather than ignored.
+pub(super) fn search_page(
+    store: &SnapshotStore,
+    filters: &GeneSearchFilters,
+    limit: usize,
+    offset: usize,
+) -> Result<SearchPage<GeneSearchResult>, BioMcpError> {
+    let unsupported = [
+        ("--type", &filters.gene_type),
+        ("--chromosome", &filters.chromosome),
+        ("--region", &filters.region),
+        ("--pathway", &filters.pathway),
+        ("--go", &filters.go_term),
+    ]
+    .into_iter()
+    .filter(|(_, value)| value.as_deref().is_some_and(|v| !v.trim().is_empty()))
+    .map(|(flag, _)| flag)
+    .collect::<Vec<_>>();
+    if !unsupported.is_empty() {
+        return Err(offline_unavailable(format!(
+            "Offline gene search matches stored symbols, aliases, and names only; {} cannot be applied.",
+            unsupported.join(", ")
+        )));
+    }
+    let query = filters
+        .query
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| {
+            BioMcpError::InvalidArgument(
+                "Query is required. Example: biomcp search gene -q BRAF".into(),
+            )
+        })?;
+
+    let matches = store
+        .all::<Gene>(SnapshotEntity::Gene)?
+        .into_iter()
+        .filter(|gene| matches_query(gene, query))
+        .collect::<Vec<_>>();
+    let total = matches.len();
+    let results = matches
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .map(search_result)
+        .collect();
+    Ok(SearchPage::offset(results, Some(total)))
+}
@@ -0,0 +1,132 @@
+//! Model-organism orthologs for the gene `orthologs` section.
+//!
+//! Ensembl Compara supplies the homology pairs for mouse, rat, and zebrafish;
+//! a batch lookup then fills in the ortholog symbols, which the homology
+//! endpoint only reports as stable IDs.
+
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::error::BioMcpError;
+use crate::sources::ensembl::{EnsemblClient, EnsemblHomology, EnsemblLookup};
+
+use super::GeneSpecies;
+
+const ORTHOLOG_SPECIES: [GeneSpecies; 3] =
+    [GeneSpecies::Mouse, GeneSpecies::Rat, GeneSpecies::Zebrafish];
+const ENSEMBL_WEB: &str = "https://www.ensembl.org";
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GeneOrtholog {
+    /// `--species` value for the ortholog, e.g. `mouse`.
+    pub species: String,
+    pub taxid: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    pub ensembl_id: String,
+    /// Compara relationship with the `ortholog_` prefix dropped, e.g. `one2one`.
+    pub orthology_type: String,
+    /// Percent of the ortholog's protein identical to the human protein.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_identity: Option<f64>,
+    /// Percent of the human protein identical to the ortholog's protein.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_identity: Option<f64>,
+    pub url: String,
+}
+
+fn ensembl_gene_url(production_name: &str, id: &str) -> String {
+    let mut chars = production_name.chars();
+    let species = chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default();
+    format!("{ENSEMBL_WEB}/{species}/Gene/Summary?g={id}")
+}
+
+fn ortholog_row(homology: EnsemblHomology) -> Option<GeneOrtholog> {
+    let target = homology.target;
+    let species = match target.taxon_id {
+        Some(taxid) => GeneSpecies::from_taxid(taxid),
+        None => ORTHOLOG_SPECIES
+            .into_iter()
+            .find(|species| species.ensembl_name() == Some(target.species.as_str()))?,
+    };
+    if !ORTHOLOG_SPECIES.contains(&species) || target.id.trim().is_empty() {
+        return None;
+    }
+    Some(GeneOrtholog {
+        species: species.to_string(),
+        taxid: species.taxid(),
+        symbol: None,
+        url: ensembl_gene_url(&target.species, &target.id),
+        ensembl_id: target.id,
+        orthology_type: homology
+            .kind
+            .strip_prefix("ortholog_")
+            .unwrap_or(&homology.kind)
+            .to_string(),
+        target_identity: target.perc_id,
+        query_identity: homology.source.perc_id,
+    })
+}
+
+/// Keeps the fixed species order and puts the closest ortholog first within
+/// each species.
+fn ortholog_rows(
+    homologies: Vec<EnsemblHomology>,
+    symbols: &HashMap<String, EnsemblLookup>,
+) -> Vec<GeneOrtholog> {
+    let mut rows = homologies
+        .into_iter()
+        .filter_map(ortholog_row)
+        .map(|mut row| {
+            row.symbol = symbols
+                .get(&row.ensembl_id)
+                .and_then(|lookup| lookup.display_name.clone())
+                .filter(|name| !name.trim().is_empty());
+            row
+        })
+        .collect::<Vec<_>>();
+    let rank = |row: &GeneOrtholog| {
+        ORTHOLOG_SPECIES
+            .iter()
+            .position(|species| species.taxid() == row.taxid)
+            .unwrap_or(ORTHOLOG_SPECIES.len())
+    };
+    rows.sort_by(|a, b| {
+        rank(a).cmp(&rank(b)).then_with(|| {
+            b.target_identity
+                .unwrap_or(0.0)
+                .total_cmp(&a.target_identity.unwrap_or(0.0))
+        })
+    });
+    rows
+}
+
+/// `gene` is the human Ensembl gene ID when known, otherwise the symbol.
+pub(super) async fn fetch_orthologs(gene: &str) -> Result<Vec<GeneOrtholog>, BioMcpError> {
+    let client = EnsemblClient::new()?;
+    let targets = ORTHOLOG_SPECIES
+        .iter()
+        .filter_map(|species| species.ensembl_name())
+        .collect::<Vec<_>>();
+    let homologies = client.human_orthologues(gene, &targets).await?;
+    let ids = homologies
+        .iter()
+        .map(|homology| homology.target.id.clone())
+        .filter(|id| !id.trim().is_empty())
+        .collect::<Vec<_>>();
+    // Symbols are cosmetic; the IDs and links still stand without them.
+    let symbols = client.lookup_many(&ids).await.unwrap_or_else(|err| {
+        warn!(gene, "Ensembl symbol lookup failed for orthologs: {err}");
+        HashMap::new()
+    });
+    Ok(ortholog_rows(homologies, &symbols))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,107 @@
+//! Tests for gene ortholog rows.
+
+use super::*;
+
+fn homology(kind: &str, species: &str, taxid: u32, id: &str, perc_id: f64) -> EnsemblHomology {
+    serde_json::from_value(serde_json::json!({
+        "type": kind,
+        "source": {
+            "id": "ENSG00000157764",
+            "species": "homo_sapiens",
+            "taxon_id": 9606,
+            "perc_id": 94.5
+        },
+        "target": {
+            "id": id,
+            "species": species,
+            "taxon_id": taxid,
+            "perc_id": perc_id
+        }
+    }))
+    .expect("homology should deserialize")
+}
+
+fn lookup(id: &str, symbol: &str) -> (String, EnsemblLookup) {
+    let row = serde_json::from_value(serde_json::json!({"id": id, "display_name": symbol}))
+        .expect("lookup should deserialize");
+    (id.to_string(), row)
+}
+
+#[test]
+fn ortholog_rows_order_by_species_then_identity_and_attach_symbols() {
+    let homologies = vec![
+        homology(
+            "ortholog_one2many",
+            "danio_rerio",
+            7955,
+            "ENSDARG00000017661",
+            71.2,
+        ),
+        homology(
+            "ortholog_one2many",
+            "danio_rerio",
+            7955,
+            "ENSDARG00000095235",
+            80.4,
+        ),
+        homology(
+            "ortholog_one2one",
+            "rattus_norvegicus",
+            10116,
+            "ENSRNOG00000010957",
+            95.9,
+        ),
+        homology(
+            "ortholog_one2one",
+            "mus_musculus",
+            10090,
+            "ENSMUSG00000002413",
+            96.4,
+        ),
+    ];
+    let symbols = HashMap::from([
+        lookup("ENSMUSG00000002413", "Braf"),
+        lookup("ENSDARG00000017661", "braf"),
+    ]);
+
+    let rows = ortholog_rows(homologies, &symbols);
+    let ids = rows
+        .iter()
+        .map(|row| row.ensembl_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ids,
+        vec![
+            "ENSMUSG00000002413",
+            "ENSRNOG00000010957",
+            "ENSDARG00000095235",
+            "ENSDARG00000017661",
+        ]
+    );
+    assert_eq!(rows[0].species, "mouse");
+    assert_eq!(rows[0].symbol.as_deref(), Some("Braf"));
+    assert_eq!(rows[0].orthology_type, "one2one");
+    assert_eq!(rows[0].target_identity, Some(96.4));
+    assert_eq!(rows[0].query_identity, Some(94.5));
+    assert_eq!(
+        rows[0].url,
+        "https://www.ensembl.org/Mus_musculus/Gene/Summary?g=ENSMUSG00000002413"
+    );
+    assert_eq!(rows[1].symbol, None);
+    assert_eq!(rows[2].orthology_type, "one2many");
+}
+
+#[test]
+fn ortholog_rows_drop_species_outside_the_model_organism_set() {
+    let rows = ortholog_rows(
+        vec![homology(
+            "ortholog_one2one",
+            "drosophila_melanogaster",
+            7227,
+            "FBgn0003079",
+            42.0,
+        )],
+        &HashMap::new(),
+    );
+    assert!(rows.is_empty());
+}
@@ -0,0 +1,424 @@
+//! Stored gene cards and field-level diffs for periodic re-review.
+//!
+//! `diff gene` saves each card it fetches as JSON under
+//! `<cache_root>/snapshots/gene/<SYMBOL>/<id>.json`, where the id is the UTC
+//! capture time (`20261016T093000Z`). A later run compares a fresh card with a
+//! stored one and reports what moved: drugs, ClinVar counts, ClinGen validity,
+//! CIViC counts, pathways, and names. Sections that were unavailable on either
+//! side are skipped rather than reported as removed.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::error::BioMcpError;
+
+use super::Gene;
+
+const SNAPSHOT_DIR: &str = "snapshots";
+const SNAPSHOT_ENTITY_DIR: &str = "gene";
+/// Oldest snapshots for a gene are pruned past this many.
+const MAX_SNAPSHOTS_PER_GENE: usize = 50;
+
+/// Sections fetched for every diff so snapshots stay comparable.
+pub const GENE_DIFF_SECTIONS: &[&str] = &[
+    "druggability",
+    "clinvar-summary",
+    "clingen",
+    "civic",
+    "pathways",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneSnapshot {
+    pub id: String,
+    /// RFC 3339 timestamp, UTC, second precision.
+    pub taken_at: String,
+    pub gene: Gene,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeneFieldChange {
+    pub field: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneDiff {
+    pub symbol: String,
+    /// Snapshot compared against; `None` when this run recorded the first one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_snapshot: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_taken_at: Option<String>,
+    pub current_taken_at: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<GeneFieldChange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saved_snapshot: Option<String>,
+}
+
+fn snapshot_id(taken_at: &str) -> String {
+    taken_at
+        .chars()
+        .filter(|ch| !matches!(ch, '-' | ':'))
+        .collect()
+}
+
+fn snapshot_dir(root: &Path, symbol: &str) -> Result<PathBuf, BioMcpError> {
+    let symbol = symbol.trim().to_ascii_uppercase();
+    if symbol.is_empty()
+        || !symbol
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'))
+        || symbol.starts_with('.')
+    {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Cannot store snapshots for gene symbol '{symbol}'"
+        )));
+    }
+    Ok(snapshot_root(root).join(symbol))
+}
+
+/// Snapshot ids in `dir`, oldest first.
+pub(crate) fn list_snapshots(dir: &Path) -> Result<Vec<String>, BioMcpError> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut ids = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                return None;
+            }
+            path.file_stem()?.to_str().map(str::to_string)
+        })
+        .collect::<Vec<_>>();
+    ids.sort();
+    Ok(ids)
+}
+
+fn load_snapshot(dir: &Path, id: &str) -> Result<GeneSnapshot, BioMcpError> {
+    let path = dir.join(format!("{id}.json"));
+    let content = std::fs::read_to_string(&path).map_err(|err| {
+        BioMcpError::Io(std::io::Error::new(
+            err.kind(),
+            format!("failed to read {}: {}", path.display(), err),
+        ))
+    })?;
+    serde_json::from_str(&content).map_err(|err| {
+        BioMcpError::InvalidArgument(format!(
+            "Unreadable gene snapshot {}: {err}",
+            path.display()
+        ))
+    })
+}
+
+fn save_snapshot(dir: &Path, snapshot: &GeneSnapshot) -> Result<(), BioMcpError> {
+    std::fs::create_dir_all(dir)?;
+    let content = serde_json::to_string(snapshot).map_err(|err| {
+        BioMcpError::InvalidArgument(format!("failed to encode gene snapshot: {err}"))
+    })?;
+    let path = dir.join(format!("{}.json", snapshot.id));
+    let staging = path.with_extension("json.tmp");
+    std::fs::write(&staging, content)?;
+    std::fs::rename(&staging, &path)?;
+
+    let ids = list_snapshots(dir)?;
+    let excess = ids.len().saturating_sub(MAX_SNAPSHOTS_PER_GENE);
+    for id in &ids[..excess] {
+        if let Err(err) = std::fs::remove_file(dir.join(format!("{id}.json"))) {
+            warn!(id, "failed to prune gene snapshot: {err}");
+        }
+    }
+    Ok(())
+}
+
+fn snapshot_root(root: &Path) -> PathBuf {
+    root.join(SNAPSHOT_DIR).join(SNAPSHOT_ENTITY_DIR)
+}
+
+fn stored_in(root: &Path) -> Result<Vec<(String, String)>, BioMcpError> {
+    let entries = match std::fs::read_dir(snapshot_root(root)) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut out = Vec::new();
+    for entry in entries.filter_map(Result::ok) {
+        let Some(symbol) = entry.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+        if let Some(latest) = list_snapshots(&entry.path())?.pop() {
+            out.push((symbol, latest));
+        }
+    }
+    out.sort();
+    Ok(out)
+}
+
+/// Genes with stored snapshots, as `(symbol, latest snapshot id)` pairs.
+pub fn stored_snapshots() -> Result<Vec<(String, String)>, BioMcpError> {
+    stored_in(&crate::cache::resolve_cache_config()?.cache_root)
+}
+
+/// The latest stored snapshot for `symbol`, as JSON.
+pub fn latest_snapshot_json(symbol: &str) -> Result<Option<String>, BioMcpError> {
+    let dir = snapshot_dir(&crate::cache::resolve_cache_config()?.cache_root, symbol)?;
+    let Some(id) = list_snapshots(&dir)?.pop() else {
+        return Ok(None);
+    };
+    let path = dir.join(format!("{id}.json"));
+    Ok(Some(std::fs::read_to_string(path)?))
+}
+
+/// Picks the snapshot to compare against: the latest by default, an exact
+/// snapshot id, or the latest taken on or before a `YYYY[-MM[-DD]]` date.
+pub(crate) fn select_snapshot(
+    symbol: &str,
+    ids: &[String],
+    since: Option<&str>,
+) -> Result<Option<String>, BioMcpError> {
+    let Some(since) = since.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Ok(ids.last().cloned());
+    };
+    if ids.is_empty() {
+        return Ok(None);
+    }
+    if let Some(id) = ids.iter().find(|id| id.as_str() == since) {
+        return Ok(Some(id.clone()));
+    }
+
+    let date = crate::utils::date::validate_since(since).map_err(|_| {
+        BioMcpError::InvalidArgument(format!(
+            "--since expects a snapshot id or a YYYY-MM-DD date. Stored snapshots for {symbol}: {}",
+            ids.join(", ")
+        ))
+    })?;
+    let cutoff = date.replace('-', "");
+    ids.iter()
+        .rev()
+        .find(|id| id.get(..8).is_some_and(|day| day <= cutoff.as_str()))
+        .cloned()
+        .map(Some)
+        .ok_or_else(|| BioMcpError::NotFound {
+            entity: "gene snapshot".into(),
+            id: format!("{symbol} on or before {date}"),
+            suggestion: format!("Oldest stored snapshot: {}", ids[0]),
+        })
+}
+
+fn push_set_change(
+    out: &mut Vec<GeneFieldChange>,
+    field: &str,
+    before: BTreeSet<String>,
+    after: BTreeSet<String>,
+) {
+    let added = after.difference(&before).cloned().collect::<Vec<_>>();
+    let removed = before.difference(&after).cloned().collect::<Vec<_>>();
+    if added.is_empty() && removed.is_empty() {
+        return;
+    }
+    out.push(GeneFieldChange {
+        field: field.to_string(),
+        added,
+        removed,
+        before: None,
+        after: None,
+    });
+}
+
+fn push_value_change(
+    out: &mut Vec<GeneFieldChange>,
+    field: &str,
+    before: Option<String>,
+    after: Option<String>,
+) {
+    if before == after {
+        return;
+    }
+    out.push(GeneFieldChange {
+        field: field.to_string(),
+        added: Vec::new(),
+        removed: Vec::new(),
+        before,
+        after,
+    });
+}
+
+fn names<'a>(values: impl IntoIterator<Item = &'a String>) -> BTreeSet<String> {
+    values
+        .into_iter()
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Field-level changes from `previous` to `current`.
+pub fn diff_genes(previous: &Gene, current: &Gene) -> Vec<GeneFieldChange> {
+    let mut out = Vec::new();
+    push_value_change(
+        &mut out,
+        "name",
+        Some(previous.name.clone()),
+        Some(current.name.clone()),
+    );
+    push_set_change(
+        &mut out,
+        "aliases",
+        names(&previous.aliases),
+        names(&current.aliases),
+    );
+    push_set_change(
+        &mut out,
+        "clinical drugs",
+        names(&previous.clinical_drugs),
+        names(&current.clinical_drugs),
+    );
+    push_set_change(
+        &mut out,
+        "clinical diseases",
+        names(&previous.clinical_diseases),
+        names(&current.clinical_diseases),
+    );
+
+    if let (Some(before), Some(after)) = (&previous.druggability, &current.druggability) {
+        let drugs = |value: &crate::sources::dgidb::GeneDruggability| {
+            names(value.interactions.iter().map(|row| &row.drug))
+        };
+        push_set_change(&mut out, "drug interactions", drugs(before), drugs(after));
+    }
+
+    if let (Some(before), Some(after)) = (&previous.clinvar_summary, &current.clinvar_summary) {
+        push_value_change(
+            &mut out,
+            "ClinVar variants",
+            before.total_variants.map(|count| count.to_string()),
+            after.total_variants.map(|count| count.to_string()),
+        );
+        let labels = names(
+            before
+                .significance
+                .iter()
+                .chain(&after.significance)
+                .map(|row| &row.label),
+        );
+        for label in labels {
+            let count = |rows: &[super::GeneClinVarCount]| {
+                rows.iter()
+                    .find(|row| row.label == label)
+                    .map(|row| row.count.to_string())
+            };
+            push_value_change(
+                &mut out,
+                &format!("ClinVar {label}"),
+                count(&before.significance),
+                count(&after.significance),
+            );
+        }
+    }
+
+    if let (Some(before), Some(after)) = (&previous.clingen, &current.clingen) {
+        let validity = |value: &crate::sources::clingen::GeneClinGen| {
+            value
+                .validity
+                .iter()
+                .map(|row| format!("{} ({})", row.disease, row.classification))
+                .collect::<BTreeSet<_>>()
+        };
+        push_set_change(
+            &mut out,
+            "ClinGen validity",
+            validity(before),
+            validity(after),
+        );
+        push_value_change(
+            &mut out,
+            "ClinGen haploinsufficiency",
+            before.haploinsufficiency.clone(),
+            after.haploinsufficiency.clone(),
+        );
+    }
+
+    if let (Some(before), Some(after)) = (&previous.civic, &current.civic) {
+        push_value_change(
+            &mut out,
+            "CIViC evidence items",
+            Some(before.evidence_total_count.to_string()),
+            Some(after.evidence_total_count.to_string()),
+        );
+        push_value_change(
+            &mut out,
+            "CIViC assertions",
+            Some(before.assertion_total_count.to_string()),
+            Some(after.assertion_total_count.to_string()),
+        );
+    }
+
+    if let (Some(before), Some(after)) = (&previous.pathways, &current.pathways) {
+        push_set_change(
+            &mut out,
+            "pathways",
+            names(before.iter().map(|row| &row.name)),
+            names(after.iter().map(|row| &row.name)),
+        );
+    }
+    out
+}
+
+/// Fetches `symbol` and compares it with a stored snapshot, then stores the
+/// fresh card unless `save` is false.
+pub async fn diff(symbol: &str, since: Option<&str>, save: bool) -> Result<GeneDiff, BioMcpError> {
+    let sections = GENE_DIFF_SECTIONS
+        .iter()
+        .map(|section| section.to_string())
+        .collect::<Vec<_>>();
+    let gene = super::get(symbol, &sections).await?;
+    let root = crate::cache::resolve_cache_config()?.cache_root;
+    let dir = snapshot_dir(&root, &gene.symbol)?;
+
+    let ids = list_snapshots(&dir)?;
+    let previous = select_snapshot(&gene.symbol, &ids, since)?
+        .map(|id| load_snapshot(&dir, &id))
+        .transpose()?;
+
+    let taken_at = humantime::format_rfc3339_seconds(SystemTime::now()).to_string();
+    let current = GeneSnapshot {
+        id: snapshot_id(&taken_at),
+        taken_at,
+        gene,
+    };
+    let changes = previous
+        .as_ref()
+        .map(|previous| diff_genes(&previous.gene, &current.gene))
+        .unwrap_or_default();
+    if save {
+        save_snapshot(&dir, &current)?;
+    }
+
+    Ok(GeneDiff {
+        symbol: current.gene.symbol.clone(),
+        previous_snapshot: previous.as_ref().map(|row| row.id.clone()),
+        previous_taken_at: previous.map(|row| row.taken_at),
+        current_taken_at: current.taken_at,
+        changes,
+        saved_snapshot: save.then_some(current.id),
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,191 @@
+//! Tests for gene snapshot storage and diffing.
+
+use std::time::UNIX_EPOCH;
+
+use super::*;
+
+struct TempDirGuard {
+    path: PathBuf,
+}
+
+impl TempDirGuard {
+    fn new(label: &str) -> Self {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "biomcp-gene-snapshot-test-{label}-{}-{stamp}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&path).expect("temp dir should be created");
+        Self { path }
+    }
+}
+
+impl Drop for TempDirGuard {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+fn gene(value: serde_json::Value) -> Gene {
+    let mut base = serde_json::json!({
+        "symbol": "BRAF",
+        "name": "B-Raf proto-oncogene, serine/threonine kinase",
+        "entrez_id": "673",
+        "aliases": ["BRAF1"]
+    });
+    if let (Some(base), Some(extra)) = (base.as_object_mut(), value.as_object()) {
+        base.extend(extra.clone());
+    }
+    serde_json::from_value(base).expect("gene json")
+}
+
+fn ids(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn diff_genes_reports_new_drugs_and_clinvar_count_changes() {
+    let previous = gene(serde_json::json!({
+        "druggability": {"interactions": [
+            {"drug": "VEMURAFENIB", "source_count": 3}
+        ]},
+        "clinvar_summary": {
+            "total_variants": 1200,
+            "significance": [{"label": "Pathogenic", "count": 90}]
+        }
+    }));
+    let current = gene(serde_json::json!({
+        "aliases": ["BRAF1", "B-RAF1"],
+        "druggability": {"interactions": [
+            {"drug": "VEMURAFENIB", "source_count": 3},
+            {"drug": "TOVORAFENIB", "source_count": 1}
+        ]},
+        "clinvar_summary": {
+            "total_variants": 1250,
+            "significance": [
+                {"label": "Pathogenic", "count": 94},
+                {"label": "Likely pathogenic", "count": 12}
+            ]
+        }
+    }));
+
+    let changes = diff_genes(&previous, &current);
+    let fields = changes
+        .iter()
+        .map(|row| row.field.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fields,
+        vec![
+            "aliases",
+            "drug interactions",
+            "ClinVar variants",
+            "ClinVar Likely pathogenic",
+            "ClinVar Pathogenic",
+        ]
+    );
+    assert_eq!(changes[1].added, vec!["TOVORAFENIB".to_string()]);
+    assert!(changes[1].removed.is_empty());
+    assert_eq!(changes[2].before.as_deref(), Some("1200"));
+    assert_eq!(changes[2].after.as_deref(), Some("1250"));
+    assert_eq!(changes[3].before, None);
+    assert_eq!(changes[3].after.as_deref(), Some("12"));
+}
+
+#[test]
+fn diff_genes_skips_sections_missing_on_either_side() {
+    let previous = gene(serde_json::json!({
+        "druggability": {"interactions": [{"drug": "VEMURAFENIB", "source_count": 3}]}
+    }));
+    let current = gene(serde_json::json!({}));
+    assert!(diff_genes(&previous, &current).is_empty());
+}
+
+#[test]
+fn select_snapshot_resolves_latest_exact_id_and_date_cutoff() {
+    let stored = ids(&["20260105T080000Z", "20260310T120000Z", "20260901T090000Z"]);
+
+    assert_eq!(
+        select_snapshot("BRAF", &stored, None).expect("latest"),
+        Some("20260901T090000Z".to_string())
+    );
+    assert_eq!(
+        select_snapshot("BRAF", &stored, Some("20260310T120000Z")).expect("exact"),
+        Some("20260310T120000Z".to_string())
+    );
+    assert_eq!(
+        select_snapshot("BRAF", &stored, Some("2026-06")).expect("date"),
+        Some("20260310T120000Z".to_string())
+    );
+    assert_eq!(
+        select_snapshot("BRAF", &[], Some("2026-06-01")).expect("no snapshots"),
+        None
+    );
+
+    let err = select_snapshot("BRAF", &stored, Some("2025-12-31")).expect_err("too early");
+    assert!(err.to_string().contains("20260105T080000Z"));
+    let err = select_snapshot("BRAF", &stored, Some("last week")).expect_err("bad value");
+    assert!(err.to_string().contains("Stored snapshots for BRAF"));
+}
+
+#[test]
+fn save_snapshot_round_trips_and_prunes_oldest() {
+    let root = TempDirGuard::new("prune");
+    let dir = snapshot_dir(&root.path, "braf").expect("snapshot dir");
+    assert!(dir.ends_with("snapshots/gene/BRAF"));
+
+    for day in 1..=(MAX_SNAPSHOTS_PER_GENE + 2) {
+        let taken_at = format!("2026-01-01T00:00:{:02}Z", day % 60);
+        let snapshot = GeneSnapshot {
+            id: format!("20260101T{day:06}Z"),
+            taken_at,
+            gene: gene(serde_json::json!({})),
+        };
+        save_snapshot(&dir, &snapshot).expect("save");
+    }
+
+    let stored = list_snapshots(&dir).expect("list");
+    assert_eq!(stored.len(), MAX_SNAPSHOTS_PER_GENE);
+    assert_eq!(stored[0], "20260101T000003Z");
+    let loaded = load_snapshot(&dir, &stored[0]).expect("load");
+    assert_eq!(loaded.gene.symbol, "BRAF");
+}
+
+#[test]
+fn snapshot_dir_rejects_path_like_symbols() {
+    let root = PathBuf::from("/tmp/biomcp");
+    assert!(snapshot_dir(&root, "../etc").is_err());
+    assert!(snapshot_dir(&root, "").is_err());
+    assert_eq!(snapshot_id("2026-10-16T09:30:00Z"), "20261016T093000Z");
+}
+
+#[test]
+fn stored_in_reports_latest_snapshot_per_gene() {
+    let root = TempDirGuard::new("stored");
+    assert!(stored_in(&root.path).expect("empty root").is_empty());
+
+    for (symbol, id) in [
+        ("BRAF", "20260105T080000Z"),
+        ("BRAF", "20260310T120000Z"),
+        ("EGFR", "20260201T000000Z"),
+    ] {
+        let dir = snapshot_dir(&root.path, symbol).expect("snapshot dir");
+        let snapshot = GeneSnapshot {
+            id: id.to_string(),
+            taken_at: "2026-01-01T00:00:00Z".to_string(),
+            gene: gene(serde_json::json!({"symbol": symbol})),
+        };
+        save_snapshot(&dir, &snapshot).expect("save");
+    }
+
+    assert_eq!(
+        stored_in(&root.path).expect("stored"),
+        vec![
+            ("BRAF".to_string(), "20260310T120000Z".to_string()),
+            ("EGFR".to_string(), "20260201T000000Z".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,124 @@
+//! Species selection for MyGene.info lookups.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::BioMcpError;
+
+const HUMAN_TAXID: u32 = 9606;
+const MOUSE_TAXID: u32 = 10090;
+const RAT_TAXID: u32 = 10116;
+const ZEBRAFISH_TAXID: u32 = 7955;
+
+/// Organism for a gene lookup. Named species cover the common model
+/// organisms; any other organism is addressed by its NCBI taxonomy ID.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GeneSpecies {
+    #[default]
+    Human,
+    Mouse,
+    Rat,
+    Zebrafish,
+    Taxid(u32),
+}
+
+impl GeneSpecies {
+    pub fn from_taxid(taxid: u32) -> Self {
+        match taxid {
+            HUMAN_TAXID => Self::Human,
+            MOUSE_TAXID => Self::Mouse,
+            RAT_TAXID => Self::Rat,
+            ZEBRAFISH_TAXID => Self::Zebrafish,
+            other => Self::Taxid(other),
+        }
+    }
+
+    pub fn taxid(self) -> u32 {
+        match self {
+            Self::Human => HUMAN_TAXID,
+            Self::Mouse => MOUSE_TAXID,
+            Self::Rat => RAT_TAXID,
+            Self::Zebrafish => ZEBRAFISH_TAXID,
+            Self::Taxid(taxid) => taxid,
+        }
+    }
+
+    pub fn is_human(self) -> bool {
+        self == Self::Human
+    }
+
+    /// Value for the MyGene.info `species` parameter.
+    pub fn mygene_value(self) -> String {
+        match self {
+            Self::Taxid(taxid) => taxid.to_string(),
+            named => named.to_string(),
+        }
+    }
+
+    /// `--species` value for follow-up commands; `None` for the human default.
+    pub fn flag_value(self) -> Option<String> {
+        (!self.is_human()).then(|| self.to_string())
+    }
+
+    /// Ensembl production name, e.g. `mus_musculus`; `None` for bare taxids.
+    pub fn ensembl_name(self) -> Option<&'static str> {
+        match self {
+            Self::Human => Some("homo_sapiens"),
+            Self::Mouse => Some("mus_musculus"),
+            Self::Rat => Some("rattus_norvegicus"),
+            Self::Zebrafish => Some("danio_rerio"),
+            Self::Taxid(_) => None,
+        }
+    }
+
+    /// Human-readable organism, e.g. `mouse (Mus musculus, taxid 10090)`.
+    pub fn description(self) -> String {
+        let binomial = match self {
+            Self::Human => "Homo sapiens",
+            Self::Mouse => "Mus musculus",
+            Self::Rat => "Rattus norvegicus",
+            Self::Zebrafish => "Danio rerio",
+            Self::Taxid(taxid) => return format!("taxid {taxid}"),
+        };
+        format!("{self} ({binomial}, taxid {})", self.taxid())
+    }
+}
+
+impl fmt::Display for GeneSpecies {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Human => f.write_str("human"),
+            Self::Mouse => f.write_str("mouse"),
+            Self::Rat => f.write_str("rat"),
+            Self::Zebrafish => f.write_str("zebrafish"),
+            Self::Taxid(taxid) => write!(f, "{taxid}"),
+        }
+    }
+}
+
+impl FromStr for GeneSpecies {
+    type Err = BioMcpError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let normalized = value.to_ascii_lowercase();
+        let taxid = normalized.strip_prefix("taxid:").unwrap_or(&normalized);
+        if let Ok(taxid) = taxid.parse::<u32>()
+            && taxid > 0
+        {
+            return Ok(Self::from_taxid(taxid));
+        }
+        match normalized.as_str() {
+            "human" | "homo sapiens" => Ok(Self::Human),
+            "mouse" | "mus musculus" => Ok(Self::Mouse),
+            "rat" | "rattus norvegicus" => Ok(Self::Rat),
+            "zebrafish" | "danio rerio" => Ok(Self::Zebrafish),
+            _ => Err(BioMcpError::InvalidArgument(format!(
+                "Unknown species \"{value}\". Use human, mouse, rat, zebrafish, or an NCBI taxonomy ID (e.g., 7227)."
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,44 @@
+//! Tests for gene species parsing.
+
+use super::*;
+
+#[test]
+fn parses_named_species_and_taxids() {
+    assert_eq!("mouse".parse::<GeneSpecies>().unwrap(), GeneSpecies::Mouse);
+    assert_eq!("Rat".parse::<GeneSpecies>().unwrap(), GeneSpecies::Rat);
+    assert_eq!(
+        "Danio rerio".parse::<GeneSpecies>().unwrap(),
+        GeneSpecies::Zebrafish
+    );
+    assert_eq!("9606".parse::<GeneSpecies>().unwrap(), GeneSpecies::Human);
+    assert_eq!(
+        "taxid:10090".parse::<GeneSpecies>().unwrap(),
+        GeneSpecies::Mouse
+    );
+    assert_eq!(
+        "7227".parse::<GeneSpecies>().unwrap(),
+        GeneSpecies::Taxid(7227)
+    );
+}
+
+#[test]
+fn rejects_unknown_species_and_zero_taxid() {
+    let err = "yeast".parse::<GeneSpecies>().expect_err("unknown species");
+    assert!(err.to_string().contains("Unknown species \"yeast\""));
+    assert!("0".parse::<GeneSpecies>().is_err());
+}
+
+#[test]
+fn flag_and_mygene_values_round_trip() {
+    assert_eq!(GeneSpecies::Human.flag_value(), None);
+    assert_eq!(GeneSpecies::Mouse.flag_value().as_deref(), Some("mouse"));
+    assert_eq!(GeneSpecies::Taxid(7227).mygene_value(), "7227");
+    assert_eq!(
+        GeneSpecies::Zebrafish.description(),
+        "zebrafish (Danio rerio, taxid 7955)"
+    );
+    assert_eq!(GeneSpecies::Taxid(7227).description(), "taxid 7227");
+    for species in [GeneSpecies::Rat, GeneSpecies::Taxid(7227)] {
+        assert_eq!(species.to_string().parse::<GeneSpecies>().unwrap(), species);
+    }
+}
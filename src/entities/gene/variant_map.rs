@@ -0,0 +1,290 @@
+//! Protein-position variant density for lollipop plots.
+//!
+//! Germline pathogenic variants come from ClinVar (via MyVariant.info) and
+//! somatic mutations from the configured cBioPortal study. Both are keyed by
+//! the residue parsed from the protein change, then binned for a coarse
+//! histogram. Either source may fail independently; the map records a note
+//! instead of failing the command.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::error::BioMcpError;
+use crate::sources::cbioportal::{CBioPortalClient, CBioProteinChangeSummary};
+use crate::sources::mygene::MyGeneClient;
+use crate::sources::myvariant::{MyVariantClient, MyVariantProteinChangeResponse};
+use crate::transform;
+
+use super::GeneSpecies;
+
+const VARIANT_MAP_TIMEOUT: Duration = Duration::from_secs(20);
+const CLINVAR_VARIANT_LIMIT: usize = 1000;
+const SOMATIC_MUTATION_LIMIT: usize = 5000;
+const TARGET_BIN_COUNT: u32 = 40;
+const MIN_BIN_SIZE: u32 = 10;
+const TOP_CHANGES_PER_POSITION: usize = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneVariantMap {
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protein_length: Option<u32>,
+    pub bin_size: u32,
+    pub pathogenic_total: usize,
+    pub somatic_total: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub somatic_study: Option<String>,
+    pub positions: Vec<GeneVariantPosition>,
+    pub bins: Vec<GeneVariantBin>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneVariantPosition {
+    pub position: u32,
+    pub pathogenic: u32,
+    pub somatic: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneVariantBin {
+    pub start: u32,
+    pub end: u32,
+    pub pathogenic: u32,
+    pub somatic: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VariantOrigin {
+    Pathogenic,
+    Somatic,
+}
+
+fn protein_position_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^(?:p\.)?\(?(?:[A-Z][a-z]{2}|[A-Z*])(\d+)").expect("valid regex")
+    })
+}
+
+/// Strips any accession prefix and returns the first residue of a protein change.
+fn parse_protein_change(raw: &str) -> Option<(u32, String)> {
+    let change = raw.rsplit(':').next()?.trim();
+    let position = protein_position_re()
+        .captures(change)?
+        .get(1)?
+        .as_str()
+        .parse::<u32>()
+        .ok()
+        .filter(|v| *v > 0)?;
+    Some((position, change.to_string()))
+}
+
+fn clinvar_protein_changes(resp: &MyVariantProteinChangeResponse) -> Vec<(u32, String)> {
+    resp.hits
+        .iter()
+        .filter_map(|hit| {
+            let clinvar = hit
+                .clinvar
+                .as_ref()
+                .and_then(|c| c.hgvs.as_ref())
+                .and_then(|h| h.protein.first());
+            let dbnsfp = hit.dbnsfp.as_ref().and_then(|d| d.hgvsp.first());
+            clinvar
+                .and_then(parse_protein_change)
+                .or_else(|| dbnsfp.and_then(parse_protein_change))
+        })
+        .collect()
+}
+
+fn bin_size_for(length: u32) -> u32 {
+    let raw = length.div_ceil(TARGET_BIN_COUNT).max(MIN_BIN_SIZE);
+    raw.div_ceil(MIN_BIN_SIZE) * MIN_BIN_SIZE
+}
+
+fn build_variant_map(
+    symbol: String,
+    protein_length: Option<u32>,
+    changes: &[(VariantOrigin, u32, String)],
+) -> GeneVariantMap {
+    let mut by_position: BTreeMap<u32, (GeneVariantPosition, BTreeMap<String, usize>)> =
+        BTreeMap::new();
+    for (origin, position, change) in changes {
+        let (row, labels) = by_position.entry(*position).or_insert_with(|| {
+            (
+                GeneVariantPosition {
+                    position: *position,
+                    pathogenic: 0,
+                    somatic: 0,
+                    changes: Vec::new(),
+                },
+                BTreeMap::new(),
+            )
+        });
+        match origin {
+            VariantOrigin::Pathogenic => row.pathogenic += 1,
+            VariantOrigin::Somatic => row.somatic += 1,
+        }
+        *labels.entry(change.clone()).or_default() += 1;
+    }
+
+    let positions = by_position
+        .into_values()
+        .map(|(mut row, labels)| {
+            let mut labels = labels.into_iter().collect::<Vec<_>>();
+            labels.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            row.changes = labels
+                .into_iter()
+                .take(TOP_CHANGES_PER_POSITION)
+                .map(|(label, _)| label)
+                .collect();
+            row
+        })
+        .collect::<Vec<_>>();
+
+    let max_position = positions.last().map(|row| row.position).unwrap_or(0);
+    let length = protein_length.unwrap_or(0).max(max_position);
+    let bin_size = bin_size_for(length.max(1));
+    let bins = (0..length.div_ceil(bin_size))
+        .map(|idx| {
+            let start = idx * bin_size + 1;
+            let end = ((idx + 1) * bin_size).min(length);
+            let (pathogenic, somatic) = positions
+                .iter()
+                .filter(|row| (start..=end).contains(&row.position))
+                .fold((0, 0), |(p, s), row| (p + row.pathogenic, s + row.somatic));
+            GeneVariantBin {
+                start,
+                end,
+                pathogenic,
+                somatic,
+            }
+        })
+        .collect();
+
+    let count = |origin| changes.iter().filter(|(o, _, _)| *o == origin).count();
+    GeneVariantMap {
+        symbol,
+        protein_length,
+        bin_size,
+        pathogenic_total: count(VariantOrigin::Pathogenic),
+        somatic_total: count(VariantOrigin::Somatic),
+        somatic_study: None,
+        positions,
+        bins,
+        notes: Vec::new(),
+    }
+}
+
+async fn fetch_clinvar(symbol: &str) -> Result<MyVariantProteinChangeResponse, BioMcpError> {
+    MyVariantClient::new()?
+        .clinvar_gene_pathogenic_protein_changes(symbol, CLINVAR_VARIANT_LIMIT)
+        .await
+}
+
+async fn fetch_somatic(symbol: &str) -> Result<CBioProteinChangeSummary, BioMcpError> {
+    CBioPortalClient::new()?
+        .get_protein_changes(symbol, SOMATIC_MUTATION_LIMIT)
+        .await
+}
+
+async fn with_timeout<T>(
+    fut: impl Future<Output = Result<T, BioMcpError>>,
+    label: &str,
+) -> Result<T, String> {
+    match tokio::time::timeout(VARIANT_MAP_TIMEOUT, fut).await {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(err)) => {
+            warn!("{label} unavailable for gene variant map: {err}");
+            Err(format!("{label} unavailable: {err}"))
+        }
+        Err(_) => {
+            warn!(
+                timeout_secs = VARIANT_MAP_TIMEOUT.as_secs(),
+                "{label} gene variant map lookup timed out"
+            );
+            Err(format!("{label} timed out"))
+        }
+    }
+}
+
+/// Builds per-residue counts of ClinVar pathogenic and cBioPortal somatic variants.
+pub async fn variant_map(symbol: &str) -> Result<GeneVariantMap, BioMcpError> {
+    let symbol = symbol.trim();
+    if symbol.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "Gene symbol is required. Example: biomcp gene variant-map EGFR".into(),
+        ));
+    }
+
+    let gene = transform::gene::from_mygene_get(
+        MyGeneClient::new()?.get(symbol, false).await?,
+        GeneSpecies::Human,
+    );
+    let symbol = gene.symbol.clone();
+
+    let (protein, clinvar, somatic) = tokio::join!(
+        with_timeout(
+            super::fetch_protein_section(gene.uniprot_id.as_deref(), &symbol, GeneSpecies::Human),
+            "UniProt"
+        ),
+        with_timeout(fetch_clinvar(&symbol), "ClinVar (MyVariant.info)"),
+        with_timeout(fetch_somatic(&symbol), "cBioPortal"),
+    );
+
+    let protein_length = protein.ok().flatten().and_then(|p| p.length);
+    let mut notes = Vec::new();
+    let mut changes = Vec::new();
+    match &clinvar {
+        Ok(resp) => {
+            let rows = clinvar_protein_changes(resp);
+            if let Some(total) = resp.total
+                && total as usize > resp.hits.len()
+            {
+                notes.push(format!(
+                    "ClinVar: first {} of {total} pathogenic/likely pathogenic variants scanned.",
+                    resp.hits.len()
+                ));
+            }
+            changes.extend(
+                rows.into_iter()
+                    .map(|(pos, change)| (VariantOrigin::Pathogenic, pos, change)),
+            );
+        }
+        Err(note) => notes.push(note.clone()),
+    }
+    let mut somatic_study = None;
+    match somatic {
+        Ok(summary) => {
+            if summary.mutation_count >= SOMATIC_MUTATION_LIMIT {
+                notes.push(format!(
+                    "cBioPortal: first {SOMATIC_MUTATION_LIMIT} mutations scanned."
+                ));
+            }
+            changes.extend(
+                summary
+                    .changes
+                    .into_iter()
+                    .map(|row| (VariantOrigin::Somatic, row.position, row.protein_change)),
+            );
+            somatic_study = Some(summary.study_id);
+        }
+        Err(note) => notes.push(note),
+    }
+
+    let mut map = build_variant_map(symbol, protein_length, &changes);
+    map.somatic_study = somatic_study;
+    map.notes = notes;
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,77 @@
+//! Tests for gene variant map aggregation.
+
+use super::*;
+
+#[test]
+fn parse_protein_change_handles_accessions_and_short_forms() {
+    assert_eq!(
+        parse_protein_change("NP_005219.2:p.Leu858Arg"),
+        Some((858, "p.Leu858Arg".to_string()))
+    );
+    assert_eq!(
+        parse_protein_change("p.(Gly719Ser)"),
+        Some((719, "p.(Gly719Ser)".to_string()))
+    );
+    assert_eq!(
+        parse_protein_change("E746_A750del"),
+        Some((746, "E746_A750del".to_string()))
+    );
+    assert_eq!(parse_protein_change("p.?"), None);
+    assert_eq!(parse_protein_change("c.2573T>G"), None);
+}
+
+#[test]
+fn bin_size_targets_about_forty_bins() {
+    assert_eq!(bin_size_for(1), 10);
+    assert_eq!(bin_size_for(393), 10);
+    assert_eq!(bin_size_for(1210), 40);
+}
+
+#[test]
+fn build_variant_map_counts_positions_and_bins_by_origin() {
+    let changes = vec![
+        (VariantOrigin::Pathogenic, 858, "p.Leu858Arg".to_string()),
+        (VariantOrigin::Somatic, 858, "L858R".to_string()),
+        (VariantOrigin::Somatic, 858, "L858R".to_string()),
+        (VariantOrigin::Somatic, 746, "E746_A750del".to_string()),
+        (VariantOrigin::Pathogenic, 12, "p.Gly12Asp".to_string()),
+    ];
+    let map = build_variant_map("EGFR".into(), Some(1210), &changes);
+
+    assert_eq!(map.bin_size, 40);
+    assert_eq!(map.bins.len(), 31);
+    assert_eq!(map.bins.last().map(|bin| bin.end), Some(1210));
+    assert_eq!(map.pathogenic_total, 2);
+    assert_eq!(map.somatic_total, 3);
+
+    let positions = map
+        .positions
+        .iter()
+        .map(|row| (row.position, row.pathogenic, row.somatic))
+        .collect::<Vec<_>>();
+    assert_eq!(positions, vec![(12, 1, 0), (746, 0, 1), (858, 1, 2)]);
+    assert_eq!(map.positions[2].changes, vec!["L858R", "p.Leu858Arg"]);
+
+    let bin = map
+        .bins
+        .iter()
+        .find(|bin| (bin.start..=bin.end).contains(&858))
+        .expect("bin covering 858");
+    assert_eq!(
+        (bin.start, bin.end, bin.pathogenic, bin.somatic),
+        (841, 880, 1, 2)
+    );
+}
+
+#[test]
+fn build_variant_map_falls_back_to_max_position_without_length() {
+    let changes = vec![(VariantOrigin::Somatic, 95, "R95*".to_string())];
+    let map = build_variant_map("TP53".into(), None, &changes);
+    assert_eq!(map.protein_length, None);
+    assert_eq!(map.bins.len(), 10);
+    assert_eq!(map.bins[9].somatic, 1);
+
+    let empty = build_variant_map("TP53".into(), None, &[]);
+    assert!(empty.bins.is_empty());
+    assert!(empty.positions.is_empty());
+}
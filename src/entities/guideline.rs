@@ -0,0 +1,435 @@
+//! Clinical practice guideline search across guideline registries.
+//!
+//! USPSTF recommendations come from the Prevention TaskForce API and carry the
+//! task force letter grade. Society guidelines (NICE, AHA, ACC, ESC, ...) are
+//! located through Europe PMC's `practice guideline` publication type, which
+//! indexes both journal-published society guidelines and NICE guidance from the
+//! NCBI Bookshelf; those rows report a publication year but no grade.
+
+use std::collections::HashSet;
+
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::SearchPage;
+use crate::error::BioMcpError;
+use crate::sources::europepmc::{EuropePmcClient, EuropePmcResult};
+use crate::sources::uspstf::{UspstfClient, UspstfExport};
+
+const MAX_CONDITION_LEN: usize = 200;
+const MAX_SEARCH_LIMIT: usize = 50;
+const RECOMMENDATION_SUMMARY_MAX_CHARS: usize = 280;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum GuidelineOrg {
+    Uspstf,
+    Nice,
+    Aha,
+    Acc,
+    Esc,
+    Idsa,
+    Asco,
+    Ada,
+    Who,
+}
+
+impl GuidelineOrg {
+    pub const ALL: &'static [GuidelineOrg] = &[
+        GuidelineOrg::Uspstf,
+        GuidelineOrg::Nice,
+        GuidelineOrg::Aha,
+        GuidelineOrg::Acc,
+        GuidelineOrg::Esc,
+        GuidelineOrg::Idsa,
+        GuidelineOrg::Asco,
+        GuidelineOrg::Ada,
+        GuidelineOrg::Who,
+    ];
+
+    pub fn acronym(self) -> &'static str {
+        match self {
+            Self::Uspstf => "USPSTF",
+            Self::Nice => "NICE",
+            Self::Aha => "AHA",
+            Self::Acc => "ACC",
+            Self::Esc => "ESC",
+            Self::Idsa => "IDSA",
+            Self::Asco => "ASCO",
+            Self::Ada => "ADA",
+            Self::Who => "WHO",
+        }
+    }
+
+    pub fn full_name(self) -> &'static str {
+        match self {
+            Self::Uspstf => "US Preventive Services Task Force",
+            Self::Nice => "National Institute for Health and Care Excellence",
+            Self::Aha => "American Heart Association",
+            Self::Acc => "American College of Cardiology",
+            Self::Esc => "European Society of Cardiology",
+            Self::Idsa => "Infectious Diseases Society of America",
+            Self::Asco => "American Society of Clinical Oncology",
+            Self::Ada => "American Diabetes Association",
+            Self::Who => "World Health Organization",
+        }
+    }
+
+    fn from_flag(value: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|org| org.acronym().eq_ignore_ascii_case(value.trim()))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuidelineSearchResult {
+    /// Issuing organization acronym; absent for unfiltered literature-indexed hits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommendation: Option<String>,
+    /// Registry-assigned recommendation grade (USPSTF A/B/C/D/I).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grade: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<String>,
+    /// Registry the row came from (`USPSTF` or `Europe PMC`).
+    pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pmid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GuidelineSearchFilters {
+    pub condition: String,
+    /// Requested organizations; empty means USPSTF (when keyed) plus all literature-indexed guidelines.
+    pub orgs: Vec<GuidelineOrg>,
+}
+
+/// Parses a comma-separated `--org` list, deduplicating and rejecting unknown acronyms.
+pub fn parse_org_list(value: &str) -> Result<Vec<GuidelineOrg>, BioMcpError> {
+    let mut out = Vec::new();
+    for token in value.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        let org = GuidelineOrg::from_flag(token).ok_or_else(|| {
+            let supported = GuidelineOrg::ALL
+                .iter()
+                .map(|org| org.acronym())
+                .collect::<Vec<_>>()
+                .join(", ");
+            BioMcpError::InvalidArgument(format!(
+                "Unknown guideline organization '{token}'. Supported: {supported}"
+            ))
+        })?;
+        if !out.contains(&org) {
+            out.push(org);
+        }
+    }
+    Ok(out)
+}
+
+pub fn search_query_summary(filters: &GuidelineSearchFilters) -> String {
+    let mut parts = vec![format!("condition={}", filters.condition.trim())];
+    if !filters.orgs.is_empty() {
+        let orgs = filters
+            .orgs
+            .iter()
+            .map(|org| org.acronym())
+            .collect::<Vec<_>>()
+            .join(",");
+        parts.push(format!("org={orgs}"));
+    }
+    parts.join(", ")
+}
+
+fn validate_filters(filters: &GuidelineSearchFilters, limit: usize) -> Result<(), BioMcpError> {
+    let condition = filters.condition.trim();
+    if condition.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "--condition is required. Example: biomcp search guidelines --condition \"atrial fibrillation\"".into(),
+        ));
+    }
+    if condition.len() > MAX_CONDITION_LEN {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--condition must be at most {MAX_CONDITION_LEN} characters"
+        )));
+    }
+    if limit == 0 || limit > MAX_SEARCH_LIMIT {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--limit must be between 1 and {MAX_SEARCH_LIMIT}"
+        )));
+    }
+    Ok(())
+}
+
+fn normalize_terms(value: &str) -> String {
+    value
+        .to_ascii_lowercase()
+        .split(|ch: char| !ch.is_ascii_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn strip_html(value: &str) -> String {
+    let mut out = String::new();
+    let mut inside = false;
+    for ch in value.chars() {
+        match ch {
+            '<' => inside = true,
+            '>' => {
+                inside = false;
+                out.push(' ');
+            }
+            _ if !inside => out.push(ch),
+            _ => {}
+        }
+    }
+    out.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(" .", ".")
+        .replace(" ,", ",")
+}
+
+fn summarize(value: &str) -> Option<String> {
+    let text = strip_html(value);
+    if text.is_empty() {
+        return None;
+    }
+    if text.chars().count() <= RECOMMENDATION_SUMMARY_MAX_CHARS {
+        return Some(text);
+    }
+    let truncated = text
+        .chars()
+        .take(RECOMMENDATION_SUMMARY_MAX_CHARS)
+        .collect::<String>();
+    Some(format!("{}...", truncated.trim_end()))
+}
+
+fn json_scalar(value: Option<&serde_json::Value>) -> Option<String> {
+    match value? {
+        serde_json::Value::String(value) => Some(value.trim().to_string()),
+        serde_json::Value::Number(value) => Some(value.to_string()),
+        _ => None,
+    }
+    .filter(|value| !value.is_empty())
+}
+
+/// Matches USPSTF recommendations whose title or curated keywords contain the condition.
+fn uspstf_results(export: &UspstfExport, condition: &str) -> Vec<GuidelineSearchResult> {
+    let needle = normalize_terms(condition);
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let needle = format!(" {needle} ");
+
+    export
+        .specific_recommendations
+        .iter()
+        .filter_map(|specific| {
+            let general = specific.general_recommendation(export);
+            let haystack = [
+                specific.title.as_deref(),
+                general.and_then(|g| g.title.as_deref()),
+                general.and_then(|g| g.keywords.as_deref()),
+            ]
+            .into_iter()
+            .flatten()
+            .map(|value| format!(" {} ", normalize_terms(value)))
+            .collect::<String>();
+            if !haystack.contains(&needle) {
+                return None;
+            }
+
+            let title = specific
+                .title
+                .as_deref()
+                .or_else(|| general.and_then(|g| g.title.as_deref()))
+                .map(str::trim)
+                .filter(|value| !value.is_empty())?;
+            Some(GuidelineSearchResult {
+                organization: Some(GuidelineOrg::Uspstf.acronym().to_string()),
+                title: title.to_string(),
+                recommendation: specific.text.as_deref().and_then(summarize),
+                grade: specific
+                    .grade
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string),
+                year: general.and_then(|g| json_scalar(g.topic_year.as_ref())),
+                source: "USPSTF".to_string(),
+                pmid: None,
+                url: general
+                    .and_then(|g| g.uspstf_alias.as_deref())
+                    .map(str::trim)
+                    .filter(|alias| !alias.is_empty())
+                    .map(|alias| {
+                        format!(
+                            "https://www.uspreventiveservicestaskforce.org/uspstf/recommendation/{alias}"
+                        )
+                    }),
+            })
+        })
+        .collect()
+}
+
+fn quote_term(value: &str) -> String {
+    let value = value.replace('"', " ");
+    format!(
+        "\"{}\"",
+        value.split_whitespace().collect::<Vec<_>>().join(" ")
+    )
+}
+
+/// Europe PMC query for practice guidelines about a condition, optionally scoped to one issuer.
+fn europepmc_guideline_query(condition: &str, org: Option<GuidelineOrg>) -> String {
+    let mut query = format!(
+        "{} AND PUB_TYPE:\"practice guideline\"",
+        quote_term(condition)
+    );
+    if let Some(org) = org {
+        query.push_str(&format!(
+            " AND ({} OR TITLE:{})",
+            quote_term(org.full_name()),
+            org.acronym()
+        ));
+    }
+    query
+}
+
+fn europepmc_result(
+    row: EuropePmcResult,
+    org: Option<GuidelineOrg>,
+) -> Option<GuidelineSearchResult> {
+    let title = row
+        .title
+        .as_deref()
+        .map(str::trim)
+        .map(|value| value.trim_end_matches('.'))
+        .filter(|value| !value.is_empty())?
+        .to_string();
+    let pmid = row
+        .pmid
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let url = match (&pmid, row.doi.as_deref().map(str::trim)) {
+        (Some(pmid), _) => Some(format!("https://pubmed.ncbi.nlm.nih.gov/{pmid}/")),
+        (None, Some(doi)) if !doi.is_empty() => Some(format!("https://doi.org/{doi}")),
+        _ => None,
+    };
+    Some(GuidelineSearchResult {
+        organization: org.map(|org| org.acronym().to_string()),
+        title,
+        recommendation: None,
+        grade: None,
+        year: row
+            .pub_year
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string),
+        source: "Europe PMC".to_string(),
+        pmid,
+        url,
+    })
+}
+
+/// Round-robins per-registry result lists so one issuer cannot crowd out the rest,
+/// dropping repeated guidelines reached through more than one organization query.
+fn interleave(groups: Vec<Vec<GuidelineSearchResult>>) -> Vec<GuidelineSearchResult> {
+    let mut iters = groups.into_iter().map(Vec::into_iter).collect::<Vec<_>>();
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    loop {
+        let mut progressed = false;
+        for iter in &mut iters {
+            let Some(row) = iter.next() else {
+                continue;
+            };
+            progressed = true;
+            let key = row
+                .pmid
+                .clone()
+                .or_else(|| row.url.clone())
+                .unwrap_or_else(|| normalize_terms(&row.title));
+            if seen.insert(key) {
+                out.push(row);
+            }
+        }
+        if !progressed {
+            return out;
+        }
+    }
+}
+
+pub async fn search_page(
+    filters: &GuidelineSearchFilters,
+    limit: usize,
+    offset: usize,
+) -> Result<SearchPage<GuidelineSearchResult>, BioMcpError> {
+    validate_filters(filters, limit)?;
+    let condition = filters.condition.trim();
+    let fetch_size = offset.saturating_add(limit).min(100);
+
+    let uspstf = UspstfClient::new()?;
+    let include_uspstf = if filters.orgs.is_empty() {
+        uspstf.has_api_key()
+    } else {
+        filters.orgs.contains(&GuidelineOrg::Uspstf)
+    };
+    let literature_scopes = if filters.orgs.is_empty() {
+        vec![None]
+    } else {
+        filters
+            .orgs
+            .iter()
+            .copied()
+            .filter(|org| *org != GuidelineOrg::Uspstf)
+            .map(Some)
+            .collect()
+    };
+
+    let mut groups = Vec::new();
+    if include_uspstf {
+        let export = uspstf.export().await?;
+        groups.push(uspstf_results(&export, condition));
+    }
+
+    if !literature_scopes.is_empty() {
+        let europepmc = EuropePmcClient::new()?;
+        let queries = literature_scopes.iter().map(|org| {
+            let query = europepmc_guideline_query(condition, *org);
+            let europepmc = &europepmc;
+            async move { europepmc.search_query(&query, 1, fetch_size).await }
+        });
+        for (org, response) in literature_scopes.iter().zip(join_all(queries).await) {
+            let rows = response?
+                .result_list
+                .map(|list| list.result)
+                .unwrap_or_default();
+            groups.push(
+                rows.into_iter()
+                    .filter_map(|row| europepmc_result(row, *org))
+                    .collect(),
+            );
+        }
+    }
+
+    let results = interleave(groups)
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .collect();
+    Ok(SearchPage::offset(results, None))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,282 @@
+//! Tests for guideline organization parsing, registry matching, and merging.
+
+use std::collections::HashMap;
+
+use super::*;
+use crate::sources::uspstf::{UspstfGeneralRecommendation, UspstfSpecificRecommendation};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn env_lock_async() -> tokio::sync::MutexGuard<'static, ()> {
+    crate::test_support::env_lock().lock().await
+}
+
+struct EnvVarGuard {
+    name: &'static str,
+    previous: Option<String>,
+}
+
+impl Drop for EnvVarGuard {
+    fn drop(&mut self) {
+        // Safety: tests serialize environment mutation with `env_lock_async()`.
+        unsafe {
+            match &self.previous {
+                Some(value) => std::env::set_var(self.name, value),
+                None => std::env::remove_var(self.name),
+            }
+        }
+    }
+}
+
+fn set_env_var(name: &'static str, value: Option<&str>) -> EnvVarGuard {
+    let previous = std::env::var(name).ok();
+    // Safety: tests serialize environment mutation with `env_lock_async()`.
+    unsafe {
+        match value {
+            Some(value) => std::env::set_var(name, value),
+            None => std::env::remove_var(name),
+        }
+    }
+    EnvVarGuard { name, previous }
+}
+
+fn uspstf_export() -> UspstfExport {
+    UspstfExport {
+        specific_recommendations: vec![
+            UspstfSpecificRecommendation {
+                id: Some(serde_json::json!(413)),
+                title: Some("Atrial Fibrillation: Screening -- Adults 50 years or older".into()),
+                grade: Some("I".into()),
+                gender: None,
+                text: Some(
+                    "<p>The USPSTF concludes that the current evidence is <b>insufficient</b>.</p>"
+                        .into(),
+                ),
+                general: Some(serde_json::json!(390)),
+            },
+            UspstfSpecificRecommendation {
+                id: Some(serde_json::json!(1)),
+                title: Some("Abdominal Aortic Aneurysm: Screening -- Men 65 to 75 years".into()),
+                grade: Some("B".into()),
+                gender: None,
+                text: None,
+                general: Some(serde_json::json!("2")),
+            },
+        ],
+        general_recommendations: HashMap::from([
+            (
+                "390".to_string(),
+                UspstfGeneralRecommendation {
+                    title: Some("Atrial Fibrillation: Screening".into()),
+                    topic_type: Some("Screening".into()),
+                    topic_year: Some(serde_json::json!(2022)),
+                    uspstf_alias: Some("atrial-fibrillation-screening".into()),
+                    keywords: Some("AF|Stroke".into()),
+                },
+            ),
+            (
+                "2".to_string(),
+                UspstfGeneralRecommendation {
+                    title: Some("Abdominal Aortic Aneurysm: Screening".into()),
+                    topic_type: Some("Screening".into()),
+                    topic_year: Some(serde_json::json!("2019")),
+                    uspstf_alias: None,
+                    keywords: Some("AAA|Smoking".into()),
+                },
+            ),
+        ]),
+    }
+}
+
+fn row(title: &str, pmid: Option<&str>, org: Option<GuidelineOrg>) -> GuidelineSearchResult {
+    GuidelineSearchResult {
+        organization: org.map(|org| org.acronym().to_string()),
+        title: title.into(),
+        recommendation: None,
+        grade: None,
+        year: None,
+        source: "Europe PMC".into(),
+        pmid: pmid.map(str::to_string),
+        url: None,
+    }
+}
+
+#[test]
+fn parse_org_list_dedupes_and_rejects_unknown_organizations() {
+    let orgs = parse_org_list("aha, NICE,,AHA,uspstf").expect("orgs");
+    assert_eq!(
+        orgs,
+        vec![GuidelineOrg::Aha, GuidelineOrg::Nice, GuidelineOrg::Uspstf]
+    );
+
+    let err = parse_org_list("AHA,CDC").expect_err("unknown org");
+    let message = err.to_string();
+    assert!(message.contains("Unknown guideline organization 'CDC'"));
+    assert!(message.contains("USPSTF, NICE, AHA"));
+}
+
+#[test]
+fn search_query_summary_lists_condition_and_orgs() {
+    let filters = GuidelineSearchFilters {
+        condition: " atrial fibrillation ".into(),
+        orgs: vec![GuidelineOrg::Aha, GuidelineOrg::Nice],
+    };
+    assert_eq!(
+        search_query_summary(&filters),
+        "condition=atrial fibrillation, org=AHA,NICE"
+    );
+}
+
+#[test]
+fn uspstf_results_match_titles_and_keywords_on_word_boundaries() {
+    let export = uspstf_export();
+
+    let rows = uspstf_results(&export, "Atrial fibrillation");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].grade.as_deref(), Some("I"));
+    assert_eq!(rows[0].year.as_deref(), Some("2022"));
+    assert_eq!(
+        rows[0].recommendation.as_deref(),
+        Some("The USPSTF concludes that the current evidence is insufficient.")
+    );
+    assert_eq!(
+        rows[0].url.as_deref(),
+        Some(
+            "https://www.uspreventiveservicestaskforce.org/uspstf/recommendation/atrial-fibrillation-screening"
+        )
+    );
+
+    let rows = uspstf_results(&export, "AAA");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].grade.as_deref(), Some("B"));
+    assert_eq!(rows[0].year.as_deref(), Some("2019"));
+    assert!(rows[0].url.is_none());
+
+    assert!(uspstf_results(&export, "fib").is_empty());
+}
+
+#[test]
+fn europepmc_guideline_query_scopes_to_practice_guidelines_and_issuer() {
+    assert_eq!(
+        europepmc_guideline_query("atrial \"fibrillation\"", None),
+        "\"atrial fibrillation\" AND PUB_TYPE:\"practice guideline\""
+    );
+    assert_eq!(
+        europepmc_guideline_query("atrial fibrillation", Some(GuidelineOrg::Aha)),
+        "\"atrial fibrillation\" AND PUB_TYPE:\"practice guideline\" AND (\"American Heart Association\" OR TITLE:AHA)"
+    );
+}
+
+#[test]
+fn interleave_round_robins_sources_and_drops_duplicates() {
+    let merged = interleave(vec![
+        vec![
+            row("AHA one", Some("1"), Some(GuidelineOrg::Aha)),
+            row("AHA two", Some("2"), Some(GuidelineOrg::Aha)),
+            row("AHA three", Some("3"), Some(GuidelineOrg::Aha)),
+        ],
+        vec![
+            row("Shared ACC/AHA", Some("2"), Some(GuidelineOrg::Acc)),
+            row("ACC only", Some("4"), Some(GuidelineOrg::Acc)),
+        ],
+    ]);
+    let titles = merged
+        .iter()
+        .map(|row| row.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["AHA one", "AHA two", "ACC only", "AHA three"]);
+}
+
+#[tokio::test]
+async fn search_page_rejects_missing_condition_and_bad_limit() {
+    let filters = GuidelineSearchFilters::default();
+    let err = search_page(&filters, 10, 0)
+        .await
+        .expect_err("condition required");
+    assert!(err.to_string().contains("--condition is required"));
+
+    let filters = GuidelineSearchFilters {
+        condition: "asthma".into(),
+        orgs: Vec::new(),
+    };
+    let err = search_page(&filters, 51, 0).await.expect_err("limit");
+    assert!(err.to_string().contains("--limit must be between 1 and 50"));
+}
+
+#[tokio::test]
+async fn search_page_requires_uspstf_key_when_org_is_requested() {
+    let _lock = env_lock_async().await;
+    let _key = set_env_var("USPSTF_API_KEY", None);
+    let filters = GuidelineSearchFilters {
+        condition: "atrial fibrillation".into(),
+        orgs: vec![GuidelineOrg::Uspstf],
+    };
+    let err = search_page(&filters, 5, 0).await.expect_err("key required");
+    assert!(err.to_string().contains("USPSTF_API_KEY"));
+}
+
+#[tokio::test]
+async fn search_page_merges_uspstf_grades_with_society_guidelines() {
+    let _lock = env_lock_async().await;
+    let uspstf = MockServer::start().await;
+    let europepmc = MockServer::start().await;
+    let _uspstf_base = set_env_var("BIOMCP_USPSTF_BASE", Some(&uspstf.uri()));
+    let _uspstf_key = set_env_var("USPSTF_API_KEY", Some("test-key"));
+    let _europepmc_base = set_env_var("BIOMCP_EUROPEPMC_BASE", Some(&europepmc.uri()));
+
+    Mock::given(method("GET"))
+        .and(path("/json"))
+        .and(query_param("key", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "specificRecommendations": [{
+                "id": 413,
+                "title": "Atrial Fibrillation: Screening -- Adults 50 years or older",
+                "grade": "I",
+                "text": "Evidence is insufficient.",
+                "general": 390
+            }],
+            "generalRecommendations": {
+                "390": {"title": "Atrial Fibrillation: Screening", "topicYear": "2022"}
+            }
+        })))
+        .expect(1)
+        .mount(&uspstf)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param(
+            "query",
+            "\"atrial fibrillation\" AND PUB_TYPE:\"practice guideline\" AND (\"American Heart Association\" OR TITLE:AHA)",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "hitCount": 1,
+            "resultList": {"result": [{
+                "id": "38033089",
+                "title": "2023 ACC/AHA/ACCP/HRS Guideline for the Diagnosis and Management of Atrial Fibrillation.",
+                "pmid": "38033089",
+                "pubYear": "2023"
+            }]}
+        })))
+        .expect(1)
+        .mount(&europepmc)
+        .await;
+
+    let filters = GuidelineSearchFilters {
+        condition: "atrial fibrillation".into(),
+        orgs: vec![GuidelineOrg::Uspstf, GuidelineOrg::Aha],
+    };
+    let page = search_page(&filters, 5, 0).await.expect("guidelines");
+    assert_eq!(page.results.len(), 2);
+    assert_eq!(page.results[0].source, "USPSTF");
+    assert_eq!(page.results[0].grade.as_deref(), Some("I"));
+    assert_eq!(page.results[1].organization.as_deref(), Some("AHA"));
+    assert_eq!(page.results[1].year.as_deref(), Some("2023"));
+    assert_eq!(
+        page.results[1].title,
+        "2023 ACC/AHA/ACCP/HRS Guideline for the Diagnosis and Management of Atrial Fibrillation"
+    );
+    assert_eq!(
+        page.results[1].url.as_deref(),
+        Some("https://pubmed.ncbi.nlm.nih.gov/38033089/")
+    );
+}
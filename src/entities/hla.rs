@@ -0,0 +1,578 @@
+//! HLA allele cards: nomenclature, population frequencies, and associations.
+//!
+//! Allele names are resolved against the IPD-IMGT/HLA registry. Population
+//! frequencies and drug hypersensitivity recommendations come from CPIC, whose
+//! HLA frequency tables are compiled from Allele Frequency Net. Disease
+//! associations use a small curated table of well-replicated HLA risk alleles.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::error::BioMcpError;
+use crate::sources::cpic::{CpicClient, CpicFrequencyRow, CpicPairRow, CpicRecommendationRow};
+use crate::sources::ipd_imgt::{IpdImgtAlleleSearchResponse, IpdImgtClient};
+use crate::utils::text::non_empty;
+
+const HLA_SECTION_FREQUENCIES: &str = "frequencies";
+const HLA_SECTION_DRUGS: &str = "drugs";
+const HLA_SECTION_DISEASES: &str = "diseases";
+const HLA_SECTION_ALL: &str = "all";
+
+pub const HLA_SECTION_NAMES: &[&str] = &[
+    HLA_SECTION_FREQUENCIES,
+    HLA_SECTION_DRUGS,
+    HLA_SECTION_DISEASES,
+    HLA_SECTION_ALL,
+];
+
+const OPTIONAL_ENRICHMENT_TIMEOUT: Duration = Duration::from_secs(10);
+const IPD_IMGT_ALLELE_LIMIT: usize = 100;
+const IPD_IMGT_DISPLAY_LIMIT: usize = 10;
+const CPIC_ROW_LIMIT: usize = 200;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hla {
+    /// Normalized WHO allele name, e.g. `HLA-B*57:01`.
+    pub allele: String,
+    /// HGNC gene symbol of the locus, e.g. `HLA-B`.
+    pub gene: String,
+    pub locus: String,
+    pub resolution: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry: Option<HlaRegistry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry_note: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub frequencies: Vec<HlaFrequency>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub drug_associations: Vec<HlaDrugAssociation>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disease_associations: Vec<HlaDiseaseAssociation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HlaRegistry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accession: Option<String>,
+    pub allele_count: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alleles: Vec<HlaRegistryAllele>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HlaRegistryAllele {
+    pub name: String,
+    pub accession: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HlaFrequency {
+    pub allele: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub population_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_frequency: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_frequency: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HlaDrugAssociation {
+    pub drug: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genotype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implication: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommendation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpic_level: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guideline_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guideline_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HlaDiseaseAssociation {
+    /// Curated allele or allele group the association was reported for.
+    pub allele: String,
+    pub disease: String,
+    pub effect: String,
+}
+
+/// Parsed HLA allele name: locus plus colon-delimited nomenclature fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct HlaAllele {
+    locus: String,
+    fields: Vec<String>,
+    suffix: Option<char>,
+}
+
+impl HlaAllele {
+    /// Registry-style name without the `HLA-` prefix, e.g. `B*57:01`.
+    fn name(&self) -> String {
+        let mut out = format!("{}*{}", self.locus, self.fields.join(":"));
+        if let Some(suffix) = self.suffix {
+            out.push(suffix);
+        }
+        out
+    }
+
+    fn gene(&self) -> String {
+        format!("HLA-{}", self.locus)
+    }
+
+    fn resolution(&self) -> &'static str {
+        match self.fields.len() {
+            1 => "allele group",
+            2 => "protein (2-field)",
+            3 => "synonymous (3-field)",
+            _ => "non-coding (4-field)",
+        }
+    }
+
+    /// True when one allele name refines the other, field by field.
+    fn overlaps(&self, locus: &str, fields: &[String]) -> bool {
+        if !self.locus.eq_ignore_ascii_case(locus) {
+            return false;
+        }
+        let shared = self.fields.len().min(fields.len());
+        shared > 0 && self.fields[..shared] == fields[..shared]
+    }
+}
+
+fn allele_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        // Explicit loci keep colon-free legacy names (`B5701`, `DRB11501`) unambiguous.
+        Regex::new(
+            r"^(DRB[1-9]|D[PQ][AB][12]|DRA|DM[AB]|DO[AB]|[A-CE-HJ-LNPSTUVWY])\*?([0-9]{2,3}(?::[0-9]{2,3}){0,3}|[0-9]{4}|[0-9]{6}|[0-9]{8})([NLSCAQ])?$",
+        )
+        .expect("valid regex")
+    })
+}
+
+fn field_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^\*?([0-9]{2,3}(?::[0-9]{2,3}){0,3})").expect("valid regex"))
+}
+
+fn parse_allele(raw: &str) -> Result<HlaAllele, BioMcpError> {
+    let invalid = || {
+        BioMcpError::InvalidArgument(format!(
+            "Invalid HLA allele \"{}\". Use WHO nomenclature such as HLA-B*57:01 or B*57:01.",
+            raw.trim()
+        ))
+    };
+    let upper = raw.trim().to_ascii_uppercase();
+    let value = upper.strip_prefix("HLA-").unwrap_or(&upper);
+    let caps = allele_re().captures(value).ok_or_else(invalid)?;
+    let locus = caps[1].to_string();
+    let digits = &caps[2];
+    // Legacy colon-free names use two digits per field.
+    let fields = if digits.contains(':') || digits.len() < 4 {
+        digits.split(':').map(str::to_string).collect()
+    } else {
+        digits
+            .as_bytes()
+            .chunks(2)
+            .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+            .collect()
+    };
+    Ok(HlaAllele {
+        locus,
+        fields,
+        suffix: caps.get(3).and_then(|m| m.as_str().chars().next()),
+    })
+}
+
+/// Extracts nomenclature fields from CPIC allele labels such as `*57:01 positive`.
+fn cpic_allele_fields(label: &str) -> Option<Vec<String>> {
+    let caps = field_re().captures(label.trim())?;
+    Some(caps[1].split(':').map(str::to_string).collect())
+}
+
+struct CuratedDiseaseAssociation {
+    locus: &'static str,
+    fields: &'static [&'static str],
+    disease: &'static str,
+    effect: &'static str,
+}
+
+const CURATED_DISEASE_ASSOCIATIONS: &[CuratedDiseaseAssociation] = &[
+    CuratedDiseaseAssociation {
+        locus: "A",
+        fields: &["29", "02"],
+        disease: "birdshot chorioretinopathy",
+        effect: "risk",
+    },
+    CuratedDiseaseAssociation {
+        locus: "B",
+        fields: &["27"],
+        disease: "ankylosing spondylitis",
+        effect: "risk",
+    },
+    CuratedDiseaseAssociation {
+        locus: "B",
+        fields: &["27"],
+        disease: "reactive arthritis",
+        effect: "risk",
+    },
+    CuratedDiseaseAssociation {
+        locus: "B",
+        fields: &["51"],
+        disease: "Behcet disease",
+        effect: "risk",
+    },
+    CuratedDiseaseAssociation {
+        locus: "B",
+        fields: &["57", "01"],
+        disease: "HIV-1 infection (slower progression)",
+        effect: "protective",
+    },
+    CuratedDiseaseAssociation {
+        locus: "C",
+        fields: &["06", "02"],
+        disease: "psoriasis",
+        effect: "risk",
+    },
+    CuratedDiseaseAssociation {
+        locus: "DQA1",
+        fields: &["05", "01"],
+        disease: "celiac disease (DQ2.5)",
+        effect: "risk",
+    },
+    CuratedDiseaseAssociation {
+        locus: "DQB1",
+        fields: &["02", "01"],
+        disease: "celiac disease (DQ2.5)",
+        effect: "risk",
+    },
+    CuratedDiseaseAssociation {
+        locus: "DQB1",
+        fields: &["03", "02"],
+        disease: "celiac disease (DQ8)",
+        effect: "risk",
+    },
+    CuratedDiseaseAssociation {
+        locus: "DQB1",
+        fields: &["03", "02"],
+        disease: "type 1 diabetes",
+        effect: "risk",
+    },
+    CuratedDiseaseAssociation {
+        locus: "DQB1",
+        fields: &["06", "02"],
+        disease: "narcolepsy type 1",
+        effect: "risk",
+    },
+    CuratedDiseaseAssociation {
+        locus: "DQB1",
+        fields: &["06", "02"],
+        disease: "type 1 diabetes",
+        effect: "protective",
+    },
+    CuratedDiseaseAssociation {
+        locus: "DRB1",
+        fields: &["03", "01"],
+        disease: "type 1 diabetes",
+        effect: "risk",
+    },
+    CuratedDiseaseAssociation {
+        locus: "DRB1",
+        fields: &["04", "01"],
+        disease: "rheumatoid arthritis",
+        effect: "risk",
+    },
+    CuratedDiseaseAssociation {
+        locus: "DRB1",
+        fields: &["15", "01"],
+        disease: "multiple sclerosis",
+        effect: "risk",
+    },
+];
+
+fn disease_associations(allele: &HlaAllele) -> Vec<HlaDiseaseAssociation> {
+    CURATED_DISEASE_ASSOCIATIONS
+        .iter()
+        .filter(|row| {
+            let fields = row.fields.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+            allele.overlaps(row.locus, &fields)
+        })
+        .map(|row| HlaDiseaseAssociation {
+            allele: format!("HLA-{}*{}", row.locus, row.fields.join(":")),
+            disease: row.disease.to_string(),
+            effect: row.effect.to_string(),
+        })
+        .collect()
+}
+
+fn map_frequencies(allele: &HlaAllele, rows: &[CpicFrequencyRow]) -> Vec<HlaFrequency> {
+    let mut seen = HashSet::new();
+    let mut out = rows
+        .iter()
+        .filter(|row| {
+            cpic_allele_fields(&row.name)
+                .is_some_and(|fields| allele.overlaps(&allele.locus, &fields))
+        })
+        .filter(|row| {
+            seen.insert(format!(
+                "{}|{}",
+                row.name.trim(),
+                row.population_group
+                    .as_deref()
+                    .unwrap_or_default()
+                    .to_ascii_lowercase()
+            ))
+        })
+        .map(|row| HlaFrequency {
+            allele: format!("{}{}", allele.gene(), row.name.trim()),
+            population_group: row.population_group.clone(),
+            subject_count: row.subjectcount,
+            frequency: row
+                .freq_weighted_avg
+                .or(row.freq_avg)
+                .or(row.freq_max)
+                .or(row.freq_min),
+            min_frequency: row.freq_min,
+            max_frequency: row.freq_max,
+        })
+        .collect::<Vec<_>>();
+    out.sort_by(|a, b| {
+        a.allele.cmp(&b.allele).then_with(|| {
+            a.population_group
+                .as_deref()
+                .unwrap_or_default()
+                .cmp(b.population_group.as_deref().unwrap_or_default())
+        })
+    });
+    out
+}
+
+fn lookup_value(map: &HashMap<String, String>, gene: &str) -> Option<String> {
+    map.iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(gene))
+        .and_then(|(_, value)| non_empty(Some(value.as_str())))
+}
+
+/// Keeps CPIC recommendations whose genotype lookup names a carrier of `allele`.
+fn map_drug_associations(
+    allele: &HlaAllele,
+    recommendations: &[CpicRecommendationRow],
+    pairs: &[CpicPairRow],
+) -> Vec<HlaDrugAssociation> {
+    let gene = allele.gene();
+    let levels = pairs
+        .iter()
+        .filter(|row| row.genesymbol.eq_ignore_ascii_case(&gene))
+        .map(|row| {
+            (
+                row.drugname.trim().to_ascii_lowercase(),
+                row.cpiclevel.clone(),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut seen = HashSet::new();
+    let mut out = recommendations
+        .iter()
+        .filter_map(|row| {
+            let genotype = lookup_value(&row.lookupkey, &gene)?;
+            let fields = cpic_allele_fields(&genotype)?;
+            let carrier = genotype.to_ascii_lowercase().contains("positive");
+            if !carrier || !allele.overlaps(&allele.locus, &fields) {
+                return None;
+            }
+            let drug = row.drugname.trim();
+            if drug.is_empty() || !seen.insert((drug.to_ascii_lowercase(), genotype.clone())) {
+                return None;
+            }
+            Some(HlaDrugAssociation {
+                drug: drug.to_string(),
+                genotype: Some(format!("{gene}{genotype}")),
+                implication: lookup_value(&row.implications, &gene),
+                recommendation: non_empty(row.drugrecommendation.as_deref()),
+                classification: non_empty(row.classification.as_deref()),
+                cpic_level: levels.get(&drug.to_ascii_lowercase()).cloned().flatten(),
+                guideline_name: row.guidelinename.clone(),
+                guideline_url: row.guidelineurl.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+    out.sort_by(|a, b| a.drug.cmp(&b.drug));
+    out
+}
+
+fn map_registry(allele: &HlaAllele, resp: &IpdImgtAlleleSearchResponse) -> HlaRegistry {
+    let alleles = resp
+        .data
+        .iter()
+        .filter(|row| {
+            parse_allele(&row.name)
+                .is_ok_and(|parsed| allele.overlaps(&parsed.locus, &parsed.fields))
+        })
+        .map(|row| HlaRegistryAllele {
+            name: format!("HLA-{}", row.name.trim()),
+            accession: row.accession.trim().to_string(),
+        })
+        .collect::<Vec<_>>();
+    let exact = alleles
+        .iter()
+        .find(|row| {
+            row.name
+                .eq_ignore_ascii_case(&format!("HLA-{}", allele.name()))
+        })
+        .or_else(|| alleles.first())
+        .map(|row| row.accession.clone())
+        .filter(|v| !v.is_empty());
+    // The registry total also counts prefix-only matches such as `B*57:010`.
+    let dropped = resp.data.len() - alleles.len();
+    let allele_count = resp
+        .total()
+        .unwrap_or(0)
+        .saturating_sub(dropped)
+        .max(alleles.len());
+    HlaRegistry {
+        accession: exact,
+        allele_count,
+        alleles: alleles.into_iter().take(IPD_IMGT_DISPLAY_LIMIT).collect(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct HlaSections {
+    include_frequencies: bool,
+    include_drugs: bool,
+    include_diseases: bool,
+}
+
+fn parse_sections(sections: &[String]) -> Result<HlaSections, BioMcpError> {
+    let mut out = HlaSections::default();
+    let mut include_all = false;
+
+    for raw in sections {
+        let section = raw.trim().to_ascii_lowercase();
+        if section.is_empty() {
+            continue;
+        }
+        if section == "--json" || section == "-j" {
+            continue;
+        }
+
+        match section.as_str() {
+            HLA_SECTION_FREQUENCIES => out.include_frequencies = true,
+            HLA_SECTION_DRUGS => out.include_drugs = true,
+            HLA_SECTION_DISEASES => out.include_diseases = true,
+            HLA_SECTION_ALL => include_all = true,
+            _ => {
+                return Err(BioMcpError::InvalidArgument(format!(
+                    "Unknown section \"{section}\" for hla. Available: {}",
+                    HLA_SECTION_NAMES.join(", ")
+                )));
+            }
+        }
+    }
+
+    if include_all {
+        out.include_frequencies = true;
+        out.include_drugs = true;
+        out.include_diseases = true;
+    }
+
+    Ok(out)
+}
+
+async fn fetch_registry(allele: &HlaAllele) -> Result<IpdImgtAlleleSearchResponse, BioMcpError> {
+    IpdImgtClient::new()?
+        .alleles_by_prefix(&allele.name(), IPD_IMGT_ALLELE_LIMIT)
+        .await
+}
+
+pub async fn get(query: &str, sections: &[String]) -> Result<Hla, BioMcpError> {
+    let parsed_sections = parse_sections(sections)?;
+    if query.trim().is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "HLA allele is required. Example: biomcp get hla HLA-B*57:01".into(),
+        ));
+    }
+    let allele = parse_allele(query)?;
+    let gene = allele.gene();
+
+    let mut out = Hla {
+        allele: format!("HLA-{}", allele.name()),
+        gene: gene.clone(),
+        locus: allele.locus.clone(),
+        resolution: allele.resolution().to_string(),
+        registry: None,
+        registry_note: None,
+        frequencies: Vec::new(),
+        drug_associations: Vec::new(),
+        disease_associations: Vec::new(),
+    };
+
+    match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, fetch_registry(&allele)).await {
+        Ok(Ok(resp)) => {
+            let registry = map_registry(&allele, &resp);
+            if registry.alleles.is_empty() {
+                return Err(BioMcpError::NotFound {
+                    entity: "hla".into(),
+                    id: out.allele,
+                    suggestion: format!(
+                        "Check the allele name, or try the allele group: biomcp get hla HLA-{}*{}",
+                        allele.locus, allele.fields[0]
+                    ),
+                });
+            }
+            out.registry = Some(registry);
+        }
+        Ok(Err(err)) => {
+            warn!("IPD-IMGT/HLA lookup unavailable: {err}");
+            out.registry_note =
+                Some("IPD-IMGT/HLA registry unavailable; allele name was not verified.".into());
+        }
+        Err(_) => {
+            warn!(
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "IPD-IMGT/HLA lookup timed out"
+            );
+            out.registry_note =
+                Some("IPD-IMGT/HLA registry timed out; allele name was not verified.".into());
+        }
+    }
+
+    if parsed_sections.include_frequencies || parsed_sections.include_drugs {
+        let cpic = CpicClient::new()?;
+        if parsed_sections.include_frequencies {
+            let rows = cpic.frequencies_by_gene(&gene, CPIC_ROW_LIMIT).await?;
+            out.frequencies = map_frequencies(&allele, &rows);
+        }
+        if parsed_sections.include_drugs {
+            let (recommendations, pairs) = tokio::try_join!(
+                cpic.recommendations_by_gene(&gene, CPIC_ROW_LIMIT),
+                cpic.pairs_by_gene(&gene, CPIC_ROW_LIMIT),
+            )?;
+            out.drug_associations = map_drug_associations(&allele, &recommendations, &pairs);
+        }
+    }
+
+    if parsed_sections.include_diseases {
+        out.disease_associations = disease_associations(&allele);
+    }
+
+    crate::transform::fields::apply("hla", out)
+}
+
+#[cfg(test)]
+mod tests;
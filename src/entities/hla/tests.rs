@@ -0,0 +1,192 @@
+//! Tests for HLA allele parsing and association mapping.
+
+use super::*;
+
+fn frequency_row(name: &str, population: &str, freq: f64) -> CpicFrequencyRow {
+    CpicFrequencyRow {
+        genesymbol: "HLA-B".into(),
+        name: name.into(),
+        population_group: Some(population.into()),
+        subjectcount: Some(1000),
+        freq_weighted_avg: Some(freq),
+        freq_avg: None,
+        freq_max: None,
+        freq_min: None,
+    }
+}
+
+fn recommendation_row(drug: &str, genotype: &str, text: &str) -> CpicRecommendationRow {
+    CpicRecommendationRow {
+        recommendationid: None,
+        lookupkey: HashMap::from([("HLA-B".to_string(), genotype.to_string())]),
+        drugname: drug.into(),
+        guidelinename: Some("CPIC Guideline for abacavir and HLA-B".into()),
+        guidelineurl: None,
+        implications: HashMap::from([(
+            "HLA-B".to_string(),
+            "Significantly increased risk of hypersensitivity".to_string(),
+        )]),
+        drugrecommendation: Some(text.into()),
+        classification: Some("Strong".into()),
+        phenotypes: HashMap::new(),
+        activityscore: HashMap::new(),
+        population: None,
+    }
+}
+
+#[test]
+fn parse_allele_normalizes_prefix_case_and_legacy_names() {
+    let parsed = parse_allele("hla-b*57:01").expect("prefixed allele");
+    assert_eq!(parsed.name(), "B*57:01");
+    assert_eq!(parsed.gene(), "HLA-B");
+    assert_eq!(parsed.resolution(), "protein (2-field)");
+
+    assert_eq!(parse_allele("B5701").expect("legacy").name(), "B*57:01");
+    assert_eq!(
+        parse_allele("DRB11501").expect("legacy class II").name(),
+        "DRB1*15:01"
+    );
+    assert_eq!(
+        parse_allele("B*27").expect("group").resolution(),
+        "allele group"
+    );
+    assert_eq!(
+        parse_allele("A*24:09N").expect("null allele").suffix,
+        Some('N')
+    );
+}
+
+#[test]
+fn parse_allele_rejects_non_hla_input() {
+    for raw in ["HLA-B", "CYP2D6*4", "B*5:01", "abacavir"] {
+        let err = parse_allele(raw).expect_err("should reject");
+        assert!(err.to_string().contains("HLA-B*57:01"), "{raw}: {err}");
+    }
+}
+
+#[test]
+fn overlaps_matches_refinements_in_either_direction() {
+    let allele = parse_allele("B*27:05").expect("allele");
+    assert!(allele.overlaps("B", &["27".to_string()]));
+    assert!(allele.overlaps("B", &["27".into(), "05".into(), "02".into()]));
+    assert!(!allele.overlaps("B", &["27".into(), "02".into()]));
+    assert!(!allele.overlaps("C", &["27".into()]));
+}
+
+#[test]
+fn map_frequencies_keeps_matching_alleles_only() {
+    let allele = parse_allele("HLA-B*57:01").expect("allele");
+    let rows = vec![
+        frequency_row("*57:01", "European", 0.068),
+        frequency_row("*57:01", "European", 0.068),
+        frequency_row("*58:01", "East Asian", 0.079),
+        frequency_row("*57:01", "African American/Afro-Caribbean", 0.010),
+    ];
+
+    let out = map_frequencies(&allele, &rows);
+    assert_eq!(out.len(), 2);
+    assert!(out.iter().all(|row| row.allele == "HLA-B*57:01"));
+    assert_eq!(
+        out[0].population_group.as_deref(),
+        Some("African American/Afro-Caribbean")
+    );
+    assert_eq!(out[1].frequency, Some(0.068));
+}
+
+#[test]
+fn map_drug_associations_keeps_positive_carrier_recommendations() {
+    let allele = parse_allele("HLA-B*57:01").expect("allele");
+    let recommendations = vec![
+        recommendation_row("abacavir", "*57:01 positive", "Abacavir is not recommended"),
+        recommendation_row(
+            "abacavir",
+            "*57:01 negative",
+            "Use abacavir per standard dosing",
+        ),
+        recommendation_row(
+            "allopurinol",
+            "*58:01 positive",
+            "Allopurinol is contraindicated",
+        ),
+    ];
+    let pairs = vec![CpicPairRow {
+        pairid: None,
+        genesymbol: "HLA-B".into(),
+        drugname: "abacavir".into(),
+        cpiclevel: Some("A".into()),
+        pgxtesting: None,
+        guidelinename: None,
+        guidelineurl: None,
+        usedforrecommendation: None,
+        provisional: None,
+    }];
+
+    let out = map_drug_associations(&allele, &recommendations, &pairs);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].drug, "abacavir");
+    assert_eq!(out[0].genotype.as_deref(), Some("HLA-B*57:01 positive"));
+    assert_eq!(out[0].cpic_level.as_deref(), Some("A"));
+    assert_eq!(
+        out[0].recommendation.as_deref(),
+        Some("Abacavir is not recommended")
+    );
+}
+
+#[test]
+fn disease_associations_match_allele_groups() {
+    let b27 = parse_allele("HLA-B*27:05").expect("allele");
+    let diseases = disease_associations(&b27)
+        .into_iter()
+        .map(|row| row.disease)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        diseases,
+        vec!["ankylosing spondylitis", "reactive arthritis"]
+    );
+
+    let dq = parse_allele("DQB1*06:02").expect("allele");
+    let effects = disease_associations(&dq)
+        .into_iter()
+        .map(|row| (row.disease, row.effect))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        effects,
+        vec![
+            ("narcolepsy type 1".to_string(), "risk".to_string()),
+            ("type 1 diabetes".to_string(), "protective".to_string()),
+        ]
+    );
+
+    assert!(disease_associations(&parse_allele("A*02:01").expect("allele")).is_empty());
+}
+
+#[test]
+fn map_registry_drops_prefix_only_siblings() {
+    let allele = parse_allele("B*57:01").expect("allele");
+    let resp: IpdImgtAlleleSearchResponse = serde_json::from_value(serde_json::json!({
+        "data": [
+            {"accession": "HLA03233", "name": "B*57:01:02"},
+            {"accession": "HLA00381", "name": "B*57:01:01:01"},
+            {"accession": "HLA99999", "name": "B*57:010"}
+        ],
+        "meta": {"total": 3}
+    }))
+    .expect("response");
+
+    let registry = map_registry(&allele, &resp);
+    assert_eq!(registry.alleles.len(), 2);
+    assert_eq!(registry.accession.as_deref(), Some("HLA03233"));
+    assert_eq!(registry.allele_count, 2);
+}
+
+#[test]
+fn parse_sections_rejects_unknown_names() {
+    let parsed = parse_sections(&["all".to_string()]).expect("all");
+    assert!(parsed.include_frequencies && parsed.include_drugs && parsed.include_diseases);
+
+    let err = parse_sections(&["alleles".to_string()]).expect_err("unknown");
+    assert!(
+        err.to_string()
+            .contains("frequencies, drugs, diseases, all")
+    );
+}
@@ -3,13 +3,26 @@
 pub(crate) mod adverse_event;
 pub(crate) mod article;
 pub(crate) mod discover;
+pub(crate) mod dossier;
 pub(crate) mod disease;
 pub(crate) mod drug;
+pub(crate) mod entity_schema;
 pub(crate) mod gene;
+pub(crate) mod guideline;
+pub(crate) mod hla;
+pub(crate) mod network;
+pub(crate) mod normalize;
+pub(crate) mod ontology;
+pub(crate) mod panel;
 pub(crate) mod pathway;
 pub(crate) mod pgx;
 pub(crate) mod protein;
+pub(crate) mod repurpose;
+pub(crate) mod section_errors;
+pub(crate) mod section_manifest;
+pub(crate) mod structure;
 pub(crate) mod study;
+pub(crate) mod transcript;
 pub(crate) mod trial;
 pub(crate) mod variant;
 
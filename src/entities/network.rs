@@ -0,0 +1,309 @@
+//! Protein-protein interaction graphs built from STRING.
+//!
+//! Each level of the walk asks STRING for the top partners of every protein
+//! added at the previous level. STRING answers with the partner subnetwork, so
+//! edges between partners are kept as well, and an edge reported by several
+//! queries is stored once with its highest score.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use futures::{StreamExt, stream};
+use serde::Serialize;
+
+use crate::error::BioMcpError;
+use crate::sources::string::{StringClient, StringInteraction};
+
+const HUMAN_TAXON: u32 = 9606;
+pub(crate) const MAX_NETWORK_DEPTH: usize = 3;
+/// Proteins per graph before the walk stops adding nodes.
+const MAX_NETWORK_NODES: usize = 300;
+/// STRING returns at most 25 partners per query.
+const MAX_NETWORK_PARTNERS: usize = 25;
+const NETWORK_FETCH_CONCURRENCY: usize = 4;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InteractionNetwork {
+    /// Gene symbol STRING was queried with.
+    pub seed: String,
+    pub accession: String,
+    pub depth: usize,
+    /// Minimum STRING combined score (0-1) an edge needs to be kept.
+    pub min_score: f64,
+    pub nodes: Vec<NetworkNode>,
+    pub edges: Vec<NetworkEdge>,
+    /// The walk stopped at the node limit before reaching `depth`.
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkNode {
+    pub id: String,
+    /// Walk level the protein was first reached at; the seed is level 0.
+    pub level: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkEdge {
+    pub source: String,
+    pub target: String,
+    pub score: f64,
+}
+
+#[derive(Default)]
+struct NetworkBuilder {
+    index: HashMap<String, usize>,
+    nodes: Vec<NetworkNode>,
+    edges: HashMap<(usize, usize), f64>,
+    truncated: bool,
+}
+
+impl NetworkBuilder {
+    fn node(&mut self, name: &str, level: usize) -> usize {
+        let key = name.to_ascii_uppercase();
+        if let Some(idx) = self.index.get(&key) {
+            return *idx;
+        }
+        let idx = self.nodes.len();
+        self.nodes.push(NetworkNode {
+            id: name.to_string(),
+            level,
+        });
+        self.index.insert(key, idx);
+        idx
+    }
+
+    /// Adds one STRING row, returning proteins seen for the first time.
+    fn add_interaction(
+        &mut self,
+        row: &StringInteraction,
+        min_score: f64,
+        level: usize,
+    ) -> Vec<String> {
+        let name = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        let (Some(a), Some(b), Some(score)) = (
+            name(&row.preferred_name_a),
+            name(&row.preferred_name_b),
+            row.score,
+        ) else {
+            return Vec::new();
+        };
+        if score < min_score || a.eq_ignore_ascii_case(&b) {
+            return Vec::new();
+        }
+
+        let added = [&a, &b]
+            .into_iter()
+            .filter(|name| !self.index.contains_key(&name.to_ascii_uppercase()))
+            .cloned()
+            .collect::<Vec<_>>();
+        if self.nodes.len() + added.len() > MAX_NETWORK_NODES {
+            self.truncated = true;
+            return Vec::new();
+        }
+        let (a, b) = (self.node(&a, level), self.node(&b, level));
+        let best = self.edges.entry((a.min(b), a.max(b))).or_insert(score);
+        *best = best.max(score);
+        added
+    }
+
+    fn finish(
+        self,
+        seed: String,
+        accession: String,
+        depth: usize,
+        min_score: f64,
+    ) -> InteractionNetwork {
+        let mut edges = self
+            .edges
+            .into_iter()
+            .map(|((a, b), score)| NetworkEdge {
+                source: self.nodes[a].id.clone(),
+                target: self.nodes[b].id.clone(),
+                score,
+            })
+            .collect::<Vec<_>>();
+        edges.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.source.cmp(&b.source))
+                .then_with(|| a.target.cmp(&b.target))
+        });
+        InteractionNetwork {
+            seed,
+            accession,
+            depth,
+            min_score,
+            nodes: self.nodes,
+            edges,
+            truncated: self.truncated,
+        }
+    }
+}
+
+/// Builds the STRING interaction graph `depth` levels out from a protein.
+pub async fn protein_network(
+    accession: &str,
+    depth: usize,
+    min_score: f64,
+    partners: usize,
+) -> Result<InteractionNetwork, BioMcpError> {
+    if !(1..=MAX_NETWORK_DEPTH).contains(&depth) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--depth must be between 1 and {MAX_NETWORK_DEPTH}"
+        )));
+    }
+    if !(0.0..=1.0).contains(&min_score) {
+        return Err(BioMcpError::InvalidArgument(
+            "--min-score must be between 0 and 1 (STRING combined score, e.g. 0.7)".into(),
+        ));
+    }
+    if !(1..=MAX_NETWORK_PARTNERS).contains(&partners) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--limit must be between 1 and {MAX_NETWORK_PARTNERS}"
+        )));
+    }
+
+    let protein = crate::entities::protein::get(accession, &[]).await?;
+    let seed = protein
+        .gene_symbol
+        .as_deref()
+        .map(str::trim)
+        .filter(|symbol| !symbol.is_empty())
+        .unwrap_or(&protein.accession)
+        .to_string();
+
+    let client = StringClient::new()?;
+    let required_score = (min_score * 1000.0).round() as u16;
+    let mut builder = NetworkBuilder::default();
+    builder.node(&seed, 0);
+    let mut frontier = vec![seed.clone()];
+
+    for level in 1..=depth {
+        if frontier.is_empty() || builder.truncated {
+            break;
+        }
+        let fetched = stream::iter(frontier.iter().map(|name| {
+            let client = &client;
+            async move {
+                client
+                    .scored_interactions(name, HUMAN_TAXON, partners, required_score)
+                    .await
+            }
+        }))
+        .buffered(NETWORK_FETCH_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+        let mut next = Vec::new();
+        for rows in fetched {
+            for row in rows? {
+                next.extend(builder.add_interaction(&row, min_score, level));
+            }
+        }
+        frontier = next;
+    }
+
+    Ok(builder.finish(seed, protein.accession, depth, min_score))
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn dot_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl InteractionNetwork {
+    /// Renders the graph as GraphML with `level` and `score` attributes.
+    pub fn to_graphml(&self) -> String {
+        let mut out = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n  \
+<key id=\"level\" for=\"node\" attr.name=\"level\" attr.type=\"int\"/>\n  \
+<key id=\"score\" for=\"edge\" attr.name=\"score\" attr.type=\"double\"/>\n",
+        );
+        let _ = writeln!(
+            out,
+            "  <graph id=\"{}\" edgedefault=\"undirected\">",
+            xml_escape(&self.seed)
+        );
+        for node in &self.nodes {
+            let _ = writeln!(
+                out,
+                "    <node id=\"{}\"><data key=\"level\">{}</data></node>",
+                xml_escape(&node.id),
+                node.level
+            );
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                out,
+                "    <edge source=\"{}\" target=\"{}\"><data key=\"score\">{}</data></edge>",
+                xml_escape(&edge.source),
+                xml_escape(&edge.target),
+                edge.score
+            );
+        }
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+
+    /// Renders the graph as an undirected Graphviz DOT graph.
+    pub fn to_dot(&self) -> String {
+        let mut out = format!("graph {} {{\n", dot_quote(&self.seed));
+        for node in &self.nodes {
+            let _ = writeln!(out, "  {} [level={}];", dot_quote(&node.id), node.level);
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                out,
+                "  {} -- {} [score={}];",
+                dot_quote(&edge.source),
+                dot_quote(&edge.target),
+                edge.score
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Cytoscape.js `elements` JSON, which Cytoscape imports directly.
+    pub fn to_cytoscape(&self) -> serde_json::Value {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| serde_json::json!({"data": {"id": node.id, "level": node.level}}))
+            .collect::<Vec<_>>();
+        let edges = self
+            .edges
+            .iter()
+            .map(|edge| {
+                serde_json::json!({"data": {
+                    "id": format!("{}-{}", edge.source, edge.target),
+                    "source": edge.source,
+                    "target": edge.target,
+                    "score": edge.score,
+                }})
+            })
+            .collect::<Vec<_>>();
+        serde_json::json!({
+            "data": {"name": format!("STRING network: {}", self.seed)},
+            "elements": {"nodes": nodes, "edges": edges},
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,85 @@
+use super::*;
+
+fn row(a: &str, b: &str, score: f64) -> StringInteraction {
+    StringInteraction {
+        preferred_name_a: Some(a.to_string()),
+        preferred_name_b: Some(b.to_string()),
+        score: Some(score),
+    }
+}
+
+#[test]
+fn builder_keeps_best_score_and_drops_weak_edges() {
+    let mut builder = NetworkBuilder::default();
+    builder.node("BRAF", 0);
+
+    let added = builder.add_interaction(&row("BRAF", "MAP2K1", 0.91), 0.7, 1);
+    assert_eq!(added, vec!["MAP2K1".to_string()]);
+    assert!(
+        builder
+            .add_interaction(&row("map2k1", "BRAF", 0.99), 0.7, 2)
+            .is_empty()
+    );
+    assert!(
+        builder
+            .add_interaction(&row("BRAF", "YWHAB", 0.4), 0.7, 1)
+            .is_empty()
+    );
+    assert_eq!(
+        builder.add_interaction(&row("MAP2K1", "MAPK1", 0.95), 0.7, 2),
+        vec!["MAPK1".to_string()]
+    );
+
+    let network = builder.finish("BRAF".into(), "P15056".into(), 2, 0.7);
+    assert_eq!(network.nodes.len(), 3);
+    assert_eq!(network.nodes[2].level, 2);
+    assert_eq!(network.edges.len(), 2);
+    assert_eq!(network.edges[0].source, "BRAF");
+    assert_eq!(network.edges[0].target, "MAP2K1");
+    assert_eq!(network.edges[0].score, 0.99);
+    assert!(!network.truncated);
+}
+
+#[test]
+fn builder_stops_adding_nodes_at_the_limit() {
+    let mut builder = NetworkBuilder::default();
+    builder.node("SEED", 0);
+    for idx in 1..=MAX_NETWORK_NODES {
+        builder.add_interaction(&row("SEED", &format!("P{idx}"), 0.9), 0.7, 1);
+    }
+    assert!(builder.truncated);
+    assert_eq!(builder.nodes.len(), MAX_NETWORK_NODES);
+    assert_eq!(builder.edges.len(), MAX_NETWORK_NODES - 1);
+}
+
+#[test]
+fn exports_escape_identifiers() {
+    let mut builder = NetworkBuilder::default();
+    builder.node("BRAF", 0);
+    builder.add_interaction(&row("BRAF", "A&\"B", 0.8), 0.7, 1);
+    let network = builder.finish("BRAF".into(), "P15056".into(), 1, 0.7);
+
+    let graphml = network.to_graphml();
+    assert!(graphml.contains("<node id=\"A&amp;&quot;B\"><data key=\"level\">1</data></node>"));
+    assert!(graphml.contains(
+        "<edge source=\"BRAF\" target=\"A&amp;&quot;B\"><data key=\"score\">0.8</data></edge>"
+    ));
+
+    let dot = network.to_dot();
+    assert!(dot.starts_with("graph \"BRAF\" {\n"));
+    assert!(dot.contains("  \"BRAF\" -- \"A&\\\"B\" [score=0.8];"));
+
+    let cytoscape = network.to_cytoscape();
+    assert_eq!(cytoscape["elements"]["nodes"][1]["data"]["id"], "A&\"B");
+    assert_eq!(cytoscape["elements"]["edges"][0]["data"]["score"], 0.8);
+}
+
+#[tokio::test]
+async fn protein_network_rejects_out_of_range_options() {
+    let err = protein_network("P15056", 4, 0.7, 10).await.unwrap_err();
+    assert!(err.to_string().contains("--depth must be between 1 and 3"));
+    let err = protein_network("P15056", 1, 700.0, 10).await.unwrap_err();
+    assert!(err.to_string().contains("--min-score"));
+    let err = protein_network("P15056", 1, 0.7, 0).await.unwrap_err();
+    assert!(err.to_string().contains("--limit must be between 1 and 25"));
+}
@@ -0,0 +1,108 @@
+//! NCI Thesaurus normalization of abbreviated disease and drug names.
+//!
+//! Queries such as `NSCLC` or `5-FU` rarely match the labels MyDisease.info and
+//! MyChem.info index. Abbreviation-like names are matched against NCIt first
+//! and searched under the concept's preferred name and synonyms. Commands run
+//! with `--no-normalize` wrap their fetch in [`with_normalization`] to query the
+//! name exactly as typed.
+
+use std::future::Future;
+
+use tracing::warn;
+
+use crate::sources::nci_evs::{NciEvsClient, NcitConcept};
+
+/// Longest single token still treated as an abbreviation.
+const MAX_ABBREVIATION_LEN: usize = 12;
+
+/// Expanded names queried per disease concept, preferred name first.
+const DISEASE_NAME_LIMIT: usize = 3;
+
+tokio::task_local! {
+    static NORMALIZATION_DISABLED: ();
+}
+
+/// Runs `fut` with NCIt normalization on or off.
+pub(crate) async fn with_normalization<F>(enabled: bool, fut: F) -> F::Output
+where
+    F: Future,
+{
+    if enabled {
+        fut.await
+    } else {
+        NORMALIZATION_DISABLED.scope((), fut).await
+    }
+}
+
+/// False inside a `--no-normalize` fetch.
+pub(crate) fn enabled() -> bool {
+    NORMALIZATION_DISABLED.try_with(|_| ()).is_err()
+}
+
+/// A single short token written mostly in capitals or digits (`NSCLC`, `5-FU`,
+/// `mCRPC`). Ordinary words and ontology IDs are left alone.
+pub(crate) fn looks_abbreviated(name: &str) -> bool {
+    let name = name.trim();
+    if name.is_empty()
+        || name.len() > MAX_ABBREVIATION_LEN
+        || name.contains(':')
+        || name.chars().any(char::is_whitespace)
+    {
+        return false;
+    }
+    let upper = name.chars().filter(char::is_ascii_uppercase).count();
+    let lower = name.chars().filter(char::is_ascii_lowercase).count();
+    upper > 0 && upper > lower
+}
+
+/// Whether `name` should be expanded through NCIt before querying.
+pub(crate) fn applies(name: &str) -> bool {
+    enabled() && looks_abbreviated(name)
+}
+
+fn disease_names(concept: &NcitConcept, queried: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    let candidates = std::iter::once(concept.name.trim())
+        .chain(concept.synonyms_of_type("PT"))
+        .chain(concept.synonyms_of_type("SY"));
+    for name in candidates {
+        if name.is_empty()
+            || looks_abbreviated(name)
+            || name.eq_ignore_ascii_case(queried.trim())
+            || out.iter().any(|seen| seen.eq_ignore_ascii_case(name))
+        {
+            continue;
+        }
+        out.push(name.to_string());
+        if out.len() == DISEASE_NAME_LIMIT {
+            break;
+        }
+    }
+    out
+}
+
+/// NCIt preferred name and synonyms for an abbreviated disease query; empty
+/// when normalization does not apply or NCI EVS has no match. NCI EVS failures
+/// are logged and the query runs as typed.
+pub(crate) async fn expand_disease_name(name: &str) -> Vec<String> {
+    if !applies(name) {
+        return Vec::new();
+    }
+    let result = match NciEvsClient::new() {
+        Ok(client) => client.disease_concepts(name).await,
+        Err(err) => Err(err),
+    };
+    match result {
+        Ok(concepts) => concepts
+            .first()
+            .map(|concept| disease_names(concept, name))
+            .unwrap_or_default(),
+        Err(err) => {
+            warn!(disease = %name, "NCI EVS unavailable for name normalization: {err}");
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
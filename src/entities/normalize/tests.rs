@@ -0,0 +1,58 @@
+use super::*;
+
+fn concept(value: serde_json::Value) -> NcitConcept {
+    serde_json::from_value(value).expect("concept")
+}
+
+#[test]
+fn looks_abbreviated_accepts_short_capitalized_tokens_only() {
+    for name in ["NSCLC", "5-FU", "mCRPC", "T-DM1", " CML "] {
+        assert!(looks_abbreviated(name), "{name} should be abbreviated");
+    }
+    for name in [
+        "melanoma",
+        "Melanoma",
+        "lung cancer",
+        "MONDO:0005105",
+        "5",
+        "ABCDEFGHIJKLMN",
+    ] {
+        assert!(!looks_abbreviated(name), "{name} should not be abbreviated");
+    }
+}
+
+#[test]
+fn disease_names_put_preferred_name_first_and_skip_abbreviations() {
+    let concept = concept(serde_json::json!({
+        "code": "C2926",
+        "name": "Lung Non-Small Cell Carcinoma",
+        "synonyms": [
+            {"name": "NSCLC", "termType": "SY"},
+            {"name": "Non-Small Cell Lung Cancer", "termType": "SY"},
+            {"name": "non-small cell lung cancer", "termType": "SY"},
+            {"name": "Non-Small Cell Lung Carcinoma", "termType": "SY"},
+            {"name": "NSCLC - Non-Small Cell Lung Cancer", "termType": "SY"}
+        ]
+    }));
+
+    assert_eq!(
+        disease_names(&concept, "NSCLC"),
+        vec![
+            "Lung Non-Small Cell Carcinoma",
+            "Non-Small Cell Lung Cancer",
+            "Non-Small Cell Lung Carcinoma",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn with_normalization_disables_expansion_inside_scope() {
+    assert!(applies("NSCLC"));
+    assert!(!with_normalization(false, async { applies("NSCLC") }).await);
+    assert!(with_normalization(true, async { applies("NSCLC") }).await);
+    assert!(
+        with_normalization(false, expand_disease_name("NSCLC"))
+            .await
+            .is_empty()
+    );
+}
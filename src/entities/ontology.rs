@@ -0,0 +1,227 @@
+//! Disease ontology traversal over the MONDO hierarchy.
+//!
+//! MONDO is a polyhierarchy: a term can have several parents, so walking down
+//! or up from one term reaches some terms along more than one path. The walk
+//! fetches each term's relatives from OLS once and marks later occurrences as
+//! repeats instead of expanding them again, which keeps the tree readable and
+//! bounds the number of requests.
+
+use std::collections::HashSet;
+
+use futures::{StreamExt, stream};
+use serde::Serialize;
+
+use crate::error::BioMcpError;
+use crate::sources::ols4::{OlsClient, OlsRelation, OlsTerm};
+
+const MONDO_ONTOLOGY: &str = "mondo";
+const MONDO_IRI_PREFIX: &str = "http://purl.obolibrary.org/obo/MONDO_";
+pub(crate) const MAX_ONTOLOGY_DEPTH: usize = 10;
+/// Terms listed per tree before the walk stops expanding.
+const MAX_ONTOLOGY_NODES: usize = 250;
+const RELATIVES_FETCH_CONCURRENCY: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OntologyDirection {
+    Children,
+    Ancestors,
+}
+
+impl OntologyDirection {
+    fn relation(self) -> OlsRelation {
+        match self {
+            Self::Children => OlsRelation::Children,
+            Self::Ancestors => OlsRelation::Parents,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OntologyTree {
+    /// Ontology prefix, e.g. `MONDO`.
+    pub ontology: String,
+    pub direction: OntologyDirection,
+    pub depth: usize,
+    pub root: OntologyNode,
+    /// Terms listed below the root, repeats included.
+    pub node_count: usize,
+    /// The walk stopped at the term limit before reaching `depth`.
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OntologyNode {
+    pub id: String,
+    pub name: String,
+    /// The term has relatives in this direction beyond the depth limit.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub has_more: bool,
+    /// Already listed elsewhere in the tree; its relatives are shown there.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub repeated: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<OntologyNode>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parents: Vec<OntologyNode>,
+}
+
+/// Normalizes `MONDO:0005105`, `MONDO_0005105`, or `mondo:0005105` to the CURIE form.
+fn normalize_mondo_id(value: &str) -> Result<String, BioMcpError> {
+    let value = value.trim();
+    let digits = value
+        .get(..6)
+        .filter(|prefix| {
+            prefix.eq_ignore_ascii_case("MONDO:") || prefix.eq_ignore_ascii_case("MONDO_")
+        })
+        .map(|_| &value[6..])
+        .filter(|digits| digits.len() == 7 && digits.bytes().all(|b| b.is_ascii_digit()));
+    match digits {
+        Some(digits) => Ok(format!("MONDO:{digits}")),
+        None => Err(BioMcpError::InvalidArgument(format!(
+            "Expected a MONDO ID like MONDO:0005105 (got '{value}'). Find one with: biomcp search disease <name>"
+        ))),
+    }
+}
+
+fn mondo_iri(curie: &str) -> String {
+    format!("{MONDO_IRI_PREFIX}{}", curie.trim_start_matches("MONDO:"))
+}
+
+/// MONDO CURIE for a term, skipping imported classes from other ontologies.
+fn mondo_curie(term: &OlsTerm) -> Option<String> {
+    term.obo_id
+        .as_deref()
+        .filter(|id| id.starts_with("MONDO:"))
+        .map(str::to_string)
+}
+
+struct WalkNode {
+    id: String,
+    name: String,
+    has_more: bool,
+    repeated: bool,
+    relatives: Vec<usize>,
+}
+
+impl WalkNode {
+    fn new(id: String, term: &OlsTerm, direction: OntologyDirection) -> Self {
+        Self {
+            id,
+            name: term.label.trim().to_string(),
+            has_more: match direction {
+                OntologyDirection::Children => term.has_children,
+                OntologyDirection::Ancestors => !term.is_root,
+            },
+            repeated: false,
+            relatives: Vec::new(),
+        }
+    }
+}
+
+fn build_node(nodes: &[WalkNode], idx: usize, direction: OntologyDirection) -> OntologyNode {
+    let node = &nodes[idx];
+    let relatives = node
+        .relatives
+        .iter()
+        .map(|child| build_node(nodes, *child, direction))
+        .collect::<Vec<_>>();
+    let (children, parents) = match direction {
+        OntologyDirection::Children => (relatives, Vec::new()),
+        OntologyDirection::Ancestors => (Vec::new(), relatives),
+    };
+    OntologyNode {
+        id: node.id.clone(),
+        name: node.name.clone(),
+        has_more: node.has_more,
+        repeated: node.repeated,
+        children,
+        parents,
+    }
+}
+
+/// Walks `depth` levels of MONDO children or ancestors from `id`.
+pub async fn disease_hierarchy(
+    id: &str,
+    direction: OntologyDirection,
+    depth: usize,
+) -> Result<OntologyTree, BioMcpError> {
+    if !(1..=MAX_ONTOLOGY_DEPTH).contains(&depth) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--depth must be between 1 and {MAX_ONTOLOGY_DEPTH}"
+        )));
+    }
+    let curie = normalize_mondo_id(id)?;
+    let client = OlsClient::new()?;
+    let Some(root_term) = client.term(MONDO_ONTOLOGY, &mondo_iri(&curie)).await? else {
+        return Err(BioMcpError::NotFound {
+            entity: "disease".into(),
+            id: curie,
+            suggestion: "Find MONDO IDs with: biomcp search disease <name>".into(),
+        });
+    };
+
+    let mut nodes = vec![WalkNode::new(curie.clone(), &root_term, direction)];
+    let mut expanded = HashSet::from([curie]);
+    let mut frontier = vec![0];
+    let mut truncated = false;
+
+    for _ in 0..depth {
+        if frontier.is_empty() || truncated {
+            break;
+        }
+        let fetched = stream::iter(frontier.iter().map(|idx| {
+            let client = &client;
+            let iri = mondo_iri(&nodes[*idx].id);
+            async move {
+                client
+                    .related_terms(MONDO_ONTOLOGY, &iri, direction.relation())
+                    .await
+            }
+        }))
+        .buffered(RELATIVES_FETCH_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+        let mut next = Vec::new();
+        for (parent_idx, terms) in frontier.iter().copied().zip(fetched) {
+            let mut terms = terms?
+                .into_iter()
+                .filter(|term| !term.is_obsolete)
+                .filter_map(|term| Some((mondo_curie(&term)?, term)))
+                .collect::<Vec<_>>();
+            terms.sort_by(|a, b| a.1.label.to_lowercase().cmp(&b.1.label.to_lowercase()));
+            terms.dedup_by(|a, b| a.0 == b.0);
+            nodes[parent_idx].has_more = false;
+            for (term_id, term) in terms {
+                if nodes.len() > MAX_ONTOLOGY_NODES {
+                    truncated = true;
+                    nodes[parent_idx].has_more = true;
+                    break;
+                }
+                let mut node = WalkNode::new(term_id.clone(), &term, direction);
+                if expanded.insert(term_id) {
+                    next.push(nodes.len());
+                } else {
+                    node.repeated = true;
+                    node.has_more = false;
+                }
+                nodes[parent_idx].relatives.push(nodes.len());
+                nodes.push(node);
+            }
+        }
+        frontier = next;
+    }
+
+    Ok(OntologyTree {
+        ontology: "MONDO".to_string(),
+        direction,
+        depth,
+        node_count: nodes.len() - 1,
+        root: build_node(&nodes, 0, direction),
+        truncated,
+    })
+}
+
+#[cfg(test)]
+mod tests;
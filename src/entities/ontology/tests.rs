@@ -0,0 +1,160 @@
+//! Tests for MONDO hierarchy traversal.
+
+use super::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn env_lock_async() -> tokio::sync::MutexGuard<'static, ()> {
+    crate::test_support::env_lock().lock().await
+}
+
+struct EnvVarGuard {
+    name: &'static str,
+    previous: Option<String>,
+}
+
+impl Drop for EnvVarGuard {
+    fn drop(&mut self) {
+        // Safety: tests serialize environment mutation with `env_lock_async()`.
+        unsafe {
+            match &self.previous {
+                Some(value) => std::env::set_var(self.name, value),
+                None => std::env::remove_var(self.name),
+            }
+        }
+    }
+}
+
+fn set_env_var(name: &'static str, value: Option<&str>) -> EnvVarGuard {
+    let previous = std::env::var(name).ok();
+    // Safety: tests serialize environment mutation with `env_lock_async()`.
+    unsafe {
+        match value {
+            Some(value) => std::env::set_var(name, value),
+            None => std::env::remove_var(name),
+        }
+    }
+    EnvVarGuard { name, previous }
+}
+
+fn term_path(digits: &str) -> String {
+    format!(
+        "/api/ontologies/mondo/terms/http%253A%252F%252Fpurl.obolibrary.org%252Fobo%252FMONDO_{digits}"
+    )
+}
+
+fn term(digits: &str, label: &str, has_children: bool) -> serde_json::Value {
+    serde_json::json!({
+        "iri": format!("http://purl.obolibrary.org/obo/MONDO_{digits}"),
+        "label": label,
+        "obo_id": format!("MONDO:{digits}"),
+        "has_children": has_children,
+        "is_root": false
+    })
+}
+
+async fn mount_children(server: &MockServer, digits: &str, terms: Vec<serde_json::Value>) {
+    Mock::given(method("GET"))
+        .and(path(format!("{}/children", term_path(digits))))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "_embedded": {"terms": terms}
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[test]
+fn normalize_mondo_id_accepts_curie_and_underscore_forms() {
+    assert_eq!(
+        normalize_mondo_id("MONDO:0005105").unwrap(),
+        "MONDO:0005105"
+    );
+    assert_eq!(
+        normalize_mondo_id(" mondo_0005105 ").unwrap(),
+        "MONDO:0005105"
+    );
+    for bad in ["melanoma", "DOID:1909", "MONDO:5105", "MONDO:00051050"] {
+        let err = normalize_mondo_id(bad).unwrap_err();
+        assert!(err.to_string().contains("Expected a MONDO ID"), "{bad}");
+    }
+}
+
+#[tokio::test]
+async fn disease_hierarchy_walks_children_and_marks_repeated_terms() {
+    let _lock = env_lock_async().await;
+    let server = MockServer::start().await;
+    let _base = set_env_var("BIOMCP_OLS4_BASE", Some(&server.uri()));
+
+    Mock::given(method("GET"))
+        .and(path(term_path("0000100")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(term(
+            "0000100",
+            "root disease",
+            true,
+        )))
+        .mount(&server)
+        .await;
+    mount_children(
+        &server,
+        "0000100",
+        vec![
+            term("0000102", "beta subtype", true),
+            term("0000101", "alpha subtype", true),
+            serde_json::json!({"iri": "http://purl.obolibrary.org/obo/BFO_0000001", "label": "entity", "obo_id": "BFO:0000001"}),
+        ],
+    )
+    .await;
+    mount_children(
+        &server,
+        "0000101",
+        vec![term("0000103", "shared leaf", true)],
+    )
+    .await;
+    mount_children(
+        &server,
+        "0000102",
+        vec![term("0000103", "shared leaf", true)],
+    )
+    .await;
+
+    let tree = disease_hierarchy("MONDO_0000100", OntologyDirection::Children, 2)
+        .await
+        .expect("tree");
+
+    assert_eq!(tree.root.id, "MONDO:0000100");
+    assert_eq!(tree.node_count, 4);
+    assert!(!tree.truncated);
+    let names = tree
+        .root
+        .children
+        .iter()
+        .map(|node| node.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["alpha subtype", "beta subtype"]);
+    let first = &tree.root.children[0].children[0];
+    assert_eq!(first.id, "MONDO:0000103");
+    assert!(first.has_more);
+    assert!(!first.repeated);
+    let second = &tree.root.children[1].children[0];
+    assert!(second.repeated);
+    assert!(!second.has_more);
+    assert!(tree.root.parents.is_empty());
+}
+
+#[tokio::test]
+async fn disease_hierarchy_rejects_unknown_terms_and_bad_depth() {
+    let _lock = env_lock_async().await;
+    let server = MockServer::start().await;
+    let _base = set_env_var("BIOMCP_OLS4_BASE", Some(&server.uri()));
+
+    let err = disease_hierarchy("MONDO:0000999", OntologyDirection::Ancestors, 1)
+        .await
+        .expect_err("unknown term");
+    assert!(matches!(err, BioMcpError::NotFound { .. }), "{err}");
+
+    let err = disease_hierarchy("MONDO:0000999", OntologyDirection::Children, 0)
+        .await
+        .expect_err("depth");
+    assert!(err.to_string().contains("--depth must be between 1 and 10"));
+}
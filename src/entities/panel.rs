@@ -0,0 +1,345 @@
+//! Consolidated clinical report for a gene panel.
+//!
+//! Every gene is fetched concurrently with the ClinGen, CIViC, and ClinVar
+//! summary sections, then reduced to a short card: a summary, curated disease
+//! associations, and clinically actionable variants. Genes that fail to
+//! resolve are reported in `notes` so one bad symbol does not sink the panel.
+
+use std::collections::HashSet;
+
+use futures::future::join_all;
+use serde::Serialize;
+
+use crate::entities::gene::{self, Gene};
+use crate::error::BioMcpError;
+use crate::sources::civic::CivicContext;
+
+pub(crate) const PANEL_MAX_GENES: usize = 50;
+const PANEL_GENE_SECTIONS: &[&str] = &["clingen", "civic", "clinvar-summary"];
+const PANEL_DISEASES_PER_GENE: usize = 8;
+const PANEL_VARIANTS_PER_GENE: usize = 8;
+/// CIViC evidence levels treated as actionable: validated (A) and clinical (B).
+const ACTIONABLE_EVIDENCE_LEVELS: &[&str] = &["A", "B"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GenePanelReport {
+    pub genes: Vec<GenePanelGene>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GenePanelGene {
+    pub symbol: String,
+    pub name: String,
+    pub entrez_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    pub diseases: Vec<GenePanelDisease>,
+    pub actionable_variants: Vec<GenePanelVariant>,
+    /// Drugs with clinical evidence against the gene product (OpenTargets).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub drugs: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clinvar: Option<GenePanelClinVar>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GenePanelDisease {
+    pub disease: String,
+    /// ClinGen gene-disease validity; absent for OpenTargets associations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inheritance: Option<String>,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GenePanelVariant {
+    pub molecular_profile: String,
+    /// AMP tier/level for assertions, CIViC evidence level for evidence items.
+    pub level: String,
+    pub significance: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disease: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub therapies: Vec<String>,
+    /// CIViC record name, e.g. `AID7` or `EID1409`.
+    pub source_id: String,
+}
+
+/// Per-variant ClinVar counts; one variant can count toward both labels.
+#[derive(Debug, Clone, Serialize)]
+pub struct GenePanelClinVar {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_variants: Option<u64>,
+    pub pathogenic: u64,
+    pub likely_pathogenic: u64,
+}
+
+/// Reads panel genes from comma-, whitespace-, or line-separated text; `#` starts a comment.
+pub fn parse_panel_genes(raw: &str) -> Result<Vec<String>, BioMcpError> {
+    let mut seen = HashSet::new();
+    let mut genes = Vec::new();
+    for line in raw.lines() {
+        let line = line.split('#').next().unwrap_or_default();
+        for token in line.split(|ch: char| ch == ',' || ch == ';' || ch.is_whitespace()) {
+            let token = token.trim().trim_matches('"');
+            if token.is_empty() {
+                continue;
+            }
+            if !crate::sources::is_valid_gene_symbol(token) {
+                return Err(BioMcpError::InvalidArgument(format!(
+                    "Invalid gene symbol in panel: {token}"
+                )));
+            }
+            let gene = token.to_ascii_uppercase();
+            if seen.insert(gene.clone()) {
+                genes.push(gene);
+            }
+        }
+    }
+    Ok(genes)
+}
+
+fn clingen_rank(classification: &str) -> u8 {
+    match classification.trim().to_ascii_lowercase().as_str() {
+        "definitive" => 0,
+        "strong" => 1,
+        "moderate" => 2,
+        "limited" => 3,
+        "disputed" | "disputed evidence" => 5,
+        "refuted" | "refuted evidence" => 6,
+        _ => 4,
+    }
+}
+
+fn panel_diseases(gene: &Gene) -> Vec<GenePanelDisease> {
+    let mut validity = gene
+        .clingen
+        .as_ref()
+        .map(|clingen| clingen.validity.clone())
+        .unwrap_or_default();
+    validity.sort_by_key(|row| clingen_rank(&row.classification));
+
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    for row in validity {
+        if seen.insert(row.disease.to_ascii_lowercase()) {
+            out.push(GenePanelDisease {
+                disease: row.disease,
+                classification: Some(row.classification),
+                inheritance: row.moi,
+                source: "ClinGen".to_string(),
+            });
+        }
+    }
+    for disease in &gene.clinical_diseases {
+        if seen.insert(disease.to_ascii_lowercase()) {
+            out.push(GenePanelDisease {
+                disease: disease.clone(),
+                classification: None,
+                inheritance: None,
+                source: "OpenTargets".to_string(),
+            });
+        }
+    }
+    out.truncate(PANEL_DISEASES_PER_GENE);
+    out
+}
+
+/// Orders AMP levels such as `TIER_I_LEVEL_A` by tier, then level; unknown values sort last.
+fn amp_rank(level: Option<&str>) -> (u8, String) {
+    let Some(level) = level else {
+        return (u8::MAX, String::new());
+    };
+    let upper = level.trim().to_ascii_uppercase();
+    let rest = upper.strip_prefix("TIER_").unwrap_or(&upper);
+    let (tier, level) = rest.split_once('_').unwrap_or((rest, ""));
+    let tier = match tier {
+        "I" => 1,
+        "II" => 2,
+        "III" => 3,
+        "IV" => 4,
+        _ => 9,
+    };
+    (tier, level.to_string())
+}
+
+/// `TIER_I_LEVEL_A` -> `Tier I Level A`.
+fn amp_label(level: &str) -> String {
+    level
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| match word.to_ascii_uppercase().as_str() {
+            "TIER" => "Tier".to_string(),
+            "LEVEL" => "Level".to_string(),
+            other => other.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `SENSITIVITYRESPONSE` -> `Sensitivity/Response`; other CIViC enums are sentence-cased.
+fn significance_label(value: &str) -> String {
+    let upper = value.trim().to_ascii_uppercase();
+    if upper == "SENSITIVITYRESPONSE" {
+        return "Sensitivity/Response".to_string();
+    }
+    let lower = upper.replace('_', " ").to_ascii_lowercase();
+    let mut chars = lower.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn evidence_level_rank(level: &str) -> usize {
+    ACTIONABLE_EVIDENCE_LEVELS
+        .iter()
+        .position(|value| value.eq_ignore_ascii_case(level.trim()))
+        .unwrap_or(ACTIONABLE_EVIDENCE_LEVELS.len())
+}
+
+/// Non-rejected CIViC assertions by AMP level, then level A/B predictive evidence items.
+fn actionable_variants(civic: &CivicContext) -> Vec<GenePanelVariant> {
+    let mut assertions = civic
+        .assertions
+        .iter()
+        .filter(|row| !row.status.eq_ignore_ascii_case("rejected"))
+        .collect::<Vec<_>>();
+    assertions.sort_by_key(|row| amp_rank(row.amp_level.as_deref()));
+    let mut evidence = civic
+        .evidence_items
+        .iter()
+        .filter(|row| {
+            row.evidence_type.eq_ignore_ascii_case("predictive")
+                && evidence_level_rank(&row.evidence_level) < ACTIONABLE_EVIDENCE_LEVELS.len()
+                && !row.status.eq_ignore_ascii_case("rejected")
+        })
+        .collect::<Vec<_>>();
+    evidence.sort_by_key(|row| evidence_level_rank(&row.evidence_level));
+
+    let rows = assertions
+        .into_iter()
+        .map(|row| GenePanelVariant {
+            molecular_profile: row.molecular_profile.clone(),
+            level: row
+                .amp_level
+                .as_deref()
+                .map(amp_label)
+                .unwrap_or_else(|| "Assertion".to_string()),
+            significance: significance_label(&row.significance),
+            disease: row.disease.clone(),
+            therapies: row.therapies.clone(),
+            source_id: row.name.clone(),
+        })
+        .chain(evidence.into_iter().map(|row| GenePanelVariant {
+            molecular_profile: row.molecular_profile.clone(),
+            level: format!("Level {}", row.evidence_level),
+            significance: significance_label(&row.significance),
+            disease: row.disease.clone(),
+            therapies: row.therapies.clone(),
+            source_id: row.name.clone(),
+        }));
+
+    let mut seen = HashSet::new();
+    rows.filter(|row| {
+        seen.insert((
+            row.molecular_profile.to_ascii_lowercase(),
+            row.disease.clone().unwrap_or_default().to_ascii_lowercase(),
+            row.therapies.join("+").to_ascii_lowercase(),
+        ))
+    })
+    .take(PANEL_VARIANTS_PER_GENE)
+    .collect()
+}
+
+fn panel_clinvar(gene: &Gene) -> Option<GenePanelClinVar> {
+    let summary = gene.clinvar_summary.as_ref()?;
+    let count = |label: &str| {
+        summary
+            .significance
+            .iter()
+            .filter(|row| row.label.trim().eq_ignore_ascii_case(label))
+            .map(|row| row.count)
+            .sum()
+    };
+    if summary.total_variants.is_none() && summary.significance.is_empty() {
+        return None;
+    }
+    Some(GenePanelClinVar {
+        total_variants: summary.total_variants,
+        pathogenic: count("pathogenic"),
+        likely_pathogenic: count("likely pathogenic"),
+    })
+}
+
+fn panel_gene(gene: &Gene) -> GenePanelGene {
+    GenePanelGene {
+        symbol: gene.symbol.clone(),
+        name: gene.name.clone(),
+        entrez_id: gene.entrez_id.clone(),
+        location: gene.location.clone(),
+        summary: gene
+            .summary
+            .as_deref()
+            .map(crate::transform::trial::truncate_summary)
+            .filter(|summary| !summary.is_empty()),
+        diseases: panel_diseases(gene),
+        actionable_variants: gene
+            .civic
+            .as_ref()
+            .map(actionable_variants)
+            .unwrap_or_default(),
+        drugs: gene.clinical_drugs.clone(),
+        clinvar: panel_clinvar(gene),
+    }
+}
+
+/// Builds a consolidated clinical report for `genes`, in input order.
+pub async fn report(genes: &[String]) -> Result<GenePanelReport, BioMcpError> {
+    if genes.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "At least one gene is required. Example: biomcp report panel --genes BRCA1,BRCA2,TP53"
+                .into(),
+        ));
+    }
+    if genes.len() > PANEL_MAX_GENES {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Gene panel reports are limited to {PANEL_MAX_GENES} genes."
+        )));
+    }
+
+    let sections = PANEL_GENE_SECTIONS
+        .iter()
+        .map(|section| section.to_string())
+        .collect::<Vec<_>>();
+    let results = join_all(genes.iter().map(|symbol| gene::get(symbol, &sections))).await;
+
+    let mut rows = Vec::new();
+    let mut notes = Vec::new();
+    let mut first_err = None;
+    for (symbol, result) in genes.iter().zip(results) {
+        match result {
+            Ok(gene) => rows.push(panel_gene(&gene)),
+            Err(err) => {
+                notes.push(format!("{symbol} was skipped: {err}"));
+                first_err.get_or_insert(err);
+            }
+        }
+    }
+    if rows.is_empty()
+        && let Some(err) = first_err
+    {
+        return Err(err);
+    }
+
+    Ok(GenePanelReport { genes: rows, notes })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,146 @@
+//! Tests for gene panel parsing and per-gene clinical cards.
+
+use serde_json::json;
+
+use super::*;
+
+fn brca1() -> Gene {
+    serde_json::from_value(json!({
+        "symbol": "BRCA1",
+        "name": "BRCA1 DNA repair associated",
+        "entrez_id": "672",
+        "location": "17q21.31",
+        "summary": "This gene encodes a nuclear phosphoprotein. It plays a role in maintaining genomic stability. It also acts as a tumor suppressor.",
+        "aliases": [],
+        "clinical_diseases": ["breast cancer", "Hereditary breast ovarian cancer syndrome"],
+        "clinical_drugs": ["olaparib"],
+        "clingen": {
+            "validity": [
+                {"disease": "Fanconi anemia", "classification": "Limited", "moi": "AR"},
+                {"disease": "Hereditary breast ovarian cancer syndrome", "classification": "Definitive", "moi": "AD"}
+            ]
+        },
+        "civic": {
+            "evidence_total_count": 3,
+            "assertion_total_count": 2,
+            "evidence_items": [
+                {
+                    "id": 1, "name": "EID1", "molecular_profile": "BRCA1 Mutation",
+                    "evidence_type": "PREDICTIVE", "evidence_level": "C",
+                    "significance": "SENSITIVITYRESPONSE", "status": "ACCEPTED"
+                },
+                {
+                    "id": 2, "name": "EID2", "molecular_profile": "BRCA1 Loss",
+                    "evidence_type": "PREDICTIVE", "evidence_level": "B",
+                    "significance": "SENSITIVITYRESPONSE", "disease": "Ovarian Cancer",
+                    "therapies": ["Olaparib"], "status": "ACCEPTED"
+                }
+            ],
+            "assertions": [
+                {
+                    "id": 9, "name": "AID9", "molecular_profile": "BRCA1 Mutation",
+                    "assertion_type": "PREDICTIVE", "assertion_direction": "SUPPORTS",
+                    "amp_level": "TIER_II_LEVEL_C", "significance": "SENSITIVITYRESPONSE",
+                    "disease": "Breast Cancer", "therapies": ["Talazoparib"],
+                    "status": "ACCEPTED", "approvals_count": 0
+                },
+                {
+                    "id": 7, "name": "AID7", "molecular_profile": "BRCA1 Mutation",
+                    "assertion_type": "PREDICTIVE", "assertion_direction": "SUPPORTS",
+                    "amp_level": "TIER_I_LEVEL_A", "significance": "SENSITIVITYRESPONSE",
+                    "disease": "Ovarian Cancer", "therapies": ["Olaparib"],
+                    "status": "ACCEPTED", "approvals_count": 1
+                }
+            ]
+        },
+        "clinvar_summary": {
+            "total_variants": 12000,
+            "significance": [
+                {"label": "Pathogenic", "count": 3100},
+                {"label": "Likely pathogenic", "count": 400},
+                {"label": "Conflicting interpretations of pathogenicity", "count": 90}
+            ],
+            "review_status": [],
+            "recent": []
+        }
+    }))
+    .expect("gene fixture")
+}
+
+#[test]
+fn parse_panel_genes_accepts_lists_and_files() {
+    let genes = parse_panel_genes("# HBOC panel\nbrca1, BRCA2\nTP53 palb2\nBRCA1 # repeat\n")
+        .expect("valid panel");
+    assert_eq!(genes, vec!["BRCA1", "BRCA2", "TP53", "PALB2"]);
+
+    let err = parse_panel_genes("BRCA1,BRAF V600E?").expect_err("invalid token");
+    assert!(
+        err.to_string()
+            .contains("Invalid gene symbol in panel: V600E?")
+    );
+}
+
+#[test]
+fn panel_gene_ranks_diseases_and_actionable_variants() {
+    let card = panel_gene(&brca1());
+    assert_eq!(
+        card.summary.as_deref(),
+        Some(
+            "This gene encodes a nuclear phosphoprotein. It plays a role in maintaining genomic stability."
+        )
+    );
+
+    let diseases = card
+        .diseases
+        .iter()
+        .map(|row| (row.disease.as_str(), row.source.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        diseases,
+        vec![
+            ("Hereditary breast ovarian cancer syndrome", "ClinGen"),
+            ("Fanconi anemia", "ClinGen"),
+            ("breast cancer", "OpenTargets"),
+        ]
+    );
+
+    let variants = card
+        .actionable_variants
+        .iter()
+        .map(|row| (row.source_id.as_str(), row.level.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        variants,
+        vec![
+            ("AID7", "Tier I Level A"),
+            ("AID9", "Tier II Level C"),
+            ("EID2", "Level B"),
+        ]
+    );
+    assert_eq!(
+        card.actionable_variants[0].significance,
+        "Sensitivity/Response"
+    );
+
+    let clinvar = card.clinvar.expect("clinvar counts");
+    assert_eq!(clinvar.total_variants, Some(12000));
+    assert_eq!((clinvar.pathogenic, clinvar.likely_pathogenic), (3100, 400));
+}
+
+#[test]
+fn significance_label_sentence_cases_civic_enums() {
+    assert_eq!(significance_label("RESISTANCE"), "Resistance");
+    assert_eq!(significance_label("ADVERSE_RESPONSE"), "Adverse response");
+}
+
+#[tokio::test]
+async fn report_rejects_empty_and_oversized_panels() {
+    let err = report(&[]).await.expect_err("empty panel");
+    assert!(err.to_string().contains("At least one gene is required"));
+
+    let genes = (0..=PANEL_MAX_GENES)
+        .map(|idx| format!("GENE{idx}"))
+        .collect::<Vec<_>>();
+    let err = report(&genes).await.expect_err("oversized panel");
+    assert!(err.to_string().contains("limited to 50 genes"));
+}
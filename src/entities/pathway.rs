@@ -2,9 +2,11 @@ use std::collections::HashSet;
 use std::sync::OnceLock;
 
 use regex::Regex;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
 
+use crate::entities::section_errors;
 use crate::error::BioMcpError;
 use crate::sources::gprofiler::GProfilerClient;
 use crate::sources::kegg::{KeggClient, is_human_pathway_id};
@@ -13,7 +15,7 @@ use crate::sources::reactome::ReactomeClient;
 use crate::sources::wikipathways::{WikiPathwaysClient, is_wikipathways_id};
 use crate::transform;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Pathway {
     pub source: String,
     pub id: String,
@@ -30,7 +32,7 @@ pub struct Pathway {
     pub enrichment: Vec<PathwayEnrichment>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PathwayEnrichment {
     pub source: String,
     pub id: String,
@@ -432,6 +434,7 @@ async fn add_pathway_enrichment(pathway: &mut Pathway, fallback_genes: &[String]
         Ok(client) => client,
         Err(err) => {
             warn!("g:Profiler enrichment unavailable: {err}");
+            section_errors::record(PATHWAY_SECTION_ENRICHMENT, "g:Profiler", &err);
             return;
         }
     };
@@ -455,7 +458,10 @@ async fn add_pathway_enrichment(pathway: &mut Pathway, fallback_genes: &[String]
                 })
                 .collect();
         }
-        Err(err) => warn!("g:Profiler enrichment unavailable: {err}"),
+        Err(err) => {
+            warn!("g:Profiler enrichment unavailable: {err}");
+            section_errors::record(PATHWAY_SECTION_ENRICHMENT, "g:Profiler", &err);
+        }
     }
 }
 
@@ -625,7 +631,7 @@ pub async fn get(st_id: &str, sections: &[String]) -> Result<Pathway, BioMcpErro
         if !parsed_sections.include_genes {
             pathway.genes.clear();
         }
-        return Ok(pathway);
+        return transform::fields::apply("pathway", pathway);
     }
 
     if matches!(
@@ -645,20 +651,31 @@ pub async fn get(st_id: &str, sections: &[String]) -> Result<Pathway, BioMcpErro
                                 Ok(symbols) => {
                                     pathway.genes = symbols.into_iter().take(50).collect();
                                 }
-                                Err(err) => warn!(
-                                    "WikiPathways gene symbol resolution unavailable via MyGene: {err}"
-                                ),
+                                Err(err) => {
+                                    warn!(
+                                        "WikiPathways gene symbol resolution unavailable via MyGene: {err}"
+                                    );
+                                    section_errors::record(
+                                        PATHWAY_SECTION_GENES,
+                                        "MyGene.info",
+                                        &err,
+                                    );
+                                }
                             },
                             Err(err) => {
-                                warn!("WikiPathways gene symbol resolution unavailable: {err}")
+                                warn!("WikiPathways gene symbol resolution unavailable: {err}");
+                                section_errors::record(PATHWAY_SECTION_GENES, "MyGene.info", &err);
                             }
                         }
                     }
                 }
-                Err(err) => warn!("WikiPathways xref retrieval unavailable: {err}"),
+                Err(err) => {
+                    warn!("WikiPathways xref retrieval unavailable: {err}");
+                    section_errors::record(PATHWAY_SECTION_GENES, "WikiPathways", &err);
+                }
             }
         }
-        return Ok(pathway);
+        return transform::fields::apply("pathway", pathway);
     }
 
     let client = ReactomeClient::new()?;
@@ -672,6 +689,7 @@ pub async fn get(st_id: &str, sections: &[String]) -> Result<Pathway, BioMcpErro
             Ok(lines) => lines,
             Err(err) => {
                 warn!("Reactome participants unavailable: {err}");
+                section_errors::record(PATHWAY_SECTION_GENES, "Reactome", &err);
                 Vec::new()
             }
         };
@@ -683,6 +701,7 @@ pub async fn get(st_id: &str, sections: &[String]) -> Result<Pathway, BioMcpErro
             Ok(events) => events,
             Err(err) => {
                 warn!("Reactome contained events unavailable: {err}");
+                section_errors::record(PATHWAY_SECTION_EVENTS, "Reactome", &err);
                 Vec::new()
             }
         };
@@ -697,7 +716,7 @@ pub async fn get(st_id: &str, sections: &[String]) -> Result<Pathway, BioMcpErro
         add_pathway_enrichment(&mut pathway, &fallback_genes).await;
     }
 
-    Ok(pathway)
+    transform::fields::apply("pathway", pathway)
 }
 
 #[cfg(test)]
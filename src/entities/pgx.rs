@@ -1,16 +1,26 @@
 use std::collections::HashSet;
 use std::time::Duration;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
 
 use crate::entities::SearchPage;
+use crate::entities::section_errors;
 use crate::error::BioMcpError;
 use crate::sources::cpic::{
     CpicClient, CpicFrequencyRow, CpicGuidelineSummaryRow, CpicPairRow, CpicRecommendationRow,
 };
 use crate::sources::pharmgkb::{PharmGkbAnnotation, PharmGkbClient};
 
+mod diplotype;
+mod dose_check;
+mod panel;
+
+pub use self::diplotype::{PgxDiplotype, diplotype};
+pub use self::dose_check::{PgxDoseCheck, PgxDoseCheckStatus, dose_check};
+pub use self::panel::{PgxPanel, panel, parse_vcf_star_alleles};
+
 const PGX_SECTION_RECOMMENDATIONS: &str = "recommendations";
 const PGX_SECTION_FREQUENCIES: &str = "frequencies";
 const PGX_SECTION_GUIDELINES: &str = "guidelines";
@@ -27,7 +37,7 @@ pub const PGX_SECTION_NAMES: &[&str] = &[
 
 const OPTIONAL_ENRICHMENT_TIMEOUT: Duration = Duration::from_secs(10);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Pgx {
     pub query: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -48,7 +58,7 @@ pub struct Pgx {
     pub annotations_note: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PgxInteraction {
     pub genesymbol: String,
     pub drugname: String,
@@ -62,7 +72,7 @@ pub struct PgxInteraction {
     pub guidelineurl: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PgxRecommendation {
     pub drugname: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -83,7 +93,7 @@ pub struct PgxRecommendation {
     pub guidelineurl: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PgxFrequency {
     pub genesymbol: String,
     pub allele: String,
@@ -99,7 +109,7 @@ pub struct PgxFrequency {
     pub max_frequency: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PgxGuideline {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -306,7 +316,10 @@ pub async fn get(query: &str, sections: &[String]) -> Result<Pgx, BioMcpError> {
             for gene in unique_genes.into_iter().take(3) {
                 match cpic.frequencies_by_gene(&gene, 12).await {
                     Ok(frequencies) => rows.extend(map_frequencies(&frequencies)),
-                    Err(err) => warn!(gene = %gene, "CPIC frequency lookup failed: {err}"),
+                    Err(err) => {
+                        warn!(gene = %gene, "CPIC frequency lookup failed: {err}");
+                        section_errors::record(PGX_SECTION_FREQUENCIES, "CPIC", &err);
+                    }
                 }
             }
         }
@@ -339,10 +352,16 @@ pub async fn get(query: &str, sections: &[String]) -> Result<Pgx, BioMcpError> {
             }
         };
 
-        match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, annotation_fut).await {
+        match tokio::time::timeout(
+            section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+            annotation_fut,
+        )
+        .await
+        {
             Ok(Ok(annotations)) => out.annotations = annotations,
             Ok(Err(err)) => {
                 warn!("PharmGKB enrichment unavailable: {err}");
+                section_errors::record(PGX_SECTION_ANNOTATIONS, "PharmGKB", &err);
                 out.annotations_note = Some(
                     "PharmGKB annotations unavailable; returned CPIC core content.".to_string(),
                 );
@@ -352,13 +371,18 @@ pub async fn get(query: &str, sections: &[String]) -> Result<Pgx, BioMcpError> {
                     timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
                     "PharmGKB enrichment timed out"
                 );
+                section_errors::record_timeout(
+                    PGX_SECTION_ANNOTATIONS,
+                    "PharmGKB",
+                    OPTIONAL_ENRICHMENT_TIMEOUT,
+                );
                 out.annotations_note =
                     Some("PharmGKB annotations timed out; returned CPIC core content.".to_string());
             }
         }
     }
 
-    Ok(out)
+    crate::transform::fields::apply("pgx", out)
 }
 
 #[allow(dead_code)]
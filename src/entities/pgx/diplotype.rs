@@ -0,0 +1,191 @@
+//! Diplotype-to-phenotype translation from CPIC's allele tables.
+//!
+//! CPIC maps each diplotype of a pharmacogene to a phenotype and, for genes
+//! scored by activity (CYP2D6, CYP2C9, DPYD), a total activity score. The same
+//! row carries the lookup key CPIC uses to index its recommendations, so the
+//! drug recommendations for a diplotype come from one exact lookup rather than
+//! from matching phenotype text.
+
+use serde::{Deserialize, Serialize};
+
+use super::is_likely_gene;
+use super::panel::lookup_ignore_case;
+use crate::error::BioMcpError;
+use crate::sources::cpic::{CpicClient, CpicDiplotypeRow, CpicRecommendationRow};
+use crate::utils::text::non_empty;
+
+const DIPLOTYPE_RECOMMENDATION_LIMIT: usize = 200;
+const DIPLOTYPE_MAX_CHARS: usize = 64;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PgxDiplotype {
+    pub gene: String,
+    /// Diplotype as CPIC writes it, e.g. `*1/*4`.
+    pub diplotype: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phenotype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_score: Option<String>,
+    /// Value CPIC indexes recommendations by: the activity score for scored
+    /// genes, otherwise the phenotype.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lookup_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ehr_priority: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consultation: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recommendations: Vec<PgxDiplotypeRecommendation>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+/// One CPIC drug recommendation that applies to the diplotype.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PgxDiplotypeRecommendation {
+    pub drug: String,
+    /// Other genes the recommendation is keyed on, e.g. `HLA-B: *15:02 negative`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub other_phenotypes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommendation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implication: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guidelinename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guidelineurl: Option<String>,
+}
+
+/// Normalizes `*1 / *4` to `*1/*4`, rejecting values without two alleles.
+fn normalize_diplotype(value: &str) -> Result<String, BioMcpError> {
+    let compact = value.split_whitespace().collect::<String>();
+    let valid = compact.len() <= DIPLOTYPE_MAX_CHARS
+        && compact.split_once('/').is_some_and(|(first, second)| {
+            !first.is_empty() && !second.is_empty() && !second.contains('/')
+        });
+    if !valid {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Invalid diplotype: {value}. Expected two alleles separated by '/', e.g. *1/*4"
+        )));
+    }
+    Ok(compact)
+}
+
+fn recommendations(gene: &str, rows: &[CpicRecommendationRow]) -> Vec<PgxDiplotypeRecommendation> {
+    let mut out: Vec<PgxDiplotypeRecommendation> = Vec::new();
+    for row in rows {
+        let drug = row.drugname.trim();
+        if drug.is_empty() {
+            continue;
+        }
+        let mut other_phenotypes = row
+            .phenotypes
+            .iter()
+            .filter(|(other, value)| !other.eq_ignore_ascii_case(gene) && !value.trim().is_empty())
+            .map(|(other, value)| format!("{}: {}", other.to_ascii_uppercase(), value.trim()))
+            .collect::<Vec<_>>();
+        other_phenotypes.sort();
+        let recommendation = non_empty(row.drugrecommendation.as_deref());
+        if out.iter().any(|existing| {
+            existing.drug.eq_ignore_ascii_case(drug)
+                && existing.recommendation == recommendation
+                && existing.other_phenotypes == other_phenotypes
+        }) {
+            continue;
+        }
+        out.push(PgxDiplotypeRecommendation {
+            drug: drug.to_string(),
+            other_phenotypes,
+            recommendation,
+            implication: lookup_ignore_case(&row.implications, gene).map(str::to_string),
+            classification: non_empty(row.classification.as_deref()),
+            guidelinename: non_empty(row.guidelinename.as_deref()),
+            guidelineurl: non_empty(row.guidelineurl.as_deref()),
+        });
+    }
+    out.sort_by(|a, b| {
+        a.drug
+            .to_ascii_lowercase()
+            .cmp(&b.drug.to_ascii_lowercase())
+            .then_with(|| a.other_phenotypes.cmp(&b.other_phenotypes))
+    });
+    out
+}
+
+fn build_diplotype(
+    gene: &str,
+    row: &CpicDiplotypeRow,
+    recommendation_rows: &[CpicRecommendationRow],
+) -> PgxDiplotype {
+    let phenotype = non_empty(row.generesult.as_deref());
+    let lookup_key = lookup_ignore_case(&row.lookupkey, gene).map(str::to_string);
+    let recommendations = recommendations(gene, recommendation_rows);
+    let mut notes = Vec::new();
+    if lookup_key.is_none() {
+        notes.push(format!(
+            "CPIC has no recommendation lookup key for {gene} {}.",
+            row.diplotype
+        ));
+    } else if recommendations.is_empty() {
+        notes.push(format!(
+            "CPIC has no drug recommendations for {gene} {}.",
+            phenotype.as_deref().unwrap_or(row.diplotype.as_str())
+        ));
+    }
+    PgxDiplotype {
+        gene: gene.to_string(),
+        diplotype: row.diplotype.clone(),
+        phenotype,
+        activity_score: non_empty(row.totalactivityscore.as_deref())
+            .filter(|score| !score.eq_ignore_ascii_case("n/a")),
+        lookup_key,
+        ehr_priority: non_empty(row.ehrpriority.as_deref()),
+        consultation: non_empty(row.consultationtext.as_deref()),
+        recommendations,
+        notes,
+    }
+}
+
+/// Translates a `gene` diplotype such as `*1/*4` to CPIC's phenotype, activity
+/// score, and drug recommendations.
+pub async fn diplotype(gene: &str, diplotype: &str) -> Result<PgxDiplotype, BioMcpError> {
+    let gene = gene.trim().to_ascii_uppercase();
+    if gene.is_empty() || diplotype.trim().is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "Gene and diplotype are required. Example: biomcp pgx diplotype CYP2D6 '*1/*4'".into(),
+        ));
+    }
+    if !is_likely_gene(&gene) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Invalid gene symbol: {gene}"
+        )));
+    }
+    let diplotype = normalize_diplotype(diplotype)?;
+
+    let cpic = CpicClient::new()?;
+    let rows = cpic.diplotypes(&gene, &diplotype).await?;
+    let Some(row) = rows.first() else {
+        return Err(BioMcpError::NotFound {
+            entity: "diplotype".into(),
+            id: format!("{gene} {diplotype}"),
+            suggestion: format!(
+                "CPIC assigns phenotypes to {gene} diplotypes of named alleles only. Try: biomcp get pgx {gene} frequencies"
+            ),
+        });
+    };
+
+    let recommendation_rows = match lookup_ignore_case(&row.lookupkey, &gene) {
+        Some(key) => {
+            cpic.recommendations_by_lookup(&gene, key, DIPLOTYPE_RECOMMENDATION_LIMIT)
+                .await?
+        }
+        None => Vec::new(),
+    };
+    Ok(build_diplotype(&gene, row, &recommendation_rows))
+}
+
+#[cfg(test)]
+mod tests;
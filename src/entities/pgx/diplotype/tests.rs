@@ -0,0 +1,126 @@
+//! Tests for diplotype-to-phenotype translation.
+
+use std::collections::HashMap;
+
+use super::*;
+
+fn diplotype_row() -> CpicDiplotypeRow {
+    CpicDiplotypeRow {
+        genesymbol: "CYP2D6".into(),
+        diplotype: "*1/*4".into(),
+        generesult: Some("Intermediate Metabolizer".into()),
+        totalactivityscore: Some("1.0".into()),
+        lookupkey: HashMap::from([("CYP2D6".to_string(), "1.0".to_string())]),
+        ehrpriority: Some("Abnormal/Priority/High Risk".into()),
+        consultationtext: None,
+    }
+}
+
+fn recommendation(drug: &str, phenotypes: &[(&str, &str)], text: &str) -> CpicRecommendationRow {
+    CpicRecommendationRow {
+        recommendationid: None,
+        lookupkey: HashMap::new(),
+        drugname: drug.into(),
+        guidelinename: Some("CYP2D6, OPRM1, and COMT and Opioids".into()),
+        guidelineurl: Some(
+            "https://cpicpgx.org/guidelines/guideline-for-codeine-and-cyp2d6/".into(),
+        ),
+        implications: HashMap::from([(
+            "CYP2D6".to_string(),
+            "Reduced morphine formation".to_string(),
+        )]),
+        drugrecommendation: Some(text.into()),
+        classification: Some("Moderate".into()),
+        phenotypes: phenotypes
+            .iter()
+            .map(|(gene, value)| (gene.to_string(), value.to_string()))
+            .collect(),
+        activityscore: HashMap::new(),
+        population: None,
+    }
+}
+
+#[test]
+fn normalize_diplotype_compacts_whitespace_and_needs_two_alleles() {
+    assert_eq!(normalize_diplotype(" *1 / *4 ").unwrap(), "*1/*4");
+    assert_eq!(normalize_diplotype("*1/*1x2").unwrap(), "*1/*1x2");
+    for bad in ["*1", "*1/", "/*4", "*1/*2/*3"] {
+        let err = normalize_diplotype(bad).unwrap_err();
+        assert!(err.to_string().contains("Invalid diplotype"), "{bad}");
+    }
+}
+
+#[test]
+fn build_diplotype_reports_phenotype_score_and_sorted_recommendations() {
+    let rows = vec![
+        recommendation(
+            "tramadol",
+            &[("CYP2D6", "Intermediate Metabolizer")],
+            "Use tramadol label recommended age- or weight-specific dosing.",
+        ),
+        recommendation(
+            "codeine",
+            &[("CYP2D6", "Intermediate Metabolizer")],
+            "Use codeine label recommended age- or weight-specific dosing.",
+        ),
+        recommendation(
+            "codeine",
+            &[("CYP2D6", "Intermediate Metabolizer")],
+            "Use codeine label recommended age- or weight-specific dosing.",
+        ),
+    ];
+
+    let result = build_diplotype("CYP2D6", &diplotype_row(), &rows);
+    assert_eq!(
+        result.phenotype.as_deref(),
+        Some("Intermediate Metabolizer")
+    );
+    assert_eq!(result.activity_score.as_deref(), Some("1.0"));
+    assert_eq!(result.lookup_key.as_deref(), Some("1.0"));
+    assert_eq!(
+        result
+            .recommendations
+            .iter()
+            .map(|row| row.drug.as_str())
+            .collect::<Vec<_>>(),
+        ["codeine", "tramadol"]
+    );
+    assert_eq!(
+        result.recommendations[0].implication.as_deref(),
+        Some("Reduced morphine formation")
+    );
+    assert!(result.notes.is_empty());
+}
+
+#[test]
+fn build_diplotype_lists_other_genes_and_notes_missing_recommendations() {
+    let rows = vec![recommendation(
+        "phenytoin",
+        &[
+            ("CYP2C9", "Intermediate Metabolizer"),
+            ("HLA-B", "*15:02 negative"),
+        ],
+        "Consider 25% reduction of recommended starting maintenance dose.",
+    )];
+    let result = build_diplotype("CYP2C9", &diplotype_row(), &rows);
+    assert_eq!(
+        result.recommendations[0].other_phenotypes,
+        ["HLA-B: *15:02 negative"]
+    );
+
+    let result = build_diplotype("CYP2D6", &diplotype_row(), &[]);
+    assert_eq!(
+        result.notes,
+        ["CPIC has no drug recommendations for CYP2D6 Intermediate Metabolizer."]
+    );
+
+    let mut row = diplotype_row();
+    row.lookupkey.clear();
+    row.totalactivityscore = Some("n/a".into());
+    let result = build_diplotype("CYP2D6", &row, &[]);
+    assert!(result.activity_score.is_none());
+    assert_eq!(
+        result.notes,
+        ["CPIC has no recommendation lookup key for CYP2D6 *1/*4."]
+    );
+}
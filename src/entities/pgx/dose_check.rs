@@ -0,0 +1,551 @@
+//! Genotype-guided dose cross-check between CPIC and the FDA label.
+//!
+//! CPIC recommendations for the drug are narrowed to the called phenotype of
+//! one gene; the current FDA label (OpenFDA) is scanned for sentences naming
+//! the gene and that phenotype. Both sides are reduced to a coarse dosing
+//! action (avoid, reduce, increase, standard) so a pharmacist can see at a
+//! glance whether the two sources point the same way. The comparison is
+//! keyword-based and always shows the source text it was drawn from.
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::is_likely_gene;
+use super::panel::lookup_ignore_case;
+use crate::error::BioMcpError;
+use crate::sources::cpic::{CpicClient, CpicRecommendationRow};
+use crate::sources::openfda::OpenFdaClient;
+use crate::utils::text::non_empty;
+
+const DOSE_CHECK_RECOMMENDATION_LIMIT: usize = 200;
+const LABEL_EXCERPT_LIMIT: usize = 6;
+const LABEL_EXCERPT_MAX_CHARS: usize = 400;
+/// Label fields scanned for gene-specific dosing language, most specific first.
+const LABEL_FIELDS: &[&str] = &[
+    "pharmacogenomics",
+    "dosage_and_administration",
+    "warnings_and_cautions",
+    "warnings",
+    "clinical_pharmacology",
+];
+
+/// Coarse dosing direction extracted from recommendation or label text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DosingAction {
+    /// Avoid the drug or choose an alternative.
+    Avoid,
+    Reduce,
+    Increase,
+    Standard,
+    /// No dosing direction could be read from the text.
+    Unspecified,
+}
+
+impl DosingAction {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Avoid => "avoid / use alternative",
+            Self::Reduce => "reduce dose",
+            Self::Increase => "increase dose",
+            Self::Standard => "standard dosing",
+            Self::Unspecified => "unspecified",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PgxDoseCheckStatus {
+    Agree,
+    Disagree,
+    /// The label has no sentence about this gene and phenotype.
+    LabelSilent,
+    NoCpicRecommendation,
+    /// One side gives no readable dosing direction.
+    Unclear,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PgxDoseCheck {
+    pub drug: String,
+    pub gene: String,
+    pub phenotype: String,
+    pub status: PgxDoseCheckStatus,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cpic: Vec<PgxDoseRecommendation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<PgxDoseLabel>,
+    /// Disagreements between CPIC and the label, one line each.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+/// One CPIC recommendation matching the requested phenotype.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PgxDoseRecommendation {
+    /// CPIC's wording of the matched phenotype, e.g. `Intermediate Metabolizer`.
+    pub phenotype: String,
+    /// Other genes the recommendation is keyed on, e.g. `NUDT15: Normal Metabolizer`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub other_phenotypes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommendation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implication: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    pub action: DosingAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guidelinename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guidelineurl: Option<String>,
+}
+
+/// Gene-specific sentences from the current FDA label.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PgxDoseLabel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand_name: Option<String>,
+    /// Whether the excerpts name the phenotype, not just the gene.
+    pub phenotype_specific: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub excerpts: Vec<String>,
+    pub action: DosingAction,
+}
+
+const AVOID_TERMS: &[&str] = &[
+    "avoid",
+    "alternative",
+    "not recommended",
+    "contraindicated",
+    "do not use",
+    "should not be used",
+];
+const REDUCE_TERMS: &[&str] = &[
+    "reduce the dose",
+    "reduce dose",
+    "reduced dose",
+    "reduced starting",
+    "reduce dosage",
+    "reduce the dosage",
+    "reduced dosage",
+    "dose reduction",
+    "dosage reduction",
+    "lower dose",
+    "lower starting dose",
+    "decrease the dose",
+    "% of normal",
+    "% of the normal",
+    "% of the recommended",
+    "% of the starting",
+];
+const INCREASE_TERMS: &[&str] = &[
+    "increase the dose",
+    "increase dose",
+    "increased dose",
+    "increase starting dose",
+    "higher dose",
+    "dose increase",
+];
+const STANDARD_TERMS: &[&str] = &[
+    "label recommended",
+    "label-recommended",
+    "recommended starting dose",
+    "normal starting dose",
+    "standard dose",
+    "standard starting",
+    "normal dose",
+    "usual dose",
+    "no dose adjustment",
+    "no dosage adjustment",
+];
+
+/// Reads the most restrictive dosing direction named in `text`.
+fn dosing_action(text: &str) -> DosingAction {
+    let lower = text.to_ascii_lowercase();
+    let has_any = |terms: &[&str]| terms.iter().any(|term| lower.contains(term));
+    if has_any(AVOID_TERMS) {
+        DosingAction::Avoid
+    } else if has_any(REDUCE_TERMS) {
+        DosingAction::Reduce
+    } else if has_any(INCREASE_TERMS) {
+        DosingAction::Increase
+    } else if has_any(STANDARD_TERMS) {
+        DosingAction::Standard
+    } else {
+        DosingAction::Unspecified
+    }
+}
+
+/// Label wordings that stand for a called phenotype, in addition to the
+/// phenotype itself.
+fn phenotype_terms(phenotype: &str) -> Vec<String> {
+    let lower = phenotype.trim().to_ascii_lowercase();
+    let mut terms = vec![lower.clone()];
+    let synonyms: &[&str] = if lower.contains("ultrarapid") || lower.contains("ultra-rapid") {
+        &["ultrarapid", "ultra-rapid"]
+    } else if lower.contains("poor") {
+        &[
+            "poor metabolizer",
+            "poor function",
+            "homozygous",
+            "deficiency",
+            "deficient",
+            "no function",
+            "absent",
+        ]
+    } else if lower.contains("intermediate") {
+        &[
+            "intermediate",
+            "heterozygous",
+            "reduced activity",
+            "partial deficiency",
+        ]
+    } else if lower.contains("decreased function") {
+        &["decreased function", "reduced function"]
+    } else if lower.contains("rapid") {
+        &["rapid metabolizer"]
+    } else if lower.contains("normal") || lower.contains("extensive") {
+        &[
+            "normal metabolizer",
+            "extensive metabolizer",
+            "normal activity",
+            "normal function",
+        ]
+    } else if lower.contains("positive") {
+        &["positive", "carrier"]
+    } else {
+        &[]
+    };
+    for synonym in synonyms {
+        if !terms.iter().any(|term| term == synonym) {
+            terms.push(synonym.to_string());
+        }
+    }
+    terms
+}
+
+/// Whether `text` names `word` as a whole token, e.g. `TPMT` but not `TPMTX`.
+fn mentions_token(text: &str, word: &str) -> bool {
+    text.match_indices(word).any(|(idx, _)| {
+        let before = text[..idx].chars().next_back();
+        let after = text[idx + word.len()..].chars().next();
+        !before.is_some_and(|c| c.is_ascii_alphanumeric())
+            && !after.is_some_and(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+fn label_field_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .filter_map(serde_json::Value::as_str)
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => String::new(),
+    }
+}
+
+fn sentences(text: &str) -> Vec<String> {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    flat.split(". ")
+        .map(str::trim)
+        .filter(|sentence| !sentence.is_empty())
+        .map(|sentence| {
+            let sentence = sentence.trim_end_matches('.');
+            let mut out = sentence
+                .chars()
+                .take(LABEL_EXCERPT_MAX_CHARS)
+                .collect::<String>();
+            if sentence.chars().count() > LABEL_EXCERPT_MAX_CHARS {
+                out.push_str("...");
+            } else {
+                out.push('.');
+            }
+            out
+        })
+        .collect()
+}
+
+fn openfda_first(label: &serde_json::Value, key: &str) -> Option<String> {
+    match label.get("openfda")?.get(key)? {
+        serde_json::Value::Array(items) => {
+            non_empty(items.iter().find_map(serde_json::Value::as_str))
+        }
+        serde_json::Value::String(value) => non_empty(Some(value)),
+        _ => None,
+    }
+}
+
+/// Picks the label for `drug` from an OpenFDA label response, preferring an
+/// exact generic-name match over the newest result.
+fn select_label<'a>(response: &'a serde_json::Value, drug: &str) -> Option<&'a serde_json::Value> {
+    let results = response.get("results")?.as_array()?;
+    results
+        .iter()
+        .find(|result| {
+            openfda_first(result, "generic_name")
+                .is_some_and(|name| name.eq_ignore_ascii_case(drug))
+        })
+        .or_else(|| results.first())
+}
+
+fn dose_label(label: &serde_json::Value, gene: &str, phenotype: &str) -> PgxDoseLabel {
+    let terms = phenotype_terms(phenotype);
+    let mut gene_sentences: Vec<String> = Vec::new();
+    for field in LABEL_FIELDS {
+        let Some(value) = label.get(*field) else {
+            continue;
+        };
+        for sentence in sentences(&label_field_text(value)) {
+            if mentions_token(&sentence, gene) && !gene_sentences.contains(&sentence) {
+                gene_sentences.push(sentence);
+            }
+        }
+    }
+    let phenotype_sentences = gene_sentences
+        .iter()
+        .filter(|sentence| {
+            let lower = sentence.to_ascii_lowercase();
+            terms.iter().any(|term| lower.contains(term.as_str()))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let phenotype_specific = !phenotype_sentences.is_empty();
+    let action = phenotype_sentences
+        .iter()
+        .map(|sentence| dosing_action(sentence))
+        .min()
+        .unwrap_or(DosingAction::Unspecified);
+    let mut excerpts = if phenotype_specific {
+        phenotype_sentences
+    } else {
+        gene_sentences
+    };
+    excerpts.truncate(LABEL_EXCERPT_LIMIT);
+
+    PgxDoseLabel {
+        set_id: label
+            .get("set_id")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|id| non_empty(Some(id))),
+        brand_name: openfda_first(label, "brand_name"),
+        phenotype_specific,
+        excerpts,
+        action,
+    }
+}
+
+/// Matches CPIC's phenotype wording exactly, or by whole words when `exact` is
+/// off (e.g. `intermediate` for `Intermediate Metabolizer`).
+fn phenotype_matches(value: &str, phenotype: &str, exact: bool) -> bool {
+    let value = value.trim().to_ascii_lowercase();
+    let phenotype = phenotype.trim().to_ascii_lowercase();
+    value == phenotype || (!exact && mentions_token(&value, &phenotype))
+}
+
+fn matching_recommendations(
+    drug: &str,
+    gene: &str,
+    phenotype: &str,
+    rows: &[CpicRecommendationRow],
+) -> Vec<PgxDoseRecommendation> {
+    let exact_drug = rows
+        .iter()
+        .any(|row| row.drugname.trim().eq_ignore_ascii_case(drug));
+    let exact_phenotype = rows.iter().any(|row| {
+        lookup_ignore_case(&row.phenotypes, gene)
+            .is_some_and(|value| phenotype_matches(value, phenotype, true))
+    });
+    let mut out: Vec<PgxDoseRecommendation> = Vec::new();
+    for row in rows {
+        if exact_drug && !row.drugname.trim().eq_ignore_ascii_case(drug) {
+            continue;
+        }
+        let Some(matched) = lookup_ignore_case(&row.phenotypes, gene) else {
+            continue;
+        };
+        if !phenotype_matches(matched, phenotype, exact_phenotype) {
+            continue;
+        }
+
+        let mut other_phenotypes = row
+            .phenotypes
+            .iter()
+            .filter(|(other, value)| !other.eq_ignore_ascii_case(gene) && !value.trim().is_empty())
+            .map(|(other, value)| format!("{}: {}", other.to_ascii_uppercase(), value.trim()))
+            .collect::<Vec<_>>();
+        other_phenotypes.sort();
+        let recommendation = non_empty(row.drugrecommendation.as_deref());
+        if out.iter().any(|existing| {
+            existing.recommendation == recommendation
+                && existing.other_phenotypes == other_phenotypes
+        }) {
+            continue;
+        }
+        out.push(PgxDoseRecommendation {
+            phenotype: matched.to_string(),
+            other_phenotypes,
+            action: dosing_action(recommendation.as_deref().unwrap_or_default()),
+            recommendation,
+            implication: lookup_ignore_case(&row.implications, gene).map(str::to_string),
+            classification: non_empty(row.classification.as_deref()),
+            guidelinename: non_empty(row.guidelinename.as_deref()),
+            guidelineurl: non_empty(row.guidelineurl.as_deref()),
+        });
+    }
+    out
+}
+
+/// CPIC phenotypes on file for `gene` with this drug, for the no-match note.
+fn known_phenotypes(drug: &str, gene: &str, rows: &[CpicRecommendationRow]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for row in rows
+        .iter()
+        .filter(|row| row.drugname.trim().eq_ignore_ascii_case(drug))
+    {
+        if let Some(value) = lookup_ignore_case(&row.phenotypes, gene)
+            && !out.iter().any(|known| known.eq_ignore_ascii_case(value))
+        {
+            out.push(value.to_string());
+        }
+    }
+    out.sort();
+    out
+}
+
+/// Compares matched CPIC recommendations with the label excerpts.
+fn build_dose_check(
+    drug: &str,
+    gene: &str,
+    phenotype: &str,
+    rows: &[CpicRecommendationRow],
+    label: Option<PgxDoseLabel>,
+    mut notes: Vec<String>,
+) -> PgxDoseCheck {
+    let cpic = matching_recommendations(drug, gene, phenotype, rows);
+    let mut flags = Vec::new();
+
+    let cpic_actions = {
+        let mut actions = cpic
+            .iter()
+            .map(|row| row.action)
+            .filter(|action| *action != DosingAction::Unspecified)
+            .collect::<Vec<_>>();
+        actions.sort();
+        actions.dedup();
+        actions
+    };
+    if cpic_actions.len() > 1 {
+        notes.push(format!(
+            "CPIC advice for {gene} {phenotype} differs by the other genes' phenotypes; compare each row."
+        ));
+    }
+
+    let status = match label.as_ref() {
+        _ if cpic.is_empty() => {
+            let known = known_phenotypes(drug, gene, rows);
+            notes.push(if known.is_empty() {
+                format!("CPIC has no {gene}-based recommendation for {drug}.")
+            } else {
+                format!(
+                    "No CPIC recommendation for {gene} \"{phenotype}\" with {drug}. CPIC phenotypes: {}.",
+                    known.join(", ")
+                )
+            });
+            PgxDoseCheckStatus::NoCpicRecommendation
+        }
+        None => PgxDoseCheckStatus::LabelSilent,
+        Some(label) if label.excerpts.is_empty() => PgxDoseCheckStatus::LabelSilent,
+        Some(label) if label.action == DosingAction::Unspecified || cpic_actions.is_empty() => {
+            PgxDoseCheckStatus::Unclear
+        }
+        Some(label) if cpic_actions.contains(&label.action) => PgxDoseCheckStatus::Agree,
+        Some(label) => {
+            let cpic_side = cpic_actions
+                .iter()
+                .map(|action| action.label())
+                .collect::<Vec<_>>()
+                .join(" or ");
+            flags.push(format!(
+                "CPIC says {cpic_side} for {gene} {phenotype}; the FDA label says {}.",
+                label.action.label()
+            ));
+            PgxDoseCheckStatus::Disagree
+        }
+    };
+    if status == PgxDoseCheckStatus::LabelSilent && !cpic.is_empty() {
+        flags.push(format!(
+            "The FDA label gives no {gene}-specific dosing for this phenotype; CPIC does."
+        ));
+    }
+
+    PgxDoseCheck {
+        drug: drug.to_string(),
+        gene: gene.to_string(),
+        phenotype: phenotype.to_string(),
+        status,
+        cpic,
+        label,
+        flags,
+        notes,
+    }
+}
+
+/// Cross-checks CPIC dosing for `gene` `phenotype` against the FDA label of `drug`.
+pub async fn dose_check(
+    drug: &str,
+    gene: &str,
+    phenotype: &str,
+) -> Result<PgxDoseCheck, BioMcpError> {
+    let drug = drug.trim();
+    let gene = gene.trim().to_ascii_uppercase();
+    let phenotype = phenotype.trim().replace('_', " ");
+    if drug.is_empty() || gene.is_empty() || phenotype.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "--drug, --gene, and --phenotype are required. Example: biomcp pgx dose-check --drug azathioprine --gene TPMT --phenotype \"intermediate metabolizer\"".into(),
+        ));
+    }
+    if !is_likely_gene(&gene) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Invalid gene symbol: {gene}"
+        )));
+    }
+
+    let cpic = CpicClient::new()?;
+    let (rows, label_response) = tokio::join!(
+        cpic.recommendations_by_drug(drug, DOSE_CHECK_RECOMMENDATION_LIMIT),
+        async { OpenFdaClient::new()?.label_search(drug).await }
+    );
+    let rows = rows?;
+
+    let mut notes = Vec::new();
+    let label = match label_response {
+        Ok(Some(response)) => {
+            select_label(&response, drug).map(|label| dose_label(label, &gene, &phenotype))
+        }
+        Ok(None) => {
+            notes.push(format!("No FDA label found in OpenFDA for {drug}."));
+            None
+        }
+        Err(err) => {
+            warn!(drug = %drug, "OpenFDA label lookup failed: {err}");
+            notes.push(format!("FDA label unavailable: {err}"));
+            None
+        }
+    };
+
+    Ok(build_dose_check(
+        drug, &gene, &phenotype, &rows, label, notes,
+    ))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,187 @@
+//! Tests for CPIC-versus-label dose cross-checks.
+
+use std::collections::HashMap;
+
+use super::*;
+
+fn recommendation(phenotypes: &[(&str, &str)], text: &str) -> CpicRecommendationRow {
+    CpicRecommendationRow {
+        recommendationid: None,
+        lookupkey: HashMap::new(),
+        drugname: "azathioprine".into(),
+        guidelinename: Some("Thiopurines and TPMT and NUDT15".into()),
+        guidelineurl: Some(
+            "https://cpicpgx.org/guidelines/guideline-for-thiopurines-and-tpmt/".into(),
+        ),
+        implications: HashMap::from([(
+            "TPMT".to_string(),
+            "Moderate to high concentrations of TGN metabolites".to_string(),
+        )]),
+        drugrecommendation: Some(text.into()),
+        classification: Some("Strong".into()),
+        phenotypes: phenotypes
+            .iter()
+            .map(|(gene, value)| (gene.to_string(), value.to_string()))
+            .collect(),
+        activityscore: HashMap::new(),
+        population: None,
+    }
+}
+
+fn azathioprine_rows() -> Vec<CpicRecommendationRow> {
+    vec![
+        recommendation(
+            &[
+                ("TPMT", "Intermediate Metabolizer"),
+                ("NUDT15", "Normal Metabolizer"),
+            ],
+            "Start with reduced starting doses (30-80% of normal dose) if normal starting dose is 2-3 mg/kg/day.",
+        ),
+        recommendation(
+            &[
+                ("TPMT", "Poor Metabolizer"),
+                ("NUDT15", "Normal Metabolizer"),
+            ],
+            "For nonmalignant conditions, consider alternative nonthiopurine immunosuppressant therapy.",
+        ),
+        recommendation(
+            &[
+                ("TPMT", "Normal Metabolizer"),
+                ("NUDT15", "Normal Metabolizer"),
+            ],
+            "Start with normal starting dose (e.g., 2-3 mg/kg/day).",
+        ),
+    ]
+}
+
+fn label_json(pharmacogenomics: &str) -> serde_json::Value {
+    serde_json::json!({
+        "set_id": "f7a8a6b0-0000-4c1e-9c1a-000000000001",
+        "openfda": {"generic_name": ["AZATHIOPRINE"], "brand_name": ["Imuran"]},
+        "pharmacogenomics": [pharmacogenomics]
+    })
+}
+
+#[test]
+fn dosing_action_prefers_the_most_restrictive_direction() {
+    assert_eq!(
+        dosing_action("Consider alternative therapy or dosage reduction."),
+        DosingAction::Avoid
+    );
+    assert_eq!(
+        dosing_action("Start with reduced starting doses (30-80% of normal dose)."),
+        DosingAction::Reduce
+    );
+    assert_eq!(
+        dosing_action("Initiate therapy with the recommended starting dose."),
+        DosingAction::Standard
+    );
+    assert_eq!(
+        dosing_action("Monitor blood counts weekly."),
+        DosingAction::Unspecified
+    );
+}
+
+#[test]
+fn build_dose_check_agrees_when_label_matches_cpic_direction() {
+    let label = dose_label(
+        &label_json(
+            "Patients with intermediate TPMT activity may need a dose reduction. Patients with homozygous TPMT deficiency should use alternative therapy. TPMTX is unrelated.",
+        ),
+        "TPMT",
+        "intermediate metabolizer",
+    );
+    assert!(label.phenotype_specific);
+    assert_eq!(label.excerpts.len(), 1);
+    assert_eq!(label.brand_name.as_deref(), Some("Imuran"));
+
+    let check = build_dose_check(
+        "azathioprine",
+        "TPMT",
+        "intermediate metabolizer",
+        &azathioprine_rows(),
+        Some(label),
+        Vec::new(),
+    );
+    assert_eq!(check.status, PgxDoseCheckStatus::Agree);
+    assert_eq!(check.cpic.len(), 1);
+    assert_eq!(check.cpic[0].phenotype, "Intermediate Metabolizer");
+    assert_eq!(
+        check.cpic[0].other_phenotypes,
+        vec!["NUDT15: Normal Metabolizer"]
+    );
+    assert_eq!(check.cpic[0].action, DosingAction::Reduce);
+    assert!(check.flags.is_empty());
+}
+
+#[test]
+fn build_dose_check_flags_disagreement_and_label_silence() {
+    let standard_label = dose_label(
+        &label_json(
+            "No dose adjustment is needed for patients who are TPMT intermediate metabolizers.",
+        ),
+        "TPMT",
+        "Intermediate Metabolizer",
+    );
+    let check = build_dose_check(
+        "azathioprine",
+        "TPMT",
+        "Intermediate Metabolizer",
+        &azathioprine_rows(),
+        Some(standard_label),
+        Vec::new(),
+    );
+    assert_eq!(check.status, PgxDoseCheckStatus::Disagree);
+    assert_eq!(
+        check.flags,
+        vec![
+            "CPIC says reduce dose for TPMT Intermediate Metabolizer; the FDA label says standard dosing."
+        ]
+    );
+
+    let silent_label = dose_label(
+        &label_json("Azathioprine is an imidazolyl derivative of 6-mercaptopurine."),
+        "TPMT",
+        "poor metabolizer",
+    );
+    assert!(silent_label.excerpts.is_empty());
+    let check = build_dose_check(
+        "azathioprine",
+        "TPMT",
+        "poor metabolizer",
+        &azathioprine_rows(),
+        Some(silent_label),
+        Vec::new(),
+    );
+    assert_eq!(check.status, PgxDoseCheckStatus::LabelSilent);
+    assert_eq!(check.cpic[0].action, DosingAction::Avoid);
+    assert_eq!(check.flags.len(), 1);
+}
+
+#[test]
+fn build_dose_check_lists_known_phenotypes_when_none_match() {
+    let check = build_dose_check(
+        "azathioprine",
+        "TPMT",
+        "ultrarapid metabolizer",
+        &azathioprine_rows(),
+        None,
+        Vec::new(),
+    );
+    assert_eq!(check.status, PgxDoseCheckStatus::NoCpicRecommendation);
+    assert!(check.notes[0].contains(
+        "CPIC phenotypes: Intermediate Metabolizer, Normal Metabolizer, Poor Metabolizer."
+    ));
+}
+
+#[test]
+fn select_label_prefers_exact_generic_name() {
+    let response = serde_json::json!({
+        "results": [
+            {"set_id": "combo", "openfda": {"generic_name": ["AZATHIOPRINE SODIUM"]}},
+            {"set_id": "plain", "openfda": {"generic_name": ["AZATHIOPRINE"]}}
+        ]
+    });
+    let selected = select_label(&response, "azathioprine").expect("label");
+    assert_eq!(selected["set_id"], "plain");
+}
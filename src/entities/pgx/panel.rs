@@ -0,0 +1,398 @@
+//! Multi-gene pre-emptive PGx panel built from CPIC gene-drug pairs.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use futures::future::try_join_all;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::{cpic_level_rank, is_likely_gene};
+use crate::error::BioMcpError;
+use crate::sources::cpic::{CpicClient, CpicPairRow, CpicRecommendationRow};
+use crate::utils::text::non_empty;
+
+const PANEL_MAX_GENES: usize = 20;
+const PANEL_PAIRS_PER_GENE: usize = 200;
+const PANEL_RECOMMENDATIONS_PER_GENE: usize = 200;
+const VCF_GENE_KEYS: &[&str] = &["GENE", "SYMBOL", "PX_GENE"];
+const VCF_DIPLOTYPE_KEYS: &[&str] = &["DIPLOTYPE", "STAR", "HAPLOTYPE", "PX_DIPLOTYPE"];
+const VCF_PHENOTYPE_KEYS: &[&str] = &["PHENOTYPE", "PX_PHENOTYPE"];
+
+/// Combined actionable-drug table across a panel of pharmacogenes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PgxPanel {
+    pub genes: Vec<PgxPanelGene>,
+    pub drugs: Vec<PgxPanelDrug>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genotype_source: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PgxPanelGene {
+    pub gene: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diplotype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phenotype: Option<String>,
+    pub actionable_drugs: usize,
+    pub other_drugs: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PgxPanelDrug {
+    pub drugname: String,
+    pub genes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpiclevel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pgxtesting: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guidelinename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guidelineurl: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recommendations: Vec<PgxPanelRecommendation>,
+}
+
+/// CPIC recommendation matched to a called phenotype.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PgxPanelRecommendation {
+    pub gene: String,
+    pub phenotype: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommendation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+}
+
+/// Star-allele call for one gene, as read from a genotype VCF.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgxGenotypeCall {
+    pub gene: String,
+    pub diplotype: Option<String>,
+    pub phenotype: Option<String>,
+}
+
+fn parse_gene_list(raw: &str) -> Result<Vec<String>, BioMcpError> {
+    let mut seen = HashSet::new();
+    let mut genes = Vec::new();
+    for token in raw.split(',') {
+        let gene = token.trim().to_ascii_uppercase();
+        if gene.is_empty() {
+            continue;
+        }
+        if !is_likely_gene(&gene) {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "Invalid gene symbol in --genes: {}",
+                token.trim()
+            )));
+        }
+        if seen.insert(gene.clone()) {
+            genes.push(gene);
+        }
+    }
+    Ok(genes)
+}
+
+fn info_value<'a>(info: &HashMap<String, &'a str>, keys: &[&str]) -> Option<&'a str> {
+    keys.iter()
+        .find_map(|key| info.get(*key).copied())
+        .map(str::trim)
+        .filter(|value| !value.is_empty() && *value != ".")
+}
+
+/// Reads star-allele calls from VCF INFO fields.
+///
+/// Each call is a record whose INFO carries `GENE=<symbol>` plus a
+/// `DIPLOTYPE=*1/*4` (or `STAR=`) value and an optional `PHENOTYPE=`; records
+/// without a gene and a diplotype or phenotype are ignored. The first call per
+/// gene wins.
+pub fn parse_vcf_star_alleles(content: &str) -> Result<Vec<PgxGenotypeCall>, BioMcpError> {
+    let mut calls: Vec<PgxGenotypeCall> = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let columns = line.split('\t').collect::<Vec<_>>();
+        if columns.len() < 8 {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "VCF line {} has {} columns; expected at least 8 tab-separated columns",
+                idx + 1,
+                columns.len()
+            )));
+        }
+
+        let info = columns[7]
+            .split(';')
+            .filter_map(|field| field.split_once('='))
+            .map(|(key, value)| (key.trim().to_ascii_uppercase(), value))
+            .collect::<HashMap<_, _>>();
+        let Some(gene) = info_value(&info, VCF_GENE_KEYS).map(str::to_ascii_uppercase) else {
+            continue;
+        };
+        let diplotype = info_value(&info, VCF_DIPLOTYPE_KEYS).map(str::to_string);
+        let phenotype = info_value(&info, VCF_PHENOTYPE_KEYS).map(|value| value.replace('_', " "));
+        if diplotype.is_none() && phenotype.is_none() {
+            continue;
+        }
+        if calls.iter().any(|call| call.gene == gene) {
+            continue;
+        }
+        calls.push(PgxGenotypeCall {
+            gene,
+            diplotype,
+            phenotype,
+        });
+    }
+    Ok(calls)
+}
+
+fn is_actionable(level: Option<&str>) -> bool {
+    cpic_level_rank(level) <= 1
+}
+
+fn summarize_genes(
+    genes: &[String],
+    pairs: &[Vec<CpicPairRow>],
+    calls: &[PgxGenotypeCall],
+) -> Vec<PgxPanelGene> {
+    genes
+        .iter()
+        .zip(pairs)
+        .map(|(gene, rows)| {
+            let mut actionable = HashSet::new();
+            let mut other = HashSet::new();
+            for row in rows {
+                let drug = row.drugname.trim().to_ascii_lowercase();
+                if drug.is_empty() {
+                    continue;
+                }
+                if is_actionable(row.cpiclevel.as_deref()) {
+                    actionable.insert(drug);
+                } else {
+                    other.insert(drug);
+                }
+            }
+            other.retain(|drug| !actionable.contains(drug));
+            let call = calls.iter().find(|call| call.gene == *gene);
+            PgxPanelGene {
+                gene: gene.clone(),
+                diplotype: call.and_then(|call| call.diplotype.clone()),
+                phenotype: call.and_then(|call| call.phenotype.clone()),
+                actionable_drugs: actionable.len(),
+                other_drugs: other.len(),
+            }
+        })
+        .collect()
+}
+
+fn merge_actionable_drugs(pairs: &[Vec<CpicPairRow>]) -> Vec<PgxPanelDrug> {
+    let mut by_drug: BTreeMap<String, PgxPanelDrug> = BTreeMap::new();
+    for row in pairs.iter().flatten() {
+        let drug = row.drugname.trim();
+        let gene = row.genesymbol.trim().to_ascii_uppercase();
+        if drug.is_empty() || gene.is_empty() || !is_actionable(row.cpiclevel.as_deref()) {
+            continue;
+        }
+
+        let entry = by_drug
+            .entry(drug.to_ascii_lowercase())
+            .or_insert_with(|| PgxPanelDrug {
+                drugname: drug.to_string(),
+                genes: Vec::new(),
+                cpiclevel: None,
+                pgxtesting: None,
+                guidelinename: None,
+                guidelineurl: None,
+                recommendations: Vec::new(),
+            });
+        if !entry.genes.contains(&gene) {
+            entry.genes.push(gene);
+        }
+        if entry.cpiclevel.is_none()
+            || cpic_level_rank(row.cpiclevel.as_deref())
+                < cpic_level_rank(entry.cpiclevel.as_deref())
+        {
+            entry.cpiclevel = non_empty(row.cpiclevel.as_deref());
+            entry.pgxtesting = non_empty(row.pgxtesting.as_deref()).or(entry.pgxtesting.take());
+        }
+        if entry.guidelinename.is_none() {
+            entry.guidelinename = non_empty(row.guidelinename.as_deref());
+            entry.guidelineurl = non_empty(row.guidelineurl.as_deref());
+        }
+    }
+
+    let mut out = by_drug.into_values().collect::<Vec<_>>();
+    out.sort_by(|a, b| {
+        cpic_level_rank(a.cpiclevel.as_deref())
+            .cmp(&cpic_level_rank(b.cpiclevel.as_deref()))
+            .then_with(|| b.genes.len().cmp(&a.genes.len()))
+            .then_with(|| a.drugname.cmp(&b.drugname))
+    });
+    out
+}
+
+pub(super) fn lookup_ignore_case<'a>(
+    map: &'a HashMap<String, String>,
+    gene: &str,
+) -> Option<&'a str> {
+    map.iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(gene))
+        .map(|(_, value)| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+/// Keeps recommendations whose phenotype for every called gene matches the call.
+fn matching_recommendations(
+    gene: &str,
+    rows: &[CpicRecommendationRow],
+    calls: &[PgxGenotypeCall],
+) -> Vec<(String, PgxPanelRecommendation)> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    for row in rows {
+        let drug = row.drugname.trim();
+        let Some(phenotype) = lookup_ignore_case(&row.phenotypes, gene) else {
+            continue;
+        };
+        let consistent = calls.iter().all(|call| {
+            let Some(called) = call.phenotype.as_deref() else {
+                return true;
+            };
+            lookup_ignore_case(&row.phenotypes, &call.gene)
+                .is_none_or(|value| value.eq_ignore_ascii_case(called))
+        });
+        if drug.is_empty() || !consistent {
+            continue;
+        }
+
+        let recommendation = non_empty(row.drugrecommendation.as_deref());
+        let key = (drug.to_ascii_lowercase(), recommendation.clone());
+        if !seen.insert(key) {
+            continue;
+        }
+        out.push((
+            drug.to_ascii_lowercase(),
+            PgxPanelRecommendation {
+                gene: gene.to_string(),
+                phenotype: phenotype.to_string(),
+                recommendation,
+                classification: non_empty(row.classification.as_deref()),
+            },
+        ));
+    }
+    out
+}
+
+fn attach_recommendations(
+    drugs: &mut [PgxPanelDrug],
+    matched: Vec<(String, PgxPanelRecommendation)>,
+) {
+    for (drug_key, recommendation) in matched {
+        if let Some(drug) = drugs
+            .iter_mut()
+            .find(|drug| drug.drugname.eq_ignore_ascii_case(&drug_key))
+        {
+            drug.recommendations.push(recommendation);
+        }
+    }
+}
+
+/// Builds a pre-emptive panel report for `genes` (comma-separated) and optional genotype calls.
+///
+/// When `genes` is empty the panel covers the genes present in `calls`.
+pub async fn panel(
+    genes: Option<&str>,
+    calls: Vec<PgxGenotypeCall>,
+    genotype_source: Option<String>,
+) -> Result<PgxPanel, BioMcpError> {
+    let mut genes = parse_gene_list(genes.unwrap_or_default())?;
+    if genes.is_empty() {
+        genes = calls.iter().map(|call| call.gene.clone()).collect();
+    }
+    if genes.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "At least one gene is required. Example: biomcp pgx panel --genes CYP2D6,CYP2C19,TPMT,DPYD"
+                .into(),
+        ));
+    }
+    if genes.len() > PANEL_MAX_GENES {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "PGx panels are limited to {PANEL_MAX_GENES} genes."
+        )));
+    }
+
+    let cpic = CpicClient::new()?;
+    let pairs = try_join_all(
+        genes
+            .iter()
+            .map(|gene| cpic.pairs_by_gene(gene, PANEL_PAIRS_PER_GENE)),
+    )
+    .await?;
+
+    let mut notes = Vec::new();
+    let unknown = genes
+        .iter()
+        .zip(&pairs)
+        .filter(|(_, rows)| rows.is_empty())
+        .map(|(gene, _)| gene.as_str())
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        notes.push(format!(
+            "No CPIC gene-drug pairs for: {}.",
+            unknown.join(", ")
+        ));
+    }
+
+    let mut drugs = merge_actionable_drugs(&pairs);
+    for call in calls.iter().filter(|call| genes.contains(&call.gene)) {
+        if call.phenotype.is_none() {
+            notes.push(format!(
+                "{} {} has no phenotype call; add PHENOTYPE= to the VCF INFO to match CPIC recommendations.",
+                call.gene,
+                call.diplotype.as_deref().unwrap_or_default()
+            ));
+            continue;
+        }
+        match cpic
+            .recommendations_by_gene(&call.gene, PANEL_RECOMMENDATIONS_PER_GENE)
+            .await
+        {
+            Ok(rows) => attach_recommendations(
+                &mut drugs,
+                matching_recommendations(&call.gene, &rows, &calls),
+            ),
+            Err(err) => {
+                warn!(gene = %call.gene, "CPIC recommendation lookup failed: {err}");
+                notes.push(format!(
+                    "CPIC recommendations unavailable for {}; returned the drug table only.",
+                    call.gene
+                ));
+            }
+        }
+    }
+    let skipped = calls
+        .iter()
+        .filter(|call| !genes.contains(&call.gene))
+        .map(|call| call.gene.as_str())
+        .collect::<Vec<_>>();
+    if !skipped.is_empty() {
+        notes.push(format!(
+            "Genotype calls outside --genes were ignored: {}.",
+            skipped.join(", ")
+        ));
+    }
+
+    Ok(PgxPanel {
+        genes: summarize_genes(&genes, &pairs, &calls),
+        drugs,
+        genotype_source,
+        notes,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,183 @@
+//! Tests for PGx panel merging and genotype VCF parsing.
+
+use super::*;
+
+fn pair(gene: &str, drug: &str, level: &str) -> CpicPairRow {
+    CpicPairRow {
+        pairid: None,
+        genesymbol: gene.into(),
+        drugname: drug.into(),
+        cpiclevel: Some(level.into()),
+        pgxtesting: Some("Actionable PGx".into()),
+        guidelinename: Some(format!("CPIC Guideline for {drug}")),
+        guidelineurl: None,
+        usedforrecommendation: None,
+        provisional: None,
+    }
+}
+
+fn recommendation(drug: &str, phenotypes: &[(&str, &str)], text: &str) -> CpicRecommendationRow {
+    CpicRecommendationRow {
+        recommendationid: None,
+        lookupkey: HashMap::new(),
+        drugname: drug.into(),
+        guidelinename: None,
+        guidelineurl: None,
+        implications: HashMap::new(),
+        drugrecommendation: Some(text.into()),
+        classification: Some("Strong".into()),
+        phenotypes: phenotypes
+            .iter()
+            .map(|(gene, value)| (gene.to_string(), value.to_string()))
+            .collect(),
+        activityscore: HashMap::new(),
+        population: None,
+    }
+}
+
+#[test]
+fn parse_gene_list_dedupes_and_rejects_invalid_symbols() {
+    let genes = parse_gene_list("cyp2d6, CYP2C19,,CYP2D6,TPMT").expect("genes");
+    assert_eq!(genes, vec!["CYP2D6", "CYP2C19", "TPMT"]);
+
+    let err = parse_gene_list("CYP2D6,not a gene").expect_err("invalid");
+    assert!(err.to_string().contains("not a gene"));
+}
+
+#[test]
+fn parse_vcf_star_alleles_reads_info_calls() {
+    let vcf = "##fileformat=VCFv4.2\n\
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n\
+22\t42130692\t.\tG\tA\t.\tPASS\tGENE=CYP2D6;DIPLOTYPE=*1/*4;PHENOTYPE=Intermediate_Metabolizer\n\
+22\t42128945\t.\tC\tT\t.\tPASS\tGENE=CYP2D6;DIPLOTYPE=*1/*1\n\
+10\t94781859\t.\tG\tA\t.\tPASS\tSYMBOL=cyp2c19;STAR=*2/*17\n\
+1\t97450058\t.\tC\tT\t.\tPASS\tDP=30\n";
+
+    let calls = parse_vcf_star_alleles(vcf).expect("calls");
+    assert_eq!(
+        calls,
+        vec![
+            PgxGenotypeCall {
+                gene: "CYP2D6".into(),
+                diplotype: Some("*1/*4".into()),
+                phenotype: Some("Intermediate Metabolizer".into()),
+            },
+            PgxGenotypeCall {
+                gene: "CYP2C19".into(),
+                diplotype: Some("*2/*17".into()),
+                phenotype: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn parse_vcf_star_alleles_rejects_short_rows() {
+    let err = parse_vcf_star_alleles("22 42130692 . G A\n").expect_err("space separated");
+    assert!(err.to_string().contains("line 1"));
+}
+
+#[test]
+fn merge_actionable_drugs_combines_genes_and_keeps_best_level() {
+    let pairs = vec![
+        vec![
+            pair("CYP2D6", "amitriptyline", "A"),
+            pair("CYP2D6", "codeine", "A"),
+            pair("CYP2D6", "aripiprazole", "C"),
+        ],
+        vec![
+            pair("CYP2C19", "amitriptyline", "A"),
+            pair("CYP2C19", "clopidogrel", "A"),
+        ],
+        vec![
+            pair("TPMT", "azathioprine", "A"),
+            pair("TPMT", "cisplatin", "B"),
+        ],
+    ];
+
+    let drugs = merge_actionable_drugs(&pairs);
+    let names = drugs
+        .iter()
+        .map(|drug| drug.drugname.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![
+            "amitriptyline",
+            "azathioprine",
+            "clopidogrel",
+            "codeine",
+            "cisplatin"
+        ]
+    );
+    assert_eq!(drugs[0].genes, vec!["CYP2D6", "CYP2C19"]);
+    assert_eq!(drugs[4].cpiclevel.as_deref(), Some("B"));
+
+    let genes = summarize_genes(
+        &["CYP2D6".into(), "CYP2C19".into(), "TPMT".into()],
+        &pairs,
+        &[],
+    );
+    assert_eq!(genes[0].actionable_drugs, 2);
+    assert_eq!(genes[0].other_drugs, 1);
+}
+
+#[test]
+fn matching_recommendations_respects_all_called_phenotypes() {
+    let calls = vec![
+        PgxGenotypeCall {
+            gene: "CYP2D6".into(),
+            diplotype: Some("*1/*4".into()),
+            phenotype: Some("Intermediate Metabolizer".into()),
+        },
+        PgxGenotypeCall {
+            gene: "CYP2C19".into(),
+            diplotype: None,
+            phenotype: Some("Normal Metabolizer".into()),
+        },
+    ];
+    let rows = vec![
+        recommendation(
+            "codeine",
+            &[("CYP2D6", "Intermediate Metabolizer")],
+            "Use label recommended dosing",
+        ),
+        recommendation(
+            "codeine",
+            &[("CYP2D6", "Poor Metabolizer")],
+            "Avoid codeine",
+        ),
+        recommendation(
+            "amitriptyline",
+            &[
+                ("CYP2D6", "Intermediate Metabolizer"),
+                ("CYP2C19", "Poor Metabolizer"),
+            ],
+            "Avoid amitriptyline",
+        ),
+        recommendation(
+            "amitriptyline",
+            &[
+                ("CYP2D6", "Intermediate Metabolizer"),
+                ("CYP2C19", "Normal Metabolizer"),
+            ],
+            "Consider a 25% reduction of the starting dose",
+        ),
+    ];
+
+    let matched = matching_recommendations("CYP2D6", &rows, &calls);
+    let texts = matched
+        .iter()
+        .map(|(drug, row)| (drug.as_str(), row.recommendation.as_deref().unwrap_or("")))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        texts,
+        vec![
+            ("codeine", "Use label recommended dosing"),
+            (
+                "amitriptyline",
+                "Consider a 25% reduction of the starting dose"
+            ),
+        ]
+    );
+}
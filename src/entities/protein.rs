@@ -1,10 +1,12 @@
 use std::sync::OnceLock;
 
 use regex::Regex;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
 
 use crate::entities::SearchPage;
+use crate::entities::section_errors;
 use crate::error::BioMcpError;
 use crate::sources::complexportal::{ComplexPortalClient, ComplexPortalComplex};
 use crate::sources::interpro::InterProClient;
@@ -13,7 +15,7 @@ use crate::sources::string::StringClient;
 use crate::sources::uniprot::UniProtClient;
 use crate::transform;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Protein {
     pub accession: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -39,7 +41,7 @@ pub struct Protein {
     pub complexes: Vec<ProteinComplex>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProteinDomain {
     pub accession: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -48,21 +50,21 @@ pub struct ProteinDomain {
     pub domain_type: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProteinInteraction {
     pub partner: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub score: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum ProteinComplexCuration {
     Curated,
     Predicted,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProteinComplexComponent {
     pub accession: String,
     pub name: String,
@@ -70,7 +72,7 @@ pub struct ProteinComplexComponent {
     pub stoichiometry: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProteinComplex {
     pub accession: String,
     pub name: String,
@@ -81,6 +83,21 @@ pub struct ProteinComplex {
     pub components: Vec<ProteinComplexComponent>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProteinDomainHit {
+    pub accession: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gene_symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub species: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<u32>,
+    pub reviewed: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub domain_locations: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProteinSearchResult {
     pub accession: String,
@@ -130,7 +147,7 @@ fn uniprot_accession_re() -> &'static Regex {
     })
 }
 
-fn is_uniprot_accession(value: &str) -> bool {
+pub(crate) fn is_uniprot_accession(value: &str) -> bool {
     uniprot_accession_re().is_match(value.trim())
 }
 
@@ -201,66 +218,148 @@ fn parse_sections(sections: &[String]) -> Result<ProteinSections, BioMcpError> {
     Ok(out)
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct ProteinSearchFilters {
+    pub query: String,
+    pub all_species: bool,
+    pub reviewed: bool,
+    pub disease: Option<String>,
+    pub existence: Option<u8>,
+    /// NCBI taxonomy ID; replaces the human-only default when set.
+    pub organism: Option<u32>,
+    /// Pfam/InterPro/SMART/CDD/PANTHER accession; routes the search to InterPro.
+    pub domain: Option<String>,
+}
+
+pub const DEFAULT_TSV_COLUMNS: &[&str] = &[
+    "accession",
+    "id",
+    "gene_primary",
+    "protein_name",
+    "organism_id",
+    "length",
+    "reviewed",
+];
+const MAX_TSV_PAGE_FETCHES: usize = 5_000;
+
+const ORGANISM_ALIASES: &[(&str, u32)] = &[
+    ("human", 9606),
+    ("homo sapiens", 9606),
+    ("mouse", 10090),
+    ("mus musculus", 10090),
+    ("rat", 10116),
+    ("rattus norvegicus", 10116),
+    ("zebrafish", 7955),
+    ("danio rerio", 7955),
+    ("fly", 7227),
+    ("drosophila melanogaster", 7227),
+    ("worm", 6239),
+    ("caenorhabditis elegans", 6239),
+    ("yeast", 559292),
+    ("saccharomyces cerevisiae", 559292),
+    ("arabidopsis", 3702),
+    ("arabidopsis thaliana", 3702),
+    ("e. coli", 83333),
+    ("escherichia coli", 83333),
+];
+
+/// Parses `--organism` as an NCBI taxonomy ID or a common species name.
+pub fn parse_organism(value: &str) -> Result<u32, BioMcpError> {
+    let value = value.trim();
+    if let Ok(taxon) = value.parse::<u32>()
+        && taxon > 0
+    {
+        return Ok(taxon);
+    }
+    let normalized = value.to_ascii_lowercase();
+    ORGANISM_ALIASES
+        .iter()
+        .find(|(name, _)| *name == normalized)
+        .map(|(_, taxon)| *taxon)
+        .ok_or_else(|| {
+            BioMcpError::InvalidArgument(format!(
+                "Unknown --organism \"{value}\". Use an NCBI taxonomy ID (e.g. 9606) or one of: human, mouse, rat, zebrafish, fly, worm, yeast, arabidopsis, e. coli"
+            ))
+        })
+}
+
+fn parse_tsv_columns(columns: &[String]) -> Result<String, BioMcpError> {
+    let columns: Vec<&str> = columns
+        .iter()
+        .map(|column| column.trim())
+        .filter(|column| !column.is_empty())
+        .collect();
+    if columns.is_empty() {
+        return Ok(DEFAULT_TSV_COLUMNS.join(","));
+    }
+    if let Some(bad) = columns.iter().find(|column| {
+        !column
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_')
+    }) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Invalid --columns entry \"{bad}\". Use UniProt return field names such as accession,gene_primary,length"
+        )));
+    }
+    Ok(columns.join(","))
+}
+
 #[allow(dead_code)]
 pub async fn search(
     query: &str,
     limit: usize,
     all_species: bool,
 ) -> Result<Vec<ProteinSearchResult>, BioMcpError> {
-    Ok(
-        search_page(query, limit, 0, None, all_species, false, None, None)
-            .await?
-            .results,
-    )
+    let filters = ProteinSearchFilters {
+        query: query.to_string(),
+        all_species,
+        ..Default::default()
+    };
+    Ok(search_page(&filters, limit, 0, None).await?.results)
 }
 
-pub fn search_query_summary(
-    query: &str,
-    reviewed: bool,
-    disease: Option<&str>,
-    existence: Option<u8>,
-    all_species: bool,
-) -> String {
+pub fn search_query_summary(filters: &ProteinSearchFilters) -> String {
     let mut parts = Vec::new();
-    let query = query.trim();
+    let query = filters.query.trim();
     if !query.is_empty() {
         parts.push(query.to_string());
     }
+    if let Some(domain) = filters.domain.as_deref() {
+        parts.push(format!("domain={}", domain.trim()));
+    }
+    if let Some(organism) = filters.organism {
+        parts.push(format!("organism={organism}"));
+    }
     // Reviewed entries are the default safety mode.
-    if reviewed || !all_species {
+    if filters.reviewed || (!filters.all_species && filters.organism.is_none()) {
         parts.push("reviewed=true".to_string());
     }
-    if let Some(disease) = disease.map(str::trim).filter(|v| !v.is_empty()) {
+    if let Some(disease) = filters
+        .disease
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
         parts.push(format!("disease={disease}"));
     }
-    if let Some(existence) = existence {
+    if let Some(existence) = filters.existence {
         parts.push(format!("existence={existence}"));
     }
-    if all_species {
+    if filters.all_species {
         parts.push("all_species=true".to_string());
     }
     parts.join(", ")
 }
 
-#[allow(clippy::too_many_arguments)]
-pub async fn search_page(
-    query: &str,
-    limit: usize,
-    offset: usize,
-    next_page: Option<String>,
-    all_species: bool,
-    reviewed: bool,
-    disease: Option<&str>,
-    existence: Option<u8>,
-) -> Result<SearchPage<ProteinSearchResult>, BioMcpError> {
-    let query = query.trim();
-    if query.is_empty() {
+fn scoped_query(filters: &ProteinSearchFilters) -> Result<String, BioMcpError> {
+    let query = filters.query.trim();
+    if query.is_empty() && filters.organism.is_none() {
         return Err(BioMcpError::InvalidArgument(
             "Query is required. Example: biomcp search protein -q kinase".into(),
         ));
     }
 
-    if let Some(level) = existence
+    if let Some(level) = filters.existence
         && !(1..=5).contains(&level)
     {
         return Err(BioMcpError::InvalidArgument(
@@ -268,21 +367,149 @@ pub async fn search_page(
         ));
     }
 
-    let mut scoped_terms = vec![format!("({query})")];
-    if !all_species {
-        scoped_terms.push("organism_id:9606".to_string());
+    let mut scoped_terms = Vec::new();
+    if !query.is_empty() {
+        scoped_terms.push(format!("({query})"));
     }
-    if reviewed || !all_species {
+    match filters.organism {
+        Some(taxon) => scoped_terms.push(format!("organism_id:{taxon}")),
+        None if !filters.all_species => scoped_terms.push("organism_id:9606".to_string()),
+        None => {}
+    }
+    if filters.reviewed || (!filters.all_species && filters.organism.is_none()) {
         scoped_terms.push("reviewed:true".to_string());
     }
-    if let Some(disease) = disease.map(str::trim).filter(|v| !v.is_empty()) {
+    if let Some(disease) = filters
+        .disease
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
         let disease = disease.replace('"', "\\\"");
         scoped_terms.push(format!("cc_disease:\"{disease}\""));
     }
-    if let Some(level) = existence {
+    if let Some(level) = filters.existence {
         scoped_terms.push(format!("existence:{level}"));
     }
-    let scoped_query = scoped_terms.join(" AND ");
+    Ok(scoped_terms.join(" AND "))
+}
+
+/// Lists proteins carrying `filters.domain` via InterPro.
+pub async fn search_by_domain(
+    filters: &ProteinSearchFilters,
+    limit: usize,
+    next_page: Option<String>,
+) -> Result<SearchPage<ProteinDomainHit>, BioMcpError> {
+    let Some(domain) = filters
+        .domain
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    else {
+        return Err(BioMcpError::InvalidArgument(
+            "--domain requires an accession. Example: biomcp search protein --domain PF07714"
+                .into(),
+        ));
+    };
+    if !filters.query.trim().is_empty() || filters.disease.is_some() || filters.existence.is_some()
+    {
+        return Err(BioMcpError::InvalidArgument(
+            "--domain cannot be combined with a text query, --disease, or --existence".into(),
+        ));
+    }
+
+    let tax_id = match filters.organism {
+        Some(taxon) => Some(taxon),
+        None if !filters.all_species => Some(9606),
+        None => None,
+    };
+    let reviewed_only = filters.reviewed || (!filters.all_species && filters.organism.is_none());
+    let page = InterProClient::new()?
+        .proteins_with_entry(domain, tax_id, reviewed_only, limit, next_page.as_deref())
+        .await?;
+    let results = page
+        .results
+        .into_iter()
+        .map(|hit| ProteinDomainHit {
+            name: hit.name.unwrap_or_else(|| hit.accession.clone()),
+            accession: hit.accession,
+            gene_symbol: hit.gene,
+            species: hit.organism,
+            length: hit.length,
+            reviewed: hit.reviewed,
+            domain_locations: hit.locations,
+        })
+        .collect();
+    Ok(SearchPage::cursor(
+        results,
+        page.total,
+        page.next_page_token,
+    ))
+}
+
+/// Walks UniProt's cursor pagination and returns every matching record as TSV.
+pub async fn export_tsv(
+    filters: &ProteinSearchFilters,
+    columns: &[String],
+) -> Result<String, BioMcpError> {
+    if filters.organism.is_none() && filters.all_species && !filters.reviewed {
+        return Err(BioMcpError::InvalidArgument(
+            "--all across every species needs --organism or --reviewed; unscoped UniProtKB has hundreds of millions of entries".into(),
+        ));
+    }
+    let scoped_query = scoped_query(filters)?;
+    let fields = parse_tsv_columns(columns)?;
+    let client = UniProtClient::new()?;
+
+    let mut tsv = String::new();
+    let mut rows = 0usize;
+    let mut total = None;
+    let mut cursor: Option<String> = None;
+    for fetched_pages in 0..MAX_TSV_PAGE_FETCHES {
+        let page = client
+            .search_tsv(
+                &scoped_query,
+                &fields,
+                crate::sources::uniprot::UNIPROT_MAX_PAGE_SIZE,
+                cursor.as_deref(),
+            )
+            .await?;
+        if total.is_none() {
+            total = page.total;
+        }
+        let mut lines = page.body.lines();
+        let header = lines.next();
+        if fetched_pages == 0
+            && let Some(header) = header
+        {
+            tsv.push_str(header);
+            tsv.push('\n');
+        }
+        for line in lines.filter(|line| !line.is_empty()) {
+            tsv.push_str(line);
+            tsv.push('\n');
+            rows += 1;
+        }
+        tracing::debug!(rows, total = ?total, "protein TSV export page {}", fetched_pages + 1);
+
+        cursor = page.next_page_token;
+        if cursor.is_none() {
+            return Ok(tsv);
+        }
+    }
+
+    Err(BioMcpError::InvalidArgument(format!(
+        "--all stopped after {MAX_TSV_PAGE_FETCHES} pages ({rows} rows). Narrow the search with a query, --reviewed, or --existence."
+    )))
+}
+
+pub async fn search_page(
+    filters: &ProteinSearchFilters,
+    limit: usize,
+    offset: usize,
+    next_page: Option<String>,
+) -> Result<SearchPage<ProteinSearchResult>, BioMcpError> {
+    let scoped_query = scoped_query(filters)?;
 
     let client = UniProtClient::new()?;
     if next_page
@@ -507,20 +734,29 @@ pub async fn get_with_structure_limit(
 
     match domains_res {
         Ok(domains) => protein.domains = domains,
-        Err(err) => warn!("InterPro unavailable for protein domains: {err}"),
+        Err(err) => {
+            warn!("InterPro unavailable for protein domains: {err}");
+            section_errors::record(PROTEIN_SECTION_DOMAINS, "InterPro", &err);
+        }
     }
 
     match interactions_res {
         Ok(rows) => protein.interactions = rows,
-        Err(err) => warn!("STRING unavailable for protein interactions: {err}"),
+        Err(err) => {
+            warn!("STRING unavailable for protein interactions: {err}");
+            section_errors::record(PROTEIN_SECTION_INTERACTIONS, "STRING", &err);
+        }
     }
 
     match complexes_res {
         Ok(rows) => protein.complexes = rows,
-        Err(err) => warn!("ComplexPortal unavailable for protein complexes: {err}"),
+        Err(err) => {
+            warn!("ComplexPortal unavailable for protein complexes: {err}");
+            section_errors::record(PROTEIN_SECTION_COMPLEXES, "ComplexPortal", &err);
+        }
     }
 
-    Ok(protein)
+    transform::fields::apply("protein", protein)
 }
 
 fn map_complexportal_complex(row: ComplexPortalComplex) -> ProteinComplex {
@@ -595,6 +831,51 @@ mod tests {
         assert!(matches!(err, BioMcpError::InvalidArgument(_)));
     }
 
+    #[test]
+    fn parse_organism_accepts_taxon_ids_and_common_names() {
+        assert_eq!(parse_organism("9606").unwrap(), 9606);
+        assert_eq!(parse_organism(" Human ").unwrap(), 9606);
+        assert_eq!(parse_organism("Mus musculus").unwrap(), 10090);
+        assert!(parse_organism("0").is_err());
+        assert!(parse_organism("martian").is_err());
+    }
+
+    #[test]
+    fn scoped_query_uses_organism_instead_of_human_default() {
+        let filters = ProteinSearchFilters {
+            organism: Some(10090),
+            reviewed: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            scoped_query(&filters).unwrap(),
+            "organism_id:10090 AND reviewed:true"
+        );
+
+        let filters = ProteinSearchFilters {
+            query: "kinase".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            scoped_query(&filters).unwrap(),
+            "(kinase) AND organism_id:9606 AND reviewed:true"
+        );
+        assert!(scoped_query(&ProteinSearchFilters::default()).is_err());
+    }
+
+    #[test]
+    fn parse_tsv_columns_defaults_and_validates_field_names() {
+        assert_eq!(
+            parse_tsv_columns(&[]).unwrap(),
+            DEFAULT_TSV_COLUMNS.join(",")
+        );
+        assert_eq!(
+            parse_tsv_columns(&["accession".into(), " gene_primary ".into()]).unwrap(),
+            "accession,gene_primary"
+        );
+        assert!(parse_tsv_columns(&["accession;drop".into()]).is_err());
+    }
+
     #[test]
     fn uniprot_accession_validation_accepts_accessions_and_rejects_symbols() {
         assert!(is_uniprot_accession("P15056"));
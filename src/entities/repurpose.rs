@@ -0,0 +1,181 @@
+//! Expression-based drug repurposing through CLUE connectivity queries.
+//!
+//! An up/down gene signature (for example from a disease-vs-normal
+//! comparison) is submitted to the LINCS L1000 Touchstone reference. Compounds
+//! whose signatures anti-correlate with the query are candidates to reverse
+//! it. Perturbagens with a common name are mapped to `get drug` lookups; Broad
+//! `BRD-` compounds without one stay unmapped.
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::error::BioMcpError;
+use crate::sources::clue::{CLUE_MAX_GENES, CLUE_MIN_GENES, ClueClient, ClueConnectivityRow};
+
+pub(crate) const MAX_WAIT_SECS: u64 = 3600;
+pub(crate) const MAX_REPURPOSE_LIMIT: usize = 100;
+const COMPOUND_PERT_TYPE: &str = "trt_cp";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RepurposeResult {
+    pub job_id: String,
+    pub up_genes: usize,
+    pub down_genes: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unresolved_genes: Vec<String>,
+    /// Compounds scored by CLUE before ranking and truncation.
+    pub compounds_scored: usize,
+    pub candidates: Vec<RepurposeCandidate>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RepurposeCandidate {
+    pub rank: usize,
+    pub pert_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Name to pass to `get drug`, when the perturbagen has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moa: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<String>,
+    pub score: f64,
+}
+
+/// Splits a gene list file on lines, commas, tabs, and spaces; `#` starts a comment.
+pub fn parse_gene_list(content: &str) -> Vec<String> {
+    let mut genes: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or_default();
+        for token in line.split(|ch: char| ch == ',' || ch == ';' || ch.is_whitespace()) {
+            let token = token.trim().trim_matches('"');
+            if token.is_empty() || genes.iter().any(|gene| gene.eq_ignore_ascii_case(token)) {
+                continue;
+            }
+            genes.push(token.to_string());
+        }
+    }
+    genes
+}
+
+fn drug_name(row: &ClueConnectivityRow) -> Option<String> {
+    row.pert_iname
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty() && !name.to_ascii_uppercase().starts_with("BRD-"))
+        .map(str::to_string)
+}
+
+/// Keeps compounds, most negative (signature-reversing) scores first.
+fn rank_candidates(
+    rows: Vec<ClueConnectivityRow>,
+    limit: usize,
+) -> (usize, Vec<RepurposeCandidate>) {
+    let mut compounds = rows
+        .into_iter()
+        .filter(|row| {
+            row.pert_type
+                .as_deref()
+                .is_none_or(|kind| kind.eq_ignore_ascii_case(COMPOUND_PERT_TYPE))
+        })
+        .collect::<Vec<_>>();
+    compounds.sort_by(|a, b| {
+        a.score
+            .total_cmp(&b.score)
+            .then_with(|| a.pert_id.cmp(&b.pert_id))
+    });
+    let scored = compounds.len();
+
+    let candidates = compounds
+        .into_iter()
+        .filter(|row| row.score < 0.0)
+        .take(limit)
+        .enumerate()
+        .map(|(index, row)| RepurposeCandidate {
+            rank: index + 1,
+            drug: drug_name(&row),
+            targets: row
+                .target
+                .as_deref()
+                .map(|value| {
+                    value
+                        .split('|')
+                        .map(str::trim)
+                        .filter(|target| !target.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            name: row.pert_iname,
+            pert_id: row.pert_id,
+            moa: row.moa,
+            score: row.score,
+        })
+        .collect();
+    (scored, candidates)
+}
+
+async fn resolve_signature(
+    label: &str,
+    genes: &[String],
+    unresolved: &mut Vec<String>,
+) -> Result<Vec<String>, BioMcpError> {
+    if genes.is_empty() {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "The {label} gene list is empty"
+        )));
+    }
+    let (mut ids, missing) = crate::entities::gene::entrez_ids(genes).await?;
+    unresolved.extend(missing);
+    if ids.len() < CLUE_MIN_GENES {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Only {} {label}-regulated genes resolved to Entrez IDs; CLUE needs at least {CLUE_MIN_GENES}",
+            ids.len()
+        )));
+    }
+    // CLUE caps each direction; callers pass genes ranked by effect size.
+    ids.truncate(CLUE_MAX_GENES);
+    Ok(ids)
+}
+
+/// Runs a CLUE connectivity query and returns the top signature-reversing compounds.
+pub async fn repurpose(
+    up: &[String],
+    down: &[String],
+    limit: usize,
+    wait: Duration,
+) -> Result<RepurposeResult, BioMcpError> {
+    if limit == 0 || limit > MAX_REPURPOSE_LIMIT {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--limit must be between 1 and {MAX_REPURPOSE_LIMIT}"
+        )));
+    }
+    let client = ClueClient::new()?;
+    client.require_api_key()?;
+
+    let mut unresolved = Vec::new();
+    let up_ids = resolve_signature("up", up, &mut unresolved).await?;
+    let down_ids = resolve_signature("down", down, &mut unresolved).await?;
+
+    let job_id = client
+        .submit_query("biomcp_repurpose", &up_ids, &down_ids)
+        .await?;
+    let job = client.wait_for_job(&job_id, wait).await?;
+    let rows = client.connectivity(&job).await?;
+    let (compounds_scored, candidates) = rank_candidates(rows, limit);
+
+    Ok(RepurposeResult {
+        job_id,
+        up_genes: up_ids.len(),
+        down_genes: down_ids.len(),
+        unresolved_genes: unresolved,
+        compounds_scored,
+        candidates,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,54 @@
+//! Tests for CLUE-based repurposing candidate ranking.
+
+use super::*;
+
+fn row(pert_id: &str, name: &str, pert_type: &str, score: f64) -> ClueConnectivityRow {
+    ClueConnectivityRow {
+        pert_id: pert_id.to_string(),
+        pert_iname: Some(name.to_string()),
+        pert_type: Some(pert_type.to_string()),
+        moa: None,
+        target: Some("HDAC1|HDAC2".to_string()),
+        cell_id: None,
+        score,
+    }
+}
+
+#[test]
+fn parse_gene_list_splits_mixed_separators_and_drops_comments() {
+    let genes = parse_gene_list("# up genes\nTP53, MYC\tEGFR\n\"brca1\" # note\ntp53\n\n");
+    assert_eq!(genes, vec!["TP53", "MYC", "EGFR", "brca1"]);
+}
+
+#[test]
+fn rank_candidates_keeps_reversing_compounds_in_score_order() {
+    let (scored, candidates) = rank_candidates(
+        vec![
+            row("BRD-K1", "vorinostat", "trt_cp", -97.4),
+            row("BRD-A2", "BRD-A2", "trt_cp", -99.0),
+            row("CGS001-7157", "TP53", "trt_sh.cgs", -99.9),
+            row("BRD-K3", "sirolimus", "trt_cp", 42.0),
+            row("BRD-K4", "trichostatin-a", "trt_cp", -90.0),
+        ],
+        2,
+    );
+
+    assert_eq!(scored, 4);
+    assert_eq!(candidates.len(), 2);
+    assert_eq!(candidates[0].rank, 1);
+    assert_eq!(candidates[0].pert_id, "BRD-A2");
+    assert_eq!(candidates[0].drug, None);
+    assert_eq!(candidates[1].drug.as_deref(), Some("vorinostat"));
+    assert_eq!(candidates[1].targets, vec!["HDAC1", "HDAC2"]);
+}
+
+#[tokio::test]
+async fn repurpose_rejects_out_of_range_limit_before_lookups() {
+    let err = repurpose(&[], &[], 0, Duration::from_secs(1))
+        .await
+        .expect_err("zero limit");
+    assert!(
+        err.to_string()
+            .contains("--limit must be between 1 and 100")
+    );
+}
@@ -0,0 +1,208 @@
+//! Failed optional sections, reported in JSON output as `_meta.section_errors`.
+//!
+//! Enrichment failures are non-fatal: the section is left empty and a warning
+//! is logged. Enrichment code also calls [`record`] or [`record_timeout`] so a
+//! caller that ran the fetch under [`collect`] can tell "no data" apart from
+//! "fetch failed" and retry only the sections that failed.
+//!
+//! A fetch run under [`with_soft_deadline`] also clips each section's timeout
+//! with [`budget`], so sections still pending at the deadline are cut short and
+//! reported back as skipped instead of delaying the whole response.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::error::BioMcpError;
+
+tokio::task_local! {
+    static SECTION_ERRORS: Arc<Mutex<Vec<SectionError>>>;
+    static SOFT_DEADLINE: Arc<SoftDeadline>;
+}
+
+#[derive(Debug)]
+struct SoftDeadline {
+    at: Instant,
+    limit: Duration,
+    skipped: Mutex<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct SectionError {
+    /// Section name as accepted by `get <entity> <id> <section>`.
+    pub section: String,
+    /// Upstream source that failed.
+    pub source: String,
+    pub error_code: String,
+    pub message: String,
+}
+
+/// Runs `fut` and returns the section errors it recorded.
+pub(crate) async fn collect<F>(fut: F) -> (F::Output, Vec<SectionError>)
+where
+    F: Future,
+{
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let output = SECTION_ERRORS.scope(errors.clone(), fut).await;
+    let errors = errors
+        .lock()
+        .map(|errors| errors.clone())
+        .unwrap_or_default();
+    (output, errors)
+}
+
+/// [`collect`] for a fallible fetch, pairing the errors with its success value.
+pub(crate) async fn collect_ok<F, T, E>(fut: F) -> Result<(T, Vec<SectionError>), E>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    let (result, errors) = collect(fut).await;
+    result.map(|value| (value, errors))
+}
+
+fn push(entry: SectionError) {
+    let _ = SECTION_ERRORS.try_with(|errors| {
+        if let Ok(mut errors) = errors.lock()
+            && !errors.contains(&entry)
+        {
+            errors.push(entry);
+        }
+    });
+}
+
+/// Records that `source` failed while filling `section`.
+pub(crate) fn record(section: &str, source: &str, err: &BioMcpError) {
+    push(SectionError {
+        section: section.to_string(),
+        source: source.to_string(),
+        error_code: err.code().to_string(),
+        message: err.to_string(),
+    });
+}
+
+/// Runs `fut` with a soft deadline `limit` from now and returns the sections
+/// that were cut short by it, in the order they were skipped.
+pub(crate) async fn with_soft_deadline<F>(limit: Duration, fut: F) -> (F::Output, Vec<String>)
+where
+    F: Future,
+{
+    let deadline = Arc::new(SoftDeadline {
+        at: Instant::now() + limit,
+        limit,
+        skipped: Mutex::new(Vec::new()),
+    });
+    let output = SOFT_DEADLINE.scope(deadline.clone(), fut).await;
+    let skipped = deadline
+        .skipped
+        .lock()
+        .map(|skipped| skipped.clone())
+        .unwrap_or_default();
+    (output, skipped)
+}
+
+/// Clips a section timeout to the time left before the soft deadline, if any.
+pub(crate) fn budget(timeout: Duration) -> Duration {
+    SOFT_DEADLINE
+        .try_with(|deadline| timeout.min(deadline.at.saturating_duration_since(Instant::now())))
+        .unwrap_or(timeout)
+}
+
+/// Marks `section` as skipped when the soft deadline has passed.
+fn record_deadline(section: &str, source: &str) -> bool {
+    SOFT_DEADLINE
+        .try_with(|deadline| {
+            if Instant::now() < deadline.at {
+                return false;
+            }
+            if let Ok(mut skipped) = deadline.skipped.lock()
+                && !skipped.iter().any(|seen| seen == section)
+            {
+                skipped.push(section.to_string());
+            }
+            push(SectionError {
+                section: section.to_string(),
+                source: source.to_string(),
+                error_code: "deadline".to_string(),
+                message: format!(
+                    "{source} had not answered when the {}s soft deadline was reached",
+                    deadline.limit.as_secs()
+                ),
+            });
+            true
+        })
+        .unwrap_or(false)
+}
+
+/// Records that `source` did not answer within `timeout` while filling `section`.
+pub(crate) fn record_timeout(section: &str, source: &str, timeout: Duration) {
+    if record_deadline(section, source) {
+        return;
+    }
+    push(SectionError {
+        section: section.to_string(),
+        source: source.to_string(),
+        error_code: "timeout".to_string(),
+        message: format!("{source} did not respond within {}s", timeout.as_secs()),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn collect_returns_recorded_errors_once_each() {
+        let err = BioMcpError::Api {
+            api: "monarch".to_string(),
+            message: "HTTP 500".to_string(),
+        };
+        let (value, errors) = collect(async {
+            record("phenotypes", "Monarch", &err);
+            record("phenotypes", "Monarch", &err);
+            record_timeout("civic", "CIViC", Duration::from_secs(8));
+            7
+        })
+        .await;
+
+        assert_eq!(value, 7);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].section, "phenotypes");
+        assert_eq!(errors[0].error_code, "api_error");
+        assert!(errors[0].message.contains("HTTP 500"));
+        assert_eq!(errors[1].error_code, "timeout");
+        assert_eq!(errors[1].message, "CIViC did not respond within 8s");
+    }
+
+    #[tokio::test]
+    async fn soft_deadline_clips_budgets_and_reports_skipped_sections() {
+        assert_eq!(budget(Duration::from_secs(8)), Duration::from_secs(8));
+
+        let ((before, after, errors), skipped) =
+            with_soft_deadline(Duration::from_millis(20), async {
+                let before = budget(Duration::from_secs(8));
+                tokio::time::sleep(Duration::from_millis(30)).await;
+                let after = budget(Duration::from_secs(8));
+                let (_, errors) = collect(async {
+                    record_timeout("civic", "CIViC", Duration::from_secs(8));
+                    record_timeout("civic", "CIViC", Duration::from_secs(8));
+                })
+                .await;
+                (before, after, errors)
+            })
+            .await;
+
+        assert!(before <= Duration::from_millis(20));
+        assert_eq!(after, Duration::ZERO);
+        assert_eq!(skipped, vec!["civic".to_string()]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_code, "deadline");
+    }
+
+    #[test]
+    fn record_outside_collect_is_a_no_op() {
+        record_timeout("civic", "CIViC", Duration::from_secs(8));
+    }
+}
@@ -0,0 +1,666 @@
+//! Machine-readable manifest of the sections each `get` entity accepts.
+//!
+//! Section names come from the entity modules' `*_SECTION_NAMES` constants;
+//! this module adds what an agent needs to plan calls: a one-line
+//! description, the upstream sources consulted, a coarse latency class, and
+//! any API key the section needs. Markdown "More:" hints reuse the same
+//! descriptions.
+
+use serde::Serialize;
+
+use crate::error::BioMcpError;
+
+/// Entities with section-filtered `get` commands, in `biomcp list` order.
+pub(crate) const MANIFEST_ENTITIES: &[(&str, &[&str])] = &[
+    ("gene", crate::entities::gene::GENE_SECTION_NAMES),
+    ("variant", crate::entities::variant::VARIANT_SECTION_NAMES),
+    ("article", crate::entities::article::ARTICLE_SECTION_NAMES),
+    ("trial", crate::entities::trial::TRIAL_SECTION_NAMES),
+    ("drug", crate::entities::drug::DRUG_SECTION_NAMES),
+    ("disease", crate::entities::disease::DISEASE_SECTION_NAMES),
+    ("pgx", crate::entities::pgx::PGX_SECTION_NAMES),
+    ("hla", crate::entities::hla::HLA_SECTION_NAMES),
+    ("pathway", crate::entities::pathway::PATHWAY_SECTION_NAMES),
+    ("protein", crate::entities::protein::PROTEIN_SECTION_NAMES),
+    ("study", crate::entities::study::STUDY_SECTION_NAMES),
+    (
+        "adverse-event",
+        crate::entities::adverse_event::ADVERSE_EVENT_SECTION_NAMES,
+    ),
+];
+
+/// Rough cost of adding a section to a `get` call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LatencyClass {
+    /// Read from the base record; no extra request.
+    Fast,
+    /// One extra upstream request.
+    Moderate,
+    /// Several upstream requests, large payloads, or slow APIs.
+    Slow,
+}
+
+/// API key a section reads from the environment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SectionAuth {
+    None,
+    /// Works without the key; setting it raises rate limits.
+    Optional {
+        env: &'static str,
+    },
+    /// Skipped with a note unless the key is set.
+    Required {
+        env: &'static str,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct SectionInfo {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub sources: &'static [&'static str],
+    pub latency: LatencyClass,
+    pub auth: SectionAuth,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SectionManifest {
+    pub entity: &'static str,
+    /// Usage shape, e.g. `biomcp get gene <id> [section...]`.
+    pub usage: String,
+    pub sections: Vec<SectionInfo>,
+}
+
+struct SectionMeta {
+    description: &'static str,
+    sources: &'static [&'static str],
+    latency: LatencyClass,
+    auth: SectionAuth,
+}
+
+const fn meta(
+    description: &'static str,
+    sources: &'static [&'static str],
+    latency: LatencyClass,
+    auth: SectionAuth,
+) -> SectionMeta {
+    SectionMeta {
+        description,
+        sources,
+        latency,
+        auth,
+    }
+}
+
+fn section_meta(entity: &str, section: &str) -> Option<SectionMeta> {
+    use LatencyClass::{Fast, Moderate, Slow};
+    use SectionAuth::{None as NoKey, Optional, Required};
+
+    const NCBI_KEY: SectionAuth = Optional {
+        env: "NCBI_API_KEY",
+    };
+    const OPENFDA_KEY: SectionAuth = Optional {
+        env: "OPENFDA_API_KEY",
+    };
+    const DISGENET_KEY: SectionAuth = Required {
+        env: "DISGENET_API_KEY",
+    };
+
+    let row = match (entity, section) {
+        ("gene", "pathways") => meta(
+            "Reactome/KEGG pathway context",
+            &["Reactome", "KEGG", "WikiPathways"],
+            Slow,
+            NoKey,
+        ),
+        ("gene", "ontology") => meta(
+            "GO-style functional enrichment",
+            &["Enrichr"],
+            Moderate,
+            NoKey,
+        ),
+        ("gene", "diseases") => meta("disease associations", &["Enrichr"], Moderate, NoKey),
+        ("gene", "protein") => meta(
+            "UniProt function and localization detail",
+            &["UniProt"],
+            Moderate,
+            NoKey,
+        ),
+        ("gene", "go") => meta("QuickGO term annotations", &["QuickGO"], Moderate, NoKey),
+        ("gene", "interactions") => {
+            meta("STRING interaction partners", &["STRING"], Moderate, NoKey)
+        }
+        ("gene", "civic") => meta("CIViC clinical evidence", &["CIViC"], Moderate, NoKey),
+        ("gene", "expression") => meta("GTEx tissue expression", &["GTEx"], Moderate, NoKey),
+        ("gene", "hpa") => meta(
+            "Human Protein Atlas tissue expression and localization",
+            &["Human Protein Atlas"],
+            Moderate,
+            NoKey,
+        ),
+        ("gene", "druggability") => meta(
+            "DGIdb interactions and tractability",
+            &["DGIdb", "Open Targets"],
+            Slow,
+            NoKey,
+        ),
+        ("gene", "tractability") => meta(
+            "Open Targets tractability buckets and safety events",
+            &["Open Targets"],
+            Moderate,
+            NoKey,
+        ),
+        ("gene", "clingen") => meta(
+            "ClinGen validity and dosage sensitivity",
+            &["ClinGen"],
+            Moderate,
+            NoKey,
+        ),
+        ("gene", "constraint") => meta(
+            "gnomAD gene constraint metrics",
+            &["gnomAD"],
+            Moderate,
+            NoKey,
+        ),
+        ("gene", "disgenet") => meta(
+            "DisGeNET scored disease links",
+            &["DisGeNET"],
+            Moderate,
+            DISGENET_KEY,
+        ),
+        ("gene", "clinvar-summary") => meta(
+            "ClinVar significance and review-status counts",
+            &["MyVariant.info"],
+            Moderate,
+            NoKey,
+        ),
+        ("gene", "funding") => meta("NIH Reporter grant support", &["NIH RePORTER"], Slow, NoKey),
+        ("gene", "key-papers") => meta(
+            "most-cited seminal papers and recent reviews",
+            &["Europe PMC"],
+            Moderate,
+            NoKey,
+        ),
+        ("gene", "dependency") => meta(
+            "DepMap CRISPR gene effect and most dependent lineages",
+            &["DepMap"],
+            Moderate,
+            NoKey,
+        ),
+        ("gene", "orthologs") => meta(
+            "mouse, rat, and zebrafish orthologs with protein identity",
+            &["Ensembl Compara"],
+            Moderate,
+            NoKey,
+        ),
+        ("variant", "predict") => meta(
+            "AlphaGenome regulatory effect predictions",
+            &["AlphaGenome"],
+            Slow,
+            Required {
+                env: "ALPHAGENOME_API_KEY",
+            },
+        ),
+        ("variant", "predictions") => meta(
+            "dbNSFP in-silico pathogenicity scores",
+            &["MyVariant.info"],
+            Fast,
+            NoKey,
+        ),
+        ("variant", "clinvar") => meta(
+            "ClinVar significance, review status, and conditions",
+            &["MyVariant.info"],
+            Fast,
+            NoKey,
+        ),
+        ("variant", "clinvar-submissions") => meta(
+            "individual ClinVar submitter assertions",
+            &["ClinVar E-utilities"],
+            Moderate,
+            NCBI_KEY,
+        ),
+        ("variant", "population") => meta(
+            "gnomAD allele frequencies by population",
+            &["MyVariant.info"],
+            Fast,
+            NoKey,
+        ),
+        ("variant", "conservation") => meta(
+            "phyloP and phastCons conservation scores",
+            &["MyVariant.info"],
+            Fast,
+            NoKey,
+        ),
+        ("variant", "cosmic") => meta(
+            "COSMIC somatic mutation context",
+            &["MyVariant.info"],
+            Fast,
+            NoKey,
+        ),
+        ("variant", "cgi") => meta(
+            "Cancer Genome Interpreter drug associations",
+            &["MyVariant.info"],
+            Fast,
+            NoKey,
+        ),
+        ("variant", "civic") => meta(
+            "CIViC clinical evidence and assertions",
+            &["CIViC"],
+            Moderate,
+            NoKey,
+        ),
+        ("variant", "cbioportal") => meta(
+            "cancer-type mutation frequencies",
+            &["cBioPortal"],
+            Slow,
+            NoKey,
+        ),
+        ("variant", "gwas") => meta(
+            "GWAS trait associations",
+            &["GWAS Catalog"],
+            Moderate,
+            NoKey,
+        ),
+        ("variant", "transcripts") => meta(
+            "per-transcript consequences",
+            &["MyVariant.info"],
+            Fast,
+            NoKey,
+        ),
+        ("variant", "litvar") => meta("LitVar publication links", &["LitVar2"], Moderate, NoKey),
+        ("article", "annotations") => meta(
+            "PubTator normalized entity mentions",
+            &["PubTator3"],
+            Moderate,
+            NCBI_KEY,
+        ),
+        ("article", "fulltext") => meta(
+            "cached full text when available",
+            &["PMC Open Access", "NCBI ID Converter", "Unpaywall"],
+            Slow,
+            NCBI_KEY,
+        ),
+        ("article", "tldr") => meta(
+            "Semantic Scholar summary and influence",
+            &["Semantic Scholar"],
+            Moderate,
+            Optional { env: "S2_API_KEY" },
+        ),
+        ("article", "citations") => meta(
+            "top citing and referenced articles",
+            &["Europe PMC"],
+            Moderate,
+            NoKey,
+        ),
+        ("trial", "eligibility") => meta(
+            "inclusion and exclusion criteria",
+            &["ClinicalTrials.gov"],
+            Fast,
+            NoKey,
+        ),
+        ("trial", "locations") => meta(
+            "site list and contact details",
+            &["ClinicalTrials.gov"],
+            Fast,
+            NoKey,
+        ),
+        ("trial", "outcomes") => meta(
+            "endpoint measures and time frames",
+            &["ClinicalTrials.gov"],
+            Fast,
+            NoKey,
+        ),
+        ("trial", "arms") => meta(
+            "study arms and assigned interventions",
+            &["ClinicalTrials.gov"],
+            Fast,
+            NoKey,
+        ),
+        ("trial", "references") => meta(
+            "linked publications and PMID citations",
+            &["ClinicalTrials.gov"],
+            Fast,
+            NoKey,
+        ),
+        ("trial", "publications") => meta(
+            "results and protocol papers via registry links and Europe PMC",
+            &["ClinicalTrials.gov", "Europe PMC"],
+            Slow,
+            NoKey,
+        ),
+        ("trial", "results") => meta(
+            "posted baseline, outcome effect estimates, and adverse-event tables",
+            &["ClinicalTrials.gov"],
+            Slow,
+            NoKey,
+        ),
+        ("drug", "label") => meta(
+            "approved-indication and FDA label detail beyond the base card",
+            &["OpenFDA"],
+            Moderate,
+            OPENFDA_KEY,
+        ),
+        ("drug", "regulatory") => meta(
+            "approval and supplement history; use only if the base card lacks approval context",
+            &["Drugs@FDA", "EMA", "WHO Prequalification"],
+            Slow,
+            OPENFDA_KEY,
+        ),
+        ("drug", "safety") => meta(
+            "regulatory safety detail; use `biomcp drug adverse-events <name>` first when you want post-marketing signal",
+            &["OpenFDA", "EMA"],
+            Slow,
+            OPENFDA_KEY,
+        ),
+        ("drug", "shortage") => meta(
+            "current shortage status and open trials that use the drug",
+            &["OpenFDA", "ClinicalTrials.gov"],
+            Slow,
+            OPENFDA_KEY,
+        ),
+        ("drug", "targets") => meta(
+            "ChEMBL and OpenTargets targets",
+            &["ChEMBL", "Open Targets", "CIViC"],
+            Slow,
+            NoKey,
+        ),
+        ("drug", "indications") => meta(
+            "OpenTargets indication evidence",
+            &["Open Targets"],
+            Moderate,
+            NoKey,
+        ),
+        ("drug", "interactions") => meta(
+            "label interactions and public-data fallback",
+            &["OpenFDA", "MedlinePlus"],
+            Moderate,
+            OPENFDA_KEY,
+        ),
+        ("drug", "civic") => meta("CIViC therapy evidence", &["CIViC"], Moderate, NoKey),
+        ("drug", "approvals") => meta(
+            "Drugs@FDA approval history",
+            &["Drugs@FDA"],
+            Moderate,
+            OPENFDA_KEY,
+        ),
+        ("drug", "patents") => meta(
+            "PatentsView patent families and estimated expiry",
+            &["PatentsView"],
+            Moderate,
+            Required {
+                env: "PATENTSVIEW_API_KEY",
+            },
+        ),
+        ("disease", "genes") => meta(
+            "associated genes",
+            &["Monarch", "CIViC", "Open Targets"],
+            Slow,
+            NoKey,
+        ),
+        ("disease", "pathways") => {
+            meta("pathways from associated genes", &["Reactome"], Slow, NoKey)
+        }
+        ("disease", "phenotypes") => meta(
+            "HPO phenotype annotations",
+            &["Monarch", "HPO"],
+            Moderate,
+            NoKey,
+        ),
+        ("disease", "variants") => meta("disease-associated variants", &["CIViC"], Moderate, NoKey),
+        ("disease", "models") => meta("model-organism evidence", &["Monarch"], Moderate, NoKey),
+        ("disease", "prevalence") => meta(
+            "prevalence and epidemiology context",
+            &["Open Targets"],
+            Moderate,
+            NoKey,
+        ),
+        ("disease", "survival") => meta(
+            "SEER relative survival by stage",
+            &["SEER Explorer"],
+            Moderate,
+            NoKey,
+        ),
+        ("disease", "funding") => {
+            meta("NIH Reporter grant support", &["NIH RePORTER"], Slow, NoKey)
+        }
+        ("disease", "civic") => meta(
+            "CIViC disease-context evidence",
+            &["CIViC"],
+            Moderate,
+            NoKey,
+        ),
+        ("disease", "disgenet") => meta(
+            "DisGeNET scored disease-gene links",
+            &["DisGeNET"],
+            Moderate,
+            DISGENET_KEY,
+        ),
+        ("disease", "epidemiology") => meta(
+            "WHO GHO incidence and prevalence by region",
+            &["WHO GHO"],
+            Moderate,
+            NoKey,
+        ),
+        ("disease", "therapies") => meta(
+            "approved and late-stage drugs by derived line of therapy",
+            &["Open Targets", "Drugs@FDA"],
+            Slow,
+            NoKey,
+        ),
+        ("pgx", "recommendations") => meta(
+            "CPIC dosing recommendations by phenotype",
+            &["CPIC"],
+            Moderate,
+            NoKey,
+        ),
+        ("pgx", "frequencies") => meta(
+            "CPIC allele and phenotype frequencies by population",
+            &["CPIC"],
+            Moderate,
+            NoKey,
+        ),
+        ("pgx", "guidelines") => meta("CPIC guideline links", &["CPIC"], Moderate, NoKey),
+        ("pgx", "annotations") => meta(
+            "PharmGKB clinical annotations",
+            &["PharmGKB"],
+            Moderate,
+            NoKey,
+        ),
+        ("hla", "frequencies") => meta(
+            "CPIC population frequencies from Allele Frequency Net",
+            &["CPIC"],
+            Moderate,
+            NoKey,
+        ),
+        ("hla", "drugs") => meta(
+            "CPIC drug hypersensitivity recommendations",
+            &["CPIC"],
+            Moderate,
+            NoKey,
+        ),
+        ("hla", "diseases") => meta(
+            "curated disease risk associations",
+            &["BioMCP curated"],
+            Fast,
+            NoKey,
+        ),
+        ("pathway", "genes") => meta(
+            "member genes",
+            &["Reactome", "KEGG", "WikiPathways"],
+            Moderate,
+            NoKey,
+        ),
+        ("pathway", "events") => meta(
+            "contained reactions and subpathways (Reactome only)",
+            &["Reactome"],
+            Moderate,
+            NoKey,
+        ),
+        ("pathway", "enrichment") => meta(
+            "related Reactome pathways enriched for the member genes",
+            &["g:Profiler"],
+            Slow,
+            NoKey,
+        ),
+        ("protein", "domains") => meta("InterPro domain layout", &["InterPro"], Moderate, NoKey),
+        ("protein", "interactions") => {
+            meta("STRING interaction partners", &["STRING"], Moderate, NoKey)
+        }
+        ("protein", "complexes") => meta(
+            "curated macromolecular complexes",
+            &["ComplexPortal"],
+            Moderate,
+            NoKey,
+        ),
+        ("protein", "structures") => meta(
+            "PDB and AlphaFold structure references",
+            &["UniProt"],
+            Fast,
+            NoKey,
+        ),
+        ("study", "genes") => meta(
+            "most frequently mutated genes in the study",
+            &["cBioPortal"],
+            Moderate,
+            NoKey,
+        ),
+        ("adverse-event", "reactions") => meta(
+            "reported reactions with MedDRA terms",
+            &["OpenFDA"],
+            Fast,
+            OPENFDA_KEY,
+        ),
+        ("adverse-event", "outcomes") => meta(
+            "seriousness flags and patient outcomes",
+            &["OpenFDA"],
+            Fast,
+            OPENFDA_KEY,
+        ),
+        ("adverse-event", "concomitant") => meta(
+            "other drugs the patient was taking",
+            &["OpenFDA"],
+            Fast,
+            OPENFDA_KEY,
+        ),
+        ("adverse-event", "guidance") => meta(
+            "how to read a single spontaneous report",
+            &["OpenFDA"],
+            Fast,
+            OPENFDA_KEY,
+        ),
+        _ => return Option::None,
+    };
+    Some(row)
+}
+
+/// One-line description of `section` for `entity`, if the manifest knows it.
+pub(crate) fn section_description(entity: &str, section: &str) -> Option<&'static str> {
+    section_meta(entity, section).map(|meta| meta.description)
+}
+
+fn manifest_for(entity: &'static str, names: &[&'static str]) -> SectionManifest {
+    let sections = names
+        .iter()
+        .copied()
+        .filter(|name| *name != "all")
+        .filter_map(|name| {
+            section_meta(entity, name).map(|meta| SectionInfo {
+                name,
+                description: meta.description,
+                sources: meta.sources,
+                latency: meta.latency,
+                auth: meta.auth,
+            })
+        })
+        .collect();
+    SectionManifest {
+        entity,
+        usage: format!("biomcp get {entity} <id> [section...]"),
+        sections,
+    }
+}
+
+/// Section manifest for one entity; accepts `adverse_event` for `adverse-event`.
+pub fn manifest(entity: &str) -> Result<SectionManifest, BioMcpError> {
+    let key = entity.trim().to_ascii_lowercase().replace('_', "-");
+    MANIFEST_ENTITIES
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(name, names)| manifest_for(*name, names))
+        .ok_or_else(|| {
+            let known = MANIFEST_ENTITIES
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ");
+            BioMcpError::InvalidArgument(format!(
+                "Unknown entity \"{}\" for sections. Use one of: {known}",
+                entity.trim()
+            ))
+        })
+}
+
+/// Section manifests for every entity with a section-filtered `get`.
+pub fn all_manifests() -> Vec<SectionManifest> {
+    MANIFEST_ENTITIES
+        .iter()
+        .map(|(name, names)| manifest_for(*name, names))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_section_name_has_manifest_metadata() {
+        for (entity, names) in MANIFEST_ENTITIES {
+            for name in names.iter().filter(|name| **name != "all") {
+                let meta = section_meta(entity, name)
+                    .unwrap_or_else(|| panic!("missing manifest entry for {entity} {name}"));
+                assert!(!meta.sources.is_empty(), "{entity} {name} has no sources");
+            }
+        }
+    }
+
+    #[test]
+    fn manifest_serializes_sources_latency_and_auth() {
+        let manifest = manifest("Gene").expect("gene manifest");
+        assert_eq!(manifest.usage, "biomcp get gene <id> [section...]");
+        assert!(
+            !manifest
+                .sections
+                .iter()
+                .any(|section| section.name == "all")
+        );
+
+        let value = serde_json::to_value(&manifest).expect("json");
+        let disgenet = value["sections"]
+            .as_array()
+            .and_then(|rows| rows.iter().find(|row| row["name"] == "disgenet"))
+            .expect("disgenet row");
+        assert_eq!(disgenet["sources"][0], "DisGeNET");
+        assert_eq!(disgenet["latency"], "moderate");
+        assert_eq!(disgenet["auth"]["kind"], "required");
+        assert_eq!(disgenet["auth"]["env"], "DISGENET_API_KEY");
+
+        let hpa = value["sections"]
+            .as_array()
+            .and_then(|rows| rows.iter().find(|row| row["name"] == "hpa"))
+            .expect("hpa row");
+        assert_eq!(hpa["auth"]["kind"], "none");
+    }
+
+    #[test]
+    fn manifest_accepts_underscore_alias_and_rejects_unknown_entities() {
+        assert_eq!(
+            manifest("adverse_event").expect("alias").entity,
+            "adverse-event"
+        );
+        let err = manifest("transcript").expect_err("no sections");
+        assert!(err.to_string().contains("Use one of: gene, variant"));
+        assert_eq!(all_manifests().len(), MANIFEST_ENTITIES.len());
+    }
+}
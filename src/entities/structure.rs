@@ -0,0 +1,302 @@
+//! Experimental structure cards from the Protein Data Bank.
+//!
+//! Entries are read from RCSB PDB: experimental method, resolution, release
+//! dates, the primary citation, polymer chains with their UniProt mappings,
+//! and bound ligands. `search structure --uniprot` lists the entries that
+//! contain a chain mapped to one UniProt accession, best resolution first.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::SearchPage;
+use crate::error::BioMcpError;
+use crate::sources::rcsb::{RcsbClient, RcsbEntry, RcsbNonpolymerEntity, RcsbPolymerEntity};
+use crate::utils::text::non_empty;
+
+const MAX_STRUCTURE_SEARCH_LIMIT: usize = 50;
+/// Solvent is deposited as a nonpolymer entity but is not a ligand.
+const SOLVENT_COMPONENTS: &[&str] = &["HOH", "DOD"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Structure {
+    /// Upper-case PDB ID, e.g. `6VSB`.
+    pub pdb_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// Best reported resolution in Å; absent for NMR and similar methods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution_angstrom: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub molecular_weight_kda: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deposited: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub released: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub citation: Option<StructureCitation>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chains: Vec<StructureChain>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ligands: Vec<StructureLigand>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructureCitation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub journal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pmid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+}
+
+/// One polymer entity and the chains that carry it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructureChain {
+    pub entity_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub polymer_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chain_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organism: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub uniprot_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructureLigand {
+    /// PDB chemical component ID, e.g. `NAG`.
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formula: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chain_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructureSearchResult {
+    pub pdb_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution_angstrom: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub released: Option<String>,
+}
+
+fn pdb_id_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    // Classic four-character IDs plus the extended `PDB_0000xxxx` form.
+    RE.get_or_init(|| Regex::new(r"^(?:[1-9][A-Z0-9]{3}|PDB_[0-9A-Z]{8})$").expect("valid regex"))
+}
+
+fn normalize_pdb_id(raw: &str) -> Result<String, BioMcpError> {
+    let id = raw.trim().to_ascii_uppercase();
+    if id.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "PDB ID is required. Example: biomcp get structure 6VSB".into(),
+        ));
+    }
+    if !pdb_id_re().is_match(&id) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Invalid PDB ID \"{}\". Use a four-character PDB ID such as 6VSB, or find entries with biomcp search structure --uniprot <accession>.",
+            raw.trim()
+        )));
+    }
+    Ok(id)
+}
+
+/// `2020-02-26T00:00:00Z` -> `2020-02-26`.
+fn date_only(value: Option<&str>) -> Option<String> {
+    non_empty(value).map(|v| v.split('T').next().unwrap_or_default().to_string())
+}
+
+fn method(entry: &RcsbEntry) -> Option<String> {
+    let methods = entry
+        .exptl
+        .iter()
+        .flatten()
+        .filter_map(|row| non_empty(row.method.as_deref()))
+        .collect::<Vec<_>>();
+    (!methods.is_empty()).then(|| methods.join(", "))
+}
+
+fn resolution(entry: &RcsbEntry) -> Option<f64> {
+    entry
+        .rcsb_entry_info
+        .as_ref()?
+        .resolution_combined
+        .iter()
+        .flatten()
+        .flatten()
+        .copied()
+        .reduce(f64::min)
+}
+
+fn map_chain(row: &RcsbPolymerEntity) -> StructureChain {
+    let ids = row.rcsb_polymer_entity_container_identifiers.as_ref();
+    let organism = row
+        .rcsb_entity_source_organism
+        .iter()
+        .flatten()
+        .filter_map(|source| non_empty(source.ncbi_scientific_name.as_deref()))
+        .next();
+    StructureChain {
+        entity_id: ids
+            .and_then(|ids| non_empty(ids.entity_id.as_deref()))
+            .unwrap_or_default(),
+        description: row
+            .rcsb_polymer_entity
+            .as_ref()
+            .and_then(|entity| non_empty(entity.pdbx_description.as_deref())),
+        polymer_type: row
+            .entity_poly
+            .as_ref()
+            .and_then(|poly| non_empty(poly.rcsb_entity_polymer_type.as_deref())),
+        chain_ids: ids
+            .and_then(|ids| ids.auth_asym_ids.clone())
+            .unwrap_or_default(),
+        length: row
+            .entity_poly
+            .as_ref()
+            .and_then(|poly| poly.rcsb_sample_sequence_length),
+        organism,
+        uniprot_ids: ids
+            .and_then(|ids| ids.uniprot_ids.clone())
+            .unwrap_or_default(),
+    }
+}
+
+fn map_ligand(row: &RcsbNonpolymerEntity) -> Option<StructureLigand> {
+    let comp = row.nonpolymer_comp.as_ref()?.chem_comp.as_ref()?;
+    let id = non_empty(comp.id.as_deref())?;
+    if SOLVENT_COMPONENTS.contains(&id.as_str()) {
+        return None;
+    }
+    Some(StructureLigand {
+        id,
+        name: non_empty(comp.name.as_deref()),
+        formula: non_empty(comp.formula.as_deref()),
+        chain_ids: row
+            .rcsb_nonpolymer_entity_container_identifiers
+            .as_ref()
+            .and_then(|ids| ids.auth_asym_ids.clone())
+            .unwrap_or_default(),
+    })
+}
+
+fn map_structure(entry: &RcsbEntry) -> Structure {
+    let citation = entry
+        .rcsb_primary_citation
+        .as_ref()
+        .map(|row| StructureCitation {
+            title: non_empty(row.title.as_deref()),
+            journal: non_empty(row.rcsb_journal_abbrev.as_deref()),
+            year: row.year,
+            pmid: row.pubmed_id.map(|id| id.to_string()),
+            doi: non_empty(row.doi.as_deref()),
+        });
+    let accession = entry.rcsb_accession_info.as_ref();
+    Structure {
+        pdb_id: entry.rcsb_id.trim().to_ascii_uppercase(),
+        title: entry
+            .structure
+            .as_ref()
+            .and_then(|row| non_empty(row.title.as_deref())),
+        method: method(entry),
+        resolution_angstrom: resolution(entry),
+        molecular_weight_kda: entry
+            .rcsb_entry_info
+            .as_ref()
+            .and_then(|info| info.molecular_weight),
+        deposited: accession.and_then(|info| date_only(info.deposit_date.as_deref())),
+        released: accession.and_then(|info| date_only(info.initial_release_date.as_deref())),
+        citation,
+        chains: entry
+            .polymer_entities
+            .iter()
+            .flatten()
+            .map(map_chain)
+            .collect(),
+        ligands: entry
+            .nonpolymer_entities
+            .iter()
+            .flatten()
+            .filter_map(map_ligand)
+            .collect(),
+    }
+}
+
+fn map_search_result(entry: &RcsbEntry) -> StructureSearchResult {
+    StructureSearchResult {
+        pdb_id: entry.rcsb_id.trim().to_ascii_uppercase(),
+        title: entry
+            .structure
+            .as_ref()
+            .and_then(|row| non_empty(row.title.as_deref())),
+        method: method(entry),
+        resolution_angstrom: resolution(entry),
+        released: entry
+            .rcsb_accession_info
+            .as_ref()
+            .and_then(|info| date_only(info.initial_release_date.as_deref())),
+    }
+}
+
+pub async fn get(pdb_id: &str) -> Result<Structure, BioMcpError> {
+    let pdb_id = normalize_pdb_id(pdb_id)?;
+    let Some(entry) = RcsbClient::new()?.entry(&pdb_id).await? else {
+        return Err(BioMcpError::NotFound {
+            entity: "structure".into(),
+            id: pdb_id,
+            suggestion:
+                "Find PDB entries for a protein with: biomcp search structure --uniprot <accession>"
+                    .into(),
+        });
+    };
+    crate::transform::fields::apply("structure", map_structure(&entry))
+}
+
+pub async fn search_by_uniprot(
+    accession: &str,
+    limit: usize,
+    offset: usize,
+) -> Result<SearchPage<StructureSearchResult>, BioMcpError> {
+    if limit == 0 || limit > MAX_STRUCTURE_SEARCH_LIMIT {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--limit must be between 1 and {MAX_STRUCTURE_SEARCH_LIMIT}"
+        )));
+    }
+    let accession = accession.trim().to_ascii_uppercase();
+    if !crate::entities::protein::is_uniprot_accession(&accession) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Invalid UniProt accession \"{accession}\". Example: biomcp search structure --uniprot P15056"
+        )));
+    }
+
+    let client = RcsbClient::new()?;
+    let page = client.search_by_uniprot(&accession, limit, offset).await?;
+    let entries = client.entry_summaries(&page.ids).await?;
+    let results = entries.iter().map(map_search_result).collect();
+    Ok(SearchPage::offset(results, Some(page.total)))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,122 @@
+//! Tests for PDB ID validation and RCSB entry mapping.
+
+use super::*;
+
+fn entry(value: serde_json::Value) -> RcsbEntry {
+    serde_json::from_value(value).expect("rcsb entry json")
+}
+
+#[test]
+fn normalize_pdb_id_accepts_classic_and_extended_ids() {
+    assert_eq!(normalize_pdb_id(" 6vsb ").expect("classic"), "6VSB");
+    assert_eq!(
+        normalize_pdb_id("pdb_00001abc").expect("extended"),
+        "PDB_00001ABC"
+    );
+
+    for raw in ["BRAF", "6VS", "0ABC", "P15056"] {
+        let err = normalize_pdb_id(raw).expect_err(raw);
+        assert!(err.to_string().contains("Invalid PDB ID"), "{raw}");
+    }
+    let err = normalize_pdb_id("  ").expect_err("empty");
+    assert!(err.to_string().contains("PDB ID is required"));
+}
+
+#[test]
+fn map_structure_reports_best_resolution_chains_and_ligands() {
+    let structure = map_structure(&entry(serde_json::json!({
+        "rcsb_id": "6vsb",
+        "struct": {"title": "Prefusion 2019-nCoV spike glycoprotein"},
+        "exptl": [{"method": "ELECTRON MICROSCOPY"}],
+        "rcsb_entry_info": {"resolution_combined": [3.46, 3.2], "molecular_weight": 438.53},
+        "rcsb_accession_info": {
+            "deposit_date": "2020-02-10T00:00:00Z",
+            "initial_release_date": "2020-02-26T00:00:00Z"
+        },
+        "rcsb_primary_citation": {
+            "title": "Cryo-EM structure of the 2019-nCoV spike in the prefusion conformation.",
+            "rcsb_journal_abbrev": "Science",
+            "year": 2020,
+            "pdbx_database_id_PubMed": 32075877,
+            "pdbx_database_id_DOI": "10.1126/science.abb2507"
+        },
+        "polymer_entities": [{
+            "rcsb_polymer_entity": {"pdbx_description": "Spike glycoprotein"},
+            "entity_poly": {"rcsb_entity_polymer_type": "Protein", "rcsb_sample_sequence_length": 1288},
+            "rcsb_polymer_entity_container_identifiers": {
+                "entity_id": "1",
+                "auth_asym_ids": ["A", "B", "C"],
+                "uniprot_ids": ["P0DTC2"]
+            },
+            "rcsb_entity_source_organism": [
+                {"ncbi_scientific_name": "Severe acute respiratory syndrome coronavirus 2"}
+            ]
+        }],
+        "nonpolymer_entities": [
+            {
+                "nonpolymer_comp": {"chem_comp": {
+                    "id": "NAG",
+                    "name": "2-acetamido-2-deoxy-beta-D-glucopyranose",
+                    "formula": "C8 H15 N O6"
+                }},
+                "rcsb_nonpolymer_entity_container_identifiers": {"entity_id": "2", "auth_asym_ids": ["D", "E"]}
+            },
+            {
+                "nonpolymer_comp": {"chem_comp": {"id": "HOH", "name": "water"}},
+                "rcsb_nonpolymer_entity_container_identifiers": {"entity_id": "3"}
+            }
+        ]
+    })));
+
+    assert_eq!(structure.pdb_id, "6VSB");
+    assert_eq!(structure.method.as_deref(), Some("ELECTRON MICROSCOPY"));
+    assert_eq!(structure.resolution_angstrom, Some(3.2));
+    assert_eq!(structure.deposited.as_deref(), Some("2020-02-10"));
+    assert_eq!(structure.released.as_deref(), Some("2020-02-26"));
+    let citation = structure.citation.as_ref().expect("citation");
+    assert_eq!(citation.pmid.as_deref(), Some("32075877"));
+    assert_eq!(citation.journal.as_deref(), Some("Science"));
+
+    assert_eq!(structure.chains.len(), 1);
+    let chain = &structure.chains[0];
+    assert_eq!(chain.entity_id, "1");
+    assert_eq!(chain.chain_ids, vec!["A", "B", "C"]);
+    assert_eq!(chain.length, Some(1288));
+    assert_eq!(chain.uniprot_ids, vec!["P0DTC2"]);
+
+    let ligands = structure
+        .ligands
+        .iter()
+        .map(|row| row.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ligands, vec!["NAG"]);
+    assert_eq!(structure.ligands[0].chain_ids, vec!["D", "E"]);
+}
+
+#[test]
+fn map_search_result_tolerates_missing_resolution() {
+    let result = map_search_result(&entry(serde_json::json!({
+        "rcsb_id": "2L9S",
+        "exptl": [{"method": "SOLUTION NMR"}],
+        "rcsb_entry_info": {"resolution_combined": null},
+        "rcsb_accession_info": {"initial_release_date": "2011-05-18T00:00:00Z"}
+    })));
+    assert_eq!(result.pdb_id, "2L9S");
+    assert_eq!(result.method.as_deref(), Some("SOLUTION NMR"));
+    assert_eq!(result.resolution_angstrom, None);
+    assert_eq!(result.released.as_deref(), Some("2011-05-18"));
+    assert_eq!(result.title, None);
+}
+
+#[tokio::test]
+async fn search_by_uniprot_validates_inputs_before_requests() {
+    let err = search_by_uniprot("P15056", 0, 0)
+        .await
+        .expect_err("zero limit");
+    assert!(err.to_string().contains("--limit must be between 1 and 50"));
+
+    let err = search_by_uniprot("BRAF", 10, 0)
+        .await
+        .expect_err("gene symbol");
+    assert!(err.to_string().contains("Invalid UniProt accession"));
+}
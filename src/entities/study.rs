@@ -5,6 +5,13 @@ use std::path::Path;
 
 use crate::error::BioMcpError;
 
+mod portal;
+
+pub use self::portal::{
+    STUDY_SECTION_NAMES, Study, StudyMutatedGene, StudySearchFilters, StudySearchResult, get,
+    search_page, search_query_summary,
+};
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct StudyInfo {
     pub study_id: String,
@@ -0,0 +1,271 @@
+//! Public cBioPortal cancer studies, queried through the cBioPortal REST API.
+//!
+//! Unlike the rest of this module, nothing here needs a local download: study
+//! metadata and mutated-gene frequencies come straight from cbioportal.org.
+
+use serde::{Deserialize, Serialize};
+
+use crate::entities::SearchPage;
+use crate::error::BioMcpError;
+use crate::sources::cbioportal::CBioPortalClient;
+
+const STUDY_SECTION_GENES: &str = "genes";
+const STUDY_SECTION_ALL: &str = "all";
+
+pub const STUDY_SECTION_NAMES: &[&str] = &[STUDY_SECTION_GENES, STUDY_SECTION_ALL];
+
+const MAX_SEARCH_LIMIT: usize = 50;
+const MAX_QUERY_LEN: usize = 256;
+const STUDY_MUTATED_GENE_LIMIT: usize = 25;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudySearchResult {
+    pub study_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancer_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pmid: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Study {
+    pub study_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancer_type: Option<String>,
+    /// OncoTree code, e.g. `luad`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancer_type_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequenced_sample_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cna_sample_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub citation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pmid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_genome: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mutated_genes: Vec<StudyMutatedGene>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mutated_genes_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyMutatedGene {
+    pub gene: String,
+    pub mutated_samples: u32,
+    /// Mutation events across all samples; a sample can carry several.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mutation_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profiled_samples: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StudySearchFilters {
+    pub query: Option<String>,
+    /// OncoTree code (`brca`) or a fragment of the cancer type name (`breast`).
+    pub cancer_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct StudySections {
+    include_genes: bool,
+}
+
+fn parse_sections(sections: &[String]) -> Result<StudySections, BioMcpError> {
+    let mut out = StudySections::default();
+    for raw in sections {
+        let section = raw.trim().to_ascii_lowercase();
+        if section.is_empty() || section == "--json" || section == "-j" {
+            continue;
+        }
+        match section.as_str() {
+            STUDY_SECTION_GENES | STUDY_SECTION_ALL => out.include_genes = true,
+            _ => {
+                return Err(BioMcpError::InvalidArgument(format!(
+                    "Unknown section \"{section}\" for study. Available: {}",
+                    STUDY_SECTION_NAMES.join(", ")
+                )));
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn validate_study_id(study_id: &str) -> Result<&str, BioMcpError> {
+    let study_id = study_id.trim();
+    if study_id.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "Study ID is required. Example: biomcp get study luad_tcga_pan_can_atlas_2018".into(),
+        ));
+    }
+    if !study_id
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.'))
+    {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Invalid study ID '{study_id}'. Expected a cBioPortal identifier such as 'msk_impact_2017'."
+        )));
+    }
+    Ok(study_id)
+}
+
+fn validate_filters(filters: &StudySearchFilters, limit: usize) -> Result<(), BioMcpError> {
+    let query_len = filters.query.as_deref().map(str::trim).map_or(0, str::len);
+    let cancer_type_len = filters
+        .cancer_type
+        .as_deref()
+        .map(str::trim)
+        .map_or(0, str::len);
+    if query_len == 0 && cancer_type_len == 0 {
+        return Err(BioMcpError::InvalidArgument(
+            "Provide a query or --cancer-type. Example: biomcp search study \"lung adenocarcinoma\""
+                .into(),
+        ));
+    }
+    if query_len > MAX_QUERY_LEN || cancer_type_len > MAX_QUERY_LEN {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Study search terms must be at most {MAX_QUERY_LEN} characters"
+        )));
+    }
+    if limit == 0 || limit > MAX_SEARCH_LIMIT {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--limit must be between 1 and {MAX_SEARCH_LIMIT}"
+        )));
+    }
+    Ok(())
+}
+
+fn matches_cancer_type(row: &crate::sources::cbioportal::CBioCancerStudy, wanted: &str) -> bool {
+    let wanted = wanted.trim().to_ascii_lowercase();
+    if wanted.is_empty() {
+        return true;
+    }
+    row.cancer_type_id
+        .as_deref()
+        .is_some_and(|id| id.eq_ignore_ascii_case(&wanted))
+        || row
+            .cancer_type
+            .as_ref()
+            .and_then(|cancer_type| cancer_type.name.as_deref())
+            .is_some_and(|name| name.to_ascii_lowercase().contains(&wanted))
+}
+
+pub fn search_query_summary(filters: &StudySearchFilters) -> String {
+    let mut parts = Vec::new();
+    if let Some(query) = filters
+        .query
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        parts.push(query.to_string());
+    }
+    if let Some(cancer_type) = filters
+        .cancer_type
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        parts.push(format!("cancer_type={cancer_type}"));
+    }
+    parts.join(", ")
+}
+
+/// Searches public cBioPortal studies, largest cohorts first.
+pub async fn search_page(
+    filters: &StudySearchFilters,
+    limit: usize,
+    offset: usize,
+) -> Result<SearchPage<StudySearchResult>, BioMcpError> {
+    validate_filters(filters, limit)?;
+    let client = CBioPortalClient::new()?;
+    let mut studies = client.list_cancer_studies(filters.query.as_deref()).await?;
+    if let Some(cancer_type) = filters.cancer_type.as_deref() {
+        studies.retain(|row| matches_cancer_type(row, cancer_type));
+    }
+    studies.sort_by(|a, b| {
+        b.all_sample_count
+            .cmp(&a.all_sample_count)
+            .then_with(|| a.study_id.cmp(&b.study_id))
+    });
+
+    let total = studies.len();
+    let results = studies
+        .iter()
+        .skip(offset)
+        .take(limit)
+        .map(crate::transform::study::from_cbioportal_search_hit)
+        .collect();
+    Ok(SearchPage::offset(results, Some(total)))
+}
+
+/// Fetches one cBioPortal study; the `genes` section adds mutated-gene frequencies.
+pub async fn get(study_id: &str, sections: &[String]) -> Result<Study, BioMcpError> {
+    let sections = parse_sections(sections)?;
+    let study_id = validate_study_id(study_id)?;
+    let client = CBioPortalClient::new()?;
+    let record = client
+        .get_cancer_study(study_id)
+        .await?
+        .ok_or_else(|| BioMcpError::NotFound {
+            entity: "study".into(),
+            id: study_id.to_string(),
+            suggestion: format!("Try searching: biomcp search study {study_id}"),
+        })?;
+    let mut study = crate::transform::study::from_cbioportal_study(record);
+
+    if sections.include_genes {
+        match client.mutated_genes(&study.study_id).await {
+            Ok(rows) => {
+                let mut genes = rows
+                    .iter()
+                    .map(|row| {
+                        crate::transform::study::from_cbioportal_mutated_gene(
+                            row,
+                            study.sequenced_sample_count,
+                        )
+                    })
+                    .filter(|row| !row.gene.is_empty() && row.mutated_samples > 0)
+                    .collect::<Vec<_>>();
+                genes.sort_by(|a, b| {
+                    b.mutated_samples
+                        .cmp(&a.mutated_samples)
+                        .then_with(|| a.gene.cmp(&b.gene))
+                });
+                genes.truncate(STUDY_MUTATED_GENE_LIMIT);
+                if genes.is_empty() {
+                    study.mutated_genes_note =
+                        Some("No mutation data is available for this study.".into());
+                }
+                study.mutated_genes = genes;
+            }
+            Err(err) => {
+                tracing::warn!(
+                    ?err,
+                    study_id = study.study_id,
+                    "cBioPortal mutated genes unavailable"
+                );
+                study.mutated_genes_note =
+                    Some("Mutated-gene frequencies are temporarily unavailable.".into());
+            }
+        }
+    }
+
+    Ok(study)
+}
+
+#[cfg(test)]
+mod tests;
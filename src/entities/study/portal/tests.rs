@@ -0,0 +1,195 @@
+//! Tests for cBioPortal study search, lookup, and mutated-gene ranking.
+
+use super::*;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn env_lock_async() -> tokio::sync::MutexGuard<'static, ()> {
+    crate::test_support::env_lock().lock().await
+}
+
+struct EnvVarGuard {
+    name: &'static str,
+    previous: Option<String>,
+}
+
+impl Drop for EnvVarGuard {
+    fn drop(&mut self) {
+        // Safety: tests serialize environment mutation with `env_lock_async()`.
+        unsafe {
+            match &self.previous {
+                Some(value) => std::env::set_var(self.name, value),
+                None => std::env::remove_var(self.name),
+            }
+        }
+    }
+}
+
+fn set_env_var(name: &'static str, value: Option<&str>) -> EnvVarGuard {
+    let previous = std::env::var(name).ok();
+    // Safety: tests serialize environment mutation with `env_lock_async()`.
+    unsafe {
+        match value {
+            Some(value) => std::env::set_var(name, value),
+            None => std::env::remove_var(name),
+        }
+    }
+    EnvVarGuard { name, previous }
+}
+
+fn study_json(
+    study_id: &str,
+    cancer_type_id: &str,
+    cancer_type: &str,
+    samples: u32,
+) -> serde_json::Value {
+    serde_json::json!({
+        "studyId": study_id,
+        "name": format!("Study {study_id}"),
+        "description": "Whole-exome sequencing of <a href=\"https://example.org\">tumors</a>.",
+        "cancerTypeId": cancer_type_id,
+        "cancerType": {"name": cancer_type, "cancerTypeId": cancer_type_id},
+        "allSampleCount": samples,
+        "sequencedSampleCount": samples,
+        "cnaSampleCount": samples,
+        "pmid": "29625048",
+        "citation": "TCGA, Cell 2018",
+        "referenceGenome": "hg19"
+    })
+}
+
+#[test]
+fn parse_sections_accepts_genes_and_rejects_unknown() {
+    assert!(
+        parse_sections(&["genes".into()])
+            .expect("genes")
+            .include_genes
+    );
+    assert!(parse_sections(&["ALL".into()]).expect("all").include_genes);
+    assert!(!parse_sections(&[]).expect("default").include_genes);
+
+    let err = parse_sections(&["survival".into()]).expect_err("unknown section");
+    assert!(
+        err.to_string()
+            .contains("Unknown section \"survival\" for study")
+    );
+}
+
+#[test]
+fn validate_study_id_rejects_path_like_input() {
+    assert_eq!(
+        validate_study_id(" msk_impact_2017 ").expect("valid id"),
+        "msk_impact_2017"
+    );
+    assert!(validate_study_id("../studies").is_err());
+    assert!(validate_study_id("").is_err());
+}
+
+#[tokio::test]
+async fn search_page_filters_by_cancer_type_and_sorts_by_sample_count() {
+    let _guard = env_lock_async().await;
+    let server = MockServer::start().await;
+    let _base = set_env_var("BIOMCP_CBIOPORTAL_BASE", Some(&server.uri()));
+
+    Mock::given(method("GET"))
+        .and(path("/studies"))
+        .and(query_param("keyword", "tcga"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            study_json("luad_tcga", "luad", "Lung Adenocarcinoma", 230),
+            study_json("brca_tcga", "brca", "Invasive Breast Carcinoma", 1108),
+            study_json(
+                "luad_tcga_pan_can_atlas_2018",
+                "luad",
+                "Lung Adenocarcinoma",
+                566
+            ),
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let filters = StudySearchFilters {
+        query: Some("tcga".into()),
+        cancer_type: Some("lung".into()),
+    };
+    let page = search_page(&filters, 10, 0).await.expect("search page");
+
+    assert_eq!(page.total, Some(2));
+    let ids = page
+        .results
+        .iter()
+        .map(|row| row.study_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["luad_tcga_pan_can_atlas_2018", "luad_tcga"]);
+    assert_eq!(
+        page.results[0].cancer_type.as_deref(),
+        Some("Lung Adenocarcinoma")
+    );
+    assert_eq!(page.results[0].sample_count, Some(566));
+    assert_eq!(search_query_summary(&filters), "tcga, cancer_type=lung");
+}
+
+#[tokio::test]
+async fn get_adds_ranked_mutated_genes_for_genes_section() {
+    let _guard = env_lock_async().await;
+    let server = MockServer::start().await;
+    let _base = set_env_var("BIOMCP_CBIOPORTAL_BASE", Some(&server.uri()));
+
+    Mock::given(method("GET"))
+        .and(path("/studies/luad_tcga"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(study_json(
+            "luad_tcga",
+            "luad",
+            "Lung Adenocarcinoma",
+            230,
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/mutated-genes/fetch"))
+        .and(body_json(serde_json::json!({"studyIds": ["luad_tcga"]})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"hugoGeneSymbol": "KRAS", "numberOfAlteredCases": 75, "numberOfProfiledCases": 230, "totalCount": 76},
+            {"hugoGeneSymbol": "TP53", "numberOfAlteredCases": 115, "totalCount": 120},
+            {"hugoGeneSymbol": "ZNF1", "numberOfAlteredCases": 0, "numberOfProfiledCases": 230}
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let study = get("luad_tcga", &["genes".into()]).await.expect("study");
+
+    assert_eq!(study.cancer_type_id.as_deref(), Some("luad"));
+    assert_eq!(
+        study.description.as_deref(),
+        Some("Whole-exome sequencing of tumors.")
+    );
+    let genes = study
+        .mutated_genes
+        .iter()
+        .map(|row| (row.gene.as_str(), row.mutated_samples))
+        .collect::<Vec<_>>();
+    assert_eq!(genes, vec![("TP53", 115), ("KRAS", 75)]);
+    assert_eq!(study.mutated_genes[0].profiled_samples, Some(230));
+    assert_eq!(study.mutated_genes[0].frequency, Some(0.5));
+}
+
+#[tokio::test]
+async fn get_maps_missing_study_to_not_found() {
+    let _guard = env_lock_async().await;
+    let server = MockServer::start().await;
+    let _base = set_env_var("BIOMCP_CBIOPORTAL_BASE", Some(&server.uri()));
+
+    Mock::given(method("GET"))
+        .and(path("/studies/unknown_study"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let err = get("unknown_study", &[]).await.expect_err("missing study");
+    assert!(matches!(err, BioMcpError::NotFound { .. }));
+    assert!(
+        err.to_string()
+            .contains("biomcp search study unknown_study")
+    );
+}
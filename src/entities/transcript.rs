@@ -0,0 +1,278 @@
+//! Ensembl transcript cards.
+//!
+//! A transcript is read from Ensembl REST (GRCh38): genomic location, exon
+//! structure in transcript order, Ensembl canonical and MANE status, the
+//! translated protein, and RefSeq, CCDS, and UniProt cross-references. The
+//! parent gene's symbol links the card back to `get gene` and variant search.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::error::BioMcpError;
+use crate::sources::ensembl::{EnsemblClient, EnsemblLookup, EnsemblXref};
+use crate::utils::text::non_empty;
+
+/// Cross-reference databases kept on the card, in display order.
+const TRANSCRIPT_XREF_DATABASES: &[&str] = &[
+    "RefSeq_mRNA",
+    "RefSeq_ncRNA",
+    "RefSeq_peptide",
+    "CCDS",
+    "Uniprot/SWISSPROT",
+    "Uniprot_isoform",
+    "Uniprot/SPTREMBL",
+];
+const UNIPROT_XREF_DATABASES: &[&str] = &["Uniprot/SWISSPROT", "Uniprot_isoform"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transcript {
+    /// Unversioned Ensembl ID, e.g. `ENST00000288602`.
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+    /// Ensembl transcript name, e.g. `BRAF-201`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gene_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gene_symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub biotype: Option<String>,
+    #[serde(default)]
+    pub canonical: bool,
+    /// RefSeq transcript matched by MANE Select.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mane_select: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mane_plus_clinical: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assembly: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chromosome: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strand: Option<i8>,
+    /// Spliced length: the sum of exon lengths.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length_bp: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exons: Vec<TranscriptExon>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protein: Option<TranscriptProtein>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub xrefs: Vec<TranscriptXref>,
+}
+
+/// One exon, numbered 5' to 3' along the transcript.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptExon {
+    pub number: usize,
+    pub id: String,
+    pub start: u64,
+    pub end: u64,
+    pub length: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptProtein {
+    /// Versioned Ensembl protein ID when known, e.g. `ENSP00000288602.7`.
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length_aa: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cds_start: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cds_end: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub uniprot: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptXref {
+    pub database: String,
+    pub id: String,
+}
+
+fn transcript_id_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^ENST\d{11}(?:\.\d+)?$").expect("valid regex"))
+}
+
+/// Upper-cases the ID and drops any version suffix.
+fn normalize_transcript_id(raw: &str) -> Result<String, BioMcpError> {
+    let id = raw.trim().to_ascii_uppercase();
+    if id.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "Transcript ID is required. Example: biomcp get transcript ENST00000288602".into(),
+        ));
+    }
+    if !transcript_id_re().is_match(&id) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Invalid Ensembl transcript ID \"{}\". Use a human ID such as ENST00000288602; get variant <id> --source vep lists transcripts for a variant.",
+            raw.trim()
+        )));
+    }
+    Ok(id.split('.').next().unwrap_or_default().to_string())
+}
+
+fn versioned(id: &str, version: Option<u32>) -> String {
+    match version {
+        Some(version) => format!("{id}.{version}"),
+        None => id.to_string(),
+    }
+}
+
+fn mane_match(lookup: &EnsemblLookup, kind: &str) -> Option<String> {
+    lookup
+        .mane
+        .iter()
+        .find(|row| row.kind.as_deref() == Some(kind))
+        .and_then(|row| non_empty(row.refseq_match.as_deref()))
+}
+
+fn map_exons(lookup: &EnsemblLookup) -> Vec<TranscriptExon> {
+    let mut exons = lookup.exons.clone();
+    if lookup.strand == Some(-1) {
+        exons.sort_by_key(|exon| std::cmp::Reverse(exon.start));
+    } else {
+        exons.sort_by_key(|exon| exon.start);
+    }
+    exons
+        .into_iter()
+        .enumerate()
+        .map(|(index, exon)| TranscriptExon {
+            number: index + 1,
+            id: exon.id,
+            start: exon.start,
+            end: exon.end,
+            length: exon.end.saturating_sub(exon.start) + 1,
+        })
+        .collect()
+}
+
+fn map_xrefs(xrefs: &[EnsemblXref]) -> Vec<TranscriptXref> {
+    let mut out: Vec<(usize, TranscriptXref)> = Vec::new();
+    for xref in xrefs {
+        let Some(rank) = TRANSCRIPT_XREF_DATABASES
+            .iter()
+            .position(|db| *db == xref.dbname)
+        else {
+            continue;
+        };
+        let Some(id) =
+            non_empty(xref.display_id.as_deref()).or_else(|| non_empty(xref.primary_id.as_deref()))
+        else {
+            continue;
+        };
+        let database =
+            non_empty(xref.db_display_name.as_deref()).unwrap_or_else(|| xref.dbname.clone());
+        if out
+            .iter()
+            .any(|(_, row)| row.database == database && row.id == id)
+        {
+            continue;
+        }
+        out.push((rank, TranscriptXref { database, id }));
+    }
+    out.sort_by_key(|(rank, _)| *rank);
+    out.into_iter().map(|(_, row)| row).collect()
+}
+
+fn uniprot_ids(xrefs: &[EnsemblXref]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for xref in xrefs
+        .iter()
+        .filter(|xref| UNIPROT_XREF_DATABASES.contains(&xref.dbname.as_str()))
+    {
+        if let Some(id) = non_empty(xref.primary_id.as_deref())
+            && !out.contains(&id)
+        {
+            out.push(id);
+        }
+    }
+    out
+}
+
+fn map_transcript(
+    lookup: &EnsemblLookup,
+    gene_symbol: Option<String>,
+    xrefs: &[EnsemblXref],
+) -> Transcript {
+    let exons = map_exons(lookup);
+    let length_bp = (!exons.is_empty()).then(|| exons.iter().map(|exon| exon.length).sum());
+    let protein = lookup
+        .translation
+        .as_ref()
+        .filter(|row| !row.id.trim().is_empty())
+        .map(|row| TranscriptProtein {
+            id: versioned(row.id.trim(), row.version),
+            length_aa: row.length,
+            cds_start: row.start,
+            cds_end: row.end,
+            uniprot: uniprot_ids(xrefs),
+        });
+    Transcript {
+        id: lookup.id.trim().to_string(),
+        version: lookup.version,
+        name: non_empty(lookup.display_name.as_deref()),
+        gene_id: non_empty(lookup.parent.as_deref()),
+        gene_symbol,
+        biotype: non_empty(lookup.biotype.as_deref()),
+        canonical: lookup.is_canonical == Some(1),
+        mane_select: mane_match(lookup, "MANE_Select"),
+        mane_plus_clinical: mane_match(lookup, "MANE_Plus_Clinical"),
+        assembly: non_empty(lookup.assembly_name.as_deref()),
+        chromosome: non_empty(lookup.seq_region_name.as_deref()),
+        start: lookup.start,
+        end: lookup.end,
+        strand: lookup.strand,
+        length_bp,
+        exons,
+        protein,
+        xrefs: map_xrefs(xrefs),
+    }
+}
+
+pub async fn get(id: &str) -> Result<Transcript, BioMcpError> {
+    let id = normalize_transcript_id(id)?;
+    let client = EnsemblClient::new()?;
+    let Some(lookup) = client.lookup(&id, true).await? else {
+        return Err(BioMcpError::NotFound {
+            entity: "transcript".into(),
+            id,
+            suggestion: "List a variant's transcripts with: biomcp get variant <id> --source vep"
+                .into(),
+        });
+    };
+
+    let parent = non_empty(lookup.parent.as_deref());
+    let (xrefs, gene) = tokio::join!(client.xrefs(&id), async {
+        match parent.as_deref() {
+            Some(gene_id) => client.lookup(gene_id, false).await,
+            None => Ok(None),
+        }
+    });
+    let xrefs = xrefs.unwrap_or_else(|err| {
+        warn!(transcript = %id, "Ensembl xrefs unavailable: {err}");
+        Vec::new()
+    });
+    let gene_symbol = match gene {
+        Ok(gene) => gene.and_then(|gene| non_empty(gene.display_name.as_deref())),
+        Err(err) => {
+            warn!(transcript = %id, "Ensembl parent gene lookup failed: {err}");
+            None
+        }
+    };
+
+    crate::transform::fields::apply("transcript", map_transcript(&lookup, gene_symbol, &xrefs))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,138 @@
+//! Tests for transcript ID validation and Ensembl lookup mapping.
+
+use super::*;
+
+fn lookup(value: serde_json::Value) -> EnsemblLookup {
+    serde_json::from_value(value).expect("ensembl lookup json")
+}
+
+fn xref(dbname: &str, display_name: &str, id: &str) -> EnsemblXref {
+    serde_json::from_value(serde_json::json!({
+        "dbname": dbname,
+        "db_display_name": display_name,
+        "primary_id": id,
+        "display_id": id
+    }))
+    .expect("ensembl xref json")
+}
+
+#[test]
+fn normalize_transcript_id_strips_versions_and_rejects_other_ids() {
+    assert_eq!(
+        normalize_transcript_id(" enst00000288602.11 ").expect("versioned"),
+        "ENST00000288602"
+    );
+    assert_eq!(
+        normalize_transcript_id("ENST00000288602").expect("unversioned"),
+        "ENST00000288602"
+    );
+
+    for raw in ["BRAF", "ENSG00000157764", "NM_004333.6", "ENST288602"] {
+        let err = normalize_transcript_id(raw).expect_err(raw);
+        assert!(
+            err.to_string().contains("Invalid Ensembl transcript ID"),
+            "{raw}"
+        );
+    }
+    let err = normalize_transcript_id(" ").expect_err("empty");
+    assert!(err.to_string().contains("Transcript ID is required"));
+}
+
+#[test]
+fn map_transcript_orders_minus_strand_exons_and_collects_protein_xrefs() {
+    let lookup = lookup(serde_json::json!({
+        "id": "ENST00000288602",
+        "version": 11,
+        "display_name": "BRAF-201",
+        "biotype": "protein_coding",
+        "Parent": "ENSG00000157764",
+        "is_canonical": 1,
+        "assembly_name": "GRCh38",
+        "seq_region_name": "7",
+        "start": 140719327,
+        "end": 140924929,
+        "strand": -1,
+        "Exon": [
+            {"id": "ENSE00003651637", "start": 140850110, "end": 140850212},
+            {"id": "ENSE00001903155", "start": 140924566, "end": 140924929},
+            {"id": "ENSE00003507093", "start": 140719327, "end": 140726516}
+        ],
+        "Translation": {
+            "id": "ENSP00000288602",
+            "version": 7,
+            "length": 766,
+            "start": 140734597,
+            "end": 140924764
+        },
+        "MANE": [{"type": "MANE_Select", "refseq_match": "NM_004333.6"}]
+    }));
+    let xrefs = vec![
+        xref("Uniprot/SWISSPROT", "UniProtKB/Swiss-Prot", "P15056"),
+        xref("RefSeq_mRNA", "RefSeq mRNA", "NM_004333.6"),
+        xref("UCSC", "UCSC Stable ID", "uc003vwc.5"),
+        xref("RefSeq_mRNA", "RefSeq mRNA", "NM_004333.6"),
+        xref("CCDS", "CCDS", "CCDS5863.1"),
+    ];
+
+    let transcript = map_transcript(&lookup, Some("BRAF".into()), &xrefs);
+    assert_eq!(transcript.name.as_deref(), Some("BRAF-201"));
+    assert_eq!(transcript.gene_symbol.as_deref(), Some("BRAF"));
+    assert!(transcript.canonical);
+    assert_eq!(transcript.mane_select.as_deref(), Some("NM_004333.6"));
+    assert!(transcript.mane_plus_clinical.is_none());
+
+    let exon_ids = transcript
+        .exons
+        .iter()
+        .map(|exon| (exon.number, exon.id.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        exon_ids,
+        vec![
+            (1, "ENSE00001903155"),
+            (2, "ENSE00003651637"),
+            (3, "ENSE00003507093")
+        ]
+    );
+    assert_eq!(transcript.exons[0].length, 364);
+    assert_eq!(transcript.length_bp, Some(364 + 103 + 7190));
+
+    let protein = transcript.protein.as_ref().expect("protein product");
+    assert_eq!(protein.id, "ENSP00000288602.7");
+    assert_eq!(protein.length_aa, Some(766));
+    assert_eq!(protein.uniprot, vec!["P15056"]);
+
+    let xref_rows = transcript
+        .xrefs
+        .iter()
+        .map(|row| (row.database.as_str(), row.id.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        xref_rows,
+        vec![
+            ("RefSeq mRNA", "NM_004333.6"),
+            ("CCDS", "CCDS5863.1"),
+            ("UniProtKB/Swiss-Prot", "P15056")
+        ]
+    );
+}
+
+#[test]
+fn map_transcript_leaves_noncoding_transcripts_without_protein() {
+    let lookup = lookup(serde_json::json!({
+        "id": "ENST00000608495",
+        "biotype": "lncRNA",
+        "strand": 1,
+        "Exon": [
+            {"id": "ENSE2", "start": 500, "end": 599},
+            {"id": "ENSE1", "start": 100, "end": 199}
+        ]
+    }));
+
+    let transcript = map_transcript(&lookup, None, &[]);
+    assert!(!transcript.canonical);
+    assert!(transcript.protein.is_none());
+    assert_eq!(transcript.exons[0].id, "ENSE1");
+    assert_eq!(transcript.length_bp, Some(200));
+    assert!(transcript.xrefs.is_empty());
+}
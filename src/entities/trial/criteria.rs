@@ -0,0 +1,461 @@
+//! Structured eligibility read from a ClinicalTrials.gov record, checked one
+//! patient-profile attribute at a time for `match trials`.
+
+use crate::sources::clinicaltrials::{CtGovLocation, CtGovStudy};
+
+use super::profile::{PatientLocation, PatientProfile};
+use super::search::{
+    EcogLimit, contains_keyword_tokens, ecog_limit, eligibility_keyword_in_inclusion,
+    haversine_miles, parse_age_years, split_eligibility_sections,
+};
+use super::{TrialMatchCriterion, TrialMatchStatus, TrialSearchResult};
+
+/// Travel radius used when a profile gives coordinates without `distance`.
+const DEFAULT_DISTANCE_MILES: u32 = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+enum AgeLimit {
+    Absent,
+    Years(f32),
+    /// Stated, but not in a unit [`parse_age_years`] reads.
+    Unparsed(String),
+}
+
+impl AgeLimit {
+    fn from_field(value: Option<&str>) -> Self {
+        let Some(value) = value
+            .map(str::trim)
+            .filter(|value| !value.is_empty() && !value.eq_ignore_ascii_case("n/a"))
+        else {
+            return Self::Absent;
+        };
+        match parse_age_years(value) {
+            Some(years) => Self::Years(years),
+            None => Self::Unparsed(value.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct TrialSite {
+    facility: Option<String>,
+    city: Option<String>,
+    state: Option<String>,
+    country: Option<String>,
+    geo: Option<(f64, f64)>,
+}
+
+impl TrialSite {
+    fn from_location(location: &CtGovLocation) -> Self {
+        let clean = |value: Option<&String>| {
+            value
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        Self {
+            facility: clean(location.facility.as_ref()),
+            city: clean(location.city.as_ref()),
+            state: clean(location.state.as_ref()),
+            country: clean(location.country.as_ref()),
+            geo: location
+                .geo_point
+                .as_ref()
+                .and_then(|geo| geo.lat.zip(geo.lon)),
+        }
+    }
+
+    fn label(&self) -> String {
+        let place = [&self.city, &self.state, &self.country]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(", ");
+        match (&self.facility, place.is_empty()) {
+            (Some(facility), false) => format!("{facility} ({place})"),
+            (Some(facility), true) => facility.clone(),
+            (None, _) => place,
+        }
+    }
+}
+
+fn same_place(site_value: Option<&String>, wanted: &str) -> bool {
+    site_value.is_some_and(|value| value.trim().eq_ignore_ascii_case(wanted.trim()))
+}
+
+fn format_years(years: f32) -> String {
+    if years.fract() == 0.0 {
+        format!("{years:.0}")
+    } else {
+        format!("{years:.1}")
+    }
+}
+
+fn criterion(
+    criterion: &str,
+    value: impl Into<String>,
+    status: TrialMatchStatus,
+    explanation: impl Into<String>,
+) -> TrialMatchCriterion {
+    TrialMatchCriterion {
+        criterion: criterion.to_string(),
+        value: value.into(),
+        status,
+        explanation: explanation.into(),
+    }
+}
+
+/// Eligibility facts for one trial. Criteria text is lowercased and split at
+/// the exclusion header.
+#[derive(Debug, Clone)]
+pub(super) struct TrialEligibility {
+    title: String,
+    conditions: Vec<String>,
+    min_age: AgeLimit,
+    max_age: AgeLimit,
+    sex: Option<String>,
+    has_criteria_text: bool,
+    inclusion: String,
+    exclusion: String,
+    sites: Vec<TrialSite>,
+}
+
+impl TrialEligibility {
+    /// Combines a search row with its detail record. Without a record (the
+    /// fetch failed) every text-based check comes back unknown.
+    pub(super) fn new(result: &TrialSearchResult, study: Option<&CtGovStudy>) -> Self {
+        let protocol = study.and_then(|study| study.protocol_section.as_ref());
+        let module = protocol.and_then(|section| section.eligibility_module.as_ref());
+        let criteria_text = module
+            .and_then(|module| module.eligibility_criteria.as_deref())
+            .map(str::trim)
+            .filter(|text| !text.is_empty());
+        let (inclusion, exclusion) = criteria_text
+            .map(split_eligibility_sections)
+            .unwrap_or_default();
+        let sites = protocol
+            .and_then(|section| section.contacts_locations_module.as_ref())
+            .map(|module| {
+                module
+                    .locations
+                    .iter()
+                    .map(TrialSite::from_location)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            title: result.title.clone(),
+            conditions: result.conditions.clone(),
+            min_age: AgeLimit::from_field(module.and_then(|m| m.minimum_age.as_deref())),
+            max_age: AgeLimit::from_field(module.and_then(|m| m.maximum_age.as_deref())),
+            sex: module
+                .and_then(|m| m.sex.as_deref())
+                .map(|sex| sex.trim().to_ascii_uppercase())
+                .filter(|sex| !sex.is_empty()),
+            has_criteria_text: criteria_text.is_some(),
+            inclusion,
+            exclusion,
+            sites,
+        }
+    }
+
+    /// Checks every attribute the profile sets, diagnosis first.
+    pub(super) fn evaluate(&self, profile: &PatientProfile) -> Vec<TrialMatchCriterion> {
+        let mut out = vec![self.check_diagnosis(&profile.diagnosis)];
+        out.extend(profile.age.map(|age| self.check_age(age)));
+        out.extend(profile.sex.as_deref().map(|sex| self.check_sex(sex)));
+        out.extend(profile.ecog.map(|ecog| self.check_ecog(ecog)));
+        out.extend(
+            profile
+                .biomarkers
+                .iter()
+                .map(|biomarker| self.check_biomarker(biomarker)),
+        );
+        out.extend(
+            profile
+                .location
+                .as_ref()
+                .map(|location| self.check_location(location)),
+        );
+        out
+    }
+
+    fn check_diagnosis(&self, diagnosis: &str) -> TrialMatchCriterion {
+        let wanted = diagnosis.trim().to_ascii_lowercase();
+        if let Some(condition) = self.conditions.iter().find(|condition| {
+            let condition = condition.to_ascii_lowercase();
+            condition.contains(&wanted) || wanted.contains(&condition)
+        }) {
+            return criterion(
+                "diagnosis",
+                diagnosis,
+                TrialMatchStatus::Met,
+                format!("listed condition: {condition}"),
+            );
+        }
+        let haystack = format!("{} {}", self.conditions.join(" "), self.title).to_ascii_lowercase();
+        if contains_keyword_tokens(&haystack, &wanted) {
+            return criterion(
+                "diagnosis",
+                diagnosis,
+                TrialMatchStatus::Met,
+                "named in the listed conditions or title",
+            );
+        }
+        let listed = if self.conditions.is_empty() {
+            "none".to_string()
+        } else {
+            self.conditions.join("; ")
+        };
+        criterion(
+            "diagnosis",
+            diagnosis,
+            TrialMatchStatus::Unknown,
+            format!("matched by condition search, but listed conditions are: {listed}"),
+        )
+    }
+
+    fn check_age(&self, age: f32) -> TrialMatchCriterion {
+        let value = format_years(age);
+        if let AgeLimit::Years(min) = self.min_age
+            && age < min
+        {
+            return criterion(
+                "age",
+                value,
+                TrialMatchStatus::NotMet,
+                format!("minimum age is {}", format_years(min)),
+            );
+        }
+        if let AgeLimit::Years(max) = self.max_age
+            && age > max
+        {
+            return criterion(
+                "age",
+                value,
+                TrialMatchStatus::NotMet,
+                format!("maximum age is {}", format_years(max)),
+            );
+        }
+        for limit in [&self.min_age, &self.max_age] {
+            if let AgeLimit::Unparsed(raw) = limit {
+                return criterion(
+                    "age",
+                    value,
+                    TrialMatchStatus::Unknown,
+                    format!("age limit `{raw}` could not be read"),
+                );
+            }
+        }
+        let explanation = match (&self.min_age, &self.max_age) {
+            (AgeLimit::Years(min), AgeLimit::Years(max)) => {
+                format!("within {}-{} years", format_years(*min), format_years(*max))
+            }
+            (AgeLimit::Years(min), _) => format!("at least {} years", format_years(*min)),
+            (_, AgeLimit::Years(max)) => format!("at most {} years", format_years(*max)),
+            _ => "no age limits stated".to_string(),
+        };
+        criterion("age", value, TrialMatchStatus::Met, explanation)
+    }
+
+    fn check_sex(&self, sex: &str) -> TrialMatchCriterion {
+        let (status, explanation) = match self.sex.as_deref() {
+            None => (
+                TrialMatchStatus::Unknown,
+                "sex eligibility not stated".into(),
+            ),
+            Some("ALL") => (TrialMatchStatus::Met, "open to all sexes".into()),
+            Some(required) if required.eq_ignore_ascii_case(sex) => (
+                TrialMatchStatus::Met,
+                format!(
+                    "enrolls {} participants only",
+                    required.to_ascii_lowercase()
+                ),
+            ),
+            Some(required) => (
+                TrialMatchStatus::NotMet,
+                format!(
+                    "enrolls {} participants only",
+                    required.to_ascii_lowercase()
+                ),
+            ),
+        };
+        criterion("sex", sex, status, explanation)
+    }
+
+    fn check_ecog(&self, ecog: u8) -> TrialMatchCriterion {
+        let value = ecog.to_string();
+        if !self.has_criteria_text {
+            return criterion(
+                "ecog",
+                value,
+                TrialMatchStatus::Unknown,
+                "no eligibility criteria text",
+            );
+        }
+        let (status, explanation) = match ecog_limit(&self.inclusion) {
+            EcogLimit::Absent => (
+                TrialMatchStatus::Met,
+                "no performance-status requirement stated".to_string(),
+            ),
+            EcogLimit::Max(max) if ecog <= max => (
+                TrialMatchStatus::Met,
+                format!("inclusion allows ECOG 0-{max}"),
+            ),
+            EcogLimit::Max(max) => (
+                TrialMatchStatus::NotMet,
+                format!("inclusion allows ECOG 0-{max}"),
+            ),
+            EcogLimit::Unparsed => (
+                TrialMatchStatus::Unknown,
+                "performance-status requirement could not be read".to_string(),
+            ),
+        };
+        criterion("ecog", value, status, explanation)
+    }
+
+    fn check_biomarker(&self, biomarker: &str) -> TrialMatchCriterion {
+        let keyword = biomarker.trim().to_ascii_lowercase();
+        let result = |status, explanation: String| {
+            criterion("biomarker", biomarker.trim(), status, explanation)
+        };
+        if !self.has_criteria_text {
+            return result(
+                TrialMatchStatus::Unknown,
+                "no eligibility criteria text".into(),
+            );
+        }
+
+        let allowed = eligibility_keyword_in_inclusion(&self.inclusion, &self.exclusion, &keyword);
+        if contains_keyword_tokens(&self.inclusion, &keyword) && allowed {
+            return result(
+                TrialMatchStatus::Met,
+                "named in the inclusion criteria".into(),
+            );
+        }
+        if !allowed {
+            let explanation = if contains_keyword_tokens(&self.exclusion, &keyword) {
+                "named in the exclusion criteria"
+            } else {
+                "inclusion criteria rule it out"
+            };
+            return result(TrialMatchStatus::NotMet, explanation.into());
+        }
+
+        // Gene-level mentions ("EGFR-mutant", "EGFR alterations") without the
+        // specific alteration are worth surfacing but cannot be decided here.
+        let gene = keyword.split_whitespace().next().unwrap_or_default();
+        if gene != keyword {
+            let label = biomarker.split_whitespace().next().unwrap_or_default();
+            if contains_keyword_tokens(&self.inclusion, gene) {
+                return result(
+                    TrialMatchStatus::Unknown,
+                    format!("inclusion criteria mention {label} but not this alteration"),
+                );
+            }
+            if contains_keyword_tokens(&self.exclusion, gene) {
+                return result(
+                    TrialMatchStatus::Unknown,
+                    format!(
+                        "exclusion criteria mention {label}; check whether this alteration is excluded"
+                    ),
+                );
+            }
+        }
+        result(
+            TrialMatchStatus::Unknown,
+            "not mentioned in the eligibility criteria".into(),
+        )
+    }
+
+    fn check_location(&self, location: &PatientLocation) -> TrialMatchCriterion {
+        let value = location_label(location);
+        let result =
+            |status, explanation: String| criterion("location", &value, status, explanation);
+        if self.sites.is_empty() {
+            return result(TrialMatchStatus::Unknown, "no sites listed".into());
+        }
+
+        if let (Some(lat), Some(lon)) = (location.lat, location.lon)
+            && let Some((site, miles)) = self
+                .sites
+                .iter()
+                .filter_map(|site| {
+                    site.geo.map(|(site_lat, site_lon)| {
+                        (site, haversine_miles(lat, lon, site_lat, site_lon))
+                    })
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+        {
+            let radius = location.distance.unwrap_or(DEFAULT_DISTANCE_MILES);
+            let status = if miles <= f64::from(radius) {
+                TrialMatchStatus::Met
+            } else {
+                TrialMatchStatus::NotMet
+            };
+            return result(
+                status,
+                format!(
+                    "nearest site {} is {miles:.0} mi away (limit {radius} mi)",
+                    site.label()
+                ),
+            );
+        }
+
+        let matches = |site: &TrialSite| {
+            location
+                .city
+                .as_deref()
+                .is_none_or(|city| same_place(site.city.as_ref(), city))
+                && location
+                    .state
+                    .as_deref()
+                    .is_none_or(|state| same_place(site.state.as_ref(), state))
+                && location
+                    .country
+                    .as_deref()
+                    .is_none_or(|country| same_place(site.country.as_ref(), country))
+        };
+        if location.city.is_none() && location.state.is_none() && location.country.is_none() {
+            return result(
+                TrialMatchStatus::Unknown,
+                "no site coordinates to measure distance".into(),
+            );
+        }
+        if let Some(site) = self.sites.iter().find(|site| matches(site)) {
+            return result(TrialMatchStatus::Met, format!("site at {}", site.label()));
+        }
+        let in_country = location.country.as_deref().map_or(0, |country| {
+            self.sites
+                .iter()
+                .filter(|site| same_place(site.country.as_ref(), country))
+                .count()
+        });
+        let explanation = if in_country > 0 {
+            format!(
+                "no site in {value}; {in_country} site(s) elsewhere in {}",
+                location.country.as_deref().unwrap_or_default()
+            )
+        } else {
+            format!("no site in {value}")
+        };
+        result(TrialMatchStatus::NotMet, explanation)
+    }
+}
+
+fn location_label(location: &PatientLocation) -> String {
+    let place = [&location.city, &location.state, &location.country]
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(", ");
+    match (location.lat, location.lon) {
+        (Some(lat), Some(lon)) if place.is_empty() => format!("{lat}, {lon}"),
+        _ => place,
+    }
+}
+
+#[cfg(test)]
+mod tests;
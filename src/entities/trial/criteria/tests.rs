@@ -0,0 +1,204 @@
+//! Tests for per-attribute trial eligibility checks.
+
+use super::*;
+
+fn search_result(conditions: &[&str]) -> TrialSearchResult {
+    TrialSearchResult {
+        nct_id: "NCT00000001".into(),
+        title: "Osimertinib After Progression in EGFR-Mutant Lung Cancer".into(),
+        status: "RECRUITING".into(),
+        phase: Some("PHASE2".into()),
+        conditions: conditions.iter().map(|c| c.to_string()).collect(),
+        sponsor: None,
+        registry: None,
+        cross_registered_nct: None,
+        eligibility_unchecked: Vec::new(),
+    }
+}
+
+fn study(eligibility: serde_json::Value, locations: serde_json::Value) -> CtGovStudy {
+    serde_json::from_value(serde_json::json!({
+        "protocolSection": {
+            "identificationModule": {"nctId": "NCT00000001"},
+            "eligibilityModule": eligibility,
+            "contactsLocationsModule": {"locations": locations}
+        }
+    }))
+    .expect("study fixture")
+}
+
+const CRITERIA: &str = "Inclusion Criteria:\n\
+    * Histologically confirmed NSCLC with EGFR L858R or exon 19 deletion\n\
+    * ECOG performance status 0-1\n\
+    \n\
+    Exclusion Criteria:\n\
+    * Known ALK rearrangement\n\
+    * Prior osimertinib";
+
+fn eligibility() -> TrialEligibility {
+    let study = study(
+        serde_json::json!({
+            "eligibilityCriteria": CRITERIA,
+            "minimumAge": "18 Years",
+            "maximumAge": "75 Years",
+            "sex": "ALL"
+        }),
+        serde_json::json!([
+            {
+                "facility": "Dana-Farber Cancer Institute",
+                "city": "Boston",
+                "state": "Massachusetts",
+                "country": "United States",
+                "geoPoint": {"lat": 42.3376, "lon": -71.1077}
+            },
+            {
+                "facility": "MD Anderson",
+                "city": "Houston",
+                "state": "Texas",
+                "country": "United States",
+                "geoPoint": {"lat": 29.7075, "lon": -95.3977}
+            }
+        ]),
+    );
+    TrialEligibility::new(
+        &search_result(&["Non Small Cell Lung Cancer"]),
+        Some(&study),
+    )
+}
+
+fn profile(text: &str) -> PatientProfile {
+    PatientProfile::parse(text).expect("profile")
+}
+
+fn statuses(criteria: &[TrialMatchCriterion]) -> Vec<(&str, TrialMatchStatus)> {
+    criteria
+        .iter()
+        .map(|c| (c.criterion.as_str(), c.status))
+        .collect()
+}
+
+#[test]
+fn evaluate_checks_each_profile_attribute_in_order() {
+    let criteria = eligibility().evaluate(&profile(
+        "diagnosis: non small cell lung cancer\nage: 62\nsex: female\necog: 1\n\
+         biomarkers: [EGFR L858R, ALK fusion, KRAS G12C]\n\
+         location:\n  city: Boston\n  country: United States",
+    ));
+
+    assert_eq!(
+        statuses(&criteria),
+        vec![
+            ("diagnosis", TrialMatchStatus::Met),
+            ("age", TrialMatchStatus::Met),
+            ("sex", TrialMatchStatus::Met),
+            ("ecog", TrialMatchStatus::Met),
+            ("biomarker", TrialMatchStatus::Met),
+            ("biomarker", TrialMatchStatus::Unknown),
+            ("biomarker", TrialMatchStatus::Unknown),
+            ("location", TrialMatchStatus::Met),
+        ]
+    );
+    assert_eq!(criteria[1].explanation, "within 18-75 years");
+    assert_eq!(criteria[3].explanation, "inclusion allows ECOG 0-1");
+    assert!(
+        criteria[5]
+            .explanation
+            .contains("exclusion criteria mention ALK")
+    );
+    assert_eq!(
+        criteria[6].explanation,
+        "not mentioned in the eligibility criteria"
+    );
+    assert!(
+        criteria[7]
+            .explanation
+            .contains("Dana-Farber Cancer Institute")
+    );
+}
+
+#[test]
+fn evaluate_flags_unmet_age_ecog_and_excluded_biomarkers() {
+    let criteria = eligibility().evaluate(&profile(
+        "diagnosis: lung cancer\nage: 80\necog: 2\nbiomarkers: [ALK rearrangement]",
+    ));
+
+    assert_eq!(
+        statuses(&criteria),
+        vec![
+            ("diagnosis", TrialMatchStatus::Met),
+            ("age", TrialMatchStatus::NotMet),
+            ("ecog", TrialMatchStatus::NotMet),
+            ("biomarker", TrialMatchStatus::NotMet),
+        ]
+    );
+    assert_eq!(criteria[1].explanation, "maximum age is 75");
+    assert_eq!(criteria[3].explanation, "named in the exclusion criteria");
+}
+
+#[test]
+fn check_location_uses_distance_when_coordinates_are_given() {
+    let trial = eligibility();
+    let near = profile("diagnosis: nsclc\nlocation:\n  lat: 42.36\n  lon: -71.06\n  distance: 25");
+    let near = trial.check_location(near.location.as_ref().expect("location"));
+    assert_eq!(near.status, TrialMatchStatus::Met);
+    assert!(near.explanation.starts_with("nearest site Dana-Farber"));
+
+    let far = profile("diagnosis: nsclc\nlocation:\n  lat: 40.71\n  lon: -74.00");
+    let far = trial.check_location(far.location.as_ref().expect("location"));
+    assert_eq!(far.status, TrialMatchStatus::NotMet);
+    assert!(far.explanation.ends_with("(limit 100 mi)"));
+}
+
+#[test]
+fn check_location_reports_sites_elsewhere_in_the_country() {
+    let located = profile("diagnosis: nsclc\nlocation:\n  city: Chicago\n  country: united states");
+    let check = eligibility().check_location(located.location.as_ref().expect("location"));
+    assert_eq!(check.status, TrialMatchStatus::NotMet);
+    assert_eq!(
+        check.explanation,
+        "no site in Chicago, united states; 2 site(s) elsewhere in united states"
+    );
+}
+
+#[test]
+fn missing_detail_record_leaves_text_checks_unknown() {
+    let trial = TrialEligibility::new(&search_result(&["Melanoma"]), None);
+    let criteria = trial.evaluate(&profile(
+        "diagnosis: lung cancer\nage: 40\nsex: male\necog: 0\nbiomarkers: [BRAF V600E]\n\
+         location:\n  country: France",
+    ));
+
+    assert_eq!(
+        statuses(&criteria),
+        vec![
+            ("diagnosis", TrialMatchStatus::Met),
+            ("age", TrialMatchStatus::Met),
+            ("sex", TrialMatchStatus::Unknown),
+            ("ecog", TrialMatchStatus::Unknown),
+            ("biomarker", TrialMatchStatus::Unknown),
+            ("location", TrialMatchStatus::Unknown),
+        ]
+    );
+    assert_eq!(criteria[1].explanation, "no age limits stated");
+}
+
+#[test]
+fn check_age_and_sex_handle_unreadable_and_restricted_records() {
+    let study = study(
+        serde_json::json!({"minimumAge": "6 Decades", "sex": "FEMALE"}),
+        serde_json::json!([]),
+    );
+    let trial = TrialEligibility::new(&search_result(&["Breast Cancer"]), Some(&study));
+
+    let age = trial.check_age(50.0);
+    assert_eq!(age.status, TrialMatchStatus::Unknown);
+    assert_eq!(age.explanation, "age limit `6 Decades` could not be read");
+
+    let sex = trial.check_sex("male");
+    assert_eq!(sex.status, TrialMatchStatus::NotMet);
+    assert_eq!(sex.explanation, "enrolls female participants only");
+
+    let diagnosis = trial.check_diagnosis("glioblastoma");
+    assert_eq!(diagnosis.status, TrialMatchStatus::Unknown);
+    assert!(diagnosis.explanation.ends_with("Breast Cancer"));
+}
@@ -9,10 +9,12 @@ use crate::transform;
 
 use super::{
     TRIAL_SECTION_ALL, TRIAL_SECTION_ARMS, TRIAL_SECTION_ELIGIBILITY, TRIAL_SECTION_LOCATIONS,
-    TRIAL_SECTION_NAMES, TRIAL_SECTION_OUTCOMES, TRIAL_SECTION_REFERENCES, Trial, TrialSource,
+    TRIAL_SECTION_NAMES, TRIAL_SECTION_OUTCOMES, TRIAL_SECTION_PUBLICATIONS,
+    TRIAL_SECTION_REFERENCES, TRIAL_SECTION_RESULTS, Trial, TrialResults, TrialSource,
+    publications,
 };
 
-const ELIGIBILITY_MAX_CHARS: usize = 12_000;
+const ELIGIBILITY_PART_CHARS: usize = 12_000;
 
 #[derive(Debug, Clone, Copy, Default)]
 struct TrialSections {
@@ -21,6 +23,8 @@ struct TrialSections {
     include_outcomes: bool,
     include_arms: bool,
     include_references: bool,
+    include_publications: bool,
+    include_results: bool,
 }
 
 fn parse_sections(sections: &[String]) -> Result<TrialSections, BioMcpError> {
@@ -41,6 +45,8 @@ fn parse_sections(sections: &[String]) -> Result<TrialSections, BioMcpError> {
             TRIAL_SECTION_OUTCOMES => out.include_outcomes = true,
             TRIAL_SECTION_ARMS => out.include_arms = true,
             TRIAL_SECTION_REFERENCES => out.include_references = true,
+            TRIAL_SECTION_PUBLICATIONS => out.include_publications = true,
+            TRIAL_SECTION_RESULTS => out.include_results = true,
             TRIAL_SECTION_ALL => include_all = true,
             _ => {
                 return Err(BioMcpError::InvalidArgument(format!(
@@ -57,18 +63,60 @@ fn parse_sections(sections: &[String]) -> Result<TrialSections, BioMcpError> {
         out.include_outcomes = true;
         out.include_arms = true;
         out.include_references = true;
+        out.include_publications = true;
+        out.include_results = true;
     }
 
     Ok(out)
 }
 
-fn truncate_inline_text(value: &str, max_chars: usize) -> String {
-    let count = value.chars().count();
-    if count <= max_chars {
-        return value.to_string();
+/// Splits `value` into parts of at most `max_chars`, breaking after a newline when one is available.
+fn split_text_parts(value: &str, max_chars: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut rest = value;
+    while rest.chars().count() > max_chars {
+        let hard_end = rest
+            .char_indices()
+            .nth(max_chars)
+            .map_or(rest.len(), |(idx, _)| idx);
+        let end = rest[..hard_end]
+            .rfind('\n')
+            .filter(|idx| *idx > 0)
+            .map_or(hard_end, |idx| idx + 1);
+        parts.push(rest[..end].trim_end().to_string());
+        rest = rest[end..].trim_start_matches('\n');
+    }
+    if !rest.is_empty() || parts.is_empty() {
+        parts.push(rest.to_string());
+    }
+    parts
+}
+
+/// Returns one part of long eligibility text with a continuation command for the next part.
+fn eligibility_part(nct_id: &str, criteria: &str, offset: usize) -> Result<String, BioMcpError> {
+    let parts = split_text_parts(criteria, ELIGIBILITY_PART_CHARS);
+    let total = parts.len();
+    let Some(part) = parts.into_iter().nth(offset) else {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--offset {offset} is past the last eligibility part for {nct_id} (parts 0-{})",
+            total - 1
+        )));
+    };
+    if total == 1 {
+        return Ok(part);
+    }
+
+    let count = criteria.chars().count();
+    let position = offset + 1;
+    if position < total {
+        Ok(format!(
+            "{part}\n\n(part {position} of {total}, {count} chars total; continue with `biomcp get trial {nct_id} eligibility --offset {position}`)"
+        ))
+    } else {
+        Ok(format!(
+            "{part}\n\n(part {position} of {total}, {count} chars total)"
+        ))
     }
-    let truncated = value.chars().take(max_chars).collect::<String>();
-    format!("{truncated}\n\n(truncated, {count} chars total)")
 }
 
 fn looks_like_nct_id(value: &str) -> bool {
@@ -92,11 +140,42 @@ fn normalize_nct_id(value: &str) -> String {
     trimmed.to_string()
 }
 
+fn ctis_get_unsupported() -> BioMcpError {
+    BioMcpError::InvalidArgument(
+        "get trial supports --source ctgov or nci. Find EU trials with `biomcp search trial --source ctis` and open them at https://euclinicaltrials.eu/search-for-clinical-trials/".into(),
+    )
+}
+
+/// Publications reuse the registry references, which are dropped again unless requested.
+async fn attach_publications(trial: &mut Trial, section_flags: TrialSections) {
+    if !section_flags.include_publications {
+        return;
+    }
+    let references = trial.references.clone().unwrap_or_default();
+    trial.publications = Some(publications::fetch(&trial.nct_id, &references).await);
+    if !section_flags.include_references {
+        trial.references = None;
+    }
+}
+
 pub async fn get(
     nct_id: &str,
     sections: &[String],
     source: TrialSource,
 ) -> Result<Trial, BioMcpError> {
+    get_with_eligibility_offset(nct_id, sections, source, 0).await
+}
+
+/// Same as [`get`], but returns the `eligibility_offset`-th part of long eligibility text.
+pub async fn get_with_eligibility_offset(
+    nct_id: &str,
+    sections: &[String],
+    source: TrialSource,
+    eligibility_offset: usize,
+) -> Result<Trial, BioMcpError> {
+    if matches!(source, TrialSource::Ctis) {
+        return Err(ctis_get_unsupported());
+    }
     let nct_id = normalize_nct_id(nct_id);
     let nct_id = nct_id.trim();
     if nct_id.is_empty() {
@@ -133,14 +212,18 @@ pub async fn get(
 
                 if let Some(criteria) = criteria {
                     trial.eligibility_text =
-                        Some(truncate_inline_text(criteria, ELIGIBILITY_MAX_CHARS));
+                        Some(eligibility_part(nct_id, criteria, eligibility_offset)?);
                 }
             }
             if section_flags.include_references && trial.references.is_none() {
                 trial.references = Some(Vec::new());
             }
+            if section_flags.include_results && trial.results.is_none() {
+                trial.results = Some(TrialResults::default());
+            }
+            attach_publications(&mut trial, section_flags).await;
 
-            Ok(trial)
+            transform::fields::apply("trial", trial)
         }
         TrialSource::NciCts => {
             let client = NciCtsClient::new()?;
@@ -157,7 +240,7 @@ pub async fn get(
                     .filter(|s| !s.is_empty());
                 if let Some(criteria) = criteria {
                     trial.eligibility_text =
-                        Some(truncate_inline_text(criteria, ELIGIBILITY_MAX_CHARS));
+                        Some(eligibility_part(nct_id, criteria, eligibility_offset)?);
                 } else {
                     warn!(nct_id, "NCI CTS eligibility criteria not found in response");
                 }
@@ -165,9 +248,17 @@ pub async fn get(
             if section_flags.include_references && trial.references.is_none() {
                 trial.references = Some(Vec::new());
             }
+            if section_flags.include_results {
+                warn!(
+                    nct_id,
+                    "NCI CTS does not carry posted results; use --source ctgov"
+                );
+            }
+            attach_publications(&mut trial, section_flags).await;
 
-            Ok(trial)
+            transform::fields::apply("trial", trial)
         }
+        TrialSource::Ctis => Err(ctis_get_unsupported()),
     }
 }
 
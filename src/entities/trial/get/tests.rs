@@ -23,3 +23,90 @@ async fn get_rejects_non_nct_id_with_format_hint() {
         other => panic!("expected InvalidArgument, got: {other}"),
     }
 }
+
+#[test]
+fn split_text_parts_breaks_on_line_boundaries() {
+    let text = "Inclusion:\n- adult\n- ECOG 0-1\nExclusion:\n- pregnancy";
+    let parts = split_text_parts(text, 20);
+    assert_eq!(
+        parts,
+        vec![
+            "Inclusion:\n- adult",
+            "- ECOG 0-1",
+            "Exclusion:",
+            "- pregnancy"
+        ]
+    );
+    assert_eq!(split_text_parts("abcdef", 4), vec!["abcd", "ef"]);
+    assert_eq!(split_text_parts("short", 20), vec!["short"]);
+}
+
+#[test]
+fn eligibility_part_adds_continuation_command_until_last_part() {
+    let criteria = "x".repeat(ELIGIBILITY_PART_CHARS + 10);
+
+    let first = eligibility_part("NCT02576665", &criteria, 0).expect("first part");
+    assert!(first.contains("(part 1 of 2, 12010 chars total; continue with"));
+    assert!(first.contains("`biomcp get trial NCT02576665 eligibility --offset 1`"));
+
+    let last = eligibility_part("NCT02576665", &criteria, 1).expect("last part");
+    assert!(last.starts_with("xxxxxxxxxx\n\n(part 2 of 2, 12010 chars total)"));
+
+    let err = eligibility_part("NCT02576665", &criteria, 2).expect_err("past end");
+    assert!(err.to_string().contains("parts 0-1"));
+
+    assert_eq!(
+        eligibility_part("NCT02576665", "Adults only.", 0).expect("single part"),
+        "Adults only."
+    );
+}
+
+#[test]
+fn parse_sections_includes_results_with_all() {
+    let flags = parse_sections(&["results".to_string()]).expect("results");
+    assert!(flags.include_results);
+    assert!(!flags.include_outcomes);
+
+    let flags = parse_sections(&["all".to_string()]).expect("all");
+    assert!(flags.include_results);
+}
+
+#[tokio::test]
+async fn get_results_marks_trials_without_posted_results() {
+    use super::super::test_support::{
+        Mock, MockServer, ResponseTemplate, json, lock_env, method, path, query_param, set_env_var,
+    };
+
+    let _lock = lock_env().await;
+    let server = MockServer::start().await;
+    let _base = set_env_var("BIOMCP_CTGOV_BASE", Some(&server.uri()));
+
+    Mock::given(method("GET"))
+        .and(path("/studies/NCT00000001"))
+        .and(query_param(
+            "fields",
+            "AdverseEventsModule,BaselineCharacteristicsModule,BriefSummary,BriefTitle,CompletionDate,Condition,EnrollmentCount,HasResults,InterventionName,LeadSponsorName,MaximumAge,MinimumAge,NCTId,OutcomeMeasuresModule,OverallStatus,Phase,StartDate,StudyType",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "protocolSection": {
+                "identificationModule": {"nctId": "NCT00000001", "briefTitle": "Ongoing trial"},
+                "statusModule": {"overallStatus": "RECRUITING"}
+            },
+            "hasResults": false
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let trial = get(
+        "NCT00000001",
+        &["results".to_string()],
+        TrialSource::ClinicalTrialsGov,
+    )
+    .await
+    .expect("trial");
+    let results = trial.results.expect("results section");
+    assert!(!results.posted);
+    assert!(results.baseline.is_none());
+    assert!(results.outcome_measures.is_empty());
+}
@@ -0,0 +1,186 @@
+//! Ranks recruiting ClinicalTrials.gov trials against a patient profile.
+//!
+//! Candidates come from the regular trial search with the diagnosis as the
+//! condition plus the profile's age, sex, and geo radius. Trials mentioning a
+//! profile biomarker are searched first and the pool is topped up without the
+//! biomarker terms. Each candidate's eligibility record is then checked one
+//! attribute at a time (see [`super::criteria`]) and scored.
+
+use futures::{StreamExt, stream};
+use tracing::warn;
+
+use crate::error::BioMcpError;
+use crate::sources::clinicaltrials::ClinicalTrialsClient;
+
+use super::criteria::TrialEligibility;
+use super::profile::PatientProfile;
+use super::search::search_page;
+use super::{
+    TRIAL_SECTION_ELIGIBILITY, TRIAL_SECTION_LOCATIONS, TrialMatch, TrialMatchCriterion,
+    TrialMatchStatus, TrialMatches, TrialSearchFilters, TrialSearchResult,
+};
+
+const MAX_MATCH_LIMIT: usize = 25;
+/// Candidates checked per requested match, capped by the search page size.
+const CANDIDATES_PER_MATCH: usize = 3;
+const MAX_CANDIDATES: usize = 50;
+const DETAIL_FETCH_CONCURRENCY: usize = 8;
+
+fn candidate_filters(profile: &PatientProfile) -> TrialSearchFilters {
+    let location = profile.location.as_ref();
+    let radius = location
+        .and_then(|location| location.lat.zip(location.lon).zip(location.distance))
+        .map(|((lat, lon), distance)| (lat, lon, distance));
+    TrialSearchFilters {
+        condition: Some(profile.diagnosis.clone()),
+        status: Some("recruiting".into()),
+        age: profile.age,
+        sex: profile.sex.clone(),
+        lat: radius.map(|(lat, _, _)| lat),
+        lon: radius.map(|(_, lon, _)| lon),
+        distance: radius.map(|(_, _, distance)| distance),
+        ..Default::default()
+    }
+}
+
+/// `--mutation` expression matching any profile biomarker.
+fn biomarker_query(biomarkers: &[String]) -> Option<String> {
+    let terms = biomarkers
+        .iter()
+        .map(|biomarker| biomarker.trim())
+        .filter(|biomarker| !biomarker.is_empty())
+        .collect::<Vec<_>>();
+    (!terms.is_empty()).then(|| terms.join(" OR "))
+}
+
+fn criterion_weight(criterion: &TrialMatchCriterion) -> u32 {
+    match criterion.criterion.as_str() {
+        "biomarker" => 3,
+        "diagnosis" | "age" => 2,
+        _ => 1,
+    }
+}
+
+/// Weighted share of criteria met, counting unknown criteria as half met.
+fn match_score(criteria: &[TrialMatchCriterion]) -> u8 {
+    let possible = criteria
+        .iter()
+        .map(|c| 2 * criterion_weight(c))
+        .sum::<u32>();
+    if possible == 0 {
+        return 0;
+    }
+    let earned = criteria
+        .iter()
+        .map(|c| match c.status {
+            TrialMatchStatus::Met => 2 * criterion_weight(c),
+            TrialMatchStatus::Unknown => criterion_weight(c),
+            TrialMatchStatus::NotMet => 0,
+        })
+        .sum::<u32>();
+    ((earned * 100 + possible / 2) / possible) as u8
+}
+
+fn score_trial(result: &TrialSearchResult, criteria: Vec<TrialMatchCriterion>) -> TrialMatch {
+    let eligible = !criteria
+        .iter()
+        .any(|c| c.status == TrialMatchStatus::NotMet && c.criterion != "location");
+    TrialMatch {
+        nct_id: result.nct_id.clone(),
+        title: result.title.clone(),
+        status: result.status.clone(),
+        phase: result.phase.clone(),
+        conditions: result.conditions.clone(),
+        score: match_score(&criteria),
+        eligible,
+        criteria,
+    }
+}
+
+/// Eligible trials first, then by score. Ties keep search order.
+fn rank_matches(matches: &mut [TrialMatch]) {
+    matches.sort_by(|a, b| b.eligible.cmp(&a.eligible).then(b.score.cmp(&a.score)));
+}
+
+async fn candidate_trials(
+    profile: &PatientProfile,
+    pool: usize,
+) -> Result<Vec<TrialSearchResult>, BioMcpError> {
+    let filters = candidate_filters(profile);
+    let mut candidates = Vec::new();
+    if let Some(mutation) = biomarker_query(&profile.biomarkers) {
+        let biomarker_filters = TrialSearchFilters {
+            mutation: Some(mutation),
+            ..filters.clone()
+        };
+        candidates = search_page(&biomarker_filters, pool, 0, None)
+            .await?
+            .results;
+    }
+    if candidates.len() < pool {
+        for result in search_page(&filters, pool, 0, None).await?.results {
+            if candidates.len() >= pool {
+                break;
+            }
+            if !candidates.iter().any(|seen| seen.nct_id == result.nct_id) {
+                candidates.push(result);
+            }
+        }
+    }
+    Ok(candidates)
+}
+
+/// Searches recruiting ClinicalTrials.gov trials for the profile's diagnosis
+/// and ranks up to `limit` of them by how well their eligibility fits.
+pub async fn match_trials(
+    profile: &PatientProfile,
+    limit: usize,
+) -> Result<TrialMatches, BioMcpError> {
+    if limit == 0 || limit > MAX_MATCH_LIMIT {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "--limit must be between 1 and {MAX_MATCH_LIMIT}"
+        )));
+    }
+
+    let pool = (limit * CANDIDATES_PER_MATCH).min(MAX_CANDIDATES);
+    let candidates = candidate_trials(profile, pool).await?;
+    let client = ClinicalTrialsClient::new()?;
+    let sections = [
+        TRIAL_SECTION_ELIGIBILITY.to_string(),
+        TRIAL_SECTION_LOCATIONS.to_string(),
+    ];
+
+    let mut matches = stream::iter(candidates.iter().map(|candidate| {
+        let client = &client;
+        let sections = &sections;
+        async move {
+            let study = match client.get(&candidate.nct_id, sections).await {
+                Ok(study) => Some(study),
+                Err(err) => {
+                    warn!(
+                        nct_id = %candidate.nct_id,
+                        error = %err,
+                        "trial match detail fetch failed, scoring from search row"
+                    );
+                    None
+                }
+            };
+            let criteria = TrialEligibility::new(candidate, study.as_ref()).evaluate(profile);
+            score_trial(candidate, criteria)
+        }
+    }))
+    .buffered(DETAIL_FETCH_CONCURRENCY)
+    .collect::<Vec<_>>()
+    .await;
+
+    rank_matches(&mut matches);
+    matches.truncate(limit);
+    Ok(TrialMatches {
+        profile: profile.clone(),
+        candidates_scanned: candidates.len(),
+        matches,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,241 @@
+//! Tests for ranking trials against a patient profile.
+
+use super::super::test_support::*;
+use super::*;
+
+fn criterion(name: &str, status: TrialMatchStatus) -> TrialMatchCriterion {
+    TrialMatchCriterion {
+        criterion: name.into(),
+        value: "x".into(),
+        status,
+        explanation: String::new(),
+    }
+}
+
+fn row(nct_id: &str) -> TrialSearchResult {
+    TrialSearchResult {
+        nct_id: nct_id.into(),
+        title: format!("Trial {nct_id}"),
+        status: "RECRUITING".into(),
+        phase: None,
+        conditions: vec!["Non-small Cell Lung Cancer".into()],
+        sponsor: None,
+        registry: None,
+        cross_registered_nct: None,
+        eligibility_unchecked: Vec::new(),
+    }
+}
+
+fn search_study(nct_id: &str) -> serde_json::Value {
+    json!({
+        "protocolSection": {
+            "identificationModule": {"nctId": nct_id, "briefTitle": format!("Trial {nct_id}")},
+            "statusModule": {"overallStatus": "RECRUITING"},
+            "conditionsModule": {"conditions": ["Non-small Cell Lung Cancer"]},
+            "eligibilityModule": {"minimumAge": "18 Years", "maximumAge": "80 Years"}
+        }
+    })
+}
+
+fn detail_study(nct_id: &str, sex: &str, criteria: &str) -> serde_json::Value {
+    json!({
+        "protocolSection": {
+            "identificationModule": {"nctId": nct_id, "briefTitle": format!("Trial {nct_id}")},
+            "conditionsModule": {"conditions": ["Non-small Cell Lung Cancer"]},
+            "eligibilityModule": {
+                "eligibilityCriteria": criteria,
+                "minimumAge": "18 Years",
+                "maximumAge": "80 Years",
+                "sex": sex
+            }
+        }
+    })
+}
+
+#[test]
+fn match_score_weights_biomarkers_and_half_counts_unknowns() {
+    assert_eq!(match_score(&[]), 0);
+    assert_eq!(
+        match_score(&[
+            criterion("diagnosis", TrialMatchStatus::Met),
+            criterion("biomarker", TrialMatchStatus::Met),
+        ]),
+        100
+    );
+    // 2 (diagnosis) + 3/2 (unknown biomarker) of 5.
+    assert_eq!(
+        match_score(&[
+            criterion("diagnosis", TrialMatchStatus::Met),
+            criterion("biomarker", TrialMatchStatus::Unknown),
+        ]),
+        70
+    );
+    assert_eq!(
+        match_score(&[
+            criterion("diagnosis", TrialMatchStatus::Met),
+            criterion("sex", TrialMatchStatus::NotMet),
+        ]),
+        67
+    );
+}
+
+#[test]
+fn rank_matches_puts_eligible_trials_first_and_ignores_location_for_eligibility() {
+    let mut matches = vec![
+        score_trial(
+            &row("NCT1"),
+            vec![
+                criterion("diagnosis", TrialMatchStatus::Met),
+                criterion("age", TrialMatchStatus::NotMet),
+            ],
+        ),
+        score_trial(
+            &row("NCT2"),
+            vec![
+                criterion("diagnosis", TrialMatchStatus::Unknown),
+                criterion("location", TrialMatchStatus::NotMet),
+            ],
+        ),
+        score_trial(
+            &row("NCT3"),
+            vec![
+                criterion("diagnosis", TrialMatchStatus::Met),
+                criterion("location", TrialMatchStatus::Met),
+            ],
+        ),
+        score_trial(
+            &row("NCT4"),
+            vec![
+                criterion("diagnosis", TrialMatchStatus::Met),
+                criterion("location", TrialMatchStatus::Met),
+            ],
+        ),
+    ];
+    assert!(!matches[0].eligible);
+    assert!(matches[1].eligible);
+
+    rank_matches(&mut matches);
+    let order = matches
+        .iter()
+        .map(|m| m.nct_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(order, vec!["NCT3", "NCT4", "NCT2", "NCT1"]);
+}
+
+#[test]
+fn candidate_filters_search_recruiting_trials_and_only_pass_a_full_radius() {
+    let profile = PatientProfile::parse(
+        "diagnosis: melanoma\nage: 40\nsex: male\nbiomarkers: [BRAF V600E, '', NRAS Q61K]\n\
+         location:\n  lat: 42.36\n  lon: -71.06",
+    )
+    .expect("profile");
+    let filters = candidate_filters(&profile);
+    assert_eq!(filters.condition.as_deref(), Some("melanoma"));
+    assert_eq!(filters.status.as_deref(), Some("recruiting"));
+    assert_eq!(filters.age, Some(40.0));
+    assert_eq!(filters.sex.as_deref(), Some("male"));
+    assert_eq!(
+        (filters.lat, filters.lon, filters.distance),
+        (None, None, None)
+    );
+    assert_eq!(
+        biomarker_query(&profile.biomarkers).as_deref(),
+        Some("BRAF V600E OR NRAS Q61K")
+    );
+
+    let radius = PatientProfile::parse(
+        "diagnosis: melanoma\nlocation:\n  lat: 42.36\n  lon: -71.06\n  distance: 50",
+    )
+    .expect("profile");
+    let filters = candidate_filters(&radius);
+    assert_eq!(
+        (filters.lat, filters.lon, filters.distance),
+        (Some(42.36), Some(-71.06), Some(50))
+    );
+    assert_eq!(biomarker_query(&radius.biomarkers), None);
+}
+
+#[tokio::test]
+async fn match_trials_rejects_out_of_range_limit() {
+    let profile = PatientProfile::parse("diagnosis: melanoma").expect("profile");
+    for limit in [0, MAX_MATCH_LIMIT + 1] {
+        let err = match_trials(&profile, limit).await.expect_err("limit");
+        assert!(err.to_string().contains("--limit must be between 1 and 25"));
+    }
+}
+
+#[tokio::test]
+async fn match_trials_ranks_biomarker_candidates_and_scores_failed_fetches_from_search_rows() {
+    let _lock = lock_env().await;
+    let server = MockServer::start().await;
+    let _base = set_env_var("BIOMCP_CTGOV_BASE", Some(&server.uri()));
+
+    Mock::given(method("GET"))
+        .and(path("/studies"))
+        .and(query_param("query.cond", "non-small cell lung cancer"))
+        .and(query_param("filter.overallStatus", "RECRUITING"))
+        .and(query_param_is_missing("query.term"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "studies": [search_study("NCT00000002"), search_study("NCT00000001"), search_study("NCT00000003")],
+            "nextPageToken": null
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/studies"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "studies": [search_study("NCT00000001")],
+            "nextPageToken": null
+        })))
+        .with_priority(10)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/studies/NCT00000001"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(detail_study(
+            "NCT00000001",
+            "ALL",
+            "Inclusion Criteria:\n* EGFR L858R or exon 19 deletion\n\nExclusion Criteria:\n* Prior osimertinib",
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/studies/NCT00000002"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(detail_study(
+            "NCT00000002",
+            "MALE",
+            "Inclusion Criteria:\n* Stage IV NSCLC",
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/studies/NCT00000003"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let profile = PatientProfile::parse(
+        "age: 62\nsex: female\ndiagnosis: non-small cell lung cancer\nbiomarkers:\n  - EGFR L858R",
+    )
+    .expect("profile");
+    let result = match_trials(&profile, 2).await.expect("matches");
+
+    assert_eq!(result.candidates_scanned, 3);
+    let order = result
+        .matches
+        .iter()
+        .map(|m| m.nct_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(order, vec!["NCT00000001", "NCT00000003"]);
+    assert_eq!(result.matches[0].score, 100);
+    assert!(result.matches[0].eligible);
+    let biomarker = &result.matches[0].criteria[3];
+    assert_eq!(biomarker.value, "EGFR L858R");
+    assert_eq!(biomarker.status, TrialMatchStatus::Met);
+    assert_eq!(
+        result.matches[1].criteria[2].explanation,
+        "sex eligibility not stated"
+    );
+}
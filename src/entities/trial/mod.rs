@@ -1,18 +1,31 @@
 //! Trial entity models and workflows exposed through the stable trial facade.
 
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::error::BioMcpError;
 
+mod criteria;
 mod get;
+mod matching;
+mod profile;
+mod publications;
+mod registry;
 mod search;
+mod summary;
 #[cfg(test)]
 mod test_support;
 
-pub use self::get::get;
-pub use self::search::{count_all, search, search_page};
+pub use self::get::{get, get_with_eligibility_offset};
+pub use self::matching::match_trials;
+pub use self::profile::{PatientLocation, PatientProfile};
+pub use self::registry::{TrialRegistry, normalize_registry_id};
+pub use self::search::{condition_synonyms, count_all, search, search_page};
+pub use self::summary::summarize;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Trial {
     pub nct_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -49,9 +62,13 @@ pub struct Trial {
     pub arms: Option<Vec<TrialArm>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub references: Option<Vec<TrialReference>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publications: Option<Vec<TrialPublication>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results: Option<TrialResults>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TrialLocation {
     pub facility: String,
     pub city: String,
@@ -66,7 +83,7 @@ pub struct TrialLocation {
     pub contact_phone: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TrialOutcomes {
     #[serde(default)]
     pub primary: Vec<TrialOutcome>,
@@ -74,7 +91,7 @@ pub struct TrialOutcomes {
     pub secondary: Vec<TrialOutcome>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TrialOutcome {
     pub measure: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -83,7 +100,7 @@ pub struct TrialOutcome {
     pub time_frame: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TrialArm {
     pub label: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -94,7 +111,7 @@ pub struct TrialArm {
     pub interventions: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TrialReference {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pmid: Option<String>,
@@ -103,7 +120,178 @@ pub struct TrialReference {
     pub reference_type: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Results posted to ClinicalTrials.gov after the trial completed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct TrialResults {
+    /// Whether the registry record carries posted results.
+    pub posted: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<TrialBaseline>,
+    #[serde(default)]
+    pub outcome_measures: Vec<TrialOutcomeResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adverse_events: Option<TrialAdverseEvents>,
+}
+
+/// Arm or comparison group a results table reports on.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrialResultGroup {
+    pub id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// One table row; `values` line up with the table's groups.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrialResultRow {
+    /// Class and category titles, empty for a single overall row.
+    pub label: String,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrialBaseline {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub population: Option<String>,
+    pub groups: Vec<TrialResultGroup>,
+    /// Participants analyzed per group.
+    #[serde(default)]
+    pub participants: Vec<String>,
+    #[serde(default)]
+    pub measures: Vec<TrialBaselineMeasure>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrialBaselineMeasure {
+    pub title: String,
+    /// Statistic and unit, e.g. `Mean (Standard Deviation), years`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statistic: Option<String>,
+    pub rows: Vec<TrialResultRow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrialOutcomeResult {
+    /// `PRIMARY`, `SECONDARY`, `OTHER_PRE_SPECIFIED`, or `POST_HOC`.
+    pub outcome_type: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_frame: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statistic: Option<String>,
+    pub groups: Vec<TrialResultGroup>,
+    #[serde(default)]
+    pub participants: Vec<String>,
+    #[serde(default)]
+    pub rows: Vec<TrialResultRow>,
+    #[serde(default)]
+    pub analyses: Vec<TrialOutcomeAnalysis>,
+}
+
+/// Between-group comparison with its effect estimate.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrialOutcomeAnalysis {
+    /// Titles of the compared groups.
+    pub groups: Vec<String>,
+    /// Effect measure, e.g. `Hazard Ratio (HR)`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ci_pct: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ci_lower: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ci_upper: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrialAdverseEvents {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_frame: Option<String>,
+    /// Percent frequency above which non-serious events were reported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_threshold: Option<String>,
+    pub groups: Vec<TrialAdverseEventGroup>,
+    /// Most frequent serious events; each value is `affected/at risk` for the group.
+    #[serde(default)]
+    pub serious: Vec<TrialAdverseEvent>,
+    #[serde(default)]
+    pub serious_omitted: usize,
+    #[serde(default)]
+    pub other: Vec<TrialAdverseEvent>,
+    #[serde(default)]
+    pub other_omitted: usize,
+}
+
+/// Participants affected out of those at risk, as `affected/at risk`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrialAdverseEventGroup {
+    pub id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deaths: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serious: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrialAdverseEvent {
+    pub term: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organ_system: Option<String>,
+    pub values: Vec<String>,
+}
+
+/// Role a linked paper plays for the trial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TrialPublicationKind {
+    Results,
+    Protocol,
+    Background,
+    Related,
+}
+
+impl TrialPublicationKind {
+    fn rank(self) -> u8 {
+        match self {
+            Self::Results => 0,
+            Self::Protocol => 1,
+            Self::Background => 2,
+            Self::Related => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrialPublication {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pmid: Option<String>,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub journal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    pub kind: TrialPublicationKind,
+    /// ClinicalTrials.gov reference type (`RESULT`, `BACKGROUND`, `DERIVED`) when registry-linked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_type: Option<String>,
+    #[serde(default)]
+    pub found_via: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TrialSearchResult {
     pub nct_id: String,
     pub title: String,
@@ -114,11 +302,23 @@ pub struct TrialSearchResult {
     pub conditions: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sponsor: Option<String>,
+    /// Issuing registry when `nct_id` holds a non-ClinicalTrials.gov number (e.g. `CTIS`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry: Option<String>,
+    /// ClinicalTrials.gov record for the same trial when it is cross-registered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cross_registered_nct: Option<String>,
+    /// Requested eligibility filters (`age`, `ECOG`) this trial's record did
+    /// not state in a machine-checkable form; such trials are kept.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub eligibility_unchecked: Vec<String>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct TrialSearchFilters {
     pub condition: Option<String>,
+    /// Thesaurus synonyms OR-ed with `condition` in CTGov queries.
+    pub condition_synonyms: Vec<String>,
     pub intervention: Option<String>,
     pub facility: Option<String>,
     pub status: Option<String>,
@@ -126,6 +326,8 @@ pub struct TrialSearchFilters {
     pub study_type: Option<String>,
     pub age: Option<f32>,
     pub sex: Option<String>,
+    /// Patient ECOG performance status (0-4), checked against the inclusion criteria text.
+    pub ecog: Option<u8>,
     pub sponsor: Option<String>,
     pub sponsor_type: Option<String>,
     pub date_from: Option<String>,
@@ -148,6 +350,7 @@ pub enum TrialSource {
     #[default]
     ClinicalTrialsGov,
     NciCts,
+    Ctis,
 }
 
 impl TrialSource {
@@ -155,8 +358,9 @@ impl TrialSource {
         match value.trim().to_ascii_lowercase().as_str() {
             "" | "ctgov" | "clinicaltrials" | "clinicaltrials.gov" => Ok(Self::ClinicalTrialsGov),
             "nci" | "nci_cts" | "cts" => Ok(Self::NciCts),
+            "ctis" | "eu" | "euct" => Ok(Self::Ctis),
             other => Err(BioMcpError::InvalidArgument(format!(
-                "Unknown --source '{other}'. Expected 'ctgov' or 'nci'."
+                "Unknown --source '{other}'. Expected 'ctgov', 'nci', or 'ctis'."
             ))),
         }
     }
@@ -167,6 +371,8 @@ const TRIAL_SECTION_LOCATIONS: &str = "locations";
 const TRIAL_SECTION_OUTCOMES: &str = "outcomes";
 const TRIAL_SECTION_ARMS: &str = "arms";
 const TRIAL_SECTION_REFERENCES: &str = "references";
+const TRIAL_SECTION_PUBLICATIONS: &str = "publications";
+const TRIAL_SECTION_RESULTS: &str = "results";
 const TRIAL_SECTION_ALL: &str = "all";
 
 pub const TRIAL_SECTION_NAMES: &[&str] = &[
@@ -175,9 +381,68 @@ pub const TRIAL_SECTION_NAMES: &[&str] = &[
     TRIAL_SECTION_OUTCOMES,
     TRIAL_SECTION_ARMS,
     TRIAL_SECTION_REFERENCES,
+    TRIAL_SECTION_PUBLICATIONS,
+    TRIAL_SECTION_RESULTS,
     TRIAL_SECTION_ALL,
 ];
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrialSummaryBy {
+    Condition,
+    Phase,
+    Status,
+    Sponsor,
+}
+
+impl TrialSummaryBy {
+    pub fn from_flag(value: &str) -> Result<Self, BioMcpError> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "condition" => Ok(Self::Condition),
+            "phase" => Ok(Self::Phase),
+            "status" => Ok(Self::Status),
+            "sponsor" => Ok(Self::Sponsor),
+            other => Err(BioMcpError::InvalidArgument(format!(
+                "Unknown --summarize-by '{other}'. Expected one of: condition, phase, status, sponsor."
+            ))),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Condition => "condition",
+            Self::Phase => "phase",
+            Self::Status => "status",
+            Self::Sponsor => "sponsor",
+        }
+    }
+}
+
+/// Portfolio counts over the trials matching a search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrialSummary {
+    pub summarize_by: TrialSummaryBy,
+    /// Matching trials reported by the source, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<usize>,
+    pub trials_scanned: usize,
+    /// More trials matched than were scanned, so counts cover a prefix.
+    pub truncated: bool,
+    /// Groups before `--limit` was applied.
+    pub group_count: usize,
+    /// Phase labels seen across the scanned trials, earliest phase first.
+    pub phases: Vec<String>,
+    pub groups: Vec<TrialSummaryGroup>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrialSummaryGroup {
+    pub key: String,
+    pub trials: usize,
+    /// Trial counts keyed by phase label.
+    pub phases: BTreeMap<String, usize>,
+}
+
 /// Describes the precision of a trial `--count-only` result.
 #[derive(Debug, PartialEq)]
 pub enum TrialCount {
@@ -188,3 +453,49 @@ pub enum TrialCount {
     /// Traversal cap was hit, so the exact total is unknown.
     Unknown,
 }
+
+/// How a trial's eligibility record compares with one profile attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrialMatchStatus {
+    Met,
+    NotMet,
+    /// The record does not state the requirement in a readable form.
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrialMatchCriterion {
+    /// `diagnosis`, `age`, `sex`, `ecog`, `biomarker`, or `location`.
+    pub criterion: String,
+    /// Profile value that was checked.
+    pub value: String,
+    pub status: TrialMatchStatus,
+    pub explanation: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrialMatch {
+    pub nct_id: String,
+    pub title: String,
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+    #[serde(default)]
+    pub conditions: Vec<String>,
+    /// 0-100: met criteria count in full and unknown ones by half, weighted
+    /// toward diagnosis, age, and biomarkers.
+    pub score: u8,
+    /// No criterion other than location is unmet.
+    pub eligible: bool,
+    pub criteria: Vec<TrialMatchCriterion>,
+}
+
+/// Recruiting trials ranked against a patient profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrialMatches {
+    pub profile: PatientProfile,
+    /// Search results whose eligibility records were checked.
+    pub candidates_scanned: usize,
+    pub matches: Vec<TrialMatch>,
+}
@@ -0,0 +1,335 @@
+//! Patient profiles read by `match trials`.
+//!
+//! Profiles are short YAML files. Only the subset a profile needs is
+//! understood: `key: value` scalars, block (`- item`) and inline (`[a, b]`)
+//! lists, and one nested mapping level for `location`. `#` starts a comment.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+
+const PROFILE_KEYS: &str = "age, sex, diagnosis, biomarkers, ecog, location";
+const LOCATION_KEYS: &str = "city, state, country, lat, lon, distance";
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PatientProfile {
+    /// Age in years.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age: Option<f32>,
+    /// `female` or `male`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sex: Option<String>,
+    pub diagnosis: String,
+    #[serde(default)]
+    pub biomarkers: Vec<String>,
+    /// ECOG performance status (0-4).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ecog: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<PatientLocation>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PatientLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lat: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lon: Option<f64>,
+    /// Travel radius in miles around `lat`/`lon`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ProfileValue {
+    Scalar(String),
+    List(Vec<String>),
+    Map(Vec<(String, String)>),
+}
+
+fn profile_error(message: impl Into<String>) -> BioMcpError {
+    BioMcpError::InvalidArgument(format!("Invalid patient profile: {}", message.into()))
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut prev = ' ';
+    for (idx, ch) in line.char_indices() {
+        match (quote, ch) {
+            (None, '"' | '\'') => quote = Some(ch),
+            (Some(open), _) if ch == open => quote = None,
+            (None, '#') if prev.is_whitespace() => return &line[..idx],
+            _ => {}
+        }
+        prev = ch;
+    }
+    line
+}
+
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner.to_string();
+        }
+    }
+    value.to_string()
+}
+
+fn parse_inline_value(value: &str) -> ProfileValue {
+    match value
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+    {
+        Some(items) => ProfileValue::List(
+            items
+                .split(',')
+                .map(unquote)
+                .filter(|item| !item.is_empty())
+                .collect(),
+        ),
+        None => ProfileValue::Scalar(unquote(value)),
+    }
+}
+
+fn split_key_value(line: &str, line_no: usize) -> Result<(String, &str), BioMcpError> {
+    let Some((key, value)) = line.split_once(':') else {
+        return Err(profile_error(format!(
+            "line {line_no}: expected `key: value`, got `{}`",
+            line.trim()
+        )));
+    };
+    let key = unquote(key).to_ascii_lowercase();
+    if key.is_empty() {
+        return Err(profile_error(format!("line {line_no}: missing key")));
+    }
+    Ok((key, value.trim()))
+}
+
+/// Reads the top-level keys of a profile document in file order.
+fn parse_document(text: &str) -> Result<Vec<(String, ProfileValue)>, BioMcpError> {
+    let mut entries: Vec<(String, ProfileValue)> = Vec::new();
+    let mut open_block: Option<String> = None;
+
+    for (idx, raw) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = strip_comment(raw).trim_end();
+        if line.trim().is_empty() || line.trim() == "---" {
+            continue;
+        }
+
+        if !line.starts_with([' ', '\t']) {
+            let (key, value) = split_key_value(line, line_no)?;
+            if entries.iter().any(|(seen, _)| *seen == key) {
+                return Err(profile_error(format!(
+                    "line {line_no}: `{key}` is set more than once"
+                )));
+            }
+            if value.is_empty() {
+                open_block = Some(key.clone());
+                entries.push((key, ProfileValue::List(Vec::new())));
+            } else {
+                open_block = None;
+                entries.push((key, parse_inline_value(value)));
+            }
+            continue;
+        }
+
+        let (Some(key), Some((_, block))) = (open_block.as_deref(), entries.last_mut()) else {
+            return Err(profile_error(format!(
+                "line {line_no}: indented line without a parent key"
+            )));
+        };
+        let mixed = || {
+            profile_error(format!(
+                "line {line_no}: `{key}` mixes list items and `key: value` entries"
+            ))
+        };
+        let item = line.trim();
+        if let Some(list_item) = item.strip_prefix('-') {
+            let ProfileValue::List(items) = block else {
+                return Err(mixed());
+            };
+            let list_item = unquote(list_item);
+            if !list_item.is_empty() {
+                items.push(list_item);
+            }
+            continue;
+        }
+
+        let (sub_key, value) = split_key_value(item, line_no)?;
+        if matches!(block, ProfileValue::List(items) if items.is_empty()) {
+            *block = ProfileValue::Map(Vec::new());
+        }
+        let ProfileValue::Map(fields) = block else {
+            return Err(mixed());
+        };
+        fields.push((sub_key, unquote(value)));
+    }
+
+    Ok(entries)
+}
+
+fn scalar(key: &str, value: ProfileValue) -> Result<String, BioMcpError> {
+    match value {
+        ProfileValue::Scalar(value) => Ok(value),
+        _ => Err(profile_error(format!("`{key}` must be a single value"))),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(key: &str, value: &str) -> Result<T, BioMcpError> {
+    value
+        .trim()
+        .parse::<T>()
+        .map_err(|_| profile_error(format!("`{key}` must be a number, got `{value}`")))
+}
+
+fn parse_age(value: &str) -> Result<f32, BioMcpError> {
+    let mut parts = value.split_whitespace();
+    let amount = parts.next().unwrap_or_default();
+    if parts
+        .next()
+        .is_some_and(|unit| !matches!(unit.to_ascii_lowercase().as_str(), "year" | "years"))
+    {
+        return Err(profile_error(format!(
+            "`age` must be in years (e.g. `62`), got `{value}`"
+        )));
+    }
+    let age = parse_number::<f32>("age", amount)?;
+    if !(0.0..=120.0).contains(&age) {
+        return Err(profile_error(format!(
+            "`age` must be between 0 and 120, got `{value}`"
+        )));
+    }
+    Ok(age)
+}
+
+fn parse_sex(value: &str) -> Result<Option<String>, BioMcpError> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "female" | "f" | "woman" => Ok(Some("female".into())),
+        "male" | "m" | "man" => Ok(Some("male".into())),
+        "" | "all" | "any" => Ok(None),
+        other => Err(profile_error(format!(
+            "`sex` must be female or male, got `{other}`"
+        ))),
+    }
+}
+
+fn parse_location(value: ProfileValue) -> Result<PatientLocation, BioMcpError> {
+    let ProfileValue::Map(fields) = value else {
+        return Err(profile_error(format!(
+            "`location` must be a mapping with any of: {LOCATION_KEYS}"
+        )));
+    };
+
+    let mut location = PatientLocation::default();
+    for (key, value) in fields {
+        let text = Some(value.trim().to_string()).filter(|value| !value.is_empty());
+        match key.as_str() {
+            "city" => location.city = text,
+            "state" => location.state = text,
+            "country" => location.country = text,
+            "lat" | "latitude" => location.lat = Some(parse_number("location.lat", &value)?),
+            "lon" | "lng" | "longitude" => {
+                location.lon = Some(parse_number("location.lon", &value)?)
+            }
+            "distance" => {
+                let miles = value.trim().trim_end_matches("mi").trim();
+                location.distance = Some(parse_number("location.distance", miles)?);
+            }
+            other => {
+                return Err(profile_error(format!(
+                    "unknown `location` key `{other}`; expected one of: {LOCATION_KEYS}"
+                )));
+            }
+        }
+    }
+
+    if location.lat.is_some() != location.lon.is_some() {
+        return Err(profile_error(
+            "`location.lat` and `location.lon` go together",
+        ));
+    }
+    if location.distance.is_some() && location.lat.is_none() {
+        return Err(profile_error(
+            "`location.distance` needs `location.lat` and `location.lon`",
+        ));
+    }
+    if location.lat.is_none() && location.city.is_none() && location.country.is_none() {
+        return Err(profile_error(
+            "`location` needs `city`, `country`, or `lat`/`lon`",
+        ));
+    }
+    Ok(location)
+}
+
+impl PatientProfile {
+    /// Parses a YAML patient profile such as:
+    ///
+    /// ```yaml
+    /// age: 62
+    /// sex: female
+    /// diagnosis: non-small cell lung cancer
+    /// biomarkers: [EGFR L858R]
+    /// location:
+    ///   city: Boston
+    ///   country: United States
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, BioMcpError> {
+        let mut profile = Self::default();
+        for (key, value) in parse_document(text)? {
+            match key.as_str() {
+                "age" => profile.age = Some(parse_age(&scalar("age", value)?)?),
+                "sex" => profile.sex = parse_sex(&scalar("sex", value)?)?,
+                "diagnosis" | "condition" => {
+                    profile.diagnosis = scalar("diagnosis", value)?.trim().to_string()
+                }
+                "biomarkers" | "biomarker" | "mutations" => {
+                    profile.biomarkers = match value {
+                        ProfileValue::Scalar(value) => value
+                            .split(',')
+                            .map(|item| item.trim().to_string())
+                            .collect(),
+                        ProfileValue::List(items) => items,
+                        ProfileValue::Map(_) => {
+                            return Err(profile_error("`biomarkers` must be a list"));
+                        }
+                    };
+                    profile.biomarkers.retain(|item| !item.trim().is_empty());
+                }
+                "ecog" => {
+                    let ecog = parse_number::<u8>("ecog", &scalar("ecog", value)?)?;
+                    if ecog > 4 {
+                        return Err(profile_error("`ecog` must be between 0 and 4"));
+                    }
+                    profile.ecog = Some(ecog);
+                }
+                "location" => profile.location = Some(parse_location(value)?),
+                other => {
+                    return Err(profile_error(format!(
+                        "unknown key `{other}`; expected one of: {PROFILE_KEYS}"
+                    )));
+                }
+            }
+        }
+
+        if profile.diagnosis.is_empty() {
+            return Err(profile_error(
+                "`diagnosis` is required (e.g. `diagnosis: non-small cell lung cancer`)",
+            ));
+        }
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests;
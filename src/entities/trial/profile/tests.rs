@@ -0,0 +1,94 @@
+//! Tests for patient profile parsing.
+
+use super::*;
+
+#[test]
+fn parse_reads_scalars_block_lists_and_location_mapping() {
+    let profile = PatientProfile::parse(
+        r#"
+# Patient 17
+age: 62 years
+sex: F
+diagnosis: "non-small cell lung cancer"   # primary
+biomarkers:
+  - EGFR L858R
+  - 'PD-L1 #high'
+ecog: 1
+location:
+  city: Boston
+  country: United States
+  lat: 42.36
+  lon: -71.06
+  distance: 150 mi
+"#,
+    )
+    .expect("profile");
+
+    assert_eq!(profile.age, Some(62.0));
+    assert_eq!(profile.sex.as_deref(), Some("female"));
+    assert_eq!(profile.diagnosis, "non-small cell lung cancer");
+    assert_eq!(profile.biomarkers, vec!["EGFR L858R", "PD-L1 #high"]);
+    assert_eq!(profile.ecog, Some(1));
+    let location = profile.location.expect("location");
+    assert_eq!(location.city.as_deref(), Some("Boston"));
+    assert_eq!(location.country.as_deref(), Some("United States"));
+    assert_eq!(location.lat, Some(42.36));
+    assert_eq!(location.lon, Some(-71.06));
+    assert_eq!(location.distance, Some(150));
+}
+
+#[test]
+fn parse_accepts_inline_and_comma_separated_biomarkers() {
+    let inline =
+        PatientProfile::parse("diagnosis: melanoma\nbiomarkers: [BRAF V600E, \"NRAS Q61K\"]")
+            .expect("inline list");
+    assert_eq!(inline.biomarkers, vec!["BRAF V600E", "NRAS Q61K"]);
+
+    let scalar = PatientProfile::parse("condition: melanoma\nbiomarker: BRAF V600E, NRAS Q61K")
+        .expect("comma list");
+    assert_eq!(scalar.diagnosis, "melanoma");
+    assert_eq!(scalar.biomarkers, vec!["BRAF V600E", "NRAS Q61K"]);
+    assert!(scalar.location.is_none());
+}
+
+#[test]
+fn parse_requires_diagnosis() {
+    let err = PatientProfile::parse("age: 50\nsex: male").expect_err("missing diagnosis");
+    assert!(err.to_string().contains("`diagnosis` is required"));
+}
+
+#[test]
+fn parse_rejects_unknown_keys_and_bad_values() {
+    let err = PatientProfile::parse("diagnosis: melanoma\nstage: IV").expect_err("unknown key");
+    assert!(err.to_string().contains("unknown key `stage`"));
+
+    let err = PatientProfile::parse("diagnosis: melanoma\necog: 5").expect_err("ecog range");
+    assert!(err.to_string().contains("between 0 and 4"));
+
+    let err = PatientProfile::parse("diagnosis: melanoma\nage: 18 months").expect_err("age unit");
+    assert!(err.to_string().contains("in years"));
+
+    let err = PatientProfile::parse("diagnosis: melanoma\nsex: unknown").expect_err("sex");
+    assert!(err.to_string().contains("female or male"));
+
+    let err = PatientProfile::parse("diagnosis: melanoma\ndiagnosis: nsclc").expect_err("dup");
+    assert!(err.to_string().contains("more than once"));
+}
+
+#[test]
+fn parse_rejects_malformed_blocks_and_locations() {
+    let err = PatientProfile::parse("diagnosis: melanoma\nbiomarkers:\n  - BRAF\n  gene: NRAS")
+        .expect_err("mixed block");
+    assert!(err.to_string().contains("line 4"));
+
+    let err = PatientProfile::parse("  - BRAF\ndiagnosis: melanoma").expect_err("orphan");
+    assert!(err.to_string().contains("without a parent key"));
+
+    let err = PatientProfile::parse("diagnosis: melanoma\nlocation: Boston")
+        .expect_err("scalar location");
+    assert!(err.to_string().contains("must be a mapping"));
+
+    let err = PatientProfile::parse("diagnosis: melanoma\nlocation:\n  lat: 42.3\n  distance: 50")
+        .expect_err("lat without lon");
+    assert!(err.to_string().contains("go together"));
+}
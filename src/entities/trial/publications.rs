@@ -0,0 +1,177 @@
+//! Trial publication linkage from ClinicalTrials.gov references and Europe PMC.
+//!
+//! Sponsors list only some papers on the registry record, while most results
+//! papers cite the NCT ID in their abstract or methods. Merging both sources
+//! by PMID and labeling each paper lets callers jump straight to the results.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use tracing::warn;
+
+use crate::entities::article::ArticleSearchResult;
+use crate::entities::section_errors;
+use crate::error::BioMcpError;
+use crate::sources::europepmc::EuropePmcClient;
+use crate::transform;
+
+use super::{TRIAL_SECTION_PUBLICATIONS, TrialPublication, TrialPublicationKind, TrialReference};
+
+const EUROPE_PMC_PUBLICATION_LIMIT: usize = 25;
+const CTGOV_LABEL: &str = "ClinicalTrials.gov";
+const EUROPE_PMC_LABEL: &str = "Europe PMC";
+
+fn protocol_title_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?i)\b(study protocol|trial protocol|protocol for|protocol of|design and rationale|rationale and design|study design|statistical analysis plan)\b",
+        )
+        .expect("valid regex")
+    })
+}
+
+fn results_title_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?i)\b(results?|final analysis|primary analysis|interim analysis|efficacy|overall survival|progression-free survival|randomi[sz]ed|phase (1|2|3|i|ii|iii)\b|open-label)",
+        )
+        .expect("valid regex")
+    })
+}
+
+/// Labels a paper from the registry reference type, title, and publication type.
+pub(super) fn classify_publication(
+    reference_type: Option<&str>,
+    title: &str,
+    publication_type: Option<&str>,
+) -> TrialPublicationKind {
+    if protocol_title_re().is_match(title) {
+        return TrialPublicationKind::Protocol;
+    }
+    let reference_type = reference_type.map(|v| v.trim().to_ascii_uppercase());
+    match reference_type.as_deref() {
+        Some("RESULT") => return TrialPublicationKind::Results,
+        Some("BACKGROUND") => return TrialPublicationKind::Background,
+        _ => {}
+    }
+    let is_review = publication_type.is_some_and(|v| v.eq_ignore_ascii_case("Review"))
+        || publication_type.is_some_and(|v| v.eq_ignore_ascii_case("Meta-Analysis"));
+    if !is_review && results_title_re().is_match(title) {
+        return TrialPublicationKind::Results;
+    }
+    TrialPublicationKind::Related
+}
+
+fn from_reference(reference: &TrialReference) -> TrialPublication {
+    TrialPublication {
+        pmid: reference.pmid.clone(),
+        title: reference.citation.clone(),
+        journal: None,
+        date: None,
+        kind: classify_publication(
+            reference.reference_type.as_deref(),
+            &reference.citation,
+            None,
+        ),
+        reference_type: reference.reference_type.clone(),
+        found_via: vec![CTGOV_LABEL.to_string()],
+    }
+}
+
+/// Merges registry references with Europe PMC hits, keyed by PMID.
+pub(super) fn merge_publications(
+    references: &[TrialReference],
+    hits: &[ArticleSearchResult],
+) -> Vec<TrialPublication> {
+    let mut out = references.iter().map(from_reference).collect::<Vec<_>>();
+    let mut by_pmid = out
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, row)| row.pmid.clone().map(|pmid| (pmid, idx)))
+        .collect::<HashMap<_, _>>();
+
+    for hit in hits {
+        if hit.is_retracted == Some(true) {
+            continue;
+        }
+        if let Some(&idx) = by_pmid.get(&hit.pmid) {
+            let row = &mut out[idx];
+            if !hit.title.trim().is_empty() {
+                row.title = hit.title.clone();
+            }
+            row.journal = hit.journal.clone();
+            row.date = hit.date.clone();
+            row.kind = classify_publication(
+                row.reference_type.as_deref(),
+                &row.title,
+                hit.publication_type.as_deref(),
+            );
+            row.found_via.push(EUROPE_PMC_LABEL.to_string());
+            continue;
+        }
+        by_pmid.insert(hit.pmid.clone(), out.len());
+        out.push(TrialPublication {
+            pmid: Some(hit.pmid.clone()),
+            title: hit.title.clone(),
+            journal: hit.journal.clone(),
+            date: hit.date.clone(),
+            kind: classify_publication(None, &hit.title, hit.publication_type.as_deref()),
+            reference_type: None,
+            found_via: vec![EUROPE_PMC_LABEL.to_string()],
+        });
+    }
+
+    // Stable sort keeps registry order first within each kind.
+    out.sort_by_key(|row| row.kind.rank());
+    out
+}
+
+async fn search_europe_pmc(
+    client: &EuropePmcClient,
+    nct_id: &str,
+) -> Result<Vec<ArticleSearchResult>, BioMcpError> {
+    let resp = client
+        .search_query(&format!("\"{nct_id}\""), 1, EUROPE_PMC_PUBLICATION_LIMIT)
+        .await?;
+    Ok(resp
+        .result_list
+        .map(|list| list.result)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(transform::article::from_europepmc_search_result)
+        .collect())
+}
+
+pub(super) async fn fetch_with_client(
+    client: &EuropePmcClient,
+    nct_id: &str,
+    references: &[TrialReference],
+) -> Vec<TrialPublication> {
+    let hits = match search_europe_pmc(client, nct_id).await {
+        Ok(hits) => hits,
+        Err(err) => {
+            warn!(nct_id, error = %err, "Europe PMC trial publication search failed");
+            section_errors::record(TRIAL_SECTION_PUBLICATIONS, "Europe PMC", &err);
+            Vec::new()
+        }
+    };
+    merge_publications(references, &hits)
+}
+
+/// Collects linked publications; Europe PMC failures degrade to registry references only.
+pub(super) async fn fetch(nct_id: &str, references: &[TrialReference]) -> Vec<TrialPublication> {
+    match EuropePmcClient::new() {
+        Ok(client) => fetch_with_client(&client, nct_id, references).await,
+        Err(err) => {
+            warn!(nct_id, error = %err, "Europe PMC client unavailable for trial publications");
+            section_errors::record(TRIAL_SECTION_PUBLICATIONS, "Europe PMC", &err);
+            merge_publications(references, &[])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,114 @@
+//! Tests for trial publication linkage.
+
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::*;
+
+fn reference(pmid: &str, citation: &str, reference_type: &str) -> TrialReference {
+    TrialReference {
+        pmid: Some(pmid.into()),
+        citation: citation.into(),
+        reference_type: Some(reference_type.into()),
+    }
+}
+
+#[test]
+fn classify_publication_separates_results_and_protocol_papers() {
+    assert_eq!(
+        classify_publication(Some("RESULT"), "Long-term follow-up of CheckMate 067", None),
+        TrialPublicationKind::Results
+    );
+    assert_eq!(
+        classify_publication(
+            Some("DERIVED"),
+            "Study protocol for a randomised trial of adjuvant nivolumab",
+            None
+        ),
+        TrialPublicationKind::Protocol
+    );
+    assert_eq!(
+        classify_publication(
+            None,
+            "Overall survival with combined nivolumab and ipilimumab",
+            None
+        ),
+        TrialPublicationKind::Results
+    );
+    assert_eq!(
+        classify_publication(None, "Checkpoint inhibitor efficacy", Some("Review")),
+        TrialPublicationKind::Related
+    );
+    assert_eq!(
+        classify_publication(Some("BACKGROUND"), "Melanoma epidemiology", None),
+        TrialPublicationKind::Background
+    );
+}
+
+#[tokio::test]
+async fn fetch_with_client_merges_registry_references_with_europe_pmc_hits() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("query", "\"NCT01844505\""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "hitCount": 3,
+            "resultList": {"result": [
+                {
+                    "pmid": "26027431",
+                    "title": "Combined Nivolumab and Ipilimumab or Monotherapy in Untreated Melanoma.",
+                    "journalTitle": "N Engl J Med",
+                    "firstPublicationDate": "2015-05-31"
+                },
+                {
+                    "pmid": "30000001",
+                    "title": "Rationale and design of the CheckMate 067 trial",
+                    "journalTitle": "Future Oncol",
+                    "pubYear": "2018"
+                },
+                {
+                    "pmid": "30000002",
+                    "title": "Retracted analysis",
+                    "pubTypeList": {"pubType": ["Retracted Publication"]}
+                }
+            ]}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = EuropePmcClient::new_for_test(server.uri()).expect("client");
+    let references = vec![
+        reference("20000001", "Background on CTLA-4 blockade.", "BACKGROUND"),
+        reference("26027431", "Larkin J, et al. N Engl J Med. 2015.", "RESULT"),
+    ];
+    let rows = fetch_with_client(&client, "NCT01844505", &references).await;
+
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0].pmid.as_deref(), Some("26027431"));
+    assert_eq!(rows[0].kind, TrialPublicationKind::Results);
+    assert_eq!(rows[0].journal.as_deref(), Some("N Engl J Med"));
+    assert_eq!(rows[0].found_via, vec!["ClinicalTrials.gov", "Europe PMC"]);
+    assert_eq!(rows[1].pmid.as_deref(), Some("30000001"));
+    assert_eq!(rows[1].kind, TrialPublicationKind::Protocol);
+    assert_eq!(rows[1].found_via, vec!["Europe PMC"]);
+    assert_eq!(rows[2].kind, TrialPublicationKind::Background);
+}
+
+#[tokio::test]
+async fn fetch_with_client_keeps_registry_references_when_europe_pmc_fails() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(503).set_body_string("unavailable"))
+        .mount(&server)
+        .await;
+
+    let client = EuropePmcClient::new_for_test(server.uri()).expect("client");
+    let references = vec![reference("26027431", "Larkin J, et al.", "RESULT")];
+    let rows = fetch_with_client(&client, "NCT01844505", &references).await;
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].found_via, vec!["ClinicalTrials.gov"]);
+}
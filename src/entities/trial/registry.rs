@@ -0,0 +1,84 @@
+//! Trial registry identifier recognition and normalization.
+//!
+//! Non-US trials are often cross-registered on ClinicalTrials.gov, where the
+//! foreign number appears as a free-text secondary ID such as
+//! `EU CT 2023-503456-12-00` or `EudraCT Number: 2019-001234-56`. Normalizing
+//! both sides to the bare registry number lets search results be matched.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrialRegistry {
+    ClinicalTrialsGov,
+    Ctis,
+    EudraCt,
+    Isrctn,
+    Anzctr,
+    Drks,
+}
+
+impl TrialRegistry {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ClinicalTrialsGov => "ClinicalTrials.gov",
+            Self::Ctis => "CTIS",
+            Self::EudraCt => "EudraCT",
+            Self::Isrctn => "ISRCTN",
+            Self::Anzctr => "ANZCTR",
+            Self::Drks => "DRKS",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryId {
+    pub registry: TrialRegistry,
+    pub id: String,
+}
+
+fn registry_patterns() -> &'static [(TrialRegistry, Regex)] {
+    static PATTERNS: OnceLock<Vec<(TrialRegistry, Regex)>> = OnceLock::new();
+    PATTERNS.get_or_init(|| {
+        // CTIS numbers extend the EudraCT shape, so they must be tried first.
+        [
+            (TrialRegistry::ClinicalTrialsGov, r"(?i)\bNCT\s?(\d{8})\b"),
+            (TrialRegistry::Ctis, r"\b(\d{4}-5\d{5}-\d{2}-\d{2})\b"),
+            (TrialRegistry::EudraCt, r"\b(\d{4}-\d{6}-\d{2})\b"),
+            (TrialRegistry::Isrctn, r"(?i)\bISRCTN\s?(\d{8})\b"),
+            (TrialRegistry::Anzctr, r"(?i)\bACTRN\s?(\d{14})\b"),
+            (TrialRegistry::Drks, r"(?i)\bDRKS\s?(\d{8})\b"),
+        ]
+        .into_iter()
+        .map(|(registry, pattern)| (registry, Regex::new(pattern).expect("valid regex")))
+        .collect()
+    })
+}
+
+/// Extracts and normalizes the first registry identifier found in `raw`.
+pub fn normalize_registry_id(raw: &str) -> Option<RegistryId> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return None;
+    }
+    registry_patterns()
+        .iter()
+        .find_map(|(registry, re)| {
+            let digits = re.captures(raw)?.get(1)?.as_str();
+            Some((*registry, digits))
+        })
+        .map(|(registry, digits)| {
+            let id = match registry {
+                TrialRegistry::ClinicalTrialsGov => format!("NCT{digits}"),
+                TrialRegistry::Isrctn => format!("ISRCTN{digits}"),
+                TrialRegistry::Anzctr => format!("ACTRN{digits}"),
+                TrialRegistry::Drks => format!("DRKS{digits}"),
+                TrialRegistry::Ctis | TrialRegistry::EudraCt => digits.to_string(),
+            };
+            RegistryId { registry, id }
+        })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,38 @@
+//! Sidecar tests for trial registry identifier normalization.
+
+use super::*;
+
+fn normalized(raw: &str) -> Option<(TrialRegistry, String)> {
+    normalize_registry_id(raw).map(|id| (id.registry, id.id))
+}
+
+#[test]
+fn normalize_registry_id_strips_registry_prefixes() {
+    assert_eq!(
+        normalized("EU CT 2023-503456-12-00"),
+        Some((TrialRegistry::Ctis, "2023-503456-12-00".into()))
+    );
+    assert_eq!(
+        normalized("EudraCT Number: 2019-001234-56"),
+        Some((TrialRegistry::EudraCt, "2019-001234-56".into()))
+    );
+    assert_eq!(
+        normalized("nct 01234567"),
+        Some((TrialRegistry::ClinicalTrialsGov, "NCT01234567".into()))
+    );
+    assert_eq!(
+        normalized("ISRCTN12345678"),
+        Some((TrialRegistry::Isrctn, "ISRCTN12345678".into()))
+    );
+    assert_eq!(
+        normalized("ACTRN12620000123456"),
+        Some((TrialRegistry::Anzctr, "ACTRN12620000123456".into()))
+    );
+}
+
+#[test]
+fn normalize_registry_id_ignores_sponsor_protocol_numbers() {
+    assert_eq!(normalized("ABC-001"), None);
+    assert_eq!(normalized("   "), None);
+    assert_eq!(normalized("2019-12"), None);
+}
@@ -1,5 +1,7 @@
 //! CTGov trial search query, pagination, and count helpers.
 
+use std::collections::HashSet;
+
 use crate::entities::SearchPage;
 use crate::error::BioMcpError;
 use crate::sources::clinicaltrials::{ClinicalTrialsClient, CtGovSearchParams, CtGovStudy};
@@ -9,10 +11,10 @@ use crate::utils::date::validate_since;
 use super::super::{TrialCount, TrialSearchFilters, TrialSearchResult, TrialSource};
 use super::{
     CtGovSearchContext, build_essie_fragments, essie_escape, essie_escape_boolean_expression,
-    normalize_intervention_query, normalize_sex, normalize_sponsor_type,
+    has_unparsed_age_limit, normalize_intervention_query, normalize_sex, normalize_sponsor_type,
     prepare_ctgov_search_context, sort_trials_by_status_priority, validate_search_page_args,
-    validate_trial_search, verify_age_eligibility, verify_eligibility_criteria,
-    verify_facility_geo,
+    validate_trial_search, verify_age_eligibility, verify_ecog_eligibility,
+    verify_eligibility_criteria, verify_facility_geo,
 };
 
 pub(super) const CTGOV_COUNT_PAGE_SIZE: usize = 1000;
@@ -191,6 +193,26 @@ pub(super) fn ctgov_query_term(
     }
 }
 
+/// `query.cond` value: the condition as given, OR-ed with its synonyms.
+pub(super) fn ctgov_condition_query(filters: &TrialSearchFilters) -> Option<String> {
+    let condition = filters
+        .condition
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())?;
+    if filters.condition_synonyms.is_empty() {
+        return Some(condition.to_string());
+    }
+    let mut terms = vec![format!("({condition})")];
+    terms.extend(
+        filters
+            .condition_synonyms
+            .iter()
+            .map(|synonym| format!("\"{}\"", essie_escape(synonym))),
+    );
+    Some(terms.join(" OR "))
+}
+
 fn build_ctgov_search_params(
     filters: &TrialSearchFilters,
     context: &CtGovSearchContext,
@@ -198,7 +220,7 @@ fn build_ctgov_search_params(
     page_size: usize,
 ) -> CtGovSearchParams {
     CtGovSearchParams {
-        condition: filters.condition.clone(),
+        condition: ctgov_condition_query(filters),
         intervention: filters
             .intervention
             .as_deref()
@@ -216,12 +238,15 @@ fn build_ctgov_search_params(
     }
 }
 
+/// Applies client-side filters. The set holds NCT IDs of kept studies whose
+/// ECOG requirement could not be read.
 async fn apply_ctgov_post_filters(
     client: &ClinicalTrialsClient,
     filters: &TrialSearchFilters,
     context: &CtGovSearchContext,
     mut studies: Vec<CtGovStudy>,
-) -> Vec<CtGovStudy> {
+) -> (Vec<CtGovStudy>, HashSet<String>) {
+    let mut ecog_unchecked = HashSet::new();
     if let Some((facility_name, lat, lon, distance)) = context.facility_geo_verification.as_ref() {
         studies = verify_facility_geo(client, studies, facility_name, *lat, *lon, *distance).await;
     }
@@ -231,7 +256,29 @@ async fn apply_ctgov_post_filters(
     if let Some(age) = filters.age {
         studies = verify_age_eligibility(studies, age);
     }
-    studies
+    if let Some(ecog) = filters.ecog {
+        let (kept, unreadable) = verify_ecog_eligibility(client, studies, ecog).await;
+        studies = kept;
+        ecog_unchecked.extend(unreadable);
+    }
+    (studies, ecog_unchecked)
+}
+
+/// Requested eligibility filters `study` could not be checked against.
+fn unchecked_eligibility(
+    filters: &TrialSearchFilters,
+    study: &CtGovStudy,
+    nct_id: &str,
+    ecog_unchecked: &HashSet<String>,
+) -> Vec<String> {
+    let mut unchecked = Vec::new();
+    if filters.age.is_some() && has_unparsed_age_limit(study) {
+        unchecked.push("age".to_string());
+    }
+    if ecog_unchecked.contains(nct_id.trim()) {
+        unchecked.push("ECOG".to_string());
+    }
+    unchecked
 }
 
 pub(super) async fn search_page_with_ctgov_client(
@@ -284,7 +331,9 @@ pub(super) async fn search_page_with_ctgov_client(
             break;
         }
 
-        studies = apply_ctgov_post_filters(client, filters, &context, studies).await;
+        let (filtered, ecog_unchecked) =
+            apply_ctgov_post_filters(client, filters, &context, studies).await;
+        studies = filtered;
         if context.uses_expensive_post_filters {
             verified_total = verified_total.saturating_add(studies.len());
         }
@@ -300,7 +349,10 @@ pub(super) async fn search_page_with_ctgov_client(
                 continue;
             }
             if rows.len() < limit {
-                rows.push(transform::trial::from_ctgov_hit(&study));
+                let mut row = transform::trial::from_ctgov_hit(&study);
+                row.eligibility_unchecked =
+                    unchecked_eligibility(filters, &study, &row.nct_id, &ecog_unchecked);
+                rows.push(row);
             }
             if rows.len() >= limit {
                 break;
@@ -397,7 +449,7 @@ pub(super) async fn count_all_with_ctgov_client(
         page_count += 1;
 
         let next_page_token = resp.next_page_token;
-        let studies = apply_ctgov_post_filters(client, filters, &context, resp.studies).await;
+        let (studies, _) = apply_ctgov_post_filters(client, filters, &context, resp.studies).await;
         verified_total = verified_total.saturating_add(studies.len());
 
         if next_page_token.is_none() {
@@ -25,6 +25,27 @@ AREA[Keyword](\"dMMR\" OR \"MSI\\-H\"))"
     assert!(query.contains("AREA[EligibilityCriteria](\"mismatch repair deficient\")"));
 }
 
+#[test]
+fn ctgov_condition_query_ors_escaped_synonyms_with_the_condition() {
+    let mut filters = TrialSearchFilters {
+        condition: Some(" stomach cancer ".into()),
+        ..Default::default()
+    };
+    assert_eq!(
+        ctgov_condition_query(&filters).as_deref(),
+        Some("stomach cancer")
+    );
+
+    filters.condition_synonyms = vec![
+        "gastric carcinoma".into(),
+        "gastro-oesophageal cancer".into(),
+    ];
+    assert_eq!(
+        ctgov_condition_query(&filters).as_deref(),
+        Some("(stomach cancer) OR \"gastric carcinoma\" OR \"gastro\\-oesophageal cancer\"")
+    );
+}
+
 #[test]
 fn ctgov_query_term_broadens_simple_mutation_across_discovery_fields() {
     let filters = TrialSearchFilters {
@@ -506,3 +527,67 @@ async fn count_all_returns_unknown_when_expensive_post_filter_hits_page_cap() {
         TrialCount::Unknown
     );
 }
+
+#[tokio::test]
+async fn search_page_applies_ecog_filter_and_flags_unreadable_limits() {
+    let server = MockServer::start().await;
+    let client = ClinicalTrialsClient::new_for_test(server.uri()).expect("client");
+
+    Mock::given(method("GET"))
+        .and(path("/studies"))
+        .and(query_param("query.cond", "nsclc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "studies": [
+                ctgov_search_study_fixture("NCT00000001", "18 Years", "N/A"),
+                ctgov_search_study_fixture("NCT00000002", "18 Years", "N/A"),
+                ctgov_search_study_fixture("NCT00000003", "18 yrs old", "N/A")
+            ],
+            "nextPageToken": null,
+            "totalCount": 3
+        })))
+        .mount(&server)
+        .await;
+    for (nct_id, criteria) in [
+        (
+            "NCT00000001",
+            "Inclusion Criteria:\nECOG performance status 0-1\n\nExclusion Criteria:\nECOG 2 or higher",
+        ),
+        (
+            "NCT00000002",
+            "Inclusion Criteria:\nECOG performance status ≤ 2. Adequate organ function",
+        ),
+        (
+            "NCT00000003",
+            "Inclusion Criteria:\nKarnofsky performance status of at least 70%",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(format!("/studies/{nct_id}")))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(ctgov_eligibility_detail_fixture(nct_id, criteria)),
+            )
+            .mount(&server)
+            .await;
+    }
+
+    let filters = TrialSearchFilters {
+        condition: Some("nsclc".into()),
+        age: Some(60.0),
+        ecog: Some(2),
+        ..Default::default()
+    };
+    let page = search_page_with_ctgov_client(&client, &filters, 10, 0, None)
+        .await
+        .expect("page");
+
+    let ids = page
+        .results
+        .iter()
+        .map(|row| row.nct_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["NCT00000002", "NCT00000003"]);
+    assert!(page.results[0].eligibility_unchecked.is_empty());
+    assert_eq!(page.results[1].eligibility_unchecked, vec!["age", "ECOG"]);
+    assert_eq!(page.total, Some(2));
+}
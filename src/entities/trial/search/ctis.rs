@@ -0,0 +1,137 @@
+//! EU CTIS trial search helpers.
+
+use std::collections::HashMap;
+
+use tracing::warn;
+
+use crate::entities::SearchPage;
+use crate::error::BioMcpError;
+use crate::sources::clinicaltrials::{ClinicalTrialsClient, CtGovRegistryLink};
+use crate::sources::ctis::{CtisClient, CtisSearchParams};
+use crate::transform;
+
+use super::super::registry::normalize_registry_id;
+use super::super::{TrialSearchFilters, TrialSearchResult};
+
+fn is_set(value: Option<&str>) -> bool {
+    value.map(str::trim).is_some_and(|v| !v.is_empty())
+}
+
+pub(super) fn validate_ctis_filters(filters: &TrialSearchFilters) -> Result<(), BioMcpError> {
+    let unsupported = [
+        ("--facility", is_set(filters.facility.as_deref())),
+        ("--status", is_set(filters.status.as_deref())),
+        ("--phase", is_set(filters.phase.as_deref())),
+        ("--study-type", is_set(filters.study_type.as_deref())),
+        ("--age", filters.age.is_some()),
+        ("--sex", is_set(filters.sex.as_deref())),
+        ("--ecog", filters.ecog.is_some()),
+        ("--sponsor-type", is_set(filters.sponsor_type.as_deref())),
+        ("--date-from", is_set(filters.date_from.as_deref())),
+        ("--date-to", is_set(filters.date_to.as_deref())),
+        ("--mutation", is_set(filters.mutation.as_deref())),
+        ("--criteria", is_set(filters.criteria.as_deref())),
+        ("--biomarker", is_set(filters.biomarker.as_deref())),
+        (
+            "--prior-therapies",
+            is_set(filters.prior_therapies.as_deref()),
+        ),
+        (
+            "--progression-on",
+            is_set(filters.progression_on.as_deref()),
+        ),
+        (
+            "--line-of-therapy",
+            is_set(filters.line_of_therapy.as_deref()),
+        ),
+        (
+            "--lat/--lon/--distance",
+            filters.lat.is_some() || filters.lon.is_some() || filters.distance.is_some(),
+        ),
+        ("--has-results", filters.results_available),
+    ];
+    if let Some((flag, _)) = unsupported.iter().find(|(_, set)| *set) {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "{flag} is not supported for --source ctis. Use -c/--condition, -i/--intervention, or --sponsor."
+        )));
+    }
+
+    if !is_set(filters.condition.as_deref())
+        && !is_set(filters.intervention.as_deref())
+        && !is_set(filters.sponsor.as_deref())
+    {
+        return Err(BioMcpError::InvalidArgument(
+            "At least one of -c/--condition, -i/--intervention, or --sponsor is required for --source ctis. Example: biomcp search trial -c melanoma --source ctis".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Marks CTIS rows whose number appears as an identifier on a ClinicalTrials.gov record.
+pub(super) fn apply_registry_links(rows: &mut [TrialSearchResult], links: &[CtGovRegistryLink]) {
+    let by_registry_id = links
+        .iter()
+        .flat_map(|link| {
+            link.other_ids.iter().filter_map(move |other| {
+                normalize_registry_id(other)
+                    .map(|normalized| ((normalized.registry, normalized.id), &link.nct_id))
+            })
+        })
+        .collect::<HashMap<_, _>>();
+
+    for row in rows {
+        row.cross_registered_nct = normalize_registry_id(&row.nct_id).and_then(|normalized| {
+            by_registry_id
+                .get(&(normalized.registry, normalized.id))
+                .map(|nct| (*nct).clone())
+        });
+    }
+}
+
+async fn link_cross_registrations(ctgov: &ClinicalTrialsClient, rows: &mut [TrialSearchResult]) {
+    let ids = rows
+        .iter()
+        .map(|row| row.nct_id.clone())
+        .collect::<Vec<_>>();
+    match ctgov.registry_links(&ids).await {
+        Ok(links) => apply_registry_links(rows, &links),
+        Err(err) => warn!(error = %err, "ClinicalTrials.gov cross-registration lookup failed"),
+    }
+}
+
+pub(super) async fn search_page_with_ctis_clients(
+    client: &CtisClient,
+    ctgov: &ClinicalTrialsClient,
+    filters: &TrialSearchFilters,
+    limit: usize,
+    offset: usize,
+) -> Result<SearchPage<TrialSearchResult>, BioMcpError> {
+    if offset % limit != 0 {
+        return Err(BioMcpError::InvalidArgument(
+            "--offset must be a multiple of --limit for --source ctis".into(),
+        ));
+    }
+
+    let params = CtisSearchParams {
+        medical_condition: filters.condition.clone(),
+        product_name: filters.intervention.clone(),
+        sponsor: filters.sponsor.clone(),
+        page: offset / limit + 1,
+        size: limit,
+    };
+    let resp = client.search(&params).await?;
+    let mut rows = resp
+        .data
+        .iter()
+        .map(transform::trial::from_ctis_hit)
+        .filter(|row| !row.nct_id.is_empty())
+        .collect::<Vec<_>>();
+    if !rows.is_empty() {
+        link_cross_registrations(ctgov, &mut rows).await;
+    }
+
+    Ok(SearchPage::offset(rows, resp.total()))
+}
+
+#[cfg(test)]
+mod tests;
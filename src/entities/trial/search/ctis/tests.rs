@@ -0,0 +1,141 @@
+//! Tests for EU CTIS trial search helpers.
+
+use super::super::super::test_support::*;
+use super::*;
+
+fn ctis_filters(condition: &str) -> TrialSearchFilters {
+    TrialSearchFilters {
+        condition: Some(condition.into()),
+        source: TrialSource::Ctis,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn validate_ctis_filters_rejects_ctgov_only_flags() {
+    let filters = TrialSearchFilters {
+        status: Some("recruiting".into()),
+        ..ctis_filters("melanoma")
+    };
+    let err = validate_ctis_filters(&filters).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("--status is not supported for --source ctis")
+    );
+
+    let err = validate_ctis_filters(&TrialSearchFilters {
+        source: TrialSource::Ctis,
+        ..Default::default()
+    })
+    .unwrap_err();
+    assert!(err.to_string().contains("At least one of -c/--condition"));
+
+    assert!(validate_ctis_filters(&ctis_filters("melanoma")).is_ok());
+}
+
+#[test]
+fn apply_registry_links_matches_normalized_secondary_ids() {
+    let mut rows = vec![
+        TrialSearchResult {
+            nct_id: "2023-503456-12-00".into(),
+            title: "Cross-registered".into(),
+            status: "Ongoing".into(),
+            phase: None,
+            conditions: Vec::new(),
+            sponsor: None,
+            registry: Some("CTIS".into()),
+            cross_registered_nct: None,
+            eligibility_unchecked: Vec::new(),
+        },
+        TrialSearchResult {
+            nct_id: "2024-512345-01-00".into(),
+            title: "EU only".into(),
+            status: "Authorised".into(),
+            phase: None,
+            conditions: Vec::new(),
+            sponsor: None,
+            registry: Some("CTIS".into()),
+            cross_registered_nct: None,
+            eligibility_unchecked: Vec::new(),
+        },
+    ];
+    let links = vec![CtGovRegistryLink {
+        nct_id: "NCT05123456".into(),
+        other_ids: vec!["ABC-001".into(), "EU CT 2023-503456-12-00".into()],
+    }];
+
+    apply_registry_links(&mut rows, &links);
+    assert_eq!(rows[0].cross_registered_nct.as_deref(), Some("NCT05123456"));
+    assert_eq!(rows[1].cross_registered_nct, None);
+}
+
+#[tokio::test]
+async fn ctis_search_page_annotates_cross_registered_trials() {
+    let ctis = MockServer::start().await;
+    let ctgov = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "pagination": {"page": 1, "size": 2, "totalRecords": 2},
+            "data": [
+                {
+                    "ctNumber": "2023-503456-12-00",
+                    "ctTitle": "Nivolumab adjuvant melanoma",
+                    "ctStatus": "Ongoing",
+                    "trialPhase": "Phase III",
+                    "conditions": "Melanoma",
+                    "sponsor": "Example Oncology"
+                },
+                {"ctNumber": "2024-512345-01-00", "ctTitle": "EU-only melanoma trial"}
+            ]
+        })))
+        .expect(1)
+        .mount(&ctis)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/studies"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "studies": [{
+                "protocolSection": {
+                    "identificationModule": {
+                        "nctId": "NCT05123456",
+                        "secondaryIdInfos": [{"id": "2023-503456-12-00"}]
+                    }
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(&ctgov)
+        .await;
+
+    let client = CtisClient::new_for_test(ctis.uri()).expect("ctis client");
+    let ctgov_client = ClinicalTrialsClient::new_for_test(ctgov.uri()).expect("ctgov client");
+    let page =
+        search_page_with_ctis_clients(&client, &ctgov_client, &ctis_filters("melanoma"), 2, 0)
+            .await
+            .expect("ctis search");
+
+    assert_eq!(page.total, Some(2));
+    assert_eq!(page.results.len(), 2);
+    assert_eq!(page.results[0].nct_id, "2023-503456-12-00");
+    assert_eq!(page.results[0].registry.as_deref(), Some("CTIS"));
+    assert_eq!(page.results[0].phase.as_deref(), Some("Phase III"));
+    assert_eq!(
+        page.results[0].cross_registered_nct.as_deref(),
+        Some("NCT05123456")
+    );
+    assert_eq!(page.results[1].cross_registered_nct, None);
+}
+
+#[tokio::test]
+async fn ctis_search_page_requires_offset_aligned_to_limit() {
+    let client = CtisClient::new_for_test("http://127.0.0.1:9".into()).expect("ctis client");
+    let ctgov_client =
+        ClinicalTrialsClient::new_for_test("http://127.0.0.1:9".into()).expect("ctgov client");
+    let err =
+        search_page_with_ctis_clients(&client, &ctgov_client, &ctis_filters("melanoma"), 10, 5)
+            .await
+            .unwrap_err();
+    assert!(err.to_string().contains("multiple of --limit"));
+}
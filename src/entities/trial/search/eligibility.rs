@@ -1,4 +1,4 @@
-//! Trial eligibility, facility-geo, and age post-filter helpers.
+//! Trial eligibility, facility-geo, age, and ECOG post-filter helpers.
 
 use futures::{StreamExt, stream};
 use regex::Regex;
@@ -23,7 +23,12 @@ fn normalize_facility_text(value: &str) -> Option<String> {
     (!normalized.is_empty()).then_some(normalized)
 }
 
-fn haversine_miles(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+pub(in crate::entities::trial) fn haversine_miles(
+    lat1: f64,
+    lon1: f64,
+    lat2: f64,
+    lon2: f64,
+) -> f64 {
     const EARTH_RADIUS_MILES: f64 = 3958.7613;
     let to_rad = |deg: f64| deg.to_radians();
     let d_lat = to_rad(lat2 - lat1);
@@ -108,7 +113,7 @@ fn exclusion_criteria_header_re() -> &'static Regex {
     })
 }
 
-fn split_eligibility_sections(text: &str) -> (String, String) {
+pub(in crate::entities::trial) fn split_eligibility_sections(text: &str) -> (String, String) {
     let trimmed = text.trim();
     if trimmed.is_empty() {
         return (String::new(), String::new());
@@ -123,7 +128,10 @@ fn split_eligibility_sections(text: &str) -> (String, String) {
     (inclusion, exclusion)
 }
 
-fn contains_keyword_tokens(section_text: &str, keyword: &str) -> bool {
+pub(in crate::entities::trial) fn contains_keyword_tokens(
+    section_text: &str,
+    keyword: &str,
+) -> bool {
     if section_text.is_empty() {
         return false;
     }
@@ -213,7 +221,7 @@ fn keyword_has_negative_inclusion_context(inclusion_text: &str, keyword: &str) -
         .any(contains_exclusion_language)
 }
 
-fn eligibility_keyword_in_inclusion(
+pub(in crate::entities::trial) fn eligibility_keyword_in_inclusion(
     inclusion_text: &str,
     exclusion_text: &str,
     keyword: &str,
@@ -389,7 +397,7 @@ pub(super) async fn verify_eligibility_criteria(
     verified
 }
 
-fn parse_age_years(value: &str) -> Option<f32> {
+pub(in crate::entities::trial) fn parse_age_years(value: &str) -> Option<f32> {
     let mut parts = value.split_whitespace();
     let amount = parts.next()?.parse::<f32>().ok()?;
     let unit = parts.next().map(|token| {
@@ -407,6 +415,24 @@ fn parse_age_years(value: &str) -> Option<f32> {
     }
 }
 
+/// True when a study states an age limit that [`parse_age_years`] cannot read,
+/// so `--age` could not be checked against it.
+pub(super) fn has_unparsed_age_limit(study: &CtGovStudy) -> bool {
+    let Some(module) = study
+        .protocol_section
+        .as_ref()
+        .and_then(|s| s.eligibility_module.as_ref())
+    else {
+        return false;
+    };
+    [module.minimum_age.as_deref(), module.maximum_age.as_deref()]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .filter(|value| !value.is_empty() && !value.eq_ignore_ascii_case("n/a"))
+        .any(|value| parse_age_years(value).is_none())
+}
+
 pub(super) fn verify_age_eligibility(studies: Vec<CtGovStudy>, age: f32) -> Vec<CtGovStudy> {
     studies
         .into_iter()
@@ -428,5 +454,138 @@ pub(super) fn verify_age_eligibility(studies: Vec<CtGovStudy>, age: f32) -> Vec<
         .collect()
 }
 
+/// ECOG performance-status requirement found in inclusion criteria text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(in crate::entities::trial) enum EcogLimit {
+    /// No performance-status requirement is stated.
+    Absent,
+    /// Highest ECOG score the trial admits.
+    Max(u8),
+    /// A performance-status requirement is stated but no ECOG limit could be read.
+    Unparsed,
+}
+
+fn ecog_mention_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"\b(?:ecog|zubrod|who performance status)\b[^\n;]{0,80}")
+            .expect("ecog mention regex is valid")
+    })
+}
+
+fn ecog_inclusive_bound_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?:≤|<=|=<|less than or equal to|(?:no|not) (?:greater|more|higher) than|up to|at most|maximum of)\s*(?:of\s*)?([0-4])\b",
+        )
+        .expect("ecog inclusive bound regex is valid")
+    })
+}
+
+fn ecog_strict_bound_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?:<|less than)\s*([1-5])\b").expect("ecog strict bound regex is valid")
+    })
+}
+
+fn ecog_score_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\b([0-4])\b").expect("ecog score regex is valid"))
+}
+
+/// Reads the ECOG limit from one mention such as `ecog performance status 0-1`,
+/// `ecog ≤ 2`, or `ecog ps of 0, 1, or 2`. The mention ends at the first
+/// sentence break or parenthesis so numbers from the next criterion or an
+/// appendix reference are not picked up.
+fn ecog_mention_limit(mention: &str) -> Option<u8> {
+    let mention = mention.split(". ").next().unwrap_or(mention);
+    let mention = mention.split('(').next().unwrap_or(mention);
+    let capture = |re: &Regex| {
+        re.captures(mention)
+            .and_then(|caps| caps.get(1))
+            .and_then(|m| m.as_str().parse::<u8>().ok())
+    };
+    if let Some(max) = capture(ecog_inclusive_bound_re()) {
+        return Some(max);
+    }
+    if let Some(bound) = capture(ecog_strict_bound_re()) {
+        return Some(bound - 1);
+    }
+    ecog_score_re()
+        .captures_iter(mention)
+        .filter_map(|caps| caps.get(1)?.as_str().parse::<u8>().ok())
+        .max()
+}
+
+/// Finds the ECOG limit in lowercased inclusion text. With several mentions
+/// the most permissive readable limit wins.
+pub(in crate::entities::trial) fn ecog_limit(inclusion: &str) -> EcogLimit {
+    let limits = ecog_mention_re()
+        .find_iter(inclusion)
+        .map(|mention| ecog_mention_limit(mention.as_str()))
+        .collect::<Vec<_>>();
+    if let Some(max) = limits.iter().flatten().max() {
+        return EcogLimit::Max(*max);
+    }
+    if !limits.is_empty()
+        || inclusion.contains("performance status")
+        || inclusion.contains("karnofsky")
+        || inclusion.contains("lansky")
+    {
+        return EcogLimit::Unparsed;
+    }
+    EcogLimit::Absent
+}
+
+/// Drops studies whose inclusion criteria cap ECOG below `ecog`. The second
+/// list holds NCT IDs of kept studies whose requirement could not be read.
+pub(super) async fn verify_ecog_eligibility(
+    client: &ClinicalTrialsClient,
+    studies: Vec<CtGovStudy>,
+    ecog: u8,
+) -> (Vec<CtGovStudy>, Vec<String>) {
+    let eligibility_section = vec![TRIAL_SECTION_ELIGIBILITY.to_string()];
+    let mut verification_stream = stream::iter(studies.into_iter().map(|study| {
+        let nct_id = ctgov_nct_id(&study);
+        let sections = eligibility_section.clone();
+        async move {
+            let Some(nct_id) = nct_id else {
+                return Some((study, None));
+            };
+            let limit = match client.get(&nct_id, &sections).await {
+                Ok(details) => details
+                    .protocol_section
+                    .as_ref()
+                    .and_then(|section| section.eligibility_module.as_ref())
+                    .and_then(|module| module.eligibility_criteria.as_deref())
+                    .map(|criteria| ecog_limit(&split_eligibility_sections(criteria).0))
+                    .unwrap_or(EcogLimit::Absent),
+                Err(e) => {
+                    warn!(nct_id, error = %e, "ECOG detail fetch failed, keeping study");
+                    EcogLimit::Unparsed
+                }
+            };
+            match limit {
+                EcogLimit::Absent => Some((study, None)),
+                EcogLimit::Max(max) => (ecog <= max).then_some((study, None)),
+                EcogLimit::Unparsed => Some((study, Some(nct_id))),
+            }
+        }
+    }))
+    .buffered(ELIGIBILITY_VERIFY_CONCURRENCY);
+
+    let mut verified = Vec::new();
+    let mut unchecked = Vec::new();
+    while let Some(maybe_study) = verification_stream.next().await {
+        if let Some((study, unreadable)) = maybe_study {
+            verified.push(study);
+            unchecked.extend(unreadable);
+        }
+    }
+    (verified, unchecked)
+}
+
 #[cfg(test)]
 mod tests;
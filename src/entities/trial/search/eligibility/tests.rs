@@ -303,3 +303,44 @@ fn facility_geo_keeps_same_site_match() {
         50
     ));
 }
+
+#[test]
+fn ecog_limit_reads_ranges_bounds_and_lists() {
+    assert_eq!(
+        ecog_limit("ecog performance status 0-1 (appendix 3)"),
+        EcogLimit::Max(1)
+    );
+    assert_eq!(
+        ecog_limit("ecog performance status 0 or 1"),
+        EcogLimit::Max(1)
+    );
+    assert_eq!(
+        ecog_limit("ecog ps ≤ 2. life expectancy of 3 months"),
+        EcogLimit::Max(2)
+    );
+    assert_eq!(ecog_limit("ecog performance status < 2"), EcogLimit::Max(1));
+    assert_eq!(ecog_limit("ecog of 0, 1, or 2"), EcogLimit::Max(2));
+    assert_eq!(
+        ecog_limit("karnofsky performance status >= 70%"),
+        EcogLimit::Unparsed
+    );
+    assert_eq!(
+        ecog_limit("adequate ecog performance status per investigator"),
+        EcogLimit::Unparsed
+    );
+    assert_eq!(
+        ecog_limit("histologically confirmed nsclc"),
+        EcogLimit::Absent
+    );
+}
+
+#[test]
+fn has_unparsed_age_limit_ignores_not_applicable_limits() {
+    let parse = |min: &str, max: &str| -> CtGovStudy {
+        serde_json::from_value(ctgov_search_study_fixture("NCT00000001", min, max))
+            .expect("study fixture should deserialize")
+    };
+    assert!(!has_unparsed_age_limit(&parse("18 Years", "N/A")));
+    assert!(has_unparsed_age_limit(&parse("18 yrs old", "N/A")));
+    assert!(has_unparsed_age_limit(&parse("18 Years", "elderly")));
+}
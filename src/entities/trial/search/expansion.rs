@@ -0,0 +1,133 @@
+//! Thesaurus synonyms that widen CTGov `--condition` searches.
+//!
+//! The condition is grounded to a MONDO disease through MyDisease.info. Its
+//! preferred name, exact MONDO synonyms, and, when MONDO cross-references an
+//! NCIt code, the NCIt preferred term and synonyms become extra `query.cond`
+//! alternatives, so "stomach cancer" also finds trials registered under
+//! "gastric carcinoma". Lookup failures leave the condition unexpanded.
+
+use tracing::warn;
+
+use crate::entities::disease::resolve_disease_hit_by_name;
+use crate::error::BioMcpError;
+use crate::sources::mydisease::{MyDiseaseClient, MyDiseaseHit};
+use crate::sources::nci_evs::NciEvsClient;
+use crate::transform;
+
+use super::has_boolean_operators;
+
+/// Each synonym is another OR branch in the CTGov query.
+const MAX_CONDITION_SYNONYMS: usize = 6;
+const MIN_SYNONYM_CHARS: usize = 4;
+
+/// Synonyms for `condition`, best first; empty when it cannot be grounded or
+/// is already a boolean expression.
+pub async fn condition_synonyms(condition: &str) -> Result<Vec<String>, BioMcpError> {
+    let mydisease = MyDiseaseClient::new()?;
+    let nci_evs = NciEvsClient::new()?;
+    Ok(condition_synonyms_with_clients(&mydisease, &nci_evs, condition).await)
+}
+
+pub(super) async fn condition_synonyms_with_clients(
+    mydisease: &MyDiseaseClient,
+    nci_evs: &NciEvsClient,
+    condition: &str,
+) -> Vec<String> {
+    let condition = condition.trim();
+    if condition.is_empty() || has_boolean_operators(condition) {
+        return Vec::new();
+    }
+
+    let hit = match resolve_disease_hit_by_name(mydisease, condition).await {
+        Ok(hit) => hit,
+        Err(BioMcpError::NotFound { .. }) => return Vec::new(),
+        Err(err) => {
+            warn!(condition, error = %err, "condition synonym lookup failed; searching as given");
+            return Vec::new();
+        }
+    };
+
+    let mut candidates = mondo_exact_synonyms(&hit);
+    let disease = transform::disease::from_mydisease_hit(hit);
+    candidates.insert(0, disease.name.clone());
+    if let Some(code) = disease
+        .xrefs
+        .get("NCI")
+        .and_then(|value| value.rsplit(':').next())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        match nci_evs.concept(code).await {
+            Ok(Some(concept)) => {
+                for term_type in ["PT", "SY"] {
+                    candidates.extend(
+                        concept
+                            .synonyms_of_type(term_type)
+                            .into_iter()
+                            .map(str::to_string),
+                    );
+                }
+            }
+            Ok(None) => {}
+            Err(err) => {
+                warn!(condition, code, error = %err, "NCIt synonym lookup failed");
+            }
+        }
+    }
+
+    select_synonyms(condition, candidates)
+}
+
+fn mondo_exact_synonyms(hit: &MyDiseaseHit) -> Vec<String> {
+    let Some(exact) = hit
+        .mondo
+        .as_ref()
+        .and_then(|mondo| mondo.get("synonym"))
+        .and_then(|synonym| synonym.get("exact"))
+    else {
+        return Vec::new();
+    };
+    match exact {
+        serde_json::Value::String(value) => vec![value.clone()],
+        serde_json::Value::Array(values) => values
+            .iter()
+            .filter_map(|value| value.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn synonym_key(value: &str) -> String {
+    value
+        .split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// All-caps single words such as `NSCLC` or `GC` are too ambiguous to search.
+fn is_acronym(value: &str) -> bool {
+    !value.contains(char::is_whitespace) && !value.chars().any(char::is_lowercase)
+}
+
+fn select_synonyms(condition: &str, candidates: Vec<String>) -> Vec<String> {
+    let mut seen = vec![synonym_key(condition)];
+    let mut out = Vec::new();
+    for candidate in candidates {
+        let candidate = candidate.trim();
+        let key = synonym_key(candidate);
+        if key.chars().count() < MIN_SYNONYM_CHARS || is_acronym(candidate) || seen.contains(&key) {
+            continue;
+        }
+        seen.push(key);
+        out.push(candidate.to_string());
+        if out.len() >= MAX_CONDITION_SYNONYMS {
+            break;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests;
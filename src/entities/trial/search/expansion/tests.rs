@@ -0,0 +1,105 @@
+//! Tests for condition synonym expansion.
+
+use super::super::super::test_support::*;
+use super::*;
+
+#[test]
+fn select_synonyms_drops_the_query_acronyms_and_duplicates() {
+    let selected = select_synonyms(
+        "Stomach cancer",
+        vec![
+            "gastric carcinoma".into(),
+            "stomach cancer".into(),
+            "Gastric Carcinoma".into(),
+            "GC".into(),
+            "NSCLC".into(),
+            "gastric cancer".into(),
+            "Carcinoma, Gastric".into(),
+        ],
+    );
+    assert_eq!(
+        selected,
+        vec!["gastric carcinoma", "gastric cancer", "Carcinoma, Gastric"]
+    );
+
+    let many = (1..=10).map(|n| format!("synonym {n}")).collect();
+    assert_eq!(select_synonyms("x", many).len(), MAX_CONDITION_SYNONYMS);
+}
+
+#[tokio::test]
+async fn condition_synonyms_merge_mondo_and_ncit_terms() {
+    let mydisease = MockServer::start().await;
+    let nci_evs = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/query"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total": 1,
+            "hits": [{
+                "_id": "MONDO:0001056",
+                "mondo": {
+                    "name": "gastric cancer",
+                    "synonym": {
+                        "exact": ["stomach cancer", "cancer of stomach"],
+                        "related": ["gastric neoplasm"]
+                    },
+                    "xrefs": {"ncit": ["C4911"]}
+                }
+            }]
+        })))
+        .mount(&mydisease)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/concept/ncit/C4911"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "code": "C4911",
+            "name": "Gastric Carcinoma",
+            "synonyms": [
+                {"name": "Gastric Carcinoma", "termType": "PT"},
+                {"name": "Stomach Carcinoma", "termType": "SY"},
+                {"name": "Gastric Cancer", "termType": "SY"}
+            ]
+        })))
+        .expect(1)
+        .mount(&nci_evs)
+        .await;
+
+    let mydisease_client =
+        MyDiseaseClient::new_for_test(format!("{}/v1", mydisease.uri())).expect("mydisease");
+    let nci_evs_client = NciEvsClient::new_for_test(nci_evs.uri()).expect("nci evs");
+    let synonyms =
+        condition_synonyms_with_clients(&mydisease_client, &nci_evs_client, "stomach cancer").await;
+    assert_eq!(
+        synonyms,
+        vec![
+            "gastric cancer",
+            "cancer of stomach",
+            "Gastric Carcinoma",
+            "Stomach Carcinoma"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn condition_synonyms_skip_boolean_queries_and_ungrounded_terms() {
+    let mydisease = MockServer::start().await;
+    let nci_evs = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/query"))
+        .respond_with(ResponseTemplate::new(503).set_body_string("unavailable"))
+        .mount(&mydisease)
+        .await;
+
+    let mydisease_client =
+        MyDiseaseClient::new_for_test(format!("{}/v1", mydisease.uri())).expect("mydisease");
+    let nci_evs_client = NciEvsClient::new_for_test(nci_evs.uri()).expect("nci evs");
+    assert!(
+        condition_synonyms_with_clients(&mydisease_client, &nci_evs_client, "melanoma OR sarcoma")
+            .await
+            .is_empty()
+    );
+    assert!(
+        condition_synonyms_with_clients(&mydisease_client, &nci_evs_client, "stomach cancer")
+            .await
+            .is_empty()
+    );
+}
@@ -1,14 +1,17 @@
 //! Trial search and count entry points exposed through the stable trial facade.
 
 mod ctgov;
+mod ctis;
 mod eligibility;
 mod essie;
+mod expansion;
 mod nci;
 mod normalization;
 
 use crate::entities::SearchPage;
 use crate::error::BioMcpError;
 use crate::sources::clinicaltrials::ClinicalTrialsClient;
+use crate::sources::ctis::CtisClient;
 use crate::sources::mydisease::MyDiseaseClient;
 use crate::sources::nci_cts::NciCtsClient;
 
@@ -16,14 +19,20 @@ use self::ctgov::validate_location;
 use self::ctgov::{
     count_all_with_ctgov_client, ctgov_agg_filters, ctgov_query_term, search_page_with_ctgov_client,
 };
+use self::ctis::{search_page_with_ctis_clients, validate_ctis_filters};
+pub(super) use self::eligibility::{
+    EcogLimit, contains_keyword_tokens, ecog_limit, eligibility_keyword_in_inclusion,
+    haversine_miles, parse_age_years, split_eligibility_sections,
+};
 use self::eligibility::{
-    collect_eligibility_keywords, verify_age_eligibility, verify_eligibility_criteria,
-    verify_facility_geo,
+    collect_eligibility_keywords, has_unparsed_age_limit, verify_age_eligibility,
+    verify_ecog_eligibility, verify_eligibility_criteria, verify_facility_geo,
 };
 use self::essie::has_essie_filters;
 use self::essie::{
     build_essie_fragments, essie_escape, essie_escape_boolean_expression, has_boolean_operators,
 };
+pub use self::expansion::condition_synonyms;
 use self::nci::search_page_with_nci_clients;
 use self::normalization::{
     normalize_intervention_query, normalize_sex, normalize_sponsor_type,
@@ -210,6 +219,11 @@ pub(super) fn validate_trial_search(
             "--age is only supported for --source ctgov".into(),
         ));
     }
+    if matches!(filters.source, TrialSource::NciCts) && filters.ecog.is_some() {
+        return Err(BioMcpError::InvalidArgument(
+            "--ecog is only supported for --source ctgov".into(),
+        ));
+    }
     if matches!(filters.source, TrialSource::NciCts)
         && filters
             .sex
@@ -260,8 +274,9 @@ pub(super) fn prepare_ctgov_search_context(
         .map(|(((facility_name, lat), lon), distance)| {
             (facility_name.to_string(), lat, lon, distance)
         });
-    let uses_expensive_post_filters =
-        facility_geo_verification.is_some() || !eligibility_keywords.is_empty();
+    let uses_expensive_post_filters = facility_geo_verification.is_some()
+        || !eligibility_keywords.is_empty()
+        || filters.ecog.is_some();
 
     Ok(CtGovSearchContext {
         normalized_status: normalized.normalized_status.clone(),
@@ -290,7 +305,7 @@ pub async fn count_all(filters: &TrialSearchFilters) -> Result<TrialCount, BioMc
             let client = ClinicalTrialsClient::new()?;
             count_all_with_ctgov_client(&client, filters).await
         }
-        TrialSource::NciCts => {
+        TrialSource::NciCts | TrialSource::Ctis => {
             let page = search_page(filters, 1, 0, None).await?;
             Ok(TrialCount::Exact(page.total.unwrap_or(page.results.len())))
         }
@@ -338,5 +353,21 @@ pub async fn search_page(
             )
             .await
         }
+        TrialSource::Ctis => {
+            validate_search_page_args(limit, offset, next_page.as_deref())?;
+            validate_ctis_filters(filters)?;
+            if next_page
+                .as_deref()
+                .map(str::trim)
+                .is_some_and(|value| !value.is_empty())
+            {
+                return Err(BioMcpError::InvalidArgument(
+                    "--next-page is only supported for --source ctgov".into(),
+                ));
+            }
+            let client = CtisClient::new()?;
+            let ctgov = ClinicalTrialsClient::new()?;
+            search_page_with_ctis_clients(&client, &ctgov, filters, limit, offset).await
+        }
     }
 }
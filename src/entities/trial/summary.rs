@@ -0,0 +1,159 @@
+//! Sponsor/portfolio summaries that aggregate trial search results by one field.
+
+use std::collections::HashMap;
+
+use crate::error::BioMcpError;
+
+use super::search::search_page;
+use super::{
+    TrialSearchFilters, TrialSearchResult, TrialSource, TrialSummary, TrialSummaryBy,
+    TrialSummaryGroup,
+};
+
+/// Trials scanned per summary; larger portfolios are reported as truncated.
+const SUMMARY_MAX_TRIALS: usize = 500;
+const SUMMARY_PAGE_SIZE: usize = 50;
+const UNSPECIFIED: &str = "unspecified";
+
+fn phase_label(result: &TrialSearchResult) -> String {
+    result
+        .phase
+        .as_deref()
+        .map(str::trim)
+        .filter(|phase| !phase.is_empty())
+        .unwrap_or(UNSPECIFIED)
+        .to_string()
+}
+
+fn phase_rank(phase: &str) -> usize {
+    match phase.to_ascii_uppercase().as_str() {
+        "EARLY_PHASE1" => 0,
+        "PHASE1" => 1,
+        "PHASE1/PHASE2" => 2,
+        "PHASE2" => 3,
+        "PHASE2/PHASE3" => 4,
+        "PHASE3" => 5,
+        "PHASE4" => 6,
+        "NA" => 7,
+        _ if phase == UNSPECIFIED => 9,
+        _ => 8,
+    }
+}
+
+fn group_keys(result: &TrialSearchResult, by: TrialSummaryBy) -> Vec<String> {
+    let keys = match by {
+        TrialSummaryBy::Condition => result.conditions.clone(),
+        TrialSummaryBy::Phase => vec![phase_label(result)],
+        TrialSummaryBy::Status => vec![result.status.clone()],
+        TrialSummaryBy::Sponsor => result.sponsor.clone().into_iter().collect(),
+    };
+    let keys = keys
+        .into_iter()
+        .map(|key| key.trim().to_string())
+        .filter(|key| !key.is_empty())
+        .collect::<Vec<_>>();
+    if keys.is_empty() {
+        vec![UNSPECIFIED.to_string()]
+    } else {
+        keys
+    }
+}
+
+/// Counts trials per group, most trials first. A trial listing several
+/// conditions counts once under each of them.
+pub(super) fn summarize_results(
+    results: &[TrialSearchResult],
+    by: TrialSummaryBy,
+) -> (Vec<String>, Vec<TrialSummaryGroup>) {
+    let mut groups: Vec<TrialSummaryGroup> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut phases: Vec<String> = Vec::new();
+
+    for result in results {
+        let phase = phase_label(result);
+        if !phases.contains(&phase) {
+            phases.push(phase.clone());
+        }
+        let mut seen: Vec<usize> = Vec::new();
+        for key in group_keys(result, by) {
+            let slot = *index.entry(key.to_ascii_lowercase()).or_insert_with(|| {
+                groups.push(TrialSummaryGroup {
+                    key,
+                    trials: 0,
+                    phases: Default::default(),
+                });
+                groups.len() - 1
+            });
+            if seen.contains(&slot) {
+                continue;
+            }
+            seen.push(slot);
+            let group = &mut groups[slot];
+            group.trials += 1;
+            *group.phases.entry(phase.clone()).or_default() += 1;
+        }
+    }
+
+    phases.sort_by(|a, b| phase_rank(a).cmp(&phase_rank(b)).then_with(|| a.cmp(b)));
+    groups.sort_by(|a, b| {
+        b.trials
+            .cmp(&a.trials)
+            .then_with(|| a.key.to_ascii_lowercase().cmp(&b.key.to_ascii_lowercase()))
+    });
+    (phases, groups)
+}
+
+/// Pages through a trial search and aggregates the matches by `by`.
+pub async fn summarize(
+    filters: &TrialSearchFilters,
+    by: TrialSummaryBy,
+    limit: usize,
+) -> Result<TrialSummary, BioMcpError> {
+    if limit == 0 {
+        return Err(BioMcpError::InvalidArgument(
+            "--limit must be >= 1 with --summarize-by".into(),
+        ));
+    }
+
+    let mut results: Vec<TrialSearchResult> = Vec::new();
+    let mut total = None;
+    let mut next_page: Option<String> = None;
+    let mut exhausted = false;
+    while results.len() < SUMMARY_MAX_TRIALS {
+        let offset = match filters.source {
+            TrialSource::ClinicalTrialsGov => 0,
+            TrialSource::NciCts | TrialSource::Ctis => results.len(),
+        };
+        let page = search_page(filters, SUMMARY_PAGE_SIZE, offset, next_page.take()).await?;
+        total = total.or(page.total);
+        let fetched = page.results.len();
+        results.extend(page.results);
+        next_page = page.next_page_token;
+        let more = match filters.source {
+            TrialSource::ClinicalTrialsGov => next_page.is_some(),
+            TrialSource::NciCts | TrialSource::Ctis => fetched == SUMMARY_PAGE_SIZE,
+        };
+        if fetched == 0 || !more {
+            exhausted = true;
+            break;
+        }
+    }
+    results.truncate(SUMMARY_MAX_TRIALS);
+
+    let trials_scanned = results.len();
+    let (phases, mut groups) = summarize_results(&results, by);
+    let group_count = groups.len();
+    groups.truncate(limit);
+    Ok(TrialSummary {
+        summarize_by: by,
+        truncated: total.map_or(!exhausted, |total| total > trials_scanned),
+        total,
+        trials_scanned,
+        group_count,
+        phases,
+        groups,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,88 @@
+//! Tests for trial portfolio summaries.
+
+use super::*;
+
+fn trial(
+    nct_id: &str,
+    phase: Option<&str>,
+    conditions: &[&str],
+    sponsor: &str,
+) -> TrialSearchResult {
+    TrialSearchResult {
+        nct_id: nct_id.into(),
+        title: format!("Study {nct_id}"),
+        status: "RECRUITING".into(),
+        phase: phase.map(str::to_string),
+        conditions: conditions.iter().map(|c| c.to_string()).collect(),
+        sponsor: Some(sponsor.into()),
+        registry: None,
+        cross_registered_nct: None,
+        eligibility_unchecked: Vec::new(),
+    }
+}
+
+#[test]
+fn summarize_results_counts_each_condition_once_per_trial() {
+    let results = vec![
+        trial(
+            "NCT1",
+            Some("PHASE3"),
+            &["Breast Cancer", "HER2-positive Breast Cancer"],
+            "Genentech, Inc.",
+        ),
+        trial(
+            "NCT2",
+            Some("PHASE3"),
+            &["breast cancer", "Breast Cancer"],
+            "Genentech, Inc.",
+        ),
+        trial(
+            "NCT3",
+            Some("PHASE1/PHASE2"),
+            &["Lung Cancer"],
+            "Genentech, Inc.",
+        ),
+        trial("NCT4", None, &[], "Genentech, Inc."),
+    ];
+
+    let (phases, groups) = summarize_results(&results, TrialSummaryBy::Condition);
+    assert_eq!(phases, vec!["PHASE1/PHASE2", "PHASE3", "unspecified"]);
+    assert_eq!(groups[0].key, "Breast Cancer");
+    assert_eq!(groups[0].trials, 2);
+    assert_eq!(groups[0].phases.get("PHASE3"), Some(&2));
+    assert_eq!(
+        groups.iter().map(|g| g.key.as_str()).collect::<Vec<_>>(),
+        vec![
+            "Breast Cancer",
+            "HER2-positive Breast Cancer",
+            "Lung Cancer",
+            "unspecified"
+        ]
+    );
+}
+
+#[test]
+fn summarize_results_by_phase_orders_groups_by_count() {
+    let results = vec![
+        trial("NCT1", Some("PHASE2"), &["A"], "X"),
+        trial("NCT2", Some("PHASE3"), &["B"], "X"),
+        trial("NCT3", Some("PHASE3"), &["C"], "X"),
+    ];
+    let (_, groups) = summarize_results(&results, TrialSummaryBy::Phase);
+    assert_eq!(groups[0].key, "PHASE3");
+    assert_eq!(groups[0].trials, 2);
+    assert_eq!(groups[1].key, "PHASE2");
+}
+
+#[test]
+fn summary_by_flag_rejects_unknown_values() {
+    assert_eq!(
+        TrialSummaryBy::from_flag("Condition").expect("valid flag"),
+        TrialSummaryBy::Condition
+    );
+    let err = TrialSummaryBy::from_flag("country").expect_err("invalid flag");
+    assert!(
+        err.to_string()
+            .contains("condition, phase, status, sponsor")
+    );
+}
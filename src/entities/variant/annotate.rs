@@ -0,0 +1,328 @@
+//! Variant annotation for local VCF files.
+//!
+//! Each ALT allele is converted to its genomic HGVS `_id`, looked up in
+//! MyVariant.info batches, and given ClinVar significance, gnomAD allele
+//! frequency, CADD PHRED, and REVEL. Results render as markdown, TSV (one row
+//! per allele), or the input VCF with `BIOMCP_*` INFO fields added.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::error::BioMcpError;
+use crate::sources::myvariant::{
+    MYVARIANT_FIELDS_SEARCH, MYVARIANT_GET_MANY_LIMIT, MyVariantClient,
+};
+use crate::utils::text::tsv_field;
+use crate::utils::vcf::{self, VcfFile};
+
+use super::GenomeAssembly;
+
+const INFO_PREFIX: &str = "BIOMCP_";
+const INFO_HEADERS: &[&str] = &[
+    "##INFO=<ID=BIOMCP_GENE,Number=A,Type=String,Description=\"Gene symbol from MyVariant.info (dbNSFP)\">",
+    "##INFO=<ID=BIOMCP_CLNSIG,Number=A,Type=String,Description=\"ClinVar clinical significance from MyVariant.info\">",
+    "##INFO=<ID=BIOMCP_GNOMAD_AF,Number=A,Type=Float,Description=\"gnomAD allele frequency from MyVariant.info\">",
+    "##INFO=<ID=BIOMCP_CADD,Number=A,Type=Float,Description=\"CADD PHRED score from MyVariant.info\">",
+    "##INFO=<ID=BIOMCP_REVEL,Number=A,Type=Float,Description=\"REVEL score from MyVariant.info (dbNSFP)\">",
+];
+const TSV_COLUMNS: &[&str] = &[
+    "chrom",
+    "pos",
+    "id",
+    "ref",
+    "alt",
+    "hgvs",
+    "gene",
+    "clinvar_significance",
+    "gnomad_af",
+    "cadd_phred",
+    "revel",
+];
+const DEFAULT_VCF_HEADER: &str = "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VcfAnnotation {
+    pub source: String,
+    pub assembly: GenomeAssembly,
+    pub records: usize,
+    /// Alleles with a genomic HGVS form, i.e. those sent to MyVariant.info.
+    pub queried: usize,
+    /// Queried alleles MyVariant.info returned a record for.
+    pub annotated: usize,
+    pub alleles: Vec<VcfAlleleAnnotation>,
+    #[serde(skip)]
+    pub(crate) vcf: VcfFile,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VcfAlleleAnnotation {
+    /// 1-based line number of the record in the input file.
+    pub line: usize,
+    pub chrom: String,
+    pub pos: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "ref")]
+    pub reference: String,
+    pub alt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hgvs: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gene: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub significance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gnomad_af: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cadd: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revel: Option<f64>,
+}
+
+fn present(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty() && value != ".").then(|| value.to_string())
+}
+
+/// Expands VCF records into one unannotated row per ALT allele.
+fn allele_rows(file: &VcfFile) -> Vec<VcfAlleleAnnotation> {
+    let mut rows = Vec::new();
+    for record in &file.records {
+        for alt in &record.alts {
+            rows.push(VcfAlleleAnnotation {
+                line: record.line,
+                chrom: record.chrom.clone(),
+                pos: record.pos,
+                id: present(&record.id),
+                reference: record.reference.clone(),
+                alt: alt.clone(),
+                hgvs: vcf::hgvs_genomic(&record.chrom, record.pos, &record.reference, alt),
+                gene: None,
+                significance: None,
+                gnomad_af: None,
+                cadd: None,
+                revel: None,
+            });
+        }
+    }
+    rows
+}
+
+/// Annotates every ALT allele of the VCF text read from `source`.
+pub async fn annotate_vcf(
+    content: &str,
+    source: &str,
+    assembly: GenomeAssembly,
+) -> Result<VcfAnnotation, BioMcpError> {
+    let file = vcf::parse(content)?;
+    if file.records.is_empty() {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "No variant records found in {source}"
+        )));
+    }
+    let mut alleles = allele_rows(&file);
+
+    let mut ids = alleles
+        .iter()
+        .filter_map(|row| row.hgvs.clone())
+        .collect::<Vec<_>>();
+    ids.sort();
+    ids.dedup();
+
+    let client = MyVariantClient::new()?;
+    let mut found = HashMap::new();
+    for chunk in ids.chunks(MYVARIANT_GET_MANY_LIMIT) {
+        let hits = client
+            .get_many(
+                chunk,
+                MYVARIANT_FIELDS_SEARCH,
+                assembly.myvariant_assembly(),
+            )
+            .await?;
+        for batch_hit in hits {
+            if let Some(hit) = batch_hit.hit {
+                found
+                    .entry(batch_hit.query)
+                    .or_insert_with(|| crate::transform::variant::from_myvariant_search_hit(&hit));
+            }
+        }
+    }
+
+    let mut queried = 0;
+    let mut annotated = 0;
+    for row in &mut alleles {
+        let Some(hgvs) = row.hgvs.as_deref() else {
+            continue;
+        };
+        queried += 1;
+        let Some(result) = found.get(hgvs) else {
+            continue;
+        };
+        annotated += 1;
+        row.gene = present(&result.gene);
+        row.significance = result.significance.clone();
+        row.gnomad_af = result.gnomad_af;
+        row.cadd = result.cadd_score;
+        row.revel = result.revel;
+    }
+
+    Ok(VcfAnnotation {
+        source: source.to_string(),
+        assembly,
+        records: file.records.len(),
+        queried,
+        annotated,
+        alleles,
+        vcf: file,
+    })
+}
+
+/// Encodes a value for an INFO field: spaces become `_`, and the characters
+/// VCF reserves there are percent-encoded.
+fn info_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for ch in value.trim().chars() {
+        match ch {
+            ' ' => out.push('_'),
+            '%' => out.push_str("%25"),
+            ',' => out.push_str("%2C"),
+            ';' => out.push_str("%3B"),
+            '=' => out.push_str("%3D"),
+            '\t' | '\n' | '\r' => out.push('_'),
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+fn number(value: Option<f64>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+impl VcfAnnotation {
+    /// Renders one row per ALT allele as TSV.
+    pub fn to_tsv(&self) -> String {
+        let mut out = TSV_COLUMNS.join("\t");
+        out.push('\n');
+        for row in &self.alleles {
+            let fields = [
+                row.chrom.clone(),
+                row.pos.to_string(),
+                row.id.clone().unwrap_or_default(),
+                row.reference.clone(),
+                row.alt.clone(),
+                row.hgvs.clone().unwrap_or_default(),
+                row.gene.clone().unwrap_or_default(),
+                row.significance.clone().unwrap_or_default(),
+                number(row.gnomad_af),
+                number(row.cadd),
+                number(row.revel),
+            ];
+            let line = fields
+                .iter()
+                .map(|field| tsv_field(field))
+                .collect::<Vec<_>>()
+                .join("\t");
+            out.push_str(&line);
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Renders the input VCF with `BIOMCP_*` INFO fields (`Number=A`, `.` for
+    /// alleles without a value). INFO fields from an earlier run are replaced.
+    pub fn to_vcf(&self) -> String {
+        let mut out = String::new();
+        if !self
+            .vcf
+            .meta
+            .iter()
+            .any(|line| line.starts_with("##fileformat="))
+        {
+            out.push_str("##fileformat=VCFv4.2\n");
+        }
+        for line in &self.vcf.meta {
+            if line.starts_with(&format!("##INFO=<ID={INFO_PREFIX}")) {
+                continue;
+            }
+            out.push_str(line);
+            out.push('\n');
+        }
+        for line in INFO_HEADERS {
+            out.push_str(line);
+            out.push('\n');
+        }
+        out.push_str(self.vcf.header.as_deref().unwrap_or(DEFAULT_VCF_HEADER));
+        out.push('\n');
+
+        let mut by_line: HashMap<usize, Vec<&VcfAlleleAnnotation>> = HashMap::new();
+        for row in &self.alleles {
+            by_line.entry(row.line).or_default().push(row);
+        }
+        for record in &self.vcf.records {
+            let rows = by_line.get(&record.line).cloned().unwrap_or_default();
+            let mut info = record.fields[7]
+                .split(';')
+                .map(str::trim)
+                .filter(|entry| {
+                    !entry.is_empty() && *entry != "." && !entry.starts_with(INFO_PREFIX)
+                })
+                .map(str::to_string)
+                .collect::<Vec<_>>();
+            let mut push = |key: &str, values: Vec<Option<String>>| {
+                if values.iter().any(Option::is_some) {
+                    let joined = values
+                        .into_iter()
+                        .map(|value| value.unwrap_or_else(|| ".".to_string()))
+                        .collect::<Vec<_>>()
+                        .join(",");
+                    info.push(format!("{INFO_PREFIX}{key}={joined}"));
+                }
+            };
+            push(
+                "GENE",
+                rows.iter()
+                    .map(|row| row.gene.as_deref().map(info_value))
+                    .collect(),
+            );
+            push(
+                "CLNSIG",
+                rows.iter()
+                    .map(|row| row.significance.as_deref().map(info_value))
+                    .collect(),
+            );
+            push(
+                "GNOMAD_AF",
+                rows.iter()
+                    .map(|row| row.gnomad_af.map(|v| v.to_string()))
+                    .collect(),
+            );
+            push(
+                "CADD",
+                rows.iter()
+                    .map(|row| row.cadd.map(|v| v.to_string()))
+                    .collect(),
+            );
+            push(
+                "REVEL",
+                rows.iter()
+                    .map(|row| row.revel.map(|v| v.to_string()))
+                    .collect(),
+            );
+
+            let mut fields = record.fields.clone();
+            fields[7] = if info.is_empty() {
+                ".".to_string()
+            } else {
+                info.join(";")
+            };
+            out.push_str(&fields.join("\t"));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests;
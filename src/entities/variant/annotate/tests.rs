@@ -0,0 +1,102 @@
+//! Sidecar tests for VCF allele annotation and annotated output.
+
+use super::*;
+use crate::entities::variant::test_support::*;
+
+const SAMPLE_VCF: &str = "##fileformat=VCFv4.2\n\
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Depth\">\n\
+##INFO=<ID=BIOMCP_CADD,Number=A,Type=Float,Description=\"stale\">\n\
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n\
+7\t140453136\trs113488022\tA\tT,C\t50\tPASS\tDP=20;BIOMCP_CADD=1\n\
+1\t1000\t.\tA\t<DEL>\t.\t.\t.\n";
+
+#[test]
+fn assembly_flag_accepts_ucsc_aliases() {
+    assert_eq!(
+        GenomeAssembly::from_flag("hg38").expect("valid flag"),
+        GenomeAssembly::Grch38
+    );
+    assert_eq!(GenomeAssembly::Grch37.as_str(), "GRCh37");
+    let err = GenomeAssembly::from_flag("t2t").expect_err("invalid flag");
+    assert!(err.to_string().contains("Expected 'grch37' or 'grch38'"));
+}
+
+#[test]
+fn info_value_escapes_reserved_characters() {
+    assert_eq!(
+        info_value("Pathogenic/Likely pathogenic, drug response"),
+        "Pathogenic/Likely_pathogenic%2C_drug_response"
+    );
+    assert_eq!(info_value("a;b=c%"), "a%3Bb%3Dc%25");
+}
+
+#[tokio::test]
+async fn annotate_vcf_batches_alleles_and_writes_tsv_and_vcf() {
+    let _lock = lock_env().await;
+    let server = MockServer::start().await;
+    let _base = set_env_var("BIOMCP_MYVARIANT_BASE", Some(&server.uri()));
+    Mock::given(method("POST"))
+        .and(path("/variant"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "query": "chr7:g.140453136A>C",
+                "notfound": true
+            },
+            {
+                "query": "chr7:g.140453136A>T",
+                "_id": "chr7:g.140453136A>T",
+                "dbnsfp": {"genename": "BRAF", "revel": {"score": 0.931}},
+                "cadd": {"phred": 32},
+                "clinvar": {"rcv": {"clinical_significance": "Pathogenic"}},
+                "gnomad_exome": {"af": {"af": 0.0000039}}
+            }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let annotation = annotate_vcf(SAMPLE_VCF, "sample.vcf", GenomeAssembly::Grch37)
+        .await
+        .expect("annotation");
+    assert_eq!(annotation.records, 2);
+    assert_eq!(annotation.alleles.len(), 3);
+    assert_eq!((annotation.queried, annotation.annotated), (2, 1));
+    let braf = &annotation.alleles[0];
+    assert_eq!(braf.hgvs.as_deref(), Some("chr7:g.140453136A>T"));
+    assert_eq!(braf.gene.as_deref(), Some("BRAF"));
+    assert_eq!(braf.significance.as_deref(), Some("Pathogenic"));
+    assert_eq!(braf.cadd, Some(32.0));
+    assert_eq!(annotation.alleles[2].hgvs, None);
+
+    let tsv = annotation.to_tsv();
+    assert!(tsv.starts_with(
+        "chrom\tpos\tid\tref\talt\thgvs\tgene\tclinvar_significance\tgnomad_af\tcadd_phred\trevel\n"
+    ));
+    assert!(tsv.contains(
+        "7\t140453136\trs113488022\tA\tT\tchr7:g.140453136A>T\tBRAF\tPathogenic\t0.0000039\t32\t0.931\n"
+    ));
+    assert!(tsv.contains("1\t1000\t\tA\t<DEL>\t\t\t\t\t\t\n"));
+
+    let vcf = annotation.to_vcf();
+    assert!(vcf.contains("##INFO=<ID=BIOMCP_CLNSIG,Number=A,Type=String"));
+    assert!(!vcf.contains("stale"));
+    assert!(vcf.contains(
+        "PASS\tDP=20;BIOMCP_GENE=BRAF,.;BIOMCP_CLNSIG=Pathogenic,.;BIOMCP_GNOMAD_AF=0.0000039,.;BIOMCP_CADD=32,.;BIOMCP_REVEL=0.931,.\n"
+    ));
+    assert!(vcf.ends_with("1\t1000\t.\tA\t<DEL>\t.\t.\t.\n"));
+}
+
+#[tokio::test]
+async fn annotate_vcf_rejects_files_without_records() {
+    let err = annotate_vcf(
+        "##fileformat=VCFv4.2\n",
+        "empty.vcf",
+        GenomeAssembly::Grch38,
+    )
+    .await
+    .expect_err("no records");
+    assert!(
+        err.to_string()
+            .contains("No variant records found in empty.vcf")
+    );
+}
@@ -0,0 +1,324 @@
+//! ClinVar submission-spreadsheet rows pre-filled from collected variant evidence.
+//!
+//! The row follows the column names of the "Variant" sheet in ClinVar's
+//! submission template. Condition IDs, the assertion method, and the final
+//! criteria stay with the curator; the suggested ACMG/AMP codes are a starting
+//! point derived from population frequency, consequence, and REVEL only.
+
+use serde::Serialize;
+
+use crate::error::BioMcpError;
+use crate::utils::text::tsv_field;
+
+use super::Variant;
+use super::resolution::hgvs_coords_re;
+
+const CLINVAR_TEMPLATE_COLUMNS: &[&str] = &[
+    "Local ID",
+    "Gene symbol",
+    "Reference sequence",
+    "HGVS",
+    "Assembly",
+    "Chromosome",
+    "Start",
+    "Reference allele",
+    "Alternate allele",
+    "Condition ID type",
+    "Condition ID value",
+    "Preferred condition name",
+    "Clinical significance",
+    "Date last evaluated",
+    "Clinical significance citations",
+    "Comment on clinical significance",
+    "Collection method",
+    "Allele origin",
+    "Affected status",
+];
+
+const CLINVAR_CLASSIFICATIONS: &[&str] = &[
+    "Pathogenic",
+    "Likely pathogenic",
+    "Uncertain significance",
+    "Likely benign",
+    "Benign",
+];
+
+const LOSS_OF_FUNCTION_CONSEQUENCES: &[&str] = &[
+    "frameshift_variant",
+    "stop_gained",
+    "splice_donor_variant",
+    "splice_acceptor_variant",
+    "start_lost",
+];
+
+const BA1_MIN_AF: f64 = 0.05;
+const BS1_MIN_AF: f64 = 0.01;
+const PM2_MAX_AF: f64 = 0.0001;
+/// ClinGen SVI calibrated REVEL thresholds for supporting-level evidence.
+const REVEL_PP3_MIN: f64 = 0.644;
+const REVEL_BP4_MAX: f64 = 0.290;
+const MAX_CITATIONS: usize = 10;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClinVarCriterion {
+    pub code: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClinVarSubmissionDraft {
+    pub local_id: String,
+    pub gene_symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_sequence: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hgvs: Option<String>,
+    pub assembly: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chromosome: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_allele: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternate_allele: Option<String>,
+    pub preferred_condition_name: String,
+    pub clinical_significance: String,
+    pub date_last_evaluated: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub citations: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suggested_criteria: Vec<ClinVarCriterion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub existing_clinvar_significance: Option<String>,
+    pub collection_method: String,
+    pub allele_origin: String,
+    pub affected_status: String,
+}
+
+/// Maps a user-supplied classification onto ClinVar's germline terms.
+pub fn parse_clinvar_classification(value: &str) -> Result<&'static str, BioMcpError> {
+    let normalized = value
+        .trim()
+        .replace(['_', '-'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let normalized = match normalized.to_ascii_lowercase().as_str() {
+        "vus" => "uncertain significance".to_string(),
+        "lp" => "likely pathogenic".to_string(),
+        "lb" => "likely benign".to_string(),
+        other => other.to_string(),
+    };
+    CLINVAR_CLASSIFICATIONS
+        .iter()
+        .copied()
+        .find(|term| term.eq_ignore_ascii_case(&normalized))
+        .ok_or_else(|| {
+            BioMcpError::InvalidArgument(format!(
+                "Unknown --classification '{}'. Expected one of: {}",
+                value.trim(),
+                CLINVAR_CLASSIFICATIONS.join(", ")
+            ))
+        })
+}
+
+fn suggested_criteria(variant: &Variant) -> Vec<ClinVarCriterion> {
+    let mut out = Vec::new();
+    let mut push = |code: &str, reason: String| {
+        out.push(ClinVarCriterion {
+            code: code.to_string(),
+            reason,
+        });
+    };
+
+    let consequence = variant
+        .vep
+        .as_ref()
+        .and_then(|vep| vep.most_severe_consequence.as_deref())
+        .or(variant.consequence.as_deref())
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    if let Some(consequence) = consequence
+        && LOSS_OF_FUNCTION_CONSEQUENCES
+            .iter()
+            .any(|term| consequence.eq_ignore_ascii_case(term))
+    {
+        push(
+            "PVS1",
+            format!("{consequence}; confirm loss of function is a disease mechanism"),
+        );
+    }
+
+    match variant.gnomad_af {
+        Some(af) if af > BA1_MIN_AF => push("BA1", format!("gnomAD AF {af:.4} > 5%")),
+        Some(af) if af > BS1_MIN_AF => push(
+            "BS1",
+            format!("gnomAD AF {af:.4} > 1%; compare with disease prevalence"),
+        ),
+        Some(af) if af < PM2_MAX_AF => push("PM2", format!("gnomAD AF {af:.6} < 0.01%")),
+        None => push("PM2", "no gnomAD allele frequency reported".to_string()),
+        Some(_) => {}
+    }
+
+    let revel = variant
+        .expanded_predictions
+        .iter()
+        .find(|row| row.tool.eq_ignore_ascii_case("REVEL"))
+        .and_then(|row| row.score);
+    match revel {
+        Some(score) if score >= REVEL_PP3_MIN => {
+            push("PP3", format!("REVEL {score:.3} >= {REVEL_PP3_MIN}"))
+        }
+        Some(score) if score <= REVEL_BP4_MAX => {
+            push("BP4", format!("REVEL {score:.3} <= {REVEL_BP4_MAX}"))
+        }
+        _ => {}
+    }
+    out
+}
+
+fn genomic_position(variant_id: &str) -> (Option<String>, Option<String>) {
+    let Some((chrom, change)) = variant_id.trim().split_once(":g.") else {
+        return (None, None);
+    };
+    let chrom = chrom.strip_prefix("chr").unwrap_or(chrom);
+    let start = change
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect::<String>();
+    (
+        Some(chrom.to_string()).filter(|value| !value.is_empty()),
+        Some(start).filter(|value| !value.is_empty()),
+    )
+}
+
+/// Builds one submission row for `variant`; `citations` are PMIDs.
+pub fn clinvar_submission_draft(
+    variant: &Variant,
+    classification: &str,
+    condition: Option<&str>,
+    citations: &[String],
+) -> Result<ClinVarSubmissionDraft, BioMcpError> {
+    let clinical_significance = parse_clinvar_classification(classification)?;
+    let (chromosome, start) = genomic_position(&variant.id);
+    let (reference_allele, alternate_allele) = hgvs_coords_re()
+        .captures(variant.id.trim())
+        .map(|caps| (Some(caps[3].to_string()), Some(caps[4].to_string())))
+        .unwrap_or((None, None));
+    let reference_sequence = variant
+        .transcripts
+        .iter()
+        .find(|row| row.mane.is_some())
+        .or_else(|| variant.transcripts.iter().find(|row| row.canonical))
+        .map(|row| row.transcript.clone());
+    let preferred_condition_name = condition
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .or_else(|| {
+            variant
+                .top_disease
+                .as_ref()
+                .map(|row| row.condition.clone())
+        })
+        .or_else(|| variant.conditions.first().cloned())
+        .unwrap_or_else(|| "not provided".to_string());
+
+    let mut pmids = Vec::new();
+    for pmid in citations
+        .iter()
+        .chain(variant.supporting_pmids.iter().flatten())
+    {
+        let pmid = pmid.trim().trim_start_matches("PMID:").trim();
+        if !pmid.is_empty()
+            && pmid.chars().all(|ch| ch.is_ascii_digit())
+            && !pmids.iter().any(|seen: &String| seen == pmid)
+        {
+            pmids.push(pmid.to_string());
+        }
+    }
+    pmids.truncate(MAX_CITATIONS);
+
+    Ok(ClinVarSubmissionDraft {
+        local_id: variant.id.clone(),
+        gene_symbol: variant.gene.clone(),
+        reference_sequence,
+        hgvs: variant.hgvs_c.clone(),
+        assembly: "GRCh37".to_string(),
+        chromosome,
+        start,
+        reference_allele,
+        alternate_allele,
+        preferred_condition_name,
+        clinical_significance: clinical_significance.to_string(),
+        date_last_evaluated: time::OffsetDateTime::now_utc().date().to_string(),
+        citations: pmids,
+        suggested_criteria: suggested_criteria(variant),
+        existing_clinvar_significance: variant.significance.clone(),
+        collection_method: "curation".to_string(),
+        allele_origin: "germline".to_string(),
+        affected_status: "not provided".to_string(),
+    })
+}
+
+impl ClinVarSubmissionDraft {
+    /// Criteria summary for the "Comment on clinical significance" column.
+    pub fn comment(&self) -> String {
+        if self.suggested_criteria.is_empty() {
+            return String::new();
+        }
+        let criteria = self
+            .suggested_criteria
+            .iter()
+            .map(|row| format!("{} ({})", row.code, row.reason))
+            .collect::<Vec<_>>()
+            .join("; ");
+        format!("Suggested ACMG/AMP criteria, pending curator review: {criteria}")
+    }
+
+    /// Renders the header and one data row in ClinVar template column order.
+    pub fn to_tsv(&self) -> String {
+        let citations = self
+            .citations
+            .iter()
+            .map(|pmid| format!("PMID:{pmid}"))
+            .collect::<Vec<_>>()
+            .join(";");
+        let fields = [
+            self.local_id.clone(),
+            self.gene_symbol.clone(),
+            self.reference_sequence.clone().unwrap_or_default(),
+            self.hgvs.clone().unwrap_or_default(),
+            self.assembly.clone(),
+            self.chromosome.clone().unwrap_or_default(),
+            self.start.clone().unwrap_or_default(),
+            self.reference_allele.clone().unwrap_or_default(),
+            self.alternate_allele.clone().unwrap_or_default(),
+            String::new(),
+            String::new(),
+            self.preferred_condition_name.clone(),
+            self.clinical_significance.clone(),
+            self.date_last_evaluated.clone(),
+            citations,
+            self.comment(),
+            self.collection_method.clone(),
+            self.allele_origin.clone(),
+            self.affected_status.clone(),
+        ];
+        let mut out = CLINVAR_TEMPLATE_COLUMNS.join("\t");
+        out.push('\n');
+        out.push_str(
+            &fields
+                .iter()
+                .map(|field| tsv_field(field))
+                .collect::<Vec<_>>()
+                .join("\t"),
+        );
+        out.push('\n');
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,103 @@
+//! Sidecar tests for ClinVar submission-template rows.
+
+use super::*;
+
+fn variant(value: serde_json::Value) -> Variant {
+    serde_json::from_value(value).expect("variant should deserialize")
+}
+
+#[test]
+fn parse_clinvar_classification_normalizes_case_and_shorthand() {
+    assert_eq!(
+        parse_clinvar_classification("likely_pathogenic").expect("valid"),
+        "Likely pathogenic"
+    );
+    assert_eq!(
+        parse_clinvar_classification("VUS").expect("valid"),
+        "Uncertain significance"
+    );
+    let err = parse_clinvar_classification("risk factor").expect_err("unsupported");
+    assert!(err.to_string().contains("Expected one of: Pathogenic"));
+}
+
+#[test]
+fn draft_prefills_coordinates_condition_citations_and_criteria() {
+    let variant = variant(serde_json::json!({
+        "id": "chr13:g.32914438delT",
+        "gene": "BRCA2",
+        "hgvs_c": "c.5946delT",
+        "consequence": "frameshift_variant",
+        "significance": "Pathogenic",
+        "top_disease": {"condition": "Hereditary breast ovarian cancer syndrome", "reports": 40},
+        "transcripts": [
+            {"transcript": "ENST00000544455", "canonical": false},
+            {"transcript": "ENST00000380152", "canonical": true}
+        ],
+        "supporting_pmids": ["29446767"]
+    }));
+
+    let draft = clinvar_submission_draft(
+        &variant,
+        "Likely pathogenic",
+        None,
+        &["PMID:25741868".to_string(), "29446767".to_string()],
+    )
+    .expect("draft");
+    assert_eq!(draft.reference_sequence.as_deref(), Some("ENST00000380152"));
+    assert_eq!(draft.chromosome.as_deref(), Some("13"));
+    assert_eq!(draft.start.as_deref(), Some("32914438"));
+    assert!(draft.reference_allele.is_none());
+    assert_eq!(
+        draft.preferred_condition_name,
+        "Hereditary breast ovarian cancer syndrome"
+    );
+    assert_eq!(draft.citations, vec!["25741868", "29446767"]);
+    let codes = draft
+        .suggested_criteria
+        .iter()
+        .map(|row| row.code.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(codes, vec!["PVS1", "PM2"]);
+
+    let tsv = draft.to_tsv();
+    let mut lines = tsv.lines();
+    let header = lines
+        .next()
+        .expect("header")
+        .split('\t')
+        .collect::<Vec<_>>();
+    let row = lines.next().expect("row").split('\t').collect::<Vec<_>>();
+    assert_eq!(header.len(), row.len());
+    let column = |name: &str| row[header.iter().position(|h| *h == name).expect(name)];
+    assert_eq!(column("HGVS"), "c.5946delT");
+    assert_eq!(column("Clinical significance"), "Likely pathogenic");
+    assert_eq!(
+        column("Clinical significance citations"),
+        "PMID:25741868;PMID:29446767"
+    );
+    assert!(
+        column("Comment on clinical significance")
+            .starts_with("Suggested ACMG/AMP criteria, pending curator review: PVS1")
+    );
+}
+
+#[test]
+fn draft_uses_explicit_condition_and_snv_alleles() {
+    let variant = variant(serde_json::json!({
+        "id": "chr7:g.140453136A>T",
+        "gene": "BRAF",
+        "gnomad_af": 0.2,
+        "expanded_predictions": [{"tool": "REVEL", "score": 0.93}]
+    }));
+
+    let draft = clinvar_submission_draft(&variant, "benign", Some("Melanoma"), &[]).expect("draft");
+    assert_eq!(draft.preferred_condition_name, "Melanoma");
+    assert_eq!(draft.reference_allele.as_deref(), Some("A"));
+    assert_eq!(draft.alternate_allele.as_deref(), Some("T"));
+    let codes = draft
+        .suggested_criteria
+        .iter()
+        .map(|row| row.code.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(codes, vec!["BA1", "PP3"]);
+}
@@ -0,0 +1,185 @@
+//! Case/control allele frequency comparison for one variant.
+//!
+//! Counts are alternate alleles over total alleles, the same AC/AN convention
+//! gnomAD uses, so `12/3400` is 12 alternate alleles among 1,700 diploid
+//! individuals. The cohorts are compared with an allelic odds ratio (Woolf
+//! 95% CI) and a two-sided Fisher exact test, then set against the gnomAD
+//! frequencies already collected in the variant's `population` section.
+
+use serde::Serialize;
+
+use crate::error::BioMcpError;
+use crate::utils::stats::{build_log_factorial, fisher_exact_two_tailed};
+
+use super::Variant;
+
+/// Keeps the log-factorial table for the Fisher test small; gnomAD's largest
+/// allele numbers are below this.
+const MAX_COHORT_ALLELES: usize = 2_000_000;
+const Z_95: f64 = 1.959_964;
+/// Control AF more than this many fold away from gnomAD suggests an ancestry
+/// or calling mismatch.
+const CONTROL_DRIFT_FOLD: f64 = 2.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlleleCount {
+    pub alt: usize,
+    pub total: usize,
+}
+
+impl AlleleCount {
+    /// Parses `ALT/TOTAL`; `flag` names the option in error messages.
+    pub fn parse(flag: &str, value: &str) -> Result<Self, BioMcpError> {
+        let invalid = || {
+            BioMcpError::InvalidArgument(format!(
+                "{flag} must be ALT/TOTAL allele counts, e.g. 12/3400 (got '{}')",
+                value.trim()
+            ))
+        };
+        let (alt, total) = value.trim().split_once('/').ok_or_else(invalid)?;
+        let alt = alt.trim().parse::<usize>().map_err(|_| invalid())?;
+        let total = total.trim().parse::<usize>().map_err(|_| invalid())?;
+        if total == 0 {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "{flag} total allele count must be greater than 0"
+            )));
+        }
+        if alt > total {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "{flag} alternate allele count {alt} exceeds total {total}"
+            )));
+        }
+        if total > MAX_COHORT_ALLELES {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "{flag} total allele count must be at most {MAX_COHORT_ALLELES}"
+            )));
+        }
+        Ok(Self { alt, total })
+    }
+
+    fn af(self) -> f64 {
+        self.alt as f64 / self.total as f64
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CohortAlleleFrequency {
+    pub alt: usize,
+    pub total: usize,
+    pub af: f64,
+}
+
+impl From<AlleleCount> for CohortAlleleFrequency {
+    fn from(count: AlleleCount) -> Self {
+        Self {
+            alt: count.alt,
+            total: count.total,
+            af: count.af(),
+        }
+    }
+}
+
+/// A gnomAD frequency with the case AF expressed as a multiple of it.
+#[derive(Debug, Clone, Serialize)]
+pub struct GnomadAfComparison {
+    pub population: String,
+    pub af: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub case_fold: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CohortAfComparison {
+    pub variant_id: String,
+    pub gene: String,
+    pub cases: CohortAlleleFrequency,
+    pub controls: CohortAlleleFrequency,
+    pub odds_ratio: f64,
+    pub odds_ratio_ci_low: f64,
+    pub odds_ratio_ci_high: f64,
+    /// A zero cell forced the Haldane-Anscombe +0.5 correction.
+    pub continuity_corrected: bool,
+    pub fisher_p_value: f64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gnomad: Vec<GnomadAfComparison>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+fn gnomad_row(population: &str, af: f64, cases: AlleleCount) -> GnomadAfComparison {
+    GnomadAfComparison {
+        population: population.to_string(),
+        af,
+        case_fold: (af > 0.0).then(|| cases.af() / af),
+    }
+}
+
+/// Compares case and control allele counts for `variant`, which should carry
+/// its `population` section.
+pub fn compare_cohort_af(
+    variant: &Variant,
+    cases: AlleleCount,
+    controls: AlleleCount,
+) -> CohortAfComparison {
+    let (a, b) = (cases.alt, cases.total - cases.alt);
+    let (c, d) = (controls.alt, controls.total - controls.alt);
+    let continuity_corrected = a == 0 || b == 0 || c == 0 || d == 0;
+    let correction = if continuity_corrected { 0.5 } else { 0.0 };
+    let [a_f, b_f, c_f, d_f] = [a, b, c, d].map(|cell| cell as f64 + correction);
+    let log_or = ((a_f * d_f) / (b_f * c_f)).ln();
+    let se = (1.0 / a_f + 1.0 / b_f + 1.0 / c_f + 1.0 / d_f).sqrt();
+
+    let log_fact = build_log_factorial(cases.total + controls.total);
+    let fisher_p_value = fisher_exact_two_tailed(a, b, c, d, &log_fact);
+
+    let mut gnomad = Vec::new();
+    if let Some(af) = variant.gnomad_af {
+        gnomad.push(gnomad_row("All", af, cases));
+    }
+    if let Some(breakdown) = variant.population_breakdown.as_ref() {
+        gnomad.extend(
+            breakdown
+                .populations
+                .iter()
+                .filter(|row| !row.is_subgroup)
+                .map(|row| gnomad_row(&row.population, row.af, cases)),
+        );
+    }
+
+    let mut notes = Vec::new();
+    if continuity_corrected {
+        notes.push(
+            "A zero count is present; the odds ratio adds 0.5 to every cell (Haldane-Anscombe)."
+                .to_string(),
+        );
+    }
+    match variant.gnomad_af {
+        None => notes.push("gnomAD reports no allele frequency for this variant.".to_string()),
+        Some(af) if af > 0.0 => {
+            let fold = controls.af() / af;
+            if fold > CONTROL_DRIFT_FOLD || fold < 1.0 / CONTROL_DRIFT_FOLD {
+                notes.push(format!(
+                    "Control AF is {fold:.1}x the gnomAD AF; check that controls are ancestry-matched."
+                ));
+            }
+        }
+        Some(_) => {}
+    }
+
+    CohortAfComparison {
+        variant_id: variant.id.clone(),
+        gene: variant.gene.clone(),
+        cases: cases.into(),
+        controls: controls.into(),
+        odds_ratio: log_or.exp(),
+        odds_ratio_ci_low: (log_or - Z_95 * se).exp(),
+        odds_ratio_ci_high: (log_or + Z_95 * se).exp(),
+        continuity_corrected,
+        fisher_p_value,
+        gnomad,
+        notes,
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,105 @@
+//! Sidecar tests for case/control allele frequency comparison.
+
+use super::*;
+
+fn variant(value: serde_json::Value) -> Variant {
+    serde_json::from_value(value).expect("variant should deserialize")
+}
+
+fn count(value: &str) -> AlleleCount {
+    AlleleCount::parse("--cases", value).expect("valid count")
+}
+
+#[test]
+fn allele_count_parse_accepts_alt_over_total_and_rejects_bad_input() {
+    assert_eq!(
+        count(" 12 / 3400 "),
+        AlleleCount {
+            alt: 12,
+            total: 3400
+        }
+    );
+
+    for (value, message) in [
+        (
+            "12",
+            "--controls must be ALT/TOTAL allele counts, e.g. 12/3400",
+        ),
+        ("a/10", "--controls must be ALT/TOTAL allele counts"),
+        ("-1/10", "--controls must be ALT/TOTAL allele counts"),
+        (
+            "1/0",
+            "--controls total allele count must be greater than 0",
+        ),
+        (
+            "11/10",
+            "--controls alternate allele count 11 exceeds total 10",
+        ),
+        (
+            "1/2000001",
+            "--controls total allele count must be at most 2000000",
+        ),
+    ] {
+        let err = AlleleCount::parse("--controls", value).expect_err(value);
+        assert!(
+            err.to_string().contains(message),
+            "{value}: unexpected error {err}"
+        );
+    }
+}
+
+#[test]
+fn compare_cohort_af_reports_odds_ratio_fisher_p_and_gnomad_folds() {
+    let variant = variant(serde_json::json!({
+        "id": "rs80357906",
+        "gene": "BRCA1",
+        "gnomad_af": 0.0004,
+        "population_breakdown": {
+            "populations": [
+                {"population": "nfe", "af": 0.0008},
+                {"population": "nfe_swe", "af": 0.002, "is_subgroup": true},
+                {"population": "afr", "af": 0.0}
+            ]
+        }
+    }));
+
+    let result = compare_cohort_af(&variant, count("12/3400"), count("1/5600"));
+    assert_eq!(result.gene, "BRCA1");
+    assert_eq!((result.cases.alt, result.cases.total), (12, 3400));
+    assert!((result.controls.af - 1.0 / 5600.0).abs() < 1e-12);
+    assert!(!result.continuity_corrected);
+    assert!((result.odds_ratio - 19.831_168).abs() < 1e-4);
+    assert!((result.odds_ratio_ci_low - 2.577_48).abs() < 1e-3);
+    assert!((result.odds_ratio_ci_high - 152.581).abs() < 1e-2);
+    assert!((result.fisher_p_value - 7.076_306_588e-5).abs() < 1e-9);
+
+    let populations = result
+        .gnomad
+        .iter()
+        .map(|row| row.population.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(populations, vec!["All", "nfe", "afr"]);
+    assert!((result.gnomad[0].case_fold.expect("fold") - 8.823_529).abs() < 1e-4);
+    assert!(result.gnomad[2].case_fold.is_none());
+    assert_eq!(
+        result.notes,
+        vec!["Control AF is 0.4x the gnomAD AF; check that controls are ancestry-matched."]
+    );
+}
+
+#[test]
+fn compare_cohort_af_corrects_zero_cells_and_notes_missing_gnomad() {
+    let variant = variant(serde_json::json!({"id": "rs1", "gene": "TP53"}));
+
+    let result = compare_cohort_af(&variant, count("0/1000"), count("5/1000"));
+    assert!(result.continuity_corrected);
+    assert!((result.odds_ratio - 0.090_455).abs() < 1e-5);
+    assert!((result.fisher_p_value - 0.062_187_578).abs() < 1e-7);
+    assert!(result.gnomad.is_empty());
+    assert_eq!(result.notes.len(), 2);
+    assert!(result.notes[0].contains("Haldane-Anscombe"));
+    assert_eq!(
+        result.notes[1],
+        "gnomAD reports no allele frequency for this variant."
+    );
+}
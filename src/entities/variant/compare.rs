@@ -0,0 +1,221 @@
+//! Side-by-side comparison of two variants.
+//!
+//! Both variants are fetched concurrently with their ClinVar, population,
+//! predictions, and CIViC sections, then reduced to the fields clinicians
+//! usually weigh when choosing between candidates. OncoKB levels are added
+//! when `ONCOKB_TOKEN` is set.
+
+use serde::Serialize;
+use tracing::warn;
+
+use crate::error::BioMcpError;
+
+use super::Variant;
+
+const COMPARE_SECTIONS: &[&str] = &["clinvar", "population", "predictions", "civic"];
+const ONCOKB_TOKEN_NOTE: &str = "Set ONCOKB_TOKEN to include OncoKB levels.";
+
+/// The compared fields of one variant.
+#[derive(Debug, Clone, Serialize)]
+pub struct VariantComparisonSide {
+    pub id: String,
+    pub label: String,
+    pub gene: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub significance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clinvar_review_stars: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gnomad_af: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cadd_score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revel_score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oncokb_level: Option<String>,
+    pub civic_evidence_count: usize,
+    pub civic_assertion_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VariantComparison {
+    pub first: VariantComparisonSide,
+    pub second: VariantComparisonSide,
+    /// Names of the fields whose values differ, e.g. `significance`.
+    pub differences: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+fn variant_label(variant: &Variant) -> String {
+    let gene = variant.gene.trim();
+    match variant.hgvs_p.as_deref().map(str::trim) {
+        Some(hgvs_p) if !gene.is_empty() && !hgvs_p.is_empty() => format!("{gene} {hgvs_p}"),
+        _ => variant.id.trim().to_string(),
+    }
+}
+
+fn revel_score(variant: &Variant) -> Option<f64> {
+    variant
+        .expanded_predictions
+        .iter()
+        .find(|score| score.tool.eq_ignore_ascii_case("REVEL"))
+        .and_then(|score| score.score)
+}
+
+/// Evidence and assertion totals, preferring live CIViC counts over the
+/// evidence cached on the MyVariant.info record.
+fn civic_counts(variant: &Variant) -> (usize, usize) {
+    let Some(civic) = variant.civic.as_ref() else {
+        return (0, 0);
+    };
+    match civic.graphql.as_ref() {
+        Some(context) => (context.evidence_total_count, context.assertion_total_count),
+        None => (civic.cached_evidence.len(), 0),
+    }
+}
+
+fn comparison_side(variant: &Variant, oncokb_level: Option<String>) -> VariantComparisonSide {
+    let (civic_evidence_count, civic_assertion_count) = civic_counts(variant);
+    VariantComparisonSide {
+        id: variant.id.clone(),
+        label: variant_label(variant),
+        gene: variant.gene.clone(),
+        significance: variant
+            .significance
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string),
+        clinvar_review_stars: variant.clinvar_review_stars,
+        gnomad_af: variant.gnomad_af,
+        cadd_score: variant.cadd_score,
+        revel_score: revel_score(variant),
+        oncokb_level,
+        civic_evidence_count,
+        civic_assertion_count,
+    }
+}
+
+fn differences(first: &VariantComparisonSide, second: &VariantComparisonSide) -> Vec<String> {
+    let same_text = |a: &Option<String>, b: &Option<String>| match (a, b) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        (None, None) => true,
+        _ => false,
+    };
+    [
+        ("gene", !first.gene.eq_ignore_ascii_case(&second.gene)),
+        (
+            "significance",
+            !same_text(&first.significance, &second.significance),
+        ),
+        (
+            "clinvar_review_stars",
+            first.clinvar_review_stars != second.clinvar_review_stars,
+        ),
+        ("gnomad_af", first.gnomad_af != second.gnomad_af),
+        ("cadd_score", first.cadd_score != second.cadd_score),
+        ("revel_score", first.revel_score != second.revel_score),
+        (
+            "oncokb_level",
+            !same_text(&first.oncokb_level, &second.oncokb_level),
+        ),
+        (
+            "civic_evidence_count",
+            first.civic_evidence_count != second.civic_evidence_count,
+        ),
+        (
+            "civic_assertion_count",
+            first.civic_assertion_count != second.civic_assertion_count,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, differs)| *differs)
+    .map(|(field, _)| field.to_string())
+    .collect()
+}
+
+/// Builds the comparison from two fetched variants and their OncoKB levels.
+fn compare_variant_cards(
+    first: &Variant,
+    second: &Variant,
+    oncokb_levels: [Option<String>; 2],
+    notes: Vec<String>,
+) -> VariantComparison {
+    let [first_level, second_level] = oncokb_levels;
+    let first = comparison_side(first, first_level);
+    let second = comparison_side(second, second_level);
+    let differences = differences(&first, &second);
+    VariantComparison {
+        first,
+        second,
+        differences,
+        notes,
+    }
+}
+
+/// Highest OncoKB level for `id`; `Ok(None)` when OncoKB cannot annotate it
+/// (no protein change) or has no level.
+async fn oncokb_level(id: &str) -> Result<Option<String>, BioMcpError> {
+    match super::oncokb(id).await {
+        Ok(result) => Ok(result.level),
+        Err(BioMcpError::InvalidArgument(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+pub async fn compare_variants(
+    first_id: &str,
+    second_id: &str,
+) -> Result<VariantComparison, BioMcpError> {
+    let (first_id, second_id) = (first_id.trim(), second_id.trim());
+    if first_id.is_empty() || second_id.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "Two variant IDs are required. Example: biomcp compare variants \"BRAF V600E\" \"BRAF V600K\"".into(),
+        ));
+    }
+    if first_id.eq_ignore_ascii_case(second_id) {
+        return Err(BioMcpError::InvalidArgument(
+            "compare variants needs two different variants".into(),
+        ));
+    }
+
+    let sections = COMPARE_SECTIONS
+        .iter()
+        .map(|section| section.to_string())
+        .collect::<Vec<_>>();
+    let (first, second) = tokio::join!(
+        super::get(first_id, &sections),
+        super::get(second_id, &sections)
+    );
+    let (first, second) = (first?, second?);
+
+    let (first_level, second_level) = tokio::join!(oncokb_level(first_id), oncokb_level(second_id));
+    let mut notes = Vec::new();
+    let mut levels = [None, None];
+    for (slot, (variant, result)) in levels
+        .iter_mut()
+        .zip([(&first, first_level), (&second, second_level)])
+    {
+        match result {
+            Ok(level) => *slot = level,
+            Err(BioMcpError::ApiKeyRequired { .. }) => {
+                if !notes.iter().any(|note| note == ONCOKB_TOKEN_NOTE) {
+                    notes.push(ONCOKB_TOKEN_NOTE.to_string());
+                }
+            }
+            Err(err) => {
+                warn!(variant = %variant.id, "OncoKB unavailable for variant comparison: {err}");
+                notes.push(format!(
+                    "OncoKB unavailable for {}: {err}",
+                    variant_label(variant)
+                ));
+            }
+        }
+    }
+
+    Ok(compare_variant_cards(&first, &second, levels, notes))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,112 @@
+//! Sidecar tests for two-variant comparison.
+
+use super::*;
+
+fn variant(value: serde_json::Value) -> Variant {
+    serde_json::from_value(value).expect("variant should deserialize")
+}
+
+#[test]
+fn compare_variant_cards_collects_fields_and_lists_differences() {
+    let v600e = variant(serde_json::json!({
+        "id": "chr7:g.140453136A>T",
+        "gene": "BRAF",
+        "hgvs_p": "p.V600E",
+        "significance": "Pathogenic",
+        "clinvar_review_stars": 3,
+        "gnomad_af": 0.000004,
+        "cadd_score": 32.0,
+        "expanded_predictions": [
+            {"tool": "REVEL", "score": 0.931},
+            {"tool": "MetaRNN", "score": 0.9}
+        ],
+        "civic": {
+            "graphql": {
+                "evidence_total_count": 412,
+                "assertion_total_count": 9
+            }
+        }
+    }));
+    let v600k = variant(serde_json::json!({
+        "id": "chr7:g.140453136_140453137delinsTT",
+        "gene": "BRAF",
+        "hgvs_p": "p.V600K",
+        "significance": "pathogenic",
+        "clinvar_review_stars": 2,
+        "cadd_score": 32.0,
+        "civic": {
+            "cached_evidence": [{
+                "id": 1,
+                "name": "EID1",
+                "molecular_profile": "BRAF V600K",
+                "evidence_type": "Predictive",
+                "evidence_level": "A",
+                "significance": "Sensitivity/Response",
+                "status": "accepted"
+            }]
+        }
+    }));
+
+    let comparison = compare_variant_cards(
+        &v600e,
+        &v600k,
+        [Some("Level 1".into()), Some("Level 1".into())],
+        Vec::new(),
+    );
+
+    assert_eq!(comparison.first.label, "BRAF p.V600E");
+    assert_eq!(comparison.second.label, "BRAF p.V600K");
+    assert_eq!(comparison.first.revel_score, Some(0.931));
+    assert_eq!(comparison.second.revel_score, None);
+    assert_eq!(
+        (
+            comparison.first.civic_evidence_count,
+            comparison.first.civic_assertion_count
+        ),
+        (412, 9)
+    );
+    assert_eq!(
+        (
+            comparison.second.civic_evidence_count,
+            comparison.second.civic_assertion_count
+        ),
+        (1, 0)
+    );
+    assert_eq!(
+        comparison.differences,
+        vec![
+            "clinvar_review_stars",
+            "gnomad_af",
+            "revel_score",
+            "civic_evidence_count",
+            "civic_assertion_count"
+        ]
+    );
+}
+
+#[test]
+fn compare_variant_cards_falls_back_to_ids_without_protein_change() {
+    let first = variant(serde_json::json!({"id": "rs12345", "gene": ""}));
+    let second = variant(serde_json::json!({"id": "rs67890", "gene": "TP53"}));
+
+    let comparison = compare_variant_cards(&first, &second, [None, None], Vec::new());
+    assert_eq!(comparison.first.label, "rs12345");
+    assert_eq!(comparison.second.label, "rs67890");
+    assert_eq!(comparison.differences, vec!["gene"]);
+}
+
+#[tokio::test]
+async fn compare_variants_rejects_missing_or_identical_ids_before_lookup() {
+    let err = compare_variants("BRAF V600E", " ")
+        .await
+        .expect_err("missing id");
+    assert!(err.to_string().contains("Two variant IDs are required"));
+
+    let err = compare_variants("rs113488022", "RS113488022")
+        .await
+        .expect_err("same id");
+    assert!(
+        err.to_string()
+            .contains("compare variants needs two different variants")
+    );
+}
@@ -4,24 +4,36 @@ use std::time::Duration;
 
 use tracing::warn;
 
+use crate::entities::section_errors;
 use crate::error::BioMcpError;
+use crate::sources::allele_registry::AlleleRegistryClient;
 use crate::sources::alphagenome::AlphaGenomeClient;
 use crate::sources::cbioportal::CBioPortalClient;
 use crate::sources::civic::CivicClient;
+use crate::sources::clinvar::ClinVarClient;
+use crate::sources::gnomad::{GnomadClient, GnomadVariantKey};
+use crate::sources::litvar::LitVarClient;
 use crate::sources::mygene::MyGeneClient;
 use crate::sources::myvariant::MyVariantClient;
 use crate::sources::oncokb::{OncoKBAnnotation, OncoKBClient};
+use crate::sources::plugin::{self, PluginEntity, PluginLookup};
+use crate::sources::vep::VepClient;
 use crate::transform;
 
 use super::gwas::add_gwas_section;
-use super::resolution::{hgvs_coords_re, parse_variant_id};
+use super::isoform;
+use super::resolution::{
+    gnomad_variant_slug, grch37_refseq_hgvs, hgvs_coords_re, parse_variant_id,
+};
 use super::{
-    TreatmentImplication, Variant, VariantCivicSection, VariantIdFormat, VariantOncoKbResult,
+    GenomeAssembly, GnomadVersion, TreatmentImplication, Variant, VariantCivicSection,
+    VariantIdFormat, VariantOncoKbResult,
 };
 
 const VARIANT_SECTION_PREDICT: &str = "predict";
 const VARIANT_SECTION_PREDICTIONS: &str = "predictions";
 const VARIANT_SECTION_CLINVAR: &str = "clinvar";
+const VARIANT_SECTION_CLINVAR_SUBMISSIONS: &str = "clinvar-submissions";
 const VARIANT_SECTION_POPULATION: &str = "population";
 const VARIANT_SECTION_CONSERVATION: &str = "conservation";
 const VARIANT_SECTION_COSMIC: &str = "cosmic";
@@ -29,12 +41,15 @@ const VARIANT_SECTION_CGI: &str = "cgi";
 const VARIANT_SECTION_CIVIC: &str = "civic";
 const VARIANT_SECTION_CBIOPORTAL: &str = "cbioportal";
 const VARIANT_SECTION_GWAS: &str = "gwas";
+const VARIANT_SECTION_TRANSCRIPTS: &str = "transcripts";
+const VARIANT_SECTION_LITVAR: &str = "litvar";
 const VARIANT_SECTION_ALL: &str = "all";
 
 pub const VARIANT_SECTION_NAMES: &[&str] = &[
     VARIANT_SECTION_PREDICT,
     VARIANT_SECTION_PREDICTIONS,
     VARIANT_SECTION_CLINVAR,
+    VARIANT_SECTION_CLINVAR_SUBMISSIONS,
     VARIANT_SECTION_POPULATION,
     VARIANT_SECTION_CONSERVATION,
     VARIANT_SECTION_COSMIC,
@@ -42,16 +57,43 @@ pub const VARIANT_SECTION_NAMES: &[&str] = &[
     VARIANT_SECTION_CIVIC,
     VARIANT_SECTION_CBIOPORTAL,
     VARIANT_SECTION_GWAS,
+    VARIANT_SECTION_TRANSCRIPTS,
+    VARIANT_SECTION_LITVAR,
     VARIANT_SECTION_ALL,
 ];
 
 const OPTIONAL_ENRICHMENT_TIMEOUT: Duration = Duration::from_secs(8);
+const LITVAR_ARTICLE_LIMIT: usize = 10;
+const VARIANT_VEP_SECTION: &str = "vep";
+
+/// Where `get variant` takes transcript consequences from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VariantAnnotationSource {
+    /// dbNSFP fields from MyVariant.info only.
+    #[default]
+    MyVariant,
+    /// MyVariant.info plus Ensembl VEP transcript consequences.
+    Vep,
+}
+
+impl VariantAnnotationSource {
+    pub fn from_flag(value: &str) -> Result<Self, BioMcpError> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "myvariant" | "mv" => Ok(Self::MyVariant),
+            "vep" | "ensembl" => Ok(Self::Vep),
+            other => Err(BioMcpError::InvalidArgument(format!(
+                "Unknown --source '{other}'. Expected 'myvariant' or 'vep'."
+            ))),
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, Default)]
 struct VariantSections {
     include_prediction: bool,
     include_expanded_predictions: bool,
     include_clinvar: bool,
+    include_clinvar_submissions: bool,
     include_population: bool,
     include_conservation: bool,
     include_cosmic: bool,
@@ -59,6 +101,8 @@ struct VariantSections {
     include_civic: bool,
     include_cbioportal: bool,
     include_gwas: bool,
+    include_transcripts: bool,
+    include_litvar: bool,
 }
 
 fn parse_sections(sections: &[String]) -> Result<VariantSections, BioMcpError> {
@@ -77,6 +121,7 @@ fn parse_sections(sections: &[String]) -> Result<VariantSections, BioMcpError> {
             VARIANT_SECTION_PREDICT => out.include_prediction = true,
             VARIANT_SECTION_PREDICTIONS => out.include_expanded_predictions = true,
             VARIANT_SECTION_CLINVAR => out.include_clinvar = true,
+            VARIANT_SECTION_CLINVAR_SUBMISSIONS => out.include_clinvar_submissions = true,
             VARIANT_SECTION_POPULATION => out.include_population = true,
             VARIANT_SECTION_CONSERVATION => out.include_conservation = true,
             VARIANT_SECTION_COSMIC => out.include_cosmic = true,
@@ -84,6 +129,8 @@ fn parse_sections(sections: &[String]) -> Result<VariantSections, BioMcpError> {
             VARIANT_SECTION_CIVIC => out.include_civic = true,
             VARIANT_SECTION_CBIOPORTAL => out.include_cbioportal = true,
             VARIANT_SECTION_GWAS => out.include_gwas = true,
+            VARIANT_SECTION_TRANSCRIPTS => out.include_transcripts = true,
+            VARIANT_SECTION_LITVAR => out.include_litvar = true,
             VARIANT_SECTION_ALL => include_all = true,
             _ => {
                 return Err(BioMcpError::InvalidArgument(format!(
@@ -98,6 +145,7 @@ fn parse_sections(sections: &[String]) -> Result<VariantSections, BioMcpError> {
         out.include_prediction = true;
         out.include_expanded_predictions = true;
         out.include_clinvar = true;
+        out.include_clinvar_submissions = true;
         out.include_population = true;
         out.include_conservation = true;
         out.include_cosmic = true;
@@ -105,6 +153,8 @@ fn parse_sections(sections: &[String]) -> Result<VariantSections, BioMcpError> {
         out.include_civic = true;
         out.include_cbioportal = true;
         out.include_gwas = true;
+        out.include_transcripts = true;
+        out.include_litvar = true;
     }
 
     Ok(out)
@@ -136,6 +186,17 @@ fn best_hit(
     hits.iter().max_by_key(|h| score_myvariant_hit(h))
 }
 
+/// ClinVar indexes the RefSeq transcript descriptions that clinical reports
+/// use, so a pasted `NM_...:c.` usually resolves without a projection call.
+fn clinvar_coding_query(hgvs: &str) -> Option<String> {
+    (hgvs.starts_with("NM_") && hgvs.contains(":c.")).then(|| {
+        format!(
+            "clinvar.hgvs.coding:\"{}\"",
+            MyVariantClient::escape_query_value(hgvs)
+        )
+    })
+}
+
 fn oncokb_alteration_from_variant(
     variant: &Variant,
     id_format: &VariantIdFormat,
@@ -209,7 +270,10 @@ fn therapies_from_oncokb(annotation: &OncoKBAnnotation) -> Vec<TreatmentImplicat
     implications
 }
 
-async fn resolve_base(id: &str) -> Result<(Variant, VariantIdFormat), BioMcpError> {
+async fn resolve_base(
+    id: &str,
+    assembly: GenomeAssembly,
+) -> Result<(Variant, VariantIdFormat), BioMcpError> {
     let id = id.trim();
     if id.is_empty() {
         return Err(BioMcpError::InvalidArgument(
@@ -219,7 +283,12 @@ async fn resolve_base(id: &str) -> Result<(Variant, VariantIdFormat), BioMcpErro
 
     let id_format = parse_variant_id(id)?;
 
-    let myvariant = MyVariantClient::new()?;
+    let mut myvariant = MyVariantClient::new()?;
+    if assembly == GenomeAssembly::Grch38 {
+        // hg19 stays implicit so GRCh37 lookups keep their existing cache keys.
+        myvariant = myvariant.with_assembly(assembly.myvariant_assembly());
+    }
+    let mut position_retry = false;
     let hit = match &id_format {
         VariantIdFormat::HgvsGenomic(hgvs) => myvariant.get(hgvs).await?,
         VariantIdFormat::RsId(rsid) => {
@@ -244,30 +313,98 @@ async fn resolve_base(id: &str) -> Result<(Variant, VariantIdFormat), BioMcpErro
             let resp = myvariant
                 .query_with_fields(&q, 5, 0, crate::sources::myvariant::MYVARIANT_FIELDS_GET)
                 .await?;
-            resp.hits
-                .into_iter()
-                .next()
+            let mut hit = resp.hits.into_iter().next();
+            // Changes numbered on an alternate isoform can be missing from the
+            // HGVS p. aliases; retry on the per-transcript residue fields.
+            if hit.is_none()
+                && let Some(parsed) = isoform::parse_protein_change(change)
+            {
+                let q = isoform::residue_position_query(gene, &parsed);
+                let resp = myvariant
+                    .query_with_fields(&q, 5, 0, crate::sources::myvariant::MYVARIANT_FIELDS_GET)
+                    .await?;
+                hit = best_hit(&resp.hits).cloned();
+                position_retry = hit.is_some();
+            }
+            hit.ok_or_else(|| BioMcpError::NotFound {
+                entity: "variant".into(),
+                id: id.to_string(),
+                suggestion: format!(
+                    "Try searching: biomcp search variant -g {gene} --hgvsp {change}"
+                ),
+            })?
+        }
+        VariantIdFormat::ClinGenAllele(caid) => {
+            let allele = AlleleRegistryClient::new()?
+                .get_allele(caid)
+                .await?
                 .ok_or_else(|| BioMcpError::NotFound {
                     entity: "variant".into(),
-                    id: id.to_string(),
-                    suggestion: format!(
-                        "Try searching: biomcp search variant -g {gene} --hgvsp {change}"
-                    ),
-                })?
+                    id: caid.to_string(),
+                    suggestion: "Check the CAid at https://reg.clinicalgenome.org".into(),
+                })?;
+            let myvariant_id = match assembly {
+                GenomeAssembly::Grch37 => allele.myvariant_hg19_id(),
+                GenomeAssembly::Grch38 => allele.myvariant_hg38_id(),
+            };
+            let myvariant_id = myvariant_id.ok_or_else(|| BioMcpError::NotFound {
+                entity: "variant".into(),
+                id: caid.to_string(),
+                suggestion: match allele.grch38_hgvs() {
+                    Some(hgvs) => format!("Try: biomcp validate variant \"{hgvs}\""),
+                    None => "The allele has no MyVariant.info cross-reference".into(),
+                },
+            })?;
+            myvariant.get(myvariant_id).await?
+        }
+        VariantIdFormat::HgvsReference(hgvs) => {
+            let clinvar_hit = match clinvar_coding_query(hgvs) {
+                Some(q) => {
+                    let resp = myvariant
+                        .query_with_fields(
+                            &q,
+                            5,
+                            0,
+                            crate::sources::myvariant::MYVARIANT_FIELDS_GET,
+                        )
+                        .await?;
+                    best_hit(&resp.hits).cloned()
+                }
+                None => None,
+            };
+            // Ensembl transcripts, genomic descriptions, and transcript
+            // versions ClinVar does not list go through VariantValidator.
+            match clinvar_hit {
+                Some(hit) => hit,
+                None => {
+                    let myvariant_id =
+                        super::validation::myvariant_id_for_hgvs(hgvs, assembly).await?;
+                    myvariant.get(&myvariant_id).await?
+                }
+            }
         }
     };
 
-    let variant = transform::variant::from_myvariant_hit(&hit);
+    let mut variant = transform::variant::from_myvariant_hit(&hit);
+    if let VariantIdFormat::ClinGenAllele(caid) = &id_format {
+        variant.clingen_allele_id = Some(caid.clone());
+    }
+    if let VariantIdFormat::GeneProteinChange { change, .. } = &id_format
+        && let Some(parsed) = isoform::parse_protein_change(change)
+    {
+        variant.isoform = isoform::annotate_isoform(&variant.transcripts, &parsed, position_retry);
+    }
     Ok((variant, id_format))
 }
 
-async fn get_base(id: &str) -> Result<Variant, BioMcpError> {
-    let (variant, _) = resolve_base(id).await?;
+async fn get_base(id: &str, assembly: GenomeAssembly) -> Result<Variant, BioMcpError> {
+    let (mut variant, _) = resolve_base(id, assembly).await?;
+    variant.assembly = Some(assembly);
     Ok(variant)
 }
 
 pub async fn oncokb(id: &str) -> Result<VariantOncoKbResult, BioMcpError> {
-    let (variant, id_format) = resolve_base(id).await?;
+    let (variant, id_format) = resolve_base(id, GenomeAssembly::Grch37).await?;
     let gene = variant.gene.trim();
     if gene.is_empty() {
         return Err(BioMcpError::InvalidArgument(
@@ -355,7 +492,7 @@ async fn add_prediction(variant: &mut Variant) -> Result<(), BioMcpError> {
                 let query = format!("ensembl.gene:\"{}\"", top_gene.trim());
                 match MyGeneClient::new() {
                     Ok(client) => {
-                        if let Ok(resp) = client.search(&query, 1, 0, None).await
+                        if let Ok(resp) = client.search(&query, 1, 0, None, "human").await
                             && let Some(symbol) = resp
                                 .hits
                                 .first()
@@ -373,7 +510,10 @@ async fn add_prediction(variant: &mut Variant) -> Result<(), BioMcpError> {
             }
             transform::variant::merge_prediction(variant, pred)
         }
-        Err(err) => warn!(variant_id = %variant.id, "AlphaGenome unavailable: {err}"),
+        Err(err) => {
+            warn!(variant_id = %variant.id, "AlphaGenome unavailable: {err}");
+            section_errors::record(VARIANT_SECTION_PREDICT, "AlphaGenome", &err);
+        }
     }
 
     Ok(())
@@ -391,17 +531,218 @@ async fn add_cbioportal(variant: &mut Variant) {
         Ok::<_, BioMcpError>(summary)
     };
 
-    match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, cbio_fut).await {
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        cbio_fut,
+    )
+    .await
+    {
         Ok(Ok(summary)) => transform::variant::merge_cbioportal(variant, &summary),
-        Ok(Err(err)) => warn!(gene = %variant.gene, "cBioPortal unavailable: {err}"),
+        Ok(Err(err)) => {
+            warn!(gene = %variant.gene, "cBioPortal unavailable: {err}");
+            section_errors::record(VARIANT_SECTION_CBIOPORTAL, "cBioPortal", &err);
+        }
+        Err(_) => {
+            warn!(
+                gene = %variant.gene,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "cBioPortal enrichment timed out"
+            );
+            section_errors::record_timeout(
+                VARIANT_SECTION_CBIOPORTAL,
+                "cBioPortal",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+        }
+    }
+}
+
+/// Fills the canonical allele ID from the ClinGen Allele Registry when
+/// MyVariant.info carries no `clingen.caid` for the record.
+async fn add_clingen_allele_id(variant: &mut Variant) {
+    if variant.clingen_allele_id.is_some() {
+        return;
+    }
+    let Some(hgvs) = grch37_refseq_hgvs(&variant.id) else {
+        return;
+    };
+
+    let lookup = async { AlleleRegistryClient::new()?.lookup_hgvs(&hgvs).await };
+    match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, lookup).await {
+        Ok(Ok(allele)) => {
+            variant.clingen_allele_id = allele
+                .as_ref()
+                .and_then(|allele| allele.caid())
+                .map(str::to_string);
+        }
+        Ok(Err(err)) => warn!(id = %variant.id, "ClinGen Allele Registry unavailable: {err}"),
         Err(_) => warn!(
-            gene = %variant.gene,
+            id = %variant.id,
             timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
-            "cBioPortal enrichment timed out"
+            "ClinGen Allele Registry lookup timed out"
         ),
     }
 }
 
+/// Lists individual submitter interpretations for the ClinVar variation that
+/// MyVariant.info linked to this record.
+async fn add_clinvar_submissions(variant: &mut Variant, variation_id: Option<&str>) {
+    let Some(variation_id) = variation_id else {
+        return;
+    };
+
+    let clinvar_fut = async { ClinVarClient::new()?.submissions(variation_id).await };
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        clinvar_fut,
+    )
+    .await
+    {
+        Ok(Ok(rows)) => variant.clinvar_submissions = rows,
+        Ok(Err(err)) => {
+            warn!(variation_id = %variation_id, "ClinVar submissions unavailable: {err}");
+            section_errors::record(VARIANT_SECTION_CLINVAR_SUBMISSIONS, "ClinVar", &err);
+        }
+        Err(_) => {
+            warn!(
+                variation_id = %variation_id,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "ClinVar submissions lookup timed out"
+            );
+            section_errors::record_timeout(
+                VARIANT_SECTION_CLINVAR_SUBMISSIONS,
+                "ClinVar",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+        }
+    }
+}
+
+/// gnomAD lookup for the variant: its own coordinates when the card is on the
+/// release's reference genome, else its rsID.
+fn gnomad_variant_key(variant: &Variant, version: GnomadVersion) -> Option<GnomadVariantKey> {
+    if variant.assembly == Some(version.assembly())
+        && let Some(slug) = gnomad_variant_slug(&variant.id)
+    {
+        return Some(GnomadVariantKey::VariantId(slug));
+    }
+    variant
+        .rsid
+        .as_deref()
+        .map(str::trim)
+        .filter(|rsid| !rsid.is_empty())
+        .map(|rsid| GnomadVariantKey::Rsid(rsid.to_string()))
+}
+
+/// Adds per-group allele counts, coverage, and filters from the gnomAD API to
+/// the population section.
+async fn add_gnomad_population(variant: &mut Variant, version: GnomadVersion) {
+    let Some(key) = gnomad_variant_key(variant, version) else {
+        let other_version = match version.assembly() {
+            GenomeAssembly::Grch37 => GnomadVersion::V4,
+            GenomeAssembly::Grch38 => GnomadVersion::V2,
+        };
+        let err = BioMcpError::InvalidArgument(format!(
+            "gnomAD {} is on {} and '{}' has no rsID to look it up by; use --assembly {} or --gnomad-version {}.",
+            version.as_str(),
+            version.assembly().as_str(),
+            variant.id,
+            version.assembly().as_str().to_ascii_lowercase(),
+            other_version.as_str(),
+        ));
+        section_errors::record(VARIANT_SECTION_POPULATION, "gnomAD", &err);
+        return;
+    };
+
+    let gnomad_fut = async {
+        GnomadClient::new()?
+            .variant_frequencies(&key, version)
+            .await
+    };
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        gnomad_fut,
+    )
+    .await
+    {
+        Ok(Ok(frequencies)) => variant.gnomad = frequencies,
+        Ok(Err(err)) => {
+            warn!(id = %variant.id, "gnomAD unavailable: {err}");
+            section_errors::record(VARIANT_SECTION_POPULATION, "gnomAD", &err);
+        }
+        Err(_) => {
+            warn!(
+                id = %variant.id,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "gnomAD lookup timed out"
+            );
+            section_errors::record_timeout(
+                VARIANT_SECTION_POPULATION,
+                "gnomAD",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+        }
+    }
+}
+
+/// LitVar query for the variant: the rsID when known, else `GENE CHANGE`.
+fn litvar_query(variant: &Variant) -> Option<String> {
+    if let Some(rsid) = variant
+        .rsid
+        .as_deref()
+        .map(str::trim)
+        .filter(|rsid| !rsid.is_empty())
+    {
+        return Some(rsid.to_string());
+    }
+    let gene = variant.gene.trim();
+    let change = variant
+        .hgvs_p
+        .as_deref()
+        .map(str::trim)
+        .map(|hgvs_p| hgvs_p.strip_prefix("p.").unwrap_or(hgvs_p))
+        .filter(|change| !change.is_empty())?;
+    (!gene.is_empty()).then(|| format!("{gene} {change}"))
+}
+
+/// Adds publications that LitVar links to this exact variant rather than to
+/// its gene.
+async fn add_litvar(variant: &mut Variant) {
+    let Some(query) = litvar_query(variant) else {
+        return;
+    };
+
+    let litvar_fut = async {
+        LitVarClient::new()?
+            .literature(&query, LITVAR_ARTICLE_LIMIT)
+            .await
+    };
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        litvar_fut,
+    )
+    .await
+    {
+        Ok(Ok(literature)) => variant.litvar = literature,
+        Ok(Err(err)) => {
+            warn!(query = %query, "LitVar unavailable: {err}");
+            section_errors::record(VARIANT_SECTION_LITVAR, "LitVar", &err);
+        }
+        Err(_) => {
+            warn!(
+                query = %query,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "LitVar lookup timed out"
+            );
+            section_errors::record_timeout(
+                VARIANT_SECTION_LITVAR,
+                "LitVar",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+        }
+    }
+}
+
 fn civic_molecular_profile_name(variant: &Variant) -> Option<String> {
     let gene = variant.gene.trim();
     if gene.is_empty() {
@@ -435,47 +776,140 @@ async fn add_civic(variant: &mut Variant) {
             .await
     };
 
-    match tokio::time::timeout(OPTIONAL_ENRICHMENT_TIMEOUT, civic_fut).await {
+    match tokio::time::timeout(
+        section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT),
+        civic_fut,
+    )
+    .await
+    {
         Ok(Ok(context)) => {
             let section = variant
                 .civic
                 .get_or_insert_with(VariantCivicSection::default);
             section.graphql = Some(context);
         }
-        Ok(Err(err)) => warn!(
-            molecular_profile = %molecular_profile_name,
-            "CIViC enrichment unavailable: {err}"
-        ),
-        Err(_) => warn!(
-            molecular_profile = %molecular_profile_name,
-            timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
-            "CIViC enrichment timed out"
-        ),
+        Ok(Err(err)) => {
+            warn!(
+                molecular_profile = %molecular_profile_name,
+                "CIViC enrichment unavailable: {err}"
+            );
+            section_errors::record(VARIANT_SECTION_CIVIC, "CIViC", &err);
+        }
+        Err(_) => {
+            warn!(
+                molecular_profile = %molecular_profile_name,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "CIViC enrichment timed out"
+            );
+            section_errors::record_timeout(
+                VARIANT_SECTION_CIVIC,
+                "CIViC",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+        }
     }
 }
 
+async fn add_vep(variant: &mut Variant, assembly: GenomeAssembly) {
+    let Some(hgvs) = transform::variant::vep_hgvs(&variant.id) else {
+        let err = BioMcpError::InvalidArgument(format!(
+            "Ensembl VEP needs a genomic HGVS variant ID; '{}' has none",
+            variant.id
+        ));
+        section_errors::record(VARIANT_VEP_SECTION, "Ensembl VEP", &err);
+        return;
+    };
+
+    let vep_fut = async {
+        let client = match assembly {
+            GenomeAssembly::Grch37 => VepClient::new()?,
+            GenomeAssembly::Grch38 => VepClient::new_grch38()?,
+        };
+        client.annotate_hgvs(&hgvs).await
+    };
+
+    match tokio::time::timeout(section_errors::budget(OPTIONAL_ENRICHMENT_TIMEOUT), vep_fut).await {
+        Ok(Ok(rows)) => match rows.first() {
+            Some(annotation) => transform::variant::merge_vep(variant, &hgvs, annotation),
+            None => warn!(hgvs = %hgvs, "Ensembl VEP returned no annotation"),
+        },
+        Ok(Err(err)) => {
+            warn!(hgvs = %hgvs, "Ensembl VEP unavailable: {err}");
+            section_errors::record(VARIANT_VEP_SECTION, "Ensembl VEP", &err);
+        }
+        Err(_) => {
+            warn!(
+                hgvs = %hgvs,
+                timeout_secs = OPTIONAL_ENRICHMENT_TIMEOUT.as_secs(),
+                "Ensembl VEP annotation timed out"
+            );
+            section_errors::record_timeout(
+                VARIANT_VEP_SECTION,
+                "Ensembl VEP",
+                OPTIONAL_ENRICHMENT_TIMEOUT,
+            );
+        }
+    }
+}
+
+/// Caveats for requested sections whose upstream data is not native to
+/// `assembly`.
+fn assembly_notes(flags: &VariantSections, assembly: GenomeAssembly) -> Vec<String> {
+    let mut notes = Vec::new();
+    match assembly {
+        GenomeAssembly::Grch37 => {
+            if flags.include_prediction {
+                notes.push(
+                    "predict: AlphaGenome scores GRCh38 coordinates only, so it was skipped for \
+                     this GRCh37 ID; re-run with `--assembly grch38`."
+                        .to_string(),
+                );
+            }
+        }
+        GenomeAssembly::Grch38 => {
+            if flags.include_population {
+                notes.push(
+                    "population: gnomAD v2 exome frequencies are GRCh37-native; MyVariant.info \
+                     serves them lifted over to GRCh38."
+                        .to_string(),
+                );
+            }
+        }
+    }
+    notes
+}
+
 fn is_gwas_only_request(flags: &VariantSections) -> bool {
     flags.include_gwas
         && !flags.include_prediction
         && !flags.include_expanded_predictions
         && !flags.include_clinvar
+        && !flags.include_clinvar_submissions
         && !flags.include_population
         && !flags.include_conservation
         && !flags.include_cosmic
         && !flags.include_cgi
         && !flags.include_civic
         && !flags.include_cbioportal
+        && !flags.include_transcripts
+        && !flags.include_litvar
 }
 
 fn gwas_only_variant_stub(rsid: &str) -> Variant {
     Variant {
         gene: String::new(),
         id: rsid.to_string(),
+        assembly: None,
+        assembly_notes: Vec::new(),
         hgvs_p: None,
         legacy_name: None,
         hgvs_c: None,
         rsid: Some(rsid.to_string()),
         cosmic_id: None,
+        clingen_allele_id: None,
+        isoform: None,
+        transcripts: Vec::new(),
+        vep: None,
         significance: None,
         clinvar_id: None,
         clinvar_review_status: None,
@@ -491,18 +925,22 @@ fn gwas_only_variant_stub(rsid: &str) -> Variant {
         conservation: None,
         expanded_predictions: Vec::new(),
         population_breakdown: None,
+        gnomad: None,
         cosmic_context: None,
         cgi_associations: Vec::new(),
         civic: None,
         clinvar_conditions: Vec::new(),
         clinvar_condition_reports: None,
         top_disease: None,
+        clinvar_submissions: Vec::new(),
         cancer_frequencies: Vec::new(),
         cancer_frequency_source: None,
         gwas: Vec::new(),
         gwas_unavailable_reason: None,
         supporting_pmids: None,
+        litvar: None,
         prediction: None,
+        plugin_sections: Vec::new(),
     }
 }
 
@@ -517,16 +955,60 @@ fn strip_clinvar_details(variant: &mut Variant) {
 }
 
 pub async fn get(id: &str, sections: &[String]) -> Result<Variant, BioMcpError> {
-    let section_flags = parse_sections(sections)?;
+    get_with_source(
+        id,
+        sections,
+        VariantAnnotationSource::MyVariant,
+        GenomeAssembly::Grch37,
+        GnomadVersion::default(),
+    )
+    .await
+}
+
+pub async fn get_with_source(
+    id: &str,
+    sections: &[String],
+    source: VariantAnnotationSource,
+    assembly: GenomeAssembly,
+    gnomad_version: GnomadVersion,
+) -> Result<Variant, BioMcpError> {
+    let (plugins, sections) = plugin::split_sections(
+        plugin::registered(),
+        PluginEntity::Variant,
+        VARIANT_SECTION_NAMES,
+        sections,
+    );
+    let section_flags = parse_sections(&sections)?;
+    if let Some(store) = crate::utils::snapshot::offline_store() {
+        if source == VariantAnnotationSource::Vep {
+            return Err(crate::utils::snapshot::offline_unavailable(
+                "--source vep annotates through Ensembl VEP and has no offline copy.".into(),
+            ));
+        }
+        if assembly == GenomeAssembly::Grch38 {
+            return Err(crate::utils::snapshot::offline_unavailable(
+                "--assembly grch38 needs MyVariant.info hg38 records; snapshots store GRCh37."
+                    .into(),
+            ));
+        }
+        return super::offline::get(&store, id, &sections);
+    }
     if is_gwas_only_request(&section_flags)
+        && plugins.is_empty()
         && let VariantIdFormat::RsId(rsid) = parse_variant_id(id)?
     {
         let mut variant = gwas_only_variant_stub(&rsid);
         add_gwas_section(&mut variant, id).await?;
-        return Ok(variant);
+        return transform::fields::apply("variant", variant);
     }
 
-    let mut variant = get_base(id).await?;
+    let mut variant = get_base(id, assembly).await?;
+    variant.assembly_notes = assembly_notes(&section_flags, assembly);
+    if assembly == GenomeAssembly::Grch37 {
+        add_clingen_allele_id(&mut variant).await;
+    }
+    // The section needs the variation ID even when the clinvar section is off.
+    let clinvar_variation_id = variant.clinvar_id.clone();
 
     if !section_flags.include_clinvar {
         strip_clinvar_details(&mut variant);
@@ -552,25 +1034,50 @@ pub async fn get(id: &str, sections: &[String]) -> Result<Variant, BioMcpError>
     if !section_flags.include_cbioportal {
         variant.cancer_frequencies.clear();
     }
+    if !section_flags.include_transcripts {
+        variant.transcripts.clear();
+    }
     if !section_flags.include_gwas {
         variant.gwas.clear();
         variant.gwas_unavailable_reason = None;
         variant.supporting_pmids = None;
     }
-    if section_flags.include_prediction {
+    if section_flags.include_prediction && assembly == GenomeAssembly::Grch38 {
         add_prediction(&mut variant).await?;
     }
+    if section_flags.include_population {
+        add_gnomad_population(&mut variant, gnomad_version).await;
+    }
     if section_flags.include_cbioportal {
         add_cbioportal(&mut variant).await;
     }
+    if section_flags.include_clinvar_submissions {
+        add_clinvar_submissions(&mut variant, clinvar_variation_id.as_deref()).await;
+    }
     if section_flags.include_civic {
         add_civic(&mut variant).await;
     }
+    if section_flags.include_litvar {
+        add_litvar(&mut variant).await;
+    }
     if section_flags.include_gwas {
         add_gwas_section(&mut variant, id).await?;
     }
+    if source == VariantAnnotationSource::Vep {
+        add_vep(&mut variant, assembly).await;
+    }
+    if !plugins.is_empty() {
+        let lookup = PluginLookup::default()
+            .with("id", Some(&variant.id))
+            .with("gene", Some(&variant.gene))
+            .with("rsid", variant.rsid.as_deref())
+            .with("hgvs_p", variant.hgvs_p.as_deref())
+            .with("hgvs_c", variant.hgvs_c.as_deref());
+        variant.plugin_sections =
+            plugin::fetch_sections(&plugins, PluginEntity::Variant, &lookup).await;
+    }
 
-    Ok(variant)
+    transform::fields::apply("variant", variant)
 }
 
 #[cfg(test)]
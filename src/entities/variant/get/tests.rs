@@ -20,6 +20,9 @@ fn parse_sections_supports_new_variant_sections() {
         "civic".to_string(),
         "cbioportal".to_string(),
         "gwas".to_string(),
+        "transcripts".to_string(),
+        "clinvar-submissions".to_string(),
+        "litvar".to_string(),
     ])
     .expect("sections should parse");
 
@@ -30,6 +33,81 @@ fn parse_sections_supports_new_variant_sections() {
     assert!(flags.include_civic);
     assert!(flags.include_cbioportal);
     assert!(flags.include_gwas);
+    assert!(flags.include_transcripts);
+    assert!(flags.include_clinvar_submissions);
+    assert!(flags.include_litvar);
+    assert!(!flags.include_clinvar);
+}
+
+#[test]
+fn litvar_query_prefers_rsid_then_gene_and_protein_change() {
+    let mut variant = gwas_only_variant_stub("rs113488022");
+    assert_eq!(litvar_query(&variant).as_deref(), Some("rs113488022"));
+
+    variant.rsid = None;
+    variant.gene = "BRAF".into();
+    variant.hgvs_p = Some("p.V600E".into());
+    assert_eq!(litvar_query(&variant).as_deref(), Some("BRAF V600E"));
+
+    variant.hgvs_p = None;
+    assert_eq!(litvar_query(&variant), None);
+}
+
+#[test]
+fn annotation_source_flag_accepts_myvariant_and_vep() {
+    assert_eq!(
+        VariantAnnotationSource::from_flag("").expect("default"),
+        VariantAnnotationSource::MyVariant
+    );
+    assert_eq!(
+        VariantAnnotationSource::from_flag("VEP").expect("vep"),
+        VariantAnnotationSource::Vep
+    );
+    let err = VariantAnnotationSource::from_flag("snpeff").expect_err("unknown source");
+    assert!(err.to_string().contains("Expected 'myvariant' or 'vep'"));
+}
+
+#[test]
+fn assembly_notes_mark_sections_not_native_to_the_assembly() {
+    let flags = parse_sections(&["predict".to_string(), "population".to_string()])
+        .expect("sections should parse");
+
+    let grch37 = assembly_notes(&flags, GenomeAssembly::Grch37);
+    assert_eq!(grch37.len(), 1);
+    assert!(grch37[0].starts_with("predict: AlphaGenome scores GRCh38"));
+
+    let grch38 = assembly_notes(&flags, GenomeAssembly::Grch38);
+    assert_eq!(grch38.len(), 1);
+    assert!(grch38[0].contains("lifted over to GRCh38"));
+
+    let clinvar_only = parse_sections(&["clinvar".to_string()]).expect("sections should parse");
+    assert!(assembly_notes(&clinvar_only, GenomeAssembly::Grch37).is_empty());
+    assert!(assembly_notes(&clinvar_only, GenomeAssembly::Grch38).is_empty());
+}
+
+#[test]
+fn gnomad_variant_key_uses_coordinates_on_the_release_assembly_else_rsid() {
+    let mut variant = gwas_only_variant_stub("rs113488022");
+    variant.id = "chr7:g.140453136A>T".into();
+    variant.assembly = Some(GenomeAssembly::Grch37);
+
+    assert_eq!(
+        gnomad_variant_key(&variant, GnomadVersion::V2),
+        Some(GnomadVariantKey::VariantId("7-140453136-A-T".into()))
+    );
+    assert_eq!(
+        gnomad_variant_key(&variant, GnomadVersion::V4),
+        Some(GnomadVariantKey::Rsid("rs113488022".into()))
+    );
+
+    variant.rsid = None;
+    assert_eq!(gnomad_variant_key(&variant, GnomadVersion::V3), None);
+    assert_eq!(
+        GnomadVersion::from_flag("V3").expect("v3"),
+        GnomadVersion::V3
+    );
+    let err = GnomadVersion::from_flag("v5").expect_err("unknown release");
+    assert!(err.to_string().contains("Expected 'v2', 'v3', or 'v4'"));
 }
 
 #[test]
@@ -56,11 +134,17 @@ fn civic_molecular_profile_name_prefers_gene_and_hgvs_p() {
     let variant = Variant {
         gene: "BRAF".into(),
         id: "chr7:g.140453136A>T".into(),
+        assembly: None,
+        assembly_notes: Vec::new(),
         hgvs_p: Some("p.V600E".into()),
         legacy_name: None,
         hgvs_c: None,
         rsid: None,
         cosmic_id: None,
+        clingen_allele_id: None,
+        isoform: None,
+        transcripts: Vec::new(),
+        vep: None,
         significance: None,
         clinvar_id: None,
         clinvar_review_status: None,
@@ -76,18 +160,22 @@ fn civic_molecular_profile_name_prefers_gene_and_hgvs_p() {
         conservation: None,
         expanded_predictions: Vec::new(),
         population_breakdown: None,
+        gnomad: None,
         cosmic_context: None,
         cgi_associations: Vec::new(),
         civic: None,
         clinvar_conditions: Vec::new(),
         clinvar_condition_reports: None,
         top_disease: None,
+        clinvar_submissions: Vec::new(),
         cancer_frequencies: Vec::new(),
         cancer_frequency_source: None,
         gwas: Vec::new(),
         gwas_unavailable_reason: None,
         supporting_pmids: None,
+        litvar: None,
         prediction: None,
+        plugin_sections: Vec::new(),
     };
 
     assert_eq!(
@@ -127,6 +215,104 @@ async fn gwas_only_request_returns_variant_when_gwas_is_unavailable() {
     assert_eq!(variant.supporting_pmids, None);
 }
 
+#[test]
+fn clinvar_coding_query_targets_refseq_transcript_descriptions_only() {
+    assert_eq!(
+        clinvar_coding_query("NM_000546.6:c.524G>A").as_deref(),
+        Some(r#"clinvar.hgvs.coding:"NM_000546.6\:c.524G>A""#)
+    );
+    assert_eq!(clinvar_coding_query("ENST00000269305.9:c.524G>A"), None);
+    assert_eq!(clinvar_coding_query("NC_000017.10:g.7577538C>T"), None);
+    assert_eq!(clinvar_coding_query("NR_024540.1:n.5C>T"), None);
+}
+
+#[tokio::test]
+async fn transcript_hgvs_resolves_through_clinvar_coding_field() {
+    let _env = lock_env().await;
+    let myvariant = MockServer::start().await;
+    let variantvalidator = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/query"))
+        .and(query_param(
+            "q",
+            r#"clinvar.hgvs.coding:"NM_000546.6\:c.524G>A""#,
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total": 1,
+            "hits": [{"_id": "chr17:g.7577538C>T"}]
+        })))
+        .expect(1)
+        .mount(&myvariant)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&variantvalidator)
+        .await;
+
+    let _myvariant = set_env_var("BIOMCP_MYVARIANT_BASE", Some(&myvariant.uri()));
+    let _vv = set_env_var(
+        "BIOMCP_VARIANTVALIDATOR_BASE",
+        Some(&variantvalidator.uri()),
+    );
+    let (variant, id_format) = resolve_base("NM_000546.6:c.524G>A", GenomeAssembly::Grch37)
+        .await
+        .expect("ClinVar transcript match");
+
+    assert_eq!(variant.id, "chr17:g.7577538C>T");
+    assert_eq!(
+        id_format,
+        VariantIdFormat::HgvsReference("NM_000546.6:c.524G>A".into())
+    );
+}
+
+#[tokio::test]
+async fn transcript_hgvs_missing_from_clinvar_falls_back_to_variantvalidator() {
+    let _env = lock_env().await;
+    let myvariant = MockServer::start().await;
+    let variantvalidator = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/query"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"total": 0, "hits": []})))
+        .mount(&myvariant)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/variant/chr17:g.7577538C%3ET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "_id": "chr17:g.7577538C>T"
+        })))
+        .expect(1)
+        .mount(&myvariant)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/VariantValidator/variantvalidator/GRCh38/NM_000546.5:c.524G%3EA/mane_select",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "flag": "gene_variant",
+            "NM_000546.6:c.524G>A": {
+                "hgvs_transcript_variant": "NM_000546.6:c.524G>A",
+                "primary_assembly_loci": {
+                    "grch37": {"hgvs_genomic_description": "NC_000017.10:g.7577538C>T"},
+                    "grch38": {"hgvs_genomic_description": "NC_000017.11:g.7674220C>T"}
+                }
+            }
+        })))
+        .expect(1)
+        .mount(&variantvalidator)
+        .await;
+
+    let _myvariant = set_env_var("BIOMCP_MYVARIANT_BASE", Some(&myvariant.uri()));
+    let _vv = set_env_var(
+        "BIOMCP_VARIANTVALIDATOR_BASE",
+        Some(&variantvalidator.uri()),
+    );
+    let (variant, _) = resolve_base("NM_000546.5:c.524G>A", GenomeAssembly::Grch37)
+        .await
+        .expect("VariantValidator projection");
+    assert_eq!(variant.id, "chr17:g.7577538C>T");
+}
+
 #[test]
 fn therapies_from_oncokb_truncation_shows_count() {
     let annotation: OncoKBAnnotation = serde_json::from_value(serde_json::json!({
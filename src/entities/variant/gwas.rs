@@ -1,6 +1,10 @@
 //! GWAS Catalog search and GWAS enrichment for variant detail retrieval.
 
+use futures::StreamExt;
+use futures::stream::FuturesUnordered;
+
 use crate::entities::SearchPage;
+use crate::entities::section_errors;
 use crate::error::BioMcpError;
 use crate::sources::gwas::{GwasAssociation, GwasClient, GwasSnp};
 use tracing::warn;
@@ -8,6 +12,12 @@ use tracing::warn;
 use super::resolution::parse_variant_id;
 use super::{GwasSearchFilters, Variant, VariantGwasAssociation, VariantIdFormat};
 
+/// Per-rsID association lookups in flight at once during `search gwas`. The
+/// shared client's rate limiter still spaces the request starts.
+const GWAS_RSID_CONCURRENCY: usize = 4;
+/// Associations fetched per rsID during `search gwas`.
+const GWAS_ASSOCIATIONS_PER_RSID: usize = 3;
+
 #[allow(dead_code)]
 pub async fn search_gwas(
     filters: &GwasSearchFilters,
@@ -63,8 +73,8 @@ pub async fn search_gwas_page(
         let snps = client
             .snps_by_gene(gene, (needed.saturating_mul(5)).clamp(needed, 200))
             .await?;
-        for rsid in unique_rsids_from_snps(&snps, needed.saturating_mul(2)) {
-            let associations = client.associations_by_rsid(&rsid, 3).await?;
+        let rsids = unique_rsids_from_snps(&snps, needed.saturating_mul(2));
+        for (rsid, associations) in associations_for_rsids(&client, rsids).await? {
             if associations.is_empty() {
                 rows.push(VariantGwasAssociation {
                     rsid,
@@ -101,8 +111,8 @@ pub async fn search_gwas_page(
         let snps = client
             .snps_by_trait(trait_query, (needed.saturating_mul(5)).clamp(needed, 200))
             .await?;
-        for rsid in unique_rsids_from_snps(&snps, needed.saturating_mul(2)) {
-            let associations = client.associations_by_rsid(&rsid, 3).await?;
+        let rsids = unique_rsids_from_snps(&snps, needed.saturating_mul(2));
+        for (rsid, associations) in associations_for_rsids(&client, rsids).await? {
             for assoc in associations {
                 if let Some(row) = map_gwas_association(&assoc, Some(&rsid)) {
                     rows.push(row);
@@ -173,6 +183,40 @@ pub fn gwas_search_query_summary(filters: &GwasSearchFilters) -> String {
     parts.join(", ")
 }
 
+/// Fetches associations for each rsID with at most `GWAS_RSID_CONCURRENCY`
+/// lookups in flight, returning them in `rsids` order so ties in the final
+/// ranking do not depend on response timing.
+async fn associations_for_rsids(
+    client: &GwasClient,
+    rsids: Vec<String>,
+) -> Result<Vec<(String, Vec<GwasAssociation>)>, BioMcpError> {
+    let lookup = |(idx, rsid): (usize, String)| async move {
+        let associations = client
+            .associations_by_rsid(&rsid, GWAS_ASSOCIATIONS_PER_RSID)
+            .await;
+        (idx, rsid, associations)
+    };
+
+    let mut pending = rsids.into_iter().enumerate();
+    let mut in_flight = pending
+        .by_ref()
+        .take(GWAS_RSID_CONCURRENCY)
+        .map(lookup)
+        .collect::<FuturesUnordered<_>>();
+    let mut out = Vec::new();
+    while let Some((idx, rsid, associations)) = in_flight.next().await {
+        out.push((idx, rsid, associations?));
+        if let Some(next) = pending.next() {
+            in_flight.push(lookup(next));
+        }
+    }
+    out.sort_by_key(|(idx, _, _)| *idx);
+    Ok(out
+        .into_iter()
+        .map(|(_, rsid, associations)| (rsid, associations))
+        .collect())
+}
+
 fn unique_rsids_from_snps(snps: &[GwasSnp], limit: usize) -> Vec<String> {
     let mut out = Vec::new();
     let mut seen = std::collections::HashSet::new();
@@ -438,6 +482,7 @@ pub(in crate::entities::variant) async fn add_gwas_section(
         Ok(client) => client,
         Err(err @ BioMcpError::SourceUnavailable { .. }) => {
             warn!(rsid = %rsid, "GWAS association data unavailable: {err}");
+            section_errors::record("gwas", "GWAS Catalog", &err);
             variant.supporting_pmids = None;
             variant.gwas_unavailable_reason =
                 Some("GWAS association data temporarily unavailable.".to_string());
@@ -449,6 +494,7 @@ pub(in crate::entities::variant) async fn add_gwas_section(
         Ok(associations) => associations,
         Err(err @ BioMcpError::SourceUnavailable { .. }) => {
             warn!(rsid = %rsid, "GWAS association data unavailable: {err}");
+            section_errors::record("gwas", "GWAS Catalog", &err);
             variant.supporting_pmids = None;
             variant.gwas_unavailable_reason =
                 Some("GWAS association data temporarily unavailable.".to_string());
@@ -1,6 +1,7 @@
 //! Sidecar tests for variant GWAS helpers.
 
 use super::super::VariantGwasAssociation;
+use super::super::test_support::*;
 use super::*;
 
 #[test]
@@ -73,3 +74,122 @@ fn collect_supporting_pmids_dedupes_case_insensitively() {
         vec!["12345".to_string(), "PMID-ABC".to_string()]
     );
 }
+
+const RSID_LOOKUP_DELAY: std::time::Duration = std::time::Duration::from_millis(400);
+
+async fn mount_snps(server: &MockServer, search: &str, rsids: &[&str]) {
+    let snps = rsids
+        .iter()
+        .map(|rsid| json!({"rsId": rsid}))
+        .collect::<Vec<_>>();
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/singleNucleotidePolymorphisms/search/{search}"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "_embedded": {"singleNucleotidePolymorphisms": snps}
+        })))
+        .mount(server)
+        .await;
+}
+
+async fn mount_delayed_association(
+    server: &MockServer,
+    rsid: &str,
+    pvalue: f64,
+    delay: std::time::Duration,
+) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/singleNucleotidePolymorphisms/{rsid}/associations"
+        )))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_delay(delay)
+                .set_body_json(json!({
+                    "_embedded": {
+                        "associations": [{
+                            "pvalue": pvalue,
+                            "efoTraits": [{"trait": "type 2 diabetes"}],
+                            "study": {"accessionId": format!("GCST-{rsid}")}
+                        }]
+                    }
+                })),
+        )
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn trait_search_overlaps_rsid_lookups() {
+    let _env = lock_env().await;
+    let server = MockServer::start().await;
+    let rsids = ["rs1", "rs2", "rs3", "rs4", "rs5", "rs6"];
+    mount_snps(&server, "findByDiseaseTrait", &rsids).await;
+    for (idx, rsid) in rsids.iter().enumerate() {
+        let pvalue = 10f64.powi(-(idx as i32) - 8);
+        mount_delayed_association(&server, rsid, pvalue, RSID_LOOKUP_DELAY).await;
+    }
+    let _base = set_env_var("BIOMCP_GWAS_BASE", Some(&server.uri()));
+
+    let started = std::time::Instant::now();
+    let page = search_gwas_page(
+        &GwasSearchFilters {
+            trait_query: Some("type 2 diabetes".to_string()),
+            ..Default::default()
+        },
+        5,
+        0,
+    )
+    .await
+    .expect("trait search should succeed");
+    let elapsed = started.elapsed();
+
+    // Six sequential lookups would take at least 2.4s.
+    assert!(
+        elapsed < RSID_LOOKUP_DELAY * 4,
+        "rsID lookups did not overlap: {elapsed:?}"
+    );
+    let rsids = page
+        .results
+        .iter()
+        .map(|row| row.rsid.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(rsids, vec!["rs6", "rs5", "rs4", "rs3", "rs2"]);
+}
+
+#[tokio::test]
+async fn rsid_lookups_return_in_request_order_when_answers_arrive_reversed() {
+    let _env = lock_env().await;
+    let server = MockServer::start().await;
+    let rsids = ["rs10", "rs11", "rs12", "rs13", "rs14"];
+    for (idx, rsid) in rsids.iter().enumerate() {
+        // Later rsIDs answer first.
+        let delay = RSID_LOOKUP_DELAY / (idx as u32 + 1);
+        mount_delayed_association(&server, rsid, 1e-9, delay).await;
+    }
+    let _base = set_env_var("BIOMCP_GWAS_BASE", Some(&server.uri()));
+
+    let client = GwasClient::new().expect("client");
+    let started = std::time::Instant::now();
+    let rows = associations_for_rsids(&client, rsids.iter().map(|r| r.to_string()).collect())
+        .await
+        .expect("lookups should succeed");
+
+    assert!(started.elapsed() < RSID_LOOKUP_DELAY * 3);
+    let order = rows
+        .iter()
+        .map(|(rsid, associations)| (rsid.as_str(), associations.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        order,
+        vec![
+            ("rs10", 1),
+            ("rs11", 1),
+            ("rs12", 1),
+            ("rs13", 1),
+            ("rs14", 1)
+        ]
+    );
+}
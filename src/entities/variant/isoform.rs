@@ -0,0 +1,101 @@
+//! Isoform-aware `GENE p.X123Y` resolution against dbNSFP transcript arrays.
+//!
+//! dbNSFP reports one residue position per Ensembl transcript, so a protein
+//! change numbered on a non-canonical isoform still resolves; this module
+//! records which transcript the numbering came from and, when the HGVS p.
+//! string lookup misses, builds the residue-position retry query.
+
+use super::{VariantIsoform, VariantTranscript};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct ProteinChange {
+    pub(super) reference: char,
+    pub(super) position: u32,
+    pub(super) alternate: char,
+}
+
+/// Parses a normalized single-letter change such as `V600E` or `R213*`.
+pub(super) fn parse_protein_change(change: &str) -> Option<ProteinChange> {
+    let change = change.trim();
+    let mut chars = change.chars();
+    let reference = chars.next().filter(|ch| ch.is_ascii_uppercase())?;
+    let alternate = chars
+        .next_back()
+        .filter(|ch| ch.is_ascii_uppercase() || *ch == '*')?;
+    let digits = chars.as_str();
+    if digits.is_empty() || !digits.chars().all(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+    let position = digits.parse::<u32>().ok().filter(|pos| *pos > 0)?;
+    Some(ProteinChange {
+        reference,
+        position,
+        alternate,
+    })
+}
+
+/// MyVariant query that matches the change by dbNSFP residue fields on any transcript.
+pub(super) fn residue_position_query(gene: &str, change: &ProteinChange) -> String {
+    // dbNSFP encodes stop-gained alternates as `X`.
+    let alternate = if change.alternate == '*' {
+        'X'
+    } else {
+        change.alternate
+    };
+    format!(
+        "dbnsfp.genename:{gene} AND dbnsfp.aa.pos:{} AND dbnsfp.aa.ref:{} AND dbnsfp.aa.alt:{alternate}",
+        change.position, change.reference
+    )
+}
+
+/// Picks the transcript whose numbering places the requested residue, preferring canonical.
+pub(super) fn annotate_isoform(
+    rows: &[VariantTranscript],
+    change: &ProteinChange,
+    position_retry: bool,
+) -> Option<VariantIsoform> {
+    let canonical_row = rows.iter().find(|row| row.canonical);
+    let places_residue = |row: &&VariantTranscript| row.position == Some(change.position);
+    let matched = rows
+        .iter()
+        .filter(places_residue)
+        .find(|row| row.canonical)
+        .or_else(|| rows.iter().find(places_residue))?;
+
+    let canonical = canonical_row.map(|_| matched.canonical);
+    let note = match canonical_row {
+        Some(canonical_row) if !matched.canonical => Some(format!(
+            "Position {} is numbered on non-canonical transcript {}; canonical transcript {} places this residue at {}.",
+            change.position,
+            matched.transcript,
+            canonical_row.transcript,
+            canonical_row
+                .position
+                .map(|pos| pos.to_string())
+                .unwrap_or_else(|| "no coding position".to_string()),
+        )),
+        _ if position_retry => Some(format!(
+            "Resolved by dbNSFP residue position {} on {} after the HGVS p. lookup found no match.",
+            change.position, matched.transcript
+        )),
+        _ => None,
+    };
+
+    Some(VariantIsoform {
+        transcript: matched.transcript.clone(),
+        protein: matched.protein.clone(),
+        position: change.position,
+        canonical,
+        canonical_transcript: canonical_row
+            .filter(|_| !matched.canonical)
+            .map(|row| row.transcript.clone()),
+        canonical_position: canonical_row
+            .filter(|_| !matched.canonical)
+            .and_then(|row| row.position),
+        position_retry,
+        note,
+    })
+}
+
+#[cfg(test)]
+mod tests;
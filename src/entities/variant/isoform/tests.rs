@@ -0,0 +1,97 @@
+//! Sidecar tests for isoform-aware protein change resolution.
+
+use super::*;
+use crate::sources::myvariant::MyVariantDbnsfp;
+use crate::transform::variant::transcripts_from_dbnsfp;
+
+fn dbnsfp(value: serde_json::Value) -> Vec<VariantTranscript> {
+    let row: MyVariantDbnsfp = serde_json::from_value(value).expect("dbnsfp fixture");
+    transcripts_from_dbnsfp(&row)
+}
+
+#[test]
+fn parse_protein_change_reads_reference_position_and_alternate() {
+    assert_eq!(
+        parse_protein_change("V600E"),
+        Some(ProteinChange {
+            reference: 'V',
+            position: 600,
+            alternate: 'E',
+        })
+    );
+    assert_eq!(
+        parse_protein_change("R213*").map(|change| change.alternate),
+        Some('*')
+    );
+    assert_eq!(parse_protein_change("V0E"), None);
+    assert_eq!(parse_protein_change("600E"), None);
+    assert_eq!(parse_protein_change("VAL600GLU"), None);
+}
+
+#[test]
+fn residue_position_query_maps_stop_to_dbnsfp_x() {
+    let change = parse_protein_change("R213*").unwrap();
+    assert_eq!(
+        residue_position_query("TP53", &change),
+        "dbnsfp.genename:TP53 AND dbnsfp.aa.pos:213 AND dbnsfp.aa.ref:R AND dbnsfp.aa.alt:X"
+    );
+}
+
+#[test]
+fn annotate_isoform_flags_non_canonical_numbering() {
+    let row = dbnsfp(serde_json::json!({
+        "aa": {"ref": "V", "alt": "E", "pos": [600, 640, -1]},
+        "ensembl": {
+            "transcriptid": ["ENST00000646891", "ENST00000644969", "ENST00000479537"],
+            "proteinid": ["ENSP00000493543", "ENSP00000496776", "."]
+        },
+        "vep_canonical": ["YES", ".", "."]
+    }));
+
+    let change = parse_protein_change("V640E").unwrap();
+    let isoform = annotate_isoform(&row, &change, false).expect("isoform match");
+    assert_eq!(isoform.transcript, "ENST00000644969");
+    assert_eq!(isoform.protein.as_deref(), Some("ENSP00000496776"));
+    assert_eq!(isoform.canonical, Some(false));
+    assert_eq!(
+        isoform.canonical_transcript.as_deref(),
+        Some("ENST00000646891")
+    );
+    assert_eq!(isoform.canonical_position, Some(600));
+    assert!(
+        isoform
+            .note
+            .as_deref()
+            .is_some_and(|note| note.contains("non-canonical transcript ENST00000644969"))
+    );
+
+    let change = parse_protein_change("V600E").unwrap();
+    let isoform = annotate_isoform(&row, &change, false).expect("canonical match");
+    assert_eq!(isoform.transcript, "ENST00000646891");
+    assert_eq!(isoform.canonical, Some(true));
+    assert!(isoform.canonical_transcript.is_none());
+    assert!(isoform.note.is_none());
+
+    let change = parse_protein_change("V700E").unwrap();
+    assert!(annotate_isoform(&row, &change, false).is_none());
+}
+
+#[test]
+fn annotate_isoform_notes_position_retry_without_canonical_flags() {
+    let row = dbnsfp(serde_json::json!({
+        "aa": {"ref": "R", "alt": "X", "pos": "213"},
+        "ensembl": {"transcriptid": ["ENST00000269305", "ENST00000445888"], "proteinid": "ENSP00000269305"}
+    }));
+
+    let change = parse_protein_change("R213*").unwrap();
+    let isoform = annotate_isoform(&row, &change, true).expect("broadcast position");
+    assert_eq!(isoform.transcript, "ENST00000269305");
+    assert_eq!(isoform.canonical, None);
+    assert!(isoform.position_retry);
+    assert!(
+        isoform
+            .note
+            .as_deref()
+            .is_some_and(|note| note.contains("after the HGVS p. lookup found no match"))
+    );
+}
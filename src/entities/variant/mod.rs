@@ -1,31 +1,58 @@
 //! Variant entity models and workflows exposed through the stable variant facade.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::sources::civic::{CivicContext, CivicEvidenceItem};
 
+mod annotate;
+mod clinvar_template;
+mod cohort_af;
+mod compare;
 mod get;
 mod gwas;
+mod isoform;
+mod normalization;
+mod offline;
+mod ranking;
 mod resolution;
 mod search;
 #[cfg(test)]
 mod test_support;
+mod validation;
 
-pub use self::get::{VARIANT_SECTION_NAMES, get, oncokb};
+pub use self::annotate::{VcfAlleleAnnotation, VcfAnnotation, annotate_vcf};
+pub use self::clinvar_template::{clinvar_submission_draft, parse_clinvar_classification};
+pub use self::cohort_af::{AlleleCount, CohortAfComparison, compare_cohort_af};
+#[allow(unused_imports)]
+pub use self::compare::{VariantComparison, VariantComparisonSide, compare_variants};
+pub use self::get::{VARIANT_SECTION_NAMES, VariantAnnotationSource, get, get_with_source, oncokb};
 #[allow(unused_imports)]
 pub use self::gwas::{gwas_search_query_summary, search_gwas, search_gwas_page};
+#[allow(unused_imports)]
+pub use self::ranking::{
+    DEFAULT_RANK_PROFILE, VariantRankProfile, VariantRankWeights, resolve_rank_profile,
+};
 pub use self::resolution::{
     classify_variant_input, parse_variant_id, parse_variant_protein_alias, variant_guidance,
 };
 #[allow(unused_imports)]
-pub use self::search::{search, search_page, search_query_summary};
+pub use self::search::{group_search_results, search, search_page, search_query_summary};
+pub use self::validation::{VariantValidation, validate_variant};
 
+pub(crate) use self::normalization::refseq_chromosome;
 pub(crate) use self::resolution::{gnomad_variant_slug, normalize_protein_change};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Variant {
     pub gene: String,
     pub id: String,
+    /// Assembly of `id`; coordinate-based sections were fetched on it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assembly: Option<GenomeAssembly>,
+    /// Sections that are GRCh38-only or carry lifted-over coordinates.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assembly_notes: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hgvs_p: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -36,6 +63,15 @@ pub struct Variant {
     pub rsid: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cosmic_id: Option<String>,
+    /// ClinGen Allele Registry canonical allele ID, e.g. `CA7200`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clingen_allele_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isoform: Option<VariantIsoform>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transcripts: Vec<VariantTranscript>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vep: Option<VariantVepAnnotation>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub significance: Option<String>,
@@ -69,6 +105,9 @@ pub struct Variant {
     pub expanded_predictions: Vec<VariantPredictionScore>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub population_breakdown: Option<VariantPopulationBreakdown>,
+    /// Per-group allele counts from the gnomAD API for the `population` section.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gnomad: Option<crate::sources::gnomad::GnomadVariantFrequencies>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cosmic_context: Option<VariantCosmicContext>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -82,6 +121,8 @@ pub struct Variant {
     pub clinvar_condition_reports: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub top_disease: Option<ConditionReportCount>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub clinvar_submissions: Vec<crate::sources::clinvar::ClinVarSubmission>,
 
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub cancer_frequencies: Vec<crate::sources::cbioportal::CancerFrequency>,
@@ -93,12 +134,17 @@ pub struct Variant {
     pub gwas_unavailable_reason: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub supporting_pmids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub litvar: Option<crate::sources::litvar::LitVarLiterature>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prediction: Option<VariantPrediction>,
+    /// Sections contributed by source plugins, in request order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plugin_sections: Vec<crate::sources::plugin::PluginSection>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct VariantGwasAssociation {
     pub rsid: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -127,7 +173,7 @@ pub struct VariantGwasAssociation {
     pub sample_description: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PopulationFrequency {
     pub population: String,
     pub af: f64,
@@ -135,7 +181,7 @@ pub struct PopulationFrequency {
     pub is_subgroup: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct VariantPopulationBreakdown {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub populations: Vec<PopulationFrequency>,
@@ -145,7 +191,86 @@ pub struct VariantPopulationBreakdown {
     pub exac_nontcga_af: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// One dbNSFP transcript row for the `transcripts` section.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VariantTranscript {
+    pub transcript: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protein: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hgvs_p: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consequence: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mane: Option<String>,
+    #[serde(default)]
+    pub canonical: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sift_pred: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sift_score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub polyphen_pred: Option<String>,
+}
+
+/// Ensembl VEP consequences merged by `get variant --source vep`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VariantVepAnnotation {
+    /// HGVS description submitted to VEP.
+    pub input: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assembly: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub most_severe_consequence: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transcripts: Vec<VariantVepTranscript>,
+}
+
+/// One VEP transcript consequence; canonical and high-impact rows sort first.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VariantVepTranscript {
+    pub transcript: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gene: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub biotype: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consequences: Vec<String>,
+    /// `HIGH`, `MODERATE`, `LOW`, or `MODIFIER`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact: Option<String>,
+    #[serde(default)]
+    pub canonical: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hgvs_c: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hgvs_p: Option<String>,
+}
+
+/// Transcript whose numbering matched a `GENE p.X123Y` lookup.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VariantIsoform {
+    pub transcript: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protein: Option<String>,
+    pub position: u32,
+    /// `None` when dbNSFP did not flag a canonical transcript.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canonical: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canonical_transcript: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canonical_position: Option<u32>,
+    /// True when the HGVS p. lookup missed and the residue-position retry matched.
+    #[serde(default)]
+    pub position_retry: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct VariantConservationScores {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phylop_100way_vertebrate: Option<f64>,
@@ -159,7 +284,7 @@ pub struct VariantConservationScores {
     pub gerp_rs: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct VariantPredictionScore {
     pub tool: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -168,7 +293,7 @@ pub struct VariantPredictionScore {
     pub prediction: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct VariantCosmicContext {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mut_freq: Option<f64>,
@@ -178,7 +303,7 @@ pub struct VariantCosmicContext {
     pub mut_nt: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct VariantCgiAssociation {
     pub drug: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -191,7 +316,7 @@ pub struct VariantCgiAssociation {
     pub source: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct VariantCivicSection {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub cached_evidence: Vec<CivicEvidenceItem>,
@@ -210,13 +335,13 @@ pub struct TreatmentImplication {
     pub note: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ConditionReportCount {
     pub condition: String,
     pub reports: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct VariantPrediction {
     /// Gene expression log fold change (RNA-seq)
     pub expression_lfc: Option<f64>,
@@ -243,6 +368,130 @@ pub struct VariantSearchResult {
     pub gnomad_af: Option<f64>,
     pub revel: Option<f64>,
     pub gerp: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cadd_score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consequence: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariantSearchGroupBy {
+    Gene,
+    Consequence,
+    Significance,
+}
+
+impl VariantSearchGroupBy {
+    pub fn from_flag(value: &str) -> Result<Self, crate::error::BioMcpError> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "gene" => Ok(Self::Gene),
+            "consequence" => Ok(Self::Consequence),
+            "significance" => Ok(Self::Significance),
+            other => Err(crate::error::BioMcpError::InvalidArgument(format!(
+                "Unknown --group-by '{other}'. Expected one of: gene, consequence, significance."
+            ))),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Gene => "gene",
+            Self::Consequence => "consequence",
+            Self::Significance => "significance",
+        }
+    }
+}
+
+/// Reference genome assembly for genomic coordinates and HGVS `g.` IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum GenomeAssembly {
+    #[serde(rename = "GRCh37")]
+    Grch37,
+    #[serde(rename = "GRCh38")]
+    Grch38,
+}
+
+impl GenomeAssembly {
+    pub fn from_flag(value: &str) -> Result<Self, crate::error::BioMcpError> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "grch37" | "hg19" => Ok(Self::Grch37),
+            "grch38" | "hg38" => Ok(Self::Grch38),
+            other => Err(crate::error::BioMcpError::InvalidArgument(format!(
+                "Unknown --assembly '{other}'. Expected 'grch37' or 'grch38'."
+            ))),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Grch37 => "GRCh37",
+            Self::Grch38 => "GRCh38",
+        }
+    }
+
+    fn myvariant_assembly(self) -> &'static str {
+        match self {
+            Self::Grch37 => "hg19",
+            Self::Grch38 => "hg38",
+        }
+    }
+}
+
+/// gnomAD release queried for the `population` section's allele counts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GnomadVersion {
+    /// gnomAD v2.1 (GRCh37).
+    V2,
+    /// gnomAD v3.1 genomes (GRCh38).
+    V3,
+    /// gnomAD v4.1 (GRCh38).
+    #[default]
+    V4,
+}
+
+impl GnomadVersion {
+    pub fn from_flag(value: &str) -> Result<Self, crate::error::BioMcpError> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "v2" | "2" | "v2.1" | "r2" => Ok(Self::V2),
+            "v3" | "3" | "v3.1" | "r3" => Ok(Self::V3),
+            "" | "v4" | "4" | "v4.1" | "r4" => Ok(Self::V4),
+            other => Err(crate::error::BioMcpError::InvalidArgument(format!(
+                "Unknown --gnomad-version '{other}'. Expected 'v2', 'v3', or 'v4'."
+            ))),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::V2 => "v2",
+            Self::V3 => "v3",
+            Self::V4 => "v4",
+        }
+    }
+
+    /// gnomAD API dataset ID for the release.
+    pub(crate) fn dataset(self) -> &'static str {
+        match self {
+            Self::V2 => "gnomad_r2_1",
+            Self::V3 => "gnomad_r3",
+            Self::V4 => "gnomad_r4",
+        }
+    }
+
+    pub fn assembly(self) -> GenomeAssembly {
+        match self {
+            Self::V2 => GenomeAssembly::Grch37,
+            Self::V3 | Self::V4 => GenomeAssembly::Grch38,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariantSearchGroup {
+    pub key: String,
+    pub count: usize,
+    pub results: Vec<VariantSearchResult>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -326,6 +575,7 @@ pub struct VariantSearchFilters {
     pub has: Option<String>,
     pub missing: Option<String>,
     pub therapy: Option<String>,
+    pub rank_profile: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -340,5 +590,12 @@ pub struct GwasSearchFilters {
 pub enum VariantIdFormat {
     RsId(String),
     HgvsGenomic(String),
-    GeneProteinChange { gene: String, change: String },
+    GeneProteinChange {
+        gene: String,
+        change: String,
+    },
+    ClinGenAllele(String),
+    /// RefSeq/Ensembl/LRG nucleotide HGVS, projected onto the genome through
+    /// VariantValidator before the MyVariant.info lookup.
+    HgvsReference(String),
 }
@@ -0,0 +1,219 @@
+//! Local HGVS clean-up shared by `validate hgvs` and `get variant`.
+//!
+//! Rewrites only notational slips whose intended meaning is unambiguous:
+//! letter case, arrow spellings, a missing `:` or coordinate-type prefix that
+//! the reference accession implies, and `chr`/VCF-style genomic shorthand.
+//! Anything that would need sequence knowledge (a missing accession version, a
+//! wrong reference base) is left to VariantValidator.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use super::validation::parse_hgvs_syntax;
+
+const EDIT_KEYWORDS: &[&str] = &["delins", "del", "dup", "ins", "inv"];
+
+fn genomic_shorthand_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?i)^(?:chr)?([0-9]{1,2}|X|Y|MT?)[:-](?:g\.)?([0-9]+)(?:([ACGT])(?:>|->|/)([ACGT])|[:-]([ACGT])[:-]([ACGT]))$",
+        )
+        .expect("valid regex")
+    })
+}
+
+fn missing_colon_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"^([A-Za-z]{2,4}_?[0-9]+(?:t[0-9]+)?\.[0-9]+(?:\([A-Za-z0-9._-]+\))?)([cgmnrp]\..+)$",
+        )
+        .expect("valid regex")
+    })
+}
+
+fn gene_protein_hgvs_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^[A-Za-z]{2,4}_?[0-9]+(?:\.[0-9]+)?\(([A-Z][A-Z0-9-]+)\):p\.\(?([A-Za-z*]{1,3}[0-9]+[A-Za-z*]{1,3})\)?$")
+            .expect("valid regex")
+    })
+}
+
+/// Rewrites `7:140453136A>T`, `chr7-140453136-A-T`, and similar single-base
+/// genomic shorthand into the `chr7:g.140453136A>T` form MyVariant.info keys on.
+pub(crate) fn normalize_genomic_shorthand(input: &str) -> Option<String> {
+    let caps = genomic_shorthand_re().captures(input.trim())?;
+    let chromosome = match caps[1].to_ascii_uppercase().as_str() {
+        "M" | "MT" => "M".to_string(),
+        chromosome @ ("X" | "Y") => chromosome.to_string(),
+        number => {
+            let number = number.parse::<u8>().ok()?;
+            if !(1..=22).contains(&number) {
+                return None;
+            }
+            number.to_string()
+        }
+    };
+    let reference = caps.get(3).or_else(|| caps.get(5))?.as_str();
+    let alternate = caps.get(4).or_else(|| caps.get(6))?.as_str();
+    if reference.eq_ignore_ascii_case(alternate) {
+        return None;
+    }
+    Some(format!(
+        "chr{chromosome}:g.{}{}>{}",
+        &caps[2],
+        reference.to_ascii_uppercase(),
+        alternate.to_ascii_uppercase()
+    ))
+}
+
+/// Chromosome name for a RefSeq chromosome accession of either build, using
+/// MyVariant.info's `MT` for the mitochondrial genome.
+pub(crate) fn refseq_chromosome(accession: &str) -> Option<String> {
+    let number = accession.strip_prefix("NC_")?.split('.').next()?;
+    if number == "012920" {
+        return Some("MT".into());
+    }
+    match number.parse::<u32>().ok()? {
+        chromosome @ 1..=22 if number.starts_with("0000") => Some(chromosome.to_string()),
+        23 if number.starts_with("0000") => Some("X".into()),
+        24 if number.starts_with("0000") => Some("Y".into()),
+        _ => None,
+    }
+}
+
+/// Converts `NC_000007.13:g.140453136A>T` into the `chr7:g.140453136A>T` ID
+/// MyVariant.info uses for the same build.
+pub(super) fn myvariant_genomic_id(genomic_hgvs: &str) -> Option<String> {
+    let (accession, change) = genomic_hgvs.trim().split_once(":g.")?;
+    let chromosome = refseq_chromosome(accession)?;
+    (!change.is_empty()).then(|| format!("chr{chromosome}:g.{change}"))
+}
+
+fn implied_coordinate_type(accession: &str) -> Option<char> {
+    let upper = accession.to_ascii_uppercase();
+    if upper.starts_with("NC_012920") {
+        Some('m')
+    } else if ["NM_", "XM_", "ENST"].iter().any(|p| upper.starts_with(p))
+        || (upper.starts_with("LRG_") && accession.contains('t'))
+    {
+        Some('c')
+    } else if ["NR_", "XR_"].iter().any(|p| upper.starts_with(p)) {
+        Some('n')
+    } else if ["NP_", "XP_", "ENSP"].iter().any(|p| upper.starts_with(p))
+        || (upper.starts_with("LRG_") && accession.contains('p'))
+    {
+        Some('p')
+    } else if ["NC_", "NG_", "NT_", "NW_", "LRG_"]
+        .iter()
+        .any(|p| upper.starts_with(p))
+    {
+        Some('g')
+    } else {
+        None
+    }
+}
+
+/// Uppercases the alphabetic prefix of an accession (`nm_000546.6` -> `NM_000546.6`)
+/// while keeping LRG transcript/protein suffixes (`LRG_199t1`) lowercase.
+fn normalize_accession_case(accession: &str) -> String {
+    let prefix_len = accession
+        .find(|ch: char| !ch.is_ascii_alphabetic())
+        .unwrap_or(accession.len());
+    format!(
+        "{}{}",
+        accession[..prefix_len].to_ascii_uppercase(),
+        &accession[prefix_len..]
+    )
+}
+
+/// Recases a nucleotide edit: bases upper (lower for `r.`), edit keywords lower.
+fn normalize_edit_case(edit: &str, coordinate_type: char) -> String {
+    let edit = edit.replace("->", ">").replace('→', ">").replace(">>", ">");
+    let lower = edit.to_ascii_lowercase();
+    let mut out = String::with_capacity(edit.len());
+    let mut index = 0;
+    while index < edit.len() {
+        if let Some(keyword) = EDIT_KEYWORDS
+            .iter()
+            .find(|keyword| lower[index..].starts_with(*keyword))
+        {
+            out.push_str(keyword);
+            index += keyword.len();
+            continue;
+        }
+        let Some(ch) = edit[index..].chars().next() else {
+            break;
+        };
+        index += ch.len_utf8();
+        out.push(match ch {
+            '/' => '>',
+            ch if coordinate_type == 'r' => ch.to_ascii_lowercase(),
+            ch => ch.to_ascii_uppercase(),
+        });
+    }
+    out
+}
+
+/// Applies every unambiguous notational fix and returns the repaired
+/// description when it differs from the input and passes the syntax check.
+pub(super) fn repair_hgvs(input: &str) -> Option<String> {
+    let compact = input.split_whitespace().collect::<String>();
+    let compact = match missing_colon_re().captures(&compact) {
+        Some(caps) => format!("{}:{}", &caps[1], &caps[2]),
+        None => compact,
+    };
+    let (reference, description) = compact.split_once(':')?;
+    let (accession, qualifier) = match reference.split_once('(') {
+        Some((accession, qualifier)) => (accession, format!("({qualifier}")),
+        None => (reference, String::new()),
+    };
+    let accession = normalize_accession_case(accession);
+    let implied = implied_coordinate_type(&accession)?;
+
+    let (stated, edit) = match description.split_once('.') {
+        Some((prefix, edit))
+            if prefix.len() == 1 && "cgmnrp".contains(&prefix.to_ascii_lowercase()) =>
+        {
+            (prefix.to_ascii_lowercase().chars().next(), edit)
+        }
+        _ => (None, description),
+    };
+    // A `NC_...(NM_...)` qualifier means the coordinates are transcript-relative.
+    let transcript_qualified = qualifier.contains('_') || qualifier.starts_with("(ENST");
+    let coordinate_type = match stated {
+        Some(stated) => stated,
+        None if transcript_qualified => 'c',
+        None => implied,
+    };
+    if coordinate_type == 'p' {
+        return None;
+    }
+
+    let repaired = format!(
+        "{accession}{qualifier}:{coordinate_type}.{}",
+        normalize_edit_case(edit, coordinate_type)
+    );
+    (repaired != input.trim() && parse_hgvs_syntax(&repaired).is_ok()).then_some(repaired)
+}
+
+/// Corrected forms to offer for a description that failed the syntax check.
+pub(super) fn suggest_hgvs_corrections(input: &str) -> Vec<String> {
+    let mut suggestions = Vec::new();
+    if let Some(repaired) = repair_hgvs(input) {
+        suggestions.push(repaired);
+    }
+    // `NM_004333.4(BRAF):p.V600E` is a gene + protein change in disguise.
+    if let Some(caps) = gene_protein_hgvs_re().captures(input.trim())
+        && let Some(change) = super::normalize_protein_change(&caps[2])
+    {
+        suggestions.push(format!("{} {change}", &caps[1]));
+    }
+    suggestions
+}
+
+#[cfg(test)]
+mod tests;
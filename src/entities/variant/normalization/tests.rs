@@ -0,0 +1,120 @@
+//! Sidecar tests for local HGVS repair and genomic shorthand rewriting.
+
+use super::*;
+
+#[test]
+fn normalize_genomic_shorthand_accepts_common_spellings() {
+    for input in [
+        "chr7:g.140453136A>T",
+        "chr7:140453136A>T",
+        "7:g.140453136A>T",
+        "CHR7:g.140453136a>t",
+        "chr7-140453136-A-T",
+        "7-140453136-A-T",
+        "7:140453136:A:T",
+        "chr7:g.140453136A/T",
+    ] {
+        assert_eq!(
+            normalize_genomic_shorthand(input).as_deref(),
+            Some("chr7:g.140453136A>T"),
+            "{input}"
+        );
+    }
+    assert_eq!(
+        normalize_genomic_shorthand("chrMT:3243A>G").as_deref(),
+        Some("chrM:g.3243A>G")
+    );
+    assert_eq!(
+        normalize_genomic_shorthand("x-153296777-G-A").as_deref(),
+        Some("chrX:g.153296777G>A")
+    );
+}
+
+#[test]
+fn normalize_genomic_shorthand_rejects_non_variants() {
+    for input in [
+        "chr23:g.100A>T",
+        "chr7:g.140453136A>A",
+        "chr7:g.140453136del",
+        "BRAF V600E",
+        "rs113488022",
+        "7-140453136",
+    ] {
+        assert_eq!(normalize_genomic_shorthand(input), None, "{input}");
+    }
+}
+
+#[test]
+fn myvariant_genomic_id_maps_refseq_chromosomes_of_either_build() {
+    assert_eq!(
+        myvariant_genomic_id("NC_000007.13:g.140453136A>T").as_deref(),
+        Some("chr7:g.140453136A>T")
+    );
+    assert_eq!(
+        myvariant_genomic_id("NC_000023.11:g.154030912G>A").as_deref(),
+        Some("chrX:g.154030912G>A")
+    );
+    assert_eq!(
+        myvariant_genomic_id("NC_000017.10:g.41245466_41245467del").as_deref(),
+        Some("chr17:g.41245466_41245467del")
+    );
+    assert_eq!(myvariant_genomic_id("NG_012232.1:g.5000A>T"), None);
+    assert_eq!(myvariant_genomic_id("NM_004333.4:c.1799T>A"), None);
+}
+
+#[test]
+fn repair_hgvs_fixes_notation_only_slips() {
+    for (input, expected) in [
+        ("NM_004333.4:c.1799t>a", "NM_004333.4:c.1799T>A"),
+        ("nm_004333.4:c.1799T>A", "NM_004333.4:c.1799T>A"),
+        ("NM_004333.4:1799T>A", "NM_004333.4:c.1799T>A"),
+        ("NM_004333.4c.1799T>A", "NM_004333.4:c.1799T>A"),
+        ("NM_004333.4:c.1799T->A", "NM_004333.4:c.1799T>A"),
+        ("NM_004333.4:c.1799T/A", "NM_004333.4:c.1799T>A"),
+        ("NM_004333.4 : c.1799T>A", "NM_004333.4:c.1799T>A"),
+        ("NM_007294.4:c.68_69DEL", "NM_007294.4:c.68_69del"),
+        (
+            "NM_000492.4:c.1521_1523DELctt",
+            "NM_000492.4:c.1521_1523delCTT",
+        ),
+        ("NC_000007.13:140453136A>T", "NC_000007.13:g.140453136A>T"),
+        ("NC_012920.1:3243A>G", "NC_012920.1:m.3243A>G"),
+        ("NR_024540.1:5C>T", "NR_024540.1:n.5C>T"),
+        ("NM_000546.6:r.215C>G", "NM_000546.6:r.215c>g"),
+        ("NM_000546.6(TP53):c.215c>g", "NM_000546.6(TP53):c.215C>G"),
+    ] {
+        assert_eq!(repair_hgvs(input).as_deref(), Some(expected), "{input}");
+    }
+}
+
+#[test]
+fn repair_hgvs_leaves_ambiguous_or_valid_input_alone() {
+    for input in [
+        "NM_004333.4:c.1799T>A",
+        "NM_004333:c.1799T>A",
+        "NM_004333.4:g.1799T>A",
+        "NC_000007.13:c.1799T>A",
+        "NP_004324.2:V600E",
+        "BRAF V600E",
+        "XYZ_1.1:c.1A>G",
+    ] {
+        assert_eq!(repair_hgvs(input), None, "{input}");
+    }
+}
+
+#[test]
+fn suggest_hgvs_corrections_offers_gene_protein_form_for_transcript_p_changes() {
+    assert_eq!(
+        suggest_hgvs_corrections("NM_004333.4(BRAF):p.V600E"),
+        vec!["BRAF V600E".to_string()]
+    );
+    assert_eq!(
+        suggest_hgvs_corrections("NM_004333.4(BRAF):p.(Val600Glu)"),
+        vec!["BRAF V600E".to_string()]
+    );
+    assert_eq!(
+        suggest_hgvs_corrections("NM_004333.4:c.1799t>a"),
+        vec!["NM_004333.4:c.1799T>A".to_string()]
+    );
+    assert!(suggest_hgvs_corrections("not a variant").is_empty());
+}
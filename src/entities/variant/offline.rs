@@ -0,0 +1,156 @@
+//! Variant cards served from an offline snapshot bundle under `--offline`.
+
+use crate::entities::SearchPage;
+use crate::error::BioMcpError;
+use crate::transform;
+use crate::utils::snapshot::{SnapshotEntity, SnapshotStore, lookup_key, offline_unavailable};
+
+use super::{Variant, VariantSearchFilters, VariantSearchResult};
+
+pub(super) fn get(
+    store: &SnapshotStore,
+    id: &str,
+    sections: &[String],
+) -> Result<Variant, BioMcpError> {
+    let variant: Variant = store.get(SnapshotEntity::Variant, id, sections)?;
+    transform::fields::apply("variant", variant)
+}
+
+fn revel(variant: &Variant) -> Option<f64> {
+    variant
+        .expanded_predictions
+        .iter()
+        .find(|score| score.tool.eq_ignore_ascii_case("REVEL"))
+        .and_then(|score| score.score)
+}
+
+fn gerp(variant: &Variant) -> Option<f64> {
+    variant
+        .conservation
+        .as_ref()
+        .and_then(|scores| scores.gerp_rs)
+}
+
+fn filter_value(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+fn text_matches(actual: Option<&str>, wanted: &Option<String>) -> bool {
+    match filter_value(wanted) {
+        None => true,
+        Some(wanted) => {
+            actual.is_some_and(|actual| lookup_key(actual).contains(&lookup_key(wanted)))
+        }
+    }
+}
+
+fn matches_filters(variant: &Variant, filters: &VariantSearchFilters) -> bool {
+    let gene_matches = match filter_value(&filters.gene) {
+        None => true,
+        Some(gene) => variant.gene.eq_ignore_ascii_case(gene),
+    };
+    let hgvsp_matches = match filter_value(&filters.hgvsp) {
+        None => true,
+        Some(hgvsp) => {
+            let wanted = hgvsp.trim_start_matches("p.");
+            [variant.hgvs_p.as_deref(), variant.legacy_name.as_deref()]
+                .into_iter()
+                .flatten()
+                .any(|name| name.trim_start_matches("p.").eq_ignore_ascii_case(wanted))
+        }
+    };
+    let rsid_matches = match filter_value(&filters.rsid) {
+        None => true,
+        Some(rsid) => variant
+            .rsid
+            .as_deref()
+            .is_some_and(|actual| actual.eq_ignore_ascii_case(rsid)),
+    };
+
+    gene_matches
+        && hgvsp_matches
+        && rsid_matches
+        && text_matches(variant.significance.as_deref(), &filters.significance)
+        && text_matches(variant.consequence.as_deref(), &filters.consequence)
+        && filters
+            .max_frequency
+            .is_none_or(|max| variant.gnomad_af.is_none_or(|af| af <= max))
+        && filters
+            .min_cadd
+            .is_none_or(|min| variant.cadd_score.is_some_and(|cadd| cadd >= min))
+        && filters
+            .revel_min
+            .is_none_or(|min| revel(variant).is_some_and(|score| score >= min))
+        && filters
+            .gerp_min
+            .is_none_or(|min| gerp(variant).is_some_and(|score| score >= min))
+}
+
+fn search_result(variant: Variant) -> VariantSearchResult {
+    VariantSearchResult {
+        revel: revel(&variant),
+        gerp: gerp(&variant),
+        id: variant.id,
+        gene: variant.gene,
+        hgvs_p: variant.hgvs_p,
+        legacy_name: variant.legacy_name,
+        significance: variant.significance,
+        clinvar_stars: variant.clinvar_review_stars,
+        gnomad_af: variant.gnomad_af,
+        cadd_score: variant.cadd_score,
+        consequence: variant.consequence,
+    }
+}
+
+/// Filters stored variants in bundle order. Filters that need MyVariant.info
+/// facets or other sources are refused rather than ignored.
+pub(super) fn search_page(
+    store: &SnapshotStore,
+    filters: &VariantSearchFilters,
+    limit: usize,
+    offset: usize,
+) -> Result<SearchPage<VariantSearchResult>, BioMcpError> {
+    let text_flags = [
+        ("--hgvsc", &filters.hgvsc),
+        ("--review-status", &filters.review_status),
+        ("--population", &filters.population),
+        ("--tumor-site", &filters.tumor_site),
+        ("--condition", &filters.condition),
+        ("--impact", &filters.impact),
+        ("--has", &filters.has),
+        ("--missing", &filters.missing),
+        ("--therapy", &filters.therapy),
+        ("--rank-profile", &filters.rank_profile),
+    ];
+    let mut unsupported = text_flags
+        .into_iter()
+        .filter(|(_, value)| filter_value(value).is_some())
+        .map(|(flag, _)| flag)
+        .collect::<Vec<_>>();
+    if filters.protein_alias.is_some() {
+        unsupported.push("a residue alias");
+    }
+    if filters.lof {
+        unsupported.push("--lof");
+    }
+    if !unsupported.is_empty() {
+        return Err(offline_unavailable(format!(
+            "Offline variant search cannot apply {}.",
+            unsupported.join(", ")
+        )));
+    }
+
+    let matches = store
+        .all::<Variant>(SnapshotEntity::Variant)?
+        .into_iter()
+        .filter(|variant| matches_filters(variant, filters))
+        .collect::<Vec<_>>();
+    let total = matches.len();
+    let results = matches
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .map(search_result)
+        .collect();
+    Ok(SearchPage::offset(results, Some(total)))
+}
@@ -0,0 +1,251 @@
+//! Variant search ranking profiles (built-in plus user-defined in `config.toml`).
+
+use serde::Serialize;
+
+use crate::config::{UserConfig, VariantRankProfileToml};
+use crate::error::BioMcpError;
+
+use super::VariantSearchResult;
+
+pub const DEFAULT_RANK_PROFILE: &str = "default";
+const BUILTIN_RANK_PROFILES: [&str; 3] = [DEFAULT_RANK_PROFILE, "clinical", "research"];
+
+/// Weights applied by `search_result_quality_score`.
+///
+/// Presence weights add a fixed bonus when a field is populated;
+/// `clinvar_per_star`, `revel_per_unit`, and `cadd_per_10_phred` scale with the
+/// value itself.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct VariantRankWeights {
+    pub significance: f64,
+    pub gnomad_af: f64,
+    pub clinvar_present: f64,
+    pub clinvar_per_star: f64,
+    pub revel_present: f64,
+    pub revel_per_unit: f64,
+    pub cadd_present: f64,
+    pub cadd_per_10_phred: f64,
+    pub gerp: f64,
+    pub hgvs_p: f64,
+    pub gene: f64,
+}
+
+impl VariantRankWeights {
+    pub const DEFAULT: Self = Self {
+        significance: 4.0,
+        gnomad_af: 4.0,
+        clinvar_present: 3.0,
+        clinvar_per_star: 0.0,
+        revel_present: 2.0,
+        revel_per_unit: 0.0,
+        cadd_present: 0.0,
+        cadd_per_10_phred: 0.0,
+        gerp: 2.0,
+        hgvs_p: 2.0,
+        gene: 1.0,
+    };
+
+    pub const CLINICAL: Self = Self {
+        significance: 6.0,
+        gnomad_af: 3.0,
+        clinvar_present: 3.0,
+        clinvar_per_star: 2.0,
+        revel_present: 1.0,
+        revel_per_unit: 0.0,
+        cadd_present: 0.0,
+        cadd_per_10_phred: 0.0,
+        gerp: 1.0,
+        hgvs_p: 2.0,
+        gene: 1.0,
+    };
+
+    pub const RESEARCH: Self = Self {
+        significance: 2.0,
+        gnomad_af: 2.0,
+        clinvar_present: 1.0,
+        clinvar_per_star: 0.0,
+        revel_present: 2.0,
+        revel_per_unit: 6.0,
+        cadd_present: 2.0,
+        cadd_per_10_phred: 1.5,
+        gerp: 2.0,
+        hgvs_p: 2.0,
+        gene: 1.0,
+    };
+
+    fn builtin(name: &str) -> Option<Self> {
+        match name {
+            DEFAULT_RANK_PROFILE => Some(Self::DEFAULT),
+            "clinical" => Some(Self::CLINICAL),
+            "research" => Some(Self::RESEARCH),
+            _ => None,
+        }
+    }
+
+    fn with_overrides(self, overrides: &VariantRankProfileToml) -> Self {
+        Self {
+            significance: overrides.significance.unwrap_or(self.significance),
+            gnomad_af: overrides.gnomad_af.unwrap_or(self.gnomad_af),
+            clinvar_present: overrides.clinvar_present.unwrap_or(self.clinvar_present),
+            clinvar_per_star: overrides.clinvar_per_star.unwrap_or(self.clinvar_per_star),
+            revel_present: overrides.revel_present.unwrap_or(self.revel_present),
+            revel_per_unit: overrides.revel_per_unit.unwrap_or(self.revel_per_unit),
+            cadd_present: overrides.cadd_present.unwrap_or(self.cadd_present),
+            cadd_per_10_phred: overrides
+                .cadd_per_10_phred
+                .unwrap_or(self.cadd_per_10_phred),
+            gerp: overrides.gerp.unwrap_or(self.gerp),
+            hgvs_p: overrides.hgvs_p.unwrap_or(self.hgvs_p),
+            gene: overrides.gene.unwrap_or(self.gene),
+        }
+    }
+
+    fn is_finite(&self) -> bool {
+        [
+            self.significance,
+            self.gnomad_af,
+            self.clinvar_present,
+            self.clinvar_per_star,
+            self.revel_present,
+            self.revel_per_unit,
+            self.cadd_present,
+            self.cadd_per_10_phred,
+            self.gerp,
+            self.hgvs_p,
+            self.gene,
+        ]
+        .iter()
+        .all(|weight| weight.is_finite())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VariantRankProfile {
+    pub name: String,
+    pub weights: VariantRankWeights,
+}
+
+impl Default for VariantRankProfile {
+    fn default() -> Self {
+        Self {
+            name: DEFAULT_RANK_PROFILE.to_string(),
+            weights: VariantRankWeights::DEFAULT,
+        }
+    }
+}
+
+fn has_text(value: Option<&str>) -> bool {
+    value.map(str::trim).is_some_and(|v| !v.is_empty())
+}
+
+pub(super) fn search_result_quality_score(
+    row: &VariantSearchResult,
+    weights: &VariantRankWeights,
+) -> f64 {
+    let mut score = 0.0;
+    if has_text(row.significance.as_deref()) {
+        score += weights.significance;
+    }
+    if row.gnomad_af.is_some() {
+        score += weights.gnomad_af;
+    }
+    if let Some(stars) = row.clinvar_stars {
+        score += weights.clinvar_present + weights.clinvar_per_star * f64::from(stars);
+    }
+    if let Some(revel) = row.revel {
+        score += weights.revel_present + weights.revel_per_unit * revel;
+    }
+    if let Some(cadd) = row.cadd_score {
+        score += weights.cadd_present + weights.cadd_per_10_phred * (cadd / 10.0);
+    }
+    if row.gerp.is_some() {
+        score += weights.gerp;
+    }
+    if has_text(row.hgvs_p.as_deref()) {
+        score += weights.hgvs_p;
+    }
+    if !row.gene.trim().is_empty() {
+        score += weights.gene;
+    }
+    score
+}
+
+fn available_profiles(config: &UserConfig) -> String {
+    let mut names = BUILTIN_RANK_PROFILES
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    for name in config.variant.rank_profiles.keys() {
+        if !names.iter().any(|existing| existing == name) {
+            names.push(name.clone());
+        }
+    }
+    names.join(", ")
+}
+
+/// Resolves a ranking profile by name, falling back to `[variant].rank_profile`
+/// and then the built-in default. User-defined profiles shadow built-ins.
+pub(crate) fn resolve_rank_profile_with_config(
+    requested: Option<&str>,
+    config: &UserConfig,
+) -> Result<VariantRankProfile, BioMcpError> {
+    let name = requested
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .or_else(|| {
+            config
+                .variant
+                .rank_profile
+                .as_deref()
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+        })
+        .unwrap_or(DEFAULT_RANK_PROFILE)
+        .to_ascii_lowercase();
+
+    let weights = if let Some(custom) = config
+        .variant
+        .rank_profiles
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(&name))
+        .map(|(_, profile)| profile)
+    {
+        let base_name = custom
+            .base
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .unwrap_or(DEFAULT_RANK_PROFILE)
+            .to_ascii_lowercase();
+        let base = VariantRankWeights::builtin(&base_name).ok_or_else(|| {
+            BioMcpError::InvalidArgument(format!(
+                "Rank profile '{name}' has unknown base '{base_name}'. Expected one of: {}",
+                BUILTIN_RANK_PROFILES.join(", ")
+            ))
+        })?;
+        base.with_overrides(custom)
+    } else {
+        VariantRankWeights::builtin(&name).ok_or_else(|| {
+            BioMcpError::InvalidArgument(format!(
+                "Unknown --rank-profile '{name}'. Expected one of: {}",
+                available_profiles(config)
+            ))
+        })?
+    };
+
+    if !weights.is_finite() {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Rank profile '{name}' has a non-finite weight"
+        )));
+    }
+
+    Ok(VariantRankProfile { name, weights })
+}
+
+pub fn resolve_rank_profile(requested: Option<&str>) -> Result<VariantRankProfile, BioMcpError> {
+    let config = crate::config::load_user_config()?;
+    resolve_rank_profile_with_config(requested, &config)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,86 @@
+//! Sidecar tests for variant ranking profiles.
+
+use super::*;
+use crate::config::parse_user_config;
+
+fn row(stars: Option<u8>, revel: Option<f64>, cadd: Option<f64>) -> VariantSearchResult {
+    VariantSearchResult {
+        id: "chr1:g.1A>T".into(),
+        gene: "TP53".into(),
+        hgvs_p: None,
+        legacy_name: None,
+        significance: None,
+        clinvar_stars: stars,
+        gnomad_af: None,
+        revel,
+        gerp: None,
+        cadd_score: cadd,
+        consequence: None,
+    }
+}
+
+#[test]
+fn default_profile_matches_legacy_presence_weights() {
+    let profile =
+        resolve_rank_profile_with_config(None, &UserConfig::default()).expect("default profile");
+    assert_eq!(profile.name, DEFAULT_RANK_PROFILE);
+    let score = search_result_quality_score(&row(Some(2), Some(0.9), None), &profile.weights);
+    assert_eq!(score, 3.0 + 2.0 + 1.0);
+}
+
+#[test]
+fn clinical_profile_prefers_reviewed_variants_and_research_prefers_scores() {
+    let reviewed = row(Some(3), None, None);
+    let scored = row(None, Some(0.95), Some(32.0));
+
+    let clinical = VariantRankWeights::CLINICAL;
+    assert!(
+        search_result_quality_score(&reviewed, &clinical)
+            > search_result_quality_score(&scored, &clinical)
+    );
+
+    let research = VariantRankWeights::RESEARCH;
+    assert!(
+        search_result_quality_score(&scored, &research)
+            > search_result_quality_score(&reviewed, &research)
+    );
+}
+
+#[test]
+fn config_profiles_inherit_from_base_and_set_the_default() {
+    let config = parse_user_config(
+        r#"
+[variant]
+rank_profile = "Lab"
+
+[variant.rank_profiles.lab]
+base = "clinical"
+gene = 0
+"#,
+        None,
+    )
+    .expect("config should parse");
+
+    let profile = resolve_rank_profile_with_config(None, &config).expect("lab profile");
+    assert_eq!(profile.name, "lab");
+    assert_eq!(profile.weights.gene, 0.0);
+    assert_eq!(
+        profile.weights.clinvar_per_star,
+        VariantRankWeights::CLINICAL.clinvar_per_star
+    );
+
+    let explicit = resolve_rank_profile_with_config(Some("research"), &config).expect("flag wins");
+    assert_eq!(explicit.weights, VariantRankWeights::RESEARCH);
+}
+
+#[test]
+fn unknown_profile_lists_builtin_and_config_names() {
+    let config = parse_user_config("[variant.rank_profiles.lab]\n", None).expect("config");
+    let err = resolve_rank_profile_with_config(Some("nope"), &config).expect_err("unknown");
+    let message = err.to_string();
+    assert!(message.contains("default, clinical, research, lab"));
+
+    let bad_base =
+        parse_user_config("[variant.rank_profiles.lab]\nbase = \"lab\"\n", None).expect("config");
+    assert!(resolve_rank_profile_with_config(Some("lab"), &bad_base).is_err());
+}
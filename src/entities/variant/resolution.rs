@@ -5,6 +5,8 @@ use std::sync::OnceLock;
 
 use crate::error::BioMcpError;
 
+use super::normalization::{normalize_genomic_shorthand, repair_hgvs};
+use super::validation::parse_hgvs_syntax;
 use super::{
     VariantGuidance, VariantGuidanceKind, VariantIdFormat, VariantInputKind, VariantProteinAlias,
     VariantShorthand,
@@ -15,6 +17,11 @@ fn rsid_re() -> &'static Regex {
     RE.get_or_init(|| Regex::new(r"(?i)^(rs\d+)$").expect("valid regex"))
 }
 
+fn clingen_allele_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)^(CA\d+)$").expect("valid regex"))
+}
+
 fn hgvs_re() -> &'static Regex {
     static RE: OnceLock<Regex> = OnceLock::new();
     RE.get_or_init(|| Regex::new(r"^(chr[0-9XYM]+:g\.\d+[ACGT]>[ACGT])$").expect("valid regex"))
@@ -103,6 +110,17 @@ fn parse_exact_gene_protein_change(input: &str) -> Option<VariantIdFormat> {
     })
 }
 
+/// Nucleotide HGVS on a versioned reference sequence, after notation repair.
+/// Protein (`p.`) descriptions stay unsupported: MyVariant.info has no
+/// accession-keyed protein lookup, so they go through `GENE CHANGE` instead.
+fn reference_hgvs(input: &str) -> Option<String> {
+    let parsed = match parse_hgvs_syntax(input) {
+        Ok(parsed) => parsed,
+        Err(_) => parse_hgvs_syntax(&repair_hgvs(input)?).ok()?,
+    };
+    (parsed.coordinate_type != 'p').then_some(parsed.canonical)
+}
+
 pub fn classify_variant_input(input: &str) -> VariantInputKind {
     let input = input.trim();
     if input.is_empty() {
@@ -115,6 +133,17 @@ pub fn classify_variant_input(input: &str) -> VariantInputKind {
     if let Some(caps) = hgvs_re().captures(input) {
         return VariantInputKind::Exact(VariantIdFormat::HgvsGenomic(caps[1].to_string()));
     }
+    if let Some(hgvs) = normalize_genomic_shorthand(input) {
+        return VariantInputKind::Exact(VariantIdFormat::HgvsGenomic(hgvs));
+    }
+    if let Some(hgvs) = reference_hgvs(input) {
+        return VariantInputKind::Exact(VariantIdFormat::HgvsReference(hgvs));
+    }
+    if let Some(caps) = clingen_allele_re().captures(input) {
+        return VariantInputKind::Exact(VariantIdFormat::ClinGenAllele(
+            caps[1].to_ascii_uppercase(),
+        ));
+    }
     if let Some(caps) = gene_protein_re().captures(input) {
         return VariantInputKind::Exact(VariantIdFormat::GeneProteinChange {
             gene: caps[1].to_string(),
@@ -214,6 +243,14 @@ Use `biomcp search variant \"{id}\"` to resolve it, or pass an exact rsID/HGVS/g
 Try:\n\
 1. biomcp search variant --hgvsp {change} --limit 10\n\
 2. biomcp discover {change}"
+        ),
+        _ if parse_hgvs_syntax(id).is_ok() => "\n\n\
+Protein HGVS on a reference sequence cannot be looked up directly.\n\
+Use the gene + protein form instead, e.g. `biomcp get variant \"BRAF V600E\"`."
+            .to_string(),
+        _ if id.contains(':') && !id.contains(char::is_whitespace) => format!(
+            "\n\nThis looks like an HGVS description that did not pass the syntax check.\n\
+Run `biomcp validate hgvs \"{id}\"` for the specific problem and suggested corrections."
         ),
         _ if looks_like_search_phrase => format!(
             "\n\nThis looks like a search phrase or alteration description, not an exact variant ID.\n\
@@ -226,8 +263,10 @@ Use `biomcp search variant \"{id}\"` to search, or pass an exact rsID/HGVS/gene+
         "Unrecognized variant format: '{id}'{search_hint}\n\n\
 Supported formats:\n\
 - rsID: rs113488022\n\
-- HGVS genomic: chr7:g.140453136A>T\n\
-- Gene + protein: BRAF V600E, BRAF p.Val600Glu"
+- HGVS genomic: chr7:g.140453136A>T (also 7-140453136-A-T)\n\
+- HGVS reference: NM_004333.4:c.1799T>A, NC_000007.14:g.140753336A>T\n\
+- Gene + protein: BRAF V600E, BRAF p.Val600Glu\n\
+- ClinGen allele ID: CA7200"
     )))
 }
 
@@ -245,6 +284,41 @@ pub(crate) fn gnomad_variant_slug(id: &str) -> Option<String> {
     ))
 }
 
+/// Rewrites a MyVariant.info hg19 ID (`chr17:g.7579472G>C`) onto its GRCh37
+/// RefSeq chromosome accession for ClinGen Allele Registry lookups.
+pub(crate) fn grch37_refseq_hgvs(myvariant_id: &str) -> Option<String> {
+    let (chrom, change) = myvariant_id.trim().split_once(":g.")?;
+    let accession = match chrom.strip_prefix("chr")? {
+        "1" => "NC_000001.10",
+        "2" => "NC_000002.11",
+        "3" => "NC_000003.11",
+        "4" => "NC_000004.11",
+        "5" => "NC_000005.9",
+        "6" => "NC_000006.11",
+        "7" => "NC_000007.13",
+        "8" => "NC_000008.10",
+        "9" => "NC_000009.11",
+        "10" => "NC_000010.10",
+        "11" => "NC_000011.9",
+        "12" => "NC_000012.11",
+        "13" => "NC_000013.10",
+        "14" => "NC_000014.8",
+        "15" => "NC_000015.9",
+        "16" => "NC_000016.9",
+        "17" => "NC_000017.10",
+        "18" => "NC_000018.9",
+        "19" => "NC_000019.9",
+        "20" => "NC_000020.10",
+        "21" => "NC_000021.8",
+        "22" => "NC_000022.10",
+        "X" => "NC_000023.10",
+        "Y" => "NC_000024.9",
+        "M" | "MT" => "NC_012920.1",
+        _ => return None,
+    };
+    (!change.is_empty()).then(|| format!("{accession}:g.{change}"))
+}
+
 fn amino_acid_one_letter(token: &str) -> Option<char> {
     match token.trim().to_ascii_uppercase().as_str() {
         "A" | "ALA" => Some('A'),
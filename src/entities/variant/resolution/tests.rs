@@ -135,3 +135,76 @@ fn parse_variant_id_suggests_search_for_complex_alteration_text() {
     assert!(message.contains("search phrase or alteration description"));
     assert!(message.contains("biomcp search variant \"EGFR Exon 19 Deletion\""));
 }
+
+#[test]
+fn parse_variant_id_accepts_clingen_allele_ids() {
+    match parse_variant_id("ca7200").unwrap() {
+        VariantIdFormat::ClinGenAllele(caid) => assert_eq!(caid, "CA7200"),
+        other => panic!("expected ClinGen allele ID, got {other:?}"),
+    }
+}
+
+#[test]
+fn parse_variant_id_normalizes_genomic_shorthand() {
+    for input in ["7-140453136-A-T", "chr7:140453136a>t", "7:g.140453136A>T"] {
+        assert_eq!(
+            parse_variant_id(input).unwrap(),
+            VariantIdFormat::HgvsGenomic("chr7:g.140453136A>T".into()),
+            "{input}"
+        );
+    }
+    assert_eq!(
+        gnomad_variant_slug("chr7-140453136-A-T").as_deref(),
+        Some("7-140453136-A-T")
+    );
+}
+
+#[test]
+fn parse_variant_id_accepts_reference_sequence_hgvs() {
+    for (input, expected) in [
+        ("NM_004333.4:c.1799T>A", "NM_004333.4:c.1799T>A"),
+        ("NM_004333.4(BRAF):c.1799T>A", "NM_004333.4:c.1799T>A"),
+        ("NM_004333.4:c.1799t>a", "NM_004333.4:c.1799T>A"),
+        ("NC_000007.14:g.140753336A>T", "NC_000007.14:g.140753336A>T"),
+        ("NM_007294.4:c.68_69del", "NM_007294.4:c.68_69del"),
+    ] {
+        assert_eq!(
+            parse_variant_id(input).unwrap(),
+            VariantIdFormat::HgvsReference(expected.into()),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn parse_variant_id_points_unusable_hgvs_at_validate_or_gene_protein_form() {
+    let message = parse_variant_id("NM_004333:c.1799T>A")
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("biomcp validate hgvs \"NM_004333:c.1799T>A\""));
+    assert!(message.contains("HGVS reference: NM_004333.4:c.1799T>A"));
+
+    let message = parse_variant_id("NP_004324.2:p.Val600Glu")
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("Protein HGVS on a reference sequence"));
+    assert!(message.contains("biomcp get variant \"BRAF V600E\""));
+}
+
+#[test]
+fn grch37_refseq_hgvs_maps_myvariant_ids_to_chromosome_accessions() {
+    assert_eq!(
+        grch37_refseq_hgvs("chr17:g.7579472G>C").as_deref(),
+        Some("NC_000017.10:g.7579472G>C")
+    );
+    assert_eq!(
+        grch37_refseq_hgvs("chrX:g.153296777_153296778del").as_deref(),
+        Some("NC_000023.10:g.153296777_153296778del")
+    );
+    assert_eq!(
+        grch37_refseq_hgvs("chrMT:g.3243A>G").as_deref(),
+        Some("NC_012920.1:g.3243A>G")
+    );
+    assert_eq!(grch37_refseq_hgvs("chrUn:g.1A>G"), None);
+    assert_eq!(grch37_refseq_hgvs("rs113488022"), None);
+}
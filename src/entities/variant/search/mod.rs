@@ -5,43 +5,10 @@ use crate::error::BioMcpError;
 use crate::sources::myvariant::{MyVariantClient, VariantSearchParams};
 use crate::transform;
 
-use super::{VariantSearchFilters, VariantSearchResult};
+use super::ranking::{resolve_rank_profile, search_result_quality_score};
+use super::{VariantSearchFilters, VariantSearchGroup, VariantSearchGroupBy, VariantSearchResult};
 
-fn search_result_quality_score(row: &VariantSearchResult) -> i32 {
-    let mut score = 0;
-    if row
-        .significance
-        .as_deref()
-        .map(str::trim)
-        .is_some_and(|v| !v.is_empty())
-    {
-        score += 4;
-    }
-    if row.gnomad_af.is_some() {
-        score += 4;
-    }
-    if row.clinvar_stars.is_some() {
-        score += 3;
-    }
-    if row.revel.is_some() {
-        score += 2;
-    }
-    if row.gerp.is_some() {
-        score += 2;
-    }
-    if row
-        .hgvs_p
-        .as_deref()
-        .map(str::trim)
-        .is_some_and(|v| !v.is_empty())
-    {
-        score += 2;
-    }
-    if !row.gene.trim().is_empty() {
-        score += 1;
-    }
-    score
-}
+const UNGROUPED_KEY: &str = "unknown";
 
 fn should_retry_exon_deletion_with_gene_only(filters: &VariantSearchFilters) -> bool {
     filters
@@ -233,6 +200,14 @@ pub fn search_query_summary(filters: &VariantSearchFilters) -> String {
     {
         parts.push(format!("therapy={v}"));
     }
+    if let Some(v) = filters
+        .rank_profile
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        parts.push(format!("rank_profile={v}"));
+    }
 
     parts.join(", ")
 }
@@ -256,6 +231,10 @@ pub async fn search_page(
             "--limit must be between 1 and {MAX_SEARCH_LIMIT}"
         )));
     }
+    if let Some(store) = crate::utils::snapshot::offline_store() {
+        return super::offline::search_page(&store, filters, limit, offset);
+    }
+    let rank_profile = resolve_rank_profile(filters.rank_profile.as_deref())?;
 
     let has_precision_filter = filters
         .hgvsp
@@ -380,13 +359,53 @@ pub async fn search_page(
         resp.total
     };
     out.sort_by(|a, b| {
-        search_result_quality_score(b)
-            .cmp(&search_result_quality_score(a))
+        search_result_quality_score(b, &rank_profile.weights)
+            .total_cmp(&search_result_quality_score(a, &rank_profile.weights))
             .then_with(|| a.id.cmp(&b.id))
     });
     out.truncate(limit);
     Ok(SearchPage::offset(out, total))
 }
 
+fn group_key(row: &VariantSearchResult, group_by: VariantSearchGroupBy) -> String {
+    let value = match group_by {
+        VariantSearchGroupBy::Gene => Some(row.gene.as_str()),
+        VariantSearchGroupBy::Consequence => row.consequence.as_deref(),
+        VariantSearchGroupBy::Significance => row.significance.as_deref(),
+    };
+    value
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .unwrap_or(UNGROUPED_KEY)
+        .to_string()
+}
+
+/// Nests an already-ranked result page under group keys, preserving rank order
+/// within each group and ordering groups by first appearance.
+pub fn group_search_results(
+    results: &[VariantSearchResult],
+    group_by: VariantSearchGroupBy,
+) -> Vec<VariantSearchGroup> {
+    let mut groups: Vec<VariantSearchGroup> = Vec::new();
+    for row in results {
+        let key = group_key(row, group_by);
+        match groups
+            .iter_mut()
+            .find(|group| group.key.eq_ignore_ascii_case(&key))
+        {
+            Some(group) => {
+                group.count += 1;
+                group.results.push(row.clone());
+            }
+            None => groups.push(VariantSearchGroup {
+                key,
+                count: 1,
+                results: vec![row.clone()],
+            }),
+        }
+    }
+    groups
+}
+
 #[cfg(test)]
 mod tests;
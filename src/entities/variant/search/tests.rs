@@ -1,6 +1,8 @@
 //! Sidecar tests for variant MyVariant search helpers.
 
-use super::super::{VariantProteinAlias, VariantSearchFilters, VariantSearchResult};
+use super::super::{
+    VariantProteinAlias, VariantSearchFilters, VariantSearchGroupBy, VariantSearchResult,
+};
 use super::*;
 
 #[test]
@@ -88,6 +90,8 @@ fn quality_score_prioritizes_significance_and_frequency() {
         gnomad_af: Some(0.001),
         revel: None,
         gerp: None,
+        cadd_score: None,
+        consequence: None,
     };
     let sparse = VariantSearchResult {
         id: "chr1:g.2A>T".into(),
@@ -99,7 +103,56 @@ fn quality_score_prioritizes_significance_and_frequency() {
         gnomad_af: None,
         revel: None,
         gerp: None,
+        cadd_score: None,
+        consequence: None,
     };
 
-    assert!(search_result_quality_score(&rich) > search_result_quality_score(&sparse));
+    let weights = crate::entities::variant::VariantRankWeights::DEFAULT;
+    assert!(
+        search_result_quality_score(&rich, &weights)
+            > search_result_quality_score(&sparse, &weights)
+    );
+}
+
+fn grouping_row(id: &str, gene: &str, consequence: Option<&str>) -> VariantSearchResult {
+    VariantSearchResult {
+        id: id.into(),
+        gene: gene.into(),
+        hgvs_p: None,
+        legacy_name: None,
+        significance: None,
+        clinvar_stars: None,
+        gnomad_af: None,
+        revel: None,
+        gerp: None,
+        cadd_score: None,
+        consequence: consequence.map(str::to_string),
+    }
+}
+
+#[test]
+fn group_search_results_preserves_rank_order_and_subtotals() {
+    let rows = vec![
+        grouping_row("v1", "BRAF", Some("missense_variant")),
+        grouping_row("v2", "BRAF", Some("stop_gained")),
+        grouping_row("v3", "BRAF", Some("missense_variant")),
+        grouping_row("v4", "BRAF", None),
+    ];
+
+    let groups = group_search_results(&rows, VariantSearchGroupBy::Consequence);
+    let keys = groups.iter().map(|g| g.key.as_str()).collect::<Vec<_>>();
+    assert_eq!(keys, vec!["missense_variant", "stop_gained", "unknown"]);
+    assert_eq!(groups[0].count, 2);
+    assert_eq!(groups[0].results[0].id, "v1");
+    assert_eq!(groups[0].results[1].id, "v3");
+    assert_eq!(groups[2].count, 1);
+}
+
+#[test]
+fn variant_search_group_by_rejects_unknown_flag() {
+    assert_eq!(
+        VariantSearchGroupBy::from_flag("Significance").expect("valid flag"),
+        VariantSearchGroupBy::Significance
+    );
+    assert!(VariantSearchGroupBy::from_flag("tissue").is_err());
 }
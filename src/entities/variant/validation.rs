@@ -0,0 +1,451 @@
+//! HGVS nomenclature validation and normalization reports.
+//!
+//! Syntax is checked locally so malformed descriptions fail without a network
+//! round trip; well-formed nucleotide descriptions are then sent to
+//! VariantValidator, which checks the stated reference allele against the
+//! transcript/genome, applies HGVS 3' shifting, and projects the variant onto
+//! GRCh38/GRCh37 and the predicted protein consequence.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+use crate::sources::variantvalidator::{
+    VariantValidatorClient, VariantValidatorRecord, VariantValidatorResponse,
+};
+use crate::utils::text::non_empty;
+
+use super::GenomeAssembly;
+use super::normalization::{myvariant_genomic_id, suggest_hgvs_corrections};
+
+const SOURCE: &str = "VariantValidator";
+const PROTEIN_NOT_CHECKED_WARNING: &str = "Protein descriptions cannot be checked against a reference allele; validate the transcript c. description instead.";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariantValidation {
+    pub input: String,
+    /// `valid`, `normalized`, or `invalid`.
+    pub status: String,
+    pub syntax_valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub syntax_error: Option<String>,
+    /// Corrected forms for descriptions that failed the syntax check.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coordinate_type: Option<String>,
+    /// `match`, `mismatch`, or `not_checked`.
+    pub reference_check: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalized_hgvs: Option<String>,
+    pub normalization_changed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gene: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcript_hgvs: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genomic_hgvs_grch38: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genomic_hgvs_grch37: Option<String>,
+    /// Left-aligned `chr-pos-ref-alt` on GRCh38.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcf_grch38: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protein_hgvs: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protein_hgvs_short: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct HgvsDescription {
+    pub(super) accession: String,
+    pub(super) coordinate_type: char,
+    /// Description without any `(GENE)` qualifier, as VariantValidator echoes it.
+    pub(super) canonical: String,
+}
+
+fn hgvs_shape_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^([A-Za-z]{2,4}_?[0-9]+(?:t[0-9]+)?(?:\.[0-9]+)?)(\([A-Za-z0-9._-]+\))?:([A-Za-z])\.(.+)$")
+            .expect("valid regex")
+    })
+}
+
+fn coding_edit_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        let pos = r"[-*]?[0-9]+(?:[+-][0-9]+)?";
+        Regex::new(&format!(
+            r"^({pos})(?:_({pos}))?(?:([ACGT])>([ACGT])|del[ACGTN]*|dup[ACGTN]*|ins[ACGTN]+|delins[ACGTN]+|inv|[ACGT]*\[[0-9]+\]|=)$"
+        ))
+        .expect("valid regex")
+    })
+}
+
+fn genomic_edit_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"^([0-9]+)(?:_([0-9]+))?(?:([ACGT])>([ACGT])|del[ACGTN]*|dup[ACGTN]*|ins[ACGTN]+|delins[ACGTN]+|inv|[ACGT]*\[[0-9]+\]|=)$",
+        )
+        .expect("valid regex")
+    })
+}
+
+fn rna_edit_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        let pos = r"[-*]?[0-9]+(?:[+-][0-9]+)?";
+        Regex::new(&format!(
+            r"^({pos})(?:_({pos}))?(?:([acgu])>([acgu])|del[acgun]*|dup[acgun]*|ins[acgun]+|delins[acgun]+|inv|=)$"
+        ))
+        .expect("valid regex")
+    })
+}
+
+fn protein_edit_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        let aa = r"(?:[A-Z][a-z]{2}|[A-Z*])";
+        Regex::new(&format!(
+            r"^(?:\(({aa}[0-9]+(?:_{aa}[0-9]+)?(?:{aa}|del|dup|ins.+|delins.+|fs.*|ext.*|=|\?)?)\)|{aa}[0-9]+(?:_{aa}[0-9]+)?(?:{aa}|del|dup|ins.+|delins.+|fs.*|ext.*|=|\?)?|=|\?|0|\(=\)|0\?)$"
+        ))
+        .expect("valid regex")
+    })
+}
+
+fn is_transcript_accession(accession: &str) -> bool {
+    ["NM_", "XM_", "NR_", "XR_", "ENST"]
+        .iter()
+        .any(|prefix| accession.starts_with(prefix))
+        || (accession.starts_with("LRG_") && accession.contains('t'))
+}
+
+fn is_protein_accession(accession: &str) -> bool {
+    ["NP_", "XP_", "ENSP"]
+        .iter()
+        .any(|prefix| accession.starts_with(prefix))
+        || (accession.starts_with("LRG_") && accession.contains('p'))
+}
+
+fn positions_ascend(start: &str, end: Option<&str>) -> bool {
+    let Some(end) = end else {
+        return true;
+    };
+    match (start.parse::<u64>(), end.parse::<u64>()) {
+        (Ok(start), Ok(end)) => start < end,
+        // Intronic and UTR offsets are ordered by the service, not locally.
+        _ => true,
+    }
+}
+
+/// Checks HGVS shape, coordinate type, and edit grammar without any network call.
+pub(super) fn parse_hgvs_syntax(input: &str) -> Result<HgvsDescription, String> {
+    let input = input.trim();
+    if !input.contains(':') {
+        return Err(
+            "missing ':' between the reference sequence and the variant description (expected e.g. NM_000546.6:c.215C>G)"
+                .into(),
+        );
+    }
+    let Some(caps) = hgvs_shape_re().captures(input) else {
+        return Err(
+            "expected <reference sequence>:<type>.<description> with a RefSeq, Ensembl, or LRG reference (e.g. NM_000546.6:c.215C>G)"
+                .into(),
+        );
+    };
+    let accession = caps[1].to_string();
+    let coordinate_type = caps[3].chars().next().unwrap_or_default();
+    let edit = &caps[4];
+
+    if !"cgmnrp".contains(coordinate_type) {
+        return Err(format!(
+            "unknown coordinate type '{coordinate_type}.'; use c., g., m., n., r., or p."
+        ));
+    }
+    if coordinate_type == 'p' && !is_protein_accession(&accession) {
+        return Err(format!(
+            "p. descriptions need a protein reference sequence (NP_/ENSP), not {accession}"
+        ));
+    }
+    if coordinate_type != 'p' && is_protein_accession(&accession) {
+        return Err(format!(
+            "{accession} is a protein reference sequence; use p. instead of {coordinate_type}."
+        ));
+    }
+    if matches!(coordinate_type, 'g' | 'm') && is_transcript_accession(&accession) {
+        return Err(format!(
+            "{accession} is a transcript reference sequence; use c., n., or r. instead of {coordinate_type}."
+        ));
+    }
+    if version_missing(&accession) {
+        return Err(format!(
+            "reference sequence {accession} needs a version suffix (e.g. {accession}.1)"
+        ));
+    }
+
+    let edit_caps = match coordinate_type {
+        'c' | 'n' => coding_edit_re().captures(edit),
+        'g' | 'm' => genomic_edit_re().captures(edit),
+        'r' => rna_edit_re().captures(edit),
+        _ => {
+            if protein_edit_re().is_match(edit) {
+                None
+            } else {
+                return Err(format!("'p.{edit}' is not a valid protein change"));
+            }
+        }
+    };
+    if coordinate_type != 'p' {
+        let Some(edit_caps) = edit_caps else {
+            return Err(format!(
+                "'{coordinate_type}.{edit}' is not a valid {coordinate_type}. position and edit (expected e.g. 215C>G, 76_78del, 1010dup)"
+            ));
+        };
+        let start = edit_caps.get(1).map_or("", |m| m.as_str());
+        let end = edit_caps.get(2).map(|m| m.as_str());
+        if edit_caps.get(3).is_some() && end.is_some() {
+            return Err(format!(
+                "substitution '{coordinate_type}.{edit}' must name a single position"
+            ));
+        }
+        let substitution = edit_caps
+            .get(3)
+            .zip(edit_caps.get(4))
+            .map(|(reference, alternate)| (reference.as_str(), alternate.as_str()));
+        if substitution.is_some_and(|(reference, alternate)| reference == alternate) {
+            return Err(format!(
+                "substitution '{coordinate_type}.{edit}' has identical reference and alternate bases; use '=' for no change"
+            ));
+        }
+        if !positions_ascend(start, end) {
+            return Err(format!(
+                "range '{coordinate_type}.{edit}' must run from the lower to the higher position"
+            ));
+        }
+    }
+
+    // `NM_000546.6(TP53):c.` carries a gene label, while `NC_...(NM_...):c.`
+    // names the transcript the coordinates refer to and must be kept.
+    let gene_qualifier = caps
+        .get(2)
+        .map(|m| m.as_str())
+        .filter(|qualifier| !qualifier.contains('_') && !qualifier.starts_with("(ENST"));
+    Ok(HgvsDescription {
+        canonical: match gene_qualifier {
+            Some(qualifier) => input.replacen(qualifier, "", 1),
+            None => input.to_string(),
+        },
+        accession,
+        coordinate_type,
+    })
+}
+
+fn version_missing(accession: &str) -> bool {
+    let versioned_namespace = [
+        "NM_", "NR_", "NC_", "NG_", "NP_", "XM_", "XR_", "XP_", "NT_", "NW_",
+    ]
+    .iter()
+    .any(|prefix| accession.starts_with(prefix));
+    versioned_namespace && !accession.contains('.')
+}
+
+fn is_reference_mismatch(warning: &str) -> bool {
+    let lower = warning.to_ascii_lowercase();
+    lower.contains("does not agree with reference")
+        || lower.contains("does not match the reference")
+}
+
+fn push_unique(out: &mut Vec<String>, value: &str) {
+    let value = value.trim();
+    if !value.is_empty() && !out.iter().any(|existing| existing == value) {
+        out.push(value.to_string());
+    }
+}
+
+fn has_projection(record: &VariantValidatorRecord) -> bool {
+    non_empty(record.hgvs_transcript_variant.as_deref()).is_some()
+        || record
+            .primary_assembly_loci
+            .values()
+            .any(|locus| non_empty(locus.hgvs_genomic_description.as_deref()).is_some())
+}
+
+fn vcf_label(record: &VariantValidatorRecord, assembly: &str) -> Option<String> {
+    let vcf = record.locus(assembly)?.vcf.as_ref()?;
+    let chr = non_empty(vcf.chr.as_deref())?;
+    let chr = if chr.starts_with("chr") {
+        chr
+    } else {
+        format!("chr{chr}")
+    };
+    Some(format!(
+        "{chr}-{}-{}-{}",
+        vcf.position()?,
+        non_empty(vcf.reference.as_deref())?,
+        non_empty(vcf.alt.as_deref())?
+    ))
+}
+
+fn empty_report(input: &str) -> VariantValidation {
+    VariantValidation {
+        input: input.to_string(),
+        status: "invalid".into(),
+        syntax_valid: false,
+        syntax_error: None,
+        suggestions: Vec::new(),
+        coordinate_type: None,
+        reference_check: "not_checked".into(),
+        normalized_hgvs: None,
+        normalization_changed: false,
+        gene: None,
+        transcript_hgvs: None,
+        genomic_hgvs_grch38: None,
+        genomic_hgvs_grch37: None,
+        vcf_grch38: None,
+        protein_hgvs: None,
+        protein_hgvs_short: None,
+        warnings: Vec::new(),
+        source: None,
+    }
+}
+
+/// Folds a VariantValidator response into the validation report.
+pub(super) fn build_report(
+    input: &str,
+    parsed: &HgvsDescription,
+    response: &VariantValidatorResponse,
+) -> VariantValidation {
+    let mut warnings = Vec::new();
+    for record in &response.records {
+        for warning in &record.validation_warnings {
+            push_unique(&mut warnings, warning);
+        }
+    }
+    let mismatch = warnings
+        .iter()
+        .any(|warning| is_reference_mismatch(warning));
+    let record = response
+        .records
+        .iter()
+        .find(|record| has_projection(record));
+
+    let mut report = empty_report(input);
+    report.syntax_valid = true;
+    report.coordinate_type = Some(parsed.coordinate_type.to_string());
+    report.source = Some(SOURCE.to_string());
+
+    if let Some(record) = record.filter(|_| !mismatch) {
+        report.gene = non_empty(record.gene_symbol.as_deref());
+        report.transcript_hgvs = non_empty(record.hgvs_transcript_variant.as_deref());
+        report.genomic_hgvs_grch38 = record
+            .locus("grch38")
+            .and_then(|locus| non_empty(locus.hgvs_genomic_description.as_deref()));
+        report.genomic_hgvs_grch37 = record
+            .locus("grch37")
+            .and_then(|locus| non_empty(locus.hgvs_genomic_description.as_deref()));
+        report.vcf_grch38 = vcf_label(record, "grch38");
+        if let Some(protein) = record.hgvs_predicted_protein_consequence.as_ref() {
+            report.protein_hgvs = non_empty(protein.tlr.as_deref());
+            report.protein_hgvs_short = non_empty(protein.slr.as_deref());
+        }
+
+        // Normalize within the input's own reference: transcript inputs keep
+        // the transcript description, genomic inputs keep the matching build.
+        report.normalized_hgvs = if matches!(parsed.coordinate_type, 'g' | 'm') {
+            [&report.genomic_hgvs_grch38, &report.genomic_hgvs_grch37]
+                .into_iter()
+                .flatten()
+                .find(|value| value.starts_with(&format!("{}:", parsed.accession)))
+                .or(report.genomic_hgvs_grch38.as_ref())
+                .cloned()
+        } else {
+            report.transcript_hgvs.clone()
+        };
+        report.reference_check = "match".into();
+        report.normalization_changed = report
+            .normalized_hgvs
+            .as_deref()
+            .is_some_and(|normalized| normalized != parsed.canonical);
+        report.status = if report.normalization_changed {
+            "normalized".into()
+        } else {
+            "valid".into()
+        };
+    } else if mismatch {
+        report.reference_check = "mismatch".into();
+    }
+    report.warnings = warnings;
+    report
+}
+
+/// Validates and normalizes one HGVS variant description.
+pub async fn validate_variant(input: &str) -> Result<VariantValidation, BioMcpError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(BioMcpError::InvalidArgument(
+            "Variant description is required. Example: biomcp validate hgvs \"NM_004333.4:c.1799T>A\"".into(),
+        ));
+    }
+
+    let parsed = match parse_hgvs_syntax(input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            let mut report = empty_report(input);
+            report.syntax_error = Some(error);
+            report.suggestions = suggest_hgvs_corrections(input);
+            return Ok(report);
+        }
+    };
+    if parsed.coordinate_type == 'p' {
+        let mut report = empty_report(input);
+        report.status = "valid".into();
+        report.syntax_valid = true;
+        report.coordinate_type = Some("p".into());
+        report.warnings = vec![PROTEIN_NOT_CHECKED_WARNING.to_string()];
+        return Ok(report);
+    }
+
+    let response = VariantValidatorClient::new()?
+        .validate(&parsed.canonical)
+        .await?;
+    Ok(build_report(input, &parsed, &response))
+}
+
+/// Projects a reference-sequence HGVS description onto `assembly` and returns
+/// the matching MyVariant.info `chr:g.` ID.
+pub(super) async fn myvariant_id_for_hgvs(
+    hgvs: &str,
+    assembly: GenomeAssembly,
+) -> Result<String, BioMcpError> {
+    let report = validate_variant(hgvs).await?;
+    if report.reference_check == "mismatch" {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "{hgvs} does not match the reference sequence. Run `biomcp validate hgvs \"{hgvs}\"` for details."
+        )));
+    }
+    let genomic = match assembly {
+        GenomeAssembly::Grch37 => report.genomic_hgvs_grch37.as_deref(),
+        GenomeAssembly::Grch38 => report.genomic_hgvs_grch38.as_deref(),
+    };
+    genomic
+        .and_then(myvariant_genomic_id)
+        .ok_or_else(|| BioMcpError::NotFound {
+            entity: "variant".into(),
+            id: hgvs.to_string(),
+            suggestion: format!(
+                "VariantValidator returned no {} chromosome projection. Try: biomcp validate hgvs \"{hgvs}\"",
+                assembly.as_str()
+            ),
+        })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,352 @@
+//! Sidecar tests for HGVS syntax checks and validation report assembly.
+
+use super::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn env_lock_async() -> tokio::sync::MutexGuard<'static, ()> {
+    crate::test_support::env_lock().lock().await
+}
+
+struct EnvVarGuard {
+    name: &'static str,
+    previous: Option<String>,
+}
+
+impl Drop for EnvVarGuard {
+    fn drop(&mut self) {
+        // Safety: tests serialize environment mutation with `env_lock_async()`.
+        unsafe {
+            match &self.previous {
+                Some(value) => std::env::set_var(self.name, value),
+                None => std::env::remove_var(self.name),
+            }
+        }
+    }
+}
+
+fn set_env_var(name: &'static str, value: Option<&str>) -> EnvVarGuard {
+    let previous = std::env::var(name).ok();
+    // Safety: tests serialize environment mutation with `env_lock_async()`.
+    unsafe {
+        match value {
+            Some(value) => std::env::set_var(name, value),
+            None => std::env::remove_var(name),
+        }
+    }
+    EnvVarGuard { name, previous }
+}
+
+fn response(value: serde_json::Value) -> VariantValidatorResponse {
+    let records = value
+        .as_object()
+        .expect("object fixture")
+        .iter()
+        .filter(|(key, _)| key.as_str() != "flag")
+        .map(|(_, record)| serde_json::from_value(record.clone()).expect("record fixture"))
+        .collect();
+    VariantValidatorResponse {
+        flag: value["flag"].as_str().map(str::to_string),
+        records,
+    }
+}
+
+#[test]
+fn parse_hgvs_syntax_accepts_common_nucleotide_and_protein_forms() {
+    for input in [
+        "NM_000546.6:c.215C>G",
+        "NM_000546.6(TP53):c.215C>G",
+        "NM_007294.4:c.68_69del",
+        "NM_000492.4:c.1521_1523delCTT",
+        "NM_000546.6:c.-28+5G>A",
+        "NM_000546.6:c.*100dup",
+        "NC_000017.11:g.7676154G>C",
+        "NC_000017.11(NM_000546.6):c.215C>G",
+        "NC_012920.1:m.3243A>G",
+        "ENST00000269305.9:c.215C>G",
+        "NM_000546.6:r.215c>g",
+        "NP_000537.3:p.(Pro72Arg)",
+        "NP_000537.3:p.R175H",
+    ] {
+        assert!(
+            parse_hgvs_syntax(input).is_ok(),
+            "{input} should be syntactically valid"
+        );
+    }
+}
+
+#[test]
+fn parse_hgvs_syntax_strips_gene_labels_but_keeps_transcript_qualifiers() {
+    let parsed = parse_hgvs_syntax("NM_000546.6(TP53):c.215C>G").expect("valid");
+    assert_eq!(parsed.canonical, "NM_000546.6:c.215C>G");
+    assert_eq!(parsed.accession, "NM_000546.6");
+    assert_eq!(parsed.coordinate_type, 'c');
+
+    let parsed = parse_hgvs_syntax("NC_000017.11(NM_000546.6):c.215C>G").expect("valid");
+    assert_eq!(parsed.canonical, "NC_000017.11(NM_000546.6):c.215C>G");
+}
+
+#[test]
+fn parse_hgvs_syntax_explains_malformed_descriptions() {
+    let cases = [
+        ("BRAF V600E", "missing ':'"),
+        ("NM_000546.6:x.215C>G", "unknown coordinate type 'x.'"),
+        ("NM_000546.6:g.215C>G", "transcript reference sequence"),
+        ("NM_000546.6:p.Pro72Arg", "protein reference sequence"),
+        ("NM_000546:c.215C>G", "needs a version suffix"),
+        ("NM_000546.6:c.215C>", "not a valid c. position and edit"),
+        ("NM_000546.6:c.215_216C>G", "single position"),
+        ("NM_000546.6:c.215C>C", "identical reference and alternate"),
+        ("NC_000017.11:g.200_100del", "lower to the higher position"),
+        ("NP_000537.3:p.72", "not a valid protein change"),
+    ];
+    for (input, expected) in cases {
+        let err = parse_hgvs_syntax(input).expect_err(input);
+        assert!(err.contains(expected), "{input}: {err}");
+    }
+}
+
+#[test]
+fn build_report_flags_three_prime_shift_as_normalized() {
+    let parsed = parse_hgvs_syntax("NM_000546.6:c.216del").expect("valid");
+    let report = build_report(
+        "NM_000546.6:c.216del",
+        &parsed,
+        &response(serde_json::json!({
+            "flag": "gene_variant",
+            "NM_000546.6:c.217del": {
+                "submitted_variant": "NM_000546.6:c.216del",
+                "hgvs_transcript_variant": "NM_000546.6:c.217del",
+                "gene_symbol": "TP53",
+                "hgvs_predicted_protein_consequence": {
+                    "tlr": "NP_000537.3:p.(Pro73LeufsTer50)",
+                    "slr": "NP_000537.3:p.(P73Lfs*50)"
+                },
+                "primary_assembly_loci": {
+                    "grch38": {
+                        "hgvs_genomic_description": "NC_000017.11:g.7676152del",
+                        "vcf": {"chr": "17", "pos": "7676151", "ref": "GG", "alt": "G"}
+                    },
+                    "grch37": {
+                        "hgvs_genomic_description": "NC_000017.10:g.7579470del"
+                    }
+                },
+                "validation_warnings": [
+                    "NM_000546.6:c.216del updated to NM_000546.6:c.217del"
+                ]
+            }
+        })),
+    );
+
+    assert_eq!(report.status, "normalized");
+    assert!(report.normalization_changed);
+    assert_eq!(report.reference_check, "match");
+    assert_eq!(
+        report.normalized_hgvs.as_deref(),
+        Some("NM_000546.6:c.217del")
+    );
+    assert_eq!(report.gene.as_deref(), Some("TP53"));
+    assert_eq!(report.vcf_grch38.as_deref(), Some("chr17-7676151-GG-G"));
+    assert_eq!(
+        report.genomic_hgvs_grch37.as_deref(),
+        Some("NC_000017.10:g.7579470del")
+    );
+    assert_eq!(
+        report.protein_hgvs_short.as_deref(),
+        Some("NP_000537.3:p.(P73Lfs*50)")
+    );
+    assert_eq!(report.warnings.len(), 1);
+}
+
+#[test]
+fn build_report_keeps_genomic_inputs_on_their_own_build() {
+    let parsed = parse_hgvs_syntax("NC_000017.10:g.7579472G>C").expect("valid");
+    let report = build_report(
+        "NC_000017.10:g.7579472G>C",
+        &parsed,
+        &response(serde_json::json!({
+            "flag": "gene_variant",
+            "NM_000546.6:c.215C>G": {
+                "submitted_variant": "NC_000017.10:g.7579472G>C",
+                "hgvs_transcript_variant": "NM_000546.6:c.215C>G",
+                "primary_assembly_loci": {
+                    "grch38": {"hgvs_genomic_description": "NC_000017.11:g.7676154G>C"},
+                    "grch37": {"hgvs_genomic_description": "NC_000017.10:g.7579472G>C"}
+                },
+                "validation_warnings": []
+            }
+        })),
+    );
+
+    assert_eq!(report.status, "valid");
+    assert!(!report.normalization_changed);
+    assert_eq!(
+        report.normalized_hgvs.as_deref(),
+        Some("NC_000017.10:g.7579472G>C")
+    );
+    assert_eq!(
+        report.transcript_hgvs.as_deref(),
+        Some("NM_000546.6:c.215C>G")
+    );
+}
+
+#[test]
+fn build_report_marks_reference_mismatch_invalid() {
+    let parsed = parse_hgvs_syntax("NM_000546.6:c.215A>G").expect("valid");
+    let report = build_report(
+        "NM_000546.6:c.215A>G",
+        &parsed,
+        &response(serde_json::json!({
+            "flag": "warning",
+            "validation_warning_1": {
+                "submitted_variant": "NM_000546.6:c.215A>G",
+                "hgvs_transcript_variant": "",
+                "primary_assembly_loci": {},
+                "validation_warnings": [
+                    "NM_000546.6:c.215A>G: Variant reference (A) does not agree with reference sequence (C)"
+                ]
+            }
+        })),
+    );
+
+    assert_eq!(report.status, "invalid");
+    assert!(report.syntax_valid);
+    assert_eq!(report.reference_check, "mismatch");
+    assert!(report.normalized_hgvs.is_none());
+    assert!(report.warnings[0].contains("does not agree with reference sequence (C)"));
+}
+
+#[tokio::test]
+async fn validate_variant_reports_syntax_errors_without_calling_the_service() {
+    let report = validate_variant("TP53 R72P").await.expect("report");
+    assert_eq!(report.status, "invalid");
+    assert!(!report.syntax_valid);
+    assert_eq!(report.reference_check, "not_checked");
+    assert!(report.source.is_none());
+    assert!(report.suggestions.is_empty());
+
+    let report = validate_variant("NM_004333.4:c.1799t>a")
+        .await
+        .expect("report");
+    assert_eq!(report.status, "invalid");
+    assert_eq!(
+        report.suggestions,
+        vec!["NM_004333.4:c.1799T>A".to_string()]
+    );
+
+    let report = validate_variant("NP_000537.3:p.(Pro72Arg)")
+        .await
+        .expect("report");
+    assert_eq!(report.status, "valid");
+    assert_eq!(report.reference_check, "not_checked");
+    assert_eq!(
+        report.warnings,
+        vec![PROTEIN_NOT_CHECKED_WARNING.to_string()]
+    );
+}
+
+#[tokio::test]
+async fn validate_variant_queries_variantvalidator_with_canonical_description() {
+    let _lock = env_lock_async().await;
+    let server = MockServer::start().await;
+    let _base = set_env_var("BIOMCP_VARIANTVALIDATOR_BASE", Some(&server.uri()));
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/VariantValidator/variantvalidator/GRCh38/NM_000546.6:c.215C%3EG/mane_select",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "flag": "gene_variant",
+            "metadata": {},
+            "NM_000546.6:c.215C>G": {
+                "submitted_variant": "NM_000546.6:c.215C>G",
+                "hgvs_transcript_variant": "NM_000546.6:c.215C>G",
+                "gene_symbol": "TP53",
+                "primary_assembly_loci": {
+                    "grch38": {
+                        "hgvs_genomic_description": "NC_000017.11:g.7676154G>C",
+                        "vcf": {"chr": "17", "pos": "7676154", "ref": "G", "alt": "C"}
+                    }
+                },
+                "validation_warnings": []
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let report = validate_variant("NM_000546.6(TP53):c.215C>G")
+        .await
+        .expect("report");
+    assert_eq!(report.status, "valid");
+    assert_eq!(report.reference_check, "match");
+    assert_eq!(report.source.as_deref(), Some("VariantValidator"));
+    assert_eq!(report.vcf_grch38.as_deref(), Some("chr17-7676154-G-C"));
+}
+
+#[tokio::test]
+async fn myvariant_id_for_hgvs_uses_the_requested_build_projection() {
+    let _lock = env_lock_async().await;
+    let server = MockServer::start().await;
+    let _base = set_env_var("BIOMCP_VARIANTVALIDATOR_BASE", Some(&server.uri()));
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/VariantValidator/variantvalidator/GRCh38/NM_004333.4:c.1799T%3EA/mane_select",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "flag": "gene_variant",
+            "NM_004333.4:c.1799T>A": {
+                "hgvs_transcript_variant": "NM_004333.4:c.1799T>A",
+                "gene_symbol": "BRAF",
+                "primary_assembly_loci": {
+                    "grch37": {"hgvs_genomic_description": "NC_000007.13:g.140453136A>T"},
+                    "grch38": {"hgvs_genomic_description": "NC_000007.14:g.140753336A>T"}
+                },
+                "validation_warnings": []
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    assert_eq!(
+        myvariant_id_for_hgvs("NM_004333.4:c.1799T>A", GenomeAssembly::Grch37)
+            .await
+            .expect("grch37 id"),
+        "chr7:g.140453136A>T"
+    );
+    assert_eq!(
+        myvariant_id_for_hgvs("NM_004333.4:c.1799T>A", GenomeAssembly::Grch38)
+            .await
+            .expect("grch38 id"),
+        "chr7:g.140753336A>T"
+    );
+}
+
+#[tokio::test]
+async fn myvariant_id_for_hgvs_rejects_reference_mismatches() {
+    let _lock = env_lock_async().await;
+    let server = MockServer::start().await;
+    let _base = set_env_var("BIOMCP_VARIANTVALIDATOR_BASE", Some(&server.uri()));
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/VariantValidator/variantvalidator/GRCh38/NM_004333.4:c.1799G%3EA/mane_select",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "flag": "warning",
+            "validation_warning_1": {
+                "validation_warnings": [
+                    "NM_004333.4:c.1799G>A: Variant reference (G) does not agree with reference sequence (T)"
+                ]
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let err = myvariant_id_for_hgvs("NM_004333.4:c.1799G>A", GenomeAssembly::Grch37)
+        .await
+        .expect_err("mismatch should fail");
+    assert!(matches!(err, BioMcpError::InvalidArgument(_)));
+    assert!(err.to_string().contains("biomcp validate hgvs"));
+}
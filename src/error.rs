@@ -56,6 +56,28 @@ pub enum BioMcpError {
     Io(#[from] std::io::Error),
 }
 
+impl BioMcpError {
+    /// Stable machine-readable code for JSON error surfaces.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::HttpClientInit(_) => "http_client_init",
+            Self::Http(err) if err.is_timeout() => "timeout",
+            Self::HttpMiddleware(reqwest_middleware::Error::Reqwest(err)) if err.is_timeout() => {
+                "timeout"
+            }
+            Self::Http(_) | Self::HttpMiddleware(_) => "http_error",
+            Self::Api { .. } => "api_error",
+            Self::ApiJson { .. } | Self::Json(_) => "invalid_response",
+            Self::NotFound { .. } => "not_found",
+            Self::InvalidArgument(_) => "invalid_argument",
+            Self::ApiKeyRequired { .. } => "api_key_required",
+            Self::SourceUnavailable { .. } => "source_unavailable",
+            Self::Template(_) => "template_error",
+            Self::Io(_) => "io_error",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::BioMcpError;
@@ -111,4 +133,23 @@ mod tests {
         assert!(msg.contains("opentargets"));
         assert!(msg.contains("HTTP 500"));
     }
+
+    #[test]
+    fn code_is_stable_per_variant() {
+        let api = BioMcpError::Api {
+            api: "monarch".to_string(),
+            message: "HTTP 500".to_string(),
+        };
+        assert_eq!(api.code(), "api_error");
+        assert_eq!(
+            BioMcpError::InvalidArgument("x".to_string()).code(),
+            "invalid_argument"
+        );
+        let key = BioMcpError::ApiKeyRequired {
+            api: "oncokb".to_string(),
+            env_var: "ONCOKB_TOKEN".to_string(),
+            docs_url: "https://www.oncokb.org/".to_string(),
+        };
+        assert_eq!(key.code(), "api_key_required");
+    }
 }
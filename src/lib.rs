@@ -10,6 +10,7 @@ pub mod mcp;
 
 #[cfg_attr(not(test), allow(dead_code))]
 mod cache;
+mod config;
 mod entities;
 mod render;
 mod sources;
@@ -13,7 +13,16 @@ fn init_tracing() {
 async fn main() -> std::process::ExitCode {
     init_tracing();
 
+    let args = std::env::args().collect::<Vec<_>>();
     let cli = biomcp_cli::cli::parse_cli_from_env();
+    if let Err(err) = biomcp_cli::cli::cache::apply_cache_dir_override(cli.cache_dir.as_deref()) {
+        eprintln!("Error: {err}");
+        return std::process::ExitCode::from(1);
+    }
+    if let Err(err) = biomcp_cli::cli::cache::init_cache_encryption() {
+        eprintln!("Error: {err}");
+        return std::process::ExitCode::from(1);
+    }
     match cli.command {
         biomcp_cli::cli::Commands::Mcp | biomcp_cli::cli::Commands::Serve => {
             match biomcp_cli::mcp::run_stdio().await {
@@ -27,7 +36,7 @@ async fn main() -> std::process::ExitCode {
         biomcp_cli::cli::Commands::ServeHttp(args) => {
             let host = args.host;
             let port = args.port;
-            match biomcp_cli::mcp::run_http(&host, port).await {
+            match biomcp_cli::mcp::run_http(&host, port, args.refresh_interval).await {
                 Ok(()) => std::process::ExitCode::SUCCESS,
                 Err(err) => {
                     eprintln!("Error: {err}");
@@ -42,12 +51,15 @@ async fn main() -> std::process::ExitCode {
                 std::process::ExitCode::from(1)
             }
         },
-        _ => match biomcp_cli::cli::run_outcome(cli).await {
+        _ => match biomcp_cli::cli::run_recorded(cli, args).await {
             Ok(output) => {
                 match output.stream {
                     biomcp_cli::cli::OutputStream::Stdout => println!("{}", output.text),
                     biomcp_cli::cli::OutputStream::Stderr => eprintln!("{}", output.text),
                 }
+                if let Some(diagnostics) = output.diagnostics {
+                    eprintln!("{diagnostics}");
+                }
                 std::process::ExitCode::from(output.exit_code)
             }
             Err(err) => {
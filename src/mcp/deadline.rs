@@ -0,0 +1,197 @@
+//! Soft deadline for `biomcp` tool calls.
+//!
+//! MCP clients abort tool calls that run past their own timeout, which throws
+//! away every section that did finish. Tool calls therefore run under a soft
+//! deadline (`BIOMCP_MCP_SOFT_DEADLINE_SECS`, default 45s, `0` disables):
+//! optional sections still pending when it passes are skipped, and the response
+//! carries a continuation hint naming them and the follow-up call to load them.
+
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::Value;
+use tracing::warn;
+
+const SOFT_DEADLINE_ENV: &str = "BIOMCP_MCP_SOFT_DEADLINE_SECS";
+const DEFAULT_SOFT_DEADLINE: Duration = Duration::from_secs(45);
+/// Global flags whose value is the next token (`--max-output-tokens 2000`).
+const GLOBAL_VALUE_FLAGS: &[&str] = &["--cache-dir", "--md-theme", "--max-output-tokens", "--raw"];
+
+fn parse_soft_deadline(value: Option<&str>) -> Option<Duration> {
+    let Some(value) = value.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Some(DEFAULT_SOFT_DEADLINE);
+    };
+    match value.parse::<u64>() {
+        Ok(0) => None,
+        Ok(secs) => Some(Duration::from_secs(secs)),
+        Err(_) => {
+            warn!(
+                "Invalid {SOFT_DEADLINE_ENV}={value:?}, using {}s",
+                DEFAULT_SOFT_DEADLINE.as_secs()
+            );
+            Some(DEFAULT_SOFT_DEADLINE)
+        }
+    }
+}
+
+/// The configured soft deadline, or `None` when disabled.
+pub(super) fn soft_deadline() -> Option<Duration> {
+    parse_soft_deadline(std::env::var(SOFT_DEADLINE_ENV).ok().as_deref())
+}
+
+/// Index of the subcommand in `command`, skipping global flags and their values.
+fn subcommand_position(command: &[String]) -> Option<usize> {
+    let mut index = 0;
+    while let Some(arg) = command.get(index) {
+        if !arg.starts_with('-') {
+            return Some(index);
+        }
+        index += if GLOBAL_VALUE_FLAGS.contains(&arg.as_str()) {
+            2
+        } else {
+            1
+        };
+    }
+    None
+}
+
+/// Machine-readable hint attached to a partial tool result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(super) struct Continuation {
+    pub reason: &'static str,
+    pub soft_deadline_secs: u64,
+    pub sections_remaining: Vec<String>,
+    pub next_command: String,
+}
+
+impl Continuation {
+    /// Builds the hint for `args` (`["biomcp", ...]`). Single-entity `get`
+    /// commands continue with just the skipped sections; anything else is
+    /// re-run as-is, with the finished upstream calls answered from cache.
+    pub(super) fn new(args: &[String], limit: Duration, skipped: Vec<String>) -> Self {
+        let command = &args[1.min(args.len())..];
+        let next = match subcommand_position(command) {
+            Some(get) if command[get] == "get" && command.len() > get + 2 => command[..=get + 2]
+                .iter()
+                .chain(skipped.iter())
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+            _ => command.iter().map(String::as_str).collect(),
+        };
+        let next_command = shlex::try_join(next.iter().copied()).unwrap_or_else(|_| next.join(" "));
+        Self {
+            reason: "soft_deadline",
+            soft_deadline_secs: limit.as_secs(),
+            sections_remaining: skipped,
+            next_command: format!("biomcp {next_command}"),
+        }
+    }
+
+    /// Adds the hint to tool output: `_meta.continuation` for JSON objects, a
+    /// trailing section for markdown.
+    pub(super) fn apply(&self, text: String) -> String {
+        if let Ok(Value::Object(mut object)) = serde_json::from_str::<Value>(&text) {
+            let meta = object
+                .entry("_meta")
+                .or_insert_with(|| Value::Object(Default::default()));
+            if let (Value::Object(meta), Ok(hint)) = (meta, serde_json::to_value(self)) {
+                meta.insert("continuation".to_string(), hint);
+                if let Ok(json) = serde_json::to_string_pretty(&object) {
+                    return json;
+                }
+            }
+        }
+
+        format!(
+            "{}\n\n## Partial Result\n\nThe {}s soft deadline passed before these sections loaded: {}.\n\nContinue with: `{}`\n",
+            text.trim_end(),
+            self.soft_deadline_secs,
+            self.sections_remaining.join(", "),
+            self.next_command
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(command: &str) -> Vec<String> {
+        std::iter::once("biomcp".to_string())
+            .chain(shlex::split(command).expect("valid command"))
+            .collect()
+    }
+
+    #[test]
+    fn parse_soft_deadline_defaults_and_disables() {
+        assert_eq!(parse_soft_deadline(None), Some(Duration::from_secs(45)));
+        assert_eq!(
+            parse_soft_deadline(Some(" 20 ")),
+            Some(Duration::from_secs(20))
+        );
+        assert_eq!(parse_soft_deadline(Some("0")), None);
+        assert_eq!(
+            parse_soft_deadline(Some("soon")),
+            Some(Duration::from_secs(45))
+        );
+    }
+
+    #[test]
+    fn continuation_for_get_requests_only_skipped_sections() {
+        let hint = Continuation::new(
+            &args("--json get gene BRAF all"),
+            Duration::from_secs(45),
+            vec!["civic".into(), "expression".into()],
+        );
+        assert_eq!(
+            hint.next_command,
+            "biomcp --json get gene BRAF civic expression"
+        );
+
+        let hint = Continuation::new(
+            &args("--max-output-tokens 2000 get gene BRAF all"),
+            Duration::from_secs(45),
+            vec!["civic".into()],
+        );
+        assert_eq!(
+            hint.next_command,
+            "biomcp --max-output-tokens 2000 get gene BRAF civic"
+        );
+
+        let hint = Continuation::new(
+            &args("batch gene BRAF,KRAS --sections civic"),
+            Duration::from_secs(45),
+            vec!["civic".into()],
+        );
+        assert_eq!(
+            hint.next_command,
+            "biomcp batch gene BRAF,KRAS --sections civic"
+        );
+    }
+
+    #[test]
+    fn apply_adds_meta_to_json_and_a_section_to_markdown() {
+        let hint = Continuation::new(
+            &args("get disease \"lung cancer\" civic"),
+            Duration::from_secs(30),
+            vec!["civic".into()],
+        );
+        assert_eq!(hint.next_command, "biomcp get disease 'lung cancer' civic");
+
+        let json = hint.apply(r#"{"id":"MONDO:0008903","_meta":{"section_errors":[]}}"#.into());
+        let value: Value = serde_json::from_str(&json).expect("json output");
+        assert_eq!(value["_meta"]["continuation"]["reason"], "soft_deadline");
+        assert_eq!(
+            value["_meta"]["continuation"]["sections_remaining"][0],
+            "civic"
+        );
+        assert_eq!(value["_meta"]["section_errors"], serde_json::json!([]));
+
+        let markdown = hint.apply("# lung cancer\n".into());
+        assert!(markdown.contains("## Partial Result"));
+        assert!(
+            markdown.contains("The 30s soft deadline passed before these sections loaded: civic.")
+        );
+        assert!(markdown.contains("Continue with: `biomcp get disease 'lung cancer' civic`"));
+    }
+}
@@ -1,9 +1,17 @@
 //! MCP server entrypoints for stdio and HTTP transports.
 
+mod deadline;
+mod prompts;
+mod refresh;
 mod shell;
+mod summarize;
+mod tenant;
+mod warmup;
 
 /// Runs the BioMCP MCP server over stdio.
 ///
+/// Templates and shared HTTP clients are warmed up before the handshake.
+///
 /// # Errors
 ///
 /// Returns an error when stdio transport setup or MCP server startup fails.
@@ -19,12 +27,27 @@ pub async fn run_stdio() -> anyhow::Result<()> {
 /// - `GET /health` — liveness probe
 /// - `GET /readyz` — readiness alias
 /// - `GET /` — identity/status response
+/// - `GET /metrics` — per-tenant usage counters, when `[tenants]` is configured
+///
+/// Templates and shared HTTP clients are warmed up before the listener binds;
+/// `BIOMCP_WARM_CONNECTIONS=1` also primes connections to the top sources.
+///
+/// With `[tenants]` configured, `/mcp` requires a tenant bearer token and each
+/// tool call uses that tenant's upstream keys and cache namespace.
+///
+/// With `refresh_interval`, recently requested commands are replayed in the
+/// background each interval so their cached upstream responses stay warm.
 ///
 /// # Errors
 ///
-/// Returns an error when TCP bind or server startup fails.
-pub async fn run_http(host: &str, port: u16) -> anyhow::Result<()> {
-    shell::run_http(host, port).await
+/// Returns an error when the tenant configuration is invalid or when TCP bind
+/// or server startup fails.
+pub async fn run_http(
+    host: &str,
+    port: u16,
+    refresh_interval: Option<std::time::Duration>,
+) -> anyhow::Result<()> {
+    shell::run_http(host, port, refresh_interval).await
 }
 
 /// Returns the deprecation guidance for the removed SSE transport command.
@@ -0,0 +1,208 @@
+//! Curated multi-step workflows served as MCP prompts.
+//!
+//! Each prompt expands its arguments into one user message that lists the
+//! BioMCP commands to run, in order, and what to report from them. The
+//! commands are ordinary read-only `biomcp` tool calls, so a client that picks
+//! a prompt from `prompts/list` can follow it without knowing the CLI.
+
+use std::fmt::Write as _;
+
+use rmcp::schemars;
+use serde::Deserialize;
+
+use crate::utils::text::non_empty;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(super) struct VariantWorkupArgs {
+    /// Variant to work up, e.g. `BRAF V600E`, `rs113488022`, or `NM_004333.6:c.1799T>A`.
+    pub variant: String,
+    /// Disease context for the treatment and trial steps, e.g. `melanoma`.
+    #[serde(default)]
+    pub disease: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(super) struct TrialMatchingArgs {
+    /// Diagnosis to match trials against, e.g. `non-small cell lung cancer`.
+    pub condition: String,
+    /// Molecular alteration the patient carries, e.g. `EGFR L858R`.
+    #[serde(default)]
+    pub mutation: Option<String>,
+    /// Patient age in years.
+    #[serde(default)]
+    pub age: Option<String>,
+    /// Patient sex: `female` or `male`.
+    #[serde(default)]
+    pub sex: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(super) struct DrugSafetyReviewArgs {
+    /// Drug to review, generic or brand name, e.g. `pembrolizumab`.
+    pub drug: String,
+    /// Indication or population the review is for, e.g. `elderly patients`.
+    #[serde(default)]
+    pub focus: Option<String>,
+}
+
+const CLOSING: &str = "Run each step with the `biomcp` tool. Skip a step only when an earlier \
+step shows it cannot apply, and say so. Cite the command behind every claim, and report \
+missing or conflicting evidence instead of filling gaps from memory.";
+
+/// Double-quotes a caller-supplied value for a `biomcp` command line.
+fn quoted(value: &str) -> String {
+    let value = value
+        .chars()
+        .filter(|c| *c != '"' && *c != '`' && !c.is_control())
+        .collect::<String>();
+    format!("\"{}\"", value.trim())
+}
+
+fn steps(intro: String, steps: &[String], report: &str) -> String {
+    let mut out = intro;
+    out.push_str("\n\n");
+    for (idx, step) in steps.iter().enumerate() {
+        let _ = writeln!(out, "{}. {step}", idx + 1);
+    }
+    let _ = write!(out, "\n{report}\n\n{CLOSING}");
+    out
+}
+
+pub(super) fn variant_workup(args: &VariantWorkupArgs) -> String {
+    let variant = quoted(&args.variant);
+    let disease = non_empty(args.disease.as_deref());
+    let intro = match disease.as_deref() {
+        Some(disease) => format!(
+            "Work up the variant {} in {disease} with BioMCP.",
+            args.variant.trim()
+        ),
+        None => format!("Work up the variant {} with BioMCP.", args.variant.trim()),
+    };
+    let trials = match disease.as_deref() {
+        Some(disease) => format!(
+            "`search trial -c {} --mutation {variant} -s recruiting` - recruiting trials for this variant in this disease.",
+            quoted(disease)
+        ),
+        None => format!("`variant trials {variant}` - trials that mention the variant."),
+    };
+    steps(
+        intro,
+        &[
+            format!(
+                "`get variant {variant} clinvar population predictions` - ClinVar classification and review status, gnomAD frequency, and in-silico predictions."
+            ),
+            format!(
+                "`get variant {variant} civic cgi cosmic` - somatic evidence, biomarker-drug associations, and how often the variant recurs in tumors."
+            ),
+            "`get gene <gene> constraint civic` - gene-level constraint and evidence, using the gene named in step 1.".to_string(),
+            format!("`variant articles {variant}` - the key literature on the variant."),
+            trials,
+        ],
+        "Report: the germline classification and its review status, population frequency, \
+predicted impact, therapeutic or prognostic evidence with its evidence level, and the most \
+relevant trials.",
+    )
+}
+
+pub(super) fn trial_matching(args: &TrialMatchingArgs) -> String {
+    let condition = quoted(&args.condition);
+    let mut search = format!("search trial -c {condition} -s recruiting");
+    let mutation = non_empty(args.mutation.as_deref());
+    if let Some(mutation) = mutation.as_deref() {
+        let _ = write!(search, " --mutation {}", quoted(mutation));
+    }
+    if let Some(age) = non_empty(args.age.as_deref()).filter(|age| age.parse::<f32>().is_ok()) {
+        let _ = write!(search, " --age {age}");
+    }
+    if let Some(sex) = non_empty(args.sex.as_deref()) {
+        let _ = write!(search, " --sex {}", quoted(&sex.to_ascii_lowercase()));
+    }
+
+    let mut plan = vec![
+        format!("`{search} --limit 10` - recruiting trials that fit the patient's diagnosis and demographics."),
+        "`get trial <nct_id> eligibility` - the full criteria for each promising trial; page with `--offset` when the text is long.".to_string(),
+        "`get trial <nct_id> locations` - sites for trials that still fit.".to_string(),
+    ];
+    if let Some(mutation) = mutation.as_deref() {
+        plan.insert(
+            0,
+            format!(
+                "`get variant {} civic` - confirm the alteration and note therapies with evidence for it.",
+                quoted(mutation)
+            ),
+        );
+    }
+    steps(
+        format!(
+            "Find clinical trials for a patient with {}.",
+            args.condition.trim()
+        ),
+        &plan,
+        "Report: a ranked list of trials with NCT ID, phase, and the eligibility criteria the \
+patient meets, fails, or that need more information.",
+    )
+}
+
+pub(super) fn drug_safety_review(args: &DrugSafetyReviewArgs) -> String {
+    let drug = quoted(&args.drug);
+    let intro = match non_empty(args.focus.as_deref()) {
+        Some(focus) => format!(
+            "Review the safety of {} for {focus} with BioMCP.",
+            args.drug.trim()
+        ),
+        None => format!("Review the safety of {} with BioMCP.", args.drug.trim()),
+    };
+    steps(
+        intro,
+        &[
+            format!(
+                "`get drug {drug} label safety` - boxed warnings, contraindications, and regional safety communications."
+            ),
+            format!("`get drug {drug} interactions` - labeled drug-drug interactions."),
+            format!(
+                "`search adverse-event --drug {drug} --serious --limit 10` - serious FAERS reports."
+            ),
+            format!(
+                "`search adverse-event --drug {drug} --count patient.reaction.reactionmeddrapt.exact` - the most reported reactions."
+            ),
+            format!(
+                "`get pgx {drug} recommendations` - pharmacogenomic dosing guidance, if CPIC covers the drug."
+            ),
+        ],
+        "Report: labeled warnings, the most frequent and most serious reported reactions (FAERS \
+counts are spontaneous reports, not incidence), clinically relevant interactions, and any \
+genotype-guided dosing.",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prompts_fill_optional_arguments_and_quote_values() {
+        let text = trial_matching(&TrialMatchingArgs {
+            condition: "breast \"cancer\"".into(),
+            mutation: Some(" ".into()),
+            age: Some("about fifty".into()),
+            sex: Some("Female".into()),
+        });
+        assert!(text.starts_with("Find clinical trials for a patient with breast \"cancer\"."));
+        assert!(text.contains(
+            "1. `search trial -c \"breast cancer\" -s recruiting --sex \"female\" --limit 10`"
+        ));
+        assert!(!text.contains("--mutation"));
+        assert!(!text.contains("--age"));
+        assert!(!text.contains("get variant"));
+
+        let text = variant_workup(&VariantWorkupArgs {
+            variant: "BRAF V600E".into(),
+            disease: Some("melanoma".into()),
+        });
+        assert!(text.starts_with("Work up the variant BRAF V600E in melanoma with BioMCP."));
+        assert!(
+            text.contains("`search trial -c \"melanoma\" --mutation \"BRAF V600E\" -s recruiting`")
+        );
+        assert!(text.ends_with(CLOSING));
+    }
+}
@@ -0,0 +1,241 @@
+//! Background cache refresh for long-running `serve-http` deployments.
+//!
+//! With `--refresh-interval`, successful tool commands are remembered in a
+//! small most-recently-used table. Each interval the commands still considered
+//! hot are replayed with the HTTP cache in reload mode, so their upstream
+//! responses are fetched again and stored fresh before an interactive caller
+//! meets an expired entry. Replays run as the tenant that issued the command,
+//! one at a time, and are kept out of query history and tenant metrics.
+
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use tracing::{debug, info, warn};
+
+use crate::sources::tenant::{TenantScope, with_tenant};
+
+const MAX_HOT_COMMANDS: usize = 32;
+/// A command stays hot for this many intervals after its last request.
+const HOT_INTERVALS: u32 = 4;
+/// Families whose commands only read upstream data; anything else (jobs,
+/// local study files, help text) is never replayed.
+const REFRESHABLE_FAMILIES: &[&str] = &[
+    "get", "search", "batch", "gene", "variant", "drug", "disease", "article", "pathway",
+    "protein", "compare", "network", "ontology", "dossier", "raw",
+];
+/// Global flags that take a separate value, skipped when finding the family.
+const VALUE_FLAGS: &[&str] = &["--max-output-tokens", "--raw", "--cache-dir", "--md-theme"];
+
+static HOT_COMMANDS: OnceLock<Mutex<HotCommands>> = OnceLock::new();
+
+#[derive(Debug, Clone)]
+struct HotCommand {
+    args: Vec<String>,
+    tenant: Option<Arc<TenantScope>>,
+    last_requested: Instant,
+}
+
+impl HotCommand {
+    fn tenant_name(&self) -> Option<&str> {
+        self.tenant.as_deref().map(TenantScope::name)
+    }
+}
+
+/// Most recently requested first, capped at [`MAX_HOT_COMMANDS`].
+#[derive(Debug, Default)]
+struct HotCommands {
+    entries: Vec<HotCommand>,
+}
+
+impl HotCommands {
+    fn touch(&mut self, args: &[String], tenant: Option<Arc<TenantScope>>, now: Instant) {
+        let tenant_name = tenant.as_deref().map(TenantScope::name);
+        self.entries
+            .retain(|entry| !(entry.args == args && entry.tenant_name() == tenant_name));
+        self.entries.insert(
+            0,
+            HotCommand {
+                args: args.to_vec(),
+                tenant,
+                last_requested: now,
+            },
+        );
+        self.entries.truncate(MAX_HOT_COMMANDS);
+    }
+
+    /// Forgets commands not requested within `window` and returns the rest.
+    fn hot(&mut self, now: Instant, window: Duration) -> Vec<HotCommand> {
+        self.entries
+            .retain(|entry| now.saturating_duration_since(entry.last_requested) <= window);
+        self.entries.clone()
+    }
+}
+
+fn command_family(args: &[String]) -> Option<String> {
+    let mut rest = args.iter().skip(1);
+    while let Some(arg) = rest.next() {
+        if VALUE_FLAGS.contains(&arg.as_str()) {
+            rest.next();
+        } else if !arg.starts_with('-') {
+            return Some(arg.trim().to_ascii_lowercase());
+        }
+    }
+    None
+}
+
+fn is_refreshable(args: &[String]) -> bool {
+    command_family(args).is_some_and(|family| REFRESHABLE_FAMILIES.contains(&family.as_str()))
+}
+
+/// Remembers a successful tool command; a no-op until [`spawn`] has run.
+pub(crate) fn record(args: &[String], tenant: Option<Arc<TenantScope>>) {
+    let Some(hot) = HOT_COMMANDS.get() else {
+        return;
+    };
+    if !is_refreshable(args) {
+        return;
+    }
+    if let Ok(mut hot) = hot.lock() {
+        hot.touch(args, tenant, Instant::now());
+    }
+}
+
+async fn refresh_once(window: Duration) {
+    let commands = match HOT_COMMANDS.get().map(Mutex::lock) {
+        Some(Ok(mut hot)) => hot.hot(Instant::now(), window),
+        _ => return,
+    };
+    if commands.is_empty() {
+        return;
+    }
+
+    let started = Instant::now();
+    let mut failed = 0usize;
+    for command in &commands {
+        let replay =
+            crate::sources::with_cache_refresh(crate::cli::execute_mcp(command.args.clone()));
+        let result = match command.tenant.clone() {
+            Some(tenant) => with_tenant(tenant, replay).await,
+            None => replay.await,
+        };
+        if let Err(err) = result {
+            failed += 1;
+            debug!(command = %command.args[1..].join(" "), "cache refresh failed: {err}");
+        }
+    }
+    info!(
+        commands = commands.len(),
+        failed,
+        elapsed_ms = started.elapsed().as_millis() as u64,
+        "hot cache entries refreshed"
+    );
+}
+
+#[cfg(test)]
+pub(super) fn is_tracking() -> bool {
+    HOT_COMMANDS.get().is_some()
+}
+
+/// Starts command tracking and the refresh loop; the first pass runs one
+/// interval after startup.
+pub(crate) fn spawn(interval: Duration) {
+    if HOT_COMMANDS
+        .set(Mutex::new(HotCommands::default()))
+        .is_err()
+    {
+        warn!("cache refresh is already running");
+        return;
+    }
+    let window = interval.saturating_mul(HOT_INTERVALS);
+    info!(
+        interval_secs = interval.as_secs(),
+        "background cache refresh enabled"
+    );
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            refresh_once(window).await;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    fn args(command: &str) -> Vec<String> {
+        std::iter::once("biomcp")
+            .chain(command.split_whitespace())
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn only_read_only_upstream_families_are_refreshable() {
+        assert!(is_refreshable(&args("get gene BRAF all")));
+        assert!(is_refreshable(&args(
+            "--max-output-tokens 800 search trial -c melanoma"
+        )));
+        assert!(is_refreshable(&args("--json batch gene BRAF,KRAS")));
+        assert!(!is_refreshable(&args("jobs submit -- get gene BRAF")));
+        assert!(!is_refreshable(&args(
+            "study query --study x --gene TP53 --type mutations"
+        )));
+        assert!(!is_refreshable(&args("list gene")));
+        assert!(!is_refreshable(&args("--json")));
+    }
+
+    #[test]
+    fn hot_commands_dedupe_per_tenant_and_expire_outside_the_window() {
+        let start = Instant::now();
+        let oncology = Arc::new(TenantScope::new("oncology", BTreeMap::new()));
+        let mut hot = HotCommands::default();
+        hot.touch(&args("get gene BRAF"), None, start);
+        hot.touch(
+            &args("get gene KRAS"),
+            None,
+            start + Duration::from_secs(10),
+        );
+        hot.touch(
+            &args("get gene BRAF"),
+            Some(oncology),
+            start + Duration::from_secs(20),
+        );
+        hot.touch(
+            &args("get gene BRAF"),
+            None,
+            start + Duration::from_secs(30),
+        );
+        assert_eq!(hot.entries.len(), 3);
+        assert_eq!(hot.entries[0].args, args("get gene BRAF"));
+        assert_eq!(hot.entries[0].tenant_name(), None);
+        assert_eq!(hot.entries[1].tenant_name(), Some("oncology"));
+
+        let live = hot.hot(start + Duration::from_secs(100), Duration::from_secs(85));
+        let live = live
+            .iter()
+            .map(|entry| (entry.args[3].as_str(), entry.tenant_name()))
+            .collect::<Vec<_>>();
+        assert_eq!(live, vec![("BRAF", None), ("BRAF", Some("oncology"))]);
+        assert_eq!(hot.entries.len(), 2);
+    }
+
+    #[test]
+    fn hot_commands_keep_only_the_most_recent() {
+        let start = Instant::now();
+        let mut hot = HotCommands::default();
+        for i in 0..(MAX_HOT_COMMANDS + 5) {
+            hot.touch(
+                &args(&format!("get gene G{i}")),
+                None,
+                start + Duration::from_secs(i as u64),
+            );
+        }
+        assert_eq!(hot.entries.len(), MAX_HOT_COMMANDS);
+        assert_eq!(hot.entries[0].args[3], format!("G{}", MAX_HOT_COMMANDS + 4));
+    }
+}
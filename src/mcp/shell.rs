@@ -1,13 +1,21 @@
 use std::future::Future;
+use std::sync::Arc;
 use std::time::Duration;
 
-use axum::{Json, Router, routing::get};
+use axum::extract::State;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::IntoResponse;
+use axum::{Json, Router, middleware, routing::get};
 use base64::Engine;
-use rmcp::handler::server::{router::tool::ToolRouter, wrapper::Parameters};
+use rmcp::handler::server::{
+    router::{prompt::PromptRouter, tool::ToolRouter},
+    wrapper::Parameters,
+};
 use rmcp::model::{
     AnnotateAble, CallToolResult, Content, Implementation, ListResourcesResult,
-    PaginatedRequestParams, RawResource, ReadResourceRequestParams, ReadResourceResult,
-    ResourceContents, ServerCapabilities, ServerInfo,
+    PaginatedRequestParams, PromptMessage, PromptMessageRole, RawResource,
+    ReadResourceRequestParams, ReadResourceResult, ResourceContents, ServerCapabilities,
+    ServerInfo,
 };
 use rmcp::schemars;
 use rmcp::service::RequestContext;
@@ -15,36 +23,155 @@ use rmcp::transport::streamable_http_server::{
     StreamableHttpServerConfig, StreamableHttpService, session::local::LocalSessionManager,
 };
 use rmcp::{
-    ErrorData as McpError, RoleServer, ServerHandler, ServiceExt, tool, tool_handler, tool_router,
+    ErrorData as McpError, RoleServer, ServerHandler, ServiceExt, prompt, prompt_handler,
+    prompt_router, tool, tool_handler, tool_router,
 };
 use serde::Deserialize;
 use serde_json::json;
 use tokio_util::sync::CancellationToken;
 
+use super::deadline;
+use super::prompts::{self, DrugSafetyReviewArgs, TrialMatchingArgs, VariantWorkupArgs};
+use super::refresh;
+use super::summarize;
+use super::tenant::{self, TenantDirectory};
+use super::warmup;
+
 #[derive(Debug, Clone)]
 pub struct BioMcpServer {
     tool_router: ToolRouter<Self>,
+    prompt_router: PromptRouter<Self>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 struct ShellCommand {
     command: String,
+    /// Optional approximate token budget for the markdown output (200 or more). Long text
+    /// fields, then trailing sections, are cut to fit, with a note naming what to re-run.
+    #[serde(default)]
+    max_output_tokens: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct SummarizeCommand {
+    /// Read-only BioMCP command whose output should be condensed, e.g. `get gene BRAF all`.
+    command: String,
+    /// Optional question the summary should answer.
+    #[serde(default)]
+    focus: Option<String>,
+    /// Summary length budget in tokens (default 600, range 100-2000).
+    #[serde(default)]
+    max_tokens: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct SectionsRequest {
+    /// Entity name such as `gene`, `variant`, or `adverse-event`; omit for every entity.
+    #[serde(default)]
+    entity: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct DossierRequest {
+    /// HGNC gene symbol, e.g. `BRAF`.
+    gene: String,
+    /// Disease name, e.g. `melanoma`.
+    disease: String,
+    /// Return the dossier as JSON instead of markdown.
+    #[serde(default)]
+    json: bool,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct RawQueryRequest {
+    /// Upstream source: `mygene`, `myvariant`, `mychem`, `uniprot`, `ensembl`,
+    /// `clinicaltrials`, `europepmc`, `pubtator`, or `reactome`.
+    source: String,
+    /// Endpoint path under the source's base URL, e.g. `query` or
+    /// `lookup/id/ENSG00000157764`. No query string.
+    path: String,
+    /// Query parameters as `key=value` strings, e.g. `["q=symbol:BRAF", "fields=symbol,name"]`.
+    #[serde(default)]
+    params: Vec<String>,
 }
 
 const RESOURCE_HELP_URI: &str = "biomcp://help";
-const GENERIC_MCP_REJECTION_MESSAGE: &str = "Error: BioMCP allows read-only commands only. Allowed families are search/get/helpers/list/version/health/batch/enrich/discover/skill plus MCP-safe study commands (`study list`, `study download --list`, `study top-mutated`, `study query`, `study filter`, `study cohort`, `study survival`, `study compare`, `study co-occurrence`).";
+const RESOURCE_ARTICLE_PREFIX: &str = "biomcp://article/";
+const RESOURCE_FULLTEXT_SUFFIX: &str = "/fulltext";
+const RESOURCE_GENE_PREFIX: &str = "biomcp://gene/";
+const RESOURCE_SNAPSHOT_SUFFIX: &str = "/snapshot";
+const GENERIC_MCP_REJECTION_MESSAGE: &str = "Error: BioMCP allows read-only commands only. Allowed families are search/get/helpers/list/version/health/selftest/batch/enrich/discover/ask/validate/skill plus MCP-safe study commands (`study list`, `study download --list`, `study top-mutated`, `study query`, `study filter`, `study cohort`, `study survival`, `study compare`, `study co-occurrence`).";
 const CACHE_FAMILY_MCP_REJECTION_MESSAGE: &str = "Error: biomcp cache commands are CLI-only over MCP because they reveal workstation-local filesystem paths.";
+const PGX_VCF_MCP_REJECTION_MESSAGE: &str = "Error: `pgx panel --vcf` is CLI-only over MCP because it reads workstation-local files. Run `pgx panel --genes <g1,g2,...>` instead.";
+const REPORT_FILE_MCP_REJECTION_MESSAGE: &str = "Error: `report panel --file` is CLI-only over MCP because it reads workstation-local files. Run `report panel --genes <g1,g2,...>` instead.";
+const ANNOTATE_MCP_REJECTION_MESSAGE: &str =
+    "Error: `annotate` commands are CLI-only over MCP because they read workstation-local files.";
+const REPURPOSE_MCP_REJECTION_MESSAGE: &str =
+    "Error: `repurpose` is CLI-only over MCP because it reads workstation-local gene list files.";
+const MATCH_MCP_REJECTION_MESSAGE: &str = "Error: `match trials` is CLI-only over MCP because it reads a workstation-local patient profile. Run `search trial -c <condition> --age <years> --sex <female|male> --mutation <text>` instead.";
+const SAMPLING_UNSUPPORTED_MESSAGE: &str = "Error: this MCP client does not support sampling, so BioMCP cannot summarize through it. Call the `biomcp` tool for the full report instead.";
+const SAMPLING_TIMEOUT: Duration = Duration::from_secs(120);
 
 impl BioMcpServer {
     pub fn new() -> Self {
         Self {
             tool_router: Self::tool_router(),
+            prompt_router: Self::prompt_router(),
         }
     }
 
     fn tool_error(message: impl Into<String>) -> CallToolResult {
         CallToolResult::error(vec![Content::text(message.into())])
     }
+
+    /// Splits a tool command into CLI args and applies the read-only allowlist.
+    fn parse_tool_command(command: &str) -> Result<Vec<String>, CallToolResult> {
+        if command.len() > 1024 {
+            return Err(Self::tool_error("Error: command is too long"));
+        }
+
+        let Some(split) = shlex::split(command) else {
+            return Err(Self::tool_error(format!(
+                "Error: Invalid command syntax: {command}"
+            )));
+        };
+
+        let mut args = vec!["biomcp".to_string()];
+        if split.first().is_some_and(|s| s == "biomcp") {
+            args.extend(split.into_iter().skip(1));
+        } else {
+            args.extend(split);
+        }
+
+        if !is_allowed_mcp_command(&args) {
+            return Err(Self::tool_error(mcp_rejection_message(&args)));
+        }
+        Ok(args)
+    }
+
+    /// Runs a parsed command, as the request's tenant when `serve-http` tenancy is on.
+    async fn execute(
+        args: Vec<String>,
+        context: &RequestContext<RoleServer>,
+    ) -> anyhow::Result<crate::cli::CliOutput> {
+        let tenant = tenant::request_tenant(context);
+        let result = match &tenant {
+            Some(tenant) => tenant.run(crate::cli::execute_mcp(args.clone())).await,
+            None => crate::cli::execute_mcp(args.clone()).await,
+        };
+        if result.is_ok() {
+            refresh::record(&args, tenant.as_ref().map(|tenant| tenant.scope()));
+        }
+        // Tenants share one server, so their queries stay out of the local history.
+        if tenant.is_none() {
+            crate::cli::history::record_query(
+                crate::utils::history::HistoryOrigin::Mcp,
+                &args,
+                result.as_ref().ok().map(|output| output.text.as_str()),
+            );
+        }
+        result
+    }
 }
 
 impl Default for BioMcpServer {
@@ -61,7 +188,15 @@ fn is_allowed_mcp_command(args: &[String]) -> bool {
 
     match cmd.as_str() {
         "search" | "get" | "variant" | "drug" | "disease" | "article" | "gene" | "pathway"
-        | "protein" | "list" | "version" | "health" | "batch" | "enrich" | "discover" => true,
+        | "protein" | "list" | "version" | "health" | "selftest" | "batch" | "enrich"
+        | "discover" | "ask" | "validate" | "compare" | "network" | "ontology" | "dossier"
+        | "raw" | "sections" | "schema" => true,
+        // Genotype VCFs are workstation-local files, so `--vcf` stays CLI-only.
+        "pgx" => !args.iter().any(|arg| is_vcf_flag(arg)),
+        // Panel files are local too; `report panel --genes` stays available.
+        "report" => !args.iter().any(|arg| is_file_flag(arg)),
+        // Background jobs may run only what the caller could run directly.
+        "jobs" => is_allowed_jobs_command(args),
         "study" => {
             let Some(sub) = args.get(2).map(|s| s.trim().to_ascii_lowercase()) else {
                 return false;
@@ -87,12 +222,85 @@ fn is_allowed_mcp_command(args: &[String]) -> bool {
     }
 }
 
+/// Adds the tool's `max_output_tokens` parameter as the global CLI flag unless
+/// the command already sets one.
+fn with_output_budget(args: &mut Vec<String>, max_output_tokens: u32) {
+    let already_set = args
+        .iter()
+        .any(|arg| arg == "--max-output-tokens" || arg.starts_with("--max-output-tokens="));
+    if !already_set {
+        args.splice(
+            1..1,
+            [
+                "--max-output-tokens".to_string(),
+                max_output_tokens.to_string(),
+            ],
+        );
+    }
+}
+
+fn is_allowed_jobs_command(args: &[String]) -> bool {
+    let Some(sub) = args.get(2).map(|s| s.trim().to_ascii_lowercase()) else {
+        return false;
+    };
+    match sub.as_str() {
+        "status" | "result" => true,
+        "submit" => submitted_command(args).is_some_and(|inner| is_allowed_mcp_command(&inner)),
+        _ => false,
+    }
+}
+
+/// The command after `--` in `jobs submit -- <command...>`, with a leading `biomcp`.
+fn submitted_command(args: &[String]) -> Option<Vec<String>> {
+    let split = args.iter().position(|arg| arg == "--")?;
+    Some(
+        std::iter::once("biomcp".to_string())
+            .chain(args[split + 1..].iter().cloned())
+            .collect(),
+    )
+}
+
+fn is_vcf_flag(arg: &str) -> bool {
+    let arg = arg.trim();
+    arg == "--vcf" || arg.starts_with("--vcf=")
+}
+
+fn is_file_flag(arg: &str) -> bool {
+    let arg = arg.trim();
+    arg == "--file" || arg.starts_with("--file=")
+}
+
+fn sections_manifest_json(entity: Option<&str>) -> Result<String, crate::error::BioMcpError> {
+    use crate::entities::section_manifest;
+
+    match entity.map(str::trim).filter(|entity| !entity.is_empty()) {
+        Some(entity) => crate::render::json::to_pretty(&section_manifest::manifest(entity)?),
+        None => crate::render::json::to_pretty(&section_manifest::all_manifests()),
+    }
+}
+
 fn mcp_rejection_message(args: &[String]) -> &'static str {
-    if args
-        .get(1)
-        .is_some_and(|cmd| cmd.trim().eq_ignore_ascii_case("cache"))
-    {
+    let cmd = args.get(1).map(|cmd| cmd.trim());
+    if cmd.is_some_and(|cmd| cmd.eq_ignore_ascii_case("cache")) {
         CACHE_FAMILY_MCP_REJECTION_MESSAGE
+    } else if cmd.is_some_and(|cmd| cmd.eq_ignore_ascii_case("annotate")) {
+        ANNOTATE_MCP_REJECTION_MESSAGE
+    } else if cmd.is_some_and(|cmd| cmd.eq_ignore_ascii_case("repurpose")) {
+        REPURPOSE_MCP_REJECTION_MESSAGE
+    } else if cmd.is_some_and(|cmd| cmd.eq_ignore_ascii_case("match")) {
+        MATCH_MCP_REJECTION_MESSAGE
+    } else if cmd.is_some_and(|cmd| cmd.eq_ignore_ascii_case("pgx"))
+        && args.iter().any(|arg| is_vcf_flag(arg))
+    {
+        PGX_VCF_MCP_REJECTION_MESSAGE
+    } else if cmd.is_some_and(|cmd| cmd.eq_ignore_ascii_case("report"))
+        && args.iter().any(|arg| is_file_flag(arg))
+    {
+        REPORT_FILE_MCP_REJECTION_MESSAGE
+    } else if cmd.is_some_and(|cmd| cmd.eq_ignore_ascii_case("jobs"))
+        && let Some(inner) = submitted_command(args)
+    {
+        mcp_rejection_message(&inner)
     } else {
         GENERIC_MCP_REJECTION_MESSAGE
     }
@@ -104,35 +312,41 @@ impl BioMcpServer {
     #[tool(annotations(title = "BioMCP", read_only_hint = true))]
     async fn biomcp(
         &self,
-        Parameters(ShellCommand { command }): Parameters<ShellCommand>,
+        Parameters(ShellCommand {
+            command,
+            max_output_tokens,
+        }): Parameters<ShellCommand>,
+        context: RequestContext<RoleServer>,
     ) -> Result<CallToolResult, McpError> {
-        if command.len() > 1024 {
-            return Ok(Self::tool_error("Error: command is too long"));
+        let mut args = match Self::parse_tool_command(&command) {
+            Ok(args) => args,
+            Err(rejected) => return Ok(rejected),
+        };
+        if let Some(max_output_tokens) = max_output_tokens {
+            with_output_budget(&mut args, max_output_tokens);
         }
 
-        let split = match shlex::split(&command) {
-            Some(args) => args,
-            None => {
-                return Ok(Self::tool_error(format!(
-                    "Error: Invalid command syntax: {command}"
-                )));
+        let (result, continuation) = match deadline::soft_deadline() {
+            Some(limit) => {
+                let (result, skipped) = crate::entities::section_errors::with_soft_deadline(
+                    limit,
+                    Self::execute(args.clone(), &context),
+                )
+                .await;
+                let continuation = (!skipped.is_empty())
+                    .then(|| deadline::Continuation::new(&args, limit, skipped));
+                (result, continuation)
             }
+            None => (Self::execute(args, &context).await, None),
         };
 
-        let mut args = vec!["biomcp".to_string()];
-        if split.first().is_some_and(|s| s == "biomcp") {
-            args.extend(split.into_iter().skip(1));
-        } else {
-            args.extend(split);
-        }
-
-        if !is_allowed_mcp_command(&args) {
-            return Ok(Self::tool_error(mcp_rejection_message(&args)));
-        }
-
-        match crate::cli::execute_mcp(args).await {
+        match result {
             Ok(output) => {
-                let mut content = vec![Content::text(output.text)];
+                let text = match continuation {
+                    Some(continuation) => continuation.apply(output.text),
+                    None => output.text,
+                };
+                let mut content = vec![Content::text(text)];
                 if let Some(svg) = output.svg {
                     let encoded = base64::engine::general_purpose::STANDARD.encode(svg.as_bytes());
                     content.push(Content::image(encoded, "image/svg+xml"));
@@ -142,14 +356,203 @@ impl BioMcpServer {
             Err(err) => Ok(Self::tool_error(format!("Error: {err}"))),
         }
     }
+
+    /// Returns the JSON manifest of `get` sections for an entity: each
+    /// section's description, upstream sources, latency class
+    /// (`fast`/`moderate`/`slow`), and API-key requirement. Check it before
+    /// `get <entity> <id> <sections...>` to request only the sections a
+    /// question needs and to skip ones whose required key is unset.
+    #[tool(annotations(title = "BioMCP Sections", read_only_hint = true))]
+    async fn biomcp_sections(
+        &self,
+        Parameters(SectionsRequest { entity }): Parameters<SectionsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        Ok(match sections_manifest_json(entity.as_deref()) {
+            Ok(text) => CallToolResult::success(vec![Content::text(text)]),
+            Err(err) => Self::tool_error(format!("Error: {err}")),
+        })
+    }
+
+    /// Builds a scored evidence dossier for one gene-disease pair: the
+    /// OpenTargets association, CIViC evidence, drugs targeting the gene,
+    /// recruiting trials, and key articles, with a 0-100 score broken down by
+    /// source. The score is a triage heuristic, not a clinical validity call.
+    #[tool(annotations(title = "BioMCP Dossier", read_only_hint = true))]
+    async fn biomcp_dossier(
+        &self,
+        Parameters(DossierRequest {
+            gene,
+            disease,
+            json,
+        }): Parameters<DossierRequest>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut args = vec!["biomcp".to_string()];
+        if json {
+            args.push("--json".to_string());
+        }
+        args.extend(["dossier".to_string(), "--gene".to_string(), gene]);
+        args.extend(["--disease".to_string(), disease]);
+        Ok(match Self::execute(args, &context).await {
+            Ok(output) => CallToolResult::success(vec![Content::text(output.text)]),
+            Err(err) => Self::tool_error(format!("Error: {err}")),
+        })
+    }
+
+    /// Sends a GET to a whitelisted upstream endpoint and returns its JSON
+    /// as-is, for fields BioMCP does not map. Each source allows only fixed
+    /// path prefixes (e.g. mygene `query`/`gene`, ensembl `lookup`/`xrefs`,
+    /// clinicaltrials `studies`); responses must be JSON and at most 1 MiB.
+    /// Prefer the `biomcp` tool when a mapped command covers the question.
+    #[tool(annotations(title = "BioMCP Raw Query", read_only_hint = true))]
+    async fn raw_query(
+        &self,
+        Parameters(RawQueryRequest {
+            source,
+            path,
+            params,
+        }): Parameters<RawQueryRequest>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut args = vec!["biomcp".to_string(), "raw".to_string(), source, path];
+        for param in params {
+            args.extend(["--param".to_string(), param]);
+        }
+        Ok(match Self::execute(args, &context).await {
+            Ok(output) => CallToolResult::success(vec![Content::text(output.text)]),
+            Err(err) => Self::tool_error(format!("Error: {err}")),
+        })
+    }
+
+    /// Runs a read-only BioMCP command and returns a condensed summary with
+    /// verified `[L<n>]` citation spans instead of the full report. The summary
+    /// is written by the calling client's own model through MCP sampling, so
+    /// this tool only works with clients that advertise the `sampling`
+    /// capability. Use it for large multi-section or multi-entity reports
+    /// (e.g. `get gene BRAF all`, `batch gene BRAF,KRAS,NRAS`) when context is tight.
+    #[tool(annotations(title = "BioMCP Summarize", read_only_hint = true))]
+    async fn biomcp_summarize(
+        &self,
+        Parameters(SummarizeCommand {
+            command,
+            focus,
+            max_tokens,
+        }): Parameters<SummarizeCommand>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let args = match Self::parse_tool_command(&command) {
+            Ok(args) => args,
+            Err(rejected) => return Ok(rejected),
+        };
+        let supports_sampling = context
+            .peer
+            .peer_info()
+            .is_some_and(|info| info.capabilities.sampling.is_some());
+        if !supports_sampling {
+            return Ok(Self::tool_error(SAMPLING_UNSUPPORTED_MESSAGE));
+        }
+
+        let display = args[1..].join(" ");
+        let output = match Self::execute(args, &context).await {
+            Ok(output) => output,
+            Err(err) => return Ok(Self::tool_error(format!("Error: {err}"))),
+        };
+        let report = summarize::NumberedReport::new(&output.text);
+        let request = summarize::sampling_request(
+            &display,
+            focus.as_deref(),
+            &report,
+            summarize::summary_max_tokens(max_tokens),
+        )
+        .map_err(|err| {
+            McpError::internal_error(format!("Invalid sampling request: {err}"), None)
+        })?;
+
+        let result = match tokio::time::timeout(
+            SAMPLING_TIMEOUT,
+            context.peer.create_message(request),
+        )
+        .await
+        {
+            Ok(Ok(result)) => result,
+            Ok(Err(err)) => {
+                return Ok(Self::tool_error(format!(
+                    "Error: MCP sampling request failed: {err}"
+                )));
+            }
+            Err(_) => {
+                return Ok(Self::tool_error(format!(
+                    "Error: MCP sampling did not answer within {} seconds",
+                    SAMPLING_TIMEOUT.as_secs()
+                )));
+            }
+        };
+        let result = serde_json::to_value(&result).map_err(|err| {
+            McpError::internal_error(format!("Invalid sampling response: {err}"), None)
+        })?;
+        let Some(summary) = summarize::sampling_text(&result) else {
+            return Ok(Self::tool_error(
+                "Error: MCP sampling returned no text summary",
+            ));
+        };
+        let model = result.get("model").and_then(serde_json::Value::as_str);
+        Ok(CallToolResult::success(vec![Content::text(
+            summarize::render_summary(&display, &summary, model, &report),
+        )]))
+    }
+}
+
+#[prompt_router]
+impl BioMcpServer {
+    /// Step-by-step workup of one variant: classification, population
+    /// frequency, predictions, somatic and therapeutic evidence, literature,
+    /// and trials.
+    #[prompt(name = "variant-workup")]
+    async fn variant_workup_prompt(
+        &self,
+        Parameters(args): Parameters<VariantWorkupArgs>,
+    ) -> Vec<PromptMessage> {
+        vec![PromptMessage::new_text(
+            PromptMessageRole::User,
+            prompts::variant_workup(&args),
+        )]
+    }
+
+    /// Finds recruiting trials for a patient's diagnosis, alteration, age, and
+    /// sex, then checks eligibility and sites for the best candidates.
+    #[prompt(name = "trial-matching")]
+    async fn trial_matching_prompt(
+        &self,
+        Parameters(args): Parameters<TrialMatchingArgs>,
+    ) -> Vec<PromptMessage> {
+        vec![PromptMessage::new_text(
+            PromptMessageRole::User,
+            prompts::trial_matching(&args),
+        )]
+    }
+
+    /// Reviews a drug's labeled warnings, interactions, FAERS adverse-event
+    /// reports, and pharmacogenomic dosing guidance.
+    #[prompt(name = "drug-safety-review")]
+    async fn drug_safety_review_prompt(
+        &self,
+        Parameters(args): Parameters<DrugSafetyReviewArgs>,
+    ) -> Vec<PromptMessage> {
+        vec![PromptMessage::new_text(
+            PromptMessageRole::User,
+            prompts::drug_safety_review(&args),
+        )]
+    }
 }
 
 #[tool_handler(router = self.tool_router)]
+#[prompt_handler]
 impl ServerHandler for BioMcpServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo::new(
             ServerCapabilities::builder()
                 .enable_tools()
+                .enable_prompts()
                 .enable_resources()
                 .build(),
         )
@@ -159,7 +562,15 @@ impl ServerHandler for BioMcpServer {
              ClinVar, gnomAD, OncoKB, Reactome, UniProt, PharmGKB, OpenFDA, and more). \
              Use the `biomcp` tool to run BioMCP CLI commands. \
              Start with `biomcp list` for a command reference, \
-             or `biomcp skill` for guided investigation workflows."
+             or `biomcp skill` for guided investigation workflows. \
+             When context is tight and your client supports sampling, \
+             `biomcp_summarize` returns a cited summary of a large report instead. \
+             `biomcp_sections` lists each entity's `get` sections with sources, latency, \
+             and API-key needs so you can request only what a question requires. \
+             `biomcp_dossier` scores the evidence linking one gene to one disease. \
+             `raw_query` returns upstream JSON from whitelisted endpoints for unmapped fields. \
+             Prompts (`variant-workup`, `trial-matching`, `drug-safety-review`) lay out \
+             recommended multi-step workflows."
                 .to_string(),
         )
     }
@@ -167,10 +578,11 @@ impl ServerHandler for BioMcpServer {
     fn list_resources(
         &self,
         _request: Option<PaginatedRequestParams>,
-        _context: RequestContext<RoleServer>,
+        context: RequestContext<RoleServer>,
     ) -> impl Future<Output = Result<ListResourcesResult, McpError>> + Send + '_ {
+        let local_cache = tenant::request_tenant(&context).is_none();
         std::future::ready(Ok(ListResourcesResult::with_all_items(
-            build_resource_list()
+            build_resource_list(local_cache)
                 .into_iter()
                 .map(|r| r.no_annotation())
                 .collect(),
@@ -180,13 +592,17 @@ impl ServerHandler for BioMcpServer {
     fn read_resource(
         &self,
         request: ReadResourceRequestParams,
-        _context: RequestContext<RoleServer>,
+        context: RequestContext<RoleServer>,
     ) -> impl Future<Output = Result<ReadResourceResult, McpError>> + Send + '_ {
-        std::future::ready(read_resource_markdown(&request.uri))
+        let local_cache = tenant::request_tenant(&context).is_none();
+        std::future::ready(read_resource_contents(&request.uri, local_cache))
     }
 }
 
-fn read_resource_markdown(uri: &str) -> Result<ReadResourceResult, McpError> {
+/// Reads a resource. Full texts and gene snapshots are shared by everyone
+/// using the cache directory, so they are served only when `local_cache` is
+/// set, i.e. outside `serve-http` tenancy.
+fn read_resource_contents(uri: &str, local_cache: bool) -> Result<ReadResourceResult, McpError> {
     if uri == RESOURCE_HELP_URI {
         let content = crate::cli::skill::show_overview()
             .map_err(|e| McpError::internal_error(format!("Failed to render {uri}: {e}"), None))?;
@@ -199,13 +615,44 @@ fn read_resource_markdown(uri: &str) -> Result<ReadResourceResult, McpError> {
         return Ok(to_resource_result(uri, content));
     }
 
+    let read_error = |e: crate::error::BioMcpError| {
+        McpError::internal_error(format!("Failed to read {uri}: {e}"), None)
+    };
+
+    if local_cache
+        && let Some(id) = uri
+            .strip_prefix(RESOURCE_ARTICLE_PREFIX)
+            .and_then(|rest| rest.strip_suffix(RESOURCE_FULLTEXT_SUFFIX))
+        && let Some((_entry, content)) =
+            crate::entities::article::read_cached_full_text(id).map_err(read_error)?
+    {
+        return Ok(to_resource_result(uri, content));
+    }
+
+    if local_cache
+        && let Some(symbol) = uri
+            .strip_prefix(RESOURCE_GENE_PREFIX)
+            .and_then(|rest| rest.strip_suffix(RESOURCE_SNAPSHOT_SUFFIX))
+    {
+        match crate::entities::gene::latest_snapshot_json(symbol) {
+            Ok(Some(content)) => {
+                return Ok(ReadResourceResult::new(vec![
+                    ResourceContents::text(content, uri).with_mime_type("application/json"),
+                ]));
+            }
+            Ok(None) | Err(crate::error::BioMcpError::InvalidArgument(_)) => {}
+            Err(err) => return Err(read_error(err)),
+        }
+    }
+
     Err(McpError::resource_not_found(
         format!("Unknown resource: {uri}"),
         None,
     ))
 }
 
-fn build_resource_list() -> Vec<RawResource> {
+/// Lists resources; see [`read_resource_contents`] for `local_cache`.
+fn build_resource_list(local_cache: bool) -> Vec<RawResource> {
     let mut resources = vec![
         RawResource::new(RESOURCE_HELP_URI, "BioMCP Overview").with_mime_type("text/markdown"),
     ];
@@ -225,6 +672,39 @@ fn build_resource_list() -> Vec<RawResource> {
         }
     }
 
+    if !local_cache {
+        return resources;
+    }
+
+    // Downloaded full texts and stored gene snapshots come from the local
+    // cache; an unreadable cache only hides them.
+    match crate::entities::article::cached_full_texts() {
+        Ok(entries) => resources.extend(entries.iter().map(|entry| {
+            let id = entry.resource_id();
+            let name = if entry.title.is_empty() {
+                format!("Full text: {id}")
+            } else {
+                format!("Full text: {}", entry.title)
+            };
+            RawResource::new(
+                format!("{RESOURCE_ARTICLE_PREFIX}{id}{RESOURCE_FULLTEXT_SUFFIX}"),
+                name,
+            )
+            .with_mime_type("text/markdown")
+        })),
+        Err(err) => tracing::warn!("cached full texts unavailable for MCP resources: {err}"),
+    }
+    match crate::entities::gene::stored_snapshots() {
+        Ok(rows) => resources.extend(rows.into_iter().map(|(symbol, id)| {
+            RawResource::new(
+                format!("{RESOURCE_GENE_PREFIX}{symbol}{RESOURCE_SNAPSHOT_SUFFIX}"),
+                format!("Gene snapshot: {symbol} ({id})"),
+            )
+            .with_mime_type("application/json")
+        })),
+        Err(err) => tracing::warn!("gene snapshots unavailable for MCP resources: {err}"),
+    }
+
     resources
 }
 
@@ -256,7 +736,15 @@ async fn index_handler() -> Json<serde_json::Value> {
     }))
 }
 
+async fn metrics_handler(State(tenants): State<Arc<TenantDirectory>>) -> impl IntoResponse {
+    (
+        [(CONTENT_TYPE, "text/plain; version=0.0.4")],
+        tenants.render_metrics(),
+    )
+}
+
 pub async fn run_stdio() -> anyhow::Result<()> {
+    warmup::warm_up().await;
     let shutdown = CancellationToken::new();
 
     let cancel = shutdown.clone();
@@ -289,11 +777,17 @@ pub async fn run_stdio() -> anyhow::Result<()> {
     Ok(())
 }
 
-pub async fn run_http(host: &str, port: u16) -> anyhow::Result<()> {
+pub async fn run_http(
+    host: &str,
+    port: u16,
+    refresh_interval: Option<Duration>,
+) -> anyhow::Result<()> {
     let ip: std::net::IpAddr = host
         .parse()
         .map_err(|e| anyhow::anyhow!("Invalid host address: {e}"))?;
     let bind = std::net::SocketAddr::new(ip, port);
+    let tenants = TenantDirectory::load()?.map(Arc::new);
+    warmup::warm_up().await;
     let shutdown = CancellationToken::new();
 
     let service: StreamableHttpService<BioMcpServer, LocalSessionManager> =
@@ -307,8 +801,16 @@ pub async fn run_http(host: &str, port: u16) -> anyhow::Result<()> {
             },
         );
 
-    let router = Router::new()
-        .nest_service("/mcp", service)
+    let mut router = Router::new().nest_service("/mcp", service);
+    if let Some(tenants) = &tenants {
+        router = router
+            .layer(middleware::from_fn_with_state(
+                tenants.clone(),
+                tenant::require_tenant,
+            ))
+            .route("/metrics", get(metrics_handler).with_state(tenants.clone()));
+    }
+    let router = router
         .route("/health", get(health_handler))
         .route("/readyz", get(health_handler))
         .route("/", get(index_handler));
@@ -321,6 +823,20 @@ pub async fn run_http(host: &str, port: u16) -> anyhow::Result<()> {
     tracing::info!("  Health probe:   GET      http://{bind}/health");
     tracing::info!("  Ready probe:    GET      http://{bind}/readyz");
     tracing::info!("  Status:         GET      http://{bind}/");
+    if let Some(tenants) = &tenants {
+        tracing::info!("  Tenant metrics: GET      http://{bind}/metrics");
+        tracing::info!(
+            "  Tenancy:        {} tenant(s); /mcp requires a bearer token",
+            tenants.len()
+        );
+    }
+    if let Some(interval) = refresh_interval {
+        tracing::info!(
+            "  Cache refresh:  every {}",
+            humantime::format_duration(interval)
+        );
+        refresh::spawn(interval);
+    }
 
     let cancel = shutdown.clone();
     tokio::spawn(async move {
@@ -340,11 +856,15 @@ pub async fn run_http(host: &str, port: u16) -> anyhow::Result<()> {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use axum::Json;
 
     use super::{
-        CACHE_FAMILY_MCP_REJECTION_MESSAGE, GENERIC_MCP_REJECTION_MESSAGE, index_handler,
-        is_allowed_mcp_command, mcp_rejection_message,
+        ANNOTATE_MCP_REJECTION_MESSAGE, BioMcpServer, CACHE_FAMILY_MCP_REJECTION_MESSAGE,
+        GENERIC_MCP_REJECTION_MESSAGE, PGX_VCF_MCP_REJECTION_MESSAGE,
+        REPURPOSE_MCP_REJECTION_MESSAGE, build_resource_list, index_handler,
+        is_allowed_mcp_command, mcp_rejection_message, read_resource_contents, with_output_budget,
     };
 
     #[test]
@@ -375,6 +895,66 @@ mod tests {
             "skill".into(),
             "variant-to-treatment".into()
         ]));
+        assert!(is_allowed_mcp_command(&[
+            "biomcp".into(),
+            "validate".into(),
+            "variant".into(),
+            "NM_000546.6:c.215C>G".into()
+        ]));
+        assert!(is_allowed_mcp_command(&[
+            "biomcp".into(),
+            "compare".into(),
+            "variants".into(),
+            "BRAF V600E".into(),
+            "BRAF V600K".into()
+        ]));
+        assert!(is_allowed_mcp_command(&[
+            "biomcp".into(),
+            "ontology".into(),
+            "disease".into(),
+            "MONDO:0005105".into(),
+            "--children".into()
+        ]));
+        assert!(is_allowed_mcp_command(&[
+            "biomcp".into(),
+            "network".into(),
+            "protein".into(),
+            "P15056".into(),
+            "--format".into(),
+            "graphml".into()
+        ]));
+        assert!(is_allowed_mcp_command(&[
+            "biomcp".into(),
+            "raw".into(),
+            "mygene".into(),
+            "query".into(),
+            "--param".into(),
+            "q=symbol:BRAF".into()
+        ]));
+        assert!(is_allowed_mcp_command(&[
+            "biomcp".into(),
+            "dossier".into(),
+            "--gene".into(),
+            "BRAF".into(),
+            "--disease".into(),
+            "melanoma".into()
+        ]));
+        assert!(is_allowed_mcp_command(&[
+            "biomcp".into(),
+            "sections".into(),
+            "gene".into()
+        ]));
+        assert!(is_allowed_mcp_command(&[
+            "biomcp".into(),
+            "schema".into(),
+            "variant".into()
+        ]));
+        assert!(is_allowed_mcp_command(&[
+            "biomcp".into(),
+            "selftest".into(),
+            "--sources".into(),
+            "mygene,ctgov".into()
+        ]));
         assert!(is_allowed_mcp_command(&[
             "biomcp".into(),
             "study".into(),
@@ -521,12 +1101,167 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pgx_panel_is_allowed_over_mcp_without_local_vcf() {
+        let panel = vec![
+            "biomcp".to_string(),
+            "pgx".into(),
+            "panel".into(),
+            "--genes".into(),
+            "CYP2D6,TPMT".into(),
+        ];
+        assert!(is_allowed_mcp_command(&panel));
+
+        for vcf in [
+            vec!["--vcf".to_string(), "/etc/passwd".into()],
+            vec!["--vcf=/home/user/sample.vcf".to_string()],
+        ] {
+            let mut args = panel.clone();
+            args.extend(vcf);
+            assert!(!is_allowed_mcp_command(&args));
+            assert_eq!(mcp_rejection_message(&args), PGX_VCF_MCP_REJECTION_MESSAGE);
+        }
+    }
+
+    #[test]
+    fn jobs_are_allowed_over_mcp_only_for_mcp_safe_commands() {
+        let jobs = |tokens: &[&str]| {
+            std::iter::once("biomcp")
+                .chain(tokens.iter().copied())
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert!(is_allowed_mcp_command(&jobs(&["jobs", "status"])));
+        assert!(is_allowed_mcp_command(&jobs(&["jobs", "result", "3"])));
+        assert!(is_allowed_mcp_command(&jobs(&[
+            "jobs", "submit", "--", "search", "trial", "-c", "melanoma"
+        ])));
+        assert!(!is_allowed_mcp_command(&jobs(&[
+            "jobs", "worker", "--dir", "/tmp"
+        ])));
+
+        let vcf = jobs(&[
+            "jobs",
+            "submit",
+            "--",
+            "pgx",
+            "panel",
+            "--vcf",
+            "/etc/passwd",
+        ]);
+        assert!(!is_allowed_mcp_command(&vcf));
+        assert_eq!(mcp_rejection_message(&vcf), PGX_VCF_MCP_REJECTION_MESSAGE);
+        let annotate = jobs(&["jobs", "submit", "--", "annotate", "vcf", "sample.vcf"]);
+        assert!(!is_allowed_mcp_command(&annotate));
+        assert_eq!(
+            mcp_rejection_message(&annotate),
+            ANNOTATE_MCP_REJECTION_MESSAGE
+        );
+    }
+
+    #[test]
+    fn report_panel_is_allowed_over_mcp_without_local_file() {
+        let panel = vec![
+            "biomcp".to_string(),
+            "report".into(),
+            "panel".into(),
+            "--genes".into(),
+            "BRCA1,BRCA2,TP53".into(),
+        ];
+        assert!(is_allowed_mcp_command(&panel));
+
+        for file in [
+            vec!["--file".to_string(), "/etc/passwd".into()],
+            vec!["--file=/home/user/panel.txt".to_string()],
+        ] {
+            let mut args = panel.clone();
+            args.extend(file);
+            assert!(!is_allowed_mcp_command(&args));
+            assert_eq!(
+                mcp_rejection_message(&args),
+                REPORT_FILE_MCP_REJECTION_MESSAGE
+            );
+        }
+    }
+
+    #[test]
+    fn annotate_is_rejected_over_mcp_as_local_file_access() {
+        let args = vec![
+            "biomcp".to_string(),
+            "annotate".into(),
+            "deg".into(),
+            "--file".into(),
+            "/home/user/results.csv".into(),
+        ];
+        assert!(!is_allowed_mcp_command(&args));
+        assert_eq!(mcp_rejection_message(&args), ANNOTATE_MCP_REJECTION_MESSAGE);
+    }
+
+    #[test]
+    fn repurpose_is_rejected_over_mcp_as_local_file_access() {
+        let args = vec![
+            "biomcp".to_string(),
+            "repurpose".into(),
+            "--up".into(),
+            "/home/user/up.txt".into(),
+            "--down".into(),
+            "/home/user/down.txt".into(),
+        ];
+        assert!(!is_allowed_mcp_command(&args));
+        assert_eq!(
+            mcp_rejection_message(&args),
+            REPURPOSE_MCP_REJECTION_MESSAGE
+        );
+    }
+
+    #[test]
+    fn match_trials_is_rejected_over_mcp_as_local_file_access() {
+        let args = vec![
+            "biomcp".to_string(),
+            "match".into(),
+            "trials".into(),
+            "--profile".into(),
+            "/home/user/patient.yaml".into(),
+        ];
+        assert!(!is_allowed_mcp_command(&args));
+        assert_eq!(mcp_rejection_message(&args), MATCH_MCP_REJECTION_MESSAGE);
+    }
+
     #[test]
     fn generic_mcp_rejection_message_stays_read_only_for_mutating_commands() {
         let args = vec!["biomcp".into(), "update".into()];
         assert_eq!(mcp_rejection_message(&args), GENERIC_MCP_REJECTION_MESSAGE);
     }
 
+    #[test]
+    fn parse_tool_command_normalizes_prefix_and_applies_allowlist() {
+        let args = BioMcpServer::parse_tool_command("biomcp get gene BRAF all")
+            .expect("read-only command should parse");
+        assert_eq!(args, vec!["biomcp", "get", "gene", "BRAF", "all"]);
+        let args =
+            BioMcpServer::parse_tool_command("batch gene BRAF,KRAS").expect("prefix is optional");
+        assert_eq!(args[1], "batch");
+
+        assert!(BioMcpServer::parse_tool_command("cache path").is_err());
+        assert!(BioMcpServer::parse_tool_command("get gene 'BRAF").is_err());
+        assert!(BioMcpServer::parse_tool_command(&"x".repeat(1025)).is_err());
+    }
+
+    #[test]
+    fn sections_manifest_json_covers_one_or_all_entities() {
+        let gene: serde_json::Value =
+            serde_json::from_str(&sections_manifest_json(Some(" gene ")).expect("gene"))
+                .expect("json");
+        assert_eq!(gene["entity"], "gene");
+
+        let all: serde_json::Value =
+            serde_json::from_str(&sections_manifest_json(None).expect("all")).expect("json");
+        assert!(all.as_array().is_some_and(|rows| rows.len() > 1));
+
+        let err = sections_manifest_json(Some("transcript")).expect_err("unknown entity");
+        assert!(err.to_string().contains("Use one of:"));
+    }
+
     #[tokio::test]
     async fn index_handler_reports_streamable_http_surface() {
         let Json(payload) = index_handler().await;
@@ -534,4 +1269,147 @@ mod tests {
         assert_eq!(payload["transport"], "streamable-http");
         assert_eq!(payload["mcp"], "/mcp");
     }
+
+    #[test]
+    fn cached_resource_uris_without_local_data_are_not_found() {
+        for uri in [
+            "biomcp://article/PMC0000000/fulltext",
+            "biomcp://gene/NOTAGENE0/snapshot",
+            "biomcp://gene/../snapshot",
+            "biomcp://article/PMC0000000",
+        ] {
+            let err = read_resource_contents(uri, true).expect_err("resource should be missing");
+            assert_eq!(
+                err.code,
+                rmcp::model::ErrorCode::RESOURCE_NOT_FOUND,
+                "{uri}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn cached_resources_are_hidden_from_tenants() {
+        let _lock = crate::test_support::env_lock().lock().await;
+        let root =
+            std::env::temp_dir().join(format!("biomcp-mcp-resources-{}", std::process::id()));
+        let snapshots = root.join("snapshots").join("gene").join("BRAF");
+        std::fs::create_dir_all(&snapshots).expect("snapshot dir");
+        std::fs::write(snapshots.join("20261016T093000Z.json"), "{}").expect("snapshot");
+        // SAFETY: serialized by the shared env lock.
+        unsafe { std::env::set_var("BIOMCP_CACHE_DIR", &root) };
+
+        let uri = "biomcp://gene/BRAF/snapshot";
+        let listed = |local_cache| {
+            build_resource_list(local_cache)
+                .iter()
+                .any(|resource| resource.uri == uri)
+        };
+        let local = (listed(true), read_resource_contents(uri, true).is_ok());
+        let tenant = (
+            listed(false),
+            read_resource_contents(uri, false).map_err(|err| err.code),
+        );
+
+        // SAFETY: serialized by the shared env lock.
+        unsafe { std::env::remove_var("BIOMCP_CACHE_DIR") };
+        let _ = std::fs::remove_dir_all(&root);
+        assert_eq!(local, (true, true));
+        assert!(!tenant.0);
+        assert_eq!(
+            tenant.1.expect_err("tenant read should miss"),
+            rmcp::model::ErrorCode::RESOURCE_NOT_FOUND
+        );
+    }
+
+    #[test]
+    fn with_output_budget_inserts_global_flag_once() {
+        let mut args = vec!["biomcp".to_string(), "search".into(), "trial".into()];
+        with_output_budget(&mut args, 2000);
+        assert_eq!(
+            args,
+            vec!["biomcp", "--max-output-tokens", "2000", "search", "trial"]
+        );
+
+        let mut args = vec![
+            "biomcp".to_string(),
+            "--max-output-tokens=500".into(),
+            "get".into(),
+        ];
+        with_output_budget(&mut args, 2000);
+        assert_eq!(args.len(), 3);
+    }
+
+    #[test]
+    fn prompts_are_listed_and_only_suggest_mcp_allowed_commands() {
+        use clap::Parser;
+
+        use super::prompts::{self, DrugSafetyReviewArgs, TrialMatchingArgs, VariantWorkupArgs};
+
+        let mut names = BioMcpServer::new()
+            .prompt_router
+            .list_all()
+            .into_iter()
+            .map(|prompt| prompt.name.to_string())
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(
+            names,
+            ["drug-safety-review", "trial-matching", "variant-workup"]
+        );
+
+        let texts = [
+            prompts::variant_workup(&VariantWorkupArgs {
+                variant: "BRAF V600E".into(),
+                disease: Some("melanoma".into()),
+            }),
+            prompts::variant_workup(&VariantWorkupArgs {
+                variant: "rs113488022".into(),
+                disease: None,
+            }),
+            prompts::trial_matching(&TrialMatchingArgs {
+                condition: "non-small cell lung cancer".into(),
+                mutation: Some("EGFR L858R".into()),
+                age: Some("64".into()),
+                sex: Some("Female".into()),
+            }),
+            prompts::drug_safety_review(&DrugSafetyReviewArgs {
+                drug: "pembrolizumab".into(),
+                focus: None,
+            }),
+        ];
+        for text in &texts {
+            let commands = text
+                .split('`')
+                .skip(1)
+                .step_by(2)
+                .filter(|span| span.contains(' '))
+                .collect::<Vec<_>>();
+            assert!(commands.len() >= 4, "{text}");
+            for command in commands {
+                let args = std::iter::once("biomcp".to_string())
+                    .chain(shlex::split(command).expect("prompt command should split"))
+                    .collect::<Vec<_>>();
+                assert!(is_allowed_mcp_command(&args), "{command}");
+                crate::cli::Cli::try_parse_from(&args)
+                    .unwrap_or_else(|err| panic!("{command} should parse: {err}"));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn run_http_starts_cache_refresh_tracking() {
+        let server = tokio::spawn(super::run_http(
+            "127.0.0.1",
+            0,
+            Some(Duration::from_secs(3600)),
+        ));
+        let tracking = async {
+            while !super::refresh::is_tracking() {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        };
+        let started = tokio::time::timeout(Duration::from_secs(10), tracking).await;
+        server.abort();
+        assert!(started.is_ok(), "serve-http should start cache refresh");
+    }
 }
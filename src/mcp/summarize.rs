@@ -0,0 +1,360 @@
+//! Report condensation through MCP sampling.
+//!
+//! `biomcp_summarize` runs a read-only BioMCP command, numbers the report
+//! lines, and asks the connected client's model (via `sampling/createMessage`)
+//! for a short summary that cites `[L<n>]` spans. BioMCP never bundles a model
+//! of its own; citations are checked against the report before they are echoed
+//! back so the caller can trust every quoted span.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use rmcp::model::CreateMessageRequestParams;
+use serde_json::{Value, json};
+
+const DEFAULT_SUMMARY_TOKENS: u32 = 600;
+const MAX_SUMMARY_TOKENS: u32 = 2000;
+const MIN_SUMMARY_TOKENS: u32 = 100;
+const MAX_REPORT_CHARS: usize = 100_000;
+const MAX_CITED_SPANS: usize = 20;
+const MAX_SPAN_LINES: usize = 3;
+const MAX_EXCERPT_CHARS: usize = 200;
+
+const SYSTEM_PROMPT: &str = "You condense biomedical reports produced by the BioMCP CLI. \
+Every report line is prefixed with its line number as `L<n>:`. Write a concise summary \
+in markdown bullet points that answers the stated focus when one is given. Use only facts \
+stated in the report; do not add outside knowledge. End every bullet with the report lines \
+that support it, written as [L12] or [L12-L15]. Keep identifiers (gene symbols, variant IDs, \
+PMIDs, NCT IDs) exactly as written.";
+
+/// Report lines with stable 1-based numbering for citation checks.
+pub(super) struct NumberedReport<'a> {
+    lines: Vec<&'a str>,
+    truncated: bool,
+}
+
+impl<'a> NumberedReport<'a> {
+    pub(super) fn new(report: &'a str) -> Self {
+        let mut lines = Vec::new();
+        let mut chars = 0usize;
+        let mut truncated = false;
+        for line in report.lines() {
+            chars += line.len() + 1;
+            if chars > MAX_REPORT_CHARS {
+                truncated = true;
+                break;
+            }
+            lines.push(line);
+        }
+        Self { lines, truncated }
+    }
+
+    pub(super) fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub(super) fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    fn numbered(&self) -> String {
+        let mut out = String::new();
+        for (idx, line) in self.lines.iter().enumerate() {
+            out.push_str(&format!("L{}: {line}\n", idx + 1));
+        }
+        out
+    }
+
+    fn excerpt(&self, start: usize, end: usize) -> String {
+        let last = end.min(start + MAX_SPAN_LINES - 1);
+        let joined = self.lines[start - 1..last]
+            .iter()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" / ");
+        if joined.chars().count() <= MAX_EXCERPT_CHARS {
+            return joined;
+        }
+        let mut out = joined.chars().take(MAX_EXCERPT_CHARS).collect::<String>();
+        out.push('…');
+        out
+    }
+}
+
+/// Clamps the caller's summary budget to the supported range.
+pub(super) fn summary_max_tokens(requested: Option<u32>) -> u32 {
+    requested
+        .unwrap_or(DEFAULT_SUMMARY_TOKENS)
+        .clamp(MIN_SUMMARY_TOKENS, MAX_SUMMARY_TOKENS)
+}
+
+fn user_prompt(command: &str, focus: Option<&str>, report: &NumberedReport<'_>) -> String {
+    let mut prompt = format!("Command: biomcp {command}\n");
+    if let Some(focus) = focus.map(str::trim).filter(|focus| !focus.is_empty()) {
+        prompt.push_str(&format!("Focus: {focus}\n"));
+    }
+    prompt.push_str("\nReport:\n");
+    prompt.push_str(&report.numbered());
+    if report.truncated() {
+        prompt.push_str(&format!(
+            "\n(Report truncated after L{}; do not speculate about omitted lines.)\n",
+            report.line_count()
+        ));
+    }
+    prompt
+}
+
+/// Builds the `sampling/createMessage` request for one report.
+pub(super) fn sampling_request(
+    command: &str,
+    focus: Option<&str>,
+    report: &NumberedReport<'_>,
+    max_tokens: u32,
+) -> Result<CreateMessageRequestParams, serde_json::Error> {
+    serde_json::from_value(json!({
+        "messages": [{
+            "role": "user",
+            "content": {"type": "text", "text": user_prompt(command, focus, report)}
+        }],
+        "systemPrompt": SYSTEM_PROMPT,
+        "includeContext": "none",
+        "temperature": 0.2,
+        "maxTokens": max_tokens,
+        "modelPreferences": {
+            "costPriority": 0.7,
+            "speedPriority": 0.7,
+            "intelligencePriority": 0.4
+        }
+    }))
+}
+
+/// Extracts the text blocks from a serialized `CreateMessageResult`.
+pub(super) fn sampling_text(result: &Value) -> Option<String> {
+    let blocks = match result.get("content")? {
+        Value::Array(items) => items.iter().collect::<Vec<_>>(),
+        other => vec![other],
+    };
+    let text = blocks
+        .into_iter()
+        .filter(|block| block.get("type").and_then(Value::as_str) == Some("text"))
+        .filter_map(|block| block.get("text").and_then(Value::as_str))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct CitedSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl CitedSpan {
+    fn label(&self) -> String {
+        if self.start == self.end {
+            format!("L{}", self.start)
+        } else {
+            format!("L{}-L{}", self.start, self.end)
+        }
+    }
+}
+
+fn citation_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"\[L(\d+)(?:\s*[-–]\s*L?(\d+))?\]").expect("valid citation regex")
+    })
+}
+
+/// Splits summary citations into spans that exist in the report and labels that do not.
+pub(super) fn cited_spans(
+    summary: &str,
+    report: &NumberedReport<'_>,
+) -> (Vec<CitedSpan>, Vec<String>) {
+    let mut valid = Vec::new();
+    let mut invalid = Vec::new();
+    let mut seen = HashSet::new();
+    for caps in citation_re().captures_iter(summary) {
+        let start = caps[1].parse::<usize>().ok();
+        let end = caps
+            .get(2)
+            .map(|end| end.as_str().parse::<usize>().ok())
+            .unwrap_or(start);
+        let label = caps[0].to_string();
+        if !seen.insert(label.clone()) {
+            continue;
+        }
+        match (start, end) {
+            (Some(start), Some(end))
+                if start >= 1 && start <= end && end <= report.line_count() =>
+            {
+                valid.push(CitedSpan { start, end });
+            }
+            _ => invalid.push(label),
+        }
+    }
+    (valid, invalid)
+}
+
+/// Renders the tool response: summary, verified citation spans, and provenance.
+pub(super) fn render_summary(
+    command: &str,
+    summary: &str,
+    model: Option<&str>,
+    report: &NumberedReport<'_>,
+) -> String {
+    let (spans, invalid) = cited_spans(summary, report);
+    let mut out = format!("# Summary: `biomcp {command}`\n\n{}\n", summary.trim());
+
+    out.push_str("\n## Cited spans\n\n");
+    if spans.is_empty() {
+        out.push_str("The summary cited no report lines.\n");
+    }
+    for span in spans.iter().take(MAX_CITED_SPANS) {
+        out.push_str(&format!(
+            "- {}: {}\n",
+            span.label(),
+            report.excerpt(span.start, span.end)
+        ));
+    }
+    if spans.len() > MAX_CITED_SPANS {
+        out.push_str(&format!(
+            "- … {} more cited spans omitted\n",
+            spans.len() - MAX_CITED_SPANS
+        ));
+    }
+    if !invalid.is_empty() {
+        out.push_str(&format!(
+            "\nCitations outside the report (treat the matching statements as unsupported): {}\n",
+            invalid.join(", ")
+        ));
+    }
+
+    let model = model
+        .map(str::trim)
+        .filter(|model| !model.is_empty())
+        .unwrap_or("the client model");
+    out.push_str(&format!(
+        "\n_Summarized by {model} through MCP sampling from a {}-line report{}. \
+         Run `biomcp {command}` with the `biomcp` tool for the full output._\n",
+        report.line_count(),
+        if report.truncated() {
+            " (truncated)"
+        } else {
+            ""
+        }
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPORT: &str =
+        "# BRAF\n\nSymbol: BRAF\nName: B-Raf proto-oncogene\n\n## Variants\n| V600E | Pathogenic |";
+
+    #[test]
+    fn numbered_report_prefixes_lines_and_truncates_large_reports() {
+        let report = NumberedReport::new(REPORT);
+        assert_eq!(report.line_count(), 7);
+        assert!(!report.truncated());
+        assert!(
+            report
+                .numbered()
+                .starts_with("L1: # BRAF\nL2: \nL3: Symbol: BRAF\n")
+        );
+
+        let large = format!("{}\n", "x".repeat(1000)).repeat(200);
+        let report = NumberedReport::new(&large);
+        assert!(report.truncated());
+        assert_eq!(report.line_count(), 99);
+    }
+
+    #[test]
+    fn cited_spans_checks_lines_against_the_report() {
+        let report = NumberedReport::new(REPORT);
+        let (spans, invalid) = cited_spans(
+            "- BRAF is B-Raf [L3-L4]\n- V600E is pathogenic [L7]\n- repeated [L7]\n- invented [L40] [L5-L2]",
+            &report,
+        );
+        assert_eq!(
+            spans,
+            vec![
+                CitedSpan { start: 3, end: 4 },
+                CitedSpan { start: 7, end: 7 }
+            ]
+        );
+        assert_eq!(invalid, vec!["[L40]".to_string(), "[L5-L2]".to_string()]);
+    }
+
+    #[test]
+    fn render_summary_quotes_cited_spans_and_flags_unsupported_citations() {
+        let report = NumberedReport::new(REPORT);
+        let out = render_summary(
+            "get gene BRAF",
+            "- BRAF encodes B-Raf [L3-L4]\n- Unsupported claim [L99]",
+            Some("claude-test"),
+            &report,
+        );
+        assert!(out.starts_with("# Summary: `biomcp get gene BRAF`"));
+        assert!(out.contains("- L3-L4: Symbol: BRAF / Name: B-Raf proto-oncogene"));
+        assert!(out.contains("Citations outside the report"));
+        assert!(out.contains("[L99]"));
+        assert!(
+            out.contains("Summarized by claude-test through MCP sampling from a 7-line report")
+        );
+    }
+
+    #[test]
+    fn sampling_text_reads_single_and_multi_block_content() {
+        let single = json!({
+            "model": "m",
+            "role": "assistant",
+            "content": {"type": "text", "text": " summary "}
+        });
+        assert_eq!(sampling_text(&single).as_deref(), Some("summary"));
+
+        let multi = json!({
+            "role": "assistant",
+            "content": [
+                {"type": "text", "text": "first"},
+                {"type": "image", "data": "", "mimeType": "image/png"},
+                {"type": "text", "text": "second"}
+            ]
+        });
+        assert_eq!(sampling_text(&multi).as_deref(), Some("first\nsecond"));
+        assert_eq!(sampling_text(&json!({"role": "assistant"})), None);
+    }
+
+    #[test]
+    fn sampling_request_carries_prompt_focus_and_budget() {
+        let report = NumberedReport::new(REPORT);
+        let request = sampling_request(
+            "get gene BRAF",
+            Some("actionable variants"),
+            &report,
+            summary_max_tokens(Some(50_000)),
+        )
+        .expect("sampling request");
+        let value = serde_json::to_value(&request).expect("serialize request");
+        assert_eq!(value["maxTokens"], MAX_SUMMARY_TOKENS);
+        assert_eq!(value["includeContext"], "none");
+        let text = value["messages"][0]["content"]["text"]
+            .as_str()
+            .expect("prompt text");
+        assert!(text.contains("Command: biomcp get gene BRAF"));
+        assert!(text.contains("Focus: actionable variants"));
+        assert!(text.contains("L7: | V600E | Pathogenic |"));
+    }
+
+    #[test]
+    fn summary_max_tokens_applies_default_and_bounds() {
+        assert_eq!(summary_max_tokens(None), DEFAULT_SUMMARY_TOKENS);
+        assert_eq!(summary_max_tokens(Some(10)), MIN_SUMMARY_TOKENS);
+        assert_eq!(summary_max_tokens(Some(900)), 900);
+    }
+}
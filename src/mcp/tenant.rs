@@ -0,0 +1,356 @@
+//! Multi-tenant mode for `serve-http`.
+//!
+//! Tenants are declared in `config.toml` under `[tenants.<name>]`. Once any are
+//! configured, every `/mcp` request must carry `Authorization: Bearer <token>`
+//! matching one of them. Tool calls then run in that tenant's scope (its own
+//! upstream keys and HTTP cache namespace, see `sources::tenant`) and are
+//! metered per tenant on `GET /metrics`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use axum::extract::{Request, State};
+use axum::http::header::{AUTHORIZATION, WWW_AUTHENTICATE};
+use axum::http::{HeaderMap, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use rmcp::RoleServer;
+use rmcp::service::RequestContext;
+use sha2::{Digest, Sha256};
+
+use crate::config::TenantToml;
+use crate::error::BioMcpError;
+use crate::sources::request_stats::{self, CacheOutcome, RequestStats};
+use crate::sources::tenant::{TENANT_KEY_ENVS, TenantScope, with_tenant};
+
+/// Running totals for one tenant.
+#[derive(Debug, Default)]
+struct TenantUsage {
+    tool_calls: AtomicU64,
+    tool_errors: AtomicU64,
+    upstream_requests: AtomicU64,
+    cache_hits: AtomicU64,
+    upstream_bytes: AtomicU64,
+}
+
+impl TenantUsage {
+    fn record(&self, ok: bool, stats: &RequestStats) {
+        self.tool_calls.fetch_add(1, Ordering::Relaxed);
+        if !ok {
+            self.tool_errors.fetch_add(1, Ordering::Relaxed);
+        }
+        self.upstream_requests
+            .fetch_add(stats.records.len() as u64, Ordering::Relaxed);
+        self.cache_hits
+            .fetch_add(stats.count(CacheOutcome::Hit) as u64, Ordering::Relaxed);
+        self.upstream_bytes
+            .fetch_add(stats.bytes_transferred(), Ordering::Relaxed);
+    }
+}
+
+type UsageCounter = fn(&TenantUsage) -> &AtomicU64;
+
+const METRICS: &[(&str, &str, UsageCounter)] = &[
+    (
+        "biomcp_tenant_tool_calls_total",
+        "Tool calls handled for the tenant.",
+        |usage| &usage.tool_calls,
+    ),
+    (
+        "biomcp_tenant_tool_errors_total",
+        "Tool calls that returned an error.",
+        |usage| &usage.tool_errors,
+    ),
+    (
+        "biomcp_tenant_upstream_requests_total",
+        "Upstream HTTP requests made for the tenant, including cache hits.",
+        |usage| &usage.upstream_requests,
+    ),
+    (
+        "biomcp_tenant_cache_hits_total",
+        "Upstream requests served from the tenant's cache namespace.",
+        |usage| &usage.cache_hits,
+    ),
+    (
+        "biomcp_tenant_upstream_bytes_total",
+        "Response bytes fetched from upstream APIs for the tenant.",
+        |usage| &usage.upstream_bytes,
+    ),
+];
+
+#[derive(Debug)]
+pub(crate) struct Tenant {
+    scope: Arc<TenantScope>,
+    usage: TenantUsage,
+}
+
+impl Tenant {
+    /// Runs a tool call as this tenant and meters it.
+    pub(crate) async fn run<F, T, E>(&self, fut: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+    {
+        let (result, stats) = with_tenant(self.scope.clone(), request_stats::collect(fut)).await;
+        self.usage.record(result.is_ok(), &stats);
+        result
+    }
+
+    /// Keys and cache namespace without metering, for server-initiated work.
+    pub(crate) fn scope(&self) -> Arc<TenantScope> {
+        self.scope.clone()
+    }
+}
+
+/// Configured tenants, looked up by bearer token digest.
+#[derive(Debug)]
+pub(crate) struct TenantDirectory {
+    tenants: Vec<Arc<Tenant>>,
+    by_token: HashMap<[u8; 32], Arc<Tenant>>,
+}
+
+impl TenantDirectory {
+    /// Builds the directory from `[tenants]`; `None` when tenancy is off.
+    pub(crate) fn load() -> Result<Option<Self>, BioMcpError> {
+        let config = crate::config::load_user_config()?;
+        Self::from_config(&config.tenants, |name| std::env::var(name).ok())
+    }
+
+    fn from_config(
+        config: &BTreeMap<String, TenantToml>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Option<Self>, BioMcpError> {
+        if config.is_empty() {
+            return Ok(None);
+        }
+        let path = crate::config::config_file_path();
+        let invalid = |message: String| crate::config::invalid_config(path.as_deref(), message);
+        let secret = |tenant: &str, var: &str| {
+            env(var)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| invalid(format!("tenant `{tenant}` needs {var} to be set")))
+        };
+
+        let mut directory = Self {
+            tenants: Vec::with_capacity(config.len()),
+            by_token: HashMap::with_capacity(config.len()),
+        };
+        for (name, entry) in config {
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+            {
+                return Err(invalid(format!(
+                    "tenant name `{name}` must use letters, digits, `-`, or `_`"
+                )));
+            }
+            let token = secret(name, &entry.token_env)?;
+            let mut keys = BTreeMap::new();
+            for (key, var) in &entry.keys_env {
+                if !TENANT_KEY_ENVS.contains(&key.as_str()) {
+                    return Err(invalid(format!(
+                        "tenant `{name}` sets unknown key {key}; expected one of {}",
+                        TENANT_KEY_ENVS.join(", ")
+                    )));
+                }
+                keys.insert(key.clone(), secret(name, var)?);
+            }
+
+            let tenant = Arc::new(Tenant {
+                scope: Arc::new(TenantScope::new(name.clone(), keys)),
+                usage: TenantUsage::default(),
+            });
+            if directory
+                .by_token
+                .insert(token_digest(&token), tenant.clone())
+                .is_some()
+            {
+                return Err(invalid(format!(
+                    "tenant `{name}` reuses another tenant's token"
+                )));
+            }
+            directory.tenants.push(tenant);
+        }
+        Ok(Some(directory))
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.tenants.len()
+    }
+
+    fn authenticate(&self, headers: &HeaderMap) -> Option<Arc<Tenant>> {
+        let value = headers.get(AUTHORIZATION)?.to_str().ok()?;
+        let (scheme, token) = value.trim().split_once(' ')?;
+        if !scheme.eq_ignore_ascii_case("bearer") {
+            return None;
+        }
+        self.by_token.get(&token_digest(token.trim())).cloned()
+    }
+
+    /// Prometheus text exposition of per-tenant usage.
+    pub(crate) fn render_metrics(&self) -> String {
+        let mut out = String::new();
+        for (metric, help, counter) in METRICS {
+            out.push_str(&format!(
+                "# HELP {metric} {help}\n# TYPE {metric} counter\n"
+            ));
+            for tenant in &self.tenants {
+                out.push_str(&format!(
+                    "{metric}{{tenant=\"{}\"}} {}\n",
+                    tenant.scope.name(),
+                    counter(&tenant.usage).load(Ordering::Relaxed)
+                ));
+            }
+        }
+        out
+    }
+}
+
+// Tokens are compared by digest so lookups do not leak matching prefixes.
+fn token_digest(token: &str) -> [u8; 32] {
+    Sha256::digest(token.as_bytes()).into()
+}
+
+/// Rejects `/mcp` requests without a known tenant token and tags the rest.
+pub(crate) async fn require_tenant(
+    State(directory): State<Arc<TenantDirectory>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    match directory.authenticate(request.headers()) {
+        Some(tenant) => {
+            request.extensions_mut().insert(tenant);
+            next.run(request).await
+        }
+        None => (
+            StatusCode::UNAUTHORIZED,
+            [(WWW_AUTHENTICATE, "Bearer")],
+            "missing or unknown tenant token\n",
+        )
+            .into_response(),
+    }
+}
+
+/// Tenant attached to the HTTP request behind an MCP call, if any.
+pub(crate) fn request_tenant(context: &RequestContext<RoleServer>) -> Option<Arc<Tenant>> {
+    context
+        .extensions
+        .get::<axum::http::request::Parts>()?
+        .extensions
+        .get::<Arc<Tenant>>()
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> BTreeMap<String, TenantToml> {
+        BTreeMap::from([
+            (
+                "oncology".to_string(),
+                TenantToml {
+                    token_env: "ONC_TOKEN".into(),
+                    keys_env: BTreeMap::from([("ONCOKB_TOKEN".into(), "ONC_ONCOKB".into())]),
+                },
+            ),
+            (
+                "genomics".to_string(),
+                TenantToml {
+                    token_env: "GEN_TOKEN".into(),
+                    keys_env: BTreeMap::new(),
+                },
+            ),
+        ])
+    }
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "ONC_TOKEN" => Some("onc-secret".into()),
+            "GEN_TOKEN" => Some("gen-secret".into()),
+            "ONC_ONCOKB" => Some("onc-oncokb".into()),
+            _ => None,
+        }
+    }
+
+    fn bearer(token: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            format!("Bearer {token}").parse().expect("header"),
+        );
+        headers
+    }
+
+    #[test]
+    fn empty_tenant_config_leaves_tenancy_off() {
+        assert!(
+            TenantDirectory::from_config(&BTreeMap::new(), env)
+                .expect("empty config")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn authenticate_maps_bearer_tokens_to_tenants() {
+        let directory = TenantDirectory::from_config(&config(), env)
+            .expect("config")
+            .expect("tenants");
+        assert_eq!(directory.len(), 2);
+
+        let tenant = directory
+            .authenticate(&bearer("onc-secret"))
+            .expect("known token");
+        assert_eq!(tenant.scope.name(), "oncology");
+        assert!(directory.authenticate(&bearer("wrong")).is_none());
+        assert!(directory.authenticate(&HeaderMap::new()).is_none());
+    }
+
+    #[test]
+    fn from_config_rejects_missing_secrets_unknown_keys_and_shared_tokens() {
+        let mut missing = config();
+        missing.get_mut("genomics").expect("tenant").token_env = "UNSET".into();
+        let err = TenantDirectory::from_config(&missing, env).expect_err("unset token");
+        assert!(err.to_string().contains("needs UNSET to be set"));
+
+        let mut unknown = config();
+        unknown
+            .get_mut("genomics")
+            .expect("tenant")
+            .keys_env
+            .insert("GITHUB_TOKEN".into(), "ONC_ONCOKB".into());
+        let err = TenantDirectory::from_config(&unknown, env).expect_err("unknown key");
+        assert!(err.to_string().contains("unknown key GITHUB_TOKEN"));
+
+        let mut shared = config();
+        shared.get_mut("genomics").expect("tenant").token_env = "ONC_TOKEN".into();
+        let err = TenantDirectory::from_config(&shared, env).expect_err("shared token");
+        assert!(err.to_string().contains("reuses another tenant's token"));
+    }
+
+    #[tokio::test]
+    async fn run_scopes_keys_and_meters_calls_per_tenant() {
+        let directory = TenantDirectory::from_config(&config(), env)
+            .expect("config")
+            .expect("tenants");
+        let oncology = directory
+            .authenticate(&bearer("onc-secret"))
+            .expect("tenant");
+
+        let key = oncology
+            .run(async { Ok::<_, ()>(crate::sources::tenant::api_key("ONCOKB_TOKEN")) })
+            .await
+            .expect("ok");
+        assert_eq!(key.as_deref(), Some("onc-oncokb"));
+        let _ = oncology.run(async { Err::<(), _>("boom") }).await;
+
+        let metrics = directory.render_metrics();
+        assert!(metrics.contains("# TYPE biomcp_tenant_tool_calls_total counter"));
+        assert!(metrics.contains("biomcp_tenant_tool_calls_total{tenant=\"oncology\"} 2"));
+        assert!(metrics.contains("biomcp_tenant_tool_errors_total{tenant=\"oncology\"} 1"));
+        assert!(metrics.contains("biomcp_tenant_tool_calls_total{tenant=\"genomics\"} 0"));
+    }
+}
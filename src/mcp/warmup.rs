@@ -0,0 +1,93 @@
+//! Startup warm-up for the MCP servers.
+//!
+//! The markdown environment and shared HTTP clients are built lazily, so
+//! without a warm-up the first tool call pays for template compilation, cache
+//! setup, and client construction. Servers run [`warm_up`] before accepting
+//! requests. Failures only log: the same work is retried lazily on first use.
+//! With `BIOMCP_WARM_CONNECTIONS` set, DNS/TLS connections to the top sources
+//! are also opened in the background.
+
+use std::time::Instant;
+
+use tracing::{debug, info, warn};
+
+const WARM_CONNECTIONS_ENV: &str = "BIOMCP_WARM_CONNECTIONS";
+
+fn parse_warm_connections(value: Option<&str>) -> bool {
+    match value.map(|value| value.trim().to_ascii_lowercase()) {
+        None => false,
+        Some(value) => match value.as_str() {
+            "1" | "true" | "yes" | "on" => true,
+            "" | "0" | "false" | "no" | "off" => false,
+            other => {
+                warn!("Unknown {WARM_CONNECTIONS_ENV}={other:?}, not priming connections");
+                false
+            }
+        },
+    }
+}
+
+/// Pre-builds templates and shared clients, then optionally primes connections.
+pub(crate) async fn warm_up() {
+    let started = Instant::now();
+    match crate::render::markdown::warm_templates() {
+        Ok(count) => debug!(templates = count, "markdown templates compiled"),
+        Err(err) => warn!("template warm-up failed: {err}"),
+    }
+
+    let clients = [
+        ("shared", crate::sources::shared_client().map(drop)),
+        (
+            "semantic-scholar",
+            crate::sources::semantic_scholar_shared_pool_client().map(drop),
+        ),
+        (
+            "streaming",
+            crate::sources::streaming_http_client().map(drop),
+        ),
+    ];
+    for (name, result) in clients {
+        if let Err(err) = result {
+            warn!(client = name, "HTTP client warm-up failed: {err}");
+        }
+    }
+    info!(
+        elapsed_ms = started.elapsed().as_millis() as u64,
+        "BioMCP warm-up finished"
+    );
+
+    let warm_connections = std::env::var(WARM_CONNECTIONS_ENV).ok();
+    if parse_warm_connections(warm_connections.as_deref()) {
+        tokio::spawn(async move {
+            let started = Instant::now();
+            match crate::sources::prime_connections().await {
+                Ok(reached) => info!(
+                    origins = reached,
+                    elapsed_ms = started.elapsed().as_millis() as u64,
+                    "source connections primed"
+                ),
+                Err(err) => warn!("connection warm-up failed: {err}"),
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_warm_connections;
+
+    #[test]
+    fn parse_warm_connections_accepts_common_switch_values() {
+        assert!(!parse_warm_connections(None));
+        assert!(parse_warm_connections(Some(" On ")));
+        assert!(parse_warm_connections(Some("1")));
+        assert!(!parse_warm_connections(Some("off")));
+        assert!(!parse_warm_connections(Some("sometimes")));
+    }
+
+    #[test]
+    fn warm_templates_compiles_the_bundled_environment() {
+        let count = crate::render::markdown::warm_templates().expect("templates compile");
+        assert!(count > 10);
+    }
+}
@@ -1,6 +1,8 @@
+use schemars::JsonSchema;
 use serde::Serialize;
 
 use crate::entities::discover::{AliasFallbackDecision, DiscoverResult};
+use crate::entities::section_errors::SectionError;
 use crate::entities::variant::{VariantGuidance, VariantGuidanceKind};
 use crate::error::BioMcpError;
 use crate::render::markdown::discover_evidence_urls;
@@ -10,17 +12,25 @@ pub fn to_pretty<T: Serialize>(value: &T) -> Result<String, BioMcpError> {
     Ok(serde_json::to_string_pretty(value)?)
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct EvidenceUrl {
     pub label: String,
     pub url: String,
 }
 
-#[derive(Serialize)]
-struct EntityMeta {
+/// Version of the entity JSON contract reported as `_meta.schema_version`.
+/// Bump it when a field is renamed, removed, or changes type.
+pub const ENTITY_SCHEMA_VERSION: u32 = 1;
+
+/// Trailing `_meta` object on every `get` entity response.
+#[derive(Serialize, JsonSchema)]
+pub(crate) struct EntityMeta {
+    /// Version of the entity JSON contract; see `biomcp schema <entity>`.
+    schema_version: u32,
     evidence_urls: Vec<EvidenceUrl>,
     next_commands: Vec<String>,
     section_sources: Vec<SectionSource>,
+    section_errors: Vec<SectionError>,
 }
 
 #[derive(Serialize)]
@@ -50,12 +60,14 @@ pub fn to_entity_json<T: Serialize>(
     evidence_urls: Vec<(&str, String)>,
     next_commands: Vec<String>,
     section_sources: Vec<SectionSource>,
+    section_errors: Vec<SectionError>,
 ) -> Result<String, BioMcpError> {
     to_pretty(&to_entity_json_value(
         entity,
         evidence_urls,
         next_commands,
         section_sources,
+        section_errors,
     )?)
 }
 
@@ -64,6 +76,7 @@ pub fn to_entity_json_value<T: Serialize>(
     evidence_urls: Vec<(&str, String)>,
     next_commands: Vec<String>,
     section_sources: Vec<SectionSource>,
+    section_errors: Vec<SectionError>,
 ) -> Result<serde_json::Value, BioMcpError> {
     let evidence_urls = evidence_urls
         .into_iter()
@@ -92,9 +105,11 @@ pub fn to_entity_json_value<T: Serialize>(
     Ok(serde_json::to_value(EntityJsonResponse {
         entity,
         _meta: EntityMeta {
+            schema_version: ENTITY_SCHEMA_VERSION,
             evidence_urls,
             next_commands,
             section_sources,
+            section_errors,
         },
     })?)
 }
@@ -367,6 +382,7 @@ mod tests {
     };
     use crate::entities::drug::Drug;
     use crate::entities::gene::Gene;
+    use crate::entities::section_errors::SectionError;
     use crate::render::provenance::SectionSource;
     use serde::Serialize;
 
@@ -401,6 +417,7 @@ mod tests {
             uniprot_id: Some("P00533".to_string()),
             summary: Some("Kinase receptor".to_string()),
             gene_type: Some("protein-coding".to_string()),
+            species: None,
             aliases: vec!["ERBB".to_string()],
             clinical_diseases: Vec::new(),
             clinical_drugs: Vec::new(),
@@ -417,8 +434,14 @@ mod tests {
             clingen: None,
             constraint: None,
             disgenet: None,
+            clinvar_summary: None,
+            tractability: None,
             funding: None,
             funding_note: None,
+            key_papers: None,
+            dependency: None,
+            orthologs: None,
+            plugin_sections: Vec::new(),
         };
 
         let json = to_pretty(&gene).expect("gene json");
@@ -439,6 +462,7 @@ mod tests {
             uniprot_id: None,
             summary: None,
             gene_type: None,
+            species: None,
             aliases: Vec::new(),
             clinical_diseases: Vec::new(),
             clinical_drugs: Vec::new(),
@@ -465,8 +489,14 @@ mod tests {
                     evidence_level: None,
                 }],
             }),
+            clinvar_summary: None,
+            tractability: None,
             funding: None,
             funding_note: None,
+            key_papers: None,
+            dependency: None,
+            orthologs: None,
+            plugin_sections: Vec::new(),
         };
 
         let json = to_pretty(&gene).expect("gene json");
@@ -505,12 +535,14 @@ mod tests {
             indications: vec!["Non-small cell lung cancer".to_string()],
             interactions: Vec::new(),
             interaction_text: None,
+            supplement_interactions: Vec::new(),
             pharm_classes: Vec::new(),
             top_adverse_events: Vec::new(),
             faers_query: None,
             label: None,
             label_set_id: None,
             shortage: None,
+            shortage_trials: None,
             approvals: None,
             us_safety_warnings: None,
             ema_regulatory: None,
@@ -518,6 +550,8 @@ mod tests {
             ema_shortage: None,
             who_prequalification: None,
             civic: None,
+            patents: None,
+            ncit: None,
         };
 
         let json = to_pretty(&drug).expect("drug json");
@@ -550,12 +584,14 @@ mod tests {
             indications: Vec::new(),
             interactions: Vec::new(),
             interaction_text: None,
+            supplement_interactions: Vec::new(),
             pharm_classes: Vec::new(),
             top_adverse_events: Vec::new(),
             faers_query: None,
             label: None,
             label_set_id: None,
             shortage: None,
+            shortage_trials: None,
             approvals: None,
             us_safety_warnings: None,
             ema_regulatory: None,
@@ -563,6 +599,8 @@ mod tests {
             ema_shortage: None,
             who_prequalification: None,
             civic: None,
+            patents: None,
+            ncit: None,
         };
 
         let json = to_pretty(&drug).expect("drug json");
@@ -593,6 +631,12 @@ mod tests {
                 label: "Summary".to_string(),
                 sources: vec!["NCBI Gene".to_string()],
             }],
+            vec![SectionError {
+                section: "civic".to_string(),
+                source: "CIViC".to_string(),
+                error_code: "timeout".to_string(),
+                message: "CIViC did not respond within 8s".to_string(),
+            }],
         )
         .expect("entity json");
 
@@ -611,6 +655,9 @@ mod tests {
             value["_meta"]["section_sources"][0]["sources"][0],
             "NCBI Gene"
         );
+        assert_eq!(value["_meta"]["section_errors"][0]["section"], "civic");
+        assert_eq!(value["_meta"]["section_errors"][0]["source"], "CIViC");
+        assert_eq!(value["_meta"]["section_errors"][0]["error_code"], "timeout");
     }
 
     #[test]
@@ -636,6 +683,7 @@ mod tests {
                 label: "Summary".to_string(),
                 sources: vec!["NCBI Gene".to_string()],
             }],
+            Vec::new(),
         )
         .expect("entity json value");
 
@@ -653,6 +701,7 @@ mod tests {
             value["_meta"]["section_sources"][0]["sources"][0],
             "NCBI Gene"
         );
+        assert_eq!(value["_meta"]["section_errors"], serde_json::json!([]));
     }
 
     #[test]
@@ -671,6 +720,7 @@ mod tests {
             ],
             Vec::new(),
             Vec::new(),
+            Vec::new(),
         )
         .expect("entity json");
 
@@ -716,6 +766,7 @@ mod tests {
                     sources: vec![" NCBI Gene / MyGene.info ".to_string(), "".to_string()],
                 },
             ],
+            Vec::new(),
         )
         .expect("entity json");
 
@@ -1,4 +1,4 @@
-//! Adverse-event, device-event, and recall markdown renderers.
+//! Adverse-event, device-event, device-clearance, and recall markdown renderers.
 
 use super::*;
 
@@ -138,6 +138,96 @@ pub fn device_event_search_markdown_with_footer(
     Ok(with_pagination_footer(body, pagination_footer))
 }
 
+pub fn device_clearance_markdown(clearance: &DeviceClearance) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("device_clearance.md.j2")?;
+    let mut review_parts = Vec::new();
+    if let Some(decision) = clearance.decision.as_deref() {
+        review_parts.push(format!("Decision: {decision}"));
+    }
+    if let Some(clearance_type) = clearance.clearance_type.as_deref() {
+        review_parts.push(format!("Type: {clearance_type}"));
+    }
+    if let Some(review_panel) = clearance.review_panel.as_deref() {
+        review_parts.push(format!("Review Panel: {review_panel}"));
+    }
+    if let Some(summary_type) = clearance.summary_type.as_deref() {
+        review_parts.push(format!("Summary: {summary_type}"));
+    }
+    if clearance.third_party_review == Some(true) {
+        review_parts.push("Third-party review".to_string());
+    }
+    if clearance.expedited_review == Some(true) {
+        review_parts.push("Expedited review".to_string());
+    }
+    let flags = clearance
+        .classification
+        .as_ref()
+        .map(|classification| {
+            [
+                (classification.implant, "Implant"),
+                (classification.life_sustaining, "Life-sustaining"),
+                (classification.gmp_exempt, "GMP exempt"),
+                (
+                    classification.third_party_eligible,
+                    "Third-party review eligible",
+                ),
+            ]
+            .into_iter()
+            .filter(|(flag, _)| *flag == Some(true))
+            .map(|(_, label)| label)
+            .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let body = tmpl.render(context! {
+        k_number => &clearance.k_number,
+        device_name => &clearance.device_name,
+        applicant => &clearance.applicant,
+        product_code => &clearance.product_code,
+        decision_date => &clearance.decision_date,
+        date_received => &clearance.date_received,
+        review_parts => review_parts,
+        classification => &clearance.classification,
+        flags => flags,
+        related_block => format_related_block(related_device_clearance(clearance)),
+    })?;
+    Ok(append_evidence_urls(
+        body,
+        device_clearance_evidence_urls(clearance),
+    ))
+}
+
+pub fn device_clearance_search_markdown_with_footer(
+    query: &str,
+    results: &[DeviceClearanceSearchResult],
+    pagination_footer: &str,
+) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("device_clearance_search.md.j2")?;
+    let body = tmpl.render(context! {
+        query => query,
+        count => results.len(),
+        results => results,
+        pagination_footer => pagination_footer,
+    })?;
+    Ok(with_pagination_footer(body, pagination_footer))
+}
+
+pub fn veterinary_event_search_markdown_with_footer(
+    query: &str,
+    results: &[VeterinaryEventSearchResult],
+    total: Option<usize>,
+    pagination_footer: &str,
+) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("veterinary_event_search.md.j2")?;
+    let body = tmpl.render(context! {
+        query => query,
+        count => results.len(),
+        total => total,
+        results => results,
+        pagination_footer => pagination_footer,
+    })?;
+    Ok(with_pagination_footer(body, pagination_footer))
+}
+
 #[allow(dead_code)]
 pub fn recall_search_markdown(
     query: &str,
@@ -50,6 +50,36 @@ fn adverse_event_search_markdown_renders_summary_and_filters() {
     assert!(markdown.contains("Use `get adverse-event <report_id>` for details."));
 }
 
+#[test]
+fn veterinary_event_search_markdown_renders_species_and_veddra_note() {
+    let results = vec![VeterinaryEventSearchResult {
+        report_id: "USA-USFDACVM-2019-US-012345".to_string(),
+        drug: "Carprofen".to_string(),
+        species: Some("Dog".to_string()),
+        reactions: vec!["Vomiting".to_string(), "Lethargy".to_string()],
+        serious: true,
+        date: Some("2019-04-12".to_string()),
+    }];
+
+    let markdown = veterinary_event_search_markdown_with_footer(
+        "drug=carprofen, species=veterinary",
+        &results,
+        Some(120),
+        "",
+    )
+    .expect("veterinary search");
+    assert!(markdown.contains("# Veterinary Adverse Events: drug=carprofen, species=veterinary"));
+    assert!(markdown.contains("Found 1 report of 120 (OpenFDA animal & veterinary)"));
+    assert!(markdown.contains(
+        "|USA-USFDACVM-2019-US-012345|Carprofen|Dog|Vomiting, Lethargy|Serious|2019-04-12|"
+    ));
+    assert!(markdown.contains("VeDDRA terms, not MedDRA"));
+
+    let empty = veterinary_event_search_markdown_with_footer("drug=x", &[], Some(0), "")
+        .expect("empty search");
+    assert!(empty.contains("No veterinary adverse events found"));
+}
+
 #[test]
 fn adverse_event_count_markdown_renders_bucket_rows() {
     let markdown = adverse_event_count_markdown(
@@ -98,6 +128,67 @@ fn device_event_renderers_include_openfda_content() {
     assert!(search_markdown.contains("|Report Key|Device|Event Type|Date|Description|"));
 }
 
+#[test]
+fn device_clearance_renderers_include_classification_and_follow_ups() {
+    let clearance = DeviceClearance {
+        k_number: "K213456".to_string(),
+        device_name: "Continuous Glucose Monitor".to_string(),
+        applicant: Some("Example Medical".to_string()),
+        product_code: Some("QBJ".to_string()),
+        decision: Some("Substantially Equivalent".to_string()),
+        decision_date: Some("2021-12-01".to_string()),
+        date_received: None,
+        clearance_type: Some("Traditional".to_string()),
+        review_panel: None,
+        summary_type: None,
+        third_party_review: Some(false),
+        expedited_review: None,
+        classification: Some(crate::entities::adverse_event::DeviceClassification {
+            product_code: "QBJ".to_string(),
+            device_name: None,
+            device_class: Some("Class II".to_string()),
+            regulation_number: Some("862.1355".to_string()),
+            medical_specialty: None,
+            definition: None,
+            implant: Some(false),
+            life_sustaining: Some(true),
+            gmp_exempt: None,
+            third_party_eligible: None,
+        }),
+    };
+
+    let markdown = device_clearance_markdown(&clearance).expect("clearance");
+    assert!(markdown.contains("# Device Clearance"));
+    assert!(markdown.contains("Decision: Substantially Equivalent | Type: Traditional"));
+    assert!(!markdown.contains("Third-party review"));
+    assert!(markdown.contains("## Classification (OpenFDA)"));
+    assert!(markdown.contains("Product Code: QBJ | Class II | 21 CFR 862.1355"));
+    assert!(markdown.contains("Flags: Life-sustaining"));
+    assert!(markdown.contains("biomcp search adverse-event --type device --product-code QBJ"));
+    assert!(markdown.contains("pmn.cfm?ID=K213456"));
+
+    let search_markdown = device_clearance_search_markdown_with_footer(
+        "product_code=QBJ",
+        &[DeviceClearanceSearchResult {
+            k_number: clearance.k_number.clone(),
+            device_name: clearance.device_name.clone(),
+            applicant: clearance.applicant.clone(),
+            product_code: clearance.product_code.clone(),
+            device_class: Some("Class II".to_string()),
+            decision: clearance.decision.clone(),
+            decision_date: clearance.decision_date.clone(),
+        }],
+        "",
+    )
+    .expect("clearance search");
+    assert!(search_markdown.contains("# Device Clearances: product_code=QBJ"));
+    assert!(
+        search_markdown.contains(
+            "|K213456|Continuous Glucose Monitor|Example Medical|QBJ|Class II|2021-12-01|"
+        )
+    );
+}
+
 #[test]
 fn recall_search_markdown_renders_result_table() {
     let markdown = recall_search_markdown(
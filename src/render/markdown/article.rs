@@ -14,6 +14,36 @@ struct ArticleSearchRenderRow {
     why: String,
     citation_count: Option<u64>,
     is_retracted: Option<bool>,
+    journal_quartile: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+struct ArticleCitationRenderRow {
+    id: String,
+    title: String,
+    journal: String,
+    year: String,
+    cited_by: String,
+}
+
+fn article_citation_rows(links: &[ArticleCitationLink]) -> Vec<ArticleCitationRenderRow> {
+    links
+        .iter()
+        .map(|link| ArticleCitationRenderRow {
+            id: link
+                .pmid
+                .clone()
+                .unwrap_or_else(|| format!("{}:{}", link.source, link.id)),
+            title: markdown_cell(&link.title),
+            journal: markdown_cell(link.journal.as_deref().unwrap_or_default()),
+            year: link
+                .year
+                .map_or_else(|| "-".to_string(), |year| year.to_string()),
+            cited_by: link
+                .cited_by_count
+                .map_or_else(|| "-".to_string(), |count| count.to_string()),
+        })
+        .collect()
 }
 
 pub fn article_markdown(
@@ -28,6 +58,17 @@ pub fn article_markdown(
     let show_annotations_section = include_all || has_requested("annotations");
     let show_fulltext_section = include_all || has_requested("fulltext");
     let show_semantic_scholar_section = !section_only || include_all || has_requested("tldr");
+    let show_citations_section = include_all || has_requested("citations");
+    let (citing_rows, reference_rows) = article
+        .citation_graph
+        .as_ref()
+        .map(|graph| {
+            (
+                article_citation_rows(&graph.citing),
+                article_citation_rows(&graph.references),
+            )
+        })
+        .unwrap_or_default();
     let article_label = if article.title.trim().is_empty() {
         "Article"
     } else {
@@ -49,12 +90,17 @@ pub fn article_markdown(
         abstract_text => &article.abstract_text,
         full_text_path => &article.full_text_path,
         full_text_note => &article.full_text_note,
+        full_text_source => &article.full_text_source,
         annotations => &article.annotations,
         semantic_scholar => &article.semantic_scholar,
+        citation_graph => &article.citation_graph,
+        citing_rows => citing_rows,
+        reference_rows => reference_rows,
         pubtator_fallback => article.pubtator_fallback,
         show_annotations_section => show_annotations_section,
         show_fulltext_section => show_fulltext_section,
         show_semantic_scholar_section => show_semantic_scholar_section,
+        show_citations_section => show_citations_section,
         sections_block => format_sections_block("article", article.pmid.as_deref().or(article.pmcid.as_deref()).or(article.doi.as_deref()).unwrap_or(""), sections_article(article, requested_sections)),
         related_block => format_related_block(related_article(article)),
     })?;
@@ -427,6 +473,25 @@ pub fn article_search_markdown_with_footer_and_context(
             why: article_ranking_why(row, filters),
             citation_count: row.citation_count,
             is_retracted: row.is_retracted,
+            journal_quartile: row.journal_rank.as_ref().map(|rank| rank.quartile.clone()),
+        })
+        .collect::<Vec<_>>();
+    let show_journal_quartile = rows.iter().any(|row| row.journal_quartile.is_some());
+    let preprint_notes = results
+        .iter()
+        .filter_map(|row| {
+            let preprint = row.preprint.as_ref()?;
+            let id = preprint
+                .doi
+                .clone()
+                .or_else(|| preprint.pmid.as_ref().map(|pmid| format!("PMID {pmid}")))?;
+            let server = preprint.server.as_deref().unwrap_or("preprint");
+            let date = preprint
+                .date
+                .as_deref()
+                .map(|date| format!(", {date}"))
+                .unwrap_or_default();
+            Some(format!("{}: {server} {id}{date}", row.pmid))
         })
         .collect::<Vec<_>>();
 
@@ -435,6 +500,8 @@ pub fn article_search_markdown_with_footer_and_context(
         query => query,
         count => results.len(),
         rows => rows,
+        show_journal_quartile => show_journal_quartile,
+        preprint_notes => preprint_notes,
         semantic_scholar_enabled => semantic_scholar_enabled,
         note => note,
         sort => filters.sort.as_str(),
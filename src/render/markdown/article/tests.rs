@@ -21,6 +21,7 @@ fn article_entities_markdown_uses_safe_gene_search_commands() {
             text: "V600E".to_string(),
             count: 2,
         }],
+        trials: Vec::new(),
     };
 
     let markdown =
@@ -47,6 +48,7 @@ fn article_markdown_renders_semantic_scholar_section() {
         abstract_text: None,
         full_text_path: None,
         full_text_note: None,
+        full_text_source: None,
         annotations: None,
         semantic_scholar: Some(crate::entities::article::ArticleSemanticScholar {
             paper_id: Some("paper-1".to_string()),
@@ -61,6 +63,7 @@ fn article_markdown_renders_semantic_scholar_section() {
                 license: Some("CC-BY".to_string()),
             }),
         }),
+        citation_graph: None,
         pubtator_fallback: false,
     };
 
@@ -72,6 +75,120 @@ fn article_markdown_renders_semantic_scholar_section() {
     assert!(markdown.contains("Open-access PDF: https://example.org/paper.pdf"));
 }
 
+#[test]
+fn article_markdown_labels_unpaywall_full_text() {
+    let article = Article {
+        pmid: Some("22663013".to_string()),
+        pmcid: None,
+        doi: Some("10.1000/oa.13".to_string()),
+        title: "Example".to_string(),
+        authors: Vec::new(),
+        journal: None,
+        date: None,
+        citation_count: None,
+        publication_type: None,
+        open_access: None,
+        abstract_text: None,
+        full_text_path: Some(std::path::PathBuf::from("/tmp/biomcp/downloads/abc.pdf")),
+        full_text_note: None,
+        full_text_source: Some(crate::entities::article::ArticleFullTextSource {
+            provider: "Unpaywall".to_string(),
+            url: "https://repo.example.org/oa.13.pdf".to_string(),
+            format: "pdf".to_string(),
+            host_type: Some("repository".to_string()),
+            version: Some("acceptedVersion".to_string()),
+            license: Some("cc-by".to_string()),
+        }),
+        annotations: None,
+        semantic_scholar: None,
+        citation_graph: None,
+        pubtator_fallback: false,
+    };
+
+    let markdown =
+        article_markdown(&article, &["fulltext".to_string()]).expect("markdown should render");
+    assert!(markdown.contains("## Full Text (Unpaywall)"));
+    assert!(markdown.contains("Saved to: /tmp/biomcp/downloads/abc.pdf"));
+    assert!(markdown.contains(
+        "Open-access copy: https://repo.example.org/oa.13.pdf (repository, acceptedVersion)"
+    ));
+    assert!(markdown.contains("License: cc-by"));
+    assert!(markdown.contains("Format: PDF"));
+
+    let sources = crate::render::provenance::article_section_sources(&article);
+    let fulltext = sources
+        .iter()
+        .find(|section| section.key == "fulltext")
+        .expect("fulltext provenance");
+    assert_eq!(fulltext.sources, vec!["Unpaywall".to_string()]);
+}
+
+#[test]
+fn article_markdown_renders_citation_graph_tables_and_related_pmids() {
+    let link = |id: &str, source: &str, title: &str, cited_by: u64| {
+        crate::entities::article::ArticleCitationLink {
+            source: source.to_string(),
+            id: id.to_string(),
+            pmid: (source == "MED").then(|| id.to_string()),
+            title: title.to_string(),
+            authors: None,
+            journal: Some("Nature".to_string()),
+            year: Some(2018),
+            cited_by_count: Some(cited_by),
+        }
+    };
+    let article = Article {
+        pmid: Some("22663011".to_string()),
+        pmcid: None,
+        doi: None,
+        title: "Example".to_string(),
+        authors: Vec::new(),
+        journal: None,
+        date: None,
+        citation_count: None,
+        publication_type: None,
+        open_access: None,
+        abstract_text: None,
+        full_text_path: None,
+        full_text_note: None,
+        full_text_source: None,
+        annotations: None,
+        semantic_scholar: None,
+        citation_graph: Some(crate::entities::article::ArticleCitationGraph {
+            cited_by_count: 1532,
+            reference_count: 41,
+            citing: vec![
+                link("29000001", "MED", "High | impact follow-up", 410),
+                link("PPR123456", "PPR", "Preprint follow-up", 0),
+                link("30000001", "MED", "Low impact follow-up", 3),
+            ],
+            references: vec![link("20000001", "MED", "Older reference", 55)],
+        }),
+        pubtator_fallback: false,
+    };
+
+    let markdown =
+        article_markdown(&article, &["citations".to_string()]).expect("markdown should render");
+    assert!(markdown.contains("## Citations (Europe PMC)"));
+    assert!(markdown.contains("Cited by: 1532 articles | References: 41"));
+    assert!(markdown.contains("| 29000001 | High \\| impact follow-up | Nature | 2018 | 410 |"));
+    assert!(markdown.contains("| PPR:PPR123456 | Preprint follow-up |"));
+    assert!(markdown.contains("### Most-cited references"));
+    assert!(markdown.contains("biomcp get article 29000001"));
+    assert!(markdown.contains("biomcp get article 30000001"));
+    assert!(markdown.contains("biomcp get article 20000001"));
+
+    let plain = article_markdown(&article, &[]).expect("markdown should render");
+    assert!(!plain.contains("## Citations (Europe PMC)"));
+
+    let sources = crate::render::provenance::article_section_sources(&article);
+    assert!(
+        sources
+            .iter()
+            .any(|section| section.key == "citations" && section.sources == ["Europe PMC"])
+    );
+}
+
 #[test]
 fn article_graph_markdown_renders_expected_table_headers() {
     let result = crate::entities::article::ArticleGraphResult {
@@ -179,6 +296,8 @@ fn article_search_markdown_preserves_rank_order_and_shows_rationale() {
             source: ArticleSource::PubTator,
             score: Some(99.1),
             is_retracted: Some(false),
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: Some("Abstract one".into()),
             ranking: Some(crate::entities::article::ArticleRankingMetadata {
                 directness_tier: 3,
@@ -218,6 +337,8 @@ fn article_search_markdown_preserves_rank_order_and_shows_rationale() {
             source: ArticleSource::EuropePmc,
             score: None,
             is_retracted: Some(false),
+            journal_rank: None,
+            preprint: None,
             abstract_snippet: Some("Abstract two".into()),
             ranking: Some(crate::entities::article::ArticleRankingMetadata {
                 directness_tier: 2,
@@ -292,6 +413,8 @@ fn article_ranking_why_tier1_mixed_shows_title_plus_abstract() {
         matched_sources: vec![ArticleSource::EuropePmc],
         score: None,
         is_retracted: None,
+        journal_rank: None,
+        preprint: None,
         abstract_snippet: None,
         ranking: Some(crate::entities::article::ArticleRankingMetadata {
             directness_tier: 1,
@@ -337,6 +460,8 @@ fn article_ranking_why_rescue_composes_with_lexical_reason() {
         matched_sources: vec![ArticleSource::PubMed],
         score: None,
         is_retracted: None,
+        journal_rank: None,
+        preprint: None,
         abstract_snippet: None,
         ranking: Some(crate::entities::article::ArticleRankingMetadata {
             directness_tier: 1,
@@ -383,6 +508,8 @@ fn article_ranking_why_semantic_includes_score_and_lexical_context() {
         matched_sources: vec![ArticleSource::EuropePmc],
         score: Some(0.81234),
         is_retracted: None,
+        journal_rank: None,
+        preprint: None,
         abstract_snippet: None,
         ranking: Some(crate::entities::article::ArticleRankingMetadata {
             directness_tier: 2,
@@ -429,6 +556,8 @@ fn article_ranking_why_hybrid_includes_score_and_lexical_context() {
         matched_sources: vec![ArticleSource::EuropePmc],
         score: Some(0.9),
         is_retracted: None,
+        journal_rank: None,
+        preprint: None,
         abstract_snippet: None,
         ranking: Some(crate::entities::article::ArticleRankingMetadata {
             directness_tier: 1,
@@ -491,6 +620,8 @@ fn article_search_markdown_prepends_debug_plan_block() {
         source: ArticleSource::PubTator,
         score: Some(99.1),
         is_retracted: Some(false),
+        journal_rank: None,
+        preprint: None,
         abstract_snippet: Some("Abstract one".into()),
         ranking: None,
         matched_sources: vec![ArticleSource::PubTator],
@@ -515,3 +646,119 @@ fn article_search_markdown_prepends_debug_plan_block() {
     assert!(markdown.contains("\"surface\": \"search_article\""));
     assert!(markdown.contains("# Articles: gene=BRAF"));
 }
+
+#[test]
+fn article_search_markdown_adds_journal_quartile_column_when_ranked() {
+    let row = |pmid: &str, quartile: Option<&str>| ArticleSearchResult {
+        pmid: pmid.into(),
+        title: format!("Article {pmid}"),
+        pmcid: None,
+        doi: None,
+        journal: Some("Journal A".into()),
+        date: Some("2025-01-01".into()),
+        citation_count: Some(3),
+        influential_citation_count: None,
+        source: ArticleSource::EuropePmc,
+        score: None,
+        is_retracted: Some(false),
+        journal_rank: quartile.map(|quartile| crate::entities::article::ArticleJournalRank {
+            quartile: quartile.into(),
+            sjr: Some(2.5),
+            year: Some(2024),
+        }),
+        preprint: None,
+        abstract_snippet: None,
+        ranking: None,
+        matched_sources: vec![ArticleSource::EuropePmc],
+        normalized_title: String::new(),
+        normalized_abstract: String::new(),
+        publication_type: None,
+        source_local_position: 0,
+    };
+    let filters = article_filters_for_test(crate::entities::article::ArticleSort::Date);
+
+    let unranked = article_search_markdown_with_footer_and_context(
+        "gene=BRAF",
+        &[row("1", None)],
+        "",
+        &filters,
+        true,
+        None,
+        None,
+    )
+    .expect("markdown should render");
+    assert!(!unranked.contains("Journal Q"));
+
+    let markdown = article_search_markdown_with_footer_and_context(
+        "gene=BRAF",
+        &[row("1", Some("Q1")), row("2", None)],
+        "",
+        &filters,
+        true,
+        None,
+        None,
+    )
+    .expect("markdown should render");
+    assert!(markdown.contains("| PMID | Title | Source(s) | Date | Why | Cit. | Journal Q |"));
+    assert!(markdown.contains("|---|---|---|---|---|---|---|"));
+    assert!(markdown.contains("|3|Q1|"));
+    assert!(markdown.contains("|3|-|"));
+}
+
+#[test]
+fn article_search_markdown_notes_published_versions_of_preprints() {
+    let mut row = ArticleSearchResult {
+        pmid: "38000002".into(),
+        title: "Single-cell atlas of glioblastoma invasion niches".into(),
+        pmcid: None,
+        doi: Some("10.1038/s41586-024-00001-x".into()),
+        journal: Some("Nature".into()),
+        date: Some("2024-02-14".into()),
+        citation_count: Some(12),
+        influential_citation_count: None,
+        source: ArticleSource::EuropePmc,
+        score: None,
+        is_retracted: Some(false),
+        journal_rank: None,
+        preprint: None,
+        abstract_snippet: None,
+        ranking: None,
+        matched_sources: vec![ArticleSource::EuropePmc],
+        normalized_title: String::new(),
+        normalized_abstract: String::new(),
+        publication_type: None,
+        source_local_position: 0,
+    };
+    let filters = article_filters_for_test(crate::entities::article::ArticleSort::Date);
+    let plain = article_search_markdown_with_footer_and_context(
+        "gene=EGFR",
+        std::slice::from_ref(&row),
+        "",
+        &filters,
+        true,
+        None,
+        None,
+    )
+    .expect("markdown should render");
+    assert!(!plain.contains("in place of preprints"));
+
+    row.preprint = Some(crate::entities::article::ArticlePreprintVersion {
+        pmid: Some("37000001".into()),
+        doi: Some("10.1101/2023.05.01.538900".into()),
+        server: Some("bioRxiv".into()),
+        date: Some("2023-05-02".into()),
+    });
+    let markdown = article_search_markdown_with_footer_and_context(
+        "gene=EGFR",
+        &[row],
+        "",
+        &filters,
+        true,
+        None,
+        None,
+    )
+    .expect("markdown should render");
+    assert!(markdown.contains(
+        "Published versions shown in place of preprints:\n- 38000002: bioRxiv 10.1101/2023.05.01.538900, 2023-05-02\n"
+    ));
+}
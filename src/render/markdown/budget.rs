@@ -0,0 +1,214 @@
+//! Output budgets for `--max-output-tokens`.
+//!
+//! Rendered markdown that exceeds the budget is cut back in stages: long
+//! free-text lines are shortened first, then whole `##` sections are dropped
+//! from the end, and finally trailing lines are cut. A closing note names
+//! what was removed so agents can re-run for just the sections they need.
+//! Token counts are estimated at four characters per token.
+
+use super::theme::{heading, is_fence};
+
+const CHARS_PER_TOKEN: usize = 4;
+/// Prose lines longer than this are shortened in the first stage.
+const LONG_TEXT_CHARS: usize = 280;
+/// Length a shortened line is cut back to, before the ellipsis.
+const SHORTENED_TEXT_CHARS: usize = 160;
+/// Budget held back for the truncation note itself.
+const NOTE_RESERVE_TOKENS: usize = 90;
+
+fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN)
+}
+
+struct Section {
+    title: Option<String>,
+    lines: Vec<String>,
+}
+
+impl Section {
+    fn tokens(&self) -> usize {
+        self.lines
+            .iter()
+            .map(|line| estimate_tokens(line) + 1)
+            .sum()
+    }
+}
+
+/// Splits markdown into the preamble and one block per `##` heading.
+fn split_sections(markdown: &str) -> Vec<Section> {
+    let mut sections = vec![Section {
+        title: None,
+        lines: Vec::new(),
+    }];
+    let mut in_fence = false;
+    for line in markdown.lines() {
+        if is_fence(line) {
+            in_fence = !in_fence;
+        } else if !in_fence && let Some((2, text)) = heading(line) {
+            sections.push(Section {
+                title: Some(text.to_string()),
+                lines: Vec::new(),
+            });
+        }
+        if let Some(section) = sections.last_mut() {
+            section.lines.push(line.to_string());
+        }
+    }
+    sections
+}
+
+fn is_prose(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    !trimmed.starts_with('|') && !trimmed.starts_with('#') && !trimmed.starts_with('<')
+}
+
+/// Cuts `line` back to a word boundary near [`SHORTENED_TEXT_CHARS`].
+fn shorten(line: &str) -> String {
+    let cut = line
+        .char_indices()
+        .nth(SHORTENED_TEXT_CHARS)
+        .map_or(line.len(), |(index, _)| index);
+    let head = &line[..cut];
+    let head = head
+        .rfind(' ')
+        .filter(|space| *space > cut / 2)
+        .map_or(head, |space| &head[..space]);
+    format!("{}…", head.trim_end_matches([',', ';', ':', ' ']))
+}
+
+fn shorten_long_text(sections: &mut [Section]) -> usize {
+    let mut shortened = 0;
+    for section in sections {
+        let mut in_fence = false;
+        for line in &mut section.lines {
+            if is_fence(line) {
+                in_fence = !in_fence;
+                continue;
+            }
+            if !in_fence && is_prose(line) && line.chars().count() > LONG_TEXT_CHARS {
+                *line = shorten(line);
+                shortened += 1;
+            }
+        }
+    }
+    shortened
+}
+
+fn total_tokens(sections: &[Section]) -> usize {
+    sections.iter().map(Section::tokens).sum()
+}
+
+/// Section argument spelling of a heading, e.g. "ClinVar Summary" -> "clinvar-summary".
+fn section_hint(title: &str) -> String {
+    title
+        .split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|ch| ch.is_alphanumeric() || *ch == '-')
+                .collect::<String>()
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn truncation_note(
+    max_tokens: usize,
+    original_tokens: usize,
+    shortened: usize,
+    dropped: &[String],
+    cut_lines: usize,
+) -> String {
+    let mut parts = Vec::new();
+    if shortened > 0 {
+        parts.push(format!("shortened {shortened} long text field(s)"));
+    }
+    if !dropped.is_empty() {
+        parts.push(format!("omitted sections: {}", dropped.join(", ")));
+    }
+    if cut_lines > 0 {
+        parts.push(format!("cut the last {cut_lines} line(s)"));
+    }
+    let mut note = format!(
+        "\n> **Truncated** to fit `--max-output-tokens {max_tokens}` (full output ≈ {original_tokens} tokens): {}.\n",
+        parts.join("; ")
+    );
+    if dropped.is_empty() {
+        note.push_str(
+            "> Re-run with a smaller `--limit`, fewer sections, or a larger budget for the rest.",
+        );
+    } else {
+        let hints = dropped
+            .iter()
+            .map(|title| section_hint(title))
+            .collect::<Vec<_>>()
+            .join(",");
+        note.push_str(&format!(
+            "> Re-run with sections={hints} (positional sections on `get`) to read what was omitted."
+        ));
+    }
+    note
+}
+
+/// Fits rendered markdown into roughly `max_tokens`; output already within
+/// budget is returned unchanged.
+pub fn apply_output_budget(markdown: &str, max_tokens: usize) -> String {
+    let original_tokens = estimate_tokens(markdown);
+    if original_tokens <= max_tokens {
+        return markdown.to_string();
+    }
+    let target = max_tokens.saturating_sub(NOTE_RESERVE_TOKENS);
+    let mut sections = split_sections(markdown);
+
+    let shortened = shorten_long_text(&mut sections);
+
+    // The preamble and first section carry the entity identity, so they stay.
+    let mut dropped = Vec::new();
+    while sections.len() > 2 && total_tokens(&sections) > target {
+        if let Some(title) = sections.pop().and_then(|section| section.title) {
+            dropped.push(title);
+        }
+    }
+    dropped.reverse();
+
+    let mut kept = Vec::new();
+    let mut used = 0;
+    let mut cut_lines = 0;
+    let mut in_fence = false;
+    for line in sections.into_iter().flat_map(|section| section.lines) {
+        let cost = estimate_tokens(&line) + 1;
+        if cut_lines > 0 || used + cost > target {
+            cut_lines += 1;
+            continue;
+        }
+        if is_fence(&line) {
+            in_fence = !in_fence;
+        }
+        used += cost;
+        kept.push(line);
+    }
+    if in_fence {
+        kept.push("```".to_string());
+    }
+    while kept.last().is_some_and(|line| line.trim().is_empty()) {
+        kept.pop();
+    }
+
+    let mut out = kept.join("\n");
+    out.push('\n');
+    out.push_str(&truncation_note(
+        max_tokens,
+        original_tokens,
+        shortened,
+        &dropped,
+        cut_lines,
+    ));
+    if markdown.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests;
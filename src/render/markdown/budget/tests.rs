@@ -0,0 +1,64 @@
+use super::{apply_output_budget, estimate_tokens, section_hint};
+
+fn report(sections: &[(&str, usize)]) -> String {
+    let mut out = String::from("# BRAF\n\nSymbol: BRAF\n");
+    for (title, rows) in sections {
+        out.push_str(&format!("\n## {title}\n\n| Name | Detail |\n|---|---|\n"));
+        for row in 0..*rows {
+            out.push_str(&format!("| {title} row {row} | some detail text |\n"));
+        }
+    }
+    out
+}
+
+#[test]
+fn output_within_budget_is_unchanged() {
+    let markdown = report(&[("Summary", 2)]);
+    assert_eq!(apply_output_budget(&markdown, 10_000), markdown);
+}
+
+#[test]
+fn long_free_text_is_shortened_before_sections_are_dropped() {
+    let long = "Serine/threonine kinase that regulates MAP kinase signaling. ".repeat(20);
+    let markdown = format!("# BRAF\n\n## Summary\n\n{long}\n\n## Pathways\n\n- MAPK signaling\n");
+    let out = apply_output_budget(&markdown, 190);
+
+    assert!(out.contains("## Pathways"));
+    assert!(out.contains("…"));
+    assert!(out.contains("shortened 1 long text field(s)"));
+    assert!(!out.contains("omitted sections"));
+}
+
+#[test]
+fn trailing_sections_are_dropped_with_rerun_hint() {
+    let markdown = report(&[("Summary", 3), ("ClinVar Summary", 40), ("Pathways", 40)]);
+    let out = apply_output_budget(&markdown, 400);
+
+    assert!(out.starts_with("# BRAF\n"));
+    assert!(out.contains("## Summary"));
+    assert!(!out.contains("## Pathways"));
+    assert!(out.contains("omitted sections: ClinVar Summary, Pathways"));
+    assert!(out.contains("sections=clinvar-summary,pathways"));
+    assert!(estimate_tokens(&out) <= 400);
+}
+
+#[test]
+fn single_large_section_is_cut_by_lines_and_fences_are_closed() {
+    let markdown = report(&[("Trials", 200)]);
+    let out = apply_output_budget(&markdown, 300);
+    assert!(out.contains("| Trials row 0 |"));
+    assert!(!out.contains("| Trials row 199 |"));
+    assert!(out.contains("cut the last"));
+    assert!(out.contains("smaller `--limit`"));
+    assert!(estimate_tokens(&out) <= 300);
+
+    let fenced = format!("# Notes\n\n```text\n{}```\n", "line of code\n".repeat(300));
+    let out = apply_output_budget(&fenced, 200);
+    assert_eq!(out.matches("```").count(), 2);
+}
+
+#[test]
+fn section_hint_matches_section_argument_spelling() {
+    assert_eq!(section_hint("ClinVar Summary"), "clinvar-summary");
+    assert_eq!(section_hint("Pathways (Reactome)"), "pathways-reactome");
+}
@@ -1,4 +1,4 @@
-//! Search-all and discover markdown renderers.
+//! Search-all, discover, and ask markdown renderers.
 
 use super::*;
 
@@ -138,3 +138,27 @@ pub fn render_discover(result: &DiscoverResult) -> Result<String, BioMcpError> {
     })?;
     Ok(append_evidence_urls(body, discover_evidence_urls(result)))
 }
+
+pub fn ask_markdown(bundle: &crate::cli::ask::AskBundle) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("ask.md.j2")?;
+    let anchors = [
+        ("gene", bundle.anchors.gene.as_deref()),
+        ("variant", bundle.anchors.variant.as_deref()),
+        ("disease", bundle.anchors.disease.as_deref()),
+        ("drug", bundle.anchors.drug.as_deref()),
+        ("keyword", bundle.anchors.keyword.as_deref()),
+    ]
+    .into_iter()
+    .filter_map(|(slot, value)| value.map(|value| format!("{slot}={value}")))
+    .collect::<Vec<_>>();
+
+    Ok(tmpl.render(context! {
+        question => &bundle.question,
+        intent => &bundle.intent,
+        notes => &bundle.notes,
+        anchors => anchors,
+        evidence => &bundle.evidence,
+        citations => &bundle.citations,
+        next_commands => &bundle.next_commands,
+    })?)
+}
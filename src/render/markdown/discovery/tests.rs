@@ -106,3 +106,54 @@ fn render_discover_renders_grouped_concepts_and_plain_language() {
     assert!(markdown.contains("BRCA1 mutation"));
     assert!(markdown.contains("biomcp get gene BRCA1"));
 }
+
+#[test]
+fn ask_markdown_renders_numbered_citations_and_anchors() {
+    use crate::cli::ask::{
+        AskAnchors, AskBundle, AskCitation, AskEvidenceItem, AskEvidenceSection,
+    };
+
+    let bundle = AskBundle {
+        question: "what first-line therapies exist for BRAF V600E melanoma?".to_string(),
+        intent: "treatment_search".to_string(),
+        anchors: AskAnchors {
+            gene: Some("BRAF".to_string()),
+            variant: Some("BRAF V600E".to_string()),
+            disease: Some("melanoma".to_string()),
+            ..Default::default()
+        },
+        evidence: vec![AskEvidenceSection {
+            entity: "article".to_string(),
+            label: "Articles".to_string(),
+            items: vec![AskEvidenceItem {
+                id: "22663011".to_string(),
+                title: "Dabrafenib in BRAF-mutated metastatic melanoma".to_string(),
+                detail: Some("Lancet · 2012".to_string()),
+                citation: Some(1),
+            }],
+            error: None,
+            command: Some("biomcp get article 22663011".to_string()),
+        }],
+        citations: vec![AskCitation {
+            index: 1,
+            source: "PubMed".to_string(),
+            id: "22663011".to_string(),
+            title: "Dabrafenib in BRAF-mutated metastatic melanoma".to_string(),
+            url: "https://pubmed.ncbi.nlm.nih.gov/22663011/".to_string(),
+        }],
+        notes: Vec::new(),
+        next_commands: vec!["biomcp search drug --indication melanoma --limit 5".to_string()],
+        wall_time_ms: 12,
+    };
+
+    let markdown = ask_markdown(&bundle).expect("ask markdown");
+    assert!(markdown.contains("# Ask: what first-line therapies"));
+    assert!(markdown.contains("Anchors: gene=BRAF, variant=BRAF V600E, disease=melanoma"));
+    assert!(markdown.contains("## Articles (1)"));
+    assert!(
+        markdown.contains("- [1] **Dabrafenib in BRAF-mutated metastatic melanoma** (`22663011`)")
+    );
+    assert!(markdown.contains("[1] PubMed 22663011"));
+    assert!(markdown.contains("https://pubmed.ncbi.nlm.nih.gov/22663011/"));
+    assert!(markdown.contains("biomcp search drug --indication melanoma --limit 5"));
+}
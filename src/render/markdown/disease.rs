@@ -55,6 +55,15 @@ struct DiseaseSurvivalSummaryRenderRow {
     latest_modeled: Option<String>,
 }
 
+#[derive(serde::Serialize)]
+struct DiseaseEpidemiologyRenderRow {
+    indicator: String,
+    region: String,
+    year: i32,
+    age_group: String,
+    value: String,
+}
+
 #[derive(serde::Serialize)]
 struct DiseaseSurvivalHistoryRenderRow {
     sex: String,
@@ -218,6 +227,57 @@ fn disease_survival_history_rows(disease: &Disease) -> Vec<DiseaseSurvivalHistor
     rows
 }
 
+fn format_epidemiology_number(value: f64, unit: &str) -> String {
+    let number = if value.fract() == 0.0 || value.abs() >= 1000.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    };
+    if unit == "%" {
+        format!("{number}%")
+    } else {
+        number
+    }
+}
+
+/// Latest-year estimate per indicator, region, and age group; JSON keeps the full window.
+fn disease_epidemiology_rows(disease: &Disease) -> Vec<DiseaseEpidemiologyRenderRow> {
+    let Some(epidemiology) = disease.epidemiology.as_ref() else {
+        return Vec::new();
+    };
+
+    let mut seen = HashSet::new();
+    epidemiology
+        .estimates
+        .iter()
+        .filter(|row| {
+            seen.insert((
+                row.indicator_code.as_str(),
+                row.region_code.as_str(),
+                row.age_group.as_deref(),
+            ))
+        })
+        .map(|row| {
+            let mut value = format_epidemiology_number(row.value, &row.unit);
+            if let (Some(lower), Some(upper)) = (row.lower, row.upper) {
+                let lower = format_epidemiology_number(lower, &row.unit);
+                let upper = format_epidemiology_number(upper, &row.unit);
+                value = format!("{value} ({lower}-{upper})");
+            }
+            if row.unit != "%" {
+                value = format!("{value} {}", row.unit);
+            }
+            DiseaseEpidemiologyRenderRow {
+                indicator: format!("{} ({})", row.indicator, row.measure),
+                region: row.region.clone(),
+                year: row.year,
+                age_group: row.age_group.clone().unwrap_or_else(|| "-".into()),
+                value,
+            }
+        })
+        .collect()
+}
+
 pub fn disease_markdown(
     disease: &Disease,
     requested_sections: &[String],
@@ -246,6 +306,8 @@ pub fn disease_markdown(
     let show_funding_section = has_requested("funding");
     let show_civic_section = include_all || has_requested("civic");
     let show_disgenet_section = has_requested("disgenet");
+    let show_epidemiology_section = has_requested("epidemiology");
+    let show_therapies_section = has_requested("therapies");
     let disease_label = if disease.name.trim().is_empty() {
         disease.id.as_str()
     } else {
@@ -262,6 +324,7 @@ pub fn disease_markdown(
     let survival_history_rows = disease_survival_history_rows(disease);
     let funding_rows = funding_rows(disease.funding.as_ref());
     let funding_summary = funding_summary_line(disease.funding.as_ref());
+    let epidemiology_rows = disease_epidemiology_rows(disease);
     let body = tmpl.render(context! {
         section_only => section_only,
         section_header => section_header(disease_label, requested_sections),
@@ -297,6 +360,11 @@ pub fn disease_markdown(
         survival_source_line => survival_source_line,
         survival_summary_rows => survival_summary_rows,
         survival_history_rows => survival_history_rows,
+        epidemiology_source => disease.epidemiology.as_ref().map(|row| row.source.as_str()),
+        epidemiology_note => &disease.epidemiology_note,
+        epidemiology_rows => epidemiology_rows,
+        therapies => &disease.therapies,
+        therapies_note => &disease.therapies_note,
         civic => &disease.civic,
         disgenet => &disease.disgenet,
         show_genes_section => show_genes_section,
@@ -309,6 +377,8 @@ pub fn disease_markdown(
         show_funding_section => show_funding_section,
         show_civic_section => show_civic_section,
         show_disgenet_section => show_disgenet_section,
+        show_epidemiology_section => show_epidemiology_section,
+        show_therapies_section => show_therapies_section,
         xrefs => xrefs,
         sections_block => format_sections_block("disease", &disease.id, sections_disease(disease, requested_sections)),
         related_block => format_related_block(related_disease(disease)),
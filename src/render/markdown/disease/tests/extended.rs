@@ -22,6 +22,10 @@ fn disease_markdown_section_only_shows_disgenet_section() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: Some(crate::entities::disease::DiseaseDisgenet {
             associations: vec![crate::entities::disease::DiseaseDisgenetAssociation {
@@ -72,6 +76,10 @@ fn disease_markdown_disgenet_renders_sparse_optional_fields() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: Some(crate::entities::disease::DiseaseDisgenet {
             associations: vec![crate::entities::disease::DiseaseDisgenetAssociation {
@@ -120,6 +128,10 @@ fn disease_markdown_funding_renders_truthful_notes_without_table() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: Some(crate::sources::nih_reporter::NihReporterFundingSection {
@@ -173,6 +185,10 @@ fn disease_markdown_all_keeps_opt_in_sections_hidden() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -59,6 +59,10 @@ fn disease_markdown_renders_opentargets_scores_in_summary_and_genes_table() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -140,6 +144,10 @@ pub(crate) fn proof_disease_markdown_renders_ot_only_gene_association_table() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -207,6 +215,10 @@ fn disease_markdown_links_source_cells_and_footer_evidence_urls() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -256,6 +268,10 @@ fn disease_markdown_preserves_full_definition_text() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -312,6 +328,10 @@ fn disease_markdown_phenotypes_section_renders_key_features() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -361,6 +381,10 @@ fn disease_markdown_phenotypes_section_renders_definition_hint_when_key_features
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -411,6 +435,10 @@ fn disease_markdown_phenotypes_section_without_definition_only_shows_completenes
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -458,6 +486,10 @@ fn disease_markdown_renders_top_variant_summary() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -602,6 +634,10 @@ fn disease_markdown_renders_survival_summary_and_note() {
             ],
         }),
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -634,3 +670,109 @@ fn disease_markdown_renders_survival_summary_and_note() {
     assert!(note_markdown.contains("SEER survival data not available for this condition."));
     assert!(!note_markdown.contains("| Sex | Latest observed year |"));
 }
+
+#[test]
+fn disease_markdown_renders_latest_epidemiology_rows_per_region() {
+    let disease: Disease = serde_json::from_value(serde_json::json!({
+        "id": "MONDO:0018076",
+        "name": "tuberculosis",
+        "epidemiology": {
+            "source": "WHO Global Health Observatory",
+            "estimates": [
+                {
+                    "indicator_code": "MDG_0000000020",
+                    "indicator": "Incidence of tuberculosis",
+                    "measure": "incidence",
+                    "unit": "per 100 000 population per year",
+                    "region_code": "GLOBAL",
+                    "region": "Global",
+                    "year": 2022,
+                    "value": 133.0,
+                    "lower": 125.0,
+                    "upper": 142.0
+                },
+                {
+                    "indicator_code": "MDG_0000000020",
+                    "indicator": "Incidence of tuberculosis",
+                    "measure": "incidence",
+                    "unit": "per 100 000 population per year",
+                    "region_code": "GLOBAL",
+                    "region": "Global",
+                    "year": 2021,
+                    "value": 134.0
+                }
+            ]
+        }
+    }))
+    .expect("disease");
+
+    let markdown = disease_markdown(&disease, &["epidemiology".to_string()]).expect("markdown");
+    assert!(markdown.contains("## Epidemiology (WHO GHO)"));
+    assert!(markdown.contains(
+        "| Incidence of tuberculosis (incidence) | Global | 2022 | - | 133 (125-142) per 100 000 population per year |"
+    ));
+    assert!(!markdown.contains("| 2021 |"));
+    assert!(markdown.contains("Source: WHO Global Health Observatory."));
+
+    let mut note_disease = disease.clone();
+    note_disease.epidemiology = None;
+    note_disease.epidemiology_note =
+        Some("No WHO GHO incidence or prevalence indicator is curated for this condition.".into());
+    let note_markdown =
+        disease_markdown(&note_disease, &["epidemiology".to_string()]).expect("note markdown");
+    assert!(note_markdown.contains("No WHO GHO incidence or prevalence indicator is curated"));
+
+    let default_markdown = disease_markdown(&disease, &["all".to_string()]).expect("all markdown");
+    assert!(!default_markdown.contains("## Epidemiology"));
+}
+
+#[test]
+fn disease_markdown_renders_derived_therapy_lines_with_basis() {
+    let disease: Disease = serde_json::from_value(serde_json::json!({
+        "id": "MONDO:0008383",
+        "name": "rheumatoid arthritis",
+        "therapies": {
+            "basis": "Derived from regulatory approvals and clinical stage (OpenTargets indications, Drugs@FDA), not from curated treatment guidelines.",
+            "therapies": [
+                {
+                    "line": "first-line",
+                    "drug": "METHOTREXATE",
+                    "chembl_id": "CHEMBL34259",
+                    "drug_type": "Small molecule",
+                    "max_clinical_stage": "Approved",
+                    "fda_application": "NDA008085",
+                    "fda_approval_date": "1953-12-07"
+                },
+                {
+                    "line": "second-line",
+                    "drug": "OLOKIZUMAB",
+                    "max_clinical_stage": "Phase 3"
+                }
+            ]
+        }
+    }))
+    .expect("disease");
+
+    let markdown = disease_markdown(&disease, &["therapies".to_string()]).expect("markdown");
+    assert!(markdown.contains("## Therapies (derived from approvals)"));
+    assert!(markdown.contains(
+        "| first-line | METHOTREXATE | Small molecule | Approved | NDA008085 | 1953-12-07 |"
+    ));
+    assert!(markdown.contains("| second-line | OLOKIZUMAB | - | Phase 3 | - | - |"));
+    assert!(markdown.contains("not from curated treatment guidelines"));
+    assert!(markdown.contains("biomcp search guidelines -c \"rheumatoid arthritis\""));
+
+    let mut note_disease = disease.clone();
+    note_disease.therapies = None;
+    note_disease.therapies_note = Some(
+        "No approved or late-stage drugs with an OpenTargets indication for this condition."
+            .into(),
+    );
+    let note_markdown =
+        disease_markdown(&note_disease, &["therapies".to_string()]).expect("note markdown");
+    assert!(note_markdown.contains("No approved or late-stage drugs"));
+    assert!(!note_markdown.contains("| Line | Drug |"));
+
+    let default_markdown = disease_markdown(&disease, &["all".to_string()]).expect("all markdown");
+    assert!(!default_markdown.contains("## Therapies"));
+}
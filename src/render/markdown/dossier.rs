@@ -0,0 +1,43 @@
+//! Gene-disease evidence dossier markdown renderer.
+
+use super::*;
+
+#[cfg(test)]
+mod tests;
+
+/// CIViC evidence items listed in the report; `--json` carries all fetched items.
+const DOSSIER_CIVIC_ROWS: usize = 10;
+
+fn civic_level_rank(level: &str) -> usize {
+    ["A", "B", "C", "D", "E"]
+        .iter()
+        .position(|known| level.trim().eq_ignore_ascii_case(known))
+        .unwrap_or(usize::MAX)
+}
+
+pub fn dossier_markdown(dossier: &EvidenceDossier) -> Result<String, BioMcpError> {
+    let mut civic_items = dossier
+        .civic
+        .as_ref()
+        .map(|civic| civic.evidence_items.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+    civic_items.sort_by_key(|item| civic_level_rank(&item.evidence_level));
+    civic_items.truncate(DOSSIER_CIVIC_ROWS);
+
+    let tmpl = env()?.get_template("dossier.md.j2")?;
+    Ok(tmpl.render(context! {
+        gene => &dossier.gene,
+        disease => &dossier.disease,
+        score => &dossier.score,
+        association => &dossier.association,
+        civic_items => civic_items,
+        civic_total => dossier.civic.as_ref().map_or(0, |civic| civic.evidence_total_count),
+        assertion_total => dossier.civic.as_ref().map_or(0, |civic| civic.assertion_total_count),
+        drugs => &dossier.drugs,
+        trials => &dossier.trials,
+        trial_total => dossier.trial_total,
+        articles => &dossier.articles,
+        article_total => dossier.article_total,
+        notes => &dossier.notes,
+    })?)
+}
@@ -0,0 +1,100 @@
+use super::*;
+use crate::entities::dossier::{
+    DossierAssociation, DossierDatatypeScore, DossierDrug, DossierScore, DossierScoreComponent,
+};
+use crate::sources::civic::{CivicContext, CivicEvidenceItem};
+
+fn evidence_item(id: i64, level: &str) -> CivicEvidenceItem {
+    CivicEvidenceItem {
+        id,
+        name: format!("EID{id}"),
+        molecular_profile: "BRAF V600E".to_string(),
+        evidence_type: "Predictive".to_string(),
+        evidence_level: level.to_string(),
+        significance: "Sensitivity/Response".to_string(),
+        disease: Some("Melanoma".to_string()),
+        therapies: vec!["Vemurafenib".to_string()],
+        status: "accepted".to_string(),
+        citation: None,
+        source_type: None,
+        publication_year: None,
+    }
+}
+
+fn dossier() -> EvidenceDossier {
+    EvidenceDossier {
+        gene: "BRAF".to_string(),
+        disease: "melanoma".to_string(),
+        score: DossierScore {
+            total: 58,
+            label: "moderate".to_string(),
+            components: vec![DossierScoreComponent {
+                name: "OpenTargets association".to_string(),
+                points: 32,
+                max: 40,
+                basis: "overall score 0.810".to_string(),
+            }],
+        },
+        association: Some(DossierAssociation {
+            disease_id: "EFO_0000756".to_string(),
+            disease_name: Some("melanoma".to_string()),
+            overall_score: Some(0.81),
+            datatype_scores: vec![DossierDatatypeScore {
+                datatype: "somatic_mutation".to_string(),
+                score: 0.9,
+            }],
+        }),
+        civic: Some(CivicContext {
+            evidence_total_count: 14,
+            assertion_total_count: 1,
+            evidence_items: vec![evidence_item(2, "C"), evidence_item(1, "A")],
+            assertions: Vec::new(),
+        }),
+        drugs: vec![DossierDrug {
+            name: "VEMURAFENIB".to_string(),
+            chembl_id: Some("CHEMBL1229517".to_string()),
+            drug_type: Some("Small molecule".to_string()),
+            stage: Some("Approved".to_string()),
+        }],
+        trials: Vec::new(),
+        trial_total: Some(0),
+        articles: Vec::new(),
+        article_total: None,
+        notes: vec!["Article search unavailable: timeout".to_string()],
+    }
+}
+
+#[test]
+fn dossier_markdown_renders_score_breakdown_and_sections() {
+    let markdown = dossier_markdown(&dossier()).expect("dossier should render");
+
+    assert!(markdown.starts_with("# Evidence dossier: BRAF in melanoma\n"));
+    assert!(markdown.contains("Score: 58/100 (moderate)."));
+    assert!(markdown.contains("| OpenTargets association | 32/40 | overall score 0.810 |"));
+    assert!(markdown.contains("melanoma (EFO_0000756), overall score 0.810."));
+    assert!(markdown.contains("| somatic_mutation | 0.900 |"));
+    assert!(markdown.contains("14 evidence items, 1 assertion."));
+    let level_a = markdown.find("| 1 | BRAF V600E |").expect("level A row");
+    let level_c = markdown.find("| 2 | BRAF V600E |").expect("level C row");
+    assert!(level_a < level_c);
+    assert!(markdown.contains("Showing 2 of 14 items, highest level first."));
+    assert!(markdown.contains("| VEMURAFENIB | Small molecule | Approved |"));
+    assert!(markdown.contains("No recruiting or active trials with BRAF as a biomarker."));
+    assert!(markdown.contains("- Article search unavailable: timeout"));
+    assert!(markdown.contains("See also: biomcp get gene BRAF civic"));
+}
+
+#[test]
+fn dossier_markdown_handles_missing_sources() {
+    let mut dossier = dossier();
+    dossier.association = None;
+    dossier.civic = None;
+    dossier.drugs.clear();
+    dossier.notes.clear();
+    let markdown = dossier_markdown(&dossier).expect("dossier should render");
+
+    assert!(markdown.contains("No OpenTargets association found."));
+    assert!(markdown.contains("No CIViC evidence for BRAF in melanoma."));
+    assert!(markdown.contains("No OpenTargets drugs for this disease target BRAF."));
+    assert!(!markdown.contains("## Notes"));
+}
@@ -30,6 +30,7 @@ pub fn drug_markdown_with_region(
     let show_shortage_section = !matches!(region, DrugRegion::Who)
         && (!section_only || include_all || has_requested("shortage"));
     let show_approvals_section = has_requested("approvals");
+    let show_patents_section = has_requested("patents");
     // Suppress US-only header facts when rendering a full card (not section_only) for EU region.
     let show_us_header = section_only || region.includes_us();
     let approval_date_display: Option<&str> = if show_us_header {
@@ -43,6 +44,7 @@ pub fn drug_markdown_with_region(
         drug_interactions_heading => crate::render::provenance::drug_interaction_heading_label(drug),
         name => &drug.name,
         drugbank_id => &drug.drugbank_id,
+        ncit => &drug.ncit,
         chembl_id => &drug.chembl_id,
         unii => &drug.unii,
         drug_type => &drug.drug_type,
@@ -61,15 +63,18 @@ pub fn drug_markdown_with_region(
         indications => &drug.indications,
         interactions => &drug.interactions,
         interaction_text => &drug.interaction_text,
+        supplement_interactions => &drug.supplement_interactions,
         pharm_classes => &drug.pharm_classes,
         label => &drug.label,
         raw_label => raw_label,
         civic => &drug.civic,
+        patents => &drug.patents,
         show_label_section => show_label_section,
         show_targets_section => show_targets_section,
         show_indications_section => show_indications_section,
         show_interactions_section => show_interactions_section,
         show_civic_section => show_civic_section,
+        show_patents_section => show_patents_section,
         regulatory_block => if show_regulatory_section { render_regulatory_block(drug, region) } else { String::new() },
         safety_block => if show_safety_section { render_safety_block(drug, region) } else { String::new() },
         shortage_block => if show_shortage_section { render_shortage_block(drug, region) } else { String::new() },
@@ -114,6 +119,18 @@ pub fn drug_search_markdown_with_footer(
     Ok(with_pagination_footer(body, pagination_footer))
 }
 
+pub fn repurpose_markdown(result: &RepurposeResult) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("repurpose.md.j2")?;
+    Ok(tmpl.render(context! {
+        job_id => &result.job_id,
+        up_genes => result.up_genes,
+        down_genes => result.down_genes,
+        unresolved_genes => &result.unresolved_genes,
+        compounds_scored => result.compounds_scored,
+        candidates => &result.candidates,
+    })?)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn drug_search_markdown_with_region(
     query: &str,
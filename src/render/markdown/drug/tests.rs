@@ -23,12 +23,14 @@ fn drug_markdown_uses_label_interaction_text_before_public_unavailable_fallback(
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: Some("DRUG INTERACTIONS\n\nWarfarin interacts with aspirin.".to_string()),
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: Vec::new(),
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: None,
+        shortage_trials: None,
         approvals: None,
         us_safety_warnings: None,
         ema_regulatory: None,
@@ -36,6 +38,8 @@ fn drug_markdown_uses_label_interaction_text_before_public_unavailable_fallback(
         ema_shortage: None,
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     let markdown = drug_markdown(&drug, &["interactions".to_string()]).expect("markdown");
@@ -67,12 +71,14 @@ fn drug_markdown_uses_truthful_public_unavailable_interactions_message() {
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: Vec::new(),
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: None,
+        shortage_trials: None,
         approvals: None,
         us_safety_warnings: None,
         ema_regulatory: None,
@@ -80,6 +86,8 @@ fn drug_markdown_uses_truthful_public_unavailable_interactions_message() {
         ema_shortage: None,
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     let markdown = drug_markdown(&drug, &["interactions".to_string()]).expect("markdown");
@@ -87,6 +95,31 @@ fn drug_markdown_uses_truthful_public_unavailable_interactions_message() {
     assert!(!markdown.contains("No known drug-drug interactions found."));
 }
 
+#[test]
+fn drug_markdown_lists_supplement_interactions_with_lower_confidence_label() {
+    let mut drug: Drug =
+        serde_json::from_value(serde_json::json!({"name": "cyclosporine"})).expect("drug");
+    drug.supplement_interactions = vec![crate::sources::medlineplus_herbs::SupplementInteraction {
+        supplement: "St. John's wort".to_string(),
+        medication: "Cyclosporine (Neoral, Sandimmune)".to_string(),
+        severity: Some("Major".to_string()),
+        description: "St. John's wort might decrease how much cyclosporine is in the body."
+            .to_string(),
+        evidence_confidence: "low".to_string(),
+        source_url: "https://medlineplus.gov/druginfo/natural/329.html".to_string(),
+    }];
+
+    let markdown = drug_markdown(&drug, &["interactions".to_string()]).expect("markdown");
+    assert!(markdown.contains("## Interactions (MedlinePlus Supplements)"));
+    assert!(
+        markdown.contains("### Supplement Interactions (MedlinePlus; lower evidence confidence)")
+    );
+    assert!(markdown.contains(
+        "| St. John's wort | Cyclosporine (Neoral, Sandimmune) | Major | St. John's wort might decrease"
+    ));
+    assert!(!markdown.contains("Interaction details not available from public sources."));
+}
+
 #[test]
 fn drug_markdown_shows_target_family_and_members_when_present() {
     let drug = Drug {
@@ -114,12 +147,14 @@ fn drug_markdown_shows_target_family_and_members_when_present() {
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: Vec::new(),
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: None,
+        shortage_trials: None,
         approvals: None,
         us_safety_warnings: None,
         ema_regulatory: None,
@@ -127,6 +162,8 @@ fn drug_markdown_shows_target_family_and_members_when_present() {
         ema_shortage: None,
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     let markdown = drug_markdown(&drug, &["targets".to_string()]).expect("markdown");
@@ -134,6 +171,32 @@ fn drug_markdown_shows_target_family_and_members_when_present() {
     assert!(markdown.contains("Members: PARP1, PARP2, PARP3"));
 }
 
+#[test]
+fn drug_markdown_header_shows_ncit_code_name_mapping() {
+    let mut drug: Drug = serde_json::from_value(serde_json::json!({
+        "name": "venetoclax",
+        "drugbank_id": "DB11581"
+    }))
+    .expect("drug json");
+    drug.ncit = Some(crate::entities::drug::DrugNcitMapping {
+        code: "C95746".to_string(),
+        preferred_name: "Venetoclax".to_string(),
+        queried_name: "ABT-199".to_string(),
+        code_names: vec!["ABT-199".to_string(), "GDC-0199".to_string()],
+    });
+
+    let markdown = drug_markdown(&drug, &[]).expect("markdown");
+    assert!(
+        markdown.contains(
+            "NCI Thesaurus: ABT-199 → Venetoclax (C95746); code names: ABT-199, GDC-0199"
+        )
+    );
+
+    drug.ncit = None;
+    let markdown = drug_markdown(&drug, &[]).expect("markdown");
+    assert!(!markdown.contains("NCI Thesaurus"));
+}
+
 #[test]
 fn drug_markdown_renders_variant_targets_as_additive_line() {
     let drug = Drug {
@@ -157,12 +220,14 @@ fn drug_markdown_renders_variant_targets_as_additive_line() {
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: Vec::new(),
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: None,
+        shortage_trials: None,
         approvals: None,
         us_safety_warnings: None,
         ema_regulatory: None,
@@ -170,6 +235,8 @@ fn drug_markdown_renders_variant_targets_as_additive_line() {
         ema_shortage: None,
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     let markdown = drug_markdown(&drug, &["targets".to_string()]).expect("markdown");
@@ -201,12 +268,14 @@ fn drug_markdown_omits_target_family_for_mixed_targets() {
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: Vec::new(),
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: None,
+        shortage_trials: None,
         approvals: None,
         us_safety_warnings: None,
         ema_regulatory: None,
@@ -214,6 +283,8 @@ fn drug_markdown_omits_target_family_for_mixed_targets() {
         ema_shortage: None,
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     let markdown = drug_markdown(&drug, &["targets".to_string()]).expect("markdown");
@@ -245,6 +316,7 @@ fn drug_markdown_with_region_all_keeps_us_and_eu_blocks_separate() {
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: vec!["Rash".to_string()],
         faers_query: None,
@@ -259,6 +331,7 @@ fn drug_markdown_with_region_all_keeps_us_and_eu_blocks_separate() {
             update_date: Some("2026-01-13".to_string()),
             initial_posting_date: None,
         }]),
+        shortage_trials: None,
         approvals: Some(vec![DrugApproval {
             application_number: "BLA125514".to_string(),
             sponsor_name: Some("Merck Sharp & Dohme".to_string()),
@@ -299,6 +372,8 @@ fn drug_markdown_with_region_all_keeps_us_and_eu_blocks_separate() {
         }]),
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     let markdown = drug_markdown_with_region(&drug, &["all".to_string()], DrugRegion::All, false)
@@ -338,12 +413,14 @@ fn drug_markdown_with_region_who_renders_regulatory_block() {
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: Vec::new(),
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: None,
+        shortage_trials: None,
         approvals: None,
         us_safety_warnings: None,
         ema_regulatory: None,
@@ -363,6 +440,8 @@ fn drug_markdown_with_region_who_renders_regulatory_block() {
             prequalification_date: Some("2019-12-18".to_string()),
         }]),
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     let markdown =
@@ -442,12 +521,14 @@ fn drug_markdown_with_region_eu_all_suppresses_us_header_facts() {
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: vec!["Fatigue".to_string(), "Rash".to_string()],
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: None,
+        shortage_trials: None,
         approvals: None,
         us_safety_warnings: None,
         ema_regulatory: Some(vec![EmaRegulatoryRow {
@@ -466,6 +547,8 @@ fn drug_markdown_with_region_eu_all_suppresses_us_header_facts() {
         ema_shortage: Some(Vec::new()),
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     let markdown = drug_markdown_with_region(&drug, &["all".to_string()], DrugRegion::Eu, false)
@@ -509,12 +592,14 @@ fn drug_markdown_with_region_eu_safety_shows_truthful_empty_subsections() {
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: Vec::new(),
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: None,
+        shortage_trials: None,
         approvals: None,
         us_safety_warnings: None,
         ema_regulatory: None,
@@ -532,6 +617,8 @@ fn drug_markdown_with_region_eu_safety_shows_truthful_empty_subsections() {
         ema_shortage: None,
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     let markdown = drug_markdown_with_region(&drug, &["safety".to_string()], DrugRegion::Eu, false)
@@ -670,3 +757,133 @@ fn drug_search_all_region_empty_state_includes_discover_only_when_both_regions_a
     .expect("markdown");
     assert!(!eu_only_markdown.contains("Try: biomcp discover MK-3475"));
 }
+
+#[test]
+fn drug_markdown_flags_open_trials_during_active_shortage() {
+    let drug: Drug = serde_json::from_value(serde_json::json!({
+        "name": "cisplatin",
+        "shortage": [{"status": "Current", "availability": "Limited"}],
+        "shortage_trials": [{
+            "nct_id": "NCT01234567",
+            "title": "Cisplatin Plus Radiation in Head and Neck Cancer",
+            "status": "RECRUITING",
+            "phase": "PHASE3",
+            "conditions": ["Head and Neck Cancer"]
+        }]
+    }))
+    .expect("drug should deserialize");
+
+    let markdown =
+        drug_markdown_with_region(&drug, &["shortage".to_string()], DrugRegion::Us, false)
+            .expect("markdown");
+    assert!(markdown.contains("### Open Trials Using This Drug (ClinicalTrials.gov)"));
+    assert!(markdown.contains("**Shortage flag:** 1 open trial(s) list cisplatin"));
+    assert!(markdown.contains(
+        "| NCT01234567 | Cisplatin Plus Radiation in Head and Neck Cancer | RECRUITING | PHASE3 |"
+    ));
+    assert!(markdown.contains("More: `biomcp drug trials cisplatin`"));
+
+    let mut resolved = drug.clone();
+    resolved.shortage_trials = None;
+    let markdown =
+        drug_markdown_with_region(&resolved, &["shortage".to_string()], DrugRegion::Us, false)
+            .expect("markdown");
+    assert!(!markdown.contains("Open Trials Using This Drug"));
+}
+
+#[test]
+fn repurpose_markdown_ranks_candidates_and_links_named_drugs() {
+    use crate::entities::repurpose::{RepurposeCandidate, RepurposeResult};
+
+    let result = RepurposeResult {
+        job_id: "5f3c9a".to_string(),
+        up_genes: 120,
+        down_genes: 98,
+        unresolved_genes: vec!["LINC00473".to_string()],
+        compounds_scored: 2429,
+        candidates: vec![
+            RepurposeCandidate {
+                rank: 1,
+                pert_id: "BRD-K81418486".to_string(),
+                name: Some("vorinostat".to_string()),
+                drug: Some("vorinostat".to_string()),
+                moa: Some("HDAC inhibitor".to_string()),
+                targets: vec!["HDAC1".to_string(), "HDAC2".to_string()],
+                score: -99.871,
+            },
+            RepurposeCandidate {
+                rank: 2,
+                pert_id: "BRD-A12345678".to_string(),
+                name: None,
+                drug: None,
+                moa: None,
+                targets: Vec::new(),
+                score: -97.5,
+            },
+        ],
+    };
+
+    let markdown = repurpose_markdown(&result).expect("markdown");
+    assert!(markdown.contains("Signature: 120 up / 98 down genes"));
+    assert!(markdown.contains("scored 2429 compounds"));
+    assert!(markdown.contains("Unresolved genes (not sent): LINC00473"));
+    assert!(
+        markdown.contains(
+            "| 1 | vorinostat | BRD-K81418486 | HDAC inhibitor | HDAC1, HDAC2 | -99.87 |"
+        )
+    );
+    assert!(markdown.contains("| 2 | - | BRD-A12345678 | - | - | -97.5 |"));
+    assert!(markdown.contains("Use `biomcp get drug vorinostat`"));
+
+    let empty = RepurposeResult {
+        candidates: Vec::new(),
+        unresolved_genes: Vec::new(),
+        ..result
+    };
+    let markdown = repurpose_markdown(&empty).expect("markdown");
+    assert!(markdown.contains("No compounds with a negative connectivity score"));
+    assert!(!markdown.contains("Unresolved genes"));
+}
+
+#[test]
+fn drug_markdown_renders_patents_section_with_estimated_expiry() {
+    use crate::entities::drug::{DrugPatent, DrugPatentFamily, DrugPatents};
+
+    let patent = |id: &str| DrugPatent {
+        patent_id: id.to_string(),
+        title: None,
+        grant_date: None,
+        patent_type: Some("utility".to_string()),
+        url: format!("https://patents.google.com/patent/US{id}"),
+    };
+    let mut drug = crate::transform::drug::merge_mychem_hits(&[], "imatinib");
+    drug.patents = Some(DrugPatents {
+        query_terms: vec!["imatinib".to_string(), "Gleevec".to_string()],
+        total_hits: 37,
+        families: vec![DrugPatentFamily {
+            assignee: Some("Novartis AG".to_string()),
+            earliest_application_date: Some("2004-04-01".to_string()),
+            estimated_expiry: Some("2024-04-01".to_string()),
+            expired: true,
+            patents: vec![
+                patent("8067421"),
+                patent("9000001"),
+                patent("9000002"),
+                patent("9000003"),
+            ],
+        }],
+        basis: "Informational only, not legal or freedom-to-operate advice.".to_string(),
+    });
+
+    let markdown = drug_markdown(&drug, &["patents".to_string()]).expect("markdown");
+    assert!(markdown.contains("## Patents (PatentsView; informational)"));
+    assert!(markdown.contains("Searched: imatinib, Gleevec (37 matching US patents)"));
+    assert!(markdown.contains(
+        "| 2024-04-01 (expired) | 2004-04-01 | Novartis AG | 4 | US8067421, US9000001, US9000002 +1 more |"
+    ));
+    assert!(markdown.contains("not legal or freedom-to-operate advice"));
+
+    drug.patents = None;
+    let markdown = drug_markdown(&drug, &["all".to_string()]).expect("markdown");
+    assert!(!markdown.contains("## Patents"));
+}
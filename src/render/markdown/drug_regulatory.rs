@@ -344,9 +344,46 @@ fn render_eu_safety_block(heading: &str, safety: Option<&EmaSafetyInfo>) -> Stri
     out
 }
 
+fn render_shortage_trials(out: &mut String, drug_name: &str, trials: &[TrialSearchResult]) {
+    let _ = writeln!(
+        out,
+        "\n### Open Trials Using This Drug (ClinicalTrials.gov)\n"
+    );
+    if trials.is_empty() {
+        out.push_str("No recruiting or active trials list this drug as an intervention.\n");
+        return;
+    }
+
+    let _ = writeln!(
+        out,
+        "**Shortage flag:** {} open trial(s) list {} as an intervention; confirm supply before enrolling or dosing.\n",
+        trials.len(),
+        markdown_cell(drug_name),
+    );
+    out.push_str("| NCT ID | Title | Status | Phase |\n");
+    out.push_str("|---|---|---|---|\n");
+    for trial in trials {
+        let _ = writeln!(
+            out,
+            "| {} | {} | {} | {} |",
+            markdown_cell(&trial.nct_id),
+            markdown_cell(&trial.title),
+            markdown_cell(&trial.status),
+            trial
+                .phase
+                .as_deref()
+                .map(markdown_cell)
+                .unwrap_or_else(|| "-".to_string()),
+        );
+    }
+    let _ = writeln!(out, "\nMore: `biomcp drug trials {}`", quote_arg(drug_name));
+}
+
 fn render_us_shortage_block(
     heading: &str,
+    drug_name: &str,
     shortage: Option<&[crate::entities::drug::DrugShortageEntry]>,
+    trials: Option<&[TrialSearchResult]>,
 ) -> String {
     let Some(shortage) = shortage else {
         return String::new();
@@ -387,6 +424,9 @@ fn render_us_shortage_block(
                 .unwrap_or_else(|| "-".to_string()),
         );
     }
+    if let Some(trials) = trials {
+        render_shortage_trials(&mut out, drug_name, trials);
+    }
     out
 }
 
@@ -485,7 +525,9 @@ pub(super) fn render_shortage_block(drug: &Drug, region: DrugRegion) -> String {
     match region {
         DrugRegion::Us => render_us_shortage_block(
             "## Shortage (US - OpenFDA Drug Shortages)",
+            &drug.name,
             drug.shortage.as_deref(),
+            drug.shortage_trials.as_deref(),
         ),
         DrugRegion::Eu => {
             render_eu_shortage_block("## Shortage (EU - EMA)", drug.ema_shortage.as_deref())
@@ -494,7 +536,9 @@ pub(super) fn render_shortage_block(drug: &Drug, region: DrugRegion) -> String {
         DrugRegion::All => {
             let us = render_us_shortage_block(
                 "## Shortage (US - OpenFDA Drug Shortages)",
+                &drug.name,
                 drug.shortage.as_deref(),
+                drug.shortage_trials.as_deref(),
             );
             let eu =
                 render_eu_shortage_block("## Shortage (EU - EMA)", drug.ema_shortage.as_deref());
@@ -361,6 +361,26 @@ pub(super) fn pathway_evidence_urls(pathway: &Pathway) -> Vec<(&'static str, Str
     )]
 }
 
+pub(super) fn study_evidence_urls(study: &Study) -> Vec<(&'static str, String)> {
+    let id = study.study_id.trim();
+    if id.is_empty() {
+        return Vec::new();
+    }
+    let mut urls = vec![(
+        "cBioPortal",
+        format!("https://www.cbioportal.org/study/summary?id={id}"),
+    )];
+    if let Some(pmid) = study
+        .pmid
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        urls.push(("PubMed", format!("https://pubmed.ncbi.nlm.nih.gov/{pmid}/")));
+    }
+    urls
+}
+
 pub(super) fn protein_evidence_urls(protein: &Protein) -> Vec<(&'static str, String)> {
     if protein.accession.trim().is_empty() {
         return Vec::new();
@@ -374,6 +394,57 @@ pub(super) fn protein_evidence_urls(protein: &Protein) -> Vec<(&'static str, Str
     )]
 }
 
+pub(super) fn structure_evidence_urls(structure: &Structure) -> Vec<(&'static str, String)> {
+    let id = structure.pdb_id.trim();
+    if id.is_empty() {
+        return Vec::new();
+    }
+    let mut urls = vec![
+        ("RCSB PDB", format!("https://www.rcsb.org/structure/{id}")),
+        (
+            "PDBe",
+            format!(
+                "https://www.ebi.ac.uk/pdbe/entry/pdb/{}",
+                id.to_ascii_lowercase()
+            ),
+        ),
+        ("mmCIF", format!("https://files.rcsb.org/download/{id}.cif")),
+    ];
+    if let Some(pmid) = structure
+        .citation
+        .as_ref()
+        .and_then(|citation| citation.pmid.as_deref())
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        urls.push(("PubMed", format!("https://pubmed.ncbi.nlm.nih.gov/{pmid}/")));
+    }
+    urls
+}
+
+pub(super) fn transcript_evidence_urls(transcript: &Transcript) -> Vec<(&'static str, String)> {
+    let id = transcript.id.trim();
+    if id.is_empty() {
+        return Vec::new();
+    }
+    let mut urls = vec![(
+        "Ensembl",
+        format!("https://www.ensembl.org/Homo_sapiens/Transcript/Summary?t={id}"),
+    )];
+    if let Some(refseq) = transcript
+        .mane_select
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        urls.push((
+            "NCBI RefSeq",
+            format!("https://www.ncbi.nlm.nih.gov/nuccore/{refseq}"),
+        ));
+    }
+    urls
+}
+
 pub(super) fn adverse_event_evidence_urls(event: &AdverseEvent) -> Vec<(&'static str, String)> {
     if event.report_id.trim().is_empty() {
         return Vec::new();
@@ -400,6 +471,59 @@ pub(super) fn device_event_evidence_urls(event: &DeviceEvent) -> Vec<(&'static s
     )]
 }
 
+pub(super) fn device_clearance_evidence_urls(
+    clearance: &DeviceClearance,
+) -> Vec<(&'static str, String)> {
+    let k_number = clearance.k_number.trim();
+    if k_number.is_empty() {
+        return Vec::new();
+    }
+    vec![
+        (
+            "FDA 510(k)",
+            format!(
+                "https://www.accessdata.fda.gov/scripts/cdrh/cfdocs/cfpmn/pmn.cfm?ID={k_number}"
+            ),
+        ),
+        (
+            "OpenFDA",
+            format!("https://api.fda.gov/device/510k.json?search=k_number:{k_number}"),
+        ),
+    ]
+}
+
+pub(super) fn hla_evidence_urls(hla: &Hla) -> Vec<(&'static str, String)> {
+    let mut urls = Vec::new();
+    if let Some(accession) = hla
+        .registry
+        .as_ref()
+        .and_then(|registry| registry.accession.as_deref())
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        urls.push((
+            "IPD-IMGT/HLA",
+            format!("https://www.ebi.ac.uk/ipd/imgt/hla/alleles/allele/?accession={accession}"),
+        ));
+    }
+    if let Some(name) = hla.allele.strip_prefix("HLA-") {
+        urls.push((
+            "Allele Frequency Net",
+            format!("https://www.allelefrequencies.net/hla6006a.asp?hla_selection={name}"),
+        ));
+    }
+    if !hla.drug_associations.is_empty() {
+        urls.push((
+            "CPIC",
+            format!(
+                "https://cpicpgx.org/genes/{}/",
+                hla.gene.to_ascii_lowercase()
+            ),
+        ));
+    }
+    urls
+}
+
 pub(super) fn pgx_evidence_urls(pgx: &Pgx) -> Vec<(&'static str, String)> {
     let mut urls = Vec::new();
     if let Some(gene) = pgx.gene.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
@@ -13,6 +13,7 @@ fn gene_evidence_urls_include_ensembl_and_omim() {
         uniprot_id: Some("P15056".to_string()),
         summary: None,
         gene_type: None,
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -29,8 +30,14 @@ fn gene_evidence_urls_include_ensembl_and_omim() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let urls = gene_evidence_urls(&gene);
@@ -145,6 +152,10 @@ fn disease_evidence_urls_include_record_links() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -189,12 +200,14 @@ fn drug_evidence_urls_include_chembl() {
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: Vec::new(),
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: None,
+        shortage_trials: None,
         approvals: None,
         us_safety_warnings: None,
         ema_regulatory: None,
@@ -202,6 +215,8 @@ fn drug_evidence_urls_include_chembl() {
         ema_shortage: None,
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     let urls = drug_evidence_urls(&drug);
@@ -234,6 +249,7 @@ fn drug_evidence_urls_include_faers_and_dailymed_when_sections_exist() {
             indications: Vec::new(),
             interactions: Vec::new(),
             interaction_text: None,
+            supplement_interactions: Vec::new(),
             pharm_classes: Vec::new(),
             top_adverse_events: vec!["Rash".to_string()],
             faers_query: Some(
@@ -248,6 +264,7 @@ fn drug_evidence_urls_include_faers_and_dailymed_when_sections_exist() {
             }),
             label_set_id: Some("set-123".to_string()),
             shortage: None,
+            shortage_trials: None,
             approvals: None,
             us_safety_warnings: None,
             ema_regulatory: None,
@@ -255,6 +272,8 @@ fn drug_evidence_urls_include_faers_and_dailymed_when_sections_exist() {
             ema_shortage: None,
             who_prequalification: None,
             civic: None,
+            patents: None,
+            ncit: None,
         };
 
     let urls = drug_evidence_urls(&drug);
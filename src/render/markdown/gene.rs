@@ -16,10 +16,18 @@ pub fn gene_markdown(gene: &Gene, requested_sections: &[String]) -> Result<Strin
     let show_hpa_section = include_all || has_requested("hpa");
     let show_druggability_section =
         include_all || has_requested("druggability") || has_requested("drugs");
+    let show_tractability_section = has_requested("tractability") || has_requested("safety");
     let show_clingen_section = include_all || has_requested("clingen");
     let show_constraint_section = include_all || has_requested("constraint");
     let show_disgenet_section = has_requested("disgenet");
+    let show_clinvar_summary_section = has_requested("clinvar-summary")
+        || has_requested("clinvar_summary")
+        || has_requested("clinvar");
     let show_funding_section = has_requested("funding");
+    let show_key_papers_section = has_requested("key-papers") || has_requested("key_papers");
+    let show_dependency_section = has_requested("dependency") || has_requested("depmap");
+    let show_orthologs_section =
+        has_requested("orthologs") || has_requested("orthologues") || has_requested("orthology");
     let funding_rows = funding_rows(gene.funding.as_ref());
     let funding_summary = funding_summary_line(gene.funding.as_ref());
     let body = tmpl.render(context! {
@@ -35,6 +43,11 @@ pub fn gene_markdown(gene: &Gene, requested_sections: &[String]) -> Result<Strin
         uniprot_id => &gene.uniprot_id,
         summary => &gene.summary,
         gene_type => &gene.gene_type,
+        species => gene
+            .species
+            .as_deref()
+            .and_then(|v| v.parse::<GeneSpecies>().ok())
+            .map(GeneSpecies::description),
         aliases => &gene.aliases,
         clinical_diseases => &gene.clinical_diseases,
         clinical_drugs => &gene.clinical_drugs,
@@ -48,27 +61,195 @@ pub fn gene_markdown(gene: &Gene, requested_sections: &[String]) -> Result<Strin
         expression => &gene.expression,
         hpa => &gene.hpa,
         druggability => &gene.druggability,
+        tractability => &gene.tractability,
         clingen => &gene.clingen,
         constraint => &gene.constraint,
         disgenet => &gene.disgenet,
+        clinvar_summary => &gene.clinvar_summary,
         funding => &gene.funding,
         funding_note => &gene.funding_note,
         funding_rows => funding_rows,
         funding_summary => funding_summary,
+        key_papers => &gene.key_papers,
+        dependency => &gene.dependency,
+        orthologs => &gene.orthologs,
+        plugin_sections => plugin_section_views(&gene.plugin_sections),
         show_civic_section => show_civic_section,
         show_expression_section => show_expression_section,
         show_hpa_section => show_hpa_section,
         show_druggability_section => show_druggability_section,
+        show_tractability_section => show_tractability_section,
         show_clingen_section => show_clingen_section,
         show_constraint_section => show_constraint_section,
         show_disgenet_section => show_disgenet_section,
+        show_clinvar_summary_section => show_clinvar_summary_section,
         show_funding_section => show_funding_section,
+        show_key_papers_section => show_key_papers_section,
+        show_dependency_section => show_dependency_section,
+        show_orthologs_section => show_orthologs_section,
         sections_block => format_sections_block("gene", &gene.symbol, sections_gene(gene, requested_sections)),
         related_block => format_related_block(related_gene(gene)),
     })?;
     Ok(append_evidence_urls(body, gene_evidence_urls(gene)))
 }
 
+const VARIANT_MAP_BAR_WIDTH: u32 = 40;
+const VARIANT_MAP_TOP_POSITIONS: usize = 15;
+
+fn variant_map_histogram_rows(map: &GeneVariantMap) -> Vec<String> {
+    let max_total = map
+        .bins
+        .iter()
+        .map(|bin| bin.pathogenic + bin.somatic)
+        .max()
+        .unwrap_or(0);
+    if max_total == 0 {
+        return Vec::new();
+    }
+    let width = map
+        .bins
+        .last()
+        .map(|bin| bin.end)
+        .unwrap_or(0)
+        .to_string()
+        .len();
+    let scale = |count: u32| {
+        if count == 0 {
+            0
+        } else {
+            (count * VARIANT_MAP_BAR_WIDTH).div_ceil(max_total) as usize
+        }
+    };
+    map.bins
+        .iter()
+        .map(|bin| {
+            let range = format!("{:>width$}-{:<width$}", bin.start, bin.end);
+            let bar = format!(
+                "{}{}",
+                "#".repeat(scale(bin.pathogenic)),
+                "=".repeat(scale(bin.somatic))
+            );
+            format!("{range} |{bar} {}/{}", bin.pathogenic, bin.somatic)
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+pub fn gene_variant_map_markdown(map: &GeneVariantMap) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("gene_variant_map.md.j2")?;
+    let mut top_positions = map.positions.iter().collect::<Vec<_>>();
+    top_positions.sort_by(|a, b| {
+        (b.pathogenic + b.somatic)
+            .cmp(&(a.pathogenic + a.somatic))
+            .then_with(|| a.position.cmp(&b.position))
+    });
+    top_positions.truncate(VARIANT_MAP_TOP_POSITIONS);
+    Ok(tmpl.render(context! {
+        symbol => &map.symbol,
+        protein_length => map.protein_length,
+        bin_size => map.bin_size,
+        pathogenic_total => map.pathogenic_total,
+        somatic_total => map.somatic_total,
+        somatic_study => &map.somatic_study,
+        histogram_rows => variant_map_histogram_rows(map),
+        top_positions => top_positions,
+        notes => &map.notes,
+    })?)
+}
+
+pub fn gene_clinvar_variants_markdown(table: &GeneClinVarVariants) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("gene_clinvar_variants.md.j2")?;
+    Ok(tmpl.render(context! {
+        symbol => &table.symbol,
+        significance => &table.significance,
+        total => table.total,
+        offset => table.offset,
+        complete => table.complete,
+        variants => &table.variants,
+    })?)
+}
+
+pub fn gene_deg_annotation_markdown(
+    table: &DegAnnotation,
+    limit: usize,
+) -> Result<String, BioMcpError> {
+    let column_index = |name: &Option<String>| {
+        name.as_ref()
+            .and_then(|name| table.columns.iter().position(|column| column == name))
+    };
+    let fold_change = column_index(&table.fold_change_column);
+    let adjusted_p = column_index(&table.adjusted_p_column);
+    let value = |row: &crate::entities::gene::DegRow, index: Option<usize>| {
+        index
+            .and_then(|index| row.values.get(index))
+            .filter(|value| !value.is_empty())
+            .cloned()
+    };
+    let rows = table
+        .rows
+        .iter()
+        .take(limit)
+        .map(|row| {
+            context! {
+                input => &row.input,
+                symbol => &row.symbol,
+                name => &row.name,
+                fold_change => value(row, fold_change),
+                adjusted_p => value(row, adjusted_p),
+                druggable => row.druggable,
+                drug_count => row.drug_count,
+                approved_drug_count => row.approved_drug_count,
+            }
+        })
+        .collect::<Vec<_>>();
+    let druggable_count = table
+        .rows
+        .iter()
+        .filter(|row| row.druggable == Some(true))
+        .count();
+
+    let tmpl = env()?.get_template("gene_deg_annotation.md.j2")?;
+    Ok(tmpl.render(context! {
+        source => &table.source,
+        gene_column => &table.gene_column,
+        id_type => &table.id_type,
+        total => table.rows.len(),
+        resolved => table.resolved,
+        druggable_checked => table.druggable_checked,
+        druggable_count => druggable_count,
+        fold_change_column => &table.fold_change_column,
+        adjusted_p_column => &table.adjusted_p_column,
+        rows => rows,
+    })?)
+}
+
+pub fn gene_diff_markdown(diff: &GeneDiff) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("gene_diff.md.j2")?;
+    Ok(tmpl.render(context! {
+        symbol => &diff.symbol,
+        previous_snapshot => &diff.previous_snapshot,
+        previous_taken_at => &diff.previous_taken_at,
+        current_taken_at => &diff.current_taken_at,
+        changes => &diff.changes,
+        saved_snapshot => &diff.saved_snapshot,
+    })?)
+}
+
+pub fn gene_panel_markdown(report: &GenePanelReport) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("panel.md.j2")?;
+    let gene_names = report
+        .genes
+        .iter()
+        .map(|row| row.symbol.as_str())
+        .collect::<Vec<_>>();
+    Ok(tmpl.render(context! {
+        gene_names => gene_names,
+        genes => &report.genes,
+        notes => &report.notes,
+    })?)
+}
+
 #[allow(dead_code)]
 pub fn gene_search_markdown(
     query: &str,
@@ -11,6 +11,7 @@ fn gene_markdown_section_only_shows_constraint_section() {
         uniprot_id: Some("P04637".to_string()),
         summary: Some("Tumor suppressor.".to_string()),
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: vec!["P53".to_string()],
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -36,8 +37,14 @@ fn gene_markdown_section_only_shows_constraint_section() {
             reference_genome: "GRCh38".to_string(),
         }),
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &["constraint".to_string()]).expect("rendered markdown");
@@ -64,6 +71,7 @@ fn gene_markdown_section_only_shows_disgenet_section() {
         uniprot_id: None,
         summary: None,
         gene_type: None,
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -90,8 +98,14 @@ fn gene_markdown_section_only_shows_disgenet_section() {
                 evidence_level: Some("Definitive".to_string()),
             }],
         }),
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &["disgenet".to_string()]).expect("rendered markdown");
@@ -118,6 +132,7 @@ fn gene_markdown_disgenet_renders_sparse_optional_fields() {
         uniprot_id: None,
         summary: None,
         gene_type: None,
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -144,8 +159,14 @@ fn gene_markdown_disgenet_renders_sparse_optional_fields() {
                 evidence_level: None,
             }],
         }),
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &["disgenet".to_string()]).expect("rendered markdown");
@@ -167,6 +188,7 @@ fn gene_markdown_funding_renders_linked_rows_and_currency() {
         uniprot_id: None,
         summary: None,
         gene_type: None,
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -183,6 +205,8 @@ fn gene_markdown_funding_renders_linked_rows_and_currency() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: Some(crate::sources::nih_reporter::NihReporterFundingSection {
             query: "ERBB2".to_string(),
             fiscal_years: vec![2022, 2023, 2024, 2025, 2026],
@@ -204,6 +228,10 @@ fn gene_markdown_funding_renders_linked_rows_and_currency() {
             }],
         }),
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &["funding".to_string()]).expect("funding markdown");
@@ -241,6 +269,7 @@ fn gene_markdown_all_keeps_opt_in_sections_hidden() {
         uniprot_id: None,
         summary: None,
         gene_type: None,
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -257,14 +286,344 @@ fn gene_markdown_all_keeps_opt_in_sections_hidden() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &["all".to_string()]).expect("all markdown");
 
     assert!(!markdown.contains("## Funding (NIH Reporter)"));
     assert!(!markdown.contains("## DisGeNET"));
+    assert!(!markdown.contains("## ClinVar Summary"));
+    assert!(!markdown.contains("## Tractability & Safety"));
+    assert!(!markdown.contains("## Dependency (DepMap CRISPR)"));
+}
+
+#[test]
+fn gene_markdown_renders_clinvar_summary_section() {
+    use crate::entities::gene::{GeneClinVarCount, GeneClinVarSubmission, GeneClinVarSummary};
+
+    let count = |label: &str, count: u64| GeneClinVarCount {
+        label: label.to_string(),
+        count,
+    };
+    let gene = Gene {
+        symbol: "BRCA1".to_string(),
+        name: "BRCA1 DNA repair associated".to_string(),
+        entrez_id: "672".to_string(),
+        ensembl_id: None,
+        location: None,
+        genomic_coordinates: None,
+        omim_id: None,
+        uniprot_id: None,
+        summary: None,
+        gene_type: None,
+        species: None,
+        aliases: Vec::new(),
+        clinical_diseases: Vec::new(),
+        clinical_drugs: Vec::new(),
+        pathways: None,
+        ontology: None,
+        diseases: None,
+        protein: None,
+        go: None,
+        interactions: None,
+        civic: None,
+        expression: None,
+        hpa: None,
+        druggability: None,
+        clingen: None,
+        constraint: None,
+        disgenet: None,
+        clinvar_summary: Some(GeneClinVarSummary {
+            total_variants: Some(14_210),
+            significance: vec![
+                count("Pathogenic", 3_120),
+                count("Likely pathogenic", 410),
+                count("Uncertain significance", 5_230),
+                count("Likely benign", 2_900),
+                count("Benign", 880),
+            ],
+            review_status: vec![count(
+                "criteria provided, multiple submitters, no conflicts",
+                6_100,
+            )],
+            recent: vec![GeneClinVarSubmission {
+                variant_id: "chr17:g.43045712G>A".to_string(),
+                clinvar_id: Some(55_555),
+                hgvs_coding: Some("NM_007294.4:c.5503C>T".to_string()),
+                significance: Some("Pathogenic".to_string()),
+                review_status: Some("reviewed by expert panel".to_string()),
+                last_evaluated: Some("2024-03-01".to_string()),
+            }],
+        }),
+        tractability: None,
+        funding: None,
+        funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
+    };
+
+    let markdown =
+        gene_markdown(&gene, &["clinvar-summary".to_string()]).expect("clinvar summary markdown");
+    assert!(markdown.contains("## ClinVar Summary (MyVariant.info)"));
+    assert!(markdown.contains("ClinVar variants in BRCA1: 14210"));
+    assert!(markdown.contains("| Uncertain significance | 5230 |"));
+    assert!(markdown.contains("| criteria provided, multiple submitters, no conflicts | 6100 |"));
+    assert!(markdown.contains(
+        "| chr17:g.43045712G>A | NM_007294.4:c.5503C>T | Pathogenic | reviewed by expert panel | 2024-03-01 |"
+    ));
+    assert!(markdown.contains("biomcp search variant -g BRCA1 --significance pathogenic"));
+}
+
+#[test]
+fn gene_markdown_renders_key_papers_section() {
+    let mut gene: Gene = serde_json::from_value(serde_json::json!({
+        "symbol": "BRAF",
+        "name": "B-Raf proto-oncogene, serine/threonine kinase",
+        "entrez_id": "673",
+        "ensembl_id": null,
+        "location": null,
+        "summary": null,
+        "gene_type": null,
+        "aliases": [],
+        "key_papers": [
+            {
+                "kind": "seminal",
+                "title": "Mutations of the BRAF gene in human cancer",
+                "pmid": "12068308",
+                "journal": "Nature",
+                "year": "2002",
+                "citations": 9876
+            },
+            {"kind": "recent_review", "title": "BRAF in 2025"}
+        ]
+    }))
+    .expect("gene should deserialize");
+
+    let markdown = gene_markdown(&gene, &["key-papers".to_string()]).expect("key papers markdown");
+    assert!(markdown.contains("## Key Papers (Europe PMC)"));
+    assert!(markdown.contains(
+        "| Mutations of the BRAF gene in human cancer (PMID 12068308) | Nature | 2002 | 9876 | most cited |"
+    ));
+    assert!(markdown.contains("| BRAF in 2025 | - | - | - | recent review |"));
+
+    gene.key_papers = Some(Vec::new());
+    let markdown = gene_markdown(&gene, &["key-papers".to_string()]).expect("empty markdown");
+    assert!(markdown.contains("No key papers returned for this gene query."));
+}
+
+#[test]
+fn gene_markdown_renders_dependency_section() {
+    let mut gene: Gene = serde_json::from_value(serde_json::json!({
+        "symbol": "KRAS",
+        "name": "KRAS proto-oncogene, GTPase",
+        "entrez_id": "3845",
+        "ensembl_id": null,
+        "location": null,
+        "summary": null,
+        "gene_type": null,
+        "aliases": [],
+        "dependency": {
+            "dataset": "Chronos_Combined",
+            "cell_lines": 1100,
+            "dependent_cell_lines": 310,
+            "mean_gene_effect": -0.4123,
+            "top_lineages": [
+                {
+                    "lineage": "Pancreas",
+                    "cell_lines": 48,
+                    "dependent_cell_lines": 41,
+                    "mean_gene_effect": -1.2346
+                }
+            ]
+        }
+    }))
+    .expect("gene should deserialize");
+
+    let markdown = gene_markdown(&gene, &["dependency".to_string()]).expect("dependency markdown");
+    assert!(markdown.contains("## Dependency (DepMap CRISPR)"));
+    assert!(markdown.contains("- Mean gene effect: -0.412 across 1100 cell lines"));
+    assert!(markdown.contains("- Dependent cell lines (gene effect <= -0.5): 310 of 1100"));
+    assert!(markdown.contains("| Lineage | Mean gene effect | Dependent lines |"));
+    assert!(markdown.contains("| Pancreas | -1.235 | 41 of 48 |"));
+
+    gene.dependency = Some(crate::sources::depmap::GeneDependency::default());
+    let markdown = gene_markdown(&gene, &["depmap".to_string()]).expect("empty markdown");
+    assert!(markdown.contains("No DepMap CRISPR gene-effect data returned for this gene query."));
+}
+
+#[test]
+fn gene_markdown_renders_orthologs_section() {
+    let mut gene: Gene = serde_json::from_value(serde_json::json!({
+        "symbol": "BRAF",
+        "name": "B-Raf proto-oncogene, serine/threonine kinase",
+        "entrez_id": "673",
+        "ensembl_id": "ENSG00000157764",
+        "location": null,
+        "summary": null,
+        "gene_type": null,
+        "aliases": [],
+        "orthologs": [
+            {
+                "species": "mouse",
+                "taxid": 10090,
+                "symbol": "Braf",
+                "ensembl_id": "ENSMUSG00000002413",
+                "orthology_type": "one2one",
+                "target_identity": 96.37,
+                "query_identity": 94.52,
+                "url": "https://www.ensembl.org/Mus_musculus/Gene/Summary?g=ENSMUSG00000002413"
+            },
+            {
+                "species": "zebrafish",
+                "taxid": 7955,
+                "ensembl_id": "ENSDARG00000017661",
+                "orthology_type": "one2many",
+                "url": "https://www.ensembl.org/Danio_rerio/Gene/Summary?g=ENSDARG00000017661"
+            }
+        ]
+    }))
+    .expect("gene should deserialize");
+
+    let markdown = gene_markdown(&gene, &["orthologs".to_string()]).expect("orthologs markdown");
+    assert!(markdown.contains("## Orthologs (Ensembl Compara)"));
+    assert!(markdown.contains(
+        "| mouse | Braf | ENSMUSG00000002413 | one2one | 96.4% / 94.5% | [Ensembl](https://www.ensembl.org/Mus_musculus/Gene/Summary?g=ENSMUSG00000002413) |"
+    ));
+    assert!(markdown.contains("| zebrafish | - | ENSDARG00000017661 | one2many | - / - |"));
+    assert!(markdown.contains("biomcp get gene Braf --species mouse"));
+
+    gene.orthologs = Some(Vec::new());
+    let markdown = gene_markdown(&gene, &["orthologues".to_string()]).expect("empty markdown");
+    assert!(markdown.contains(
+        "No mouse, rat, or zebrafish orthologs returned from Ensembl Compara for this gene."
+    ));
+}
+
+#[test]
+fn gene_markdown_renders_plugin_sections() {
+    let gene: Gene = serde_json::from_value(serde_json::json!({
+        "symbol": "BRAF",
+        "name": "B-Raf proto-oncogene, serine/threonine kinase",
+        "entrez_id": "673",
+        "ensembl_id": null,
+        "location": null,
+        "summary": null,
+        "gene_type": null,
+        "aliases": [],
+        "plugin_sections": [
+            {
+                "section": "labdb",
+                "source": "Lab DB",
+                "data": {"internal_cases": 42, "flags": ["somatic", "germline"], "note": null}
+            }
+        ]
+    }))
+    .expect("gene should deserialize");
+
+    let markdown = gene_markdown(&gene, &["labdb".to_string()]).expect("plugin markdown");
+    assert!(markdown.contains("## Lab DB (labdb)"));
+    assert!(markdown.contains("- internal_cases: 42"));
+    assert!(markdown.contains("- flags: somatic, germline"));
+    assert!(markdown.contains("- note: -"));
+}
+
+#[test]
+fn gene_markdown_renders_tractability_section() {
+    use crate::sources::opentargets::{
+        GeneSafetyEvent, GeneTractability, GeneTractabilityBucket, GeneTractabilityModalityBuckets,
+    };
+
+    let bucket = |label: &str, value: bool| GeneTractabilityBucket {
+        label: label.to_string(),
+        value,
+    };
+    let gene = Gene {
+        symbol: "EGFR".to_string(),
+        name: "epidermal growth factor receptor".to_string(),
+        entrez_id: "1956".to_string(),
+        ensembl_id: Some("ENSG00000146648".to_string()),
+        location: None,
+        genomic_coordinates: None,
+        omim_id: None,
+        uniprot_id: None,
+        summary: None,
+        gene_type: None,
+        species: None,
+        aliases: Vec::new(),
+        clinical_diseases: Vec::new(),
+        clinical_drugs: Vec::new(),
+        pathways: None,
+        ontology: None,
+        diseases: None,
+        protein: None,
+        go: None,
+        interactions: None,
+        civic: None,
+        expression: None,
+        hpa: None,
+        druggability: None,
+        clingen: None,
+        constraint: None,
+        disgenet: None,
+        clinvar_summary: None,
+        tractability: Some(GeneTractability {
+            target_id: Some("ENSG00000146648".to_string()),
+            modalities: vec![
+                GeneTractabilityModalityBuckets {
+                    modality: "small molecule".to_string(),
+                    tractable: true,
+                    buckets: vec![
+                        bucket("Approved Drug", true),
+                        bucket("Structure with Ligand", true),
+                        bucket("High-Quality Pocket", false),
+                    ],
+                },
+                GeneTractabilityModalityBuckets {
+                    modality: "PROTAC".to_string(),
+                    tractable: false,
+                    buckets: vec![bucket("Literature", false)],
+                },
+            ],
+            safety_events: vec![GeneSafetyEvent {
+                event: "dermatitis".to_string(),
+                event_id: Some("EFO_0000274".to_string()),
+                effects: vec!["Inhibition/Decrease/Downregulation (chronic)".to_string()],
+                biosamples: vec!["skin".to_string()],
+                datasources: vec!["Lynch et al. (2018)".to_string()],
+                pmids: vec!["29740437".to_string()],
+                url: None,
+            }],
+        }),
+        funding: None,
+        funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
+    };
+
+    let markdown =
+        gene_markdown(&gene, &["tractability".to_string()]).expect("tractability markdown");
+    assert!(markdown.contains("## Tractability & Safety (Open Targets)"));
+    assert!(
+        markdown.contains("| small molecule | yes | Approved Drug, Structure with Ligand | 3 |")
+    );
+    assert!(markdown.contains("| PROTAC | no | - | 1 |"));
+    assert!(markdown.contains(
+        "| dermatitis (EFO_0000274) | Inhibition/Decrease/Downregulation (chronic) | skin | Lynch et al. (2018) | 29740437 |"
+    ));
+    assert!(markdown.contains("https://platform.opentargets.org/target/ENSG00000146648"));
+    assert!(!markdown.contains("## Druggability"));
 }
 
 #[test]
@@ -280,6 +639,7 @@ fn gene_markdown_pathways_show_source_labels() {
         uniprot_id: None,
         summary: None,
         gene_type: None,
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -307,8 +667,14 @@ fn gene_markdown_pathways_show_source_labels() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &[]).expect("rendered markdown");
@@ -317,3 +683,223 @@ fn gene_markdown_pathways_show_source_labels() {
     assert!(markdown.contains("| Reactome | R-HSA-5673001 | RAF/MAP kinase cascade |"));
     assert!(!markdown.contains("Showing pathway rows from Reactome search results."));
 }
+
+#[test]
+fn gene_variant_map_markdown_renders_histogram_and_hotspots() {
+    let map: crate::entities::gene::GeneVariantMap = serde_json::from_value(serde_json::json!({
+        "symbol": "EGFR",
+        "protein_length": 30,
+        "bin_size": 10,
+        "pathogenic_total": 1,
+        "somatic_total": 3,
+        "somatic_study": "msk_impact_2017",
+        "positions": [
+            {"position": 12, "pathogenic": 1, "somatic": 3, "changes": ["G12D", "p.Gly12Asp"]}
+        ],
+        "bins": [
+            {"start": 1, "end": 10, "pathogenic": 0, "somatic": 0},
+            {"start": 11, "end": 20, "pathogenic": 1, "somatic": 3},
+            {"start": 21, "end": 30, "pathogenic": 0, "somatic": 0}
+        ],
+        "notes": ["ClinVar: first 1000 of 1200 pathogenic/likely pathogenic variants scanned."]
+    }))
+    .expect("variant map json");
+
+    let markdown = gene_variant_map_markdown(&map).expect("variant map markdown");
+    assert!(markdown.contains("# EGFR variant map"));
+    assert!(markdown.contains("Somatic (msk_impact_2017): 3"));
+    assert!(markdown.contains(&format!(
+        "11-20 |{}{} 1/3",
+        "#".repeat(10),
+        "=".repeat(30)
+    )));
+    assert!(markdown.contains(" 1-10 | 0/0"));
+    assert!(markdown.contains("| 12 | 1 | 3 | G12D, p.Gly12Asp |"));
+    assert!(markdown.contains("Note: ClinVar: first 1000 of 1200"));
+}
+
+#[test]
+fn gene_clinvar_variants_markdown_renders_table_and_export_hint() {
+    let table: crate::entities::gene::GeneClinVarVariants =
+        serde_json::from_value(serde_json::json!({
+            "symbol": "BRCA1",
+            "significance": "pathogenic",
+            "total": 2400,
+            "offset": 0,
+            "complete": false,
+            "variants": [{
+                "id": "chr17:g.41197732G>A",
+                "clinvar_id": 55555,
+                "hgvs_c": "NM_007294.4:c.5503C>T",
+                "hgvs_p": "NP_009225.1:p.Arg1835Ter",
+                "significance": "Pathogenic",
+                "review_status": "reviewed by expert panel",
+                "review_stars": 3,
+                "conditions": ["Breast-ovarian cancer, familial 1", "Hereditary cancer-predisposing syndrome"]
+            }]
+        }))
+        .expect("clinvar variants json");
+
+    let markdown = gene_clinvar_variants_markdown(&table).expect("clinvar variants markdown");
+    assert!(markdown.contains("# BRCA1 ClinVar variants (pathogenic)"));
+    assert!(markdown.contains("Showing 1-1 of 2400"));
+    assert!(markdown.contains(
+        "| chr17:g.41197732G>A | NM_007294.4:c.5503C>T | NP_009225.1:p.Arg1835Ter | Pathogenic | 3 | Breast-ovarian cancer, familial 1; Hereditary cancer-predisposing syndrome |"
+    ));
+    assert!(markdown.contains(
+        "biomcp gene variants BRCA1 --significance pathogenic --format tsv --all"
+    ));
+}
+
+#[test]
+fn gene_deg_annotation_markdown_shows_stats_druggability_and_limit() {
+    let row = |input: &str, symbol: Option<&str>, lfc: &str, drugs: Option<usize>| {
+        crate::entities::gene::DegRow {
+            input: input.to_string(),
+            values: vec![input.to_string(), lfc.to_string(), "1e-6".to_string()],
+            symbol: symbol.map(str::to_string),
+            entrez_id: None,
+            ensembl_id: None,
+            name: symbol.map(|_| "epidermal growth factor receptor".to_string()),
+            summary: None,
+            druggable: drugs.map(|count| count > 0),
+            drug_count: drugs,
+            approved_drug_count: drugs.map(|count| count / 2),
+        }
+    };
+    let table = DegAnnotation {
+        source: "results.csv".to_string(),
+        gene_column: String::new(),
+        id_type: "ensembl".to_string(),
+        columns: vec![String::new(), "log2FoldChange".to_string(), "padj".to_string()],
+        fold_change_column: Some("log2FoldChange".to_string()),
+        adjusted_p_column: Some("padj".to_string()),
+        resolved: 1,
+        druggable_checked: true,
+        rows: vec![
+            row("ENSG00000146648", Some("EGFR"), "3.2", Some(12)),
+            row("ENSG00000000000", None, "-1.1", None),
+        ],
+    };
+
+    let markdown = gene_deg_annotation_markdown(&table, 1).expect("deg markdown");
+    assert!(markdown.contains("# Gene annotation: results.csv"));
+    assert!(markdown.contains(
+        "1 of 2 rows resolved via MyGene.info (ensembl IDs in column 1); 1 genes with DGIdb drug interactions."
+    ));
+    assert!(
+        markdown.contains("| Input | Symbol | Name | log2FoldChange | padj | Drugs (approved) |")
+    );
+    assert!(markdown.contains(
+        "| ENSG00000146648 | EGFR | epidermal growth factor receptor | 3.2 | 1e-6 | 12 (6) |"
+    ));
+    assert!(!markdown.contains("ENSG00000000000"));
+    assert!(markdown.contains("Showing 1 of 2 rows."));
+    assert!(markdown.contains("biomcp annotate deg --file results.csv --druggable --format tsv"));
+}
+
+#[test]
+fn gene_panel_markdown_renders_overview_and_per_gene_sections() {
+    use crate::entities::panel::{
+        GenePanelClinVar, GenePanelDisease, GenePanelGene, GenePanelVariant,
+    };
+
+    let report = GenePanelReport {
+        genes: vec![
+            GenePanelGene {
+                symbol: "BRCA1".to_string(),
+                name: "BRCA1 DNA repair associated".to_string(),
+                entrez_id: "672".to_string(),
+                location: Some("17q21.31".to_string()),
+                summary: Some("Tumor suppressor involved in DNA repair.".to_string()),
+                diseases: vec![GenePanelDisease {
+                    disease: "Hereditary breast ovarian cancer syndrome".to_string(),
+                    classification: Some("Definitive".to_string()),
+                    inheritance: Some("AD".to_string()),
+                    source: "ClinGen".to_string(),
+                }],
+                actionable_variants: vec![GenePanelVariant {
+                    molecular_profile: "BRCA1 Mutation".to_string(),
+                    level: "Tier I Level A".to_string(),
+                    significance: "Sensitivity/Response".to_string(),
+                    disease: Some("Ovarian Cancer".to_string()),
+                    therapies: vec!["Olaparib".to_string()],
+                    source_id: "AID7".to_string(),
+                }],
+                drugs: vec!["olaparib".to_string()],
+                clinvar: Some(GenePanelClinVar {
+                    total_variants: Some(12000),
+                    pathogenic: 3100,
+                    likely_pathogenic: 400,
+                }),
+            },
+            GenePanelGene {
+                symbol: "TP53".to_string(),
+                name: "tumor protein p53".to_string(),
+                entrez_id: "7157".to_string(),
+                location: None,
+                summary: None,
+                diseases: Vec::new(),
+                actionable_variants: Vec::new(),
+                drugs: Vec::new(),
+                clinvar: None,
+            },
+        ],
+        notes: vec!["BRCA9 was skipped: gene not found".to_string()],
+    };
+
+    let markdown = gene_panel_markdown(&report).expect("panel markdown");
+    assert!(markdown.starts_with("# Gene panel report: BRCA1, TP53\n"));
+    assert!(
+        markdown
+            .contains("| BRCA1 | BRCA1 DNA repair associated | 17q21.31 | 1 | 1 | 3100 / 400 |")
+    );
+    assert!(markdown.contains("| TP53 | tumor protein p53 | - | 0 | 0 | - |"));
+    assert!(markdown.contains("## BRCA1: BRCA1 DNA repair associated\n\nTumor suppressor"));
+    assert!(
+        markdown
+            .contains("| Hereditary breast ovarian cancer syndrome | Definitive | AD | ClinGen |")
+    );
+    assert!(markdown.contains(
+        "| BRCA1 Mutation | Tier I Level A | Sensitivity/Response | Ovarian Cancer | Olaparib | AID7 |"
+    ));
+    assert!(
+        markdown.contains("- ClinVar: 3100 pathogenic, 400 likely pathogenic of 12000 variants.")
+    );
+    assert!(markdown.contains("- Clinical-stage drugs: olaparib"));
+    assert!(markdown.contains("No CIViC assertions or level A/B predictive evidence found."));
+    assert!(markdown.contains("Note: BRCA9 was skipped: gene not found"));
+}
+
+#[test]
+fn gene_diff_markdown_renders_change_table_and_baseline_note() {
+    let diff: GeneDiff = serde_json::from_value(serde_json::json!({
+        "symbol": "BRAF",
+        "previous_snapshot": "20260310T120000Z",
+        "previous_taken_at": "2026-03-10T12:00:00Z",
+        "current_taken_at": "2026-10-16T09:30:00Z",
+        "changes": [
+            {"field": "drug interactions", "added": ["TOVORAFENIB"], "removed": []},
+            {"field": "ClinVar variants", "added": [], "removed": [], "before": "1200", "after": "1250"}
+        ],
+        "saved_snapshot": "20261016T093000Z"
+    }))
+    .expect("gene diff json");
+
+    let markdown = gene_diff_markdown(&diff).expect("gene diff markdown");
+    assert!(markdown.contains("# BRAF changes"));
+    assert!(markdown.contains("Compared snapshot `20260310T120000Z`"));
+    assert!(markdown.contains("| drug interactions | TOVORAFENIB | - | - | - |"));
+    assert!(markdown.contains("| ClinVar variants | - | - | 1200 | 1250 |"));
+    assert!(markdown.contains("Saved snapshot `20261016T093000Z`"));
+
+    let baseline: GeneDiff = serde_json::from_value(serde_json::json!({
+        "symbol": "BRAF",
+        "current_taken_at": "2026-10-16T09:30:00Z",
+        "changes": [],
+        "saved_snapshot": "20261016T093000Z"
+    }))
+    .expect("baseline json");
+    let markdown = gene_diff_markdown(&baseline).expect("baseline markdown");
+    assert!(markdown.contains("No earlier snapshot for BRAF; this run is the baseline."));
+}
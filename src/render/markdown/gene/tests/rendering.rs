@@ -11,6 +11,7 @@ fn gene_markdown_includes_evidence_links() {
         uniprot_id: Some("P15056".to_string()),
         summary: Some("Kinase involved in MAPK signaling.".to_string()),
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: vec!["BRAF1".to_string()],
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -27,8 +28,14 @@ fn gene_markdown_includes_evidence_links() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &[]).expect("rendered markdown");
@@ -50,6 +57,7 @@ fn gene_markdown_section_only_shows_new_gene_enrichment_sections() {
         uniprot_id: Some("P15056".to_string()),
         summary: Some("Kinase involved in MAPK signaling.".to_string()),
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: vec!["BRAF1".to_string()],
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -66,8 +74,14 @@ fn gene_markdown_section_only_shows_new_gene_enrichment_sections() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(
@@ -105,6 +119,7 @@ fn gene_markdown_renders_combined_dgidb_and_opentargets_druggability() {
         uniprot_id: Some("P00533".to_string()),
         summary: None,
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -142,8 +157,14 @@ fn gene_markdown_renders_combined_dgidb_and_opentargets_druggability() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &["druggability".to_string()]).expect("rendered markdown");
@@ -172,6 +193,7 @@ fn gene_markdown_renders_dgidb_interaction_table_alongside_opentargets_data() {
         uniprot_id: None,
         summary: None,
         gene_type: None,
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -203,8 +225,14 @@ fn gene_markdown_renders_dgidb_interaction_table_alongside_opentargets_data() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &["druggability".to_string()]).expect("rendered markdown");
@@ -230,6 +258,7 @@ fn gene_markdown_renders_hpa_section_details() {
         uniprot_id: Some("P15056".to_string()),
         summary: Some("Kinase involved in MAPK signaling.".to_string()),
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: vec!["BRAF1".to_string()],
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -262,8 +291,14 @@ fn gene_markdown_renders_hpa_section_details() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let summary = gene_markdown(&gene, &[]).expect("rendered markdown");
@@ -306,6 +341,7 @@ fn gene_markdown_renders_protein_isoforms_with_count_and_displayed_length() {
         uniprot_id: Some("P01116".to_string()),
         summary: None,
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: vec!["K-RAS2A".to_string(), "K-RAS2B".to_string()],
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -338,8 +374,14 @@ fn gene_markdown_renders_protein_isoforms_with_count_and_displayed_length() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &["protein".to_string()]).expect("gene markdown");
@@ -362,6 +404,7 @@ fn gene_markdown_without_isoforms_keeps_protein_lines_contiguous() {
         uniprot_id: Some("P15056".to_string()),
         summary: None,
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -385,8 +428,14 @@ fn gene_markdown_without_isoforms_keeps_protein_lines_contiguous() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &["protein".to_string()]).expect("gene markdown");
@@ -408,6 +457,7 @@ fn gene_markdown_renders_protein_alternative_names() {
         uniprot_id: Some("Q99541".to_string()),
         summary: None,
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -435,8 +485,14 @@ fn gene_markdown_renders_protein_alternative_names() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &["protein".to_string()]).expect("gene markdown");
@@ -461,6 +517,7 @@ fn gene_markdown_preserves_full_protein_function_text() {
         uniprot_id: Some("O60313".to_string()),
         summary: None,
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -484,8 +541,14 @@ fn gene_markdown_preserves_full_protein_function_text() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &["protein".to_string()]).expect("gene markdown");
@@ -507,6 +570,7 @@ fn gene_markdown_omits_protein_alternative_names_when_absent() {
         uniprot_id: Some("P15056".to_string()),
         summary: None,
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: Vec::new(),
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -530,10 +594,36 @@ fn gene_markdown_omits_protein_alternative_names_when_absent() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let markdown = gene_markdown(&gene, &["protein".to_string()]).expect("gene markdown");
     assert!(!markdown.contains("- Also known as:"));
 }
+
+#[test]
+fn gene_markdown_labels_non_human_species() {
+    let gene: Gene = serde_json::from_value(serde_json::json!({
+        "symbol": "Braf",
+        "name": "Braf transforming gene",
+        "entrez_id": "109880",
+        "location": "6",
+        "species": "mouse",
+        "aliases": []
+    }))
+    .expect("mouse gene");
+
+    let markdown = gene_markdown(&gene, &[]).expect("gene markdown");
+    assert!(markdown.contains(
+        "Entrez ID: 109880\nSpecies: mouse (Mus musculus, taxid 10090)\nLocation: 6\n"
+    ));
+    assert!(markdown.contains("biomcp get gene Braf --species mouse protein"));
+    assert!(!markdown.contains("biomcp get gene Braf all"));
+}
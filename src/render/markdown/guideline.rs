@@ -0,0 +1,21 @@
+//! Clinical guideline search markdown renderers.
+
+use super::*;
+
+#[cfg(test)]
+mod tests;
+
+pub fn guideline_search_markdown_with_footer(
+    query: &str,
+    results: &[GuidelineSearchResult],
+    pagination_footer: &str,
+) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("guideline_search.md.j2")?;
+    let body = tmpl.render(context! {
+        query => query,
+        count => results.len(),
+        results => results,
+        pagination_footer => pagination_footer,
+    })?;
+    Ok(with_pagination_footer(body, pagination_footer))
+}
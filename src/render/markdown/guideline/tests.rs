@@ -0,0 +1,61 @@
+use super::*;
+
+fn uspstf_row() -> GuidelineSearchResult {
+    GuidelineSearchResult {
+        organization: Some("USPSTF".into()),
+        title: "Atrial Fibrillation: Screening -- Adults 50 years or older".into(),
+        recommendation: Some("The USPSTF concludes that the current evidence is insufficient.".into()),
+        grade: Some("I".into()),
+        year: Some("2022".into()),
+        source: "USPSTF".into(),
+        pmid: None,
+        url: Some(
+            "https://www.uspreventiveservicestaskforce.org/uspstf/recommendation/atrial-fibrillation-screening"
+                .into(),
+        ),
+    }
+}
+
+fn society_row() -> GuidelineSearchResult {
+    GuidelineSearchResult {
+        organization: Some("AHA".into()),
+        title: "2023 ACC/AHA/ACCP/HRS Guideline for the Diagnosis and Management of Atrial Fibrillation".into(),
+        recommendation: None,
+        grade: None,
+        year: Some("2023".into()),
+        source: "Europe PMC".into(),
+        pmid: Some("38033089".into()),
+        url: Some("https://pubmed.ncbi.nlm.nih.gov/38033089/".into()),
+    }
+}
+
+#[test]
+fn guideline_search_markdown_renders_grades_years_and_summaries() {
+    let markdown = guideline_search_markdown_with_footer(
+        "condition=atrial fibrillation, org=USPSTF,AHA",
+        &[uspstf_row(), society_row()],
+        "",
+    )
+    .expect("markdown");
+
+    assert!(markdown.contains("# Guidelines: condition=atrial fibrillation, org=USPSTF,AHA"));
+    assert!(markdown.contains("Found 2 guidelines"));
+    assert!(markdown.contains("| Org | Guideline | Grade | Year | Source |"));
+    assert!(markdown.contains(
+        "| USPSTF | [Atrial Fibrillation: Screening -- Adults 50 years or older](https://www.uspreventiveservicestaskforce.org/uspstf/recommendation/atrial-fibrillation-screening) | I | 2022 | USPSTF |"
+    ));
+    assert!(markdown.contains("| - | 2023 | Europe PMC (PMID 38033089) |"));
+    assert!(markdown.contains("## Recommendation Summaries"));
+    assert!(markdown.contains(
+        "- **USPSTF (Grade I)** Atrial Fibrillation: Screening -- Adults 50 years or older: The USPSTF concludes"
+    ));
+    assert!(!markdown.contains("**AHA"));
+}
+
+#[test]
+fn guideline_search_markdown_handles_empty_results() {
+    let markdown =
+        guideline_search_markdown_with_footer("condition=unknownitis", &[], "").expect("markdown");
+    assert!(markdown.contains("No guidelines found."));
+    assert!(!markdown.contains("| Org |"));
+}
@@ -0,0 +1,34 @@
+//! HLA allele markdown renderers.
+
+use super::*;
+
+#[cfg(test)]
+mod tests;
+
+pub fn hla_markdown(hla: &Hla, requested_sections: &[String]) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("hla.md.j2")?;
+    let section_only = is_section_only_requested(requested_sections);
+    let include_all = has_all_section(requested_sections);
+    let requested = requested_section_names(requested_sections);
+    let has_requested = |name: &str| requested.iter().any(|s| s.eq_ignore_ascii_case(name));
+
+    let body = tmpl.render(context! {
+        section_only => section_only,
+        section_header => section_header(&hla.allele, requested_sections),
+        allele => &hla.allele,
+        gene => &hla.gene,
+        locus => &hla.locus,
+        resolution => &hla.resolution,
+        registry => &hla.registry,
+        registry_note => &hla.registry_note,
+        frequencies => &hla.frequencies,
+        drug_associations => &hla.drug_associations,
+        disease_associations => &hla.disease_associations,
+        show_frequencies_section => include_all || has_requested("frequencies"),
+        show_drugs_section => include_all || has_requested("drugs"),
+        show_diseases_section => include_all || has_requested("diseases"),
+        sections_block => format_sections_block("hla", &force_quote_arg(&hla.allele), sections_hla(hla, requested_sections)),
+        related_block => format_related_block(related_hla(hla)),
+    })?;
+    Ok(append_evidence_urls(body, hla_evidence_urls(hla)))
+}
@@ -0,0 +1,82 @@
+use super::*;
+use crate::entities::hla::{
+    HlaDiseaseAssociation, HlaDrugAssociation, HlaFrequency, HlaRegistry, HlaRegistryAllele,
+};
+
+fn abacavir_hla() -> Hla {
+    Hla {
+        allele: "HLA-B*57:01".to_string(),
+        gene: "HLA-B".to_string(),
+        locus: "B".to_string(),
+        resolution: "protein (2-field)".to_string(),
+        registry: Some(HlaRegistry {
+            accession: Some("HLA00381".to_string()),
+            allele_count: 12,
+            alleles: vec![HlaRegistryAllele {
+                name: "HLA-B*57:01:01:01".to_string(),
+                accession: "HLA00381".to_string(),
+            }],
+        }),
+        registry_note: None,
+        frequencies: vec![HlaFrequency {
+            allele: "HLA-B*57:01".to_string(),
+            population_group: Some("European".to_string()),
+            subject_count: Some(1200),
+            frequency: Some(0.068),
+            min_frequency: None,
+            max_frequency: None,
+        }],
+        drug_associations: vec![HlaDrugAssociation {
+            drug: "abacavir".to_string(),
+            genotype: Some("HLA-B*57:01 positive".to_string()),
+            implication: Some("Significantly increased risk of hypersensitivity".to_string()),
+            recommendation: Some("Abacavir is not recommended".to_string()),
+            classification: Some("Strong".to_string()),
+            cpic_level: Some("A".to_string()),
+            guideline_name: None,
+            guideline_url: None,
+        }],
+        disease_associations: vec![HlaDiseaseAssociation {
+            allele: "HLA-B*57:01".to_string(),
+            disease: "HIV-1 infection (slower progression)".to_string(),
+            effect: "protective".to_string(),
+        }],
+    }
+}
+
+#[test]
+fn hla_markdown_renders_registry_and_requested_sections() {
+    let hla = abacavir_hla();
+    let markdown = hla_markdown(&hla, &["all".to_string()]).expect("rendered markdown");
+
+    assert!(markdown.contains("# HLA: HLA-B*57:01"));
+    assert!(markdown.contains("IPD-IMGT/HLA Accession: HLA00381"));
+    assert!(markdown.contains("Showing 1 of 12 registered alleles."));
+    assert!(markdown.contains("| HLA-B*57:01 | European | 0.068 | 1200 |"));
+    assert!(markdown.contains(
+        "| abacavir | HLA-B*57:01 positive | A | Significantly increased risk of hypersensitivity | Abacavir is not recommended | Strong |"
+    ));
+    assert!(
+        markdown.contains("| HLA-B*57:01 | HIV-1 infection (slower progression) | protective |")
+    );
+    assert!(markdown.contains("biomcp get pgx HLA-B"));
+    assert!(markdown.contains("biomcp get drug abacavir"));
+    assert!(markdown.contains(
+        "[IPD-IMGT/HLA](https://www.ebi.ac.uk/ipd/imgt/hla/alleles/allele/?accession=HLA00381)"
+    ));
+    assert!(markdown.contains("[CPIC](https://cpicpgx.org/genes/hla-b/)"));
+}
+
+#[test]
+fn hla_markdown_base_card_offers_quoted_section_commands() {
+    let mut hla = abacavir_hla();
+    hla.registry = None;
+    hla.registry_note =
+        Some("IPD-IMGT/HLA registry unavailable; allele name was not verified.".to_string());
+
+    let markdown = hla_markdown(&hla, &[]).expect("rendered markdown");
+    assert!(markdown.contains("registry unavailable"));
+    assert!(!markdown.contains("## Drug Hypersensitivity"));
+    assert!(markdown.contains("biomcp get hla \"HLA-B*57:01\" drugs"));
+    assert!(markdown.contains("biomcp search article -k \"HLA-B*57:01\""));
+}
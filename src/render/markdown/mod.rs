@@ -2,13 +2,19 @@
 
 mod adverse_event;
 mod article;
+mod budget;
 mod discovery;
 mod disease;
+mod dossier;
 mod drug;
 mod drug_regulatory;
 mod evidence;
 mod funding;
 mod gene;
+mod guideline;
+mod hla;
+mod network;
+mod ontology;
 mod pathway;
 mod pgx;
 mod protein;
@@ -16,12 +22,16 @@ mod related;
 #[cfg(test)]
 mod root_tests;
 mod sections;
+mod structure;
 mod study;
 mod support;
+mod template_overrides;
 #[cfg(test)]
 mod test_support;
 #[cfg(test)]
 pub(crate) mod tests;
+mod theme;
+mod transcript;
 mod trial;
 mod variant;
 
@@ -31,53 +41,76 @@ use self::{evidence::*, funding::*, related::*, sections::*, support::*};
 #[allow(unused_imports)]
 pub use self::adverse_event::{
     adverse_event_count_markdown, adverse_event_markdown, adverse_event_search_markdown,
-    adverse_event_search_markdown_with_footer, device_event_markdown, device_event_search_markdown,
-    device_event_search_markdown_with_footer, recall_search_markdown,
-    recall_search_markdown_with_footer,
+    adverse_event_search_markdown_with_footer, device_clearance_markdown,
+    device_clearance_search_markdown_with_footer, device_event_markdown,
+    device_event_search_markdown, device_event_search_markdown_with_footer, recall_search_markdown,
+    recall_search_markdown_with_footer, veterinary_event_search_markdown_with_footer,
 };
 #[allow(unused_imports)]
 pub use self::article::{
     article_batch_markdown, article_entities_markdown, article_graph_markdown, article_markdown,
     article_recommendations_markdown, article_search_markdown_with_footer_and_context,
 };
+pub use self::budget::apply_output_budget;
 #[allow(unused_imports)]
-pub use self::discovery::{render_discover, search_all_markdown};
+pub use self::discovery::{ask_markdown, render_discover, search_all_markdown};
 #[allow(unused_imports)]
 pub use self::disease::{
     disease_markdown, disease_search_markdown, disease_search_markdown_with_footer,
 };
+pub use self::dossier::dossier_markdown;
 #[allow(unused_imports)]
 pub use self::drug::{
     drug_markdown, drug_markdown_with_region, drug_search_markdown,
-    drug_search_markdown_with_footer, drug_search_markdown_with_region,
+    drug_search_markdown_with_footer, drug_search_markdown_with_region, repurpose_markdown,
 };
 #[allow(unused_imports)]
-pub use self::gene::{gene_markdown, gene_search_markdown, gene_search_markdown_with_footer};
+pub use self::gene::{
+    gene_clinvar_variants_markdown, gene_deg_annotation_markdown, gene_diff_markdown,
+    gene_markdown, gene_panel_markdown, gene_search_markdown, gene_search_markdown_with_footer,
+    gene_variant_map_markdown,
+};
+pub use self::guideline::guideline_search_markdown_with_footer;
+pub use self::hla::hla_markdown;
+pub use self::network::network_markdown;
+pub use self::ontology::ontology_tree_markdown;
 #[allow(unused_imports)]
 pub use self::pathway::{
     pathway_markdown, pathway_search_markdown, pathway_search_markdown_with_footer,
 };
 #[allow(unused_imports)]
-pub use self::pgx::{pgx_markdown, pgx_search_markdown, pgx_search_markdown_with_footer};
+pub use self::pgx::{
+    pgx_diplotype_markdown, pgx_dose_check_markdown, pgx_markdown, pgx_panel_markdown,
+    pgx_search_markdown, pgx_search_markdown_with_footer,
+};
 #[allow(unused_imports)]
 pub use self::protein::{
-    protein_markdown, protein_search_markdown, protein_search_markdown_with_footer,
+    protein_domain_search_markdown, protein_markdown, protein_search_markdown,
+    protein_search_markdown_with_footer,
 };
+pub use self::structure::{structure_markdown, structure_search_markdown_with_footer};
 #[allow(unused_imports)]
 pub use self::study::{
     study_co_occurrence_markdown, study_cohort_markdown, study_compare_expression_markdown,
     study_compare_mutations_markdown, study_download_catalog_markdown, study_download_markdown,
-    study_filter_markdown, study_list_markdown, study_query_markdown, study_survival_markdown,
-    study_top_mutated_markdown,
+    study_filter_markdown, study_list_markdown, study_markdown, study_query_markdown,
+    study_search_markdown_with_footer, study_survival_markdown, study_top_mutated_markdown,
 };
 #[allow(unused_imports)]
-pub use self::trial::{trial_markdown, trial_search_markdown, trial_search_markdown_with_footer};
+pub use self::theme::{MarkdownTheme, apply_theme};
+pub use self::transcript::transcript_markdown;
+pub use self::trial::{
+    trial_markdown, trial_match_markdown, trial_search_markdown, trial_search_markdown_with_footer,
+    trial_summary_markdown,
+};
 #[allow(unused_imports)]
 pub use self::variant::{
     gwas_search_markdown, gwas_search_markdown_with_footer, phenotype_search_markdown,
-    phenotype_search_markdown_with_footer, variant_markdown, variant_oncokb_markdown,
+    phenotype_search_markdown_with_footer, variant_compare_af_markdown, variant_compare_markdown,
+    variant_markdown, variant_oncokb_markdown, variant_search_grouped_markdown_with_context,
     variant_search_markdown, variant_search_markdown_with_context,
-    variant_search_markdown_with_footer,
+    variant_search_markdown_with_footer, variant_validation_markdown,
+    variant_vcf_annotation_markdown,
 };
 use std::collections::HashSet;
 use std::fmt::Write as _;
@@ -89,42 +122,63 @@ use crate::cli::debug_plan::DebugPlan;
 use crate::cli::search_all::SearchAllResults;
 use crate::entities::adverse_event::{
     AdverseEvent, AdverseEventCountBucket, AdverseEventSearchResult, AdverseEventSearchSummary,
-    DeviceEvent, DeviceEventSearchResult, RecallSearchResult,
+    DeviceClearance, DeviceClearanceSearchResult, DeviceEvent, DeviceEventSearchResult,
+    RecallSearchResult, VeterinaryEventSearchResult,
 };
 use crate::entities::article::{
     AnnotationCount, Article, ArticleAnnotations, ArticleBatchEntitySummary, ArticleBatchItem,
-    ArticleGraphResult, ArticleRankingMetadata, ArticleRankingMode, ArticleRecommendationsResult,
-    ArticleRelatedPaper, ArticleSearchFilters, ArticleSearchResult, ArticleSort, ArticleSource,
+    ArticleCitationLink, ArticleGraphResult, ArticleRankingMetadata, ArticleRankingMode,
+    ArticleRecommendationsResult, ArticleRelatedPaper, ArticleSearchFilters, ArticleSearchResult,
+    ArticleSort, ArticleSource,
 };
 use crate::entities::discover::{DiscoverResult, DiscoverType};
 use crate::entities::disease::{
     Disease, DiseaseAssociationScoreSummary, DiseaseSearchResult, PhenotypeSearchResult,
 };
+use crate::entities::dossier::EvidenceDossier;
 use crate::entities::drug::{
     Drug, DrugApproval, DrugRegion, DrugSearchResult, EmaDrugSearchResult, EmaRegulatoryRow,
     EmaSafetyInfo, EmaShortageEntry, WhoPrequalificationEntry, WhoPrequalificationSearchResult,
 };
-use crate::entities::gene::{Gene, GeneSearchResult};
+use crate::entities::gene::{
+    DegAnnotation, Gene, GeneClinVarVariants, GeneDiff, GeneSearchResult, GeneSpecies,
+    GeneVariantMap,
+};
+use crate::entities::guideline::GuidelineSearchResult;
+use crate::entities::hla::Hla;
+use crate::entities::network::InteractionNetwork;
+use crate::entities::ontology::{
+    MAX_ONTOLOGY_DEPTH, OntologyDirection, OntologyNode, OntologyTree,
+};
+use crate::entities::panel::GenePanelReport;
 use crate::entities::pathway::{Pathway, PathwaySearchResult};
-use crate::entities::pgx::{Pgx, PgxSearchResult};
+use crate::entities::pgx::{
+    Pgx, PgxDiplotype, PgxDoseCheck, PgxDoseCheckStatus, PgxPanel, PgxSearchResult,
+};
 use crate::entities::protein::{
-    Protein, ProteinComplex, ProteinComplexComponent, ProteinComplexCuration, ProteinSearchResult,
+    Protein, ProteinComplex, ProteinComplexComponent, ProteinComplexCuration, ProteinDomainHit,
+    ProteinSearchResult,
 };
+use crate::entities::repurpose::RepurposeResult;
+use crate::entities::structure::{Structure, StructureSearchResult};
 use crate::entities::study::{
     CoOccurrenceResult as StudyCoOccurrenceResult, CohortResult as StudyCohortResult,
     ExpressionComparisonResult as StudyExpressionComparisonResult,
     FilterResult as StudyFilterResult, MutationComparisonResult as StudyMutationComparisonResult,
-    SampleUniverseBasis as StudySampleUniverseBasis, StudyDownloadCatalog, StudyDownloadResult,
-    StudyInfo, StudyQueryResult, SurvivalResult as StudySurvivalResult,
-    TopMutatedGenesResult as StudyTopMutatedGenesResult,
+    SampleUniverseBasis as StudySampleUniverseBasis, Study, StudyDownloadCatalog,
+    StudyDownloadResult, StudyInfo, StudyQueryResult, StudySearchResult,
+    SurvivalResult as StudySurvivalResult, TopMutatedGenesResult as StudyTopMutatedGenesResult,
 };
-use crate::entities::trial::{Trial, TrialSearchResult};
+use crate::entities::transcript::Transcript;
+use crate::entities::trial::{Trial, TrialMatches, TrialSearchResult, TrialSummary};
 use crate::entities::variant::{
-    Variant, VariantGwasAssociation, VariantOncoKbResult, VariantPrediction, VariantSearchResult,
+    CohortAfComparison, Variant, VariantComparison, VariantGwasAssociation, VariantOncoKbResult,
+    VariantPrediction, VariantSearchGroupBy, VariantSearchResult, VariantValidation, VcfAnnotation,
     gnomad_variant_slug,
 };
 use crate::error::BioMcpError;
 use crate::sources::nih_reporter::{NihReporterFundingSection, NihReporterGrant};
+use crate::sources::plugin::PluginSection;
 
 pub(crate) fn adverse_event_evidence_urls(event: &AdverseEvent) -> Vec<(&'static str, String)> {
     evidence::adverse_event_evidence_urls(event)
@@ -144,6 +198,12 @@ pub(crate) fn device_event_evidence_urls(event: &DeviceEvent) -> Vec<(&'static s
     evidence::device_event_evidence_urls(event)
 }
 
+pub(crate) fn device_clearance_evidence_urls(
+    clearance: &DeviceClearance,
+) -> Vec<(&'static str, String)> {
+    evidence::device_clearance_evidence_urls(clearance)
+}
+
 pub(crate) fn discover_evidence_urls(result: &DiscoverResult) -> Vec<(&'static str, String)> {
     evidence::discover_evidence_urls(result)
 }
@@ -160,10 +220,18 @@ pub(crate) fn gene_evidence_urls(gene: &Gene) -> Vec<(&'static str, String)> {
     evidence::gene_evidence_urls(gene)
 }
 
+pub(crate) fn hla_evidence_urls(hla: &Hla) -> Vec<(&'static str, String)> {
+    evidence::hla_evidence_urls(hla)
+}
+
 pub(crate) fn pathway_evidence_urls(pathway: &Pathway) -> Vec<(&'static str, String)> {
     evidence::pathway_evidence_urls(pathway)
 }
 
+pub(crate) fn study_evidence_urls(study: &Study) -> Vec<(&'static str, String)> {
+    evidence::study_evidence_urls(study)
+}
+
 pub(crate) fn pgx_evidence_urls(pgx: &Pgx) -> Vec<(&'static str, String)> {
     evidence::pgx_evidence_urls(pgx)
 }
@@ -172,6 +240,14 @@ pub(crate) fn protein_evidence_urls(protein: &Protein) -> Vec<(&'static str, Str
     evidence::protein_evidence_urls(protein)
 }
 
+pub(crate) fn structure_evidence_urls(structure: &Structure) -> Vec<(&'static str, String)> {
+    evidence::structure_evidence_urls(structure)
+}
+
+pub(crate) fn transcript_evidence_urls(transcript: &Transcript) -> Vec<(&'static str, String)> {
+    evidence::transcript_evidence_urls(transcript)
+}
+
 pub(crate) fn quote_arg(value: &str) -> String {
     support::quote_arg(value)
 }
@@ -199,6 +275,10 @@ pub(crate) fn related_device_event(event: &DeviceEvent) -> Vec<String> {
     related::related_device_event(event)
 }
 
+pub(crate) fn related_device_clearance(clearance: &DeviceClearance) -> Vec<String> {
+    related::related_device_clearance(clearance)
+}
+
 pub(crate) fn related_disease(disease: &Disease) -> Vec<String> {
     related::related_disease(disease)
 }
@@ -211,10 +291,18 @@ pub(crate) fn related_gene(gene: &Gene) -> Vec<String> {
     related::related_gene(gene)
 }
 
+pub(crate) fn related_hla(hla: &Hla) -> Vec<String> {
+    related::related_hla(hla)
+}
+
 pub(crate) fn related_pathway(pathway: &Pathway) -> Vec<String> {
     related::related_pathway(pathway)
 }
 
+pub(crate) fn related_study(study: &Study) -> Vec<String> {
+    related::related_study(study)
+}
+
 pub(crate) fn related_pgx(pgx: &Pgx) -> Vec<String> {
     related::related_pgx(pgx)
 }
@@ -227,6 +315,14 @@ pub(crate) fn related_protein(protein: &Protein, requested_sections: &[String])
     related::related_protein(protein, requested_sections)
 }
 
+pub(crate) fn related_structure(structure: &Structure) -> Vec<String> {
+    related::related_structure(structure)
+}
+
+pub(crate) fn related_transcript(transcript: &Transcript) -> Vec<String> {
+    related::related_transcript(transcript)
+}
+
 pub(crate) fn related_trial(trial: &Trial) -> Vec<String> {
     related::related_trial(trial)
 }
@@ -304,6 +400,10 @@ pub(crate) fn search_next_commands_pathway(results: &[PathwaySearchResult]) -> V
     related::search_next_commands_pathway(results)
 }
 
+pub(crate) fn search_next_commands_study(results: &[StudySearchResult]) -> Vec<String> {
+    related::search_next_commands_study(results)
+}
+
 pub(crate) fn search_next_commands_faers(results: &[AdverseEventSearchResult]) -> Vec<String> {
     related::search_next_commands_faers(results)
 }
@@ -314,6 +414,12 @@ pub(crate) fn search_next_commands_device_events(
     related::search_next_commands_device_events(results)
 }
 
+pub(crate) fn search_next_commands_device_clearances(
+    results: &[DeviceClearanceSearchResult],
+) -> Vec<String> {
+    related::search_next_commands_device_clearances(results)
+}
+
 pub(crate) fn search_next_commands_recalls(results: &[RecallSearchResult]) -> Vec<String> {
     related::search_next_commands_recalls(results)
 }
@@ -322,6 +428,10 @@ pub(crate) fn search_next_commands_gwas(results: &[VariantGwasAssociation]) -> V
     related::search_next_commands_gwas(results)
 }
 
+pub(crate) fn search_next_commands_guideline(results: &[GuidelineSearchResult]) -> Vec<String> {
+    related::search_next_commands_guideline(results)
+}
+
 pub(crate) fn trial_evidence_urls(trial: &Trial) -> Vec<(&'static str, String)> {
     evidence::trial_evidence_urls(trial)
 }
@@ -483,6 +593,22 @@ fn env() -> Result<&'static Environment<'static>, BioMcpError> {
         if out.is_empty() { "0".to_string() } else { out }
     });
     env.add_template("gene.md.j2", include_str!("../../../templates/gene.md.j2"))?;
+    env.add_template(
+        "gene_diff.md.j2",
+        include_str!("../../../templates/gene_diff.md.j2"),
+    )?;
+    env.add_template(
+        "gene_variant_map.md.j2",
+        include_str!("../../../templates/gene_variant_map.md.j2"),
+    )?;
+    env.add_template(
+        "gene_clinvar_variants.md.j2",
+        include_str!("../../../templates/gene_clinvar_variants.md.j2"),
+    )?;
+    env.add_template(
+        "gene_deg_annotation.md.j2",
+        include_str!("../../../templates/gene_deg_annotation.md.j2"),
+    )?;
     env.add_template(
         "gene_search.md.j2",
         include_str!("../../../templates/gene_search.md.j2"),
@@ -507,7 +633,36 @@ fn env() -> Result<&'static Environment<'static>, BioMcpError> {
         "disease_search.md.j2",
         include_str!("../../../templates/disease_search.md.j2"),
     )?;
+    env.add_template(
+        "dossier.md.j2",
+        include_str!("../../../templates/dossier.md.j2"),
+    )?;
+    env.add_template("hla.md.j2", include_str!("../../../templates/hla.md.j2"))?;
+    env.add_template(
+        "network.md.j2",
+        include_str!("../../../templates/network.md.j2"),
+    )?;
+    env.add_template(
+        "ontology_tree.md.j2",
+        include_str!("../../../templates/ontology_tree.md.j2"),
+    )?;
+    env.add_template(
+        "panel.md.j2",
+        include_str!("../../../templates/panel.md.j2"),
+    )?;
     env.add_template("pgx.md.j2", include_str!("../../../templates/pgx.md.j2"))?;
+    env.add_template(
+        "pgx_diplotype.md.j2",
+        include_str!("../../../templates/pgx_diplotype.md.j2"),
+    )?;
+    env.add_template(
+        "pgx_dose_check.md.j2",
+        include_str!("../../../templates/pgx_dose_check.md.j2"),
+    )?;
+    env.add_template(
+        "pgx_panel.md.j2",
+        include_str!("../../../templates/pgx_panel.md.j2"),
+    )?;
     env.add_template(
         "pgx_search.md.j2",
         include_str!("../../../templates/pgx_search.md.j2"),
@@ -516,22 +671,50 @@ fn env() -> Result<&'static Environment<'static>, BioMcpError> {
         "trial.md.j2",
         include_str!("../../../templates/trial.md.j2"),
     )?;
+    env.add_template(
+        "trial_match.md.j2",
+        include_str!("../../../templates/trial_match.md.j2"),
+    )?;
     env.add_template(
         "trial_search.md.j2",
         include_str!("../../../templates/trial_search.md.j2"),
     )?;
+    env.add_template(
+        "trial_summary.md.j2",
+        include_str!("../../../templates/trial_summary.md.j2"),
+    )?;
     env.add_template(
         "variant.md.j2",
         include_str!("../../../templates/variant.md.j2"),
     )?;
+    env.add_template(
+        "variant_compare.md.j2",
+        include_str!("../../../templates/variant_compare.md.j2"),
+    )?;
+    env.add_template(
+        "variant_compare_af.md.j2",
+        include_str!("../../../templates/variant_compare_af.md.j2"),
+    )?;
     env.add_template(
         "variant_search.md.j2",
         include_str!("../../../templates/variant_search.md.j2"),
     )?;
+    env.add_template(
+        "variant_validation.md.j2",
+        include_str!("../../../templates/variant_validation.md.j2"),
+    )?;
+    env.add_template(
+        "variant_vcf_annotation.md.j2",
+        include_str!("../../../templates/variant_vcf_annotation.md.j2"),
+    )?;
     env.add_template(
         "phenotype_search.md.j2",
         include_str!("../../../templates/phenotype_search.md.j2"),
     )?;
+    env.add_template(
+        "guideline_search.md.j2",
+        include_str!("../../../templates/guideline_search.md.j2"),
+    )?;
     env.add_template(
         "gwas_search.md.j2",
         include_str!("../../../templates/gwas_search.md.j2"),
@@ -541,6 +724,10 @@ fn env() -> Result<&'static Environment<'static>, BioMcpError> {
         "drug_search.md.j2",
         include_str!("../../../templates/drug_search.md.j2"),
     )?;
+    env.add_template(
+        "repurpose.md.j2",
+        include_str!("../../../templates/repurpose.md.j2"),
+    )?;
     env.add_template(
         "pathway.md.j2",
         include_str!("../../../templates/pathway.md.j2"),
@@ -549,6 +736,14 @@ fn env() -> Result<&'static Environment<'static>, BioMcpError> {
         "pathway_search.md.j2",
         include_str!("../../../templates/pathway_search.md.j2"),
     )?;
+    env.add_template(
+        "study.md.j2",
+        include_str!("../../../templates/study.md.j2"),
+    )?;
+    env.add_template(
+        "study_search.md.j2",
+        include_str!("../../../templates/study_search.md.j2"),
+    )?;
     env.add_template(
         "protein.md.j2",
         include_str!("../../../templates/protein.md.j2"),
@@ -557,6 +752,22 @@ fn env() -> Result<&'static Environment<'static>, BioMcpError> {
         "protein_search.md.j2",
         include_str!("../../../templates/protein_search.md.j2"),
     )?;
+    env.add_template(
+        "protein_domain_search.md.j2",
+        include_str!("../../../templates/protein_domain_search.md.j2"),
+    )?;
+    env.add_template(
+        "structure.md.j2",
+        include_str!("../../../templates/structure.md.j2"),
+    )?;
+    env.add_template(
+        "structure_search.md.j2",
+        include_str!("../../../templates/structure_search.md.j2"),
+    )?;
+    env.add_template(
+        "transcript.md.j2",
+        include_str!("../../../templates/transcript.md.j2"),
+    )?;
     env.add_template(
         "adverse_event.md.j2",
         include_str!("../../../templates/adverse_event.md.j2"),
@@ -573,10 +784,22 @@ fn env() -> Result<&'static Environment<'static>, BioMcpError> {
         "device_event_search.md.j2",
         include_str!("../../../templates/device_event_search.md.j2"),
     )?;
+    env.add_template(
+        "device_clearance.md.j2",
+        include_str!("../../../templates/device_clearance.md.j2"),
+    )?;
+    env.add_template(
+        "device_clearance_search.md.j2",
+        include_str!("../../../templates/device_clearance_search.md.j2"),
+    )?;
     env.add_template(
         "recall_search.md.j2",
         include_str!("../../../templates/recall_search.md.j2"),
     )?;
+    env.add_template(
+        "veterinary_event_search.md.j2",
+        include_str!("../../../templates/veterinary_event_search.md.j2"),
+    )?;
     env.add_template(
         "search_all.md.j2",
         include_str!("../../../templates/search_all.md.j2"),
@@ -585,9 +808,19 @@ fn env() -> Result<&'static Environment<'static>, BioMcpError> {
         "discover.md.j2",
         include_str!("../../../templates/discover.md.j2"),
     )?;
+    env.add_template("ask.md.j2", include_str!("../../../templates/ask.md.j2"))?;
+    if let Some(dir) = template_overrides::template_override_dir() {
+        template_overrides::apply_template_overrides(&mut env, &dir);
+    }
 
     let _ = ENV.set(env);
     Ok(ENV
         .get()
         .expect("ENV should be initialized by the time this is reached"))
 }
+
+/// Builds the render environment now (bundled templates plus user overrides)
+/// instead of on the first render; returns the number of templates.
+pub(crate) fn warm_templates() -> Result<usize, BioMcpError> {
+    Ok(env()?.templates().count())
+}
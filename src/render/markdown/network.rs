@@ -0,0 +1,53 @@
+//! Interaction network markdown renderers.
+
+use super::*;
+
+#[cfg(test)]
+mod tests;
+
+/// Rows listed per table; exports carry the whole graph.
+const NETWORK_TABLE_LIMIT: usize = 25;
+
+#[derive(serde::Serialize)]
+struct NetworkNodeRow<'a> {
+    id: &'a str,
+    level: usize,
+    degree: usize,
+}
+
+pub fn network_markdown(network: &InteractionNetwork) -> Result<String, BioMcpError> {
+    let mut nodes = network
+        .nodes
+        .iter()
+        .map(|node| NetworkNodeRow {
+            id: &node.id,
+            level: node.level,
+            degree: network
+                .edges
+                .iter()
+                .filter(|edge| edge.source == node.id || edge.target == node.id)
+                .count(),
+        })
+        .collect::<Vec<_>>();
+    nodes.sort_by(|a, b| {
+        a.level
+            .cmp(&b.level)
+            .then_with(|| b.degree.cmp(&a.degree))
+            .then_with(|| a.id.cmp(b.id))
+    });
+    let node_count = nodes.len();
+    nodes.truncate(NETWORK_TABLE_LIMIT);
+
+    let tmpl = env()?.get_template("network.md.j2")?;
+    Ok(tmpl.render(context! {
+        seed => &network.seed,
+        accession => &network.accession,
+        depth => network.depth,
+        min_score => network.min_score,
+        nodes => nodes,
+        node_count => node_count,
+        edges => network.edges.iter().take(NETWORK_TABLE_LIMIT).collect::<Vec<_>>(),
+        edge_count => network.edges.len(),
+        truncated => network.truncated,
+    })?)
+}
@@ -0,0 +1,36 @@
+use super::*;
+use crate::entities::network::{NetworkEdge, NetworkNode};
+
+#[test]
+fn network_markdown_lists_proteins_by_level_and_edges_by_score() {
+    let node = |id: &str, level| NetworkNode {
+        id: id.to_string(),
+        level,
+    };
+    let edge = |source: &str, target: &str, score| NetworkEdge {
+        source: source.to_string(),
+        target: target.to_string(),
+        score,
+    };
+    let markdown = network_markdown(&InteractionNetwork {
+        seed: "BRAF".to_string(),
+        accession: "P15056".to_string(),
+        depth: 2,
+        min_score: 0.7,
+        nodes: vec![node("BRAF", 0), node("MAPK1", 2), node("MAP2K1", 1)],
+        edges: vec![
+            edge("BRAF", "MAP2K1", 0.999),
+            edge("MAP2K1", "MAPK1", 0.998),
+        ],
+        truncated: false,
+    })
+    .expect("network should render");
+
+    assert!(markdown.starts_with("# STRING network: BRAF (P15056)\n"));
+    assert!(markdown.contains(
+        "3 proteins and 2 interactions within 2 levels of BRAF, STRING combined score ≥ 0.7."
+    ));
+    assert!(markdown.contains("| BRAF | 0 | 1 |\n| MAP2K1 | 1 | 2 |\n| MAPK1 | 2 | 1 |"));
+    assert!(markdown.contains("| BRAF | MAP2K1 | 0.999 |"));
+    assert!(markdown.contains("See also: biomcp get protein P15056 interactions"));
+}
@@ -0,0 +1,47 @@
+//! Ontology tree markdown renderers.
+
+use super::*;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(serde::Serialize)]
+struct OntologyRow<'a> {
+    indent: String,
+    id: &'a str,
+    name: &'a str,
+    has_more: bool,
+    repeated: bool,
+}
+
+fn push_rows<'a>(node: &'a OntologyNode, level: usize, rows: &mut Vec<OntologyRow<'a>>) {
+    for relative in node.children.iter().chain(&node.parents) {
+        rows.push(OntologyRow {
+            indent: "  ".repeat(level),
+            id: &relative.id,
+            name: &relative.name,
+            has_more: relative.has_more,
+            repeated: relative.repeated,
+        });
+        push_rows(relative, level + 1, rows);
+    }
+}
+
+pub fn ontology_tree_markdown(tree: &OntologyTree) -> Result<String, BioMcpError> {
+    let mut rows = Vec::new();
+    push_rows(&tree.root, 0, &mut rows);
+    let has_more = rows.iter().any(|row| row.has_more);
+    let tmpl = env()?.get_template("ontology_tree.md.j2")?;
+    Ok(tmpl.render(context! {
+        ontology => &tree.ontology,
+        ancestors => tree.direction == OntologyDirection::Ancestors,
+        depth => tree.depth,
+        root_id => &tree.root.id,
+        root_name => &tree.root.name,
+        rows => rows,
+        node_count => tree.node_count,
+        truncated => tree.truncated,
+        has_more => has_more,
+        next_depth => (tree.depth < MAX_ONTOLOGY_DEPTH).then_some(tree.depth + 1),
+    })?)
+}
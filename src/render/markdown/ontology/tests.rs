@@ -0,0 +1,61 @@
+use super::*;
+
+fn node(id: &str, name: &str) -> OntologyNode {
+    OntologyNode {
+        id: id.to_string(),
+        name: name.to_string(),
+        has_more: false,
+        repeated: false,
+        children: Vec::new(),
+        parents: Vec::new(),
+    }
+}
+
+#[test]
+fn ontology_tree_markdown_indents_levels_and_marks_repeats() {
+    let mut alpha = node("MONDO:0000002", "alpha melanoma");
+    let mut shared = node("MONDO:0000004", "shared melanoma");
+    shared.has_more = true;
+    alpha.children.push(shared);
+    let mut repeat = node("MONDO:0000004", "shared melanoma");
+    repeat.repeated = true;
+    let mut beta = node("MONDO:0000003", "beta melanoma");
+    beta.children.push(repeat);
+    let mut root = node("MONDO:0005105", "melanoma");
+    root.children = vec![alpha, beta];
+
+    let markdown = ontology_tree_markdown(&OntologyTree {
+        ontology: "MONDO".to_string(),
+        direction: OntologyDirection::Children,
+        depth: 2,
+        root,
+        node_count: 4,
+        truncated: false,
+    })
+    .expect("ontology tree should render");
+
+    assert!(markdown.starts_with("# melanoma (MONDO:0005105)\n"));
+    assert!(markdown.contains("Subtypes in MONDO (OLS), 2 levels down:"));
+    assert!(markdown.contains(
+        "- alpha melanoma (MONDO:0000002)\n  - shared melanoma (MONDO:0000004) …\n- beta melanoma (MONDO:0000003)\n  - shared melanoma (MONDO:0000004) _(listed above)_\n"
+    ));
+    assert!(markdown.contains("raise --depth to 3 or more"));
+    assert!(markdown.contains("See also: biomcp get disease MONDO:0005105"));
+}
+
+#[test]
+fn ontology_tree_markdown_reports_root_terms_without_ancestors() {
+    let markdown = ontology_tree_markdown(&OntologyTree {
+        ontology: "MONDO".to_string(),
+        direction: OntologyDirection::Ancestors,
+        depth: 1,
+        root: node("MONDO:0000001", "disease"),
+        node_count: 0,
+        truncated: false,
+    })
+    .expect("ontology tree should render");
+
+    assert!(markdown.contains("Ancestors in MONDO (OLS), 1 level up:"));
+    assert!(markdown.contains("No ancestors: MONDO:0000001 is a root term."));
+    assert!(!markdown.contains("raise --depth"));
+}
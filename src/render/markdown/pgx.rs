@@ -43,6 +43,138 @@ pub fn pgx_markdown(pgx: &Pgx, requested_sections: &[String]) -> Result<String,
     Ok(append_evidence_urls(body, pgx_evidence_urls(pgx)))
 }
 
+pub fn pgx_panel_markdown(panel: &PgxPanel) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("pgx_panel.md.j2")?;
+    let gene_names = panel
+        .genes
+        .iter()
+        .map(|row| row.gene.as_str())
+        .collect::<Vec<_>>();
+    let recommendations = panel
+        .drugs
+        .iter()
+        .flat_map(|drug| {
+            drug.recommendations.iter().map(move |row| {
+                context! {
+                    drugname => &drug.drugname,
+                    gene => &row.gene,
+                    phenotype => &row.phenotype,
+                    recommendation => &row.recommendation,
+                    classification => &row.classification,
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    Ok(tmpl.render(context! {
+        gene_names => gene_names,
+        genes => &panel.genes,
+        drugs => &panel.drugs,
+        recommendations => recommendations,
+        genotype_source => &panel.genotype_source,
+        notes => &panel.notes,
+    })?)
+}
+
+pub fn pgx_diplotype_markdown(result: &PgxDiplotype) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("pgx_diplotype.md.j2")?;
+    let recommendations = result
+        .recommendations
+        .iter()
+        .map(|row| {
+            context! {
+                drug => &row.drug,
+                other_phenotypes => (!row.other_phenotypes.is_empty())
+                    .then(|| row.other_phenotypes.join("; ")),
+                recommendation => &row.recommendation,
+                classification => &row.classification,
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut guidelines: Vec<(&str, &str)> = Vec::new();
+    for row in &result.recommendations {
+        if let (Some(name), Some(url)) = (row.guidelinename.as_deref(), row.guidelineurl.as_deref())
+            && !guidelines.iter().any(|(_, seen)| *seen == url)
+        {
+            guidelines.push((name, url));
+        }
+    }
+    let guidelines = guidelines
+        .into_iter()
+        .map(|(name, url)| context! { name => name, url => url })
+        .collect::<Vec<_>>();
+
+    Ok(tmpl.render(context! {
+        gene => &result.gene,
+        diplotype => &result.diplotype,
+        phenotype => &result.phenotype,
+        activity_score => &result.activity_score,
+        ehr_priority => &result.ehr_priority,
+        consultation => &result.consultation,
+        recommendations => recommendations,
+        guidelines => guidelines,
+        notes => &result.notes,
+    })?)
+}
+
+pub fn pgx_dose_check_markdown(check: &PgxDoseCheck) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("pgx_dose_check.md.j2")?;
+    let label_action = check.label.as_ref().map(|label| label.action.label());
+    let verdict = match check.status {
+        PgxDoseCheckStatus::Agree => format!(
+            "CPIC and the FDA label agree ({}).",
+            label_action.unwrap_or_default()
+        ),
+        PgxDoseCheckStatus::Disagree => "CPIC and the FDA label disagree.".to_string(),
+        PgxDoseCheckStatus::LabelSilent => {
+            "Only CPIC gives dosing for this genotype; the FDA label is silent.".to_string()
+        }
+        PgxDoseCheckStatus::NoCpicRecommendation => {
+            "No CPIC recommendation matches; nothing to cross-check.".to_string()
+        }
+        PgxDoseCheckStatus::Unclear => {
+            "No clear dosing direction on one side; review the text below.".to_string()
+        }
+    };
+    let cpic = check
+        .cpic
+        .iter()
+        .map(|row| {
+            context! {
+                phenotype => &row.phenotype,
+                other_phenotypes => (!row.other_phenotypes.is_empty())
+                    .then(|| row.other_phenotypes.join("; ")),
+                action => row.action.label(),
+                recommendation => &row.recommendation,
+                classification => &row.classification,
+            }
+        })
+        .collect::<Vec<_>>();
+    let guideline = check
+        .cpic
+        .iter()
+        .find(|row| row.guidelinename.is_some() && row.guidelineurl.is_some());
+    let label_url = check
+        .label
+        .as_ref()
+        .and_then(|label| label.set_id.as_deref())
+        .and_then(dailymed_setid_url);
+
+    Ok(tmpl.render(context! {
+        drug => &check.drug,
+        gene => &check.gene,
+        phenotype => &check.phenotype,
+        verdict => verdict,
+        flags => &check.flags,
+        cpic => cpic,
+        guideline_name => guideline.and_then(|row| row.guidelinename.as_deref()),
+        guideline_url => guideline.and_then(|row| row.guidelineurl.as_deref()),
+        label => &check.label,
+        label_action => label_action,
+        label_url => label_url,
+        notes => &check.notes,
+    })?)
+}
+
 #[allow(dead_code)]
 pub fn pgx_search_markdown(
     query: &str,
@@ -19,3 +19,132 @@ fn pgx_markdown_includes_evidence_links() {
     assert!(markdown.contains("[PharmGKB](https://www.pharmgkb.org/gene/CYP2D6)"));
     assert!(markdown.contains("[PharmGKB](https://www.pharmgkb.org/chemical/warfarin)"));
 }
+
+#[test]
+fn pgx_panel_markdown_renders_drug_table_and_matched_recommendations() {
+    let panel: PgxPanel = serde_json::from_value(serde_json::json!({
+        "genes": [
+            {"gene": "CYP2D6", "diplotype": "*1/*4", "phenotype": "Intermediate Metabolizer", "actionable_drugs": 2, "other_drugs": 1},
+            {"gene": "TPMT", "actionable_drugs": 1, "other_drugs": 0}
+        ],
+        "drugs": [
+            {
+                "drugname": "codeine",
+                "genes": ["CYP2D6"],
+                "cpiclevel": "A",
+                "pgxtesting": "Actionable PGx",
+                "guidelinename": "CPIC Guideline for Codeine and CYP2D6",
+                "guidelineurl": "https://cpicpgx.org/guidelines/guideline-for-codeine-and-cyp2d6/",
+                "recommendations": [
+                    {"gene": "CYP2D6", "phenotype": "Intermediate Metabolizer", "recommendation": "Use label recommended dosing", "classification": "Moderate"}
+                ]
+            },
+            {"drugname": "azathioprine", "genes": ["TPMT"], "cpiclevel": "A"}
+        ],
+        "genotype_source": "sample.pgx.vcf",
+        "notes": ["No CPIC gene-drug pairs for: ABC1."]
+    }))
+    .expect("panel json");
+
+    let markdown = pgx_panel_markdown(&panel).expect("panel markdown");
+    assert!(markdown.contains("# PGx panel: CYP2D6, TPMT"));
+    assert!(markdown.contains("Source: CPIC | Genotypes: sample.pgx.vcf"));
+    assert!(markdown.contains("| CYP2D6 | *1/*4 | Intermediate Metabolizer | 2 | 1 |"));
+    assert!(markdown.contains(
+        "| codeine | CYP2D6 | A | Actionable PGx | [CPIC Guideline for Codeine and CYP2D6](https://cpicpgx.org/guidelines/guideline-for-codeine-and-cyp2d6/) |"
+    ));
+    assert!(markdown.contains("| azathioprine | TPMT | A | - | - |"));
+    assert!(markdown.contains(
+        "| codeine | CYP2D6 | Intermediate Metabolizer | Use label recommended dosing | Moderate |"
+    ));
+    assert!(markdown.contains("Note: No CPIC gene-drug pairs for: ABC1."));
+}
+
+#[test]
+fn pgx_dose_check_markdown_renders_verdict_flags_and_label_excerpts() {
+    let check: PgxDoseCheck = serde_json::from_value(serde_json::json!({
+        "drug": "azathioprine",
+        "gene": "TPMT",
+        "phenotype": "Intermediate Metabolizer",
+        "status": "disagree",
+        "cpic": [{
+            "phenotype": "Intermediate Metabolizer",
+            "other_phenotypes": ["NUDT15: Normal Metabolizer"],
+            "recommendation": "Start with reduced starting doses (30-80% of normal dose).",
+            "classification": "Strong",
+            "action": "reduce",
+            "guidelinename": "Thiopurines and TPMT and NUDT15",
+            "guidelineurl": "https://cpicpgx.org/guidelines/guideline-for-thiopurines-and-tpmt/"
+        }],
+        "label": {
+            "set_id": "abc-123",
+            "brand_name": "Imuran",
+            "phenotype_specific": true,
+            "excerpts": ["No dose adjustment is needed for TPMT intermediate metabolizers."],
+            "action": "standard"
+        },
+        "flags": ["CPIC says reduce dose for TPMT Intermediate Metabolizer; the FDA label says standard dosing."]
+    }))
+    .expect("dose check json");
+
+    let markdown = pgx_dose_check_markdown(&check).expect("dose check markdown");
+    assert!(markdown.contains("# PGx dose check: azathioprine with TPMT Intermediate Metabolizer"));
+    assert!(markdown.contains("Sources: CPIC | FDA label (OpenFDA): Imuran"));
+    assert!(markdown.contains("Verdict: CPIC and the FDA label disagree."));
+    assert!(markdown.contains("- Flag: CPIC says reduce dose"));
+    assert!(markdown.contains(
+        "| Intermediate Metabolizer | NUDT15: Normal Metabolizer | reduce dose | Start with reduced starting doses (30-80% of normal dose). | Strong |"
+    ));
+    assert!(markdown.contains("Action: standard dosing\n"));
+    assert!(
+        markdown.contains("- No dose adjustment is needed for TPMT intermediate metabolizers.")
+    );
+    assert!(markdown.contains(
+        "Full label: [DailyMed](https://dailymed.nlm.nih.gov/dailymed/drugInfo.cfm?setid=abc-123)"
+    ));
+}
+
+#[test]
+fn pgx_diplotype_markdown_renders_phenotype_and_recommendation_table() {
+    let result: PgxDiplotype = serde_json::from_value(serde_json::json!({
+        "gene": "CYP2D6",
+        "diplotype": "*1/*4",
+        "phenotype": "Intermediate Metabolizer",
+        "activity_score": "1.0",
+        "lookup_key": "1.0",
+        "ehr_priority": "Abnormal/Priority/High Risk",
+        "recommendations": [
+            {
+                "drug": "codeine",
+                "recommendation": "Use codeine label recommended age- or weight-specific dosing.",
+                "classification": "Moderate",
+                "guidelinename": "CYP2D6, OPRM1, and COMT and Opioids",
+                "guidelineurl": "https://cpicpgx.org/guidelines/guideline-for-codeine-and-cyp2d6/"
+            },
+            {
+                "drug": "tramadol",
+                "recommendation": "Use tramadol label recommended age- or weight-specific dosing.",
+                "classification": "Optional",
+                "guidelinename": "CYP2D6, OPRM1, and COMT and Opioids",
+                "guidelineurl": "https://cpicpgx.org/guidelines/guideline-for-codeine-and-cyp2d6/"
+            }
+        ]
+    }))
+    .expect("diplotype json");
+
+    let markdown = pgx_diplotype_markdown(&result).expect("diplotype markdown");
+    assert!(markdown.contains("# PGx diplotype: CYP2D6 *1/*4"));
+    assert!(markdown.contains("- Phenotype: Intermediate Metabolizer"));
+    assert!(markdown.contains("- Activity score: 1.0"));
+    assert!(markdown.contains("- EHR priority: Abnormal/Priority/High Risk"));
+    assert!(markdown.contains(
+        "| codeine | - | Use codeine label recommended age- or weight-specific dosing. | Moderate |"
+    ));
+    assert_eq!(
+        markdown
+            .matches("- Guideline: [CYP2D6, OPRM1, and COMT and Opioids]")
+            .count(),
+        1
+    );
+    assert!(markdown.contains("--gene CYP2D6 --phenotype \"Intermediate Metabolizer\""));
+}
@@ -138,6 +138,21 @@ pub fn protein_search_markdown(
     protein_search_markdown_with_footer(query, results, "")
 }
 
+pub fn protein_domain_search_markdown(
+    query: &str,
+    results: &[ProteinDomainHit],
+    pagination_footer: &str,
+) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("protein_domain_search.md.j2")?;
+    let body = tmpl.render(context! {
+        query => query,
+        count => results.len(),
+        results => results,
+        pagination_footer => pagination_footer,
+    })?;
+    Ok(with_pagination_footer(body, pagination_footer))
+}
+
 pub fn protein_search_markdown_with_footer(
     query: &str,
     results: &[ProteinSearchResult],
@@ -87,3 +87,36 @@ fn protein_markdown_renders_complexes_summary_and_detail_bullets() {
     assert!(!markdown.contains("AKT1"));
     assert!(!markdown.contains("See also: biomcp get protein P15056 complexes"));
 }
+
+#[test]
+fn protein_domain_search_markdown_lists_domain_ranges() {
+    let results = vec![
+        ProteinDomainHit {
+            accession: "P15056".to_string(),
+            name: "Serine/threonine-protein kinase B-raf".to_string(),
+            gene_symbol: Some("BRAF".to_string()),
+            species: Some("Homo sapiens".to_string()),
+            length: Some(766),
+            reviewed: true,
+            domain_locations: vec!["457-717".to_string()],
+        },
+        ProteinDomainHit {
+            accession: "P00533".to_string(),
+            name: "Epidermal growth factor receptor".to_string(),
+            gene_symbol: None,
+            species: None,
+            length: None,
+            reviewed: true,
+            domain_locations: Vec::new(),
+        },
+    ];
+
+    let markdown = protein_domain_search_markdown("domain=PF07714, organism=9606", &results, "")
+        .expect("markdown");
+    assert!(markdown.contains("# Proteins with domain: domain=PF07714, organism=9606"));
+    assert!(markdown.contains("Found 2 proteins"));
+    assert!(markdown.contains(
+        "| P15056 | Serine/threonine-protein kinase B-raf | BRAF | Homo sapiens | 766 | 457-717 |"
+    ));
+    assert!(markdown.contains("| P00533 | Epidermal growth factor receptor | - | - | - | - |"));
+}
@@ -50,6 +50,8 @@ pub(super) fn related_command_description(command: &str) -> Option<&'static str>
         Some("background evidence this paper builds on; use if the primary paper lacks context")
     } else if command.starts_with("biomcp article recommendations ") {
         Some("related papers to broaden coverage; use only if the primary paper lacks your answer")
+    } else if command.starts_with("biomcp get trial NCT") {
+        Some("registry record for a trial cited in this article")
     } else if command.contains(" --type review --limit 5") {
         Some("supplement sparse structured data with review literature for indication context")
     } else if command.starts_with("biomcp get gene ") && command.ends_with(" clingen constraint") {
@@ -58,6 +60,8 @@ pub(super) fn related_command_description(command: &str) -> Option<&'static str>
         Some("deepen into protein function and localization")
     } else if command.starts_with("biomcp get gene ") && command.ends_with(" hpa") {
         Some("deepen into tissue expression and localization")
+    } else if command.starts_with("biomcp get gene ") && command.contains(" --species ") {
+        Some("open the closest model-organism ortholog")
     } else if command.starts_with("biomcp search trial -c ") && command.ends_with(" -s recruiting")
     {
         Some("recruiting trials for the top ClinGen disease on this gene card")
@@ -73,6 +77,14 @@ pub(super) fn related_command_description(command: &str) -> Option<&'static str>
         Some("treatment options for this condition")
     } else if command.starts_with("biomcp get pgx ") {
         Some("pharmacogenomics card")
+    } else if command.starts_with("biomcp get hla ") {
+        Some("HLA allele frequencies and drug/disease associations")
+    } else if command.starts_with("biomcp get structure ") {
+        Some("experimental structure: method, resolution, chains, and ligands")
+    } else if command.starts_with("biomcp search structure --uniprot ") {
+        Some("other PDB entries for this protein, best resolution first")
+    } else if command.starts_with("biomcp get transcript ") {
+        Some("exon structure, canonical/MANE status, and protein product")
     } else if command.starts_with("biomcp study top-mutated --study ") {
         Some("mutation frequency ranking")
     } else if command == "biomcp study download --list" {
@@ -204,6 +216,9 @@ pub(super) fn related_gene(gene: &Gene) -> Vec<String> {
     if symbol.is_empty() {
         return Vec::new();
     }
+    if let Some(species) = gene.species.as_deref() {
+        return related_gene_for_species(gene, symbol, species);
+    }
     let mut out = Vec::new();
     let summary = gene.summary.as_deref().unwrap_or("").to_ascii_lowercase();
 
@@ -223,6 +238,17 @@ pub(super) fn related_gene(gene: &Gene) -> Vec<String> {
             force_quote_arg(&disease)
         ));
     }
+    if let Some((ortholog, species)) = gene
+        .orthologs
+        .iter()
+        .flatten()
+        .find_map(|row| Some((row.symbol.as_deref()?, &row.species)))
+    {
+        out.push(format!(
+            "biomcp get gene {} --species {species}",
+            quote_arg(ortholog)
+        ));
+    }
     out.push(format!("biomcp search pgx -g {symbol}"));
     out.push(format!("biomcp search variant -g {symbol}"));
     out.push(format!("biomcp search article -g {symbol}"));
@@ -231,6 +257,23 @@ pub(super) fn related_gene(gene: &Gene) -> Vec<String> {
     dedupe_markdown_commands(out)
 }
 
+/// Non-human genes keep the `--species` flag on card follow-ups; trial, drug,
+/// and variant searches are human-only and point back at the human gene search.
+fn related_gene_for_species(gene: &Gene, symbol: &str, species: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    if gene.protein.is_none() {
+        out.push(format!(
+            "biomcp get gene {symbol} --species {species} protein"
+        ));
+    }
+    if gene.go.is_none() {
+        out.push(format!("biomcp get gene {symbol} --species {species} go"));
+    }
+    out.push(format!("biomcp search gene -q {symbol}"));
+    out.push(format!("biomcp search article -g {symbol}"));
+    dedupe_markdown_commands(out)
+}
+
 pub(super) fn related_variant(variant: &Variant) -> Vec<String> {
     let mut out: Vec<String> = Vec::new();
     let gene = variant.gene.trim();
@@ -257,14 +300,24 @@ pub(super) fn related_variant(variant: &Variant) -> Vec<String> {
         out.push(format!("biomcp search drug --target {gene}"));
     }
 
-    if !variant.id.trim().is_empty() {
-        let id = quote_arg(&variant.id);
+    if let Some(transcript) = variant.vep.as_ref().and_then(|vep| {
+        vep.transcripts
+            .iter()
+            .find(|row| row.canonical && row.transcript.starts_with("ENST"))
+    }) {
+        out.push(format!("biomcp get transcript {}", transcript.transcript));
+    }
+
+    // Follow-up commands resolve on GRCh37, so a GRCh38 card hands them the rsID.
+    let follow_up_id = match (variant.assembly, variant.rsid.as_deref()) {
+        (Some(crate::entities::variant::GenomeAssembly::Grch38), Some(rsid)) => rsid,
+        _ => variant.id.as_str(),
+    };
+    if !follow_up_id.trim().is_empty() {
+        let id = quote_arg(follow_up_id);
         out.push(format!("biomcp variant trials {id}"));
         out.push(format!("biomcp variant articles {id}"));
-        let has_oncokb_token = std::env::var("ONCOKB_TOKEN")
-            .ok()
-            .map(|v| !v.trim().is_empty())
-            .unwrap_or(false);
+        let has_oncokb_token = crate::sources::tenant::api_key("ONCOKB_TOKEN").is_some();
         if has_oncokb_token {
             out.push(format!("biomcp variant oncokb {id}"));
         }
@@ -324,9 +377,14 @@ pub(super) fn search_next_commands_trial(results: &[TrialSearchResult]) -> Vec<S
     }
 
     let mut out = Vec::new();
+    // Non-ClinicalTrials.gov rows are only fetchable through their NCT cross-registration.
     if let Some(nct_id) = results
         .first()
-        .map(|result| quote_arg(&result.nct_id))
+        .and_then(|result| match result.registry {
+            None => Some(result.nct_id.as_str()),
+            Some(_) => result.cross_registered_nct.as_deref(),
+        })
+        .map(quote_arg)
         .filter(|nct_id| !nct_id.is_empty())
     {
         out.push(format!("biomcp get trial {nct_id}"));
@@ -355,12 +413,17 @@ pub(super) fn search_next_commands_gene(results: &[GeneSearchResult]) -> Vec<Str
     }
 
     let mut out = Vec::new();
+    let species_flag = results
+        .first()
+        .and_then(|result| result.species.as_deref())
+        .map(|species| format!(" --species {species}"))
+        .unwrap_or_default();
     if let Some(symbol) = results
         .first()
         .map(|result| quote_arg(&result.symbol))
         .filter(|symbol| !symbol.is_empty())
     {
-        out.push(format!("biomcp get gene {symbol}"));
+        out.push(format!("biomcp get gene {symbol}{species_flag}"));
     }
     out.push("biomcp list gene".to_string());
     dedupe_markdown_commands(out)
@@ -515,6 +578,23 @@ pub(super) fn search_next_commands_pgx(
     dedupe_markdown_commands(out)
 }
 
+pub(super) fn search_next_commands_study(results: &[StudySearchResult]) -> Vec<String> {
+    if results.is_empty() {
+        return Vec::new();
+    }
+
+    let mut out = Vec::new();
+    if let Some(id) = results
+        .first()
+        .map(|result| quote_arg(&result.study_id))
+        .filter(|id| !id.is_empty())
+    {
+        out.push(format!("biomcp get study {id}"));
+    }
+    out.push("biomcp list study".to_string());
+    dedupe_markdown_commands(out)
+}
+
 pub(super) fn search_next_commands_pathway(results: &[PathwaySearchResult]) -> Vec<String> {
     if results.is_empty() {
         return Vec::new();
@@ -568,6 +648,25 @@ pub(super) fn search_next_commands_device_events(
     dedupe_markdown_commands(out)
 }
 
+pub(super) fn search_next_commands_device_clearances(
+    results: &[DeviceClearanceSearchResult],
+) -> Vec<String> {
+    if results.is_empty() {
+        return Vec::new();
+    }
+
+    let mut out = Vec::new();
+    if let Some(k_number) = results
+        .first()
+        .map(|result| quote_arg(&result.k_number))
+        .filter(|k_number| !k_number.is_empty())
+    {
+        out.push(format!("biomcp get device-clearance {k_number}"));
+    }
+    out.push("biomcp list device-clearance".to_string());
+    dedupe_markdown_commands(out)
+}
+
 pub(super) fn search_next_commands_recalls(results: &[RecallSearchResult]) -> Vec<String> {
     if results.is_empty() {
         return Vec::new();
@@ -593,6 +692,24 @@ pub(super) fn search_next_commands_gwas(results: &[VariantGwasAssociation]) -> V
     dedupe_markdown_commands(out)
 }
 
+pub(super) fn search_next_commands_guideline(results: &[GuidelineSearchResult]) -> Vec<String> {
+    if results.is_empty() {
+        return Vec::new();
+    }
+
+    let mut out = Vec::new();
+    if let Some(pmid) = results
+        .iter()
+        .filter_map(|result| result.pmid.as_deref())
+        .map(quote_arg)
+        .find(|pmid| !pmid.is_empty())
+    {
+        out.push(format!("biomcp get article {pmid}"));
+    }
+    out.push("biomcp list guidelines".to_string());
+    dedupe_markdown_commands(out)
+}
+
 pub(super) fn related_phenotype_search_results(results: &[PhenotypeSearchResult]) -> Vec<String> {
     let Some(label) = results.first().and_then(|row| {
         let name = row.disease_name.trim();
@@ -872,6 +989,49 @@ pub(super) fn related_pgx(pgx: &Pgx) -> Vec<String> {
     out
 }
 
+pub(super) fn related_hla(hla: &Hla) -> Vec<String> {
+    let mut out = Vec::new();
+    let gene = hla.gene.trim();
+    if !gene.is_empty() {
+        out.push(format!("biomcp get pgx {gene}"));
+    }
+    if let Some(drug) = hla
+        .drug_associations
+        .first()
+        .map(|row| quote_arg(&row.drug))
+        .filter(|v| !v.is_empty())
+    {
+        out.push(format!("biomcp get drug {drug}"));
+    }
+    let allele = force_quote_arg(&hla.allele);
+    if !allele.is_empty() {
+        out.push(format!("biomcp search article -k {allele}"));
+    }
+    out
+}
+
+pub(super) fn related_study(study: &Study) -> Vec<String> {
+    let id = quote_arg(&study.study_id);
+    if id.is_empty() {
+        return Vec::new();
+    }
+    let mut out = Vec::new();
+    match study.mutated_genes.first() {
+        Some(row) => out.push(format!("biomcp get gene {}", quote_arg(&row.gene))),
+        None => out.push(format!("biomcp get study {id} genes")),
+    }
+    if let Some(pmid) = study
+        .pmid
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        out.push(format!("biomcp get article {pmid}"));
+    }
+    out.push(format!("biomcp study download {id}"));
+    out
+}
+
 pub(super) fn related_pathway(pathway: &Pathway) -> Vec<String> {
     let id = quote_arg(&pathway.id);
     if id.is_empty() {
@@ -894,6 +1054,15 @@ pub(super) fn related_protein(protein: &Protein, requested_sections: &[String])
             out.push(format!("biomcp get protein {accession} complexes"));
         }
     }
+    if (requested_section("structures") || requested_section("all"))
+        && let Some(pdb_id) = protein
+            .structures
+            .iter()
+            .filter_map(|row| row.split_whitespace().next())
+            .find(|id| id.len() == 4 && id.starts_with(|ch: char| ch.is_ascii_digit()))
+    {
+        out.push(format!("biomcp get structure {pdb_id}"));
+    }
     if let Some(symbol) = protein
         .gene_symbol
         .as_deref()
@@ -905,6 +1074,53 @@ pub(super) fn related_protein(protein: &Protein, requested_sections: &[String])
     out
 }
 
+pub(super) fn related_structure(structure: &Structure) -> Vec<String> {
+    let mut out = Vec::new();
+    if let Some(accession) = structure
+        .chains
+        .iter()
+        .flat_map(|chain| chain.uniprot_ids.iter())
+        .map(|id| id.trim())
+        .find(|id| !id.is_empty())
+    {
+        out.push(format!("biomcp get protein {accession}"));
+        out.push(format!("biomcp search structure --uniprot {accession}"));
+    }
+    if let Some(pmid) = structure
+        .citation
+        .as_ref()
+        .and_then(|citation| citation.pmid.as_deref())
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        out.push(format!("biomcp get article {pmid}"));
+    }
+    out
+}
+
+pub(super) fn related_transcript(transcript: &Transcript) -> Vec<String> {
+    let mut out = Vec::new();
+    if let Some(symbol) = transcript
+        .gene_symbol
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        out.push(format!("biomcp get gene {symbol}"));
+        out.push(format!("biomcp search variant -g {symbol}"));
+    }
+    if let Some(accession) = transcript
+        .protein
+        .as_ref()
+        .and_then(|protein| protein.uniprot.first())
+        .map(|id| id.split('-').next().unwrap_or_default().trim())
+        .filter(|v| !v.is_empty())
+    {
+        out.push(format!("biomcp get protein {accession}"));
+    }
+    out
+}
+
 pub(super) fn related_drug(drug: &Drug) -> Vec<String> {
     let name = quote_arg(&drug.name);
     if name.is_empty() {
@@ -965,5 +1181,20 @@ pub(super) fn related_device_event(event: &DeviceEvent) -> Vec<String> {
     ]
 }
 
+pub(super) fn related_device_clearance(clearance: &DeviceClearance) -> Vec<String> {
+    let Some(product_code) = clearance
+        .product_code
+        .as_deref()
+        .map(quote_arg)
+        .filter(|code| !code.is_empty())
+    else {
+        return Vec::new();
+    };
+    vec![
+        format!("biomcp search device-clearance --product-code {product_code}"),
+        format!("biomcp search adverse-event --type device --product-code {product_code}"),
+    ]
+}
+
 #[cfg(test)]
 mod tests;
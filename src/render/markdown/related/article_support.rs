@@ -127,7 +127,10 @@ pub(super) fn related_article(article: &Article) -> Vec<String> {
         .as_deref()
         .map(str::trim)
         .filter(|v| !v.is_empty())
-        && article.annotations.is_some()
+        && article
+            .annotations
+            .as_ref()
+            .is_some_and(ArticleAnnotations::has_entities)
     {
         out.push(format!("biomcp article entities {pmid}"));
     }
@@ -150,6 +153,12 @@ pub(super) fn related_article(article: &Article) -> Vec<String> {
             ArticleAnnotationBucket::Chemical,
             2,
         ));
+        out.extend(
+            ann.trials
+                .iter()
+                .take(2)
+                .map(|nct_id| format!("biomcp get trial {nct_id}")),
+        );
     }
     if let Some(pmid) = article
         .pmid
@@ -161,5 +170,16 @@ pub(super) fn related_article(article: &Article) -> Vec<String> {
         out.push(format!("biomcp article citations {pmid} --limit 3"));
         out.push(format!("biomcp article recommendations {pmid} --limit 3"));
     }
+    if let Some(graph) = article.citation_graph.as_ref() {
+        for links in [&graph.citing, &graph.references] {
+            out.extend(
+                links
+                    .iter()
+                    .filter_map(|link| link.pmid.as_deref())
+                    .take(2)
+                    .map(|pmid| format!("biomcp get article {pmid}")),
+            );
+        }
+    }
     dedupe_markdown_commands(out)
 }
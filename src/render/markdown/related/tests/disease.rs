@@ -47,6 +47,10 @@ fn related_disease_suggests_review_when_phenotypes_are_sparse() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -95,6 +99,10 @@ fn related_disease_promotes_top_gene_context_before_generic_pivots() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -135,6 +143,10 @@ fn related_disease_falls_back_to_unscored_top_gene_context() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -181,6 +193,10 @@ fn related_disease_uses_synonym_when_name_is_raw_id() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -219,6 +235,10 @@ fn related_disease_non_oncology_skips_study_hints() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -254,6 +274,10 @@ fn related_disease_quotes_single_word_indication_search() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -290,6 +314,10 @@ fn related_disease_oncology_without_local_match_falls_back_to_download_list() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: Some(crate::sources::civic::CivicContext::default()),
         disgenet: None,
         funding: None,
@@ -377,6 +405,10 @@ fn related_disease_oncology_with_local_match_prefers_top_mutated() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -459,6 +491,10 @@ fn related_disease_oncology_matches_noncontiguous_carcinoma_study_labels() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
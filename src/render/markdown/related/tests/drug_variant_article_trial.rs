@@ -21,12 +21,14 @@ fn related_drug_suggests_review_when_label_and_indications_are_sparse() {
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: Vec::new(),
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: None,
+        shortage_trials: None,
         approvals: None,
         us_safety_warnings: None,
         ema_regulatory: None,
@@ -34,6 +36,8 @@ fn related_drug_suggests_review_when_label_and_indications_are_sparse() {
         ema_shortage: None,
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     let related = related_drug(&drug);
@@ -63,15 +67,16 @@ fn search_next_commands_drug_eu_prefers_active_substance_match() {
 
 #[test]
 fn search_next_commands_recalls_are_list_only() {
-    let related = search_next_commands_recalls(&[crate::entities::adverse_event::RecallSearchResult {
-        recall_number: "F-0001-2026".to_string(),
-        classification: "Class I".to_string(),
-        product_description: "Infusion pump".to_string(),
-        reason_for_recall: "Sterility".to_string(),
-        status: "Ongoing".to_string(),
-        distribution_pattern: None,
-        recall_initiation_date: None,
-    }]);
+    let related =
+        search_next_commands_recalls(&[crate::entities::adverse_event::RecallSearchResult {
+            recall_number: "F-0001-2026".to_string(),
+            classification: "Class I".to_string(),
+            product_description: "Infusion pump".to_string(),
+            reason_for_recall: "Sterility".to_string(),
+            status: "Ongoing".to_string(),
+            distribution_pattern: None,
+            recall_initiation_date: None,
+        }]);
 
     assert_eq!(related, vec!["biomcp list adverse-event".to_string()]);
 }
@@ -160,6 +165,41 @@ fn related_variant_pathogenic_keeps_drug_target_without_vus_literature_pivot() {
     );
 }
 
+#[test]
+fn related_variant_grch38_card_hands_follow_ups_the_rsid() {
+    let variant: Variant = serde_json::from_value(serde_json::json!({
+        "id": "chr7:g.140753336A>T",
+        "gene": "BRAF",
+        "rsid": "rs113488022",
+        "assembly": "GRCh38"
+    }))
+    .expect("variant should deserialize");
+
+    let related = related_variant(&variant);
+    assert!(related.contains(&"biomcp variant trials rs113488022".to_string()));
+    assert!(!related.iter().any(|cmd| cmd.contains("140753336")));
+}
+
+#[test]
+fn related_variant_links_the_canonical_vep_transcript() {
+    let variant: Variant = serde_json::from_value(serde_json::json!({
+        "id": "chr7:g.140453136A>T",
+        "gene": "BRAF",
+        "vep": {
+            "input": "7:g.140453136A>T",
+            "transcripts": [
+                {"transcript": "ENST00000479537", "canonical": false},
+                {"transcript": "ENST00000288602", "canonical": true}
+            ]
+        }
+    }))
+    .expect("variant should deserialize");
+
+    let related = related_variant(&variant);
+    assert!(related.contains(&"biomcp get transcript ENST00000288602".to_string()));
+    assert!(!related.iter().any(|cmd| cmd.contains("ENST00000479537")));
+}
+
 #[test]
 fn related_article_uses_article_entities_helper_command() {
     let article = Article {
@@ -176,6 +216,7 @@ fn related_article_uses_article_entities_helper_command() {
         abstract_text: None,
         full_text_path: None,
         full_text_note: None,
+        full_text_source: None,
         annotations: Some(ArticleAnnotations {
             genes: vec![
                 AnnotationCount {
@@ -210,8 +251,10 @@ fn related_article_uses_article_entities_helper_command() {
                 count: 8,
             }],
             mutations: Vec::new(),
+            trials: Vec::new(),
         }),
         semantic_scholar: None,
+        citation_graph: None,
         pubtator_fallback: false,
     };
 
@@ -277,6 +320,53 @@ fn related_article_uses_article_entities_helper_command() {
     ));
 }
 
+#[test]
+fn related_article_links_cited_trials_without_pubtator_entities() {
+    let article = Article {
+        pmid: Some("26027431".to_string()),
+        pmcid: None,
+        doi: None,
+        title: "Combined Nivolumab and Ipilimumab in Untreated Melanoma".to_string(),
+        authors: Vec::new(),
+        journal: None,
+        date: None,
+        citation_count: None,
+        publication_type: None,
+        open_access: None,
+        abstract_text: None,
+        full_text_path: None,
+        full_text_note: None,
+        full_text_source: None,
+        annotations: Some(ArticleAnnotations {
+            genes: Vec::new(),
+            diseases: Vec::new(),
+            chemicals: Vec::new(),
+            mutations: Vec::new(),
+            trials: vec![
+                "NCT01844505".to_string(),
+                "NCT02224781".to_string(),
+                "NCT03068455".to_string(),
+            ],
+        }),
+        semantic_scholar: None,
+        citation_graph: None,
+        pubtator_fallback: true,
+    };
+
+    let related = related_article(&article);
+    assert!(
+        !related
+            .iter()
+            .any(|cmd| cmd.starts_with("biomcp article entities"))
+    );
+    assert_eq!(related[0], "biomcp get trial NCT01844505");
+    assert_eq!(related[1], "biomcp get trial NCT02224781");
+    assert!(!related.iter().any(|cmd| cmd.contains("NCT03068455")));
+
+    let rendered = format_related_block(related);
+    assert!(rendered.contains("registry record for a trial cited in this article"));
+}
+
 #[test]
 fn related_trial_promotes_results_search_for_completed_or_terminated_studies() {
     let trial = crate::entities::trial::Trial {
@@ -299,6 +389,8 @@ fn related_trial_promotes_results_search_for_completed_or_terminated_studies() {
             outcomes: None,
             arms: None,
             references: None,
+            publications: None,
+            results: None,
         };
 
     let related = related_trial(&trial);
@@ -349,6 +441,8 @@ fn related_trial_keeps_recruiting_order_without_results_search() {
         outcomes: None,
         arms: None,
         references: None,
+        publications: None,
+        results: None,
     };
 
     let related = related_trial(&trial);
@@ -380,6 +474,8 @@ fn related_trial_completed_promotes_results_search_before_condition_pivots() {
         outcomes: None,
         arms: None,
         references: None,
+        publications: None,
+        results: None,
     };
 
     let related = related_trial(&trial);
@@ -412,6 +508,8 @@ fn related_trial_results_search_without_intervention_keeps_seed_quoted() {
         outcomes: None,
         arms: None,
         references: None,
+        publications: None,
+        results: None,
     };
 
     let related = related_trial(&trial);
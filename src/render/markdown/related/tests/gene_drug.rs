@@ -14,6 +14,7 @@ fn related_gene_prioritizes_localization_deepening_when_supported() {
                 .to_string(),
         ),
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: vec!["large GTPase 1".to_string()],
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -47,8 +48,14 @@ fn related_gene_prioritizes_localization_deepening_when_supported() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let related = related_gene(&gene);
@@ -74,6 +81,7 @@ fn related_gene_promotes_clingen_trial_search_before_generic_pivots() {
                 .to_string(),
         ),
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: vec!["large GTPase 1".to_string()],
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -116,8 +124,14 @@ fn related_gene_promotes_clingen_trial_search_before_generic_pivots() {
         }),
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
 
     let related = related_gene(&gene);
@@ -134,6 +148,43 @@ fn related_gene_promotes_clingen_trial_search_before_generic_pivots() {
     assert!(related.contains(&"biomcp search pgx -g OPA1".to_string()));
 }
 
+#[test]
+fn related_gene_carries_species_for_non_human_genes() {
+    let gene: Gene = serde_json::from_value(serde_json::json!({
+        "symbol": "Braf",
+        "name": "Braf transforming gene",
+        "entrez_id": "109880",
+        "species": "mouse",
+        "aliases": [],
+        "go": []
+    }))
+    .expect("mouse gene");
+
+    assert_eq!(
+        related_gene(&gene),
+        vec![
+            "biomcp get gene Braf --species mouse protein",
+            "biomcp search gene -q Braf",
+            "biomcp search article -g Braf",
+        ]
+    );
+    assert!(sections_gene(&gene, &[]).is_empty());
+
+    let result = GeneSearchResult {
+        symbol: "Braf".to_string(),
+        name: "Braf transforming gene".to_string(),
+        entrez_id: "109880".to_string(),
+        genomic_coordinates: None,
+        uniprot_id: None,
+        omim_id: None,
+        species: Some("mouse".to_string()),
+    };
+    assert_eq!(
+        search_next_commands_gene(&[result])[0],
+        "biomcp get gene Braf --species mouse"
+    );
+}
+
 #[test]
 fn related_drug_includes_pgx_search() {
     let drug = Drug {
@@ -157,12 +208,14 @@ fn related_drug_includes_pgx_search() {
         indications: Vec::new(),
         interactions: Vec::new(),
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: Vec::new(),
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: None,
+        shortage_trials: None,
         approvals: None,
         us_safety_warnings: None,
         ema_regulatory: None,
@@ -170,6 +223,8 @@ fn related_drug_includes_pgx_search() {
         ema_shortage: None,
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
 
     let related = related_drug(&drug);
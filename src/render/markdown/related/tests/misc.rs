@@ -42,6 +42,10 @@ fn related_disease_malformed_study_lookup_falls_back_to_download_list() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: Some(crate::sources::civic::CivicContext::default()),
         disgenet: None,
         funding: None,
@@ -124,6 +128,11 @@ fn related_protein_includes_complexes_follow_up() {
     assert!(related.contains(&"biomcp get protein P15056 structures".to_string()));
     assert!(related.contains(&"biomcp get protein P15056 complexes".to_string()));
     assert!(related.contains(&"biomcp get gene BRAF".to_string()));
+    assert!(
+        !related
+            .iter()
+            .any(|cmd| cmd.starts_with("biomcp get structure "))
+    );
 }
 
 #[test]
@@ -149,5 +158,6 @@ fn related_protein_excludes_requested_sections() {
     );
     assert!(!related.contains(&"biomcp get protein P15056 structures".to_string()));
     assert!(!related.contains(&"biomcp get protein P15056 complexes".to_string()));
+    assert!(related.contains(&"biomcp get structure 6V34".to_string()));
     assert!(related.contains(&"biomcp get gene BRAF".to_string()));
 }
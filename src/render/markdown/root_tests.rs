@@ -53,6 +53,7 @@ fn markdown_detail_outputs_label_gene_drug_and_disease_sources() {
         uniprot_id: Some("P13569".to_string()),
         summary: Some("Chloride channel.".to_string()),
         gene_type: Some("protein-coding".to_string()),
+        species: None,
         aliases: vec!["ABCC7".to_string()],
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -98,8 +99,14 @@ fn markdown_detail_outputs_label_gene_drug_and_disease_sources() {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     };
     let gene_markdown = gene_markdown(&gene, &[]).expect("gene markdown");
     assert!(gene_markdown.contains("Source: NCBI Gene / MyGene.info"));
@@ -137,12 +144,14 @@ fn markdown_detail_outputs_label_gene_drug_and_disease_sources() {
             description: Some("May reduce ivacaftor exposure.".to_string()),
         }],
         interaction_text: None,
+        supplement_interactions: Vec::new(),
         pharm_classes: Vec::new(),
         top_adverse_events: vec!["Cough".to_string()],
         faers_query: None,
         label: None,
         label_set_id: None,
         shortage: Some(Vec::new()),
+        shortage_trials: None,
         approvals: Some(Vec::new()),
         us_safety_warnings: None,
         ema_regulatory: None,
@@ -150,6 +159,8 @@ fn markdown_detail_outputs_label_gene_drug_and_disease_sources() {
         ema_shortage: None,
         who_prequalification: None,
         civic: None,
+        patents: None,
+        ncit: None,
     };
     let drug_markdown = drug_markdown(&drug, &["all".to_string()]).expect("drug markdown");
     assert!(drug_markdown.contains("Type (MyChem.info): small molecule"));
@@ -188,6 +199,10 @@ fn markdown_detail_outputs_label_gene_drug_and_disease_sources() {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         civic: None,
         disgenet: None,
         funding: None,
@@ -222,6 +237,7 @@ fn markdown_detail_outputs_label_article_trial_and_pathway_sources() {
         abstract_text: Some("Abstract text.".to_string()),
         full_text_path: None,
         full_text_note: Some("Saved full text unavailable.".to_string()),
+        full_text_source: None,
         annotations: Some(ArticleAnnotations {
             genes: vec![AnnotationCount {
                 text: "CFTR".to_string(),
@@ -230,6 +246,7 @@ fn markdown_detail_outputs_label_article_trial_and_pathway_sources() {
             diseases: Vec::new(),
             chemicals: Vec::new(),
             mutations: Vec::new(),
+            trials: Vec::new(),
         }),
         semantic_scholar: Some(crate::entities::article::ArticleSemanticScholar {
             paper_id: Some("paper-1".to_string()),
@@ -240,6 +257,7 @@ fn markdown_detail_outputs_label_article_trial_and_pathway_sources() {
             is_open_access: Some(true),
             open_access_pdf: None,
         }),
+        citation_graph: None,
         pubtator_fallback: false,
     };
     let article_markdown = article_markdown(&article, &["all".to_string()]).expect("article");
@@ -294,6 +312,8 @@ fn markdown_detail_outputs_label_article_trial_and_pathway_sources() {
             citation: "Example citation".to_string(),
             reference_type: Some("background".to_string()),
         }]),
+        publications: None,
+        results: None,
     };
     let trial_markdown = trial_markdown(&trial, &["all".to_string()]).expect("trial");
     assert!(trial_markdown.contains("Source: ClinicalTrials.gov"));
@@ -58,52 +58,8 @@ pub(super) fn format_sections_block(entity: &str, id: &str, sections: Vec<String
 }
 
 pub(super) fn section_description(entity: &str, section: &str) -> &'static str {
-    match (entity, section) {
-        ("gene", "pathways") => "Reactome/KEGG pathway context",
-        ("gene", "ontology") => "GO-style functional enrichment",
-        ("gene", "diseases") => "disease associations",
-        ("gene", "protein") => "UniProt function and localization detail",
-        ("gene", "expression") => "GTEx tissue expression",
-        ("gene", "hpa") => "Human Protein Atlas tissue expression and localization",
-        ("gene", "go") => "QuickGO term annotations",
-        ("gene", "interactions") => "STRING interaction partners",
-        ("gene", "civic") => "CIViC clinical evidence",
-        ("gene", "druggability") => "DGIdb interactions and tractability",
-        ("gene", "clingen") => "ClinGen validity and dosage sensitivity",
-        ("gene", "constraint") => "gnomAD gene constraint metrics",
-        ("gene", "disgenet") => "DisGeNET scored disease links",
-        ("gene", "funding") => "NIH Reporter grant support",
-        ("article", "annotations") => "PubTator normalized entity mentions",
-        ("article", "fulltext") => "cached full text when available",
-        ("article", "tldr") => "Semantic Scholar summary and influence",
-        ("disease", "genes") => "associated genes",
-        ("disease", "pathways") => "pathways from associated genes",
-        ("disease", "phenotypes") => "HPO phenotype annotations",
-        ("disease", "variants") => "disease-associated variants",
-        ("disease", "models") => "model-organism evidence",
-        ("disease", "prevalence") => "prevalence and epidemiology context",
-        ("disease", "funding") => "NIH Reporter grant support",
-        ("disease", "civic") => "CIViC disease-context evidence",
-        ("disease", "disgenet") => "DisGeNET scored disease-gene links",
-        ("drug", "label") => "approved-indication and FDA label detail beyond the base card",
-        ("drug", "regulatory") => {
-            "approval and supplement history; use only if the base card lacks approval context"
-        }
-        ("drug", "safety") => {
-            "regulatory safety detail; use `biomcp drug adverse-events <name>` first when you want post-marketing signal"
-        }
-        ("drug", "targets") => "ChEMBL and OpenTargets targets",
-        ("drug", "indications") => "OpenTargets indication evidence",
-        ("drug", "interactions") => "label interactions and public-data fallback",
-        ("drug", "civic") => "CIViC therapy evidence",
-        ("drug", "approvals") => "Drugs@FDA approval history",
-        ("trial", "eligibility") => "inclusion and exclusion criteria",
-        ("trial", "locations") => "site list and contact details",
-        ("trial", "outcomes") => "endpoint measures and time frames",
-        ("trial", "arms") => "study arms and assigned interventions",
-        ("trial", "references") => "linked publications and PMID citations",
-        _ => "additional detail",
-    }
+    crate::entities::section_manifest::section_description(entity, section)
+        .unwrap_or("additional detail")
 }
 
 pub(super) fn sections_for(requested: &[String], available: &[&str]) -> Vec<String> {
@@ -128,7 +84,8 @@ pub(super) fn sections_for(requested: &[String], available: &[&str]) -> Vec<Stri
 
 pub(super) fn sections_gene(gene: &Gene, requested: &[String]) -> Vec<String> {
     let symbol = gene.symbol.trim();
-    if symbol.is_empty() {
+    // Non-human cards list their sections under See also with `--species`.
+    if symbol.is_empty() || gene.species.is_some() {
         return Vec::new();
     }
 
@@ -161,6 +118,8 @@ const COMPLETED_TRIAL_SECTION_NAMES: &[&str] = &[
     "outcomes",
     "references",
     "arms",
+    "publications",
+    "results",
     "eligibility",
     "locations",
     "all",
@@ -207,6 +166,20 @@ pub(super) fn sections_pgx(pgx: &Pgx, requested: &[String]) -> Vec<String> {
     sections_for(requested, crate::entities::pgx::PGX_SECTION_NAMES)
 }
 
+pub(super) fn sections_hla(hla: &Hla, requested: &[String]) -> Vec<String> {
+    if hla.allele.trim().is_empty() {
+        return Vec::new();
+    }
+    sections_for(requested, crate::entities::hla::HLA_SECTION_NAMES)
+}
+
+pub(super) fn sections_study(study: &Study, requested: &[String]) -> Vec<String> {
+    if study.study_id.trim().is_empty() {
+        return Vec::new();
+    }
+    sections_for(requested, crate::entities::study::STUDY_SECTION_NAMES)
+}
+
 pub(super) fn sections_pathway(pathway: &Pathway, requested: &[String]) -> Vec<String> {
     let id = quote_arg(&pathway.id);
     if id.is_empty() {
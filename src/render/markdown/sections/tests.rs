@@ -133,6 +133,8 @@ fn format_sections_block_describes_guardrailed_drug_and_trial_sections() {
         outcomes: None,
         arms: None,
         references: None,
+        publications: None,
+        results: None,
     };
     let terminated_sections = sections_trial(&terminated, &[]);
     assert_eq!(terminated_sections[0], "outcomes");
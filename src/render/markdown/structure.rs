@@ -0,0 +1,44 @@
+//! PDB structure markdown renderers.
+
+use super::*;
+
+#[cfg(test)]
+mod tests;
+
+pub fn structure_markdown(structure: &Structure) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("structure.md.j2")?;
+    let body = tmpl.render(context! {
+        pdb_id => &structure.pdb_id,
+        title => &structure.title,
+        method => &structure.method,
+        resolution_angstrom => &structure.resolution_angstrom,
+        molecular_weight_kda => &structure.molecular_weight_kda,
+        deposited => &structure.deposited,
+        released => &structure.released,
+        citation => &structure.citation,
+        chains => &structure.chains,
+        ligands => &structure.ligands,
+        related_block => format_related_block(related_structure(structure)),
+    })?;
+    Ok(append_evidence_urls(
+        body,
+        structure_evidence_urls(structure),
+    ))
+}
+
+pub fn structure_search_markdown_with_footer(
+    query: &str,
+    results: &[StructureSearchResult],
+    total: Option<usize>,
+    pagination_footer: &str,
+) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("structure_search.md.j2")?;
+    let body = tmpl.render(context! {
+        query => query,
+        count => results.len(),
+        total => total,
+        results => results,
+        pagination_footer => pagination_footer,
+    })?;
+    Ok(with_pagination_footer(body, pagination_footer))
+}
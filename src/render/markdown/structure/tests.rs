@@ -0,0 +1,92 @@
+use super::*;
+use crate::entities::structure::{StructureChain, StructureCitation, StructureLigand};
+
+fn spike_structure() -> Structure {
+    Structure {
+        pdb_id: "6VSB".to_string(),
+        title: Some("Prefusion 2019-nCoV spike glycoprotein".to_string()),
+        method: Some("ELECTRON MICROSCOPY".to_string()),
+        resolution_angstrom: Some(3.46),
+        molecular_weight_kda: Some(438.53),
+        deposited: Some("2020-02-10".to_string()),
+        released: Some("2020-02-26".to_string()),
+        citation: Some(StructureCitation {
+            title: Some("Cryo-EM structure of the 2019-nCoV spike.".to_string()),
+            journal: Some("Science".to_string()),
+            year: Some(2020),
+            pmid: Some("32075877".to_string()),
+            doi: Some("10.1126/science.abb2507".to_string()),
+        }),
+        chains: vec![StructureChain {
+            entity_id: "1".to_string(),
+            description: Some("Spike glycoprotein".to_string()),
+            polymer_type: Some("Protein".to_string()),
+            chain_ids: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            length: Some(1288),
+            organism: Some("Severe acute respiratory syndrome coronavirus 2".to_string()),
+            uniprot_ids: vec!["P0DTC2".to_string()],
+        }],
+        ligands: vec![StructureLigand {
+            id: "NAG".to_string(),
+            name: Some("2-acetamido-2-deoxy-beta-D-glucopyranose".to_string()),
+            formula: Some("C8 H15 N O6".to_string()),
+            chain_ids: vec!["D".to_string()],
+        }],
+    }
+}
+
+#[test]
+fn structure_markdown_renders_summary_chains_ligands_and_links() {
+    let markdown = structure_markdown(&spike_structure()).expect("rendered markdown");
+
+    assert!(markdown.contains("# Structure: 6VSB"));
+    assert!(markdown.contains("Method: ELECTRON MICROSCOPY"));
+    assert!(markdown.contains("Resolution: 3.46 Å"));
+    assert!(markdown.contains("PMID 32075877"));
+    assert!(markdown.contains(
+        "| 1 | A, B, C | Spike glycoprotein | Protein | 1288 | Severe acute respiratory syndrome coronavirus 2 | P0DTC2 |"
+    ));
+    assert!(
+        markdown.contains("| NAG | 2-acetamido-2-deoxy-beta-D-glucopyranose | C8 H15 N O6 | D |")
+    );
+    assert!(markdown.contains("biomcp get protein P0DTC2"));
+    assert!(markdown.contains("biomcp search structure --uniprot P0DTC2"));
+    assert!(markdown.contains("biomcp get article 32075877"));
+    assert!(markdown.contains("[RCSB PDB](https://www.rcsb.org/structure/6VSB)"));
+    assert!(markdown.contains("[PDBe](https://www.ebi.ac.uk/pdbe/entry/pdb/6vsb)"));
+}
+
+#[test]
+fn structure_markdown_marks_missing_resolution_and_ligands() {
+    let mut structure = spike_structure();
+    structure.method = Some("SOLUTION NMR".to_string());
+    structure.resolution_angstrom = None;
+    structure.ligands.clear();
+    structure.citation = None;
+
+    let markdown = structure_markdown(&structure).expect("rendered markdown");
+    assert!(markdown.contains("Resolution: -"));
+    assert!(markdown.contains("No bound ligands reported for this entry."));
+    assert!(!markdown.contains("biomcp get article"));
+    assert!(!markdown.contains("[PubMed]"));
+}
+
+#[test]
+fn structure_search_markdown_reports_total_and_rows() {
+    let results = vec![StructureSearchResult {
+        pdb_id: "6UUO".to_string(),
+        title: Some("BRAF in complex with MEK1".to_string()),
+        method: Some("X-RAY DIFFRACTION".to_string()),
+        resolution_angstrom: Some(2.9),
+        released: Some("2020-01-15".to_string()),
+    }];
+    let markdown = structure_search_markdown_with_footer("uniprot=P15056", &results, Some(212), "")
+        .expect("rendered markdown");
+    assert!(markdown.contains("# Structures: uniprot=P15056"));
+    assert!(markdown.contains("Found 1 entry of 212, best resolution first"));
+    assert!(
+        markdown.contains(
+            "| 6UUO | BRAF in complex with MEK1 | X-RAY DIFFRACTION | 2.9 Å | 2020-01-15 |"
+        )
+    );
+}
@@ -34,6 +34,68 @@ pub fn study_list_markdown(studies: &[StudyInfo]) -> String {
     out
 }
 
+pub fn study_markdown(study: &Study, requested_sections: &[String]) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("study.md.j2")?;
+    let section_only = is_section_only_requested(requested_sections);
+    let include_all = has_all_section(requested_sections);
+    let requested = requested_section_names(requested_sections);
+    let mutated_genes = study
+        .mutated_genes
+        .iter()
+        .map(|row| {
+            context! {
+                gene => &row.gene,
+                mutated_samples => row.mutated_samples,
+                profiled_samples => row.profiled_samples,
+                mutation_count => row.mutation_count,
+                frequency => row
+                    .frequency
+                    .map(|value| format!("{:.1}%", value * 100.0))
+                    .unwrap_or_else(|| "-".to_string()),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let body = tmpl.render(context! {
+        section_only => section_only,
+        section_header => section_header(&study.study_id, requested_sections),
+        study_id => &study.study_id,
+        name => &study.name,
+        description => &study.description,
+        cancer_type => &study.cancer_type,
+        cancer_type_id => &study.cancer_type_id,
+        sample_count => study.sample_count,
+        sequenced_sample_count => study.sequenced_sample_count,
+        cna_sample_count => study.cna_sample_count,
+        citation => &study.citation,
+        pmid => &study.pmid,
+        reference_genome => &study.reference_genome,
+        mutated_genes => mutated_genes,
+        mutated_genes_note => &study.mutated_genes_note,
+        show_genes_section => include_all || requested.iter().any(|s| s == "genes"),
+        sections_block => format_sections_block("study", &study.study_id, sections_study(study, requested_sections)),
+        related_block => format_related_block(related_study(study)),
+    })?;
+    Ok(append_evidence_urls(body, study_evidence_urls(study)))
+}
+
+pub fn study_search_markdown_with_footer(
+    query: &str,
+    results: &[StudySearchResult],
+    total: Option<usize>,
+    pagination_footer: &str,
+) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("study_search.md.j2")?;
+    let body = tmpl.render(context! {
+        query => query,
+        count => results.len(),
+        total => total,
+        results => results,
+        pagination_footer => pagination_footer,
+    })?;
+    Ok(with_pagination_footer(body, pagination_footer))
+}
+
 fn format_optional_stat(value: Option<f64>, decimals: usize) -> String {
     value
         .map(|value| format!("{value:.prec$}", prec = decimals))
@@ -402,3 +402,64 @@ fn study_compare_mutations_markdown_renders_rate_table() {
     assert!(markdown.contains("| Group | N | Mutated | Mutation Rate |"));
     assert!(markdown.contains("| TP53-mutant | 348 | 120 | 0.344828 |"));
 }
+
+fn luad_study() -> Study {
+    Study {
+        study_id: "luad_tcga".to_string(),
+        name: "Lung Adenocarcinoma (TCGA, Firehose Legacy)".to_string(),
+        description: Some("TCGA Lung Adenocarcinoma.".to_string()),
+        cancer_type: Some("Lung Adenocarcinoma".to_string()),
+        cancer_type_id: Some("luad".to_string()),
+        sample_count: Some(586),
+        sequenced_sample_count: Some(230),
+        cna_sample_count: Some(516),
+        citation: Some("TCGA, Nature 2014".to_string()),
+        pmid: Some("25079552".to_string()),
+        reference_genome: Some("hg19".to_string()),
+        mutated_genes: vec![crate::entities::study::StudyMutatedGene {
+            gene: "TP53".to_string(),
+            mutated_samples: 115,
+            mutation_count: Some(120),
+            profiled_samples: Some(230),
+            frequency: Some(0.5),
+        }],
+        mutated_genes_note: None,
+    }
+}
+
+#[test]
+fn study_markdown_renders_card_and_genes_section() {
+    let study = luad_study();
+
+    let card = study_markdown(&study, &[]).expect("study card");
+    assert!(card.contains("# Study: Lung Adenocarcinoma (TCGA, Firehose Legacy)"));
+    assert!(card.contains("Cancer Type: Lung Adenocarcinoma (luad)"));
+    assert!(card.contains("Samples: 586 (230 sequenced, 516 with CNA)"));
+    assert!(card.contains("Citation: TCGA, Nature 2014 (PMID 25079552)"));
+    assert!(!card.contains("## Mutated Genes"));
+    assert!(card.contains("https://www.cbioportal.org/study/summary?id=luad_tcga"));
+
+    let genes = study_markdown(&study, &["genes".to_string()]).expect("genes section");
+    assert!(genes.contains("# luad_tcga - genes"));
+    assert!(genes.contains("| TP53 | 115 | 230 | 50.0% | 120 |"));
+    assert!(genes.contains("biomcp get gene TP53"));
+}
+
+#[test]
+fn study_search_markdown_renders_rows_and_total() {
+    let rows = vec![crate::entities::study::StudySearchResult {
+        study_id: "luad_tcga".to_string(),
+        name: "Lung Adenocarcinoma (TCGA, Firehose Legacy)".to_string(),
+        cancer_type: Some("Lung Adenocarcinoma".to_string()),
+        sample_count: Some(586),
+        pmid: None,
+    }];
+
+    let markdown = study_search_markdown_with_footer("lung", &rows, Some(12), "")
+        .expect("study search markdown");
+    assert!(markdown.contains("# Studies: lung"));
+    assert!(markdown.contains("Found 1 study (of 12 total)"));
+    assert!(markdown.contains(
+        "| luad_tcga | Lung Adenocarcinoma (TCGA, Firehose Legacy) | Lung Adenocarcinoma | 586 | - |"
+    ));
+}
@@ -154,6 +154,80 @@ pub(super) fn markdown_cell(value: &str) -> String {
     }
 }
 
+#[derive(serde::Serialize)]
+pub(super) struct PluginSectionRow {
+    key: String,
+    value: String,
+}
+
+#[derive(serde::Serialize)]
+pub(super) struct PluginSectionView {
+    section: String,
+    source: String,
+    rows: Vec<PluginSectionRow>,
+}
+
+const PLUGIN_VALUE_MAX_CHARS: usize = 200;
+
+fn plugin_value_text(value: &serde_json::Value) -> String {
+    let text = match value {
+        serde_json::Value::Null => "-".to_string(),
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Array(items)
+            if items
+                .iter()
+                .all(|item| !item.is_array() && !item.is_object()) =>
+        {
+            items
+                .iter()
+                .map(plugin_value_text)
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+        other => other.to_string(),
+    };
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() > PLUGIN_VALUE_MAX_CHARS {
+        let cut: String = text.chars().take(PLUGIN_VALUE_MAX_CHARS).collect();
+        format!("{cut}...")
+    } else {
+        text
+    }
+}
+
+/// Flattens plugin payloads into `key: value` rows: object fields by key,
+/// list items by position, and a lone scalar as `value`.
+pub(super) fn plugin_section_views(sections: &[PluginSection]) -> Vec<PluginSectionView> {
+    sections
+        .iter()
+        .map(|section| {
+            let rows = match &section.data {
+                serde_json::Value::Object(map) => map
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value))
+                    .collect::<Vec<_>>(),
+                serde_json::Value::Array(items) => items
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, value)| ((idx + 1).to_string(), value))
+                    .collect(),
+                other => vec![("value".to_string(), other)],
+            };
+            PluginSectionView {
+                section: section.section.clone(),
+                source: section.source.clone(),
+                rows: rows
+                    .into_iter()
+                    .map(|(key, value)| PluginSectionRow {
+                        key,
+                        value: plugin_value_text(value),
+                    })
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
 pub(super) fn dedupe_markdown_commands(values: Vec<String>) -> Vec<String> {
     let mut seen = HashSet::new();
     let mut out = Vec::new();
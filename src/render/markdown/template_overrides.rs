@@ -0,0 +1,81 @@
+//! User overrides for the bundled markdown templates.
+//!
+//! Files in `<config_dir>/biomcp/templates/` (or `BIOMCP_TEMPLATE_DIR`) whose
+//! names match a bundled template, e.g. `gene.md.j2`, replace it when the
+//! render environment is first built. Unknown names and templates that fail to
+//! compile are skipped with a warning so rendering falls back to the bundled copy.
+
+use std::path::{Path, PathBuf};
+
+use minijinja::Environment;
+use tracing::warn;
+
+const TEMPLATE_DIR_ENV: &str = "BIOMCP_TEMPLATE_DIR";
+const TEMPLATE_SUFFIX: &str = ".md.j2";
+
+pub(super) fn template_override_dir() -> Option<PathBuf> {
+    match std::env::var(TEMPLATE_DIR_ENV)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+    {
+        Some(path) => Some(PathBuf::from(path)),
+        None => crate::config::config_dir().map(|dir| dir.join("templates")),
+    }
+}
+
+/// Replaces bundled templates with same-named files from `dir`.
+///
+/// Returns the names of the templates that were overridden.
+pub(super) fn apply_template_overrides(env: &mut Environment<'static>, dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut paths = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut applied = Vec::new();
+    for path in paths {
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !name.ends_with(TEMPLATE_SUFFIX) {
+            continue;
+        }
+        if env.get_template(name).is_err() {
+            warn!(
+                template = %path.display(),
+                "ignoring template override: no bundled template named {name}"
+            );
+            continue;
+        }
+        let source = match std::fs::read_to_string(&path) {
+            Ok(source) => source,
+            Err(err) => {
+                warn!(template = %path.display(), "failed to read template override: {err}");
+                continue;
+            }
+        };
+        let name = name.to_string();
+        // The environment lives for the whole process, so the override source
+        // is leaked once to satisfy minijinja's borrowed template storage.
+        let name_ref: &'static str = Box::leak(name.clone().into_boxed_str());
+        let source_ref: &'static str = Box::leak(source.into_boxed_str());
+        match env.add_template(name_ref, source_ref) {
+            Ok(()) => applied.push(name),
+            Err(err) => warn!(
+                template = %path.display(),
+                "template override failed to compile; using bundled template: {err}"
+            ),
+        }
+    }
+    applied
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,90 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use minijinja::{Environment, context};
+
+use super::apply_template_overrides;
+
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(label: &str) -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock after epoch")
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "biomcp-template-overrides-{label}-{}-{nanos}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&path).expect("create temp dir");
+        Self(path)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn bundled_env() -> Environment<'static> {
+    let mut env = Environment::new();
+    env.add_template("gene.md.j2", "# Gene: {{ symbol }}")
+        .expect("bundled template");
+    env.add_template("trial.md.j2", "# Trial: {{ id }}")
+        .expect("bundled template");
+    env
+}
+
+#[test]
+fn overrides_replace_matching_bundled_templates() {
+    let dir = TempDir::new("replace");
+    std::fs::write(
+        dir.0.join("gene.md.j2"),
+        "## Institutional gene card: {{ symbol }}",
+    )
+    .expect("write override");
+
+    let mut env = bundled_env();
+    let applied = apply_template_overrides(&mut env, &dir.0);
+
+    assert_eq!(applied, vec!["gene.md.j2".to_string()]);
+    let rendered = env
+        .get_template("gene.md.j2")
+        .and_then(|tmpl| tmpl.render(context! { symbol => "BRAF" }))
+        .expect("render override");
+    assert_eq!(rendered, "## Institutional gene card: BRAF");
+    let untouched = env
+        .get_template("trial.md.j2")
+        .and_then(|tmpl| tmpl.render(context! { id => "NCT1" }))
+        .expect("render bundled");
+    assert_eq!(untouched, "# Trial: NCT1");
+}
+
+#[test]
+fn overrides_skip_unknown_names_and_broken_templates() {
+    let dir = TempDir::new("skip");
+    std::fs::write(dir.0.join("custom.md.j2"), "unused").expect("write unknown");
+    std::fs::write(dir.0.join("gene.md.j2"), "{% if %}").expect("write broken");
+    std::fs::write(dir.0.join("notes.txt"), "ignored").expect("write non-template");
+
+    let mut env = bundled_env();
+    let applied = apply_template_overrides(&mut env, &dir.0);
+
+    assert!(applied.is_empty());
+    let rendered = env
+        .get_template("gene.md.j2")
+        .and_then(|tmpl| tmpl.render(context! { symbol => "TP53" }))
+        .expect("bundled template survives broken override");
+    assert_eq!(rendered, "# Gene: TP53");
+    assert!(env.get_template("custom.md.j2").is_err());
+}
+
+#[test]
+fn missing_override_dir_is_a_no_op() {
+    let mut env = bundled_env();
+    let applied =
+        apply_template_overrides(&mut env, &std::env::temp_dir().join("biomcp-no-such-dir"));
+    assert!(applied.is_empty());
+}
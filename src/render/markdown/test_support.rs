@@ -17,6 +17,7 @@ pub(super) fn article_filters_for_test(sort: ArticleSort) -> ArticleSearchFilter
         open_access: false,
         no_preprints: true,
         exclude_retracted: true,
+        min_quartile: None,
         max_per_source: None,
         sort,
         ranking: ArticleRankingOptions::default(),
@@ -0,0 +1,260 @@
+//! Post-render markdown themes.
+//!
+//! Templates always render the `plain` layout. Other themes rewrite the
+//! rendered text so every entity gets the same treatment: `rich` adds a table
+//! of contents and heading anchors for browsing, and `agent-compact` flattens
+//! headings, turns tables into bullet rows, and drops emoji to save tokens.
+//! Fenced code blocks are never rewritten.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarkdownTheme {
+    #[default]
+    Plain,
+    Rich,
+    AgentCompact,
+}
+
+/// Minimum number of `##` sections before `rich` emits a table of contents.
+const RICH_TOC_MIN_SECTIONS: usize = 2;
+
+/// Deepest heading level kept by `agent-compact`; deeper headings are raised to it.
+const COMPACT_MAX_HEADING_LEVEL: usize = 2;
+
+pub fn apply_theme(markdown: &str, theme: MarkdownTheme) -> String {
+    match theme {
+        MarkdownTheme::Plain => markdown.to_string(),
+        MarkdownTheme::Rich => apply_rich(markdown),
+        MarkdownTheme::AgentCompact => apply_agent_compact(markdown),
+    }
+}
+
+pub(super) fn is_fence(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("```") || trimmed.starts_with("~~~")
+}
+
+pub(super) fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.bytes().take_while(|b| *b == b'#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.starts_with(' ') {
+        return None;
+    }
+    let text = rest.trim();
+    (!text.is_empty()).then_some((level, text))
+}
+
+/// GitHub-style heading slug: lowercase, punctuation dropped, spaces to hyphens.
+fn heading_slug(text: &str) -> String {
+    text.chars()
+        .filter_map(|ch| {
+            if ch.is_alphanumeric() || ch == '-' || ch == '_' {
+                Some(ch.to_lowercase().collect::<String>())
+            } else if ch == ' ' {
+                Some("-".to_string())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn unique_slug(text: &str, seen: &mut HashMap<String, usize>) -> String {
+    let base = heading_slug(text);
+    let count = seen.entry(base.clone()).or_insert(0);
+    let slug = if *count == 0 {
+        base
+    } else {
+        format!("{base}-{count}")
+    };
+    *count += 1;
+    slug
+}
+
+fn apply_rich(markdown: &str) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut seen = HashMap::new();
+    let mut anchors: Vec<Option<String>> = vec![None; lines.len()];
+    let mut toc = Vec::new();
+    let mut title_index = None;
+    let mut in_fence = false;
+
+    for (index, line) in lines.iter().enumerate() {
+        if is_fence(line) {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        let Some((level, text)) = heading(line) else {
+            continue;
+        };
+        let slug = unique_slug(text, &mut seen);
+        match level {
+            1 if title_index.is_none() => title_index = Some(index),
+            2 => toc.push(format!("- [{text}](#{slug})")),
+            _ => {}
+        }
+        anchors[index] = Some(slug);
+    }
+
+    let with_toc = toc.len() >= RICH_TOC_MIN_SECTIONS;
+    let mut out = Vec::with_capacity(lines.len() + toc.len() * 2 + 4);
+    if with_toc && title_index.is_none() {
+        out.push("**Contents**".to_string());
+        out.push(String::new());
+        out.extend(toc.iter().cloned());
+        out.push(String::new());
+    }
+    for (index, line) in lines.iter().enumerate() {
+        if let Some(slug) = &anchors[index] {
+            out.push(format!("<a id=\"{slug}\"></a>"));
+        }
+        out.push((*line).to_string());
+        if with_toc && title_index == Some(index) {
+            out.push(String::new());
+            out.push("**Contents**".to_string());
+            out.push(String::new());
+            out.extend(toc.iter().cloned());
+        }
+    }
+    finish(out, markdown)
+}
+
+fn is_emoji(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x1F000..=0x1FAFF | 0x2600..=0x27BF | 0x2B00..=0x2BFF | 0xFE0F | 0x200D
+    )
+}
+
+fn strip_emoji(line: &str) -> String {
+    if !line.chars().any(is_emoji) {
+        return line.to_string();
+    }
+    let stripped: String = line.chars().filter(|ch| !is_emoji(*ch)).collect();
+    // Collapse the double space left where an emoji sat between words.
+    let mut out = String::with_capacity(stripped.len());
+    let mut previous_space = false;
+    for ch in stripped.chars() {
+        if ch == ' ' && previous_space && !out.trim().is_empty() {
+            continue;
+        }
+        previous_space = ch == ' ';
+        out.push(ch);
+    }
+    out
+}
+
+fn table_cells(line: &str) -> Option<Vec<String>> {
+    let trimmed = line.trim();
+    if !trimmed.starts_with('|') {
+        return None;
+    }
+    let inner = trimmed.trim_start_matches('|');
+    let inner = inner.strip_suffix('|').unwrap_or(inner);
+    Some(
+        inner
+            .split('|')
+            .map(|cell| cell.trim().to_string())
+            .collect(),
+    )
+}
+
+fn is_separator_row(cells: &[String]) -> bool {
+    !cells.is_empty()
+        && cells
+            .iter()
+            .all(|cell| !cell.is_empty() && cell.chars().all(|ch| matches!(ch, '-' | ':' | ' ')))
+}
+
+fn compact_table(rows: &[Vec<String>], out: &mut Vec<String>) {
+    let (header, body) = match rows {
+        [header, separator, body @ ..] if is_separator_row(separator) => (Some(header), body),
+        _ => (None, rows),
+    };
+    if let Some(header) = header {
+        out.push(format!("Columns: {}", header.join(" · ")));
+    }
+    for row in body {
+        if is_separator_row(row) {
+            continue;
+        }
+        out.push(format!("- {}", row.join(" · ")));
+    }
+}
+
+fn is_horizontal_rule(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.len() >= 3
+        && (trimmed.chars().all(|ch| ch == '-') || trimmed.chars().all(|ch| ch == '*'))
+}
+
+fn apply_agent_compact(markdown: &str) -> String {
+    let mut out: Vec<String> = Vec::new();
+    let mut table: Vec<Vec<String>> = Vec::new();
+    let mut in_fence = false;
+
+    for line in markdown.lines() {
+        if is_fence(line) {
+            in_fence = !in_fence;
+        }
+        if in_fence || is_fence(line) {
+            if !table.is_empty() {
+                compact_table(&table, &mut out);
+                table.clear();
+            }
+            out.push(line.to_string());
+            continue;
+        }
+
+        let line = strip_emoji(line.trim_end());
+        if let Some(cells) = table_cells(&line) {
+            table.push(cells);
+            continue;
+        }
+        if !table.is_empty() {
+            compact_table(&table, &mut out);
+            table.clear();
+        }
+
+        if is_horizontal_rule(&line) {
+            continue;
+        }
+        if line.trim().is_empty() {
+            if out.last().is_some_and(|last| !last.is_empty()) {
+                out.push(String::new());
+            }
+            continue;
+        }
+        match heading(&line) {
+            Some((level, text)) if level > COMPACT_MAX_HEADING_LEVEL => {
+                out.push(format!("{} {text}", "#".repeat(COMPACT_MAX_HEADING_LEVEL)));
+            }
+            _ => out.push(line),
+        }
+    }
+    if !table.is_empty() {
+        compact_table(&table, &mut out);
+    }
+    while out.last().is_some_and(String::is_empty) {
+        out.pop();
+    }
+    finish(out, markdown)
+}
+
+fn finish(lines: Vec<String>, original: &str) -> String {
+    let mut text = lines.join("\n");
+    if original.ends_with('\n') {
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,53 @@
+use super::{MarkdownTheme, apply_theme};
+
+const SAMPLE: &str = "# BRAF\n\n## Summary\n\nSerine/threonine kinase.\n\n### Aliases\n\n- B-RAF1\n\n## Variants\n\n| Variant | Significance |\n|---|---|\n| V600E | Pathogenic |\n| V600K | Pathogenic |\n\n---\n\n```bash\n## not a heading\n| not | a table |\n```\n";
+
+#[test]
+fn plain_theme_leaves_markdown_unchanged() {
+    assert_eq!(apply_theme(SAMPLE, MarkdownTheme::Plain), SAMPLE);
+}
+
+#[test]
+fn rich_theme_adds_toc_and_anchors() {
+    let out = apply_theme(SAMPLE, MarkdownTheme::Rich);
+
+    assert!(out.starts_with("<a id=\"braf\"></a>\n# BRAF\n\n**Contents**\n"));
+    assert!(out.contains("- [Summary](#summary)\n- [Variants](#variants)\n"));
+    assert!(out.contains("<a id=\"summary\"></a>\n## Summary"));
+    assert!(out.contains("<a id=\"aliases\"></a>\n### Aliases"));
+    assert!(!out.contains("#not-a-heading"));
+    assert!(out.ends_with("```\n"));
+}
+
+#[test]
+fn rich_theme_dedupes_repeated_heading_slugs() {
+    let out = apply_theme("## Notes\n\n## Notes\n", MarkdownTheme::Rich);
+
+    assert!(out.contains("- [Notes](#notes)\n- [Notes](#notes-1)"));
+    assert!(out.contains("<a id=\"notes-1\"></a>\n## Notes"));
+}
+
+#[test]
+fn agent_compact_theme_flattens_headings_and_tables() {
+    let out = apply_theme(SAMPLE, MarkdownTheme::AgentCompact);
+
+    assert!(out.contains("## Aliases"));
+    assert!(!out.contains("### Aliases"));
+    assert!(
+        out.contains("Columns: Variant · Significance\n- V600E · Pathogenic\n- V600K · Pathogenic")
+    );
+    assert!(!out.contains("|---|"));
+    assert!(!out.contains("\n---\n"));
+    assert!(!out.contains("\n\n\n"));
+    assert!(out.contains("```bash\n## not a heading\n| not | a table |\n```"));
+}
+
+#[test]
+fn agent_compact_theme_strips_emoji() {
+    let out = apply_theme(
+        "## ✅ Approved\n\n- 🧬 Gene: BRAF\n",
+        MarkdownTheme::AgentCompact,
+    );
+
+    assert_eq!(out, "## Approved\n\n- Gene: BRAF\n");
+}
@@ -0,0 +1,41 @@
+//! Ensembl transcript markdown renderer.
+
+use super::*;
+
+/// Exon rows shown before the table is cut; JSON keeps them all.
+const MAX_MARKDOWN_EXONS: usize = 50;
+
+#[cfg(test)]
+mod tests;
+
+pub fn transcript_markdown(transcript: &Transcript) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("transcript.md.j2")?;
+    let shown_exons = &transcript.exons[..transcript.exons.len().min(MAX_MARKDOWN_EXONS)];
+    let body = tmpl.render(context! {
+        id => &transcript.id,
+        version => &transcript.version,
+        name => &transcript.name,
+        gene_id => &transcript.gene_id,
+        gene_symbol => &transcript.gene_symbol,
+        biotype => &transcript.biotype,
+        canonical => transcript.canonical,
+        mane_select => &transcript.mane_select,
+        mane_plus_clinical => &transcript.mane_plus_clinical,
+        assembly => &transcript.assembly,
+        chromosome => &transcript.chromosome,
+        start => &transcript.start,
+        end => &transcript.end,
+        strand => &transcript.strand,
+        length_bp => &transcript.length_bp,
+        exon_count => transcript.exons.len(),
+        exons => shown_exons,
+        exons_omitted => transcript.exons.len() - shown_exons.len(),
+        protein => &transcript.protein,
+        xrefs => &transcript.xrefs,
+        related_block => format_related_block(related_transcript(transcript)),
+    })?;
+    Ok(append_evidence_urls(
+        body,
+        transcript_evidence_urls(transcript),
+    ))
+}
@@ -0,0 +1,99 @@
+use super::*;
+use crate::entities::transcript::{TranscriptExon, TranscriptProtein, TranscriptXref};
+
+fn braf_transcript() -> Transcript {
+    Transcript {
+        id: "ENST00000288602".to_string(),
+        version: Some(11),
+        name: Some("BRAF-201".to_string()),
+        gene_id: Some("ENSG00000157764".to_string()),
+        gene_symbol: Some("BRAF".to_string()),
+        biotype: Some("protein_coding".to_string()),
+        canonical: true,
+        mane_select: Some("NM_004333.6".to_string()),
+        mane_plus_clinical: None,
+        assembly: Some("GRCh38".to_string()),
+        chromosome: Some("7".to_string()),
+        start: Some(140719327),
+        end: Some(140924929),
+        strand: Some(-1),
+        length_bp: Some(467),
+        exons: vec![
+            TranscriptExon {
+                number: 1,
+                id: "ENSE00001903155".to_string(),
+                start: 140924566,
+                end: 140924929,
+                length: 364,
+            },
+            TranscriptExon {
+                number: 2,
+                id: "ENSE00003651637".to_string(),
+                start: 140850110,
+                end: 140850212,
+                length: 103,
+            },
+        ],
+        protein: Some(TranscriptProtein {
+            id: "ENSP00000288602.7".to_string(),
+            length_aa: Some(766),
+            cds_start: Some(140734597),
+            cds_end: Some(140924764),
+            uniprot: vec!["P15056".to_string()],
+        }),
+        xrefs: vec![TranscriptXref {
+            database: "RefSeq mRNA".to_string(),
+            id: "NM_004333.6".to_string(),
+        }],
+    }
+}
+
+#[test]
+fn transcript_markdown_renders_status_exons_protein_and_links() {
+    let markdown = transcript_markdown(&braf_transcript()).expect("rendered markdown");
+
+    assert!(markdown.contains("# Transcript: ENST00000288602.11 (BRAF-201)"));
+    assert!(markdown.contains("Gene: BRAF (ENSG00000157764)"));
+    assert!(markdown.contains("Ensembl Canonical: yes"));
+    assert!(markdown.contains("MANE Select: NM_004333.6"));
+    assert!(markdown.contains("Location: chr7:140719327-140924929 (-) GRCh38"));
+    assert!(markdown.contains("Length: 467 bp spliced, 2 exons"));
+    assert!(markdown.contains("Protein: ENSP00000288602.7 (766 aa)"));
+    assert!(markdown.contains("UniProt: P15056"));
+    assert!(markdown.contains("| 1 | ENSE00001903155 | 140924566 | 140924929 | 364 |"));
+    assert!(markdown.contains("| RefSeq mRNA | NM_004333.6 |"));
+    assert!(markdown.contains("biomcp get gene BRAF"));
+    assert!(markdown.contains("biomcp search variant -g BRAF"));
+    assert!(markdown.contains("biomcp get protein P15056"));
+    assert!(markdown.contains(
+        "[Ensembl](https://www.ensembl.org/Homo_sapiens/Transcript/Summary?t=ENST00000288602)"
+    ));
+    assert!(markdown.contains("[NCBI RefSeq](https://www.ncbi.nlm.nih.gov/nuccore/NM_004333.6)"));
+}
+
+#[test]
+fn transcript_markdown_handles_noncoding_transcripts_and_caps_exons() {
+    let mut transcript = braf_transcript();
+    transcript.canonical = false;
+    transcript.mane_select = None;
+    transcript.protein = None;
+    transcript.xrefs.clear();
+    transcript.exons = (1..=MAX_MARKDOWN_EXONS + 3)
+        .map(|number| TranscriptExon {
+            number,
+            id: format!("ENSE{number:011}"),
+            start: number as u64 * 100,
+            end: number as u64 * 100 + 9,
+            length: 10,
+        })
+        .collect();
+
+    let markdown = transcript_markdown(&transcript).expect("rendered markdown");
+    assert!(markdown.contains("Ensembl Canonical: no"));
+    assert!(!markdown.contains("MANE Select"));
+    assert!(markdown.contains("No protein product; this transcript is not translated."));
+    assert!(markdown.contains("3 more exons omitted; use --json for the full list."));
+    assert!(!markdown.contains(&format!("ENSE{:011}", MAX_MARKDOWN_EXONS + 1)));
+    assert!(!markdown.contains("biomcp get protein"));
+    assert!(!markdown.contains("[NCBI RefSeq]"));
+}
@@ -25,6 +25,12 @@ pub fn trial_markdown(trial: &Trial, requested_sections: &[String]) -> Result<St
         || requested
             .iter()
             .any(|s| s.eq_ignore_ascii_case("references"));
+    let show_publications_section = include_all
+        || requested
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case("publications"));
+    let show_results_section =
+        include_all || requested.iter().any(|s| s.eq_ignore_ascii_case("results"));
     let body = tmpl.render(context! {
         section_only => section_only,
         section_header => section_header(&trial.nct_id, requested_sections),
@@ -47,11 +53,15 @@ pub fn trial_markdown(trial: &Trial, requested_sections: &[String]) -> Result<St
         outcomes => &trial.outcomes,
         arms => &trial.arms,
         references => &trial.references,
+        publications => &trial.publications,
+        results => &trial.results,
         show_eligibility_section => show_eligibility_section,
         show_locations_section => show_locations_section,
         show_outcomes_section => show_outcomes_section,
         show_arms_section => show_arms_section,
         show_references_section => show_references_section,
+        show_publications_section => show_publications_section,
+        show_results_section => show_results_section,
         sections_block => format_sections_block("trial", &trial.nct_id, sections_trial(trial, requested_sections)),
         related_block => format_related_block(related_trial(trial)),
     })?;
@@ -75,8 +85,20 @@ pub fn trial_search_markdown_with_footer(
     nickname_query: Option<&str>,
 ) -> Result<String, BioMcpError> {
     let tmpl = env()?.get_template("trial_search.md.j2")?;
+    let registry = results.iter().find_map(|result| result.registry.as_deref());
+    let cross_registered_count = results
+        .iter()
+        .filter(|result| result.cross_registered_nct.is_some())
+        .count();
+    let unchecked = results
+        .iter()
+        .filter(|result| !result.eligibility_unchecked.is_empty())
+        .collect::<Vec<_>>();
     let body = tmpl.render(context! {
         query => query,
+        registry => registry,
+        cross_registered_count => cross_registered_count,
+        unchecked => unchecked,
         count => results.len(),
         total => total,
         results => results,
@@ -86,3 +108,60 @@ pub fn trial_search_markdown_with_footer(
     })?;
     Ok(with_pagination_footer(body, pagination_footer))
 }
+
+pub fn trial_summary_markdown(query: &str, summary: &TrialSummary) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("trial_summary.md.j2")?;
+    Ok(tmpl.render(context! {
+        query => query,
+        by => summary.summarize_by.as_str(),
+        total => summary.total,
+        trials_scanned => summary.trials_scanned,
+        truncated => summary.truncated,
+        group_count => summary.group_count,
+        phases => &summary.phases,
+        groups => &summary.groups,
+    })?)
+}
+
+fn match_profile_summary(matches: &TrialMatches) -> String {
+    let profile = &matches.profile;
+    let mut parts = Vec::new();
+    if let Some(age) = profile.age {
+        parts.push(format!("age {age}"));
+    }
+    parts.extend(profile.sex.clone());
+    if let Some(ecog) = profile.ecog {
+        parts.push(format!("ECOG {ecog}"));
+    }
+    if !profile.biomarkers.is_empty() {
+        parts.push(format!("biomarkers {}", profile.biomarkers.join(", ")));
+    }
+    if let Some(location) = profile.location.as_ref() {
+        let place = [&location.city, &location.state, &location.country]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(", ");
+        match (location.lat.zip(location.lon), location.distance) {
+            (Some((lat, lon)), Some(miles)) => {
+                parts.push(format!("within {miles} mi of {lat}, {lon}"))
+            }
+            (Some((lat, lon)), None) if place.is_empty() => {
+                parts.push(format!("near {lat}, {lon}"))
+            }
+            _ => parts.push(format!("near {place}")),
+        }
+    }
+    parts.join(" · ")
+}
+
+pub fn trial_match_markdown(matches: &TrialMatches) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("trial_match.md.j2")?;
+    Ok(tmpl.render(context! {
+        diagnosis => &matches.profile.diagnosis,
+        profile_summary => match_profile_summary(matches),
+        candidates_scanned => matches.candidates_scanned,
+        matches => &matches.matches,
+    })?)
+}
@@ -26,6 +26,64 @@ fn trial_search_markdown_with_footer_omits_zero_result_nickname_hint_without_fla
     assert!(!markdown.contains("ClinicalTrials.gov does not index trial nicknames."));
 }
 
+#[test]
+fn trial_search_markdown_renders_registry_rows_with_nct_cross_links() {
+    let row = |id: &str, nct: Option<&str>| crate::entities::trial::TrialSearchResult {
+        nct_id: id.to_string(),
+        title: "Adjuvant nivolumab in melanoma".to_string(),
+        status: "Ongoing".to_string(),
+        phase: Some("Phase III".to_string()),
+        conditions: vec!["Melanoma".to_string()],
+        sponsor: None,
+        registry: Some("CTIS".to_string()),
+        cross_registered_nct: nct.map(str::to_string),
+        eligibility_unchecked: Vec::new(),
+    };
+    let results = vec![
+        row("2023-503456-12-00", Some("NCT05123456")),
+        row("2024-512345-01-00", None),
+    ];
+
+    let markdown = trial_search_markdown("condition=melanoma, source=ctis", &results, Some(2))
+        .expect("markdown");
+
+    assert!(markdown.contains("|CTIS ID|Title|Status|Phase|Conditions|ClinicalTrials.gov|"));
+    assert!(markdown.contains("|2023-503456-12-00|"));
+    assert!(markdown.contains("|NCT05123456|"));
+    assert!(markdown.contains("1 of 2 also registered on ClinicalTrials.gov"));
+    assert!(!markdown.contains("|NCT ID|"));
+}
+
+#[test]
+fn trial_search_markdown_flags_unchecked_eligibility() {
+    let row = |id: &str, unchecked: &[&str]| crate::entities::trial::TrialSearchResult {
+        nct_id: id.to_string(),
+        title: "Pembrolizumab in NSCLC".to_string(),
+        status: "RECRUITING".to_string(),
+        phase: Some("PHASE2".to_string()),
+        conditions: vec!["NSCLC".to_string()],
+        sponsor: None,
+        registry: None,
+        cross_registered_nct: None,
+        eligibility_unchecked: unchecked.iter().map(|value| value.to_string()).collect(),
+    };
+    let results = vec![
+        row("NCT00000001", &[]),
+        row("NCT00000002", &["age", "ECOG"]),
+    ];
+
+    let markdown =
+        trial_search_markdown("condition=nsclc, ecog=1", &results, Some(2)).expect("markdown");
+    assert!(markdown.contains(
+        "Not machine-checked: NCT00000002 (age, ECOG). These trials were kept; review them with `get trial <nct_id> eligibility`."
+    ));
+    assert!(markdown.contains("--ecog <0-4>"));
+
+    let markdown =
+        trial_search_markdown("condition=nsclc", &results[..1], Some(1)).expect("markdown");
+    assert!(!markdown.contains("Not machine-checked"));
+}
+
 #[test]
 fn trial_markdown_includes_source_labeled_sections() {
     let trial = crate::entities::trial::Trial {
@@ -72,6 +130,8 @@ fn trial_markdown_includes_source_labeled_sections() {
             citation: "Example citation".to_string(),
             reference_type: Some("background".to_string()),
         }]),
+        publications: None,
+        results: None,
     };
 
     let markdown = trial_markdown(&trial, &["all".to_string()]).expect("trial");
@@ -85,3 +145,254 @@ fn trial_markdown_includes_source_labeled_sections() {
     assert!(markdown.contains("## Arms (ClinicalTrials.gov)"));
     assert!(markdown.contains("## References (ClinicalTrials.gov)"));
 }
+
+#[test]
+fn trial_markdown_renders_labeled_publications_table() {
+    let trial = crate::entities::trial::Trial {
+        nct_id: "NCT01844505".to_string(),
+        source: Some("ClinicalTrials.gov".to_string()),
+        title: "CheckMate 067".to_string(),
+        status: "Completed".to_string(),
+        phase: None,
+        study_type: None,
+        age_range: None,
+        conditions: Vec::new(),
+        interventions: Vec::new(),
+        sponsor: None,
+        enrollment: None,
+        summary: None,
+        start_date: None,
+        completion_date: None,
+        eligibility_text: None,
+        locations: None,
+        outcomes: None,
+        arms: None,
+        references: None,
+        publications: Some(vec![crate::entities::trial::TrialPublication {
+            pmid: Some("26027431".to_string()),
+            title: "Combined Nivolumab and Ipilimumab in Untreated Melanoma".to_string(),
+            journal: Some("N Engl J Med".to_string()),
+            date: Some("2015-05-31".to_string()),
+            kind: crate::entities::trial::TrialPublicationKind::Results,
+            reference_type: Some("RESULT".to_string()),
+            found_via: vec!["ClinicalTrials.gov".to_string(), "Europe PMC".to_string()],
+        }]),
+        results: None,
+    };
+
+    let markdown = trial_markdown(&trial, &["publications".to_string()]).expect("trial");
+    assert!(markdown.contains("## Publications (ClinicalTrials.gov + Europe PMC)"));
+    assert!(markdown.contains(
+        "| Results | 26027431 | Combined Nivolumab and Ipilimumab in Untreated Melanoma | N Engl J Med | 2015-05-31 | ClinicalTrials.gov, Europe PMC |"
+    ));
+    assert!(!markdown.contains("## References"));
+}
+
+#[test]
+fn trial_markdown_renders_results_tables() {
+    use crate::entities::trial::{
+        TrialAdverseEvent, TrialAdverseEventGroup, TrialAdverseEvents, TrialOutcomeAnalysis,
+        TrialOutcomeResult, TrialResultGroup, TrialResultRow, TrialResults,
+    };
+
+    let group = |id: &str, title: &str| TrialResultGroup {
+        id: id.to_string(),
+        title: title.to_string(),
+        description: None,
+    };
+    let mut trial = crate::entities::trial::Trial {
+        nct_id: "NCT01844505".to_string(),
+        source: Some("ClinicalTrials.gov".to_string()),
+        title: "CheckMate 067".to_string(),
+        status: "Completed".to_string(),
+        phase: None,
+        study_type: None,
+        age_range: None,
+        conditions: Vec::new(),
+        interventions: Vec::new(),
+        sponsor: None,
+        enrollment: None,
+        summary: None,
+        start_date: None,
+        completion_date: None,
+        eligibility_text: None,
+        locations: None,
+        outcomes: None,
+        arms: None,
+        references: None,
+        publications: None,
+        results: Some(TrialResults {
+            posted: true,
+            baseline: None,
+            outcome_measures: vec![TrialOutcomeResult {
+                outcome_type: "PRIMARY".to_string(),
+                title: "Progression-free Survival".to_string(),
+                description: None,
+                time_frame: Some("Up to 60 months".to_string()),
+                statistic: Some("Median (95% Confidence Interval), months".to_string()),
+                groups: vec![group("OG000", "Nivolumab"), group("OG001", "Ipilimumab")],
+                participants: vec!["316".to_string(), "315".to_string()],
+                rows: vec![TrialResultRow {
+                    label: String::new(),
+                    values: vec![
+                        "6.9 (5.1 to 9.7)".to_string(),
+                        "2.9 (2.8 to 3.2)".to_string(),
+                    ],
+                }],
+                analyses: vec![TrialOutcomeAnalysis {
+                    groups: vec!["Nivolumab".to_string(), "Ipilimumab".to_string()],
+                    estimate_type: Some("Hazard Ratio (HR)".to_string()),
+                    estimate: Some("0.53".to_string()),
+                    ci_pct: Some("95".to_string()),
+                    ci_lower: Some("0.44".to_string()),
+                    ci_upper: Some("0.64".to_string()),
+                    p_value: Some("<0.0001".to_string()),
+                    method: Some("Log Rank".to_string()),
+                }],
+            }],
+            adverse_events: Some(TrialAdverseEvents {
+                time_frame: None,
+                frequency_threshold: Some("5".to_string()),
+                groups: vec![TrialAdverseEventGroup {
+                    id: "EG000".to_string(),
+                    title: "Nivolumab".to_string(),
+                    deaths: Some("5/313".to_string()),
+                    serious: Some("142/313".to_string()),
+                    other: None,
+                }],
+                serious: vec![TrialAdverseEvent {
+                    term: "Colitis".to_string(),
+                    organ_system: Some("Gastrointestinal disorders".to_string()),
+                    values: vec!["9/313".to_string()],
+                }],
+                serious_omitted: 4,
+                other: Vec::new(),
+                other_omitted: 0,
+            }),
+        }),
+    };
+
+    let markdown = trial_markdown(&trial, &["results".to_string()]).expect("trial");
+    assert!(markdown.contains("## Results (ClinicalTrials.gov)"));
+    assert!(markdown.contains("### Primary outcome: Progression-free Survival"));
+    assert!(markdown.contains("| Result | Nivolumab | Ipilimumab |"));
+    assert!(markdown.contains("| Overall | 6.9 (5.1 to 9.7) | 2.9 (2.8 to 3.2) |"));
+    assert!(markdown.contains(
+        "- Nivolumab vs Ipilimumab: Hazard Ratio (HR) 0.53 (95% CI 0.44 to 0.64), p-value <0.0001 (Log Rank)"
+    ));
+    assert!(markdown.contains("| Deaths | 5/313 |"));
+    assert!(markdown.contains("| Colitis | Gastrointestinal disorders | 9/313 |"));
+    assert!(markdown.contains("_4 less frequent serious events not shown._"));
+    assert!(!markdown.contains("#### Other adverse events"));
+
+    trial.results = Some(TrialResults::default());
+    let markdown = trial_markdown(&trial, &["results".to_string()]).expect("trial");
+    assert!(markdown.contains("No results posted for this trial."));
+}
+
+#[test]
+fn trial_summary_markdown_renders_phase_columns_and_partial_note() {
+    let summary = TrialSummary {
+        summarize_by: crate::entities::trial::TrialSummaryBy::Condition,
+        total: Some(812),
+        trials_scanned: 500,
+        truncated: true,
+        group_count: 3,
+        phases: vec!["PHASE2".into(), "PHASE3".into()],
+        groups: vec![
+            crate::entities::trial::TrialSummaryGroup {
+                key: "Breast Cancer".into(),
+                trials: 4,
+                phases: [("PHASE2".to_string(), 1), ("PHASE3".to_string(), 3)]
+                    .into_iter()
+                    .collect(),
+            },
+            crate::entities::trial::TrialSummaryGroup {
+                key: "Lung Cancer".into(),
+                trials: 2,
+                phases: [("PHASE2".to_string(), 2)].into_iter().collect(),
+            },
+        ],
+    };
+
+    let markdown = trial_summary_markdown("sponsor=Genentech", &summary).expect("summary markdown");
+    assert!(
+        markdown.contains("Trials counted: 500 of 812 (first 500 scanned; counts are partial)")
+    );
+    assert!(markdown.contains("| Condition | Trials | PHASE2 | PHASE3 |"));
+    assert!(markdown.contains("| Breast Cancer | 4 | 1 | 3 |"));
+    assert!(markdown.contains("| Lung Cancer | 2 | 2 | 0 |"));
+    assert!(markdown.contains("Showing 2 of 3 condition groups"));
+}
+
+#[test]
+fn trial_match_markdown_lists_ranked_trials_with_criterion_explanations() {
+    use crate::entities::trial::{
+        PatientProfile, TrialMatch, TrialMatchCriterion, TrialMatchStatus, TrialMatches,
+    };
+
+    let criterion = |name: &str, value: &str, status, explanation: &str| TrialMatchCriterion {
+        criterion: name.into(),
+        value: value.into(),
+        status,
+        explanation: explanation.into(),
+    };
+    let matches = TrialMatches {
+        profile: PatientProfile::parse(
+            "age: 62\nsex: female\ndiagnosis: NSCLC\nbiomarkers: [EGFR L858R]\n\
+             location:\n  city: Boston\n  country: United States",
+        )
+        .expect("profile"),
+        candidates_scanned: 12,
+        matches: vec![TrialMatch {
+            nct_id: "NCT05000001".into(),
+            title: "Osimertinib Plus Savolitinib in EGFR-Mutant NSCLC".into(),
+            status: "RECRUITING".into(),
+            phase: Some("PHASE2".into()),
+            conditions: vec!["NSCLC".into()],
+            score: 93,
+            eligible: true,
+            criteria: vec![
+                criterion("age", "62", TrialMatchStatus::Met, "within 18-75 years"),
+                criterion(
+                    "biomarker",
+                    "EGFR L858R",
+                    TrialMatchStatus::Unknown,
+                    "not mentioned in the eligibility criteria",
+                ),
+                criterion(
+                    "location",
+                    "Boston, United States",
+                    TrialMatchStatus::NotMet,
+                    "no site in Boston, United States",
+                ),
+            ],
+        }],
+    };
+
+    let markdown = trial_match_markdown(&matches).expect("match markdown");
+    assert!(markdown.contains("# Trial Matches: NSCLC"));
+    assert!(
+        markdown.contains(
+            "Profile: age 62 · female · biomarkers EGFR L858R · near Boston, United States"
+        )
+    );
+    assert!(markdown.contains("Recruiting ClinicalTrials.gov trials checked: 12"));
+    assert!(markdown.contains("|1|NCT05000001|"));
+    assert!(markdown.contains("|93|yes|"));
+    assert!(markdown.contains("## 1. NCT05000001: Osimertinib Plus Savolitinib"));
+    assert!(markdown.contains("- Met: age 62 (within 18-75 years)"));
+    assert!(
+        markdown.contains(
+            "- Unknown: biomarker EGFR L858R (not mentioned in the eligibility criteria)"
+        )
+    );
+    assert!(markdown.contains("- Not met: location Boston, United States (no site in"));
+
+    let empty = TrialMatches {
+        matches: Vec::new(),
+        ..matches
+    };
+    let markdown = trial_match_markdown(&empty).expect("empty markdown");
+    assert!(markdown.contains("No recruiting trials found for this diagnosis."));
+}
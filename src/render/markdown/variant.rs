@@ -5,6 +5,27 @@ use super::*;
 #[cfg(test)]
 mod tests;
 
+/// Exome or genome counts of the gnomAD API result, labeled for the template.
+#[derive(serde::Serialize)]
+struct GnomadCallSetView<'a> {
+    label: &'static str,
+    #[serde(flatten)]
+    data: &'a crate::sources::gnomad::GnomadSequencingFrequencies,
+}
+
+fn gnomad_call_sets(variant: &Variant) -> Vec<GnomadCallSetView<'_>> {
+    let Some(gnomad) = variant.gnomad.as_ref() else {
+        return Vec::new();
+    };
+    [
+        ("Exome", gnomad.exome.as_ref()),
+        ("Genome", gnomad.genome.as_ref()),
+    ]
+    .into_iter()
+    .filter_map(|(label, data)| data.map(|data| GnomadCallSetView { label, data }))
+    .collect()
+}
+
 pub fn variant_markdown(
     variant: &Variant,
     requested_sections: &[String],
@@ -17,6 +38,7 @@ pub fn variant_markdown(
     let show_prediction_section = !section_only || include_all || has_requested("predict");
     let show_predictions_section = include_all || has_requested("predictions");
     let show_clinvar_section = !section_only || include_all || has_requested("clinvar");
+    let show_clinvar_submissions_section = include_all || has_requested("clinvar-submissions");
     let show_population_section = !section_only || include_all || has_requested("population");
     let show_conservation_section = include_all || has_requested("conservation");
     let show_cosmic_section = include_all || has_requested("cosmic");
@@ -24,6 +46,8 @@ pub fn variant_markdown(
     let show_civic_section = include_all || has_requested("civic");
     let show_cbioportal_section = include_all || has_requested("cbioportal");
     let show_gwas_section = include_all || has_requested("gwas");
+    let show_transcripts_section = include_all || has_requested("transcripts");
+    let show_litvar_section = include_all || has_requested("litvar");
     let variant_label = if !variant.gene.trim().is_empty() && variant.hgvs_p.is_some() {
         format!(
             "{} {}",
@@ -43,13 +67,19 @@ pub fn variant_markdown(
         section_only => section_only,
         section_header => section_header(&variant_label, requested_sections),
         id => &variant.id,
+        assembly => &variant.assembly,
+        assembly_notes => &variant.assembly_notes,
         gene => &variant.gene,
         hgvs_p => &variant.hgvs_p,
+        isoform => &variant.isoform,
+        transcripts => &variant.transcripts,
+        vep => &variant.vep,
         legacy_name => &variant.legacy_name,
         hgvs_c => &variant.hgvs_c,
         consequence => &variant.consequence,
         rsid => &variant.rsid,
         cosmic_id => &variant.cosmic_id,
+        clingen_allele_id => &variant.clingen_allele_id,
         significance => &variant.significance,
         clinvar_id => &variant.clinvar_id,
         clinvar_review_status => &variant.clinvar_review_status,
@@ -58,9 +88,12 @@ pub fn variant_markdown(
         clinvar_conditions => &variant.clinvar_conditions,
         clinvar_condition_reports => &variant.clinvar_condition_reports,
         top_disease => &variant.top_disease,
+        clinvar_submissions => &variant.clinvar_submissions,
         gnomad_af => &variant.gnomad_af,
         allele_frequency_percent => &variant.allele_frequency_percent,
         population_breakdown => &variant.population_breakdown,
+        gnomad => &variant.gnomad,
+        gnomad_call_sets => gnomad_call_sets(variant),
         cadd_score => &variant.cadd_score,
         sift_pred => &variant.sift_pred,
         polyphen_pred => &variant.polyphen_pred,
@@ -73,13 +106,16 @@ pub fn variant_markdown(
         cancer_frequency_source => &variant.cancer_frequency_source,
         gwas => &variant.gwas,
         gwas_unavailable_reason => &variant.gwas_unavailable_reason,
+        litvar => &variant.litvar,
         prediction => prediction,
         expression_interpretation => expr_i,
         splice_interpretation => splice_i,
         chromatin_interpretation => chrom_i,
+        plugin_sections => plugin_section_views(&variant.plugin_sections),
         show_prediction_section => show_prediction_section,
         show_predictions_section => show_predictions_section,
         show_clinvar_section => show_clinvar_section,
+        show_clinvar_submissions_section => show_clinvar_submissions_section,
         show_population_section => show_population_section,
         show_conservation_section => show_conservation_section,
         show_cosmic_section => show_cosmic_section,
@@ -87,6 +123,8 @@ pub fn variant_markdown(
         show_civic_section => show_civic_section,
         show_cbioportal_section => show_cbioportal_section,
         show_gwas_section => show_gwas_section,
+        show_transcripts_section => show_transcripts_section,
+        show_litvar_section => show_litvar_section,
         sections_block => format_sections_block("variant", &variant.id, sections_variant(variant, requested_sections)),
         related_block => format_related_block(related_variant(variant)),
     })?;
@@ -152,11 +190,52 @@ pub fn variant_search_markdown_with_context(
     gene_filter: Option<&str>,
     condition_filter: Option<&str>,
 ) -> Result<String, BioMcpError> {
+    render_variant_search_markdown(
+        query,
+        results,
+        None,
+        pagination_footer,
+        gene_filter,
+        condition_filter,
+    )
+}
+
+pub fn variant_search_grouped_markdown_with_context(
+    query: &str,
+    results: &[VariantSearchResult],
+    group_by: VariantSearchGroupBy,
+    pagination_footer: &str,
+    gene_filter: Option<&str>,
+    condition_filter: Option<&str>,
+) -> Result<String, BioMcpError> {
+    render_variant_search_markdown(
+        query,
+        results,
+        Some(group_by),
+        pagination_footer,
+        gene_filter,
+        condition_filter,
+    )
+}
+
+fn render_variant_search_markdown(
+    query: &str,
+    results: &[VariantSearchResult],
+    group_by: Option<VariantSearchGroupBy>,
+    pagination_footer: &str,
+    gene_filter: Option<&str>,
+    condition_filter: Option<&str>,
+) -> Result<String, BioMcpError> {
+    let groups = group_by
+        .map(|group_by| crate::entities::variant::group_search_results(results, group_by))
+        .unwrap_or_default();
     let tmpl = env()?.get_template("variant_search.md.j2")?;
     let body = tmpl.render(context! {
         query => query,
         count => results.len(),
         results => results,
+        group_by => group_by.map(VariantSearchGroupBy::as_str),
+        groups => groups,
         related_block => format_related_block(related_variant_search_results(
             results,
             gene_filter,
@@ -214,6 +293,211 @@ pub fn gwas_search_markdown_with_footer(
     Ok(with_pagination_footer(body, pagination_footer))
 }
 
+/// Builds the follow-up for a validation report.
+///
+/// MyVariant.info keys variants by hg19 coordinates, so single-nucleotide
+/// changes get a `chr:g.` lookup from the GRCh37 projection rather than the
+/// GRCh38 VCF; other validated changes are looked up by their normalized HGVS.
+/// Invalid reports re-check the first suggested correction instead.
+fn validation_lookup_command(report: &VariantValidation) -> Option<String> {
+    if report.status == "invalid" {
+        let suggestion = report.suggestions.first()?;
+        return Some(if suggestion.contains(':') {
+            format!("biomcp validate hgvs \"{suggestion}\"")
+        } else {
+            format!("biomcp get variant \"{suggestion}\"")
+        });
+    }
+    single_nucleotide_lookup(report).or_else(|| {
+        report
+            .normalized_hgvs
+            .as_deref()
+            .filter(|_| report.coordinate_type.as_deref() != Some("p"))
+            .map(|hgvs| format!("biomcp get variant \"{hgvs}\""))
+    })
+}
+
+fn single_nucleotide_lookup(report: &VariantValidation) -> Option<String> {
+    let hgvs = report.genomic_hgvs_grch37.as_deref()?;
+    let (accession, change) = hgvs.split_once(":g.")?;
+    let chr = crate::entities::variant::refseq_chromosome(accession)?;
+    let (pos, alleles) = change.split_at(change.find(|ch: char| !ch.is_ascii_digit())?);
+    let (reference, alternate) = alleles.split_once('>')?;
+    if pos.is_empty() || reference.len() != 1 || alternate.len() != 1 {
+        return None;
+    }
+    Some(format!(
+        "biomcp get variant \"chr{chr}:g.{pos}{reference}>{alternate}\""
+    ))
+}
+
+pub fn variant_validation_markdown(report: &VariantValidation) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("variant_validation.md.j2")?;
+    let body = tmpl.render(context! {
+        input => &report.input,
+        status => &report.status,
+        syntax_valid => report.syntax_valid,
+        syntax_error => &report.syntax_error,
+        suggestions => &report.suggestions,
+        coordinate_type => &report.coordinate_type,
+        reference_check => &report.reference_check,
+        normalized_hgvs => &report.normalized_hgvs,
+        normalization_changed => report.normalization_changed,
+        gene => &report.gene,
+        transcript_hgvs => &report.transcript_hgvs,
+        genomic_hgvs_grch38 => &report.genomic_hgvs_grch38,
+        genomic_hgvs_grch37 => &report.genomic_hgvs_grch37,
+        vcf_grch38 => &report.vcf_grch38,
+        protein_hgvs => &report.protein_hgvs,
+        protein_hgvs_short => &report.protein_hgvs_short,
+        warnings => &report.warnings,
+        source => &report.source,
+        lookup_command => validation_lookup_command(report),
+    })?;
+    Ok(body)
+}
+
+pub fn variant_vcf_annotation_markdown(
+    annotation: &VcfAnnotation,
+    limit: usize,
+) -> Result<String, BioMcpError> {
+    let rows = annotation.alleles.iter().take(limit).collect::<Vec<_>>();
+    let tmpl = env()?.get_template("variant_vcf_annotation.md.j2")?;
+    Ok(tmpl.render(context! {
+        source => &annotation.source,
+        assembly => annotation.assembly.as_str(),
+        records => annotation.records,
+        queried => annotation.queried,
+        annotated => annotation.annotated,
+        unqueried => annotation.alleles.len() - annotation.queried,
+        total => annotation.alleles.len(),
+        rows => rows,
+    })?)
+}
+
+fn compare_text(value: Option<&str>) -> String {
+    value.unwrap_or("-").to_string()
+}
+
+/// Formats an allele frequency like the templates' `af` filter.
+fn compare_af(value: Option<f64>) -> String {
+    value
+        .map(|value| {
+            let out = format!("{value:.6}");
+            let out = out.trim_end_matches('0').trim_end_matches('.');
+            if out.is_empty() {
+                "0".to_string()
+            } else {
+                out.to_string()
+            }
+        })
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn compare_number(value: Option<f64>, decimals: usize) -> String {
+    value
+        .map(|value| format!("{value:.prec$}", prec = decimals))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+pub fn variant_compare_markdown(comparison: &VariantComparison) -> Result<String, BioMcpError> {
+    let (first, second) = (&comparison.first, &comparison.second);
+    let stars = |value: Option<u8>| value.map(|stars| format!("{stars}/4"));
+    let rows = [
+        (
+            "Gene",
+            "gene",
+            compare_text(Some(first.gene.as_str()).filter(|v| !v.is_empty())),
+            compare_text(Some(second.gene.as_str()).filter(|v| !v.is_empty())),
+        ),
+        (
+            "ClinVar significance",
+            "significance",
+            compare_text(first.significance.as_deref()),
+            compare_text(second.significance.as_deref()),
+        ),
+        (
+            "ClinVar review",
+            "clinvar_review_stars",
+            compare_text(stars(first.clinvar_review_stars).as_deref()),
+            compare_text(stars(second.clinvar_review_stars).as_deref()),
+        ),
+        (
+            "gnomAD AF",
+            "gnomad_af",
+            compare_af(first.gnomad_af),
+            compare_af(second.gnomad_af),
+        ),
+        (
+            "CADD",
+            "cadd_score",
+            compare_number(first.cadd_score, 1),
+            compare_number(second.cadd_score, 1),
+        ),
+        (
+            "REVEL",
+            "revel_score",
+            compare_number(first.revel_score, 3),
+            compare_number(second.revel_score, 3),
+        ),
+        (
+            "OncoKB level",
+            "oncokb_level",
+            compare_text(first.oncokb_level.as_deref()),
+            compare_text(second.oncokb_level.as_deref()),
+        ),
+        (
+            "CIViC evidence items",
+            "civic_evidence_count",
+            first.civic_evidence_count.to_string(),
+            second.civic_evidence_count.to_string(),
+        ),
+        (
+            "CIViC assertions",
+            "civic_assertion_count",
+            first.civic_assertion_count.to_string(),
+            second.civic_assertion_count.to_string(),
+        ),
+    ]
+    .into_iter()
+    .map(|(label, field, first, second)| {
+        context! {
+            field => label,
+            first => first,
+            second => second,
+            differs => comparison.differences.iter().any(|name| name == field),
+        }
+    })
+    .collect::<Vec<_>>();
+
+    let tmpl = env()?.get_template("variant_compare.md.j2")?;
+    Ok(tmpl.render(context! {
+        first_label => &first.label,
+        second_label => &second.label,
+        first_id => &first.id,
+        second_id => &second.id,
+        rows => rows,
+        differs => !comparison.differences.is_empty(),
+        notes => &comparison.notes,
+    })?)
+}
+
+pub fn variant_compare_af_markdown(comparison: &CohortAfComparison) -> Result<String, BioMcpError> {
+    let tmpl = env()?.get_template("variant_compare_af.md.j2")?;
+    Ok(tmpl.render(context! {
+        variant_id => &comparison.variant_id,
+        gene => &comparison.gene,
+        cases => &comparison.cases,
+        controls => &comparison.controls,
+        odds_ratio => comparison.odds_ratio,
+        odds_ratio_ci_low => comparison.odds_ratio_ci_low,
+        odds_ratio_ci_high => comparison.odds_ratio_ci_high,
+        fisher_p_value => comparison.fisher_p_value,
+        gnomad => &comparison.gnomad,
+        notes => &comparison.notes,
+    })?)
+}
+
 pub fn variant_oncokb_markdown(result: &VariantOncoKbResult) -> String {
     let mut out = String::new();
     out.push_str("# OncoKB\n\n");
@@ -1,5 +1,5 @@
 use super::*;
-use crate::entities::variant::TreatmentImplication;
+use crate::entities::variant::{AlleleCount, TreatmentImplication, VariantComparisonSide};
 
 #[test]
 fn markdown_render_variant_entity() {
@@ -18,6 +18,200 @@ fn markdown_render_variant_entity() {
     assert!(markdown.contains("Legacy Name: EGFR L858R"));
 }
 
+#[test]
+fn variant_markdown_renders_clingen_allele_id() {
+    let variant: Variant = serde_json::from_value(serde_json::json!({
+        "id": "chr17:g.7579472G>C",
+        "gene": "TP53",
+        "clingen_allele_id": "CA7200"
+    }))
+    .expect("variant should deserialize");
+
+    let markdown = variant_markdown(&variant, &[]).expect("rendered markdown");
+    assert!(markdown.contains("ClinGen Allele: CA7200"));
+}
+
+#[test]
+fn variant_markdown_renders_isoform_annotation() {
+    let variant: Variant = serde_json::from_value(serde_json::json!({
+        "id": "chr7:g.140453136A>T",
+        "gene": "BRAF",
+        "hgvs_p": "p.V640E",
+        "isoform": {
+            "transcript": "ENST00000644969",
+            "protein": "ENSP00000496776",
+            "position": 640,
+            "canonical": false,
+            "canonical_transcript": "ENST00000646891",
+            "canonical_position": 600,
+            "note": "Position 640 is numbered on non-canonical transcript ENST00000644969."
+        }
+    }))
+    .expect("variant should deserialize");
+
+    let markdown = variant_markdown(&variant, &[]).expect("rendered markdown");
+    assert!(markdown.contains("Isoform: ENST00000644969 (ENSP00000496776), non-canonical"));
+    assert!(markdown.contains("Isoform note: Position 640 is numbered on non-canonical"));
+}
+
+#[test]
+fn variant_markdown_renders_transcripts_section() {
+    let variant: Variant = serde_json::from_value(serde_json::json!({
+        "id": "chr7:g.140453136A>T",
+        "gene": "BRAF",
+        "transcripts": [
+            {
+                "transcript": "ENST00000646891",
+                "protein": "ENSP00000493543",
+                "position": 600,
+                "hgvs_p": "p.V600E",
+                "consequence": "missense_variant",
+                "mane": "MANE Select",
+                "canonical": true,
+                "sift_pred": "Deleterious",
+                "sift_score": 0.0,
+                "polyphen_pred": "Probably Damaging"
+            },
+            {"transcript": "ENST00000479537"}
+        ]
+    }))
+    .expect("variant should deserialize");
+
+    let markdown =
+        variant_markdown(&variant, &["transcripts".to_string()]).expect("rendered markdown");
+    assert!(markdown.contains("## Transcripts (dbNSFP via MyVariant.info)"));
+    assert!(markdown.contains(
+        "| ENST00000646891 | ENSP00000493543 | p.V600E | missense_variant | MANE Select, canonical | Deleterious (0.000) | Probably Damaging |"
+    ));
+    assert!(markdown.contains("| ENST00000479537 | - | - | non-coding | - | - | - |"));
+
+    let markdown = variant_markdown(&variant, &[]).expect("rendered markdown");
+    assert!(!markdown.contains("## Transcripts"));
+}
+
+#[test]
+fn variant_markdown_renders_vep_consequences() {
+    let variant: Variant = serde_json::from_value(serde_json::json!({
+        "id": "chr13:g.32914438delT",
+        "gene": "BRCA2",
+        "vep": {
+            "input": "13:g.32914438delT",
+            "assembly": "GRCh37",
+            "most_severe_consequence": "frameshift_variant",
+            "transcripts": [
+                {
+                    "transcript": "ENST00000380152",
+                    "gene": "BRCA2",
+                    "consequences": ["frameshift_variant"],
+                    "impact": "HIGH",
+                    "canonical": true,
+                    "hgvs_c": "ENST00000380152.3:c.5946delT",
+                    "hgvs_p": "ENSP00000369497.3:p.Ser1982ArgfsTer22"
+                },
+                {"transcript": "ENST00000470094", "impact": "MODIFIER"}
+            ]
+        }
+    }))
+    .expect("variant should deserialize");
+
+    let markdown = variant_markdown(&variant, &[]).expect("rendered markdown");
+    assert!(markdown.contains("## Consequences (Ensembl VEP)"));
+    assert!(markdown.contains("- Input: 13:g.32914438delT (GRCh37)"));
+    assert!(markdown.contains("- Most severe: frameshift_variant"));
+    assert!(markdown.contains(
+        "| ENST00000380152 | BRCA2 | frameshift_variant | HIGH | yes | ENST00000380152.3:c.5946delT | ENSP00000369497.3:p.Ser1982ArgfsTer22 |"
+    ));
+    assert!(markdown.contains("| ENST00000470094 | - | - | MODIFIER | - | - | - |"));
+}
+
+#[test]
+fn variant_markdown_renders_clinvar_submissions_table() {
+    let variant: Variant = serde_json::from_value(serde_json::json!({
+        "id": "chr7:g.140453136A>T",
+        "gene": "BRAF",
+        "hgvs_p": "p.V600E",
+        "clinvar_submissions": [
+            {
+                "submitter": "OMIM",
+                "accession": "SCV000034778",
+                "classification": "Pathogenic",
+                "review_status": "no assertion criteria provided",
+                "last_evaluated": "2016-01-01"
+            },
+            {"submitter": "Cancer Genomics Lab"}
+        ]
+    }))
+    .expect("variant should deserialize");
+
+    let markdown = variant_markdown(&variant, &["clinvar-submissions".to_string()])
+        .expect("rendered markdown");
+    assert!(markdown.contains("## ClinVar Submissions"));
+    assert!(markdown.contains(
+        "| OMIM | Pathogenic | no assertion criteria provided | 2016-01-01 | SCV000034778 |"
+    ));
+    assert!(markdown.contains("| Cancer Genomics Lab | - | - | - | - |"));
+
+    let markdown = variant_markdown(&variant, &[]).expect("rendered markdown");
+    assert!(!markdown.contains("## ClinVar Submissions"));
+}
+
+#[test]
+fn variant_markdown_renders_assembly_and_notes() {
+    let variant: Variant = serde_json::from_value(serde_json::json!({
+        "id": "chr7:g.140753336A>T",
+        "gene": "BRAF",
+        "assembly": "GRCh38",
+        "assembly_notes": [
+            "population: gnomAD v2 exome frequencies are GRCh37-native; MyVariant.info serves them lifted over to GRCh38."
+        ]
+    }))
+    .expect("variant should deserialize");
+
+    let markdown = variant_markdown(&variant, &[]).expect("rendered markdown");
+    assert!(markdown.contains("Assembly: GRCh38"));
+
+    let markdown =
+        variant_markdown(&variant, &["population".to_string()]).expect("rendered markdown");
+    assert!(markdown.contains("Assembly note: population: gnomAD v2 exome frequencies"));
+}
+
+#[test]
+fn variant_markdown_renders_litvar_publications_and_sentences() {
+    let variant: Variant = serde_json::from_value(serde_json::json!({
+        "id": "chr7:g.140453136A>T",
+        "gene": "BRAF",
+        "hgvs_p": "p.V600E",
+        "litvar": {
+            "litvar_id": "litvar@rs113488022##",
+            "name": "p.V600E",
+            "publication_count": 11540,
+            "articles": [
+                {
+                    "pmid": "20818844",
+                    "title": "Inhibition of mutated, activated BRAF in metastatic melanoma.",
+                    "journal": "N Engl J Med",
+                    "year": "2010",
+                    "sentences": ["Tumors carrying V600E responded"]
+                },
+                {"pmid": "22663011"}
+            ]
+        }
+    }))
+    .expect("variant should deserialize");
+
+    let markdown = variant_markdown(&variant, &["litvar".to_string()]).expect("rendered markdown");
+    assert!(markdown.contains("## Literature (LitVar)"));
+    assert!(markdown.contains("Publications mentioning p.V600E: 11540"));
+    assert!(markdown.contains(
+        "- PMID 20818844 (2010): Inhibition of mutated, activated BRAF in metastatic melanoma. _N Engl J Med_"
+    ));
+    assert!(markdown.contains("  > Tumors carrying V600E responded"));
+    assert!(markdown.contains("- PMID 22663011: -"));
+
+    let markdown = variant_markdown(&variant, &[]).expect("rendered markdown");
+    assert!(!markdown.contains("## Literature (LitVar)"));
+}
+
 #[test]
 fn variant_markdown_renders_compact_clinvar_and_population_fields() {
     let variant: Variant = serde_json::from_value(serde_json::json!({
@@ -36,6 +230,51 @@ fn variant_markdown_renders_compact_clinvar_and_population_fields() {
     assert!(markdown.contains("(0.0100%)"));
 }
 
+#[test]
+fn variant_markdown_renders_gnomad_api_counts() {
+    let variant: Variant = serde_json::from_value(serde_json::json!({
+        "id": "chr7:g.140453136A>T",
+        "gene": "BRAF",
+        "rsid": "rs113488022",
+        "gnomad": {
+            "version": "v4",
+            "variant_id": "7-140753336-A-T",
+            "reference_genome": "GRCh38",
+            "flags": ["lcr"],
+            "exome": {
+                "ac": 12,
+                "an": 1461894,
+                "af": 0.0000082,
+                "nhomalt": 0,
+                "popmax": "nfe",
+                "popmax_faf95": 0.000005,
+                "mean_coverage": 61.24,
+                "over_20x": 0.97,
+                "populations": [
+                    {"id": "nfe", "population": "European (non-Finnish)", "ac": 10, "an": 1000000, "af": 0.00001, "nhomalt": 0},
+                    {"id": "nfe_est", "population": "nfe_est", "ac": 0, "an": 0, "is_subgroup": true}
+                ]
+            },
+            "genome": {"ac": 1, "an": 152000, "af": 0.0000066, "filters": ["AC0"]}
+        }
+    }))
+    .expect("variant should deserialize");
+
+    let markdown =
+        variant_markdown(&variant, &["population".to_string()]).expect("rendered markdown");
+    assert!(markdown.contains("### gnomAD v4 (7-140753336-A-T, GRCh38)"));
+    assert!(markdown.contains("Flags: lcr"));
+    assert!(
+        markdown.contains("Exome: AC 12 / AN 1461894 (AF 0.000008), homozygotes 0, filters PASS")
+    );
+    assert!(markdown.contains("  Popmax: nfe (FAF95 0.000005)"));
+    assert!(markdown.contains("  Coverage: mean 61.2x, 97.0% of samples at 20x"));
+    assert!(markdown.contains("| European (non-Finnish) | 10 | 1000000 | 0.00001 | 0 |"));
+    assert!(markdown.contains("|   nfe_est | 0 | 0 | - | - |"));
+    assert!(markdown.contains("Genome: AC 1 / AN 152000 (AF 0.000007), filters AC0"));
+    assert!(!markdown.contains("No population data reported."));
+}
+
 #[test]
 fn variant_markdown_renders_gwas_unavailable_message() {
     let variant: Variant = serde_json::from_value(serde_json::json!({
@@ -65,6 +304,8 @@ fn variant_search_markdown_renders_legacy_name_column_and_fallback() {
             gnomad_af: None,
             revel: Some(0.935),
             gerp: Some(5.12),
+            cadd_score: None,
+            consequence: None,
         },
         VariantSearchResult {
             id: "chr6:g.118880100A>G".to_string(),
@@ -76,6 +317,8 @@ fn variant_search_markdown_renders_legacy_name_column_and_fallback() {
             gnomad_af: None,
             revel: None,
             gerp: None,
+            cadd_score: None,
+            consequence: None,
         },
     ];
 
@@ -99,6 +342,8 @@ fn variant_search_markdown_renders_related_commands_from_context() {
             gnomad_af: None,
             revel: Some(0.91),
             gerp: Some(5.7),
+            cadd_score: None,
+            consequence: None,
         },
         VariantSearchResult {
             id: "rs7626962".to_string(),
@@ -110,6 +355,8 @@ fn variant_search_markdown_renders_related_commands_from_context() {
             gnomad_af: None,
             revel: Some(0.88),
             gerp: Some(5.1),
+            cadd_score: None,
+            consequence: None,
         },
     ];
 
@@ -128,6 +375,46 @@ fn variant_search_markdown_renders_related_commands_from_context() {
     assert!(markdown.contains("biomcp search disease --query Brugada"));
 }
 
+#[test]
+fn variant_search_grouped_markdown_nests_rows_under_group_headers() {
+    let row = |id: &str, significance: Option<&str>| VariantSearchResult {
+        id: id.to_string(),
+        gene: "BRCA1".to_string(),
+        hgvs_p: None,
+        legacy_name: None,
+        significance: significance.map(str::to_string),
+        clinvar_stars: None,
+        gnomad_af: None,
+        revel: None,
+        gerp: None,
+        cadd_score: None,
+        consequence: None,
+    };
+    let results = vec![
+        row("rs1", Some("Pathogenic")),
+        row("rs2", Some("Benign")),
+        row("rs3", Some("Pathogenic")),
+    ];
+
+    let markdown = variant_search_grouped_markdown_with_context(
+        "gene=BRCA1",
+        &results,
+        crate::entities::variant::VariantSearchGroupBy::Significance,
+        "",
+        Some("BRCA1"),
+        None,
+    )
+    .expect("rendered markdown");
+
+    assert!(markdown.contains("Grouped by: significance"));
+    assert!(markdown.contains("## Pathogenic (2)"));
+    assert!(markdown.contains("## Benign (1)"));
+    let pathogenic = markdown.find("## Pathogenic").expect("pathogenic header");
+    let benign = markdown.find("## Benign").expect("benign header");
+    let rs3 = markdown.find("| rs3 |").expect("rs3 row");
+    assert!(pathogenic < rs3 && rs3 < benign);
+}
+
 #[test]
 fn phenotype_search_markdown_renders_top_disease_follow_up() {
     let results = vec![
@@ -213,3 +500,225 @@ fn gwas_search_markdown_renders_result_row() {
     assert!(markdown.contains("| rs121434568 | Lung adenocarcinoma |"));
     assert!(markdown.contains("| OR 1.230 |") || markdown.contains("OR 1.230"));
 }
+
+#[test]
+fn variant_validation_markdown_renders_normalized_report_and_lookup() {
+    let report: VariantValidation = serde_json::from_value(serde_json::json!({
+        "input": "NM_000546.6(TP53):c.215C>G",
+        "status": "valid",
+        "syntax_valid": true,
+        "coordinate_type": "c",
+        "reference_check": "match",
+        "normalized_hgvs": "NM_000546.6:c.215C>G",
+        "normalization_changed": false,
+        "gene": "TP53",
+        "transcript_hgvs": "NM_000546.6:c.215C>G",
+        "genomic_hgvs_grch38": "NC_000017.11:g.7676154G>C",
+        "genomic_hgvs_grch37": "NC_000017.10:g.7579472G>C",
+        "vcf_grch38": "chr17-7676154-G-C",
+        "protein_hgvs": "NP_000537.3:p.(Pro72Arg)",
+        "protein_hgvs_short": "NP_000537.3:p.(P72R)",
+        "source": "VariantValidator"
+    }))
+    .expect("report should deserialize");
+
+    let markdown = variant_validation_markdown(&report).expect("rendered markdown");
+    assert!(markdown.contains("# Variant validation: NM_000546.6(TP53):c.215C>G"));
+    assert!(markdown.contains("| Reference allele | matches reference sequence |"));
+    assert!(markdown.contains("| Normalization | already normalized |"));
+    assert!(markdown.contains("| Genomic (GRCh38) | `NC_000017.11:g.7676154G>C` |"));
+    assert!(markdown.contains("`NP_000537.3:p.(Pro72Arg)` / `NP_000537.3:p.(P72R)`"));
+    assert!(markdown.contains("Next: `biomcp get variant \"chr17:g.7579472G>C\"`"));
+    assert!(!markdown.contains("chr17:g.7676154G>C\""));
+}
+
+#[test]
+fn variant_validation_markdown_renders_syntax_error_without_lookup() {
+    let report: VariantValidation = serde_json::from_value(serde_json::json!({
+        "input": "BRAF V600E",
+        "status": "invalid",
+        "syntax_valid": false,
+        "syntax_error": "missing ':' between the reference sequence and the variant description",
+        "reference_check": "not_checked",
+        "normalization_changed": false
+    }))
+    .expect("report should deserialize");
+
+    let markdown = variant_validation_markdown(&report).expect("rendered markdown");
+    assert!(markdown.contains("| Status | **invalid** |"));
+    assert!(markdown.contains("| HGVS syntax | invalid |"));
+    assert!(markdown.contains("Syntax error: missing ':'"));
+    assert!(!markdown.contains("Canonical Representations"));
+    assert!(!markdown.contains("Did you mean"));
+    assert!(!markdown.contains("Next:"));
+}
+
+#[test]
+fn variant_validation_markdown_offers_suggested_corrections() {
+    let report: VariantValidation = serde_json::from_value(serde_json::json!({
+        "input": "NM_004333.4:c.1799t>a",
+        "status": "invalid",
+        "syntax_valid": false,
+        "syntax_error": "'c.1799t>a' is not a valid c. position and edit",
+        "suggestions": ["NM_004333.4:c.1799T>A"],
+        "reference_check": "not_checked",
+        "normalization_changed": false
+    }))
+    .expect("report should deserialize");
+
+    let markdown = variant_validation_markdown(&report).expect("rendered markdown");
+    assert!(markdown.contains("Did you mean: `NM_004333.4:c.1799T>A`"));
+    assert!(markdown.contains("Next: `biomcp validate hgvs \"NM_004333.4:c.1799T>A\"`"));
+
+    let report: VariantValidation = serde_json::from_value(serde_json::json!({
+        "input": "NM_004333.4(BRAF):p.V600E",
+        "status": "invalid",
+        "syntax_valid": false,
+        "suggestions": ["BRAF V600E"],
+        "reference_check": "not_checked",
+        "normalization_changed": false
+    }))
+    .expect("report should deserialize");
+    let markdown = variant_validation_markdown(&report).expect("rendered markdown");
+    assert!(markdown.contains("Next: `biomcp get variant \"BRAF V600E\"`"));
+}
+
+#[test]
+fn variant_validation_markdown_looks_up_indels_by_normalized_hgvs() {
+    let report: VariantValidation = serde_json::from_value(serde_json::json!({
+        "input": "NM_007294.4:c.68_69del",
+        "status": "valid",
+        "syntax_valid": true,
+        "coordinate_type": "c",
+        "reference_check": "match",
+        "normalized_hgvs": "NM_007294.4:c.68_69del",
+        "normalization_changed": false,
+        "genomic_hgvs_grch37": "NC_000017.10:g.41276045_41276046del",
+        "source": "VariantValidator"
+    }))
+    .expect("report should deserialize");
+
+    let markdown = variant_validation_markdown(&report).expect("rendered markdown");
+    assert!(markdown.contains("Next: `biomcp get variant \"NM_007294.4:c.68_69del\"`"));
+}
+
+#[test]
+fn variant_vcf_annotation_markdown_renders_allele_rows_and_limit() {
+    let allele = |alt: &str, hgvs: Option<&str>, significance: Option<&str>| {
+        crate::entities::variant::VcfAlleleAnnotation {
+            line: 4,
+            chrom: "7".to_string(),
+            pos: 140453136,
+            id: Some("rs113488022".to_string()),
+            reference: "A".to_string(),
+            alt: alt.to_string(),
+            hgvs: hgvs.map(str::to_string),
+            gene: significance.map(|_| "BRAF".to_string()),
+            significance: significance.map(str::to_string),
+            gnomad_af: significance.map(|_| 0.0000039),
+            cadd: significance.map(|_| 32.0),
+            revel: significance.map(|_| 0.931),
+        }
+    };
+    let annotation = VcfAnnotation {
+        source: "sample.vcf".to_string(),
+        assembly: crate::entities::variant::GenomeAssembly::Grch38,
+        records: 2,
+        queried: 2,
+        annotated: 1,
+        alleles: vec![
+            allele("T", Some("chr7:g.140453136A>T"), Some("Pathogenic")),
+            allele("C", Some("chr7:g.140453136A>C"), None),
+            allele("<DEL>", None, None),
+        ],
+        vcf: crate::utils::vcf::VcfFile {
+            meta: Vec::new(),
+            header: None,
+            records: Vec::new(),
+        },
+    };
+
+    let markdown = variant_vcf_annotation_markdown(&annotation, 2).expect("vcf markdown");
+    assert!(markdown.contains("# VCF annotation: sample.vcf"));
+    assert!(markdown.contains(
+        "1 of 2 alleles found in MyVariant.info (GRCh38) across 2 records; 1 symbolic or non-primary-contig alleles were not queried."
+    ));
+    assert!(markdown.contains(
+        "| 7:140453136 A>T | rs113488022 | BRAF | Pathogenic | 0.000004 | 32.0 | 0.931 |"
+    ));
+    assert!(markdown.contains("| 7:140453136 A>C | rs113488022 | - | - | - | - | - |"));
+    assert!(markdown.contains("Showing 2 of 3 alleles."));
+    assert!(markdown.contains("biomcp annotate vcf sample.vcf --assembly grch38 --format vcf"));
+}
+
+#[test]
+fn variant_compare_af_markdown_renders_cohorts_statistics_and_gnomad_rows() {
+    let variant: Variant = serde_json::from_value(serde_json::json!({
+        "id": "rs80357906",
+        "gene": "BRCA1",
+        "gnomad_af": 0.0004,
+        "population_breakdown": {"populations": [{"population": "afr", "af": 0.0}]}
+    }))
+    .expect("variant should deserialize");
+    let comparison = crate::entities::variant::compare_cohort_af(
+        &variant,
+        AlleleCount::parse("--cases", "12/3400").expect("cases"),
+        AlleleCount::parse("--controls", "1/5600").expect("controls"),
+    );
+
+    let markdown = variant_compare_af_markdown(&comparison).expect("rendered markdown");
+    assert!(markdown.contains("# Allele frequency comparison: BRCA1 rs80357906"));
+    assert!(markdown.contains("| Cases | 12 | 3400 | 0.003529 |"));
+    assert!(markdown.contains("| Controls | 1 | 5600 | 0.000179 |"));
+    assert!(markdown.contains("Odds ratio (allelic): 19.83 (95% CI 2.58-152.58)"));
+    assert!(markdown.contains("Fisher exact p (two-sided): 7.08e-5"));
+    assert!(markdown.contains("| All | 0.0004 | 8.8x |"));
+    assert!(markdown.contains("| afr | 0 | - |"));
+    assert!(markdown.contains("- Control AF is 0.4x the gnomAD AF"));
+}
+
+#[test]
+fn variant_compare_markdown_bolds_differing_fields() {
+    let side = |id: &str, hgvs_p: &str| VariantComparisonSide {
+        id: id.to_string(),
+        label: format!("BRAF {hgvs_p}"),
+        gene: "BRAF".to_string(),
+        significance: Some("Pathogenic".to_string()),
+        clinvar_review_stars: Some(2),
+        gnomad_af: None,
+        cadd_score: Some(32.0),
+        revel_score: None,
+        oncokb_level: None,
+        civic_evidence_count: 0,
+        civic_assertion_count: 0,
+    };
+    let mut first = side("rs113488022", "p.V600E");
+    first.clinvar_review_stars = Some(3);
+    first.gnomad_af = Some(0.000004);
+    first.revel_score = Some(0.931);
+    first.oncokb_level = Some("Level 1".to_string());
+    let comparison = VariantComparison {
+        first,
+        second: side("rs121913227", "p.V600K"),
+        differences: vec![
+            "clinvar_review_stars".into(),
+            "gnomad_af".into(),
+            "revel_score".into(),
+            "oncokb_level".into(),
+        ],
+        notes: vec!["Set ONCOKB_TOKEN to include OncoKB levels.".into()],
+    };
+    let markdown = variant_compare_markdown(&comparison).expect("rendered markdown");
+    assert!(markdown.contains("# Variant comparison: BRAF p.V600E vs BRAF p.V600K"));
+    assert!(markdown.contains("| Field | BRAF p.V600E | BRAF p.V600K |"));
+    assert!(markdown.contains("| ClinVar significance | Pathogenic | Pathogenic |"));
+    assert!(markdown.contains("| ClinVar review | **3/4** | **2/4** |"));
+    assert!(markdown.contains("| gnomAD AF | **0.000004** | **-** |"));
+    assert!(markdown.contains("| CADD | 32.0 | 32.0 |"));
+    assert!(markdown.contains("| REVEL | **0.931** | **-** |"));
+    assert!(markdown.contains("| OncoKB level | **Level 1** | **-** |"));
+    assert!(markdown.contains("| CIViC evidence items | 0 | 0 |"));
+    assert!(markdown.contains("Bold values differ between the two variants."));
+    assert!(markdown.contains("- Set ONCOKB_TOKEN to include OncoKB levels."));
+    assert!(markdown.contains("biomcp get variant \"rs121913227\" all"));
+}
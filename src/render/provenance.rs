@@ -1,20 +1,28 @@
 use std::collections::HashSet;
 
+use schemars::JsonSchema;
 use serde::Serialize;
 
-use crate::entities::adverse_event::{AdverseEvent, AdverseEventReport, DeviceEvent};
+use crate::entities::adverse_event::{
+    AdverseEvent, AdverseEventReport, DeviceClearance, DeviceEvent,
+};
 use crate::entities::article::Article;
 use crate::entities::discover::DiscoverResult;
 use crate::entities::disease::Disease;
 use crate::entities::drug::Drug;
 use crate::entities::gene::Gene;
+use crate::entities::hla::Hla;
 use crate::entities::pathway::Pathway;
 use crate::entities::pgx::Pgx;
 use crate::entities::protein::Protein;
+use crate::entities::structure::Structure;
+use crate::entities::study::Study;
+use crate::entities::transcript::Transcript;
 use crate::entities::trial::Trial;
 use crate::entities::variant::Variant;
+use crate::sources::plugin::PluginSection;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
 pub struct SectionSource {
     pub key: String,
     pub label: String,
@@ -64,6 +72,18 @@ where
     out
 }
 
+fn push_plugin_sections(out: &mut Vec<SectionSource>, sections: &[PluginSection]) {
+    for section in sections {
+        push_section(
+            out,
+            true,
+            &section.section,
+            &section.source,
+            [section.source.as_str()],
+        );
+    }
+}
+
 fn push_section<I, S>(
     out: &mut Vec<SectionSource>,
     present: bool,
@@ -155,6 +175,9 @@ pub(crate) fn drug_interaction_sources(drug: &Drug) -> Vec<String> {
     if has_opt_text(&drug.interaction_text) {
         sources.push("OpenFDA label".to_string());
     }
+    if !drug.supplement_interactions.is_empty() {
+        sources.push("MedlinePlus Supplements".to_string());
+    }
     normalize_sources(sources)
 }
 
@@ -259,6 +282,13 @@ pub(crate) fn gene_section_sources(gene: &Gene) -> Vec<SectionSource> {
         "Druggability",
         ["DGIdb", "Open Targets"],
     );
+    push_section(
+        &mut out,
+        gene.tractability.is_some(),
+        "tractability",
+        "Tractability & Safety",
+        ["Open Targets"],
+    );
     push_section(
         &mut out,
         gene.clingen.is_some(),
@@ -280,6 +310,13 @@ pub(crate) fn gene_section_sources(gene: &Gene) -> Vec<SectionSource> {
         "DisGeNET",
         ["DisGeNET"],
     );
+    push_section(
+        &mut out,
+        gene.clinvar_summary.is_some(),
+        "clinvar-summary",
+        "ClinVar Summary",
+        ["MyVariant.info (ClinVar)"],
+    );
     push_section(
         &mut out,
         gene.funding.is_some() || has_opt_text(&gene.funding_note),
@@ -287,6 +324,28 @@ pub(crate) fn gene_section_sources(gene: &Gene) -> Vec<SectionSource> {
         "Funding",
         ["NIH Reporter"],
     );
+    push_section(
+        &mut out,
+        gene.key_papers.is_some(),
+        "key-papers",
+        "Key Papers",
+        ["Europe PMC"],
+    );
+    push_section(
+        &mut out,
+        gene.dependency.is_some(),
+        "dependency",
+        "Dependency",
+        ["DepMap"],
+    );
+    push_section(
+        &mut out,
+        gene.orthologs.is_some(),
+        "orthologs",
+        "Orthologs",
+        ["Ensembl Compara"],
+    );
+    push_plugin_sections(&mut out, &gene.plugin_sections);
     out
 }
 
@@ -410,6 +469,13 @@ pub(crate) fn drug_section_sources(drug: &Drug) -> Vec<SectionSource> {
         "Shortage",
         ["OpenFDA Drug Shortages"],
     );
+    push_section(
+        &mut out,
+        drug.shortage_trials.is_some(),
+        "shortage_trials",
+        "Shortage Trials",
+        ["ClinicalTrials.gov"],
+    );
     push_section(
         &mut out,
         drug.ema_shortage.is_some(),
@@ -425,6 +491,13 @@ pub(crate) fn drug_section_sources(drug: &Drug) -> Vec<SectionSource> {
         ["OpenFDA Drugs@FDA"],
     );
     push_section(&mut out, drug.civic.is_some(), "civic", "CIViC", ["CIViC"]);
+    push_section(
+        &mut out,
+        drug.patents.is_some(),
+        "patents",
+        "Patents",
+        ["PatentsView"],
+    );
     out
 }
 
@@ -542,6 +615,20 @@ pub(crate) fn disease_section_sources(disease: &Disease) -> Vec<SectionSource> {
         "DisGeNET",
         ["DisGeNET"],
     );
+    push_section(
+        &mut out,
+        disease.epidemiology.is_some() || has_opt_text(&disease.epidemiology_note),
+        "epidemiology",
+        "Epidemiology",
+        ["WHO GHO"],
+    );
+    push_section(
+        &mut out,
+        disease.therapies.is_some() || has_opt_text(&disease.therapies_note),
+        "therapies",
+        "Therapies",
+        ["OpenTargets", "OpenFDA Drugs@FDA"],
+    );
     out
 }
 
@@ -583,10 +670,24 @@ pub(crate) fn variant_section_sources(variant: &Variant) -> Vec<SectionSource> {
     );
     push_section(
         &mut out,
-        variant.gnomad_af.is_some() || variant.population_breakdown.is_some(),
+        !variant.clinvar_submissions.is_empty(),
+        "clinvar-submissions",
+        "ClinVar Submissions",
+        ["ClinVar (NCBI E-utilities)"],
+    );
+    let mut population_sources = Vec::new();
+    if variant.gnomad_af.is_some() || variant.population_breakdown.is_some() {
+        population_sources.push("gnomAD via MyVariant.info");
+    }
+    if variant.gnomad.is_some() {
+        population_sources.push("gnomAD API");
+    }
+    push_section(
+        &mut out,
+        !population_sources.is_empty(),
         "population",
         "Population",
-        ["gnomAD via MyVariant.info"],
+        population_sources,
     );
     push_section(
         &mut out,
@@ -633,6 +734,20 @@ pub(crate) fn variant_section_sources(variant: &Variant) -> Vec<SectionSource> {
         "cBioPortal",
         ["cBioPortal"],
     );
+    push_section(
+        &mut out,
+        !variant.transcripts.is_empty(),
+        "transcripts",
+        "Transcripts",
+        ["MyVariant.info"],
+    );
+    push_section(
+        &mut out,
+        variant.vep.is_some(),
+        "vep",
+        "Ensembl VEP Consequences",
+        ["Ensembl VEP"],
+    );
     push_section(
         &mut out,
         !variant.gwas.is_empty() || has_opt_text(&variant.gwas_unavailable_reason),
@@ -640,6 +755,14 @@ pub(crate) fn variant_section_sources(variant: &Variant) -> Vec<SectionSource> {
         "GWAS",
         ["GWAS Catalog"],
     );
+    push_section(
+        &mut out,
+        variant.litvar.is_some(),
+        "litvar",
+        "Literature (LitVar)",
+        ["LitVar2"],
+    );
+    push_plugin_sections(&mut out, &variant.plugin_sections);
     out
 }
 
@@ -687,7 +810,10 @@ pub(crate) fn article_section_sources(article: &Article) -> Vec<SectionSource> {
         article.full_text_path.is_some() || has_opt_text(&article.full_text_note),
         "fulltext",
         "Full Text",
-        ["PMC OA"],
+        [article
+            .full_text_source
+            .as_ref()
+            .map_or("PMC OA", |source| source.provider.as_str())],
     );
     push_section(
         &mut out,
@@ -696,6 +822,13 @@ pub(crate) fn article_section_sources(article: &Article) -> Vec<SectionSource> {
         "Semantic Scholar",
         ["Semantic Scholar"],
     );
+    push_section(
+        &mut out,
+        article.citation_graph.is_some(),
+        "citations",
+        "Citations",
+        ["Europe PMC"],
+    );
     out
 }
 
@@ -770,6 +903,20 @@ pub(crate) fn trial_section_sources(trial: &Trial) -> Vec<SectionSource> {
         "References",
         source_ref,
     );
+    push_section(
+        &mut out,
+        trial.publications.is_some(),
+        "publications",
+        "Publications",
+        ["ClinicalTrials.gov", "Europe PMC"],
+    );
+    push_section(
+        &mut out,
+        trial.results.is_some(),
+        "results",
+        "Results",
+        source_ref,
+    );
     out
 }
 
@@ -910,6 +1057,124 @@ pub(crate) fn pgx_section_sources(pgx: &Pgx) -> Vec<SectionSource> {
     out
 }
 
+pub(crate) fn hla_section_sources(hla: &Hla) -> Vec<SectionSource> {
+    let mut out = Vec::new();
+    push_section(
+        &mut out,
+        hla.registry.is_some() || has_opt_text(&hla.registry_note),
+        "registry",
+        "Registered Alleles",
+        ["IPD-IMGT/HLA"],
+    );
+    push_section(
+        &mut out,
+        !hla.frequencies.is_empty(),
+        "frequencies",
+        "Population Frequencies",
+        ["CPIC", "Allele Frequency Net"],
+    );
+    push_section(
+        &mut out,
+        !hla.drug_associations.is_empty(),
+        "drugs",
+        "Drug Hypersensitivity",
+        ["CPIC"],
+    );
+    push_section(
+        &mut out,
+        !hla.disease_associations.is_empty(),
+        "diseases",
+        "Disease Associations",
+        ["BioMCP curated"],
+    );
+    out
+}
+
+pub(crate) fn study_section_sources(study: &Study) -> Vec<SectionSource> {
+    let mut out = Vec::new();
+    push_section(
+        &mut out,
+        has_text(&study.study_id) || has_text(&study.name),
+        "study",
+        "Study",
+        ["cBioPortal"],
+    );
+    push_section(
+        &mut out,
+        !study.mutated_genes.is_empty() || has_opt_text(&study.mutated_genes_note),
+        "genes",
+        "Mutated Genes",
+        ["cBioPortal"],
+    );
+    out
+}
+
+pub(crate) fn structure_section_sources(structure: &Structure) -> Vec<SectionSource> {
+    let mut out = Vec::new();
+    let summary_present = has_text(&structure.pdb_id)
+        || has_opt_text(&structure.title)
+        || has_opt_text(&structure.method)
+        || structure.resolution_angstrom.is_some();
+    push_section(
+        &mut out,
+        summary_present,
+        "summary",
+        "Summary",
+        ["RCSB PDB"],
+    );
+    push_section(
+        &mut out,
+        structure.citation.is_some(),
+        "citation",
+        "Primary Citation",
+        ["RCSB PDB"],
+    );
+    push_section(
+        &mut out,
+        !structure.chains.is_empty(),
+        "chains",
+        "Chains",
+        ["RCSB PDB", "SIFTS UniProt mapping"],
+    );
+    push_section(
+        &mut out,
+        !structure.ligands.is_empty(),
+        "ligands",
+        "Ligands",
+        ["RCSB PDB"],
+    );
+    out
+}
+
+pub(crate) fn transcript_section_sources(transcript: &Transcript) -> Vec<SectionSource> {
+    let mut out = Vec::new();
+    let summary_present =
+        has_text(&transcript.id) || has_opt_text(&transcript.biotype) || transcript.start.is_some();
+    push_section(&mut out, summary_present, "summary", "Summary", ["Ensembl"]);
+    push_section(
+        &mut out,
+        transcript.protein.is_some(),
+        "protein",
+        "Protein Product",
+        ["Ensembl"],
+    );
+    push_section(
+        &mut out,
+        !transcript.exons.is_empty(),
+        "exons",
+        "Exons",
+        ["Ensembl"],
+    );
+    push_section(
+        &mut out,
+        !transcript.xrefs.is_empty(),
+        "xrefs",
+        "Cross-references",
+        ["Ensembl"],
+    );
+    out
+}
+
 pub(crate) fn adverse_event_section_sources(event: &AdverseEvent) -> Vec<SectionSource> {
     let mut out = Vec::new();
     let overview_present = has_text(&event.report_id)
@@ -975,6 +1240,24 @@ pub(crate) fn device_event_section_sources(event: &DeviceEvent) -> Vec<SectionSo
     out
 }
 
+pub(crate) fn device_clearance_section_sources(clearance: &DeviceClearance) -> Vec<SectionSource> {
+    let mut out = Vec::new();
+    let summary_present = has_text(&clearance.k_number)
+        || has_text(&clearance.device_name)
+        || has_opt_text(&clearance.applicant)
+        || has_opt_text(&clearance.decision)
+        || has_opt_text(&clearance.decision_date);
+    push_section(&mut out, summary_present, "summary", "Summary", ["OpenFDA"]);
+    push_section(
+        &mut out,
+        clearance.classification.is_some(),
+        "classification",
+        "Classification",
+        ["OpenFDA"],
+    );
+    out
+}
+
 pub(crate) fn adverse_event_report_section_sources(
     report: &AdverseEventReport,
 ) -> Vec<SectionSource> {
@@ -1045,11 +1328,17 @@ mod tests {
         let variant = Variant {
             gene: String::new(),
             id: "rs7903146".to_string(),
+            assembly: None,
+            assembly_notes: Vec::new(),
             hgvs_p: None,
             legacy_name: None,
             hgvs_c: None,
             rsid: Some("rs7903146".to_string()),
             cosmic_id: None,
+            clingen_allele_id: None,
+            isoform: None,
+            transcripts: Vec::new(),
+            vep: None,
             significance: None,
             clinvar_id: None,
             clinvar_review_status: None,
@@ -1065,18 +1354,22 @@ mod tests {
             conservation: None,
             expanded_predictions: Vec::new(),
             population_breakdown: None,
+            gnomad: None,
             cosmic_context: None,
             cgi_associations: Vec::new(),
             civic: None,
             clinvar_conditions: Vec::new(),
             clinvar_condition_reports: None,
             top_disease: None,
+            clinvar_submissions: Vec::new(),
             cancer_frequencies: Vec::new(),
             cancer_frequency_source: None,
             gwas: Vec::new(),
             gwas_unavailable_reason: Some("GWAS association data temporarily unavailable.".into()),
             supporting_pmids: None,
+            litvar: None,
             prediction: None,
+            plugin_sections: Vec::new(),
         };
 
         let sources = variant_section_sources(&variant);
@@ -1106,12 +1399,14 @@ mod tests {
             indications: Vec::new(),
             interactions: Vec::new(),
             interaction_text: None,
+            supplement_interactions: Vec::new(),
             pharm_classes: Vec::new(),
             top_adverse_events: Vec::new(),
             faers_query: None,
             label: None,
             label_set_id: None,
             shortage: None,
+            shortage_trials: None,
             approvals: None,
             us_safety_warnings: None,
             ema_regulatory: None,
@@ -1119,6 +1414,8 @@ mod tests {
             ema_shortage: None,
             who_prequalification: None,
             civic: None,
+            patents: None,
+            ncit: None,
         };
 
         let sources = drug_section_sources(&drug);
@@ -1152,12 +1449,14 @@ mod tests {
             indications: Vec::new(),
             interactions: Vec::new(),
             interaction_text: None,
+            supplement_interactions: Vec::new(),
             pharm_classes: Vec::new(),
             top_adverse_events: Vec::new(),
             faers_query: None,
             label: None,
             label_set_id: None,
             shortage: None,
+            shortage_trials: None,
             approvals: None,
             us_safety_warnings: None,
             ema_regulatory: None,
@@ -1177,6 +1476,8 @@ mod tests {
                 prequalification_date: Some("2019-12-18".to_string()),
             }]),
             civic: None,
+            patents: None,
+            ncit: None,
         };
 
         let sources = drug_section_sources(&drug);
@@ -1209,6 +1510,10 @@ mod tests {
             prevalence_note: None,
             survival: None,
             survival_note: Some("SEER survival data not available for this condition.".into()),
+            epidemiology: None,
+            epidemiology_note: None,
+            therapies: None,
+            therapies_note: None,
             civic: None,
             disgenet: None,
             funding: None,
@@ -1237,6 +1542,7 @@ mod tests {
             uniprot_id: None,
             summary: None,
             gene_type: None,
+            species: None,
             aliases: Vec::new(),
             clinical_diseases: Vec::new(),
             clinical_drugs: Vec::new(),
@@ -1253,6 +1559,8 @@ mod tests {
             clingen: None,
             constraint: None,
             disgenet: None,
+            clinvar_summary: None,
+            tractability: None,
             funding: Some(crate::sources::nih_reporter::NihReporterFundingSection {
                 query: "ERBB2".to_string(),
                 fiscal_years: vec![2022, 2023, 2024, 2025, 2026],
@@ -1269,6 +1577,10 @@ mod tests {
                 }],
             }),
             funding_note: None,
+            key_papers: None,
+            dependency: None,
+            orthologs: None,
+            plugin_sections: Vec::new(),
         };
 
         let sources = gene_section_sources(&gene);
@@ -1303,6 +1615,10 @@ mod tests {
             prevalence_note: None,
             survival: None,
             survival_note: None,
+            epidemiology: None,
+            epidemiology_note: None,
+            therapies: None,
+            therapies_note: None,
             civic: None,
             disgenet: None,
             funding: None,
@@ -0,0 +1,321 @@
+//! ClinGen Allele Registry client for canonical allele IDs (CAids).
+
+use std::borrow::Cow;
+
+use serde::Deserialize;
+
+use crate::error::BioMcpError;
+
+const ALLELE_REGISTRY_BASE: &str = "https://reg.genome.network";
+const ALLELE_REGISTRY_API: &str = "clingen-allele-registry";
+const ALLELE_REGISTRY_BASE_ENV: &str = "BIOMCP_ALLELE_REGISTRY_BASE";
+
+/// Allele record as returned by `/allele/{CAid}` and `/allele?hgvs=`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CarAllele {
+    /// Registry URI, e.g. `http://reg.genome.network/allele/CA7200`.
+    #[serde(rename = "@id")]
+    pub uri: String,
+    #[serde(default)]
+    pub genomic_alleles: Vec<CarGenomicAllele>,
+    #[serde(default)]
+    pub transcript_alleles: Vec<CarTranscriptAllele>,
+    #[serde(default)]
+    pub external_records: CarExternalRecords,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CarGenomicAllele {
+    pub reference_genome: Option<String>,
+    #[serde(default)]
+    pub hgvs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CarTranscriptAllele {
+    pub gene_symbol: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CarExternalRecords {
+    #[serde(rename = "MyVariantInfo_hg19", default)]
+    pub myvariant_hg19: Vec<CarExternalId>,
+    #[serde(rename = "MyVariantInfo_hg38", default)]
+    pub myvariant_hg38: Vec<CarExternalId>,
+    #[serde(rename = "ClinVarVariations", default)]
+    pub clinvar_variations: Vec<CarClinVarVariation>,
+    #[serde(rename = "dbSNP", default)]
+    pub dbsnp: Vec<CarDbsnp>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CarExternalId {
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CarClinVarVariation {
+    pub variation_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CarDbsnp {
+    pub rs: Option<u64>,
+}
+
+impl CarAllele {
+    /// Canonical allele ID parsed from the registry URI (`CA7200`).
+    pub fn caid(&self) -> Option<&str> {
+        self.uri
+            .rsplit('/')
+            .next()
+            .map(str::trim)
+            .filter(|id| id.starts_with("CA") || id.starts_with("PA"))
+    }
+
+    /// MyVariant.info HGVS ID on hg19, the key BioMCP variant lookups use.
+    pub fn myvariant_hg19_id(&self) -> Option<&str> {
+        first_external_id(&self.external_records.myvariant_hg19)
+    }
+
+    /// MyVariant.info HGVS ID on hg38, used by `get variant --assembly grch38`.
+    pub fn myvariant_hg38_id(&self) -> Option<&str> {
+        first_external_id(&self.external_records.myvariant_hg38)
+    }
+
+    pub fn grch38_hgvs(&self) -> Option<&str> {
+        self.genomic_alleles
+            .iter()
+            .find(|allele| allele.reference_genome.as_deref() == Some("GRCh38"))
+            .and_then(|allele| {
+                allele
+                    .hgvs
+                    .iter()
+                    .find(|hgvs| hgvs.starts_with("NC_"))
+                    .or_else(|| allele.hgvs.first())
+            })
+            .map(String::as_str)
+    }
+
+    pub fn clinvar_variation_id(&self) -> Option<u64> {
+        self.external_records
+            .clinvar_variations
+            .iter()
+            .find_map(|record| record.variation_id)
+    }
+
+    pub fn dbsnp_rsid(&self) -> Option<String> {
+        self.external_records
+            .dbsnp
+            .iter()
+            .find_map(|record| record.rs)
+            .map(|rs| format!("rs{rs}"))
+    }
+
+    pub fn gene_symbol(&self) -> Option<&str> {
+        self.transcript_alleles
+            .iter()
+            .filter_map(|allele| allele.gene_symbol.as_deref())
+            .map(str::trim)
+            .find(|symbol| !symbol.is_empty())
+    }
+}
+
+fn first_external_id(records: &[CarExternalId]) -> Option<&str> {
+    records
+        .iter()
+        .filter_map(|record| record.id.as_deref())
+        .map(str::trim)
+        .find(|id| !id.is_empty())
+}
+
+pub struct AlleleRegistryClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+}
+
+impl AlleleRegistryClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(ALLELE_REGISTRY_BASE, ALLELE_REGISTRY_BASE_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base.as_ref().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    /// Fetches a registered allele by canonical ID; `None` when the registry has no such allele.
+    pub async fn get_allele(&self, caid: &str) -> Result<Option<CarAllele>, BioMcpError> {
+        let caid = caid.trim();
+        if caid.is_empty() || !caid.chars().all(|ch| ch.is_ascii_alphanumeric()) {
+            return Err(BioMcpError::InvalidArgument(
+                "ClinGen allele ID must look like CA7200".into(),
+            ));
+        }
+        let req = self.client.get(self.endpoint(&format!("allele/{caid}")));
+        self.get_json(req).await
+    }
+
+    /// Looks up the canonical allele for an HGVS expression without registering it.
+    pub async fn lookup_hgvs(&self, hgvs: &str) -> Result<Option<CarAllele>, BioMcpError> {
+        let hgvs = hgvs.trim();
+        if hgvs.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "HGVS expression is required for a ClinGen allele lookup".into(),
+            ));
+        }
+        let req = self
+            .client
+            .get(self.endpoint("allele"))
+            .query(&[("hgvs", hgvs)]);
+        self.get_json(req).await
+    }
+
+    async fn get_json(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+    ) -> Result<Option<CarAllele>, BioMcpError> {
+        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, ALLELE_REGISTRY_API).await?;
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: ALLELE_REGISTRY_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(
+            ALLELE_REGISTRY_API,
+            content_type.as_ref(),
+            &bytes,
+        )?;
+        serde_json::from_slice(&bytes)
+            .map(Some)
+            .map_err(|source| BioMcpError::ApiJson {
+                api: ALLELE_REGISTRY_API.to_string(),
+                source,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn tp53_allele() -> serde_json::Value {
+        serde_json::json!({
+            "@id": "http://reg.genome.network/allele/CA7200",
+            "type": "nucleotide",
+            "genomicAlleles": [
+                {
+                    "referenceGenome": "GRCh37",
+                    "hgvs": ["NC_000017.10:g.7579472G>C"]
+                },
+                {
+                    "referenceGenome": "GRCh38",
+                    "hgvs": ["CM000679.2:g.7676154G>C", "NC_000017.11:g.7676154G>C"]
+                }
+            ],
+            "transcriptAlleles": [{"geneSymbol": "TP53", "hgvs": ["NM_000546.6:c.215C>G"]}],
+            "externalRecords": {
+                "MyVariantInfo_hg19": [{"id": "chr17:g.7579472G>C"}],
+                "MyVariantInfo_hg38": [{"id": "chr17:g.7676154G>C"}],
+                "ClinVarVariations": [{"variationId": 12351}],
+                "dbSNP": [{"rs": 1042522}]
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn get_allele_parses_identifiers_and_cross_references() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/allele/CA7200"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(tp53_allele()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = AlleleRegistryClient::new_for_test(server.uri()).expect("client");
+        let allele = client
+            .get_allele("CA7200")
+            .await
+            .expect("lookup")
+            .expect("allele");
+        assert_eq!(allele.caid(), Some("CA7200"));
+        assert_eq!(allele.myvariant_hg19_id(), Some("chr17:g.7579472G>C"));
+        assert_eq!(allele.grch38_hgvs(), Some("NC_000017.11:g.7676154G>C"));
+        assert_eq!(allele.clinvar_variation_id(), Some(12351));
+        assert_eq!(allele.dbsnp_rsid().as_deref(), Some("rs1042522"));
+        assert_eq!(allele.gene_symbol(), Some("TP53"));
+    }
+
+    #[tokio::test]
+    async fn lookup_hgvs_sends_expression_and_maps_404_to_none() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/allele"))
+            .and(query_param("hgvs", "NC_000017.10:g.7579472G>C"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(tp53_allele()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/allele/CA999999999"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "errorType": "NotFound",
+                "description": "Allele not found"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = AlleleRegistryClient::new_for_test(server.uri()).expect("client");
+        let allele = client
+            .lookup_hgvs("NC_000017.10:g.7579472G>C")
+            .await
+            .expect("lookup")
+            .expect("allele");
+        assert_eq!(allele.caid(), Some("CA7200"));
+        assert!(
+            client
+                .get_allele("CA999999999")
+                .await
+                .expect("missing allele")
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_allele_rejects_malformed_ids() {
+        let client = AlleleRegistryClient::new_for_test("http://127.0.0.1:9".into()).unwrap();
+        let err = client.get_allele("CA7200/../x").await.unwrap_err();
+        assert!(matches!(err, BioMcpError::InvalidArgument(_)));
+    }
+}
@@ -23,10 +23,7 @@ pub struct AlphaGenomeClient {
 
 impl AlphaGenomeClient {
     pub async fn new() -> Result<Self, BioMcpError> {
-        let api_key = std::env::var(ALPHAGENOME_API_KEY_ENV)
-            .ok()
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
+        let api_key = crate::sources::tenant::api_key(ALPHAGENOME_API_KEY_ENV)
             .ok_or_else(|| {
                 BioMcpError::InvalidArgument(format!(
                     "{ALPHAGENOME_API_KEY_ENV} environment variable is required for `get variant <id> predict`."
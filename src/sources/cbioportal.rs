@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::error::BioMcpError;
@@ -88,6 +89,31 @@ impl CBioPortalClient {
         })
     }
 
+    async fn get_json_optional<T: serde::de::DeserializeOwned>(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+    ) -> Result<Option<T>, BioMcpError> {
+        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        let status = resp.status();
+        let bytes = crate::sources::read_limited_body(resp, CBIOPORTAL_API).await?;
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: CBIOPORTAL_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+        serde_json::from_slice(&bytes)
+            .map(Some)
+            .map_err(|source| BioMcpError::ApiJson {
+                api: CBIOPORTAL_API.to_string(),
+                source,
+            })
+    }
+
     async fn post_json<T: serde::de::DeserializeOwned, B: Serialize>(
         &self,
         req: reqwest_middleware::RequestBuilder,
@@ -279,6 +305,95 @@ impl CBioPortalClient {
             cancer_distribution: dist,
         })
     }
+
+    /// Lists public cancer studies, optionally narrowed by cBioPortal's keyword match.
+    pub async fn list_cancer_studies(
+        &self,
+        keyword: Option<&str>,
+    ) -> Result<Vec<CBioCancerStudy>, BioMcpError> {
+        let url = self.endpoint("studies");
+        let mut params = vec![
+            ("projection", "DETAILED"),
+            ("pageSize", "10000"),
+            ("pageNumber", "0"),
+        ];
+        if let Some(keyword) = keyword.map(str::trim).filter(|v| !v.is_empty()) {
+            params.push(("keyword", keyword));
+        }
+        self.get_json(self.client.get(&url).query(&params)).await
+    }
+
+    pub async fn get_cancer_study(
+        &self,
+        study_id: &str,
+    ) -> Result<Option<CBioCancerStudy>, BioMcpError> {
+        let url = self.endpoint(&format!("studies/{}", study_id.trim()));
+        self.get_json_optional(self.client.get(&url).query(&[("projection", "DETAILED")]))
+            .await
+    }
+
+    /// Per-gene mutated-sample counts across the study's mutation profile.
+    pub async fn mutated_genes(&self, study_id: &str) -> Result<Vec<CBioMutatedGene>, BioMcpError> {
+        let url = self.endpoint("mutated-genes/fetch");
+        let body = CBioStudyViewFilter {
+            study_ids: vec![study_id.trim().to_string()],
+        };
+        self.post_json(self.client.post(&url), &body).await
+    }
+
+    /// Collects protein positions of somatic mutations in the configured study.
+    pub async fn get_protein_changes(
+        &self,
+        gene: &str,
+        max_mutations: usize,
+    ) -> Result<CBioProteinChangeSummary, BioMcpError> {
+        let study_id = configured_study_id();
+        let sample_list_id = configured_sample_list_id();
+        let mutation_profile_id = configured_mutation_profile_id();
+        let entrez = self.resolve_entrez_gene_id(gene).await?.to_string();
+        let url = self.endpoint(&format!(
+            "molecular-profiles/{mutation_profile_id}/mutations"
+        ));
+
+        let page_size: usize = 500;
+        let page_size_s = page_size.to_string();
+        let mut changes = Vec::new();
+        let mut mutation_count = 0_usize;
+        for page_number in 0..max_mutations.div_ceil(page_size) {
+            let page_number_s = page_number.to_string();
+            let resp: Vec<CBioMutationProteinChange> = self
+                .get_json(self.client.get(&url).query(&[
+                    ("sampleListId", sample_list_id.as_str()),
+                    ("entrezGeneId", entrez.as_str()),
+                    ("projection", "SUMMARY"),
+                    ("pageSize", page_size_s.as_str()),
+                    ("pageNumber", page_number_s.as_str()),
+                ]))
+                .await?;
+            let resp_len = resp.len();
+            mutation_count += resp_len;
+            changes.extend(resp.into_iter().filter_map(|row| {
+                let position = u32::try_from(row.protein_pos_start?).ok()?;
+                let protein_change = row
+                    .protein_change
+                    .map(|v| v.trim().to_string())
+                    .filter(|v| !v.is_empty())?;
+                (position > 0).then_some(CBioProteinChange {
+                    position,
+                    protein_change,
+                })
+            }));
+            if resp_len < page_size {
+                break;
+            }
+        }
+
+        Ok(CBioProteinChangeSummary {
+            study_id,
+            mutation_count,
+            changes,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -293,12 +408,57 @@ struct CBioStudy {
     sequenced_sample_count: Option<i32>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CBioCancerType {
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CBioCancerStudy {
+    pub study_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub cancer_type_id: Option<String>,
+    pub cancer_type: Option<CBioCancerType>,
+    pub all_sample_count: Option<u32>,
+    pub sequenced_sample_count: Option<u32>,
+    pub cna_sample_count: Option<u32>,
+    pub pmid: Option<String>,
+    pub citation: Option<String>,
+    pub reference_genome: Option<String>,
+    pub import_date: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CBioStudyViewFilter {
+    study_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CBioMutatedGene {
+    pub hugo_gene_symbol: String,
+    pub number_of_altered_cases: Option<u32>,
+    pub number_of_profiled_cases: Option<u32>,
+    pub total_count: Option<u32>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct CBioMutation {
     sample_id: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CBioMutationProteinChange {
+    protein_change: Option<String>,
+    protein_pos_start: Option<i32>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct CBioClinicalDataSingleStudyFilter {
@@ -313,7 +473,7 @@ struct CBioClinicalData {
     value: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CancerFrequency {
     pub cancer_type: String,
     pub frequency: f64,
@@ -332,10 +492,23 @@ pub struct CBioMutationSummary {
     pub cancer_distribution: Vec<CancerFrequency>,
 }
 
+#[derive(Debug, Clone)]
+pub struct CBioProteinChange {
+    pub position: u32,
+    pub protein_change: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CBioProteinChangeSummary {
+    pub study_id: String,
+    pub mutation_count: usize,
+    pub changes: Vec<CBioProteinChange>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{method, path, query_param};
+    use wiremock::matchers::{body_json, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[tokio::test]
@@ -372,4 +545,85 @@ mod tests {
         assert!(msg.contains("cbioportal"));
         assert!(msg.contains("500"));
     }
+
+    #[tokio::test]
+    async fn protein_changes_skip_rows_without_positions() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/genes"))
+            .and(query_param("keyword", "EGFR"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"entrezGeneId": 1956, "hugoGeneSymbol": "EGFR"}
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/molecular-profiles/{DEFAULT_MUTATION_PROFILE_ID}/mutations"
+            )))
+            .and(query_param("entrezGeneId", "1956"))
+            .and(query_param("projection", "SUMMARY"))
+            .and(query_param("pageNumber", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"proteinChange": "L858R", "proteinPosStart": 858},
+                {"proteinChange": "X1_splice", "proteinPosStart": -1},
+                {"proteinChange": "E746_A750del", "proteinPosStart": 746},
+                {"proteinChange": null, "proteinPosStart": null}
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CBioPortalClient::new_for_test(server.uri()).unwrap();
+        let summary = client.get_protein_changes("EGFR", 2000).await.unwrap();
+        assert_eq!(summary.mutation_count, 4);
+        let positions = summary
+            .changes
+            .iter()
+            .map(|row| (row.position, row.protein_change.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![(858, "L858R"), (746, "E746_A750del")]);
+    }
+
+    #[tokio::test]
+    async fn cancer_study_lookup_maps_404_to_none_and_posts_study_filter() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/studies/missing_study"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Study not found: missing_study"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/mutated-genes/fetch"))
+            .and(body_json(serde_json::json!({"studyIds": ["luad_tcga"]})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "hugoGeneSymbol": "TP53",
+                    "entrezGeneId": 7157,
+                    "numberOfAlteredCases": 260,
+                    "numberOfProfiledCases": 507,
+                    "totalCount": 289
+                }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CBioPortalClient::new_for_test(server.uri()).unwrap();
+        assert!(
+            client
+                .get_cancer_study("missing_study")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let genes = client.mutated_genes("luad_tcga").await.unwrap();
+        assert_eq!(genes[0].hugo_gene_symbol, "TP53");
+        assert_eq!(genes[0].number_of_altered_cases, Some(260));
+        assert_eq!(genes[0].number_of_profiled_cases, Some(507));
+    }
 }
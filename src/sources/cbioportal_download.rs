@@ -163,7 +163,9 @@ fn datahub_client(
         builder = builder.timeout(timeout);
     }
     let client = builder.build().map_err(BioMcpError::HttpClientInit)?;
-    Ok(reqwest_middleware::ClientBuilder::new(client).build())
+    Ok(reqwest_middleware::ClientBuilder::new(client)
+        .with(crate::sources::concurrency::ConcurrencyLimitMiddleware::new())
+        .build())
 }
 
 fn unique_temp_path(parent: &Path, prefix: &str) -> Result<PathBuf, BioMcpError> {
@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 use std::collections::HashSet;
 
+use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
@@ -165,30 +166,57 @@ impl CivicClient {
             .await
     }
 
+    /// Accepted evidence whose molecular profile name contains `molecular_profile_name`
+    /// (a gene symbol matches all of its variants) and whose disease matches `disease_name`.
+    pub async fn by_molecular_profile_and_disease(
+        &self,
+        molecular_profile_name: &str,
+        disease_name: &str,
+        limit: usize,
+    ) -> Result<CivicContext, BioMcpError> {
+        self.fetch_context(
+            CivicFilter::MolecularProfileAndDisease(molecular_profile_name, disease_name),
+            limit,
+        )
+        .await
+    }
+
     async fn fetch_context(
         &self,
         filter: CivicFilter<'_>,
         limit: usize,
     ) -> Result<CivicContext, BioMcpError> {
-        let (variable_name, variable_value) = match filter {
-            CivicFilter::MolecularProfile(value) => (
+        let filters = match filter {
+            CivicFilter::MolecularProfile(value) => vec![(
                 "molecularProfileName",
                 required_query_value("molecular profile name", value)?,
-            ),
+            )],
             CivicFilter::Therapy(value) => {
-                ("therapyName", required_query_value("therapy name", value)?)
+                vec![("therapyName", required_query_value("therapy name", value)?)]
             }
             CivicFilter::Disease(value) => {
-                ("diseaseName", required_query_value("disease name", value)?)
+                vec![("diseaseName", required_query_value("disease name", value)?)]
             }
+            CivicFilter::MolecularProfileAndDisease(profile, disease) => vec![
+                (
+                    "molecularProfileName",
+                    required_query_value("molecular profile name", profile)?,
+                ),
+                (
+                    "diseaseName",
+                    required_query_value("disease name", disease)?,
+                ),
+            ],
         };
         let first = limit.clamp(1, 25);
         let mut variables = serde_json::Map::new();
         variables.insert("first".to_string(), serde_json::json!(first));
-        variables.insert(
-            variable_name.to_string(),
-            serde_json::Value::String(variable_value),
-        );
+        for (variable_name, variable_value) in filters {
+            variables.insert(
+                variable_name.to_string(),
+                serde_json::Value::String(variable_value),
+            );
+        }
 
         let body = GraphQlRequest {
             query: CIVIC_CONTEXT_QUERY,
@@ -234,7 +262,7 @@ impl CivicClient {
     }
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct CivicContext {
     pub evidence_total_count: usize,
     pub assertion_total_count: usize,
@@ -244,7 +272,7 @@ pub struct CivicContext {
     pub assertions: Vec<CivicAssertion>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CivicEvidenceItem {
     pub id: i64,
     pub name: String,
@@ -292,7 +320,7 @@ impl CivicEvidenceItem {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CivicAssertion {
     pub id: i64,
     pub name: String,
@@ -378,6 +406,7 @@ enum CivicFilter<'a> {
     MolecularProfile(&'a str),
     Therapy(&'a str),
     Disease(&'a str),
+    MolecularProfileAndDisease(&'a str, &'a str),
 }
 
 #[derive(Debug, Serialize)]
@@ -489,7 +518,7 @@ struct CivicCountConnection {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{method, path};
+    use wiremock::matchers::{body_string_contains, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[test]
@@ -573,4 +602,31 @@ mod tests {
         let err = client.by_therapy("vemurafenib", 5).await.unwrap_err();
         assert!(matches!(err, BioMcpError::Api { .. }));
     }
+
+    #[tokio::test]
+    async fn by_molecular_profile_and_disease_sends_both_filters() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("\"molecularProfileName\":\"BRAF\""))
+            .and(body_string_contains("\"diseaseName\":\"melanoma\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "evidenceItems": {"totalCount": 40, "nodes": []},
+                    "assertions": {"totalCount": 3, "nodes": []}
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CivicClient::new_for_test(server.uri()).unwrap();
+        let out = client
+            .by_molecular_profile_and_disease("BRAF", " melanoma ", 10)
+            .await
+            .unwrap();
+        assert_eq!(out.evidence_total_count, 40);
+        assert_eq!(out.assertion_total_count, 3);
+    }
 }
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
+use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
@@ -160,7 +161,7 @@ impl ClinGenClient {
     }
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct GeneClinGen {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub validity: Vec<ClinGenValidity>,
@@ -170,7 +171,7 @@ pub struct GeneClinGen {
     pub triplosensitivity: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ClinGenValidity {
     pub disease: String,
     pub classification: String,
@@ -5,12 +5,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::BioMcpError;
 
-const CTGOV_BASE: &str = "https://clinicaltrials.gov/api/v2";
+pub(crate) const CTGOV_BASE: &str = "https://clinicaltrials.gov/api/v2";
 const CTGOV_API: &str = "clinicaltrials.gov";
-const CTGOV_BASE_ENV: &str = "BIOMCP_CTGOV_BASE";
+pub(crate) const CTGOV_BASE_ENV: &str = "BIOMCP_CTGOV_BASE";
 
 const CTGOV_SEARCH_FIELDS: &str = "NCTId,BriefTitle,OverallStatus,Phase,StudyType,Condition,InterventionName,LeadSponsorName,EnrollmentCount,BriefSummary,StartDate,CompletionDate,MinimumAge,MaximumAge";
 
+const CTGOV_REGISTRY_LINK_FIELDS: &str = "NCTId,OrgStudyId,SecondaryId";
+const CTGOV_REGISTRY_LINK_MAX_PAGE: usize = 100;
+
 const CTGOV_GET_FIELDS_BASE: &[&str] = &[
     "NCTId",
     "BriefTitle",
@@ -28,7 +31,7 @@ const CTGOV_GET_FIELDS_BASE: &[&str] = &[
     "MaximumAge",
 ];
 
-const CTGOV_GET_FIELDS_ELIGIBILITY: &[&str] = &["EligibilityCriteria"];
+const CTGOV_GET_FIELDS_ELIGIBILITY: &[&str] = &["EligibilityCriteria", "Sex"];
 
 const CTGOV_GET_FIELDS_LOCATIONS: &[&str] = &[
     "LocationFacility",
@@ -69,6 +72,13 @@ const CTGOV_GET_FIELDS_ARMS: &[&str] = &[
 const CTGOV_GET_FIELDS_REFERENCES: &[&str] =
     &["ReferencePMID", "ReferenceType", "ReferenceCitation"];
 
+const CTGOV_GET_FIELDS_RESULTS: &[&str] = &[
+    "HasResults",
+    "BaselineCharacteristicsModule",
+    "OutcomeMeasuresModule",
+    "AdverseEventsModule",
+];
+
 #[derive(Clone)]
 pub struct ClinicalTrialsClient {
     client: reqwest_middleware::ClientWithMiddleware,
@@ -102,7 +112,8 @@ fn build_get_fields(sections: &[String]) -> String {
             "locations" => fields.extend_from_slice(CTGOV_GET_FIELDS_LOCATIONS),
             "outcomes" => fields.extend_from_slice(CTGOV_GET_FIELDS_OUTCOMES),
             "arms" => fields.extend_from_slice(CTGOV_GET_FIELDS_ARMS),
-            "references" => fields.extend_from_slice(CTGOV_GET_FIELDS_REFERENCES),
+            "references" | "publications" => fields.extend_from_slice(CTGOV_GET_FIELDS_REFERENCES),
+            "results" => fields.extend_from_slice(CTGOV_GET_FIELDS_RESULTS),
             "all" => add_all_sections = true,
             _ => {}
         }
@@ -114,6 +125,7 @@ fn build_get_fields(sections: &[String]) -> String {
         fields.extend_from_slice(CTGOV_GET_FIELDS_OUTCOMES);
         fields.extend_from_slice(CTGOV_GET_FIELDS_ARMS);
         fields.extend_from_slice(CTGOV_GET_FIELDS_REFERENCES);
+        fields.extend_from_slice(CTGOV_GET_FIELDS_RESULTS);
     }
 
     fields.sort_unstable();
@@ -247,6 +259,55 @@ impl ClinicalTrialsClient {
         self.get_json(req).await
     }
 
+    /// Finds NCT records that list any of `ids` as an org-study or secondary identifier.
+    pub async fn registry_links(
+        &self,
+        ids: &[String],
+    ) -> Result<Vec<CtGovRegistryLink>, BioMcpError> {
+        let terms = ids
+            .iter()
+            .map(|id| id.trim().replace('"', ""))
+            .filter(|id| !id.is_empty())
+            .map(|id| format!("\"{id}\""))
+            .collect::<Vec<_>>();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+        let terms = terms.join(" OR ");
+        let query_term = format!("AREA[SecondaryId]({terms}) OR AREA[OrgStudyId]({terms})");
+        let page_size = (ids.len() * 2)
+            .min(CTGOV_REGISTRY_LINK_MAX_PAGE)
+            .to_string();
+
+        let req = self.client.get(self.endpoint("studies")).query(&[
+            ("query.term", query_term.as_str()),
+            ("pageSize", page_size.as_str()),
+            ("fields", CTGOV_REGISTRY_LINK_FIELDS),
+        ]);
+        let resp: CtGovRegistryLinkResponse = self.get_json(req).await?;
+
+        Ok(resp
+            .studies
+            .into_iter()
+            .filter_map(|study| {
+                let module = study.protocol_section?.identification_module?;
+                let nct_id = module
+                    .nct_id
+                    .map(|v| v.trim().to_string())
+                    .filter(|v| !v.is_empty())?;
+                let other_ids = module
+                    .org_study_id_info
+                    .into_iter()
+                    .chain(module.secondary_id_infos)
+                    .filter_map(|info| info.id)
+                    .map(|id| id.trim().to_string())
+                    .filter(|id| !id.is_empty())
+                    .collect();
+                Some(CtGovRegistryLink { nct_id, other_ids })
+            })
+            .collect())
+    }
+
     pub async fn get(&self, nct_id: &str, sections: &[String]) -> Result<CtGovStudy, BioMcpError> {
         let url = self.endpoint(&format!("studies/{nct_id}"));
         let fields = build_get_fields(sections);
@@ -263,7 +324,12 @@ impl ClinicalTrialsClient {
         }
 
         let status = resp.status();
-        let bytes = crate::sources::read_limited_body(resp, CTGOV_API).await?;
+        let bytes = crate::sources::read_limited_body_with_limit(
+            resp,
+            CTGOV_API,
+            crate::sources::LARGE_DOCUMENT_MAX_BODY_BYTES,
+        )
+        .await?;
         if !status.is_success() {
             let excerpt = crate::sources::body_excerpt(&bytes);
             return Err(BioMcpError::Api {
@@ -279,6 +345,45 @@ impl ClinicalTrialsClient {
     }
 }
 
+/// NCT record plus the other registry identifiers it lists.
+#[derive(Debug, Clone)]
+pub struct CtGovRegistryLink {
+    pub nct_id: String,
+    pub other_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CtGovRegistryLinkResponse {
+    #[serde(default)]
+    studies: Vec<CtGovRegistryLinkStudy>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CtGovRegistryLinkStudy {
+    protocol_section: Option<CtGovRegistryLinkSection>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CtGovRegistryLinkSection {
+    identification_module: Option<CtGovRegistryLinkIdentification>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CtGovRegistryLinkIdentification {
+    nct_id: Option<String>,
+    org_study_id_info: Option<CtGovIdInfo>,
+    #[serde(default)]
+    secondary_id_infos: Vec<CtGovIdInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CtGovIdInfo {
+    id: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CtGovSearchResponse {
@@ -292,6 +397,10 @@ pub struct CtGovSearchResponse {
 #[serde(rename_all = "camelCase")]
 pub struct CtGovStudy {
     pub protocol_section: Option<CtGovProtocolSection>,
+    #[serde(default)]
+    pub has_results: Option<bool>,
+    #[serde(default)]
+    pub results_section: Option<CtGovResultsSection>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -403,6 +512,8 @@ pub struct CtGovEligibilityModule {
     pub eligibility_criteria: Option<String>,
     pub minimum_age: Option<String>,
     pub maximum_age: Option<String>,
+    /// `ALL`, `FEMALE`, or `MALE`.
+    pub sex: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -475,6 +586,176 @@ pub struct CtGovReferencesModule {
     pub references: Vec<CtGovReference>,
 }
 
+/// Posted results; present only for studies with `hasResults`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovResultsSection {
+    pub baseline_characteristics_module: Option<CtGovBaselineModule>,
+    pub outcome_measures_module: Option<CtGovOutcomeMeasuresModule>,
+    pub adverse_events_module: Option<CtGovAdverseEventsModule>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovResultGroup {
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovResultDenom {
+    /// Usually `Participants`.
+    pub units: Option<String>,
+    #[serde(default)]
+    pub counts: Vec<CtGovResultCount>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovResultCount {
+    pub group_id: Option<String>,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovResultClass {
+    pub title: Option<String>,
+    #[serde(default)]
+    pub categories: Vec<CtGovResultCategory>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovResultCategory {
+    pub title: Option<String>,
+    #[serde(default)]
+    pub measurements: Vec<CtGovResultMeasurement>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovResultMeasurement {
+    pub group_id: Option<String>,
+    pub value: Option<String>,
+    pub spread: Option<String>,
+    pub lower_limit: Option<String>,
+    pub upper_limit: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovBaselineModule {
+    pub population_description: Option<String>,
+    #[serde(default)]
+    pub groups: Vec<CtGovResultGroup>,
+    #[serde(default)]
+    pub denoms: Vec<CtGovResultDenom>,
+    #[serde(default)]
+    pub measures: Vec<CtGovBaselineMeasure>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovBaselineMeasure {
+    pub title: Option<String>,
+    pub param_type: Option<String>,
+    pub dispersion_type: Option<String>,
+    pub unit_of_measure: Option<String>,
+    #[serde(default)]
+    pub classes: Vec<CtGovResultClass>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovOutcomeMeasuresModule {
+    #[serde(default)]
+    pub outcome_measures: Vec<CtGovOutcomeMeasure>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovOutcomeMeasure {
+    /// `PRIMARY`, `SECONDARY`, `OTHER_PRE_SPECIFIED`, or `POST_HOC`.
+    #[serde(rename = "type")]
+    pub outcome_type: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub time_frame: Option<String>,
+    pub param_type: Option<String>,
+    pub dispersion_type: Option<String>,
+    pub unit_of_measure: Option<String>,
+    #[serde(default)]
+    pub groups: Vec<CtGovResultGroup>,
+    #[serde(default)]
+    pub denoms: Vec<CtGovResultDenom>,
+    #[serde(default)]
+    pub classes: Vec<CtGovResultClass>,
+    #[serde(default)]
+    pub analyses: Vec<CtGovOutcomeAnalysis>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovOutcomeAnalysis {
+    #[serde(default)]
+    pub group_ids: Vec<String>,
+    pub param_type: Option<String>,
+    pub param_value: Option<String>,
+    pub ci_pct_value: Option<String>,
+    pub ci_lower_limit: Option<String>,
+    pub ci_upper_limit: Option<String>,
+    pub p_value: Option<String>,
+    pub statistical_method: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovAdverseEventsModule {
+    /// Percent threshold above which other (non-serious) events were reported.
+    pub frequency_threshold: Option<String>,
+    pub time_frame: Option<String>,
+    #[serde(default)]
+    pub event_groups: Vec<CtGovAdverseEventGroup>,
+    #[serde(default)]
+    pub serious_events: Vec<CtGovAdverseEvent>,
+    #[serde(default)]
+    pub other_events: Vec<CtGovAdverseEvent>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovAdverseEventGroup {
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub deaths_num_affected: Option<u32>,
+    pub deaths_num_at_risk: Option<u32>,
+    pub serious_num_affected: Option<u32>,
+    pub serious_num_at_risk: Option<u32>,
+    pub other_num_affected: Option<u32>,
+    pub other_num_at_risk: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovAdverseEvent {
+    pub term: Option<String>,
+    pub organ_system: Option<String>,
+    #[serde(default)]
+    pub stats: Vec<CtGovAdverseEventStats>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtGovAdverseEventStats {
+    pub group_id: Option<String>,
+    pub num_events: Option<u32>,
+    pub num_affected: Option<u32>,
+    pub num_at_risk: Option<u32>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -521,6 +802,55 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn build_get_fields_adds_results_modules() {
+        let fields = build_get_fields(&["results".to_string()]);
+        for field in CTGOV_GET_FIELDS_RESULTS {
+            assert!(fields.split(',').any(|value| value == *field), "{field}");
+        }
+        assert!(!build_get_fields(&["outcomes".to_string()]).contains("HasResults"));
+        assert!(build_get_fields(&["all".to_string()]).contains("AdverseEventsModule"));
+    }
+
+    #[tokio::test]
+    async fn registry_links_reads_secondary_and_org_study_ids() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/studies"))
+            .and(query_param(
+                "query.term",
+                "AREA[SecondaryId](\"2023-503456-12-00\") OR AREA[OrgStudyId](\"2023-503456-12-00\")",
+            ))
+            .and(query_param("fields", CTGOV_REGISTRY_LINK_FIELDS))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "studies": [{
+                    "protocolSection": {
+                        "identificationModule": {
+                            "nctId": "NCT05123456",
+                            "orgStudyIdInfo": {"id": "ABC-001"},
+                            "secondaryIdInfos": [{"id": "EU CT 2023-503456-12-00"}]
+                        }
+                    }
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = ClinicalTrialsClient::new_for_test(server.uri()).unwrap();
+        let links = client
+            .registry_links(&["2023-503456-12-00".to_string()])
+            .await
+            .unwrap();
+
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].nct_id, "NCT05123456");
+        assert_eq!(
+            links[0].other_ids,
+            vec!["ABC-001", "EU CT 2023-503456-12-00"]
+        );
+    }
+
     #[tokio::test]
     async fn search_includes_geo_filter_when_requested() {
         let server = MockServer::start().await;
@@ -0,0 +1,292 @@
+use std::borrow::Cow;
+
+use roxmltree::{Document, Node, ParsingOptions};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+
+const CLINVAR_BASE: &str = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils";
+const CLINVAR_API: &str = "clinvar-eutils";
+const CLINVAR_BASE_ENV: &str = "BIOMCP_CLINVAR_BASE";
+
+/// Classification elements in the order ClinVar has used them: germline,
+/// somatic clinical impact, oncogenicity, then the pre-2024 `Description`.
+const CLASSIFICATION_TAGS: &[&str] = &[
+    "GermlineClassification",
+    "SomaticClinicalImpact",
+    "OncogenicityClassification",
+    "Description",
+];
+
+/// One submitter's interpretation (SCV record) of a ClinVar variation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ClinVarSubmission {
+    pub submitter: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accession: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_evaluated: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct ClinVarClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+    api_key: Option<String>,
+}
+
+impl ClinVarClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(CLINVAR_BASE, CLINVAR_BASE_ENV),
+            api_key: crate::sources::ncbi_api_key(),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String, api_key: Option<String>) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+            api_key: api_key
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty()),
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base.as_ref().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    /// Lists the submitter records behind a ClinVar variation ID.
+    ///
+    /// The esummary record only carries SCV accessions, so this reads the VCV
+    /// XML from efetch, which has the classification, review status, and
+    /// last-evaluated date of every submission.
+    pub async fn submissions(
+        &self,
+        variation_id: &str,
+    ) -> Result<Vec<ClinVarSubmission>, BioMcpError> {
+        let variation_id = variation_id.trim();
+        let variation_id = variation_id
+            .strip_prefix("VCV")
+            .unwrap_or(variation_id)
+            .trim_start_matches('0');
+        if variation_id.is_empty() || !variation_id.chars().all(|ch| ch.is_ascii_digit()) {
+            return Err(BioMcpError::InvalidArgument(
+                "ClinVar variation ID must be numeric (e.g. 13961).".into(),
+            ));
+        }
+
+        let req = self.client.get(self.endpoint("efetch.fcgi")).query(&[
+            ("db", "clinvar"),
+            ("rettype", "vcv"),
+            ("is_variationid", "true"),
+            ("id", variation_id),
+        ]);
+        let req = crate::sources::append_ncbi_api_key(req, self.api_key.as_deref());
+        let resp = crate::sources::apply_cache_mode_with_auth(req, self.api_key.is_some())
+            .send()
+            .await?;
+        let status = resp.status();
+        let bytes = crate::sources::read_limited_body_with_limit(
+            resp,
+            CLINVAR_API,
+            crate::sources::LARGE_DOCUMENT_MAX_BODY_BYTES,
+        )
+        .await?;
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: CLINVAR_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+        parse_submissions(&String::from_utf8_lossy(&bytes))
+    }
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, tag: &str) -> Option<Node<'a, 'input>> {
+    node.children()
+        .find(|child| child.is_element() && child.has_tag_name(tag))
+}
+
+fn clean(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn parse_submission(assertion: Node<'_, '_>) -> Option<ClinVarSubmission> {
+    let accession = child(assertion, "ClinVarAccession");
+    let submitter =
+        clean(accession.and_then(|node| node.attribute("SubmitterName"))).or_else(|| {
+            clean(
+                child(assertion, "ClinVarSubmissionID")
+                    .and_then(|node| node.attribute("submitter")),
+            )
+        })?;
+    let classification =
+        child(assertion, "Classification").or_else(|| child(assertion, "Interpretation"));
+    let label = classification.and_then(|node| {
+        CLASSIFICATION_TAGS
+            .iter()
+            .find_map(|tag| clean(child(node, tag).and_then(|value| value.text())))
+    });
+    let review_status = classification
+        .and_then(|node| child(node, "ReviewStatus"))
+        .or_else(|| child(assertion, "ReviewStatus"))
+        .and_then(|node| clean(node.text()));
+
+    Some(ClinVarSubmission {
+        submitter,
+        accession: clean(accession.and_then(|node| node.attribute("Accession"))),
+        classification: label,
+        review_status,
+        last_evaluated: clean(classification.and_then(|node| node.attribute("DateLastEvaluated"))),
+    })
+}
+
+fn parse_submissions(xml: &str) -> Result<Vec<ClinVarSubmission>, BioMcpError> {
+    let options = ParsingOptions {
+        allow_dtd: true,
+        ..ParsingOptions::default()
+    };
+    let doc = Document::parse_with_options(xml, options).map_err(|err| BioMcpError::Api {
+        api: CLINVAR_API.to_string(),
+        message: format!("Invalid VCV XML: {err}"),
+    })?;
+    if let Some(error) = doc
+        .descendants()
+        .find(|node| node.is_element() && node.has_tag_name("error"))
+        .and_then(|node| clean(node.text()))
+    {
+        return Err(BioMcpError::Api {
+            api: CLINVAR_API.to_string(),
+            message: error,
+        });
+    }
+    Ok(doc
+        .descendants()
+        .filter(|node| node.is_element() && node.has_tag_name("ClinicalAssertion"))
+        .filter_map(parse_submission)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const VCV_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ClinVarResult-Set>
+  <VariationArchive VariationID="13961" Accession="VCV000013961">
+    <ClassifiedRecord>
+      <ClinicalAssertionList>
+        <ClinicalAssertion ID="20155">
+          <ClinVarSubmissionID localKey="164757.0001" submitter="OMIM"/>
+          <ClinVarAccession Accession="SCV000034778" Type="SCV" Version="3" SubmitterName="OMIM"/>
+          <Classification DateLastEvaluated="2016-01-01">
+            <ReviewStatus>no assertion criteria provided</ReviewStatus>
+            <GermlineClassification>Pathogenic</GermlineClassification>
+          </Classification>
+        </ClinicalAssertion>
+        <ClinicalAssertion ID="2964412">
+          <ClinVarAccession Accession="SCV004807015" Type="SCV" Version="1" SubmitterName="Cancer Genomics Lab"/>
+          <Classification>
+            <ReviewStatus>criteria provided, single submitter</ReviewStatus>
+            <SomaticClinicalImpact>Tier I - Strong</SomaticClinicalImpact>
+          </Classification>
+        </ClinicalAssertion>
+        <ClinicalAssertion ID="77">
+          <ClinVarSubmissionID localKey="x" submitter="Legacy Lab"/>
+          <ReviewStatus>criteria provided, single submitter</ReviewStatus>
+          <Interpretation DateLastEvaluated="2012-05-04">
+            <Description>Likely pathogenic</Description>
+          </Interpretation>
+        </ClinicalAssertion>
+      </ClinicalAssertionList>
+    </ClassifiedRecord>
+  </VariationArchive>
+</ClinVarResult-Set>"#;
+
+    #[test]
+    fn parse_submissions_reads_current_and_legacy_assertions() {
+        let rows = parse_submissions(VCV_XML).expect("parse");
+        assert_eq!(
+            rows,
+            vec![
+                ClinVarSubmission {
+                    submitter: "OMIM".into(),
+                    accession: Some("SCV000034778".into()),
+                    classification: Some("Pathogenic".into()),
+                    review_status: Some("no assertion criteria provided".into()),
+                    last_evaluated: Some("2016-01-01".into()),
+                },
+                ClinVarSubmission {
+                    submitter: "Cancer Genomics Lab".into(),
+                    accession: Some("SCV004807015".into()),
+                    classification: Some("Tier I - Strong".into()),
+                    review_status: Some("criteria provided, single submitter".into()),
+                    last_evaluated: None,
+                },
+                ClinVarSubmission {
+                    submitter: "Legacy Lab".into(),
+                    accession: None,
+                    classification: Some("Likely pathogenic".into()),
+                    review_status: Some("criteria provided, single submitter".into()),
+                    last_evaluated: Some("2012-05-04".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_submissions_surfaces_eutils_error_documents() {
+        let err = parse_submissions(
+            "<eFetchResult><ERROR>ignored</ERROR><error>ID list is empty</error></eFetchResult>",
+        )
+        .expect_err("error document");
+        assert!(err.to_string().contains("ID list is empty"));
+    }
+
+    #[tokio::test]
+    async fn submissions_requests_vcv_xml_by_variation_id() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/efetch.fcgi"))
+            .and(query_param("db", "clinvar"))
+            .and(query_param("rettype", "vcv"))
+            .and(query_param("is_variationid", "true"))
+            .and(query_param("id", "13961"))
+            .and(query_param("api_key", "test-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(VCV_XML))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = ClinVarClient::new_for_test(server.uri(), Some("test-key".into())).unwrap();
+        let rows = client.submissions("VCV000013961").await.unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].submitter, "OMIM");
+    }
+
+    #[tokio::test]
+    async fn submissions_rejects_non_numeric_ids() {
+        let client = ClinVarClient::new_for_test("http://127.0.0.1:9".into(), None).unwrap();
+        let err = client.submissions("rs113488022").await.unwrap_err();
+        assert!(matches!(err, BioMcpError::InvalidArgument(_)));
+    }
+}
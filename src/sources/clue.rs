@@ -0,0 +1,588 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use http_cache_reqwest::CacheMode;
+use reqwest::header::CONTENT_TYPE;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+
+// CLUE (Connectivity Map / LINCS L1000) query API
+// Docs: https://clue.io/api
+const CLUE_BASE: &str = "https://api.clue.io/api";
+const CLUE_API: &str = "clue";
+const CLUE_BASE_ENV: &str = "BIOMCP_CLUE_BASE";
+const CLUE_API_KEY_ENV: &str = "CLUE_API_KEY";
+const CLUE_DOCS_URL: &str = "https://clue.io/api";
+const CLUE_QUERY_TOOL: &str = "sig_gutc_tool";
+const CLUE_POLL_INTERVAL: Duration = Duration::from_secs(10);
+const MAX_RESULT_TGZ_BYTES: usize = 64 * 1024 * 1024;
+const MAX_ARCHIVE_ENTRY_BYTES: u64 = 32 * 1024 * 1024;
+
+/// CLUE rejects query signatures outside 10-150 genes per direction.
+pub(crate) const CLUE_MIN_GENES: usize = 10;
+pub(crate) const CLUE_MAX_GENES: usize = 150;
+
+/// Result files in preference order: the perturbagen-level summary collapses
+/// cell lines and doses, `query_result.gct` lists individual signatures.
+const RESULT_FILES: &[&str] = &["pert_id_summary.gct", "query_result.gct"];
+
+#[derive(Clone)]
+pub struct ClueClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+    api_key: Option<String>,
+    poll_interval: Duration,
+}
+
+impl ClueClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(CLUE_BASE, CLUE_BASE_ENV),
+            api_key: crate::sources::tenant::api_key(CLUE_API_KEY_ENV),
+            poll_interval: CLUE_POLL_INTERVAL,
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String, api_key: Option<String>) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+            api_key: api_key
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty()),
+            poll_interval: Duration::from_millis(1),
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base.as_ref().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    pub(crate) fn require_api_key(&self) -> Result<&str, BioMcpError> {
+        self.api_key
+            .as_deref()
+            .ok_or_else(|| BioMcpError::ApiKeyRequired {
+                api: CLUE_API.to_string(),
+                env_var: CLUE_API_KEY_ENV.to_string(),
+                docs_url: CLUE_DOCS_URL.to_string(),
+            })
+    }
+
+    // Job state changes between polls, so nothing here goes through the cache.
+    async fn send_json<T: DeserializeOwned>(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+    ) -> Result<T, BioMcpError> {
+        let resp = req.with_extension(CacheMode::NoStore).send().await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, CLUE_API).await?;
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: CLUE_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+        crate::sources::ensure_json_content_type(CLUE_API, content_type.as_ref(), &bytes)?;
+        serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+            api: CLUE_API.to_string(),
+            source,
+        })
+    }
+
+    /// Submits a Touchstone connectivity query for up/down Entrez gene sets
+    /// and returns the CLUE job ID.
+    pub async fn submit_query(
+        &self,
+        name: &str,
+        up_entrez: &[String],
+        down_entrez: &[String],
+    ) -> Result<String, BioMcpError> {
+        let api_key = self.require_api_key()?;
+        for (label, genes) in [("up", up_entrez), ("down", down_entrez)] {
+            if !(CLUE_MIN_GENES..=CLUE_MAX_GENES).contains(&genes.len()) {
+                return Err(BioMcpError::InvalidArgument(format!(
+                    "CLUE queries need {CLUE_MIN_GENES}-{CLUE_MAX_GENES} {label}-regulated genes; got {}",
+                    genes.len()
+                )));
+            }
+        }
+
+        let body = ClueQueryRequest {
+            tool_id: CLUE_QUERY_TOOL,
+            data_type: "L1000",
+            dataset: "Touchstone",
+            name,
+            up_tag: gmt_line("UP", up_entrez),
+            down_tag: gmt_line("DN", down_entrez),
+            ignore_warnings: true,
+        };
+        let resp: ClueSubmitResponse = self
+            .send_json(
+                self.client
+                    .post(self.endpoint("jobs"))
+                    .header("user_key", api_key)
+                    .json(&body),
+            )
+            .await?;
+        resp.result
+            .and_then(|job| job.job_id)
+            .or(resp.job.job_id)
+            .map(|id| id.trim().to_string())
+            .filter(|id| !id.is_empty())
+            .ok_or_else(|| BioMcpError::Api {
+                api: CLUE_API.to_string(),
+                message: "Query submission returned no job_id".into(),
+            })
+    }
+
+    pub async fn job_status(&self, job_id: &str) -> Result<ClueJob, BioMcpError> {
+        let api_key = self.require_api_key()?;
+        let url = self.endpoint(&format!("jobs/findByJobId/{}", job_id.trim()));
+        self.send_json(self.client.get(&url).header("user_key", api_key))
+            .await
+    }
+
+    /// Polls a job until its results are downloadable or `timeout` passes.
+    pub async fn wait_for_job(
+        &self,
+        job_id: &str,
+        timeout: Duration,
+    ) -> Result<ClueJob, BioMcpError> {
+        let started = Instant::now();
+        loop {
+            let job = self.job_status(job_id).await?;
+            let status = job.status.as_deref().unwrap_or_default();
+            if status.eq_ignore_ascii_case("completed") && job.download_url.is_some() {
+                return Ok(job);
+            }
+            if status.eq_ignore_ascii_case("failed") || status.eq_ignore_ascii_case("error") {
+                let detail = job
+                    .error_message
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|message| !message.is_empty())
+                    .unwrap_or("no error detail");
+                return Err(BioMcpError::Api {
+                    api: CLUE_API.to_string(),
+                    message: format!("Query job {job_id} failed: {detail}"),
+                });
+            }
+            if started.elapsed() >= timeout {
+                return Err(BioMcpError::Api {
+                    api: CLUE_API.to_string(),
+                    message: format!(
+                        "Query job {job_id} still {} after {}s; raise --wait or check https://clue.io/history",
+                        if status.is_empty() { "pending" } else { status },
+                        timeout.as_secs()
+                    ),
+                });
+            }
+            tracing::debug!(job_id, status, "CLUE job not ready");
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Downloads a completed job's result archive and parses its connectivity table.
+    pub async fn connectivity(
+        &self,
+        job: &ClueJob,
+    ) -> Result<Vec<ClueConnectivityRow>, BioMcpError> {
+        let raw_url = job
+            .download_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .ok_or_else(|| BioMcpError::Api {
+                api: CLUE_API.to_string(),
+                message: "Completed job has no download_url".into(),
+            })?;
+        // CLUE reports S3 result locations as protocol-relative URLs.
+        let url = if raw_url.starts_with("//") {
+            format!("https:{raw_url}")
+        } else {
+            raw_url.to_string()
+        };
+
+        let resp = self
+            .client
+            .get(&url)
+            .with_extension(CacheMode::NoStore)
+            .send()
+            .await?;
+        let status = resp.status();
+        let bytes =
+            crate::sources::read_limited_body_with_limit(resp, CLUE_API, MAX_RESULT_TGZ_BYTES)
+                .await?;
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: CLUE_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+
+        tokio::task::spawn_blocking(move || extract_connectivity(&bytes))
+            .await
+            .map_err(|err| BioMcpError::Api {
+                api: CLUE_API.to_string(),
+                message: format!("Task join error: {err}"),
+            })?
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ClueQueryRequest<'a> {
+    tool_id: &'a str,
+    data_type: &'a str,
+    dataset: &'a str,
+    name: &'a str,
+    #[serde(rename = "uptag-cmapfile")]
+    up_tag: String,
+    #[serde(rename = "dntag-cmapfile")]
+    down_tag: String,
+    #[serde(rename = "ignoreWarnings")]
+    ignore_warnings: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ClueSubmitResponse {
+    result: Option<ClueJob>,
+    #[serde(flatten)]
+    job: ClueJob,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ClueJob {
+    pub job_id: Option<String>,
+    pub status: Option<String>,
+    pub download_url: Option<String>,
+    #[serde(rename = "errorMessage")]
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClueConnectivityRow {
+    pub pert_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pert_iname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pert_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moa: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cell_id: Option<String>,
+    /// Connectivity score; negative values reverse the query signature.
+    pub score: f64,
+}
+
+/// One GMT row: tag name, description, then Entrez IDs.
+fn gmt_line(tag: &str, genes: &[String]) -> String {
+    let mut line = format!("{tag}\tbiomcp");
+    for gene in genes {
+        line.push('\t');
+        line.push_str(gene.trim());
+    }
+    line
+}
+
+fn extract_connectivity(tgz_bytes: &[u8]) -> Result<Vec<ClueConnectivityRow>, BioMcpError> {
+    use std::io::Read;
+
+    let gz = flate2::read::GzDecoder::new(tgz_bytes);
+    let mut archive = tar::Archive::new(gz);
+    let mut found: HashMap<&'static str, String> = HashMap::new();
+    for entry in archive.entries()? {
+        let entry = entry?;
+        if entry.size() > MAX_ARCHIVE_ENTRY_BYTES {
+            continue;
+        }
+        let path = entry.path()?;
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let Some(&wanted) = RESULT_FILES.iter().find(|name| **name == file_name) else {
+            continue;
+        };
+        if found.contains_key(wanted) {
+            continue;
+        }
+        let mut out = Vec::new();
+        entry
+            .take(MAX_ARCHIVE_ENTRY_BYTES + 1)
+            .read_to_end(&mut out)?;
+        found.insert(wanted, String::from_utf8_lossy(&out).into_owned());
+    }
+
+    let text = RESULT_FILES
+        .iter()
+        .find_map(|name| found.get(name))
+        .ok_or_else(|| BioMcpError::Api {
+            api: CLUE_API.to_string(),
+            message: format!(
+                "Result archive has none of the expected files ({})",
+                RESULT_FILES.join(", ")
+            ),
+        })?;
+    parse_gct(text)
+}
+
+fn gct_error(message: impl Into<String>) -> BioMcpError {
+    BioMcpError::Api {
+        api: CLUE_API.to_string(),
+        message: format!("Invalid GCT result: {}", message.into()),
+    }
+}
+
+/// Parses a GCT 1.2 or 1.3 connectivity table, taking the first data column
+/// as the score.
+fn parse_gct(text: &str) -> Result<Vec<ClueConnectivityRow>, BioMcpError> {
+    let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+    let version = lines.next().unwrap_or_default().trim();
+    let dims = lines
+        .next()
+        .ok_or_else(|| gct_error("missing dimensions line"))?
+        .split('\t')
+        .map(|value| value.trim().parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| gct_error("non-numeric dimensions line"))?;
+    let (row_meta, col_meta) = match (version, dims.as_slice()) {
+        ("#1.3", [_, _, row_meta, col_meta]) => (*row_meta, *col_meta),
+        ("#1.2", [_, _]) => (1, 0),
+        _ => return Err(gct_error(format!("unsupported header {version}"))),
+    };
+
+    let header = lines
+        .next()
+        .ok_or_else(|| gct_error("missing column header"))?
+        .split('\t')
+        .map(|value| value.trim().to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    let score_index = 1 + row_meta;
+    if header.len() <= score_index {
+        return Err(gct_error("no data columns"));
+    }
+    let meta = |names: &[&str]| {
+        header[1..score_index]
+            .iter()
+            .position(|column| names.contains(&column.as_str()))
+            .map(|index| index + 1)
+    };
+    // GCT 1.2 carries the perturbagen name in its Description column.
+    let name_index = meta(&["pert_iname", "description"]);
+    let id_index = meta(&["pert_id"]);
+    let type_index = meta(&["pert_type"]);
+    let moa_index = meta(&["moa"]);
+    let target_index = meta(&["target_name", "target"]);
+    let cell_index = meta(&["cell_id", "cell_iname"]);
+
+    let mut rows = Vec::new();
+    for line in lines.skip(col_meta) {
+        let fields = line.split('\t').map(str::trim).collect::<Vec<_>>();
+        let field = |index: Option<usize>| {
+            index
+                .and_then(|index| fields.get(index))
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty() && !value.eq_ignore_ascii_case("-666"))
+                .map(str::to_string)
+        };
+        let Some(score) = fields
+            .get(score_index)
+            .and_then(|value| value.parse::<f64>().ok())
+            .filter(|value| value.is_finite())
+        else {
+            continue;
+        };
+        let Some(pert_id) = field(id_index).or_else(|| field(Some(0))) else {
+            continue;
+        };
+        rows.push(ClueConnectivityRow {
+            pert_id,
+            pert_iname: field(name_index),
+            pert_type: field(type_index),
+            moa: field(moa_index),
+            target: field(target_index),
+            cell_id: field(cell_index),
+            score,
+        });
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+    use std::io::Write;
+    use tar::{Builder, Header};
+    use wiremock::matchers::{body_string_contains, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const SUMMARY_GCT: &str = "#1.3\n3\t1\t4\t0\nid\tpert_iname\tpert_type\tmoa\ttarget_name\tUP_DN\nBRD-K81418486\tvorinostat\ttrt_cp\tHDAC inhibitor\tHDAC1|HDAC2\t-97.4\nBRD-A12345678\tBRD-A12345678\ttrt_cp\t-666\t-666\t-88.1\nCGS001-7157\tTP53\ttrt_sh.cgs\t-666\t-666\t91.2\n";
+
+    fn tgz(files: &[(&str, &str)]) -> Vec<u8> {
+        let mut tar_buf = Vec::new();
+        {
+            let mut builder = Builder::new(&mut tar_buf);
+            for (name, contents) in files {
+                let mut header = Header::new_gnu();
+                header.set_size(contents.len() as u64);
+                header.set_mode(0o644);
+                header.set_cksum();
+                builder
+                    .append_data(&mut header, name, contents.as_bytes())
+                    .unwrap();
+            }
+            builder.finish().unwrap();
+        }
+        let mut gz = GzEncoder::new(Vec::new(), Compression::default());
+        gz.write_all(&tar_buf).unwrap();
+        gz.finish().unwrap()
+    }
+
+    fn genes(count: usize) -> Vec<String> {
+        (1..=count).map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_gct_reads_row_metadata_and_skips_missing_values() {
+        let rows = parse_gct(SUMMARY_GCT).expect("gct");
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].pert_id, "BRD-K81418486");
+        assert_eq!(rows[0].pert_iname.as_deref(), Some("vorinostat"));
+        assert_eq!(rows[0].moa.as_deref(), Some("HDAC inhibitor"));
+        assert_eq!(rows[0].target.as_deref(), Some("HDAC1|HDAC2"));
+        assert_eq!(rows[0].score, -97.4);
+        assert_eq!(rows[1].moa, None);
+
+        let legacy = parse_gct("#1.2\n1\t1\nName\tDescription\tscore\nBRD-K1\tsirolimus\t-0.9\n")
+            .expect("gct 1.2");
+        assert_eq!(legacy[0].pert_iname.as_deref(), Some("sirolimus"));
+        assert!(parse_gct("#1.4\n1\t1\n").is_err());
+    }
+
+    #[test]
+    fn extract_connectivity_prefers_perturbagen_summary() {
+        let archive = tgz(&[
+            (
+                "my_query/arfs/TAG/query_result.gct",
+                "#1.2\n1\t1\nName\tDescription\tscore\nsig1\tother\t-1\n",
+            ),
+            ("my_query/arfs/TAG/pert_id_summary.gct", SUMMARY_GCT),
+        ]);
+        let rows = extract_connectivity(&archive).expect("rows");
+        assert_eq!(rows.len(), 3);
+
+        let err = extract_connectivity(&tgz(&[("readme.txt", "none")])).expect_err("missing");
+        assert!(err.to_string().contains("pert_id_summary.gct"));
+    }
+
+    #[tokio::test]
+    async fn submit_query_requires_key_and_gene_counts() {
+        let client = ClueClient::new_for_test("http://127.0.0.1".into(), None).unwrap();
+        let err = client
+            .submit_query("q", &genes(10), &genes(10))
+            .await
+            .expect_err("missing key");
+        assert!(matches!(err, BioMcpError::ApiKeyRequired { .. }));
+
+        let client = ClueClient::new_for_test("http://127.0.0.1".into(), Some("k".into())).unwrap();
+        let err = client
+            .submit_query("q", &genes(9), &genes(10))
+            .await
+            .expect_err("too few genes");
+        assert!(err.to_string().contains("10-150 up-regulated genes; got 9"));
+    }
+
+    #[tokio::test]
+    async fn query_round_trip_submits_polls_and_downloads_results() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/jobs"))
+            .and(header("user_key", "test-key"))
+            .and(body_string_contains("sig_gutc_tool"))
+            .and(body_string_contains("UP\\tbiomcp\\t1\\t2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "result": {"job_id": "job-1"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/jobs/findByJobId/job-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "job_id": "job-1",
+                "status": "completed",
+                "download_url": format!("{}/results/job-1.tar.gz", server.uri())
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/results/job-1.tar.gz"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(tgz(&[("job-1/pert_id_summary.gct", SUMMARY_GCT)])),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ClueClient::new_for_test(server.uri(), Some("test-key".into())).unwrap();
+        let job_id = client
+            .submit_query("q", &genes(10), &genes(12))
+            .await
+            .expect("job id");
+        assert_eq!(job_id, "job-1");
+        let job = client
+            .wait_for_job(&job_id, Duration::from_secs(5))
+            .await
+            .expect("completed job");
+        let rows = client.connectivity(&job).await.expect("rows");
+        assert_eq!(rows[0].pert_iname.as_deref(), Some("vorinostat"));
+    }
+
+    #[tokio::test]
+    async fn wait_for_job_reports_failed_and_timed_out_jobs() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/jobs/findByJobId/bad"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "failed",
+                "errorMessage": "too few BING genes"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/jobs/findByJobId/slow"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "running"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = ClueClient::new_for_test(server.uri(), Some("k".into())).unwrap();
+        let err = client
+            .wait_for_job("bad", Duration::from_secs(5))
+            .await
+            .expect_err("failed job");
+        assert!(err.to_string().contains("too few BING genes"));
+
+        let err = client
+            .wait_for_job("slow", Duration::ZERO)
+            .await
+            .expect_err("timeout");
+        assert!(err.to_string().contains("still running after 0s"));
+    }
+}
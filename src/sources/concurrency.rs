@@ -0,0 +1,130 @@
+//! Process-wide cap on in-flight upstream HTTP requests.
+//!
+//! Per-source rate limits space out calls to one API, but a burst of agent tool
+//! calls can still fan out to many sources at once and open hundreds of
+//! connections. [`ConcurrencyLimitMiddleware`] sits below the HTTP cache and the
+//! rate limiter, so cache hits and rate-limit waits never hold a permit.
+//! Clients that bypass the middleware stack take one with [`acquire_permit`].
+//! The limit defaults to 8 and is read once from `BIOMCP_MAX_CONCURRENT_REQUESTS`.
+
+use std::sync::{Arc, OnceLock};
+
+use http::Extensions;
+use reqwest_middleware::{Middleware, Next};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::warn;
+
+const MAX_CONCURRENT_REQUESTS_ENV: &str = "BIOMCP_MAX_CONCURRENT_REQUESTS";
+const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 8;
+
+fn parse_max_concurrent_requests(value: Option<&str>) -> usize {
+    let Some(value) = value.map(str::trim).filter(|value| !value.is_empty()) else {
+        return DEFAULT_MAX_CONCURRENT_REQUESTS;
+    };
+    match value.parse::<usize>() {
+        Ok(limit) if limit > 0 => limit.min(Semaphore::MAX_PERMITS),
+        _ => {
+            warn!(
+                "Invalid {MAX_CONCURRENT_REQUESTS_ENV}={value:?}, using {DEFAULT_MAX_CONCURRENT_REQUESTS}"
+            );
+            DEFAULT_MAX_CONCURRENT_REQUESTS
+        }
+    }
+}
+
+static GLOBAL_PERMITS: OnceLock<Arc<Semaphore>> = OnceLock::new();
+
+fn global_permits() -> Arc<Semaphore> {
+    GLOBAL_PERMITS
+        .get_or_init(|| {
+            let limit = parse_max_concurrent_requests(
+                std::env::var(MAX_CONCURRENT_REQUESTS_ENV).ok().as_deref(),
+            );
+            Arc::new(Semaphore::new(limit))
+        })
+        .clone()
+}
+
+/// Waits for a permit from the process-wide pool, for requests sent with a
+/// plain `reqwest::Client` that bypasses the middleware stack. Hold it until
+/// response headers arrive. `None` only if the pool were closed, which it never is.
+pub(crate) async fn acquire_permit() -> Option<OwnedSemaphorePermit> {
+    global_permits().acquire_owned().await.ok()
+}
+
+/// Holds one permit from the process-wide pool until response headers arrive.
+#[derive(Clone, Debug)]
+pub(crate) struct ConcurrencyLimitMiddleware {
+    permits: Arc<Semaphore>,
+}
+
+impl ConcurrencyLimitMiddleware {
+    pub(crate) fn new() -> Self {
+        Self {
+            permits: global_permits(),
+        }
+    }
+
+    #[cfg(test)]
+    fn with_permits(permits: Arc<Semaphore>) -> Self {
+        Self { permits }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for ConcurrencyLimitMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let _permit = self
+            .permits
+            .acquire()
+            .await
+            .map_err(reqwest_middleware::Error::middleware)?;
+        next.run(req, extensions).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn parse_max_concurrent_requests_defaults_and_rejects_invalid_values() {
+        assert_eq!(parse_max_concurrent_requests(None), 8);
+        assert_eq!(parse_max_concurrent_requests(Some("")), 8);
+        assert_eq!(parse_max_concurrent_requests(Some(" 16 ")), 16);
+        assert_eq!(parse_max_concurrent_requests(Some("0")), 8);
+        assert_eq!(parse_max_concurrent_requests(Some("many")), 8);
+    }
+
+    #[tokio::test]
+    async fn middleware_serializes_requests_beyond_the_permit_count() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/slow"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(150)))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+            .with(ConcurrencyLimitMiddleware::with_permits(Arc::new(
+                Semaphore::new(1),
+            )))
+            .build();
+        let url = format!("{}/slow", server.uri());
+        let started = Instant::now();
+        let responses = futures::future::join_all((0..3).map(|_| client.get(&url).send())).await;
+
+        assert!(responses.iter().all(|resp| resp.is_ok()));
+        assert!(started.elapsed() >= Duration::from_millis(450));
+    }
+}
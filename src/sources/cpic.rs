@@ -195,6 +195,63 @@ impl CpicClient {
         self.get_json(req).await
     }
 
+    /// Diplotype rows for `gene` matching `diplotype` in either allele order.
+    pub async fn diplotypes(
+        &self,
+        gene_symbol: &str,
+        diplotype: &str,
+    ) -> Result<Vec<CpicDiplotypeRow>, BioMcpError> {
+        let gene_symbol = normalize_gene_symbol(gene_symbol)?;
+        let mut candidates = vec![diplotype.to_string()];
+        if let Some((first, second)) = diplotype.split_once('/') {
+            let swapped = format!("{second}/{first}");
+            if swapped != diplotype {
+                candidates.push(swapped);
+            }
+        }
+        let filter = format!(
+            "in.({})",
+            candidates
+                .iter()
+                .map(|value| format!("\"{}\"", value.replace('"', "")))
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+        let url = self.endpoint("diplotype");
+
+        let req = self.client.get(&url).query(&[
+            ("genesymbol", format!("eq.{gene_symbol}")),
+            ("diplotype", filter),
+            ("select", "*".to_string()),
+        ]);
+
+        self.get_json(req).await
+    }
+
+    /// Recommendations keyed on `gene` having the CPIC lookup value `key`
+    /// (an activity score such as `1.0` or a phenotype such as
+    /// `Poor Metabolizer`), including multi-gene recommendations.
+    pub async fn recommendations_by_lookup(
+        &self,
+        gene_symbol: &str,
+        key: &str,
+        limit: usize,
+    ) -> Result<Vec<CpicRecommendationRow>, BioMcpError> {
+        let gene_symbol = normalize_gene_symbol(gene_symbol)?;
+        let limit = limit.clamp(1, 200);
+        let url = self.endpoint("recommendation_view");
+        let lookup = serde_json::json!({ gene_symbol.as_str(): key });
+
+        let req = self.client.get(&url).query(&[
+            ("lookupkey", format!("cs.{lookup}")),
+            ("select", "*".to_string()),
+            ("limit", limit.to_string()),
+            ("order", "drugname.asc".to_string()),
+        ]);
+
+        self.get_json(req).await
+    }
+
     pub async fn frequencies_by_gene(
         &self,
         gene_symbol: &str,
@@ -331,6 +388,27 @@ pub struct CpicRecommendationRow {
     pub population: Option<String>,
 }
 
+/// One row of CPIC's diplotype-to-phenotype table.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CpicDiplotypeRow {
+    #[serde(default)]
+    pub genesymbol: String,
+    #[serde(default)]
+    pub diplotype: String,
+    /// Phenotype, e.g. `Intermediate Metabolizer`.
+    #[serde(default)]
+    pub generesult: Option<String>,
+    #[serde(default)]
+    pub totalactivityscore: Option<String>,
+    /// Key used to look up recommendations, e.g. `{"CYP2D6": "1.0"}`.
+    #[serde(default)]
+    pub lookupkey: HashMap<String, String>,
+    #[serde(default)]
+    pub ehrpriority: Option<String>,
+    #[serde(default)]
+    pub consultationtext: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct CpicFrequencyRow {
     #[serde(default)]
@@ -433,6 +511,64 @@ mod tests {
         assert_eq!(rows[0].drugrecommendation.as_deref(), Some("Avoid codeine"));
     }
 
+    #[tokio::test]
+    async fn diplotypes_match_either_allele_order() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/diplotype"))
+            .and(query_param("genesymbol", "eq.CYP2D6"))
+            .and(query_param("diplotype", "in.(\"*4/*1\",\"*1/*4\")"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "genesymbol": "CYP2D6",
+                    "diplotype": "*1/*4",
+                    "generesult": "Intermediate Metabolizer",
+                    "totalactivityscore": "1.0",
+                    "lookupkey": {"CYP2D6": "1.0"},
+                    "ehrpriority": "Abnormal/Priority/High Risk"
+                }
+            ])))
+            .mount(&server)
+            .await;
+
+        let client = CpicClient::new_for_test(server.uri()).expect("client");
+        let rows = client
+            .diplotypes("cyp2d6", "*4/*1")
+            .await
+            .expect("rows should parse");
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].generesult.as_deref(), Some("Intermediate Metabolizer"));
+        assert_eq!(rows[0].lookupkey.get("CYP2D6").map(String::as_str), Some("1.0"));
+    }
+
+    #[tokio::test]
+    async fn recommendations_by_lookup_filters_on_lookup_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/recommendation_view"))
+            .and(query_param("lookupkey", r#"cs.{"CYP2D6":"1.0"}"#))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "drugname": "codeine",
+                    "phenotypes": {"CYP2D6": "Intermediate Metabolizer"},
+                    "drugrecommendation": "Use codeine label recommended age- or weight-specific dosing.",
+                    "classification": "Strong"
+                }
+            ])))
+            .mount(&server)
+            .await;
+
+        let client = CpicClient::new_for_test(server.uri()).expect("client");
+        let rows = client
+            .recommendations_by_lookup("CYP2D6", "1.0", 50)
+            .await
+            .expect("rows should parse");
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].drugname, "codeine");
+    }
+
     #[tokio::test]
     async fn guidelines_by_gene_parses_guideline_rows() {
         let server = MockServer::start().await;
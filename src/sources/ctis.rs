@@ -0,0 +1,215 @@
+use std::borrow::Cow;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+
+const CTIS_BASE: &str = "https://euclinicaltrials.eu/ctis-public-api";
+const CTIS_API: &str = "ctis";
+const CTIS_BASE_ENV: &str = "BIOMCP_CTIS_BASE";
+pub(crate) const CTIS_MAX_PAGE_SIZE: usize = 100;
+
+/// EU Clinical Trials Information System (CTIS) public search client.
+///
+/// CTIS replaced the EU Clinical Trials Register for trials authorised under
+/// EU Regulation 536/2014 and also hosts EudraCT trials transitioned to it.
+#[derive(Clone)]
+pub struct CtisClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CtisSearchParams {
+    pub medical_condition: Option<String>,
+    pub product_name: Option<String>,
+    pub sponsor: Option<String>,
+    /// 1-based page number.
+    pub page: usize,
+    pub size: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CtisSearchRequest<'a> {
+    pagination: CtisRequestPagination,
+    sort: CtisRequestSort,
+    search_criteria: CtisSearchCriteria<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct CtisRequestPagination {
+    page: usize,
+    size: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct CtisRequestSort {
+    property: &'static str,
+    direction: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CtisSearchCriteria<'a> {
+    medical_condition: Option<&'a str>,
+    product_name: Option<&'a str>,
+    sponsor: Option<&'a str>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CtisSearchResponse {
+    #[serde(default)]
+    pub data: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub pagination: Option<CtisResponsePagination>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CtisResponsePagination {
+    #[serde(default, alias = "totalElements")]
+    pub total_records: Option<usize>,
+}
+
+impl CtisSearchResponse {
+    pub fn total(&self) -> Option<usize> {
+        self.pagination.as_ref().and_then(|p| p.total_records)
+    }
+}
+
+fn trimmed_non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|v| !v.is_empty())
+}
+
+impl CtisClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(CTIS_BASE, CTIS_BASE_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    pub(crate) fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base.as_ref().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    async fn post_json<T: DeserializeOwned, B: Serialize>(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+        body: &B,
+    ) -> Result<T, BioMcpError> {
+        let resp = crate::sources::apply_cache_mode(req.json(body))
+            .send()
+            .await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, CTIS_API).await?;
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: CTIS_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+        crate::sources::ensure_json_content_type(CTIS_API, content_type.as_ref(), &bytes)?;
+        serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+            api: CTIS_API.to_string(),
+            source,
+        })
+    }
+
+    pub async fn search(
+        &self,
+        params: &CtisSearchParams,
+    ) -> Result<CtisSearchResponse, BioMcpError> {
+        if params.size == 0 || params.size > CTIS_MAX_PAGE_SIZE {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "CTIS page size must be between 1 and {CTIS_MAX_PAGE_SIZE}"
+            )));
+        }
+
+        let body = CtisSearchRequest {
+            pagination: CtisRequestPagination {
+                page: params.page.max(1),
+                size: params.size,
+            },
+            sort: CtisRequestSort {
+                property: "decisionDate",
+                direction: "DESC",
+            },
+            search_criteria: CtisSearchCriteria {
+                medical_condition: trimmed_non_empty(params.medical_condition.as_deref()),
+                product_name: trimmed_non_empty(params.product_name.as_deref()),
+                sponsor: trimmed_non_empty(params.sponsor.as_deref()),
+            },
+        };
+        let url = self.endpoint("search");
+        self.post_json(self.client.post(&url), &body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn search_posts_criteria_and_reads_total() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/search"))
+            .and(body_partial_json(serde_json::json!({
+                "pagination": {"page": 2, "size": 10},
+                "searchCriteria": {"medicalCondition": "melanoma", "productName": "nivolumab"}
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "pagination": {"page": 2, "size": 10, "totalRecords": 31},
+                "data": [{"ctNumber": "2023-503456-12-00", "ctTitle": "Nivolumab in melanoma"}]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = CtisClient::new_for_test(server.uri()).unwrap();
+        let resp = client
+            .search(&CtisSearchParams {
+                medical_condition: Some("melanoma".into()),
+                product_name: Some(" nivolumab ".into()),
+                sponsor: None,
+                page: 2,
+                size: 10,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(resp.total(), Some(31));
+        assert_eq!(resp.data.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn search_rejects_oversized_pages() {
+        let client = CtisClient::new_for_test("http://127.0.0.1:9".into()).unwrap();
+        let err = client
+            .search(&CtisSearchParams {
+                size: CTIS_MAX_PAGE_SIZE + 1,
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("CTIS page size"));
+    }
+}
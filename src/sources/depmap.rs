@@ -0,0 +1,335 @@
+//! DepMap portal client for CRISPR gene-effect summaries.
+//!
+//! Gene effect (Chronos) scores come from the portal's Breadbox dataset API:
+//! one matrix request returns the gene's score in every screened cell line,
+//! and one tabular request maps those cell lines (DepMap model IDs) to their
+//! OncoTree lineage. BioMCP only keeps the summary: the pan-cancer mean, how
+//! many lines are dependent, and the lineages with the strongest mean effect.
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+
+const DEPMAP_BASE: &str = "https://depmap.org/portal/api";
+const DEPMAP_API: &str = "depmap";
+const DEPMAP_BASE_ENV: &str = "BIOMCP_DEPMAP_BASE";
+/// CRISPR (DepMap Public, Chronos) gene effect, combined screens.
+const DEPMAP_GENE_EFFECT_DATASET: &str = "Chronos_Combined";
+const DEPMAP_MODEL_DATASET: &str = "depmap_model_metadata";
+const DEPMAP_LINEAGE_COLUMN: &str = "OncotreeLineage";
+/// Gene effect at or below this score counts as a dependency; 0 is no effect
+/// and -1 is the median of common essential genes.
+const DEPMAP_DEPENDENT_GENE_EFFECT: f64 = -0.5;
+/// Lineages with fewer screened lines are too noisy to rank.
+const DEPMAP_MIN_LINEAGE_CELL_LINES: usize = 5;
+const DEPMAP_TOP_LINEAGES: usize = 5;
+
+pub struct DepMapClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+}
+
+impl DepMapClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(DEPMAP_BASE, DEPMAP_BASE_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base.as_ref().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    async fn post_json<B: Serialize, T: DeserializeOwned>(
+        &self,
+        url: String,
+        body: &B,
+    ) -> Result<T, BioMcpError> {
+        let req = self.client.post(url).json(body);
+        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, DEPMAP_API).await?;
+
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: DEPMAP_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(DEPMAP_API, content_type.as_ref(), &bytes)?;
+        serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+            api: DEPMAP_API.to_string(),
+            source,
+        })
+    }
+
+    /// Gene effect per DepMap model ID; empty when the gene was not screened.
+    async fn gene_effects(&self, symbol: &str) -> Result<HashMap<String, f64>, BioMcpError> {
+        let url = self.endpoint(&format!("datasets/matrix/{DEPMAP_GENE_EFFECT_DATASET}"));
+        let body = serde_json::json!({
+            "features": [symbol],
+            "feature_identifier": "label",
+        });
+        let resp: HashMap<String, HashMap<String, Option<f64>>> =
+            self.post_json(url, &body).await?;
+        let Some(scores) = resp
+            .into_iter()
+            .find(|(label, _)| label.eq_ignore_ascii_case(symbol))
+            .map(|(_, scores)| scores)
+        else {
+            return Ok(HashMap::new());
+        };
+        Ok(scores
+            .into_iter()
+            .filter_map(|(model_id, score)| Some((model_id, score.filter(|v| v.is_finite())?)))
+            .collect())
+    }
+
+    /// OncoTree lineage per DepMap model ID.
+    async fn model_lineages(&self) -> Result<HashMap<String, String>, BioMcpError> {
+        let url = self.endpoint(&format!("datasets/tabular/{DEPMAP_MODEL_DATASET}"));
+        let body = serde_json::json!({ "columns": [DEPMAP_LINEAGE_COLUMN] });
+        let mut resp: HashMap<String, HashMap<String, Option<String>>> =
+            self.post_json(url, &body).await?;
+        Ok(resp
+            .remove(DEPMAP_LINEAGE_COLUMN)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(model_id, lineage)| {
+                let lineage = lineage?.trim().to_string();
+                (!lineage.is_empty()).then_some((model_id, lineage))
+            })
+            .collect())
+    }
+
+    pub async fn gene_dependency(&self, symbol: &str) -> Result<GeneDependency, BioMcpError> {
+        let symbol = symbol.trim();
+        if symbol.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "Gene symbol is required for DepMap dependency".into(),
+            ));
+        }
+
+        let effects = self.gene_effects(symbol).await?;
+        if effects.is_empty() {
+            return Ok(GeneDependency::default());
+        }
+        let lineages = self.model_lineages().await?;
+        Ok(summarize(&effects, &lineages))
+    }
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+fn count_dependent(values: &[f64]) -> usize {
+    values
+        .iter()
+        .filter(|v| **v <= DEPMAP_DEPENDENT_GENE_EFFECT)
+        .count()
+}
+
+fn summarize(effects: &HashMap<String, f64>, lineages: &HashMap<String, String>) -> GeneDependency {
+    let scores: Vec<f64> = effects.values().copied().collect();
+
+    let mut by_lineage: HashMap<&str, Vec<f64>> = HashMap::new();
+    for (model_id, score) in effects {
+        if let Some(lineage) = lineages.get(model_id) {
+            by_lineage.entry(lineage.as_str()).or_default().push(*score);
+        }
+    }
+
+    let mut top_lineages: Vec<LineageDependency> = by_lineage
+        .into_iter()
+        .filter(|(_, scores)| scores.len() >= DEPMAP_MIN_LINEAGE_CELL_LINES)
+        .filter_map(|(lineage, scores)| {
+            Some(LineageDependency {
+                lineage: lineage.to_string(),
+                cell_lines: scores.len(),
+                dependent_cell_lines: count_dependent(&scores),
+                mean_gene_effect: mean(&scores)?,
+            })
+        })
+        .collect();
+    top_lineages.sort_by(|a, b| {
+        a.mean_gene_effect
+            .partial_cmp(&b.mean_gene_effect)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.lineage.cmp(&b.lineage))
+    });
+    top_lineages.truncate(DEPMAP_TOP_LINEAGES);
+
+    GeneDependency {
+        dataset: Some(DEPMAP_GENE_EFFECT_DATASET.to_string()),
+        cell_lines: scores.len(),
+        dependent_cell_lines: count_dependent(&scores),
+        mean_gene_effect: mean(&scores),
+        top_lineages,
+    }
+}
+
+/// Pan-cancer CRISPR gene-effect summary for one gene.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct GeneDependency {
+    /// DepMap dataset the scores come from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dataset: Option<String>,
+    pub cell_lines: usize,
+    /// Cell lines with gene effect at or below -0.5.
+    pub dependent_cell_lines: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mean_gene_effect: Option<f64>,
+    /// Lineages with the most negative mean gene effect, strongest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub top_lineages: Vec<LineageDependency>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LineageDependency {
+    pub lineage: String,
+    pub cell_lines: usize,
+    pub dependent_cell_lines: usize,
+    pub mean_gene_effect: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn lineage_rows(lineage: &str, scores: &[f64], offset: usize) -> Vec<(String, f64, String)> {
+        scores
+            .iter()
+            .enumerate()
+            .map(|(idx, score)| {
+                (
+                    format!("ACH-{:06}", offset + idx),
+                    *score,
+                    lineage.to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn gene_dependency_summarizes_mean_and_ranks_lineages() {
+        let server = MockServer::start().await;
+
+        let mut rows = lineage_rows("Skin", &[-1.2, -1.0, -0.9, -0.8, -0.6], 0);
+        rows.extend(lineage_rows("Bowel", &[-0.7, -0.4, -0.3, -0.2, -0.1], 10));
+        rows.extend(lineage_rows("Lung", &[-0.1, 0.0, 0.1, 0.0, -0.1], 20));
+        // Too few lines to rank, but still part of the pan-cancer numbers.
+        rows.extend(lineage_rows("Thyroid", &[-2.0, -2.0], 30));
+
+        let effects: serde_json::Map<String, serde_json::Value> = rows
+            .iter()
+            .map(|(id, score, _)| (id.clone(), serde_json::json!(score)))
+            .chain([("ACH-999999".to_string(), serde_json::Value::Null)])
+            .collect();
+        let lineages: serde_json::Map<String, serde_json::Value> = rows
+            .iter()
+            .map(|(id, _, lineage)| (id.clone(), serde_json::json!(lineage)))
+            .collect();
+
+        Mock::given(method("POST"))
+            .and(path("/datasets/matrix/Chronos_Combined"))
+            .and(body_partial_json(serde_json::json!({"features": ["BRAF"]})))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "BRAF": effects })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/datasets/tabular/depmap_model_metadata"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "OncotreeLineage": lineages })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = DepMapClient::new_for_test(server.uri()).expect("client");
+        let dependency = client.gene_dependency("BRAF").await.expect("dependency");
+
+        assert_eq!(dependency.dataset.as_deref(), Some("Chronos_Combined"));
+        assert_eq!(dependency.cell_lines, 17);
+        assert_eq!(dependency.dependent_cell_lines, 8);
+        let mean = dependency.mean_gene_effect.expect("mean");
+        assert!((mean - (-10.3 / 17.0)).abs() < 1e-9);
+
+        let ranked: Vec<&str> = dependency
+            .top_lineages
+            .iter()
+            .map(|row| row.lineage.as_str())
+            .collect();
+        assert_eq!(ranked, ["Skin", "Bowel", "Lung"]);
+        assert_eq!(dependency.top_lineages[0].cell_lines, 5);
+        assert_eq!(dependency.top_lineages[0].dependent_cell_lines, 5);
+        assert!((dependency.top_lineages[0].mean_gene_effect - (-0.9)).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn gene_dependency_is_empty_when_gene_was_not_screened() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/datasets/matrix/Chronos_Combined"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/datasets/tabular/depmap_model_metadata"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = DepMapClient::new_for_test(server.uri()).expect("client");
+        let dependency = client
+            .gene_dependency("NOTAGENE")
+            .await
+            .expect("dependency");
+        assert_eq!(dependency.cell_lines, 0);
+        assert!(dependency.mean_gene_effect.is_none());
+        assert!(dependency.top_lineages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn gene_dependency_surfaces_http_errors() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/datasets/matrix/Chronos_Combined"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("maintenance"))
+            .mount(&server)
+            .await;
+
+        let client = DepMapClient::new_for_test(server.uri()).expect("client");
+        let err = client.gene_dependency("BRAF").await.expect_err("error");
+        assert!(err.to_string().contains("HTTP 503"));
+    }
+}
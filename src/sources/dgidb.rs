@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::{BTreeSet, HashMap};
 
+use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
@@ -11,6 +12,7 @@ const DGIDB_BASE: &str = "https://dgidb.org/api";
 const DGIDB_API: &str = "dgidb";
 const DGIDB_BASE_ENV: &str = "BIOMCP_DGIDB_BASE";
 const DGIDB_MAX_INTERACTIONS: usize = 15;
+const DGIDB_BATCH_GENE_LIMIT: usize = 100;
 
 const DGIDB_GENE_QUERY: &str = r#"
 query DgidbGeneDruggability($gene: String!, $first: Int!) {
@@ -38,6 +40,22 @@ query DgidbGeneDruggability($gene: String!, $first: Int!) {
 }
 "#;
 
+const DGIDB_GENE_DRUG_COUNTS_QUERY: &str = r#"
+query DgidbGeneDrugCounts($genes: [String!]!, $first: Int!) {
+  genes(names: $genes, first: $first) {
+    nodes {
+      name
+      interactions {
+        drug {
+          name
+          approved
+        }
+      }
+    }
+  }
+}
+"#;
+
 pub struct DgidbClient {
     client: reqwest_middleware::ClientWithMiddleware,
     base: Cow<'static, str>,
@@ -109,20 +127,7 @@ impl DgidbClient {
         };
         let resp: GraphQlResponse<DgidbGeneData> =
             self.post_json(self.client.post(&url), &body).await?;
-
-        if let Some(errors) = resp.errors {
-            let message = errors
-                .into_iter()
-                .filter_map(|row| clean_optional(row.message))
-                .collect::<Vec<_>>()
-                .join("; ");
-            if !message.is_empty() {
-                return Err(BioMcpError::Api {
-                    api: DGIDB_API.to_string(),
-                    message,
-                });
-            }
-        }
+        check_graphql_errors(resp.errors)?;
 
         let Some(node) = resp
             .data
@@ -225,9 +230,83 @@ impl DgidbClient {
             safety_liabilities: Vec::new(),
         })
     }
+
+    /// Distinct interacting and approved drug counts for up to 100 symbols in
+    /// one request, keyed by upper-case symbol. Genes DGIdb does not know are
+    /// absent from the map.
+    pub async fn gene_drug_counts(
+        &self,
+        gene_names: &[String],
+    ) -> Result<HashMap<String, GeneDrugCounts>, BioMcpError> {
+        if gene_names.is_empty() {
+            return Ok(HashMap::new());
+        }
+        if gene_names.len() > DGIDB_BATCH_GENE_LIMIT {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "DGIdb batch lookup supports at most {DGIDB_BATCH_GENE_LIMIT} genes per request"
+            )));
+        }
+        let genes = gene_names
+            .iter()
+            .map(|name| normalize_gene_symbol(name))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let url = self.endpoint("graphql");
+        let body = GraphQlRequest {
+            query: DGIDB_GENE_DRUG_COUNTS_QUERY,
+            variables: serde_json::json!({
+                "genes": genes,
+                "first": genes.len(),
+            }),
+        };
+        let resp: GraphQlResponse<DgidbGeneData> =
+            self.post_json(self.client.post(&url), &body).await?;
+        check_graphql_errors(resp.errors)?;
+
+        let nodes = resp
+            .data
+            .and_then(|row| row.genes)
+            .map(|conn| conn.nodes)
+            .unwrap_or_default();
+        let mut out = HashMap::new();
+        for node in nodes {
+            let Some(name) = clean_optional(node.name) else {
+                continue;
+            };
+            let mut drugs = BTreeSet::new();
+            let mut approved = BTreeSet::new();
+            for row in node.interactions {
+                let Some(drug) = row.drug else {
+                    continue;
+                };
+                let Some(drug_name) = clean_optional(drug.name) else {
+                    continue;
+                };
+                let key = drug_name.to_ascii_lowercase();
+                if drug.approved == Some(true) {
+                    approved.insert(key.clone());
+                }
+                drugs.insert(key);
+            }
+            out.insert(
+                name.to_ascii_uppercase(),
+                GeneDrugCounts {
+                    drugs: drugs.len(),
+                    approved_drugs: approved.len(),
+                },
+            );
+        }
+        Ok(out)
+    }
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct GeneDrugCounts {
+    pub drugs: usize,
+    pub approved_drugs: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct GeneDruggability {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub categories: Vec<String>,
@@ -239,7 +318,7 @@ pub struct GeneDruggability {
     pub safety_liabilities: Vec<GeneSafetyLiability>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GeneTractabilityModality {
     pub modality: String,
     pub tractable: bool,
@@ -247,7 +326,7 @@ pub struct GeneTractabilityModality {
     pub evidence_labels: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GeneSafetyLiability {
     pub event: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -258,7 +337,7 @@ pub struct GeneSafetyLiability {
     pub biosample: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DrugInteraction {
     pub drug: String,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -309,6 +388,7 @@ struct DgidbGeneConnection {
 
 #[derive(Debug, Clone, Deserialize)]
 struct DgidbGeneNode {
+    name: Option<String>,
     #[serde(default, rename = "geneCategories")]
     gene_categories: Vec<DgidbCategoryRow>,
     #[serde(default)]
@@ -349,6 +429,22 @@ struct DgidbSourceRow {
     source_db_name: Option<String>,
 }
 
+fn check_graphql_errors(errors: Option<Vec<GraphQlError>>) -> Result<(), BioMcpError> {
+    let message = errors
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|row| clean_optional(row.message))
+        .collect::<Vec<_>>()
+        .join("; ");
+    if message.is_empty() {
+        return Ok(());
+    }
+    Err(BioMcpError::Api {
+        api: DGIDB_API.to_string(),
+        message,
+    })
+}
+
 fn normalize_gene_symbol(value: &str) -> Result<String, BioMcpError> {
     let normalized = value.trim().to_ascii_uppercase();
     if normalized.is_empty() {
@@ -474,6 +570,49 @@ mod tests {
         assert!(err.to_string().contains("GraphQL validation failed"));
     }
 
+    #[tokio::test]
+    async fn gene_drug_counts_counts_distinct_and_approved_drugs_per_gene() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("DgidbGeneDrugCounts"))
+            .and(body_string_contains("EGFR"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "genes": {
+                        "nodes": [{
+                            "name": "EGFR",
+                            "interactions": [
+                                {"drug": {"name": "ERLOTINIB", "approved": true}},
+                                {"drug": {"name": "erlotinib", "approved": true}},
+                                {"drug": {"name": "CETUXIMAB", "approved": true}},
+                                {"drug": {"name": "NECITUMUMAB", "approved": false}}
+                            ]
+                        }]
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = DgidbClient::new_for_test(server.uri()).expect("client");
+        let counts = client
+            .gene_drug_counts(&["egfr".to_string(), "XIST".to_string()])
+            .await
+            .expect("counts");
+
+        assert_eq!(
+            counts.get("EGFR"),
+            Some(&GeneDrugCounts {
+                drugs: 3,
+                approved_drugs: 2
+            })
+        );
+        assert!(!counts.contains_key("XIST"));
+    }
+
     #[tokio::test]
     async fn gene_interactions_rejects_invalid_symbol() {
         let client = DgidbClient::new_for_test("http://127.0.0.1".into()).expect("client");
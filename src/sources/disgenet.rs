@@ -27,10 +27,7 @@ impl DisgenetClient {
         Ok(Self {
             client: crate::sources::shared_client()?,
             base: crate::sources::env_base(DISGENET_BASE, DISGENET_BASE_ENV),
-            api_key: std::env::var(DISGENET_API_KEY_ENV)
-                .ok()
-                .map(|value| value.trim().to_string())
-                .filter(|value| !value.is_empty()),
+            api_key: crate::sources::tenant::api_key(DISGENET_API_KEY_ENV),
         })
     }
 
@@ -419,6 +416,7 @@ mod tests {
             uniprot_id: None,
             summary: None,
             gene_type: None,
+            species: None,
             aliases: Vec::new(),
             clinical_diseases: Vec::new(),
             clinical_drugs: Vec::new(),
@@ -435,8 +433,14 @@ mod tests {
             clingen: None,
             constraint: None,
             disgenet: None,
+            clinvar_summary: None,
+            tractability: None,
             funding: None,
             funding_note: None,
+            key_papers: None,
+            dependency: None,
+            orthologs: None,
+            plugin_sections: Vec::new(),
         }
     }
 
@@ -467,6 +471,10 @@ mod tests {
             prevalence_note: None,
             survival: None,
             survival_note: None,
+            epidemiology: None,
+            epidemiology_note: None,
+            therapies: None,
+            therapies_note: None,
             civic: None,
             disgenet: None,
             funding: None,
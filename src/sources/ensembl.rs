@@ -0,0 +1,413 @@
+//! Ensembl REST client for stable-ID lookups, cross-references, and Compara
+//! orthologues.
+//!
+//! Uses the main GRCh38 site. Ensembl answers unknown IDs with HTTP 400 and an
+//! `"ID '...' not found"` error body, which [`EnsemblClient::lookup`] maps to
+//! `None`.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+
+pub(crate) const ENSEMBL_BASE: &str = "https://rest.ensembl.org";
+const ENSEMBL_API: &str = "ensembl";
+pub(crate) const ENSEMBL_BASE_ENV: &str = "BIOMCP_ENSEMBL_BASE";
+
+/// `lookup/id` record; transcripts carry exons and translation with `expand=1`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EnsemblLookup {
+    pub id: String,
+    pub version: Option<u32>,
+    pub display_name: Option<String>,
+    pub biotype: Option<String>,
+    pub assembly_name: Option<String>,
+    pub seq_region_name: Option<String>,
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+    pub strand: Option<i8>,
+    /// Parent gene ID for transcripts.
+    #[serde(rename = "Parent")]
+    pub parent: Option<String>,
+    /// `1` on the Ensembl canonical transcript.
+    pub is_canonical: Option<u8>,
+    #[serde(rename = "Exon", default)]
+    pub exons: Vec<EnsemblExon>,
+    #[serde(rename = "Translation")]
+    pub translation: Option<EnsemblTranslation>,
+    /// MANE records, requested with `mane=1`.
+    #[serde(rename = "MANE", default)]
+    pub mane: Vec<EnsemblMane>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EnsemblExon {
+    pub id: String,
+    pub start: u64,
+    pub end: u64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EnsemblTranslation {
+    pub id: String,
+    pub version: Option<u32>,
+    /// Protein length in amino acids.
+    pub length: Option<u32>,
+    /// Genomic start of the coding sequence.
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EnsemblMane {
+    /// `MANE_Select` or `MANE_Plus_Clinical`.
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    pub refseq_match: Option<String>,
+}
+
+/// One `xrefs/id` entry.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EnsemblXref {
+    pub dbname: String,
+    pub db_display_name: Option<String>,
+    pub primary_id: Option<String>,
+    pub display_id: Option<String>,
+}
+
+/// One side of a Compara homology pair.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EnsemblHomologyMember {
+    pub id: String,
+    /// Production name, e.g. `mus_musculus`.
+    pub species: String,
+    pub taxon_id: Option<u32>,
+    pub protein_id: Option<String>,
+    /// Percentage of this member's protein identical to the other member.
+    pub perc_id: Option<f64>,
+}
+
+/// One `homology/id` pair; `kind` is e.g. `ortholog_one2one`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EnsemblHomology {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub source: EnsemblHomologyMember,
+    pub target: EnsemblHomologyMember,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct EnsemblHomologyResponse {
+    #[serde(default)]
+    data: Vec<EnsemblHomologyEntry>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct EnsemblHomologyEntry {
+    #[serde(default)]
+    homologies: Vec<EnsemblHomology>,
+}
+
+#[derive(Serialize)]
+struct EnsemblLookupBatchRequest<'a> {
+    ids: &'a [String],
+}
+
+#[derive(Debug, Deserialize)]
+struct EnsemblErrorBody {
+    error: Option<String>,
+}
+
+pub struct EnsemblClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+}
+
+impl EnsemblClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(ENSEMBL_BASE, ENSEMBL_BASE_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+        })
+    }
+
+    fn endpoint(&self, path: &str, id: &str) -> String {
+        format!("{}/{path}/{id}", self.base.as_ref().trim_end_matches('/'))
+    }
+
+    /// Sends `req` for JSON; `None` when Ensembl reports the ID as unknown.
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+    ) -> Result<Option<T>, BioMcpError> {
+        let req = req.query(&[("content-type", "application/json")]);
+        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, ENSEMBL_API).await?;
+
+        if !status.is_success() {
+            let message = serde_json::from_slice::<EnsemblErrorBody>(&bytes)
+                .ok()
+                .and_then(|body| body.error)
+                .unwrap_or_else(|| crate::sources::body_excerpt(&bytes));
+            if status == reqwest::StatusCode::BAD_REQUEST && message.contains("not found") {
+                return Ok(None);
+            }
+            return Err(BioMcpError::Api {
+                api: ENSEMBL_API.to_string(),
+                message: format!("HTTP {status}: {message}"),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(ENSEMBL_API, content_type.as_ref(), &bytes)?;
+        serde_json::from_slice(&bytes)
+            .map(Some)
+            .map_err(|source| BioMcpError::ApiJson {
+                api: ENSEMBL_API.to_string(),
+                source,
+            })
+    }
+
+    /// Looks up an unversioned stable ID; `expand` adds exons and translation
+    /// to transcripts.
+    pub async fn lookup(
+        &self,
+        id: &str,
+        expand: bool,
+    ) -> Result<Option<EnsemblLookup>, BioMcpError> {
+        let req = self
+            .client
+            .get(self.endpoint("lookup/id", id.trim()))
+            .query(&[("expand", if expand { "1" } else { "0" }), ("mane", "1")]);
+        self.get_json(req).await
+    }
+
+    /// Cross-references attached directly to a stable ID.
+    pub async fn xrefs(&self, id: &str) -> Result<Vec<EnsemblXref>, BioMcpError> {
+        let req = self.client.get(self.endpoint("xrefs/id", id.trim()));
+        Ok(self.get_json(req).await?.unwrap_or_default())
+    }
+
+    /// Compara orthologues of a human gene, addressed by Ensembl gene ID or
+    /// symbol, in the given target species (production names). Empty when
+    /// Ensembl does not know the gene.
+    pub async fn human_orthologues(
+        &self,
+        gene: &str,
+        target_species: &[&str],
+    ) -> Result<Vec<EnsemblHomology>, BioMcpError> {
+        let gene = gene.trim();
+        let path = if gene.to_ascii_uppercase().starts_with("ENSG") {
+            "homology/id/human"
+        } else {
+            "homology/symbol/human"
+        };
+        let mut params = vec![
+            ("type", "orthologues"),
+            ("format", "full"),
+            ("sequence", "none"),
+        ];
+        params.extend(
+            target_species
+                .iter()
+                .map(|species| ("target_species", *species)),
+        );
+        let req = self.client.get(self.endpoint(path, gene)).query(&params);
+        let resp: Option<EnsemblHomologyResponse> = self.get_json(req).await?;
+        Ok(resp
+            .map(|resp| {
+                resp.data
+                    .into_iter()
+                    .flat_map(|entry| entry.homologies)
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Batch `lookup/id`; IDs Ensembl does not know are left out.
+    pub async fn lookup_many(
+        &self,
+        ids: &[String],
+    ) -> Result<HashMap<String, EnsemblLookup>, BioMcpError> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let url = format!("{}/lookup/id", self.base.as_ref().trim_end_matches('/'));
+        let req = self
+            .client
+            .post(url)
+            .json(&EnsemblLookupBatchRequest { ids });
+        let rows: Option<HashMap<String, Option<EnsemblLookup>>> = self.get_json(req).await?;
+        Ok(rows
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(id, row)| row.map(|row| (id, row)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn lookup_parses_expanded_transcript() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/lookup/id/ENST00000288602"))
+            .and(query_param("expand", "1"))
+            .and(query_param("mane", "1"))
+            .and(query_param("content-type", "application/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "ENST00000288602",
+                "version": 11,
+                "object_type": "Transcript",
+                "display_name": "BRAF-201",
+                "biotype": "protein_coding",
+                "Parent": "ENSG00000157764",
+                "is_canonical": 1,
+                "seq_region_name": "7",
+                "start": 140719327,
+                "end": 140924929,
+                "strand": -1,
+                "assembly_name": "GRCh38",
+                "Exon": [
+                    {"id": "ENSE00001903155", "start": 140924566, "end": 140924929},
+                    {"id": "ENSE00003651637", "start": 140850110, "end": 140850212}
+                ],
+                "Translation": {"id": "ENSP00000288602", "version": 7, "length": 766},
+                "MANE": [{"type": "MANE_Select", "refseq_match": "NM_004333.6"}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EnsemblClient::new_for_test(server.uri()).expect("client");
+        let lookup = client
+            .lookup("ENST00000288602", true)
+            .await
+            .expect("lookup")
+            .expect("known transcript");
+        assert_eq!(lookup.parent.as_deref(), Some("ENSG00000157764"));
+        assert_eq!(lookup.is_canonical, Some(1));
+        assert_eq!(lookup.strand, Some(-1));
+        assert_eq!(lookup.exons.len(), 2);
+        assert_eq!(
+            lookup.translation.as_ref().and_then(|row| row.length),
+            Some(766)
+        );
+        assert_eq!(lookup.mane[0].refseq_match.as_deref(), Some("NM_004333.6"));
+    }
+
+    #[tokio::test]
+    async fn human_orthologues_flattens_homologies_and_lookup_many_skips_unknown_ids() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/homology/id/human/ENSG00000157764"))
+            .and(query_param("type", "orthologues"))
+            .and(query_param("sequence", "none"))
+            .and(query_param("target_species", "mus_musculus"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{
+                    "id": "ENSG00000157764",
+                    "homologies": [{
+                        "type": "ortholog_one2one",
+                        "method_link_type": "ENSEMBL_ORTHOLOGUES",
+                        "source": {
+                            "id": "ENSG00000157764",
+                            "species": "homo_sapiens",
+                            "taxon_id": 9606,
+                            "perc_id": 95.28
+                        },
+                        "target": {
+                            "id": "ENSMUSG00000002413",
+                            "species": "mus_musculus",
+                            "taxon_id": 10090,
+                            "protein_id": "ENSMUSP00000002487",
+                            "perc_id": 96.37
+                        }
+                    }]
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/lookup/id"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ENSMUSG00000002413": {"id": "ENSMUSG00000002413", "display_name": "Braf"},
+                "ENSMUSG99999999999": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EnsemblClient::new_for_test(server.uri()).expect("client");
+        let homologies = client
+            .human_orthologues("ENSG00000157764", &["mus_musculus"])
+            .await
+            .expect("orthologues");
+        assert_eq!(homologies.len(), 1);
+        assert_eq!(homologies[0].kind, "ortholog_one2one");
+        assert_eq!(homologies[0].target.taxon_id, Some(10090));
+        assert_eq!(homologies[0].source.perc_id, Some(95.28));
+
+        let lookups = client
+            .lookup_many(&[
+                "ENSMUSG00000002413".to_string(),
+                "ENSMUSG99999999999".to_string(),
+            ])
+            .await
+            .expect("lookup many");
+        assert_eq!(lookups.len(), 1);
+        assert_eq!(
+            lookups["ENSMUSG00000002413"].display_name.as_deref(),
+            Some("Braf")
+        );
+    }
+
+    #[tokio::test]
+    async fn lookup_maps_unknown_ids_to_none_and_surfaces_other_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/lookup/id/ENST99999999999"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "ID 'ENST99999999999' not found"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/xrefs/id/ENST00000288602"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("maintenance"))
+            .mount(&server)
+            .await;
+
+        let client = EnsemblClient::new_for_test(server.uri()).expect("client");
+        assert!(
+            client
+                .lookup("ENST99999999999", true)
+                .await
+                .expect("lookup")
+                .is_none()
+        );
+        let err = client
+            .xrefs("ENST00000288602")
+            .await
+            .expect_err("server error");
+        assert!(err.to_string().contains("HTTP 503"));
+    }
+}
@@ -6,9 +6,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::BioMcpError;
 
-const EUROPE_PMC_BASE: &str = "https://www.ebi.ac.uk/europepmc/webservices/rest";
+pub(crate) const EUROPE_PMC_BASE: &str = "https://www.ebi.ac.uk/europepmc/webservices/rest";
 const EUROPE_PMC_API: &str = "europepmc";
-const EUROPE_PMC_BASE_ENV: &str = "BIOMCP_EUROPEPMC_BASE";
+pub(crate) const EUROPE_PMC_BASE_ENV: &str = "BIOMCP_EUROPEPMC_BASE";
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum EuropePmcSort {
@@ -33,7 +33,7 @@ impl EuropePmcClient {
     }
 
     #[cfg(test)]
-    fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+    pub(crate) fn new_for_test(base: String) -> Result<Self, BioMcpError> {
         Ok(Self {
             client: crate::sources::test_client()?,
             base: Cow::Owned(base),
@@ -217,7 +217,12 @@ impl EuropePmcClient {
         }
 
         let status = resp.status();
-        let bytes = crate::sources::read_limited_body(resp, EUROPE_PMC_API).await?;
+        let bytes = crate::sources::read_limited_body_with_limit(
+            resp,
+            EUROPE_PMC_API,
+            crate::sources::LARGE_DOCUMENT_MAX_BODY_BYTES,
+        )
+        .await?;
         if !status.is_success() {
             let excerpt = crate::sources::body_excerpt(&bytes);
             return Err(BioMcpError::Api {
@@ -228,6 +233,62 @@ impl EuropePmcClient {
 
         Ok(Some(String::from_utf8_lossy(&bytes).to_string()))
     }
+
+    /// Articles citing `source`/`id` (e.g. `MED`/PMID), first page only.
+    pub async fn citations(
+        &self,
+        source: &str,
+        id: &str,
+        page_size: usize,
+    ) -> Result<EuropePmcCitationsResponse, BioMcpError> {
+        let url = self.linked_articles_endpoint(source, id, "citations")?;
+        let page_size = page_size.clamp(1, 1000).to_string();
+        let req = self.client.get(&url).query(&[
+            ("format", "json"),
+            ("page", "1"),
+            ("pageSize", page_size.as_str()),
+        ]);
+        self.get_json(req).await
+    }
+
+    /// Reference list of `source`/`id`, first page only. Europe PMC rejects
+    /// page sizes below 25 for this endpoint.
+    pub async fn references(
+        &self,
+        source: &str,
+        id: &str,
+        page_size: usize,
+    ) -> Result<EuropePmcReferencesResponse, BioMcpError> {
+        let url = self.linked_articles_endpoint(source, id, "references")?;
+        let page_size = page_size.clamp(25, 1000).to_string();
+        let req = self.client.get(&url).query(&[
+            ("format", "json"),
+            ("page", "1"),
+            ("pageSize", page_size.as_str()),
+        ]);
+        self.get_json(req).await
+    }
+
+    fn linked_articles_endpoint(
+        &self,
+        source: &str,
+        id: &str,
+        kind: &str,
+    ) -> Result<String, BioMcpError> {
+        let source = source.trim();
+        let id = id.trim();
+        if !matches!(source, "MED" | "PMC") {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "Europe PMC {kind} need a MED or PMC source, got \"{source}\""
+            )));
+        }
+        if id.is_empty() || id.len() > 64 || !id.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "Invalid Europe PMC article id for {kind}: \"{id}\""
+            )));
+        }
+        Ok(self.endpoint(&format!("{source}/{id}/{kind}")))
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -271,6 +332,52 @@ pub struct EuropePmcResult {
     pub abstract_text: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct EuropePmcCitationsResponse {
+    #[serde(rename = "hitCount")]
+    pub hit_count: Option<u64>,
+    #[serde(rename = "citationList")]
+    pub citation_list: Option<EuropePmcCitationList>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EuropePmcCitationList {
+    #[serde(default)]
+    pub citation: Vec<EuropePmcLinkedArticle>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EuropePmcReferencesResponse {
+    #[serde(rename = "hitCount")]
+    pub hit_count: Option<u64>,
+    #[serde(rename = "referenceList")]
+    pub reference_list: Option<EuropePmcReferenceList>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EuropePmcReferenceList {
+    #[serde(default)]
+    pub reference: Vec<EuropePmcLinkedArticle>,
+}
+
+/// One citing or referenced article. Numbers arrive as JSON numbers on the
+/// citations endpoint and as strings on the references endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EuropePmcLinkedArticle {
+    pub id: Option<String>,
+    /// `MED` for PubMed records; `PPR`, `AGR`, `CTX`, ... otherwise.
+    pub source: Option<String>,
+    pub title: Option<String>,
+    #[serde(rename = "authorString")]
+    pub author_string: Option<String>,
+    #[serde(rename = "journalAbbreviation")]
+    pub journal_abbreviation: Option<String>,
+    #[serde(rename = "pubYear")]
+    pub pub_year: Option<serde_json::Value>,
+    #[serde(rename = "citedByCount")]
+    pub cited_by_count: Option<serde_json::Value>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -355,6 +462,51 @@ mod tests {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn citations_and_references_use_source_scoped_endpoints() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/MED/22663011/citations"))
+            .and(query_param("format", "json"))
+            .and(query_param("pageSize", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "hitCount": 2,
+                "citationList": {"citation": [
+                    {"id": "30000001", "source": "MED", "title": "Citing one", "pubYear": 2019, "citedByCount": 12},
+                    {"id": "PPR100", "source": "PPR", "title": "Preprint citing", "pubYear": 2020}
+                ]}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/MED/22663011/references"))
+            .and(query_param("pageSize", "25"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "hitCount": 1,
+                "referenceList": {"reference": [
+                    {"id": "20000001", "source": "MED", "title": "Referenced one", "pubYear": "2010", "citedByCount": "40"}
+                ]}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EuropePmcClient::new_for_test(server.uri()).unwrap();
+        let citations = client.citations("MED", "22663011", 100).await.unwrap();
+        assert_eq!(citations.hit_count, Some(2));
+        let rows = citations.citation_list.expect("citation list").citation;
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].source.as_deref(), Some("PPR"));
+
+        let references = client.references("MED", "22663011", 10).await.unwrap();
+        let rows = references.reference_list.expect("reference list").reference;
+        assert_eq!(rows[0].id.as_deref(), Some("20000001"));
+
+        let err = client.citations("DOI", "10.1/x", 10).await.unwrap_err();
+        assert!(matches!(err, BioMcpError::InvalidArgument(_)));
+    }
+
     #[tokio::test]
     async fn search_by_pmid_rejects_non_numeric_values() {
         let client = EuropePmcClient::new_for_test("http://127.0.0.1".into()).unwrap();
@@ -1,8 +1,10 @@
 use std::borrow::Cow;
 
+use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::entities::variant::GnomadVersion;
 use crate::error::BioMcpError;
 
 pub(crate) const GNOMAD_BASE: &str = "https://gnomad.broadinstitute.org/api";
@@ -25,6 +27,74 @@ pub struct GnomadConstraintData {
     pub transcript: Option<String>,
 }
 
+/// Allele counts for one gnomAD release of a variant, per sequencing type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GnomadVariantFrequencies {
+    /// gnomAD release, e.g. `v4`.
+    pub version: String,
+    /// gnomAD variant ID on the release's reference genome, e.g. `7-140753336-A-T`.
+    pub variant_id: String,
+    pub reference_genome: String,
+    /// Variant-level quality flags such as `lcr` (low-complexity region) or `segdup`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exome: Option<GnomadSequencingFrequencies>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genome: Option<GnomadSequencingFrequencies>,
+}
+
+/// Exome or genome call set of a gnomAD variant.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GnomadSequencingFrequencies {
+    pub ac: u64,
+    pub an: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub af: Option<f64>,
+    /// Number of homozygous alternate individuals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nhomalt: Option<u64>,
+    /// Failed quality filters, e.g. `AC0` or `RF`; empty means PASS.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filters: Vec<String>,
+    /// Genetic ancestry group with the highest filtering allele frequency (FAF95).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub popmax: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub popmax_faf95: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mean_coverage: Option<f64>,
+    /// Fraction of samples covered at 20x or more at this position.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub over_20x: Option<f64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub populations: Vec<GnomadPopulationCounts>,
+}
+
+/// Allele counts for one genetic ancestry group or subgroup.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GnomadPopulationCounts {
+    /// gnomAD group ID, e.g. `nfe` or the subgroup `nfe_est`.
+    pub id: String,
+    pub population: String,
+    pub ac: u64,
+    pub an: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub af: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nhomalt: Option<u64>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_subgroup: bool,
+}
+
+/// How to find a variant in gnomAD.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GnomadVariantKey {
+    /// `chrom-pos-ref-alt` on the release's reference genome.
+    VariantId(String),
+    Rsid(String),
+}
+
 #[derive(Serialize)]
 struct GraphQlRequest {
     query: &'static str,
@@ -64,6 +134,180 @@ struct ConstraintPayload {
     syn_z: Option<f64>,
 }
 
+#[derive(Deserialize)]
+struct VariantResponse {
+    variant: Option<VariantPayload>,
+}
+
+#[derive(Deserialize)]
+struct VariantPayload {
+    variant_id: String,
+    reference_genome: Option<String>,
+    #[serde(default)]
+    flags: Option<Vec<String>>,
+    exome: Option<SequencingPayload>,
+    genome: Option<SequencingPayload>,
+    coverage: Option<CoveragePayload>,
+}
+
+#[derive(Deserialize)]
+struct SequencingPayload {
+    ac: Option<u64>,
+    an: Option<u64>,
+    homozygote_count: Option<u64>,
+    #[serde(default)]
+    filters: Option<Vec<String>>,
+    faf95: Option<Faf95Payload>,
+    #[serde(default)]
+    populations: Option<Vec<PopulationPayload>>,
+}
+
+#[derive(Deserialize)]
+struct Faf95Payload {
+    popmax: Option<f64>,
+    popmax_population: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PopulationPayload {
+    id: String,
+    ac: Option<u64>,
+    an: Option<u64>,
+    homozygote_count: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct CoveragePayload {
+    exome: Option<SiteCoveragePayload>,
+    genome: Option<SiteCoveragePayload>,
+}
+
+#[derive(Deserialize)]
+struct SiteCoveragePayload {
+    mean: Option<f64>,
+    over_20: Option<f64>,
+}
+
+const VARIANT_FREQUENCIES_QUERY: &str = r#"
+query VariantFrequencies($variantId: String, $rsid: String, $dataset: DatasetId!) {
+  variant(variantId: $variantId, rsid: $rsid, dataset: $dataset) {
+    variant_id
+    reference_genome
+    flags
+    exome { ...Frequencies }
+    genome { ...Frequencies }
+    coverage {
+      exome { mean over_20 }
+      genome { mean over_20 }
+    }
+  }
+}
+
+fragment Frequencies on VariantDetailsSequencingTypeData {
+  ac
+  an
+  homozygote_count
+  filters
+  faf95 { popmax popmax_population }
+  populations { id ac an homozygote_count }
+}
+"#;
+
+fn graphql_error_messages(errors: &[GraphQlError]) -> Vec<&str> {
+    errors
+        .iter()
+        .filter_map(|error| error.message.as_deref())
+        .map(str::trim)
+        .filter(|message| !message.is_empty())
+        .collect()
+}
+
+fn graphql_error(messages: &[&str]) -> BioMcpError {
+    let message = if messages.is_empty() {
+        "GraphQL request failed".to_string()
+    } else {
+        messages.join("; ")
+    };
+    BioMcpError::Api {
+        api: GNOMAD_API.to_string(),
+        message,
+    }
+}
+
+fn allele_frequency(ac: u64, an: u64) -> Option<f64> {
+    (an > 0).then(|| ac as f64 / an as f64)
+}
+
+/// Display name for a gnomAD genetic ancestry group ID; subgroups keep their ID.
+fn population_label(id: &str) -> String {
+    let label = match id {
+        "afr" => "African/African American",
+        "ami" => "Amish",
+        "amr" => "Latino/Admixed American",
+        "asj" => "Ashkenazi Jewish",
+        "eas" => "East Asian",
+        "fin" => "European (Finnish)",
+        "mid" => "Middle Eastern",
+        "nfe" => "European (non-Finnish)",
+        "sas" => "South Asian",
+        "oth" | "remaining" => "Remaining",
+        other => other,
+    };
+    label.to_string()
+}
+
+/// Sex-stratified rows (`XX`, `afr_XY`, `female`) repeat the ancestry totals
+/// and are left out.
+fn is_sex_stratified(id: &str) -> bool {
+    let last = id.rsplit('_').next().unwrap_or(id);
+    matches!(last, "XX" | "XY" | "female" | "male")
+}
+
+fn map_sequencing(
+    payload: SequencingPayload,
+    coverage: Option<&SiteCoveragePayload>,
+) -> GnomadSequencingFrequencies {
+    let ac = payload.ac.unwrap_or(0);
+    let an = payload.an.unwrap_or(0);
+    let populations = payload
+        .populations
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|population| !is_sex_stratified(&population.id))
+        .map(|population| {
+            let ac = population.ac.unwrap_or(0);
+            let an = population.an.unwrap_or(0);
+            GnomadPopulationCounts {
+                population: population_label(&population.id),
+                is_subgroup: population.id.contains('_'),
+                id: population.id,
+                ac,
+                an,
+                af: allele_frequency(ac, an),
+                nhomalt: population.homozygote_count,
+            }
+        })
+        .collect();
+    let faf95 = payload.faf95;
+    GnomadSequencingFrequencies {
+        ac,
+        an,
+        af: allele_frequency(ac, an),
+        nhomalt: payload.homozygote_count,
+        filters: payload.filters.unwrap_or_default(),
+        popmax: faf95
+            .as_ref()
+            .and_then(|faf95| faf95.popmax_population.as_deref())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string),
+        popmax_faf95: faf95.and_then(|faf95| faf95.popmax),
+        mean_coverage: coverage.and_then(|coverage| coverage.mean),
+        over_20x: coverage.and_then(|coverage| coverage.over_20),
+        populations,
+    }
+}
+
 impl GnomadClient {
     pub fn new() -> Result<Self, BioMcpError> {
         Ok(Self {
@@ -151,12 +395,7 @@ query GeneConstraint($symbol: String!) {
         let gene = resp.data.and_then(|data| data.gene);
 
         if !errors.is_empty() {
-            let messages = errors
-                .iter()
-                .filter_map(|error| error.message.as_deref())
-                .map(str::trim)
-                .filter(|message| !message.is_empty())
-                .collect::<Vec<_>>();
+            let messages = graphql_error_messages(&errors);
 
             if gene.is_none()
                 && !messages.is_empty()
@@ -167,16 +406,7 @@ query GeneConstraint($symbol: String!) {
                 return Ok(None);
             }
 
-            let message = if messages.is_empty() {
-                "GraphQL request failed".to_string()
-            } else {
-                messages.join("; ")
-            };
-
-            return Err(BioMcpError::Api {
-                api: GNOMAD_API.to_string(),
-                message,
-            });
+            return Err(graphql_error(&messages));
         }
 
         let Some(gene) = gene else {
@@ -208,6 +438,69 @@ query GeneConstraint($symbol: String!) {
             transcript,
         }))
     }
+
+    /// Exome and genome allele counts of a variant in one gnomAD release, or
+    /// `None` when the release does not contain the variant.
+    pub async fn variant_frequencies(
+        &self,
+        key: &GnomadVariantKey,
+        version: GnomadVersion,
+    ) -> Result<Option<GnomadVariantFrequencies>, BioMcpError> {
+        let variables = match key {
+            GnomadVariantKey::VariantId(id) => {
+                serde_json::json!({ "variantId": id.trim(), "dataset": version.dataset() })
+            }
+            GnomadVariantKey::Rsid(rsid) => {
+                serde_json::json!({ "rsid": rsid.trim(), "dataset": version.dataset() })
+            }
+        };
+        let body = GraphQlRequest {
+            query: VARIANT_FREQUENCIES_QUERY,
+            variables,
+        };
+
+        let resp: GraphQlResponse<VariantResponse> = self
+            .post_json(self.client.post(self.endpoint("")), &body)
+            .await?;
+
+        let errors = resp.errors.unwrap_or_default();
+        let variant = resp.data.and_then(|data| data.variant);
+
+        if !errors.is_empty() {
+            let messages = graphql_error_messages(&errors);
+            if variant.is_none()
+                && !messages.is_empty()
+                && messages
+                    .iter()
+                    .all(|message| message.eq_ignore_ascii_case("Variant not found"))
+            {
+                return Ok(None);
+            }
+            return Err(graphql_error(&messages));
+        }
+
+        let Some(variant) = variant else {
+            return Ok(None);
+        };
+        let coverage = variant.coverage.as_ref();
+        Ok(Some(GnomadVariantFrequencies {
+            version: version.as_str().to_string(),
+            variant_id: variant.variant_id,
+            reference_genome: variant
+                .reference_genome
+                .unwrap_or_else(|| version.assembly().as_str().to_string()),
+            flags: variant.flags.unwrap_or_default(),
+            exome: variant.exome.map(|exome| {
+                map_sequencing(exome, coverage.and_then(|coverage| coverage.exome.as_ref()))
+            }),
+            genome: variant.genome.map(|genome| {
+                map_sequencing(
+                    genome,
+                    coverage.and_then(|coverage| coverage.genome.as_ref()),
+                )
+            }),
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -332,4 +625,100 @@ mod tests {
         assert!(matches!(err, BioMcpError::Api { .. }));
         assert!(err.to_string().contains("upstream exploded"));
     }
+
+    #[tokio::test]
+    async fn variant_frequencies_maps_counts_coverage_and_popmax() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_string_contains("VariantFrequencies"))
+            .and(body_string_contains("\"rsid\":\"rs113488022\""))
+            .and(body_string_contains("\"dataset\":\"gnomad_r4\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "variant": {
+                        "variant_id": "7-140753336-A-T",
+                        "reference_genome": "GRCh38",
+                        "flags": ["lcr"],
+                        "exome": {
+                            "ac": 12,
+                            "an": 1461894,
+                            "homozygote_count": 1,
+                            "filters": [],
+                            "faf95": {"popmax": 0.0000123, "popmax_population": "nfe"},
+                            "populations": [
+                                {"id": "nfe", "ac": 10, "an": 1000000, "homozygote_count": 1},
+                                {"id": "nfe_XX", "ac": 6, "an": 500000, "homozygote_count": 0},
+                                {"id": "afr", "ac": 0, "an": 0, "homozygote_count": 0}
+                            ]
+                        },
+                        "genome": null,
+                        "coverage": {
+                            "exome": {"mean": 61.2, "over_20": 0.97},
+                            "genome": {"mean": 30.1, "over_20": 0.88}
+                        }
+                    }
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = GnomadClient::new_for_test(server.uri()).expect("client");
+        let frequencies = client
+            .variant_frequencies(
+                &GnomadVariantKey::Rsid("rs113488022".into()),
+                GnomadVersion::V4,
+            )
+            .await
+            .expect("frequencies")
+            .expect("variant result");
+
+        assert_eq!(frequencies.version, "v4");
+        assert_eq!(frequencies.variant_id, "7-140753336-A-T");
+        assert_eq!(frequencies.flags, vec!["lcr".to_string()]);
+        assert!(frequencies.genome.is_none());
+        let exome = frequencies.exome.expect("exome");
+        assert_eq!((exome.ac, exome.an, exome.nhomalt), (12, 1461894, Some(1)));
+        assert_eq!(exome.popmax.as_deref(), Some("nfe"));
+        assert_eq!(exome.popmax_faf95, Some(0.0000123));
+        assert_eq!(exome.mean_coverage, Some(61.2));
+        assert_eq!(exome.over_20x, Some(0.97));
+        let ids = exome
+            .populations
+            .iter()
+            .map(|population| population.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["nfe", "afr"]);
+        assert_eq!(exome.populations[0].population, "European (non-Finnish)");
+        assert_eq!(exome.populations[0].af, Some(0.00001));
+        assert_eq!(exome.populations[1].af, None);
+    }
+
+    #[tokio::test]
+    async fn variant_frequencies_returns_none_for_variant_not_found() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_string_contains("\"variantId\":\"1-55516888-G-GA\""))
+            .and(body_string_contains("\"dataset\":\"gnomad_r2_1\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "errors": [{"message": "Variant not found"}],
+                "data": {"variant": null}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = GnomadClient::new_for_test(server.uri()).expect("client");
+        let frequencies = client
+            .variant_frequencies(
+                &GnomadVariantKey::VariantId("1-55516888-G-GA".into()),
+                GnomadVersion::V2,
+            )
+            .await
+            .expect("not found should degrade");
+
+        assert!(frequencies.is_none());
+    }
 }
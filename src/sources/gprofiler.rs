@@ -54,7 +54,12 @@ impl GProfilerClient {
         req: reqwest::RequestBuilder,
         body: &B,
     ) -> Result<T, BioMcpError> {
-        let resp = req.json(body).send().await?;
+        // The plain client skips the middleware stack, so take the global
+        // concurrency permit here.
+        let resp = {
+            let _permit = crate::sources::concurrency::acquire_permit().await;
+            req.json(body).send().await?
+        };
         let status = resp.status();
         let bytes = crate::sources::read_limited_body(resp, GPROFILER_API).await?;
 
@@ -3,6 +3,7 @@ use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::sync::OnceLock;
 
+use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
@@ -232,13 +233,13 @@ fn compact_tissue_rows(rows: Vec<TissueExpression>) -> Vec<TissueExpression> {
     out
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct GeneExpression {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tissues: Vec<TissueExpression>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TissueExpression {
     pub tissue: String,
     pub median_tpm: f64,
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 use reqwest::header::HeaderValue;
 use roxmltree::{Document, Node};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::error::BioMcpError;
@@ -76,7 +77,7 @@ impl HpaClient {
     }
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub struct GeneHpa {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tissues: Vec<HpaTissueExpression>,
@@ -97,7 +98,7 @@ pub struct GeneHpa {
     pub rna_summary: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub struct HpaTissueExpression {
     pub tissue: String,
     pub level: String,
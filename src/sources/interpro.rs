@@ -109,6 +109,150 @@ impl InterProClient {
 
         Ok(out)
     }
+
+    /// Lists UniProt proteins that carry `entry_accession` (Pfam, InterPro, SMART, ...).
+    ///
+    /// `cursor` is the `next` URL from a previous page; InterPro encodes the
+    /// filters and page size into it.
+    pub async fn proteins_with_entry(
+        &self,
+        entry_accession: &str,
+        tax_id: Option<u32>,
+        reviewed_only: bool,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<InterProProteinPage, BioMcpError> {
+        let entry_accession = entry_accession.trim();
+        let database = entry_member_database(entry_accession).ok_or_else(|| {
+            BioMcpError::InvalidArgument(format!(
+                "Unrecognized domain accession \"{entry_accession}\". Use a Pfam (PF07714), InterPro (IPR000719), SMART (SM00219), CDD (cd00192), or PANTHER (PTHR24416) accession."
+            ))
+        })?;
+
+        let resp: InterProProteinResponse = match normalize_cursor(cursor)? {
+            Some(url) => self.get_json(self.client.get(url)).await?,
+            None => {
+                let proteins = if reviewed_only { "reviewed" } else { "uniprot" };
+                let mut path = format!("protein/{proteins}/entry/{database}/{entry_accession}/");
+                if let Some(tax_id) = tax_id {
+                    path.push_str(&format!("taxonomy/uniprot/{tax_id}/"));
+                }
+                let url = self.endpoint(&path);
+                let page_size = limit.clamp(1, MAX_PROTEIN_PAGE_SIZE).to_string();
+                self.get_json(
+                    self.client
+                        .get(&url)
+                        .query(&[("page_size", page_size.as_str())]),
+                )
+                .await?
+            }
+        };
+
+        let results = resp
+            .results
+            .into_iter()
+            .filter_map(|row| {
+                let meta = row.metadata?;
+                let accession = meta.accession.trim().to_string();
+                if accession.is_empty() {
+                    return None;
+                }
+                let locations = row
+                    .entries
+                    .iter()
+                    .flat_map(|entry| entry.entry_protein_locations.iter())
+                    .flat_map(|location| location.fragments.iter())
+                    .map(|fragment| format!("{}-{}", fragment.start, fragment.end))
+                    .collect();
+                Some(InterProProteinHit {
+                    accession,
+                    name: meta
+                        .name
+                        .map(|v| v.trim().to_string())
+                        .filter(|v| !v.is_empty()),
+                    gene: meta
+                        .gene
+                        .map(|v| v.trim().to_string())
+                        .filter(|v| !v.is_empty()),
+                    organism: meta
+                        .source_organism
+                        .and_then(|org| org.scientific_name.or(org.full_name))
+                        .map(|v| v.trim().to_string())
+                        .filter(|v| !v.is_empty()),
+                    length: meta.length,
+                    reviewed: meta
+                        .source_database
+                        .is_some_and(|db| db.eq_ignore_ascii_case("reviewed")),
+                    locations,
+                })
+            })
+            .collect();
+
+        Ok(InterProProteinPage {
+            results,
+            total: resp.count,
+            next_page_token: resp.next.filter(|v| !v.trim().is_empty()),
+        })
+    }
+}
+
+const MAX_PROTEIN_PAGE_SIZE: usize = 200;
+
+/// Maps a member-database accession to its InterPro API source name.
+fn entry_member_database(accession: &str) -> Option<&'static str> {
+    let accession = accession.trim();
+    let digits_after = |prefix: &str, len: usize| {
+        accession
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.len() == len && rest.chars().all(|ch| ch.is_ascii_digit()))
+    };
+    if digits_after("PF", 5) {
+        Some("pfam")
+    } else if digits_after("IPR", 6) {
+        Some("interpro")
+    } else if digits_after("SM", 5) {
+        Some("smart")
+    } else if digits_after("cd", 5) {
+        Some("cdd")
+    } else if digits_after("PTHR", 5) {
+        Some("panther")
+    } else {
+        None
+    }
+}
+
+fn normalize_cursor(cursor: Option<&str>) -> Result<Option<&str>, BioMcpError> {
+    let Some(cursor) = cursor.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+    let valid = reqwest::Url::parse(cursor)
+        .ok()
+        .is_some_and(|url| url.host_str() == Some("www.ebi.ac.uk"));
+    if !valid {
+        return Err(BioMcpError::InvalidArgument(
+            "--next-page token must be an InterPro URL. Use pagination.next_page_token from the previous result.".into(),
+        ));
+    }
+    Ok(Some(cursor))
+}
+
+#[derive(Debug, Clone)]
+pub struct InterProProteinPage {
+    pub results: Vec<InterProProteinHit>,
+    pub total: Option<usize>,
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InterProProteinHit {
+    pub accession: String,
+    pub name: Option<String>,
+    pub gene: Option<String>,
+    pub organism: Option<String>,
+    pub length: Option<u32>,
+    pub reviewed: bool,
+    /// Domain match ranges on the protein, e.g. `457-717`.
+    pub locations: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -137,6 +281,57 @@ struct InterProMetadata {
     r#type: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct InterProProteinResponse {
+    count: Option<usize>,
+    next: Option<String>,
+    #[serde(default)]
+    results: Vec<InterProProteinRow>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InterProProteinRow {
+    metadata: Option<InterProProteinMetadata>,
+    #[serde(default)]
+    entries: Vec<InterProProteinEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InterProProteinMetadata {
+    accession: String,
+    name: Option<String>,
+    gene: Option<String>,
+    source_database: Option<String>,
+    length: Option<u32>,
+    source_organism: Option<InterProSourceOrganism>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InterProSourceOrganism {
+    #[serde(rename = "scientificName")]
+    scientific_name: Option<String>,
+    #[serde(rename = "fullName")]
+    full_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InterProProteinEntry {
+    #[serde(default)]
+    entry_protein_locations: Vec<InterProLocation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InterProLocation {
+    #[serde(default)]
+    fragments: Vec<InterProFragment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InterProFragment {
+    start: u32,
+    end: u32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,6 +360,62 @@ mod tests {
         assert_eq!(rows[0].name.as_deref(), Some("Protein kinase"));
     }
 
+    #[tokio::test]
+    async fn proteins_with_entry_scopes_taxonomy_and_maps_locations() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/protein/reviewed/entry/pfam/PF07714/taxonomy/uniprot/9606/",
+            ))
+            .and(query_param("page_size", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "count": 94,
+                "next": "https://www.ebi.ac.uk/interpro/api/protein/reviewed/entry/pfam/PF07714/taxonomy/uniprot/9606/?cursor=abc&page_size=2",
+                "results": [{
+                    "metadata": {
+                        "accession": "P15056",
+                        "name": "Serine/threonine-protein kinase B-raf",
+                        "gene": "BRAF",
+                        "source_database": "reviewed",
+                        "length": 766,
+                        "source_organism": {"taxId": "9606", "scientificName": "Homo sapiens"}
+                    },
+                    "entries": [{
+                        "accession": "pf07714",
+                        "entry_protein_locations": [{"fragments": [{"start": 457, "end": 717}]}]
+                    }]
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = InterProClient::new_for_test(server.uri()).unwrap();
+        let page = client
+            .proteins_with_entry("PF07714", Some(9606), true, 2, None)
+            .await
+            .unwrap();
+        assert_eq!(page.total, Some(94));
+        assert!(page.next_page_token.is_some());
+        assert_eq!(page.results.len(), 1);
+        let hit = &page.results[0];
+        assert_eq!(hit.accession, "P15056");
+        assert_eq!(hit.gene.as_deref(), Some("BRAF"));
+        assert_eq!(hit.organism.as_deref(), Some("Homo sapiens"));
+        assert!(hit.reviewed);
+        assert_eq!(hit.locations, vec!["457-717"]);
+    }
+
+    #[test]
+    fn entry_member_database_recognizes_supported_accessions() {
+        assert_eq!(entry_member_database("PF07714"), Some("pfam"));
+        assert_eq!(entry_member_database("IPR000719"), Some("interpro"));
+        assert_eq!(entry_member_database("SM00219"), Some("smart"));
+        assert_eq!(entry_member_database("cd00192"), Some("cdd"));
+        assert_eq!(entry_member_database("PTHR24416"), Some("panther"));
+        assert_eq!(entry_member_database("kinase"), None);
+        assert_eq!(entry_member_database("PF0771"), None);
+    }
+
     #[tokio::test]
     async fn domains_rejects_empty_accession() {
         let client = InterProClient::new_for_test("http://127.0.0.1".into()).unwrap();
@@ -0,0 +1,185 @@
+use std::borrow::Cow;
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::BioMcpError;
+
+const IPD_IMGT_BASE: &str = "https://www.ebi.ac.uk/cgi-bin/ipd/api";
+const IPD_IMGT_API: &str = "ipd-imgt";
+const IPD_IMGT_BASE_ENV: &str = "BIOMCP_IPD_IMGT_BASE";
+const IPD_IMGT_MAX_LIMIT: usize = 100;
+
+/// IPD-IMGT/HLA allele registry client.
+///
+/// The registry is the WHO-nomenclature source of truth for HLA allele names
+/// and their stable `HLA#####` accessions.
+pub struct IpdImgtClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+}
+
+impl IpdImgtClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(IPD_IMGT_BASE, IPD_IMGT_BASE_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base.as_ref().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+    ) -> Result<T, BioMcpError> {
+        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, IPD_IMGT_API).await?;
+
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: IPD_IMGT_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(IPD_IMGT_API, content_type.as_ref(), &bytes)?;
+        serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+            api: IPD_IMGT_API.to_string(),
+            source,
+        })
+    }
+
+    /// Lists registered HLA alleles whose name starts with `prefix` (e.g. `B*57:01`).
+    pub async fn alleles_by_prefix(
+        &self,
+        prefix: &str,
+        limit: usize,
+    ) -> Result<IpdImgtAlleleSearchResponse, BioMcpError> {
+        let prefix = prefix.trim();
+        if prefix.is_empty() || prefix.contains('"') {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "Invalid HLA allele name: {prefix}"
+            )));
+        }
+        let limit = limit.clamp(1, IPD_IMGT_MAX_LIMIT);
+        let url = self.endpoint("allele");
+
+        let req = self.client.get(&url).query(&[
+            ("project", "HLA".to_string()),
+            ("query", format!("startsWith(name,\"{prefix}\")")),
+            ("fields", "name,accession".to_string()),
+            ("limit", limit.to_string()),
+        ]);
+
+        self.get_json(req).await
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IpdImgtAlleleSearchResponse {
+    #[serde(default)]
+    pub data: Vec<IpdImgtAllele>,
+    #[serde(default)]
+    pub meta: Option<IpdImgtMeta>,
+}
+
+impl IpdImgtAlleleSearchResponse {
+    pub fn total(&self) -> Option<usize> {
+        self.meta.as_ref().and_then(|meta| meta.total)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IpdImgtAllele {
+    #[serde(default)]
+    pub accession: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IpdImgtMeta {
+    #[serde(default)]
+    pub total: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn alleles_by_prefix_builds_starts_with_query() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/allele"))
+            .and(query_param("project", "HLA"))
+            .and(query_param("query", "startsWith(name,\"B*57:01\")"))
+            .and(query_param("limit", "25"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"accession": "HLA00381", "name": "B*57:01:01:01"},
+                    {"accession": "HLA03233", "name": "B*57:01:02"}
+                ],
+                "meta": {"total": 2}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = IpdImgtClient::new_for_test(server.uri()).expect("client");
+        let resp = client
+            .alleles_by_prefix("B*57:01", 25)
+            .await
+            .expect("alleles should parse");
+
+        assert_eq!(resp.total(), Some(2));
+        assert_eq!(resp.data[0].accession, "HLA00381");
+        assert_eq!(resp.data[1].name, "B*57:01:02");
+    }
+
+    #[tokio::test]
+    async fn alleles_by_prefix_reports_http_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/allele"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("maintenance"))
+            .mount(&server)
+            .await;
+
+        let client = IpdImgtClient::new_for_test(server.uri()).expect("client");
+        let err = client
+            .alleles_by_prefix("A*02:01", 5)
+            .await
+            .expect_err("503 should fail");
+        assert!(err.to_string().contains("ipd-imgt"));
+    }
+
+    #[tokio::test]
+    async fn alleles_by_prefix_rejects_quotes() {
+        let client = IpdImgtClient::new_for_test("http://127.0.0.1:9".into()).expect("client");
+        let err = client
+            .alleles_by_prefix("B*57\")", 5)
+            .await
+            .expect_err("quote should be rejected");
+        assert!(matches!(err, BioMcpError::InvalidArgument(_)));
+    }
+}
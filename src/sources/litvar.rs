@@ -0,0 +1,342 @@
+//! NCBI LitVar2 client for publications that mention a specific variant.
+
+use std::borrow::Cow;
+
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+
+const LITVAR_BASE: &str = "https://www.ncbi.nlm.nih.gov/research/litvar2-api";
+const LITVAR_API: &str = "litvar2";
+const LITVAR_BASE_ENV: &str = "BIOMCP_LITVAR_BASE";
+/// Highlighted passages kept per article.
+const MAX_SENTENCES_PER_ARTICLE: usize = 2;
+
+/// Variant entry from `variant/autocomplete/`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LitVarVariant {
+    /// LitVar ID, e.g. `litvar@rs113488022##`.
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub rsid: Option<String>,
+    pub name: Option<String>,
+    pub pmids_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct LitVarSearchPage {
+    #[serde(default)]
+    results: Vec<LitVarSearchHit>,
+    count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct LitVarSearchHit {
+    pmid: Option<serde_json::Value>,
+    title: Option<String>,
+    journal: Option<String>,
+    date: Option<String>,
+    text_hl: Option<String>,
+}
+
+/// One publication that LitVar links to the variant itself.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct LitVarArticle {
+    pub pmid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub journal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<String>,
+    /// Passages where the variant is mentioned.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sentences: Vec<String>,
+}
+
+/// Variant-level literature summary for the `litvar` section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct LitVarLiterature {
+    pub litvar_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub publication_count: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub articles: Vec<LitVarArticle>,
+}
+
+#[derive(Clone)]
+pub struct LitVarClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+}
+
+impl LitVarClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(LITVAR_BASE, LITVAR_BASE_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base.as_ref().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+    ) -> Result<T, BioMcpError> {
+        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, LITVAR_API).await?;
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: LITVAR_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+        crate::sources::ensure_json_content_type(LITVAR_API, content_type.as_ref(), &bytes)?;
+        serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+            api: LITVAR_API.to_string(),
+            source,
+        })
+    }
+
+    /// Resolves free text (`rs113488022`, `BRAF V600E`) to LitVar variants.
+    pub async fn autocomplete(&self, query: &str) -> Result<Vec<LitVarVariant>, BioMcpError> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "LitVar variant query is required".into(),
+            ));
+        }
+        let req = self
+            .client
+            .get(self.endpoint("variant/autocomplete/"))
+            .query(&[("query", query)]);
+        self.get_json(req).await
+    }
+
+    /// Finds the LitVar record for `query` and lists up to `limit` of the
+    /// highest-scoring publications that mention it; `None` when LitVar does
+    /// not know the variant.
+    pub async fn literature(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Option<LitVarLiterature>, BioMcpError> {
+        let candidates = self.autocomplete(query).await?;
+        let Some(variant) = best_variant(candidates, query) else {
+            return Ok(None);
+        };
+
+        let req = self.client.get(self.endpoint("search/")).query(&[
+            ("variant", variant.id.as_str()),
+            ("sort", "score desc"),
+            ("page", "1"),
+        ]);
+        let page: LitVarSearchPage = self.get_json(req).await?;
+        let articles = page
+            .results
+            .into_iter()
+            .filter_map(article_from_hit)
+            .take(limit)
+            .collect::<Vec<_>>();
+        let publication_count = variant
+            .pmids_count
+            .or(page.count)
+            .unwrap_or(articles.len() as u32);
+
+        Ok(Some(LitVarLiterature {
+            litvar_id: variant.id,
+            name: variant.name,
+            publication_count,
+            articles,
+        }))
+    }
+}
+
+/// Prefers an exact rsID hit, then the first suggestion.
+fn best_variant(mut candidates: Vec<LitVarVariant>, query: &str) -> Option<LitVarVariant> {
+    let query = query.trim();
+    let exact = candidates.iter().position(|variant| {
+        variant
+            .rsid
+            .as_deref()
+            .is_some_and(|rsid| rsid.eq_ignore_ascii_case(query))
+    });
+    match exact {
+        Some(index) => Some(candidates.swap_remove(index)),
+        None => candidates.into_iter().next(),
+    }
+}
+
+fn clean(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Splits `text_hl` into plain passages, dropping the `@@@` and `<m>` mention
+/// markers LitVar wraps around variant names.
+fn highlight_sentences(text: &str) -> Vec<String> {
+    let plain = text
+        .replace("@@@", "")
+        .replace("<m>", "")
+        .replace("</m>", "");
+    plain
+        .split("...")
+        .map(|passage| passage.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|passage| !passage.is_empty())
+        .take(MAX_SENTENCES_PER_ARTICLE)
+        .collect()
+}
+
+fn article_from_hit(hit: LitVarSearchHit) -> Option<LitVarArticle> {
+    let pmid = match hit.pmid? {
+        serde_json::Value::Number(value) => value.to_string(),
+        serde_json::Value::String(value) => value.trim().to_string(),
+        _ => return None,
+    };
+    if pmid.is_empty() {
+        return None;
+    }
+    let year = clean(hit.date).and_then(|date| {
+        let year = date.get(..4)?;
+        year.chars()
+            .all(|ch| ch.is_ascii_digit())
+            .then(|| year.to_string())
+    });
+    Some(LitVarArticle {
+        pmid,
+        title: clean(hit.title),
+        journal: clean(hit.journal),
+        year,
+        sentences: hit
+            .text_hl
+            .as_deref()
+            .map(highlight_sentences)
+            .unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn highlight_sentences_strips_markers_and_keeps_two_passages() {
+        let sentences = highlight_sentences(
+            "The @@@<m>BRAF V600E</m>@@@ mutation\n predicts response ... \
+             Patients with <m>V600E</m> ... a third passage",
+        );
+        assert_eq!(
+            sentences,
+            vec![
+                "The BRAF V600E mutation predicts response",
+                "Patients with V600E"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn literature_resolves_rsid_and_lists_scored_publications() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/variant/autocomplete/"))
+            .and(query_param("query", "rs113488022"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"_id": "litvar@rs121913377##", "rsid": "rs121913377", "gene": ["BRAF"], "name": "p.V600K"},
+                {
+                    "_id": "litvar@rs113488022##",
+                    "rsid": "rs113488022",
+                    "gene": ["BRAF"],
+                    "name": "p.V600E",
+                    "pmids_count": 11540
+                }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search/"))
+            .and(query_param("variant", "litvar@rs113488022##"))
+            .and(query_param("sort", "score desc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "results": [
+                    {
+                        "pmid": 20818844,
+                        "title": "Inhibition of mutated, activated BRAF in metastatic melanoma.",
+                        "journal": "N Engl J Med",
+                        "date": "2010-08-26T00:00:00Z",
+                        "text_hl": "Tumors carrying @@@<m>V600E</m>@@@ responded"
+                    },
+                    {"pmid": null, "title": "No identifier"},
+                    {"pmid": "22663011", "title": "Second", "date": "n.d."}
+                ],
+                "count": 11000
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = LitVarClient::new_for_test(server.uri()).expect("client");
+        let literature = client
+            .literature("rs113488022", 5)
+            .await
+            .expect("lookup")
+            .expect("known variant");
+        assert_eq!(literature.litvar_id, "litvar@rs113488022##");
+        assert_eq!(literature.name.as_deref(), Some("p.V600E"));
+        assert_eq!(literature.publication_count, 11540);
+        assert_eq!(literature.articles.len(), 2);
+        assert_eq!(literature.articles[0].pmid, "20818844");
+        assert_eq!(literature.articles[0].year.as_deref(), Some("2010"));
+        assert_eq!(
+            literature.articles[0].sentences,
+            vec!["Tumors carrying V600E responded"]
+        );
+        assert_eq!(literature.articles[1].pmid, "22663011");
+        assert!(literature.articles[1].year.is_none());
+    }
+
+    #[tokio::test]
+    async fn literature_returns_none_for_unknown_variants() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/variant/autocomplete/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+
+        let client = LitVarClient::new_for_test(server.uri()).expect("client");
+        assert!(
+            client
+                .literature("FAKE1 X1Y", 5)
+                .await
+                .expect("lookup")
+                .is_none()
+        );
+        let err = client.autocomplete("  ").await.expect_err("empty query");
+        assert!(matches!(err, BioMcpError::InvalidArgument(_)));
+    }
+}
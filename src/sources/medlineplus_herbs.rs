@@ -0,0 +1,402 @@
+//! MedlinePlus Herbs and Supplements monograph client for supplement-drug interactions.
+//!
+//! The monographs follow the Natural Medicines layout: an "Are there interactions with
+//! medications?" section grouped by severity, with one medication heading and one
+//! description paragraph per interaction.
+
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::error::BioMcpError;
+
+const MEDLINEPLUS_HERBS_BASE: &str = "https://medlineplus.gov";
+const MEDLINEPLUS_HERBS_API: &str = "medlineplus_herbs";
+const MEDLINEPLUS_HERBS_BASE_ENV: &str = "BIOMCP_MEDLINEPLUS_HERBS_BASE";
+const INTERACTIONS_HEADING: &str = "are there interactions with medications?";
+
+/// Evidence label attached to every monograph-derived interaction.
+pub const SUPPLEMENT_EVIDENCE_CONFIDENCE: &str = "low";
+
+/// Curated supplement monograph with its MedlinePlus natural-product page id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SupplementMonograph {
+    pub name: &'static str,
+    pub page_id: u32,
+}
+
+/// Supplements most often implicated in clinically relevant drug interactions.
+pub const SUPPLEMENT_MONOGRAPHS: &[SupplementMonograph] = &[
+    SupplementMonograph {
+        name: "St. John's wort",
+        page_id: 329,
+    },
+    SupplementMonograph {
+        name: "Ginkgo",
+        page_id: 333,
+    },
+    SupplementMonograph {
+        name: "Garlic",
+        page_id: 300,
+    },
+    SupplementMonograph {
+        name: "Turmeric",
+        page_id: 662,
+    },
+    SupplementMonograph {
+        name: "Goldenseal",
+        page_id: 943,
+    },
+    SupplementMonograph {
+        name: "Kava",
+        page_id: 872,
+    },
+    SupplementMonograph {
+        name: "Echinacea",
+        page_id: 981,
+    },
+    SupplementMonograph {
+        name: "Fish oil",
+        page_id: 993,
+    },
+    SupplementMonograph {
+        name: "Green tea",
+        page_id: 960,
+    },
+    SupplementMonograph {
+        name: "Licorice",
+        page_id: 881,
+    },
+    SupplementMonograph {
+        name: "Red yeast rice",
+        page_id: 925,
+    },
+    SupplementMonograph {
+        name: "Valerian",
+        page_id: 870,
+    },
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SupplementInteraction {
+    pub supplement: String,
+    pub medication: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    pub description: String,
+    pub evidence_confidence: String,
+    pub source_url: String,
+}
+
+pub struct MedlinePlusHerbsClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+}
+
+impl MedlinePlusHerbsClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(MEDLINEPLUS_HERBS_BASE, MEDLINEPLUS_HERBS_BASE_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+        })
+    }
+
+    fn endpoint(&self, page_id: u32) -> String {
+        format!(
+            "{}/druginfo/natural/{page_id}.html",
+            self.base.as_ref().trim_end_matches('/')
+        )
+    }
+
+    /// Fetches one monograph and returns its medication interaction entries.
+    pub async fn monograph_interactions(
+        &self,
+        monograph: SupplementMonograph,
+    ) -> Result<Vec<SupplementInteraction>, BioMcpError> {
+        let url = self.endpoint(monograph.page_id);
+        let resp = crate::sources::apply_cache_mode(self.client.get(&url))
+            .send()
+            .await?;
+        let status = resp.status();
+        let bytes = crate::sources::read_limited_body(resp, MEDLINEPLUS_HERBS_API).await?;
+
+        if !status.is_success() {
+            return Err(BioMcpError::Api {
+                api: MEDLINEPLUS_HERBS_API.to_string(),
+                message: format!("HTTP {status}: {}", crate::sources::body_excerpt(&bytes)),
+            });
+        }
+
+        let html = String::from_utf8_lossy(&bytes);
+        if !page_matches_monograph(&html, monograph.name) {
+            return Err(BioMcpError::Api {
+                api: MEDLINEPLUS_HERBS_API.to_string(),
+                message: format!(
+                    "Page {} is not the {} monograph",
+                    monograph.page_id, monograph.name
+                ),
+            });
+        }
+
+        let source_url = format!(
+            "{MEDLINEPLUS_HERBS_BASE}/druginfo/natural/{}.html",
+            monograph.page_id
+        );
+        Ok(parse_medication_interactions(&html)
+            .into_iter()
+            .map(|entry| SupplementInteraction {
+                supplement: monograph.name.to_string(),
+                medication: entry.medication,
+                severity: entry.severity,
+                description: entry.description,
+                evidence_confidence: SUPPLEMENT_EVIDENCE_CONFIDENCE.to_string(),
+                source_url: source_url.clone(),
+            })
+            .collect())
+    }
+
+    /// Checks every curated monograph for interactions that name one of `drug_names`.
+    ///
+    /// Monographs that fail to load are skipped so one unavailable page does not hide
+    /// the rest of the supplement coverage.
+    pub async fn interactions_for(&self, drug_names: &[String]) -> Vec<SupplementInteraction> {
+        let patterns = drug_names
+            .iter()
+            .filter_map(|name| name_pattern(name))
+            .collect::<Vec<_>>();
+        if patterns.is_empty() {
+            return Vec::new();
+        }
+
+        let pages = futures::future::join_all(
+            SUPPLEMENT_MONOGRAPHS
+                .iter()
+                .map(|monograph| self.monograph_interactions(*monograph)),
+        )
+        .await;
+
+        let mut out = Vec::new();
+        for (monograph, page) in SUPPLEMENT_MONOGRAPHS.iter().zip(pages) {
+            match page {
+                Ok(entries) => out.extend(entries.into_iter().filter(|entry| {
+                    patterns.iter().any(|pattern| {
+                        pattern.is_match(&entry.medication) || pattern.is_match(&entry.description)
+                    })
+                })),
+                Err(err) => {
+                    warn!(
+                        supplement = monograph.name,
+                        "MedlinePlus monograph unavailable: {err}"
+                    );
+                }
+            }
+        }
+        out
+    }
+}
+
+fn name_pattern(name: &str) -> Option<Regex> {
+    let name = name.trim();
+    if name.len() < 3 {
+        return None;
+    }
+    Regex::new(&format!(r"(?i)\b{}\b", regex::escape(name))).ok()
+}
+
+fn page_matches_monograph(html: &str, name: &str) -> bool {
+    static TITLE_RE: OnceLock<Regex> = OnceLock::new();
+    let re = TITLE_RE.get_or_init(|| Regex::new(r"(?is)<title[^>]*>(.*?)</title>").unwrap());
+    let Some(title) = re.captures(html).and_then(|caps| caps.get(1)) else {
+        return false;
+    };
+    let title = normalize_apostrophes(&decode_entities(title.as_str())).to_ascii_lowercase();
+    title.contains(&normalize_apostrophes(name).to_ascii_lowercase())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct MedicationInteraction {
+    medication: String,
+    severity: Option<String>,
+    description: String,
+}
+
+fn parse_medication_interactions(html: &str) -> Vec<MedicationInteraction> {
+    let lines = html_to_lines(html);
+    let Some(start) = lines
+        .iter()
+        .position(|line| line.eq_ignore_ascii_case(INTERACTIONS_HEADING))
+    else {
+        return Vec::new();
+    };
+
+    let mut out = Vec::new();
+    let mut severity: Option<String> = None;
+    let mut medication: Option<String> = None;
+    for line in &lines[start + 1..] {
+        // Every monograph section heading is phrased as a question.
+        if line.ends_with('?') {
+            break;
+        }
+        if matches!(line.as_str(), "Major" | "Moderate" | "Minor") {
+            severity = Some(line.clone());
+            medication = None;
+            continue;
+        }
+        if line.starts_with("Do not take")
+            || line.starts_with("Be cautious")
+            || line.starts_with("Be watchful")
+        {
+            continue;
+        }
+        match medication.take() {
+            None => medication = Some(line.clone()),
+            Some(name) => out.push(MedicationInteraction {
+                medication: name,
+                severity: severity.clone(),
+                description: line.clone(),
+            }),
+        }
+    }
+    out
+}
+
+fn html_to_lines(html: &str) -> Vec<String> {
+    static DROP_RE: OnceLock<Regex> = OnceLock::new();
+    static BLOCK_RE: OnceLock<Regex> = OnceLock::new();
+    static TAG_RE: OnceLock<Regex> = OnceLock::new();
+    let drop_re = DROP_RE.get_or_init(|| {
+        Regex::new(r"(?is)<(script|style|head)\b.*?</(script|style|head)>").unwrap()
+    });
+    let block_re = BLOCK_RE.get_or_init(|| {
+        Regex::new(r"(?i)</?(p|div|h[1-6]|li|ul|ol|section|article|br|tr|td)\b[^>]*>").unwrap()
+    });
+    let tag_re = TAG_RE.get_or_init(|| Regex::new(r"(?s)<[^>]*>").unwrap());
+
+    let text = drop_re.replace_all(html, "");
+    let text = block_re.replace_all(&text, "\n");
+    let text = tag_re.replace_all(&text, "");
+    decode_entities(&text)
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+fn decode_entities(value: &str) -> String {
+    value
+        .replace("&nbsp;", " ")
+        .replace("&#39;", "'")
+        .replace("&rsquo;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+fn normalize_apostrophes(value: &str) -> String {
+    value.replace(['\u{2019}', '\u{2018}'], "'")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const ST_JOHNS_WORT_HTML: &str = r#"<html><head><title>St. John&#39;s Wort: MedlinePlus Supplements</title>
+<style>.x { color: red; }</style></head><body>
+<h1>St. John's Wort</h1>
+<section><h2>How effective is it?</h2><p>Possibly effective for depression.</p></section>
+<section><h2>Are there interactions with medications?</h2>
+<div><h3>Major</h3><p>Do not take this combination.</p>
+<h4>Cyclosporine (Neoral, Sandimmune)</h4>
+<p>St. John's wort might <em>decrease</em> how much cyclosporine is in the body.</p>
+<h4>Medications changed by the liver (Cytochrome P450 3A4 (CYP3A4) substrates)</h4>
+<p>Some of these medications include alprazolam (Xanax), simvastatin (Zocor), and tacrolimus.</p>
+<h3>Moderate</h3><p>Be cautious with this combination.</p>
+<h4>Warfarin (Coumadin)</h4>
+<p>St. John's wort might decrease the effects of warfarin &amp; increase clotting risk.</p>
+</div></section>
+<section><h2>Are there interactions with herbs and supplements?</h2>
+<h4>Tacrolimus-like herbs</h4><p>Should not be parsed.</p></section>
+</body></html>"#;
+
+    #[test]
+    fn parse_medication_interactions_groups_entries_by_severity() {
+        let entries = parse_medication_interactions(ST_JOHNS_WORT_HTML);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].medication, "Cyclosporine (Neoral, Sandimmune)");
+        assert_eq!(entries[0].severity.as_deref(), Some("Major"));
+        assert_eq!(
+            entries[0].description,
+            "St. John's wort might decrease how much cyclosporine is in the body."
+        );
+        assert_eq!(entries[2].medication, "Warfarin (Coumadin)");
+        assert_eq!(entries[2].severity.as_deref(), Some("Moderate"));
+        assert!(entries[2].description.contains("warfarin & increase"));
+    }
+
+    #[test]
+    fn page_matches_monograph_checks_the_title() {
+        assert!(page_matches_monograph(
+            ST_JOHNS_WORT_HTML,
+            "St. John's wort"
+        ));
+        assert!(!page_matches_monograph(ST_JOHNS_WORT_HTML, "Ginkgo"));
+        assert!(parse_medication_interactions("<p>No sections</p>").is_empty());
+    }
+
+    #[tokio::test]
+    async fn interactions_for_matches_drug_names_in_headings_and_descriptions() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/druginfo/natural/329.html"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html; charset=utf-8")
+                    .set_body_string(ST_JOHNS_WORT_HTML),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let client = MedlinePlusHerbsClient::new_for_test(server.uri()).expect("client");
+        let hits = client
+            .interactions_for(&["Tacrolimus".to_string(), "Prograf".to_string()])
+            .await;
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].supplement, "St. John's wort");
+        assert_eq!(hits[0].severity.as_deref(), Some("Major"));
+        assert_eq!(hits[0].evidence_confidence, SUPPLEMENT_EVIDENCE_CONFIDENCE);
+        assert_eq!(
+            hits[0].source_url,
+            "https://medlineplus.gov/druginfo/natural/329.html"
+        );
+
+        let hits = client.interactions_for(&["warfarin".to_string()]).await;
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].medication, "Warfarin (Coumadin)");
+
+        assert!(
+            client
+                .interactions_for(&["xx".to_string()])
+                .await
+                .is_empty()
+        );
+    }
+}
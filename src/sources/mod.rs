@@ -3,7 +3,7 @@
 use std::borrow::Cow;
 use std::future::Future;
 use std::path::Path;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 use std::time::Duration;
 
 use http::Extensions;
@@ -12,10 +12,11 @@ use reqwest::StatusCode;
 use reqwest::header::{CACHE_CONTROL, HeaderMap, HeaderValue, RETRY_AFTER};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, Middleware, Next, RequestBuilder};
 use reqwest_retry::{RetryTransientMiddleware, policies::ExponentialBackoff};
-use tracing::warn;
+use tracing::{debug, warn};
 
 use crate::error::BioMcpError;
 
+pub(crate) mod allele_registry;
 pub(crate) mod alphagenome;
 pub(crate) mod cbioportal;
 pub(crate) mod cbioportal_download;
@@ -24,12 +25,18 @@ pub(crate) mod chembl;
 pub(crate) mod civic;
 pub(crate) mod clingen;
 pub(crate) mod clinicaltrials;
+pub(crate) mod clinvar;
+pub(crate) mod clue;
 pub(crate) mod complexportal;
+pub(crate) mod concurrency;
 pub(crate) mod cpic;
+pub(crate) mod ctis;
+pub(crate) mod depmap;
 pub(crate) mod dgidb;
 pub(crate) mod disgenet;
 pub(crate) mod ema;
 pub(crate) mod enrichr;
+pub(crate) mod ensembl;
 pub(crate) mod europepmc;
 pub(crate) mod gnomad;
 pub(crate) mod gprofiler;
@@ -38,9 +45,12 @@ pub(crate) mod gwas;
 pub(crate) mod hpa;
 pub(crate) mod hpo;
 pub(crate) mod interpro;
+pub(crate) mod ipd_imgt;
 pub(crate) mod kegg;
 pub(crate) mod litsense2;
+pub(crate) mod litvar;
 pub(crate) mod medlineplus;
+pub(crate) mod medlineplus_herbs;
 pub(crate) mod monarch;
 pub(crate) mod mychem;
 pub(crate) mod mydisease;
@@ -49,28 +59,45 @@ pub(crate) mod myvariant;
 pub(crate) mod ncbi_efetch;
 pub(crate) mod ncbi_idconv;
 pub(crate) mod nci_cts;
+pub(crate) mod nci_evs;
 pub(crate) mod nih_reporter;
 pub(crate) mod ols4;
 pub(crate) mod oncokb;
 pub(crate) mod openfda;
 pub(crate) mod opentargets;
+pub(crate) mod passthrough;
+pub(crate) mod patentsview;
 pub(crate) mod pharmgkb;
+pub(crate) mod plugin;
 pub(crate) mod pmc_oa;
 pub(crate) mod pubmed;
 pub(crate) mod pubtator;
 pub(crate) mod quickgo;
 pub(crate) mod rate_limit;
+pub(crate) mod raw_capture;
+pub(crate) mod rcsb;
 pub(crate) mod reactome;
+pub(crate) mod request_stats;
+pub(crate) mod scimago;
 pub(crate) mod seer;
 pub(crate) mod semantic_scholar;
 pub(crate) mod string;
+pub(crate) mod tenant;
 pub(crate) mod umls;
 pub(crate) mod uniprot;
+pub(crate) mod unpaywall;
+pub(crate) mod uspstf;
+pub(crate) mod variantvalidator;
+pub(crate) mod vep;
+pub(crate) mod who_gho;
 pub(crate) mod who_pq;
 pub(crate) mod wikipathways;
 
 const ERROR_BODY_MAX_BYTES: usize = 2048;
 pub(crate) const DEFAULT_MAX_BODY_BYTES: usize = 8 * 1024 * 1024;
+/// Ceiling for single-document fetches (full CTGov records, PMC full text) that
+/// routinely outgrow the default limit and are paged or saved to disk downstream.
+pub(crate) const LARGE_DOCUMENT_MAX_BODY_BYTES: usize = 32 * 1024 * 1024;
 pub(crate) const BIOTHINGS_MAX_RESULT_WINDOW: usize = 10_000;
 
 static HTTP_CLIENT: OnceLock<ClientWithMiddleware> = OnceLock::new();
@@ -79,6 +106,8 @@ static STREAMING_HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
 
 tokio::task_local! {
     static NO_CACHE: bool;
+    static CACHE_REFRESH: bool;
+    static CACHE_ENTITY: &'static str;
 }
 
 fn parse_cache_mode(value: Option<&str>) -> Option<CacheMode> {
@@ -125,9 +154,43 @@ pub(crate) fn is_no_cache_enabled() -> bool {
     matches!(NO_CACHE.try_with(|v| *v), Ok(true))
 }
 
+/// Runs `fut` with default-mode requests sent upstream and the fresh
+/// responses written back to the HTTP cache (`serve-http --refresh-interval`).
+pub(crate) async fn with_cache_refresh<R, F>(fut: F) -> R
+where
+    F: Future<Output = R>,
+{
+    CACHE_REFRESH.scope(true, fut).await
+}
+
+/// Reload mode for the default cache behavior inside [`with_cache_refresh`];
+/// explicit modes (no-cache, authenticated, `BIOMCP_CACHE_MODE`) still win.
+fn refresh_cache_mode(mode: Option<CacheMode>) -> Option<CacheMode> {
+    match mode {
+        None if matches!(CACHE_REFRESH.try_with(|v| *v), Ok(true)) => Some(CacheMode::Reload),
+        mode => mode,
+    }
+}
+
+/// Labels HTTP cache entries written inside `fut` with the entity being served
+/// (e.g. `gene`), so `biomcp cache stats` can break usage down by entity.
+pub(crate) async fn with_cache_entity<R, F>(entity: Option<&'static str>, fut: F) -> R
+where
+    F: Future<Output = R>,
+{
+    match entity {
+        Some(entity) => CACHE_ENTITY.scope(entity, fut).await,
+        None => fut.await,
+    }
+}
+
+pub(crate) fn current_cache_entity() -> Option<&'static str> {
+    CACHE_ENTITY.try_with(|v| *v).ok()
+}
+
 pub(crate) fn apply_cache_mode(req: RequestBuilder) -> RequestBuilder {
     let no_cache = is_no_cache_enabled();
-    if let Some(mode) = resolve_cache_mode(no_cache, false, env_cache_mode()) {
+    if let Some(mode) = refresh_cache_mode(resolve_cache_mode(no_cache, false, env_cache_mode())) {
         return req.with_extension(mode);
     }
     req
@@ -138,7 +201,11 @@ pub(crate) fn apply_cache_mode_with_auth(
     authenticated: bool,
 ) -> RequestBuilder {
     let no_cache = is_no_cache_enabled();
-    if let Some(mode) = resolve_cache_mode(no_cache, authenticated, env_cache_mode()) {
+    if let Some(mode) = refresh_cache_mode(resolve_cache_mode(
+        no_cache,
+        authenticated,
+        env_cache_mode(),
+    )) {
         return req.with_extension(mode);
     }
     req
@@ -161,17 +228,11 @@ pub(crate) fn is_valid_gene_symbol(symbol: &str) -> bool {
 }
 
 pub(crate) fn ncbi_api_key() -> Option<String> {
-    std::env::var("NCBI_API_KEY")
-        .ok()
-        .map(|s| s.trim().to_string())
-        .filter(|s| !s.is_empty())
+    tenant::api_key("NCBI_API_KEY")
 }
 
 pub(crate) fn s2_api_key() -> Option<String> {
-    std::env::var("S2_API_KEY")
-        .ok()
-        .map(|s| s.trim().to_string())
-        .filter(|s| !s.is_empty())
+    tenant::api_key("S2_API_KEY")
 }
 
 pub(crate) fn append_ncbi_api_key(req: RequestBuilder, api_key: Option<&str>) -> RequestBuilder {
@@ -210,6 +271,7 @@ fn retry_sleep_duration(attempt: u32, retry_after_floor: Option<Duration>) -> Du
 /// - Cache: Disk-based HTTP cache under the resolved canonical cache root
 ///   (`BIOMCP_CACHE_DIR`, `cache.toml`, or XDG default)
 /// - Cache TTL: `Cache-Control: max-stale=86400` makes “no caching headers” responses usable for 24h
+/// - Stats: `--verbose` runs record per-call hit/revalidation/fetch outcomes (see `request_stats`)
 #[derive(Clone, Copy)]
 enum SharedHttpClientKind {
     Default,
@@ -262,6 +324,7 @@ fn build_http_client(kind: SharedHttpClientKind) -> Result<ClientWithMiddleware,
     });
     let cache_path = cache_root.join("http");
     std::fs::create_dir_all(&cache_path)?;
+    let cipher = crate::cache::CacheCipher::shared(config.encrypt)?;
 
     let mut default_headers = HeaderMap::new();
     default_headers.insert(CACHE_CONTROL, HeaderValue::from_static("max-stale=86400"));
@@ -282,14 +345,20 @@ fn build_http_client(kind: SharedHttpClientKind) -> Result<ClientWithMiddleware,
             shared: true,
             ..CacheOptions::default()
         }),
+        // Namespaced per tenant under `serve-http` tenancy; the default key otherwise.
+        cache_key: Some(Arc::new(tenant::cache_key)),
         ..HttpCacheOptions::default()
     };
 
-    let builder = ClientBuilder::new(base_client).with(Cache(HttpCache {
-        mode: CacheMode::Default,
-        manager: crate::cache::SizeAwareCacheManager::new(cache_path, config),
-        options: cache_options,
-    }));
+    let builder = ClientBuilder::new(base_client)
+        .with(request_stats::RequestStatsMiddleware)
+        .with(Cache(HttpCache {
+            mode: CacheMode::Default,
+            manager: crate::cache::SizeAwareCacheManager::new(cache_path, config)
+                .with_cipher(cipher),
+            options: cache_options,
+        }))
+        .with(request_stats::NetworkStatsMiddleware);
     let builder = builder.with(
         RetryTransientMiddleware::new_with_policy(retry_policy)
             .with_retry_log_level(tracing::Level::DEBUG),
@@ -300,7 +369,10 @@ fn build_http_client(kind: SharedHttpClientKind) -> Result<ClientWithMiddleware,
             builder.with(SemanticScholarSharedPoolRateLimitMiddleware)
         }
     };
-    Ok(builder.with(rate_limit::RateLimitMiddleware::new()).build())
+    Ok(builder
+        .with(rate_limit::RateLimitMiddleware::new())
+        .with(concurrency::ConcurrencyLimitMiddleware::new())
+        .build())
 }
 
 #[cfg(test)]
@@ -368,7 +440,8 @@ pub(crate) fn is_semantic_scholar_shared_pool_rate_limit_error(
 /// Returns a shared HTTP client without middleware.
 ///
 /// Use this for requests with streaming bodies (e.g., multipart) that cannot be cloned and therefore
-/// cannot pass through the retry/cache middleware stack.
+/// cannot pass through the retry/cache middleware stack. Send through [`retry_send`] so requests
+/// still count against the global concurrency cap.
 pub(crate) fn streaming_http_client() -> Result<reqwest::Client, BioMcpError> {
     if let Some(client) = STREAMING_HTTP_CLIENT.get() {
         return Ok(client.clone());
@@ -393,10 +466,59 @@ pub(crate) fn streaming_http_client() -> Result<reqwest::Client, BioMcpError> {
     }
 }
 
+/// Base URLs of the most-used sources, as `(default, override env var)` pairs.
+const WARM_SOURCE_BASES: &[(&str, &str)] = &[
+    (mygene::MYGENE_BASE, mygene::MYGENE_BASE_ENV),
+    (myvariant::MYVARIANT_BASE, myvariant::MYVARIANT_BASE_ENV),
+    (mychem::MYCHEM_BASE, mychem::MYCHEM_BASE_ENV),
+    (pubmed::PUBMED_EUTILS_BASE, pubmed::PUBMED_EUTILS_BASE_ENV),
+    (pubtator::PUBTATOR_BASE, pubtator::PUBTATOR_BASE_ENV),
+    (europepmc::EUROPE_PMC_BASE, europepmc::EUROPE_PMC_BASE_ENV),
+    (clinicaltrials::CTGOV_BASE, clinicaltrials::CTGOV_BASE_ENV),
+    (uniprot::UNIPROT_BASE, uniprot::UNIPROT_BASE_ENV),
+];
+const WARM_CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Distinct origins (`scheme://host[:port]`) behind [`WARM_SOURCE_BASES`].
+fn warm_origins() -> Vec<String> {
+    let mut origins = Vec::new();
+    for (default, env_var) in WARM_SOURCE_BASES {
+        let Ok(url) = reqwest::Url::parse(&env_base(default, env_var)) else {
+            continue;
+        };
+        let origin = url.origin().ascii_serialization();
+        if !origins.contains(&origin) {
+            origins.push(origin);
+        }
+    }
+    origins
+}
+
+/// Opens pooled DNS/TLS connections to the top sources with one uncached
+/// `HEAD /` each; returns how many origins answered.
+pub(crate) async fn prime_connections() -> Result<usize, BioMcpError> {
+    let client = shared_client()?;
+    let probes = warm_origins().into_iter().map(|origin| {
+        let req = client
+            .head(format!("{origin}/"))
+            .timeout(WARM_CONNECTION_TIMEOUT)
+            .with_extension(CacheMode::NoStore);
+        async move {
+            let result = req.send().await;
+            if let Err(err) = &result {
+                debug!(%origin, "connection warm-up failed: {err}");
+            }
+            result.is_ok()
+        }
+    });
+    let reached = futures::future::join_all(probes).await;
+    Ok(reached.into_iter().filter(|ok| *ok).count())
+}
+
 /// Retry wrapper for streaming requests that bypass middleware.
 ///
 /// `build_request` is invoked on each attempt so non-cloneable request bodies
-/// can be reconstructed safely.
+/// can be reconstructed safely. Each attempt holds a global concurrency permit.
 pub(crate) async fn retry_send<F, Fut>(
     api: &str,
     max_retries: u32,
@@ -412,7 +534,11 @@ where
 
     for attempt in 0..total_attempts {
         let mut retry_after_floor = None;
-        match build_request().await {
+        let sent = {
+            let _permit = concurrency::acquire_permit().await;
+            build_request().await
+        };
+        match sent {
             Ok(resp)
                 if resp.status().is_server_error()
                     || resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS =>
@@ -601,6 +727,7 @@ pub(crate) async fn read_limited_body_with_limit(
     api: &str,
     max_bytes: usize,
 ) -> Result<Vec<u8>, BioMcpError> {
+    let raw_target = raw_capture::is_capturing().then(|| (resp.url().clone(), resp.status()));
     let mut body: Vec<u8> = Vec::new();
 
     while let Some(chunk) = resp.chunk().await? {
@@ -614,6 +741,9 @@ pub(crate) async fn read_limited_body_with_limit(
         body.extend_from_slice(&chunk);
     }
 
+    if let Some((url, status)) = raw_target {
+        raw_capture::record(api, &url, status.as_u16(), &body);
+    }
     Ok(body)
 }
 
@@ -699,6 +829,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn warm_origins_dedupe_hosts_and_follow_base_overrides() {
+        let _lock = env_lock();
+        let _mygene = set_env_var("BIOMCP_MYGENE_BASE", Some("http://127.0.0.1:9/v3"));
+        let _mychem = set_env_var("BIOMCP_MYCHEM_BASE", Some("http://127.0.0.1:9/v1"));
+        let _uniprot = set_env_var("BIOMCP_UNIPROT_BASE", None);
+
+        let origins = warm_origins();
+        assert_eq!(
+            origins
+                .iter()
+                .filter(|origin| *origin == "http://127.0.0.1:9")
+                .count(),
+            1
+        );
+        assert!(!origins.iter().any(|origin| origin == "https://mygene.info"));
+        assert!(
+            origins
+                .iter()
+                .any(|origin| origin == "https://rest.uniprot.org")
+        );
+        assert!(origins.len() < WARM_SOURCE_BASES.len());
+    }
+
     #[test]
     fn parse_cache_mode_returns_none_for_default_or_unset() {
         assert!(parse_cache_mode(None).is_none());
@@ -751,6 +905,19 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn refresh_cache_mode_reloads_only_the_default_mode_inside_scope() {
+        assert!(refresh_cache_mode(None).is_none());
+        with_cache_refresh(async {
+            assert!(matches!(refresh_cache_mode(None), Some(CacheMode::Reload)));
+            assert!(matches!(
+                refresh_cache_mode(Some(CacheMode::NoStore)),
+                Some(CacheMode::NoStore)
+            ));
+        })
+        .await;
+    }
+
     #[test]
     fn resolve_cache_mode_defaults_to_none() {
         assert!(resolve_cache_mode(false, false, None).is_none());
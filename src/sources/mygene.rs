@@ -8,11 +8,12 @@ use crate::error::BioMcpError;
 use crate::sources::is_valid_gene_symbol;
 use crate::utils::serde::StringOrVec;
 
-const MYGENE_BASE: &str = "https://mygene.info/v3";
+pub(crate) const MYGENE_BASE: &str = "https://mygene.info/v3";
 const MYGENE_API: &str = "mygene.info";
-const MYGENE_BASE_ENV: &str = "BIOMCP_MYGENE_BASE";
+pub(crate) const MYGENE_BASE_ENV: &str = "BIOMCP_MYGENE_BASE";
 const MYGENE_MAX_RESULT_WINDOW: usize = 10_000;
 const MYGENE_BATCH_GENE_LIMIT: usize = 200;
+const MYGENE_QUERY_MANY_LIMIT: usize = 1000;
 
 pub struct MyGeneClient {
     client: reqwest_middleware::ClientWithMiddleware,
@@ -85,13 +86,14 @@ impl MyGeneClient {
         })
     }
 
-    /// Search genes by query
+    /// Search genes by query within one species (common name or NCBI taxid).
     pub async fn search(
         &self,
         query: &str,
         limit: usize,
         offset: usize,
         chromosome: Option<&str>,
+        species: &str,
     ) -> Result<MyGeneSearchResponse, BioMcpError> {
         Self::validate_search_window(limit, offset)?;
         let url = self.endpoint("query");
@@ -99,7 +101,7 @@ impl MyGeneClient {
         let from = offset.to_string();
         let mut req = self.client.get(&url).query(&[
             ("q", query),
-            ("species", "human"),
+            ("species", species),
             (
                 "fields",
                 "symbol,name,entrezgene,type_of_gene,genomic_pos.chr,genomic_pos.start,genomic_pos.end,MIM,uniprot,pathway.kegg.id,pathway.reactome.id,go.BP.id,go.CC.id,go.MF.id",
@@ -121,6 +123,17 @@ impl MyGeneClient {
         &self,
         symbol: &str,
         include_transcripts: bool,
+    ) -> Result<MyGeneGetResponse, BioMcpError> {
+        self.get_for_species(symbol, include_transcripts, "human")
+            .await
+    }
+
+    /// Get gene by symbol within one species (common name or NCBI taxid).
+    pub async fn get_for_species(
+        &self,
+        symbol: &str,
+        include_transcripts: bool,
+        species: &str,
     ) -> Result<MyGeneGetResponse, BioMcpError> {
         let query_url = self.endpoint("query");
         let symbol = symbol.trim();
@@ -150,7 +163,7 @@ impl MyGeneClient {
         let query_resp: MyGeneGetQueryResponse = self
             .get_json(self.client.get(&query_url).query(&[
                 ("q", q.as_str()),
-                ("species", "human"),
+                ("species", species),
                 ("fields", fields),
                 ("size", "1"),
             ]))
@@ -163,7 +176,11 @@ impl MyGeneClient {
             .ok_or_else(|| BioMcpError::NotFound {
                 entity: "gene".into(),
                 id: symbol.into(),
-                suggestion: format!("Try searching: biomcp search gene -q {symbol}"),
+                suggestion: if species == "human" {
+                    format!("Try searching: biomcp search gene -q {symbol}")
+                } else {
+                    format!("Try searching: biomcp search gene -q {symbol} --species {species}")
+                },
             })
     }
 
@@ -241,6 +258,34 @@ impl MyGeneClient {
 
         Ok(out)
     }
+
+    /// Batch `POST /query` lookup of up to 1000 terms against `scopes`
+    /// (e.g. `ensembl.gene` or `symbol,alias`). Misses come back with
+    /// `notfound: true`; a term matching several genes yields several hits.
+    pub async fn query_many(
+        &self,
+        terms: &[String],
+        scopes: &str,
+    ) -> Result<Vec<MyGeneQueryManyHit>, BioMcpError> {
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+        if terms.len() > MYGENE_QUERY_MANY_LIMIT {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "MyGene batch query supports at most {MYGENE_QUERY_MANY_LIMIT} terms per request"
+            )));
+        }
+
+        let url = self.endpoint("query");
+        let terms_csv = terms.join(",");
+        self.get_json(self.client.post(&url).form(&[
+            ("q", terms_csv.as_str()),
+            ("scopes", scopes),
+            ("fields", "symbol,name,summary,entrezgene,ensembl.gene"),
+            ("species", "human"),
+        ]))
+        .await
+    }
 }
 
 fn first_string_value(value: &serde_json::Value) -> Option<String> {
@@ -327,6 +372,18 @@ struct MyGeneBatchGeneHit {
     symbol: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct MyGeneQueryManyHit {
+    pub query: Option<String>,
+    #[serde(default)]
+    pub notfound: bool,
+    pub symbol: Option<String>,
+    pub name: Option<String>,
+    pub summary: Option<String>,
+    pub entrezgene: Option<StringOrU64>,
+    pub ensembl: Option<EnsemblField>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum StringOrU64 {
@@ -413,7 +470,7 @@ impl GenomicPosField {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{method, path, query_param};
+    use wiremock::matchers::{body_string_contains, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[tokio::test]
@@ -440,7 +497,10 @@ mod tests {
             .mount(&server)
             .await;
 
-        let resp = client.search("symbol:EGFR", 5, 0, Some("7")).await.unwrap();
+        let resp = client
+            .search("symbol:EGFR", 5, 0, Some("7"), "human")
+            .await
+            .unwrap();
         assert_eq!(resp.hits.len(), 1);
     }
 
@@ -537,6 +597,34 @@ mod tests {
         assert!(first.protein.is_some());
     }
 
+    #[tokio::test]
+    async fn get_for_species_sends_species_and_suggests_species_search() {
+        let server = MockServer::start().await;
+        let client = MyGeneClient::new_for_test(format!("{}/v3", server.uri())).unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/v3/query"))
+            .and(query_param("q", "symbol:\"Braf\""))
+            .and(query_param("species", "mouse"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"total": 0, "hits": []})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let err = client
+            .get_for_species("Braf", false, "mouse")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BioMcpError::NotFound { .. }));
+        assert!(
+            err.to_string()
+                .contains("biomcp search gene -q Braf --species mouse")
+        );
+    }
+
     #[tokio::test]
     async fn get_rejects_invalid_symbol_characters() {
         let client = MyGeneClient::new_for_test("http://127.0.0.1/v3".into()).unwrap();
@@ -560,7 +648,10 @@ mod tests {
             .mount(&server)
             .await;
 
-        let err = client.search("EGFR", 1, 0, None).await.unwrap_err();
+        let err = client
+            .search("EGFR", 1, 0, None, "human")
+            .await
+            .unwrap_err();
         let msg = err.to_string();
         assert!(msg.contains("mygene.info"));
         assert!(msg.contains("HTML"));
@@ -570,7 +661,7 @@ mod tests {
     async fn search_rejects_offset_above_mygene_window() {
         let client = MyGeneClient::new_for_test("http://127.0.0.1/v3".into()).unwrap();
         let err = client
-            .search("symbol:EGFR", 5, 10_000, None)
+            .search("symbol:EGFR", 5, 10_000, None, "human")
             .await
             .unwrap_err();
         assert!(matches!(err, BioMcpError::InvalidArgument(_)));
@@ -581,7 +672,7 @@ mod tests {
     async fn search_rejects_offset_limit_window_overflow() {
         let client = MyGeneClient::new_for_test("http://127.0.0.1/v3".into()).unwrap();
         let err = client
-            .search("symbol:EGFR", 2, 9_999, None)
+            .search("symbol:EGFR", 2, 9_999, None, "human")
             .await
             .unwrap_err();
         assert!(matches!(err, BioMcpError::InvalidArgument(_)));
@@ -690,6 +781,47 @@ mod tests {
         assert_eq!(symbols, vec!["EGFR", "TP53", "BRCA1"]);
     }
 
+    #[tokio::test]
+    async fn query_many_posts_terms_with_scopes_and_keeps_misses() {
+        let server = MockServer::start().await;
+        let client = MyGeneClient::new_for_test(format!("{}/v3", server.uri())).unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/v3/query"))
+            .and(body_string_contains("scopes=ensembl.gene"))
+            .and(body_string_contains("ENSG00000157764"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"[
+                  {"query":"ENSG00000157764","_id":"673","symbol":"BRAF","name":"B-Raf proto-oncogene","entrezgene":673,"ensembl":{"gene":"ENSG00000157764"}},
+                  {"query":"ENSG00000000000","notfound":true}
+                ]"#,
+                "application/json",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let hits = client
+            .query_many(
+                &["ENSG00000157764".to_string(), "ENSG00000000000".to_string()],
+                "ensembl.gene",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].symbol.as_deref(), Some("BRAF"));
+        assert_eq!(
+            hits[0]
+                .ensembl
+                .as_ref()
+                .and_then(EnsemblField::gene)
+                .map(String::as_str),
+            Some("ENSG00000157764")
+        );
+        assert!(hits[1].notfound);
+    }
+
     #[tokio::test]
     async fn symbols_for_entrez_ids_rejects_empty_input() {
         let client = MyGeneClient::new_for_test("http://127.0.0.1/v3".into()).unwrap();
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
 
+use http_cache_reqwest::CacheMode;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer, Serialize};
 
@@ -8,14 +10,16 @@ use crate::error::BioMcpError;
 use crate::sources::is_valid_gene_symbol;
 use crate::utils::serde::StringOrVec;
 
-const MYVARIANT_BASE: &str = "https://myvariant.info/v1";
+pub(crate) const MYVARIANT_BASE: &str = "https://myvariant.info/v1";
 const MYVARIANT_API: &str = "myvariant.info";
-const MYVARIANT_BASE_ENV: &str = "BIOMCP_MYVARIANT_BASE";
+pub(crate) const MYVARIANT_BASE_ENV: &str = "BIOMCP_MYVARIANT_BASE";
 
 pub(crate) const MYVARIANT_FIELDS_GET: &str = concat!(
     "_id,cadd.phred,cadd.consequence,",
     "clinvar.rcv.clinical_significance,clinvar.rcv.review_status,clinvar.rcv.conditions,clinvar.variant_id,",
     "dbnsfp.genename,dbnsfp.hgvsp,dbnsfp.hgvsc,",
+    "dbnsfp.aa.ref,dbnsfp.aa.alt,dbnsfp.aa.pos,",
+    "dbnsfp.ensembl.transcriptid,dbnsfp.ensembl.proteinid,dbnsfp.vep_canonical,dbnsfp.mane,",
     "dbnsfp.sift.pred,dbnsfp.sift.score,",
     "dbnsfp.polyphen2.hdiv.pred,",
     "dbnsfp.revel.score,dbnsfp.revel.rankscore,",
@@ -26,7 +30,7 @@ pub(crate) const MYVARIANT_FIELDS_GET: &str = concat!(
     "dbnsfp.phylop.100way_vertebrate.rankscore,dbnsfp.phylop.470way_mammalian.rankscore,",
     "dbnsfp.phastcons.100way_vertebrate.rankscore,dbnsfp.phastcons.470way_mammalian.rankscore,",
     "dbnsfp.gerp++.rs,",
-    "dbsnp.rsid,",
+    "dbsnp.rsid,clingen.caid,",
     "gnomad_exome.af.af,gnomad_exome.af.af_afr,gnomad_exome.af.af_eas,gnomad_exome.af.af_nfe,gnomad_exome.af.af_sas,",
     "gnomad_exome.af.af_amr,gnomad_exome.af.af_asj,gnomad_exome.af.af_fin,",
     "gnomad_exome.af.af_afr_female,gnomad_exome.af.af_afr_male,",
@@ -43,7 +47,16 @@ pub(crate) const MYVARIANT_FIELDS_GET: &str = concat!(
     "cosmic.cosmic_id,cosmic.mut_freq,cosmic.tumor_site,cosmic.mut_nt,",
     "cgi,civic"
 );
-pub(crate) const MYVARIANT_FIELDS_SEARCH: &str = "_id,dbnsfp.genename,dbnsfp.hgvsp,dbnsfp.revel.score,dbnsfp.gerp++.rs,clinvar.rcv.clinical_significance,clinvar.rcv.review_status,dbsnp.rsid,gnomad_exome.af.af,gnomad.exomes.af.af,gnomad.genomes.af.af,cadd.consequence";
+pub(crate) const MYVARIANT_CLINVAR_SIGNIFICANCE_FACET: &str = "clinvar.rcv.clinical_significance";
+pub(crate) const MYVARIANT_CLINVAR_REVIEW_STATUS_FACET: &str = "clinvar.rcv.review_status";
+const MYVARIANT_CLINVAR_FACETS: &str =
+    "clinvar.rcv.clinical_significance,clinvar.rcv.review_status";
+const MYVARIANT_FIELDS_PROTEIN_CHANGE: &str = "_id,clinvar.hgvs.protein,dbnsfp.hgvsp";
+const MYVARIANT_FIELDS_CLINVAR_RECENT: &str = "_id,clinvar.variant_id,clinvar.hgvs.coding,clinvar.rcv.clinical_significance,clinvar.rcv.review_status,clinvar.rcv.last_evaluated";
+const MYVARIANT_FIELDS_CLINVAR_TABLE: &str = "_id,clinvar.variant_id,clinvar.hgvs.coding,clinvar.hgvs.protein,clinvar.rcv.clinical_significance,clinvar.rcv.review_status,clinvar.rcv.conditions.name,dbsnp.rsid";
+/// Largest id list MyVariant.info accepts in one `POST /variant` request.
+pub(crate) const MYVARIANT_GET_MANY_LIMIT: usize = 1000;
+pub(crate) const MYVARIANT_FIELDS_SEARCH: &str = "_id,dbnsfp.genename,dbnsfp.hgvsp,dbnsfp.revel.score,dbnsfp.gerp++.rs,clinvar.rcv.clinical_significance,clinvar.rcv.review_status,dbsnp.rsid,gnomad_exome.af.af,gnomad.exomes.af.af,gnomad.genomes.af.af,cadd.phred,cadd.consequence";
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
@@ -68,6 +81,7 @@ where
 pub struct MyVariantClient {
     client: reqwest_middleware::ClientWithMiddleware,
     base: Cow<'static, str>,
+    assembly: Option<&'static str>,
 }
 
 pub struct VariantSearchParams {
@@ -276,6 +290,7 @@ impl MyVariantClient {
         Ok(Self {
             client: crate::sources::shared_client()?,
             base: crate::sources::env_base(MYVARIANT_BASE, MYVARIANT_BASE_ENV),
+            assembly: None,
         })
     }
 
@@ -284,9 +299,27 @@ impl MyVariantClient {
         Ok(Self {
             client: crate::sources::test_client()?,
             base: Cow::Owned(base),
+            assembly: None,
         })
     }
 
+    /// Keys `get` and `query_with_fields` on `assembly` (`hg19` or `hg38`)
+    /// instead of leaving MyVariant.info on its hg19 default.
+    pub fn with_assembly(mut self, assembly: &'static str) -> Self {
+        self.assembly = Some(assembly);
+        self
+    }
+
+    fn with_assembly_param(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+    ) -> reqwest_middleware::RequestBuilder {
+        match self.assembly {
+            Some(assembly) => req.query(&[("assembly", assembly)]),
+            None => req,
+        }
+    }
+
     fn endpoint(&self, path: &str) -> String {
         format!(
             "{}/{}",
@@ -303,7 +336,14 @@ impl MyVariantClient {
         &self,
         req: reqwest_middleware::RequestBuilder,
     ) -> Result<T, BioMcpError> {
-        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        self.send_json(crate::sources::apply_cache_mode(req)).await
+    }
+
+    async fn send_json<T: DeserializeOwned>(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+    ) -> Result<T, BioMcpError> {
+        let resp = req.send().await?;
         let status = resp.status();
         let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
         let bytes = crate::sources::read_limited_body(resp, MYVARIANT_API).await?;
@@ -339,12 +379,12 @@ impl MyVariantClient {
         let url = self.endpoint("query");
         let size = limit.to_string();
         let from = offset.to_string();
-        self.get_json(self.client.get(&url).query(&[
+        self.get_json(self.with_assembly_param(self.client.get(&url).query(&[
             ("q", q),
             ("size", size.as_str()),
             ("from", from.as_str()),
             ("fields", fields),
-        ]))
+        ])))
         .await
     }
 
@@ -625,9 +665,11 @@ impl MyVariantClient {
         let url = self.endpoint(&format!("variant/{id}"));
         let value: serde_json::Value = self
             .get_json(
-                self.client
-                    .get(&url)
-                    .query(&[("fields", MYVARIANT_FIELDS_GET)]),
+                self.with_assembly_param(
+                    self.client
+                        .get(&url)
+                        .query(&[("fields", MYVARIANT_FIELDS_GET)]),
+                ),
             )
             .await?;
 
@@ -653,6 +695,194 @@ impl MyVariantClient {
             source,
         })
     }
+
+    /// Fetches many variants by `_id` in one POST. Every requested id gets an
+    /// entry, with `hit` unset when MyVariant.info has no record for it.
+    /// `assembly` is `hg19` (the MyVariant.info default) or `hg38`.
+    pub async fn get_many(
+        &self,
+        ids: &[String],
+        fields: &str,
+        assembly: &str,
+    ) -> Result<Vec<MyVariantBatchHit>, BioMcpError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        if ids.len() > MYVARIANT_GET_MANY_LIMIT {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "MyVariant batch lookup supports at most {MYVARIANT_GET_MANY_LIMIT} IDs per request"
+            )));
+        }
+
+        let url = self.endpoint("variant");
+        let ids_csv = ids.join(",");
+        let values: Vec<serde_json::Value> = self
+            .get_json(self.client.post(&url).form(&[
+                ("ids", ids_csv.as_str()),
+                ("fields", fields),
+                ("assembly", assembly),
+            ]))
+            .await?;
+
+        let mut out = Vec::with_capacity(values.len());
+        for value in values {
+            let query = value
+                .get("query")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let notfound = value
+                .get("notfound")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false);
+            let hit = if notfound {
+                None
+            } else {
+                Some(
+                    serde_json::from_value::<MyVariantHit>(value).map_err(|source| {
+                        BioMcpError::ApiJson {
+                            api: MYVARIANT_API.to_string(),
+                            source,
+                        }
+                    })?,
+                )
+            };
+            out.push(MyVariantBatchHit { query, hit });
+        }
+        Ok(out)
+    }
+
+    fn clinvar_gene_query(symbol: &str) -> Result<String, BioMcpError> {
+        let symbol = symbol.trim();
+        if symbol.is_empty() || !is_valid_gene_symbol(symbol) {
+            return Err(BioMcpError::InvalidArgument(
+                "Gene symbol must contain only letters, numbers, '_' or '-'".into(),
+            ));
+        }
+        Ok(format!(
+            "clinvar.gene.symbol:{}",
+            Self::escape_query_value(symbol)
+        ))
+    }
+
+    /// Counts ClinVar variants in a gene by clinical significance and review status.
+    pub async fn clinvar_gene_facets(
+        &self,
+        symbol: &str,
+        facet_size: usize,
+    ) -> Result<MyVariantFacetResponse, BioMcpError> {
+        let q = Self::clinvar_gene_query(symbol)?;
+        let url = self.endpoint("query");
+        let facet_size = facet_size.clamp(1, 100).to_string();
+        self.get_json(self.client.get(&url).query(&[
+            ("q", q.as_str()),
+            ("size", "0"),
+            ("facets", MYVARIANT_CLINVAR_FACETS),
+            ("facet_size", facet_size.as_str()),
+        ]))
+        .await
+    }
+
+    /// Lists the gene's ClinVar variants with the most recently evaluated records first.
+    pub async fn clinvar_gene_recent(
+        &self,
+        symbol: &str,
+        limit: usize,
+    ) -> Result<MyVariantClinVarRecentResponse, BioMcpError> {
+        let q = Self::clinvar_gene_query(symbol)?;
+        let url = self.endpoint("query");
+        let size = limit.clamp(1, 100).to_string();
+        self.get_json(self.client.get(&url).query(&[
+            ("q", q.as_str()),
+            ("size", size.as_str()),
+            ("sort", "-clinvar.rcv.last_evaluated"),
+            ("fields", MYVARIANT_FIELDS_CLINVAR_RECENT),
+        ]))
+        .await
+    }
+
+    fn clinvar_gene_variants_query(
+        symbol: &str,
+        significance: Option<&str>,
+    ) -> Result<String, BioMcpError> {
+        let mut q = Self::clinvar_gene_query(symbol)?;
+        if let Some(sig) = significance.map(str::trim).filter(|v| !v.is_empty()) {
+            let sig = normalize_significance_filter(sig)?;
+            q.push_str(&format!(
+                " AND clinvar.rcv.clinical_significance:{}",
+                Self::escape_query_value(&sig)
+            ));
+        }
+        Ok(q)
+    }
+
+    /// Lists one page of the gene's ClinVar variants with table fields only.
+    pub async fn clinvar_gene_variants(
+        &self,
+        symbol: &str,
+        significance: Option<&str>,
+        limit: usize,
+        offset: usize,
+    ) -> Result<MyVariantClinVarTableResponse, BioMcpError> {
+        let q = Self::clinvar_gene_variants_query(symbol, significance)?;
+        crate::sources::validate_biothings_result_window("ClinVar gene variants", limit, offset)?;
+        let url = self.endpoint("query");
+        let size = limit.clamp(1, 1000).to_string();
+        let from = offset.to_string();
+        self.get_json(self.client.get(&url).query(&[
+            ("q", q.as_str()),
+            ("size", size.as_str()),
+            ("from", from.as_str()),
+            ("fields", MYVARIANT_FIELDS_CLINVAR_TABLE),
+        ]))
+        .await
+    }
+
+    /// Walks the gene's ClinVar variants with BioThings `fetch_all` scrolling.
+    ///
+    /// Pass `None` to open the scroll and the returned `_scroll_id` to continue it;
+    /// this bypasses the 10,000-hit result window. Scroll contexts expire after
+    /// about a minute, so these requests are never cached.
+    pub async fn clinvar_gene_variants_scroll(
+        &self,
+        symbol: &str,
+        significance: Option<&str>,
+        scroll_id: Option<&str>,
+    ) -> Result<MyVariantClinVarTableResponse, BioMcpError> {
+        let url = self.endpoint("query");
+        let req = match scroll_id {
+            Some(scroll_id) => self.client.get(&url).query(&[("scroll_id", scroll_id)]),
+            None => {
+                let q = Self::clinvar_gene_variants_query(symbol, significance)?;
+                self.client.get(&url).query(&[
+                    ("q", q.as_str()),
+                    ("fields", MYVARIANT_FIELDS_CLINVAR_TABLE),
+                    ("fetch_all", "true"),
+                ])
+            }
+        };
+        self.send_json(req.with_extension(CacheMode::NoStore)).await
+    }
+
+    /// Lists protein changes for the gene's pathogenic and likely pathogenic ClinVar variants.
+    pub async fn clinvar_gene_pathogenic_protein_changes(
+        &self,
+        symbol: &str,
+        limit: usize,
+    ) -> Result<MyVariantProteinChangeResponse, BioMcpError> {
+        let q = format!(
+            "{} AND clinvar.rcv.clinical_significance:(pathogenic OR likely_pathogenic)",
+            Self::clinvar_gene_query(symbol)?
+        );
+        let url = self.endpoint("query");
+        let size = limit.clamp(1, 1000).to_string();
+        self.get_json(self.client.get(&url).query(&[
+            ("q", q.as_str()),
+            ("size", size.as_str()),
+            ("fields", MYVARIANT_FIELDS_PROTEIN_CHANGE),
+        ]))
+        .await
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -670,6 +900,7 @@ pub struct MyVariantHit {
 
     pub cadd: Option<MyVariantCadd>,
     pub clinvar: Option<MyVariantClinVar>,
+    pub clingen: Option<MyVariantClinGen>,
     pub dbnsfp: Option<MyVariantDbnsfp>,
     pub dbsnp: Option<MyVariantDbsnp>,
     pub gnomad_exome: Option<MyVariantGnomadExome>,
@@ -681,6 +912,14 @@ pub struct MyVariantHit {
     pub civic: Option<serde_json::Value>,
 }
 
+/// One entry of a [`MyVariantClient::get_many`] response.
+#[derive(Debug, Clone)]
+pub struct MyVariantBatchHit {
+    /// The requested id, echoed back by MyVariant.info.
+    pub query: String,
+    pub hit: Option<MyVariantHit>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MyVariantCadd {
     pub phred: Option<f64>,
@@ -692,6 +931,12 @@ pub struct MyVariantDbsnp {
     pub rsid: Option<String>,
 }
 
+/// ClinGen Allele Registry cross-reference carried by MyVariant.info.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MyVariantClinGen {
+    pub caid: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MyVariantCosmic {
     #[serde(default)]
@@ -759,6 +1004,14 @@ pub struct MyVariantDbnsfp {
     pub hgvsp: StringOrVec,
     #[serde(default)]
     pub hgvsc: StringOrVec,
+    pub aa: Option<MyVariantDbnsfpAa>,
+    pub ensembl: Option<MyVariantDbnsfpEnsembl>,
+    /// Per-transcript `YES`/`.` flags aligned with `ensembl.transcriptid`.
+    #[serde(default)]
+    pub vep_canonical: StringOrVec,
+    /// Per-transcript MANE labels (`MANE Select`, `MANE Plus Clinical`, or `.`).
+    #[serde(default)]
+    pub mane: StringOrVec,
     pub sift: Option<MyVariantSift>,
     pub polyphen2: Option<MyVariantPolyPhen2>,
     pub revel: Option<MyVariantScoreRank>,
@@ -772,6 +1025,42 @@ pub struct MyVariantDbnsfp {
     pub gerp: Option<MyVariantGerp>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MyVariantDbnsfpAa {
+    #[serde(default, rename = "ref")]
+    pub reference: StringOrVec,
+    #[serde(default)]
+    pub alt: StringOrVec,
+    /// Per-transcript residue positions aligned with `ensembl.transcriptid`.
+    #[serde(default, deserialize_with = "de_vec_or_single")]
+    pub pos: Vec<MyVariantAaPos>,
+}
+
+/// dbNSFP reports `-1` or `.` for transcripts where the variant is non-coding.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum MyVariantAaPos {
+    Number(i64),
+    Text(String),
+}
+
+impl MyVariantAaPos {
+    pub fn value(&self) -> Option<u32> {
+        match self {
+            Self::Number(value) => u32::try_from(*value).ok().filter(|value| *value > 0),
+            Self::Text(value) => value.trim().parse::<u32>().ok().filter(|value| *value > 0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MyVariantDbnsfpEnsembl {
+    #[serde(default)]
+    pub transcriptid: StringOrVec,
+    #[serde(default)]
+    pub proteinid: StringOrVec,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MyVariantSift {
     pub pred: Option<StringOrVec>,
@@ -835,6 +1124,112 @@ pub struct MyVariantClinVarRcv {
     pub conditions: Option<serde_json::Value>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct MyVariantFacetResponse {
+    pub total: Option<u64>,
+    #[serde(default)]
+    pub facets: HashMap<String, MyVariantFacet>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct MyVariantFacet {
+    #[serde(default)]
+    pub terms: Vec<MyVariantFacetTerm>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantFacetTerm {
+    pub term: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantClinVarRecentResponse {
+    #[serde(default)]
+    pub hits: Vec<MyVariantClinVarRecentHit>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantClinVarRecentHit {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub clinvar: Option<MyVariantClinVarRecent>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantClinVarRecent {
+    pub variant_id: Option<u64>,
+    #[serde(default)]
+    pub hgvs: Option<MyVariantClinVarHgvs>,
+    #[serde(default, deserialize_with = "de_vec_or_single")]
+    pub rcv: Vec<MyVariantClinVarRecentRcv>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantClinVarHgvs {
+    #[serde(default)]
+    pub coding: StringOrVec,
+    #[serde(default)]
+    pub protein: StringOrVec,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantClinVarTableResponse {
+    pub total: Option<u64>,
+    #[serde(rename = "_scroll_id")]
+    pub scroll_id: Option<String>,
+    #[serde(default)]
+    pub hits: Vec<MyVariantClinVarTableHit>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantClinVarTableHit {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub clinvar: Option<MyVariantClinVarTable>,
+    pub dbsnp: Option<MyVariantDbsnp>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantClinVarTable {
+    pub variant_id: Option<u64>,
+    #[serde(default)]
+    pub hgvs: Option<MyVariantClinVarHgvs>,
+    #[serde(default, deserialize_with = "de_vec_or_single")]
+    pub rcv: Vec<MyVariantClinVarRcv>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantProteinChangeResponse {
+    pub total: Option<u64>,
+    #[serde(default)]
+    pub hits: Vec<MyVariantProteinChangeHit>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantProteinChangeHit {
+    pub clinvar: Option<MyVariantClinVarProteinChange>,
+    pub dbnsfp: Option<MyVariantDbnsfpProteinChange>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantClinVarProteinChange {
+    pub hgvs: Option<MyVariantClinVarHgvs>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantDbnsfpProteinChange {
+    #[serde(default)]
+    pub hgvsp: StringOrVec,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyVariantClinVarRecentRcv {
+    pub clinical_significance: Option<String>,
+    pub review_status: Option<String>,
+    pub last_evaluated: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum FloatOrVec {
@@ -849,13 +1244,20 @@ impl FloatOrVec {
             Self::Multiple(v) => v.first().copied(),
         }
     }
+
+    pub fn to_vec(&self) -> Vec<f64> {
+        match self {
+            Self::Single(v) => vec![*v],
+            Self::Multiple(v) => v.clone(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde_json::json;
-    use wiremock::matchers::{method, path, query_param};
+    use wiremock::matchers::{body_string_contains, method, path, path_regex, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[tokio::test]
@@ -1284,4 +1686,341 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn clinvar_gene_facets_requests_significance_and_review_status() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/query"))
+            .and(query_param("q", "clinvar.gene.symbol:BRCA1"))
+            .and(query_param("size", "0"))
+            .and(query_param("facets", MYVARIANT_CLINVAR_FACETS))
+            .and(query_param("facet_size", "20"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "total": 12,
+                "hits": [],
+                "facets": {
+                    "clinvar.rcv.clinical_significance": {
+                        "_type": "terms",
+                        "terms": [
+                            {"term": "pathogenic", "count": 7},
+                            {"term": "uncertain significance", "count": 5}
+                        ]
+                    },
+                    "clinvar.rcv.review_status": {
+                        "_type": "terms",
+                        "terms": [{"term": "reviewed by expert panel", "count": 9}]
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = MyVariantClient::new_for_test(server.uri()).unwrap();
+        let resp = client.clinvar_gene_facets("BRCA1", 20).await.unwrap();
+        assert_eq!(resp.total, Some(12));
+        let significance = &resp.facets[MYVARIANT_CLINVAR_SIGNIFICANCE_FACET];
+        assert_eq!(significance.terms.len(), 2);
+        assert_eq!(significance.terms[0].term, "pathogenic");
+        assert_eq!(
+            resp.facets[MYVARIANT_CLINVAR_REVIEW_STATUS_FACET].terms[0].count,
+            9
+        );
+    }
+
+    #[tokio::test]
+    async fn clinvar_gene_recent_sorts_by_last_evaluated() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/query"))
+            .and(query_param("q", "clinvar.gene.symbol:BRCA1"))
+            .and(query_param("size", "5"))
+            .and(query_param("sort", "-clinvar.rcv.last_evaluated"))
+            .and(query_param("fields", MYVARIANT_FIELDS_CLINVAR_RECENT))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "total": 1,
+                "hits": [{
+                    "_id": "chr17:g.43045712G>A",
+                    "clinvar": {
+                        "variant_id": 55555,
+                        "hgvs": {"coding": ["NM_007294.4:c.5503C>T", "LRG_292t1:c.5503C>T"]},
+                        "rcv": {
+                            "clinical_significance": "Pathogenic",
+                            "review_status": "reviewed by expert panel",
+                            "last_evaluated": "2024-03-01"
+                        }
+                    }
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = MyVariantClient::new_for_test(server.uri()).unwrap();
+        let resp = client.clinvar_gene_recent("BRCA1", 5).await.unwrap();
+        let clinvar = resp.hits[0].clinvar.as_ref().expect("clinvar block");
+        assert_eq!(clinvar.variant_id, Some(55555));
+        assert_eq!(clinvar.rcv.len(), 1);
+        assert_eq!(clinvar.rcv[0].last_evaluated.as_deref(), Some("2024-03-01"));
+        assert_eq!(
+            clinvar.hgvs.as_ref().and_then(|h| h.coding.first()),
+            Some("NM_007294.4:c.5503C>T")
+        );
+    }
+
+    #[tokio::test]
+    async fn clinvar_gene_facets_rejects_invalid_symbol() {
+        let client = MyVariantClient::new_for_test("http://127.0.0.1:9".into()).unwrap();
+        let err = client
+            .clinvar_gene_facets("BRCA1 OR *", 20)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BioMcpError::InvalidArgument(_)));
+    }
+
+    #[tokio::test]
+    async fn clinvar_gene_pathogenic_protein_changes_filters_significance() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/query"))
+            .and(query_param(
+                "q",
+                "clinvar.gene.symbol:EGFR AND clinvar.rcv.clinical_significance:(pathogenic OR likely_pathogenic)",
+            ))
+            .and(query_param("size", "1000"))
+            .and(query_param("fields", MYVARIANT_FIELDS_PROTEIN_CHANGE))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "total": 2,
+                "hits": [
+                    {
+                        "_id": "chr7:g.55259515T>G",
+                        "clinvar": {"hgvs": {"protein": "NP_005219.2:p.Leu858Arg"}}
+                    },
+                    {
+                        "_id": "chr7:g.55241707G>A",
+                        "dbnsfp": {"hgvsp": ["p.G719S", "p.Gly719Ser"]}
+                    }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = MyVariantClient::new_for_test(server.uri()).unwrap();
+        let resp = client
+            .clinvar_gene_pathogenic_protein_changes("EGFR", 5000)
+            .await
+            .unwrap();
+        assert_eq!(resp.total, Some(2));
+        let protein = resp.hits[0]
+            .clinvar
+            .as_ref()
+            .and_then(|c| c.hgvs.as_ref())
+            .and_then(|h| h.protein.first());
+        assert_eq!(protein, Some("NP_005219.2:p.Leu858Arg"));
+        assert_eq!(
+            resp.hits[1].dbnsfp.as_ref().and_then(|d| d.hgvsp.first()),
+            Some("p.G719S")
+        );
+    }
+
+    #[tokio::test]
+    async fn clinvar_gene_variants_pages_with_significance_filter() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/query"))
+            .and(query_param(
+                "q",
+                "clinvar.gene.symbol:BRCA1 AND clinvar.rcv.clinical_significance:likely_pathogenic",
+            ))
+            .and(query_param("size", "25"))
+            .and(query_param("from", "50"))
+            .and(query_param("fields", MYVARIANT_FIELDS_CLINVAR_TABLE))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "total": 51,
+                "hits": [{
+                    "_id": "chr17:g.43045712G>A",
+                    "clinvar": {
+                        "variant_id": 55555,
+                        "hgvs": {
+                            "coding": ["LRG_292t1:c.5503C>T", "NM_007294.4:c.5503C>T"],
+                            "protein": "NP_009225.1:p.Arg1835Ter"
+                        },
+                        "rcv": {
+                            "clinical_significance": "Likely pathogenic",
+                            "review_status": "criteria provided, single submitter",
+                            "conditions": {"name": "Hereditary breast ovarian cancer syndrome"}
+                        }
+                    },
+                    "dbsnp": {"rsid": "rs80357260"}
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = MyVariantClient::new_for_test(server.uri()).unwrap();
+        let resp = client
+            .clinvar_gene_variants("BRCA1", Some("Likely Pathogenic"), 25, 50)
+            .await
+            .unwrap();
+        assert_eq!(resp.total, Some(51));
+        let hit = &resp.hits[0];
+        assert_eq!(
+            hit.dbsnp.as_ref().and_then(|d| d.rsid.as_deref()),
+            Some("rs80357260")
+        );
+        let clinvar = hit.clinvar.as_ref().expect("clinvar block");
+        assert_eq!(clinvar.variant_id, Some(55555));
+        assert_eq!(clinvar.rcv.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn clinvar_gene_variants_rejects_window_past_biothings_limit() {
+        let client = MyVariantClient::new_for_test("http://127.0.0.1:9".into()).unwrap();
+        let err = client
+            .clinvar_gene_variants("BRCA1", None, 100, 9950)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BioMcpError::InvalidArgument(_)));
+    }
+
+    #[tokio::test]
+    async fn clinvar_gene_variants_scroll_opens_and_continues() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/query"))
+            .and(query_param("q", "clinvar.gene.symbol:BRCA1"))
+            .and(query_param("fetch_all", "true"))
+            .and(query_param("fields", MYVARIANT_FIELDS_CLINVAR_TABLE))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "total": 2,
+                "_scroll_id": "scroll-1",
+                "hits": [{"_id": "chr17:g.43045712G>A"}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/query"))
+            .and(query_param("scroll_id", "scroll-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "total": 2,
+                "_scroll_id": "scroll-1",
+                "hits": [{"_id": "chr17:g.43057062T>G"}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = MyVariantClient::new_for_test(server.uri()).unwrap();
+        let first = client
+            .clinvar_gene_variants_scroll("BRCA1", None, None)
+            .await
+            .unwrap();
+        assert_eq!(first.scroll_id.as_deref(), Some("scroll-1"));
+        let next = client
+            .clinvar_gene_variants_scroll("BRCA1", None, first.scroll_id.as_deref())
+            .await
+            .unwrap();
+        assert_eq!(next.hits[0].id, "chr17:g.43057062T>G");
+    }
+
+    #[tokio::test]
+    async fn get_many_posts_ids_and_marks_misses() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/variant"))
+            .and(body_string_contains("assembly=hg38"))
+            .and(body_string_contains("chr7%3Ag.140753336A%3ET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {
+                    "query": "chr7:g.140753336A>T",
+                    "_id": "chr7:g.140753336A>T",
+                    "cadd": {"phred": 32.0},
+                    "clinvar": {"rcv": {"clinical_significance": "Pathogenic"}}
+                },
+                {"query": "chr1:g.100A>G", "notfound": true}
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = MyVariantClient::new_for_test(server.uri()).unwrap();
+        let hits = client
+            .get_many(
+                &[
+                    "chr7:g.140753336A>T".to_string(),
+                    "chr1:g.100A>G".to_string(),
+                ],
+                MYVARIANT_FIELDS_SEARCH,
+                "hg38",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].query, "chr7:g.140753336A>T");
+        let hit = hits[0].hit.as_ref().expect("found hit");
+        assert_eq!(hit.cadd.as_ref().and_then(|cadd| cadd.phred), Some(32.0));
+        assert_eq!(hits[1].query, "chr1:g.100A>G");
+        assert!(hits[1].hit.is_none());
+    }
+
+    #[tokio::test]
+    async fn with_assembly_keys_get_and_query_on_hg38() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/variant/chr7:g\.140753336A"))
+            .and(query_param("assembly", "hg38"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"_id": "chr7:g.140753336A>T"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/query"))
+            .and(query_param("q", "dbsnp.rsid:rs113488022"))
+            .and(query_param("assembly", "hg38"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "total": 1,
+                "hits": [{"_id": "chr7:g.140753336A>T"}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = MyVariantClient::new_for_test(server.uri())
+            .unwrap()
+            .with_assembly("hg38");
+        let hit = client.get("chr7:g.140753336A>T").await.unwrap();
+        assert_eq!(hit.id, "chr7:g.140753336A>T");
+        let resp = client
+            .query_with_fields("dbsnp.rsid:rs113488022", 1, 0, MYVARIANT_FIELDS_GET)
+            .await
+            .unwrap();
+        assert_eq!(resp.hits[0].id, "chr7:g.140753336A>T");
+    }
+
+    #[tokio::test]
+    async fn get_many_rejects_oversized_batches() {
+        let client = MyVariantClient::new_for_test("http://127.0.0.1".into()).unwrap();
+        let ids = (1..=MYVARIANT_GET_MANY_LIMIT + 1)
+            .map(|pos| format!("chr1:g.{pos}A>G"))
+            .collect::<Vec<_>>();
+        let err = client
+            .get_many(&ids, MYVARIANT_FIELDS_SEARCH, "hg19")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BioMcpError::InvalidArgument(_)));
+    }
 }
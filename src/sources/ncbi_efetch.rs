@@ -84,7 +84,12 @@ impl NcbiEfetchClient {
     ) -> Result<String, BioMcpError> {
         let resp = req.with_extension(CacheMode::NoStore).send().await?;
         let status = resp.status();
-        let bytes = crate::sources::read_limited_body(resp, NCBI_EFETCH_API).await?;
+        let bytes = crate::sources::read_limited_body_with_limit(
+            resp,
+            NCBI_EFETCH_API,
+            crate::sources::LARGE_DOCUMENT_MAX_BODY_BYTES,
+        )
+        .await?;
         if !status.is_success() {
             let excerpt = crate::sources::body_excerpt(&bytes);
             return Err(BioMcpError::Api {
@@ -76,15 +76,13 @@ fn trimmed_non_empty(value: &str) -> Option<&str> {
 
 impl NciCtsClient {
     pub fn new() -> Result<Self, BioMcpError> {
-        let api_key = std::env::var(NCI_API_KEY_ENV)
-            .ok()
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .ok_or_else(|| BioMcpError::ApiKeyRequired {
+        let api_key = crate::sources::tenant::api_key(NCI_API_KEY_ENV).ok_or_else(|| {
+            BioMcpError::ApiKeyRequired {
                 api: NCI_CTS_API.to_string(),
                 env_var: NCI_API_KEY_ENV.to_string(),
                 docs_url: "https://clinicaltrialsapi.cancer.gov/".to_string(),
-            })?;
+            }
+        })?;
 
         Ok(Self {
             client: crate::sources::shared_client()?,
@@ -0,0 +1,367 @@
+//! NCI EVS REST client for NCI Thesaurus (NCIt) drug and disease concepts.
+//!
+//! NCIt agent concepts back the NCI Drug Dictionary. Their synonyms carry the
+//! investigational code names (`ABT-199`), brand names, and the preferred
+//! generic name, which MyChem.info and openFDA do not always index. Disease
+//! concepts are read by code to widen trial condition searches, and matched by
+//! name to expand abbreviations such as `NSCLC` before MyDisease.info queries.
+
+use std::borrow::Cow;
+
+use serde::Deserialize;
+
+use crate::error::BioMcpError;
+
+const NCI_EVS_BASE: &str = "https://api-evsrest.nci.nih.gov/api/v1";
+const NCI_EVS_API: &str = "nci-evs";
+const NCI_EVS_BASE_ENV: &str = "BIOMCP_NCI_EVS_BASE";
+
+/// NCIt semantic types that describe an administrable agent rather than a
+/// gene, disease, or procedure that happens to share a synonym.
+const DRUG_SEMANTIC_TYPES: &[&str] = &[
+    "Pharmacologic Substance",
+    "Clinical Drug",
+    "Antibiotic",
+    "Immunologic Factor",
+    "Hormone",
+    "Organic Chemical",
+    "Inorganic Chemical",
+    "Amino Acid, Peptide, or Protein",
+    "Biologically Active Substance",
+];
+
+/// NCIt semantic types for diseases, so `CML` matches the leukemia concept
+/// rather than an agent or gene abbreviation.
+const DISEASE_SEMANTIC_TYPES: &[&str] = &[
+    "Neoplastic Process",
+    "Disease or Syndrome",
+    "Mental or Behavioral Dysfunction",
+    "Congenital Abnormality",
+    "Acquired Abnormality",
+    "Pathologic Function",
+];
+
+pub struct NciEvsClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+}
+
+impl NciEvsClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(NCI_EVS_BASE, NCI_EVS_BASE_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    pub(crate) fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base.as_ref().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    /// NCIt drug concepts whose preferred name or a synonym matches `term`
+    /// exactly (case-insensitive), best match first.
+    pub async fn drug_concepts(&self, term: &str) -> Result<Vec<NcitConcept>, BioMcpError> {
+        Ok(self
+            .match_concepts(term)
+            .await?
+            .into_iter()
+            .filter(|concept| concept.has_semantic_type(DRUG_SEMANTIC_TYPES))
+            .collect())
+    }
+
+    /// NCIt disease concepts whose preferred name or a synonym matches `term`
+    /// exactly (case-insensitive), best match first.
+    pub async fn disease_concepts(&self, term: &str) -> Result<Vec<NcitConcept>, BioMcpError> {
+        Ok(self
+            .match_concepts(term)
+            .await?
+            .into_iter()
+            .filter(|concept| concept.has_semantic_type(DISEASE_SEMANTIC_TYPES))
+            .collect())
+    }
+
+    async fn match_concepts(&self, term: &str) -> Result<Vec<NcitConcept>, BioMcpError> {
+        let term = term.trim();
+        if term.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let resp =
+            crate::sources::apply_cache_mode(self.client.get(self.endpoint("concept/ncit/search")))
+                .query(&[
+                    ("terms", term),
+                    ("type", "match"),
+                    ("include", "synonyms,properties"),
+                    ("pageSize", "5"),
+                ])
+                .send()
+                .await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, NCI_EVS_API).await?;
+
+        if !status.is_success() {
+            return Err(BioMcpError::Api {
+                api: NCI_EVS_API.to_string(),
+                message: format!("HTTP {status}: {}", crate::sources::body_excerpt(&bytes)),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(NCI_EVS_API, content_type.as_ref(), &bytes)?;
+        let response: NcitSearchResponse =
+            serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+                api: NCI_EVS_API.to_string(),
+                source,
+            })?;
+        Ok(response.concepts)
+    }
+
+    /// One NCIt concept with its synonyms; `None` for an unknown code.
+    pub async fn concept(&self, code: &str) -> Result<Option<NcitConcept>, BioMcpError> {
+        let code = code.trim();
+        if code.is_empty() {
+            return Ok(None);
+        }
+
+        let resp = crate::sources::apply_cache_mode(
+            self.client
+                .get(self.endpoint(&format!("concept/ncit/{code}"))),
+        )
+        .query(&[("include", "synonyms")])
+        .send()
+        .await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, NCI_EVS_API).await?;
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(BioMcpError::Api {
+                api: NCI_EVS_API.to_string(),
+                message: format!("HTTP {status}: {}", crate::sources::body_excerpt(&bytes)),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(NCI_EVS_API, content_type.as_ref(), &bytes)?;
+        serde_json::from_slice(&bytes)
+            .map(Some)
+            .map_err(|source| BioMcpError::ApiJson {
+                api: NCI_EVS_API.to_string(),
+                source,
+            })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct NcitSearchResponse {
+    #[serde(default)]
+    concepts: Vec<NcitConcept>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NcitConcept {
+    pub code: String,
+    pub name: String,
+    #[serde(default)]
+    pub synonyms: Vec<NcitSynonym>,
+    #[serde(default)]
+    properties: Vec<NcitProperty>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NcitSynonym {
+    pub name: String,
+    /// NCIt term type: `PT` preferred, `SY` synonym, `BR` brand, `CN` code name.
+    #[serde(default, rename = "termType")]
+    pub term_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct NcitProperty {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    value: String,
+}
+
+impl NcitConcept {
+    fn has_semantic_type(&self, semantic_types: &[&str]) -> bool {
+        self.properties.iter().any(|property| {
+            property.kind == "Semantic_Type" && semantic_types.contains(&property.value.as_str())
+        })
+    }
+
+    /// Distinct names with the given NCIt term type, in source order.
+    pub fn synonyms_of_type(&self, term_type: &str) -> Vec<&str> {
+        let mut out: Vec<&str> = Vec::new();
+        for synonym in &self.synonyms {
+            let name = synonym.name.trim();
+            if name.is_empty()
+                || synonym.term_type.as_deref() != Some(term_type)
+                || out.iter().any(|seen| seen.eq_ignore_ascii_case(name))
+            {
+                continue;
+            }
+            out.push(name);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::NciEvsClient;
+
+    #[tokio::test]
+    async fn drug_concepts_uses_match_contract_and_keeps_drug_concepts() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/concept/ncit/search"))
+            .and(query_param("terms", "ABT-199"))
+            .and(query_param("type", "match"))
+            .and(query_param("include", "synonyms,properties"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total": 2,
+                "concepts": [
+                    {
+                        "code": "C95746",
+                        "name": "Venetoclax",
+                        "synonyms": [
+                            {"name": "VENETOCLAX", "termType": "PT", "source": "FDA"},
+                            {"name": "Venetoclax", "termType": "PT", "source": "NCI"},
+                            {"name": "ABT-199", "termType": "CN", "source": "NCI"},
+                            {"name": "GDC-0199", "termType": "CN", "source": "NCI"},
+                            {"name": "Venclexta", "termType": "BR", "source": "NCI"}
+                        ],
+                        "properties": [
+                            {"type": "Semantic_Type", "value": "Pharmacologic Substance"}
+                        ]
+                    },
+                    {
+                        "code": "C99999",
+                        "name": "ABT-199 Resistance",
+                        "properties": [
+                            {"type": "Semantic_Type", "value": "Finding"}
+                        ]
+                    }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = NciEvsClient::new_for_test(server.uri()).expect("client");
+        let concepts = client.drug_concepts("ABT-199").await.expect("search");
+        assert_eq!(concepts.len(), 1);
+        assert_eq!(concepts[0].code, "C95746");
+        assert_eq!(concepts[0].synonyms_of_type("PT"), vec!["VENETOCLAX"]);
+        assert_eq!(
+            concepts[0].synonyms_of_type("CN"),
+            vec!["ABT-199", "GDC-0199"]
+        );
+        assert_eq!(concepts[0].synonyms_of_type("BR"), vec!["Venclexta"]);
+    }
+
+    #[tokio::test]
+    async fn disease_concepts_keep_disease_semantic_types_only() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/concept/ncit/search"))
+            .and(query_param("terms", "NSCLC"))
+            .and(query_param("type", "match"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total": 2,
+                "concepts": [
+                    {
+                        "code": "C2926",
+                        "name": "Lung Non-Small Cell Carcinoma",
+                        "synonyms": [
+                            {"name": "NSCLC", "termType": "AB", "source": "NCI"},
+                            {"name": "Non-Small Cell Lung Cancer", "termType": "SY", "source": "NCI"}
+                        ],
+                        "properties": [
+                            {"type": "Semantic_Type", "value": "Neoplastic Process"}
+                        ]
+                    },
+                    {
+                        "code": "C99998",
+                        "name": "NSCLC Cell Line",
+                        "properties": [
+                            {"type": "Semantic_Type", "value": "Cell"}
+                        ]
+                    }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = NciEvsClient::new_for_test(server.uri()).expect("client");
+        let concepts = client.disease_concepts("NSCLC").await.expect("search");
+        assert_eq!(concepts.len(), 1);
+        assert_eq!(concepts[0].name, "Lung Non-Small Cell Carcinoma");
+        assert_eq!(
+            concepts[0].synonyms_of_type("SY"),
+            vec!["Non-Small Cell Lung Cancer"]
+        );
+        assert!(
+            client
+                .drug_concepts("NSCLC")
+                .await
+                .expect("search")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn concept_reads_synonyms_and_maps_unknown_codes_to_none() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/concept/ncit/C4911"))
+            .and(query_param("include", "synonyms"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "code": "C4911",
+                "name": "Gastric Carcinoma",
+                "synonyms": [
+                    {"name": "Gastric Carcinoma", "termType": "PT", "source": "NCI"},
+                    {"name": "Stomach Cancer", "termType": "SY", "source": "NCI"},
+                    {"name": "Gastric Cancer", "termType": "SY", "source": "NCI"}
+                ]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/concept/ncit/C0000"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let client = NciEvsClient::new_for_test(server.uri()).expect("client");
+        let concept = client
+            .concept("C4911")
+            .await
+            .expect("concept")
+            .expect("known code");
+        assert_eq!(concept.name, "Gastric Carcinoma");
+        assert_eq!(
+            concept.synonyms_of_type("SY"),
+            vec!["Stomach Cancer", "Gastric Cancer"]
+        );
+        assert!(client.concept("C0000").await.expect("lookup").is_none());
+    }
+}
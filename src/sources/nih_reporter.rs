@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
+use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use time::{Date, Month, OffsetDateTime};
@@ -116,7 +117,7 @@ fn current_funding_window_date() -> Date {
         .unwrap_or_else(|_| OffsetDateTime::now_utc().date())
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub struct NihReporterFundingSection {
     pub query: String,
     pub fiscal_years: Vec<i32>,
@@ -124,7 +125,7 @@ pub struct NihReporterFundingSection {
     pub grants: Vec<NihReporterGrant>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub struct NihReporterGrant {
     pub project_title: String,
     pub project_num: String,
@@ -1,6 +1,8 @@
 use std::borrow::Cow;
+use std::fmt::Write as _;
 
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
 
 use crate::error::BioMcpError;
 
@@ -8,6 +10,37 @@ const OLS4_BASE: &str = "https://www.ebi.ac.uk/ols4";
 const OLS4_API: &str = "ols4";
 const OLS4_BASE_ENV: &str = "BIOMCP_OLS4_BASE";
 const OLS4_ONTOLOGIES: &str = "hgnc,mesh,mondo,doid,hp,go,chebi,dron,ncit,ordo,wikipathways,so";
+/// OLS pages child and parent lists; one page this size covers nearly every term.
+const OLS4_TERM_PAGE_SIZE: &str = "500";
+
+/// Direct relatives of a term along `is_a` edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OlsRelation {
+    Children,
+    Parents,
+}
+
+impl OlsRelation {
+    fn path_segment(self) -> &'static str {
+        match self {
+            Self::Children => "children",
+            Self::Parents => "parents",
+        }
+    }
+}
+
+/// Percent-encodes `iri` twice, as OLS expects term IRIs in URL paths.
+fn double_encode_iri(iri: &str) -> String {
+    let mut out = String::with_capacity(iri.len() * 3);
+    for byte in iri.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(byte as char);
+        } else {
+            let _ = write!(out, "%25{byte:02X}");
+        }
+    }
+    out
+}
 
 pub struct OlsClient {
     client: reqwest_middleware::ClientWithMiddleware,
@@ -72,6 +105,92 @@ impl OlsClient {
             })?;
         Ok(response.response.docs)
     }
+
+    /// GETs an OLS term endpoint, mapping 404 (unknown term) to `None`.
+    async fn get_term_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Option<T>, BioMcpError> {
+        let req = self.client.get(self.endpoint(path)).query(query);
+        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, OLS4_API).await?;
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(BioMcpError::Api {
+                api: OLS4_API.to_string(),
+                message: format!("HTTP {status}: {}", crate::sources::body_excerpt(&bytes)),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(OLS4_API, content_type.as_ref(), &bytes)?;
+        serde_json::from_slice(&bytes)
+            .map(Some)
+            .map_err(|source| BioMcpError::ApiJson {
+                api: OLS4_API.to_string(),
+                source,
+            })
+    }
+
+    /// Looks up a term by IRI in `ontology` (an OLS ontology id such as `mondo`).
+    pub async fn term(&self, ontology: &str, iri: &str) -> Result<Option<OlsTerm>, BioMcpError> {
+        let path = format!("api/ontologies/{ontology}/terms/{}", double_encode_iri(iri));
+        self.get_term_json(&path, &[]).await
+    }
+
+    /// Direct children or parents of a term; empty when it has none.
+    pub async fn related_terms(
+        &self,
+        ontology: &str,
+        iri: &str,
+        relation: OlsRelation,
+    ) -> Result<Vec<OlsTerm>, BioMcpError> {
+        let path = format!(
+            "api/ontologies/{ontology}/terms/{}/{}",
+            double_encode_iri(iri),
+            relation.path_segment()
+        );
+        let page: Option<OlsTermPage> = self
+            .get_term_json(&path, &[("size", OLS4_TERM_PAGE_SIZE)])
+            .await?;
+        Ok(page
+            .and_then(|page| page.embedded)
+            .map(|embedded| embedded.terms)
+            .unwrap_or_default())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct OlsTermPage {
+    #[serde(default, rename = "_embedded")]
+    embedded: Option<OlsTermList>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct OlsTermList {
+    #[serde(default)]
+    terms: Vec<OlsTerm>,
+}
+
+/// Ontology class as returned by the OLS term endpoints.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OlsTerm {
+    pub iri: String,
+    #[serde(default)]
+    pub label: String,
+    #[serde(default)]
+    pub obo_id: Option<String>,
+    #[serde(default)]
+    pub has_children: bool,
+    #[serde(default)]
+    pub is_root: bool,
+    #[serde(default)]
+    pub is_obsolete: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -117,7 +236,7 @@ mod tests {
     use wiremock::matchers::{method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
-    use super::OlsClient;
+    use super::{OlsClient, OlsRelation};
 
     #[tokio::test]
     async fn search_uses_required_query_contract() {
@@ -156,4 +275,68 @@ mod tests {
         assert_eq!(rows.len(), 1);
         assert_eq!(rows[0].label, "EGFR");
     }
+
+    #[tokio::test]
+    async fn term_hierarchy_double_encodes_iri_and_reads_embedded_terms() {
+        let server = MockServer::start().await;
+        let term_path =
+            "/api/ontologies/mondo/terms/http%253A%252F%252Fpurl.obolibrary.org%252Fobo%252FMONDO_0005105";
+        Mock::given(method("GET"))
+            .and(path(term_path))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "iri": "http://purl.obolibrary.org/obo/MONDO_0005105",
+                "label": "melanoma",
+                "obo_id": "MONDO:0005105",
+                "has_children": true,
+                "is_root": false
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("{term_path}/children")))
+            .and(query_param("size", "500"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "_embedded": {"terms": [{
+                    "iri": "http://purl.obolibrary.org/obo/MONDO_0005012",
+                    "label": "cutaneous melanoma",
+                    "obo_id": "MONDO:0005012",
+                    "has_children": true
+                }]},
+                "page": {"size": 500, "totalElements": 1, "totalPages": 1, "number": 0}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("{term_path}/parents")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "page": {"size": 500, "totalElements": 0, "totalPages": 0, "number": 0}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = OlsClient::new_for_test(server.uri()).expect("client");
+        let iri = "http://purl.obolibrary.org/obo/MONDO_0005105";
+        let term = client.term("mondo", iri).await.expect("term").expect("found");
+        assert_eq!(term.label, "melanoma");
+        assert!(term.has_children);
+
+        let children = client
+            .related_terms("mondo", iri, OlsRelation::Children)
+            .await
+            .expect("children");
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].obo_id.as_deref(), Some("MONDO:0005012"));
+
+        let parents = client
+            .related_terms("mondo", iri, OlsRelation::Parents)
+            .await
+            .expect("parents");
+        assert!(parents.is_empty());
+
+        let missing = client
+            .term("mondo", "http://purl.obolibrary.org/obo/MONDO_9999999")
+            .await
+            .expect("missing term");
+        assert!(missing.is_none());
+    }
 }
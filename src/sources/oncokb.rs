@@ -19,10 +19,7 @@ pub struct OncoKBClient {
 
 impl OncoKBClient {
     pub fn new() -> Result<Self, BioMcpError> {
-        let token = std::env::var(ONCOKB_TOKEN_ENV)
-            .ok()
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty());
+        let token = crate::sources::tenant::api_key(ONCOKB_TOKEN_ENV);
         let base = std::env::var(ONCOKB_BASE_ENV)
             .ok()
             .map(|s| s.trim().to_string())
@@ -20,10 +20,7 @@ impl OpenFdaClient {
         Ok(Self {
             client: crate::sources::shared_client()?,
             base: crate::sources::env_base(OPENFDA_BASE, OPENFDA_BASE_ENV),
-            api_key: std::env::var("OPENFDA_API_KEY")
-                .ok()
-                .map(|v| v.trim().to_string())
-                .filter(|v| !v.is_empty()),
+            api_key: crate::sources::tenant::api_key("OPENFDA_API_KEY"),
         })
     }
 
@@ -378,6 +375,108 @@ impl OpenFdaClient {
         }
         self.get_json_optional(req).await
     }
+
+    /// Searches 510(k) premarket notifications, newest decision first.
+    pub async fn device_510k_search(
+        &self,
+        query: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Option<OpenFdaResponse<Device510kResult>>, BioMcpError> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "Query is required. Example: biomcp search device-clearance --product-code DXY"
+                    .into(),
+            ));
+        }
+        if query.len() > 1024 {
+            return Err(BioMcpError::InvalidArgument("Query is too long.".into()));
+        }
+        if limit == 0 || limit > 50 {
+            return Err(BioMcpError::InvalidArgument(
+                "--limit must be between 1 and 50".into(),
+            ));
+        }
+
+        let url = self.endpoint("device/510k.json");
+        let skip = offset.to_string();
+        let mut req = self.client.get(&url).query(&[
+            ("search", query),
+            ("limit", &limit.to_string()),
+            ("skip", skip.as_str()),
+            ("sort", "decision_date:desc"),
+        ]);
+        if let Some(key) = self.api_key.as_deref() {
+            req = req.query(&[("api_key", key)]);
+        }
+        self.get_json_optional(req).await
+    }
+
+    /// Looks up the device classification record for a three-letter product code.
+    pub async fn device_classification(
+        &self,
+        product_code: &str,
+    ) -> Result<Option<DeviceClassificationResult>, BioMcpError> {
+        let product_code = product_code.trim();
+        if product_code.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "Product code is required.".into(),
+            ));
+        }
+
+        let url = self.endpoint("device/classification.json");
+        let query = format!(
+            "product_code:\"{}\"",
+            Self::escape_query_value(product_code)
+        );
+        let mut req = self
+            .client
+            .get(&url)
+            .query(&[("search", query.as_str()), ("limit", "1")]);
+        if let Some(key) = self.api_key.as_deref() {
+            req = req.query(&[("api_key", key)]);
+        }
+        let resp: Option<OpenFdaResponse<DeviceClassificationResult>> =
+            self.get_json_optional(req).await?;
+        Ok(resp.and_then(|resp| resp.results.into_iter().next()))
+    }
+
+    pub async fn veterinary_event_search(
+        &self,
+        query: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Option<OpenFdaResponse<VeterinaryEventResult>>, BioMcpError> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "Query is required. Example: biomcp search adverse-event --species veterinary -d carprofen"
+                    .into(),
+            ));
+        }
+        if query.len() > 1024 {
+            return Err(BioMcpError::InvalidArgument("Query is too long.".into()));
+        }
+        if limit == 0 || limit > 50 {
+            return Err(BioMcpError::InvalidArgument(
+                "--limit must be between 1 and 50".into(),
+            ));
+        }
+
+        let url = self.endpoint("animalandveterinary/event.json");
+        let skip = offset.to_string();
+        let mut req = self.client.get(&url).query(&[
+            ("search", query),
+            ("limit", &limit.to_string()),
+            ("skip", skip.as_str()),
+            ("sort", "original_receive_date:desc"),
+        ]);
+        if let Some(key) = self.api_key.as_deref() {
+            req = req.query(&[("api_key", key)]);
+        }
+        self.get_json_optional(req).await
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -617,6 +716,114 @@ pub struct DeviceEventText {
     pub text: Option<String>,
 }
 
+/// One 510(k) premarket notification from `device/510k.json`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Device510kResult {
+    pub k_number: String,
+    #[serde(default)]
+    pub device_name: Option<String>,
+    #[serde(default)]
+    pub applicant: Option<String>,
+    #[serde(default)]
+    pub product_code: Option<String>,
+    #[serde(default)]
+    pub decision_description: Option<String>,
+    #[serde(default)]
+    pub decision_date: Option<String>,
+    #[serde(default)]
+    pub date_received: Option<String>,
+    #[serde(default)]
+    pub clearance_type: Option<String>,
+    #[serde(default)]
+    pub advisory_committee_description: Option<String>,
+    #[serde(default)]
+    pub statement_or_summary: Option<String>,
+    #[serde(default)]
+    pub third_party_flag: Option<String>,
+    #[serde(default)]
+    pub expedited_review_flag: Option<String>,
+    #[serde(default)]
+    pub openfda: Option<Device510kOpenFda>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Device510kOpenFda {
+    #[serde(default)]
+    pub device_class: Option<String>,
+    #[serde(default)]
+    pub regulation_number: Option<String>,
+}
+
+/// One product-code record from `device/classification.json`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeviceClassificationResult {
+    pub product_code: String,
+    #[serde(default)]
+    pub device_name: Option<String>,
+    #[serde(default)]
+    pub device_class: Option<String>,
+    #[serde(default)]
+    pub regulation_number: Option<String>,
+    #[serde(default)]
+    pub medical_specialty_description: Option<String>,
+    #[serde(default)]
+    pub definition: Option<String>,
+    #[serde(default)]
+    pub implant_flag: Option<String>,
+    #[serde(default)]
+    pub life_sustain_support_flag: Option<String>,
+    #[serde(default)]
+    pub gmp_exempt_flag: Option<String>,
+    #[serde(default)]
+    pub third_party_flag: Option<String>,
+}
+
+/// One report from the openFDA animal & veterinary adverse-event endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VeterinaryEventResult {
+    #[serde(default)]
+    pub unique_aer_id_number: Option<String>,
+    #[serde(default)]
+    pub report_id: Option<String>,
+    #[serde(default)]
+    pub original_receive_date: Option<String>,
+    /// Published as `"true"`/`"false"` strings, occasionally as JSON booleans.
+    #[serde(default)]
+    pub serious_ae: Option<serde_json::Value>,
+    #[serde(default)]
+    pub animal: Option<VeterinaryAnimal>,
+    #[serde(default)]
+    pub drug: Vec<VeterinaryDrug>,
+    #[serde(default)]
+    pub reaction: Vec<VeterinaryReaction>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VeterinaryAnimal {
+    #[serde(default)]
+    pub species: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VeterinaryDrug {
+    #[serde(default)]
+    pub brand_name: Option<String>,
+    #[serde(default)]
+    pub active_ingredients: Vec<VeterinaryActiveIngredient>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VeterinaryActiveIngredient {
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VeterinaryReaction {
+    #[serde(default)]
+    pub veddra_term_name: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -662,6 +869,117 @@ mod tests {
         assert!(resp.is_some());
     }
 
+    #[tokio::test]
+    async fn veterinary_event_search_hits_animal_endpoint() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/animalandveterinary/event.json"))
+            .and(query_param("sort", "original_receive_date:desc"))
+            .and(query_param("limit", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "meta": {"results": {"skip": 0, "limit": 2, "total": 1}},
+                "results": [{
+                    "unique_aer_id_number": "USA-USFDACVM-2019-US-012345",
+                    "original_receive_date": "20190412",
+                    "serious_ae": "true",
+                    "animal": {"species": "Dog"},
+                    "drug": [{
+                        "brand_name": "Rimadyl",
+                        "active_ingredients": [{"name": "Carprofen"}]
+                    }],
+                    "reaction": [{"veddra_term_name": "Vomiting"}]
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = OpenFdaClient::new_for_test(server.uri(), None).unwrap();
+        let resp = client
+            .veterinary_event_search("drug.active_ingredients.name:\"carprofen\"", 2, 0)
+            .await
+            .unwrap()
+            .expect("response");
+        assert_eq!(resp.results.len(), 1);
+        let row = &resp.results[0];
+        assert_eq!(
+            row.animal.as_ref().and_then(|a| a.species.as_deref()),
+            Some("Dog")
+        );
+        assert_eq!(
+            row.drug[0].active_ingredients[0].name.as_deref(),
+            Some("Carprofen")
+        );
+        assert_eq!(
+            row.reaction[0].veddra_term_name.as_deref(),
+            Some("Vomiting")
+        );
+    }
+
+    #[tokio::test]
+    async fn device_510k_search_and_classification_hit_device_endpoints() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/device/510k.json"))
+            .and(query_param("search", "k_number:\"K213456\""))
+            .and(query_param("sort", "decision_date:desc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "meta": {"results": {"skip": 0, "limit": 1, "total": 1}},
+                "results": [{
+                    "k_number": "K213456",
+                    "device_name": "Continuous Glucose Monitor",
+                    "product_code": "QBJ",
+                    "decision_date": "2021-11-03",
+                    "openfda": {"device_class": "2", "regulation_number": "862.1355"}
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/device/classification.json"))
+            .and(query_param("search", "product_code:\"QBJ\""))
+            .and(query_param("limit", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "meta": {"results": {"skip": 0, "limit": 1, "total": 1}},
+                "results": [{
+                    "product_code": "QBJ",
+                    "device_class": "2",
+                    "implant_flag": "N",
+                    "medical_specialty_description": "Clinical Chemistry"
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = OpenFdaClient::new_for_test(server.uri(), None).unwrap();
+        let resp = client
+            .device_510k_search("k_number:\"K213456\"", 1, 0)
+            .await
+            .unwrap()
+            .expect("510(k) response");
+        let row = &resp.results[0];
+        assert_eq!(row.product_code.as_deref(), Some("QBJ"));
+        assert_eq!(
+            row.openfda.as_ref().and_then(|o| o.device_class.as_deref()),
+            Some("2")
+        );
+
+        let classification = client
+            .device_classification("QBJ")
+            .await
+            .unwrap()
+            .expect("classification");
+        assert_eq!(classification.implant_flag.as_deref(), Some("N"));
+        assert_eq!(
+            classification.medical_specialty_description.as_deref(),
+            Some("Clinical Chemistry")
+        );
+    }
+
     #[tokio::test]
     async fn drugsfda_search_validates_limit_bounds() {
         let client = OpenFdaClient::new_for_test("http://127.0.0.1".into(), None).unwrap();
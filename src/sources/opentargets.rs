@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
@@ -366,6 +367,82 @@ query TargetDruggabilityContext($ensemblId: String!) {
         })
     }
 
+    /// Full OpenTargets tractability ladder and safety liabilities for a target.
+    pub async fn target_tractability(&self, symbol: &str) -> Result<GeneTractability, BioMcpError> {
+        let symbol = symbol.trim();
+        if symbol.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "OpenTargets target symbol is required".into(),
+            ));
+        }
+
+        let Some(target_id) = self.resolve_target_id(symbol).await? else {
+            return Ok(GeneTractability::default());
+        };
+
+        let url = self.endpoint("graphql");
+        let body = GraphQlRequest {
+            query: r#"
+query TargetTractability($ensemblId: String!) {
+  target(ensemblId: $ensemblId) {
+    tractability {
+      label
+      modality
+      value
+    }
+    safetyLiabilities {
+      event
+      eventId
+      datasource
+      literature
+      url
+      effects {
+        direction
+        dosing
+      }
+      biosamples {
+        tissueLabel
+        cellLabel
+        cellFormat
+      }
+    }
+  }
+}
+"#,
+            variables: serde_json::json!({
+                "ensemblId": target_id,
+            }),
+        };
+
+        let resp: GraphQlResponse<TargetDruggabilityData> =
+            self.post_json(self.client.post(&url), &body).await?;
+
+        if let Some(errors) = resp.errors {
+            let msg = errors
+                .into_iter()
+                .filter_map(|e| e.message)
+                .collect::<Vec<_>>()
+                .join("; ");
+            if !msg.is_empty() {
+                return Err(BioMcpError::Api {
+                    api: OPENTARGETS_API.to_string(),
+                    message: msg,
+                });
+            }
+        }
+
+        let Some(target) = resp.data.and_then(|d| d.target) else {
+            warn_missing_field("TargetTractability", "data.target");
+            return Ok(GeneTractability::default());
+        };
+
+        Ok(GeneTractability {
+            target_id: Some(target_id),
+            modalities: tractability_buckets(target.tractability),
+            safety_events: safety_events(target.safety_liabilities),
+        })
+    }
+
     pub async fn target_clinical_context(
         &self,
         symbol: &str,
@@ -655,6 +732,227 @@ query DiseasePrevalence($efoId: String!, $size: Int!) {
         Ok(out)
     }
 
+    /// Drugs and clinical candidates indicated for a disease, one row per drug at its
+    /// furthest clinical stage.
+    pub async fn disease_drug_candidates(
+        &self,
+        disease_query: &str,
+        limit: usize,
+    ) -> Result<Vec<OpenTargetsDiseaseDrug>, BioMcpError> {
+        let disease_query = disease_query.trim();
+        if disease_query.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "OpenTargets disease query is required".into(),
+            ));
+        }
+
+        let Some(efo_id) = self.resolve_disease_id(disease_query).await? else {
+            return Ok(Vec::new());
+        };
+        let size = limit.clamp(1, 50);
+        let url = self.endpoint("graphql");
+        let body = GraphQlRequest {
+            query: r#"
+query DiseaseDrugCandidates($efoId: String!) {
+  disease(efoId: $efoId) {
+    drugAndClinicalCandidates {
+      rows {
+        maxClinicalStage
+        drug {
+          id
+          name
+          drugType
+        }
+      }
+    }
+  }
+}
+"#,
+            variables: serde_json::json!({ "efoId": efo_id }),
+        };
+
+        let resp: GraphQlResponse<DiseaseDrugCandidatesData> =
+            self.post_json(self.client.post(&url), &body).await?;
+
+        if let Some(errors) = resp.errors {
+            let msg = errors
+                .into_iter()
+                .filter_map(|e| e.message)
+                .collect::<Vec<_>>()
+                .join("; ");
+            if !msg.is_empty() {
+                return Err(BioMcpError::Api {
+                    api: OPENTARGETS_API.to_string(),
+                    message: msg,
+                });
+            }
+        }
+
+        let Some(rows) = resp
+            .data
+            .and_then(|d| d.disease)
+            .and_then(|d| d.drug_and_clinical_candidates)
+            .map(|c| c.rows)
+        else {
+            warn_missing_field(
+                "DiseaseDrugCandidates",
+                "data.disease.drugAndClinicalCandidates",
+            );
+            return Ok(Vec::new());
+        };
+
+        let mut out = furthest_stage_drugs(rows.into_iter().filter_map(|row| {
+            let drug = row.drug?;
+            Some(OpenTargetsDiseaseDrug {
+                chembl_id: clean_optional(drug.id),
+                name: clean_optional(drug.name)?,
+                drug_type: clean_optional(drug.drug_type),
+                max_clinical_stage: clean_optional(row.max_clinical_stage),
+            })
+        }));
+        out.truncate(size);
+        Ok(out)
+    }
+
+    /// Association score for one gene-disease pair, plus the drugs indicated for
+    /// the disease whose mechanism of action targets the gene.
+    pub async fn gene_disease_evidence(
+        &self,
+        symbol: &str,
+        disease_query: &str,
+    ) -> Result<Option<OpenTargetsGeneDiseaseEvidence>, BioMcpError> {
+        let (symbol, disease_query) = (symbol.trim(), disease_query.trim());
+        if symbol.is_empty() || disease_query.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "OpenTargets gene symbol and disease query are required".into(),
+            ));
+        }
+
+        let (Some(efo_id), Some(target_id)) = (
+            self.resolve_disease_id(disease_query).await?,
+            self.resolve_target_id(symbol).await?,
+        ) else {
+            return Ok(None);
+        };
+
+        let url = self.endpoint("graphql");
+        let body = GraphQlRequest {
+            query: r#"
+query GeneDiseaseEvidence($efoId: String!, $ensemblId: String!) {
+  disease(efoId: $efoId) {
+    id
+    name
+    associatedTargets(Bs: [$ensemblId]) {
+      rows {
+        score
+        datatypeScores {
+          id
+          score
+        }
+        target {
+          approvedSymbol
+        }
+      }
+    }
+    drugAndClinicalCandidates {
+      rows {
+        maxClinicalStage
+        drug {
+          id
+          name
+          drugType
+          mechanismsOfAction {
+            rows {
+              targets {
+                approvedSymbol
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+}
+"#,
+            variables: serde_json::json!({
+                "efoId": efo_id,
+                "ensemblId": target_id,
+            }),
+        };
+
+        let resp: GraphQlResponse<GeneDiseaseEvidenceData> =
+            self.post_json(self.client.post(&url), &body).await?;
+
+        if let Some(errors) = resp.errors {
+            let msg = errors
+                .into_iter()
+                .filter_map(|e| e.message)
+                .collect::<Vec<_>>()
+                .join("; ");
+            if !msg.is_empty() {
+                return Err(BioMcpError::Api {
+                    api: OPENTARGETS_API.to_string(),
+                    message: msg,
+                });
+            }
+        }
+
+        let Some(disease) = resp.data.and_then(|d| d.disease) else {
+            warn_missing_field("GeneDiseaseEvidence", "data.disease");
+            return Ok(None);
+        };
+
+        let association = disease
+            .associated_targets
+            .map(|v| v.rows)
+            .unwrap_or_default()
+            .into_iter()
+            .next();
+        let mut datatype_scores = association
+            .as_ref()
+            .map(|row| {
+                row.datatype_scores
+                    .iter()
+                    .filter_map(|score| Some((clean_optional(score.id.clone())?, score.score?)))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        datatype_scores.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let drug_rows = disease
+            .drug_and_clinical_candidates
+            .map(|c| c.rows)
+            .unwrap_or_default();
+        let drugs = furthest_stage_drugs(drug_rows.into_iter().filter_map(|row| {
+            let drug = row.drug?;
+            let targets_gene = drug
+                .mechanisms_of_action
+                .map(|moa| moa.rows)
+                .unwrap_or_default()
+                .iter()
+                .flat_map(|moa| &moa.targets)
+                .filter_map(|target| target.approved_symbol.as_deref())
+                .any(|target| target.trim().eq_ignore_ascii_case(symbol));
+            if !targets_gene {
+                return None;
+            }
+            Some(OpenTargetsDiseaseDrug {
+                chembl_id: clean_optional(drug.id),
+                name: clean_optional(drug.name)?,
+                drug_type: clean_optional(drug.drug_type),
+                max_clinical_stage: clean_optional(row.max_clinical_stage),
+            })
+        }));
+
+        Ok(Some(OpenTargetsGeneDiseaseEvidence {
+            disease_id: clean_optional(disease.id).unwrap_or(efo_id),
+            disease_name: clean_optional(disease.name),
+            overall_score: association.and_then(|row| row.score),
+            datatype_scores,
+            drugs,
+        }))
+    }
+
     async fn resolve_disease_id(&self, disease_query: &str) -> Result<Option<String>, BioMcpError> {
         let prefixed = normalize_disease_id(disease_query);
         if let Some(id) = prefixed.as_deref().filter(|id| id.starts_with("EFO_")) {
@@ -866,12 +1164,73 @@ pub struct OpenTargetsSafetyLiability {
     pub biosample: Option<String>,
 }
 
+/// Gene `tractability` section: every OpenTargets bucket per modality plus
+/// the full list of known safety events.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct GeneTractability {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<String>,
+    #[serde(default)]
+    pub modalities: Vec<GeneTractabilityModalityBuckets>,
+    #[serde(default)]
+    pub safety_events: Vec<GeneSafetyEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GeneTractabilityModalityBuckets {
+    pub modality: String,
+    pub tractable: bool,
+    pub buckets: Vec<GeneTractabilityBucket>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GeneTractabilityBucket {
+    pub label: String,
+    pub value: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GeneSafetyEvent {
+    pub event: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub effects: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub biosamples: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub datasources: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pmids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct OpenTargetsTargetClinicalContext {
     pub diseases: Vec<String>,
     pub drugs: Vec<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct OpenTargetsDiseaseDrug {
+    pub chembl_id: Option<String>,
+    pub name: String,
+    pub drug_type: Option<String>,
+    pub max_clinical_stage: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenTargetsGeneDiseaseEvidence {
+    pub disease_id: String,
+    pub disease_name: Option<String>,
+    /// Overall association score (0-1); `None` when OpenTargets has no evidence.
+    pub overall_score: Option<f64>,
+    /// Per-datatype scores such as `somatic_mutation` or `literature`, highest first.
+    pub datatype_scores: Vec<(String, f64)>,
+    pub drugs: Vec<OpenTargetsDiseaseDrug>,
+}
+
 #[derive(Debug, Clone)]
 pub struct OpenTargetsDiseasePrevalence {
     pub estimate: String,
@@ -955,6 +1314,74 @@ struct DiseasePrevalenceData {
     disease: Option<DiseasePrevalenceNode>,
 }
 
+#[derive(Debug, Deserialize)]
+struct DiseaseDrugCandidatesData {
+    disease: Option<DiseaseDrugCandidatesNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiseaseDrugCandidatesNode {
+    drug_and_clinical_candidates: Option<DiseaseDrugAndClinicalCandidates>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DiseaseDrugAndClinicalCandidates {
+    #[serde(default)]
+    rows: Vec<DiseaseDrugCandidateRow>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiseaseDrugCandidateRow {
+    max_clinical_stage: Option<String>,
+    drug: Option<DiseaseDrugNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiseaseDrugNode {
+    id: Option<String>,
+    name: Option<String>,
+    drug_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeneDiseaseEvidenceData {
+    disease: Option<GeneDiseaseNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeneDiseaseNode {
+    id: Option<String>,
+    name: Option<String>,
+    associated_targets: Option<AssociatedTargets>,
+    drug_and_clinical_candidates: Option<GeneDiseaseDrugCandidates>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeneDiseaseDrugCandidates {
+    #[serde(default)]
+    rows: Vec<GeneDiseaseDrugRow>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeneDiseaseDrugRow {
+    max_clinical_stage: Option<String>,
+    drug: Option<GeneDiseaseDrugNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeneDiseaseDrugNode {
+    id: Option<String>,
+    name: Option<String>,
+    drug_type: Option<String>,
+    mechanisms_of_action: Option<DrugMechanismsOfAction>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct DiseasePrevalenceNode {
@@ -1142,8 +1569,14 @@ struct TractabilityRow {
 #[serde(rename_all = "camelCase")]
 struct SafetyLiabilityRow {
     event: Option<String>,
+    #[serde(default)]
+    event_id: Option<String>,
     datasource: Option<String>,
     #[serde(default)]
+    literature: Option<String>,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
     effects: Vec<SafetyEffectRow>,
     #[serde(default)]
     biosamples: Vec<SafetyBiosampleRow>,
@@ -1152,7 +1585,6 @@ struct SafetyLiabilityRow {
 #[derive(Debug, Deserialize)]
 struct SafetyEffectRow {
     direction: Option<String>,
-    #[allow(dead_code)]
     dosing: Option<String>,
 }
 
@@ -1170,6 +1602,59 @@ fn clean_optional(value: Option<String>) -> Option<String> {
         .filter(|v| !v.is_empty())
 }
 
+/// Orders OpenTargets `maxClinicalStage` values; approval ranks highest, unknown lowest.
+fn clinical_stage_rank(stage: Option<&str>) -> u8 {
+    match stage
+        .map(|v| v.trim().to_ascii_uppercase())
+        .as_deref()
+        .unwrap_or_default()
+    {
+        "APPROVAL" => 9,
+        "PHASE_4" => 8,
+        "PHASE_3" => 7,
+        "PHASE_2_3" => 6,
+        "PHASE_2" => 5,
+        "PHASE_1_2" => 4,
+        "PHASE_1" => 3,
+        "EARLY_PHASE_1" => 2,
+        "" | "UNKNOWN" => 0,
+        _ => 1,
+    }
+}
+
+/// One row per drug at its furthest clinical stage, furthest stage first.
+fn furthest_stage_drugs(
+    candidates: impl IntoIterator<Item = OpenTargetsDiseaseDrug>,
+) -> Vec<OpenTargetsDiseaseDrug> {
+    let mut out: Vec<OpenTargetsDiseaseDrug> = Vec::new();
+    for candidate in candidates {
+        match out
+            .iter_mut()
+            .find(|existing| existing.name.eq_ignore_ascii_case(&candidate.name))
+        {
+            Some(existing) => {
+                if clinical_stage_rank(candidate.max_clinical_stage.as_deref())
+                    > clinical_stage_rank(existing.max_clinical_stage.as_deref())
+                {
+                    *existing = candidate;
+                }
+            }
+            None => out.push(candidate),
+        }
+    }
+
+    out.sort_by(|a, b| {
+        clinical_stage_rank(b.max_clinical_stage.as_deref())
+            .cmp(&clinical_stage_rank(a.max_clinical_stage.as_deref()))
+            .then_with(|| {
+                a.name
+                    .to_ascii_lowercase()
+                    .cmp(&b.name.to_ascii_lowercase())
+            })
+    });
+    out
+}
+
 fn score_for_id(rows: &[AssociationScoreRow], id: &str) -> Option<f64> {
     rows.iter().find_map(|row| {
         row.id
@@ -1199,18 +1684,18 @@ fn max_score_for_ids(rows: &[AssociationScoreRow], ids: &[&str]) -> Option<f64>
         })
 }
 
+const KNOWN_MODALITIES: [(&str, &str); 4] = [
+    ("SM", "small molecule"),
+    ("AB", "antibody"),
+    ("PR", "PROTAC"),
+    ("OC", "other modality"),
+];
+
 fn summarize_tractability(rows: Vec<TractabilityRow>) -> Vec<OpenTargetsTractabilityModality> {
     if rows.is_empty() {
         return Vec::new();
     }
 
-    const KNOWN_MODALITIES: [(&str, &str); 4] = [
-        ("SM", "small molecule"),
-        ("AB", "antibody"),
-        ("PR", "PROTAC"),
-        ("OC", "other modality"),
-    ];
-
     #[derive(Default)]
     struct TractabilityAccumulator {
         tractable: bool,
@@ -1274,6 +1759,122 @@ fn summarize_tractability(rows: Vec<TractabilityRow>) -> Vec<OpenTargetsTractabi
     out
 }
 
+/// Groups every tractability row by modality, keeping OpenTargets bucket order.
+fn tractability_buckets(rows: Vec<TractabilityRow>) -> Vec<GeneTractabilityModalityBuckets> {
+    let mut out: Vec<(String, GeneTractabilityModalityBuckets)> = Vec::new();
+    for row in rows {
+        let (Some(code), Some(label)) = (
+            clean_optional(row.modality).map(|value| value.to_ascii_uppercase()),
+            clean_optional(row.label),
+        ) else {
+            continue;
+        };
+        let value = row.value.unwrap_or(false);
+        let idx = match out.iter().position(|(existing, _)| existing == &code) {
+            Some(idx) => idx,
+            None => {
+                let modality = KNOWN_MODALITIES
+                    .iter()
+                    .find(|(known, _)| *known == code.as_str())
+                    .map(|(_, name)| (*name).to_string())
+                    .unwrap_or_else(|| code.to_ascii_lowercase());
+                out.push((
+                    code,
+                    GeneTractabilityModalityBuckets {
+                        modality,
+                        tractable: false,
+                        buckets: Vec::new(),
+                    },
+                ));
+                out.len() - 1
+            }
+        };
+        let group = &mut out[idx].1;
+        group.tractable |= value;
+        if !group
+            .buckets
+            .iter()
+            .any(|bucket| bucket.label.eq_ignore_ascii_case(&label))
+        {
+            group.buckets.push(GeneTractabilityBucket { label, value });
+        }
+    }
+
+    out.sort_by_key(|(code, _)| {
+        KNOWN_MODALITIES
+            .iter()
+            .position(|(known, _)| *known == code.as_str())
+            .unwrap_or(KNOWN_MODALITIES.len())
+    });
+    out.into_iter().map(|(_, group)| group).collect()
+}
+
+fn push_unique(values: &mut Vec<String>, value: Option<String>) {
+    if let Some(value) = value
+        && !values
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(&value))
+    {
+        values.push(value);
+    }
+}
+
+/// Merges safety liability rows by event without the summary cap used by `druggability`.
+fn safety_events(rows: Vec<SafetyLiabilityRow>) -> Vec<GeneSafetyEvent> {
+    let mut out: Vec<GeneSafetyEvent> = Vec::new();
+    for row in rows {
+        let Some(event) = clean_optional(row.event) else {
+            continue;
+        };
+        let idx = match out
+            .iter()
+            .position(|existing| existing.event.eq_ignore_ascii_case(&event))
+        {
+            Some(idx) => idx,
+            None => {
+                out.push(GeneSafetyEvent {
+                    event,
+                    event_id: None,
+                    effects: Vec::new(),
+                    biosamples: Vec::new(),
+                    datasources: Vec::new(),
+                    pmids: Vec::new(),
+                    url: None,
+                });
+                out.len() - 1
+            }
+        };
+
+        let entry = &mut out[idx];
+        if entry.event_id.is_none() {
+            entry.event_id = clean_optional(row.event_id);
+        }
+        if entry.url.is_none() {
+            entry.url = clean_optional(row.url);
+        }
+        push_unique(&mut entry.datasources, clean_optional(row.datasource));
+        push_unique(&mut entry.pmids, clean_optional(row.literature));
+        for effect in row.effects {
+            let direction = clean_optional(effect.direction);
+            let dosing = clean_optional(effect.dosing);
+            let label = match (direction, dosing) {
+                (Some(direction), Some(dosing)) => Some(format!("{direction} ({dosing})")),
+                (direction, dosing) => direction.or(dosing),
+            };
+            push_unique(&mut entry.effects, label);
+        }
+        for biosample in row.biosamples {
+            push_unique(
+                &mut entry.biosamples,
+                clean_optional(biosample.tissue_label)
+                    .or_else(|| clean_optional(biosample.cell_label))
+                    .or_else(|| clean_optional(biosample.cell_format)),
+            );
+        }
+    }
+    out
+}
+
 fn summarize_safety_liabilities(rows: Vec<SafetyLiabilityRow>) -> Vec<OpenTargetsSafetyLiability> {
     let mut out: Vec<OpenTargetsSafetyLiability> = Vec::new();
     let mut indices: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
@@ -1753,6 +2354,118 @@ pub(crate) mod tests {
         assert!(context.safety_liabilities.is_empty());
     }
 
+    #[tokio::test]
+    async fn target_tractability_keeps_bucket_ladder_and_all_safety_events() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("SearchTarget"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "search": {
+                        "hits": [
+                            {"id": "ENSG00000146648", "entity": "target", "object": {"approvedSymbol": "EGFR"}}
+                        ]
+                    }
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("TargetTractability"))
+            .and(body_string_contains("\"ensemblId\":\"ENSG00000146648\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "target": {
+                        "tractability": [
+                            {"label": "Approved Drug", "modality": "AB", "value": true},
+                            {"label": "UniProt loc high conf", "modality": "AB", "value": true},
+                            {"label": "Approved Drug", "modality": "SM", "value": true},
+                            {"label": "Structure with Ligand", "modality": "SM", "value": true},
+                            {"label": "High-Quality Pocket", "modality": "SM", "value": false},
+                            {"label": "Literature", "modality": "PR", "value": false},
+                            {"label": "", "modality": "OC", "value": true}
+                        ],
+                        "safetyLiabilities": [
+                            {
+                                "event": "dermatitis",
+                                "eventId": "EFO_0000274",
+                                "datasource": "Lynch et al. (2018)",
+                                "literature": "29740437",
+                                "url": null,
+                                "effects": [{"direction": "Inhibition/Decrease/Downregulation", "dosing": "chronic"}],
+                                "biosamples": [{"tissueLabel": "skin", "cellLabel": null, "cellFormat": null}]
+                            },
+                            {
+                                "event": "Dermatitis",
+                                "eventId": null,
+                                "datasource": "ToxCast",
+                                "literature": null,
+                                "url": "https://www.epa.gov/comptox-tools",
+                                "effects": [{"direction": "Inhibition/Decrease/Downregulation", "dosing": "chronic"}],
+                                "biosamples": [{"tissueLabel": null, "cellLabel": "keratinocyte", "cellFormat": null}]
+                            },
+                            {
+                                "event": "diarrhoea",
+                                "datasource": "Lynch et al. (2018)",
+                                "effects": [],
+                                "biosamples": []
+                            }
+                        ]
+                    }
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = OpenTargetsClient::new_for_test(server.uri()).unwrap();
+        let tractability = client.target_tractability("EGFR").await.unwrap();
+
+        assert_eq!(tractability.target_id.as_deref(), Some("ENSG00000146648"));
+        let modalities = tractability
+            .modalities
+            .iter()
+            .map(|group| {
+                (
+                    group.modality.as_str(),
+                    group.tractable,
+                    group.buckets.len(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            modalities,
+            vec![
+                ("small molecule", true, 3),
+                ("antibody", true, 2),
+                ("PROTAC", false, 1)
+            ]
+        );
+        assert!(!tractability.modalities[0].buckets[2].value);
+
+        assert_eq!(tractability.safety_events.len(), 2);
+        let dermatitis = &tractability.safety_events[0];
+        assert_eq!(dermatitis.event_id.as_deref(), Some("EFO_0000274"));
+        assert_eq!(
+            dermatitis.effects,
+            vec!["Inhibition/Decrease/Downregulation (chronic)"]
+        );
+        assert_eq!(dermatitis.biosamples, vec!["skin", "keratinocyte"]);
+        assert_eq!(
+            dermatitis.datasources,
+            vec!["Lynch et al. (2018)", "ToxCast"]
+        );
+        assert_eq!(dermatitis.pmids, vec!["29740437"]);
+        assert_eq!(
+            dermatitis.url.as_deref(),
+            Some("https://www.epa.gov/comptox-tools")
+        );
+        assert_eq!(tractability.safety_events[1].event, "diarrhoea");
+    }
+
     #[tokio::test]
     async fn disease_prevalence_maps_frequency_evidence() {
         let server = MockServer::start().await;
@@ -1909,6 +2622,131 @@ pub(crate) mod tests {
         assert!(context.drugs.is_empty());
     }
 
+    #[tokio::test]
+    async fn disease_drug_candidates_keeps_furthest_stage_per_drug() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("DiseaseDrugCandidates"))
+            .and(body_string_contains("\"efoId\":\"EFO_0000685\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "disease": {
+                        "drugAndClinicalCandidates": {
+                            "rows": [
+                                {"maxClinicalStage": "PHASE_2", "drug": {"id": "CHEMBL1", "name": "Upadacitinib", "drugType": "Small molecule"}},
+                                {"maxClinicalStage": "APPROVAL", "drug": {"id": "CHEMBL2", "name": "Methotrexate", "drugType": "Small molecule"}},
+                                {"maxClinicalStage": "APPROVAL", "drug": {"id": "CHEMBL1", "name": "UPADACITINIB", "drugType": "Small molecule"}},
+                                {"maxClinicalStage": "PHASE_3", "drug": {"id": "CHEMBL3", "name": "Olokizumab", "drugType": "Antibody"}},
+                                {"maxClinicalStage": "APPROVAL", "drug": {"id": "CHEMBL4", "name": " "}}
+                            ]
+                        }
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = OpenTargetsClient::new_for_test(server.uri()).unwrap();
+        let rows = client
+            .disease_drug_candidates("EFO_0000685", 10)
+            .await
+            .unwrap();
+        let summary = rows
+            .iter()
+            .map(|row| (row.name.as_str(), row.max_clinical_stage.as_deref()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("Methotrexate", Some("APPROVAL")),
+                ("UPADACITINIB", Some("APPROVAL")),
+                ("Olokizumab", Some("PHASE_3")),
+            ]
+        );
+        assert_eq!(rows[2].drug_type.as_deref(), Some("Antibody"));
+    }
+
+    #[tokio::test]
+    async fn gene_disease_evidence_scores_pair_and_keeps_drugs_targeting_gene() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("SearchTarget"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "search": {
+                        "hits": [
+                            {"id": "ENSG00000157764", "entity": "target", "object": {"approvedSymbol": "BRAF"}}
+                        ]
+                    }
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("GeneDiseaseEvidence"))
+            .and(body_string_contains("\"efoId\":\"EFO_0000756\""))
+            .and(body_string_contains("\"ensemblId\":\"ENSG00000157764\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "disease": {
+                        "id": "EFO_0000756",
+                        "name": "melanoma",
+                        "associatedTargets": {
+                            "rows": [{
+                                "score": 0.82,
+                                "datatypeScores": [
+                                    {"id": "literature", "score": 0.4},
+                                    {"id": "somatic_mutation", "score": 0.9}
+                                ],
+                                "target": {"approvedSymbol": "BRAF"}
+                            }]
+                        },
+                        "drugAndClinicalCandidates": {
+                            "rows": [
+                                {"maxClinicalStage": "PHASE_3", "drug": {"id": "CHEMBL1", "name": "Vemurafenib", "mechanismsOfAction": {"rows": [{"targets": [{"approvedSymbol": "BRAF"}]}]}}},
+                                {"maxClinicalStage": "APPROVAL", "drug": {"id": "CHEMBL1", "name": "Vemurafenib", "mechanismsOfAction": {"rows": [{"targets": [{"approvedSymbol": "BRAF"}]}]}}},
+                                {"maxClinicalStage": "APPROVAL", "drug": {"id": "CHEMBL2", "name": "Pembrolizumab", "mechanismsOfAction": {"rows": [{"targets": [{"approvedSymbol": "PDCD1"}]}]}}}
+                            ]
+                        }
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = OpenTargetsClient::new_for_test(server.uri()).unwrap();
+        let evidence = client
+            .gene_disease_evidence("BRAF", "EFO_0000756")
+            .await
+            .unwrap()
+            .expect("pair should resolve");
+        assert_eq!(evidence.disease_name.as_deref(), Some("melanoma"));
+        assert_eq!(evidence.overall_score, Some(0.82));
+        assert_eq!(evidence.datatype_scores[0].0, "somatic_mutation");
+        assert_eq!(evidence.drugs.len(), 1);
+        assert_eq!(evidence.drugs[0].name, "Vemurafenib");
+        assert_eq!(
+            evidence.drugs[0].max_clinical_stage.as_deref(),
+            Some("APPROVAL")
+        );
+    }
+
+    #[test]
+    fn clinical_stage_rank_orders_approval_above_trial_phases() {
+        assert!(clinical_stage_rank(Some("APPROVAL")) > clinical_stage_rank(Some("PHASE_4")));
+        assert!(clinical_stage_rank(Some("phase_3")) > clinical_stage_rank(Some("PHASE_2_3")));
+        assert!(clinical_stage_rank(Some("PRECLINICAL")) > clinical_stage_rank(None));
+        assert_eq!(clinical_stage_rank(Some("UNKNOWN")), 0);
+    }
+
     #[tokio::test]
     async fn drug_sections_propagates_graphql_error_message() {
         let server = MockServer::start().await;
@@ -0,0 +1,411 @@
+//! Constrained GET passthrough behind `biomcp raw` and the `raw_query` MCP tool.
+//!
+//! Each whitelisted source pins a base URL and the path prefixes a caller may
+//! reach under it; nothing else is addressable. Requests go through the shared
+//! client, so retries, rate limits, and the HTTP cache apply as they do for
+//! mapped commands. Bodies must be JSON and are capped well below the normal
+//! per-source limit.
+
+use crate::error::BioMcpError;
+use crate::sources::{
+    clinicaltrials, ensembl, europepmc, mychem, mygene, myvariant, pubtator, reactome, uniprot,
+};
+
+pub(crate) const PASSTHROUGH_MAX_BODY_BYTES: usize = 1024 * 1024;
+const MAX_PATH_CHARS: usize = 256;
+const MAX_PARAMS: usize = 20;
+const MAX_PARAM_VALUE_CHARS: usize = 512;
+/// Credentials never travel through the passthrough; every source below is keyless.
+const BLOCKED_PARAM_KEY_PARTS: &[&str] = &["key", "token", "secret", "password"];
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PassthroughSource {
+    name: &'static str,
+    api: &'static str,
+    base: &'static str,
+    base_env: &'static str,
+    /// Allowed path prefixes; a prefix matches itself and anything below it.
+    paths: &'static [&'static str],
+    /// Parameters the source needs to answer in JSON.
+    fixed_params: &'static [(&'static str, &'static str)],
+}
+
+pub(crate) const PASSTHROUGH_SOURCES: &[PassthroughSource] = &[
+    PassthroughSource {
+        name: "mygene",
+        api: "mygene.info",
+        base: mygene::MYGENE_BASE,
+        base_env: mygene::MYGENE_BASE_ENV,
+        paths: &["query", "gene", "metadata"],
+        fixed_params: &[],
+    },
+    PassthroughSource {
+        name: "myvariant",
+        api: "myvariant.info",
+        base: myvariant::MYVARIANT_BASE,
+        base_env: myvariant::MYVARIANT_BASE_ENV,
+        paths: &["query", "variant", "metadata"],
+        fixed_params: &[],
+    },
+    PassthroughSource {
+        name: "mychem",
+        api: "mychem.info",
+        base: mychem::MYCHEM_BASE,
+        base_env: mychem::MYCHEM_BASE_ENV,
+        paths: &["query", "chem", "metadata"],
+        fixed_params: &[],
+    },
+    PassthroughSource {
+        name: "uniprot",
+        api: "uniprot",
+        base: uniprot::UNIPROT_BASE,
+        base_env: uniprot::UNIPROT_BASE_ENV,
+        paths: &["uniprotkb"],
+        fixed_params: &[("format", "json")],
+    },
+    PassthroughSource {
+        name: "ensembl",
+        api: "ensembl",
+        base: ensembl::ENSEMBL_BASE,
+        base_env: ensembl::ENSEMBL_BASE_ENV,
+        paths: &["lookup", "xrefs", "homology", "overlap"],
+        fixed_params: &[("content-type", "application/json")],
+    },
+    PassthroughSource {
+        name: "clinicaltrials",
+        api: "clinicaltrials.gov",
+        base: clinicaltrials::CTGOV_BASE,
+        base_env: clinicaltrials::CTGOV_BASE_ENV,
+        paths: &["studies"],
+        fixed_params: &[("format", "json")],
+    },
+    PassthroughSource {
+        name: "europepmc",
+        api: "europepmc",
+        base: europepmc::EUROPE_PMC_BASE,
+        base_env: europepmc::EUROPE_PMC_BASE_ENV,
+        paths: &["search"],
+        fixed_params: &[("format", "json")],
+    },
+    PassthroughSource {
+        name: "pubtator",
+        api: "pubtator3",
+        base: pubtator::PUBTATOR_BASE,
+        base_env: pubtator::PUBTATOR_BASE_ENV,
+        paths: &[
+            "search",
+            "entity/autocomplete",
+            "publications/export/biocjson",
+        ],
+        fixed_params: &[],
+    },
+    PassthroughSource {
+        name: "reactome",
+        api: "reactome",
+        base: reactome::REACTOME_BASE,
+        base_env: reactome::REACTOME_BASE_ENV,
+        paths: &["data", "search"],
+        fixed_params: &[],
+    },
+];
+
+fn source_names() -> String {
+    PASSTHROUGH_SOURCES
+        .iter()
+        .map(|source| source.name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(crate) fn find_source(name: &str) -> Result<&'static PassthroughSource, BioMcpError> {
+    let name = name.trim();
+    PASSTHROUGH_SOURCES
+        .iter()
+        .find(|source| source.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| {
+            BioMcpError::InvalidArgument(format!(
+                "Unknown raw source \"{name}\". Available: {}",
+                source_names()
+            ))
+        })
+}
+
+// `%` is excluded so an encoded `..` (`%2e%2e`) cannot be normalized by the
+// URL parser into a segment outside the allowed prefixes.
+fn is_path_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '/' | '-' | '_' | '.' | ':' | ',' | '+' | '~')
+}
+
+/// Normalizes `path` and checks it against the source's allowed prefixes.
+/// Query strings are refused here; parameters go through `--param`.
+pub(crate) fn validate_path(source: &PassthroughSource, path: &str) -> Result<String, BioMcpError> {
+    let path = path.trim().trim_start_matches('/');
+    let allowed = || source.paths.join(", ");
+    if path.is_empty() {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "A path is required for {}. Allowed prefixes: {}",
+            source.name,
+            allowed()
+        )));
+    }
+    if path.chars().count() > MAX_PATH_CHARS {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Raw path is longer than {MAX_PATH_CHARS} characters."
+        )));
+    }
+    if path.contains('?') || path.contains('#') {
+        return Err(BioMcpError::InvalidArgument(
+            "Raw paths cannot carry a query string. Pass parameters with --param key=value.".into(),
+        ));
+    }
+    if !path.chars().all(is_path_char)
+        || path.contains("//")
+        || path
+            .split('/')
+            .any(|segment| segment == ".." || segment == ".")
+    {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Raw path \"{path}\" has characters or segments that are not allowed."
+        )));
+    }
+    let matches_prefix = source.paths.iter().any(|prefix| {
+        path == *prefix
+            || path
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with('/'))
+    });
+    if !matches_prefix {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Path \"{path}\" is not allowed for {}. Allowed prefixes: {}",
+            source.name,
+            allowed()
+        )));
+    }
+    Ok(path.trim_end_matches('/').to_string())
+}
+
+/// Parses one `key=value` parameter.
+pub(crate) fn parse_param(raw: &str) -> Result<(String, String), BioMcpError> {
+    let Some((key, value)) = raw.split_once('=') else {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Parameter \"{raw}\" must look like key=value."
+        )));
+    };
+    let key = key.trim();
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '[' | ']'))
+    {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Parameter name \"{key}\" is not allowed."
+        )));
+    }
+    let lower = key.to_ascii_lowercase();
+    if BLOCKED_PARAM_KEY_PARTS
+        .iter()
+        .any(|part| lower.contains(part))
+    {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Parameter \"{key}\" looks like a credential; raw queries only reach keyless sources."
+        )));
+    }
+    if value.chars().count() > MAX_PARAM_VALUE_CHARS {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "Parameter \"{key}\" is longer than {MAX_PARAM_VALUE_CHARS} characters."
+        )));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
+pub(crate) fn parse_params(raw: &[String]) -> Result<Vec<(String, String)>, BioMcpError> {
+    if raw.len() > MAX_PARAMS {
+        return Err(BioMcpError::InvalidArgument(format!(
+            "At most {MAX_PARAMS} parameters are allowed per raw query."
+        )));
+    }
+    raw.iter().map(|param| parse_param(param)).collect()
+}
+
+async fn get_json(
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: &str,
+    source: &PassthroughSource,
+    path: &str,
+    params: &[(String, String)],
+) -> Result<serde_json::Value, BioMcpError> {
+    let url = format!("{}/{path}", base.trim_end_matches('/'));
+    let req = client.get(url).query(source.fixed_params).query(params);
+    let resp = crate::sources::apply_cache_mode(req).send().await?;
+    let status = resp.status();
+    let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+    let bytes =
+        crate::sources::read_limited_body_with_limit(resp, source.api, PASSTHROUGH_MAX_BODY_BYTES)
+            .await
+            .map_err(|err| match err {
+                BioMcpError::Api { api, message } if message.contains("exceeded") => {
+                    BioMcpError::Api {
+                        api,
+                        message: format!("{message}; narrow the query with a page-size parameter"),
+                    }
+                }
+                other => other,
+            })?;
+
+    if !status.is_success() {
+        return Err(BioMcpError::Api {
+            api: source.api.to_string(),
+            message: format!(
+                "HTTP {status}: {}",
+                crate::sources::summarize_http_error_body(content_type.as_ref(), &bytes)
+            ),
+        });
+    }
+
+    crate::sources::ensure_json_content_type(source.api, content_type.as_ref(), &bytes)?;
+    serde_json::from_slice(&bytes).map_err(|source_err| BioMcpError::ApiJson {
+        api: source.api.to_string(),
+        source: source_err,
+    })
+}
+
+/// Sends a validated GET to a whitelisted source and returns the JSON body.
+pub(crate) async fn fetch(
+    source: &str,
+    path: &str,
+    params: &[String],
+) -> Result<serde_json::Value, BioMcpError> {
+    let source = find_source(source)?;
+    let path = validate_path(source, path)?;
+    let params = parse_params(params)?;
+    let base = crate::sources::env_base(source.base, source.base_env);
+    get_json(
+        crate::sources::shared_client()?,
+        &base,
+        source,
+        &path,
+        &params,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn validate_path_keeps_requests_under_allowed_prefixes() {
+        let ensembl = find_source("Ensembl").expect("ensembl");
+        assert_eq!(
+            validate_path(ensembl, "/lookup/id/ENSG00000157764").expect("lookup"),
+            "lookup/id/ENSG00000157764"
+        );
+        assert!(validate_path(ensembl, "lookupx/id/ENSG00000157764").is_err());
+        assert!(validate_path(ensembl, "vep/human/hgvs/x").is_err());
+        assert!(validate_path(ensembl, "lookup/../vep").is_err());
+        assert!(validate_path(ensembl, "lookup/%2e%2e/vep/human/hgvs/x").is_err());
+        assert!(validate_path(ensembl, "lookup/%2E./vep/human/hgvs/x").is_err());
+        assert!(validate_path(ensembl, "lookup/id/%41").is_err());
+        assert!(validate_path(ensembl, "lookup//id").is_err());
+        assert!(validate_path(ensembl, "").is_err());
+
+        let err = validate_path(ensembl, "lookup/id/X?expand=1").expect_err("query string");
+        assert!(err.to_string().contains("--param"));
+
+        let err = find_source("opentargets").expect_err("unknown source");
+        assert!(err.to_string().contains("Available: mygene, myvariant"));
+    }
+
+    #[test]
+    fn parse_params_rejects_credentials_and_malformed_pairs() {
+        assert_eq!(
+            parse_params(&["q=symbol:BRAF".into(), "fields=symbol,name".into()]).expect("params"),
+            vec![
+                ("q".to_string(), "symbol:BRAF".to_string()),
+                ("fields".to_string(), "symbol,name".to_string()),
+            ]
+        );
+        assert!(parse_param("q").is_err());
+        assert!(parse_param("=BRAF").is_err());
+        assert!(parse_param("a b=c").is_err());
+        assert!(parse_param("api_key=abc").is_err());
+        assert!(parse_param("access_token=abc").is_err());
+        assert!(parse_param(&format!("q={}", "x".repeat(MAX_PARAM_VALUE_CHARS + 1))).is_err());
+        let many = vec!["q=x".to_string(); MAX_PARAMS + 1];
+        assert!(parse_params(&many).is_err());
+    }
+
+    #[tokio::test]
+    async fn get_json_sends_fixed_and_caller_params_and_rejects_non_json() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/lookup/id/ENSG00000157764"))
+            .and(query_param("content-type", "application/json"))
+            .and(query_param("expand", "1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"id": "ENSG00000157764"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/lookup/id/html"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_string("<html>maintenance</html>"),
+            )
+            .mount(&server)
+            .await;
+
+        let ensembl = find_source("ensembl").expect("ensembl");
+        let client = crate::sources::test_client().expect("client");
+        let value = get_json(
+            client.clone(),
+            &server.uri(),
+            ensembl,
+            "lookup/id/ENSG00000157764",
+            &[("expand".to_string(), "1".to_string())],
+        )
+        .await
+        .expect("json body");
+        assert_eq!(value["id"], "ENSG00000157764");
+
+        let err = get_json(client, &server.uri(), ensembl, "lookup/id/html", &[])
+            .await
+            .expect_err("html body");
+        assert!(err.to_string().contains("Unexpected HTML response"));
+    }
+
+    #[tokio::test]
+    async fn get_json_caps_body_size() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/query"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "application/json")
+                    .set_body_string(format!(
+                        "{{\"hits\":\"{}\"}}",
+                        "x".repeat(PASSTHROUGH_MAX_BODY_BYTES)
+                    )),
+            )
+            .mount(&server)
+            .await;
+
+        let mygene = find_source("mygene").expect("mygene");
+        let err = get_json(
+            crate::sources::test_client().expect("client"),
+            &server.uri(),
+            mygene,
+            "query",
+            &[],
+        )
+        .await
+        .expect_err("oversized body");
+        assert!(err.to_string().contains("page-size parameter"));
+    }
+}
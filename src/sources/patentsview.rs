@@ -0,0 +1,234 @@
+//! PatentsView PatentSearch API client for granted US patents mentioning a drug.
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+
+const PATENTSVIEW_BASE: &str = "https://search.patentsview.org/api/v1";
+const PATENTSVIEW_API: &str = "patentsview";
+const PATENTSVIEW_API_KEY_ENV: &str = "PATENTSVIEW_API_KEY";
+const PATENTSVIEW_BASE_ENV: &str = "BIOMCP_PATENTSVIEW_BASE";
+const PATENTSVIEW_DOCS_URL: &str = "https://search.patentsview.org/docs/";
+const PATENTSVIEW_MAX_SIZE: usize = 1000;
+const PATENTSVIEW_FIELDS: &[&str] = &[
+    "patent_id",
+    "patent_title",
+    "patent_date",
+    "patent_type",
+    "patent_earliest_application_date",
+    "assignees.assignee_organization",
+];
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PatentsViewResponse {
+    #[serde(default)]
+    pub total_hits: usize,
+    #[serde(default)]
+    pub patents: Vec<PatentsViewPatent>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PatentsViewPatent {
+    pub patent_id: String,
+    pub patent_title: Option<String>,
+    /// Grant date, `YYYY-MM-DD`.
+    pub patent_date: Option<String>,
+    pub patent_type: Option<String>,
+    /// Earliest filing or priority date in the patent's application chain.
+    pub patent_earliest_application_date: Option<String>,
+    #[serde(default)]
+    pub assignees: Vec<PatentsViewAssignee>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PatentsViewAssignee {
+    pub assignee_organization: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PatentSearchRequest<'a> {
+    q: serde_json::Value,
+    f: &'a [&'a str],
+    o: PatentSearchOptions,
+    s: serde_json::Value,
+}
+
+#[derive(Debug, Serialize)]
+struct PatentSearchOptions {
+    size: usize,
+}
+
+pub struct PatentsViewClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+    api_key: Option<String>,
+}
+
+impl PatentsViewClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(PATENTSVIEW_BASE, PATENTSVIEW_BASE_ENV),
+            api_key: crate::sources::tenant::api_key(PATENTSVIEW_API_KEY_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String, api_key: Option<String>) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+            api_key,
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base.as_ref().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    fn require_api_key(&self) -> Result<&str, BioMcpError> {
+        self.api_key
+            .as_deref()
+            .ok_or_else(|| BioMcpError::ApiKeyRequired {
+                api: PATENTSVIEW_API.to_string(),
+                env_var: PATENTSVIEW_API_KEY_ENV.to_string(),
+                docs_url: PATENTSVIEW_DOCS_URL.to_string(),
+            })
+    }
+
+    /// Searches granted patents whose title or abstract contains any of `terms`
+    /// as a phrase, newest grants first.
+    pub async fn search_by_terms(
+        &self,
+        terms: &[String],
+        size: usize,
+    ) -> Result<PatentsViewResponse, BioMcpError> {
+        let key = self.require_api_key()?;
+        let clauses = terms
+            .iter()
+            .map(|term| term.trim())
+            .filter(|term| !term.is_empty())
+            .flat_map(|term| {
+                [
+                    serde_json::json!({"_text_phrase": {"patent_title": term}}),
+                    serde_json::json!({"_text_phrase": {"patent_abstract": term}}),
+                ]
+            })
+            .collect::<Vec<_>>();
+        if clauses.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "A drug name is required for a patent search.".into(),
+            ));
+        }
+
+        let body = PatentSearchRequest {
+            q: serde_json::json!({"_or": clauses}),
+            f: PATENTSVIEW_FIELDS,
+            o: PatentSearchOptions {
+                size: size.clamp(1, PATENTSVIEW_MAX_SIZE),
+            },
+            s: serde_json::json!([{"patent_date": "desc"}]),
+        };
+        let req = self
+            .client
+            .post(self.endpoint("patent/"))
+            .header("X-Api-Key", key)
+            .json(&body);
+        let resp = crate::sources::apply_cache_mode_with_auth(req, true)
+            .send()
+            .await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, PATENTSVIEW_API).await?;
+
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: PATENTSVIEW_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(PATENTSVIEW_API, content_type.as_ref(), &bytes)?;
+        serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+            api: PATENTSVIEW_API.to_string(),
+            source,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn search_requires_api_key() {
+        let client =
+            PatentsViewClient::new_for_test("http://127.0.0.1:9".into(), None).expect("client");
+        let err = client
+            .search_by_terms(&["imatinib".to_string()], 10)
+            .await
+            .expect_err("missing key");
+        assert!(err.to_string().contains(PATENTSVIEW_API_KEY_ENV));
+    }
+
+    #[tokio::test]
+    async fn search_by_terms_posts_phrase_query_and_parses_patents() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/patent/"))
+            .and(header("X-Api-Key", "test-key"))
+            .and(body_partial_json(serde_json::json!({
+                "q": {"_or": [
+                    {"_text_phrase": {"patent_title": "imatinib"}},
+                    {"_text_phrase": {"patent_abstract": "imatinib"}},
+                    {"_text_phrase": {"patent_title": "Gleevec"}},
+                    {"_text_phrase": {"patent_abstract": "Gleevec"}}
+                ]},
+                "o": {"size": 50}
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "error": false,
+                "count": 1,
+                "total_hits": 37,
+                "patents": [{
+                    "patent_id": "7544799",
+                    "patent_title": "Crystal modification of a N-phenyl-2-pyrimidineamine derivative",
+                    "patent_date": "2009-06-09",
+                    "patent_type": "utility",
+                    "patent_earliest_application_date": "1997-07-18",
+                    "assignees": [{"assignee_organization": "Novartis AG"}]
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            PatentsViewClient::new_for_test(server.uri(), Some("test-key".into())).expect("client");
+        let resp = client
+            .search_by_terms(&["imatinib".to_string(), " Gleevec ".to_string()], 50)
+            .await
+            .expect("patents");
+        assert_eq!(resp.total_hits, 37);
+        assert_eq!(resp.patents.len(), 1);
+        let patent = &resp.patents[0];
+        assert_eq!(patent.patent_id, "7544799");
+        assert_eq!(
+            patent.patent_earliest_application_date.as_deref(),
+            Some("1997-07-18")
+        );
+        assert_eq!(
+            patent.assignees[0].assignee_organization.as_deref(),
+            Some("Novartis AG")
+        );
+    }
+}
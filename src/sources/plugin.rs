@@ -0,0 +1,629 @@
+//! Source plugins: institution-defined sections for `get gene` and `get variant`.
+//!
+//! A plugin contributes one named section, requested like a built-in one
+//! (`biomcp get gene BRAF labdb`). Plugins come from two places:
+//!
+//! - Declarative files: each `*.toml` in `<config_dir>/biomcp/plugins/` (or
+//!   `BIOMCP_PLUGIN_DIR`) describes a JSON HTTP endpoint with `{symbol}`-style
+//!   URL templates. Files that fail to parse or validate are skipped with a
+//!   warning, like template overrides, so a broken plugin never breaks the
+//!   built-in sections.
+//! - Compiled-in types: a private build implements [`SourcePlugin`] and adds
+//!   the type to [`compiled_plugins`] behind its own cargo feature.
+//!
+//! Plugin sections are opt-in: `all` never includes them, and built-in section
+//! names always win over a plugin that reuses one.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::entities::section_errors;
+use crate::error::BioMcpError;
+
+const PLUGIN_DIR_ENV: &str = "BIOMCP_PLUGIN_DIR";
+const PLUGIN_SUFFIX: &str = ".toml";
+const GENE_PLACEHOLDERS: &[&str] = &["symbol", "entrez_id", "ensembl_id", "uniprot_id"];
+const VARIANT_PLACEHOLDERS: &[&str] = &["id", "gene", "rsid", "hgvs_p", "hgvs_c"];
+
+/// Entity a plugin section can be attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PluginEntity {
+    Gene,
+    Variant,
+}
+
+impl PluginEntity {
+    fn placeholders(self) -> &'static [&'static str] {
+        match self {
+            Self::Gene => GENE_PLACEHOLDERS,
+            Self::Variant => VARIANT_PLACEHOLDERS,
+        }
+    }
+}
+
+/// Identifiers of the record a plugin is asked about, keyed by placeholder
+/// name (`symbol`, `entrez_id`, `rsid`, ...). Absent identifiers are omitted.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PluginLookup {
+    values: BTreeMap<&'static str, String>,
+}
+
+impl PluginLookup {
+    pub(crate) fn with(mut self, key: &'static str, value: Option<&str>) -> Self {
+        if let Some(value) = value.map(str::trim).filter(|value| !value.is_empty()) {
+            self.values.insert(key, value.to_string());
+        }
+        self
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+}
+
+/// One plugin section attached to a gene or variant card.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PluginSection {
+    /// Section token the caller requested, e.g. `labdb`.
+    pub section: String,
+    /// Plugin name, used as the section's source label.
+    pub source: String,
+    /// Plugin payload, passed through unchanged.
+    pub data: serde_json::Value,
+}
+
+#[async_trait::async_trait]
+pub(crate) trait SourcePlugin: Send + Sync {
+    /// Source label shown in headings, provenance, and section errors.
+    fn name(&self) -> &str;
+
+    /// Section token accepted after the entity identifier.
+    fn section(&self) -> &str;
+
+    fn supports(&self, entity: PluginEntity) -> bool;
+
+    /// Fetches the section payload; `Ok(None)` means the source has no data
+    /// for this record.
+    async fn fetch(
+        &self,
+        entity: PluginEntity,
+        lookup: &PluginLookup,
+    ) -> Result<Option<serde_json::Value>, BioMcpError>;
+}
+
+/// Plugins built into this binary. Private builds register their own types
+/// here, each behind a cargo feature.
+fn compiled_plugins() -> Vec<Arc<dyn SourcePlugin>> {
+    Vec::new()
+}
+
+/// A plugin file in the plugin directory.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct HttpPluginToml {
+    name: String,
+    section: String,
+    /// URL template for `get gene`, e.g. `https://vdb.example.org/genes/{symbol}`.
+    gene_url: Option<String>,
+    /// URL template for `get variant`, e.g. `https://vdb.example.org/variants/{id}`.
+    variant_url: Option<String>,
+    /// Variable holding a token sent as `Authorization: Bearer <token>`.
+    token_env: Option<String>,
+    /// Header that carries the raw token instead of `Authorization`.
+    token_header: Option<String>,
+    /// Dotted path to the payload inside the response, e.g. `data.summary`.
+    result_path: Option<String>,
+}
+
+/// Declarative plugin backed by one JSON HTTP endpoint per entity.
+#[derive(Debug, Clone)]
+pub(crate) struct HttpPlugin {
+    spec: HttpPluginToml,
+}
+
+fn is_valid_section_name(section: &str) -> bool {
+    !section.is_empty()
+        && section.len() <= 40
+        && section != "all"
+        && section.starts_with(|c: char| c.is_ascii_lowercase())
+        && section
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Placeholder names in `template`, or an error for an unclosed brace.
+fn template_placeholders(template: &str) -> Result<Vec<&str>, String> {
+    let mut out = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let after = &rest[start + 1..];
+        let end = after
+            .find('}')
+            .ok_or_else(|| format!("unclosed placeholder in {template}"))?;
+        out.push(&after[..end]);
+        rest = &after[end + 1..];
+    }
+    Ok(out)
+}
+
+fn encode_component(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+impl HttpPlugin {
+    fn parse(content: &str) -> Result<Self, String> {
+        let mut spec = toml::from_str::<HttpPluginToml>(content).map_err(|err| err.to_string())?;
+        spec.name = spec.name.trim().to_string();
+        spec.section = spec.section.trim().to_ascii_lowercase();
+        if spec.name.is_empty() {
+            return Err("name must not be empty".into());
+        }
+        if !is_valid_section_name(&spec.section) {
+            return Err(format!(
+                "section \"{}\" must be lowercase letters, digits, and hyphens, and not \"all\"",
+                spec.section
+            ));
+        }
+        if spec.gene_url.is_none() && spec.variant_url.is_none() {
+            return Err("set gene_url, variant_url, or both".into());
+        }
+        for (entity, template) in [
+            (PluginEntity::Gene, spec.gene_url.as_deref()),
+            (PluginEntity::Variant, spec.variant_url.as_deref()),
+        ] {
+            let Some(template) = template else {
+                continue;
+            };
+            if !template.starts_with("https://") && !template.starts_with("http://") {
+                return Err(format!("{template} must be an http(s) URL"));
+            }
+            for name in template_placeholders(template)? {
+                if !entity.placeholders().contains(&name) {
+                    return Err(format!(
+                        "unknown placeholder {{{name}}} in {template}. Available: {}",
+                        entity.placeholders().join(", ")
+                    ));
+                }
+            }
+        }
+        Ok(Self { spec })
+    }
+
+    fn url_template(&self, entity: PluginEntity) -> Option<&str> {
+        match entity {
+            PluginEntity::Gene => self.spec.gene_url.as_deref(),
+            PluginEntity::Variant => self.spec.variant_url.as_deref(),
+        }
+    }
+
+    /// Request URL for `lookup`, or `None` when an identifier the template
+    /// needs is missing from this record.
+    fn request_url(&self, entity: PluginEntity, lookup: &PluginLookup) -> Option<String> {
+        let template = self.url_template(entity)?;
+        let mut url = template.to_string();
+        for name in template_placeholders(template).ok()? {
+            let value = lookup.get(name)?;
+            url = url.replace(&format!("{{{name}}}"), &encode_component(value));
+        }
+        Some(url)
+    }
+
+    fn select_result(&self, value: serde_json::Value) -> Option<serde_json::Value> {
+        let Some(path) = self.spec.result_path.as_deref() else {
+            return Some(value);
+        };
+        let mut current = value;
+        for key in path.split('.').filter(|key| !key.is_empty()) {
+            current = match current {
+                serde_json::Value::Object(mut map) => map.remove(key)?,
+                _ => return None,
+            };
+        }
+        Some(current)
+    }
+}
+
+#[async_trait::async_trait]
+impl SourcePlugin for HttpPlugin {
+    fn name(&self) -> &str {
+        &self.spec.name
+    }
+
+    fn section(&self) -> &str {
+        &self.spec.section
+    }
+
+    fn supports(&self, entity: PluginEntity) -> bool {
+        self.url_template(entity).is_some()
+    }
+
+    async fn fetch(
+        &self,
+        entity: PluginEntity,
+        lookup: &PluginLookup,
+    ) -> Result<Option<serde_json::Value>, BioMcpError> {
+        let Some(url) = self.request_url(entity, lookup) else {
+            return Ok(None);
+        };
+        let token = match self.spec.token_env.as_deref() {
+            Some(env_var) => Some(crate::sources::tenant::api_key(env_var).ok_or_else(|| {
+                BioMcpError::ApiKeyRequired {
+                    api: self.spec.name.clone(),
+                    env_var: env_var.to_string(),
+                    docs_url: "https://biomcp.org/how-to/add-source-plugins/".to_string(),
+                }
+            })?),
+            None => None,
+        };
+
+        let client = crate::sources::shared_client()?;
+        let mut req = client.get(&url);
+        if let Some(token) = token.as_deref() {
+            req = match self.spec.token_header.as_deref() {
+                Some(header) => req.header(header, token),
+                None => req.bearer_auth(token),
+            };
+        }
+        let resp = crate::sources::apply_cache_mode_with_auth(req, token.is_some())
+            .send()
+            .await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, &self.spec.name).await?;
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: self.spec.name.clone(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+        crate::sources::ensure_json_content_type(&self.spec.name, content_type.as_ref(), &bytes)?;
+        let value: serde_json::Value =
+            serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+                api: self.spec.name.clone(),
+                source,
+            })?;
+        Ok(self.select_result(value).filter(|value| !value.is_null()))
+    }
+}
+
+fn plugin_dir() -> Option<PathBuf> {
+    match std::env::var(PLUGIN_DIR_ENV)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+    {
+        Some(path) => Some(PathBuf::from(path)),
+        None => crate::config::config_dir().map(|dir| dir.join("plugins")),
+    }
+}
+
+/// Loads every valid `*.toml` plugin in `dir`, in file-name order. A file that
+/// fails to load, or reuses a section already taken by an earlier plugin, is
+/// skipped with a warning.
+fn load_plugin_dir(dir: &Path, taken: &mut Vec<String>) -> Vec<Arc<dyn SourcePlugin>> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(PLUGIN_SUFFIX))
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut out: Vec<Arc<dyn SourcePlugin>> = Vec::new();
+    for path in paths {
+        let plugin = match std::fs::read_to_string(&path) {
+            Ok(content) => HttpPlugin::parse(&content),
+            Err(err) => Err(err.to_string()),
+        };
+        let plugin = match plugin {
+            Ok(plugin) => plugin,
+            Err(err) => {
+                warn!(plugin = %path.display(), "ignoring source plugin: {err}");
+                continue;
+            }
+        };
+        if taken.iter().any(|section| section == plugin.section()) {
+            warn!(
+                plugin = %path.display(),
+                "ignoring source plugin: section \"{}\" is already registered",
+                plugin.section()
+            );
+            continue;
+        }
+        taken.push(plugin.section().to_string());
+        out.push(Arc::new(plugin));
+    }
+    out
+}
+
+/// All plugins for this process: compiled-in first, then the plugin
+/// directory, read once on first use.
+pub(crate) fn registered() -> &'static [Arc<dyn SourcePlugin>] {
+    static PLUGINS: OnceLock<Vec<Arc<dyn SourcePlugin>>> = OnceLock::new();
+    PLUGINS.get_or_init(|| {
+        let mut plugins = compiled_plugins();
+        let mut taken = plugins
+            .iter()
+            .map(|plugin| plugin.section().to_string())
+            .collect::<Vec<_>>();
+        if let Some(dir) = plugin_dir() {
+            plugins.extend(load_plugin_dir(&dir, &mut taken));
+        }
+        plugins
+    })
+}
+
+/// Separates plugin section tokens from built-in ones.
+///
+/// Returns the plugins the caller asked for and the remaining tokens, which
+/// go to the entity's own section parser unchanged. Tokens listed in
+/// `builtin` are never claimed by a plugin.
+pub(crate) fn split_sections<'a>(
+    plugins: &'a [Arc<dyn SourcePlugin>],
+    entity: PluginEntity,
+    builtin: &[&str],
+    sections: &[String],
+) -> (Vec<&'a Arc<dyn SourcePlugin>>, Vec<String>) {
+    let mut requested: Vec<&'a Arc<dyn SourcePlugin>> = Vec::new();
+    let mut rest = Vec::with_capacity(sections.len());
+    for raw in sections {
+        let token = raw.trim().to_ascii_lowercase();
+        let plugin = (!builtin.contains(&token.as_str()))
+            .then(|| {
+                plugins
+                    .iter()
+                    .find(|plugin| plugin.supports(entity) && plugin.section() == token)
+            })
+            .flatten();
+        match plugin {
+            Some(plugin) => {
+                if !requested.iter().any(|seen| Arc::ptr_eq(seen, plugin)) {
+                    requested.push(plugin);
+                }
+            }
+            None => rest.push(raw.clone()),
+        }
+    }
+    (requested, rest)
+}
+
+/// Runs the requested plugins concurrently. A failing plugin leaves its
+/// section out and records a section error under the plugin's section name.
+pub(crate) async fn fetch_sections(
+    plugins: &[&Arc<dyn SourcePlugin>],
+    entity: PluginEntity,
+    lookup: &PluginLookup,
+) -> Vec<PluginSection> {
+    let results = futures::future::join_all(
+        plugins
+            .iter()
+            .map(|plugin| async move { (*plugin, plugin.fetch(entity, lookup).await) }),
+    )
+    .await;
+
+    let mut out = Vec::new();
+    for (plugin, result) in results {
+        match result {
+            Ok(Some(data)) => out.push(PluginSection {
+                section: plugin.section().to_string(),
+                source: plugin.name().to_string(),
+                data,
+            }),
+            Ok(None) => {}
+            Err(err) => {
+                warn!(plugin = plugin.name(), "source plugin failed: {err}");
+                section_errors::record(plugin.section(), plugin.name(), &err);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn plugin(content: &str) -> Arc<dyn SourcePlugin> {
+        Arc::new(HttpPlugin::parse(content).expect("plugin should parse"))
+    }
+
+    #[test]
+    fn parse_rejects_bad_sections_placeholders_and_missing_urls() {
+        let err = HttpPlugin::parse(
+            "name = \"Lab DB\"\nsection = \"all\"\ngene_url = \"https://x/{symbol}\"\n",
+        )
+        .unwrap_err();
+        assert!(err.contains("section"));
+
+        let err = HttpPlugin::parse("name = \"Lab DB\"\nsection = \"labdb\"\n").unwrap_err();
+        assert!(err.contains("gene_url"));
+
+        let err = HttpPlugin::parse(
+            "name = \"Lab DB\"\nsection = \"labdb\"\nvariant_url = \"https://x/{symbol}\"\n",
+        )
+        .unwrap_err();
+        assert!(err.contains("unknown placeholder {symbol}"));
+
+        let err = HttpPlugin::parse(
+            "name = \"Lab DB\"\nsection = \"labdb\"\ngene_url = \"https://x/{symbol}\"\nheaders = 1\n",
+        )
+        .unwrap_err();
+        assert!(err.contains("unknown field"));
+    }
+
+    #[test]
+    fn request_url_encodes_values_and_needs_every_placeholder() {
+        let plugin = HttpPlugin::parse(
+            "name = \"Lab DB\"\nsection = \"labdb\"\nvariant_url = \"https://vdb.example.org/v/{gene}/{hgvs_p}\"\n",
+        )
+        .unwrap();
+        let lookup = PluginLookup::default()
+            .with("gene", Some("BRAF"))
+            .with("hgvs_p", Some("p.V600E"));
+        assert_eq!(
+            plugin
+                .request_url(PluginEntity::Variant, &lookup)
+                .as_deref(),
+            Some("https://vdb.example.org/v/BRAF/p.V600E")
+        );
+        let lookup = PluginLookup::default()
+            .with("gene", Some("BRAF"))
+            .with("hgvs_p", Some("p.V600E fs"));
+        assert_eq!(
+            plugin
+                .request_url(PluginEntity::Variant, &lookup)
+                .as_deref(),
+            Some("https://vdb.example.org/v/BRAF/p.V600E%20fs")
+        );
+        let lookup = PluginLookup::default().with("gene", Some("BRAF"));
+        assert!(plugin.request_url(PluginEntity::Variant, &lookup).is_none());
+        assert!(plugin.request_url(PluginEntity::Gene, &lookup).is_none());
+    }
+
+    #[test]
+    fn split_sections_leaves_builtins_and_unknown_tokens_for_the_entity() {
+        let plugins = vec![plugin(
+            "name = \"Lab DB\"\nsection = \"labdb\"\ngene_url = \"https://x/{symbol}\"\n",
+        )];
+        let sections = [
+            "civic".to_string(),
+            "LabDB".into(),
+            "labdb".into(),
+            "bogus".into(),
+        ];
+        let (requested, rest) =
+            split_sections(&plugins, PluginEntity::Gene, &["civic", "all"], &sections);
+        assert_eq!(requested.len(), 1);
+        assert_eq!(rest, ["civic", "bogus"]);
+
+        let (requested, rest) =
+            split_sections(&plugins, PluginEntity::Variant, &["civic"], &sections);
+        assert!(requested.is_empty());
+        assert_eq!(rest.len(), 4);
+    }
+
+    #[test]
+    fn load_plugin_dir_skips_invalid_files_and_duplicate_sections() {
+        let dir = std::env::temp_dir().join(format!("biomcp-plugins-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("a.toml"),
+            "name = \"Lab DB\"\nsection = \"labdb\"\ngene_url = \"https://x/{symbol}\"\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("b.toml"), "name = \"Broken\"\n").unwrap();
+        std::fs::write(
+            dir.join("c.toml"),
+            "name = \"Other\"\nsection = \"labdb\"\ngene_url = \"https://y/{symbol}\"\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("notes.md"), "not a plugin").unwrap();
+
+        let mut taken = Vec::new();
+        let plugins = load_plugin_dir(&dir, &mut taken);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(plugins.len(), 1);
+        assert_eq!(plugins[0].name(), "Lab DB");
+        assert_eq!(taken, ["labdb"]);
+    }
+
+    #[tokio::test]
+    async fn http_plugin_fetches_selects_result_path_and_records_failures() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/genes/BRAF"))
+            .and(header("x-lab-key", "secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"summary": {"internal_cases": 42, "classification": "oncogene"}}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/genes/TP53"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("down"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/genes/NOPE"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let lab = plugin(&format!(
+            "name = \"Lab DB\"\nsection = \"labdb\"\ngene_url = \"{}/genes/{{symbol}}\"\ntoken_env = \"BIOMCP_TEST_LAB_KEY\"\ntoken_header = \"x-lab-key\"\nresult_path = \"data.summary\"\n",
+            server.uri()
+        ));
+        let requested = vec![&lab];
+
+        let tenant = Arc::new(crate::sources::tenant::TenantScope::new(
+            "lab",
+            BTreeMap::from([("BIOMCP_TEST_LAB_KEY".to_string(), "secret".to_string())]),
+        ));
+        let run = |symbol: &'static str,
+                   tenant: Option<Arc<crate::sources::tenant::TenantScope>>| {
+            let requested = requested.clone();
+            async move {
+                let lookup = PluginLookup::default().with("symbol", Some(symbol));
+                let fut = section_errors::collect(async {
+                    fetch_sections(&requested, PluginEntity::Gene, &lookup).await
+                });
+                match tenant {
+                    Some(tenant) => crate::sources::tenant::with_tenant(tenant, fut).await,
+                    None => fut.await,
+                }
+            }
+        };
+
+        let (sections, errors) = run("BRAF", Some(tenant.clone())).await;
+        assert!(errors.is_empty());
+        assert_eq!(
+            sections,
+            vec![PluginSection {
+                section: "labdb".into(),
+                source: "Lab DB".into(),
+                data: serde_json::json!({"internal_cases": 42, "classification": "oncogene"}),
+            }]
+        );
+
+        let (sections, errors) = run("NOPE", Some(tenant.clone())).await;
+        assert!(sections.is_empty());
+        assert!(errors.is_empty());
+
+        let (sections, errors) = run("TP53", Some(tenant)).await;
+        assert!(sections.is_empty());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].section, "labdb");
+        assert_eq!(errors[0].source, "Lab DB");
+        assert_eq!(errors[0].error_code, "api_error");
+
+        let (_, errors) = run("BRAF", None).await;
+        assert_eq!(errors[0].error_code, "api_key_required");
+    }
+}
@@ -8,8 +8,8 @@ use serde::Deserialize;
 
 use crate::error::BioMcpError;
 
-const PUBMED_EUTILS_BASE: &str = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils";
-const PUBMED_EUTILS_BASE_ENV: &str = "BIOMCP_PUBMED_BASE";
+pub(crate) const PUBMED_EUTILS_BASE: &str = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils";
+pub(crate) const PUBMED_EUTILS_BASE_ENV: &str = "BIOMCP_PUBMED_BASE";
 const PUBMED_EUTILS_API: &str = "pubmed-eutils";
 
 #[derive(Clone)]
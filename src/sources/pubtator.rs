@@ -5,9 +5,9 @@ use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::error::BioMcpError;
 
-const PUBTATOR_BASE: &str = "https://www.ncbi.nlm.nih.gov/research/pubtator3-api";
+pub(crate) const PUBTATOR_BASE: &str = "https://www.ncbi.nlm.nih.gov/research/pubtator3-api";
 const PUBTATOR_API: &str = "pubtator3";
-const PUBTATOR_BASE_ENV: &str = "BIOMCP_PUBTATOR_BASE";
+pub(crate) const PUBTATOR_BASE_ENV: &str = "BIOMCP_PUBTATOR_BASE";
 
 #[derive(Clone)]
 pub struct PubTatorClient {
@@ -101,6 +101,12 @@ impl RateLimiter {
                 "https://rest.kegg.jp",
                 Duration::from_millis(334),
             ),
+            policy(
+                "variantvalidator",
+                "BIOMCP_VARIANTVALIDATOR_BASE",
+                "https://rest.variantvalidator.org",
+                Duration::from_millis(500),
+            ),
         ];
         Self::new(policies, Duration::from_millis(100))
     }
@@ -416,6 +422,17 @@ mod tests {
         assert_eq!(key, "policy:kegg");
     }
 
+    #[test]
+    fn variantvalidator_urls_resolve_to_variantvalidator_policy() {
+        let limiter = RateLimiter::from_env();
+        let key = limiter
+            .resolve_key_for_str(
+                "https://rest.variantvalidator.org/VariantValidator/variantvalidator/GRCh38/NM_000546.6:c.215C%3EG/mane_select",
+            )
+            .expect("variantvalidator URL should parse");
+        assert_eq!(key, "policy:variantvalidator");
+    }
+
     #[test]
     fn pubmed_eutils_urls_resolve_to_pubmed_policy() {
         let limiter = RateLimiter::from_env();
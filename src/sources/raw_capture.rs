@@ -0,0 +1,257 @@
+//! Unprocessed upstream response bodies for `--raw <source>` runs.
+//!
+//! [`collect`] scopes a capture to one source name. While it runs,
+//! `read_limited_body_with_limit` hands every body it reads to [`record`],
+//! which keeps the ones whose API label matches the name (size-capped) and
+//! notes the labels of everything else. Bodies read outside a scope are not
+//! recorded.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+
+use crate::error::BioMcpError;
+
+tokio::task_local! {
+    static RAW_CAPTURE: Arc<Mutex<RawCapture>>;
+}
+
+const MAX_RAW_RESPONSES: usize = 5;
+const MAX_RAW_BODY_BYTES: usize = 256 * 1024;
+/// Trailing parts of API labels that `--raw` may leave off, so `myvariant`
+/// matches `myvariant.info` and `pubmed` matches `pubmed-eutils`.
+const OPTIONAL_LABEL_SUFFIXES: &[&str] = &["info", "gov", "eutils", "2", "3"];
+const REDACTED_QUERY_KEYS: &[&str] = &["key", "token", "secret", "password"];
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct RawResponse {
+    pub api: String,
+    pub url: String,
+    pub status: u16,
+    pub bytes: usize,
+    /// The body was cut at the size cap and is returned as text.
+    pub truncated: bool,
+    /// Parsed JSON when the body is JSON, otherwise the body text.
+    pub body: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub(crate) struct RawCapture {
+    pub source: String,
+    pub responses: Vec<RawResponse>,
+    /// Matching responses dropped after the first few.
+    #[serde(skip_serializing_if = "is_zero")]
+    pub omitted: usize,
+    /// API labels of the other responses read during the command.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub other_sources: Vec<String>,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+fn normalize_label(value: &str) -> String {
+    value
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect()
+}
+
+/// Whether the `--raw` value names the API label a source reports.
+pub(crate) fn source_matches(wanted: &str, api: &str) -> bool {
+    let wanted = normalize_label(wanted);
+    let api = normalize_label(api);
+    !wanted.is_empty()
+        && api
+            .strip_prefix(&wanted)
+            .is_some_and(|rest| rest.is_empty() || OPTIONAL_LABEL_SUFFIXES.contains(&rest))
+}
+
+/// Runs `fut` while capturing bodies from the API named `source`.
+pub(crate) async fn collect<F>(source: &str, fut: F) -> (F::Output, RawCapture)
+where
+    F: Future,
+{
+    let capture = Arc::new(Mutex::new(RawCapture {
+        source: source.trim().to_string(),
+        ..RawCapture::default()
+    }));
+    let output = RAW_CAPTURE.scope(capture.clone(), fut).await;
+    let capture = capture
+        .lock()
+        .map(|capture| capture.clone())
+        .unwrap_or_default();
+    (output, capture)
+}
+
+pub(crate) fn is_capturing() -> bool {
+    RAW_CAPTURE.try_with(|_| ()).is_ok()
+}
+
+/// Records one response body read under `api`.
+pub(crate) fn record(api: &str, url: &reqwest::Url, status: u16, body: &[u8]) {
+    let _ = RAW_CAPTURE.try_with(|capture| {
+        let Ok(mut capture) = capture.lock() else {
+            return;
+        };
+        if !source_matches(&capture.source, api) {
+            if !capture.other_sources.iter().any(|seen| seen == api) {
+                capture.other_sources.push(api.to_string());
+            }
+            return;
+        }
+        if capture.responses.len() >= MAX_RAW_RESPONSES {
+            capture.omitted += 1;
+            return;
+        }
+        let (body_value, truncated) = body_value(body);
+        capture.responses.push(RawResponse {
+            api: api.to_string(),
+            url: redacted_url(url),
+            status,
+            bytes: body.len(),
+            truncated,
+            body: body_value,
+        });
+    });
+}
+
+fn body_value(body: &[u8]) -> (serde_json::Value, bool) {
+    if body.len() <= MAX_RAW_BODY_BYTES
+        && let Ok(value) = serde_json::from_slice(body)
+    {
+        return (value, false);
+    }
+    let kept = &body[..body.len().min(MAX_RAW_BODY_BYTES)];
+    (
+        serde_json::Value::String(String::from_utf8_lossy(kept).into_owned()),
+        body.len() > MAX_RAW_BODY_BYTES,
+    )
+}
+
+/// Masks API keys and tokens passed as query parameters.
+pub(crate) fn redacted_url(url: &reqwest::Url) -> String {
+    let sensitive = |name: &str| {
+        let name = name.to_ascii_lowercase();
+        REDACTED_QUERY_KEYS.iter().any(|key| name.contains(key))
+    };
+    if !url.query_pairs().any(|(name, _)| sensitive(name.as_ref())) {
+        return url.to_string();
+    }
+    let pairs = url
+        .query_pairs()
+        .map(|(name, value)| {
+            let value = if sensitive(name.as_ref()) {
+                "REDACTED".into()
+            } else {
+                value
+            };
+            (name.into_owned(), value.into_owned())
+        })
+        .collect::<Vec<_>>();
+    let mut redacted = url.clone();
+    redacted.query_pairs_mut().clear().extend_pairs(pairs);
+    redacted.to_string()
+}
+
+impl RawCapture {
+    /// Adds the capture to a JSON document under `_raw`; documents that are
+    /// not objects are wrapped as `{"result": ..., "_raw": ...}`.
+    pub(crate) fn attach(&self, json: &str) -> Result<String, BioMcpError> {
+        let raw = serde_json::to_value(self)?;
+        let document = match serde_json::from_str::<serde_json::Value>(json)? {
+            serde_json::Value::Object(mut object) => {
+                object.insert("_raw".to_string(), raw);
+                serde_json::Value::Object(object)
+            }
+            other => serde_json::json!({"result": other, "_raw": raw}),
+        };
+        Ok(serde_json::to_string_pretty(&document)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(value: &str) -> reqwest::Url {
+        reqwest::Url::parse(value).expect("valid url")
+    }
+
+    #[test]
+    fn source_matches_accepts_short_names_for_api_labels() {
+        assert!(source_matches("myvariant", "myvariant.info"));
+        assert!(source_matches("MyVariant.info", "myvariant.info"));
+        assert!(source_matches("pubmed", "pubmed-eutils"));
+        assert!(source_matches("clinicaltrials", "clinicaltrials.gov"));
+        assert!(source_matches("litvar", "litvar2"));
+        assert!(source_matches("gnomad", "gnomAD"));
+        assert!(!source_matches("my", "myvariant.info"));
+        assert!(!source_matches("clingen", "clingen-allele-registry"));
+        assert!(!source_matches("", "vep"));
+    }
+
+    #[tokio::test]
+    async fn collect_keeps_matching_bodies_and_caps_count_and_size() {
+        let ((), capture) = collect("myvariant", async {
+            let api_url = url("https://myvariant.info/v1/variant/rs1?fields=all");
+            record("myvariant.info", &api_url, 200, br#"{"_id": "rs1"}"#);
+            record("mygene.info", &api_url, 200, b"{}");
+            record("mygene.info", &api_url, 200, b"{}");
+            record("myvariant.info", &api_url, 404, b"not found");
+            let large = vec![b'x'; MAX_RAW_BODY_BYTES + 10];
+            record("myvariant.info", &api_url, 200, &large);
+            for _ in 0..4 {
+                record("myvariant.info", &api_url, 200, b"[]");
+            }
+        })
+        .await;
+
+        assert_eq!(capture.source, "myvariant");
+        assert_eq!(capture.responses.len(), MAX_RAW_RESPONSES);
+        assert_eq!(capture.omitted, 2);
+        assert_eq!(capture.other_sources, vec!["mygene.info"]);
+        assert_eq!(capture.responses[0].body["_id"], "rs1");
+        assert_eq!(capture.responses[1].status, 404);
+        assert_eq!(capture.responses[1].body, "not found");
+        let large = &capture.responses[2];
+        assert!(large.truncated);
+        assert_eq!(large.bytes, MAX_RAW_BODY_BYTES + 10);
+        assert_eq!(large.body.as_str().map(str::len), Some(MAX_RAW_BODY_BYTES));
+        assert!(!is_capturing());
+    }
+
+    #[test]
+    fn redacted_url_masks_keys_and_tokens() {
+        assert_eq!(
+            redacted_url(&url(
+                "https://eutils.ncbi.nlm.nih.gov/efetch.fcgi?db=pubmed&api_key=secret1"
+            )),
+            "https://eutils.ncbi.nlm.nih.gov/efetch.fcgi?db=pubmed&api_key=REDACTED"
+        );
+        let plain = "https://rest.ensembl.org/vep/human/id/rs1?content-type=application/json";
+        assert_eq!(redacted_url(&url(plain)), plain);
+    }
+
+    #[test]
+    fn attach_adds_raw_key_to_objects_and_wraps_other_documents() {
+        let capture = RawCapture {
+            source: "vep".into(),
+            ..RawCapture::default()
+        };
+        let attached: serde_json::Value =
+            serde_json::from_str(&capture.attach(r#"{"id": "rs1"}"#).expect("attach"))
+                .expect("json");
+        assert_eq!(attached["id"], "rs1");
+        assert_eq!(attached["_raw"]["source"], "vep");
+        assert!(attached["_raw"].get("omitted").is_none());
+
+        let wrapped: serde_json::Value =
+            serde_json::from_str(&capture.attach("[1]").expect("attach")).expect("json");
+        assert_eq!(wrapped["result"][0], 1);
+        assert_eq!(wrapped["_raw"]["responses"], serde_json::json!([]));
+    }
+}
@@ -0,0 +1,544 @@
+//! RCSB PDB client for structure entries and UniProt-to-PDB search.
+//!
+//! Entry details come from the RCSB Data API GraphQL endpoint, which returns
+//! the entry, its polymer chains, and bound ligands in one request. UniProt
+//! lookups go through the RCSB Search API and are then hydrated with a batch
+//! `entries` query.
+
+use std::borrow::Cow;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+
+pub(crate) const RCSB_DATA_BASE: &str = "https://data.rcsb.org";
+const RCSB_SEARCH_BASE: &str = "https://search.rcsb.org";
+const RCSB_API: &str = "RCSB PDB";
+pub(crate) const RCSB_DATA_BASE_ENV: &str = "BIOMCP_RCSB_DATA_BASE";
+const RCSB_SEARCH_BASE_ENV: &str = "BIOMCP_RCSB_SEARCH_BASE";
+
+const ENTRY_QUERY: &str = r#"
+query StructureEntry($id: String!) {
+  entry(entry_id: $id) {
+    rcsb_id
+    struct { title }
+    exptl { method }
+    rcsb_entry_info { resolution_combined molecular_weight deposited_atom_count }
+    rcsb_accession_info { deposit_date initial_release_date }
+    rcsb_primary_citation {
+      title
+      rcsb_journal_abbrev
+      year
+      pdbx_database_id_PubMed
+      pdbx_database_id_DOI
+    }
+    polymer_entities {
+      rcsb_polymer_entity { pdbx_description }
+      entity_poly { rcsb_entity_polymer_type rcsb_sample_sequence_length }
+      rcsb_polymer_entity_container_identifiers { entity_id auth_asym_ids uniprot_ids }
+      rcsb_entity_source_organism { ncbi_scientific_name }
+    }
+    nonpolymer_entities {
+      nonpolymer_comp { chem_comp { id name formula } }
+      rcsb_nonpolymer_entity_container_identifiers { entity_id auth_asym_ids }
+    }
+  }
+}
+"#;
+
+const ENTRY_SUMMARIES_QUERY: &str = r#"
+query StructureSummaries($ids: [String!]!) {
+  entries(entry_ids: $ids) {
+    rcsb_id
+    struct { title }
+    exptl { method }
+    rcsb_entry_info { resolution_combined }
+    rcsb_accession_info { initial_release_date }
+  }
+}
+"#;
+
+/// UniProt accessions are indexed per polymer entity under this attribute.
+const UNIPROT_ACCESSION_ATTRIBUTE: &str =
+    "rcsb_polymer_entity_container_identifiers.reference_sequence_identifiers.database_accession";
+
+#[derive(Serialize)]
+struct GraphQlRequest {
+    query: &'static str,
+    variables: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct GraphQlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Deserialize)]
+struct GraphQlError {
+    message: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct EntryData {
+    entry: Option<RcsbEntry>,
+}
+
+#[derive(Deserialize)]
+struct EntriesData {
+    #[serde(default)]
+    entries: Option<Vec<RcsbEntry>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbEntry {
+    pub rcsb_id: String,
+    #[serde(rename = "struct")]
+    pub structure: Option<RcsbStruct>,
+    #[serde(default)]
+    pub exptl: Option<Vec<RcsbExptl>>,
+    pub rcsb_entry_info: Option<RcsbEntryInfo>,
+    pub rcsb_accession_info: Option<RcsbAccessionInfo>,
+    pub rcsb_primary_citation: Option<RcsbCitation>,
+    #[serde(default)]
+    pub polymer_entities: Option<Vec<RcsbPolymerEntity>>,
+    #[serde(default)]
+    pub nonpolymer_entities: Option<Vec<RcsbNonpolymerEntity>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbStruct {
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbExptl {
+    pub method: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbEntryInfo {
+    /// Resolution in Å; absent for NMR and other non-diffraction methods.
+    #[serde(default)]
+    pub resolution_combined: Option<Vec<Option<f64>>>,
+    /// Total deposited molecular weight in kDa.
+    pub molecular_weight: Option<f64>,
+    pub deposited_atom_count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbAccessionInfo {
+    pub deposit_date: Option<String>,
+    pub initial_release_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbCitation {
+    pub title: Option<String>,
+    pub rcsb_journal_abbrev: Option<String>,
+    pub year: Option<i32>,
+    #[serde(rename = "pdbx_database_id_PubMed")]
+    pub pubmed_id: Option<u64>,
+    #[serde(rename = "pdbx_database_id_DOI")]
+    pub doi: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbPolymerEntity {
+    pub rcsb_polymer_entity: Option<RcsbPolymerDescription>,
+    pub entity_poly: Option<RcsbEntityPoly>,
+    pub rcsb_polymer_entity_container_identifiers: Option<RcsbEntityIdentifiers>,
+    #[serde(default)]
+    pub rcsb_entity_source_organism: Option<Vec<RcsbSourceOrganism>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbPolymerDescription {
+    pub pdbx_description: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbEntityPoly {
+    pub rcsb_entity_polymer_type: Option<String>,
+    pub rcsb_sample_sequence_length: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbEntityIdentifiers {
+    pub entity_id: Option<String>,
+    #[serde(default)]
+    pub auth_asym_ids: Option<Vec<String>>,
+    #[serde(default)]
+    pub uniprot_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbSourceOrganism {
+    pub ncbi_scientific_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbNonpolymerEntity {
+    pub nonpolymer_comp: Option<RcsbNonpolymerComp>,
+    pub rcsb_nonpolymer_entity_container_identifiers: Option<RcsbEntityIdentifiers>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbNonpolymerComp {
+    pub chem_comp: Option<RcsbChemComp>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RcsbChemComp {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub formula: Option<String>,
+}
+
+/// One page of PDB IDs from the Search API, best resolution first.
+#[derive(Debug, Clone, Default)]
+pub struct RcsbSearchPage {
+    pub ids: Vec<String>,
+    pub total: usize,
+}
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    #[serde(default)]
+    total_count: usize,
+    #[serde(default)]
+    result_set: Vec<SearchHit>,
+}
+
+#[derive(Deserialize)]
+struct SearchHit {
+    identifier: String,
+}
+
+pub struct RcsbClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    data_base: Cow<'static, str>,
+    search_base: Cow<'static, str>,
+}
+
+impl RcsbClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            data_base: crate::sources::env_base(RCSB_DATA_BASE, RCSB_DATA_BASE_ENV),
+            search_base: crate::sources::env_base(RCSB_SEARCH_BASE, RCSB_SEARCH_BASE_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            data_base: Cow::Owned(base.clone()),
+            search_base: Cow::Owned(base),
+        })
+    }
+
+    fn graphql_url(&self) -> String {
+        format!("{}/graphql", self.data_base.as_ref().trim_end_matches('/'))
+    }
+
+    fn search_url(&self) -> String {
+        format!(
+            "{}/rcsbsearch/v2/query",
+            self.search_base.as_ref().trim_end_matches('/')
+        )
+    }
+
+    /// POSTs `body` and returns the raw response bytes, or `None` for the
+    /// Search API's `204 No Content` empty result.
+    async fn post<B: Serialize>(
+        &self,
+        url: String,
+        body: &B,
+    ) -> Result<Option<Vec<u8>>, BioMcpError> {
+        let req = self.client.post(url).json(body);
+        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, RCSB_API).await?;
+
+        if status == reqwest::StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: RCSB_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(RCSB_API, content_type.as_ref(), &bytes)?;
+        Ok(Some(bytes))
+    }
+
+    async fn graphql<T: DeserializeOwned>(
+        &self,
+        body: &GraphQlRequest,
+    ) -> Result<(Option<T>, Vec<String>), BioMcpError> {
+        let bytes = self
+            .post(self.graphql_url(), body)
+            .await?
+            .unwrap_or_default();
+        let resp: GraphQlResponse<T> =
+            serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+                api: RCSB_API.to_string(),
+                source,
+            })?;
+        let messages = resp
+            .errors
+            .iter()
+            .filter_map(|error| error.message.as_deref())
+            .map(str::trim)
+            .filter(|message| !message.is_empty())
+            .map(str::to_string)
+            .collect();
+        Ok((resp.data, messages))
+    }
+
+    /// Fetches one entry with its polymer chains and ligands; `None` when the
+    /// PDB ID is unknown.
+    pub async fn entry(&self, pdb_id: &str) -> Result<Option<RcsbEntry>, BioMcpError> {
+        let body = GraphQlRequest {
+            query: ENTRY_QUERY,
+            variables: serde_json::json!({ "id": pdb_id }),
+        };
+        let (data, errors) = self.graphql::<EntryData>(&body).await?;
+        if let Some(entry) = data.and_then(|data| data.entry) {
+            return Ok(Some(entry));
+        }
+        if errors.is_empty()
+            || errors
+                .iter()
+                .all(|message| message.to_ascii_lowercase().contains("not found"))
+        {
+            return Ok(None);
+        }
+        Err(BioMcpError::Api {
+            api: RCSB_API.to_string(),
+            message: errors.join("; "),
+        })
+    }
+
+    /// Title, method, resolution, and release date for each PDB ID, in
+    /// request order.
+    pub async fn entry_summaries(&self, pdb_ids: &[String]) -> Result<Vec<RcsbEntry>, BioMcpError> {
+        if pdb_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let body = GraphQlRequest {
+            query: ENTRY_SUMMARIES_QUERY,
+            variables: serde_json::json!({ "ids": pdb_ids }),
+        };
+        let (data, errors) = self.graphql::<EntriesData>(&body).await?;
+        let Some(mut entries) = data.and_then(|data| data.entries) else {
+            return Err(BioMcpError::Api {
+                api: RCSB_API.to_string(),
+                message: if errors.is_empty() {
+                    "GraphQL request returned no entries".to_string()
+                } else {
+                    errors.join("; ")
+                },
+            });
+        };
+        entries.sort_by_key(|entry| {
+            pdb_ids
+                .iter()
+                .position(|id| id.eq_ignore_ascii_case(&entry.rcsb_id))
+                .unwrap_or(usize::MAX)
+        });
+        Ok(entries)
+    }
+
+    /// PDB entries containing a chain mapped to the UniProt accession.
+    pub async fn search_by_uniprot(
+        &self,
+        accession: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<RcsbSearchPage, BioMcpError> {
+        let body = serde_json::json!({
+            "query": {
+                "type": "terminal",
+                "service": "text",
+                "parameters": {
+                    "attribute": UNIPROT_ACCESSION_ATTRIBUTE,
+                    "operator": "exact_match",
+                    "value": accession,
+                }
+            },
+            "return_type": "entry",
+            "request_options": {
+                "paginate": { "start": offset, "rows": limit },
+                "sort": [
+                    { "sort_by": "rcsb_entry_info.resolution_combined", "direction": "asc" }
+                ]
+            }
+        });
+        let Some(bytes) = self.post(self.search_url(), &body).await? else {
+            return Ok(RcsbSearchPage::default());
+        };
+        let resp: SearchResponse =
+            serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+                api: RCSB_API.to_string(),
+                source,
+            })?;
+        Ok(RcsbSearchPage {
+            ids: resp
+                .result_set
+                .into_iter()
+                .map(|hit| hit.identifier)
+                .collect(),
+            total: resp.total_count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn entry_parses_chains_ligands_and_citation() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("StructureEntry"))
+            .and(body_string_contains("\"id\":\"6VSB\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"entry": {
+                    "rcsb_id": "6VSB",
+                    "struct": {"title": "Prefusion 2019-nCoV spike glycoprotein with a single receptor-binding domain up"},
+                    "exptl": [{"method": "ELECTRON MICROSCOPY"}],
+                    "rcsb_entry_info": {"resolution_combined": [3.46], "molecular_weight": 438.53},
+                    "rcsb_accession_info": {"deposit_date": "2020-02-10T00:00:00Z", "initial_release_date": "2020-02-26T00:00:00Z"},
+                    "rcsb_primary_citation": {"title": "Cryo-EM structure of the 2019-nCoV spike in the prefusion conformation.", "pdbx_database_id_PubMed": 32075877, "pdbx_database_id_DOI": "10.1126/science.abb2507"},
+                    "polymer_entities": [{
+                        "rcsb_polymer_entity": {"pdbx_description": "Spike glycoprotein"},
+                        "entity_poly": {"rcsb_entity_polymer_type": "Protein", "rcsb_sample_sequence_length": 1288},
+                        "rcsb_polymer_entity_container_identifiers": {"entity_id": "1", "auth_asym_ids": ["A", "B", "C"], "uniprot_ids": ["P0DTC2"]},
+                        "rcsb_entity_source_organism": [{"ncbi_scientific_name": "Severe acute respiratory syndrome coronavirus 2"}]
+                    }],
+                    "nonpolymer_entities": [{
+                        "nonpolymer_comp": {"chem_comp": {"id": "NAG", "name": "2-acetamido-2-deoxy-beta-D-glucopyranose", "formula": "C8 H15 N O6"}},
+                        "rcsb_nonpolymer_entity_container_identifiers": {"entity_id": "2", "auth_asym_ids": ["D"]}
+                    }]
+                }}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RcsbClient::new_for_test(server.uri()).expect("client");
+        let entry = client.entry("6VSB").await.expect("entry").expect("found");
+        assert_eq!(entry.rcsb_id, "6VSB");
+        let info = entry.rcsb_entry_info.expect("entry info");
+        assert_eq!(info.resolution_combined, Some(vec![Some(3.46)]));
+        let polymers = entry.polymer_entities.expect("polymers");
+        let ids = polymers[0]
+            .rcsb_polymer_entity_container_identifiers
+            .as_ref()
+            .expect("identifiers");
+        assert_eq!(
+            ids.uniprot_ids.as_deref(),
+            Some(&["P0DTC2".to_string()][..])
+        );
+        let citation = entry.rcsb_primary_citation.expect("citation");
+        assert_eq!(citation.pubmed_id, Some(32_075_877));
+        assert_eq!(citation.doi.as_deref(), Some("10.1126/science.abb2507"));
+    }
+
+    #[tokio::test]
+    async fn entry_returns_none_for_unknown_id() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"entry": null}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = RcsbClient::new_for_test(server.uri()).expect("client");
+        assert!(client.entry("0ZZZ").await.expect("lookup").is_none());
+    }
+
+    #[tokio::test]
+    async fn search_by_uniprot_pages_by_resolution_and_handles_no_content() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rcsbsearch/v2/query"))
+            .and(body_string_contains("\"value\":\"P15056\""))
+            .and(body_string_contains("\"start\":5"))
+            .and(body_string_contains("rcsb_entry_info.resolution_combined"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "result_type": "entry",
+                "total_count": 212,
+                "result_set": [
+                    {"identifier": "6UUO", "score": 1.0},
+                    {"identifier": "4MNE", "score": 1.0}
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rcsbsearch/v2/query"))
+            .and(body_string_contains("\"value\":\"Q00000\""))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+
+        let client = RcsbClient::new_for_test(server.uri()).expect("client");
+        let page = client
+            .search_by_uniprot("P15056", 2, 5)
+            .await
+            .expect("search");
+        assert_eq!(page.ids, vec!["6UUO".to_string(), "4MNE".to_string()]);
+        assert_eq!(page.total, 212);
+
+        let empty = client
+            .search_by_uniprot("Q00000", 10, 0)
+            .await
+            .expect("empty search");
+        assert!(empty.ids.is_empty());
+        assert_eq!(empty.total, 0);
+    }
+
+    #[tokio::test]
+    async fn entry_summaries_keep_request_order() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("StructureSummaries"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"entries": [
+                    {"rcsb_id": "4MNE", "exptl": [{"method": "X-RAY DIFFRACTION"}]},
+                    {"rcsb_id": "6UUO", "exptl": [{"method": "X-RAY DIFFRACTION"}]}
+                ]}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = RcsbClient::new_for_test(server.uri()).expect("client");
+        let entries = client
+            .entry_summaries(&["6UUO".to_string(), "4MNE".to_string()])
+            .await
+            .expect("summaries");
+        let ids = entries
+            .iter()
+            .map(|entry| entry.rcsb_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["6UUO", "4MNE"]);
+    }
+}
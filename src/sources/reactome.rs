@@ -6,9 +6,9 @@ use serde::de::DeserializeOwned;
 use crate::error::BioMcpError;
 use crate::utils::serde::StringOrVec;
 
-const REACTOME_BASE: &str = "https://reactome.org/ContentService";
+pub(crate) const REACTOME_BASE: &str = "https://reactome.org/ContentService";
 const REACTOME_API: &str = "reactome";
-const REACTOME_BASE_ENV: &str = "BIOMCP_REACTOME_BASE";
+pub(crate) const REACTOME_BASE_ENV: &str = "BIOMCP_REACTOME_BASE";
 
 pub struct ReactomeClient {
     client: reqwest_middleware::ClientWithMiddleware,
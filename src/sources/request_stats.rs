@@ -0,0 +1,460 @@
+//! Per-task upstream request statistics for `--verbose` and `--provenance` runs.
+//!
+//! Two middleware layers cooperate: [`NetworkStatsMiddleware`] sits below the HTTP
+//! cache and tags requests that reached the network, and [`RequestStatsMiddleware`]
+//! sits above it and records one entry per upstream call into the collector scoped
+//! with [`collect`]. Calls made outside a collector scope are not buffered or recorded.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use http::Extensions;
+use reqwest::{ResponseBuilderExt, StatusCode};
+use reqwest_middleware::{Middleware, Next};
+use serde::Serialize;
+
+use crate::error::BioMcpError;
+
+tokio::task_local! {
+    static REQUEST_STATS: Arc<Mutex<RequestStats>>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CacheOutcome {
+    /// Served from the local HTTP cache without contacting the upstream.
+    Hit,
+    /// Conditional request answered with `304 Not Modified`.
+    Revalidated,
+    /// Full response body fetched from the upstream.
+    Fetched,
+    /// The call failed before a response was available.
+    Failed,
+}
+
+impl CacheOutcome {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Hit => "hit",
+            Self::Revalidated => "revalidated",
+            Self::Fetched => "fetched",
+            Self::Failed => "failed",
+        }
+    }
+
+    /// Cache column of a `_provenance` entry; full fetches are cache misses.
+    fn provenance_label(self) -> &'static str {
+        match self {
+            Self::Fetched => "miss",
+            other => other.label(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct RequestRecord {
+    pub method: String,
+    /// Request URL with API keys and tokens masked.
+    pub url: String,
+    pub status: Option<u16>,
+    pub outcome: CacheOutcome,
+    pub bytes: u64,
+    /// Time from sending the request to receiving the response headers,
+    /// including cache lookup, retries, and rate limiting.
+    pub elapsed_ms: u64,
+}
+
+/// One upstream call as listed under `_provenance` in JSON output.
+#[derive(Debug, Serialize)]
+struct ProvenanceEntry<'a> {
+    method: &'a str,
+    url: &'a str,
+    status: Option<u16>,
+    cache: &'static str,
+    elapsed_ms: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RequestStats {
+    pub records: Vec<RequestRecord>,
+}
+
+impl RequestStats {
+    pub(crate) fn count(&self, outcome: CacheOutcome) -> usize {
+        self.records
+            .iter()
+            .filter(|record| record.outcome == outcome)
+            .count()
+    }
+
+    pub(crate) fn bytes_transferred(&self) -> u64 {
+        self.records.iter().map(|record| record.bytes).sum()
+    }
+
+    /// Renders a summary line plus one line per upstream call.
+    pub(crate) fn render(&self) -> String {
+        let mut lines = vec![format!(
+            "Request stats: requests={} cache_hits={} revalidated={} fetched={} failed={} bytes_transferred={}",
+            self.records.len(),
+            self.count(CacheOutcome::Hit),
+            self.count(CacheOutcome::Revalidated),
+            self.count(CacheOutcome::Fetched),
+            self.count(CacheOutcome::Failed),
+            self.bytes_transferred(),
+        )];
+        for record in &self.records {
+            let status = record
+                .status
+                .map(|status| status.to_string())
+                .unwrap_or_else(|| "-".to_string());
+            lines.push(format!(
+                "  {} {} {} status={} bytes={} elapsed_ms={}",
+                record.outcome.label(),
+                record.method,
+                record.url,
+                status,
+                record.bytes,
+                record.elapsed_ms,
+            ));
+        }
+        lines.join("\n")
+    }
+
+    /// Adds the recorded calls to a JSON document under `_provenance`; documents
+    /// that are not objects are wrapped as `{"result": ..., "_provenance": ...}`.
+    /// Output that is not JSON, such as `biomcp cache path`, is returned unchanged.
+    pub(crate) fn attach_provenance(&self, json: &str) -> Result<String, BioMcpError> {
+        let Ok(document) = serde_json::from_str::<serde_json::Value>(json) else {
+            return Ok(json.to_string());
+        };
+        let provenance = self
+            .records
+            .iter()
+            .map(|record| ProvenanceEntry {
+                method: &record.method,
+                url: &record.url,
+                status: record.status,
+                cache: record.outcome.provenance_label(),
+                elapsed_ms: record.elapsed_ms,
+            })
+            .collect::<Vec<_>>();
+        let provenance = serde_json::to_value(provenance)?;
+        let document = match document {
+            serde_json::Value::Object(mut object) => {
+                object.insert("_provenance".to_string(), provenance);
+                serde_json::Value::Object(object)
+            }
+            other => serde_json::json!({"result": other, "_provenance": provenance}),
+        };
+        Ok(serde_json::to_string_pretty(&document)?)
+    }
+}
+
+/// Runs `fut` with a fresh stats collector and returns its output with the recorded calls.
+///
+/// Calls recorded by a nested collector are also passed on to the enclosing one, so
+/// tenant metering still sees `--verbose` runs.
+pub(crate) async fn collect<F>(fut: F) -> (F::Output, RequestStats)
+where
+    F: Future,
+{
+    let stats = Arc::new(Mutex::new(RequestStats::default()));
+    let output = REQUEST_STATS.scope(stats.clone(), fut).await;
+    let stats = stats.lock().map(|stats| stats.clone()).unwrap_or_default();
+    for entry in &stats.records {
+        record(entry.clone());
+    }
+    (output, stats)
+}
+
+fn is_collecting() -> bool {
+    REQUEST_STATS.try_with(|_| ()).is_ok()
+}
+
+fn record(entry: RequestRecord) {
+    let _ = REQUEST_STATS.try_with(|stats| {
+        if let Ok(mut stats) = stats.lock() {
+            stats.records.push(entry);
+        }
+    });
+}
+
+/// Marker left in request extensions when a call reached the upstream.
+#[derive(Debug, Clone, Copy)]
+struct NetworkExchange {
+    not_modified: bool,
+    bytes: u64,
+}
+
+/// Outer layer: classifies each call as hit, revalidation, or full fetch.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RequestStatsMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for RequestStatsMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        if !is_collecting() {
+            return next.run(req, extensions).await;
+        }
+
+        let method = req.method().to_string();
+        let url = crate::sources::raw_capture::redacted_url(req.url());
+        let started = Instant::now();
+        let result = next.run(req, extensions).await;
+        let elapsed_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        let exchange = extensions.get::<NetworkExchange>().copied();
+        let outcome = match exchange {
+            None if result.is_err() => CacheOutcome::Failed,
+            None => CacheOutcome::Hit,
+            Some(exchange) if exchange.not_modified => CacheOutcome::Revalidated,
+            Some(_) => CacheOutcome::Fetched,
+        };
+        record(RequestRecord {
+            method,
+            url,
+            status: result
+                .as_ref()
+                .ok()
+                .map(|response| response.status().as_u16()),
+            outcome,
+            bytes: exchange.map(|exchange| exchange.bytes).unwrap_or(0),
+            elapsed_ms,
+        });
+        result
+    }
+}
+
+/// Inner layer: runs below the cache, so it only sees requests that hit the network.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct NetworkStatsMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for NetworkStatsMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        if !is_collecting() {
+            return next.run(req, extensions).await;
+        }
+
+        let response = next.run(req, extensions).await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            extensions.insert(NetworkExchange {
+                not_modified: true,
+                bytes: 0,
+            });
+            return Ok(response);
+        }
+
+        // Buffer the body so decompressed and chunked responses report their real size.
+        let status = response.status();
+        let version = response.version();
+        let url = response.url().clone();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+        extensions.insert(NetworkExchange {
+            not_modified: false,
+            bytes: body.len() as u64,
+        });
+
+        let mut rebuilt = http::Response::builder()
+            .status(status)
+            .version(version)
+            .url(url)
+            .body(body)
+            .map_err(reqwest_middleware::Error::middleware)?;
+        *rebuilt.headers_mut() = headers;
+        Ok(reqwest::Response::from(rebuilt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn stats_client() -> reqwest_middleware::ClientWithMiddleware {
+        reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+            .with(RequestStatsMiddleware)
+            .with(NetworkStatsMiddleware)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn collect_classifies_fetches_and_revalidations() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fresh"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("0123456789"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/etag"))
+            .respond_with(ResponseTemplate::new(304))
+            .mount(&server)
+            .await;
+
+        let client = stats_client();
+        let (body, stats) = collect(async {
+            let body = client
+                .get(format!("{}/fresh", server.uri()))
+                .send()
+                .await
+                .expect("fresh response")
+                .text()
+                .await
+                .expect("fresh body");
+            client
+                .get(format!("{}/etag", server.uri()))
+                .send()
+                .await
+                .expect("304 response");
+            body
+        })
+        .await;
+
+        assert_eq!(body, "0123456789");
+        assert_eq!(stats.records.len(), 2);
+        assert_eq!(stats.records[0].outcome, CacheOutcome::Fetched);
+        assert_eq!(stats.records[0].bytes, 10);
+        assert_eq!(stats.records[1].outcome, CacheOutcome::Revalidated);
+        assert_eq!(stats.records[1].status, Some(304));
+        assert_eq!(stats.bytes_transferred(), 10);
+        assert!(stats.render().starts_with(
+            "Request stats: requests=2 cache_hits=0 revalidated=1 fetched=1 failed=0 bytes_transferred=10"
+        ));
+    }
+
+    #[tokio::test]
+    async fn requests_without_network_marker_count_as_cache_hits() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cached"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("cached"))
+            .mount(&server)
+            .await;
+
+        // Without the inner layer nothing marks the exchange, mirroring a cache hit.
+        let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+            .with(RequestStatsMiddleware)
+            .build();
+        let (_, stats) = collect(async {
+            client
+                .get(format!("{}/cached", server.uri()))
+                .send()
+                .await
+                .expect("response");
+        })
+        .await;
+
+        assert_eq!(stats.records.len(), 1);
+        assert_eq!(stats.records[0].outcome, CacheOutcome::Hit);
+        assert_eq!(stats.bytes_transferred(), 0);
+        assert!(stats.render().contains("  hit GET "));
+    }
+
+    #[tokio::test]
+    async fn nested_collectors_pass_records_to_the_enclosing_collector() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/nested"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("nested"))
+            .mount(&server)
+            .await;
+
+        let client = stats_client();
+        let (inner, outer) = collect(async {
+            let (_, inner) = collect(async {
+                client
+                    .get(format!("{}/nested", server.uri()))
+                    .send()
+                    .await
+                    .expect("response");
+            })
+            .await;
+            inner
+        })
+        .await;
+
+        assert_eq!(inner.records.len(), 1);
+        assert_eq!(outer.records.len(), 1);
+        assert_eq!(outer.bytes_transferred(), 6);
+    }
+
+    #[tokio::test]
+    async fn attach_provenance_lists_masked_urls_with_cache_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/keyed"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+            .mount(&server)
+            .await;
+
+        let client = stats_client();
+        let (_, stats) = collect(async {
+            client
+                .get(format!("{}/keyed?id=7157&api_key=secret1", server.uri()))
+                .send()
+                .await
+                .expect("response");
+        })
+        .await;
+
+        let attached: serde_json::Value = serde_json::from_str(
+            &stats
+                .attach_provenance(r#"{"symbol": "TP53"}"#)
+                .expect("attach"),
+        )
+        .expect("json");
+        assert_eq!(attached["symbol"], "TP53");
+        let entry = &attached["_provenance"][0];
+        assert_eq!(
+            entry["url"],
+            format!("{}/keyed?id=7157&api_key=REDACTED", server.uri())
+        );
+        assert_eq!(entry["method"], "GET");
+        assert_eq!(entry["status"], 200);
+        assert_eq!(entry["cache"], "miss");
+        assert!(entry["elapsed_ms"].is_u64());
+
+        let wrapped: serde_json::Value =
+            serde_json::from_str(&stats.attach_provenance("[1]").expect("attach")).expect("json");
+        assert_eq!(wrapped["result"][0], 1);
+        assert_eq!(wrapped["_provenance"].as_array().map(Vec::len), Some(1));
+        assert_eq!(
+            stats.attach_provenance("/tmp/biomcp").expect("attach"),
+            "/tmp/biomcp"
+        );
+    }
+
+    #[tokio::test]
+    async fn calls_outside_collector_are_not_recorded() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/plain"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("plain"))
+            .mount(&server)
+            .await;
+
+        let client = stats_client();
+        let body = client
+            .get(format!("{}/plain", server.uri()))
+            .send()
+            .await
+            .expect("response")
+            .text()
+            .await
+            .expect("body");
+        assert_eq!(body, "plain");
+        assert!(!is_collecting());
+    }
+}
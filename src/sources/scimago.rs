@@ -0,0 +1,464 @@
+//! SCImago Journal Rank (SJR) local dataset for article journal context.
+//!
+//! The yearly SCImago journal export is downloaded into `BIOMCP_SCIMAGO_DIR`
+//! (or the default data directory) the first time a search filters on journal
+//! quartile. Article rows are matched by full journal title or by ISO-style
+//! abbreviation (`Nat Med`, `Proc Natl Acad Sci U S A`); abbreviations only
+//! match when exactly one journal fits.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use http_cache_reqwest::CacheMode;
+
+use crate::entities::article::ArticleJournalRank;
+use crate::error::BioMcpError;
+
+const SOURCE_NAME: &str = "SCImago Journal Rank";
+const SCIMAGO_API: &str = "scimago";
+pub(crate) const SCIMAGO_EXPORT_URL: &str = "https://www.scimagojr.com/journalrank.php?out=xls";
+pub(crate) const SCIMAGO_EXPORT_URL_ENV: &str = "BIOMCP_SCIMAGO_URL";
+pub(crate) const SCIMAGO_CSV_FILE: &str = "scimagojr.csv";
+pub(crate) const SCIMAGO_REQUIRED_FILES: &[&str] = &[SCIMAGO_CSV_FILE];
+pub(crate) const SCIMAGO_STALE_AFTER: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+pub(crate) const SCIMAGO_SIZE_HINT: &str = "~12 MB";
+const SCIMAGO_MAX_BODY_BYTES: usize = 64 * 1024 * 1024;
+
+const TITLE_HEADER: &str = "TITLE";
+const QUARTILE_HEADER: &str = "SJR BEST QUARTILE";
+const SJR_HEADER: &str = "SJR";
+const TITLE_STOPWORDS: &[&str] = &[
+    "a", "an", "and", "das", "de", "der", "des", "die", "et", "for", "in", "la", "le", "of", "on",
+    "the", "und",
+];
+
+#[derive(Debug)]
+struct JournalEntry {
+    words: Vec<String>,
+    rank: ArticleJournalRank,
+}
+
+/// Parsed SCImago export with exact-title and abbreviation lookups.
+#[derive(Debug, Default)]
+pub(crate) struct JournalRankIndex {
+    entries: Vec<JournalEntry>,
+    by_title: HashMap<String, usize>,
+    /// Entry positions keyed by the first letter of the first significant word.
+    by_initial: HashMap<char, Vec<usize>>,
+}
+
+struct CachedIndex {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    index: Arc<JournalRankIndex>,
+}
+
+static INDEX_CACHE: Mutex<Option<CachedIndex>> = Mutex::new(None);
+
+#[derive(Debug, Clone)]
+pub(crate) struct ScimagoClient {
+    root: PathBuf,
+}
+
+impl ScimagoClient {
+    /// Downloads the export when it is missing or stale.
+    pub(crate) async fn ready() -> Result<Self, BioMcpError> {
+        let root = resolve_scimago_root();
+        sync_scimago_root(&root).await?;
+        Ok(Self { root })
+    }
+
+    pub(crate) fn index(&self) -> Result<Arc<JournalRankIndex>, BioMcpError> {
+        let path = self.root.join(SCIMAGO_CSV_FILE);
+        load_index(&path).map_err(|err| BioMcpError::SourceUnavailable {
+            source_name: SOURCE_NAME.to_string(),
+            reason: format!(
+                "Could not read the SCImago journal export at {}: {err}",
+                path.display()
+            ),
+            suggestion: format!(
+                "Place {SCIMAGO_CSV_FILE} (exported from {SCIMAGO_EXPORT_URL}) in {} or set BIOMCP_SCIMAGO_DIR.",
+                self.root.display()
+            ),
+        })
+    }
+}
+
+/// Journal rank from local SCImago data, or `None` when the journal is not
+/// matched or the dataset has not been downloaded.
+pub(crate) fn lookup(journal: &str) -> Option<ArticleJournalRank> {
+    let path = resolve_scimago_root().join(SCIMAGO_CSV_FILE);
+    if !path.is_file() {
+        return None;
+    }
+    load_index(&path).ok()?.lookup(journal).cloned()
+}
+
+fn load_index(path: &Path) -> Result<Arc<JournalRankIndex>, BioMcpError> {
+    let modified = std::fs::metadata(path)?.modified().ok();
+    let mut cache = INDEX_CACHE.lock().unwrap_or_else(|err| err.into_inner());
+    if let Some(cached) = cache.as_ref()
+        && cached.path == path
+        && cached.modified == modified
+    {
+        return Ok(Arc::clone(&cached.index));
+    }
+    let bytes = std::fs::read(path)?;
+    let index = Arc::new(parse_scimago_csv(&String::from_utf8_lossy(&bytes))?);
+    *cache = Some(CachedIndex {
+        path: path.to_path_buf(),
+        modified,
+        index: Arc::clone(&index),
+    });
+    Ok(index)
+}
+
+fn title_words(value: &str) -> Vec<String> {
+    value
+        .to_lowercase()
+        .replace('&', " and ")
+        .split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn is_stopword(word: &str) -> bool {
+    TITLE_STOPWORDS.contains(&word)
+}
+
+/// Lowercased significant words joined by spaces, for exact title matches.
+fn title_key(words: &[String]) -> String {
+    words
+        .iter()
+        .filter(|word| !is_stopword(word))
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn initial(words: &[String]) -> Option<char> {
+    words.iter().find(|word| !is_stopword(word))?.chars().next()
+}
+
+/// Whether `abbrev` can abbreviate `word`: same first letter and the
+/// remaining letters in order (`natl` → `national`, `med` → `medicine`).
+fn abbreviates(abbrev: &str, word: &str) -> bool {
+    let mut abbrev = abbrev.chars();
+    let mut word = word.chars();
+    if abbrev.next() != word.next() {
+        return false;
+    }
+    abbrev.all(|ch| word.by_ref().any(|candidate| candidate == ch))
+}
+
+/// Walks the title and abbreviation together; title stopwords without a
+/// matching abbreviation word are skipped.
+fn abbreviates_title(abbrev: &[String], title: &[String]) -> bool {
+    let mut abbrev = abbrev.iter().peekable();
+    for word in title {
+        match abbrev.peek() {
+            Some(next) if abbreviates(next, word) => {
+                abbrev.next();
+            }
+            _ if is_stopword(word) => {}
+            _ => return false,
+        }
+    }
+    abbrev.peek().is_none()
+}
+
+impl JournalRankIndex {
+    fn insert(&mut self, words: Vec<String>, rank: ArticleJournalRank) {
+        let Some(first) = initial(&words) else {
+            return;
+        };
+        let position = self.entries.len();
+        // The export is sorted by rank, so the first row for a title wins.
+        self.by_title.entry(title_key(&words)).or_insert(position);
+        self.by_initial.entry(first).or_default().push(position);
+        self.entries.push(JournalEntry { words, rank });
+    }
+
+    pub(crate) fn lookup(&self, journal: &str) -> Option<&ArticleJournalRank> {
+        let words = title_words(journal);
+        let first = initial(&words)?;
+        if let Some(position) = self.by_title.get(&title_key(&words)) {
+            return Some(&self.entries[*position].rank);
+        }
+        let mut matches = self
+            .by_initial
+            .get(&first)?
+            .iter()
+            .map(|position| &self.entries[*position])
+            .filter(|entry| abbreviates_title(&words, &entry.words));
+        let found = matches.next()?;
+        matches.next().is_none().then_some(&found.rank)
+    }
+}
+
+fn normalize_header(value: &str) -> String {
+    value
+        .trim_matches('\u{feff}')
+        .trim()
+        .to_ascii_uppercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Dataset year from the `Total Docs. (2024)` column header.
+fn dataset_year(headers: &csv::StringRecord) -> Option<u16> {
+    headers.iter().find_map(|header| {
+        let rest = normalize_header(header)
+            .strip_prefix("TOTAL DOCS. (")?
+            .strip_suffix(')')?
+            .to_string();
+        rest.parse::<u16>().ok()
+    })
+}
+
+fn parse_quartile(value: &str) -> Option<String> {
+    let value = value.trim().to_ascii_uppercase();
+    let digit = value.strip_prefix('Q')?;
+    matches!(digit, "1" | "2" | "3" | "4").then_some(value)
+}
+
+/// SJR values use a decimal comma (`86,091`).
+fn parse_sjr(value: &str) -> Option<f64> {
+    value.trim().replace(',', ".").parse::<f64>().ok()
+}
+
+fn parse_scimago_csv(payload: &str) -> Result<JournalRankIndex, BioMcpError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(b';')
+        .flexible(true)
+        .from_reader(payload.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|err| BioMcpError::Api {
+            api: SCIMAGO_API.to_string(),
+            message: format!("Failed to read SCImago headers: {err}"),
+        })?
+        .clone();
+    let column = |name: &str| {
+        headers
+            .iter()
+            .position(|header| normalize_header(header) == name)
+            .ok_or_else(|| BioMcpError::Api {
+                api: SCIMAGO_API.to_string(),
+                message: format!("SCImago export is missing required column: {name}"),
+            })
+    };
+    let title_idx = column(TITLE_HEADER)?;
+    let quartile_idx = column(QUARTILE_HEADER)?;
+    let sjr_idx = column(SJR_HEADER)?;
+    let year = dataset_year(&headers);
+
+    let mut index = JournalRankIndex::default();
+    for record in reader.records() {
+        let record = record.map_err(|err| BioMcpError::Api {
+            api: SCIMAGO_API.to_string(),
+            message: format!("Failed to parse SCImago export: {err}"),
+        })?;
+        let Some(quartile) = record.get(quartile_idx).and_then(parse_quartile) else {
+            continue;
+        };
+        let words = title_words(record.get(title_idx).unwrap_or_default());
+        index.insert(
+            words,
+            ArticleJournalRank {
+                quartile,
+                sjr: record.get(sjr_idx).and_then(parse_sjr),
+                year,
+            },
+        );
+    }
+    Ok(index)
+}
+
+fn scimago_export_url() -> String {
+    std::env::var(SCIMAGO_EXPORT_URL_ENV)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| SCIMAGO_EXPORT_URL.to_string())
+}
+
+fn has_readable_local_file(path: &Path) -> bool {
+    path.is_file() && File::open(path).is_ok()
+}
+
+fn file_is_stale(path: &Path) -> bool {
+    path.metadata()
+        .ok()
+        .and_then(|metadata| metadata.modified().ok())
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age >= SCIMAGO_STALE_AFTER)
+}
+
+fn scimago_sync_error(root: &Path, detail: impl Into<String>) -> BioMcpError {
+    BioMcpError::SourceUnavailable {
+        source_name: SOURCE_NAME.to_string(),
+        reason: format!(
+            "Could not prepare SCImago journal data under {}. {}",
+            root.display(),
+            detail.into()
+        ),
+        suggestion: format!(
+            "Retry with network access, or preseed {SCIMAGO_CSV_FILE} from {} into {} or set BIOMCP_SCIMAGO_DIR.",
+            scimago_export_url(),
+            root.display()
+        ),
+    }
+}
+
+async fn sync_scimago_root(root: &Path) -> Result<(), BioMcpError> {
+    let path = root.join(SCIMAGO_CSV_FILE);
+    let existing = has_readable_local_file(&path);
+    if existing && !file_is_stale(&path) {
+        return Ok(());
+    }
+
+    tokio::fs::create_dir_all(root).await?;
+    let intro = if existing {
+        "Refreshing stale"
+    } else {
+        "Downloading"
+    };
+    writeln!(
+        std::io::stderr().lock(),
+        "{intro} SCImago journal data ({SCIMAGO_SIZE_HINT})..."
+    )?;
+
+    match sync_export(&path).await {
+        Ok(()) => Ok(()),
+        Err(err) if existing => {
+            writeln!(
+                std::io::stderr().lock(),
+                "Warning: SCImago refresh failed for {SCIMAGO_CSV_FILE}: {err}. Using existing data."
+            )?;
+            Ok(())
+        }
+        Err(err) => Err(scimago_sync_error(root, err.to_string())),
+    }
+}
+
+async fn sync_export(path: &Path) -> Result<(), BioMcpError> {
+    let client = crate::sources::shared_client()?;
+    let response = client
+        .get(scimago_export_url())
+        .with_extension(CacheMode::NoStore)
+        .send()
+        .await?;
+    let status = response.status();
+    let body =
+        crate::sources::read_limited_body_with_limit(response, SCIMAGO_API, SCIMAGO_MAX_BODY_BYTES)
+            .await?;
+    if !status.is_success() {
+        return Err(BioMcpError::Api {
+            api: SCIMAGO_API.to_string(),
+            message: format!(
+                "{SCIMAGO_CSV_FILE}: HTTP {status}: {}",
+                crate::sources::body_excerpt(&body)
+            ),
+        });
+    }
+    parse_scimago_csv(&String::from_utf8_lossy(&body))?;
+    crate::utils::download::write_atomic_bytes(path, &body).await
+}
+
+pub(crate) fn scimago_missing_files<'a>(root: &Path, files: &[&'a str]) -> Vec<&'a str> {
+    files
+        .iter()
+        .filter(|file| !root.join(file).is_file())
+        .copied()
+        .collect()
+}
+
+pub(crate) fn resolve_scimago_root() -> PathBuf {
+    if let Some(path) = std::env::var("BIOMCP_SCIMAGO_DIR")
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+    {
+        return PathBuf::from(path);
+    }
+
+    match dirs::data_dir() {
+        Some(path) => path.join("biomcp").join("scimago"),
+        None => std::env::temp_dir().join("biomcp").join("scimago"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = "Rank;Sourceid;Title;Type;Issn;SJR;SJR Best Quartile;H index;Total Docs. (2024)\n\
+1;28773;\"Ca-A Cancer Journal for Clinicians\";journal;\"15424863, 00079235\";86,091;Q1;223;43\n\
+2;15847;\"Nature Medicine\";journal;\"1078-8956\";19,045;Q1;600;500\n\
+3;21206;\"Proceedings of the National Academy of Sciences of the United States of America\";journal;\"00278424\";3,737;Q1;800;3000\n\
+4;99001;\"Journal of Clinical Oncology\";journal;\"0732183X\";10,163;Q1;500;1000\n\
+5;99002;\"Oncology Letters\";journal;\"17921074\";0,521;Q3;70;2000\n\
+6;99003;\"Oncology Reports\";journal;\"1021335X\";0,812;Q2;90;900\n\
+7;99004;\"Unranked Bulletin\";journal;\"12345678\";;-;1;10\n";
+
+    #[test]
+    fn parse_scimago_csv_reads_quartile_sjr_and_year() {
+        let index = parse_scimago_csv(FIXTURE).expect("fixture should parse");
+        let rank = index.lookup("Nature medicine").expect("exact title");
+        assert_eq!(rank.quartile, "Q1");
+        assert_eq!(rank.sjr, Some(19.045));
+        assert_eq!(rank.year, Some(2024));
+        assert!(index.lookup("Unranked Bulletin").is_none());
+    }
+
+    #[test]
+    fn lookup_matches_iso_abbreviations_only_when_unambiguous() {
+        let index = parse_scimago_csv(FIXTURE).expect("fixture should parse");
+        assert_eq!(
+            index.lookup("Nat Med").map(|rank| rank.quartile.as_str()),
+            Some("Q1")
+        );
+        assert_eq!(
+            index
+                .lookup("Proc Natl Acad Sci U S A")
+                .map(|rank| rank.quartile.as_str()),
+            Some("Q1")
+        );
+        assert_eq!(
+            index
+                .lookup("J Clin Oncol")
+                .map(|rank| rank.quartile.as_str()),
+            Some("Q1")
+        );
+        assert_eq!(
+            index
+                .lookup("Oncol Lett")
+                .map(|rank| rank.quartile.as_str()),
+            Some("Q3")
+        );
+        assert!(index.lookup("Oncol Rev").is_none());
+        assert!(index.lookup("Oncology").is_none());
+        assert!(index.lookup("CA Cancer J Clin").is_some());
+    }
+
+    #[test]
+    fn parse_scimago_csv_requires_quartile_column() {
+        let err = parse_scimago_csv("Rank;Title;SJR\n1;Nature;1,0\n").expect_err("should fail");
+        assert!(err.to_string().contains("SJR BEST QUARTILE"));
+    }
+
+    #[test]
+    fn scimago_missing_files_tracks_required_file_contract() {
+        let root =
+            std::env::temp_dir().join(format!("biomcp-scimago-source-test-{}", std::process::id()));
+        let missing = scimago_missing_files(&root, SCIMAGO_REQUIRED_FILES);
+        assert_eq!(missing, vec![SCIMAGO_CSV_FILE]);
+    }
+}
@@ -831,6 +831,10 @@ mod tests {
             prevalence_note: None,
             survival: None,
             survival_note: None,
+            epidemiology: None,
+            epidemiology_note: None,
+            therapies: None,
+            therapies_note: None,
             civic: None,
             disgenet: None,
             funding: None,
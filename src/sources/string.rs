@@ -63,6 +63,29 @@ impl StringClient {
         identifiers: &str,
         species: u32,
         limit: usize,
+    ) -> Result<Vec<StringInteraction>, BioMcpError> {
+        self.network(identifiers, species, limit, None).await
+    }
+
+    /// Partner subnetwork of `identifier` keeping only edges whose combined
+    /// score is at least `required_score` (STRING's 0-1000 scale).
+    pub async fn scored_interactions(
+        &self,
+        identifier: &str,
+        species: u32,
+        limit: usize,
+        required_score: u16,
+    ) -> Result<Vec<StringInteraction>, BioMcpError> {
+        self.network(identifier, species, limit, Some(required_score.min(1000)))
+            .await
+    }
+
+    async fn network(
+        &self,
+        identifiers: &str,
+        species: u32,
+        limit: usize,
+        required_score: Option<u16>,
     ) -> Result<Vec<StringInteraction>, BioMcpError> {
         let identifiers = identifiers.trim();
         if identifiers.is_empty() {
@@ -74,12 +97,15 @@ impl StringClient {
         let url = self.endpoint("json/network");
         let species = species.to_string();
         let limit = limit.clamp(1, 25).to_string();
-        self.get_json(self.client.get(&url).query(&[
-            ("identifiers", identifiers),
-            ("species", species.as_str()),
-            ("limit", limit.as_str()),
-        ]))
-        .await
+        let mut query = vec![
+            ("identifiers", identifiers.to_string()),
+            ("species", species),
+            ("limit", limit),
+        ];
+        if let Some(required_score) = required_score {
+            query.push(("required_score", required_score.to_string()));
+        }
+        self.get_json(self.client.get(&url).query(&query)).await
     }
 }
 
@@ -152,4 +178,32 @@ mod tests {
         assert_eq!(rows[0].preferred_name_a.as_deref(), Some("BRAF"));
         assert_eq!(rows[0].preferred_name_b.as_deref(), Some("MAP2K1"));
     }
+
+    #[tokio::test]
+    async fn scored_interactions_sends_required_score() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/json/network"))
+            .and(query_param("identifiers", "BRAF"))
+            .and(query_param("limit", "25"))
+            .and(query_param("required_score", "700"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                    "preferredName_A": "BRAF",
+                    "preferredName_B": "RAF1",
+                    "score": 0.999
+                }])),
+            )
+            .mount(&server)
+            .await;
+
+        let client = StringClient::new_for_test(server.uri()).unwrap();
+        let rows = client
+            .scored_interactions("BRAF", 9606, 40, 700)
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].score, Some(0.999));
+    }
 }
@@ -0,0 +1,158 @@
+//! Tenant scoping for shared `serve-http` deployments.
+//!
+//! A multi-tenant HTTP server runs each tool call inside [`with_tenant`]. While
+//! the scope is active, [`api_key`] reads overridable upstream keys only from
+//! the tenant, never from the server process environment, and [`cache_key`]
+//! prefixes HTTP cache keys with the tenant name so cached responses are never
+//! shared between tenants.
+//! Outside a scope (CLI runs, stdio MCP) both behave exactly as before.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::Arc;
+
+tokio::task_local! {
+    static TENANT: Arc<TenantScope>;
+}
+
+/// Upstream key variables a tenant may override.
+pub(crate) const TENANT_KEY_ENVS: &[&str] = &[
+    "ALPHAGENOME_API_KEY",
+    "CLUE_API_KEY",
+    "DISGENET_API_KEY",
+    "NCBI_API_KEY",
+    "NCI_API_KEY",
+    "ONCOKB_TOKEN",
+    "OPENFDA_API_KEY",
+    "PATENTSVIEW_API_KEY",
+    "S2_API_KEY",
+    "UMLS_API_KEY",
+    "UNPAYWALL_EMAIL",
+    "USPSTF_API_KEY",
+];
+
+/// Identity and upstream keys for the tenant a request runs as.
+#[derive(Debug, Clone)]
+pub(crate) struct TenantScope {
+    name: String,
+    keys: BTreeMap<String, String>,
+}
+
+impl TenantScope {
+    pub(crate) fn new(name: impl Into<String>, keys: BTreeMap<String, String>) -> Self {
+        Self {
+            name: name.into(),
+            keys,
+        }
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Runs `fut` as `tenant`.
+pub(crate) async fn with_tenant<R, F>(tenant: Arc<TenantScope>, fut: F) -> R
+where
+    F: Future<Output = R>,
+{
+    TENANT.scope(tenant, fut).await
+}
+
+fn current_tenant() -> Option<Arc<TenantScope>> {
+    TENANT.try_with(Arc::clone).ok()
+}
+
+/// Name and key overrides of the active tenant, for work that continues in a
+/// child process (`jobs submit`).
+pub(crate) fn current_scope() -> Option<(String, BTreeMap<String, String>)> {
+    current_tenant().map(|tenant| (tenant.name.clone(), tenant.keys.clone()))
+}
+
+/// Resolves an upstream API key. Inside a tenant scope, keys listed in
+/// [`TENANT_KEY_ENVS`] come only from the tenant, so a tenant without its own
+/// key sees the source as not configured instead of using the server's. Other
+/// variables, and every key outside a scope, come from the process environment.
+pub(crate) fn api_key(env_var: &str) -> Option<String> {
+    let value = match current_tenant() {
+        Some(tenant) if TENANT_KEY_ENVS.contains(&env_var) => tenant.keys.get(env_var).cloned(),
+        Some(tenant) => tenant
+            .keys
+            .get(env_var)
+            .cloned()
+            .or_else(|| std::env::var(env_var).ok()),
+        None => std::env::var(env_var).ok(),
+    };
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// HTTP cache key: the `METHOD:URI` default, namespaced by the active tenant.
+pub(crate) fn cache_key(parts: &http::request::Parts) -> String {
+    let key = format!("{}:{}", parts.method, parts.uri);
+    match current_tenant() {
+        Some(tenant) => format!("tenant:{}:{key}", tenant.name),
+        None => key,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(uri: &str) -> http::request::Parts {
+        http::Request::get(uri)
+            .body(())
+            .expect("request")
+            .into_parts()
+            .0
+    }
+
+    fn oncology() -> Arc<TenantScope> {
+        Arc::new(TenantScope::new(
+            "oncology",
+            BTreeMap::from([("ONCOKB_TOKEN".to_string(), " team-token ".to_string())]),
+        ))
+    }
+
+    #[tokio::test]
+    async fn cache_key_is_namespaced_only_inside_a_tenant_scope() {
+        let request = parts("https://example.org/q?gene=BRAF");
+        assert_eq!(cache_key(&request), "GET:https://example.org/q?gene=BRAF");
+        let scoped = with_tenant(oncology(), async { cache_key(&request) }).await;
+        assert_eq!(
+            scoped,
+            "tenant:oncology:GET:https://example.org/q?gene=BRAF"
+        );
+    }
+
+    #[tokio::test]
+    async fn api_key_never_falls_back_to_server_keys_a_tenant_can_override() {
+        let _lock = crate::test_support::env_lock().lock().await;
+        // SAFETY: serialized by the shared env lock.
+        unsafe {
+            std::env::set_var("NCBI_API_KEY", "server-ncbi");
+            std::env::set_var("BIOMCP_TEST_PLUGIN_KEY", "server-plugin");
+        }
+
+        let (oncokb, ncbi, plugin) = with_tenant(oncology(), async {
+            (
+                api_key("ONCOKB_TOKEN"),
+                api_key("NCBI_API_KEY"),
+                api_key("BIOMCP_TEST_PLUGIN_KEY"),
+            )
+        })
+        .await;
+        assert_eq!(oncokb.as_deref(), Some("team-token"));
+        assert_eq!(ncbi, None);
+        assert_eq!(plugin.as_deref(), Some("server-plugin"));
+        assert_eq!(api_key("NCBI_API_KEY").as_deref(), Some("server-ncbi"));
+
+        // SAFETY: serialized by the shared env lock.
+        unsafe {
+            std::env::remove_var("NCBI_API_KEY");
+            std::env::remove_var("BIOMCP_TEST_PLUGIN_KEY");
+        }
+    }
+}
@@ -19,11 +19,7 @@ pub struct UmlsClient {
 
 impl UmlsClient {
     pub fn new() -> Result<Option<Self>, BioMcpError> {
-        let Some(api_key) = std::env::var(UMLS_API_KEY_ENV)
-            .ok()
-            .map(|value| value.trim().to_string())
-            .filter(|value| !value.is_empty())
-        else {
+        let Some(api_key) = crate::sources::tenant::api_key(UMLS_API_KEY_ENV) else {
             return Ok(None);
         };
 
@@ -9,9 +9,10 @@ use serde::de::DeserializeOwned;
 
 use crate::error::BioMcpError;
 
-const UNIPROT_BASE: &str = "https://rest.uniprot.org";
+pub(crate) const UNIPROT_BASE: &str = "https://rest.uniprot.org";
 const UNIPROT_API: &str = "uniprot";
-const UNIPROT_BASE_ENV: &str = "BIOMCP_UNIPROT_BASE";
+pub(crate) const UNIPROT_BASE_ENV: &str = "BIOMCP_UNIPROT_BASE";
+pub(crate) const UNIPROT_MAX_PAGE_SIZE: usize = 500;
 
 pub struct UniProtClient {
     client: reqwest::Client,
@@ -25,6 +26,14 @@ pub struct UniProtSearchPage {
     pub next_page_token: Option<String>,
 }
 
+/// One cursor page of a UniProtKB TSV search, header line included.
+#[derive(Debug, Clone)]
+pub struct UniProtTsvPage {
+    pub body: String,
+    pub total: Option<usize>,
+    pub next_page_token: Option<String>,
+}
+
 impl UniProtClient {
     pub fn new() -> Result<Self, BioMcpError> {
         Ok(Self {
@@ -203,6 +212,98 @@ impl UniProtClient {
             next_page_token,
         })
     }
+
+    /// Fetches one page of TSV rows with the requested return `fields`.
+    ///
+    /// Pass the previous page's `next_page_token` as `cursor` to continue;
+    /// UniProt's next link already carries the query, fields, and page size.
+    pub async fn search_tsv(
+        &self,
+        query: &str,
+        fields: &str,
+        size: usize,
+        cursor: Option<&str>,
+    ) -> Result<UniProtTsvPage, BioMcpError> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "UniProt query is required".into(),
+            ));
+        }
+
+        let url = self.endpoint("uniprotkb/search");
+        let size = size.clamp(1, UNIPROT_MAX_PAGE_SIZE).to_string();
+        let token = normalize_next_page_token(cursor)?;
+        crate::sources::rate_limit::wait_for_url_str(&url).await;
+        let resp = crate::sources::retry_send(UNIPROT_API, 3, || async {
+            if let Some(token) = token.as_deref()
+                && (token.starts_with("http://") || token.starts_with("https://"))
+            {
+                return self
+                    .client
+                    .get(token)
+                    .header(ACCEPT, "text/plain")
+                    .send()
+                    .await;
+            }
+            let mut params = vec![
+                ("query", query),
+                ("format", "tsv"),
+                ("size", size.as_str()),
+                ("fields", fields),
+            ];
+            if let Some(token) = token.as_deref() {
+                params.push(("cursor", token));
+            }
+            self.client
+                .get(&url)
+                .header(ACCEPT, "text/plain")
+                .query(&params)
+                .send()
+                .await
+        })
+        .await?;
+        let status = resp.status();
+        let total = resp
+            .headers()
+            .get("x-total-results")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<usize>().ok());
+        let next_page_token = parse_uniprot_next_link(resp.headers().get("link"));
+        let bytes = crate::sources::read_limited_body(resp, UNIPROT_API).await?;
+        let payload = gunzip_if_needed(bytes)?;
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&payload);
+            return Err(BioMcpError::Api {
+                api: UNIPROT_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+        let body = String::from_utf8(payload).map_err(|err| BioMcpError::Api {
+            api: UNIPROT_API.to_string(),
+            message: format!("Invalid UTF-8 in TSV response: {err}"),
+        })?;
+        Ok(UniProtTsvPage {
+            body,
+            total,
+            next_page_token,
+        })
+    }
+}
+
+fn gunzip_if_needed(payload: Vec<u8>) -> Result<Vec<u8>, BioMcpError> {
+    if !payload.starts_with(&[0x1f, 0x8b]) {
+        return Ok(payload);
+    }
+    let mut decoder = GzDecoder::new(payload.as_slice());
+    let mut decoded = Vec::new();
+    decoder
+        .read_to_end(&mut decoded)
+        .map_err(|err| BioMcpError::Api {
+            api: UNIPROT_API.to_string(),
+            message: format!("Failed to decode gzip response: {err}"),
+        })?;
+    Ok(decoded)
 }
 
 fn parse_uniprot_next_link(value: Option<&reqwest::header::HeaderValue>) -> Option<String> {
@@ -670,6 +771,40 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn search_tsv_requests_fields_and_reports_next_cursor() {
+        let server = MockServer::start().await;
+        let next = "https://rest.uniprot.org/uniprotkb/search?cursor=abc&format=tsv";
+        Mock::given(method("GET"))
+            .and(path("/uniprotkb/search"))
+            .and(query_param("query", "organism_id:9606 AND reviewed:true"))
+            .and(query_param("format", "tsv"))
+            .and(query_param("size", "500"))
+            .and(query_param("fields", "accession,gene_primary"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-total-results", "20417")
+                    .insert_header("link", format!("<{next}>; rel=\"next\"").as_str())
+                    .set_body_string("Entry\tGene Names (primary)\nP15056\tBRAF\n"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = UniProtClient::new_for_test(server.uri()).unwrap();
+        let page = client
+            .search_tsv(
+                "organism_id:9606 AND reviewed:true",
+                "accession,gene_primary",
+                1000,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(page.body, "Entry\tGene Names (primary)\nP15056\tBRAF\n");
+        assert_eq!(page.total, Some(20417));
+        assert_eq!(page.next_page_token.as_deref(), Some(next));
+    }
+
     #[test]
     fn record_helpers_extract_display_function_and_structures() {
         let record: UniProtRecord = serde_json::from_value(serde_json::json!({
@@ -0,0 +1,331 @@
+//! Unpaywall client for locating legal open-access copies by DOI.
+//!
+//! Unpaywall indexes publisher and repository copies of scholarly articles and
+//! reports the best open-access location for a DOI. BioMCP uses it when an
+//! article has no PubMed Central full text: the lookup names a PDF or landing
+//! page, and [`UnpaywallClient::download`] fetches that document. The API asks
+//! every caller to identify itself with a contact email, so the client needs
+//! `UNPAYWALL_EMAIL`.
+
+use std::borrow::Cow;
+
+use http_cache_reqwest::CacheMode;
+use reqwest::header::CONTENT_TYPE;
+use serde::Deserialize;
+
+use crate::error::BioMcpError;
+
+const UNPAYWALL_BASE: &str = "https://api.unpaywall.org";
+const UNPAYWALL_API: &str = "unpaywall";
+const UNPAYWALL_BASE_ENV: &str = "BIOMCP_UNPAYWALL_BASE";
+const UNPAYWALL_EMAIL_ENV: &str = "UNPAYWALL_EMAIL";
+const UNPAYWALL_DOCS_URL: &str = "https://unpaywall.org/products/api";
+/// Publisher PDFs with embedded figures run large; anything past this is not
+/// worth keeping in the download cache.
+const MAX_DOCUMENT_BYTES: usize = 32 * 1024 * 1024;
+
+pub struct UnpaywallClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+    email: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct UnpaywallRecord {
+    #[serde(default)]
+    is_oa: bool,
+    #[serde(default)]
+    best_oa_location: Option<UnpaywallLocation>,
+}
+
+/// One open-access copy of an article as Unpaywall reports it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct UnpaywallLocation {
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub url_for_pdf: Option<String>,
+    #[serde(default)]
+    pub url_for_landing_page: Option<String>,
+    /// `publisher` or `repository`.
+    #[serde(default)]
+    pub host_type: Option<String>,
+    /// `publishedVersion`, `acceptedVersion`, or `submittedVersion`.
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub license: Option<String>,
+}
+
+impl UnpaywallLocation {
+    /// Document to download: the PDF when Unpaywall has one, otherwise the
+    /// location's HTML page.
+    pub fn document_url(&self) -> Option<&str> {
+        [&self.url_for_pdf, &self.url, &self.url_for_landing_page]
+            .into_iter()
+            .filter_map(|value| value.as_deref())
+            .map(str::trim)
+            .find(|value| !value.is_empty())
+    }
+}
+
+/// A downloaded open-access document.
+#[derive(Debug, Clone)]
+pub struct UnpaywallDocument {
+    pub bytes: Vec<u8>,
+    pub is_pdf: bool,
+}
+
+impl UnpaywallClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        let email = crate::sources::tenant::api_key(UNPAYWALL_EMAIL_ENV).ok_or_else(|| {
+            BioMcpError::ApiKeyRequired {
+                api: UNPAYWALL_API.to_string(),
+                env_var: UNPAYWALL_EMAIL_ENV.to_string(),
+                docs_url: UNPAYWALL_DOCS_URL.to_string(),
+            }
+        })?;
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(UNPAYWALL_BASE, UNPAYWALL_BASE_ENV),
+            email,
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String, email: &str) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+            email: email.to_string(),
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base.as_ref().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    /// Best open-access location for `doi`, or `None` when Unpaywall does not
+    /// know the DOI or has no open copy of it.
+    pub async fn best_oa_location(
+        &self,
+        doi: &str,
+    ) -> Result<Option<UnpaywallLocation>, BioMcpError> {
+        let doi = doi.trim();
+        if doi.is_empty() {
+            return Ok(None);
+        }
+        if doi.len() > 512 {
+            return Err(BioMcpError::InvalidArgument("DOI is too long.".into()));
+        }
+
+        let url = self.endpoint(&format!("v2/{doi}"));
+        let req = self
+            .client
+            .get(&url)
+            .query(&[("email", self.email.as_str())]);
+        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, UNPAYWALL_API).await?;
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: UNPAYWALL_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(UNPAYWALL_API, content_type.as_ref(), &bytes)?;
+        let record: UnpaywallRecord =
+            serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+                api: UNPAYWALL_API.to_string(),
+                source,
+            })?;
+        if !record.is_oa {
+            return Ok(None);
+        }
+        Ok(record
+            .best_oa_location
+            .filter(|location| location.document_url().is_some()))
+    }
+
+    /// Downloads an open-access document named by [`best_oa_location`].
+    ///
+    /// [`best_oa_location`]: UnpaywallClient::best_oa_location
+    pub async fn download(&self, url: &str) -> Result<UnpaywallDocument, BioMcpError> {
+        let resp = self
+            .client
+            .get(url)
+            .with_extension(CacheMode::NoStore)
+            .send()
+            .await?;
+        let status = resp.status();
+        let content_type = resp
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_ascii_lowercase);
+        let bytes =
+            crate::sources::read_limited_body_with_limit(resp, UNPAYWALL_API, MAX_DOCUMENT_BYTES)
+                .await?;
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: UNPAYWALL_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+
+        let is_pdf = bytes.starts_with(b"%PDF")
+            || content_type
+                .as_deref()
+                .is_some_and(|value| value.contains("application/pdf"));
+        let is_html = content_type
+            .as_deref()
+            .is_some_and(|value| value.contains("html"));
+        if !is_pdf && !is_html {
+            return Err(BioMcpError::Api {
+                api: UNPAYWALL_API.to_string(),
+                message: format!(
+                    "Unsupported open-access document type: {}",
+                    content_type.as_deref().unwrap_or("unknown")
+                ),
+            });
+        }
+
+        Ok(UnpaywallDocument { bytes, is_pdf })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn best_oa_location_prefers_pdf_and_sends_email() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v2/10.1000/example.1"))
+            .and(query_param("email", "curator@example.org"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "doi": "10.1000/example.1",
+                "is_oa": true,
+                "best_oa_location": {
+                    "url": "https://repo.example.org/record/1",
+                    "url_for_pdf": "https://repo.example.org/record/1/file.pdf",
+                    "url_for_landing_page": "https://repo.example.org/record/1",
+                    "host_type": "repository",
+                    "version": "acceptedVersion",
+                    "license": "cc-by"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UnpaywallClient::new_for_test(server.uri(), "curator@example.org").unwrap();
+        let location = client
+            .best_oa_location("10.1000/example.1")
+            .await
+            .unwrap()
+            .expect("open-access location");
+        assert_eq!(
+            location.document_url(),
+            Some("https://repo.example.org/record/1/file.pdf")
+        );
+        assert_eq!(location.host_type.as_deref(), Some("repository"));
+        assert_eq!(location.version.as_deref(), Some("acceptedVersion"));
+        assert_eq!(location.license.as_deref(), Some("cc-by"));
+    }
+
+    #[tokio::test]
+    async fn best_oa_location_is_none_for_closed_or_unknown_dois() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v2/10.1000/closed"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "doi": "10.1000/closed",
+                "is_oa": false,
+                "best_oa_location": null
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/10.1000/missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": true,
+                "message": "'10.1000/missing' is an invalid doi."
+            })))
+            .mount(&server)
+            .await;
+
+        let client = UnpaywallClient::new_for_test(server.uri(), "curator@example.org").unwrap();
+        let location = client.best_oa_location("10.1000/closed").await.unwrap();
+        assert!(location.is_none(), "closed DOI should have no location");
+        let location = client.best_oa_location("10.1000/missing").await.unwrap();
+        assert!(location.is_none(), "missing DOI should have no location");
+    }
+
+    #[tokio::test]
+    async fn download_detects_pdf_and_html_and_rejects_other_types() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/file.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "application/octet-stream")
+                    .set_body_bytes(b"%PDF-1.7\n".to_vec()),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/article"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html; charset=utf-8")
+                    .set_body_string("<html><body><p>Results</p></body></html>"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/archive.zip"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "application/zip")
+                    .set_body_bytes(b"PK".to_vec()),
+            )
+            .mount(&server)
+            .await;
+
+        let client = UnpaywallClient::new_for_test(server.uri(), "curator@example.org").unwrap();
+        let pdf = client
+            .download(&format!("{}/file.pdf", server.uri()))
+            .await
+            .unwrap();
+        assert!(pdf.is_pdf);
+
+        let html = client
+            .download(&format!("{}/article", server.uri()))
+            .await
+            .unwrap();
+        assert!(!html.is_pdf);
+        assert!(String::from_utf8_lossy(&html.bytes).contains("Results"));
+
+        let err = client
+            .download(&format!("{}/archive.zip", server.uri()))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("application/zip"));
+    }
+}
@@ -0,0 +1,202 @@
+//! USPSTF Prevention TaskForce API client for graded preventive-service recommendations.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::error::BioMcpError;
+
+const USPSTF_BASE: &str = "https://data.uspreventiveservicestaskforce.org/api";
+const USPSTF_API: &str = "uspstf";
+const USPSTF_API_KEY_ENV: &str = "USPSTF_API_KEY";
+const USPSTF_BASE_ENV: &str = "BIOMCP_USPSTF_BASE";
+const USPSTF_DOCS_URL: &str = "https://www.uspreventiveservicestaskforce.org/apps/api.jsp";
+
+/// Full Prevention TaskForce export; the API returns every recommendation in one document.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UspstfExport {
+    #[serde(default)]
+    pub specific_recommendations: Vec<UspstfSpecificRecommendation>,
+    #[serde(default)]
+    pub general_recommendations: HashMap<String, UspstfGeneralRecommendation>,
+}
+
+/// Population-specific recommendation statement carrying the letter grade.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UspstfSpecificRecommendation {
+    pub id: Option<serde_json::Value>,
+    pub title: Option<String>,
+    pub grade: Option<String>,
+    pub gender: Option<String>,
+    pub text: Option<String>,
+    /// Key of the parent topic in `generalRecommendations`.
+    pub general: Option<serde_json::Value>,
+}
+
+/// Topic-level record shared by the specific recommendations of one topic.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UspstfGeneralRecommendation {
+    pub title: Option<String>,
+    pub topic_type: Option<String>,
+    pub topic_year: Option<serde_json::Value>,
+    pub uspstf_alias: Option<String>,
+    /// Pipe-separated search keywords curated by USPSTF.
+    pub keywords: Option<String>,
+}
+
+impl UspstfSpecificRecommendation {
+    /// Parent topic record, when the export links one.
+    pub fn general_recommendation<'a>(
+        &self,
+        export: &'a UspstfExport,
+    ) -> Option<&'a UspstfGeneralRecommendation> {
+        let key = match self.general.as_ref()? {
+            serde_json::Value::String(value) => value.trim().to_string(),
+            serde_json::Value::Number(value) => value.to_string(),
+            _ => return None,
+        };
+        export.general_recommendations.get(&key)
+    }
+}
+
+pub struct UspstfClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+    api_key: Option<String>,
+}
+
+impl UspstfClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(USPSTF_BASE, USPSTF_BASE_ENV),
+            api_key: crate::sources::tenant::api_key(USPSTF_API_KEY_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String, api_key: Option<String>) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+            api_key,
+        })
+    }
+
+    pub fn has_api_key(&self) -> bool {
+        self.api_key.is_some()
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base.as_ref().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    fn require_api_key(&self) -> Result<&str, BioMcpError> {
+        self.api_key
+            .as_deref()
+            .ok_or_else(|| BioMcpError::ApiKeyRequired {
+                api: USPSTF_API.to_string(),
+                env_var: USPSTF_API_KEY_ENV.to_string(),
+                docs_url: USPSTF_DOCS_URL.to_string(),
+            })
+    }
+
+    /// Fetches the full recommendation export.
+    pub async fn export(&self) -> Result<UspstfExport, BioMcpError> {
+        let key = self.require_api_key()?;
+        let req = self
+            .client
+            .get(self.endpoint("json"))
+            .query(&[("key", key)]);
+        let resp = crate::sources::apply_cache_mode_with_auth(req, true)
+            .send()
+            .await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, USPSTF_API).await?;
+
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: USPSTF_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(USPSTF_API, content_type.as_ref(), &bytes)?;
+        serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+            api: USPSTF_API.to_string(),
+            source,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn export_requires_api_key() {
+        let client = UspstfClient::new_for_test("http://127.0.0.1:9".into(), None).expect("client");
+        let err = client.export().await.expect_err("missing key");
+        assert!(err.to_string().contains(USPSTF_API_KEY_ENV));
+    }
+
+    #[tokio::test]
+    async fn export_parses_specific_and_general_recommendations() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/json"))
+            .and(query_param("key", "test-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "specificRecommendations": [
+                    {
+                        "id": 413,
+                        "title": "Atrial Fibrillation: Screening -- Adults 50 years or older",
+                        "grade": "I",
+                        "gender": "men and women",
+                        "text": "<p>The USPSTF concludes that the current evidence is insufficient.</p>",
+                        "general": 390
+                    }
+                ],
+                "generalRecommendations": {
+                    "390": {
+                        "title": "Atrial Fibrillation: Screening",
+                        "topicType": "Screening",
+                        "topicYear": "2022",
+                        "uspstfAlias": "atrial-fibrillation-screening",
+                        "keywords": "Atrial Fibrillation|AF|Stroke"
+                    }
+                },
+                "tools": {}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            UspstfClient::new_for_test(server.uri(), Some("test-key".into())).expect("client");
+        let export = client.export().await.expect("export");
+        assert_eq!(export.specific_recommendations.len(), 1);
+        let specific = &export.specific_recommendations[0];
+        assert_eq!(specific.grade.as_deref(), Some("I"));
+        let general = specific
+            .general_recommendation(&export)
+            .expect("general recommendation");
+        assert_eq!(
+            general.uspstf_alias.as_deref(),
+            Some("atrial-fibrillation-screening")
+        );
+        assert_eq!(general.topic_year, Some(serde_json::json!("2022")));
+    }
+}
@@ -0,0 +1,262 @@
+//! VariantValidator REST client for HGVS validation, normalization, and liftover.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::error::BioMcpError;
+
+const VARIANTVALIDATOR_BASE: &str = "https://rest.variantvalidator.org";
+const VARIANTVALIDATOR_API: &str = "variantvalidator";
+const VARIANTVALIDATOR_BASE_ENV: &str = "BIOMCP_VARIANTVALIDATOR_BASE";
+const GENOME_BUILD: &str = "GRCh38";
+const SELECT_TRANSCRIPTS: &str = "mane_select";
+
+/// Parsed VariantValidator response.
+///
+/// The service keys each validated record by its output description and adds
+/// a top-level `flag` (`gene_variant`, `intergenic`, `warning`, ...) plus a
+/// `metadata` block; invalid inputs come back under `validation_warning_N` keys.
+#[derive(Debug, Clone, Default)]
+pub struct VariantValidatorResponse {
+    pub flag: Option<String>,
+    pub records: Vec<VariantValidatorRecord>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct VariantValidatorRecord {
+    pub submitted_variant: Option<String>,
+    pub hgvs_transcript_variant: Option<String>,
+    pub gene_symbol: Option<String>,
+    pub hgvs_predicted_protein_consequence: Option<VariantValidatorProtein>,
+    #[serde(default)]
+    pub primary_assembly_loci: HashMap<String, VariantValidatorLocus>,
+    #[serde(default)]
+    pub validation_warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct VariantValidatorProtein {
+    /// Three-letter amino-acid description, e.g. `NP_000537.3:p.(Pro72Arg)`.
+    pub tlr: Option<String>,
+    /// Single-letter amino-acid description, e.g. `NP_000537.3:p.(P72R)`.
+    pub slr: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct VariantValidatorLocus {
+    pub hgvs_genomic_description: Option<String>,
+    pub vcf: Option<VariantValidatorVcf>,
+}
+
+/// Left-aligned VCF coordinates; `pos` arrives as a string in current releases.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct VariantValidatorVcf {
+    pub chr: Option<String>,
+    pub pos: Option<serde_json::Value>,
+    #[serde(rename = "ref")]
+    pub reference: Option<String>,
+    pub alt: Option<String>,
+}
+
+impl VariantValidatorRecord {
+    /// Primary-assembly locus for `grch38` / `grch37`.
+    pub fn locus(&self, assembly: &str) -> Option<&VariantValidatorLocus> {
+        self.primary_assembly_loci.get(assembly)
+    }
+}
+
+impl VariantValidatorVcf {
+    pub fn position(&self) -> Option<String> {
+        match self.pos.as_ref()? {
+            serde_json::Value::String(value) => {
+                Some(value.trim().to_string()).filter(|value| !value.is_empty())
+            }
+            serde_json::Value::Number(value) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+}
+
+pub struct VariantValidatorClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+}
+
+impl VariantValidatorClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(VARIANTVALIDATOR_BASE, VARIANTVALIDATOR_BASE_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+        })
+    }
+
+    fn build_segment_url(&self, segments: &[&str]) -> Result<reqwest::Url, BioMcpError> {
+        let mut url = reqwest::Url::parse(self.base.as_ref()).map_err(|err| BioMcpError::Api {
+            api: VARIANTVALIDATOR_API.to_string(),
+            message: format!("Invalid VariantValidator base URL: {err}"),
+        })?;
+        {
+            let mut path = url.path_segments_mut().map_err(|_| BioMcpError::Api {
+                api: VARIANTVALIDATOR_API.to_string(),
+                message: "Invalid VariantValidator base URL path".to_string(),
+            })?;
+            path.pop_if_empty();
+            for segment in segments {
+                path.push(segment);
+            }
+        }
+        Ok(url)
+    }
+
+    /// Validates one HGVS description against GRCh38 and the MANE Select transcript set.
+    pub async fn validate(
+        &self,
+        description: &str,
+    ) -> Result<VariantValidatorResponse, BioMcpError> {
+        let description = description.trim();
+        if description.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "HGVS description is required".into(),
+            ));
+        }
+        let url = self.build_segment_url(&[
+            "VariantValidator",
+            "variantvalidator",
+            GENOME_BUILD,
+            description,
+            SELECT_TRANSCRIPTS,
+        ])?;
+        let req = self
+            .client
+            .get(url)
+            .query(&[("content-type", "application/json")]);
+        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, VARIANTVALIDATOR_API).await?;
+
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: VARIANTVALIDATOR_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(
+            VARIANTVALIDATOR_API,
+            content_type.as_ref(),
+            &bytes,
+        )?;
+        let body: serde_json::Map<String, serde_json::Value> = serde_json::from_slice(&bytes)
+            .map_err(|source| BioMcpError::ApiJson {
+                api: VARIANTVALIDATOR_API.to_string(),
+                source,
+            })?;
+        parse_response(body)
+    }
+}
+
+fn parse_response(
+    body: serde_json::Map<String, serde_json::Value>,
+) -> Result<VariantValidatorResponse, BioMcpError> {
+    let mut out = VariantValidatorResponse::default();
+    for (key, value) in body {
+        match key.as_str() {
+            "flag" => out.flag = value.as_str().map(str::to_string),
+            "metadata" => {}
+            _ if value.is_object() => {
+                let record =
+                    serde_json::from_value(value).map_err(|source| BioMcpError::ApiJson {
+                        api: VARIANTVALIDATOR_API.to_string(),
+                        source,
+                    })?;
+                out.records.push(record);
+            }
+            _ => {}
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn validate_encodes_description_and_parses_loci() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/VariantValidator/variantvalidator/GRCh38/NM_000546.6:c.215C%3EG/mane_select",
+            ))
+            .and(query_param("content-type", "application/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "flag": "gene_variant",
+                "metadata": {"variantvalidator_version": "3.0.1"},
+                "NM_000546.6:c.215C>G": {
+                    "submitted_variant": "NM_000546.6:c.215C>G",
+                    "hgvs_transcript_variant": "NM_000546.6:c.215C>G",
+                    "gene_symbol": "TP53",
+                    "hgvs_predicted_protein_consequence": {
+                        "tlr": "NP_000537.3:p.(Pro72Arg)",
+                        "slr": "NP_000537.3:p.(P72R)"
+                    },
+                    "primary_assembly_loci": {
+                        "grch38": {
+                            "hgvs_genomic_description": "NC_000017.11:g.7676154G>C",
+                            "vcf": {"chr": "17", "pos": "7676154", "ref": "G", "alt": "C"}
+                        }
+                    },
+                    "validation_warnings": []
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = VariantValidatorClient::new_for_test(server.uri()).expect("client");
+        let resp = client
+            .validate("NM_000546.6:c.215C>G")
+            .await
+            .expect("validate");
+        assert_eq!(resp.flag.as_deref(), Some("gene_variant"));
+        assert_eq!(resp.records.len(), 1);
+        let record = &resp.records[0];
+        assert_eq!(record.gene_symbol.as_deref(), Some("TP53"));
+        let vcf = record
+            .locus("grch38")
+            .and_then(|locus| locus.vcf.as_ref())
+            .expect("grch38 vcf");
+        assert_eq!(vcf.position().as_deref(), Some("7676154"));
+        assert_eq!(vcf.reference.as_deref(), Some("G"));
+    }
+
+    #[tokio::test]
+    async fn validate_surfaces_http_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("maintenance"))
+            .mount(&server)
+            .await;
+
+        let client = VariantValidatorClient::new_for_test(server.uri()).expect("client");
+        let err = client
+            .validate("NM_000546.6:c.215C>G")
+            .await
+            .expect_err("503 should fail");
+        assert!(err.to_string().contains("HTTP 503"));
+    }
+}
@@ -0,0 +1,213 @@
+//! Ensembl VEP REST client for transcript-level consequence annotation.
+//!
+//! MyVariant.info `_id`s are GRCh37 genomic HGVS by default, so
+//! [`VepClient::new`] uses the GRCh37 Ensembl REST mirror.
+//! [`VepClient::new_grch38`] uses the main site for `_id`s fetched with
+//! `get variant --assembly grch38`.
+
+use std::borrow::Cow;
+
+use serde::Deserialize;
+
+use crate::error::BioMcpError;
+
+const VEP_BASE: &str = "https://grch37.rest.ensembl.org";
+const VEP_API: &str = "vep";
+const VEP_BASE_ENV: &str = "BIOMCP_VEP_BASE";
+const VEP_GRCH38_BASE: &str = "https://rest.ensembl.org";
+const VEP_GRCH38_BASE_ENV: &str = "BIOMCP_VEP_GRCH38_BASE";
+
+/// One VEP result; the endpoint returns one per submitted description.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct VepAnnotation {
+    pub assembly_name: Option<String>,
+    pub most_severe_consequence: Option<String>,
+    #[serde(default)]
+    pub transcript_consequences: Vec<VepTranscriptConsequence>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct VepTranscriptConsequence {
+    pub transcript_id: Option<String>,
+    pub gene_symbol: Option<String>,
+    pub biotype: Option<String>,
+    #[serde(default)]
+    pub consequence_terms: Vec<String>,
+    /// `HIGH`, `MODERATE`, `LOW`, or `MODIFIER`.
+    pub impact: Option<String>,
+    /// Present (as `1`) only on the Ensembl canonical transcript.
+    pub canonical: Option<u8>,
+    pub hgvsc: Option<String>,
+    pub hgvsp: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VepErrorBody {
+    error: Option<String>,
+}
+
+pub struct VepClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+}
+
+impl VepClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(VEP_BASE, VEP_BASE_ENV),
+        })
+    }
+
+    pub fn new_grch38() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(VEP_GRCH38_BASE, VEP_GRCH38_BASE_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+        })
+    }
+
+    fn hgvs_url(&self, hgvs: &str) -> Result<reqwest::Url, BioMcpError> {
+        let mut url = reqwest::Url::parse(self.base.as_ref()).map_err(|err| BioMcpError::Api {
+            api: VEP_API.to_string(),
+            message: format!("Invalid Ensembl VEP base URL: {err}"),
+        })?;
+        {
+            let mut path = url.path_segments_mut().map_err(|_| BioMcpError::Api {
+                api: VEP_API.to_string(),
+                message: "Invalid Ensembl VEP base URL path".to_string(),
+            })?;
+            path.pop_if_empty();
+            path.extend(["vep", "human", "hgvs", hgvs]);
+        }
+        Ok(url)
+    }
+
+    /// Annotates one HGVS description, flagging canonical transcripts and
+    /// returning transcript-level HGVS.
+    pub async fn annotate_hgvs(&self, hgvs: &str) -> Result<Vec<VepAnnotation>, BioMcpError> {
+        let hgvs = hgvs.trim();
+        if hgvs.is_empty() {
+            return Err(BioMcpError::InvalidArgument(
+                "HGVS description is required".into(),
+            ));
+        }
+        let req = self.client.get(self.hgvs_url(hgvs)?).query(&[
+            ("canonical", "1"),
+            ("hgvs", "1"),
+            ("content-type", "application/json"),
+        ]);
+        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, VEP_API).await?;
+
+        if !status.is_success() {
+            let message = serde_json::from_slice::<VepErrorBody>(&bytes)
+                .ok()
+                .and_then(|body| body.error)
+                .unwrap_or_else(|| crate::sources::body_excerpt(&bytes));
+            return Err(BioMcpError::Api {
+                api: VEP_API.to_string(),
+                message: format!("HTTP {status}: {message}"),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(VEP_API, content_type.as_ref(), &bytes)?;
+        serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+            api: VEP_API.to_string(),
+            source,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn annotate_hgvs_requests_canonical_flags_and_parses_transcripts() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/vep/human/hgvs/13:g.32914438delT"))
+            .and(query_param("canonical", "1"))
+            .and(query_param("hgvs", "1"))
+            .and(query_param("content-type", "application/json"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                    "input": "13:g.32914438delT",
+                    "assembly_name": "GRCh37",
+                    "most_severe_consequence": "frameshift_variant",
+                    "transcript_consequences": [
+                        {
+                            "transcript_id": "ENST00000380152",
+                            "gene_symbol": "BRCA2",
+                            "biotype": "protein_coding",
+                            "consequence_terms": ["frameshift_variant"],
+                            "impact": "HIGH",
+                            "canonical": 1,
+                            "hgvsc": "ENST00000380152.3:c.5946delT",
+                            "hgvsp": "ENSP00000369497.3:p.Ser1982ArgfsTer22"
+                        },
+                        {
+                            "transcript_id": "ENST00000470094",
+                            "gene_symbol": "BRCA2",
+                            "consequence_terms": ["downstream_gene_variant"],
+                            "impact": "MODIFIER"
+                        }
+                    ]
+                }])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = VepClient::new_for_test(server.uri()).expect("client");
+        let rows = client
+            .annotate_hgvs("13:g.32914438delT")
+            .await
+            .expect("annotate");
+        assert_eq!(rows.len(), 1);
+        let row = &rows[0];
+        assert_eq!(
+            row.most_severe_consequence.as_deref(),
+            Some("frameshift_variant")
+        );
+        assert_eq!(row.transcript_consequences.len(), 2);
+        assert_eq!(row.transcript_consequences[0].canonical, Some(1));
+        assert_eq!(row.transcript_consequences[1].canonical, None);
+        assert_eq!(
+            row.transcript_consequences[0].impact.as_deref(),
+            Some("HIGH")
+        );
+    }
+
+    #[tokio::test]
+    async fn annotate_hgvs_surfaces_ensembl_error_message() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "Unable to parse HGVS notation '7:g.1X>T'"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = VepClient::new_for_test(server.uri()).expect("client");
+        let err = client
+            .annotate_hgvs("7:g.1X>T")
+            .await
+            .expect_err("400 should fail");
+        let message = err.to_string();
+        assert!(message.contains("HTTP 400"));
+        assert!(message.contains("Unable to parse HGVS notation"));
+    }
+}
@@ -0,0 +1,353 @@
+//! WHO Global Health Observatory (GHO) OData client for regional epidemiology estimates.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use crate::entities::disease::Disease;
+use crate::error::BioMcpError;
+
+const WHO_GHO_BASE: &str = "https://ghoapi.azureedge.net/api";
+const WHO_GHO_API: &str = "who_gho";
+const WHO_GHO_BASE_ENV: &str = "BIOMCP_WHO_GHO_BASE";
+const WHO_GHO_REGION_FILTER: &str = "SpatialDimType eq 'REGION' or SpatialDimType eq 'GLOBAL'";
+
+/// Curated GHO indicator with the disease names it answers for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WhoGhoIndicator {
+    pub code: &'static str,
+    pub measure: &'static str,
+    pub label: &'static str,
+    pub unit: &'static str,
+    aliases: &'static [&'static str],
+}
+
+const CURATED_INDICATORS: &[WhoGhoIndicator] = &[
+    WhoGhoIndicator {
+        code: "MDG_0000000020",
+        measure: "incidence",
+        label: "Incidence of tuberculosis",
+        unit: "per 100 000 population per year",
+        aliases: &["tuberculosis", "pulmonary tuberculosis", "tb"],
+    },
+    WhoGhoIndicator {
+        code: "MALARIA_EST_INCIDENCE",
+        measure: "incidence",
+        label: "Estimated malaria incidence",
+        unit: "per 1 000 population at risk",
+        aliases: &["malaria", "plasmodium falciparum malaria"],
+    },
+    WhoGhoIndicator {
+        code: "HIV_0000000026",
+        measure: "incidence",
+        label: "New HIV infections",
+        unit: "per 1 000 uninfected population",
+        aliases: &[
+            "hiv infectious disease",
+            "hiv infection",
+            "human immunodeficiency virus infectious disease",
+            "aids",
+            "hiv",
+        ],
+    },
+    WhoGhoIndicator {
+        code: "HIV_0000000001",
+        measure: "prevalence",
+        label: "People living with HIV",
+        unit: "people",
+        aliases: &[
+            "hiv infectious disease",
+            "hiv infection",
+            "human immunodeficiency virus infectious disease",
+            "aids",
+            "hiv",
+        ],
+    },
+    WhoGhoIndicator {
+        code: "NCD_GLUC_04",
+        measure: "prevalence",
+        label: "Raised fasting blood glucose (age-standardized)",
+        unit: "%",
+        aliases: &[
+            "diabetes mellitus",
+            "type 2 diabetes mellitus",
+            "diabetes",
+            "type 2 diabetes",
+        ],
+    },
+    WhoGhoIndicator {
+        code: "NCD_HYP_PREVALENCE_A",
+        measure: "prevalence",
+        label: "Hypertension among adults aged 30-79 (age-standardized)",
+        unit: "%",
+        aliases: &[
+            "hypertension",
+            "essential hypertension",
+            "hypertensive disorder",
+        ],
+    },
+    WhoGhoIndicator {
+        code: "NCD_BMI_30A",
+        measure: "prevalence",
+        label: "Obesity among adults, BMI >= 30 (age-standardized)",
+        unit: "%",
+        aliases: &["obesity", "obesity disorder"],
+    },
+];
+
+/// Returns the curated indicators whose aliases match the disease name or a synonym.
+pub fn resolve_indicators(disease: &Disease) -> Vec<WhoGhoIndicator> {
+    let candidates = std::iter::once(disease.name.as_str())
+        .chain(disease.synonyms.iter().map(String::as_str))
+        .map(normalize_match_term)
+        .filter(|value| !value.is_empty())
+        .collect::<HashSet<_>>();
+
+    CURATED_INDICATORS
+        .iter()
+        .filter(|indicator| {
+            indicator
+                .aliases
+                .iter()
+                .any(|alias| candidates.contains(*alias))
+        })
+        .copied()
+        .collect()
+}
+
+fn normalize_match_term(value: &str) -> String {
+    value
+        .to_ascii_lowercase()
+        .split(|ch: char| !ch.is_ascii_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Human-readable WHO region name for a GHO spatial code.
+pub fn region_label(code: &str) -> &str {
+    match code {
+        "GLOBAL" => "Global",
+        "AFR" => "Africa",
+        "AMR" => "Americas",
+        "SEAR" => "South-East Asia",
+        "EUR" => "Europe",
+        "EMR" => "Eastern Mediterranean",
+        "WPR" => "Western Pacific",
+        other => other,
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct WhoGhoResponse {
+    #[serde(default)]
+    value: Vec<WhoGhoRow>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct WhoGhoRow {
+    pub spatial_dim_type: Option<String>,
+    pub spatial_dim: Option<String>,
+    pub time_dim: Option<i32>,
+    pub dim1_type: Option<String>,
+    pub dim1: Option<String>,
+    pub dim2_type: Option<String>,
+    pub dim2: Option<String>,
+    pub numeric_value: Option<f64>,
+    pub low: Option<f64>,
+    pub high: Option<f64>,
+}
+
+impl WhoGhoRow {
+    /// Value of the first dimension whose type matches `dim_type` (e.g. `SEX`, `AGEGROUP`).
+    pub fn dimension(&self, dim_type: &str) -> Option<&str> {
+        [(&self.dim1_type, &self.dim1), (&self.dim2_type, &self.dim2)]
+            .into_iter()
+            .find(|(kind, _)| kind.as_deref() == Some(dim_type))
+            .and_then(|(_, value)| value.as_deref())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+    }
+}
+
+pub struct WhoGhoClient {
+    client: reqwest_middleware::ClientWithMiddleware,
+    base: Cow<'static, str>,
+}
+
+impl WhoGhoClient {
+    pub fn new() -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::shared_client()?,
+            base: crate::sources::env_base(WHO_GHO_BASE, WHO_GHO_BASE_ENV),
+        })
+    }
+
+    #[cfg(test)]
+    fn new_for_test(base: String) -> Result<Self, BioMcpError> {
+        Ok(Self {
+            client: crate::sources::test_client()?,
+            base: Cow::Owned(base),
+        })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base.as_ref().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+    ) -> Result<T, BioMcpError> {
+        let resp = crate::sources::apply_cache_mode(req).send().await?;
+        let status = resp.status();
+        let content_type = resp.headers().get(reqwest::header::CONTENT_TYPE).cloned();
+        let bytes = crate::sources::read_limited_body(resp, WHO_GHO_API).await?;
+
+        if !status.is_success() {
+            let excerpt = crate::sources::body_excerpt(&bytes);
+            return Err(BioMcpError::Api {
+                api: WHO_GHO_API.to_string(),
+                message: format!("HTTP {status}: {excerpt}"),
+            });
+        }
+
+        crate::sources::ensure_json_content_type(WHO_GHO_API, content_type.as_ref(), &bytes)?;
+        serde_json::from_slice(&bytes).map_err(|source| BioMcpError::ApiJson {
+            api: WHO_GHO_API.to_string(),
+            source,
+        })
+    }
+
+    /// Fetches WHO-region and global rows for one indicator.
+    pub async fn regional_rows(&self, code: &str) -> Result<Vec<WhoGhoRow>, BioMcpError> {
+        let code = code.trim();
+        if code.is_empty()
+            || !code
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+        {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "Invalid WHO GHO indicator code: {code:?}"
+            )));
+        }
+
+        let req = self
+            .client
+            .get(self.endpoint(code))
+            .query(&[("$filter", WHO_GHO_REGION_FILTER)]);
+        let response: WhoGhoResponse = self.get_json(req).await?;
+        Ok(response.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn disease(name: &str) -> Disease {
+        serde_json::from_value(serde_json::json!({"id": "MONDO:0000001", "name": name}))
+            .expect("disease")
+    }
+
+    #[test]
+    fn resolve_indicators_matches_name_and_synonyms() {
+        let mut disease = disease("tuberculosis");
+        let codes = resolve_indicators(&disease)
+            .into_iter()
+            .map(|indicator| indicator.code)
+            .collect::<Vec<_>>();
+        assert_eq!(codes, vec!["MDG_0000000020"]);
+
+        disease.name = "human immunodeficiency virus infectious disease".into();
+        assert_eq!(resolve_indicators(&disease).len(), 2);
+
+        disease.name = "essential hypertension".into();
+        disease.synonyms = vec!["Type 2 Diabetes Mellitus".into()];
+        let codes = resolve_indicators(&disease)
+            .into_iter()
+            .map(|indicator| indicator.code)
+            .collect::<Vec<_>>();
+        assert_eq!(codes, vec!["NCD_GLUC_04", "NCD_HYP_PREVALENCE_A"]);
+
+        disease.name = "melanoma".into();
+        disease.synonyms.clear();
+        assert!(resolve_indicators(&disease).is_empty());
+    }
+
+    #[test]
+    fn dimension_reads_either_dimension_slot() {
+        let row = WhoGhoRow {
+            spatial_dim_type: Some("REGION".into()),
+            spatial_dim: Some("AFR".into()),
+            time_dim: Some(2022),
+            dim1_type: Some("SEX".into()),
+            dim1: Some("SEX_BTSX".into()),
+            dim2_type: Some("AGEGROUP".into()),
+            dim2: Some("AGE30-79".into()),
+            numeric_value: Some(35.5),
+            low: None,
+            high: None,
+        };
+        assert_eq!(row.dimension("SEX"), Some("SEX_BTSX"));
+        assert_eq!(row.dimension("AGEGROUP"), Some("AGE30-79"));
+        assert_eq!(row.dimension("RESIDENCEAREATYPE"), None);
+        assert_eq!(region_label("SEAR"), "South-East Asia");
+    }
+
+    #[tokio::test]
+    async fn regional_rows_filters_to_regions_and_parses_odata_values() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/MDG_0000000020"))
+            .and(query_param("$filter", WHO_GHO_REGION_FILTER))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "@odata.context": "https://ghoapi.azureedge.net/api/$metadata#MDG_0000000020",
+                "value": [
+                    {
+                        "Id": 1,
+                        "IndicatorCode": "MDG_0000000020",
+                        "SpatialDimType": "REGION",
+                        "SpatialDim": "SEAR",
+                        "TimeDimType": "YEAR",
+                        "TimeDim": 2022,
+                        "Dim1Type": null,
+                        "Dim1": null,
+                        "NumericValue": 246.0,
+                        "Low": 210.0,
+                        "High": 285.0
+                    }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = WhoGhoClient::new_for_test(server.uri()).expect("client");
+        let rows = client.regional_rows("MDG_0000000020").await.expect("rows");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].spatial_dim.as_deref(), Some("SEAR"));
+        assert_eq!(rows[0].time_dim, Some(2022));
+        assert_eq!(rows[0].numeric_value, Some(246.0));
+        assert_eq!(rows[0].high, Some(285.0));
+    }
+
+    #[tokio::test]
+    async fn regional_rows_rejects_unsafe_indicator_codes() {
+        let client = WhoGhoClient::new_for_test("http://127.0.0.1:9".into()).expect("client");
+        let err = client
+            .regional_rows("MDG?$top=1")
+            .await
+            .expect_err("unsafe code");
+        assert!(err.to_string().contains("Invalid WHO GHO indicator code"));
+    }
+}
@@ -1,11 +1,13 @@
 use std::collections::HashSet;
 
 use crate::entities::adverse_event::{
-    AdverseEvent, AdverseEventSearchResult, DeviceEvent, DeviceEventSearchResult,
-    RecallSearchResult,
+    AdverseEvent, AdverseEventSearchResult, DeviceClassification, DeviceClearance,
+    DeviceClearanceSearchResult, DeviceEvent, DeviceEventSearchResult, RecallSearchResult,
+    VeterinaryEventSearchResult,
 };
 use crate::sources::openfda::{
-    DeviceEventResult, EnforcementResult, FaersEventResult, FaersPatient,
+    Device510kResult, DeviceClassificationResult, DeviceEventResult, EnforcementResult,
+    FaersEventResult, FaersPatient, VeterinaryEventResult,
 };
 
 fn normalize_drug_name(value: &str) -> String {
@@ -475,11 +477,191 @@ pub fn from_openfda_device_get_result(r: &DeviceEventResult) -> DeviceEvent {
     }
 }
 
+fn clean_text(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+}
+
+/// openFDA publishes device classes as `1`/`2`/`3`, with `U` and `N` for
+/// unclassified products.
+fn device_class_label(value: Option<&str>) -> Option<String> {
+    let value = value.map(str::trim).filter(|v| !v.is_empty())?;
+    Some(match value {
+        "1" => "Class I".to_string(),
+        "2" => "Class II".to_string(),
+        "3" => "Class III".to_string(),
+        "U" | "N" => "Unclassified".to_string(),
+        other => other.to_string(),
+    })
+}
+
+fn yes_no_flag(value: Option<&str>) -> Option<bool> {
+    match value.map(str::trim)?.to_ascii_uppercase().as_str() {
+        "Y" => Some(true),
+        "N" => Some(false),
+        _ => None,
+    }
+}
+
+fn clearance_device_name(r: &Device510kResult) -> String {
+    clean_text(r.device_name.as_deref()).unwrap_or_else(|| "unknown".to_string())
+}
+
+pub fn from_openfda_510k_search_result(r: &Device510kResult) -> DeviceClearanceSearchResult {
+    DeviceClearanceSearchResult {
+        k_number: r.k_number.trim().to_ascii_uppercase(),
+        device_name: clearance_device_name(r),
+        applicant: clean_text(r.applicant.as_deref()),
+        product_code: clean_text(r.product_code.as_deref()),
+        device_class: device_class_label(
+            r.openfda
+                .as_ref()
+                .and_then(|openfda| openfda.device_class.as_deref()),
+        ),
+        decision: clean_text(r.decision_description.as_deref()),
+        decision_date: clean_text(r.decision_date.as_deref()),
+    }
+}
+
+pub fn from_openfda_510k_result(r: &Device510kResult) -> DeviceClearance {
+    DeviceClearance {
+        k_number: r.k_number.trim().to_ascii_uppercase(),
+        device_name: clearance_device_name(r),
+        applicant: clean_text(r.applicant.as_deref()),
+        product_code: clean_text(r.product_code.as_deref()),
+        decision: clean_text(r.decision_description.as_deref()),
+        decision_date: clean_text(r.decision_date.as_deref()),
+        date_received: clean_text(r.date_received.as_deref()),
+        clearance_type: clean_text(r.clearance_type.as_deref()),
+        review_panel: clean_text(r.advisory_committee_description.as_deref()),
+        summary_type: clean_text(r.statement_or_summary.as_deref()),
+        third_party_review: yes_no_flag(r.third_party_flag.as_deref()),
+        expedited_review: yes_no_flag(r.expedited_review_flag.as_deref()),
+        classification: None,
+    }
+}
+
+pub fn from_openfda_device_classification(r: &DeviceClassificationResult) -> DeviceClassification {
+    DeviceClassification {
+        product_code: r.product_code.trim().to_ascii_uppercase(),
+        device_name: clean_text(r.device_name.as_deref()),
+        device_class: device_class_label(r.device_class.as_deref()),
+        regulation_number: clean_text(r.regulation_number.as_deref()),
+        medical_specialty: clean_text(r.medical_specialty_description.as_deref()),
+        definition: truncate_text(clean_text(r.definition.as_deref()), 1200),
+        implant: yes_no_flag(r.implant_flag.as_deref()),
+        life_sustaining: yes_no_flag(r.life_sustain_support_flag.as_deref()),
+        gmp_exempt: yes_no_flag(r.gmp_exempt_flag.as_deref()),
+        third_party_eligible: yes_no_flag(r.third_party_flag.as_deref()),
+    }
+}
+
+fn pick_veterinary_drug(r: &VeterinaryEventResult, requested_drug: Option<&str>) -> String {
+    let names = r
+        .drug
+        .iter()
+        .flat_map(|drug| {
+            drug.active_ingredients
+                .iter()
+                .filter_map(|ingredient| clean_text(ingredient.name.as_deref()))
+                .chain(clean_text(drug.brand_name.as_deref()))
+        })
+        .collect::<Vec<_>>();
+    requested_drug
+        .and_then(|query| {
+            names
+                .iter()
+                .find(|name| drug_name_matches_query(name, query))
+        })
+        .or_else(|| names.first())
+        .cloned()
+        .unwrap_or_else(|| "unknown".into())
+}
+
+fn veterinary_serious(value: Option<&serde_json::Value>) -> bool {
+    match value {
+        Some(serde_json::Value::Bool(v)) => *v,
+        Some(serde_json::Value::String(v)) => v.trim().eq_ignore_ascii_case("true"),
+        _ => false,
+    }
+}
+
+pub fn from_openfda_veterinary_search_result(
+    r: &VeterinaryEventResult,
+    requested_drug: Option<&str>,
+) -> VeterinaryEventSearchResult {
+    let mut reactions: Vec<String> = Vec::new();
+    for name in r
+        .reaction
+        .iter()
+        .filter_map(|reaction| clean_text(reaction.veddra_term_name.as_deref()))
+    {
+        if reactions.len() >= 3 {
+            break;
+        }
+        if !reactions
+            .iter()
+            .any(|seen| seen.eq_ignore_ascii_case(&name))
+        {
+            reactions.push(name);
+        }
+    }
+
+    VeterinaryEventSearchResult {
+        report_id: clean_text(r.unique_aer_id_number.as_deref())
+            .or_else(|| clean_text(r.report_id.as_deref()))
+            .unwrap_or_else(|| "unknown".into()),
+        drug: pick_veterinary_drug(r, requested_drug),
+        species: r
+            .animal
+            .as_ref()
+            .and_then(|animal| clean_text(animal.species.as_deref())),
+        reactions,
+        serious: veterinary_serious(r.serious_ae.as_ref()),
+        date: normalize_date_yyyymmdd(r.original_receive_date.as_deref()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::sources::openfda::{FaersDrug, FaersOpenFdaDrug, FaersReaction};
 
+    #[test]
+    fn veterinary_search_result_prefers_requested_ingredient() {
+        let report: VeterinaryEventResult = serde_json::from_value(serde_json::json!({
+            "unique_aer_id_number": "USA-USFDACVM-2019-US-012345",
+            "original_receive_date": "20190412",
+            "serious_ae": "true",
+            "animal": {"species": "Dog"},
+            "drug": [
+                {"brand_name": "Heartgard", "active_ingredients": [{"name": "Ivermectin"}]},
+                {"brand_name": "Rimadyl", "active_ingredients": [{"name": "Carprofen"}]}
+            ],
+            "reaction": [
+                {"veddra_term_name": "Vomiting"},
+                {"veddra_term_name": "vomiting"},
+                {"veddra_term_name": "Lethargy"}
+            ]
+        }))
+        .expect("report");
+
+        let row = from_openfda_veterinary_search_result(&report, Some("carprofen"));
+        assert_eq!(row.report_id, "USA-USFDACVM-2019-US-012345");
+        assert_eq!(row.drug, "Carprofen");
+        assert_eq!(row.species.as_deref(), Some("Dog"));
+        assert_eq!(row.reactions, vec!["Vomiting", "Lethargy"]);
+        assert!(row.serious);
+        assert_eq!(row.date.as_deref(), Some("2019-04-12"));
+
+        let row = from_openfda_veterinary_search_result(&report, Some("meloxicam"));
+        assert_eq!(row.drug, "Ivermectin");
+        assert!(!veterinary_serious(Some(&serde_json::json!("false"))));
+        assert!(veterinary_serious(Some(&serde_json::json!(true))));
+    }
+
     #[test]
     fn normalize_drug_name_trims_and_lowercases() {
         assert_eq!(normalize_drug_name("  Pembrolizumab "), "pembrolizumab");
@@ -589,4 +771,44 @@ mod tests {
         let row = from_openfda_faers_search_result(&report, Some("metformin"));
         assert_eq!(row.drug, "metformin");
     }
+
+    #[test]
+    fn from_openfda_510k_result_labels_class_and_flags() {
+        let row: Device510kResult = serde_json::from_value(serde_json::json!({
+            "k_number": "k213456",
+            "device_name": " Continuous Glucose Monitor ",
+            "applicant": "Dexcom, Inc.",
+            "product_code": "QBJ",
+            "decision_description": "Substantially Equivalent",
+            "decision_date": "2021-11-03",
+            "clearance_type": "Traditional",
+            "third_party_flag": "N",
+            "expedited_review_flag": "",
+            "openfda": {"device_class": "2", "regulation_number": "862.1355"}
+        }))
+        .expect("510(k) row");
+
+        let summary = from_openfda_510k_search_result(&row);
+        assert_eq!(summary.k_number, "K213456");
+        assert_eq!(summary.device_class.as_deref(), Some("Class II"));
+
+        let clearance = from_openfda_510k_result(&row);
+        assert_eq!(clearance.device_name, "Continuous Glucose Monitor");
+        assert_eq!(clearance.third_party_review, Some(false));
+        assert_eq!(clearance.expedited_review, None);
+
+        let classification: DeviceClassificationResult =
+            serde_json::from_value(serde_json::json!({
+                "product_code": "QBJ",
+                "device_class": "U",
+                "implant_flag": "Y",
+                "life_sustain_support_flag": "N"
+            }))
+            .expect("classification row");
+        let classification = from_openfda_device_classification(&classification);
+        assert_eq!(classification.device_class.as_deref(), Some("Unclassified"));
+        assert_eq!(classification.implant, Some(true));
+        assert_eq!(classification.life_sustaining, Some(false));
+        assert_eq!(classification.gmp_exempt, None);
+    }
 }
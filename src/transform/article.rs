@@ -7,11 +7,11 @@ mod jats;
 
 pub use self::anchors::{
     article_search_abstract_snippet, article_search_fallback_title, clean_abstract, clean_title,
-    normalize_article_search_text,
+    extract_text_from_html, normalize_article_search_text,
 };
 #[allow(unused_imports)]
 pub use self::anchors::{truncate_abstract, truncate_authors};
-pub use self::annotations::extract_annotations;
+pub use self::annotations::{attach_trial_ids, extract_annotations};
 pub use self::federation::{
     from_europepmc_result, from_europepmc_search_result, from_pubmed_esummary_entry,
     from_pubtator_document, from_pubtator_search_result, merge_europepmc_metadata,
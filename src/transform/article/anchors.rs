@@ -37,6 +37,35 @@ fn strip_inline_html_tags(value: &str) -> String {
     re.replace_all(value, "").to_string()
 }
 
+/// Plain text from an HTML article page. Scripts, styles, and navigation are
+/// dropped and block elements become paragraph breaks, so the saved copy reads
+/// like the JATS rendering rather than one run-on line.
+pub fn extract_text_from_html(html: &str) -> String {
+    static SKIPPED_RE: OnceLock<Regex> = OnceLock::new();
+    static BLOCK_RE: OnceLock<Regex> = OnceLock::new();
+    let skipped = SKIPPED_RE.get_or_init(|| {
+        Regex::new(
+            r"(?is)<!--.*?-->|<script\b.*?</script>|<style\b.*?</style>|<head\b.*?</head>|<nav\b.*?</nav>|<noscript\b.*?</noscript>",
+        )
+        .expect("valid skipped-element regex")
+    });
+    let block = BLOCK_RE.get_or_init(|| {
+        Regex::new(
+            r"(?i)<br\s*/?>|</?(?:p|div|h[1-6]|li|tr|section|article|table|ul|ol|blockquote|figure|figcaption)\b[^>]*>",
+        )
+        .expect("valid block-element regex")
+    });
+
+    let without_skipped = skipped.replace_all(html, " ");
+    let with_breaks = block.replace_all(&without_skipped, "\n");
+    let text = decode_html_entities(&strip_inline_html_tags(&with_breaks));
+    text.lines()
+        .map(collapse_whitespace)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
 fn normalize_compound_hyphens(value: &str) -> String {
     static COMPOUND_HYPHEN_RE: OnceLock<Regex> = OnceLock::new();
     let re = COMPOUND_HYPHEN_RE
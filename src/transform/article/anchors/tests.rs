@@ -48,3 +48,18 @@ fn truncate_authors_first_last() {
     ];
     assert_eq!(truncate_authors(&authors), vec!["A", "E"]);
 }
+
+#[test]
+fn extract_text_from_html_keeps_paragraphs_and_drops_page_chrome() {
+    let html = r#"<html><head><title>Ignored</title><style>p { color: red; }</style></head>
+<body><nav>Home | Journals</nav>
+<h1>KRAS&nbsp;G12C inhibition</h1>
+<p>Sotorasib   showed <i>durable</i> responses.<br>Median PFS was 6.8 months &amp; rising.</p>
+<script>trackPageView();</script>
+<div>Funding: none.</div></body></html>"#;
+
+    assert_eq!(
+        extract_text_from_html(html),
+        "KRAS G12C inhibition\n\nSotorasib showed durable responses.\n\nMedian PFS was 6.8 months & rising.\n\nFunding: none."
+    );
+}
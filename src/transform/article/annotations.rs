@@ -1,10 +1,15 @@
 //! PubTator annotation aggregation for article detail views.
 
 use std::collections::HashMap;
+use std::sync::OnceLock;
 
-use crate::entities::article::{AnnotationCount, ArticleAnnotations};
+use regex::Regex;
+
+use crate::entities::article::{AnnotationCount, Article, ArticleAnnotations};
 use crate::sources::pubtator::PubTatorDocument;
 
+const MAX_ARTICLE_TRIAL_IDS: usize = 8;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum AnnotationKind {
     Gene,
@@ -95,17 +100,53 @@ pub fn extract_annotations(doc: &PubTatorDocument) -> Option<ArticleAnnotations>
         diseases: finalize_counts(diseases),
         chemicals: finalize_counts(chemicals),
         mutations: finalize_counts(mutations),
+        trials: Vec::new(),
     };
 
-    if annotations.genes.is_empty()
-        && annotations.diseases.is_empty()
-        && annotations.chemicals.is_empty()
-        && annotations.mutations.is_empty()
-    {
-        None
-    } else {
-        Some(annotations)
+    annotations.has_entities().then_some(annotations)
+}
+
+fn nct_id_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)\bNCT\s?(\d{8})\b").expect("valid regex"))
+}
+
+/// Returns distinct NCT IDs in order of first mention.
+fn extract_nct_ids(text: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for caps in nct_id_re().captures_iter(text) {
+        let id = format!("NCT{}", &caps[1]);
+        if !out.contains(&id) {
+            out.push(id);
+        }
+        if out.len() >= MAX_ARTICLE_TRIAL_IDS {
+            break;
+        }
+    }
+    out
+}
+
+/// Adds NCT IDs cited in the title or abstract to the article annotations.
+pub fn attach_trial_ids(article: &mut Article) {
+    let text = format!(
+        "{}\n{}",
+        article.title,
+        article.abstract_text.as_deref().unwrap_or_default()
+    );
+    let trials = extract_nct_ids(&text);
+    if trials.is_empty() {
+        return;
     }
+    article
+        .annotations
+        .get_or_insert_with(|| ArticleAnnotations {
+            genes: Vec::new(),
+            diseases: Vec::new(),
+            chemicals: Vec::new(),
+            mutations: Vec::new(),
+            trials: Vec::new(),
+        })
+        .trials = trials;
 }
 
 #[cfg(test)]
@@ -107,3 +107,29 @@ fn extract_annotations_preserves_first_seen_order_for_equal_counts() {
         ]
     );
 }
+
+#[test]
+fn extract_nct_ids_normalizes_and_dedupes_in_mention_order() {
+    assert_eq!(
+        extract_nct_ids(
+            "Registered at ClinicalTrials.gov (NCT01844505; nct 02224781). See also NCT01844505."
+        ),
+        vec!["NCT01844505", "NCT02224781"]
+    );
+    assert!(extract_nct_ids("NCT123 and NCT0184450512").is_empty());
+}
+
+#[test]
+fn attach_trial_ids_creates_annotations_for_abstract_mentions() {
+    let mut article: Article = serde_json::from_value(serde_json::json!({
+        "pmid": "26027431",
+        "title": "Combined Nivolumab and Ipilimumab in Untreated Melanoma",
+        "abstract_text": "... (Funded by Bristol-Myers Squibb; CheckMate 067 ClinicalTrials.gov number, NCT01844505.)"
+    }))
+    .expect("article");
+
+    attach_trial_ids(&mut article);
+    let annotations = article.annotations.expect("annotations");
+    assert_eq!(annotations.trials, vec!["NCT01844505"]);
+    assert!(!annotations.has_entities());
+}
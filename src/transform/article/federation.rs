@@ -54,8 +54,10 @@ pub fn from_pubtator_document(doc: &PubTatorDocument) -> Article {
             .filter(|t| !t.is_empty()),
         full_text_path: None,
         full_text_note: None,
+        full_text_source: None,
         annotations: None,
         semantic_scholar: None,
+        citation_graph: None,
         pubtator_fallback: false,
     }
 }
@@ -219,8 +221,10 @@ pub fn from_europepmc_result(hit: &EuropePmcResult) -> Article {
             .filter(|text| !text.is_empty()),
         full_text_path: None,
         full_text_note: None,
+        full_text_source: None,
         annotations: None,
         semantic_scholar: None,
+        citation_graph: None,
         pubtator_fallback: false,
     }
 }
@@ -297,6 +301,8 @@ pub fn from_europepmc_search_result(hit: &EuropePmcResult) -> Option<ArticleSear
         source: ArticleSource::EuropePmc,
         score: None,
         is_retracted: Some(is_retracted_publication(hit)),
+        journal_rank: None,
+        preprint: None,
         abstract_snippet: abstract_text
             .as_deref()
             .and_then(article_search_abstract_snippet),
@@ -341,6 +347,8 @@ pub fn from_pubtator_search_result(hit: &PubTatorSearchResult) -> Option<Article
         source: ArticleSource::PubTator,
         score: hit.score,
         is_retracted: None,
+        journal_rank: None,
+        preprint: None,
         abstract_snippet: None,
         ranking: None,
         matched_sources: vec![ArticleSource::PubTator],
@@ -450,6 +458,8 @@ pub fn from_pubmed_esummary_entry(entry: &ESummaryEntry) -> Option<ArticleSearch
         matched_sources: vec![ArticleSource::PubMed],
         score: None,
         is_retracted: None,
+        journal_rank: None,
+        preprint: None,
         abstract_snippet: None,
         ranking: None,
         normalized_title: normalize_article_search_text(&title),
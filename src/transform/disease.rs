@@ -721,6 +721,10 @@ pub fn from_mydisease_hit(hit: MyDiseaseHit) -> Disease {
         prevalence_note: None,
         survival: None,
         survival_note: None,
+        epidemiology: None,
+        epidemiology_note: None,
+        therapies: None,
+        therapies_note: None,
         funding: None,
         funding_note: None,
         civic: None,
@@ -1023,6 +1027,10 @@ mod tests {
             prevalence_note: None,
             survival: None,
             survival_note: None,
+            epidemiology: None,
+            epidemiology_note: None,
+            therapies: None,
+            therapies_note: None,
             civic: None,
             disgenet: None,
             funding: None,
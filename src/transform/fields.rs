@@ -0,0 +1,256 @@
+//! Deployment field filtering for entity output.
+//!
+//! `[fields.<entity>]` tables in `config.toml` carry an optional `allow` list
+//! and a `deny` list of `--json` field paths. Dotted paths reach into nested
+//! objects and apply to every element of an array (`label.warnings`). Filters
+//! run on the entity model before rendering, so markdown and JSON output drop
+//! the same fields.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::config::{FieldFilterToml, invalid_config};
+use crate::error::BioMcpError;
+
+/// Entity names accepted as `[fields.<entity>]` keys.
+pub(crate) const FIELD_FILTER_ENTITIES: &[&str] = &[
+    "adverse-event",
+    "article",
+    "device-clearance",
+    "disease",
+    "drug",
+    "gene",
+    "hla",
+    "pathway",
+    "pgx",
+    "protein",
+    "structure",
+    "transcript",
+    "trial",
+    "variant",
+];
+
+/// Applies the configured field filter for `entity`, if any.
+pub(crate) fn apply<T>(entity: &str, value: T) -> Result<T, BioMcpError>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let config = crate::config::load_user_config()?;
+    let path = crate::config::config_file_path();
+    apply_filters(&config.fields, path.as_deref(), entity, value)
+}
+
+fn apply_filters<T>(
+    filters: &BTreeMap<String, FieldFilterToml>,
+    path: Option<&Path>,
+    entity: &str,
+    value: T,
+) -> Result<T, BioMcpError>
+where
+    T: Serialize + DeserializeOwned,
+{
+    if let Some(unknown) = filters
+        .keys()
+        .find(|key| !FIELD_FILTER_ENTITIES.contains(&key.as_str()))
+    {
+        return Err(invalid_config(
+            path,
+            format!(
+                "unknown [fields.{unknown}] entity; expected one of: {}",
+                FIELD_FILTER_ENTITIES.join(", ")
+            ),
+        ));
+    }
+    let Some(filter) = filters.get(entity) else {
+        return Ok(value);
+    };
+    let allow = filter
+        .allow
+        .as_deref()
+        .map(|allow| parse_paths(allow, entity, path))
+        .transpose()?;
+    let deny = parse_paths(&filter.deny, entity, path)?;
+    if allow.is_none() && deny.is_empty() {
+        return Ok(value);
+    }
+
+    let mut json = serde_json::to_value(&value)?;
+    if let Some(allow) = &allow {
+        let allow = allow.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        retain_paths(&mut json, &allow);
+    }
+    for field in &deny {
+        remove_path(&mut json, field);
+    }
+    serde_json::from_value(json).map_err(|err| {
+        invalid_config(
+            path,
+            format!("[fields.{entity}] removes a field that {entity} output requires: {err}"),
+        )
+    })
+}
+
+fn parse_paths(
+    fields: &[String],
+    entity: &str,
+    path: Option<&Path>,
+) -> Result<Vec<Vec<String>>, BioMcpError> {
+    fields
+        .iter()
+        .map(|field| {
+            let segments = field
+                .split('.')
+                .map(|segment| segment.trim().to_string())
+                .collect::<Vec<_>>();
+            if segments.iter().any(String::is_empty) {
+                return Err(invalid_config(
+                    path,
+                    format!("[fields.{entity}] has an invalid field path `{field}`"),
+                ));
+            }
+            Ok(segments)
+        })
+        .collect()
+}
+
+fn retain_paths(value: &mut Value, paths: &[&[String]]) {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                retain_paths(item, paths);
+            }
+        }
+        Value::Object(map) => map.retain(|key, child| {
+            let nested = paths
+                .iter()
+                .filter_map(|path| path.split_first())
+                .filter(|(head, _)| *head == key)
+                .map(|(_, rest)| rest)
+                .collect::<Vec<_>>();
+            if nested.is_empty() {
+                return false;
+            }
+            if !nested.iter().any(|rest| rest.is_empty()) {
+                retain_paths(child, &nested);
+            }
+            true
+        }),
+        _ => {}
+    }
+}
+
+fn remove_path(value: &mut Value, path: &[String]) {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                remove_path(item, path);
+            }
+        }
+        Value::Object(map) => {
+            let Some((head, rest)) = path.split_first() else {
+                return;
+            };
+            if rest.is_empty() {
+                map.remove(head);
+            } else if let Some(child) = map.get_mut(head) {
+                remove_path(child, rest);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::config::parse_user_config;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Card {
+        id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        narrative: Option<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        reports: Vec<Report>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Report {
+        date: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+    }
+
+    fn card() -> Card {
+        Card {
+            id: "1".into(),
+            name: Some("aspirin".into()),
+            narrative: Some("patient narrative".into()),
+            reports: vec![Report {
+                date: "2026-01-01".into(),
+                text: Some("free text".into()),
+            }],
+        }
+    }
+
+    fn filter(toml: &str, entity: &str) -> Result<Card, BioMcpError> {
+        let config = parse_user_config(toml, None).expect("config should parse");
+        apply_filters(&config.fields, None, entity, card())
+    }
+
+    #[test]
+    fn deny_removes_top_level_and_nested_array_fields() {
+        let card = filter(
+            "[fields.adverse-event]\ndeny = [\"narrative\", \"reports.text\"]\n",
+            "adverse-event",
+        )
+        .expect("filter should apply");
+        assert!(card.narrative.is_none());
+        assert_eq!(card.name.as_deref(), Some("aspirin"));
+        assert_eq!(card.reports[0].date, "2026-01-01");
+        assert!(card.reports[0].text.is_none());
+    }
+
+    #[test]
+    fn allow_keeps_listed_paths_and_deny_applies_after() {
+        let card = filter(
+            "[fields.gene]\nallow = [\"id\", \"name\", \"reports.date\"]\ndeny = [\"name\"]\n",
+            "gene",
+        )
+        .expect("filter should apply");
+        assert!(card.name.is_none());
+        assert!(card.narrative.is_none());
+        assert_eq!(card.reports.len(), 1);
+        assert!(card.reports[0].text.is_none());
+    }
+
+    #[test]
+    fn filters_for_other_entities_leave_output_unchanged() {
+        let card = filter("[fields.drug]\ndeny = [\"narrative\"]\n", "gene")
+            .expect("unfiltered entity should pass through");
+        assert_eq!(card.narrative.as_deref(), Some("patient narrative"));
+    }
+
+    #[test]
+    fn removing_required_fields_or_unknown_entities_is_a_config_error() {
+        let err = filter("[fields.gene]\ndeny = [\"id\"]\n", "gene")
+            .expect_err("required field removal should fail");
+        assert!(err.to_string().contains("[fields.gene] removes a field"));
+
+        let err = filter("[fields.genes]\ndeny = [\"id\"]\n", "gene")
+            .expect_err("unknown entity should fail");
+        assert!(err.to_string().contains("unknown [fields.genes] entity"));
+
+        let err = filter("[fields.gene]\ndeny = [\"reports..text\"]\n", "gene")
+            .expect_err("empty path segment should fail");
+        assert!(err.to_string().contains("invalid field path"));
+    }
+}
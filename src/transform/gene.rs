@@ -1,4 +1,4 @@
-use crate::entities::gene::{Gene, GenePathway, GeneSearchResult};
+use crate::entities::gene::{Gene, GenePathway, GeneSearchResult, GeneSpecies};
 use crate::sources::mygene::{MyGeneGetResponse, MyGeneHit};
 
 fn normalize_summary(summary: Option<String>) -> Option<String> {
@@ -143,7 +143,7 @@ fn format_genomic_coordinates(resp: &MyGeneGetResponse) -> Option<String> {
     Some(format!("{chr}:{start}-{end} (strand: {strand})"))
 }
 
-pub fn from_mygene_get(resp: MyGeneGetResponse) -> Gene {
+pub fn from_mygene_get(resp: MyGeneGetResponse, species: GeneSpecies) -> Gene {
     let genomic_coordinates = format_genomic_coordinates(&resp);
     let omim_id = extract_omim_id(resp.mim.as_ref());
     let uniprot_id = extract_uniprot_id(resp.uniprot.as_ref());
@@ -165,6 +165,7 @@ pub fn from_mygene_get(resp: MyGeneGetResponse) -> Gene {
         uniprot_id,
         summary: normalize_summary(resp.summary),
         gene_type: resp.type_of_gene,
+        species: species.flag_value(),
         aliases,
         clinical_diseases: Vec::new(),
         clinical_drugs: Vec::new(),
@@ -181,12 +182,18 @@ pub fn from_mygene_get(resp: MyGeneGetResponse) -> Gene {
         clingen: None,
         constraint: None,
         disgenet: None,
+        clinvar_summary: None,
+        tractability: None,
         funding: None,
         funding_note: None,
+        key_papers: None,
+        dependency: None,
+        orthologs: None,
+        plugin_sections: Vec::new(),
     }
 }
 
-pub fn from_mygene_hit(hit: &MyGeneHit) -> GeneSearchResult {
+pub fn from_mygene_hit(hit: &MyGeneHit, species: GeneSpecies) -> GeneSearchResult {
     let genomic_coordinates = hit.genomic_pos.as_ref().and_then(|pos| {
         let chr = pos.chr()?.trim();
         let start = pos.start()?;
@@ -208,6 +215,7 @@ pub fn from_mygene_hit(hit: &MyGeneHit) -> GeneSearchResult {
         genomic_coordinates,
         uniprot_id: extract_uniprot_id(hit.uniprot.as_ref()),
         omim_id: extract_omim_id(hit.mim.as_ref()),
+        species: species.flag_value(),
     }
 }
 
@@ -291,7 +299,7 @@ mod tests {
         }))
         .expect("valid MyGene response");
 
-        let gene = from_mygene_get(resp);
+        let gene = from_mygene_get(resp, GeneSpecies::Human);
         assert_eq!(gene.symbol, "EGFR");
         assert_eq!(gene.entrez_id, "1956");
         assert_eq!(gene.uniprot_id.as_deref(), Some("P00533"));
@@ -313,7 +321,7 @@ mod tests {
         }))
         .expect("valid MyGene response");
 
-        let gene = from_mygene_get(resp);
+        let gene = from_mygene_get(resp, GeneSpecies::Human);
         assert_eq!(gene.symbol, "BRCA1");
         assert_eq!(gene.ensembl_id.as_deref(), Some("ENSG00000012048"));
         assert_eq!(gene.omim_id.as_deref(), Some("113705"));
@@ -335,9 +343,37 @@ mod tests {
         }))
         .expect("valid MyGene response");
 
-        let gene = from_mygene_get(resp);
+        let gene = from_mygene_get(resp, GeneSpecies::Human);
         assert_eq!(gene.symbol, "TP53");
         assert_eq!(gene.aliases, vec!["P53", "BCC7", "LFS1"]);
         assert_eq!(gene.uniprot_id.as_deref(), Some("P04637"));
+        assert_eq!(gene.species, None);
+    }
+
+    #[test]
+    fn non_human_gene_records_species_flag() {
+        let resp: MyGeneGetResponse = serde_json::from_value(serde_json::json!({
+            "symbol": "Braf",
+            "name": "Braf transforming gene",
+            "entrezgene": 109880,
+            "type_of_gene": "protein-coding",
+            "genomic_pos": {"chr": "6", "start": 39600728, "end": 39725191, "strand": -1},
+            "uniprot": {"Swiss-Prot": "P28028"}
+        }))
+        .expect("valid MyGene response");
+
+        let gene = from_mygene_get(resp, GeneSpecies::Mouse);
+        assert_eq!(gene.symbol, "Braf");
+        assert_eq!(gene.species.as_deref(), Some("mouse"));
+        assert_eq!(gene.uniprot_id.as_deref(), Some("P28028"));
+
+        let hit: MyGeneHit = serde_json::from_value(serde_json::json!({
+            "symbol": "braf",
+            "name": "v-raf murine sarcoma viral oncogene homolog B",
+            "entrezgene": 798010
+        }))
+        .expect("valid MyGene hit");
+        let result = from_mygene_hit(&hit, GeneSpecies::Zebrafish);
+        assert_eq!(result.species.as_deref(), Some("zebrafish"));
     }
 }
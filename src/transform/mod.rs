@@ -4,8 +4,10 @@ pub(crate) mod adverse_event;
 pub(crate) mod article;
 pub(crate) mod disease;
 pub(crate) mod drug;
+pub(crate) mod fields;
 pub(crate) mod gene;
 pub(crate) mod pathway;
 pub(crate) mod protein;
+pub(crate) mod study;
 pub(crate) mod trial;
 pub(crate) mod variant;
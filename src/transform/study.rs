@@ -0,0 +1,64 @@
+use crate::entities::study::{Study, StudyMutatedGene, StudySearchResult};
+use crate::sources::cbioportal::{CBioCancerStudy, CBioMutatedGene};
+use crate::utils::text::non_empty;
+
+fn cancer_type_name(study: &CBioCancerStudy) -> Option<String> {
+    non_empty(
+        study
+            .cancer_type
+            .as_ref()
+            .and_then(|cancer_type| cancer_type.name.as_deref()),
+    )
+}
+
+pub fn from_cbioportal_search_hit(study: &CBioCancerStudy) -> StudySearchResult {
+    StudySearchResult {
+        study_id: study.study_id.clone(),
+        name: study.name.trim().to_string(),
+        cancer_type: cancer_type_name(study),
+        sample_count: study.all_sample_count,
+        pmid: non_empty(study.pmid.as_deref()),
+    }
+}
+
+pub fn from_cbioportal_study(study: CBioCancerStudy) -> Study {
+    Study {
+        cancer_type: cancer_type_name(&study),
+        study_id: study.study_id,
+        name: study.name.trim().to_string(),
+        description: study
+            .description
+            .as_deref()
+            .map(crate::transform::article::clean_abstract)
+            .filter(|v| !v.is_empty()),
+        cancer_type_id: non_empty(study.cancer_type_id.as_deref()),
+        sample_count: study.all_sample_count,
+        sequenced_sample_count: study.sequenced_sample_count,
+        cna_sample_count: study.cna_sample_count,
+        citation: non_empty(study.citation.as_deref()),
+        pmid: non_empty(study.pmid.as_deref()),
+        reference_genome: non_empty(study.reference_genome.as_deref()),
+        mutated_genes: Vec::new(),
+        mutated_genes_note: None,
+    }
+}
+
+/// Frequency is mutated samples over profiled samples, falling back to the
+/// study's sequenced sample count when the gene row omits it.
+pub fn from_cbioportal_mutated_gene(
+    row: &CBioMutatedGene,
+    sequenced_sample_count: Option<u32>,
+) -> StudyMutatedGene {
+    let mutated_samples = row.number_of_altered_cases.unwrap_or(0);
+    let profiled_samples = row
+        .number_of_profiled_cases
+        .filter(|count| *count > 0)
+        .or(sequenced_sample_count.filter(|count| *count > 0));
+    StudyMutatedGene {
+        gene: row.hugo_gene_symbol.trim().to_string(),
+        mutated_samples,
+        mutation_count: row.total_count,
+        profiled_samples,
+        frequency: profiled_samples.map(|total| f64::from(mutated_samples) / f64::from(total)),
+    }
+}
@@ -1,9 +1,18 @@
 use std::borrow::Cow;
 
 use crate::entities::trial::{
-    Trial, TrialArm, TrialLocation, TrialOutcome, TrialOutcomes, TrialReference, TrialSearchResult,
+    Trial, TrialAdverseEvent, TrialAdverseEventGroup, TrialAdverseEvents, TrialArm, TrialBaseline,
+    TrialBaselineMeasure, TrialLocation, TrialOutcome, TrialOutcomeAnalysis, TrialOutcomeResult,
+    TrialOutcomes, TrialReference, TrialRegistry, TrialResultGroup, TrialResultRow, TrialResults,
+    TrialSearchResult, normalize_registry_id,
 };
-use crate::sources::clinicaltrials::CtGovStudy;
+use crate::sources::clinicaltrials::{
+    CtGovAdverseEvent, CtGovResultClass, CtGovResultDenom, CtGovResultGroup,
+    CtGovResultMeasurement, CtGovStudy,
+};
+
+/// Serious and other adverse events kept per table, most frequent first.
+const ADVERSE_EVENT_LIMIT: usize = 20;
 
 fn truncate_utf8(s: &str, max_bytes: usize, suffix: &str) -> String {
     if s.len() <= max_bytes {
@@ -246,6 +255,339 @@ fn extract_references(study: &CtGovStudy) -> Option<Vec<TrialReference>> {
     (!out.is_empty()).then_some(out)
 }
 
+fn result_groups(groups: &[CtGovResultGroup]) -> Vec<TrialResultGroup> {
+    groups
+        .iter()
+        .filter_map(|group| {
+            let id = clean_opt(group.id.as_deref())?;
+            Some(TrialResultGroup {
+                title: clean_opt(group.title.as_deref()).unwrap_or_else(|| id.clone()),
+                id,
+                description: clean_opt(group.description.as_deref()),
+            })
+        })
+        .collect()
+}
+
+/// Looks up each group's value in `values`, using `-` for groups without one.
+fn align_to_groups<'a>(
+    groups: &[TrialResultGroup],
+    values: impl Iterator<Item = (Option<&'a str>, String)> + Clone,
+) -> Vec<String> {
+    groups
+        .iter()
+        .map(|group| {
+            values
+                .clone()
+                .find(|(id, _)| *id == Some(group.id.as_str()))
+                .map_or_else(|| "-".to_string(), |(_, value)| value)
+        })
+        .collect()
+}
+
+fn participant_counts(groups: &[TrialResultGroup], denoms: &[CtGovResultDenom]) -> Vec<String> {
+    let Some(denom) = denoms
+        .iter()
+        .find(|denom| {
+            denom
+                .units
+                .as_deref()
+                .is_none_or(|units| units.eq_ignore_ascii_case("participants"))
+        })
+        .or_else(|| denoms.first())
+    else {
+        return Vec::new();
+    };
+    align_to_groups(
+        groups,
+        denom.counts.iter().filter_map(|count| {
+            Some((
+                count.group_id.as_deref(),
+                clean_opt(count.value.as_deref())?,
+            ))
+        }),
+    )
+}
+
+/// Formats a measurement as `value (spread)` or `value (lower to upper)`.
+fn format_measurement(measurement: &CtGovResultMeasurement) -> Option<String> {
+    let value = clean_opt(measurement.value.as_deref())?;
+    if let Some(spread) = clean_opt(measurement.spread.as_deref()) {
+        return Some(format!("{value} ({spread})"));
+    }
+    match (
+        clean_opt(measurement.lower_limit.as_deref()),
+        clean_opt(measurement.upper_limit.as_deref()),
+    ) {
+        (Some(lower), Some(upper)) => Some(format!("{value} ({lower} to {upper})")),
+        _ => Some(value),
+    }
+}
+
+fn result_rows(groups: &[TrialResultGroup], classes: &[CtGovResultClass]) -> Vec<TrialResultRow> {
+    let mut rows = Vec::new();
+    for class in classes {
+        let class_title = clean_opt(class.title.as_deref());
+        for category in &class.categories {
+            let label = [class_title.clone(), clean_opt(category.title.as_deref())]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(": ");
+            let values = align_to_groups(
+                groups,
+                category.measurements.iter().filter_map(|measurement| {
+                    Some((
+                        measurement.group_id.as_deref(),
+                        format_measurement(measurement)?,
+                    ))
+                }),
+            );
+            if values.iter().any(|value| value != "-") {
+                rows.push(TrialResultRow { label, values });
+            }
+        }
+    }
+    rows
+}
+
+/// Describes the reported statistic, e.g. `Mean (Standard Deviation), years`.
+fn result_statistic(
+    param_type: Option<&str>,
+    dispersion_type: Option<&str>,
+    unit: Option<&str>,
+) -> Option<String> {
+    let statistic = match (clean_opt(param_type), clean_opt(dispersion_type)) {
+        (Some(param), Some(dispersion)) if !dispersion.eq_ignore_ascii_case("NA") => Some(format!(
+            "{} ({})",
+            sentence_case_token(&param),
+            sentence_case_token(&dispersion)
+        )),
+        (Some(param), _) => Some(sentence_case_token(&param)),
+        (None, _) => None,
+    };
+    match (statistic, clean_opt(unit)) {
+        (Some(statistic), Some(unit)) => Some(format!("{statistic}, {unit}")),
+        (statistic, unit) => statistic.or(unit),
+    }
+}
+
+/// Turns CT.gov enum tokens such as `STANDARD_DEVIATION` into `Standard deviation`.
+fn sentence_case_token(value: &str) -> String {
+    if !value.contains('_') && value.chars().any(|c| c.is_ascii_lowercase()) {
+        return value.to_string();
+    }
+    let lower = value.replace('_', " ").to_ascii_lowercase();
+    let mut chars = lower.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+fn extract_baseline(study: &CtGovStudy) -> Option<TrialBaseline> {
+    let module = study
+        .results_section
+        .as_ref()
+        .and_then(|r| r.baseline_characteristics_module.as_ref())?;
+    let groups = result_groups(&module.groups);
+    if groups.is_empty() {
+        return None;
+    }
+    let measures = module
+        .measures
+        .iter()
+        .filter_map(|measure| {
+            let rows = result_rows(&groups, &measure.classes);
+            if rows.is_empty() {
+                return None;
+            }
+            Some(TrialBaselineMeasure {
+                title: clean_opt(measure.title.as_deref())?,
+                statistic: result_statistic(
+                    measure.param_type.as_deref(),
+                    measure.dispersion_type.as_deref(),
+                    measure.unit_of_measure.as_deref(),
+                ),
+                rows,
+            })
+        })
+        .collect();
+    Some(TrialBaseline {
+        population: clean_opt(module.population_description.as_deref()),
+        participants: participant_counts(&groups, &module.denoms),
+        groups,
+        measures,
+    })
+}
+
+fn extract_outcome_results(study: &CtGovStudy) -> Vec<TrialOutcomeResult> {
+    let Some(module) = study
+        .results_section
+        .as_ref()
+        .and_then(|r| r.outcome_measures_module.as_ref())
+    else {
+        return Vec::new();
+    };
+    module
+        .outcome_measures
+        .iter()
+        .filter_map(|measure| {
+            let title = clean_opt(measure.title.as_deref())?;
+            let groups = result_groups(&measure.groups);
+            let analyses = measure
+                .analyses
+                .iter()
+                .map(|analysis| TrialOutcomeAnalysis {
+                    groups: analysis
+                        .group_ids
+                        .iter()
+                        .map(|id| {
+                            groups
+                                .iter()
+                                .find(|group| &group.id == id)
+                                .map_or_else(|| id.clone(), |group| group.title.clone())
+                        })
+                        .collect(),
+                    estimate_type: clean_opt(analysis.param_type.as_deref()),
+                    estimate: clean_opt(analysis.param_value.as_deref()),
+                    ci_pct: clean_opt(analysis.ci_pct_value.as_deref()),
+                    ci_lower: clean_opt(analysis.ci_lower_limit.as_deref()),
+                    ci_upper: clean_opt(analysis.ci_upper_limit.as_deref()),
+                    p_value: clean_opt(analysis.p_value.as_deref()),
+                    method: clean_opt(analysis.statistical_method.as_deref()),
+                })
+                .filter(|analysis| analysis.estimate.is_some() || analysis.p_value.is_some())
+                .collect();
+            Some(TrialOutcomeResult {
+                outcome_type: clean_opt(measure.outcome_type.as_deref())
+                    .unwrap_or_else(|| "OTHER".to_string()),
+                title,
+                description: clean_opt(measure.description.as_deref()),
+                time_frame: clean_opt(measure.time_frame.as_deref()),
+                statistic: result_statistic(
+                    measure.param_type.as_deref(),
+                    measure.dispersion_type.as_deref(),
+                    measure.unit_of_measure.as_deref(),
+                ),
+                participants: participant_counts(&groups, &measure.denoms),
+                rows: result_rows(&groups, &measure.classes),
+                groups,
+                analyses,
+            })
+        })
+        .collect()
+}
+
+fn affected_of(affected: Option<u32>, at_risk: Option<u32>) -> Option<String> {
+    match (affected, at_risk) {
+        (Some(affected), Some(at_risk)) => Some(format!("{affected}/{at_risk}")),
+        (Some(affected), None) => Some(affected.to_string()),
+        _ => None,
+    }
+}
+
+/// Keeps the `ADVERSE_EVENT_LIMIT` events affecting the most participants in any group.
+fn top_adverse_events(
+    groups: &[TrialAdverseEventGroup],
+    events: &[CtGovAdverseEvent],
+) -> (Vec<TrialAdverseEvent>, usize) {
+    let mut ranked = events
+        .iter()
+        .filter_map(|event| {
+            let term = clean_opt(event.term.as_deref())?;
+            let max_affected = event
+                .stats
+                .iter()
+                .filter_map(|stats| stats.num_affected)
+                .max()
+                .unwrap_or(0);
+            let values = groups
+                .iter()
+                .map(|group| {
+                    event
+                        .stats
+                        .iter()
+                        .find(|stats| stats.group_id.as_deref() == Some(group.id.as_str()))
+                        .and_then(|stats| affected_of(stats.num_affected, stats.num_at_risk))
+                        .unwrap_or_else(|| "-".to_string())
+                })
+                .collect();
+            Some((
+                max_affected,
+                TrialAdverseEvent {
+                    term,
+                    organ_system: clean_opt(event.organ_system.as_deref()),
+                    values,
+                },
+            ))
+        })
+        .collect::<Vec<_>>();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.term.cmp(&b.1.term)));
+    let omitted = ranked.len().saturating_sub(ADVERSE_EVENT_LIMIT);
+    let kept = ranked
+        .into_iter()
+        .take(ADVERSE_EVENT_LIMIT)
+        .map(|(_, event)| event)
+        .collect();
+    (kept, omitted)
+}
+
+fn extract_adverse_events(study: &CtGovStudy) -> Option<TrialAdverseEvents> {
+    let module = study
+        .results_section
+        .as_ref()
+        .and_then(|r| r.adverse_events_module.as_ref())?;
+    let groups = module
+        .event_groups
+        .iter()
+        .filter_map(|group| {
+            let id = clean_opt(group.id.as_deref())?;
+            Some(TrialAdverseEventGroup {
+                title: clean_opt(group.title.as_deref()).unwrap_or_else(|| id.clone()),
+                id,
+                deaths: affected_of(group.deaths_num_affected, group.deaths_num_at_risk),
+                serious: affected_of(group.serious_num_affected, group.serious_num_at_risk),
+                other: affected_of(group.other_num_affected, group.other_num_at_risk),
+            })
+        })
+        .collect::<Vec<_>>();
+    if groups.is_empty() {
+        return None;
+    }
+    let (serious, serious_omitted) = top_adverse_events(&groups, &module.serious_events);
+    let (other, other_omitted) = top_adverse_events(&groups, &module.other_events);
+    Some(TrialAdverseEvents {
+        time_frame: clean_opt(module.time_frame.as_deref()),
+        frequency_threshold: clean_opt(module.frequency_threshold.as_deref()),
+        groups,
+        serious,
+        serious_omitted,
+        other,
+        other_omitted,
+    })
+}
+
+/// Posted results, or `None` when the results fields were not requested.
+fn extract_results(study: &CtGovStudy) -> Option<TrialResults> {
+    if study.has_results.is_none() && study.results_section.is_none() {
+        return None;
+    }
+    let baseline = extract_baseline(study);
+    let outcome_measures = extract_outcome_results(study);
+    let adverse_events = extract_adverse_events(study);
+    let posted = study.has_results.unwrap_or(false)
+        || baseline.is_some()
+        || !outcome_measures.is_empty()
+        || adverse_events.is_some();
+    Some(TrialResults {
+        posted,
+        baseline,
+        outcome_measures,
+        adverse_events,
+    })
+}
+
 pub fn from_ctgov_study(study: &CtGovStudy) -> Trial {
     let p = study.protocol_section.as_ref();
     let id = p
@@ -343,6 +685,8 @@ pub fn from_ctgov_study(study: &CtGovStudy) -> Trial {
         outcomes: extract_outcomes(study),
         arms: extract_arms(study),
         references: extract_references(study),
+        publications: None,
+        results: extract_results(study),
     }
 }
 
@@ -387,6 +731,9 @@ pub fn from_ctgov_hit(study: &CtGovStudy) -> TrialSearchResult {
         phase,
         conditions,
         sponsor,
+        registry: None,
+        cross_registered_nct: None,
+        eligibility_unchecked: Vec::new(),
     }
 }
 
@@ -459,6 +806,33 @@ pub fn from_nci_hit(hit: &serde_json::Value) -> TrialSearchResult {
         phase,
         conditions,
         sponsor,
+        registry: None,
+        cross_registered_nct: None,
+        eligibility_unchecked: Vec::new(),
+    }
+}
+
+pub fn from_ctis_hit(hit: &serde_json::Value) -> TrialSearchResult {
+    let nct_id = json_get_string(hit, &["ctNumber", "ct_number"])
+        .and_then(|raw| normalize_registry_id(&raw).map(|normalized| normalized.id))
+        .unwrap_or_default();
+    let title =
+        json_get_string(hit, &["ctTitle", "title", "shortTitle", "fullTitle"]).unwrap_or_default();
+    let status = json_get_string(hit, &["ctStatus", "status", "overallStatus"]).unwrap_or_default();
+    let phase = json_get_string(hit, &["trialPhase", "phase"]);
+    let sponsor = json_get_string(hit, &["sponsor", "sponsorName"]);
+    let conditions = json_get_string_list(hit, &["conditions", "medicalCondition"], 10);
+
+    TrialSearchResult {
+        nct_id,
+        title,
+        status,
+        phase,
+        conditions,
+        sponsor,
+        registry: Some(TrialRegistry::Ctis.label().to_string()),
+        cross_registered_nct: None,
+        eligibility_unchecked: Vec::new(),
     }
 }
 
@@ -514,6 +888,8 @@ pub fn from_nci_trial(trial: &serde_json::Value) -> Trial {
         outcomes: None,
         arms: None,
         references: None,
+        publications: None,
+        results: None,
     }
 }
 
@@ -715,4 +1091,129 @@ mod tests {
         assert_eq!(hit_a.status.to_ascii_uppercase(), "RECRUITING");
         assert_eq!(hit_b.status.to_ascii_uppercase(), "RECRUITING");
     }
+
+    #[test]
+    fn from_ctgov_study_maps_posted_results_tables() {
+        let serious_events = (0..23)
+            .map(|idx| {
+                json!({
+                    "term": format!("Event {idx:02}"),
+                    "organSystem": "General disorders",
+                    "stats": [
+                        {"groupId": "EG000", "numAffected": idx, "numAtRisk": 313},
+                        {"groupId": "EG001", "numAffected": 1, "numAtRisk": 311}
+                    ]
+                })
+            })
+            .collect::<Vec<_>>();
+        let groups = json!([
+            {"id": "OG000", "title": "Nivolumab"},
+            {"id": "OG001", "title": "Ipilimumab"}
+        ]);
+        let study: CtGovStudy = serde_json::from_value(json!({
+            "protocolSection": {
+                "identificationModule": {"nctId": "NCT01844505", "briefTitle": "CheckMate 067"},
+                "statusModule": {"overallStatus": "COMPLETED"}
+            },
+            "hasResults": true,
+            "resultsSection": {
+                "baselineCharacteristicsModule": {
+                    "groups": [
+                        {"id": "BG000", "title": "Nivolumab"},
+                        {"id": "BG001", "title": "Total"}
+                    ],
+                    "denoms": [{"units": "Participants", "counts": [
+                        {"groupId": "BG000", "value": "316"},
+                        {"groupId": "BG001", "value": "945"}
+                    ]}],
+                    "measures": [{
+                        "title": "Age, Continuous",
+                        "paramType": "MEAN",
+                        "dispersionType": "STANDARD_DEVIATION",
+                        "unitOfMeasure": "years",
+                        "classes": [{"categories": [{"measurements": [
+                            {"groupId": "BG000", "value": "59.3", "spread": "13.5"}
+                        ]}]}]
+                    }]
+                },
+                "outcomeMeasuresModule": {"outcomeMeasures": [{
+                    "type": "PRIMARY",
+                    "title": "Progression-free Survival",
+                    "timeFrame": "Up to 60 months",
+                    "paramType": "MEDIAN",
+                    "dispersionType": "95% Confidence Interval",
+                    "unitOfMeasure": "months",
+                    "groups": groups,
+                    "classes": [{"categories": [{"measurements": [
+                        {"groupId": "OG000", "value": "6.9", "lowerLimit": "5.1", "upperLimit": "9.7"},
+                        {"groupId": "OG001", "value": "2.9", "lowerLimit": "2.8", "upperLimit": "3.2"}
+                    ]}]}],
+                    "analyses": [{
+                        "groupIds": ["OG000", "OG001"],
+                        "paramType": "Hazard Ratio (HR)",
+                        "paramValue": "0.53",
+                        "ciPctValue": "95",
+                        "ciLowerLimit": "0.44",
+                        "ciUpperLimit": "0.64",
+                        "pValue": "<0.0001",
+                        "statisticalMethod": "Log Rank"
+                    }]
+                }]},
+                "adverseEventsModule": {
+                    "frequencyThreshold": "5",
+                    "eventGroups": [
+                        {"id": "EG000", "title": "Nivolumab", "deathsNumAffected": 5, "deathsNumAtRisk": 313, "seriousNumAffected": 142, "seriousNumAtRisk": 313},
+                        {"id": "EG001", "title": "Ipilimumab", "seriousNumAffected": 135, "seriousNumAtRisk": 311}
+                    ],
+                    "seriousEvents": serious_events
+                }
+            }
+        }))
+        .expect("study");
+
+        let results = from_ctgov_study(&study).results.expect("results");
+        assert!(results.posted);
+
+        let baseline = results.baseline.expect("baseline");
+        assert_eq!(baseline.participants, vec!["316", "945"]);
+        assert_eq!(
+            baseline.measures[0].statistic.as_deref(),
+            Some("Mean (Standard deviation), years")
+        );
+        assert_eq!(baseline.measures[0].rows[0].label, "");
+        assert_eq!(
+            baseline.measures[0].rows[0].values,
+            vec!["59.3 (13.5)", "-"]
+        );
+
+        let outcome = &results.outcome_measures[0];
+        assert_eq!(outcome.outcome_type, "PRIMARY");
+        assert_eq!(
+            outcome.rows[0].values,
+            vec!["6.9 (5.1 to 9.7)", "2.9 (2.8 to 3.2)"]
+        );
+        let analysis = &outcome.analyses[0];
+        assert_eq!(analysis.groups, vec!["Nivolumab", "Ipilimumab"]);
+        assert_eq!(analysis.estimate.as_deref(), Some("0.53"));
+        assert_eq!(analysis.p_value.as_deref(), Some("<0.0001"));
+
+        let adverse_events = results.adverse_events.expect("adverse events");
+        assert_eq!(adverse_events.groups[0].deaths.as_deref(), Some("5/313"));
+        assert_eq!(adverse_events.groups[1].deaths, None);
+        assert_eq!(adverse_events.serious.len(), ADVERSE_EVENT_LIMIT);
+        assert_eq!(adverse_events.serious_omitted, 3);
+        assert_eq!(adverse_events.serious[0].term, "Event 22");
+        assert_eq!(adverse_events.serious[0].values, vec!["22/313", "1/311"]);
+    }
+
+    #[test]
+    fn from_ctgov_study_leaves_results_unset_unless_requested() {
+        let study: CtGovStudy = serde_json::from_value(json!({
+            "protocolSection": {
+                "identificationModule": {"nctId": "NCT01234567", "briefTitle": "Test Trial"}
+            }
+        }))
+        .expect("study");
+        assert!(from_ctgov_study(&study).results.is_none());
+    }
 }
@@ -3,11 +3,15 @@ use std::collections::HashMap;
 use crate::entities::variant::{
     ConditionReportCount, PopulationFrequency, Variant, VariantCgiAssociation, VariantCivicSection,
     VariantConservationScores, VariantCosmicContext, VariantPopulationBreakdown, VariantPrediction,
-    VariantPredictionScore, VariantSearchResult, normalize_protein_change,
+    VariantPredictionScore, VariantSearchResult, VariantTranscript, VariantVepAnnotation,
+    VariantVepTranscript, normalize_protein_change,
 };
 use crate::sources::cbioportal::CBioMutationSummary;
 use crate::sources::civic::CivicEvidenceItem;
-use crate::sources::myvariant::{FloatOrVec, MyVariantClinVarRcv, MyVariantGnomadAf, MyVariantHit};
+use crate::sources::myvariant::{
+    FloatOrVec, MyVariantClinVarRcv, MyVariantDbnsfp, MyVariantGnomadAf, MyVariantHit,
+};
+use crate::sources::vep::VepAnnotation;
 use crate::utils::serde::StringOrVec;
 
 fn normalize_gene(gene: &str) -> Option<String> {
@@ -688,7 +692,7 @@ fn dedupe_limit(values: Vec<String>, max: usize) -> Vec<String> {
     out
 }
 
-fn clinvar_condition_names(rcv: &MyVariantClinVarRcv) -> Vec<String> {
+pub(crate) fn clinvar_condition_names(rcv: &MyVariantClinVarRcv) -> Vec<String> {
     let Some(v) = rcv.conditions.as_ref() else {
         return vec![];
     };
@@ -776,7 +780,7 @@ fn significance_rank(value: &str) -> i32 {
     0
 }
 
-fn pick_significance(rcvs: &[MyVariantClinVarRcv]) -> Option<String> {
+pub(crate) fn pick_significance(rcvs: &[MyVariantClinVarRcv]) -> Option<String> {
     let mut best: Option<(&str, i32)> = None;
     for r in rcvs {
         let Some(sig) = r.clinical_significance.as_deref() else {
@@ -814,7 +818,7 @@ fn clinvar_review_stars(review_status: &str) -> Option<u8> {
     None
 }
 
-fn pick_review_status(rcvs: &[MyVariantClinVarRcv]) -> (Option<String>, Option<u8>) {
+pub(crate) fn pick_review_status(rcvs: &[MyVariantClinVarRcv]) -> (Option<String>, Option<u8>) {
     let mut best: Option<(u8, &str)> = None;
     let mut fallback_status: Option<&str> = None;
 
@@ -871,6 +875,124 @@ pub(crate) fn normalize_oncokb_level(value: &str) -> String {
     v.to_string()
 }
 
+/// Picks the value for transcript `index` from a dbNSFP column.
+///
+/// Scalar columns apply to every transcript; arrays whose length differs from
+/// the transcript count cannot be aligned and yield `None`.
+fn per_transcript<T: Clone>(values: &[T], count: usize, index: usize) -> Option<T> {
+    match values.len() {
+        1 => values.first().cloned(),
+        len if len == count => values.get(index).cloned(),
+        _ => None,
+    }
+}
+
+fn dbnsfp_text(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty() && v != ".")
+}
+
+fn transcript_consequence(reference: &str, alternate: &str, position: u32) -> &'static str {
+    match (reference, alternate) {
+        (r, a) if r == a => "synonymous_variant",
+        (_, "X" | "*") => "stop_gained",
+        ("X" | "*", _) => "stop_lost",
+        ("M", _) if position == 1 => "start_lost",
+        _ => "missense_variant",
+    }
+}
+
+/// Splits dbNSFP's per-transcript arrays (aligned with `ensembl.transcriptid`) into rows.
+pub(crate) fn transcripts_from_dbnsfp(dbnsfp: &MyVariantDbnsfp) -> Vec<VariantTranscript> {
+    let Some(ensembl) = dbnsfp.ensembl.as_ref() else {
+        return Vec::new();
+    };
+    let transcripts = ensembl.transcriptid.clone().into_vec();
+    let count = transcripts.len();
+    let proteins = ensembl.proteinid.clone().into_vec();
+    let (references, alternates, positions) = dbnsfp
+        .aa
+        .as_ref()
+        .map(|aa| {
+            (
+                aa.reference.clone().into_vec(),
+                aa.alt.clone().into_vec(),
+                aa.pos.iter().map(|pos| pos.value()).collect::<Vec<_>>(),
+            )
+        })
+        .unwrap_or_default();
+    let canonical = dbnsfp.vep_canonical.clone().into_vec();
+    let mane = dbnsfp.mane.clone().into_vec();
+    let sift_preds = dbnsfp
+        .sift
+        .as_ref()
+        .and_then(|s| s.pred.clone())
+        .map(StringOrVec::into_vec)
+        .unwrap_or_default();
+    let sift_scores = dbnsfp
+        .sift
+        .as_ref()
+        .and_then(|s| s.score.as_ref())
+        .map(FloatOrVec::to_vec)
+        .unwrap_or_default();
+    let polyphen_preds = dbnsfp
+        .polyphen2
+        .as_ref()
+        .and_then(|p| p.hdiv.as_ref())
+        .and_then(|h| h.pred.clone())
+        .map(StringOrVec::into_vec)
+        .unwrap_or_default();
+
+    transcripts
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, transcript)| {
+            let transcript = dbnsfp_text(Some(transcript))?;
+            let position = per_transcript(&positions, count, index).flatten();
+            // Missense predictors only score transcripts that code the residue.
+            let coding = position.is_some();
+            let reference = dbnsfp_text(per_transcript(&references, count, index));
+            let alternate = dbnsfp_text(per_transcript(&alternates, count, index));
+            let (hgvs_p, consequence) = match (position, reference, alternate) {
+                (Some(position), Some(reference), Some(alternate)) => (
+                    Some(format!(
+                        "p.{reference}{position}{}",
+                        if alternate == "X" { "*" } else { &alternate }
+                    )),
+                    Some(transcript_consequence(&reference, &alternate, position).to_string()),
+                ),
+                _ => (None, None),
+            };
+            Some(VariantTranscript {
+                transcript,
+                protein: dbnsfp_text(per_transcript(&proteins, count, index)),
+                position,
+                hgvs_p,
+                consequence,
+                mane: dbnsfp_text(per_transcript(&mane, count, index)),
+                canonical: canonical
+                    .get(index)
+                    .is_some_and(|flag| flag.trim().eq_ignore_ascii_case("YES")),
+                sift_pred: dbnsfp_text(per_transcript(&sift_preds, count, index))
+                    .filter(|_| coding)
+                    .map(|pred| normalize_sift(&pred)),
+                sift_score: per_transcript(&sift_scores, count, index).filter(|_| coding),
+                polyphen_pred: dbnsfp_text(per_transcript(&polyphen_preds, count, index))
+                    .filter(|_| coding)
+                    .map(|pred| normalize_polyphen(&pred)),
+            })
+        })
+        .collect()
+}
+
+fn dbnsfp_transcripts(hit: &MyVariantHit) -> Vec<VariantTranscript> {
+    hit.dbnsfp
+        .as_ref()
+        .map(transcripts_from_dbnsfp)
+        .unwrap_or_default()
+}
+
 pub fn from_myvariant_hit(hit: &MyVariantHit) -> Variant {
     let mut gene = String::new();
     let mut hgvs_p: Option<String> = None;
@@ -919,6 +1041,13 @@ pub fn from_myvariant_hit(hit: &MyVariantHit) -> Variant {
         .map(|s| s.trim().to_string())
         .find(|s| !s.is_empty());
 
+    let clingen_allele_id = hit
+        .clingen
+        .as_ref()
+        .and_then(|c| c.caid.as_deref())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+
     let clinvar_id = hit
         .clinvar
         .as_ref()
@@ -959,12 +1088,18 @@ pub fn from_myvariant_hit(hit: &MyVariantHit) -> Variant {
 
     Variant {
         id: hit.id.clone(),
+        assembly: None,
+        assembly_notes: Vec::new(),
         gene,
         hgvs_p,
         legacy_name,
         hgvs_c,
         rsid,
         cosmic_id,
+        clingen_allele_id,
+        isoform: None,
+        transcripts: dbnsfp_transcripts(hit),
+        vep: None,
         significance,
         clinvar_id,
         clinvar_review_status,
@@ -982,6 +1117,7 @@ pub fn from_myvariant_hit(hit: &MyVariantHit) -> Variant {
         conservation: extract_conservation(hit),
         expanded_predictions: extract_expanded_predictions(hit),
         population_breakdown: extract_population_breakdown(hit),
+        gnomad: None,
         cosmic_context: extract_cosmic_details(hit),
         cgi_associations: extract_cgi_associations(hit),
         civic: (!cached_civic.is_empty()).then_some(VariantCivicSection {
@@ -989,12 +1125,15 @@ pub fn from_myvariant_hit(hit: &MyVariantHit) -> Variant {
             graphql: None,
         }),
         top_disease,
+        clinvar_submissions: Vec::new(),
         cancer_frequencies: Vec::new(),
         cancer_frequency_source: None,
         gwas: Vec::new(),
         gwas_unavailable_reason: None,
         supporting_pmids: None,
+        litvar: None,
         prediction: None,
+        plugin_sections: Vec::new(),
     }
 }
 
@@ -1019,6 +1158,8 @@ pub fn from_myvariant_search_hit(hit: &MyVariantHit) -> VariantSearchResult {
         .as_ref()
         .and_then(|dbnsfp| dbnsfp.gerp.as_ref())
         .and_then(|gerp| first_score(gerp.rs.as_ref()));
+    let cadd_score = hit.cadd.as_ref().and_then(|c| c.phred);
+    let consequence = pick_consequence(hit);
 
     VariantSearchResult {
         id: hit.id.clone(),
@@ -1030,6 +1171,8 @@ pub fn from_myvariant_search_hit(hit: &MyVariantHit) -> VariantSearchResult {
         gnomad_af,
         revel,
         gerp,
+        cadd_score,
+        consequence,
     }
 }
 
@@ -1045,6 +1188,86 @@ pub fn merge_prediction(variant: &mut Variant, prediction: VariantPrediction) {
     variant.prediction = Some(prediction);
 }
 
+/// Ensembl-style genomic HGVS (`7:g.140453136A>T`) for a MyVariant.info `_id`.
+pub(crate) fn vep_hgvs(myvariant_id: &str) -> Option<String> {
+    let (chrom, change) = myvariant_id.trim().split_once(":g.")?;
+    let chrom = chrom.strip_prefix("chr").unwrap_or(chrom);
+    let chrom = if chrom.eq_ignore_ascii_case("M") {
+        "MT"
+    } else {
+        chrom
+    };
+    if chrom.is_empty() || change.is_empty() {
+        return None;
+    }
+    Some(format!("{chrom}:g.{change}"))
+}
+
+fn vep_text(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+}
+
+fn vep_impact_rank(impact: Option<&str>) -> u8 {
+    match impact.map(str::trim) {
+        Some(value) if value.eq_ignore_ascii_case("HIGH") => 0,
+        Some(value) if value.eq_ignore_ascii_case("MODERATE") => 1,
+        Some(value) if value.eq_ignore_ascii_case("LOW") => 2,
+        Some(value) if value.eq_ignore_ascii_case("MODIFIER") => 3,
+        _ => 4,
+    }
+}
+
+pub fn merge_vep(variant: &mut Variant, input: &str, annotation: &VepAnnotation) {
+    let mut transcripts = annotation
+        .transcript_consequences
+        .iter()
+        .filter_map(|row| {
+            let transcript = vep_text(row.transcript_id.as_deref())?;
+            Some(VariantVepTranscript {
+                transcript,
+                gene: vep_text(row.gene_symbol.as_deref()),
+                biotype: vep_text(row.biotype.as_deref()),
+                consequences: row.consequence_terms.clone(),
+                impact: vep_text(row.impact.as_deref()).map(|value| value.to_ascii_uppercase()),
+                canonical: row.canonical == Some(1),
+                hgvs_c: vep_text(row.hgvsc.as_deref()),
+                hgvs_p: vep_text(row.hgvsp.as_deref()),
+            })
+        })
+        .collect::<Vec<_>>();
+    transcripts.sort_by(|a, b| {
+        b.canonical
+            .cmp(&a.canonical)
+            .then_with(|| {
+                vep_impact_rank(a.impact.as_deref()).cmp(&vep_impact_rank(b.impact.as_deref()))
+            })
+            .then_with(|| a.transcript.cmp(&b.transcript))
+    });
+
+    let most_severe_consequence = vep_text(annotation.most_severe_consequence.as_deref());
+    if variant.consequence.is_none() {
+        variant.consequence = most_severe_consequence.clone();
+    }
+    if variant.gene.trim().is_empty()
+        && let Some(gene) = transcripts
+            .iter()
+            .find(|row| row.canonical)
+            .and_then(|row| row.gene.as_deref())
+            .and_then(normalize_gene)
+    {
+        variant.gene = gene;
+    }
+    variant.vep = Some(VariantVepAnnotation {
+        input: input.to_string(),
+        assembly: vep_text(annotation.assembly_name.as_deref()),
+        most_severe_consequence,
+        transcripts,
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1202,6 +1425,7 @@ mod tests {
             "exac": {"af": 0.0004},
             "exac_nontcga": {"af": 0.0005},
             "cosmic": {"cosmic_id": "COSM476", "mut_freq": 2.8, "tumor_site": "skin"},
+            "clingen": {"caid": "CA123643"},
             "cgi": [{"drug": "vemurafenib", "association": "Responsive", "evidence_level": "FDA"}],
             "civic": {
                 "molecularProfiles": [{
@@ -1222,6 +1446,7 @@ mod tests {
         .expect("variant payload should parse");
 
         let variant = from_myvariant_hit(&hit);
+        assert_eq!(variant.clingen_allele_id.as_deref(), Some("CA123643"));
         assert!(variant.conservation.is_some());
         assert!(!variant.expanded_predictions.is_empty());
         assert!(variant.population_breakdown.is_some());
@@ -1334,4 +1559,119 @@ mod tests {
         let variant = from_myvariant_hit(&hit);
         assert_eq!(variant.legacy_name, None);
     }
+
+    #[test]
+    fn from_myvariant_hit_splits_dbnsfp_transcripts() {
+        let hit: MyVariantHit = serde_json::from_value(serde_json::json!({
+            "_id": "chr7:g.140453136A>T",
+            "dbnsfp": {
+                "genename": "BRAF",
+                "aa": {"ref": "V", "alt": "E", "pos": [600, 640, -1]},
+                "ensembl": {
+                    "transcriptid": ["ENST00000646891", "ENST00000644969", "ENST00000479537"],
+                    "proteinid": ["ENSP00000493543", "ENSP00000496776", "."]
+                },
+                "vep_canonical": ["YES", ".", "."],
+                "mane": ["MANE Select", ".", "."],
+                "sift": {"pred": ["D", "D", "."], "score": [0.0, 0.01, 0.5]},
+                "polyphen2": {"hdiv": {"pred": "D"}}
+            }
+        }))
+        .expect("variant payload should parse");
+
+        let variant = from_myvariant_hit(&hit);
+        assert_eq!(variant.transcripts.len(), 3);
+
+        let canonical = &variant.transcripts[0];
+        assert_eq!(canonical.transcript, "ENST00000646891");
+        assert_eq!(canonical.protein.as_deref(), Some("ENSP00000493543"));
+        assert_eq!(canonical.hgvs_p.as_deref(), Some("p.V600E"));
+        assert_eq!(canonical.consequence.as_deref(), Some("missense_variant"));
+        assert_eq!(canonical.mane.as_deref(), Some("MANE Select"));
+        assert!(canonical.canonical);
+        assert_eq!(canonical.sift_pred.as_deref(), Some("Deleterious"));
+        assert_eq!(canonical.sift_score, Some(0.0));
+        assert_eq!(
+            canonical.polyphen_pred.as_deref(),
+            Some("Probably damaging")
+        );
+
+        let alternate = &variant.transcripts[1];
+        assert_eq!(alternate.hgvs_p.as_deref(), Some("p.V640E"));
+        assert!(alternate.mane.is_none());
+        assert!(!alternate.canonical);
+
+        let non_coding = &variant.transcripts[2];
+        assert!(non_coding.protein.is_none());
+        assert!(non_coding.position.is_none());
+        assert!(non_coding.consequence.is_none());
+        assert!(non_coding.sift_pred.is_none());
+        assert!(non_coding.sift_score.is_none());
+        assert!(non_coding.polyphen_pred.is_none());
+    }
+
+    #[test]
+    fn vep_hgvs_strips_chr_prefix_and_maps_mitochondria() {
+        assert_eq!(
+            vep_hgvs("chr13:g.32914438delT").as_deref(),
+            Some("13:g.32914438delT")
+        );
+        assert_eq!(vep_hgvs("chrM:g.3243A>G").as_deref(), Some("MT:g.3243A>G"));
+        assert_eq!(vep_hgvs("rs113488022"), None);
+    }
+
+    #[test]
+    fn merge_vep_orders_canonical_first_and_fills_missing_consequence() {
+        let hit: MyVariantHit = serde_json::from_value(serde_json::json!({
+            "_id": "chr13:g.32914438delT"
+        }))
+        .expect("variant payload should parse");
+        let mut variant = from_myvariant_hit(&hit);
+        assert!(variant.consequence.is_none());
+
+        let annotation: VepAnnotation = serde_json::from_value(serde_json::json!({
+            "assembly_name": "GRCh37",
+            "most_severe_consequence": "frameshift_variant",
+            "transcript_consequences": [
+                {
+                    "transcript_id": "ENST00000470094",
+                    "gene_symbol": "BRCA2",
+                    "consequence_terms": ["downstream_gene_variant"],
+                    "impact": "MODIFIER"
+                },
+                {
+                    "transcript_id": "ENST00000544455",
+                    "gene_symbol": "BRCA2",
+                    "consequence_terms": ["frameshift_variant"],
+                    "impact": "HIGH"
+                },
+                {
+                    "transcript_id": "ENST00000380152",
+                    "gene_symbol": "BRCA2",
+                    "consequence_terms": ["frameshift_variant"],
+                    "impact": "high",
+                    "canonical": 1,
+                    "hgvsc": "ENST00000380152.3:c.5946delT"
+                }
+            ]
+        }))
+        .expect("VEP payload should parse");
+
+        merge_vep(&mut variant, "13:g.32914438delT", &annotation);
+        assert_eq!(variant.consequence.as_deref(), Some("frameshift_variant"));
+        assert_eq!(variant.gene, "BRCA2");
+        let vep = variant.vep.expect("VEP annotation");
+        assert_eq!(vep.assembly.as_deref(), Some("GRCh37"));
+        let order = vep
+            .transcripts
+            .iter()
+            .map(|row| row.transcript.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            order,
+            vec!["ENST00000380152", "ENST00000544455", "ENST00000470094"]
+        );
+        assert!(vep.transcripts[0].canonical);
+        assert_eq!(vep.transcripts[0].impact.as_deref(), Some("HIGH"));
+    }
 }
@@ -0,0 +1,218 @@
+//! Five-field cron expressions (`minute hour day-of-month month day-of-week`)
+//! evaluated in UTC for `biomcp schedule`.
+
+use time::{Date, Duration, Month, OffsetDateTime, Time};
+
+use crate::error::BioMcpError;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+/// Long enough to reach the next Feb 29 from any start.
+const SEARCH_LIMIT_DAYS: i64 = 366 * 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// Standard cron rule: when both day fields are restricted, either may match.
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+fn invalid(expr: &str, detail: impl std::fmt::Display) -> BioMcpError {
+    BioMcpError::InvalidArgument(format!(
+        "Invalid cron expression '{expr}': {detail}. Use five fields `minute hour day-of-month month day-of-week`, e.g. \"0 6 * * MON\"."
+    ))
+}
+
+fn parse_value(token: &str, min: u8, max: u8, names: &[&str]) -> Option<u8> {
+    if let Some(index) = names
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(token))
+    {
+        // Month names start at 1, weekday names at 0 (Sunday).
+        return u8::try_from(index).ok().map(|index| index + min);
+    }
+    token
+        .parse::<u8>()
+        .ok()
+        .filter(|value| (min..=max).contains(value))
+}
+
+fn parse_field(
+    expr: &str,
+    field: &str,
+    min: u8,
+    max: u8,
+    names: &[&str],
+) -> Result<u64, BioMcpError> {
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step = step
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|step| *step > 0)
+                    .ok_or_else(|| invalid(expr, format!("bad step in '{part}'")))?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            let start = parse_value(start, min, max, names);
+            let end = parse_value(end, min, max, names);
+            match (start, end) {
+                (Some(start), Some(end)) if start <= end => (start, end),
+                _ => return Err(invalid(expr, format!("bad range '{range}'"))),
+            }
+        } else {
+            let value = parse_value(range, min, max, names)
+                .ok_or_else(|| invalid(expr, format!("'{range}' is outside {min}-{max}")))?;
+            // `5/15` means "from 5, every 15".
+            (value, if step > 1 { max } else { value })
+        };
+        for value in (start..=end).step_by(usize::from(step)) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+impl CronSchedule {
+    pub(crate) fn parse(expr: &str) -> Result<Self, BioMcpError> {
+        let expanded = match expr.trim().to_ascii_lowercase().as_str() {
+            "@hourly" => "0 * * * *".to_string(),
+            "@daily" | "@midnight" => "0 0 * * *".to_string(),
+            "@weekly" => "0 0 * * 0".to_string(),
+            "@monthly" => "0 0 1 * *".to_string(),
+            _ => expr.trim().to_string(),
+        };
+        let fields = expanded.split_whitespace().collect::<Vec<_>>();
+        let [minute, hour, day, month, weekday] = fields.as_slice() else {
+            return Err(invalid(
+                expr,
+                format!("expected 5 fields, got {}", fields.len()),
+            ));
+        };
+        let mut weekdays = parse_field(expr, weekday, 0, 7, &WEEKDAY_NAMES)?;
+        // Both 0 and 7 mean Sunday.
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+        Ok(Self {
+            minutes: parse_field(expr, minute, 0, 59, &[])?,
+            hours: parse_field(expr, hour, 0, 23, &[])?,
+            days: parse_field(expr, day, 1, 31, &[])?,
+            months: parse_field(expr, month, 1, 12, &MONTH_NAMES)?,
+            weekdays,
+            days_restricted: !day.starts_with('*'),
+            weekdays_restricted: !weekday.starts_with('*'),
+        })
+    }
+
+    fn day_matches(&self, date: Date) -> bool {
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().number_days_from_sunday()) != 0;
+        match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => day || weekday,
+            (true, false) => day,
+            (false, true) => weekday,
+            (false, false) => true,
+        }
+    }
+
+    /// First matching minute strictly after `after`, in UTC.
+    pub(crate) fn next_after(&self, after: OffsetDateTime) -> Option<OffsetDateTime> {
+        let after = after.to_offset(time::UtcOffset::UTC);
+        let mut at = after.replace_time(Time::from_hms(after.hour(), after.minute(), 0).ok()?)
+            + Duration::minutes(1);
+        let limit = at + Duration::days(SEARCH_LIMIT_DAYS);
+        while at < limit {
+            if self.months & (1 << u8::from(at.month())) == 0 {
+                let (year, month) = match at.month() {
+                    Month::December => (at.year() + 1, Month::January),
+                    month => (at.year(), month.next()),
+                };
+                at = at
+                    .replace_date(Date::from_calendar_date(year, month, 1).ok()?)
+                    .replace_time(Time::MIDNIGHT);
+            } else if !self.day_matches(at.date()) {
+                at = at.replace_time(Time::MIDNIGHT) + Duration::days(1);
+            } else if self.hours & (1 << at.hour()) == 0 {
+                at = at.replace_time(Time::from_hms(at.hour(), 0, 0).ok()?) + Duration::hours(1);
+            } else if self.minutes & (1 << at.minute()) == 0 {
+                at += Duration::minutes(1);
+            } else {
+                return Some(at);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CronSchedule;
+    use time::OffsetDateTime;
+
+    fn at(timestamp: i64) -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(timestamp).expect("valid timestamp")
+    }
+
+    #[test]
+    fn weekly_expression_fires_on_next_monday_morning() {
+        let schedule = CronSchedule::parse("0 6 * * MON").expect("valid cron");
+        // Friday 2026-10-16 09:30 UTC -> Monday 2026-10-19 06:00 UTC.
+        let next = schedule.next_after(at(1_792_143_000)).expect("next run");
+        assert_eq!(next.unix_timestamp(), 1_792_389_600);
+        // Exactly on a matching minute, the next run is a week later.
+        let following = schedule.next_after(next).expect("following run");
+        assert_eq!(following.unix_timestamp(), 1_792_389_600 + 7 * 86_400);
+    }
+
+    #[test]
+    fn steps_ranges_lists_and_macros_parse() {
+        let schedule = CronSchedule::parse("*/15 9-17 1,15 JAN-MAR 1-5").expect("valid cron");
+        assert_eq!(schedule.minutes, 1 | 1 << 15 | 1 << 30 | 1 << 45);
+        assert_eq!(schedule.months, 0b1110);
+        assert!(schedule.days_restricted && schedule.weekdays_restricted);
+
+        let sunday = CronSchedule::parse("0 0 * * 7").expect("7 is Sunday");
+        assert_eq!(sunday.weekdays, 1);
+        assert_eq!(
+            CronSchedule::parse("@weekly").expect("macro"),
+            CronSchedule::parse("0 0 * * SUN").expect("expanded")
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        for expr in [
+            "0 6 * *",
+            "60 * * * *",
+            "0 6 * * FUNDAY",
+            "*/0 * * * *",
+            "5-1 * * * *",
+        ] {
+            let err = CronSchedule::parse(expr).expect_err(expr);
+            assert!(
+                err.to_string().contains("Invalid cron expression"),
+                "{expr}"
+            );
+        }
+    }
+
+    #[test]
+    fn impossible_dates_never_fire() {
+        let schedule = CronSchedule::parse("0 0 31 2 *").expect("parses");
+        assert_eq!(schedule.next_after(at(1_792_143_000)), None);
+    }
+}
@@ -10,10 +10,14 @@ pub fn cache_key(id: &str) -> String {
 }
 
 fn download_path(id: &str) -> Result<PathBuf, BioMcpError> {
+    download_path_with_extension(id, "txt")
+}
+
+fn download_path_with_extension(id: &str, extension: &str) -> Result<PathBuf, BioMcpError> {
     Ok(crate::cache::resolve_cache_config()?
         .cache_root
         .join("downloads")
-        .join(format!("{}.txt", cache_key(id))))
+        .join(format!("{}.{extension}", cache_key(id))))
 }
 
 async fn create_unique_sibling_temp(
@@ -122,6 +126,22 @@ pub async fn save_atomic(id: &str, content: &str) -> Result<PathBuf, BioMcpError
     Ok(path)
 }
 
+/// Like [`save_atomic`] for binary documents such as PDFs, saved with the
+/// given file extension instead of `.txt`.
+pub async fn save_bytes_atomic(
+    id: &str,
+    extension: &str,
+    content: &[u8],
+) -> Result<PathBuf, BioMcpError> {
+    let path = download_path_with_extension(id, extension)?;
+    if matches!(tokio::fs::metadata(&path).await, Ok(metadata) if metadata.is_file()) {
+        return Ok(path);
+    }
+
+    write_atomic_bytes(&path, content).await?;
+    Ok(path)
+}
+
 #[cfg(test)]
 mod tests {
     use std::future::Future;
@@ -129,7 +149,7 @@ mod tests {
     use std::time::{SystemTime, UNIX_EPOCH};
     use tokio::sync::MutexGuard;
 
-    use super::{cache_key, download_path, save_atomic, write_atomic_bytes};
+    use super::{cache_key, download_path, save_atomic, save_bytes_atomic, write_atomic_bytes};
 
     fn block_on<F: Future>(future: F) -> F::Output {
         tokio::runtime::Builder::new_current_thread()
@@ -305,6 +325,26 @@ mod tests {
         assert_eq!(content, "hello world");
     }
 
+    #[test]
+    fn save_bytes_atomic_keeps_the_requested_extension() {
+        let _lock = env_lock();
+        let root = TempDirGuard::new("save-bytes-atomic");
+        let override_root = root.path().join("override-root");
+        let _cache_dir = set_env_var("BIOMCP_CACHE_DIR", Some(&override_root.to_string_lossy()));
+        let id = "doi:10.1000/example.pdf";
+
+        let path = block_on(save_bytes_atomic(id, "pdf", b"%PDF-1.7\n"))
+            .expect("save_bytes_atomic should write the document");
+
+        assert_eq!(
+            path,
+            override_root
+                .join("downloads")
+                .join(format!("{}.pdf", cache_key(id)))
+        );
+        assert_eq!(std::fs::read(&path).expect("saved file"), b"%PDF-1.7\n");
+    }
+
     #[test]
     fn save_atomic_errors_when_target_path_is_directory() {
         let _lock = env_lock();
@@ -0,0 +1,428 @@
+//! Local query history stored as JSON lines in `<cache_root>/history.jsonl`.
+//!
+//! Recording is opt-in with `BIOMCP_HISTORY=on`. CLI and MCP queries are then
+//! appended with their arguments, timestamp, and an approximate result count so
+//! past lookups can be listed, replayed, and exported. When cache encryption is
+//! on, each line is sealed with the cache key.
+
+use std::io::{BufRead as _, BufReader, Read as _, Seek as _, SeekFrom, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::cache::CacheCipher;
+use crate::error::BioMcpError;
+
+const HISTORY_FILE: &str = "history.jsonl";
+const HISTORY_ENV: &str = "BIOMCP_HISTORY";
+/// Oldest entries are dropped once the file grows past this many.
+const MAX_HISTORY_ENTRIES: usize = 5000;
+/// Marks a line sealed with the cache key; plaintext lines start with `{`.
+const SEALED_PREFIX: &str = "enc:";
+/// Associated data binding sealed lines to the history file.
+const SEALED_AAD: &str = "biomcp-history";
+/// How much of the file end is read to find the newest id before appending.
+const TAIL_BYTES: u64 = 64 * 1024;
+
+/// Serializes appends from concurrent MCP tool calls within one process.
+static HISTORY_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum HistoryOrigin {
+    Cli,
+    Mcp,
+}
+
+impl HistoryOrigin {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Cli => "cli",
+            Self::Mcp => "mcp",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct HistoryEntry {
+    pub(crate) id: u64,
+    /// RFC 3339 timestamp, UTC, second precision.
+    pub(crate) timestamp: String,
+    pub(crate) origin: HistoryOrigin,
+    /// Subcommand path such as `search trial` or `get gene`.
+    pub(crate) command: String,
+    /// Full argument list without the leading `biomcp`, as needed for replay.
+    pub(crate) args: Vec<String>,
+    pub(crate) ok: bool,
+    /// Rows or records in the output, when they can be counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) result_count: Option<usize>,
+}
+
+fn parse_enabled(value: Option<&str>) -> bool {
+    matches!(
+        value
+            .map(|value| value.trim().to_ascii_lowercase())
+            .as_deref(),
+        Some("1" | "on" | "true" | "yes")
+    )
+}
+
+pub(crate) fn is_enabled() -> bool {
+    parse_enabled(std::env::var(HISTORY_ENV).ok().as_deref())
+}
+
+/// The history file and, when cache encryption is on, the key that seals it.
+pub(crate) struct HistoryStore {
+    path: PathBuf,
+    cipher: Option<Arc<CacheCipher>>,
+}
+
+impl HistoryStore {
+    pub(crate) fn open() -> Result<Self, BioMcpError> {
+        let config = crate::cache::resolve_cache_config()?;
+        Ok(Self {
+            cipher: CacheCipher::shared(config.encrypt)?,
+            path: config.cache_root.join(HISTORY_FILE),
+        })
+    }
+
+    fn decode(&self, line: &str) -> Result<HistoryEntry, String> {
+        let Some(sealed) = line.strip_prefix(SEALED_PREFIX) else {
+            return serde_json::from_str(line).map_err(|err| err.to_string());
+        };
+        let cipher = self
+            .cipher
+            .as_deref()
+            .ok_or("line is encrypted and cache encryption is off")?;
+        let plain = STANDARD
+            .decode(sealed)
+            .ok()
+            .and_then(|sealed| cipher.open(SEALED_AAD, &sealed))
+            .ok_or("line could not be opened with the current cache key")?;
+        serde_json::from_slice(&plain).map_err(|err| err.to_string())
+    }
+
+    fn encode(&self, entry: &HistoryEntry) -> Result<String, BioMcpError> {
+        let json = serde_json::to_string(entry).map_err(|err| {
+            BioMcpError::InvalidArgument(format!("failed to encode history: {err}"))
+        })?;
+        match &self.cipher {
+            Some(cipher) => Ok(format!(
+                "{SEALED_PREFIX}{}",
+                STANDARD.encode(cipher.seal(SEALED_AAD, json.as_bytes())?)
+            )),
+            None => Ok(json),
+        }
+    }
+
+    /// Reads all entries, oldest first. Unreadable lines are skipped.
+    pub(crate) fn load(&self) -> Result<Vec<HistoryEntry>, BioMcpError> {
+        let content = match std::fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => {
+                return Err(BioMcpError::Io(std::io::Error::new(
+                    err.kind(),
+                    format!("failed to read {}: {}", self.path.display(), err),
+                )));
+            }
+        };
+        Ok(content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match self.decode(line) {
+                Ok(entry) => Some(entry),
+                Err(err) => {
+                    warn!(path = %self.path.display(), "skipping unreadable history line: {err}");
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Ids of the oldest and newest readable entries, read from the two ends of
+    /// the file so an append does not parse the whole history.
+    fn id_range(&self) -> Result<Option<(u64, u64)>, BioMcpError> {
+        let mut file = match std::fs::File::open(&self.path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let Some(first) = BufReader::new(&file)
+            .lines()
+            .map_while(Result::ok)
+            .find_map(|line| self.decode(&line).ok())
+        else {
+            return Ok(None);
+        };
+
+        let len = file.metadata()?.len();
+        let start = len.saturating_sub(TAIL_BYTES);
+        file.seek(SeekFrom::Start(start))?;
+        let mut tail = Vec::new();
+        file.read_to_end(&mut tail)?;
+        let tail = String::from_utf8_lossy(&tail);
+        let mut lines = tail.lines().rev().collect::<Vec<_>>();
+        if start > 0 {
+            // The first line of the window is usually cut mid-record.
+            lines.pop();
+        }
+        let last = lines.into_iter().find_map(|line| self.decode(line).ok());
+        let last = match last {
+            Some(last) => last,
+            None => match self.load()?.pop() {
+                Some(last) => last,
+                None => return Ok(None),
+            },
+        };
+        Ok(Some((first.id, last.id.max(first.id))))
+    }
+
+    /// Appends one query and returns the stored entry with its assigned id.
+    pub(crate) fn append(
+        &self,
+        origin: HistoryOrigin,
+        command: String,
+        args: Vec<String>,
+        ok: bool,
+        result_count: Option<usize>,
+    ) -> Result<HistoryEntry, BioMcpError> {
+        let _guard = HISTORY_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let range = self.id_range()?;
+        let entry = HistoryEntry {
+            id: range.map_or(1, |(_, last)| last + 1),
+            timestamp: humantime::format_rfc3339_seconds(SystemTime::now()).to_string(),
+            origin,
+            command,
+            args,
+            ok,
+            result_count,
+        };
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        // Ids are sequential and trimming keeps the newest run, so the id range
+        // is the entry count without reading every line.
+        let stored = range.map_or(0, |(first, last)| last - first + 1);
+        if stored < MAX_HISTORY_ENTRIES as u64 {
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            writeln!(file, "{}", self.encode(&entry)?)?;
+            return Ok(entry);
+        }
+
+        let mut entries = self.load()?;
+        entries.push(entry.clone());
+        let keep = entries.split_off(entries.len().saturating_sub(MAX_HISTORY_ENTRIES));
+        let mut content = String::new();
+        for kept in &keep {
+            content.push_str(&self.encode(kept)?);
+            content.push('\n');
+        }
+        let staging = self.path.with_extension("jsonl.tmp");
+        std::fs::write(&staging, content)?;
+        std::fs::rename(&staging, &self.path)?;
+        Ok(entry)
+    }
+}
+
+/// Counts results in command output: `count` or the `results` array of JSON
+/// output, or the data rows of the first markdown table.
+pub(crate) fn result_count(output: &str) -> Option<usize> {
+    if let Ok(value) = serde_json::from_str::<serde_json::Value>(output) {
+        return match &value {
+            serde_json::Value::Array(items) => Some(items.len()),
+            serde_json::Value::Object(object) => object
+                .get("count")
+                .and_then(serde_json::Value::as_u64)
+                .map(|count| count as usize)
+                .or_else(|| {
+                    object
+                        .get("results")
+                        .and_then(serde_json::Value::as_array)
+                        .map(Vec::len)
+                }),
+            _ => None,
+        };
+    }
+
+    let mut lines = output
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| !line.starts_with('|'));
+    lines.next()?;
+    let separator = lines.next()?;
+    if !separator.starts_with("|-") && !separator.starts_with("| -") {
+        return None;
+    }
+    Some(lines.take_while(|line| line.starts_with('|')).count())
+}
+
+/// Records a finished query; failures only log so history never breaks a command.
+pub(crate) fn record(
+    origin: HistoryOrigin,
+    command: String,
+    args: Vec<String>,
+    output: Option<&str>,
+) {
+    if !is_enabled() {
+        return;
+    }
+    let result = HistoryStore::open().and_then(|store| {
+        store.append(
+            origin,
+            command,
+            args,
+            output.is_some(),
+            output.and_then(result_count),
+        )
+    });
+    if let Err(err) = result {
+        warn!("failed to record query history: {err}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use super::*;
+
+    struct TempDirGuard {
+        path: PathBuf,
+    }
+
+    impl TempDirGuard {
+        fn new(label: &str) -> Self {
+            let stamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos();
+            let path = std::env::temp_dir().join(format!(
+                "biomcp-history-test-{label}-{}-{stamp}",
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&path).expect("temp dir should be created");
+            Self { path }
+        }
+    }
+
+    impl Drop for TempDirGuard {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn argv(tokens: &[&str]) -> Vec<String> {
+        tokens.iter().map(|token| token.to_string()).collect()
+    }
+
+    fn store(dir: &TempDirGuard, cipher: Option<u8>) -> HistoryStore {
+        HistoryStore {
+            path: dir.path.join(HISTORY_FILE),
+            cipher: cipher
+                .map(|byte| Arc::new(CacheCipher::from_key_bytes(&[byte; 32]).expect("cipher"))),
+        }
+    }
+
+    #[test]
+    fn append_assigns_sequential_ids_and_round_trips() {
+        let dir = TempDirGuard::new("append");
+        let store = store(&dir, None);
+
+        let first = store
+            .append(
+                HistoryOrigin::Cli,
+                "search trial".into(),
+                argv(&["search", "trial", "-c", "melanoma"]),
+                true,
+                Some(10),
+            )
+            .expect("first append");
+        let second = store
+            .append(
+                HistoryOrigin::Mcp,
+                "get gene".into(),
+                argv(&["get", "gene", "BRAF"]),
+                false,
+                None,
+            )
+            .expect("second append");
+        assert_eq!((first.id, second.id), (1, 2));
+
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&store.path)
+            .and_then(|mut file| writeln!(file, "not json"))
+            .expect("append junk");
+        let entries = store.load().expect("load");
+        assert_eq!(entries, vec![first, second.clone()]);
+        assert_eq!(entries[1].origin.as_str(), "mcp");
+
+        // The next id comes from the newest readable line, past the junk.
+        let third = store
+            .append(
+                HistoryOrigin::Cli,
+                "get gene".into(),
+                argv(&["get", "gene", "TP53"]),
+                true,
+                None,
+            )
+            .expect("third append");
+        assert_eq!(third.id, 3);
+        assert_eq!(store.id_range().expect("range"), Some((1, 3)));
+    }
+
+    #[test]
+    fn encrypted_history_hides_queries_and_needs_the_key() {
+        let dir = TempDirGuard::new("sealed");
+        let sealed = store(&dir, Some(5));
+        let entry = sealed
+            .append(
+                HistoryOrigin::Cli,
+                "get variant".into(),
+                argv(&["get", "variant", "BRAF V600E"]),
+                true,
+                Some(1),
+            )
+            .expect("append");
+
+        let raw = std::fs::read_to_string(&sealed.path).expect("read");
+        assert!(raw.starts_with(SEALED_PREFIX));
+        assert!(!raw.contains("V600E"));
+        assert_eq!(sealed.load().expect("load"), vec![entry]);
+        assert!(store(&dir, Some(6)).load().expect("load").is_empty());
+        assert!(store(&dir, None).load().expect("load").is_empty());
+    }
+
+    #[test]
+    fn result_count_reads_json_and_markdown_tables() {
+        assert_eq!(result_count(r#"{"count": 3, "results": []}"#), Some(3));
+        assert_eq!(result_count(r#"{"results": [1, 2]}"#), Some(2));
+        assert_eq!(result_count("[1, 2, 3, 4]"), Some(4));
+        assert_eq!(result_count(r#"{"symbol": "BRAF"}"#), None);
+
+        let markdown =
+            "# Trials\n\n| NCT | Title |\n|---|---|\n| NCT1 | A |\n| NCT2 | B |\n\nNext: ...";
+        assert_eq!(result_count(markdown), Some(2));
+        assert_eq!(result_count("# BRAF\n\nNo table here."), None);
+    }
+
+    #[test]
+    fn parse_enabled_is_opt_in() {
+        assert!(!parse_enabled(None));
+        assert!(parse_enabled(Some("on")));
+        assert!(parse_enabled(Some(" TRUE ")));
+        assert!(!parse_enabled(Some(" OFF ")));
+        assert!(!parse_enabled(Some("0")));
+    }
+}
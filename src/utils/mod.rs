@@ -1,6 +1,12 @@
-//! Internal utility helpers for date parsing, downloads, query escaping, and serde helpers.
+//! Internal utility helpers for cron schedules, date parsing, downloads, query history, query escaping, serde helpers, offline snapshot bundles, 2x2 table statistics, shared text cleanup, and VCF parsing.
 
+pub(crate) mod cron;
 pub(crate) mod date;
 pub(crate) mod download;
+pub(crate) mod history;
 pub(crate) mod query;
 pub(crate) mod serde;
+pub(crate) mod snapshot;
+pub(crate) mod stats;
+pub(crate) mod text;
+pub(crate) mod vcf;
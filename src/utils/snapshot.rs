@@ -0,0 +1,488 @@
+//! Offline snapshot bundles of normalized entity JSON.
+//!
+//! `biomcp snapshot create` fetches gene and variant cards while online and
+//! writes them into one bundle directory:
+//!
+//! ```text
+//! <dir>/manifest.json
+//! <dir>/gene/<nnnn>-<SYMBOL>.json
+//! <dir>/variant/<nnnn>-<ID>.json
+//! ```
+//!
+//! The manifest lists each record with the lookup keys it answers (the name
+//! as listed, the canonical symbol or ID, the rsID) and the sections it was
+//! fetched with. The global `--offline` flag scopes a loaded bundle to one
+//! command; entity `get`/`search` functions then read from it instead of the
+//! network, and anything outside the bundle fails as source-unavailable
+//! rather than not-found, so no alias fallback reaches for the network.
+//! The bundle defaults to `<cache_root>/offline-snapshot`; set
+//! `BIOMCP_SNAPSHOT_DIR` to use another directory.
+
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::BioMcpError;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const MANIFEST_VERSION: u32 = 1;
+const SNAPSHOT_DIR_ENV: &str = "BIOMCP_SNAPSHOT_DIR";
+const DEFAULT_SNAPSHOT_DIR: &str = "offline-snapshot";
+const OFFLINE_SOURCE: &str = "offline snapshot";
+
+tokio::task_local! {
+    static OFFLINE_STORE: Arc<SnapshotStore>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum SnapshotEntity {
+    Gene,
+    Variant,
+}
+
+impl SnapshotEntity {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Gene => "gene",
+            Self::Variant => "variant",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SnapshotRecord {
+    pub(crate) entity: SnapshotEntity,
+    /// File stem under `<dir>/<entity>/`.
+    pub(crate) file: String,
+    /// Normalized lookup keys, see [`lookup_key`].
+    pub(crate) keys: Vec<String>,
+    /// Sections the record was fetched with; offline `get` serves any subset.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) sections: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SnapshotManifest {
+    pub(crate) version: u32,
+    /// RFC 3339 timestamp, UTC, second precision.
+    pub(crate) created_at: String,
+    pub(crate) biomcp_version: String,
+    #[serde(default)]
+    pub(crate) records: Vec<SnapshotRecord>,
+}
+
+impl SnapshotManifest {
+    pub(crate) fn count(&self, entity: SnapshotEntity) -> usize {
+        self.records
+            .iter()
+            .filter(|record| record.entity == entity)
+            .count()
+    }
+}
+
+/// Case- and whitespace-insensitive form used for every manifest key.
+pub(crate) fn lookup_key(raw: &str) -> String {
+    raw.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_uppercase()
+}
+
+pub(crate) fn default_dir() -> Result<PathBuf, BioMcpError> {
+    if let Some(dir) = std::env::var_os(SNAPSHOT_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        return Ok(PathBuf::from(dir));
+    }
+    Ok(crate::cache::resolve_cache_config()?
+        .cache_root
+        .join(DEFAULT_SNAPSHOT_DIR))
+}
+
+/// Sections in `requested` that a record fetched with `stored` does not hold.
+fn missing_sections(stored: &[String], requested: &[String]) -> Vec<String> {
+    if stored.iter().any(|section| section == "all") {
+        return Vec::new();
+    }
+    let mut missing = Vec::new();
+    for section in requested {
+        let section = section.trim().to_ascii_lowercase();
+        if section.is_empty() || section == "--json" || section == "-j" {
+            continue;
+        }
+        if !stored.contains(&section) && !missing.contains(&section) {
+            missing.push(section);
+        }
+    }
+    missing
+}
+
+/// Error for a lookup or filter the bundle cannot answer.
+pub(crate) fn offline_unavailable(reason: String) -> BioMcpError {
+    BioMcpError::SourceUnavailable {
+        source_name: OFFLINE_SOURCE.into(),
+        reason,
+        suggestion: "add it to an entity list and re-run biomcp snapshot create with network access, or drop --offline".into(),
+    }
+}
+
+/// A bundle loaded for `--offline` reads.
+#[derive(Debug)]
+pub(crate) struct SnapshotStore {
+    dir: PathBuf,
+    manifest: SnapshotManifest,
+}
+
+impl SnapshotStore {
+    pub(crate) fn open(dir: &Path) -> Result<Self, BioMcpError> {
+        let path = dir.join(MANIFEST_FILE);
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(BioMcpError::SourceUnavailable {
+                    source_name: OFFLINE_SOURCE.into(),
+                    reason: format!("No snapshot bundle at {}.", dir.display()),
+                    suggestion: "biomcp snapshot create --entities genes.txt variants.txt (with network access), or set BIOMCP_SNAPSHOT_DIR".into(),
+                });
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let manifest: SnapshotManifest = serde_json::from_str(&content).map_err(|err| {
+            BioMcpError::InvalidArgument(format!(
+                "Unreadable snapshot manifest {}: {err}",
+                path.display()
+            ))
+        })?;
+        if manifest.version != MANIFEST_VERSION {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "Snapshot manifest {} has version {}; this biomcp reads version {MANIFEST_VERSION}. Re-create the bundle with biomcp snapshot create.",
+                path.display(),
+                manifest.version
+            )));
+        }
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            manifest,
+        })
+    }
+
+    fn record(&self, entity: SnapshotEntity, key: &str) -> Option<&SnapshotRecord> {
+        let key = lookup_key(key);
+        self.manifest
+            .records
+            .iter()
+            .find(|record| record.entity == entity && record.keys.contains(&key))
+    }
+
+    fn read<T: DeserializeOwned>(&self, record: &SnapshotRecord) -> Result<T, BioMcpError> {
+        let path = self
+            .dir
+            .join(record.entity.as_str())
+            .join(format!("{}.json", record.file));
+        let content = std::fs::read_to_string(&path).map_err(|err| {
+            BioMcpError::Io(std::io::Error::new(
+                err.kind(),
+                format!("failed to read {}: {}", path.display(), err),
+            ))
+        })?;
+        serde_json::from_str(&content).map_err(|err| {
+            BioMcpError::InvalidArgument(format!(
+                "Unreadable snapshot record {}: {err}",
+                path.display()
+            ))
+        })
+    }
+
+    /// The stored record for `key`, provided it holds every requested section.
+    pub(crate) fn get<T: DeserializeOwned>(
+        &self,
+        entity: SnapshotEntity,
+        key: &str,
+        sections: &[String],
+    ) -> Result<T, BioMcpError> {
+        let key = key.trim();
+        let Some(record) = self.record(entity, key) else {
+            return Err(offline_unavailable(format!(
+                "{} '{key}' is not in the snapshot at {}.",
+                entity.as_str(),
+                self.dir.display()
+            )));
+        };
+        let missing = missing_sections(&record.sections, sections);
+        if !missing.is_empty() {
+            return Err(offline_unavailable(format!(
+                "The snapshot holds {} '{key}' without the {} section(s); it was fetched with: {}.",
+                entity.as_str(),
+                missing.join(", "),
+                if record.sections.is_empty() {
+                    "the default card".to_string()
+                } else {
+                    record.sections.join(", ")
+                }
+            )));
+        }
+        self.read(record)
+    }
+
+    /// Every stored record of `entity`, in manifest order.
+    pub(crate) fn all<T: DeserializeOwned>(
+        &self,
+        entity: SnapshotEntity,
+    ) -> Result<Vec<T>, BioMcpError> {
+        self.manifest
+            .records
+            .iter()
+            .filter(|record| record.entity == entity)
+            .map(|record| self.read(record))
+            .collect()
+    }
+}
+
+/// Serves entity reads inside `fut` from `store` when one is given.
+pub(crate) async fn with_offline<R, F>(store: Option<Arc<SnapshotStore>>, fut: F) -> R
+where
+    F: Future<Output = R>,
+{
+    match store {
+        Some(store) => OFFLINE_STORE.scope(store, fut).await,
+        None => fut.await,
+    }
+}
+
+pub(crate) fn offline_store() -> Option<Arc<SnapshotStore>> {
+    OFFLINE_STORE.try_with(Arc::clone).ok()
+}
+
+/// File-name-safe form of a lookup key; the index prefix keeps stems unique.
+fn file_stem(index: usize, key: &str) -> String {
+    let safe = key
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.') {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .take(80)
+        .collect::<String>();
+    format!("{index:04}-{safe}")
+}
+
+/// Builds a bundle in a staging directory and swaps it into place on
+/// [`SnapshotWriter::finish`], so a failed run leaves the old bundle intact.
+pub(crate) struct SnapshotWriter {
+    dir: PathBuf,
+    staging: PathBuf,
+    records: Vec<SnapshotRecord>,
+}
+
+impl SnapshotWriter {
+    pub(crate) fn create(dir: &Path) -> Result<Self, BioMcpError> {
+        if dir.is_dir()
+            && !dir.join(MANIFEST_FILE).is_file()
+            && std::fs::read_dir(dir)?.next().is_some()
+        {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "{} exists and is not a snapshot bundle; choose an empty directory with --dir",
+                dir.display()
+            )));
+        }
+        let mut staging = dir.as_os_str().to_owned();
+        staging.push(".partial");
+        let staging = PathBuf::from(staging);
+        if staging.exists() {
+            std::fs::remove_dir_all(&staging)?;
+        }
+        std::fs::create_dir_all(&staging)?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            staging,
+            records: Vec::new(),
+        })
+    }
+
+    /// Stores `value` under every key in `keys`; the first key names the file.
+    pub(crate) fn add<T: Serialize>(
+        &mut self,
+        entity: SnapshotEntity,
+        keys: &[&str],
+        sections: &[String],
+        value: &T,
+    ) -> Result<(), BioMcpError> {
+        let mut normalized: Vec<String> = Vec::new();
+        for key in keys {
+            let key = lookup_key(key);
+            if !key.is_empty() && !normalized.contains(&key) {
+                normalized.push(key);
+            }
+        }
+        let Some(first) = normalized.first() else {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "snapshot {} record needs at least one lookup key",
+                entity.as_str()
+            )));
+        };
+        let file = file_stem(self.records.len() + 1, first);
+        let entity_dir = self.staging.join(entity.as_str());
+        std::fs::create_dir_all(&entity_dir)?;
+        std::fs::write(
+            entity_dir.join(format!("{file}.json")),
+            serde_json::to_string(value)?,
+        )?;
+        self.records.push(SnapshotRecord {
+            entity,
+            file,
+            keys: normalized,
+            sections: sections
+                .iter()
+                .map(|section| section.trim().to_ascii_lowercase())
+                .filter(|section| !section.is_empty())
+                .collect(),
+        });
+        Ok(())
+    }
+
+    pub(crate) fn finish(self, created_at: String) -> Result<SnapshotManifest, BioMcpError> {
+        let manifest = SnapshotManifest {
+            version: MANIFEST_VERSION,
+            created_at,
+            biomcp_version: env!("CARGO_PKG_VERSION").to_string(),
+            records: self.records,
+        };
+        std::fs::write(
+            self.staging.join(MANIFEST_FILE),
+            serde_json::to_string_pretty(&manifest)?,
+        )?;
+        if self.dir.exists() {
+            std::fs::remove_dir_all(&self.dir)?;
+        }
+        if let Some(parent) = self.dir.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::rename(&self.staging, &self.dir)?;
+        Ok(manifest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::*;
+
+    struct TempDirGuard {
+        path: PathBuf,
+    }
+
+    impl TempDirGuard {
+        fn new(label: &str) -> Self {
+            let stamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos();
+            let path = std::env::temp_dir().join(format!(
+                "biomcp-snapshot-test-{label}-{}-{stamp}",
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&path).expect("temp dir should be created");
+            Self { path }
+        }
+    }
+
+    impl Drop for TempDirGuard {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn sections(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn writer_round_trips_records_by_any_key_and_checks_sections() {
+        let root = TempDirGuard::new("round-trip");
+        let dir = root.path.join("bundle");
+        let mut writer = SnapshotWriter::create(&dir).expect("writer");
+        writer
+            .add(
+                SnapshotEntity::Gene,
+                &["braf", "BRAF"],
+                &sections(&["pathways"]),
+                &serde_json::json!({"symbol": "BRAF"}),
+            )
+            .expect("add gene");
+        writer
+            .add(
+                SnapshotEntity::Variant,
+                &["BRAF  V600E", "chr7:g.140453136A>T", "rs113488022"],
+                &[],
+                &serde_json::json!({"id": "chr7:g.140453136A>T"}),
+            )
+            .expect("add variant");
+        let manifest = writer
+            .finish("2026-10-16T00:00:00Z".into())
+            .expect("finish");
+        assert_eq!(manifest.count(SnapshotEntity::Gene), 1);
+        assert_eq!(manifest.records[1].file, "0002-BRAF_V600E");
+
+        let store = SnapshotStore::open(&dir).expect("open");
+        let gene: serde_json::Value = store
+            .get(
+                SnapshotEntity::Gene,
+                " Braf ",
+                &sections(&["pathways", "--json"]),
+            )
+            .expect("gene");
+        assert_eq!(gene["symbol"], "BRAF");
+        let variant: serde_json::Value = store
+            .get(SnapshotEntity::Variant, "braf v600e", &[])
+            .expect("variant by alias");
+        assert_eq!(variant["id"], "chr7:g.140453136A>T");
+
+        let err = store
+            .get::<serde_json::Value>(SnapshotEntity::Gene, "BRAF", &sections(&["civic"]))
+            .expect_err("uncovered section");
+        assert!(err.to_string().contains("without the civic section(s)"));
+        let err = store
+            .get::<serde_json::Value>(SnapshotEntity::Gene, "TP53", &[])
+            .expect_err("uncovered gene");
+        assert_eq!(err.code(), "source_unavailable");
+        assert!(
+            err.to_string()
+                .contains("gene 'TP53' is not in the snapshot")
+        );
+
+        let genes: Vec<serde_json::Value> = store.all(SnapshotEntity::Gene).expect("all");
+        assert_eq!(genes.len(), 1);
+    }
+
+    #[test]
+    fn open_reports_missing_bundle_and_writer_refuses_foreign_dirs() {
+        let root = TempDirGuard::new("missing");
+        let err = SnapshotStore::open(&root.path.join("none")).expect_err("missing bundle");
+        assert!(err.to_string().contains("No snapshot bundle at"));
+
+        std::fs::write(root.path.join("notes.txt"), "keep me").expect("write");
+        let err = SnapshotWriter::create(&root.path)
+            .err()
+            .expect("foreign dir");
+        assert!(err.to_string().contains("is not a snapshot bundle"));
+        assert!(root.path.join("notes.txt").is_file());
+    }
+
+    #[test]
+    fn missing_sections_honors_all_and_ignores_json_flags() {
+        assert!(missing_sections(&sections(&["all"]), &sections(&["civic"])).is_empty());
+        assert_eq!(
+            missing_sections(
+                &sections(&["civic"]),
+                &sections(&["civic", "-j", "gwas", "GWAS"])
+            ),
+            vec!["gwas"]
+        );
+    }
+}
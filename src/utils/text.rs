@@ -0,0 +1,39 @@
+/// Trims an optional upstream or user value, treating blank strings as missing.
+pub(crate) fn non_empty(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Makes a value safe for one TSV cell: tabs and line breaks become spaces.
+pub(crate) fn tsv_field(value: &str) -> String {
+    value
+        .chars()
+        .map(|ch| {
+            if matches!(ch, '\t' | '\n' | '\r') {
+                ' '
+            } else {
+                ch
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{non_empty, tsv_field};
+
+    #[test]
+    fn non_empty_trims_and_drops_blank_values() {
+        assert_eq!(non_empty(Some("  BRAF ")).as_deref(), Some("BRAF"));
+        assert_eq!(non_empty(Some(" \t")), None);
+        assert_eq!(non_empty(None), None);
+    }
+
+    #[test]
+    fn tsv_field_replaces_tabs_and_line_breaks() {
+        assert_eq!(tsv_field("a\tb\r\nc"), "a b  c");
+        assert_eq!(tsv_field("plain"), "plain");
+    }
+}
@@ -0,0 +1,240 @@
+//! Minimal VCF reader for local annotation.
+//!
+//! Only the fixed columns are interpreted: meta-information and the `#CHROM`
+//! header are kept verbatim so annotated output can reproduce them, and each
+//! data line keeps its raw fields. [`hgvs_genomic`] turns one REF/ALT pair into
+//! the MyVariant.info `chrN:g.` identifier for that allele.
+
+use crate::error::BioMcpError;
+
+/// Upper bound on data lines; larger call sets should be filtered first.
+const MAX_VCF_RECORDS: usize = 100_000;
+const FIXED_COLUMNS: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct VcfRecord {
+    /// 1-based line number in the source file.
+    pub(crate) line: usize,
+    pub(crate) chrom: String,
+    pub(crate) pos: u64,
+    pub(crate) id: String,
+    pub(crate) reference: String,
+    pub(crate) alts: Vec<String>,
+    /// Every tab-separated field of the line, including the fixed columns.
+    pub(crate) fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct VcfFile {
+    /// `##` meta-information lines, without the trailing newline.
+    pub(crate) meta: Vec<String>,
+    /// The `#CHROM` header line, when present.
+    pub(crate) header: Option<String>,
+    pub(crate) records: Vec<VcfRecord>,
+}
+
+fn invalid(line: usize, message: impl std::fmt::Display) -> BioMcpError {
+    BioMcpError::InvalidArgument(format!("VCF line {line}: {message}"))
+}
+
+fn is_nucleotides(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|ch| matches!(ch.to_ascii_uppercase(), 'A' | 'C' | 'G' | 'T' | 'N'))
+}
+
+/// Parses VCF text; data lines need the eight fixed tab-separated columns.
+pub(crate) fn parse(content: &str) -> Result<VcfFile, BioMcpError> {
+    let content = content.trim_start_matches('\u{feff}');
+    let mut meta = Vec::new();
+    let mut header = None;
+    let mut records = Vec::new();
+    for (idx, raw) in content.lines().enumerate() {
+        let line = idx + 1;
+        let raw = raw.trim_end_matches('\r');
+        if raw.trim().is_empty() {
+            continue;
+        }
+        if raw.starts_with("##") {
+            meta.push(raw.to_string());
+            continue;
+        }
+        if raw.starts_with('#') {
+            header = Some(raw.to_string());
+            continue;
+        }
+
+        let fields = raw.split('\t').map(str::to_string).collect::<Vec<_>>();
+        if fields.len() < FIXED_COLUMNS {
+            return Err(invalid(
+                line,
+                format_args!(
+                    "has {} columns; expected at least {FIXED_COLUMNS} tab-separated columns",
+                    fields.len()
+                ),
+            ));
+        }
+        let pos = fields[1]
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .filter(|pos| *pos > 0)
+            .ok_or_else(|| invalid(line, format_args!("invalid POS '{}'", fields[1])))?;
+        let reference = fields[3].trim().to_ascii_uppercase();
+        if !is_nucleotides(&reference) {
+            return Err(invalid(line, format_args!("invalid REF '{}'", fields[3])));
+        }
+        if records.len() == MAX_VCF_RECORDS {
+            return Err(BioMcpError::InvalidArgument(format!(
+                "VCF has more than {MAX_VCF_RECORDS} records; filter it (e.g. by region or PASS) first"
+            )));
+        }
+        records.push(VcfRecord {
+            line,
+            chrom: fields[0].trim().to_string(),
+            pos,
+            id: fields[2].trim().to_string(),
+            reference,
+            alts: fields[4]
+                .split(',')
+                .map(|alt| alt.trim().to_ascii_uppercase())
+                .collect(),
+            fields,
+        });
+    }
+    Ok(VcfFile {
+        meta,
+        header,
+        records,
+    })
+}
+
+/// MyVariant.info chromosome label: `chr` prefix, `MT` for mitochondria.
+fn normalize_chrom(chrom: &str) -> Option<String> {
+    let trimmed = chrom.trim();
+    let bare = trimmed
+        .strip_prefix("chr")
+        .or_else(|| trimmed.strip_prefix("CHR"))
+        .or_else(|| trimmed.strip_prefix("Chr"))
+        .unwrap_or(trimmed)
+        .to_ascii_uppercase();
+    let bare = match bare.as_str() {
+        "M" => "MT".to_string(),
+        _ => bare,
+    };
+    let numbered = bare
+        .parse::<u8>()
+        .is_ok_and(|number| (1..=22).contains(&number));
+    (numbered || matches!(bare.as_str(), "X" | "Y" | "MT")).then(|| format!("chr{bare}"))
+}
+
+/// Genomic HGVS for one allele in the form MyVariant.info uses as `_id`
+/// (`chr7:g.140453136A>T`, `chr17:g.41276045_41276046del`,
+/// `chr13:g.32914438_32914439insT`). Symbolic, missing, and spanning-deletion
+/// alleles, and contigs other than 1-22/X/Y/MT, return `None`.
+pub(crate) fn hgvs_genomic(chrom: &str, pos: u64, reference: &str, alt: &str) -> Option<String> {
+    let chrom = normalize_chrom(chrom)?;
+    let reference = reference.to_ascii_uppercase();
+    let alt = alt.to_ascii_uppercase();
+    if !is_nucleotides(&reference) || !is_nucleotides(&alt) || reference == alt {
+        return None;
+    }
+
+    if reference.len() == 1 && alt.len() == 1 {
+        return Some(format!("{chrom}:g.{pos}{reference}>{alt}"));
+    }
+
+    // Strip the shared anchor base(s) VCF places before indels.
+    let shared = reference
+        .bytes()
+        .zip(alt.bytes())
+        .take_while(|(r, a)| r == a)
+        .count();
+    let start = pos + shared as u64;
+    let deleted = &reference[shared..];
+    let inserted = &alt[shared..];
+    let span = |len: usize| {
+        let end = start + len as u64 - 1;
+        if end == start {
+            start.to_string()
+        } else {
+            format!("{start}_{end}")
+        }
+    };
+    Some(match (deleted.len(), inserted.len()) {
+        (1, 1) => format!("{chrom}:g.{start}{deleted}>{inserted}"),
+        (len, 0) => format!("{chrom}:g.{}del", span(len)),
+        (0, _) => format!("{chrom}:g.{}_{start}ins{inserted}", start - 1),
+        (len, _) => format!("{chrom}:g.{}delins{inserted}", span(len)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_keeps_header_lines_and_splits_alts() {
+        let vcf = "##fileformat=VCFv4.2\n\
+                   ##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Depth\">\n\
+                   #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n\
+                   7\t140453136\trs113488022\ta\tT,C\t50\tPASS\tDP=20\r\n\
+                   \n\
+                   chr17\t41276044\t.\tACT\tA\t.\t.\t.\n";
+        let parsed = parse(vcf).expect("valid vcf");
+        assert_eq!(parsed.meta.len(), 2);
+        assert!(
+            parsed
+                .header
+                .as_deref()
+                .is_some_and(|h| h.starts_with("#CHROM"))
+        );
+        assert_eq!(parsed.records.len(), 2);
+        let first = &parsed.records[0];
+        assert_eq!(first.line, 4);
+        assert_eq!(first.reference, "A");
+        assert_eq!(first.alts, vec!["T", "C"]);
+        assert_eq!(first.fields[7], "DP=20");
+        assert_eq!(parsed.records[1].line, 6);
+    }
+
+    #[test]
+    fn parse_rejects_short_rows_and_bad_positions() {
+        let err = parse("1\t100\t.\tA\n").expect_err("short row");
+        assert!(err.to_string().contains("VCF line 1: has 4 columns"));
+        let err = parse("1\tabc\t.\tA\tT\t.\t.\t.\n").expect_err("bad pos");
+        assert!(err.to_string().contains("invalid POS 'abc'"));
+    }
+
+    #[test]
+    fn hgvs_genomic_matches_myvariant_ids() {
+        assert_eq!(
+            hgvs_genomic("7", 140453136, "A", "T").as_deref(),
+            Some("chr7:g.140453136A>T")
+        );
+        assert_eq!(
+            hgvs_genomic("chr17", 41276044, "ACT", "A").as_deref(),
+            Some("chr17:g.41276045_41276046del")
+        );
+        assert_eq!(
+            hgvs_genomic("17", 41276044, "AC", "A").as_deref(),
+            Some("chr17:g.41276045del")
+        );
+        assert_eq!(
+            hgvs_genomic("13", 32914438, "G", "GT").as_deref(),
+            Some("chr13:g.32914438_32914439insT")
+        );
+        assert_eq!(
+            hgvs_genomic("chrM", 3243, "AG", "TC").as_deref(),
+            Some("chrMT:g.3243_3244delinsTC")
+        );
+        assert_eq!(
+            hgvs_genomic("2", 500, "AG", "AT").as_deref(),
+            Some("chr2:g.501G>T")
+        );
+        assert_eq!(hgvs_genomic("1", 100, "A", "<DEL>"), None);
+        assert_eq!(hgvs_genomic("1", 100, "A", "*"), None);
+        assert_eq!(hgvs_genomic("GL000220.1", 100, "A", "T"), None);
+    }
+}
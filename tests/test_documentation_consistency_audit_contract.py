@@ -130,13 +130,16 @@ ENTITY_GUIDE_HEADINGS = {
 
 PUBLIC_ENV_VARS = (
     "ALPHAGENOME_API_KEY",
+    "CLUE_API_KEY",
     "DISGENET_API_KEY",
     "NCBI_API_KEY",
     "NCI_API_KEY",
     "ONCOKB_TOKEN",
     "OPENFDA_API_KEY",
+    "PATENTSVIEW_API_KEY",
     "S2_API_KEY",
     "UMLS_API_KEY",
+    "USPSTF_API_KEY",
 )
 
 CHART_REFERENCE_PAGES = [
@@ -272,7 +275,7 @@ def test_entity_guides_keep_canonical_section_flow_and_note_missing_families() -
     assert 'biomcp disease articles "Lynch syndrome"' in disease
     assert "no direct `trial <helper>` family" in trial
     assert "`biomcp drug adverse-events <name>`" in adverse_event
-    assert "PGX does not expose a separate helper family" in pgx
+    assert "biomcp pgx panel --genes CYP2D6,CYP2C19,TPMT,DPYD" in pgx
     assert "GWAS is search-only." in gwas
     assert "Phenotype is search-only." in phenotype
     assert "## Practical tips" in pathway
@@ -48,6 +48,31 @@ async def test_initialize_advertises_tools_and_resources(
         assert "biomcp skill" in initialize_result.instructions
 
 
+@pytest.mark.asyncio
+async def test_prompts_list_and_get_workflows(mcp_session_factory) -> None:
+    async with mcp_session_factory() as (session, initialize_result):
+        assert initialize_result.capabilities.prompts is not None
+
+        result = await session.list_prompts()
+        prompts = {prompt.name: prompt for prompt in result.prompts}
+        assert set(prompts) == {"variant-workup", "trial-matching", "drug-safety-review"}
+        required = {
+            argument.name
+            for argument in prompts["variant-workup"].arguments or []
+            if argument.required
+        }
+        assert required == {"variant"}
+
+        prompt = await session.get_prompt(
+            "trial-matching",
+            arguments={"condition": "melanoma", "mutation": "BRAF V600E", "age": "54"},
+        )
+        assert len(prompt.messages) == 1
+        text = prompt.messages[0].content.text
+        assert 'search trial -c "melanoma" -s recruiting --mutation "BRAF V600E" --age 54' in text
+        assert "get trial <nct_id> eligibility" in text
+
+
 @pytest.mark.asyncio
 async def test_list_tools_includes_biomcp(mcp_session_factory) -> None:
     async with mcp_session_factory() as (session, _initialize_result):
@@ -120,6 +145,15 @@ async def test_biomcp_description_matches_list_contract(
         assert "cache stats" not in description
         assert "cache clean" not in description
         assert "cache clear" not in description
+        assert "bookmark save" not in description
+        assert "bookmark run" not in description
+        assert "history replay" not in description
+        assert "annotate deg" not in description
+        assert "annotate vcf" not in description
+        assert "repurpose --up" not in description
+        assert "match trials" not in description
+        assert "pgx panel --genes <g1,g2,...>" in description
+        assert "--vcf <path>" not in description
         assert "update [--check]" not in description
         assert "uninstall" not in description
 
@@ -127,8 +161,10 @@ async def test_biomcp_description_matches_list_contract(
 @pytest.mark.asyncio
 async def test_list_resources_returns_expected_inventory(
     mcp_session_factory,
+    tmp_path,
 ) -> None:
-    async with mcp_session_factory() as (session, _initialize_result):
+    extra_env = {"BIOMCP_CACHE_DIR": str(tmp_path)}
+    async with mcp_session_factory(extra_env) as (session, _initialize_result):
         result = await session.list_resources()
         actual = [(str(resource.uri), resource.name) for resource in result.resources]
 
@@ -138,8 +174,10 @@ async def test_list_resources_returns_expected_inventory(
 @pytest.mark.asyncio
 async def test_read_resource_returns_markdown_for_every_uri(
     mcp_session_factory,
+    tmp_path,
 ) -> None:
-    async with mcp_session_factory() as (session, _initialize_result):
+    extra_env = {"BIOMCP_CACHE_DIR": str(tmp_path)}
+    async with mcp_session_factory(extra_env) as (session, _initialize_result):
         listed = await session.list_resources()
         resource_uris = [str(resource.uri) for resource in listed.resources]
         assert resource_uris
@@ -53,13 +53,17 @@ def _expected_skill_resources() -> list[tuple[str, str]]:
 
 
 @pytest.fixture
-def http_server_url() -> Iterator[str]:
+def http_server_url(tmp_path: Path) -> Iterator[str]:
     binary = _require_release_binary()
     port = _reserve_port()
     base_url = f"http://127.0.0.1:{port}"
+    # An empty cache keeps downloaded full texts and gene snapshots out of the
+    # resource inventory.
+    env = {**os.environ, "BIOMCP_CACHE_DIR": str(tmp_path / "cache")}
     proc = subprocess.Popen(
         [str(binary), "serve-http", "--host", "127.0.0.1", "--port", str(port)],
         cwd=REPO_ROOT,
+        env=env,
         stdout=subprocess.PIPE,
         stderr=subprocess.STDOUT,
         text=True,
@@ -68,6 +68,7 @@ def test_public_skill_docs_match_current_cli_contract() -> None:
     assert "biomcp cache path" in cli_reference
     assert "biomcp cache stats" in cli_reference
     assert "biomcp cache clean" in cli_reference
+    assert "biomcp cache purge" in cli_reference
     assert "biomcp cache clear" in cli_reference
     assert "biomcp skill list                 # list embedded worked examples" in cli_reference
     assert 'biomcp discover "developmental delay"' in cli_reference
@@ -75,7 +76,7 @@ def test_public_skill_docs_match_current_cli_contract() -> None:
     assert (
         "`--json` normally returns structured output, but `biomcp cache path` "
         "is a plain-text exception. `biomcp cache stats`, `biomcp cache clean`, "
-        "and `biomcp cache clear` respect `--json` on success. `biomcp cache clear` "
+        "`biomcp cache purge`, and `biomcp cache clear` respect `--json` on success. `biomcp cache clear` "
         "still refuses non-TTY destructive runs with plain stderr unless you pass `--yes`."
         in cli_reference
     )
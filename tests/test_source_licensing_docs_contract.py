@@ -7,19 +7,26 @@ from pathlib import Path
 REPO_ROOT = Path(__file__).resolve().parents[1]
 
 DIRECT_SOURCE_MODULES = {
+    "allele_registry": "ClinGen Allele Registry",
     "alphagenome": "AlphaGenome",
     "cbioportal": "cBioPortal",
     "chembl": "ChEMBL",
     "civic": "CIViC",
     "clingen": "ClinGen",
     "clinicaltrials": "ClinicalTrials.gov",
+    "clinvar": "ClinVar",
+    "clue": "CLUE",
     "complexportal": "ComplexPortal",
     "cpic": "CPIC",
+    "ctis": "CTIS",
+    "depmap": "DepMap",
     "dgidb": "DGIdb",
     "disgenet": "DisGeNET",
     "ema": "EMA",
     "who_pq": "WHO Prequalification",
+    "who_gho": "WHO GHO",
     "enrichr": "Enrichr",
+    "ensembl": "Ensembl REST",
     "europepmc": "Europe PMC",
     "gnomad": "gnomAD",
     "gprofiler": "g:Profiler",
@@ -28,9 +35,12 @@ DIRECT_SOURCE_MODULES = {
     "hpa": "Human Protein Atlas",
     "hpo": "HPO JAX API",
     "interpro": "InterPro",
+    "ipd_imgt": "IPD-IMGT/HLA",
     "kegg": "KEGG",
     "litsense2": "LitSense2",
+    "litvar": "LitVar",
     "medlineplus": "MedlinePlus",
+    "medlineplus_herbs": "MedlinePlus Supplements",
     "monarch": "Monarch Initiative",
     "mychem": "MyChem.info",
     "mydisease": "MyDisease.info",
@@ -39,29 +49,36 @@ DIRECT_SOURCE_MODULES = {
     "ncbi_efetch": "NCBI E-utilities",
     "ncbi_idconv": "NCBI ID Converter",
     "nci_cts": "NCI CTS",
+    "nci_evs": "NCI EVS",
     "nih_reporter": "NIH Reporter",
     "ols4": "OLS4",
     "oncokb": "OncoKB",
     "openfda": "OpenFDA",
     "opentargets": "OpenTargets",
+    "patentsview": "PatentsView",
     "pharmgkb": "PharmGKB",
     "pmc_oa": "PMC OA",
     "pubmed": "PubMed",
     "pubtator": "PubTator3",
     "quickgo": "QuickGO",
+    "rcsb": "RCSB PDB",
     "reactome": "Reactome",
+    "scimago": "SCImago Journal Rank",
     "seer": "SEER Explorer",
     "semantic_scholar": "Semantic Scholar",
     "string": "STRING",
     "umls": "UMLS",
     "uniprot": "UniProt",
+    "unpaywall": "Unpaywall",
+    "uspstf": "USPSTF",
+    "variantvalidator": "VariantValidator",
+    "vep": "Ensembl VEP",
     "wikipathways": "WikiPathways",
 }
 
 INDIRECT_ONLY_ROWS = {
     "AlphaFold DB": "UniProt",
     "Cancer Genome Interpreter": "MyVariant.info",
-    "ClinVar": "MyVariant.info",
     "COSMIC": "MyVariant.info",
     "Disease Ontology": "MyDisease.info",
     "DrugBank": "MyChem.info",
@@ -99,7 +116,18 @@ def test_sources_inventory_is_complete_and_schema_conformant() -> None:
     discovered_modules = [
         module
         for module in discovered_modules
-        if module not in {"rate_limit", "cbioportal_download", "cbioportal_study"}
+        if module
+        not in {
+            "concurrency",
+            "passthrough",
+            "plugin",
+            "rate_limit",
+            "raw_capture",
+            "request_stats",
+            "tenant",
+            "cbioportal_download",
+            "cbioportal_study",
+        }
     ]
     assert sorted(discovered_modules) == sorted(DIRECT_SOURCE_MODULES)
 
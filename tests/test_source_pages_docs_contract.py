@@ -74,22 +74,24 @@ SOURCE_PAGE_SPECS = {
     "clinvar.md": {
         "title": "ClinVar MCP Tool for Variant Interpretation | BioMCP",
         "description": "Use BioMCP to pull ClinVar clinical significance, review status, and disease context for human variants through one variant lookup workflow.",
-        "api_access": "No standalone BioMCP key path; ClinVar content is surfaced indirectly via MyVariant.info.",
+        "api_access": "No key required; set `NCBI_API_KEY` for higher E-utilities throughput on the `clinvar-submissions` section.",
         "official_url": "https://www.ncbi.nlm.nih.gov/clinvar/",
         "required_intro_phrases": [
-            "indirect-only",
             "MyVariant.info",
-            "does not act as a direct ClinVar API client",
+            "NCBI E-utilities",
+            "per-submitter",
         ],
         "exposes": [
             "get variant <id>",
             "get variant <id> clinvar",
+            "get variant <id> clinvar-submissions",
             "search variant -g <gene> --significance <value>",
         ],
         "example_commands": [
             "biomcp get variant rs113488022",
             "biomcp get variant rs113488022 clinvar",
             'biomcp get variant "BRAF V600E" clinvar',
+            "biomcp get variant rs113488022 clinvar-submissions",
             "biomcp search variant -g BRCA1 --significance pathogenic --limit 5",
         ],
     },
@@ -8,13 +8,22 @@ import sys
 from pathlib import Path
 
 BLOCKED_FAMILIES = {
+    "annotate",
+    "bookmark",
     "cache",
     "chart",
+    "diff",
     "ema",
+    "exec",
+    "history",
+    "match",
     "mcp",
+    "repurpose",
+    "schedule",
     "serve",
     "serve-http",
     "serve-sse",
+    "snapshot",
     "uninstall",
     "update",
     "who",
@@ -7,20 +7,37 @@ import re
 import sys
 from pathlib import Path
 
-HELPER_MODULES = {"cbioportal_download", "cbioportal_study", "rate_limit"}
-EXEMPT_MODULES = {"ema", "who_pq"}
+HELPER_MODULES = {
+    "cbioportal_download",
+    "cbioportal_study",
+    "concurrency",
+    "passthrough",
+    "plugin",
+    "rate_limit",
+    "raw_capture",
+    "request_stats",
+    "tenant",
+}
+EXEMPT_MODULES = {"ema", "scimago", "who_pq"}
 HEALTH_ALIASES = {
+    "allele_registry": "ClinGen Allele Registry",
     "cbioportal": "cBioPortal",
     "clinicaltrials": "ClinicalTrials.gov",
+    "ensembl": "Ensembl REST",
     "gprofiler": "g:Profiler",
     "gwas": "GWAS Catalog",
+    "ipd_imgt": "IPD-IMGT/HLA",
+    "medlineplus_herbs": "MedlinePlus Supplements",
     "ncbi_efetch": "NCBI E-utilities",
     "ncbi_idconv": "NCBI ID Converter",
     "nci_cts": "NCI CTS",
+    "nci_evs": "NCI EVS",
     "pmc_oa": "PMC OA",
     "pubtator": "PubTator3",
+    "rcsb": "RCSB PDB",
     "seer": "SEER Explorer",
     "semantic_scholar": "Semantic Scholar",
+    "vep": "Ensembl VEP",
 }
 
 